@@ -1,6 +1,8 @@
 package arwen
 
 import (
+	"math/big"
+
 	"github.com/ElrondNetwork/wasm-vm/math"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 )
@@ -20,7 +22,14 @@ type AsyncCall struct {
 	SuccessCallback string
 	ErrorCallback   string
 
-	CallbackClosure []byte
+	CallbackClosure  []byte
+	CallbackArgTypes CallbackArgTypes
+
+	ExpiryRound uint64
+
+	// ValueLocked is set once the value of the call was added to the ledger
+	// of locked values, so that only its callback releases it
+	ValueLocked bool
 }
 
 // Clone creates a deep clone of the AsyncCall
@@ -36,6 +45,13 @@ func (ac *AsyncCall) Clone() *AsyncCall {
 		ValueBytes:      make([]byte, len(ac.ValueBytes)),
 		SuccessCallback: ac.SuccessCallback,
 		ErrorCallback:   ac.ErrorCallback,
+		ExpiryRound:     ac.ExpiryRound,
+		ValueLocked:     ac.ValueLocked,
+	}
+
+	if len(ac.CallbackArgTypes) > 0 {
+		clone.CallbackArgTypes = make(CallbackArgTypes, len(ac.CallbackArgTypes))
+		copy(clone.CallbackArgTypes, ac.CallbackArgTypes)
 	}
 
 	copy(clone.Destination, ac.Destination)
@@ -113,6 +129,21 @@ func (ac *AsyncCall) UpdateStatus(returnCode vmcommon.ReturnCode) {
 	}
 }
 
+// HasExpiry returns true if the async call must be answered before an expiry round
+func (ac *AsyncCall) HasExpiry() bool {
+	return ac.ExpiryRound > 0
+}
+
+// HasExpired returns true if the expiry round of the async call was reached
+func (ac *AsyncCall) HasExpired(currentRound uint64) bool {
+	return ac.HasExpiry() && currentRound >= ac.ExpiryRound
+}
+
+// IsRejected returns true if the async call was rejected
+func (ac *AsyncCall) IsRejected() bool {
+	return ac.Status == AsyncCallRejected
+}
+
 // Reject sets the rejected status for this async call
 func (ac *AsyncCall) Reject() {
 	ac.Status = AsyncCallRejected
@@ -128,6 +159,28 @@ func (ac *AsyncCall) GetCallbackName() string {
 	return ac.ErrorCallback
 }
 
+// NormalizeCallbackArguments verifies the arguments of the success callback,
+// i.e. the return code followed by the values finished by the destination,
+// against the registered CallbackArgTypes. On a mismatch, the async call is
+// rejected and the arguments become those of a failed call, carrying the
+// decode error as message.
+func (ac *AsyncCall) NormalizeCallbackArguments(arguments [][]byte) [][]byte {
+	if ac.Status != AsyncCallResolved || len(ac.CallbackArgTypes) == 0 || len(arguments) == 0 {
+		return arguments
+	}
+
+	values, err := ac.CallbackArgTypes.Normalize(arguments[1:])
+	if err != nil {
+		ac.Reject()
+		return [][]byte{
+			big.NewInt(int64(vmcommon.UserError)).Bytes(),
+			[]byte(err.Error()),
+		}
+	}
+
+	return append([][]byte{arguments[0]}, values...)
+}
+
 // IsInterfaceNil returns true if there is no value under the interface
 func (ac *AsyncCall) IsInterfaceNil() bool {
 	return ac == nil
@@ -135,17 +188,20 @@ func (ac *AsyncCall) IsInterfaceNil() bool {
 
 func (ac *AsyncCall) toSerializable() *SerializableAsyncCall {
 	return &SerializableAsyncCall{
-		CallID:          ac.CallID,
-		Status:          SerializableAsyncCallStatus(ac.Status),
-		ExecutionMode:   SerializableAsyncCallExecutionMode(ac.ExecutionMode),
-		Destination:     ac.Destination,
-		Data:            ac.Data,
-		GasLimit:        ac.GasLimit,
-		GasLocked:       ac.GasLocked,
-		ValueBytes:      ac.ValueBytes,
-		SuccessCallback: ac.SuccessCallback,
-		ErrorCallback:   ac.ErrorCallback,
-		CallbackClosure: ac.CallbackClosure,
+		CallID:           ac.CallID,
+		Status:           SerializableAsyncCallStatus(ac.Status),
+		ExecutionMode:    SerializableAsyncCallExecutionMode(ac.ExecutionMode),
+		Destination:      ac.Destination,
+		Data:             ac.Data,
+		GasLimit:         ac.GasLimit,
+		GasLocked:        ac.GasLocked,
+		ValueBytes:       ac.ValueBytes,
+		SuccessCallback:  ac.SuccessCallback,
+		ErrorCallback:    ac.ErrorCallback,
+		CallbackClosure:  ac.CallbackClosure,
+		CallbackArgTypes: ac.CallbackArgTypes.Bytes(),
+		ExpiryRound:      ac.ExpiryRound,
+		ValueLocked:      ac.ValueLocked,
 	}
 }
 
@@ -159,16 +215,31 @@ func fromSerializableAsyncCalls(serializableAsyncCalls []*SerializableAsyncCall)
 
 func (serAsyncCall *SerializableAsyncCall) fromSerializable() *AsyncCall {
 	return &AsyncCall{
-		CallID:          serAsyncCall.CallID,
-		Status:          AsyncCallStatus(serAsyncCall.Status),
-		ExecutionMode:   AsyncCallExecutionMode(serAsyncCall.ExecutionMode),
-		Destination:     serAsyncCall.Destination,
-		Data:            serAsyncCall.Data,
-		GasLimit:        serAsyncCall.GasLimit,
-		GasLocked:       serAsyncCall.GasLocked,
-		ValueBytes:      serAsyncCall.ValueBytes,
-		SuccessCallback: serAsyncCall.SuccessCallback,
-		ErrorCallback:   serAsyncCall.ErrorCallback,
-		CallbackClosure: serAsyncCall.CallbackClosure,
+		CallID:           serAsyncCall.CallID,
+		Status:           AsyncCallStatus(serAsyncCall.Status),
+		ExecutionMode:    AsyncCallExecutionMode(serAsyncCall.ExecutionMode),
+		Destination:      serAsyncCall.Destination,
+		Data:             serAsyncCall.Data,
+		GasLimit:         serAsyncCall.GasLimit,
+		GasLocked:        serAsyncCall.GasLocked,
+		ValueBytes:       serAsyncCall.ValueBytes,
+		SuccessCallback:  serAsyncCall.SuccessCallback,
+		ErrorCallback:    serAsyncCall.ErrorCallback,
+		CallbackClosure:  serAsyncCall.CallbackClosure,
+		CallbackArgTypes: callbackArgTypesFromBytes(serAsyncCall.CallbackArgTypes),
+		ExpiryRound:      serAsyncCall.ExpiryRound,
+		ValueLocked:      serAsyncCall.ValueLocked,
+	}
+}
+
+func callbackArgTypesFromBytes(data []byte) CallbackArgTypes {
+	if len(data) == 0 {
+		return nil
+	}
+
+	argTypes := make(CallbackArgTypes, len(data))
+	for i, argType := range data {
+		argTypes[i] = CallbackArgType(argType)
 	}
+	return argTypes
 }
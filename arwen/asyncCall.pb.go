@@ -82,17 +82,20 @@ func (SerializableAsyncCallExecutionMode) EnumDescriptor() ([]byte, []int) {
 }
 
 type SerializableAsyncCall struct {
-	CallID          []byte                             `protobuf:"bytes,1,opt,name=CallID,proto3" json:"CallID,omitempty"`
-	Status          SerializableAsyncCallStatus        `protobuf:"varint,2,opt,name=Status,proto3,enum=arwen.SerializableAsyncCallStatus" json:"Status,omitempty"`
-	ExecutionMode   SerializableAsyncCallExecutionMode `protobuf:"varint,3,opt,name=ExecutionMode,proto3,enum=arwen.SerializableAsyncCallExecutionMode" json:"ExecutionMode,omitempty"`
-	Destination     []byte                             `protobuf:"bytes,5,opt,name=Destination,proto3" json:"Destination,omitempty"`
-	Data            []byte                             `protobuf:"bytes,6,opt,name=Data,proto3" json:"Data,omitempty"`
-	GasLimit        uint64                             `protobuf:"varint,7,opt,name=GasLimit,proto3" json:"GasLimit,omitempty"`
-	GasLocked       uint64                             `protobuf:"varint,8,opt,name=GasLocked,proto3" json:"GasLocked,omitempty"`
-	ValueBytes      []byte                             `protobuf:"bytes,9,opt,name=ValueBytes,proto3" json:"ValueBytes,omitempty"`
-	SuccessCallback string                             `protobuf:"bytes,10,opt,name=SuccessCallback,proto3" json:"SuccessCallback,omitempty"`
-	ErrorCallback   string                             `protobuf:"bytes,11,opt,name=ErrorCallback,proto3" json:"ErrorCallback,omitempty"`
-	CallbackClosure []byte                             `protobuf:"bytes,12,opt,name=CallbackClosure,proto3" json:"CallbackClosure,omitempty"`
+	CallID           []byte                             `protobuf:"bytes,1,opt,name=CallID,proto3" json:"CallID,omitempty"`
+	Status           SerializableAsyncCallStatus        `protobuf:"varint,2,opt,name=Status,proto3,enum=arwen.SerializableAsyncCallStatus" json:"Status,omitempty"`
+	ExecutionMode    SerializableAsyncCallExecutionMode `protobuf:"varint,3,opt,name=ExecutionMode,proto3,enum=arwen.SerializableAsyncCallExecutionMode" json:"ExecutionMode,omitempty"`
+	Destination      []byte                             `protobuf:"bytes,5,opt,name=Destination,proto3" json:"Destination,omitempty"`
+	Data             []byte                             `protobuf:"bytes,6,opt,name=Data,proto3" json:"Data,omitempty"`
+	GasLimit         uint64                             `protobuf:"varint,7,opt,name=GasLimit,proto3" json:"GasLimit,omitempty"`
+	GasLocked        uint64                             `protobuf:"varint,8,opt,name=GasLocked,proto3" json:"GasLocked,omitempty"`
+	ValueBytes       []byte                             `protobuf:"bytes,9,opt,name=ValueBytes,proto3" json:"ValueBytes,omitempty"`
+	SuccessCallback  string                             `protobuf:"bytes,10,opt,name=SuccessCallback,proto3" json:"SuccessCallback,omitempty"`
+	ErrorCallback    string                             `protobuf:"bytes,11,opt,name=ErrorCallback,proto3" json:"ErrorCallback,omitempty"`
+	CallbackClosure  []byte                             `protobuf:"bytes,12,opt,name=CallbackClosure,proto3" json:"CallbackClosure,omitempty"`
+	CallbackArgTypes []byte                             `protobuf:"bytes,13,opt,name=CallbackArgTypes,proto3" json:"CallbackArgTypes,omitempty"`
+	ExpiryRound      uint64                             `protobuf:"varint,14,opt,name=ExpiryRound,proto3" json:"ExpiryRound,omitempty"`
+	ValueLocked      bool                               `protobuf:"varint,15,opt,name=ValueLocked,proto3" json:"ValueLocked,omitempty"`
 }
 
 func (m *SerializableAsyncCall) Reset()      { *m = SerializableAsyncCall{} }
@@ -200,12 +203,36 @@ func (m *SerializableAsyncCall) GetCallbackClosure() []byte {
 	return nil
 }
 
+func (m *SerializableAsyncCall) GetCallbackArgTypes() []byte {
+	if m != nil {
+		return m.CallbackArgTypes
+	}
+	return nil
+}
+
+func (m *SerializableAsyncCall) GetExpiryRound() uint64 {
+	if m != nil {
+		return m.ExpiryRound
+	}
+	return 0
+}
+
+func (m *SerializableAsyncCall) GetValueLocked() bool {
+	if m != nil {
+		return m.ValueLocked
+	}
+	return false
+}
+
 type SerializableAsyncCallGroup struct {
-	Callback     string                   `protobuf:"bytes,1,opt,name=Callback,proto3" json:"Callback,omitempty"`
-	GasLocked    uint64                   `protobuf:"varint,2,opt,name=GasLocked,proto3" json:"GasLocked,omitempty"`
-	CallbackData []byte                   `protobuf:"bytes,3,opt,name=CallbackData,proto3" json:"CallbackData,omitempty"`
-	Identifier   string                   `protobuf:"bytes,4,opt,name=Identifier,proto3" json:"Identifier,omitempty"`
-	AsyncCalls   []*SerializableAsyncCall `protobuf:"bytes,5,rep,name=AsyncCalls,proto3" json:"AsyncCalls,omitempty"`
+	Callback         string                   `protobuf:"bytes,1,opt,name=Callback,proto3" json:"Callback,omitempty"`
+	GasLocked        uint64                   `protobuf:"varint,2,opt,name=GasLocked,proto3" json:"GasLocked,omitempty"`
+	CallbackData     []byte                   `protobuf:"bytes,3,opt,name=CallbackData,proto3" json:"CallbackData,omitempty"`
+	Identifier       string                   `protobuf:"bytes,4,opt,name=Identifier,proto3" json:"Identifier,omitempty"`
+	AsyncCalls       []*SerializableAsyncCall `protobuf:"bytes,5,rep,name=AsyncCalls,proto3" json:"AsyncCalls,omitempty"`
+	ErrorCallback    string                   `protobuf:"bytes,6,opt,name=ErrorCallback,proto3" json:"ErrorCallback,omitempty"`
+	ParentIdentifier string                   `protobuf:"bytes,7,opt,name=ParentIdentifier,proto3" json:"ParentIdentifier,omitempty"`
+	Failed           bool                     `protobuf:"varint,8,opt,name=Failed,proto3" json:"Failed,omitempty"`
 }
 
 func (m *SerializableAsyncCallGroup) Reset()      { *m = SerializableAsyncCallGroup{} }
@@ -271,6 +298,27 @@ func (m *SerializableAsyncCallGroup) GetAsyncCalls() []*SerializableAsyncCall {
 	return nil
 }
 
+func (m *SerializableAsyncCallGroup) GetErrorCallback() string {
+	if m != nil {
+		return m.ErrorCallback
+	}
+	return ""
+}
+
+func (m *SerializableAsyncCallGroup) GetParentIdentifier() string {
+	if m != nil {
+		return m.ParentIdentifier
+	}
+	return ""
+}
+
+func (m *SerializableAsyncCallGroup) GetFailed() bool {
+	if m != nil {
+		return m.Failed
+	}
+	return false
+}
+
 func init() {
 	proto.RegisterEnum("arwen.SerializableAsyncCallStatus", SerializableAsyncCallStatus_name, SerializableAsyncCallStatus_value)
 	proto.RegisterEnum("arwen.SerializableAsyncCallExecutionMode", SerializableAsyncCallExecutionMode_name, SerializableAsyncCallExecutionMode_value)
@@ -281,44 +329,46 @@ func init() {
 func init() { proto.RegisterFile("asyncCall.proto", fileDescriptor_a0e9b586d6e1f667) }
 
 var fileDescriptor_a0e9b586d6e1f667 = []byte{
-	// 582 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x84, 0x54, 0x41, 0x4f, 0x13, 0x41,
-	0x14, 0xde, 0x69, 0x4b, 0x85, 0x07, 0x48, 0x33, 0x89, 0x66, 0xc4, 0x32, 0x59, 0x1b, 0x43, 0x2a,
-	0x89, 0x25, 0xc1, 0x9b, 0x31, 0x31, 0x96, 0x22, 0x21, 0xd1, 0x60, 0x5a, 0xf4, 0xe0, 0x6d, 0xba,
-	0x3b, 0x94, 0xb1, 0xcb, 0x0c, 0x99, 0x99, 0x15, 0xf1, 0xe4, 0xc5, 0xbb, 0x3f, 0xc3, 0x5f, 0x62,
-	0x3c, 0x72, 0xec, 0xd1, 0x2e, 0x17, 0x8f, 0xfc, 0x04, 0xb3, 0x53, 0x58, 0xbb, 0xa5, 0x29, 0xa7,
-	0x7d, 0xef, 0x7b, 0xdf, 0xfb, 0xde, 0x37, 0x6f, 0x27, 0x03, 0x2b, 0xcc, 0x9c, 0xc9, 0x60, 0x9b,
-	0x45, 0x51, 0xe3, 0x44, 0x2b, 0xab, 0xf0, 0x1c, 0xd3, 0xa7, 0x5c, 0xae, 0x3e, 0xed, 0x09, 0x7b,
-	0x14, 0x77, 0x1b, 0x81, 0x3a, 0xde, 0xec, 0xa9, 0x9e, 0xda, 0x74, 0xd5, 0x6e, 0x7c, 0xe8, 0x32,
-	0x97, 0xb8, 0x68, 0xd4, 0x55, 0xfb, 0x55, 0x84, 0x7b, 0x1d, 0xae, 0x05, 0x8b, 0xc4, 0x57, 0xd6,
-	0x8d, 0xf8, 0xab, 0x6b, 0x55, 0x7c, 0x1f, 0xca, 0xe9, 0x77, 0xaf, 0x45, 0x90, 0x8f, 0xea, 0x4b,
-	0xed, 0xab, 0x0c, 0x3f, 0x87, 0x72, 0xc7, 0x32, 0x1b, 0x1b, 0x52, 0xf0, 0x51, 0xfd, 0xee, 0x56,
-	0xad, 0xe1, 0x06, 0x37, 0xa6, 0xaa, 0x8c, 0x98, 0xed, 0xab, 0x0e, 0xbc, 0x0f, 0xcb, 0x3b, 0x5f,
-	0x78, 0x10, 0x5b, 0xa1, 0xe4, 0x5b, 0x15, 0x72, 0x52, 0x74, 0x12, 0x4f, 0x66, 0x49, 0xe4, 0x1a,
-	0xda, 0xf9, 0x7e, 0xec, 0xc3, 0x62, 0x8b, 0x1b, 0x2b, 0x24, 0x4b, 0x21, 0x32, 0xe7, 0x9c, 0x8e,
-	0x43, 0x18, 0x43, 0xa9, 0xc5, 0x2c, 0x23, 0x65, 0x57, 0x72, 0x31, 0x5e, 0x85, 0xf9, 0x5d, 0x66,
-	0xde, 0x88, 0x63, 0x61, 0xc9, 0x1d, 0x1f, 0xd5, 0x4b, 0xed, 0x2c, 0xc7, 0x55, 0x58, 0x48, 0x63,
-	0x15, 0xf4, 0x79, 0x48, 0xe6, 0x5d, 0xf1, 0x3f, 0x80, 0x29, 0xc0, 0x07, 0x16, 0xc5, 0xbc, 0x79,
-	0x66, 0xb9, 0x21, 0x0b, 0x4e, 0x73, 0x0c, 0xc1, 0x75, 0x58, 0xe9, 0xc4, 0x41, 0xc0, 0x8d, 0x49,
-	0xad, 0x77, 0x59, 0xd0, 0x27, 0xe0, 0xa3, 0xfa, 0x42, 0x7b, 0x12, 0xc6, 0x8f, 0x61, 0x79, 0x47,
-	0x6b, 0xa5, 0x33, 0xde, 0xa2, 0xe3, 0xe5, 0xc1, 0x54, 0xef, 0x3a, 0xde, 0x8e, 0x94, 0x89, 0x35,
-	0x27, 0x4b, 0x6e, 0xe8, 0x24, 0x5c, 0x1b, 0x20, 0x58, 0x9d, 0xba, 0xbf, 0x5d, 0xad, 0xe2, 0x93,
-	0xf4, 0xc8, 0xd9, 0x24, 0xe4, 0x26, 0x65, 0x79, 0xfe, 0xc8, 0x85, 0xc9, 0x23, 0xd7, 0x60, 0xe9,
-	0x9a, 0xe9, 0x16, 0x59, 0x74, 0xf3, 0x73, 0x58, 0xba, 0x96, 0xbd, 0x90, 0x4b, 0x2b, 0x0e, 0x05,
-	0xd7, 0xa4, 0xe4, 0xf4, 0xc7, 0x10, 0xfc, 0x02, 0x20, 0xf3, 0x63, 0xc8, 0x9c, 0x5f, 0xac, 0x2f,
-	0x6e, 0x55, 0x67, 0xfd, 0xf4, 0xf6, 0x18, 0x7f, 0xe3, 0x3b, 0x82, 0x87, 0x33, 0x6e, 0x17, 0xf6,
-	0xa1, 0x3a, 0xb5, 0xfc, 0x8e, 0xcb, 0x50, 0xc8, 0x5e, 0xc5, 0xc3, 0x8f, 0x60, 0x6d, 0xfa, 0x18,
-	0x6e, 0x54, 0xf4, 0x99, 0x87, 0x15, 0x34, 0x83, 0xf2, 0x89, 0x07, 0x96, 0x87, 0x95, 0xc2, 0xc6,
-	0x10, 0x41, 0xed, 0xf6, 0x2b, 0x8a, 0xd7, 0xe0, 0xc1, 0x38, 0xab, 0x73, 0x26, 0x83, 0x8c, 0x50,
-	0xf1, 0xf0, 0x06, 0xac, 0xdf, 0x10, 0x69, 0xc6, 0x22, 0xb2, 0x42, 0xbe, 0x8e, 0x65, 0xb0, 0x27,
-	0xad, 0x66, 0x9d, 0x23, 0xa6, 0x53, 0x53, 0xb7, 0x70, 0xb7, 0xb5, 0x32, 0x66, 0xc4, 0x2d, 0xe0,
-	0xf5, 0xbc, 0xb9, 0x9d, 0x4e, 0xeb, 0xe0, 0x40, 0x33, 0x69, 0x0e, 0xb9, 0xde, 0x97, 0xa9, 0xcb,
-	0x26, 0x0b, 0xfa, 0x95, 0x22, 0xae, 0x02, 0xb9, 0xa1, 0xf9, 0x5e, 0xf6, 0xa5, 0x3a, 0x95, 0x95,
-	0x52, 0xf3, 0xe5, 0xf9, 0x90, 0x7a, 0x83, 0x21, 0xf5, 0x2e, 0x87, 0x14, 0x7d, 0x4b, 0x28, 0xfa,
-	0x99, 0x50, 0xf4, 0x3b, 0xa1, 0xe8, 0x3c, 0xa1, 0x68, 0x90, 0x50, 0xf4, 0x27, 0xa1, 0xe8, 0x6f,
-	0x42, 0xbd, 0xcb, 0x84, 0xa2, 0x1f, 0x17, 0xd4, 0x3b, 0xbf, 0xa0, 0xde, 0xe0, 0x82, 0x7a, 0x1f,
-	0x47, 0xef, 0x4f, 0xb7, 0xec, 0xde, 0x95, 0x67, 0xff, 0x02, 0x00, 0x00, 0xff, 0xff, 0xea, 0xb7,
-	0xe3, 0x16, 0xa0, 0x04, 0x00, 0x00,
+	// 611 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x85, 0x54, 0x4d, 0x6f, 0x12, 0x41,
+	0x18, 0xee, 0x02, 0x45, 0x18, 0x68, 0x21, 0x93, 0x68, 0x46, 0xa4, 0xa4, 0x12, 0xd3, 0x50, 0x12,
+	0x69, 0x52, 0x6f, 0xc6, 0xc4, 0x94, 0x0f, 0x1b, 0x12, 0x4d, 0xcd, 0x82, 0x1e, 0xbc, 0x0d, 0xbb,
+	0x03, 0x5d, 0xd9, 0xce, 0x90, 0x99, 0x59, 0x5b, 0x3c, 0x79, 0xf1, 0xee, 0xcf, 0xf0, 0x17, 0xf8,
+	0x1b, 0x3c, 0x72, 0xe4, 0x28, 0xf5, 0xe2, 0xd1, 0x9f, 0xe0, 0xec, 0x00, 0xeb, 0x2e, 0x10, 0x7a,
+	0x78, 0xb3, 0xf3, 0x3e, 0xef, 0x33, 0xcf, 0xbc, 0x1f, 0x33, 0x0b, 0x72, 0x58, 0x8c, 0xa9, 0xd5,
+	0xc0, 0xae, 0x5b, 0x1b, 0x71, 0x26, 0x19, 0xdc, 0xc5, 0xfc, 0x9a, 0xd0, 0xc2, 0xd3, 0x81, 0x23,
+	0x2f, 0xbd, 0x5e, 0xcd, 0x62, 0x57, 0x27, 0x03, 0x36, 0x60, 0x27, 0x3a, 0xda, 0xf3, 0xfa, 0xda,
+	0xd3, 0x8e, 0x5e, 0xcd, 0x77, 0x95, 0x7f, 0x24, 0xc0, 0xfd, 0x0e, 0xe1, 0x0e, 0x76, 0x9d, 0xcf,
+	0xb8, 0xe7, 0x92, 0xb3, 0xa5, 0x2a, 0x7c, 0x00, 0x92, 0xfe, 0xb7, 0xdd, 0x44, 0xc6, 0xa1, 0x51,
+	0xc9, 0x9a, 0x0b, 0x0f, 0x3e, 0x07, 0xc9, 0x8e, 0xc4, 0xd2, 0x13, 0x28, 0xa6, 0xf0, 0xfd, 0xd3,
+	0x72, 0x4d, 0x1f, 0x5c, 0xdb, 0xa8, 0x32, 0x67, 0x9a, 0x8b, 0x1d, 0xf0, 0x02, 0xec, 0xb5, 0x6e,
+	0x88, 0xe5, 0x49, 0x87, 0xd1, 0x37, 0xcc, 0x26, 0x28, 0xae, 0x25, 0x8e, 0xb7, 0x49, 0x44, 0x36,
+	0x98, 0xd1, 0xfd, 0xf0, 0x10, 0x64, 0x9a, 0x44, 0x48, 0x87, 0x62, 0x1f, 0x42, 0xbb, 0x3a, 0xd3,
+	0x30, 0x04, 0x21, 0x48, 0x34, 0xb1, 0xc4, 0x28, 0xa9, 0x43, 0x7a, 0x0d, 0x0b, 0x20, 0x75, 0x8e,
+	0xc5, 0x6b, 0xe7, 0xca, 0x91, 0xe8, 0x9e, 0xc2, 0x13, 0x66, 0xe0, 0xc3, 0x22, 0x48, 0xfb, 0x6b,
+	0x66, 0x0d, 0x89, 0x8d, 0x52, 0x3a, 0xf8, 0x1f, 0x80, 0x25, 0x00, 0xde, 0x63, 0xd7, 0x23, 0xf5,
+	0xb1, 0x24, 0x02, 0xa5, 0xb5, 0x66, 0x08, 0x81, 0x15, 0x90, 0xeb, 0x78, 0x96, 0x45, 0x84, 0xf0,
+	0x53, 0xef, 0x61, 0x6b, 0x88, 0x80, 0x22, 0xa5, 0xcd, 0x55, 0x18, 0x3e, 0x51, 0xad, 0xe0, 0x9c,
+	0xf1, 0x80, 0x97, 0xd1, 0xbc, 0x28, 0xe8, 0xeb, 0x2d, 0xd7, 0x0d, 0x97, 0x09, 0x8f, 0x13, 0x94,
+	0xd5, 0x87, 0xae, 0xc2, 0xb0, 0x0a, 0xf2, 0x4b, 0xe8, 0x8c, 0x0f, 0xba, 0xe3, 0x91, 0xca, 0x6f,
+	0x4f, 0x53, 0xd7, 0x70, 0xbf, 0x6b, 0xad, 0x9b, 0x91, 0xc3, 0xc7, 0x26, 0xf3, 0xa8, 0x8d, 0xf6,
+	0x75, 0x95, 0x61, 0xc8, 0x67, 0xe8, 0xaa, 0x16, 0x7d, 0xc8, 0x29, 0x46, 0xca, 0x0c, 0x43, 0xe5,
+	0xa9, 0x01, 0x0a, 0x1b, 0xe7, 0x75, 0xce, 0x99, 0x37, 0xf2, 0x5b, 0x1c, 0x54, 0x66, 0xe8, 0xca,
+	0x02, 0x3f, 0xda, 0xe2, 0xd8, 0x6a, 0x8b, 0xcb, 0x20, 0xbb, 0x64, 0xea, 0xc1, 0xc5, 0x75, 0x11,
+	0x11, 0xcc, 0x1f, 0x43, 0xdb, 0x26, 0x54, 0x3a, 0x7d, 0x87, 0x70, 0x94, 0xd0, 0xfa, 0x21, 0x04,
+	0xbe, 0x00, 0x20, 0xc8, 0x47, 0xa8, 0x5b, 0x11, 0xaf, 0x64, 0x4e, 0x8b, 0xdb, 0x2e, 0x99, 0x19,
+	0xe2, 0x57, 0xbf, 0x1a, 0xe0, 0xd1, 0x96, 0xdb, 0xac, 0x9a, 0x53, 0xdc, 0x18, 0x7e, 0x4b, 0xa8,
+	0xed, 0xd0, 0x41, 0x7e, 0x07, 0x3e, 0x06, 0x07, 0x9b, 0x8f, 0x21, 0x82, 0xb9, 0x9f, 0x88, 0x9d,
+	0x37, 0xb6, 0x50, 0x3e, 0x12, 0x4b, 0x2a, 0x4a, 0xac, 0x3a, 0x33, 0x40, 0xf9, 0xee, 0x27, 0x01,
+	0x0f, 0xc0, 0xc3, 0x30, 0xab, 0xa3, 0x48, 0x01, 0x41, 0xe5, 0x52, 0x05, 0x47, 0x6b, 0x22, 0x75,
+	0xcf, 0x71, 0xd5, 0x0b, 0x79, 0xe5, 0x51, 0xab, 0x4d, 0x25, 0xc7, 0x9d, 0x4b, 0xcc, 0xfd, 0xa4,
+	0xee, 0xe0, 0x36, 0x38, 0x13, 0x62, 0xce, 0x8d, 0xc1, 0xa3, 0x68, 0x72, 0xad, 0x4e, 0xb3, 0xdb,
+	0xe5, 0x98, 0x8a, 0x3e, 0xe1, 0x17, 0xd4, 0xcf, 0xb2, 0xae, 0xa6, 0x95, 0x8f, 0xab, 0x69, 0xa3,
+	0x35, 0xcd, 0x77, 0x74, 0x48, 0xd9, 0x35, 0xcd, 0x27, 0xea, 0x2f, 0x27, 0xb3, 0xd2, 0xce, 0x54,
+	0xd9, 0xdf, 0x59, 0xc9, 0xf8, 0x72, 0x5b, 0x32, 0xbe, 0x2b, 0xfb, 0xa9, 0x6c, 0xa2, 0x6c, 0xaa,
+	0xec, 0x97, 0xb2, 0x3f, 0xb7, 0x2a, 0xae, 0xbe, 0xdf, 0x7e, 0x97, 0x76, 0x26, 0xca, 0xa6, 0xca,
+	0x3e, 0xcc, 0xff, 0x77, 0xbd, 0xa4, 0xfe, 0x8f, 0x3d, 0xfb, 0x07, 0x0a, 0x6e, 0xfa, 0xf9, 0x10,
+	0x05, 0x00, 0x00,
 }
 
 func (x SerializableAsyncCallStatus) String() string {
@@ -387,6 +437,15 @@ func (this *SerializableAsyncCall) Equal(that interface{}) bool {
 	if !bytes.Equal(this.CallbackClosure, that1.CallbackClosure) {
 		return false
 	}
+	if !bytes.Equal(this.CallbackArgTypes, that1.CallbackArgTypes) {
+		return false
+	}
+	if this.ExpiryRound != that1.ExpiryRound {
+		return false
+	}
+	if this.ValueLocked != that1.ValueLocked {
+		return false
+	}
 	return true
 }
 func (this *SerializableAsyncCallGroup) Equal(that interface{}) bool {
@@ -428,13 +487,22 @@ func (this *SerializableAsyncCallGroup) Equal(that interface{}) bool {
 			return false
 		}
 	}
+	if this.ErrorCallback != that1.ErrorCallback {
+		return false
+	}
+	if this.ParentIdentifier != that1.ParentIdentifier {
+		return false
+	}
+	if this.Failed != that1.Failed {
+		return false
+	}
 	return true
 }
 func (this *SerializableAsyncCall) GoString() string {
 	if this == nil {
 		return "nil"
 	}
-	s := make([]string, 0, 15)
+	s := make([]string, 0, 18)
 	s = append(s, "&arwen.SerializableAsyncCall{")
 	s = append(s, "CallID: "+fmt.Sprintf("%#v", this.CallID)+",\n")
 	s = append(s, "Status: "+fmt.Sprintf("%#v", this.Status)+",\n")
@@ -447,6 +515,9 @@ func (this *SerializableAsyncCall) GoString() string {
 	s = append(s, "SuccessCallback: "+fmt.Sprintf("%#v", this.SuccessCallback)+",\n")
 	s = append(s, "ErrorCallback: "+fmt.Sprintf("%#v", this.ErrorCallback)+",\n")
 	s = append(s, "CallbackClosure: "+fmt.Sprintf("%#v", this.CallbackClosure)+",\n")
+	s = append(s, "CallbackArgTypes: "+fmt.Sprintf("%#v", this.CallbackArgTypes)+",\n")
+	s = append(s, "ExpiryRound: "+fmt.Sprintf("%#v", this.ExpiryRound)+",\n")
+	s = append(s, "ValueLocked: "+fmt.Sprintf("%#v", this.ValueLocked)+",\n")
 	s = append(s, "}")
 	return strings.Join(s, "")
 }
@@ -454,7 +525,7 @@ func (this *SerializableAsyncCallGroup) GoString() string {
 	if this == nil {
 		return "nil"
 	}
-	s := make([]string, 0, 9)
+	s := make([]string, 0, 12)
 	s = append(s, "&arwen.SerializableAsyncCallGroup{")
 	s = append(s, "Callback: "+fmt.Sprintf("%#v", this.Callback)+",\n")
 	s = append(s, "GasLocked: "+fmt.Sprintf("%#v", this.GasLocked)+",\n")
@@ -463,6 +534,9 @@ func (this *SerializableAsyncCallGroup) GoString() string {
 	if this.AsyncCalls != nil {
 		s = append(s, "AsyncCalls: "+fmt.Sprintf("%#v", this.AsyncCalls)+",\n")
 	}
+	s = append(s, "ErrorCallback: "+fmt.Sprintf("%#v", this.ErrorCallback)+",\n")
+	s = append(s, "ParentIdentifier: "+fmt.Sprintf("%#v", this.ParentIdentifier)+",\n")
+	s = append(s, "Failed: "+fmt.Sprintf("%#v", this.Failed)+",\n")
 	s = append(s, "}")
 	return strings.Join(s, "")
 }
@@ -494,6 +568,28 @@ func (m *SerializableAsyncCall) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.ValueLocked {
+		i--
+		if m.ValueLocked {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x78
+	}
+	if m.ExpiryRound != 0 {
+		i = encodeVarintAsyncCall(dAtA, i, uint64(m.ExpiryRound))
+		i--
+		dAtA[i] = 0x70
+	}
+	if len(m.CallbackArgTypes) > 0 {
+		i -= len(m.CallbackArgTypes)
+		copy(dAtA[i:], m.CallbackArgTypes)
+		i = encodeVarintAsyncCall(dAtA, i, uint64(len(m.CallbackArgTypes)))
+		i--
+		dAtA[i] = 0x6a
+	}
 	if len(m.CallbackClosure) > 0 {
 		i -= len(m.CallbackClosure)
 		copy(dAtA[i:], m.CallbackClosure)
@@ -586,6 +682,30 @@ func (m *SerializableAsyncCallGroup) MarshalToSizedBuffer(dAtA []byte) (int, err
 	_ = i
 	var l int
 	_ = l
+	if m.Failed {
+		i--
+		if m.Failed {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x40
+	}
+	if len(m.ParentIdentifier) > 0 {
+		i -= len(m.ParentIdentifier)
+		copy(dAtA[i:], m.ParentIdentifier)
+		i = encodeVarintAsyncCall(dAtA, i, uint64(len(m.ParentIdentifier)))
+		i--
+		dAtA[i] = 0x3a
+	}
+	if len(m.ErrorCallback) > 0 {
+		i -= len(m.ErrorCallback)
+		copy(dAtA[i:], m.ErrorCallback)
+		i = encodeVarintAsyncCall(dAtA, i, uint64(len(m.ErrorCallback)))
+		i--
+		dAtA[i] = 0x32
+	}
 	if len(m.AsyncCalls) > 0 {
 		for iNdEx := len(m.AsyncCalls) - 1; iNdEx >= 0; iNdEx-- {
 			{
@@ -686,6 +806,16 @@ func (m *SerializableAsyncCall) Size() (n int) {
 	if l > 0 {
 		n += 1 + l + sovAsyncCall(uint64(l))
 	}
+	l = len(m.CallbackArgTypes)
+	if l > 0 {
+		n += 1 + l + sovAsyncCall(uint64(l))
+	}
+	if m.ExpiryRound != 0 {
+		n += 1 + sovAsyncCall(uint64(m.ExpiryRound))
+	}
+	if m.ValueLocked {
+		n += 2
+	}
 	return n
 }
 
@@ -716,6 +846,17 @@ func (m *SerializableAsyncCallGroup) Size() (n int) {
 			n += 1 + l + sovAsyncCall(uint64(l))
 		}
 	}
+	l = len(m.ErrorCallback)
+	if l > 0 {
+		n += 1 + l + sovAsyncCall(uint64(l))
+	}
+	l = len(m.ParentIdentifier)
+	if l > 0 {
+		n += 1 + l + sovAsyncCall(uint64(l))
+	}
+	if m.Failed {
+		n += 2
+	}
 	return n
 }
 
@@ -741,6 +882,9 @@ func (this *SerializableAsyncCall) String() string {
 		`SuccessCallback:` + fmt.Sprintf("%v", this.SuccessCallback) + `,`,
 		`ErrorCallback:` + fmt.Sprintf("%v", this.ErrorCallback) + `,`,
 		`CallbackClosure:` + fmt.Sprintf("%v", this.CallbackClosure) + `,`,
+		`CallbackArgTypes:` + fmt.Sprintf("%v", this.CallbackArgTypes) + `,`,
+		`ExpiryRound:` + fmt.Sprintf("%v", this.ExpiryRound) + `,`,
+		`ValueLocked:` + fmt.Sprintf("%v", this.ValueLocked) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -760,6 +904,9 @@ func (this *SerializableAsyncCallGroup) String() string {
 		`CallbackData:` + fmt.Sprintf("%v", this.CallbackData) + `,`,
 		`Identifier:` + fmt.Sprintf("%v", this.Identifier) + `,`,
 		`AsyncCalls:` + repeatedStringForAsyncCalls + `,`,
+		`ErrorCallback:` + fmt.Sprintf("%v", this.ErrorCallback) + `,`,
+		`ParentIdentifier:` + fmt.Sprintf("%v", this.ParentIdentifier) + `,`,
+		`Failed:` + fmt.Sprintf("%v", this.Failed) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -1111,6 +1258,79 @@ func (m *SerializableAsyncCall) Unmarshal(dAtA []byte) error {
 				m.CallbackClosure = []byte{}
 			}
 			iNdEx = postIndex
+		case 13:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field CallbackArgTypes", wireType)
+			}
+			var byteLen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsyncCall
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				byteLen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if byteLen < 0 {
+				return ErrInvalidLengthAsyncCall
+			}
+			postIndex := iNdEx + byteLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAsyncCall
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.CallbackArgTypes = append(m.CallbackArgTypes[:0], dAtA[iNdEx:postIndex]...)
+			if m.CallbackArgTypes == nil {
+				m.CallbackArgTypes = []byte{}
+			}
+			iNdEx = postIndex
+		case 14:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ExpiryRound", wireType)
+			}
+			m.ExpiryRound = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsyncCall
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.ExpiryRound |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 15:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ValueLocked", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsyncCall
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.ValueLocked = bool(v != 0)
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAsyncCall(dAtA[iNdEx:])
@@ -1315,6 +1535,90 @@ func (m *SerializableAsyncCallGroup) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ErrorCallback", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsyncCall
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAsyncCall
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAsyncCall
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ErrorCallback = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ParentIdentifier", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsyncCall
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAsyncCall
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAsyncCall
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ParentIdentifier = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 8:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Failed", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsyncCall
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Failed = bool(v != 0)
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAsyncCall(dAtA[iNdEx:])
@@ -5,23 +5,34 @@ import (
 )
 
 // AsyncCallGroup is a structure containing a group of async calls and a callback
-// that should be called when all these async calls are resolved
+// that should be called when all these async calls are resolved; a group may
+// be the sub-group of another, which then also waits for the sub-group
 type AsyncCallGroup struct {
-	Callback     string
-	GasLocked    uint64
-	CallbackData []byte
-	Identifier   string
-	AsyncCalls   []*AsyncCall
+	Callback         string
+	ErrorCallback    string
+	GasLocked        uint64
+	CallbackData     []byte
+	Identifier       string
+	ParentIdentifier string
+	Failed           bool
+	AsyncCalls       []*AsyncCall
+
+	// gasLockedInTx is set when the gas of the callbacks was locked by the
+	// current transaction; it is not persisted
+	gasLockedInTx bool
 }
 
 // NewAsyncCallGroup creates a new instance of AsyncCallGroup
 func NewAsyncCallGroup(identifier string) *AsyncCallGroup {
 	return &AsyncCallGroup{
-		Callback:     "",
-		GasLocked:    0,
-		CallbackData: make([]byte, 0),
-		Identifier:   identifier,
-		AsyncCalls:   make([]*AsyncCall, 0),
+		Callback:         "",
+		ErrorCallback:    "",
+		GasLocked:        0,
+		CallbackData:     make([]byte, 0),
+		Identifier:       identifier,
+		ParentIdentifier: "",
+		Failed:           false,
+		AsyncCalls:       make([]*AsyncCall, 0),
 	}
 }
 
@@ -29,10 +40,15 @@ func NewAsyncCallGroup(identifier string) *AsyncCallGroup {
 func (acg *AsyncCallGroup) Clone() *AsyncCallGroup {
 	callCount := len(acg.AsyncCalls)
 	clone := &AsyncCallGroup{
-		Callback:   acg.Callback,
-		GasLocked:  acg.GasLocked,
-		Identifier: acg.Identifier,
-		AsyncCalls: make([]*AsyncCall, callCount),
+		Callback:         acg.Callback,
+		ErrorCallback:    acg.ErrorCallback,
+		GasLocked:        acg.GasLocked,
+		CallbackData:     make([]byte, len(acg.CallbackData)),
+		Identifier:       acg.Identifier,
+		ParentIdentifier: acg.ParentIdentifier,
+		Failed:           acg.Failed,
+		AsyncCalls:       make([]*AsyncCall, callCount),
+		gasLockedInTx:    acg.gasLockedInTx,
 	}
 
 	copy(clone.CallbackData, acg.CallbackData)
@@ -62,7 +78,35 @@ func (acg *AsyncCallGroup) IsComplete() bool {
 
 // HasCallback verifies whether a callback function has been set for this AsyncCallGroup
 func (acg *AsyncCallGroup) HasCallback() bool {
-	return acg.Callback != ""
+	return acg.Callback != "" || acg.ErrorCallback != ""
+}
+
+// GetCallbackName returns the callback to execute on the completion of the
+// AsyncCallGroup, depending on whether any of its calls or sub-groups failed
+func (acg *AsyncCallGroup) GetCallbackName() string {
+	if acg.Failed {
+		return acg.ErrorCallback
+	}
+	return acg.Callback
+}
+
+// HasParent verifies whether the AsyncCallGroup is the sub-group of another
+func (acg *AsyncCallGroup) HasParent() bool {
+	return acg.ParentIdentifier != ""
+}
+
+// LockGas records the gas locked by the current transaction for the
+// callbacks of the AsyncCallGroup
+func (acg *AsyncCallGroup) LockGas(gas uint64) {
+	acg.GasLocked = gas
+	acg.gasLockedInTx = true
+}
+
+// IsGasLockedInTx verifies whether the gas of the callbacks was locked by
+// the current transaction, as opposed to a transaction which saved the group
+// before a cross-shard call
+func (acg *AsyncCallGroup) IsGasLockedInTx() bool {
+	return acg.gasLockedInTx
 }
 
 // FindByDestination returns the index of an AsyncCall in this AsyncCallGroup
@@ -118,11 +162,14 @@ func (acg *AsyncCallGroup) toSerializable() *SerializableAsyncCallGroup {
 	}
 
 	return &SerializableAsyncCallGroup{
-		Callback:     acg.Callback,
-		GasLocked:    acg.GasLocked,
-		CallbackData: acg.CallbackData,
-		Identifier:   acg.Identifier,
-		AsyncCalls:   serializableAsyncCalls,
+		Callback:         acg.Callback,
+		ErrorCallback:    acg.ErrorCallback,
+		GasLocked:        acg.GasLocked,
+		CallbackData:     acg.CallbackData,
+		Identifier:       acg.Identifier,
+		ParentIdentifier: acg.ParentIdentifier,
+		Failed:           acg.Failed,
+		AsyncCalls:       serializableAsyncCalls,
 	}
 }
 
@@ -146,10 +193,13 @@ func FromSerializableAsyncCallGroups(serializableAsyncCallGroups []*Serializable
 
 func (serializableCallGroup *SerializableAsyncCallGroup) fromSerializable() *AsyncCallGroup {
 	return &AsyncCallGroup{
-		Callback:     serializableCallGroup.Callback,
-		GasLocked:    serializableCallGroup.GasLocked,
-		CallbackData: serializableCallGroup.CallbackData,
-		Identifier:   serializableCallGroup.Identifier,
-		AsyncCalls:   fromSerializableAsyncCalls(serializableCallGroup.AsyncCalls),
+		Callback:         serializableCallGroup.Callback,
+		ErrorCallback:    serializableCallGroup.ErrorCallback,
+		GasLocked:        serializableCallGroup.GasLocked,
+		CallbackData:     serializableCallGroup.CallbackData,
+		Identifier:       serializableCallGroup.Identifier,
+		ParentIdentifier: serializableCallGroup.ParentIdentifier,
+		Failed:           serializableCallGroup.Failed,
+		AsyncCalls:       fromSerializableAsyncCalls(serializableCallGroup.AsyncCalls),
 	}
 }
@@ -0,0 +1,123 @@
+package arwen
+
+import (
+	"fmt"
+)
+
+// CallbackArgType is the type expected by a success callback for one of the
+// values finished by the destination of an async call
+type CallbackArgType uint8
+
+const (
+	// CallbackArgAny accepts any value, unchanged
+	CallbackArgAny CallbackArgType = iota
+
+	// CallbackArgBool accepts the empty value or 0 for false and 1 for true;
+	// false is normalized to the empty value
+	CallbackArgBool
+
+	// CallbackArgU8 accepts an unsigned integer of at most 1 byte
+	CallbackArgU8
+
+	// CallbackArgU32 accepts an unsigned integer of at most 4 bytes
+	CallbackArgU32
+
+	// CallbackArgU64 accepts an unsigned integer of at most 8 bytes
+	CallbackArgU64
+
+	// CallbackArgI64 accepts a signed integer of at most 8 bytes
+	CallbackArgI64
+
+	// CallbackArgBigUint accepts an unsigned integer of any length
+	CallbackArgBigUint
+
+	// CallbackArgAddress accepts an address of exactly AddressLen bytes
+	CallbackArgAddress
+
+	numCallbackArgTypes
+)
+
+// CallbackArgTypes lists the types expected by a success callback, one for
+// each value finished by the destination of the async call
+type CallbackArgTypes []CallbackArgType
+
+// ParseCallbackArgTypes interprets each byte of the input as a CallbackArgType
+func ParseCallbackArgTypes(data []byte) (CallbackArgTypes, error) {
+	argTypes := make(CallbackArgTypes, len(data))
+	for i, argType := range data {
+		if CallbackArgType(argType) >= numCallbackArgTypes {
+			return nil, fmt.Errorf("%w: %d at index %d", ErrInvalidCallbackArgType, argType, i)
+		}
+		argTypes[i] = CallbackArgType(argType)
+	}
+	return argTypes, nil
+}
+
+// Bytes returns the encoding of the types, one byte each
+func (argTypes CallbackArgTypes) Bytes() []byte {
+	if len(argTypes) == 0 {
+		return nil
+	}
+
+	data := make([]byte, len(argTypes))
+	for i, argType := range argTypes {
+		data[i] = byte(argType)
+	}
+	return data
+}
+
+// Normalize verifies that the values match the types and returns them in
+// their canonical encoding, without the leading zeros of unsigned integers
+func (argTypes CallbackArgTypes) Normalize(values [][]byte) ([][]byte, error) {
+	if len(values) != len(argTypes) {
+		return nil, fmt.Errorf("%w: expected %d values, received %d", ErrCallbackArgsDecode, len(argTypes), len(values))
+	}
+
+	normalized := make([][]byte, len(values))
+	for i, value := range values {
+		normalizedValue, ok := argTypes[i].normalize(value)
+		if !ok {
+			return nil, fmt.Errorf("%w: value %d does not match type %d", ErrCallbackArgsDecode, i, argTypes[i])
+		}
+		normalized[i] = normalizedValue
+	}
+	return normalized, nil
+}
+
+func (argType CallbackArgType) normalize(value []byte) ([]byte, bool) {
+	switch argType {
+	case CallbackArgBool:
+		unsigned := trimLeadingZeros(value)
+		if len(unsigned) == 0 {
+			return []byte{}, true
+		}
+		return unsigned, len(unsigned) == 1 && unsigned[0] == 1
+	case CallbackArgU8:
+		return normalizeUnsigned(value, 1)
+	case CallbackArgU32:
+		return normalizeUnsigned(value, 4)
+	case CallbackArgU64:
+		return normalizeUnsigned(value, 8)
+	case CallbackArgI64:
+		return value, len(value) <= 8
+	case CallbackArgBigUint:
+		return trimLeadingZeros(value), true
+	case CallbackArgAddress:
+		return value, len(value) == AddressLen
+	}
+	return value, true
+}
+
+func normalizeUnsigned(value []byte, maxLength int) ([]byte, bool) {
+	unsigned := trimLeadingZeros(value)
+	return unsigned, len(unsigned) <= maxLength
+}
+
+func trimLeadingZeros(value []byte) []byte {
+	for i, b := range value {
+		if b != 0 {
+			return value[i:]
+		}
+	}
+	return []byte{}
+}
@@ -0,0 +1,121 @@
+package arwen
+
+import (
+	"bytes"
+	"errors"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+func TestCallbackArgTypes_Parse(t *testing.T) {
+	argTypes, err := ParseCallbackArgTypes([]byte{0, 4, 7})
+	require.Nil(t, err)
+	require.Equal(t, CallbackArgTypes{CallbackArgAny, CallbackArgU64, CallbackArgAddress}, argTypes)
+	require.Equal(t, []byte{0, 4, 7}, argTypes.Bytes())
+
+	_, err = ParseCallbackArgTypes([]byte{1, byte(numCallbackArgTypes)})
+	require.True(t, errors.Is(err, ErrInvalidCallbackArgType))
+}
+
+func TestCallbackArgTypes_Normalize(t *testing.T) {
+	address := bytes.Repeat([]byte{1}, AddressLen)
+	argTypes := CallbackArgTypes{CallbackArgBool, CallbackArgU32, CallbackArgBigUint, CallbackArgAddress, CallbackArgAny}
+
+	normalized, err := argTypes.Normalize([][]byte{{0}, {0, 0, 1, 2}, {0, 5}, address, {0}})
+	require.Nil(t, err)
+	require.Equal(t, [][]byte{{}, {1, 2}, {5}, address, {0}}, normalized)
+
+	_, err = argTypes.Normalize([][]byte{{2}, {1}, {1}, address, {}})
+	require.True(t, errors.Is(err, ErrCallbackArgsDecode))
+
+	_, err = argTypes.Normalize([][]byte{{1}, {1, 0, 0, 0, 0}, {1}, address, {}})
+	require.True(t, errors.Is(err, ErrCallbackArgsDecode))
+
+	_, err = argTypes.Normalize([][]byte{{1}, {1}, {1}, address[1:], {}})
+	require.True(t, errors.Is(err, ErrCallbackArgsDecode))
+
+	_, err = argTypes.Normalize([][]byte{{1}})
+	require.True(t, errors.Is(err, ErrCallbackArgsDecode))
+	require.Equal(t, ErrCallbackArgsDecode.Code, ErrorCodeOf(err))
+}
+
+func TestAsyncCall_NormalizeCallbackArguments(t *testing.T) {
+	asyncCall := &AsyncCall{
+		Status:           AsyncCallResolved,
+		SuccessCallback:  "success",
+		ErrorCallback:    "error",
+		CallbackArgTypes: CallbackArgTypes{CallbackArgU8},
+	}
+
+	arguments := asyncCall.NormalizeCallbackArguments([][]byte{{0}, {0, 7}})
+	require.Equal(t, [][]byte{{0}, {7}}, arguments)
+	require.Equal(t, "success", asyncCall.GetCallbackName())
+
+	arguments = asyncCall.NormalizeCallbackArguments([][]byte{{0}, {1, 7}})
+	require.True(t, asyncCall.IsRejected())
+	require.Equal(t, "error", asyncCall.GetCallbackName())
+	require.Len(t, arguments, 2)
+	require.Equal(t, []byte{byte(vmcommon.UserError)}, arguments[0])
+	require.Contains(t, string(arguments[1]), ErrCallbackArgsDecode.Error())
+
+	failedArguments := [][]byte{{byte(vmcommon.UserError)}, []byte("failed")}
+	require.Equal(t, failedArguments, asyncCall.NormalizeCallbackArguments(failedArguments))
+}
+
+func TestAsyncCall_CallbackArgTypesSerialization(t *testing.T) {
+	asyncCall := &AsyncCall{
+		CallID:           []byte("callID"),
+		Status:           AsyncCallPending,
+		SuccessCallback:  "success",
+		CallbackArgTypes: CallbackArgTypes{CallbackArgBool, CallbackArgI64},
+	}
+
+	serialized, err := asyncCall.toSerializable().Marshal()
+	require.Nil(t, err)
+
+	serializableAsyncCall := &SerializableAsyncCall{}
+	err = serializableAsyncCall.Unmarshal(serialized)
+	require.Nil(t, err)
+	require.Equal(t, asyncCall.CallbackArgTypes, serializableAsyncCall.fromSerializable().CallbackArgTypes)
+	require.Equal(t, asyncCall.CallbackArgTypes, asyncCall.Clone().CallbackArgTypes)
+}
+
+func TestAsyncCall_Expiry(t *testing.T) {
+	asyncCall := &AsyncCall{
+		CallID:      []byte("callID"),
+		Status:      AsyncCallPending,
+		ExpiryRound: 10,
+	}
+	require.True(t, asyncCall.HasExpiry())
+	require.False(t, asyncCall.HasExpired(9))
+	require.True(t, asyncCall.HasExpired(10))
+	require.False(t, (&AsyncCall{}).HasExpired(10))
+
+	serialized, err := asyncCall.toSerializable().Marshal()
+	require.Nil(t, err)
+
+	serializableAsyncCall := &SerializableAsyncCall{}
+	err = serializableAsyncCall.Unmarshal(serialized)
+	require.Nil(t, err)
+	require.Equal(t, uint64(10), serializableAsyncCall.fromSerializable().ExpiryRound)
+	require.Equal(t, uint64(10), asyncCall.Clone().ExpiryRound)
+}
+
+func TestAsyncCall_ValueLocked(t *testing.T) {
+	asyncCall := &AsyncCall{
+		CallID:      []byte("callID"),
+		Status:      AsyncCallPending,
+		ValueLocked: true,
+	}
+
+	serialized, err := asyncCall.toSerializable().Marshal()
+	require.Nil(t, err)
+
+	serializableAsyncCall := &SerializableAsyncCall{}
+	err = serializableAsyncCall.Unmarshal(serialized)
+	require.Nil(t, err)
+	require.True(t, serializableAsyncCall.fromSerializable().ValueLocked)
+	require.True(t, asyncCall.Clone().ValueLocked)
+}
@@ -4,6 +4,7 @@ import (
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/config"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 )
 
 // ArwenVersion returns the current arwen version
@@ -38,8 +39,101 @@ const (
 	// BreakpointMemoryLimit means that Wasmer must stop immediately
 	// due to over-allocation of WASM memory
 	BreakpointMemoryLimit
+
+	// BreakpointInternalVMError means that Wasmer must stop immediately
+	// due to a panic in a VM hook
+	BreakpointInternalVMError
+
+	// BreakpointReturnDataTooLarge means that Wasmer must stop immediately
+	// because the contract exceeded the maximum size of the return data
+	BreakpointReturnDataTooLarge
+
+	// BreakpointTooManyLogs means that Wasmer must stop immediately
+	// because the contract exceeded the limits of the logs
+	BreakpointTooManyLogs
 )
 
+// InternalVMError is the return code of the executions interrupted by a bug
+// in the host code; it follows the last return code defined by vmcommon
+const InternalVMError = vmcommon.SimulateFailed + 1
+
+// AsyncCallTimeout is the return code received by the callback of an async
+// call whose destination did not respond before the expiry round of the call
+const AsyncCallTimeout = InternalVMError + 1
+
+// MemoryLimitExceeded is the return code of the executions stopped because the
+// contract grew its memory beyond the limits of the gas schedule
+const MemoryLimitExceeded = AsyncCallTimeout + 1
+
+// ExecutionDenied is the return code of the executions and deployments
+// refused by the ExecutionPolicy of the host
+const ExecutionDenied = MemoryLimitExceeded + 1
+
+// SCExecutionPaused is the return code of the executions and deployments
+// refused while the execution of the contracts is paused
+const SCExecutionPaused = ExecutionDenied + 1
+
+// ReturnDataTooLarge is the return code of the executions stopped because the
+// contract finished more bytes than the limit of the gas schedule
+const ReturnDataTooLarge = SCExecutionPaused + 1
+
+// TooManyLogs is the return code of the executions stopped because the
+// contract wrote more logs, or larger logs, than the limits of the gas schedule
+const TooManyLogs = ReturnDataTooLarge + 1
+
+// NonceHandling selects whether the VM handles the nonces of the senders of
+// the transactions, instead of the embedder
+type NonceHandling uint8
+
+const (
+	// NonceHandlingDisabled leaves the nonces of the senders to the embedder
+	NonceHandlingDisabled NonceHandling = iota
+
+	// NonceHandlingCheck validates the transactions without recording them,
+	// for the hosts which run queries or estimate the gas of transactions
+	NonceHandlingCheck
+
+	// NonceHandlingEnabled validates the transactions, and records the
+	// incremented nonces of their senders in the VMOutput
+	NonceHandlingEnabled
+)
+
+// DeterminismChecks selects how the validator treats the constructs of new
+// contract code which threaten the determinism of its executions, such as
+// the WASI clock and random imports
+type DeterminismChecks uint8
+
+const (
+	// DeterminismChecksDisabled does not look for the hazards
+	DeterminismChecksDisabled DeterminismChecks = iota
+
+	// DeterminismChecksWarn accepts the contracts, logging a warning for
+	// each hazard found in their code
+	DeterminismChecksWarn
+
+	// DeterminismChecksReject refuses the contracts with any hazard
+	DeterminismChecksReject
+)
+
+// AsyncCallExpiryIdentifier is the identifier of the log entries announcing
+// the expiry round of the cross-shard async calls
+const AsyncCallExpiryIdentifier = "asyncCallExpiry"
+
+// AsyncCallbackFailedIdentifier is the identifier of the log entries
+// recording the failed callbacks of in-shard async calls, whose effects are
+// reverted while the effects of the called contract are kept
+const AsyncCallbackFailedIdentifier = "asyncCallbackFailed"
+
+// GasRefundedToCallerIdentifier is the identifier of the log entries
+// recording that a contract paid back part of the gas of the transaction to
+// its sender, with the sender, the gas and the refunded value as topics
+const GasRefundedToCallerIdentifier = "gasRefundedToCaller"
+
+// ContractBuildInfoIdentifier is the identifier of the log entries recording
+// the build info section of newly deployed contract code, with the framework
+// version, the compiler version and the build flags as topics
+const ContractBuildInfoIdentifier = "contractBuildInfo"
+
 const (
 	// BreakpointNoneString is the human-readable name of BreakpointNone
 	BreakpointNoneString = "BreakpointNone"
@@ -56,6 +150,15 @@ const (
 	// BreakpointOutOfGasString is the human-readable name of BreakpointOutOfGas
 	BreakpointOutOfGasString = "BreakpointOutOfGas"
 
+	// BreakpointInternalVMErrorString is the human-readable name of BreakpointInternalVMError
+	BreakpointInternalVMErrorString = "BreakpointInternalVMError"
+
+	// BreakpointReturnDataTooLargeString is the human-readable name of BreakpointReturnDataTooLarge
+	BreakpointReturnDataTooLargeString = "BreakpointReturnDataTooLarge"
+
+	// BreakpointTooManyLogsString is the human-readable name of BreakpointTooManyLogs
+	BreakpointTooManyLogsString = "BreakpointTooManyLogs"
+
 	// UnknownBreakpointString is the human-readable label for an unknown breakpoint value
 	UnknownBreakpointString = "unknown breakpoint"
 )
@@ -73,6 +176,12 @@ func (b BreakpointValue) String() string {
 		return BreakpointSignalErrorString
 	case BreakpointOutOfGas:
 		return BreakpointOutOfGasString
+	case BreakpointInternalVMError:
+		return BreakpointInternalVMErrorString
+	case BreakpointReturnDataTooLarge:
+		return BreakpointReturnDataTooLargeString
+	case BreakpointTooManyLogs:
+		return BreakpointTooManyLogsString
 	default:
 		return UnknownBreakpointString
 	}
@@ -108,9 +217,24 @@ const CallbackFunctionName = "callBack"
 // TimeLockKeyPrefix is the storage key prefix used for timelock-related storage.
 const TimeLockKeyPrefix = "TIMELOCK"
 
+// EscrowKeyPrefix is the storage key prefix used for the escrows locked by a contract.
+const EscrowKeyPrefix = "ESCROW"
+
 // AsyncDataPrefix is the storage key prefix used for AsyncContext-related storage.
 const AsyncDataPrefix = "ASYNC"
 
+// CallbackNamePrefix is the storage key prefix under which the VM records the
+// functions which a contract registered as the callbacks of its async calls.
+const CallbackNamePrefix = "CALLBACK"
+
+// LockedValueDataPrefix is the storage key prefix used for the ledger of the
+// values sent with async calls which are waiting for their callback.
+const LockedValueDataPrefix = "LOCKED"
+
+// StorageMigrationKeyPrefix is the storage key prefix under which the VM keeps
+// the storage migrations registered by an upgraded contract, with their progress.
+const StorageMigrationKeyPrefix = "MIGRATION"
+
 // AsyncCallStatus represents the different status an async call can have
 type AsyncCallStatus uint8
 
@@ -151,6 +275,10 @@ const (
 
 	// DeleteFunctionName specifies if the call is an deleteContract call
 	DeleteFunctionName = "deleteContract"
+
+	// OwnershipTransferLogIdentifier is the identifier of the log entry by
+	// which a contract records that its owner was changed
+	OwnershipTransferLogIdentifier = "ownershipTransferred"
 )
 
 // CodeDeployInput contains code deploy state, whether it comes from a ContractCreateInput or a ContractCallInput
@@ -165,6 +293,7 @@ type CodeDeployInput struct {
 type VMHostParameters struct {
 	VMType                              []byte
 	OverrideVMExecutor                  executor.ExecutorAbstractFactory
+	VMExecutorKind                      ExecutorKind
 	BlockGasLimit                       uint64
 	GasSchedule                         config.GasScheduleMap
 	BuiltInFuncContainer                vmcommon.BuiltInFunctionContainer
@@ -173,7 +302,167 @@ type VMHostParameters struct {
 	WasmerSIGSEGVPassthrough            bool
 	EpochNotifier                       vmcommon.EpochNotifier
 	EnableEpochsHandler                 vmcommon.EnableEpochsHandler
+	VMVersionSchedule                   VMVersionSchedule
 	TimeOutForSCExecutionInMilliseconds uint32
+	MetricsSink                         MetricsSink
+	ExecutionTracer                     ExecutionTracer
+	ExecutionPolicy                     ExecutionPolicy
+	GuardedAccounts                     GuardedAccountsHandler
+	VMLogger                            logging.VMLogger
+	DebugMode                           bool
+	ExecutionPaused                     bool
+	NonceHandling                       NonceHandling
+	StrictEndpointValidation            bool
+	ContractLimits                      ContractLimits
+	MaxReturnDataSize                   uint64
+	MaxManagedAllocation                uint64
+	AsyncLimits                         AsyncLimits
+	StorageLimits                       StorageLimits
+	ProtectRegisteredCallbacks          bool
+	DeterminismChecks                   DeterminismChecks
+	MeteringExemptContracts             [][]byte
+	RestrictLibraryCalls                bool
+	CodeCache                           CodeCache
+	BundleOutputTransfers               bool
+	GasValueConverter                   GasValueConverter
+	ShadowVMExecutor                    executor.ExecutorAbstractFactory
+	ShadowDivergenceHandler             ShadowDivergenceHandler
+}
+
+// AsyncLimits bound the fan-out of the contracts: the async calls in a group
+// and the groups registered by a contract execution, the output transfers of
+// a whole transaction, and the call data of each async call; a zero limit is
+// not enforced
+type AsyncLimits struct {
+	MaxCallsPerGroup   uint32
+	MaxCallGroups      uint32
+	MaxOutputTransfers uint32
+	MaxCallDataSize    uint32
+}
+
+// ShadowDivergence is a transaction whose outcome on the shadow executor of
+// a host differs from its canonical outcome: the first differing lines of the
+// errors and of the VMOutputs, as described by DescribeVMOutput
+type ShadowDivergence struct {
+	Kind      ExecutionKind
+	Caller    []byte
+	Recipient []byte
+	Function  string
+	Canonical string
+	Shadow    string
+}
+
+// StorageLimits bound the keys and the values which contracts write to
+// storage, once the StorageLimits flag of the EnableEpochsHandler is enabled;
+// the writes made by the VM itself are not bound, and a zero limit is not
+// enforced
+type StorageLimits struct {
+	MaxKeyLength   uint32
+	MaxValueLength uint32
+}
+
+// ContractLimits are the thresholds checked on the code of the deployed
+// contracts, before compiling it; a zero threshold is not enforced
+type ContractLimits struct {
+	MaxCodeSize         uint64
+	MaxFunctions        uint32
+	MaxDataSegments     uint32
+	MaxDataSegmentsSize uint64
+	MaxTableSize        uint32
+}
+
+// IsEnabled returns true if at least one of the thresholds is enforced
+func (limits ContractLimits) IsEnabled() bool {
+	return limits != ContractLimits{}
+}
+
+// ExecutionKind tells which entry point of the VM ran an execution
+type ExecutionKind uint8
+
+const (
+	// ExecutionCall is the call of an endpoint of an existing contract
+	ExecutionCall ExecutionKind = iota
+
+	// ExecutionCreate is the deployment of a new contract
+	ExecutionCreate
+
+	// ExecutionUpgrade is the upgrade of the code of an existing contract
+	ExecutionUpgrade
+
+	// ExecutionDelete is the deletion of an existing contract
+	ExecutionDelete
+)
+
+// String returns the name of the execution kind
+func (kind ExecutionKind) String() string {
+	switch kind {
+	case ExecutionCall:
+		return "call"
+	case ExecutionCreate:
+		return "create"
+	case ExecutionUpgrade:
+		return "upgrade"
+	case ExecutionDelete:
+		return "delete"
+	}
+	return "unknown"
+}
+
+// ExecutorKind tells which executor runs the contracts of a host, unless
+// OverrideVMExecutor replaces it
+type ExecutorKind uint8
+
+const (
+	// WasmerExecutor compiles the contracts to native code with wasmer
+	WasmerExecutor ExecutorKind = iota
+
+	// InterpreterExecutor interprets the contracts without native code, for
+	// the platforms which forbid JIT compilation
+	InterpreterExecutor
+)
+
+// String returns the name of the executor kind
+func (kind ExecutorKind) String() string {
+	switch kind {
+	case WasmerExecutor:
+		return "wasmer"
+	case InterpreterExecutor:
+		return "interpreter"
+	}
+	return "unknown"
+}
+
+// InstanceSource tells how the runtime obtained the executor instance of a contract
+type InstanceSource uint8
+
+const (
+	// InstanceFromWarmCache means that an instance kept in the warm instance cache was reused
+	InstanceFromWarmCache InstanceSource = iota
+
+	// InstanceFromCompiledCode means that the instance was created from the
+	// compiled code cached by the node, skipping the compilation
+	InstanceFromCompiledCode
+
+	// InstanceFromBytecode means that the contract bytecode was compiled
+	InstanceFromBytecode
+)
+
+// String returns the name of the instance source
+func (source InstanceSource) String() string {
+	switch source {
+	case InstanceFromWarmCache:
+		return "warm_cache"
+	case InstanceFromCompiledCode:
+		return "compiled_code"
+	case InstanceFromBytecode:
+		return "bytecode"
+	}
+	return "unknown"
+}
+
+// IsCacheHit returns true if the instance was obtained without compiling the contract
+func (source InstanceSource) IsCacheHit() bool {
+	return source == InstanceFromWarmCache || source == InstanceFromCompiledCode
 }
 
 // AsyncCallInfo contains the information required to handle the asynchronous call of another SmartContract
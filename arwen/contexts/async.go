@@ -6,6 +6,7 @@ import (
 	"math/big"
 
 	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 	"github.com/ElrondNetwork/wasm-vm/math"
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
@@ -16,7 +17,7 @@ import (
 
 var _ arwen.AsyncContext = (*asyncContext)(nil)
 
-var logAsync = logger.GetOrCreate("arwen/async")
+var logAsync = logging.GetOrCreate(logging.SubsystemAsync, "arwen/async")
 
 type asyncContext struct {
 	host        arwen.VMHost
@@ -38,6 +39,8 @@ type asyncContext struct {
 	childResults           *vmcommon.VMOutput
 	contextCallbackEnabled bool
 
+	processedCallbackIDs [][]byte // the remote calls whose callback has completed
+
 	address                      []byte
 	callID                       []byte
 	callType                     vm.CallType
@@ -46,6 +49,12 @@ type asyncContext struct {
 
 	asyncStorageDataPrefix []byte
 	callbackParentCall     *arwen.AsyncCall
+
+	asyncLimits                arwen.AsyncLimits
+	protectRegisteredCallbacks bool
+
+	promiseGroups   []string // identifiers of the groups, by handle
+	promisesCounter int32
 }
 
 // NewAsyncContext creates a new asyncContext.
@@ -54,6 +63,7 @@ func NewAsyncContext(
 	callArgsParser arwen.CallArgsParser,
 	esdtTransferParser vmcommon.ESDTTransferParser,
 	marshalizer *marshal.GogoProtoMarshalizer,
+	asyncLimits arwen.AsyncLimits,
 ) (*asyncContext, error) {
 	if check.IfNil(host) {
 		return nil, arwen.ErrNilVMHost
@@ -81,6 +91,7 @@ func NewAsyncContext(
 		contextCallbackEnabled: false,
 		asyncStorageDataPrefix: storage.GetVmProtectedPrefix(arwen.AsyncDataPrefix),
 		callbackParentCall:     nil,
+		asyncLimits:            asyncLimits,
 	}
 
 	return context, nil
@@ -101,7 +112,10 @@ func (context *asyncContext) InitState() {
 	context.callsCounter = 0
 	context.totalCallsCounter = 0
 	context.childResults = nil
+	context.processedCallbackIDs = nil
 	context.callbackParentCall = nil
+	context.promiseGroups = make([]string, 0)
+	context.promisesCounter = 0
 }
 
 // InitStateFromInput initializes the internal state of the AsyncContext with
@@ -165,6 +179,9 @@ func (context *asyncContext) PushState() {
 		callsCounter:                 context.callsCounter,
 		totalCallsCounter:            context.totalCallsCounter,
 		childResults:                 context.childResults,
+		processedCallbackIDs:         context.processedCallbackIDs,
+		promiseGroups:                context.promiseGroups,
+		promisesCounter:              context.promisesCounter,
 	}
 
 	context.stateStack = append(context.stateStack, newState)
@@ -208,8 +225,11 @@ func (context *asyncContext) PopSetActiveState() {
 	context.returnData = prevState.returnData
 	context.asyncCallGroups = prevState.asyncCallGroups
 	context.childResults = prevState.childResults
+	context.processedCallbackIDs = prevState.processedCallbackIDs
 	context.callsCounter = prevState.callsCounter
 	context.totalCallsCounter = prevState.totalCallsCounter
+	context.promiseGroups = prevState.promiseGroups
+	context.promisesCounter = prevState.promisesCounter
 	context.gasAccumulated = math.AddUint64(context.gasAccumulated, prevState.gasAccumulated)
 }
 
@@ -230,11 +250,14 @@ func (context *asyncContext) Clone() arwen.AsyncContext {
 		callsCounter:                 context.callsCounter,
 		totalCallsCounter:            context.totalCallsCounter,
 		childResults:                 context.childResults,
+		processedCallbackIDs:         context.processedCallbackIDs,
 		host:                         context.host,
 		marshalizer:                  context.marshalizer,
 		callArgsParser:               context.callArgsParser,
 		esdtTransferParser:           context.esdtTransferParser,
 		stateStack:                   context.stateStack,
+		asyncLimits:                  context.asyncLimits,
+		protectRegisteredCallbacks:   context.protectRegisteredCallbacks,
 	}
 }
 
@@ -457,6 +480,15 @@ func (context *asyncContext) RegisterAsyncCall(groupID string, call *arwen.Async
 	runtime := context.host.Runtime()
 	metering := context.host.Metering()
 
+	if runtime.ReadOnly() {
+		return arwen.ErrInvalidCallOnReadOnlyMode
+	}
+
+	err := context.validateAsyncCall(call)
+	if err != nil {
+		return err
+	}
+
 	// Lock gas only if a callback is defined (either for success or for error).
 	shouldLockGas := false
 	if call.SuccessCallback != "" {
@@ -478,7 +510,16 @@ func (context *asyncContext) RegisterAsyncCall(groupID string, call *arwen.Async
 		call.GasLocked = math.AddUint64(call.GasLocked, metering.ComputeExtraGasLockedForAsync())
 	}
 
-	err := metering.UseGasForAsyncStep()
+	err = context.registerCallbackName(call.SuccessCallback)
+	if err != nil {
+		return err
+	}
+	err = context.registerCallbackName(call.ErrorCallback)
+	if err != nil {
+		return err
+	}
+
+	err = metering.UseGasForAsyncStep()
 	if err != nil {
 		return err
 	}
@@ -500,6 +541,9 @@ func (context *asyncContext) RegisterAsyncCall(groupID string, call *arwen.Async
 func (context *asyncContext) RegisterLegacyAsyncCall(address []byte, data []byte, value []byte) error {
 	metering := context.host.Metering()
 	logAsync.Trace("RegisterLegacyAsyncCall", "gas left", metering.GasLeft())
+	if context.host.Runtime().ReadOnly() {
+		return arwen.ErrInvalidCallOnReadOnlyMode
+	}
 	if !context.canRegisterLegacyAsyncCall() {
 		return arwen.ErrLegacyAsyncCallInvalid
 	}
@@ -570,6 +614,11 @@ func (context *asyncContext) addAsyncCall(groupID string, call *arwen.AsyncCall)
 	}
 
 	call.ExecutionMode = execMode
+	err = context.useGasForLockedValue(groupID, call)
+	if err != nil {
+		return err
+	}
+
 	if execMode == arwen.ESDTTransferOnCallBack {
 		context.incrementCallsCounter()
 		call.CallID = context.generateNewCallID()
@@ -579,15 +628,14 @@ func (context *asyncContext) addAsyncCall(groupID string, call *arwen.AsyncCall)
 		return arwen.ErrAsyncNoMultiLevel
 	}
 
-	group, ok := context.GetCallGroup(groupID)
-	if !ok {
-		group = arwen.NewAsyncCallGroup(groupID)
-		err := context.AddCallGroup(group)
-		if err != nil {
-			return err
-		}
+	group, err := context.getOrCreateCallGroup(groupID)
+	if err != nil {
+		return err
 	}
 
+	if context.asyncLimits.MaxCallsPerGroup > 0 && len(group.AsyncCalls) >= int(context.asyncLimits.MaxCallsPerGroup) {
+		return arwen.ErrMaxAsyncCallsPerGroupExceeded
+	}
 	group.AddAsyncCall(call)
 
 	logAsync.Trace(
@@ -642,7 +690,7 @@ func (context *asyncContext) Execute() error {
 		// * call host.sendAsyncCallCrossShard() for each pending AsyncCall, to
 		//   generate the corresponding cross-shard OutputAccount entries
 		// Note that all async calls below this point are pending by definition.
-		for _, group := range context.asyncCallGroups {
+		for _, group := range context.callGroupsInSendingOrder() {
 			for _, call := range group.AsyncCalls {
 				if call.Status != arwen.AsyncCallPending {
 					continue
@@ -651,10 +699,24 @@ func (context *asyncContext) Execute() error {
 				if err != nil {
 					return err
 				}
+
+				err = context.lockValueOfAsyncCall(group.Identifier, call)
+				if err != nil {
+					return err
+				}
 			}
 		}
 
 		context.deleteCallGroupByID(arwen.LegacyAsyncCallGroupID)
+
+		// Step 3: complete the groups left without calls, whose callbacks
+		// can be called right away
+		if context.isSubGroupsEnabled() {
+			err = context.completeEmptyGroups()
+			if err != nil {
+				return err
+			}
+		}
 	}
 
 	return nil
@@ -683,7 +745,7 @@ func (context *asyncContext) UpdateCurrentAsyncCallStatus(
 		context.marshalizer)
 	if err != nil {
 		if err == arwen.ErrNoStoredAsyncContextFound {
-			return getLegacyCallback(address, vmInput), true, nil
+			return context.getLegacyCallback(address, vmInput), true, nil
 		} else {
 			return nil, false, err
 		}
@@ -693,8 +755,12 @@ func (context *asyncContext) UpdateCurrentAsyncCallStatus(
 	call := asyncCallInfo.GetAsyncCall()
 	err = asyncCallInfo.GetError()
 	if err != nil {
+		if err == arwen.ErrAsyncCallNotFound && context.isCallbackDeduplicationEnabled() && loadedContext.hasProcessedCallback(callID) {
+			logAsync.Warn("ignoring callback delivered again", "address", address, "callID", callID)
+			return nil, false, nil
+		}
 		if err == arwen.ErrAsyncCallNotFound {
-			return getLegacyCallback(address, vmInput), true, nil
+			return context.getLegacyCallback(address, vmInput), true, nil
 		} else {
 			return nil, false, err
 		}
@@ -702,22 +768,43 @@ func (context *asyncContext) UpdateCurrentAsyncCallStatus(
 
 	// The first argument of the callback is the return code of the destination call
 	destReturnCode := big.NewInt(0).SetBytes(vmInput.Arguments[0]).Uint64()
+	if vmcommon.ReturnCode(destReturnCode) == arwen.AsyncCallTimeout &&
+		!call.HasExpired(context.host.Blockchain().CurrentRound()) {
+		return nil, false, arwen.ErrAsyncCallNotExpired
+	}
 	call.UpdateStatus(vmcommon.ReturnCode(destReturnCode))
 
+	err = context.unlockValueOfAsyncCall(call)
+	if err != nil {
+		return nil, false, err
+	}
+
 	return call, false, nil
 }
 
-func getLegacyCallback(address []byte, vmInput *vmcommon.VMInput) *arwen.AsyncCall {
+// getLegacyCallback rebuilds the AsyncCall of a callback for which no
+// AsyncContext was saved, such as those of the async calls registered before
+// the promises; once the LegacyCallbackNoOp flag is enabled, like
+// RegisterLegacyAsyncCall, it only names the callBack function if the
+// contract exports it, so that the callbacks of the legacy contracts without
+// callBack complete without executing anything
+func (context *asyncContext) getLegacyCallback(address []byte, vmInput *vmcommon.VMInput) *arwen.AsyncCall {
 	var valueBytes []byte = nil
 	if vmInput.CallValue != nil {
 		valueBytes = vmInput.CallValue.Bytes()
 	}
+
+	callbackFunction := arwen.CallbackFunctionName
+	if context.host.EnableEpochsHandler().IsLegacyCallbackNoOpFlagEnabled() && !context.host.Runtime().HasFunction(arwen.CallbackFunctionName) {
+		callbackFunction = ""
+	}
+
 	return &arwen.AsyncCall{
 		Status:          arwen.AsyncCallResolved,
 		Destination:     address,
 		ValueBytes:      valueBytes,
-		SuccessCallback: arwen.CallbackFunctionName,
-		ErrorCallback:   arwen.CallbackFunctionName,
+		SuccessCallback: callbackFunction,
+		ErrorCallback:   callbackFunction,
 		GasLimit:        vmInput.GasProvided,
 		GasLocked:       vmInput.GasLocked,
 	}
@@ -792,7 +879,8 @@ func (context *asyncContext) computeGasLimitForLegacyAsyncCall(gasToLock uint64)
 	return gasLimit, nil
 }
 
-// DeleteAsyncCallAndCleanGroup deletes the specified async call and the group if this is the last call
+// DeleteAsyncCallAndCleanGroup deletes the specified async call and the group if this is the last call;
+// the completed group calls its callback and notifies its parent group, if any
 func (context *asyncContext) DeleteAsyncCallAndCleanGroup(callID []byte) error {
 	asyncCallInfo := context.GetAsyncCallByCallID(callID)
 	groupIndex := asyncCallInfo.GetGroupIndex()
@@ -803,13 +891,21 @@ func (context *asyncContext) DeleteAsyncCallAndCleanGroup(callID []byte) error {
 	}
 
 	currentCallGroup := context.asyncCallGroups[groupIndex]
-	currentCallGroup.DeleteAsyncCall(callIndex)
+	context.recordProcessedCallback(asyncCallInfo.GetAsyncCall())
+	if !context.isSubGroupsEnabled() {
+		currentCallGroup.DeleteAsyncCall(callIndex)
+		if currentCallGroup.IsComplete() {
+			context.deleteCallGroup(groupIndex)
+		}
+		return nil
+	}
 
-	if currentCallGroup.IsComplete() {
-		context.deleteCallGroup(groupIndex)
+	if context.isCallRejected(asyncCallInfo.GetAsyncCall()) {
+		currentCallGroup.Failed = true
 	}
+	currentCallGroup.DeleteAsyncCall(callIndex)
 
-	return nil
+	return context.completeGroupIfDone(currentCallGroup)
 }
 
 func (context *asyncContext) callCallback(callID []byte, vmOutput *vmcommon.VMOutput, err error) (bool, *vmcommon.VMOutput, error) {
@@ -67,7 +67,7 @@ type SerializableVMOutput struct {
 	OutputAccounts  map[string]*SerializableOutputAccount `protobuf:"bytes,6,rep,name=OutputAccounts,proto3" json:"OutputAccounts,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
 	DeletedAccounts [][]byte                              `protobuf:"bytes,7,rep,name=DeletedAccounts,proto3" json:"DeletedAccounts,omitempty"`
 	TouchedAccounts [][]byte                              `protobuf:"bytes,8,rep,name=TouchedAccounts,proto3" json:"TouchedAccounts,omitempty"`
-	Logs            *SerializableLogEntry                 `protobuf:"bytes,9,opt,name=Logs,proto3" json:"Logs,omitempty"`
+	Logs            []*SerializableLogEntry               `protobuf:"bytes,9,rep,name=Logs,proto3" json:"Logs,omitempty"`
 }
 
 func (m *SerializableVMOutput) Reset()      { *m = SerializableVMOutput{} }
@@ -154,7 +154,7 @@ func (m *SerializableVMOutput) GetTouchedAccounts() [][]byte {
 	return nil
 }
 
-func (m *SerializableVMOutput) GetLogs() *SerializableLogEntry {
+func (m *SerializableVMOutput) GetLogs() []*SerializableLogEntry {
 	if m != nil {
 		return m.Logs
 	}
@@ -168,6 +168,7 @@ type SerializableOutputTransfer struct {
 	Data          []byte               `protobuf:"bytes,4,opt,name=Data,proto3" json:"Data,omitempty"`
 	CallType      SerializableCallType `protobuf:"varint,5,opt,name=CallType,proto3,enum=contexts.SerializableCallType" json:"CallType,omitempty"`
 	SenderAddress []byte               `protobuf:"bytes,6,opt,name=SenderAddress,proto3" json:"SenderAddress,omitempty"`
+	AsyncData     []byte               `protobuf:"bytes,7,opt,name=AsyncData,proto3" json:"AsyncData,omitempty"`
 }
 
 func (m *SerializableOutputTransfer) Reset()      { *m = SerializableOutputTransfer{} }
@@ -240,6 +241,13 @@ func (m *SerializableOutputTransfer) GetSenderAddress() []byte {
 	return nil
 }
 
+func (m *SerializableOutputTransfer) GetAsyncData() []byte {
+	if m != nil {
+		return m.AsyncData
+	}
+	return nil
+}
+
 type SerializableStorageUpdate struct {
 	Offset  []byte `protobuf:"bytes,1,opt,name=Offset,proto3" json:"Offset,omitempty"`
 	Data    []byte `protobuf:"bytes,2,opt,name=Data,proto3" json:"Data,omitempty"`
@@ -296,16 +304,18 @@ func (m *SerializableStorageUpdate) GetWritten() bool {
 }
 
 type SerializableOutputAccount struct {
-	Address             []byte                                `protobuf:"bytes,1,opt,name=Address,proto3" json:"Address,omitempty"`
-	Nonce               uint64                                `protobuf:"varint,2,opt,name=Nonce,proto3" json:"Nonce,omitempty"`
-	Balance             *math_big.Int                         `protobuf:"bytes,3,opt,name=Balance,proto3,casttypewith=math/big.Int;github.com/ElrondNetwork/elrond-go-core/data.BigIntCaster" json:"Balance"`
-	StorageUpdates      map[string]*SerializableStorageUpdate `protobuf:"bytes,4,rep,name=StorageUpdates,proto3" json:"StorageUpdates,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
-	Code                []byte                                `protobuf:"bytes,5,opt,name=Code,proto3" json:"Code,omitempty"`
-	CodeMetadata        []byte                                `protobuf:"bytes,6,opt,name=CodeMetadata,proto3" json:"CodeMetadata,omitempty"`
-	CodeDeployerAddress []byte                                `protobuf:"bytes,7,opt,name=CodeDeployerAddress,proto3" json:"CodeDeployerAddress,omitempty"`
-	BalanceDelta        *math_big.Int                         `protobuf:"bytes,8,opt,name=BalanceDelta,proto3,casttypewith=math/big.Int;github.com/ElrondNetwork/elrond-go-core/data.BigIntCaster" json:"BalanceDelta"`
-	OutputTransfers     []*SerializableOutputTransfer         `protobuf:"bytes,9,rep,name=OutputTransfers,proto3" json:"OutputTransfers,omitempty"`
-	GasUsed             uint64                                `protobuf:"varint,10,opt,name=GasUsed,proto3" json:"GasUsed,omitempty"`
+	Address                 []byte                                `protobuf:"bytes,1,opt,name=Address,proto3" json:"Address,omitempty"`
+	Nonce                   uint64                                `protobuf:"varint,2,opt,name=Nonce,proto3" json:"Nonce,omitempty"`
+	Balance                 *math_big.Int                         `protobuf:"bytes,3,opt,name=Balance,proto3,casttypewith=math/big.Int;github.com/ElrondNetwork/elrond-go-core/data.BigIntCaster" json:"Balance"`
+	StorageUpdates          map[string]*SerializableStorageUpdate `protobuf:"bytes,4,rep,name=StorageUpdates,proto3" json:"StorageUpdates,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	Code                    []byte                                `protobuf:"bytes,5,opt,name=Code,proto3" json:"Code,omitempty"`
+	CodeMetadata            []byte                                `protobuf:"bytes,6,opt,name=CodeMetadata,proto3" json:"CodeMetadata,omitempty"`
+	CodeDeployerAddress     []byte                                `protobuf:"bytes,7,opt,name=CodeDeployerAddress,proto3" json:"CodeDeployerAddress,omitempty"`
+	BalanceDelta            *math_big.Int                         `protobuf:"bytes,8,opt,name=BalanceDelta,proto3,casttypewith=math/big.Int;github.com/ElrondNetwork/elrond-go-core/data.BigIntCaster" json:"BalanceDelta"`
+	OutputTransfers         []*SerializableOutputTransfer         `protobuf:"bytes,9,rep,name=OutputTransfers,proto3" json:"OutputTransfers,omitempty"`
+	GasUsed                 uint64                                `protobuf:"varint,10,opt,name=GasUsed,proto3" json:"GasUsed,omitempty"`
+	BytesAddedToStorage     uint64                                `protobuf:"varint,11,opt,name=BytesAddedToStorage,proto3" json:"BytesAddedToStorage,omitempty"`
+	BytesDeletedFromStorage uint64                                `protobuf:"varint,12,opt,name=BytesDeletedFromStorage,proto3" json:"BytesDeletedFromStorage,omitempty"`
 }
 
 func (m *SerializableOutputAccount) Reset()      { *m = SerializableOutputAccount{} }
@@ -406,6 +416,20 @@ func (m *SerializableOutputAccount) GetGasUsed() uint64 {
 	return 0
 }
 
+func (m *SerializableOutputAccount) GetBytesAddedToStorage() uint64 {
+	if m != nil {
+		return m.BytesAddedToStorage
+	}
+	return 0
+}
+
+func (m *SerializableOutputAccount) GetBytesDeletedFromStorage() uint64 {
+	if m != nil {
+		return m.BytesDeletedFromStorage
+	}
+	return 0
+}
+
 type SerializableLogEntry struct {
 	Identifier []byte   `protobuf:"bytes,1,opt,name=Identifier,proto3" json:"Identifier,omitempty"`
 	Address    []byte   `protobuf:"bytes,2,opt,name=Address,proto3" json:"Address,omitempty"`
@@ -484,6 +508,7 @@ type SerializableAsyncContext struct {
 	CallsCounter                 uint64                              `protobuf:"varint,12,opt,name=CallsCounter,proto3" json:"CallsCounter,omitempty"`
 	TotalCallsCounter            uint64                              `protobuf:"varint,13,opt,name=TotalCallsCounter,proto3" json:"TotalCallsCounter,omitempty"`
 	ChildResults                 *SerializableVMOutput               `protobuf:"bytes,14,opt,name=ChildResults,proto3" json:"ChildResults,omitempty"`
+	ProcessedCallbackIDs         [][]byte                            `protobuf:"bytes,15,rep,name=ProcessedCallbackIDs,proto3" json:"ProcessedCallbackIDs,omitempty"`
 }
 
 func (m *SerializableAsyncContext) Reset()      { *m = SerializableAsyncContext{} }
@@ -612,6 +637,13 @@ func (m *SerializableAsyncContext) GetChildResults() *SerializableVMOutput {
 	return nil
 }
 
+func (m *SerializableAsyncContext) GetProcessedCallbackIDs() [][]byte {
+	if m != nil {
+		return m.ProcessedCallbackIDs
+	}
+	return nil
+}
+
 func init() {
 	proto.RegisterEnum("contexts.SerializableCallType", SerializableCallType_name, SerializableCallType_value)
 	proto.RegisterType((*SerializableVMOutput)(nil), "contexts.SerializableVMOutput")
@@ -627,78 +659,79 @@ func init() {
 func init() { proto.RegisterFile("async.proto", fileDescriptor_83b2a03067ffcb1d) }
 
 var fileDescriptor_83b2a03067ffcb1d = []byte{
-	// 1125 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xb4, 0x56, 0x4f, 0x6f, 0xe3, 0x44,
-	0x14, 0x8f, 0xf3, 0xa7, 0x4d, 0x5f, 0xb2, 0x69, 0x99, 0xad, 0x2a, 0x13, 0xad, 0xdc, 0x10, 0x56,
-	0x28, 0x42, 0xd4, 0x41, 0xe5, 0x00, 0x2c, 0x5c, 0x9a, 0xa6, 0x54, 0x15, 0x6d, 0x57, 0x72, 0xd3,
-	0x45, 0xea, 0x05, 0x4d, 0xec, 0x89, 0x6b, 0xd5, 0xf1, 0x04, 0xcf, 0x78, 0xbb, 0x45, 0x08, 0xf1,
-	0x11, 0xf8, 0x18, 0x88, 0x2f, 0xc0, 0x57, 0xe0, 0xd8, 0x63, 0xb9, 0x74, 0x69, 0x7a, 0x41, 0x3d,
-	0xed, 0x9d, 0x0b, 0x9a, 0xb1, 0x9d, 0x7a, 0xd2, 0x6c, 0x05, 0x68, 0xf7, 0xe4, 0x79, 0x3f, 0xbf,
-	0xf7, 0xe6, 0xcd, 0x7b, 0xbf, 0xf7, 0x66, 0xa0, 0x82, 0xd9, 0x59, 0x60, 0x9b, 0xa3, 0x90, 0x72,
-	0x8a, 0xca, 0x36, 0x0d, 0x38, 0x79, 0xc1, 0x59, 0xfd, 0x4b, 0xd7, 0xe3, 0xc7, 0x51, 0xdf, 0xb4,
-	0xe9, 0xb0, 0xbd, 0xe5, 0x87, 0x34, 0x70, 0xf6, 0x09, 0x3f, 0xa5, 0xe1, 0x49, 0x1b, 0x87, 0xa7,
-	0x24, 0x58, 0x3b, 0xc5, 0x6c, 0xb8, 0xf6, 0x7c, 0x18, 0x4b, 0x6d, 0xe9, 0x63, 0x13, 0xfb, 0x7e,
-	0xec, 0xa7, 0xbe, 0x96, 0xb1, 0x76, 0xa9, 0x4b, 0xdb, 0x12, 0xee, 0x47, 0x03, 0x29, 0x49, 0x41,
-	0xae, 0x62, 0xf5, 0xe6, 0xcb, 0x22, 0x2c, 0x1f, 0x90, 0xd0, 0xc3, 0xbe, 0xf7, 0x3d, 0xee, 0xfb,
-	0xe4, 0xd9, 0xde, 0xd3, 0x88, 0x8f, 0x22, 0x8e, 0x0c, 0x00, 0x8b, 0xf0, 0x28, 0x0c, 0xba, 0x98,
-	0x63, 0x5d, 0x6b, 0x14, 0x5a, 0x55, 0x2b, 0x83, 0xdc, 0xfe, 0xdf, 0xa4, 0x0e, 0xd1, 0xf3, 0x0d,
-	0xad, 0x55, 0xb4, 0x32, 0x08, 0x7a, 0x0c, 0x0f, 0x62, 0x69, 0x8f, 0x30, 0x86, 0x5d, 0xa2, 0x17,
-	0x1a, 0x5a, 0x6b, 0xc1, 0x52, 0x41, 0xd4, 0x84, 0xea, 0x36, 0x66, 0x16, 0x19, 0x62, 0x2f, 0xf0,
-	0x02, 0x57, 0x2f, 0x4a, 0x3f, 0x0a, 0x86, 0xbe, 0x83, 0x05, 0x29, 0x0f, 0xa2, 0xc0, 0xd1, 0x4b,
-	0x0d, 0xad, 0x55, 0xed, 0x1c, 0xdc, 0x5c, 0xae, 0x96, 0x9e, 0x61, 0x3f, 0x22, 0xbf, 0xbe, 0x5c,
-	0xfd, 0x6a, 0x88, 0xf9, 0x71, 0xbb, 0xef, 0xb9, 0xe6, 0x4e, 0xc0, 0xbf, 0x78, 0x6d, 0xf2, 0x88,
-	0x94, 0xd6, 0x5c, 0xba, 0x66, 0xd3, 0x90, 0xb4, 0x1d, 0xcc, 0xb1, 0xd9, 0xf1, 0xdc, 0x9d, 0x80,
-	0x6f, 0x62, 0xc6, 0x49, 0x68, 0xdd, 0xee, 0x82, 0x8e, 0xa0, 0x16, 0xa7, 0x61, 0xc3, 0xb6, 0x69,
-	0x14, 0x70, 0xa6, 0xcf, 0x35, 0x0a, 0xad, 0xca, 0xfa, 0xba, 0x99, 0x56, 0xc9, 0x9c, 0x95, 0x34,
-	0x53, 0x35, 0xda, 0x0a, 0x78, 0x78, 0x66, 0x4d, 0x79, 0x42, 0x2d, 0x58, 0xec, 0x12, 0x9f, 0x70,
-	0xe2, 0x4c, 0x9c, 0xcf, 0xcb, 0xec, 0x4e, 0xc3, 0x42, 0xb3, 0x47, 0x23, 0xfb, 0x38, 0xa3, 0x59,
-	0x8e, 0x35, 0xa7, 0x60, 0xb4, 0x0e, 0xc5, 0x5d, 0xea, 0x32, 0x7d, 0xa1, 0xa1, 0xb5, 0x2a, 0xeb,
-	0xc6, 0xec, 0x28, 0x77, 0xa9, 0x1b, 0x47, 0x24, 0x75, 0xeb, 0x03, 0x78, 0x38, 0x23, 0x5c, 0xb4,
-	0x04, 0x85, 0x13, 0x72, 0xa6, 0x6b, 0xb2, 0x5a, 0x62, 0x89, 0x3e, 0x87, 0xd2, 0x73, 0x91, 0x6a,
-	0x59, 0xe4, 0xca, 0xfa, 0xfb, 0xb3, 0xbd, 0x2b, 0xbe, 0xac, 0xd8, 0xe2, 0x49, 0xfe, 0x33, 0xad,
-	0xf9, 0x5b, 0x1e, 0xea, 0x77, 0x15, 0x7b, 0x21, 0x0e, 0xd8, 0x80, 0x84, 0xc8, 0x83, 0xb8, 0x90,
-	0x72, 0xc7, 0xb7, 0x54, 0xd9, 0xd8, 0x21, 0xaa, 0x43, 0x79, 0x1b, 0xb3, 0x5d, 0x6f, 0xe8, 0xf1,
-	0x84, 0xb0, 0x13, 0x19, 0x3d, 0x92, 0x24, 0xdb, 0xa5, 0xf6, 0x09, 0x71, 0x24, 0x55, 0x8b, 0xd6,
-	0x2d, 0x80, 0x10, 0x14, 0x65, 0x1b, 0x08, 0x7a, 0x56, 0x2d, 0xb9, 0x46, 0x4f, 0xa0, 0x2c, 0xda,
-	0xae, 0x77, 0x36, 0x22, 0x92, 0x95, 0xb5, 0xd7, 0xe5, 0x3d, 0xd5, 0xb2, 0x26, 0xfa, 0xa2, 0x39,
-	0x0e, 0x48, 0xe0, 0x90, 0x70, 0xc3, 0x71, 0x42, 0xc2, 0x04, 0xbd, 0x84, 0x63, 0x15, 0x6c, 0x62,
-	0x78, 0x37, 0xeb, 0xe7, 0x80, 0xd3, 0x10, 0xbb, 0xe4, 0x70, 0xe4, 0x60, 0x4e, 0xd0, 0x0a, 0xcc,
-	0x3d, 0x1d, 0x0c, 0x18, 0xe1, 0x71, 0xe2, 0xac, 0x44, 0x9a, 0x84, 0x9a, 0xcf, 0x84, 0xaa, 0xc3,
-	0xfc, 0x37, 0xa1, 0xc7, 0x39, 0x09, 0xe4, 0xd1, 0xca, 0x56, 0x2a, 0x36, 0xff, 0x28, 0xa9, 0x7b,
-	0x28, 0x55, 0x14, 0x76, 0x69, 0x80, 0xf1, 0x26, 0xa9, 0x88, 0x96, 0xa1, 0xb4, 0x4f, 0x03, 0x3b,
-	0x6d, 0xfc, 0x58, 0x40, 0x14, 0xe6, 0x3b, 0xd8, 0xc7, 0x02, 0x2f, 0xc8, 0x6a, 0x1e, 0xde, 0x5c,
-	0xae, 0xa6, 0xd0, 0x1b, 0xac, 0x67, 0xea, 0x12, 0x7d, 0x0b, 0x35, 0x25, 0x2b, 0x4c, 0x2f, 0xca,
-	0x3e, 0xfd, 0xf4, 0x5f, 0x70, 0xd4, 0x54, 0x2d, 0x93, 0x66, 0x55, 0x41, 0x91, 0x4d, 0x39, 0xdf,
-	0x4a, 0x71, 0x36, 0xe5, 0x64, 0x6b, 0x42, 0x55, 0x7c, 0xf7, 0x08, 0xc7, 0x22, 0xb4, 0xa4, 0x76,
-	0x0a, 0x86, 0x3e, 0x86, 0x87, 0x42, 0xee, 0x92, 0x91, 0x4f, 0xcf, 0x6e, 0xcb, 0x3c, 0x2f, 0x55,
-	0x67, 0xfd, 0x42, 0x3f, 0x42, 0x35, 0x39, 0x55, 0x97, 0xf8, 0x1c, 0xeb, 0x65, 0x99, 0xc0, 0xa3,
-	0x9b, 0xcb, 0x55, 0x05, 0x7f, 0x83, 0x59, 0x54, 0xfc, 0xa2, 0x7d, 0x58, 0x54, 0x3b, 0x53, 0x4c,
-	0x13, 0x91, 0xcb, 0xc7, 0xf7, 0xe5, 0x32, 0x55, 0xb6, 0xa6, 0x8d, 0x05, 0x77, 0xb6, 0x31, 0x3b,
-	0x64, 0xc4, 0xd1, 0x41, 0x72, 0x24, 0x15, 0xc5, 0xe0, 0x99, 0x91, 0xfa, 0xff, 0x3d, 0x78, 0x14,
-	0x5f, 0xd9, 0xc1, 0xf3, 0x83, 0x7a, 0xb3, 0xa5, 0xe3, 0x4f, 0xdc, 0x5c, 0x3b, 0x0e, 0x09, 0xb8,
-	0x37, 0xf0, 0x48, 0x98, 0x10, 0x3b, 0x83, 0x64, 0x59, 0x9f, 0x57, 0x59, 0xbf, 0x02, 0x73, 0x3d,
-	0x3a, 0xf2, 0x6c, 0xa6, 0x17, 0xe4, 0x1c, 0x4e, 0xa4, 0x59, 0xe3, 0xa1, 0xf9, 0x77, 0x11, 0xf4,
-	0xec, 0xf6, 0x1b, 0xf2, 0x9e, 0x8e, 0x0f, 0x70, 0x4f, 0x63, 0xad, 0xc0, 0x9c, 0x98, 0x12, 0x3b,
-	0xdd, 0x64, 0xef, 0x44, 0x52, 0xa6, 0x4d, 0xe1, 0x3f, 0x4e, 0x1b, 0x03, 0x40, 0xac, 0x63, 0xae,
-	0x25, 0x41, 0x66, 0x10, 0x49, 0x68, 0x29, 0x25, 0x3b, 0x97, 0x12, 0x42, 0x67, 0x30, 0xd4, 0x81,
-	0x47, 0x62, 0xd5, 0xc7, 0xf6, 0x89, 0x3c, 0xc9, 0x4e, 0xe0, 0x71, 0x0f, 0x73, 0x9a, 0xda, 0xc4,
-	0x4d, 0x70, 0xaf, 0x8e, 0x98, 0xbf, 0xe9, 0x7f, 0xd9, 0x09, 0x0b, 0xd6, 0x44, 0x4e, 0x63, 0x10,
-	0x6b, 0x99, 0xca, 0xf2, 0x6d, 0x0c, 0x29, 0x86, 0x3e, 0x80, 0xda, 0x36, 0x66, 0x1b, 0xb6, 0x1d,
-	0x0d, 0x23, 0x1f, 0x73, 0xe2, 0xc8, 0xfb, 0xae, 0x68, 0x4d, 0xa1, 0x53, 0x4f, 0x17, 0x88, 0xcf,
-	0x9b, 0x79, 0xba, 0x7c, 0x0d, 0x8b, 0x93, 0x57, 0xd3, 0x76, 0x48, 0xa3, 0x11, 0xd3, 0x2b, 0x92,
-	0xea, 0xef, 0x99, 0xf2, 0x4d, 0x65, 0xde, 0xad, 0x5b, 0xaa, 0x69, 0x4d, 0x5b, 0xa6, 0x81, 0xb3,
-	0x4d, 0x31, 0x53, 0x48, 0xa8, 0x57, 0xe3, 0x17, 0x4c, 0x16, 0x43, 0x1f, 0xc1, 0x3b, 0x3d, 0xca,
-	0xb1, 0xaf, 0x28, 0x3e, 0x90, 0x8a, 0x77, 0x7f, 0xa0, 0x0e, 0x54, 0x37, 0x8f, 0x3d, 0xdf, 0xb1,
-	0x08, 0x8b, 0x7c, 0xce, 0xf4, 0xda, 0x7d, 0x97, 0x7a, 0xfa, 0xf4, 0xb0, 0x14, 0x9b, 0x0f, 0x43,
-	0x95, 0xfb, 0x13, 0x2a, 0xd4, 0x00, 0xba, 0x5e, 0x48, 0x6c, 0x2e, 0x90, 0xa5, 0x1c, 0x5a, 0x86,
-	0x25, 0x79, 0xc0, 0xe3, 0x90, 0x06, 0x34, 0x62, 0x12, 0xd5, 0x90, 0x0e, 0xcb, 0xd3, 0x68, 0x07,
-	0xdb, 0x27, 0x4b, 0x79, 0x54, 0x87, 0x95, 0xad, 0x83, 0x6e, 0x2f, 0x6d, 0xf3, 0x8d, 0xc0, 0xd9,
-	0x7a, 0x41, 0xec, 0x88, 0x93, 0xa5, 0x42, 0x67, 0xeb, 0xfc, 0xca, 0xc8, 0x5d, 0x5c, 0x19, 0xb9,
-	0x57, 0x57, 0x86, 0xf6, 0xd3, 0xd8, 0xd0, 0x7e, 0x19, 0x1b, 0xda, 0xef, 0x63, 0x43, 0x3b, 0x1f,
-	0x1b, 0xda, 0xc5, 0xd8, 0xd0, 0xfe, 0x1c, 0x1b, 0xda, 0x5f, 0x63, 0x23, 0xf7, 0x6a, 0x6c, 0x68,
-	0x3f, 0x5f, 0x1b, 0xb9, 0xf3, 0x6b, 0x23, 0x77, 0x71, 0x6d, 0xe4, 0x8e, 0x2a, 0xa6, 0xd9, 0x4e,
-	0x4f, 0xd7, 0x9f, 0x93, 0x0f, 0xd3, 0x4f, 0xfe, 0x09, 0x00, 0x00, 0xff, 0xff, 0x4b, 0x07, 0x1e,
-	0x06, 0x1e, 0x0b, 0x00, 0x00,
+	// 1146 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xb5, 0x56, 0xcd, 0x6f, 0x1b, 0x45,
+	0x14, 0x8f, 0xbf, 0x12, 0x67, 0xe2, 0x3a, 0x61, 0x1a, 0x15, 0x13, 0x55, 0x6e, 0x31, 0x55, 0x15,
+	0x21, 0xbc, 0x46, 0xe1, 0x40, 0x29, 0x5c, 0xe2, 0x8f, 0x56, 0x11, 0x49, 0x2a, 0xad, 0x9d, 0x22,
+	0xe5, 0x52, 0x8d, 0x77, 0xc7, 0xce, 0x2a, 0xeb, 0x1d, 0xb3, 0x3b, 0xdb, 0xd4, 0x08, 0x21, 0xfe,
+	0x04, 0xfe, 0x0c, 0xc4, 0xff, 0x81, 0xc4, 0x31, 0x17, 0xa4, 0x9c, 0x28, 0x2d, 0x17, 0xc4, 0x89,
+	0x3b, 0x17, 0xde, 0xbc, 0xdd, 0xb5, 0x77, 0x1c, 0x27, 0x02, 0x54, 0x0e, 0xa3, 0x9d, 0xf7, 0x9b,
+	0xf7, 0xde, 0xbc, 0xef, 0x1d, 0xb2, 0xc6, 0x82, 0x89, 0x67, 0x19, 0x63, 0x5f, 0x48, 0x41, 0x8b,
+	0x96, 0xf0, 0x24, 0x7f, 0x21, 0x83, 0xad, 0xcf, 0x86, 0x8e, 0x3c, 0x09, 0xfb, 0x86, 0x25, 0x46,
+	0x8d, 0x8e, 0xeb, 0x0b, 0xcf, 0x3e, 0xe4, 0xf2, 0x4c, 0xf8, 0xa7, 0x0d, 0xe6, 0x9f, 0x71, 0xaf,
+	0x7e, 0xc6, 0x82, 0x51, 0xfd, 0xf9, 0x28, 0xa2, 0x1a, 0xa8, 0xa3, 0xc5, 0x5c, 0x37, 0xd2, 0xb3,
+	0x55, 0x4f, 0x49, 0x0f, 0xc5, 0x50, 0x34, 0x10, 0xee, 0x87, 0x03, 0xa4, 0x90, 0xc0, 0x5d, 0xc4,
+	0x5e, 0x7b, 0x99, 0x27, 0x9b, 0x5d, 0xee, 0x3b, 0xcc, 0x75, 0xbe, 0x62, 0x7d, 0x97, 0x3f, 0x3d,
+	0x78, 0x12, 0xca, 0x71, 0x28, 0x69, 0x95, 0x10, 0x93, 0xcb, 0xd0, 0xf7, 0xda, 0x4c, 0xb2, 0x4a,
+	0xe6, 0x6e, 0x6e, 0xbb, 0x64, 0xa6, 0x90, 0xd9, 0x79, 0x4b, 0xd8, 0xbc, 0x92, 0xbd, 0x9b, 0xd9,
+	0xce, 0x9b, 0x29, 0x84, 0xde, 0x23, 0x37, 0x22, 0xea, 0x80, 0x07, 0x01, 0x1b, 0xf2, 0x4a, 0x0e,
+	0x58, 0x56, 0x4d, 0x1d, 0xa4, 0x35, 0x52, 0x7a, 0xcc, 0x02, 0x93, 0x8f, 0x98, 0xe3, 0x39, 0xde,
+	0xb0, 0x92, 0x47, 0x3d, 0x1a, 0x46, 0xbf, 0x24, 0xab, 0x48, 0x0f, 0x42, 0xcf, 0xae, 0x14, 0x80,
+	0xa1, 0xd4, 0xec, 0xfe, 0xf1, 0xcb, 0x9d, 0xc2, 0x53, 0xe6, 0x86, 0xfc, 0x87, 0x97, 0x77, 0x1e,
+	0x8d, 0x98, 0x3c, 0x69, 0xf4, 0x9d, 0xa1, 0xb1, 0xe7, 0xc9, 0x4f, 0xaf, 0x0c, 0x1e, 0x47, 0xaa,
+	0x3e, 0x14, 0x75, 0x4b, 0xf8, 0xbc, 0x61, 0x83, 0x13, 0x46, 0xd3, 0x19, 0x82, 0x4c, 0x8b, 0x05,
+	0x92, 0xfb, 0xe6, 0xec, 0x16, 0x7a, 0x4c, 0xca, 0x51, 0x18, 0x76, 0x2d, 0x4b, 0x84, 0x9e, 0x0c,
+	0x2a, 0xcb, 0x10, 0x80, 0xb5, 0x9d, 0x1d, 0x23, 0xc9, 0x92, 0xb1, 0x28, 0x68, 0x86, 0x2e, 0xd4,
+	0xf1, 0xa4, 0x3f, 0x31, 0xe7, 0x34, 0xd1, 0x6d, 0xb2, 0xde, 0xe6, 0x2e, 0x97, 0xdc, 0x9e, 0x2a,
+	0x5f, 0xc1, 0xe8, 0xce, 0xc3, 0x8a, 0xb3, 0x27, 0x42, 0xeb, 0x24, 0xc5, 0x59, 0x8c, 0x38, 0xe7,
+	0x60, 0xba, 0x43, 0xf2, 0xfb, 0x62, 0x18, 0x54, 0x56, 0xd1, 0xca, 0xea, 0x62, 0x2b, 0x81, 0x23,
+	0xb2, 0x08, 0x79, 0xb7, 0x06, 0xe4, 0xe6, 0x02, 0x73, 0xe9, 0x06, 0xc9, 0x9d, 0xf2, 0x09, 0x24,
+	0x5c, 0x65, 0x4b, 0x6d, 0xe9, 0x27, 0xa4, 0xf0, 0x5c, 0x85, 0x1a, 0x93, 0xbc, 0xb6, 0xf3, 0xde,
+	0x62, 0xed, 0x9a, 0x2e, 0x33, 0x92, 0x78, 0x98, 0x7d, 0x90, 0xa9, 0xfd, 0x9c, 0x25, 0x5b, 0x97,
+	0x19, 0x7b, 0x3e, 0xf3, 0x82, 0x01, 0xf7, 0xa9, 0x43, 0xa2, 0x44, 0xe2, 0x8d, 0xff, 0x53, 0x66,
+	0x23, 0x85, 0x74, 0x8b, 0x14, 0x21, 0xc5, 0xfb, 0xce, 0xc8, 0x91, 0x71, 0xc1, 0x4e, 0x69, 0x7a,
+	0x1b, 0x8b, 0x6c, 0x5f, 0x58, 0xa7, 0xdc, 0xc6, 0x52, 0xcd, 0x9b, 0x33, 0x80, 0x52, 0x92, 0xc7,
+	0x36, 0x50, 0xe5, 0x59, 0x32, 0x71, 0x4f, 0x1f, 0x92, 0xa2, 0x6a, 0xbb, 0xde, 0x64, 0xcc, 0xb1,
+	0x2a, 0xcb, 0x57, 0xc5, 0x3d, 0xe1, 0x32, 0xa7, 0xfc, 0xaa, 0x39, 0xba, 0xdc, 0xb3, 0xb9, 0xbf,
+	0x6b, 0xdb, 0x3e, 0xb4, 0x02, 0x94, 0x97, 0x52, 0xac, 0x83, 0xca, 0xa6, 0x5d, 0xd5, 0xdd, 0x78,
+	0xf5, 0x0a, 0x72, 0xcc, 0x80, 0x1a, 0x23, 0xef, 0xa4, 0x6f, 0xe9, 0x4a, 0xe1, 0x43, 0x47, 0x1d,
+	0x8d, 0xc1, 0x7d, 0x4e, 0x6f, 0x91, 0xe5, 0x27, 0x83, 0x41, 0xc0, 0x65, 0x14, 0x56, 0x33, 0xa6,
+	0xa6, 0x8e, 0x64, 0x53, 0x8e, 0x54, 0xc8, 0xca, 0x17, 0xbe, 0x23, 0x25, 0xf7, 0xd0, 0xf1, 0xa2,
+	0x99, 0x90, 0xb5, 0x1f, 0x97, 0xf5, 0x3b, 0xb4, 0x1c, 0x2b, 0xb9, 0xc4, 0xfc, 0xe8, 0x92, 0x84,
+	0xa4, 0x9b, 0xa4, 0x70, 0x28, 0x3c, 0x2b, 0x19, 0x0b, 0x11, 0x41, 0x05, 0x59, 0x69, 0x32, 0x97,
+	0x29, 0x3c, 0x87, 0xb9, 0x3e, 0x82, 0x5c, 0x27, 0xd0, 0x1b, 0xcc, 0x76, 0xa2, 0x92, 0x3e, 0x23,
+	0x65, 0x2d, 0x2a, 0x01, 0xe4, 0x4f, 0xf5, 0xc7, 0xc7, 0xff, 0xa0, 0x82, 0x0d, 0x5d, 0x32, 0x6e,
+	0x65, 0x1d, 0x54, 0xd1, 0xc4, 0xe9, 0x57, 0x88, 0xa2, 0x89, 0x73, 0x0f, 0x26, 0x9a, 0xfa, 0x1e,
+	0x70, 0xc9, 0x94, 0x69, 0x71, 0x66, 0x35, 0x8c, 0x7e, 0x48, 0x6e, 0x2a, 0xba, 0xcd, 0xc7, 0xae,
+	0x98, 0xcc, 0x8a, 0x20, 0x4a, 0xf1, 0xa2, 0x23, 0xfa, 0x0d, 0x29, 0xc5, 0x5e, 0xc1, 0x90, 0x00,
+	0xad, 0x45, 0x0c, 0xe0, 0x31, 0x04, 0x50, 0xc3, 0xdf, 0x60, 0x14, 0x35, 0xbd, 0xf4, 0x90, 0xac,
+	0xeb, 0x7d, 0x9b, 0xcc, 0x9a, 0x7b, 0xd7, 0xc5, 0x32, 0x61, 0x36, 0xe7, 0x85, 0x55, 0xed, 0x40,
+	0x77, 0x1d, 0x05, 0xd0, 0x6c, 0x04, 0x6b, 0x24, 0x21, 0x55, 0x6c, 0x9a, 0x13, 0x08, 0x2e, 0x78,
+	0xce, 0xed, 0x9e, 0x88, 0x23, 0x5e, 0x59, 0x43, 0xae, 0x45, 0x47, 0xf4, 0x01, 0x79, 0x1b, 0xe1,
+	0x78, 0x7c, 0x3e, 0xf2, 0xc5, 0x28, 0x91, 0x2a, 0xa1, 0xd4, 0x55, 0xc7, 0x6a, 0x04, 0x2e, 0x48,
+	0xf3, 0x7f, 0x1e, 0x81, 0x9a, 0xae, 0xf4, 0x08, 0xfc, 0x5a, 0xff, 0xc7, 0x26, 0x83, 0x58, 0xfd,
+	0x43, 0xf7, 0x6c, 0xee, 0x49, 0x67, 0xe0, 0x70, 0x3f, 0x6e, 0xa2, 0x14, 0x92, 0xee, 0xb0, 0xac,
+	0xde, 0x61, 0xd0, 0xdf, 0x3d, 0x31, 0x76, 0xac, 0x00, 0x5a, 0x49, 0xfd, 0x11, 0x62, 0x6a, 0xd1,
+	0xa0, 0xaa, 0xfd, 0x95, 0x27, 0x95, 0xf4, 0xf5, 0x38, 0x42, 0x5a, 0x91, 0x03, 0xd7, 0x34, 0x31,
+	0x5c, 0xa1, 0xe6, 0xd5, 0x5e, 0x3b, 0xbe, 0x3b, 0xa6, 0xb4, 0xb9, 0x97, 0xfb, 0x97, 0x73, 0x0f,
+	0x1c, 0x56, 0xfb, 0xa8, 0xae, 0x63, 0x23, 0x53, 0x08, 0x36, 0x0f, 0x52, 0xf1, 0xcd, 0x85, 0xb8,
+	0x79, 0x52, 0x18, 0x6d, 0x92, 0xdb, 0x6a, 0xd7, 0x67, 0xd6, 0x29, 0x7a, 0xb2, 0xe7, 0x39, 0xd2,
+	0x61, 0x10, 0xfa, 0x58, 0x26, 0x6a, 0xb8, 0x6b, 0x79, 0xd4, 0x9f, 0x20, 0x39, 0xc7, 0xae, 0x5b,
+	0x35, 0xa7, 0x74, 0x62, 0x83, 0xda, 0x63, 0x28, 0x8b, 0x33, 0x1b, 0x12, 0x8c, 0xde, 0x27, 0x65,
+	0xa8, 0x57, 0x18, 0x15, 0xe1, 0x28, 0x74, 0x21, 0xd9, 0x36, 0x74, 0x83, 0xaa, 0xb4, 0x39, 0x74,
+	0xee, 0x11, 0x45, 0x22, 0x7f, 0x53, 0x8f, 0xa8, 0xcf, 0xc9, 0xfa, 0xf4, 0xfd, 0xf6, 0xd8, 0x17,
+	0xe1, 0x38, 0x80, 0x42, 0x57, 0x6d, 0xf5, 0xae, 0x81, 0xaf, 0x3b, 0xe3, 0x72, 0xde, 0x12, 0x4e,
+	0x73, 0x5e, 0x32, 0x31, 0x3c, 0x68, 0xa9, 0xf9, 0x05, 0xf5, 0x14, 0x15, 0xbf, 0x86, 0xd1, 0x0f,
+	0xc8, 0x5b, 0x3d, 0x21, 0x99, 0xab, 0x31, 0xde, 0x40, 0xc6, 0xcb, 0x07, 0x10, 0xea, 0x52, 0xeb,
+	0xc4, 0x71, 0x6d, 0x93, 0x07, 0xa1, 0x0b, 0xaf, 0x8f, 0x32, 0x56, 0x7f, 0xf5, 0xfa, 0x47, 0x90,
+	0xa9, 0xc9, 0xbc, 0xef, 0xeb, 0xb5, 0x3f, 0x2d, 0x85, 0x32, 0x21, 0x6d, 0xc7, 0xe7, 0x96, 0x54,
+	0xc8, 0xc6, 0x12, 0xfc, 0x33, 0x36, 0xd0, 0xc1, 0x13, 0x98, 0x4b, 0x22, 0x0c, 0x10, 0xcd, 0x40,
+	0x79, 0x6e, 0xce, 0xa3, 0x4d, 0x48, 0xc2, 0x46, 0x16, 0x52, 0x78, 0xab, 0xd3, 0x6d, 0xf7, 0x92,
+	0x91, 0xb2, 0xeb, 0xd9, 0x9d, 0x17, 0xdc, 0x0a, 0x25, 0xdf, 0xc8, 0x35, 0x3b, 0xe7, 0xaf, 0xaa,
+	0x4b, 0x17, 0xb0, 0xfe, 0x7c, 0x55, 0xcd, 0x7c, 0xfb, 0xba, 0x9a, 0xf9, 0x1e, 0xd6, 0x4f, 0xb0,
+	0xce, 0x61, 0x5d, 0xc0, 0xfa, 0x15, 0xd6, 0xef, 0xaf, 0xe1, 0x1c, 0xbe, 0xdf, 0xfd, 0x56, 0x5d,
+	0x3a, 0x87, 0x75, 0x01, 0xeb, 0x78, 0xcd, 0x30, 0x1a, 0x89, 0x77, 0xfd, 0x65, 0x7c, 0x22, 0x7f,
+	0xf4, 0x37, 0x7b, 0x00, 0x18, 0x15, 0xa8, 0x0b, 0x00, 0x00,
 }
 
 func (x SerializableCallType) String() string {
@@ -774,9 +807,14 @@ func (this *SerializableVMOutput) Equal(that interface{}) bool {
 			return false
 		}
 	}
-	if !this.Logs.Equal(that1.Logs) {
+	if len(this.Logs) != len(that1.Logs) {
 		return false
 	}
+	for i := range this.Logs {
+		if !this.Logs[i].Equal(that1.Logs[i]) {
+			return false
+		}
+	}
 	return true
 }
 func (this *SerializableOutputTransfer) Equal(that interface{}) bool {
@@ -819,6 +857,9 @@ func (this *SerializableOutputTransfer) Equal(that interface{}) bool {
 	if !bytes.Equal(this.SenderAddress, that1.SenderAddress) {
 		return false
 	}
+	if !bytes.Equal(this.AsyncData, that1.AsyncData) {
+		return false
+	}
 	return true
 }
 func (this *SerializableStorageUpdate) Equal(that interface{}) bool {
@@ -916,6 +957,12 @@ func (this *SerializableOutputAccount) Equal(that interface{}) bool {
 	if this.GasUsed != that1.GasUsed {
 		return false
 	}
+	if this.BytesAddedToStorage != that1.BytesAddedToStorage {
+		return false
+	}
+	if this.BytesDeletedFromStorage != that1.BytesDeletedFromStorage {
+		return false
+	}
 	return true
 }
 func (this *SerializableLogEntry) Equal(that interface{}) bool {
@@ -1022,6 +1069,14 @@ func (this *SerializableAsyncContext) Equal(that interface{}) bool {
 	if !this.ChildResults.Equal(that1.ChildResults) {
 		return false
 	}
+	if len(this.ProcessedCallbackIDs) != len(that1.ProcessedCallbackIDs) {
+		return false
+	}
+	for i := range this.ProcessedCallbackIDs {
+		if !bytes.Equal(this.ProcessedCallbackIDs[i], that1.ProcessedCallbackIDs[i]) {
+			return false
+		}
+	}
 	return true
 }
 func (this *SerializableVMOutput) GoString() string {
@@ -1060,7 +1115,7 @@ func (this *SerializableOutputTransfer) GoString() string {
 	if this == nil {
 		return "nil"
 	}
-	s := make([]string, 0, 10)
+	s := make([]string, 0, 11)
 	s = append(s, "&contexts.SerializableOutputTransfer{")
 	s = append(s, "Value: "+fmt.Sprintf("%#v", this.Value)+",\n")
 	s = append(s, "GasLimit: "+fmt.Sprintf("%#v", this.GasLimit)+",\n")
@@ -1068,6 +1123,7 @@ func (this *SerializableOutputTransfer) GoString() string {
 	s = append(s, "Data: "+fmt.Sprintf("%#v", this.Data)+",\n")
 	s = append(s, "CallType: "+fmt.Sprintf("%#v", this.CallType)+",\n")
 	s = append(s, "SenderAddress: "+fmt.Sprintf("%#v", this.SenderAddress)+",\n")
+	s = append(s, "AsyncData: "+fmt.Sprintf("%#v", this.AsyncData)+",\n")
 	s = append(s, "}")
 	return strings.Join(s, "")
 }
@@ -1087,7 +1143,7 @@ func (this *SerializableOutputAccount) GoString() string {
 	if this == nil {
 		return "nil"
 	}
-	s := make([]string, 0, 14)
+	s := make([]string, 0, 16)
 	s = append(s, "&contexts.SerializableOutputAccount{")
 	s = append(s, "Address: "+fmt.Sprintf("%#v", this.Address)+",\n")
 	s = append(s, "Nonce: "+fmt.Sprintf("%#v", this.Nonce)+",\n")
@@ -1113,6 +1169,8 @@ func (this *SerializableOutputAccount) GoString() string {
 		s = append(s, "OutputTransfers: "+fmt.Sprintf("%#v", this.OutputTransfers)+",\n")
 	}
 	s = append(s, "GasUsed: "+fmt.Sprintf("%#v", this.GasUsed)+",\n")
+	s = append(s, "BytesAddedToStorage: "+fmt.Sprintf("%#v", this.BytesAddedToStorage)+",\n")
+	s = append(s, "BytesDeletedFromStorage: "+fmt.Sprintf("%#v", this.BytesDeletedFromStorage)+",\n")
 	s = append(s, "}")
 	return strings.Join(s, "")
 }
@@ -1133,7 +1191,7 @@ func (this *SerializableAsyncContext) GoString() string {
 	if this == nil {
 		return "nil"
 	}
-	s := make([]string, 0, 18)
+	s := make([]string, 0, 19)
 	s = append(s, "&contexts.SerializableAsyncContext{")
 	s = append(s, "Address: "+fmt.Sprintf("%#v", this.Address)+",\n")
 	s = append(s, "CallID: "+fmt.Sprintf("%#v", this.CallID)+",\n")
@@ -1153,6 +1211,7 @@ func (this *SerializableAsyncContext) GoString() string {
 	if this.ChildResults != nil {
 		s = append(s, "ChildResults: "+fmt.Sprintf("%#v", this.ChildResults)+",\n")
 	}
+	s = append(s, "ProcessedCallbackIDs: "+fmt.Sprintf("%#v", this.ProcessedCallbackIDs)+",\n")
 	s = append(s, "}")
 	return strings.Join(s, "")
 }
@@ -1184,17 +1243,19 @@ func (m *SerializableVMOutput) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
-	if m.Logs != nil {
-		{
-			size, err := m.Logs.MarshalToSizedBuffer(dAtA[:i])
-			if err != nil {
-				return 0, err
+	if len(m.Logs) > 0 {
+		for iNdEx := len(m.Logs) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Logs[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintAsync(dAtA, i, uint64(size))
 			}
-			i -= size
-			i = encodeVarintAsync(dAtA, i, uint64(size))
+			i--
+			dAtA[i] = 0x4a
 		}
-		i--
-		dAtA[i] = 0x4a
 	}
 	if len(m.TouchedAccounts) > 0 {
 		for iNdEx := len(m.TouchedAccounts) - 1; iNdEx >= 0; iNdEx-- {
@@ -1305,6 +1366,13 @@ func (m *SerializableOutputTransfer) MarshalToSizedBuffer(dAtA []byte) (int, err
 	_ = i
 	var l int
 	_ = l
+	if len(m.AsyncData) > 0 {
+		i -= len(m.AsyncData)
+		copy(dAtA[i:], m.AsyncData)
+		i = encodeVarintAsync(dAtA, i, uint64(len(m.AsyncData)))
+		i--
+		dAtA[i] = 0x3a
+	}
 	if len(m.SenderAddress) > 0 {
 		i -= len(m.SenderAddress)
 		copy(dAtA[i:], m.SenderAddress)
@@ -1415,6 +1483,16 @@ func (m *SerializableOutputAccount) MarshalToSizedBuffer(dAtA []byte) (int, erro
 	_ = i
 	var l int
 	_ = l
+	if m.BytesDeletedFromStorage != 0 {
+		i = encodeVarintAsync(dAtA, i, uint64(m.BytesDeletedFromStorage))
+		i--
+		dAtA[i] = 0x60
+	}
+	if m.BytesAddedToStorage != 0 {
+		i = encodeVarintAsync(dAtA, i, uint64(m.BytesAddedToStorage))
+		i--
+		dAtA[i] = 0x58
+	}
 	if m.GasUsed != 0 {
 		i = encodeVarintAsync(dAtA, i, uint64(m.GasUsed))
 		i--
@@ -1596,6 +1674,15 @@ func (m *SerializableAsyncContext) MarshalToSizedBuffer(dAtA []byte) (int, error
 	_ = i
 	var l int
 	_ = l
+	if len(m.ProcessedCallbackIDs) > 0 {
+		for iNdEx := len(m.ProcessedCallbackIDs) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.ProcessedCallbackIDs[iNdEx])
+			copy(dAtA[i:], m.ProcessedCallbackIDs[iNdEx])
+			i = encodeVarintAsync(dAtA, i, uint64(len(m.ProcessedCallbackIDs[iNdEx])))
+			i--
+			dAtA[i] = 0x7a
+		}
+	}
 	if m.ChildResults != nil {
 		{
 			size, err := m.ChildResults.MarshalToSizedBuffer(dAtA[:i])
@@ -1764,9 +1851,11 @@ func (m *SerializableVMOutput) Size() (n int) {
 			n += 1 + l + sovAsync(uint64(l))
 		}
 	}
-	if m.Logs != nil {
-		l = m.Logs.Size()
-		n += 1 + l + sovAsync(uint64(l))
+	if len(m.Logs) > 0 {
+		for _, e := range m.Logs {
+			l = e.Size()
+			n += 1 + l + sovAsync(uint64(l))
+		}
 	}
 	return n
 }
@@ -1799,6 +1888,10 @@ func (m *SerializableOutputTransfer) Size() (n int) {
 	if l > 0 {
 		n += 1 + l + sovAsync(uint64(l))
 	}
+	l = len(m.AsyncData)
+	if l > 0 {
+		n += 1 + l + sovAsync(uint64(l))
+	}
 	return n
 }
 
@@ -1879,6 +1972,12 @@ func (m *SerializableOutputAccount) Size() (n int) {
 	if m.GasUsed != 0 {
 		n += 1 + sovAsync(uint64(m.GasUsed))
 	}
+	if m.BytesAddedToStorage != 0 {
+		n += 1 + sovAsync(uint64(m.BytesAddedToStorage))
+	}
+	if m.BytesDeletedFromStorage != 0 {
+		n += 1 + sovAsync(uint64(m.BytesDeletedFromStorage))
+	}
 	return n
 }
 
@@ -1969,6 +2068,12 @@ func (m *SerializableAsyncContext) Size() (n int) {
 		l = m.ChildResults.Size()
 		n += 1 + l + sovAsync(uint64(l))
 	}
+	if len(m.ProcessedCallbackIDs) > 0 {
+		for _, b := range m.ProcessedCallbackIDs {
+			l = len(b)
+			n += 1 + l + sovAsync(uint64(l))
+		}
+	}
 	return n
 }
 
@@ -1992,6 +2097,11 @@ func (this *SerializableVMOutput) String() string {
 		mapStringForOutputAccounts += fmt.Sprintf("%v: %v,", k, this.OutputAccounts[k])
 	}
 	mapStringForOutputAccounts += "}"
+	repeatedStringForLogs := "[]*SerializableLogEntry{"
+	for _, f := range this.Logs {
+		repeatedStringForLogs += strings.Replace(f.String(), "SerializableLogEntry", "SerializableLogEntry", 1) + ","
+	}
+	repeatedStringForLogs += "}"
 	s := strings.Join([]string{`&SerializableVMOutput{`,
 		`ReturnData:` + fmt.Sprintf("%v", this.ReturnData) + `,`,
 		`ReturnCode:` + fmt.Sprintf("%v", this.ReturnCode) + `,`,
@@ -2001,7 +2111,7 @@ func (this *SerializableVMOutput) String() string {
 		`OutputAccounts:` + mapStringForOutputAccounts + `,`,
 		`DeletedAccounts:` + fmt.Sprintf("%v", this.DeletedAccounts) + `,`,
 		`TouchedAccounts:` + fmt.Sprintf("%v", this.TouchedAccounts) + `,`,
-		`Logs:` + strings.Replace(this.Logs.String(), "SerializableLogEntry", "SerializableLogEntry", 1) + `,`,
+		`Logs:` + repeatedStringForLogs + `,`,
 		`}`,
 	}, "")
 	return s
@@ -2017,6 +2127,7 @@ func (this *SerializableOutputTransfer) String() string {
 		`Data:` + fmt.Sprintf("%v", this.Data) + `,`,
 		`CallType:` + fmt.Sprintf("%v", this.CallType) + `,`,
 		`SenderAddress:` + fmt.Sprintf("%v", this.SenderAddress) + `,`,
+		`AsyncData:` + fmt.Sprintf("%v", this.AsyncData) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -2063,6 +2174,8 @@ func (this *SerializableOutputAccount) String() string {
 		`BalanceDelta:` + fmt.Sprintf("%v", this.BalanceDelta) + `,`,
 		`OutputTransfers:` + repeatedStringForOutputTransfers + `,`,
 		`GasUsed:` + fmt.Sprintf("%v", this.GasUsed) + `,`,
+		`BytesAddedToStorage:` + fmt.Sprintf("%v", this.BytesAddedToStorage) + `,`,
+		`BytesDeletedFromStorage:` + fmt.Sprintf("%v", this.BytesDeletedFromStorage) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -2104,6 +2217,7 @@ func (this *SerializableAsyncContext) String() string {
 		`CallsCounter:` + fmt.Sprintf("%v", this.CallsCounter) + `,`,
 		`TotalCallsCounter:` + fmt.Sprintf("%v", this.TotalCallsCounter) + `,`,
 		`ChildResults:` + strings.Replace(this.ChildResults.String(), "SerializableVMOutput", "SerializableVMOutput", 1) + `,`,
+		`ProcessedCallbackIDs:` + fmt.Sprintf("%v", this.ProcessedCallbackIDs) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -2507,10 +2621,8 @@ func (m *SerializableVMOutput) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Logs == nil {
-				m.Logs = &SerializableLogEntry{}
-			}
-			if err := m.Logs.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Logs = append(m.Logs, &SerializableLogEntry{})
+			if err := m.Logs[len(m.Logs)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -2730,6 +2842,40 @@ func (m *SerializableOutputTransfer) Unmarshal(dAtA []byte) error {
 				m.SenderAddress = []byte{}
 			}
 			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field AsyncData", wireType)
+			}
+			var byteLen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsync
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				byteLen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if byteLen < 0 {
+				return ErrInvalidLengthAsync
+			}
+			postIndex := iNdEx + byteLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAsync
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.AsyncData = append(m.AsyncData[:0], dAtA[iNdEx:postIndex]...)
+			if m.AsyncData == nil {
+				m.AsyncData = []byte{}
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAsync(dAtA[iNdEx:])
@@ -3337,6 +3483,44 @@ func (m *SerializableOutputAccount) Unmarshal(dAtA []byte) error {
 					break
 				}
 			}
+		case 11:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field BytesAddedToStorage", wireType)
+			}
+			m.BytesAddedToStorage = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsync
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.BytesAddedToStorage |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 12:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field BytesDeletedFromStorage", wireType)
+			}
+			m.BytesDeletedFromStorage = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsync
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.BytesDeletedFromStorage |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAsync(dAtA[iNdEx:])
@@ -3993,6 +4177,38 @@ func (m *SerializableAsyncContext) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 15:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ProcessedCallbackIDs", wireType)
+			}
+			var byteLen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAsync
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				byteLen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if byteLen < 0 {
+				return ErrInvalidLengthAsync
+			}
+			postIndex := iNdEx + byteLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAsync
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ProcessedCallbackIDs = append(m.ProcessedCallbackIDs, make([]byte, postIndex-iNdEx))
+			copy(m.ProcessedCallbackIDs[len(m.ProcessedCallbackIDs)-1], dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAsync(dAtA[iNdEx:])
@@ -0,0 +1,40 @@
+package contexts
+
+import (
+	"bytes"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// isCallbackDeduplicationEnabled returns true if the AsyncContext remembers
+// the remote calls whose callback has completed, once the
+// CallbackDeduplication flag is enabled
+func (context *asyncContext) isCallbackDeduplicationEnabled() bool {
+	return context.host.EnableEpochsHandler().IsCallbackDeduplicationFlagEnabled()
+}
+
+// recordProcessedCallback remembers a remote call being removed from its
+// group, so that its callback is ignored if the result of the call is
+// delivered again while the AsyncContext is still saved; the local calls
+// complete within the transaction, and cannot be delivered twice
+func (context *asyncContext) recordProcessedCallback(call *arwen.AsyncCall) {
+	if call == nil || !call.IsRemote() || !context.isCallbackDeduplicationEnabled() {
+		return
+	}
+	if context.hasProcessedCallback(call.CallID) {
+		return
+	}
+
+	context.processedCallbackIDs = append(context.processedCallbackIDs, call.CallID)
+}
+
+// hasProcessedCallback returns true if the callback of the remote call with
+// the given ID has already completed
+func (context *asyncContext) hasProcessedCallback(callID []byte) bool {
+	for _, processedCallID := range context.processedCallbackIDs {
+		if bytes.Equal(processedCallID, callID) {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,78 @@
+package contexts
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/stretchr/testify/require"
+)
+
+var deduplicationContract = []byte("contract")
+var deduplicationDestination = []byte("destination")
+
+func saveContextWithCompletedCallback(t *testing.T, enableEpoch uint32) *asyncContext {
+	host, _ := initializeArwenAndWasmer_AsyncContext()
+	enableEpochsHandlerStub(host).CallbackDeduplicationEnableEpochField = enableEpoch
+	initRuntime(host.Runtime(), deduplicationContract)
+
+	storedAsync := makeAsyncContext(t, host, deduplicationContract)
+	storedAsync.asyncCallGroups = []*arwen.AsyncCallGroup{
+		{
+			Identifier: "group",
+			AsyncCalls: []*arwen.AsyncCall{
+				{CallID: []byte("callID_1"), Destination: deduplicationDestination, ExecutionMode: arwen.AsyncUnknown},
+				{CallID: []byte("callID_2"), Destination: deduplicationDestination, ExecutionMode: arwen.AsyncUnknown},
+				{CallID: []byte("callID_3"), Destination: deduplicationDestination, ExecutionMode: arwen.SyncExecution},
+			},
+		},
+	}
+
+	require.Nil(t, storedAsync.DeleteAsyncCallAndCleanGroup([]byte("callID_1")))
+	require.Nil(t, storedAsync.DeleteAsyncCallAndCleanGroup([]byte("callID_3")))
+	require.Nil(t, storedAsync.Save())
+
+	return makeAsyncContext(t, host, deduplicationContract)
+}
+
+func callbackInput() *vmcommon.VMInput {
+	return &vmcommon.VMInput{
+		CallerAddr: deduplicationDestination,
+		CallType:   vm.AsynchronousCallBack,
+		Arguments:  [][]byte{{0}},
+	}
+}
+
+func TestAsyncContext_CallbackDeduplication_IgnoresDeliveredCallback(t *testing.T) {
+	async := saveContextWithCompletedCallback(t, 0)
+
+	asyncCall, isLegacy, err := async.UpdateCurrentAsyncCallStatus(deduplicationContract, []byte("callID_1"), callbackInput())
+	require.Nil(t, err)
+	require.False(t, isLegacy)
+	require.Nil(t, asyncCall)
+
+	asyncCall, isLegacy, err = async.UpdateCurrentAsyncCallStatus(deduplicationContract, []byte("callID_2"), callbackInput())
+	require.Nil(t, err)
+	require.False(t, isLegacy)
+	require.NotNil(t, asyncCall)
+	require.Equal(t, arwen.AsyncCallResolved, asyncCall.Status)
+}
+
+func TestAsyncContext_CallbackDeduplication_RecordsRemoteCallsOnly(t *testing.T) {
+	async := saveContextWithCompletedCallback(t, 0)
+
+	loadedContext, err := readAsyncContextFromStorage(async.host.Storage(), deduplicationContract, nil, marshalizer)
+	require.Nil(t, err)
+	require.Equal(t, [][]byte{[]byte("callID_1")}, loadedContext.processedCallbackIDs)
+}
+
+func TestAsyncContext_CallbackDeduplication_Disabled(t *testing.T) {
+	async := saveContextWithCompletedCallback(t, config.DisabledEpoch)
+
+	asyncCall, isLegacy, err := async.UpdateCurrentAsyncCallStatus(deduplicationContract, []byte("callID_1"), callbackInput())
+	require.Nil(t, err)
+	require.True(t, isLegacy)
+	require.NotNil(t, asyncCall)
+}
@@ -0,0 +1,363 @@
+package contexts
+
+import (
+	"math/big"
+	"sort"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/math"
+)
+
+// SetGroupCallback registers the callbacks to be called upon the completion of
+// the specified AsyncCallGroup, creating the group if it does not exist. The
+// success callback is called if all the calls and the sub-groups of the group
+// succeeded and the error callback otherwise; either may be empty. The gas is
+// locked now and provided to the callback, together with the data.
+func (context *asyncContext) SetGroupCallback(
+	groupID string,
+	successCallback string,
+	errorCallback string,
+	data []byte,
+	gas uint64,
+) error {
+	if !context.isSubGroupsEnabled() {
+		return arwen.ErrAsyncSubGroupsDisabled
+	}
+	if context.host.Runtime().ReadOnly() {
+		return arwen.ErrInvalidCallOnReadOnlyMode
+	}
+	if groupID == arwen.LegacyAsyncCallGroupID {
+		return arwen.ErrInvalidAsyncCallGroup
+	}
+	if successCallback == "" && errorCallback == "" {
+		return arwen.ErrInvalidAsyncCallGroup
+	}
+
+	group, exists := context.GetCallGroup(groupID)
+	if exists && group.HasCallback() {
+		return arwen.ErrInvalidAsyncCallGroup
+	}
+
+	for _, callback := range []string{successCallback, errorCallback} {
+		if callback == "" {
+			continue
+		}
+		err := context.host.Runtime().ValidateCallbackName(callback)
+		if err != nil {
+			return err
+		}
+		err = context.registerCallbackName(callback)
+		if err != nil {
+			return err
+		}
+	}
+
+	metering := context.host.Metering()
+	gasToLock := math.AddUint64(gas, metering.ComputeExtraGasLockedForAsync())
+	err := metering.UseGasBounded(gasToLock)
+	if err != nil {
+		return err
+	}
+
+	group, err = context.getOrCreateCallGroup(groupID)
+	if err != nil {
+		return err
+	}
+
+	group.Callback = successCallback
+	group.ErrorCallback = errorCallback
+	group.CallbackData = data
+	group.LockGas(gasToLock)
+
+	return nil
+}
+
+// AddSubGroup makes an AsyncCallGroup the sub-group of another, creating
+// either of them if they do not exist. The parent group is complete only after
+// its own calls and all its sub-groups are complete, and it fails if any of
+// them failed. The groups must form a tree, so a group has a single parent and
+// cannot become the sub-group of its own sub-groups.
+func (context *asyncContext) AddSubGroup(parentGroupID string, groupID string) error {
+	if !context.isSubGroupsEnabled() {
+		return arwen.ErrAsyncSubGroupsDisabled
+	}
+	if context.host.Runtime().ReadOnly() {
+		return arwen.ErrInvalidCallOnReadOnlyMode
+	}
+	if parentGroupID == groupID {
+		return arwen.ErrInvalidAsyncCallGroup
+	}
+	if parentGroupID == arwen.LegacyAsyncCallGroupID || groupID == arwen.LegacyAsyncCallGroupID {
+		return arwen.ErrInvalidAsyncCallGroup
+	}
+	if context.isDescendantGroup(parentGroupID, groupID) {
+		return arwen.ErrInvalidAsyncCallGroup
+	}
+
+	group, exists := context.GetCallGroup(groupID)
+	if exists && group.HasParent() && group.ParentIdentifier != parentGroupID {
+		return arwen.ErrInvalidAsyncCallGroup
+	}
+
+	_, err := context.getOrCreateCallGroup(parentGroupID)
+	if err != nil {
+		return err
+	}
+	group, err = context.getOrCreateCallGroup(groupID)
+	if err != nil {
+		return err
+	}
+
+	group.ParentIdentifier = parentGroupID
+
+	return nil
+}
+
+// CreatePromiseGroup reserves the identifier of a new AsyncCallGroup for the
+// promises of the contract, returning the handle by which the contract
+// registers them. The identifier must be valid and unique in the current
+// execution, so that unrelated modules of a contract cannot add their
+// promises to the same group by accident.
+func (context *asyncContext) CreatePromiseGroup(groupID []byte) (arwen.PromiseGroupHandle, error) {
+	if context.host.Runtime().ReadOnly() {
+		return arwen.InvalidPromiseHandle, arwen.ErrInvalidCallOnReadOnlyMode
+	}
+
+	err := arwen.ValidatePromiseGroupID(groupID)
+	if err != nil {
+		return arwen.InvalidPromiseHandle, err
+	}
+
+	_, exists := context.GetCallGroup(string(groupID))
+	if exists {
+		return arwen.InvalidPromiseHandle, arwen.ErrPromiseGroupExistsAlready
+	}
+	for _, reservedGroupID := range context.promiseGroups {
+		if reservedGroupID == string(groupID) {
+			return arwen.InvalidPromiseHandle, arwen.ErrPromiseGroupExistsAlready
+		}
+	}
+
+	context.promiseGroups = append(context.promiseGroups, string(groupID))
+	return arwen.PromiseGroupHandle(len(context.promiseGroups)), nil
+}
+
+// RegisterPromise registers the AsyncCall in the promise group created by the
+// contract with the given handle, returning the identifier of the promise
+func (context *asyncContext) RegisterPromise(groupHandle arwen.PromiseGroupHandle, call *arwen.AsyncCall) (arwen.PromiseID, error) {
+	if groupHandle <= 0 || int(groupHandle) > len(context.promiseGroups) {
+		return arwen.InvalidPromiseHandle, arwen.ErrInvalidPromiseGroupHandle
+	}
+
+	err := context.RegisterAsyncCall(context.promiseGroups[groupHandle-1], call)
+	if err != nil {
+		return arwen.InvalidPromiseHandle, err
+	}
+
+	context.promisesCounter++
+	return arwen.PromiseID(context.promisesCounter), nil
+}
+
+func (context *asyncContext) getOrCreateCallGroup(groupID string) (*arwen.AsyncCallGroup, error) {
+	group, ok := context.GetCallGroup(groupID)
+	if ok {
+		return group, nil
+	}
+
+	if context.asyncLimits.MaxCallGroups > 0 && len(context.asyncCallGroups) >= int(context.asyncLimits.MaxCallGroups) {
+		return nil, arwen.ErrMaxAsyncCallGroupsExceeded
+	}
+	group = arwen.NewAsyncCallGroup(groupID)
+	err := context.AddCallGroup(group)
+	if err != nil {
+		return nil, err
+	}
+
+	return group, nil
+}
+
+// isDescendantGroup verifies whether the group is the ancestor group itself
+// or one of its sub-groups, at any depth
+func (context *asyncContext) isDescendantGroup(groupID string, ancestorGroupID string) bool {
+	currentGroupID := groupID
+	for depth := 0; depth <= len(context.asyncCallGroups); depth++ {
+		if currentGroupID == ancestorGroupID {
+			return true
+		}
+		group, ok := context.GetCallGroup(currentGroupID)
+		if !ok || !group.HasParent() {
+			return false
+		}
+		currentGroupID = group.ParentIdentifier
+	}
+
+	return false
+}
+
+func (context *asyncContext) hasSubGroups(groupID string) bool {
+	for _, group := range context.asyncCallGroups {
+		if group.ParentIdentifier == groupID {
+			return true
+		}
+	}
+
+	return false
+}
+
+func (context *asyncContext) isGroupComplete(group *arwen.AsyncCallGroup) bool {
+	return group.IsComplete() && !context.hasSubGroups(group.Identifier)
+}
+
+// isCallRejected verifies whether the AsyncCall failed; the status of a call
+// completed by a cross-shard callback is held by the call which triggered the
+// callback, because the AsyncContext was reloaded from storage since
+func (context *asyncContext) isCallRejected(asyncCall *arwen.AsyncCall) bool {
+	parentCall := context.callbackParentCall
+	if parentCall != nil && string(parentCall.CallID) == string(asyncCall.CallID) {
+		return parentCall.IsRejected()
+	}
+
+	return asyncCall.IsRejected()
+}
+
+// completeGroupIfDone deletes the AsyncCallGroup once it is complete, calls
+// its callback and then completes its parent group, if its parent has no more
+// calls or sub-groups pending either
+func (context *asyncContext) completeGroupIfDone(group *arwen.AsyncCallGroup) error {
+	if !context.isGroupComplete(group) {
+		return nil
+	}
+
+	context.deleteCallGroupByID(group.Identifier)
+
+	if group.GetCallbackName() != "" {
+		err := context.executeGroupCallback(group)
+		if err != nil {
+			return err
+		}
+	}
+
+	if !group.HasParent() {
+		return nil
+	}
+
+	parentGroup, ok := context.GetCallGroup(group.ParentIdentifier)
+	if !ok {
+		return nil
+	}
+	if group.Failed {
+		parentGroup.Failed = true
+	}
+
+	return context.completeGroupIfDone(parentGroup)
+}
+
+// completeEmptyGroups completes the groups left without calls and sub-groups
+// after all the calls of the contract were executed or sent, such as the
+// groups created only to aggregate sub-groups whose calls were all local
+func (context *asyncContext) completeEmptyGroups() error {
+	groups := make([]*arwen.AsyncCallGroup, len(context.asyncCallGroups))
+	copy(groups, context.asyncCallGroups)
+
+	for _, group := range groups {
+		_, exists := context.GetCallGroup(group.Identifier)
+		if !exists {
+			continue
+		}
+		err := context.completeGroupIfDone(group)
+		if err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// executeGroupCallback calls the callback of a completed AsyncCallGroup. A
+// failed callback only reverts its own effects, like the callbacks of the
+// async calls.
+func (context *asyncContext) executeGroupCallback(group *arwen.AsyncCallGroup) error {
+	metering := context.host.Metering()
+
+	gasLimit := group.GasLocked
+	if group.IsGasLockedInTx() {
+		metering.RestoreGas(group.GasLocked)
+	} else if gasLimit > metering.GasLeft() {
+		// the gas was locked by the transaction which saved the group before
+		// its cross-shard calls, so the callback is funded by the gas left to
+		// the cross-shard callback completing the group
+		gasLimit = metering.GasLeft()
+	}
+
+	callbackInput := context.createGroupCallbackInput(group, gasLimit)
+	callbackVMOutput, _, callbackErr := context.host.ExecuteOnDestContext(callbackInput)
+	if callbackVMOutput == nil {
+		return arwen.ErrAsyncNoOutputFromCallback
+	}
+
+	logAsync.Trace("async call group: sync callback call",
+		"group", group.Identifier,
+		"retCode", callbackVMOutput.ReturnCode,
+		"message", callbackVMOutput.ReturnMessage,
+		"gasRemaining", callbackVMOutput.GasRemaining,
+		"error", callbackErr)
+
+	return nil
+}
+
+func (context *asyncContext) createGroupCallbackInput(group *arwen.AsyncCallGroup, gasLimit uint64) *vmcommon.ContractCallInput {
+	runtime := context.host.Runtime()
+
+	input := &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallType:       vm.AsynchronousCallBack,
+			CallerAddr:     context.address,
+			Arguments:      [][]byte{group.CallbackData},
+			CallValue:      big.NewInt(0),
+			GasPrice:       runtime.GetVMInput().GasPrice,
+			GasProvided:    gasLimit,
+			CurrentTxHash:  runtime.GetCurrentTxHash(),
+			OriginalTxHash: runtime.GetOriginalTxHash(),
+			PrevTxHash:     runtime.GetPrevTxHash(),
+			AsyncArguments: &vmcommon.AsyncArguments{
+				CallID:                       context.generateNewCallID(),
+				CallerCallID:                 context.callID,
+				CallbackAsyncInitiatorCallID: context.callID,
+			},
+		},
+		RecipientAddr: context.address,
+		Function:      group.GetCallbackName(),
+	}
+
+	logAsync.Trace("created group callback input", "group", group.Identifier, "function", input.Function)
+	logAsync.Trace("created group callback input gas", "provided", input.GasProvided, "locked", group.GasLocked)
+	return input
+}
+
+// callGroupsInSendingOrder returns the AsyncCallGroups in the order in which
+// their pending calls are sent: in ascending order of their identifiers once
+// the canonical order is enabled, and in their order of creation before. The
+// calls of each group are sent in their order of registration, so that the
+// cross-shard async calls are generated in the same order by every node.
+func (context *asyncContext) callGroupsInSendingOrder() []*arwen.AsyncCallGroup {
+	groups := make([]*arwen.AsyncCallGroup, len(context.asyncCallGroups))
+	copy(groups, context.asyncCallGroups)
+	if !context.isCanonicalOrderEnabled() {
+		return groups
+	}
+
+	sort.SliceStable(groups, func(i, j int) bool {
+		return groups[i].Identifier < groups[j].Identifier
+	})
+	return groups
+}
+
+func (context *asyncContext) isCanonicalOrderEnabled() bool {
+	return context.host.EnableEpochsHandler().IsAsyncCallsCanonicalOrderFlagEnabled()
+}
+
+func (context *asyncContext) isSubGroupsEnabled() bool {
+	return context.host.EnableEpochsHandler().IsAsyncCallSubGroupsFlagEnabled()
+}
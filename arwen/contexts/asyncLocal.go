@@ -105,9 +105,46 @@ func (context *asyncContext) ExecuteSyncCallbackAndFinishOutput(
 	err error) (bool, *vmcommon.VMOutput) {
 	callbackVMOutput, isComplete, callbackErr := context.executeSyncCallback(asyncCall, vmOutput, gasAccumulated, err)
 	context.finishAsyncLocalCallbackExecution(callbackVMOutput, callbackErr, vmOutput.ReturnCode)
+	context.writeCallbackFailedLogIfNeeded(asyncCall, callbackVMOutput, callbackErr)
 	return isComplete, callbackVMOutput
 }
 
+// writeCallbackFailedLogIfNeeded records a failed callback in the output of
+// its caller. The failed callback has reverted only its own effects, while
+// the effects of the called contract are kept, so without a log entry the
+// failure would not be visible in the results of the transaction.
+func (context *asyncContext) writeCallbackFailedLogIfNeeded(
+	asyncCall *arwen.AsyncCall,
+	callbackVMOutput *vmcommon.VMOutput,
+	callbackErr error,
+) {
+	returnCode := vmcommon.Ok
+	returnMessage := ""
+	if callbackVMOutput != nil {
+		returnCode = callbackVMOutput.ReturnCode
+		returnMessage = callbackVMOutput.ReturnMessage
+	}
+	if callbackErr != nil && returnCode == vmcommon.Ok {
+		returnCode = vmcommon.ExecutionFailed
+		returnMessage = callbackErr.Error()
+	}
+	if returnCode == vmcommon.Ok {
+		return
+	}
+
+	context.host.Output().WriteLogWithIdentifier(
+		context.address,
+		[][]byte{
+			asyncCall.GetDestination(),
+			asyncCall.CallID,
+			[]byte(asyncCall.GetCallbackName()),
+			big.NewInt(int64(returnCode)).Bytes(),
+		},
+		[]byte(returnMessage),
+		[]byte(arwen.AsyncCallbackFailedIdentifier),
+	)
+}
+
 // TODO rename to executeLocalCallback
 func (context *asyncContext) executeSyncCallback(
 	asyncCall *arwen.AsyncCall,
@@ -193,6 +230,7 @@ func (context *asyncContext) executeSyncHalfOfBuiltinFunction(asyncCall *arwen.A
 		if asyncCall.HasCallback() {
 			callbackVMOutput, _, callbackErr := context.executeSyncCallback(asyncCall, vmOutput, 0, err)
 			context.finishAsyncLocalCallbackExecution(callbackVMOutput, callbackErr, 0)
+			context.writeCallbackFailedLogIfNeeded(asyncCall, callbackVMOutput, callbackErr)
 		}
 	}
 
@@ -286,12 +324,13 @@ func (context *asyncContext) createCallbackInput(
 	actualCallbackInitiator := context.determineDestinationForAsyncCall(asyncCall.GetDestination(), asyncCall.GetData())
 
 	arguments := context.getArgumentsForCallback(vmOutput, destinationErr)
+	arguments = asyncCall.NormalizeCallbackArguments(arguments)
 
 	esdtFunction := ""
 	isESDTOnCallBack := false
 	esdtArgs := make([][]byte, 0)
 	returnWithError := false
-	if destinationErr == nil && vmOutput.ReturnCode == vmcommon.Ok {
+	if destinationErr == nil && vmOutput.ReturnCode == vmcommon.Ok && !asyncCall.IsRejected() {
 		// when execution went Ok, callBack arguments are:
 		// [0, result1, result2, ....]
 		isESDTOnCallBack, esdtFunction, esdtArgs = context.isESDTTransferOnReturnDataWithNoAdditionalData(
@@ -399,8 +438,13 @@ func (context *asyncContext) getArgumentsForCallback(vmOutput *vmcommon.VMOutput
 		arguments = append(arguments, vmOutput.ReturnData...)
 	} else {
 		// when execution returned error, callBack arguments are:
-		// [error code, error message]
+		// [error code, error message], followed by
+		// [typed error code, typed error payload] if the destination signaled
+		// a typed error
 		arguments = append(arguments, []byte(vmOutput.ReturnMessage))
+		if vmOutput.ReturnCode == vmcommon.UserError {
+			arguments = append(arguments, vmOutput.ReturnData...)
+		}
 	}
 
 	return arguments
@@ -415,30 +459,6 @@ func (context *asyncContext) isSameShardNFTTransfer(contractCallInput *vmcommon.
 		contractCallInput.Function == core.BuiltInFunctionESDTNFTTransfer
 }
 
-func (context *asyncContext) createGroupCallbackInput(group *arwen.AsyncCallGroup) *vmcommon.ContractCallInput {
-	runtime := context.host.Runtime()
-
-	input := &vmcommon.ContractCallInput{
-		VMInput: vmcommon.VMInput{
-			CallType:       vm.AsynchronousCallBack,
-			CallerAddr:     context.callerAddr,
-			Arguments:      [][]byte{group.CallbackData},
-			CallValue:      big.NewInt(0),
-			GasPrice:       runtime.GetVMInput().GasPrice,
-			GasProvided:    group.GasLocked + context.gasAccumulated,
-			CurrentTxHash:  runtime.GetCurrentTxHash(),
-			OriginalTxHash: runtime.GetOriginalTxHash(),
-			PrevTxHash:     runtime.GetPrevTxHash(),
-		},
-		RecipientAddr: runtime.GetContextAddress(),
-		Function:      group.Callback,
-	}
-
-	logAsync.Trace("created group callback input", "group", group.Identifier, "function", input.Function)
-	logAsync.Trace("created group callback input gas", "provided", input.GasProvided, "locked", group.GasLocked, "accumulated", context.gasAccumulated)
-	return input
-}
-
 func (context *asyncContext) createContextCallbackInput() *vmcommon.ContractCallInput {
 	host := context.host
 	runtime := host.Runtime()
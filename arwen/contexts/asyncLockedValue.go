@@ -0,0 +1,199 @@
+package contexts
+
+import (
+	"encoding/binary"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/core"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/math"
+)
+
+// lockedAmount is a quantity of EGLD (empty token identifier) or of an ESDT
+// token which left the contract with an async call still waiting for its callback
+type lockedAmount struct {
+	tokenIdentifier []byte
+	nonce           uint64
+	value           *big.Int
+}
+
+// lockedTokenKeyLength is the length of the key of a token in the ledger of
+// locked values, besides the token identifier itself
+const lockedTokenKeyLength = 4 + 8
+
+// LockedValueStorageKey returns the protected storage key under which the
+// ledger of locked values of an account keeps the given token; node
+// components can read it through the blockchain hook
+func LockedValueStorageKey(elrondProtectedKeyPrefix []byte, tokenIdentifier []byte, nonce uint64) []byte {
+	key := make([]byte, 0, len(elrondProtectedKeyPrefix)+len(VMStoragePrefix)+len(arwen.LockedValueDataPrefix)+len(tokenIdentifier)+lockedTokenKeyLength)
+	key = append(key, elrondProtectedKeyPrefix...)
+	key = append(key, VMStoragePrefix...)
+	key = append(key, arwen.LockedValueDataPrefix...)
+	return appendLockedTokenKey(key, tokenIdentifier, nonce)
+}
+
+// appendLockedTokenKey appends the token identifier prefixed by its length,
+// so that the nonce of a token cannot be mistaken for the end of the
+// identifier of another token
+func appendLockedTokenKey(key []byte, tokenIdentifier []byte, nonce uint64) []byte {
+	lengthBytes := make([]byte, 4)
+	binary.BigEndian.PutUint32(lengthBytes, uint32(len(tokenIdentifier)))
+	key = append(key, lengthBytes...)
+	key = append(key, tokenIdentifier...)
+	if nonce > 0 {
+		nonceBytes := make([]byte, 8)
+		binary.BigEndian.PutUint64(nonceBytes, nonce)
+		key = append(key, nonceBytes...)
+	}
+	return key
+}
+
+// GetLockedValue returns the amount of the given token which the current
+// contract sent with async calls still waiting for their callback; the empty
+// token identifier stands for EGLD
+func (context *asyncContext) GetLockedValue(tokenIdentifier []byte, nonce uint64) *big.Int {
+	storage := context.host.Storage()
+	storageKey := context.lockedValueKey(tokenIdentifier, nonce)
+
+	// values written earlier in this execution are not known by the node yet
+	storageUpdate, found := storage.GetStorageUpdates(context.address)[string(storageKey)]
+	if found && storageUpdate.Written {
+		return big.NewInt(0).SetBytes(storageUpdate.Data)
+	}
+
+	data, _ := storage.GetStorageUnmetered(storageKey)
+	return big.NewInt(0).SetBytes(data)
+}
+
+// useGasForLockedValue charges the writes to the ledger of locked values of
+// an async call which will be sent across shards, when it is added, because
+// its gas is forwarded before the ledger is written; the callback releasing
+// the value is not charged again
+func (context *asyncContext) useGasForLockedValue(groupID string, asyncCall *arwen.AsyncCall) error {
+	if !context.locksValueOfAsyncCall(groupID, asyncCall) {
+		return nil
+	}
+
+	metering := context.host.Metering()
+	storePerByte := metering.GasSchedule().BaseOperationCost.StorePerByte
+	gasToUse := uint64(0)
+	for _, amount := range context.lockedAmountsOfAsyncCall(asyncCall) {
+		gasToUse = math.AddUint64(gasToUse, math.MulUint64(storePerByte, uint64(len(amount.value.Bytes()))))
+	}
+	return metering.UseGasBounded(gasToUse)
+}
+
+// locksValueOfAsyncCall returns true if the value of the async call is kept
+// in the ledger until its callback; legacy async calls are not persisted, so
+// their callbacks could not release anything
+func (context *asyncContext) locksValueOfAsyncCall(groupID string, asyncCall *arwen.AsyncCall) bool {
+	if !context.host.EnableEpochsHandler().IsLockedValueLedgerFlagEnabled() {
+		return false
+	}
+	if groupID == arwen.LegacyAsyncCallGroupID {
+		return false
+	}
+	return asyncCall.ExecutionMode == arwen.AsyncUnknown || asyncCall.ExecutionMode == arwen.AsyncBuiltinFuncCrossShard
+}
+
+func (context *asyncContext) lockValueOfAsyncCall(groupID string, asyncCall *arwen.AsyncCall) error {
+	if !context.locksValueOfAsyncCall(groupID, asyncCall) || asyncCall.IsRejected() {
+		return nil
+	}
+
+	for _, amount := range context.lockedAmountsOfAsyncCall(asyncCall) {
+		lockedValue := context.GetLockedValue(amount.tokenIdentifier, amount.nonce)
+		err := context.setLockedValue(amount, lockedValue.Add(lockedValue, amount.value))
+		if err != nil {
+			return err
+		}
+	}
+	asyncCall.ValueLocked = true
+	return nil
+}
+
+// unlockValueOfAsyncCall releases the value locked by the async call whose
+// callback is executed; the calls sent before the ledger was enabled did not
+// lock anything, while releasing more than the ledger holds would let the
+// same value be released twice
+func (context *asyncContext) unlockValueOfAsyncCall(asyncCall *arwen.AsyncCall) error {
+	if !asyncCall.ValueLocked {
+		return nil
+	}
+
+	for _, amount := range context.lockedAmountsOfAsyncCall(asyncCall) {
+		lockedValue := context.GetLockedValue(amount.tokenIdentifier, amount.nonce)
+		if lockedValue.Cmp(amount.value) < 0 {
+			return arwen.ErrLockedValueExceeded
+		}
+		err := context.setLockedValue(amount, lockedValue.Sub(lockedValue, amount.value))
+		if err != nil {
+			return err
+		}
+	}
+	asyncCall.ValueLocked = false
+	return nil
+}
+
+func (context *asyncContext) setLockedValue(amount *lockedAmount, lockedValue *big.Int) error {
+	storageKey := context.lockedValueKey(amount.tokenIdentifier, amount.nonce)
+	_, err := context.host.Storage().SetProtectedStorageToAddressUnmetered(context.address, storageKey, lockedValue.Bytes())
+	return err
+}
+
+func (context *asyncContext) lockedValueKey(tokenIdentifier []byte, nonce uint64) []byte {
+	prefix := context.host.Storage().GetVmProtectedPrefix(arwen.LockedValueDataPrefix)
+	key := make([]byte, len(prefix), len(prefix)+len(tokenIdentifier)+lockedTokenKeyLength)
+	copy(key, prefix)
+	return appendLockedTokenKey(key, tokenIdentifier, nonce)
+}
+
+// lockedAmountsOfAsyncCall returns the EGLD value of the async call, together
+// with the ESDT tokens transferred by its data, if any
+func (context *asyncContext) lockedAmountsOfAsyncCall(asyncCall *arwen.AsyncCall) []*lockedAmount {
+	amounts := make([]*lockedAmount, 0)
+
+	value := big.NewInt(0).SetBytes(asyncCall.GetValue())
+	if value.Sign() > 0 {
+		amounts = append(amounts, &lockedAmount{value: value})
+	}
+
+	function, args, err := context.callArgsParser.ParseData(string(asyncCall.GetData()))
+	if err != nil {
+		return amounts
+	}
+
+	switch function {
+	case core.BuiltInFunctionESDTTransfer:
+		if len(args) >= 2 {
+			amounts = appendLockedAmount(amounts, args[0], nil, args[1])
+		}
+	case core.BuiltInFunctionESDTNFTTransfer:
+		if len(args) >= 3 {
+			amounts = appendLockedAmount(amounts, args[0], args[1], args[2])
+		}
+	case core.BuiltInFunctionMultiESDTNFTTransfer:
+		if len(args) < 2 {
+			return amounts
+		}
+		numTransfers := big.NewInt(0).SetBytes(args[1]).Uint64()
+		for i := uint64(0); i < numTransfers && 2+3*i+2 < uint64(len(args)); i++ {
+			offset := 2 + 3*i
+			amounts = appendLockedAmount(amounts, args[offset], args[offset+1], args[offset+2])
+		}
+	}
+
+	return amounts
+}
+
+func appendLockedAmount(amounts []*lockedAmount, tokenIdentifier []byte, nonce []byte, value []byte) []*lockedAmount {
+	amount := &lockedAmount{
+		tokenIdentifier: tokenIdentifier,
+		nonce:           big.NewInt(0).SetBytes(nonce).Uint64(),
+		value:           big.NewInt(0).SetBytes(value),
+	}
+	if len(amount.tokenIdentifier) == 0 || amount.value.Sign() == 0 {
+		return amounts
+	}
+	return append(amounts, amount)
+}
@@ -7,6 +7,7 @@ import (
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/elrond-vm-common/txDataBuilder"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
 )
 
 /*
@@ -21,7 +22,7 @@ func AddAsyncArgumentsToOutputTransfers(
 	if asyncParams == nil {
 		return nil
 	}
-	for _, outAcc := range vmOutput.OutputAccounts {
+	for _, outAcc := range arwen.SortedOutputAccounts(vmOutput.OutputAccounts) {
 		// if !bytes.Equal(address, outAcc.Address) {
 		// 	continue
 		// }
@@ -1,8 +1,6 @@
 package contexts
 
 import (
-	"errors"
-
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
 	"github.com/ElrondNetwork/elrond-go-core/marshal"
@@ -16,11 +14,11 @@ func (context *asyncContext) Save() error {
 	storage := context.host.Storage()
 
 	if len(callID) > arwen.AddressLen {
-		return errors.New("callID must be 32 bytes")
+		return arwen.ErrInvalidCallIDLength
 	}
 
 	storageKey := getAsyncContextStorageKey(context.asyncStorageDataPrefix, callID)
-	data, err := context.marshalizer.Marshal(context.toSerializable())
+	data, err := encodeAsyncContext(context.toSerializable(), context.marshalizer, context.isVersionedFormatEnabled())
 	if err != nil {
 		return err
 	}
@@ -54,6 +52,47 @@ func (context *asyncContext) DeleteFromAddress(address []byte) error {
 	return err
 }
 
+// SetProtectRegisteredCallbacks makes the AsyncContext record the callbacks of
+// the async calls in the storage of their contracts, so that the functions
+// registered as callbacks can no longer be called directly
+func (context *asyncContext) SetProtectRegisteredCallbacks(protect bool) {
+	context.protectRegisteredCallbacks = protect
+}
+
+// IsRegisteredCallback returns true if the running contract has ever
+// registered the given function as the callback of one of its async calls
+func (context *asyncContext) IsRegisteredCallback(functionName string) bool {
+	if !context.protectRegisteredCallbacks {
+		return false
+	}
+
+	storage := context.host.Storage()
+	value, _ := storage.GetStorageUnmetered(context.getCallbackNameStorageKey(functionName))
+	return len(value) > 0
+}
+
+// registerCallbackName records the callback of an async call in the storage
+// of the contract, so that the function can no longer be called directly
+func (context *asyncContext) registerCallbackName(functionName string) error {
+	if !context.protectRegisteredCallbacks || functionName == "" {
+		return nil
+	}
+	if context.IsRegisteredCallback(functionName) {
+		return nil
+	}
+
+	storage := context.host.Storage()
+	_, err := storage.SetProtectedStorageToAddressUnmetered(context.address, context.getCallbackNameStorageKey(functionName), []byte{1})
+	return err
+}
+
+func (context *asyncContext) getCallbackNameStorageKey(functionName string) []byte {
+	prefix := context.host.Storage().GetVmProtectedPrefix(arwen.CallbackNamePrefix)
+	key := make([]byte, 0, len(prefix)+len(functionName))
+	key = append(key, prefix...)
+	return append(key, functionName...)
+}
+
 func (context *asyncContext) LoadParentContextFromStackOrStorage() (arwen.AsyncContext, error) {
 	if context.callType != vm.AsynchronousCallBack {
 		return context.loadFromStackOrStorage(context.callerAddr, context.callerCallID)
@@ -92,6 +131,7 @@ func (context *asyncContext) loadSpecificContext(address []byte, callID []byte)
 	context.callsCounter = loadedContext.callsCounter
 	context.totalCallsCounter = loadedContext.totalCallsCounter
 	context.childResults = loadedContext.childResults
+	context.processedCallbackIDs = loadedContext.processedCallbackIDs
 	context.gasAccumulated = loadedContext.gasAccumulated
 
 	return nil
@@ -113,8 +153,7 @@ func readAsyncContextFromStorage(
 }
 
 func deserializeAsyncContext(data []byte, marshalizer *marshal.GogoProtoMarshalizer) (*asyncContext, error) {
-	deserializedAsyncContext := &SerializableAsyncContext{}
-	err := marshalizer.Unmarshal(deserializedAsyncContext, data)
+	deserializedAsyncContext, err := decodeAsyncContext(data, marshalizer)
 	if err != nil {
 		return nil, err
 	}
@@ -122,6 +161,10 @@ func deserializeAsyncContext(data []byte, marshalizer *marshal.GogoProtoMarshali
 	return fromSerializable(deserializedAsyncContext), nil
 }
 
+func (context *asyncContext) isVersionedFormatEnabled() bool {
+	return context.host.EnableEpochsHandler().IsVersionedAsyncContextFlagEnabled()
+}
+
 func (context *asyncContext) toSerializable() *SerializableAsyncContext {
 	return &SerializableAsyncContext{
 		Address:                      context.address,
@@ -138,6 +181,7 @@ func (context *asyncContext) toSerializable() *SerializableAsyncContext {
 		CallsCounter:                 context.callsCounter,
 		TotalCallsCounter:            context.totalCallsCounter,
 		ChildResults:                 toSerializableVMOutput(context.childResults),
+		ProcessedCallbackIDs:         context.processedCallbackIDs,
 	}
 }
 
@@ -159,6 +203,7 @@ func fromSerializable(serializedContext *SerializableAsyncContext) *asyncContext
 		returnData:                   serializedContext.ReturnData,
 		asyncCallGroups:              arwen.FromSerializableAsyncCallGroups(serializedContext.AsyncCallGroups),
 		childResults:                 fromSerializableVMOutput(serializedContext.ChildResults),
+		processedCallbackIDs:         serializedContext.ProcessedCallbackIDs,
 	}
 }
 
@@ -167,6 +212,9 @@ func (context *SerializableAsyncContext) IsComplete() bool {
 	return context.CallsCounter == 0 && len(context.AsyncCallGroups) == 0
 }
 
+// toSerializableVMOutput keeps only the results of the VMOutput, which is
+// all the async context needs of its child results; VMOutputToSerializable
+// converts the whole VMOutput
 func toSerializableVMOutput(vmOutput *vmcommon.VMOutput) *SerializableVMOutput {
 	if vmOutput == nil {
 		return nil
@@ -46,7 +46,7 @@ func (context *asyncContext) sendAsyncCallCrossShard(asyncCall *arwen.AsyncCall)
 		callData.Bytes(argument)
 	}
 
-	return output.Transfer(
+	err = output.Transfer(
 		asyncCall.GetDestination(),
 		runtime.GetContextAddress(),
 		asyncCall.GetGasLimit(),
@@ -56,6 +56,33 @@ func (context *asyncContext) sendAsyncCallCrossShard(asyncCall *arwen.AsyncCall)
 		callData.ToBytes(),
 		vm.AsynchronousCall,
 	)
+	if err != nil {
+		return err
+	}
+
+	if asyncCall.HasExpiry() {
+		context.writeAsyncCallExpiryLog(asyncCall)
+	}
+
+	return nil
+}
+
+// writeAsyncCallExpiryLog announces the expiry round of a cross-shard async
+// call, so that the block processing can send the timeout callback in case
+// the destination does not respond in time
+func (context *asyncContext) writeAsyncCallExpiryLog(asyncCall *arwen.AsyncCall) {
+	context.host.Output().WriteLogWithIdentifier(
+		context.address,
+		[][]byte{
+			asyncCall.GetDestination(),
+			asyncCall.CallID,
+			context.GetCallID(),
+			big.NewInt(0).SetUint64(asyncCall.ExpiryRound).Bytes(),
+			big.NewInt(0).SetUint64(asyncCall.GetGasLocked()).Bytes(),
+		},
+		[]byte{},
+		[]byte(arwen.AsyncCallExpiryIdentifier),
+	)
 }
 
 func createAsyncDataForAsyncCall(newCallID []byte, currentCallID []byte) []byte {
@@ -118,6 +145,12 @@ func (context *asyncContext) createDataForCrossShardCallback(
 		}
 	} else {
 		transferData.Str(returnMessage)
+		if returnCode == vmcommon.UserError {
+			// the code and the payload of a typed error, if one was signaled
+			for _, data := range returnData {
+				transferData.Bytes(data)
+			}
+		}
 	}
 	return asyncData.ToBytes(), transferData.ToBytes()
 }
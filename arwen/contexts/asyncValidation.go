@@ -0,0 +1,60 @@
+package contexts
+
+import (
+	"bytes"
+	"fmt"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/math"
+)
+
+// validateAsyncCall checks a new async call before its gas is locked, so that
+// the contract fails when creating a call which could not be sent rather than
+// the destination shard when receiving it, and charges its call data by size
+func (context *asyncContext) validateAsyncCall(call *arwen.AsyncCall) error {
+	if !context.isAsyncCallValidationEnabled() {
+		return nil
+	}
+
+	if len(call.Destination) != arwen.AddressLen {
+		return fmt.Errorf("%w: %d bytes instead of %d",
+			arwen.ErrInvalidAsyncCallDestination, len(call.Destination), arwen.AddressLen)
+	}
+
+	maxCallDataSize := context.asyncLimits.MaxCallDataSize
+	if maxCallDataSize > 0 && uint64(len(call.Data)) > uint64(maxCallDataSize) {
+		return fmt.Errorf("%w: %d bytes, at most %d",
+			arwen.ErrAsyncCallDataTooLarge, len(call.Data), maxCallDataSize)
+	}
+
+	// call data without a function only transfers value
+	functionName := asyncCallFunctionName(call.Data)
+	if len(functionName) > 0 && !isValidAsyncCallFunctionName(functionName) {
+		return fmt.Errorf("%w: %q", arwen.ErrInvalidAsyncCallFunction, functionName)
+	}
+
+	metering := context.host.Metering()
+	gasPerByte := metering.GasSchedule().ElrondAPICost.AsyncCallDataPerByte
+	return metering.UseGasBounded(math.MulUint64(gasPerByte, uint64(len(call.Data))))
+}
+
+func (context *asyncContext) isAsyncCallValidationEnabled() bool {
+	return context.host.EnableEpochsHandler().IsAsyncCallValidationFlagEnabled()
+}
+
+func asyncCallFunctionName(data []byte) string {
+	end := bytes.IndexByte(data, '@')
+	if end < 0 {
+		end = len(data)
+	}
+	return string(data[:end])
+}
+
+// the functions of the contracts and the built-in functions follow the same
+// naming rules
+func isValidAsyncCallFunctionName(functionName string) bool {
+	if len(functionName) >= maxLengthOfFunctionName {
+		return false
+	}
+	return !isFirstCharacterNumeric(functionName) && validCharactersOnly(functionName)
+}
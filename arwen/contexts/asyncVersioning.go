@@ -0,0 +1,84 @@
+package contexts
+
+import (
+	"github.com/ElrondNetwork/elrond-go-core/marshal"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// asyncContextVersionMarker starts every versioned encoding of an
+// AsyncContext and is followed by the version byte. It can never start a bare
+// protobuf message, because its lowest 3 bits would be the invalid wire type
+// 7, which is how the legacy, unversioned encoding is told apart.
+const asyncContextVersionMarker = byte(0xFF)
+
+const (
+	// asyncContextLegacyVersion is the bare SerializableAsyncContext
+	// protobuf, saved before the format was versioned
+	asyncContextLegacyVersion = byte(0)
+
+	// asyncContextVersion1 is the SerializableAsyncContext protobuf after
+	// the marker and the version byte
+	asyncContextVersion1 = byte(1)
+
+	currentAsyncContextVersion = asyncContextVersion1
+)
+
+// asyncContextMigration reads the payload of one version of the format and
+// brings it to the current SerializableAsyncContext. A new version of the
+// format adds its own entry and rewrites the entries of the older versions
+// to convert their payload to the new message, so that the contexts saved
+// before an upgrade can still be resolved by their callbacks after it.
+type asyncContextMigration func(payload []byte, marshalizer *marshal.GogoProtoMarshalizer) (*SerializableAsyncContext, error)
+
+var asyncContextMigrations = map[byte]asyncContextMigration{
+	asyncContextLegacyVersion: unmarshalSerializableAsyncContext,
+	asyncContextVersion1:      unmarshalSerializableAsyncContext,
+}
+
+// encodeAsyncContext marshals the AsyncContext in the current version of the
+// format, or in the legacy one while the versioned format is not yet enabled
+func encodeAsyncContext(
+	serializedContext *SerializableAsyncContext,
+	marshalizer *marshal.GogoProtoMarshalizer,
+	versioned bool,
+) ([]byte, error) {
+	payload, err := marshalizer.Marshal(serializedContext)
+	if err != nil {
+		return nil, err
+	}
+	if !versioned {
+		return payload, nil
+	}
+
+	data := make([]byte, 0, len(payload)+2)
+	data = append(data, asyncContextVersionMarker, currentAsyncContextVersion)
+	return append(data, payload...), nil
+}
+
+// decodeAsyncContext reads an AsyncContext saved in any known version of the
+// format, migrating it to the current SerializableAsyncContext
+func decodeAsyncContext(data []byte, marshalizer *marshal.GogoProtoMarshalizer) (*SerializableAsyncContext, error) {
+	version, payload := splitAsyncContextVersion(data)
+	migration, ok := asyncContextMigrations[version]
+	if !ok {
+		return nil, arwen.ErrUnknownAsyncContextVersion
+	}
+
+	return migration(payload, marshalizer)
+}
+
+func splitAsyncContextVersion(data []byte) (byte, []byte) {
+	if len(data) < 2 || data[0] != asyncContextVersionMarker {
+		return asyncContextLegacyVersion, data
+	}
+	return data[1], data[2:]
+}
+
+func unmarshalSerializableAsyncContext(payload []byte, marshalizer *marshal.GogoProtoMarshalizer) (*SerializableAsyncContext, error) {
+	serializedContext := &SerializableAsyncContext{}
+	err := marshalizer.Unmarshal(serializedContext, payload)
+	if err != nil {
+		return nil, err
+	}
+	return serializedContext, nil
+}
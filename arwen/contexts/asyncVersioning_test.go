@@ -0,0 +1,58 @@
+package contexts
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/stretchr/testify/require"
+)
+
+func makeSerializableAsyncContext() *SerializableAsyncContext {
+	return &SerializableAsyncContext{
+		Address:           []byte("contract"),
+		CallID:            []byte("callID"),
+		CallerAddr:        []byte("caller"),
+		Callback:          "myCallback",
+		CallsCounter:      2,
+		TotalCallsCounter: 3,
+		GasAccumulated:    1000,
+	}
+}
+
+func TestAsyncContextVersioning_EncodeDecode(t *testing.T) {
+	serializedContext := makeSerializableAsyncContext()
+
+	data, err := encodeAsyncContext(serializedContext, marshalizer, true)
+	require.Nil(t, err)
+	require.Equal(t, []byte{asyncContextVersionMarker, currentAsyncContextVersion}, data[:2])
+
+	decodedContext, err := decodeAsyncContext(data, marshalizer)
+	require.Nil(t, err)
+	require.Equal(t, serializedContext, decodedContext)
+}
+
+func TestAsyncContextVersioning_DecodeLegacy(t *testing.T) {
+	serializedContext := makeSerializableAsyncContext()
+
+	// contexts saved by the nodes before the upgrade are bare protobuf
+	legacyData, err := marshalizer.Marshal(serializedContext)
+	require.Nil(t, err)
+
+	data, err := encodeAsyncContext(serializedContext, marshalizer, false)
+	require.Nil(t, err)
+	require.Equal(t, legacyData, data)
+
+	decodedContext, err := decodeAsyncContext(legacyData, marshalizer)
+	require.Nil(t, err)
+	require.Equal(t, serializedContext, decodedContext)
+}
+
+func TestAsyncContextVersioning_DecodeUnknownVersion(t *testing.T) {
+	data, err := encodeAsyncContext(makeSerializableAsyncContext(), marshalizer, true)
+	require.Nil(t, err)
+
+	data[1] = currentAsyncContextVersion + 1
+	decodedContext, err := decodeAsyncContext(data, marshalizer)
+	require.Nil(t, decodedContext)
+	require.Equal(t, arwen.ErrUnknownAsyncContextVersion, err)
+}
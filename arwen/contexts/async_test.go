@@ -1,6 +1,9 @@
 package contexts
 
 import (
+	"bytes"
+	"encoding/binary"
+	"encoding/hex"
 	"errors"
 	"math/big"
 	"testing"
@@ -38,6 +41,7 @@ func makeAsyncContext(t testing.TB, host arwen.VMHost, address []byte) *asyncCon
 		callParser,
 		esdtParser,
 		marshalizer,
+		arwen.AsyncLimits{},
 	)
 	require.Nil(t, err)
 	require.NotNil(t, async)
@@ -75,6 +79,8 @@ func initializeArwenAndWasmer_AsyncContext() (*contextmock.VMHostMock, *worldmoc
 		vmType,
 		builtInFunctions.NewBuiltInFunctionContainer(),
 		executor,
+		false,
+		arwen.ContractLimits{},
 	)
 	runtimeContext.instance = mockWasmerInstance
 	host.RuntimeContext = runtimeContext
@@ -85,11 +91,21 @@ func initializeArwenAndWasmer_AsyncContext() (*contextmock.VMHostMock, *worldmoc
 	host.OutputContext, _ = NewOutputContext(host)
 	host.CryptoHook = factory.NewVMCrypto()
 	host.StorageContext, _ = NewStorageContext(host, world, elrondReservedTestPrefix)
-	host.EnableEpochsHandlerField = worldmock.EnableEpochsHandlerStubNoFlags()
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubNoFlags()
+	enableEpochsHandler.CurrentEpochCalled = world.CurrentEpoch
+	host.EnableEpochsHandlerField = enableEpochsHandler
+
+	// most tests register async calls towards short addresses
+	enableEpochsHandler.AsyncCallValidationEnableEpochField = config.DisabledEpoch
 
 	return host, world
 }
 
+// enableEpochsHandlerStub returns the enable epochs handler of a host made by initializeArwenAndWasmer_AsyncContext
+func enableEpochsHandlerStub(host *contextmock.VMHostMock) *worldmock.EnableEpochsHandlerStub {
+	return host.EnableEpochsHandlerField.(*worldmock.EnableEpochsHandlerStub)
+}
+
 func initializeArwenAndWasmer_AsyncContextWithAliceAndBob() (
 	*contextmock.VMHostMock,
 	*worldmock.MockWorld,
@@ -249,12 +265,154 @@ func TestAsyncContext_RegisterAsyncCall_ExistingGroup(t *testing.T) {
 	require.True(t, async.HasPendingCallGroups())
 }
 
+func TestAsyncContext_AddSubGroup(t *testing.T) {
+	host, _ := initializeArwenAndWasmer_AsyncContext()
+
+	async := makeAsyncContext(t, host, nil)
+
+	err := async.AddSubGroup("workflow", "vendors")
+	require.Nil(t, err)
+	err = async.AddSubGroup("vendors", "banks")
+	require.Nil(t, err)
+	require.Equal(t, 3, len(async.asyncCallGroups))
+
+	group, exists := async.GetCallGroup("banks")
+	require.True(t, exists)
+	require.Equal(t, "vendors", group.ParentIdentifier)
+
+	err = async.AddSubGroup("vendors", "banks")
+	require.Nil(t, err)
+	err = async.AddSubGroup("workflow", "banks")
+	require.Equal(t, arwen.ErrInvalidAsyncCallGroup, err)
+	err = async.AddSubGroup("banks", "workflow")
+	require.Equal(t, arwen.ErrInvalidAsyncCallGroup, err)
+	err = async.AddSubGroup("vendors", "vendors")
+	require.Equal(t, arwen.ErrInvalidAsyncCallGroup, err)
+	err = async.AddSubGroup("workflow", arwen.LegacyAsyncCallGroupID)
+	require.Equal(t, arwen.ErrInvalidAsyncCallGroup, err)
+	require.Equal(t, 3, len(async.asyncCallGroups))
+
+	enableEpochsHandlerStub(host).AsyncCallSubGroupsEnableEpochField = 1
+	err = async.AddSubGroup("workflow", "auditors")
+	require.Equal(t, arwen.ErrAsyncSubGroupsDisabled, err)
+}
+
+func TestAsyncContext_CompleteSubGroups(t *testing.T) {
+	host, _ := initializeArwenAndWasmer_AsyncContext()
+
+	async := makeAsyncContext(t, host, nil)
+
+	for _, groupID := range []string{"vendors", "auditors"} {
+		err := async.AddSubGroup("workflow", groupID)
+		require.Nil(t, err)
+		err = async.RegisterAsyncCall(groupID, &arwen.AsyncCall{
+			Destination: []byte("somewhere"),
+			Data:        []byte("something"),
+		})
+		require.Nil(t, err)
+		groupIndex, _ := async.findGroupByID(groupID)
+		async.SetCallIDForCallInGroup(groupIndex, 0, []byte(groupID))
+	}
+
+	vendors, _ := async.GetCallGroup("vendors")
+	vendors.AsyncCalls[0].Reject()
+	err := async.DeleteAsyncCallAndCleanGroup([]byte("vendors"))
+	require.Nil(t, err)
+
+	_, exists := async.GetCallGroup("vendors")
+	require.False(t, exists)
+	workflow, exists := async.GetCallGroup("workflow")
+	require.True(t, exists)
+	require.True(t, workflow.Failed)
+
+	err = async.DeleteAsyncCallAndCleanGroup([]byte("auditors"))
+	require.Nil(t, err)
+	require.False(t, async.HasPendingCallGroups())
+}
+
+func TestAsyncContext_CompleteGroups_SubGroupsDisabled(t *testing.T) {
+	host, _ := initializeArwenAndWasmer_AsyncContext()
+	enableEpochsHandlerStub(host).AsyncCallSubGroupsEnableEpochField = 1
+
+	async := makeAsyncContext(t, host, nil)
+	for _, callID := range []string{"first", "second"} {
+		err := async.RegisterAsyncCall("vendors", &arwen.AsyncCall{
+			Destination: []byte("somewhere"),
+			Data:        []byte("something"),
+		})
+		require.Nil(t, err)
+		groupIndex, _ := async.findGroupByID("vendors")
+		callIndex := len(async.asyncCallGroups[groupIndex].AsyncCalls) - 1
+		async.SetCallIDForCallInGroup(groupIndex, callIndex, []byte(callID))
+	}
+
+	vendors, _ := async.GetCallGroup("vendors")
+	vendors.AsyncCalls[0].Reject()
+	err := async.DeleteAsyncCallAndCleanGroup([]byte("first"))
+	require.Nil(t, err)
+	require.False(t, vendors.Failed)
+
+	err = async.DeleteAsyncCallAndCleanGroup([]byte("second"))
+	require.Nil(t, err)
+	require.False(t, async.HasPendingCallGroups())
+}
+
 func TestAsyncContext_RegisterAsyncCall_ValidationAndFields(t *testing.T) {
 	// TODO execution mode
 	// TODO non-nil destination
 	// TODO locked gas
 }
 
+func TestAsyncContext_RegisterAsyncCall_CallDataValidation(t *testing.T) {
+	host, _ := initializeArwenAndWasmer_AsyncContext()
+	enableEpochsHandlerStub(host).AsyncCallValidationEnableEpochField = 0
+	metering := host.MeteringContext.(*contextmock.MeteringContextMock)
+	metering.GasCost.ElrondAPICost.AsyncCallDataPerByte = 10
+
+	async := makeAsyncContext(t, host, nil)
+	async.asyncLimits.MaxCallDataSize = 16
+	destination := bytes.Repeat([]byte{1}, arwen.AddressLen)
+
+	testCases := []struct {
+		destination []byte
+		data        string
+		expectedErr error
+	}{
+		{[]byte("somewhere"), "something", arwen.ErrInvalidAsyncCallDestination},
+		{destination, "something@0102030405", arwen.ErrAsyncCallDataTooLarge},
+		{destination, "some thing@01", arwen.ErrInvalidAsyncCallFunction},
+		{destination, "1something@01", arwen.ErrInvalidAsyncCallFunction},
+		{destination, "something", nil},
+		{destination, "transfer_1@01", nil},
+	}
+
+	for _, testCase := range testCases {
+		gasLeft := metering.GasLeft()
+		err := async.RegisterAsyncCall("testGroup", &arwen.AsyncCall{
+			Destination: testCase.destination,
+			Data:        []byte(testCase.data),
+		})
+		if testCase.expectedErr != nil {
+			require.True(t, errors.Is(err, testCase.expectedErr), testCase.data)
+			require.Equal(t, gasLeft, metering.GasLeft(), testCase.data)
+			continue
+		}
+		require.Nil(t, err, testCase.data)
+		require.Equal(t, gasLeft-10*uint64(len(testCase.data)), metering.GasLeft(), testCase.data)
+	}
+
+	group, exists := async.GetCallGroup("testGroup")
+	require.True(t, exists)
+	require.Len(t, group.AsyncCalls, 2)
+
+	enableEpochsHandlerStub(host).AsyncCallValidationEnableEpochField = 1
+	err := async.RegisterAsyncCall("testGroup", &arwen.AsyncCall{
+		Destination: []byte("somewhere"),
+		Data:        []byte("some thing"),
+	})
+	require.Nil(t, err)
+}
+
 func TestAsyncContext_DetermineExecutionMode(t *testing.T) {
 	leftAddress := []byte("left")
 	leftAccount := &worldmock.Account{
@@ -329,6 +487,36 @@ func initRuntime(runtime arwen.RuntimeContext, address []byte) {
 	})
 }
 
+func TestAsyncContext_RegisteredCallbacks(t *testing.T) {
+	host, world, _ := initializeArwenAndWasmer_AsyncContextWithAliceAndBob()
+	host.Storage().SetAddress(Alice)
+	async := makeAsyncContext(t, host, Alice)
+
+	require.Nil(t, async.registerCallbackName("success"))
+	require.Empty(t, host.Storage().GetStorageUpdates(Alice))
+	require.False(t, async.IsRegisteredCallback("success"))
+
+	async.SetProtectRegisteredCallbacks(true)
+	require.Nil(t, async.registerCallbackName(""))
+	require.Empty(t, host.Storage().GetStorageUpdates(Alice))
+
+	require.Nil(t, async.registerCallbackName("success"))
+	key := async.getCallbackNameStorageKey("success")
+	storageUpdates := host.Storage().GetStorageUpdates(Alice)
+	require.Len(t, storageUpdates, 1)
+	require.Equal(t, []byte{1}, storageUpdates[string(key)].Data)
+
+	// the registration is persisted once the execution is committed
+	world.AcctMap.GetAccount(Alice).Storage = map[string][]byte{string(key): {1}}
+	require.True(t, async.IsRegisteredCallback("success"))
+	require.False(t, async.IsRegisteredCallback("error"))
+
+	host.Storage().SetAddress(Bob)
+	require.False(t, async.IsRegisteredCallback("success"))
+
+	require.True(t, async.Clone().(*asyncContext).protectRegisteredCallbacks)
+}
+
 func TestAsyncContext_IsValidCallbackName(t *testing.T) {
 	host, _ := initializeArwenAndWasmer_AsyncContext()
 	async := makeAsyncContext(t, host, nil)
@@ -375,6 +563,7 @@ func TestAsyncContext_UpdateCurrentCallStatus(t *testing.T) {
 	}
 
 	host, _ := initializeArwenAndWasmer_AsyncContext()
+	enableEpochsHandlerStub(host).LegacyCallbackNoOpEnableEpochField = config.DisabledEpoch
 	async := makeAsyncContext(t, host, contract)
 
 	storedAsync := &asyncContext{}
@@ -423,6 +612,36 @@ func TestAsyncContext_UpdateCurrentCallStatus(t *testing.T) {
 	require.True(t, isLegacy)
 	require.Nil(t, err)
 
+	// once the LegacyCallbackNoOp flag is enabled, the legacy callback has no
+	// callback function because the contract does not export callBack
+	enableEpochsHandlerStub(host).LegacyCallbackNoOpEnableEpochField = 0
+	asyncCall, isLegacy, err = async.UpdateCurrentAsyncCallStatus(contract, []byte{}, &vmInput.VMInput)
+	require.Equal(t, asyncCall, &arwen.AsyncCall{
+		Status:      arwen.AsyncCallResolved,
+		Destination: contract,
+		GasLimit:    vmInput.GasProvided,
+		GasLocked:   vmInput.GasLocked,
+	})
+	require.False(t, asyncCall.HasCallback())
+	require.True(t, isLegacy)
+	require.Nil(t, err)
+
+	// the legacy callback calls callBack once the contract exports it
+	mockWasmerInstance.AddMockMethod(arwen.CallbackFunctionName, func() *contextmock.InstanceMock {
+		return mockWasmerInstance
+	})
+	asyncCall, isLegacy, err = async.UpdateCurrentAsyncCallStatus(contract, []byte{}, &vmInput.VMInput)
+	require.Equal(t, asyncCall, &arwen.AsyncCall{
+		Status:          arwen.AsyncCallResolved,
+		Destination:     contract,
+		SuccessCallback: arwen.CallbackFunctionName,
+		ErrorCallback:   arwen.CallbackFunctionName,
+		GasLimit:        vmInput.GasProvided,
+		GasLocked:       vmInput.GasLocked,
+	})
+	require.True(t, isLegacy)
+	require.Nil(t, err)
+
 	// CallType == AsynchronousCallback, and there is an AsyncCall registered,
 	// but it's not the expected one.
 	err = async.RegisterAsyncCall("testGroup", &arwen.AsyncCall{
@@ -726,6 +945,29 @@ func TestAsyncContext_CreateCallbackInput_DestinationCallFailed(t *testing.T) {
 	require.Equal(t, expectedInput, callbackInput)
 }
 
+func TestAsyncContext_CallbackArguments_TypedError(t *testing.T) {
+	host, _, originalVMInput := initializeArwenAndWasmer_AsyncContextWithAliceAndBob()
+	host.Runtime().InitStateFromContractCallInput(originalVMInput)
+	async := makeAsyncContext(t, host, Alice)
+
+	vmOutput := defaultDestOutput_UserError()
+	vmOutput.ReturnData = [][]byte{{42}, []byte("payload")}
+
+	arguments := async.getArgumentsForCallback(vmOutput, arwen.ErrSignalError)
+	require.Equal(t, [][]byte{
+		{byte(vmcommon.UserError)},
+		[]byte(vmOutput.ReturnMessage),
+		{42},
+		[]byte("payload"),
+	}, arguments)
+
+	_, data := async.createDataForCrossShardCallback(vmOutput.ReturnCode, vmOutput.ReturnData, vmOutput.ReturnMessage)
+	expectedData := callbackNamePlaceholder +
+		"@04@" + hex.EncodeToString([]byte(vmOutput.ReturnMessage)) +
+		"@2a@" + hex.EncodeToString([]byte("payload"))
+	require.Equal(t, expectedData, string(data))
+}
+
 func TestAsyncContext_CreateCallbackInput_NotEnoughGas(t *testing.T) {
 	// Due to dynamic gas locking, this situation should never happen
 	host, _, originalVMInput := initializeArwenAndWasmer_AsyncContextWithAliceAndBob()
@@ -904,3 +1146,205 @@ func defaultOutputDataLengthAsArgs(asyncCall *arwen.AsyncCall, vmOutput *vmcommo
 
 	return uint64(dataLength)
 }
+
+func TestAsyncContext_LockedValueLedger(t *testing.T) {
+	host, _, originalVMInput := initializeArwenAndWasmer_AsyncContextWithAliceAndBob()
+	host.Runtime().InitStateFromContractCallInput(originalVMInput)
+	host.Storage().SetAddress(Alice)
+	async := makeAsyncContext(t, host, Alice)
+
+	nftTransfer := &arwen.AsyncCall{
+		ExecutionMode: arwen.AsyncUnknown,
+		Destination:   Bob,
+		ValueBytes:    big.NewInt(10).Bytes(),
+		Data:          []byte("ESDTNFTTransfer@" + hex.EncodeToString([]byte("NFT-123456")) + "@05@0a@" + hex.EncodeToString(Bob)),
+	}
+	secondNFTTransfer := nftTransfer.Clone()
+	fungibleTransfer := &arwen.AsyncCall{
+		ExecutionMode: arwen.AsyncBuiltinFuncCrossShard,
+		Destination:   Bob,
+		Data:          []byte("ESDTTransfer@" + hex.EncodeToString([]byte("TOK-123456")) + "@07"),
+	}
+
+	require.Nil(t, async.lockValueOfAsyncCall("group", nftTransfer))
+	require.Nil(t, async.lockValueOfAsyncCall("group", secondNFTTransfer))
+	require.Nil(t, async.lockValueOfAsyncCall("group", fungibleTransfer))
+	require.True(t, fungibleTransfer.ValueLocked)
+	require.Equal(t, big.NewInt(20), async.GetLockedValue(nil, 0))
+	require.Equal(t, big.NewInt(20), async.GetLockedValue([]byte("NFT-123456"), 5))
+	require.Equal(t, big.NewInt(0), async.GetLockedValue([]byte("NFT-123456"), 0))
+	require.Equal(t, big.NewInt(7), async.GetLockedValue([]byte("TOK-123456"), 0))
+
+	fungibleTransferCallback := fungibleTransfer.Clone()
+	require.Nil(t, async.unlockValueOfAsyncCall(nftTransfer))
+	require.Nil(t, async.unlockValueOfAsyncCall(fungibleTransfer))
+	require.False(t, fungibleTransfer.ValueLocked)
+	require.Nil(t, async.unlockValueOfAsyncCall(fungibleTransfer))
+	require.Equal(t, big.NewInt(10), async.GetLockedValue(nil, 0))
+	require.Equal(t, big.NewInt(10), async.GetLockedValue([]byte("NFT-123456"), 5))
+	require.Equal(t, big.NewInt(0), async.GetLockedValue([]byte("TOK-123456"), 0))
+
+	err := async.unlockValueOfAsyncCall(fungibleTransferCallback)
+	require.Equal(t, arwen.ErrLockedValueExceeded, err)
+
+	storageKey := LockedValueStorageKey(elrondReservedTestPrefix, []byte("NFT-123456"), 5)
+	require.Equal(t, []byte{10}, host.Storage().GetStorageUpdates(Alice)[string(storageKey)].Data)
+}
+
+func TestAsyncContext_LockedValueLedger_NotLocked(t *testing.T) {
+	host, _, originalVMInput := initializeArwenAndWasmer_AsyncContextWithAliceAndBob()
+	host.Runtime().InitStateFromContractCallInput(originalVMInput)
+	host.Storage().SetAddress(Alice)
+	async := makeAsyncContext(t, host, Alice)
+
+	newTransfer := func(executionMode arwen.AsyncCallExecutionMode) *arwen.AsyncCall {
+		return &arwen.AsyncCall{
+			ExecutionMode: executionMode,
+			Destination:   Bob,
+			ValueBytes:    big.NewInt(10).Bytes(),
+		}
+	}
+
+	require.Nil(t, async.lockValueOfAsyncCall(arwen.LegacyAsyncCallGroupID, newTransfer(arwen.AsyncUnknown)))
+	require.Nil(t, async.lockValueOfAsyncCall("group", newTransfer(arwen.SyncExecution)))
+	rejectedTransfer := newTransfer(arwen.AsyncUnknown)
+	rejectedTransfer.Status = arwen.AsyncCallRejected
+	require.Nil(t, async.lockValueOfAsyncCall("group", rejectedTransfer))
+
+	enableEpochsHandlerStub(host).LockedValueLedgerEnableEpochField = config.DisabledEpoch
+	transferBeforeActivation := newTransfer(arwen.AsyncUnknown)
+	require.Nil(t, async.lockValueOfAsyncCall("group", transferBeforeActivation))
+	require.False(t, transferBeforeActivation.ValueLocked)
+	require.Equal(t, big.NewInt(0), async.GetLockedValue(nil, 0))
+
+	// the callbacks of the calls which did not lock their value are left
+	// alone, even once the ledger is enabled
+	enableEpochsHandlerStub(host).LockedValueLedgerEnableEpochField = 0
+	require.Nil(t, async.unlockValueOfAsyncCall(transferBeforeActivation))
+	require.Equal(t, big.NewInt(0), async.GetLockedValue(nil, 0))
+}
+
+func TestAsyncContext_LockedValueLedger_Gas(t *testing.T) {
+	host, _, originalVMInput := initializeArwenAndWasmer_AsyncContextWithAliceAndBob()
+	host.Runtime().InitStateFromContractCallInput(originalVMInput)
+	async := makeAsyncContext(t, host, Alice)
+
+	metering := host.Metering()
+	storePerByte := metering.GasSchedule().BaseOperationCost.StorePerByte
+	transfer := &arwen.AsyncCall{
+		ExecutionMode: arwen.AsyncUnknown,
+		Destination:   Bob,
+		ValueBytes:    big.NewInt(300).Bytes(),
+	}
+
+	gasLeft := metering.GasLeft()
+	require.Nil(t, async.useGasForLockedValue("group", transfer))
+	require.Equal(t, gasLeft-2*storePerByte, metering.GasLeft())
+
+	gasLeft = metering.GasLeft()
+	require.Nil(t, async.useGasForLockedValue(arwen.LegacyAsyncCallGroupID, transfer))
+	require.Equal(t, gasLeft, metering.GasLeft())
+
+	enableEpochsHandlerStub(host).LockedValueLedgerEnableEpochField = config.DisabledEpoch
+	require.Nil(t, async.useGasForLockedValue("group", transfer))
+	require.Equal(t, gasLeft, metering.GasLeft())
+}
+
+func TestAsyncContext_LockedValueStorageKey(t *testing.T) {
+	nonceOfShortToken := binary.BigEndian.Uint64([]byte("K-123456"))
+	require.NotEqual(t,
+		LockedValueStorageKey(elrondReservedTestPrefix, []byte("TO"), nonceOfShortToken),
+		LockedValueStorageKey(elrondReservedTestPrefix, []byte("TOK-123456"), 0),
+	)
+
+	expectedKey := append([]byte{}, elrondReservedTestPrefix...)
+	expectedKey = append(expectedKey, VMStoragePrefix...)
+	expectedKey = append(expectedKey, arwen.LockedValueDataPrefix...)
+	expectedKey = append(expectedKey, 0, 0, 0, 3)
+	expectedKey = append(expectedKey, "TOK"...)
+	expectedKey = append(expectedKey, 0, 0, 0, 0, 0, 0, 0, 5)
+	require.Equal(t, expectedKey, LockedValueStorageKey(elrondReservedTestPrefix, []byte("TOK"), 5))
+}
+
+func TestAsyncContext_CallGroupsInSendingOrder(t *testing.T) {
+	host, _ := initializeArwenAndWasmer_AsyncContext()
+
+	async := makeAsyncContext(t, host, nil)
+	for _, groupID := range []string{"zeta", "alpha", "mu"} {
+		err := async.RegisterAsyncCall(groupID, &arwen.AsyncCall{
+			Destination: []byte("somewhere"),
+			Data:        []byte(groupID),
+		})
+		require.Nil(t, err)
+	}
+
+	groups := async.callGroupsInSendingOrder()
+	require.Len(t, groups, 3)
+	for i, groupID := range []string{"alpha", "mu", "zeta"} {
+		require.Equal(t, groupID, groups[i].Identifier)
+	}
+	require.Equal(t, "zeta", async.asyncCallGroups[0].Identifier)
+
+	enableEpochsHandlerStub(host).AsyncCallsCanonicalOrderEnableEpochField = 1
+	groups = async.callGroupsInSendingOrder()
+	for i, groupID := range []string{"zeta", "alpha", "mu"} {
+		require.Equal(t, groupID, groups[i].Identifier)
+	}
+}
+
+func TestAsyncContext_PromiseHandles(t *testing.T) {
+	host, _ := initializeArwenAndWasmer_AsyncContext()
+
+	async := makeAsyncContext(t, host, nil)
+	err := async.RegisterAsyncCall("existing", &arwen.AsyncCall{
+		Destination: []byte("somewhere"),
+		Data:        []byte("something"),
+	})
+	require.Nil(t, err)
+
+	groupHandle, err := async.CreatePromiseGroup([]byte("payments"))
+	require.Nil(t, err)
+	require.Equal(t, arwen.PromiseGroupHandle(1), groupHandle)
+	otherGroupHandle, err := async.CreatePromiseGroup([]byte("audits"))
+	require.Nil(t, err)
+	require.Equal(t, arwen.PromiseGroupHandle(2), otherGroupHandle)
+
+	for _, groupID := range []string{"payments", "existing"} {
+		invalidHandle, err := async.CreatePromiseGroup([]byte(groupID))
+		require.Equal(t, arwen.ErrPromiseGroupExistsAlready, err)
+		require.Equal(t, arwen.PromiseGroupHandle(arwen.InvalidPromiseHandle), invalidHandle)
+	}
+	_, err = async.CreatePromiseGroup([]byte("pay ments"))
+	require.Equal(t, arwen.ErrInvalidPromiseGroupID, err)
+
+	for i, handle := range []arwen.PromiseGroupHandle{otherGroupHandle, groupHandle, groupHandle} {
+		promiseID, err := async.RegisterPromise(handle, &arwen.AsyncCall{
+			Destination: []byte("somewhere"),
+			Data:        []byte("something"),
+		})
+		require.Nil(t, err)
+		require.Equal(t, arwen.PromiseID(i+1), promiseID)
+	}
+
+	payments, exists := async.GetCallGroup("payments")
+	require.True(t, exists)
+	require.Len(t, payments.AsyncCalls, 2)
+	audits, exists := async.GetCallGroup("audits")
+	require.True(t, exists)
+	require.Len(t, audits.AsyncCalls, 1)
+
+	for _, handle := range []arwen.PromiseGroupHandle{0, 3, arwen.InvalidPromiseHandle} {
+		_, err = async.RegisterPromise(handle, &arwen.AsyncCall{
+			Destination: []byte("somewhere"),
+			Data:        []byte("something"),
+		})
+		require.Equal(t, arwen.ErrInvalidPromiseGroupHandle, err)
+	}
+
+	async.InitState()
+	_, err = async.RegisterPromise(groupHandle, &arwen.AsyncCall{
+		Destination: []byte("somewhere"),
+		Data:        []byte("something"),
+	})
+	require.Equal(t, arwen.ErrInvalidPromiseGroupHandle, err)
+}
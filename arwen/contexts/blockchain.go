@@ -5,17 +5,23 @@ import (
 
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
 	"github.com/ElrondNetwork/elrond-go-core/data/esdt"
-	logger "github.com/ElrondNetwork/elrond-go-logger"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 )
 
-var logBlockchain = logger.GetOrCreate("arwen/blockchainContext")
+var logBlockchain = logging.GetOrCreate(logging.SubsystemBlockchain, "arwen/blockchainContext")
+
+const (
+	scAddressNumLeadingZeros = 8
+	shardIdentifierLen       = 2
+)
 
 type blockchainContext struct {
 	host           arwen.VMHost
 	blockChainHook vmcommon.BlockchainHook
+	codeCache      arwen.CodeCache
 	stateStack     []int
 }
 
@@ -52,6 +58,40 @@ func (context *blockchainContext) NewAddress(creatorAddress []byte) ([]byte, err
 	return context.blockChainHook.NewAddress(creatorAddress, nonce, vmType)
 }
 
+// ComputeContractAddress returns the address of the contract created by the
+// deployer with the given nonce, as derived by the blockchain hook.
+func (context *blockchainContext) ComputeContractAddress(deployerAddress []byte, nonce uint64) ([]byte, error) {
+	vmType := context.host.Runtime().GetVMType()
+	return context.blockChainHook.NewAddress(deployerAddress, nonce, vmType)
+}
+
+// ComputeDeterministicAddress returns the address of the contract created by
+// the deployer from the given salt and code hash, regardless of its nonce. The
+// address is the Keccak256 hash of the three, with the smart contract prefix
+// and the VM type in front and the shard bytes of the deployer at the end.
+func (context *blockchainContext) ComputeDeterministicAddress(deployerAddress []byte, salt []byte, codeHash []byte) ([]byte, error) {
+	if len(deployerAddress) != arwen.AddressLen {
+		return nil, arwen.ErrInvalidAddressLength
+	}
+
+	preimage := make([]byte, 0, len(deployerAddress)+len(salt)+len(codeHash))
+	preimage = append(preimage, deployerAddress...)
+	preimage = append(preimage, salt...)
+	preimage = append(preimage, codeHash...)
+	address, err := context.host.Crypto().Keccak256(preimage)
+	if err != nil {
+		return nil, err
+	}
+
+	vmType := context.host.Runtime().GetVMType()
+	for i := 0; i < scAddressNumLeadingZeros; i++ {
+		address[i] = 0
+	}
+	copy(address[scAddressNumLeadingZeros:scAddressNumLeadingZeros+len(vmType)], vmType)
+	copy(address[arwen.AddressLen-shardIdentifierLen:], deployerAddress[arwen.AddressLen-shardIdentifierLen:])
+	return address, nil
+}
+
 // AccountExists verifies if the provided address exists.
 func (context *blockchainContext) AccountExists(address []byte) bool {
 	account, err := context.blockChainHook.GetUserAccount(address)
@@ -160,7 +200,7 @@ func (context *blockchainContext) GetCode(address []byte) ([]byte, error) {
 		return nil, arwen.ErrInvalidAccount
 	}
 
-	code := context.blockChainHook.GetCode(account)
+	code := context.getAccountCode(account)
 	if len(code) == 0 {
 		return nil, arwen.ErrContractNotFound
 	}
@@ -177,11 +217,35 @@ func (context *blockchainContext) GetCodeSize(address []byte) (int32, error) {
 		return 0, err
 	}
 
-	code := context.blockChainHook.GetCode(account)
+	code := context.getAccountCode(account)
 	result := int32(len(code))
 	return result, nil
 }
 
+// SetCodeCache sets the cache from which the code of the accounts is read
+// before asking the blockchain hook; a nil cache reads every code from the
+// blockchain hook.
+func (context *blockchainContext) SetCodeCache(codeCache arwen.CodeCache) {
+	context.codeCache = codeCache
+}
+
+func (context *blockchainContext) getAccountCode(account vmcommon.UserAccountHandler) []byte {
+	codeHash := account.GetCodeHash()
+	if check.IfNil(context.codeCache) || len(codeHash) == 0 {
+		return context.blockChainHook.GetCode(account)
+	}
+
+	code, found := context.codeCache.GetCode(codeHash)
+	context.host.Metrics().ObserveCodeCacheLookup(found)
+	if found {
+		return code
+	}
+
+	code = context.blockChainHook.GetCode(account)
+	context.codeCache.PutCode(codeHash, code)
+	return code
+}
+
 // BlockHash returns the hash of the block that has the given nonce.
 func (context *blockchainContext) BlockHash(number uint64) []byte {
 	block, err := context.blockChainHook.GetBlockhash(number)
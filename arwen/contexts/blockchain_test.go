@@ -7,6 +7,8 @@ import (
 
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/codecache"
+	"github.com/ElrondNetwork/wasm-vm/crypto/factory"
 	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
 	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
@@ -255,6 +257,43 @@ func TestBlockchainContext_GetCodeHashAndSize(t *testing.T) {
 	require.Equal(t, int32(len(expectedCode)), size)
 }
 
+func TestBlockchainContext_GetCodeFromCodeCache(t *testing.T) {
+	t.Parallel()
+
+	code := []byte("pair code")
+	codeHash, _ := hashing.NewHasher().Sha256(code)
+	mockWorld := worldmock.NewMockWorld()
+	mockWorld.AcctMap.PutAccounts([]*worldmock.Account{
+		{Address: []byte("pair_first"), Balance: big.NewInt(0), Code: code, CodeHash: codeHash},
+		{Address: []byte("pair_second"), Balance: big.NewInt(0), Code: code, CodeHash: codeHash},
+	})
+
+	outputContext := &contextmock.OutputContextMock{
+		OutputAccountIsNew: true,
+		OutputAccountMock:  &vmcommon.OutputAccount{},
+	}
+	host := &contextmock.VMHostMock{OutputContext: outputContext}
+	blockchainContext, _ := NewBlockchainContext(host, mockWorld)
+	cache, _ := codecache.NewCodeCache(1000)
+	blockchainContext.SetCodeCache(cache)
+
+	fetched, err := blockchainContext.GetCode([]byte("pair_first"))
+	require.Nil(t, err)
+	require.Equal(t, code, fetched)
+	require.Equal(t, 1, cache.NumCodes())
+
+	// the code of the second pair is not read from the blockchain hook
+	mockWorld.AcctMap.GetAccount([]byte("pair_second")).Code = []byte("unreachable code")
+	outputContext.OutputAccountMock = &vmcommon.OutputAccount{}
+	fetched, err = blockchainContext.GetCode([]byte("pair_second"))
+	require.Nil(t, err)
+	require.Equal(t, code, fetched)
+
+	size, err := blockchainContext.GetCodeSize([]byte("pair_second"))
+	require.Nil(t, err)
+	require.Equal(t, int32(len(code)), size)
+}
+
 func TestBlockchainContext_NewAddress(t *testing.T) {
 	t.Parallel()
 
@@ -356,6 +395,50 @@ func TestBlockchainContext_NewAddress(t *testing.T) {
 	require.Nil(t, address)
 }
 
+func TestBlockchainContext_ComputeAddresses(t *testing.T) {
+	t.Parallel()
+
+	mockRuntime := &contextmock.RuntimeContextMock{}
+	mockRuntime.VMType = []byte{0x05, 0x00}
+	host := &contextmock.VMHostMock{
+		RuntimeContext: mockRuntime,
+		CryptoHook:     factory.NewVMCrypto(),
+	}
+
+	deployer := []byte("deployer______________________s1")
+	stubBlockchain := &contextmock.BlockchainHookStub{
+		NewAddressCalled: func(creatorAddress []byte, creatorNonce uint64, vmType []byte) ([]byte, error) {
+			require.Equal(t, deployer, creatorAddress)
+			require.Equal(t, uint64(7), creatorNonce)
+			require.Equal(t, mockRuntime.VMType, vmType)
+			return []byte("new_address"), nil
+		},
+	}
+	blockchainContext, _ := NewBlockchainContext(host, stubBlockchain)
+
+	address, err := blockchainContext.ComputeContractAddress(deployer, 7)
+	require.Nil(t, err)
+	require.Equal(t, []byte("new_address"), address)
+
+	address, err = blockchainContext.ComputeDeterministicAddress(deployer, []byte("salt"), []byte("code hash"))
+	require.Nil(t, err)
+	require.Len(t, address, arwen.AddressLen)
+	require.Equal(t, make([]byte, 8), address[:8])
+	require.Equal(t, mockRuntime.VMType, address[8:10])
+	require.Equal(t, deployer[30:], address[30:])
+
+	sameAddress, err := blockchainContext.ComputeDeterministicAddress(deployer, []byte("salt"), []byte("code hash"))
+	require.Nil(t, err)
+	require.Equal(t, address, sameAddress)
+
+	otherAddress, err := blockchainContext.ComputeDeterministicAddress(deployer, []byte("other salt"), []byte("code hash"))
+	require.Nil(t, err)
+	require.NotEqual(t, address, otherAddress)
+
+	_, err = blockchainContext.ComputeDeterministicAddress([]byte("short"), []byte("salt"), []byte("code hash"))
+	require.Equal(t, arwen.ErrInvalidAddressLength, err)
+}
+
 func TestBlockchainContext_BlockHash(t *testing.T) {
 	t.Parallel()
 
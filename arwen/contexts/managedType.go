@@ -4,18 +4,18 @@ import (
 	"bytes"
 	"crypto/elliptic"
 	"encoding/binary"
-	"errors"
 	"io"
 	basicMath "math"
 	"math/big"
+	"math/bits"
 
 	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 	"github.com/ElrondNetwork/wasm-vm/math"
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
-	logger "github.com/ElrondNetwork/elrond-go-logger"
 )
 
-var logMTypes = logger.GetOrCreate("arwen/mtypes")
+var logMTypes = logging.GetOrCreate(logging.SubsystemManagedTypes, "arwen/mtypes")
 
 const bigFloatPrecision = 53
 const encodedBigFloatMaxByteLen = 18
@@ -44,6 +44,7 @@ const p521CurveUnmarshalCompressedMultiplier = 400
 
 const minEncodedBigFloatLength = 6
 const handleLen = 4
+const bigIntWordSize = bits.UintSize / 8
 
 type managedBufferMap map[int32][]byte
 type bigIntMap map[int32]*big.Int
@@ -55,6 +56,8 @@ type managedTypesContext struct {
 	managedTypesValues  managedTypesState
 	managedTypesStack   []managedTypesState
 	randomnessGenerator math.RandomnessGenerator
+	maxAllocation       uint64
+	allocatedBytes      uint64
 }
 
 type managedTypesState struct {
@@ -106,13 +109,60 @@ func (context *managedTypesContext) GetRandReader() io.Reader {
 	return context.randomnessGenerator
 }
 
-// InitState initializes the underlying values map
+// InitState initializes the underlying values map and the allocation budget
 func (context *managedTypesContext) InitState() {
 	context.managedTypesValues = managedTypesState{
 		bigIntValues:   make(bigIntMap),
 		bigFloatValues: make(bigFloatMap),
 		ecValues:       make(ellipticCurveMap),
 		mBufferValues:  make(managedBufferMap)}
+	context.allocatedBytes = 0
+}
+
+// SetMaxAllocation sets the number of bytes the managed buffers and big ints
+// may allocate on the host during an execution, including its nested calls;
+// zero means no limit.
+func (context *managedTypesContext) SetMaxAllocation(maxAllocation uint64) {
+	context.maxAllocation = maxAllocation
+}
+
+// allocate counts the given number of bytes against the allocation budget of
+// the execution; once the budget is exceeded, it fails the execution and
+// returns false, and the bytes must not be allocated. The budget counts every
+// allocation, not the bytes still held, so that it does not depend on how the
+// contract reuses its handles.
+func (context *managedTypesContext) allocate(numBytes int) bool {
+	if context.maxAllocation == 0 || numBytes <= 0 {
+		return true
+	}
+
+	allocatedBytes := math.AddUint64(context.allocatedBytes, uint64(numBytes))
+	if allocatedBytes > context.maxAllocation {
+		logMTypes.Trace("allocation", "error", arwen.ErrMaxManagedAllocationExceeded, "bytes", numBytes)
+		context.host.Runtime().FailExecution(arwen.ErrMaxManagedAllocationExceeded)
+		return false
+	}
+	context.allocatedBytes = allocatedBytes
+	return true
+}
+
+// LiveHandles counts the values held by the handles of the current call
+// frame; each frame starts with no handles and its handles are released when
+// it returns, while the contract may drop them earlier
+func (context *managedTypesContext) LiveHandles() arwen.ManagedHandleCounts {
+	values := context.managedTypesValues
+	counts := arwen.ManagedHandleCounts{
+		ManagedBuffers: len(values.mBufferValues),
+		BigInts:        len(values.bigIntValues),
+		BigFloats:      len(values.bigFloatValues),
+	}
+	for _, mBuffer := range values.mBufferValues {
+		counts.Bytes += uint64(len(mBuffer))
+	}
+	for _, bigInt := range values.bigIntValues {
+		counts.Bytes += uint64(len(bigInt.Bits()) * bigIntWordSize)
+	}
+	return counts
 }
 
 // PushState appends the values map to the state stack
@@ -267,6 +317,10 @@ func (context *managedTypesContext) GetTwoBigInt(handle1 int32, handle2 int32) (
 }
 
 func (context *managedTypesContext) newBigIntNoCopy(value *big.Int) int32 {
+	if !context.allocate(len(value.Bits()) * bigIntWordSize) {
+		value = big.NewInt(0)
+	}
+
 	newHandle := int32(len(context.managedTypesValues.bigIntValues))
 	for {
 		if _, ok := context.managedTypesValues.bigIntValues[newHandle]; !ok {
@@ -278,6 +332,17 @@ func (context *managedTypesContext) newBigIntNoCopy(value *big.Int) int32 {
 	return newHandle
 }
 
+// DropBigInt releases the big int under the handle, which may then be reused
+// for a new big int; it returns false if there is no big int under the handle
+func (context *managedTypesContext) DropBigInt(handle int32) bool {
+	_, ok := context.managedTypesValues.bigIntValues[handle]
+	if !ok {
+		return false
+	}
+	delete(context.managedTypesValues.bigIntValues, handle)
+	return true
+}
+
 // BIG FLOAT
 
 // BigFloatPrecIsNotValid checks if the precision of a big float is not valid (not equal to 53)
@@ -508,6 +573,18 @@ func (context *managedTypesContext) NewManagedBuffer() int32 {
 	return newHandle
 }
 
+// DropManagedBuffer releases the buffer under the handle, which may then be
+// reused for a new buffer; it returns false if there is no buffer under the
+// handle. The bytes of the buffer still count against the allocation budget.
+func (context *managedTypesContext) DropManagedBuffer(mBufferHandle int32) bool {
+	_, ok := context.managedTypesValues.mBufferValues[mBufferHandle]
+	if !ok {
+		return false
+	}
+	delete(context.managedTypesValues.mBufferValues, mBufferHandle)
+	return true
+}
+
 // NewManagedBufferFromBytes creates a new buffer in the managed buffers map, sets the bytes provided, and returns the handle
 func (context *managedTypesContext) NewManagedBufferFromBytes(bytes []byte) int32 {
 	mBufferHandle := context.NewManagedBuffer()
@@ -515,8 +592,14 @@ func (context *managedTypesContext) NewManagedBufferFromBytes(bytes []byte) int3
 	return mBufferHandle
 }
 
-// SetBytes sets the bytes given as value for the managed buffer
+// SetBytes sets the bytes given as value for the managed buffer; if the
+// allocation budget is exceeded, the execution fails and the buffer is left
+// unchanged
 func (context *managedTypesContext) SetBytes(mBufferHandle int32, bytes []byte) {
+	if !context.allocate(len(bytes)) {
+		return
+	}
+
 	_, ok := context.managedTypesValues.mBufferValues[mBufferHandle]
 	if !ok {
 		context.managedTypesValues.mBufferValues[mBufferHandle] = make([]byte, 0)
@@ -539,12 +622,17 @@ func (context *managedTypesContext) GetBytes(mBufferHandle int32) ([]byte, error
 	return mBuffer, nil
 }
 
-// AppendBytes appends the given bytes to the buffer at the end
+// AppendBytes appends the given bytes to the buffer at the end; it returns
+// false if there is no buffer under the handle. If the allocation budget is
+// exceeded, the execution fails and the buffer is left unchanged.
 func (context *managedTypesContext) AppendBytes(mBufferHandle int32, bytes []byte) bool {
 	_, ok := context.managedTypesValues.mBufferValues[mBufferHandle]
 	if !ok {
 		return false
 	}
+	if !context.allocate(len(bytes)) {
+		return true
+	}
 	context.managedTypesValues.mBufferValues[mBufferHandle] = append(context.managedTypesValues.mBufferValues[mBufferHandle], bytes...)
 	return true
 }
@@ -597,6 +685,9 @@ func (context *managedTypesContext) InsertSlice(mBufferHandle int32, startPositi
 	if startPosition < 0 || startPosition > int32(len(mBuffer))-1 {
 		return nil, arwen.ErrBadBounds
 	}
+	if !context.allocate(len(slice)) {
+		return nil, arwen.ErrMaxManagedAllocationExceeded
+	}
 	mBuffer = append(mBuffer[:startPosition], append(slice, mBuffer[startPosition:]...)...)
 	context.managedTypesValues.mBufferValues[mBufferHandle] = mBuffer
 	return context.managedTypesValues.mBufferValues[mBufferHandle], nil
@@ -613,7 +704,7 @@ func (context *managedTypesContext) ReadManagedVecOfManagedBuffers(
 	context.ConsumeGasForBytes(managedVecBytes)
 
 	if len(managedVecBytes)%handleLen != 0 {
-		return nil, 0, errors.New("invalid managed vector of managed buffer handles")
+		return nil, 0, arwen.ErrInvalidManagedBufferHandlesVector
 	}
 
 	numBuffers := len(managedVecBytes) / handleLen
@@ -730,3 +730,70 @@ func TestManagedTypesContext_PopDiscardIfStackIsEmptyShouldNotPanic(t *testing.T
 
 	require.Equal(t, 0, len(managedTypesContext.managedTypesStack))
 }
+
+func TestManagedTypesContext_MaxAllocation(t *testing.T) {
+	t.Parallel()
+	host := &contextmock.VMHostStub{
+		RuntimeCalled: func() arwen.RuntimeContext {
+			return &contextmock.RuntimeContextMock{}
+		},
+	}
+	managedTypesContext, _ := NewManagedTypesContext(host)
+	managedTypesContext.SetMaxAllocation(10)
+	mBytes := []byte{1, 2, 3, 4}
+
+	mBufferHandle := managedTypesContext.NewManagedBufferFromBytes(mBytes)
+	require.True(t, managedTypesContext.AppendBytes(mBufferHandle, mBytes))
+	require.Equal(t, uint64(8), managedTypesContext.allocatedBytes)
+
+	// the allocations beyond the budget leave the buffers unchanged
+	require.True(t, managedTypesContext.AppendBytes(mBufferHandle, mBytes))
+	managedTypesContext.SetBytes(mBufferHandle, mBytes)
+	newBuf, err := managedTypesContext.InsertSlice(mBufferHandle, 0, mBytes)
+	require.Nil(t, newBuf)
+	require.Equal(t, arwen.ErrMaxManagedAllocationExceeded, err)
+	mBufferBytes, _ := managedTypesContext.GetBytes(mBufferHandle)
+	require.Equal(t, append(mBytes, mBytes...), mBufferBytes)
+	require.Equal(t, uint64(8), managedTypesContext.allocatedBytes)
+
+	// a new execution starts with a new budget
+	managedTypesContext.InitState()
+	require.Equal(t, uint64(0), managedTypesContext.allocatedBytes)
+	mBufferHandle = managedTypesContext.NewManagedBufferFromBytes(append(mBytes, mBytes...))
+	mBufferBytes, _ = managedTypesContext.GetBytes(mBufferHandle)
+	require.Equal(t, append(mBytes, mBytes...), mBufferBytes)
+}
+
+func TestManagedTypesContext_DropHandles(t *testing.T) {
+	t.Parallel()
+
+	host := &contextmock.VMHostStub{}
+	managedTypesContext, _ := NewManagedTypesContext(host)
+
+	mBufferHandle := managedTypesContext.NewManagedBufferFromBytes([]byte("abc"))
+	bigIntHandle := managedTypesContext.NewBigIntFromInt64(1)
+	require.Equal(t, arwen.ManagedHandleCounts{ManagedBuffers: 1, BigInts: 1, Bytes: 3 + bigIntWordSize}, managedTypesContext.LiveHandles())
+
+	require.True(t, managedTypesContext.DropManagedBuffer(mBufferHandle))
+	require.False(t, managedTypesContext.DropManagedBuffer(mBufferHandle))
+	_, err := managedTypesContext.GetBytes(mBufferHandle)
+	require.Equal(t, arwen.ErrNoManagedBufferUnderThisHandle, err)
+
+	require.True(t, managedTypesContext.DropBigInt(bigIntHandle))
+	require.False(t, managedTypesContext.DropBigInt(bigIntHandle))
+	require.True(t, managedTypesContext.LiveHandles().IsZero())
+
+	// the handles of a nested frame are released when it returns
+	parentHandle := managedTypesContext.NewManagedBufferFromBytes([]byte("parent"))
+	managedTypesContext.PushState()
+	managedTypesContext.InitState()
+	require.True(t, managedTypesContext.LiveHandles().IsZero())
+	managedTypesContext.NewManagedBufferFromBytes([]byte("child"))
+	managedTypesContext.NewBigIntFromInt64(2)
+	managedTypesContext.PopSetActiveState()
+
+	require.Equal(t, arwen.ManagedHandleCounts{ManagedBuffers: 1, Bytes: 6}, managedTypesContext.LiveHandles())
+	parentBytes, err := managedTypesContext.GetBytes(parentHandle)
+	require.Nil(t, err)
+	require.Equal(t, []byte("parent"), parentBytes)
+}
@@ -5,14 +5,14 @@ import (
 
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
-	logger "github.com/ElrondNetwork/elrond-go-logger"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 	"github.com/ElrondNetwork/wasm-vm/math"
 )
 
-var logMetering = logger.GetOrCreate("arwen/metering")
+var logMetering = logging.GetOrCreate(logging.SubsystemGas, "arwen/metering")
 
 type meteringContext struct {
 	host               arwen.VMHost
@@ -25,8 +25,9 @@ type meteringContext struct {
 	gasUsedByAccounts  map[string]uint64
 	restoreGasEnabled  bool
 
-	gasTracer       arwen.GasTracing
-	traceGasEnabled bool
+	gasTracer          arwen.GasTracing
+	traceGasEnabled    bool
+	tracedFunctionName string
 }
 
 // NewMeteringContext creates a new meteringContext
@@ -74,6 +75,7 @@ func (context *meteringContext) InitState() {
 		newGasTracer = NewDisabledGasTracer()
 	}
 	context.gasTracer = newGasTracer
+	context.tracedFunctionName = ""
 }
 
 // InitStateFromContractCallInput initializes the internal state of the
@@ -469,19 +471,8 @@ func (context *meteringContext) UseGasBounded(gasToUse uint64) error {
 
 // ComputeExtraGasLockedForAsync calculates the minimum amount of gas to lock for async callbacks
 func (context *meteringContext) ComputeExtraGasLockedForAsync() uint64 {
-	baseGasSchedule := context.GasSchedule().BaseOperationCost
-	apiGasSchedule := context.GasSchedule().ElrondAPICost
 	codeSize := context.host.Runtime().GetSCCodeSize()
-	costPerByte := baseGasSchedule.AoTPreparePerByte
-
-	// Exact amount of gas required to compile this SC again, to execute the callback
-	compilationGasLock := math.MulUint64(codeSize, costPerByte)
-
-	// Minimum amount required to execute the callback
-	executionGasLock := math.AddUint64(apiGasSchedule.AsyncCallStep, apiGasSchedule.AsyncCallbackGasLock)
-	gasLockedForAsync := math.AddUint64(compilationGasLock, executionGasLock)
-
-	return gasLockedForAsync
+	return context.host.VMVersion().ComputeExtraGasLockedForAsync(context.GasSchedule(), codeSize)
 }
 
 // GetGasLocked returns the locked gas
@@ -561,16 +552,31 @@ func (context *meteringContext) StartGasTracing(functionName string) {
 		if len(scAddress) != 0 {
 			context.gasTracer.BeginTrace(scAddress, functionName)
 		}
+		context.tracedFunctionName = functionName
 	}
 }
 
 func (context *meteringContext) traceGas(usedGas uint64) {
 	context.gasTracer.AddToCurrentTrace(usedGas)
+	context.traceHostFunctionGas(context.tracedFunctionName, usedGas)
 }
 
 func (context *meteringContext) addToGasTrace(functionName string, usedGas uint64) {
 	scAddress := context.getSCAddress()
 	context.gasTracer.AddTracedGas(scAddress, functionName, usedGas)
+	context.traceHostFunctionGas(functionName, usedGas)
+}
+
+// traceHostFunctionGas reports the gas used by a host function to the
+// ExecutionTracer of the host, if it traces the host functions
+func (context *meteringContext) traceHostFunctionGas(functionName string, usedGas uint64) {
+	if !context.traceGasEnabled || len(functionName) == 0 {
+		return
+	}
+	hostFunctionGasTracer, ok := context.host.ExecutionTracer().(arwen.HostFunctionGasTracer)
+	if ok {
+		hostFunctionGasTracer.TraceHostFunctionGas(functionName, usedGas)
+	}
 }
 
 func (context *meteringContext) getSCAddress() string {
@@ -8,21 +8,38 @@ import (
 	"github.com/ElrondNetwork/elrond-go-core/core"
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
-	logger "github.com/ElrondNetwork/elrond-go-logger"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
+	"github.com/ElrondNetwork/wasm-vm/logging"
+	"github.com/ElrondNetwork/wasm-vm/math"
 )
 
 var _ arwen.OutputContext = (*outputContext)(nil)
 
-var logOutput = logger.GetOrCreate("arwen/output")
+var logOutput = logging.GetOrCreate(logging.SubsystemOutput, "arwen/output")
+
+const noFinishReserved = -1
 
 type outputContext struct {
 	host        arwen.VMHost
 	outputState *vmcommon.VMOutput
 	stateStack  []*vmcommon.VMOutput
 	codeUpdates map[string]struct{}
+
+	maxReturnDataSize    uint64
+	reservedFinishIndex  int
+	reservedFinishLength uint64
+
+	maxNumberOfLogs uint64
+	maxLogSize      uint64
+
+	bundleTransfers bool
+
+	// typedError holds the code and the payload of the typed error signaled
+	// by the running contract, if any
+	typedError [][]byte
 }
 
 // NewOutputContext creates a new outputContext
@@ -32,8 +49,9 @@ func NewOutputContext(host arwen.VMHost) (*outputContext, error) {
 	}
 
 	context := &outputContext{
-		host:       host,
-		stateStack: make([]*vmcommon.VMOutput, 0),
+		host:                host,
+		stateStack:          make([]*vmcommon.VMOutput, 0),
+		reservedFinishIndex: noFinishReserved,
 	}
 
 	context.InitState()
@@ -45,6 +63,8 @@ func NewOutputContext(host arwen.VMHost) (*outputContext, error) {
 func (context *outputContext) InitState() {
 	context.outputState = newVMOutput()
 	context.codeUpdates = make(map[string]struct{})
+	context.releaseFinishReservation()
+	context.typedError = nil
 }
 
 func newVMOutput() *vmcommon.VMOutput {
@@ -79,6 +99,8 @@ func (context *outputContext) PushState() {
 	newState := newVMOutput()
 	mergeVMOutputs(newState, context.outputState)
 	context.stateStack = append(context.stateStack, newState)
+	context.releaseFinishReservation()
+	context.typedError = nil
 }
 
 // PopSetActiveState removes the latest entry from the state stack and sets it as the current vm output
@@ -91,6 +113,8 @@ func (context *outputContext) PopSetActiveState() {
 	prevState := context.stateStack[stateStackLen-1]
 	context.stateStack = context.stateStack[:stateStackLen-1]
 	context.outputState = prevState
+	context.releaseFinishReservation()
+	context.typedError = nil
 }
 
 // PopMergeActiveState merges the current state into the head of the stateStack,
@@ -110,6 +134,8 @@ func (context *outputContext) PopMergeActiveState() {
 	mergeVMOutputs(prevState, context.outputState)
 	context.outputState = newVMOutput()
 	mergeVMOutputs(context.outputState, prevState)
+	context.releaseFinishReservation()
+	context.typedError = nil
 }
 
 // PopDiscard removes the latest entry from the state stack, but maintaining
@@ -139,6 +165,8 @@ func (context *outputContext) CensorVMOutput() {
 	context.outputState.GasRemaining = 0
 	context.outputState.GasRefund = big.NewInt(0)
 	context.outputState.Logs = make([]*vmcommon.LogEntry, 0)
+	context.releaseFinishReservation()
+	context.typedError = nil
 
 	for _, account := range context.outputState.OutputAccounts {
 		newTransfers := make([]vmcommon.OutputTransfer, 0)
@@ -217,6 +245,7 @@ func (context *outputContext) SetReturnMessage(returnMessage string) {
 // ClearReturnData reinitializes the return data for the current output state.
 func (context *outputContext) ClearReturnData() {
 	context.outputState.ReturnData = make([][]byte, 0)
+	context.releaseFinishReservation()
 }
 
 // RemoveReturnData removes the return data item located at the specified index
@@ -226,17 +255,20 @@ func (context *outputContext) RemoveReturnData(index uint32) {
 		return
 	}
 	context.outputState.ReturnData = append(returnData[:index], returnData[index+1:]...)
+	context.releaseFinishReservation()
 }
 
 // Finish appends the given data to the return data of the current output state.
 func (context *outputContext) Finish(data []byte) {
 	context.outputState.ReturnData = append(context.outputState.ReturnData, data)
+	context.releaseFinishReservation()
 	logOutput.Trace("finish", "data", data)
 }
 
 // PrependFinish appends the given data to the return data of the current output state.
 func (context *outputContext) PrependFinish(data []byte) {
 	context.outputState.ReturnData = append([][]byte{data}, context.outputState.ReturnData...)
+	context.releaseFinishReservation()
 }
 
 // DeleteFirstReturnData deletes the first return data, to be used after prepend
@@ -244,6 +276,99 @@ func (context *outputContext) DeleteFirstReturnData() {
 	if len(context.outputState.ReturnData) > 0 {
 		context.outputState.ReturnData = context.outputState.ReturnData[1:]
 	}
+	context.releaseFinishReservation()
+}
+
+// SetMaxReturnDataSize sets the maximum number of bytes the return data of
+// the current output state may hold, counting the reserved finish; zero means
+// no limit.
+func (context *outputContext) SetMaxReturnDataSize(maxReturnDataSize uint64) {
+	context.maxReturnDataSize = maxReturnDataSize
+}
+
+// CheckReturnDataSize returns ErrMaxReturnDataSizeExceeded if finishing the
+// given number of bytes would exceed the maximum size of the return data.
+func (context *outputContext) CheckReturnDataSize(length uint64) error {
+	if context.maxReturnDataSize == 0 {
+		return nil
+	}
+
+	size := math.AddUint64(context.returnDataSize(), context.reservedFinishLength)
+	if math.AddUint64(size, length) > context.maxReturnDataSize {
+		logOutput.Trace("finish", "error", arwen.ErrMaxReturnDataSizeExceeded, "length", length)
+		return arwen.ErrMaxReturnDataSizeExceeded
+	}
+	return nil
+}
+
+// ReserveFinish appends an empty return data item, to be filled by AppendFinish
+// with up to the given number of bytes, so that a contract can finish large
+// data in chunks without holding it whole in its memory. The reservation ends
+// with any other change of the return data.
+func (context *outputContext) ReserveFinish(length uint64) error {
+	context.releaseFinishReservation()
+	err := context.CheckReturnDataSize(length)
+	if err != nil {
+		return err
+	}
+
+	context.outputState.ReturnData = append(context.outputState.ReturnData, make([]byte, 0, length))
+	context.reservedFinishIndex = len(context.outputState.ReturnData) - 1
+	context.reservedFinishLength = length
+	logOutput.Trace("finish reserved", "length", length)
+	return nil
+}
+
+// AppendFinish appends the given data to the return data item reserved by
+// ReserveFinish.
+func (context *outputContext) AppendFinish(data []byte) error {
+	if context.reservedFinishIndex == noFinishReserved {
+		return arwen.ErrNoFinishReserved
+	}
+	if uint64(len(data)) > context.reservedFinishLength {
+		return arwen.ErrFinishReservationExceeded
+	}
+
+	returnData := context.outputState.ReturnData
+	returnData[context.reservedFinishIndex] = append(returnData[context.reservedFinishIndex], data...)
+	context.reservedFinishLength -= uint64(len(data))
+	logOutput.Trace("finish appended", "data", data)
+	return nil
+}
+
+// SetLogLimits sets the maximum number of logs of the current output state and
+// the maximum number of bytes of the topics and data of each log; zero means
+// no limit.
+func (context *outputContext) SetLogLimits(maxNumberOfLogs uint64, maxLogSize uint64) {
+	context.maxNumberOfLogs = maxNumberOfLogs
+	context.maxLogSize = maxLogSize
+}
+
+// CheckLogSize returns ErrMaxNumberOfLogsExceeded or ErrMaxLogSizeExceeded if
+// writing a new log of the given number of bytes would exceed the log limits.
+func (context *outputContext) CheckLogSize(size uint64) error {
+	if context.maxNumberOfLogs > 0 && uint64(len(context.outputState.Logs)) >= context.maxNumberOfLogs {
+		logOutput.Trace("log entry", "error", arwen.ErrMaxNumberOfLogsExceeded)
+		return arwen.ErrMaxNumberOfLogsExceeded
+	}
+	if context.maxLogSize > 0 && size > context.maxLogSize {
+		logOutput.Trace("log entry", "error", arwen.ErrMaxLogSizeExceeded, "size", size)
+		return arwen.ErrMaxLogSizeExceeded
+	}
+	return nil
+}
+
+func (context *outputContext) returnDataSize() uint64 {
+	size := uint64(0)
+	for _, data := range context.outputState.ReturnData {
+		size = math.AddUint64(size, uint64(len(data)))
+	}
+	return size
+}
+
+func (context *outputContext) releaseFinishReservation() {
+	context.reservedFinishIndex = noFinishReserved
+	context.reservedFinishLength = 0
 }
 
 // WriteLogWithIdentifier creates a new LogEntry and appends it to the logs of the current output state.
@@ -329,6 +454,11 @@ func (context *outputContext) TransferValueOnly(destination []byte, sender []byt
 // the necessary steps to create accounts and reverses the state in case of an
 // execution error or failed value transfer.
 func (context *outputContext) Transfer(destination []byte, sender []byte, gasLimit uint64, gasLocked uint64, value *big.Int, asyncData []byte, input []byte, callType vm.CallType) error {
+	if context.host.Runtime().ReadOnly() {
+		logOutput.Trace("transfer", "error", "cannot create output transfers in readonly mode")
+		return arwen.ErrInvalidCallOnReadOnlyMode
+	}
+
 	checkPayableIfNotCallback := gasLimit > 0 && callType != vm.AsynchronousCallBack
 	err := context.TransferValueOnly(destination, sender, value, checkPayableIfNotCallback)
 	if err != nil {
@@ -488,7 +618,10 @@ func (context *outputContext) RemoveNonUpdatedStorage() {
 	}
 }
 
-// GetVMOutput updates the current VMOutput and returns it
+// GetVMOutput updates the current VMOutput and returns it, with the deleted
+// and touched accounts sorted once the SortedAccountSets flag is enabled, so
+// that identical executions produce identical VMOutputs, and with the plain
+// transfers merged if transfer bundling is enabled
 func (context *outputContext) GetVMOutput() *vmcommon.VMOutput {
 	context.removeNonUpdatedCode()
 
@@ -500,6 +633,12 @@ func (context *outputContext) GetVMOutput() *vmcommon.VMOutput {
 		return context.CreateVMOutputInCaseOfError(err)
 	}
 
+	if context.bundleTransfers {
+		bundleOutputTransfers(context.outputState.OutputAccounts)
+	}
+	if context.host.EnableEpochsHandler().IsSortedAccountSetsFlagEnabled() {
+		arwen.SortVMOutput(context.outputState)
+	}
 	return context.outputState
 }
 
@@ -512,6 +651,34 @@ func (context *outputContext) DeployCode(input arwen.CodeDeployInput) {
 
 	var empty struct{}
 	context.codeUpdates[string(input.ContractAddress)] = empty
+
+	context.writeBuildInfoLogIfNeeded(input)
+}
+
+// writeBuildInfoLogIfNeeded records the build info section of the deployed
+// code in a log entry, so that explorers can display the provenance of the
+// contract; code without the section, or whose section cannot be decoded,
+// is deployed without the log entry
+func (context *outputContext) writeBuildInfoLogIfNeeded(input arwen.CodeDeployInput) {
+	if !context.host.EnableEpochsHandler().IsContractBuildInfoLogFlagEnabled() {
+		return
+	}
+
+	buildInfo, err := inspect.ReadBuildInfo(input.ContractCode)
+	if err != nil {
+		logOutput.Trace("read build info", "address", input.ContractAddress, "error", err)
+		return
+	}
+	if buildInfo == nil {
+		return
+	}
+
+	topics := [][]byte{
+		[]byte(buildInfo.Framework),
+		[]byte(buildInfo.Rustc),
+		[]byte(buildInfo.Flags),
+	}
+	context.WriteLogWithIdentifier(input.ContractAddress, topics, nil, []byte(arwen.ContractBuildInfoIdentifier))
 }
 
 // CreateVMOutputInCaseOfError creates a new vmOutput with the given error set as return message.
@@ -528,12 +695,23 @@ func (context *outputContext) CreateVMOutputInCaseOfError(err error) *vmcommon.V
 		ReturnCode:    returnCode,
 		ReturnMessage: returnMessage,
 	}
+	if errors.Is(err, arwen.ErrSignalError) && context.typedError != nil {
+		vmOutput.ReturnData = context.typedError
+	}
 
 	context.host.Metering().UpdateGasStateOnFailure(vmOutput)
 
 	return vmOutput
 }
 
+// SetTypedError records the code and the payload of the typed error which
+// the running contract is about to signal; the VMOutput of the failed
+// execution carries them as its return data, so that they reach the callback
+// of the caller as arguments following the error message
+func (context *outputContext) SetTypedError(code []byte, payload []byte) {
+	context.typedError = [][]byte{code, payload}
+}
+
 func (context *outputContext) removeNonUpdatedCode() {
 	for address, account := range context.outputState.OutputAccounts {
 		_, ok := context.codeUpdates[address]
@@ -575,6 +753,12 @@ func (context *outputContext) resolveReturnCodeFromError(err error) vmcommon.Ret
 	if errors.Is(err, executor.ErrFunctionNonvoidSignature) {
 		return vmcommon.FunctionWrongSignature
 	}
+	if errors.Is(err, arwen.ErrEndpointArgumentsMismatch) {
+		return vmcommon.FunctionWrongSignature
+	}
+	if errors.Is(err, arwen.ErrEndpointPaymentNotAccepted) {
+		return vmcommon.UserError
+	}
 	if errors.Is(err, executor.ErrInvalidFunction) {
 		return vmcommon.UserError
 	}
@@ -599,6 +783,24 @@ func (context *outputContext) resolveReturnCodeFromError(err error) vmcommon.Ret
 	if errors.Is(err, arwen.ErrTransferInsufficientFunds) {
 		return vmcommon.OutOfFunds
 	}
+	if errors.Is(err, arwen.ErrInternalVMError) {
+		return arwen.InternalVMError
+	}
+	if errors.Is(err, arwen.ErrMemoryLimit) {
+		return context.host.VMVersion().MemoryLimitReturnCode()
+	}
+	if errors.Is(err, arwen.ErrExecutionDenied) {
+		return arwen.ExecutionDenied
+	}
+	if errors.Is(err, arwen.ErrSCExecutionPaused) {
+		return arwen.SCExecutionPaused
+	}
+	if errors.Is(err, arwen.ErrMaxReturnDataSizeExceeded) {
+		return arwen.ReturnDataTooLarge
+	}
+	if errors.Is(err, arwen.ErrLogLimitExceeded) {
+		return arwen.TooManyLogs
+	}
 
 	return vmcommon.ExecutionFailed
 }
@@ -0,0 +1,113 @@
+package contexts
+
+import (
+	"encoding/hex"
+	"math/big"
+	"strings"
+
+	"github.com/ElrondNetwork/elrond-go-core/core"
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// SetTransferBundling makes GetVMOutput merge the plain transfers sent by the
+// same sender to the same destination into a single transfer
+func (context *outputContext) SetTransferBundling(bundle bool) {
+	context.bundleTransfers = bundle
+}
+
+// bundleOutputTransfers merges the transfers of each output account which only
+// move funds, without gas, async data or a function to call: the EGLD transfers
+// of a sender are summed into one, and so are its ESDTTransfer transfers of
+// each fungible token. The merged transfer takes the place of the first one it
+// replaces, and the logs of the execution are left as they are.
+func bundleOutputTransfers(outputAccounts map[string]*vmcommon.OutputAccount) {
+	for _, account := range outputAccounts {
+		if len(account.OutputTransfers) < 2 {
+			continue
+		}
+		account.OutputTransfers = bundleTransfersOfAccount(account.OutputTransfers)
+	}
+}
+
+type bundlingKey struct {
+	sender string
+	token  string
+}
+
+type bundledTransfer struct {
+	index int
+	token []byte
+	value *big.Int
+}
+
+func bundleTransfersOfAccount(transfers []vmcommon.OutputTransfer) []vmcommon.OutputTransfer {
+	bundled := make([]vmcommon.OutputTransfer, 0, len(transfers))
+	bundles := make(map[bundlingKey]*bundledTransfer)
+	for _, transfer := range transfers {
+		token, value, ok := bundlingKeyOf(transfer)
+		if !ok {
+			bundled = append(bundled, transfer)
+			continue
+		}
+
+		key := bundlingKey{sender: string(transfer.SenderAddress), token: string(token)}
+		bundle, exists := bundles[key]
+		if !exists {
+			bundles[key] = &bundledTransfer{
+				index: len(bundled),
+				token: token,
+				value: big.NewInt(0).Set(value),
+			}
+			bundled = append(bundled, transfer)
+			continue
+		}
+		bundle.value.Add(bundle.value, value)
+	}
+
+	for _, bundle := range bundles {
+		transfer := &bundled[bundle.index]
+		if len(bundle.token) == 0 {
+			transfer.Value = bundle.value
+			continue
+		}
+		transfer.Data = esdtTransferData(bundle.token, bundle.value)
+	}
+	return bundled
+}
+
+// bundlingKeyOf returns the token and the value moved by a transfer which can
+// be merged with others, the token being empty for EGLD, and false for the
+// transfers which cannot be merged
+func bundlingKeyOf(transfer vmcommon.OutputTransfer) ([]byte, *big.Int, bool) {
+	if transfer.CallType != vm.DirectCall || transfer.GasLimit > 0 || transfer.GasLocked > 0 || len(transfer.AsyncData) > 0 {
+		return nil, nil, false
+	}
+	if len(transfer.Data) == 0 {
+		if transfer.Value == nil {
+			return nil, nil, false
+		}
+		return []byte{}, transfer.Value, true
+	}
+	if transfer.Value != nil && transfer.Value.Sign() != 0 {
+		return nil, nil, false
+	}
+
+	parts := strings.Split(string(transfer.Data), "@")
+	if len(parts) != 3 || parts[0] != core.BuiltInFunctionESDTTransfer {
+		return nil, nil, false
+	}
+	token, err := hex.DecodeString(parts[1])
+	if err != nil || len(token) == 0 {
+		return nil, nil, false
+	}
+	value, err := hex.DecodeString(parts[2])
+	if err != nil {
+		return nil, nil, false
+	}
+	return token, big.NewInt(0).SetBytes(value), true
+}
+
+func esdtTransferData(token []byte, value *big.Int) []byte {
+	return []byte(core.BuiltInFunctionESDTTransfer + "@" + hex.EncodeToString(token) + "@" + hex.EncodeToString(value.Bytes()))
+}
@@ -0,0 +1,68 @@
+package contexts
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+func TestOutputTransferBundling_MergesPlainTransfers(t *testing.T) {
+	t.Parallel()
+
+	sender := []byte("sender")
+	otherSender := []byte("otherSender")
+	asyncCall := vmcommon.OutputTransfer{
+		Value:         big.NewInt(1),
+		GasLimit:      1000,
+		AsyncData:     []byte("asyncData"),
+		Data:          []byte("callback"),
+		CallType:      vm.AsynchronousCall,
+		SenderAddress: sender,
+	}
+	transfers := []vmcommon.OutputTransfer{
+		{Value: big.NewInt(10), CallType: vm.DirectCall, SenderAddress: sender},
+		{Value: big.NewInt(0), Data: esdtTransferData([]byte("TKN-0001"), big.NewInt(5)), SenderAddress: sender},
+		asyncCall,
+		{Value: big.NewInt(20), CallType: vm.DirectCall, SenderAddress: sender},
+		{Value: big.NewInt(7), CallType: vm.DirectCall, SenderAddress: otherSender},
+		{Value: big.NewInt(0), Data: esdtTransferData([]byte("TKN-0001"), big.NewInt(6)), SenderAddress: sender},
+		{Value: big.NewInt(0), Data: esdtTransferData([]byte("TKN-0002"), big.NewInt(1)), SenderAddress: sender},
+		{Value: big.NewInt(30), GasLimit: 100, Data: []byte("function"), SenderAddress: sender},
+	}
+
+	bundled := bundleTransfersOfAccount(transfers)
+	require.Len(t, bundled, 6)
+	require.Equal(t, big.NewInt(30), bundled[0].Value)
+	require.Equal(t, esdtTransferData([]byte("TKN-0001"), big.NewInt(11)), bundled[1].Data)
+	require.Equal(t, asyncCall, bundled[2])
+	require.Equal(t, big.NewInt(7), bundled[3].Value)
+	require.Equal(t, otherSender, bundled[3].SenderAddress)
+	require.Equal(t, esdtTransferData([]byte("TKN-0002"), big.NewInt(1)), bundled[4].Data)
+	require.Equal(t, []byte("function"), bundled[5].Data)
+
+	require.Equal(t, big.NewInt(10), transfers[0].Value)
+}
+
+func TestOutputTransferBundling_KeepsOtherTransfers(t *testing.T) {
+	t.Parallel()
+
+	sender := []byte("sender")
+	nftTransfer := []byte("ESDTNFTTransfer@4e46542d30303031@01@01@64657374")
+	transfers := []vmcommon.OutputTransfer{
+		{Value: big.NewInt(0), Data: nftTransfer, SenderAddress: sender},
+		{Value: big.NewInt(0), Data: nftTransfer, SenderAddress: sender},
+		{Value: big.NewInt(0), Data: []byte("ESDTTransfer@zz@01"), SenderAddress: sender},
+		{Value: big.NewInt(0), Data: []byte("ESDTTransfer@zz@01"), SenderAddress: sender},
+		{Value: big.NewInt(1), Data: esdtTransferData([]byte("TKN-0001"), big.NewInt(1)), SenderAddress: sender},
+		{Value: big.NewInt(1), Data: esdtTransferData([]byte("TKN-0001"), big.NewInt(1)), SenderAddress: sender},
+	}
+
+	outputAccounts := map[string]*vmcommon.OutputAccount{
+		"dest": {Address: []byte("dest"), OutputTransfers: transfers},
+	}
+	bundleOutputTransfers(outputAccounts)
+	require.Equal(t, transfers, outputAccounts["dest"].OutputTransfers)
+}
@@ -1,6 +1,7 @@
 package contexts
 
 import (
+	"errors"
 	"math/big"
 	"testing"
 
@@ -178,6 +179,70 @@ func TestOutputContext_FinishReturnData(t *testing.T) {
 	require.Zero(t, len(outputContext.ReturnData()))
 }
 
+func TestOutputContext_ChunkedFinish(t *testing.T) {
+	t.Parallel()
+
+	host := &contextmock.VMHostStub{}
+	outputContext, _ := NewOutputContext(host)
+
+	err := outputContext.AppendFinish([]byte("chunk"))
+	require.Equal(t, arwen.ErrNoFinishReserved, err)
+
+	outputContext.Finish([]byte("first"))
+	err = outputContext.ReserveFinish(10)
+	require.Nil(t, err)
+	require.Equal(t, [][]byte{[]byte("first"), {}}, outputContext.ReturnData())
+
+	err = outputContext.AppendFinish([]byte("chunk"))
+	require.Nil(t, err)
+	err = outputContext.AppendFinish([]byte("chunk"))
+	require.Nil(t, err)
+	require.Equal(t, [][]byte{[]byte("first"), []byte("chunkchunk")}, outputContext.ReturnData())
+
+	err = outputContext.AppendFinish([]byte("x"))
+	require.Equal(t, arwen.ErrFinishReservationExceeded, err)
+
+	err = outputContext.ReserveFinish(5)
+	require.Nil(t, err)
+	outputContext.Finish([]byte("last"))
+	err = outputContext.AppendFinish([]byte("chunk"))
+	require.Equal(t, arwen.ErrNoFinishReserved, err)
+	require.Equal(t, [][]byte{[]byte("first"), []byte("chunkchunk"), {}, []byte("last")}, outputContext.ReturnData())
+}
+
+func TestOutputContext_MaxReturnDataSize(t *testing.T) {
+	t.Parallel()
+
+	host := &contextmock.VMHostStub{}
+	outputContext, _ := NewOutputContext(host)
+
+	require.Nil(t, outputContext.CheckReturnDataSize(1000))
+
+	outputContext.SetMaxReturnDataSize(10)
+	require.Nil(t, outputContext.CheckReturnDataSize(10))
+	require.Equal(t, arwen.ErrMaxReturnDataSizeExceeded, outputContext.CheckReturnDataSize(11))
+
+	outputContext.Finish([]byte("four"))
+	require.Nil(t, outputContext.CheckReturnDataSize(6))
+	require.Equal(t, arwen.ErrMaxReturnDataSizeExceeded, outputContext.CheckReturnDataSize(7))
+
+	err := outputContext.ReserveFinish(7)
+	require.Equal(t, arwen.ErrMaxReturnDataSizeExceeded, err)
+
+	err = outputContext.ReserveFinish(4)
+	require.Nil(t, err)
+	require.Nil(t, outputContext.CheckReturnDataSize(2))
+	require.Equal(t, arwen.ErrMaxReturnDataSizeExceeded, outputContext.CheckReturnDataSize(3))
+
+	err = outputContext.AppendFinish([]byte("ab"))
+	require.Nil(t, err)
+	require.Nil(t, outputContext.CheckReturnDataSize(2))
+	require.Equal(t, arwen.ErrMaxReturnDataSizeExceeded, outputContext.CheckReturnDataSize(3))
+
+	outputContext.ClearReturnData()
+	require.Nil(t, outputContext.CheckReturnDataSize(10))
+}
+
 func TestOutputContext_MergeCompleteAccounts(t *testing.T) {
 	t.Parallel()
 
@@ -392,6 +457,33 @@ func TestOutputContext_VMOutputError(t *testing.T) {
 	require.Equal(t, expected, vmOutput)
 }
 
+func TestOutputContext_VMOutputError_TypedError(t *testing.T) {
+	t.Parallel()
+
+	host := &contextmock.VMHostMock{
+		MeteringContext: &contextmock.MeteringContextMock{},
+		RuntimeContext: &contextmock.RuntimeContextMock{
+			VMInput: &vmcommon.ContractCallInput{},
+		},
+	}
+
+	outputContext, _ := NewOutputContext(host)
+	outputContext.SetReturnMessage("typed error")
+	outputContext.SetTypedError([]byte{42}, []byte("payload"))
+
+	vmOutput := outputContext.CreateVMOutputInCaseOfError(arwen.ErrExecutionFailed)
+	require.Nil(t, vmOutput.ReturnData)
+
+	vmOutput = outputContext.CreateVMOutputInCaseOfError(arwen.ErrSignalError)
+	require.Equal(t, vmcommon.UserError, vmOutput.ReturnCode)
+	require.Equal(t, "typed error", vmOutput.ReturnMessage)
+	require.Equal(t, [][]byte{{42}, []byte("payload")}, vmOutput.ReturnData)
+
+	outputContext.PushState()
+	vmOutput = outputContext.CreateVMOutputInCaseOfError(arwen.ErrSignalError)
+	require.Nil(t, vmOutput.ReturnData)
+}
+
 func TestOutputContext_Transfer(t *testing.T) {
 	t.Parallel()
 
@@ -567,6 +659,33 @@ func TestOutputContext_WriteLog(t *testing.T) {
 	require.Equal(t, outputContext.outputState.Logs[2].Topics, [][]byte{topic})
 }
 
+func TestOutputContext_LogLimits(t *testing.T) {
+	t.Parallel()
+
+	host := &contextmock.VMHostMock{
+		RuntimeContext: &contextmock.RuntimeContextMock{
+			CallFunction: "function",
+		},
+	}
+	outputContext, _ := NewOutputContext(host)
+
+	require.Nil(t, outputContext.CheckLogSize(1000))
+
+	outputContext.SetLogLimits(2, 10)
+	require.Nil(t, outputContext.CheckLogSize(10))
+	require.Equal(t, arwen.ErrMaxLogSizeExceeded, outputContext.CheckLogSize(11))
+	require.True(t, errors.Is(outputContext.CheckLogSize(11), arwen.ErrLogLimitExceeded))
+
+	outputContext.WriteLog([]byte("address"), [][]byte{[]byte("topic")}, []byte("data"))
+	require.Nil(t, outputContext.CheckLogSize(10))
+	outputContext.WriteLog([]byte("address"), [][]byte{[]byte("topic")}, []byte("data"))
+	require.Equal(t, arwen.ErrMaxNumberOfLogsExceeded, outputContext.CheckLogSize(0))
+	require.True(t, errors.Is(outputContext.CheckLogSize(0), arwen.ErrLogLimitExceeded))
+
+	outputContext.SetLogLimits(0, 0)
+	require.Nil(t, outputContext.CheckLogSize(1000))
+}
+
 func TestOutputContext_PopSetActiveStateIfStackIsEmptyShouldNotPanic(t *testing.T) {
 	t.Parallel()
 
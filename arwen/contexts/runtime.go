@@ -6,23 +6,29 @@ import (
 	"fmt"
 	builtinMath "math"
 	"math/big"
+	"sync/atomic"
 
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
 	"github.com/ElrondNetwork/elrond-go-core/storage"
 	"github.com/ElrondNetwork/elrond-go-core/storage/lrucache"
-	logger "github.com/ElrondNetwork/elrond-go-logger"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 	"github.com/ElrondNetwork/wasm-vm/math"
 )
 
-var logRuntime = logger.GetOrCreate("arwen/runtime")
+var logRuntime = logging.GetOrCreate(logging.SubsystemRuntime, "arwen/runtime")
 
 var _ arwen.RuntimeContext = (*runtimeContext)(nil)
 
 const warmCacheSize = 100
 
+const numInstanceSources = int(arwen.InstanceFromBytecode) + 1
+
+const unmeteredWarmInstanceSuffix = "/unmetered"
+
 type runtimeContext struct {
 	host                 arwen.VMHost
 	instance             executor.Instance
@@ -30,22 +36,49 @@ type runtimeContext struct {
 	codeAddress          []byte
 	codeHash             []byte
 	codeSize             uint64
+	endpointCallability  map[string]inspect.Callability
 	callFunction         string
 	vmType               []byte
 	readOnly             bool
 	verifyCode           bool
+	verifyEndpoints      bool
+	contractLimits       arwen.ContractLimits
+	determinismChecks    arwen.DeterminismChecks
+	meteringExemptions   map[string]bool
 	maxInstanceStackSize uint64
 
 	numRunningInstances int
 
 	warmInstanceCache storage.Cacher
+	// evictedInstances are the warm instances evicted from the cache while an
+	// activation of their contract was still running on them; they are cleaned
+	// once no activation uses them anymore
+	evictedInstances []executor.Instance
+
+	// instanceCreations and warmedUpContracts are updated atomically, since
+	// the cache statistics may be read while a contract is executing
+	instanceCreations [numInstanceSources]uint64
+	warmedUpContracts uint64
+
+	// infrastructureError is the first failure of the executor met during the
+	// current transaction, and unrestorableCodeHashes are the contracts whose
+	// compiled code kept by the node could not be loaded, so that it is not
+	// used again until it is replaced by a fresh compilation
+	infrastructureError    error
+	unrestorableCodeHashes map[string]struct{}
 
 	stateStack    []*runtimeContext
 	instanceStack []executor.Instance
 
-	validator  *wasmValidator
-	vmExecutor executor.Executor
-	errors     arwen.WrappableError
+	// suspendedStates follows instanceStack, holding the state saved when the
+	// activation pushed at the same level took over the warm instance of a
+	// suspended activation of the same contract, or nil otherwise
+	suspendedStates []*suspendedInstanceState
+
+	validator     *wasmValidator
+	hostFunctions *arwen.HostFunctionsActivation
+	vmExecutor    executor.Executor
+	errors        arwen.WrappableError
 }
 
 // NewRuntimeContext creates a new runtimeContext
@@ -54,6 +87,8 @@ func NewRuntimeContext(
 	vmType []byte,
 	builtInFuncContainer vmcommon.BuiltInFunctionContainer,
 	vmExecutor executor.Executor,
+	verifyEndpoints bool,
+	contractLimits arwen.ContractLimits,
 ) (*runtimeContext, error) {
 	if check.IfNil(host) {
 		return nil, arwen.ErrNilVMHost
@@ -66,13 +101,20 @@ func NewRuntimeContext(
 		vmType:              vmType,
 		stateStack:          make([]*runtimeContext, 0),
 		instanceStack:       make([]executor.Instance, 0),
+		suspendedStates:     make([]*suspendedInstanceState, 0),
 		validator:           newWASMValidator(scAPINames, builtInFuncContainer),
+		hostFunctions:       arwen.NewHostFunctionsActivation(host.EnableEpochsHandler()),
+		verifyEndpoints:     verifyEndpoints,
+		contractLimits:      contractLimits,
+		meteringExemptions:  make(map[string]bool),
 		numRunningInstances: 0,
 		errors:              nil,
+
+		unrestorableCodeHashes: make(map[string]struct{}),
 	}
 
 	var err error
-	context.warmInstanceCache, err = lrucache.NewCacheWithEviction(warmCacheSize, instanceEvicted)
+	context.warmInstanceCache, err = lrucache.NewCacheWithEviction(warmCacheSize, context.instanceEvicted)
 	if err != nil {
 		return nil, err
 	}
@@ -83,16 +125,60 @@ func NewRuntimeContext(
 	return context, nil
 }
 
-func instanceEvicted(_ interface{}, value interface{}) {
+// suspendedInstanceState is the state of a suspended activation, saved before
+// its instance was reset for a reentrant activation of the same contract
+type suspendedInstanceState struct {
+	instance executor.Instance
+	state    executor.InstanceState
+}
+
+func (context *runtimeContext) instanceEvicted(_ interface{}, value interface{}) {
 	instance, ok := value.(executor.Instance)
 	if !ok {
 		return
 	}
 
+	// a reentrant call, or a call to many other contracts, may evict the warm
+	// instance on which an activation lower on the stack is still running;
+	// cleaning it now would destroy the memory and globals of that activation
+	if context.isInstanceInUse(instance) {
+		logRuntime.Trace("evicted instance still in use", "id", instance.Id())
+		context.evictedInstances = append(context.evictedInstances, instance)
+		return
+	}
+
 	logRuntime.Trace("evicted instance", "id", instance.Id())
 	instance.Clean()
 }
 
+func (context *runtimeContext) isInstanceInUse(instance executor.Instance) bool {
+	if instance == context.instance {
+		return true
+	}
+	for _, stackedInstance := range context.instanceStack {
+		if stackedInstance == instance {
+			return true
+		}
+	}
+	return false
+}
+
+// cleanEvictedInstances cleans the evicted warm instances which are no longer
+// used by any activation
+func (context *runtimeContext) cleanEvictedInstances() {
+	stillInUse := context.evictedInstances[:0]
+	for _, instance := range context.evictedInstances {
+		if context.isInstanceInUse(instance) {
+			stillInUse = append(stillInUse, instance)
+			continue
+		}
+
+		logRuntime.Trace("clean evicted instance", "id", instance.Id())
+		instance.Clean()
+	}
+	context.evictedInstances = stillInUse
+}
+
 // InitState initializes all the contexts fields with default data.
 func (context *runtimeContext) InitState() {
 	context.vmInput = &vmcommon.ContractCallInput{}
@@ -103,6 +189,7 @@ func (context *runtimeContext) InitState() {
 	context.readOnly = false
 	context.numRunningInstances = 0
 	context.errors = nil
+	context.infrastructureError = nil
 
 	logRuntime.Trace("init state")
 }
@@ -111,6 +198,7 @@ func (context *runtimeContext) InitState() {
 func (context *runtimeContext) ClearWarmInstanceCache() {
 	context.warmInstanceCache.Clear()
 	context.instance = nil
+	context.cleanEvictedInstances()
 }
 
 // GetVMExecutor yields the configured contract executor.
@@ -129,18 +217,23 @@ func (context *runtimeContext) StartWasmerInstance(contract []byte, gasLimit uin
 	blockchain := context.host.Blockchain()
 	codeHash := blockchain.GetCodeHash(context.codeAddress)
 	context.codeHash = codeHash
+	context.endpointCallability = context.readEndpointCallability(contract)
 
 	defer func() {
+		// the instance replaced at the top of the stack may have been evicted
+		context.cleanEvictedInstances()
 		logRuntime.Trace("warm cache size after starting instance", "size", context.warmInstanceCache.Len())
 	}()
 
 	warmInstanceUsed := context.useWarmInstanceIfExists(gasLimit, newCode)
 	if warmInstanceUsed {
+		context.observeInstanceCreation(arwen.InstanceFromWarmCache)
 		return nil
 	}
 	compiledCodeUsed := context.makeInstanceFromCompiledCode(gasLimit, newCode)
 	if compiledCodeUsed {
 		context.numRunningInstances++
+		context.observeInstanceCreation(arwen.InstanceFromCompiledCode)
 		return nil
 	}
 
@@ -150,34 +243,110 @@ func (context *runtimeContext) StartWasmerInstance(contract []byte, gasLimit uin
 	}
 
 	context.numRunningInstances++
+	context.observeInstanceCreation(arwen.InstanceFromBytecode)
 	return nil
 }
 
-func (context *runtimeContext) makeInstanceFromCompiledCode(gasLimit uint64, newCode bool) bool {
-	if newCode || len(context.codeHash) == 0 {
-		return false
+func (context *runtimeContext) observeInstanceCreation(source arwen.InstanceSource) {
+	atomic.AddUint64(&context.instanceCreations[source], 1)
+	context.host.Metrics().ObserveInstanceCreation(source)
+	contractMetricsSink, ok := context.host.Metrics().(arwen.ContractMetricsSink)
+	if ok && contractMetricsSink.ContractsObserved() {
+		contractMetricsSink.ObserveContractInstanceCreation(context.codeHash, source)
 	}
 
-	blockchain := context.host.Blockchain()
-	found, compiledCode := blockchain.GetCompiledCode(context.codeHash)
-	if !found {
-		logRuntime.Trace("instance creation", "code", "cached compilation", "error", "compiled code was not found")
-		return false
+	meteringModeTracer, ok := context.host.ExecutionTracer().(arwen.MeteringModeTracer)
+	if ok {
+		meteringModeTracer.TraceMeteringMode(context.codeAddress, context.MeteringMode())
 	}
+}
+
+// SetMeteringExemptContracts sets the system contracts whose instances do
+// not charge the gas of the executed opcodes
+func (context *runtimeContext) SetMeteringExemptContracts(addresses [][]byte) {
+	context.meteringExemptions = make(map[string]bool, len(addresses))
+	for _, address := range addresses {
+		context.meteringExemptions[string(address)] = true
+	}
+}
+
+// SetEnableEpochsHandler replaces the activation epochs of the host functions
+// which the imports of the contracts are checked against
+func (context *runtimeContext) SetEnableEpochsHandler(enableEpochsHandler arwen.EnableEpochsHandler) {
+	context.hostFunctions = arwen.NewHostFunctionsActivation(enableEpochsHandler)
+	context.ClearWarmInstanceCache()
+}
+
+// MeteringMode returns the metering mode of the instances of the current contract
+func (context *runtimeContext) MeteringMode() executor.MeteringMode {
+	return context.meteringModeOf(context.codeAddress)
+}
 
+func (context *runtimeContext) meteringModeOf(address []byte) executor.MeteringMode {
+	if context.meteringExemptions[string(address)] {
+		return executor.MeteringDisabled
+	}
+	return executor.MeteringEnabled
+}
+
+// compilationOptions returns the options of the instances of a contract with
+// the given metering mode
+func (context *runtimeContext) compilationOptions(gasLimit uint64, meteringMode executor.MeteringMode) executor.CompilationOptions {
 	gasSchedule := context.host.Metering().GasSchedule()
-	options := executor.CompilationOptions{
+	return executor.CompilationOptions{
 		GasLimit:           gasLimit,
 		UnmeteredLocals:    uint64(gasSchedule.WASMOpcodeCost.LocalsUnmetered),
 		MaxMemoryGrow:      uint64(gasSchedule.WASMOpcodeCost.MaxMemoryGrow),
 		MaxMemoryGrowDelta: uint64(gasSchedule.WASMOpcodeCost.MaxMemoryGrowDelta),
 		OpcodeTrace:        false,
-		Metering:           true,
+		Metering:           meteringMode,
 		RuntimeBreakpoints: true,
+		MemoryGrowPerPage:  uint64(gasSchedule.WASMOpcodeCost.MemoryGrowPerPage),
+		MaxMemoryPages:     uint64(gasSchedule.WASMOpcodeCost.MaxMemoryPages),
+	}
+}
+
+// warmInstanceKey returns the key of the warm instances of a contract code;
+// the unmetered instances of the exempted contracts are kept apart from the
+// metered instances of the same code
+func warmInstanceKey(codeHash []byte, meteringMode executor.MeteringMode) []byte {
+	if meteringMode == executor.MeteringEnabled {
+		return codeHash
+	}
+	key := append([]byte{}, codeHash...)
+	return append(key, unmeteredWarmInstanceSuffix...)
+}
+
+// makeInstanceFromCompiledCode restores a metered instance from the
+// compiled code kept by the node; the compiled code of the node is always
+// metered, so the exempted contracts are compiled from their bytecode instead
+func (context *runtimeContext) makeInstanceFromCompiledCode(gasLimit uint64, newCode bool) bool {
+	if newCode || len(context.codeHash) == 0 {
+		return false
+	}
+	if context.MeteringMode() != executor.MeteringEnabled {
+		return false
+	}
+	_, unrestorable := context.unrestorableCodeHashes[string(context.codeHash)]
+	if unrestorable {
+		return false
+	}
+
+	blockchain := context.host.Blockchain()
+	found, compiledCode := blockchain.GetCompiledCode(context.codeHash)
+	if !found {
+		logRuntime.Trace("instance creation", "code", "cached compilation", "error", "compiled code was not found")
+		return false
 	}
+
+	options := context.compilationOptions(gasLimit, executor.MeteringEnabled)
 	newInstance, err := context.vmExecutor.NewInstanceFromCompiledCodeWithOptions(compiledCode, options)
 	if err != nil {
-		logRuntime.Error("instance creation", "from", "cached compilation", "error", err)
+		// the contract is compiled again from its bytecode instead, which
+		// replaces the compiled code kept by the node
+		logRuntime.Error("instance creation", "from", "cached compilation", "error", fmt.Errorf("%w: %v", arwen.ErrCompiledCodeNotRestored, err))
+		context.unrestorableCodeHashes[string(context.codeHash)] = struct{}{}
+		context.host.Metrics().ObserveInfrastructureError(false)
 		return false
 	}
 
@@ -190,19 +359,33 @@ func (context *runtimeContext) makeInstanceFromCompiledCode(gasLimit uint64, new
 }
 
 func (context *runtimeContext) makeInstanceFromContractByteCode(contract []byte, gasLimit uint64, newCode bool) error {
+	var report *inspect.ContractReport
+	if newCode {
+		var err error
+		report, err = context.inspectNewContractCode(contract)
+		if err != nil {
+			context.instance = nil
+			logRuntime.Trace("instance creation", "from", "bytecode", "error", err)
+			return err
+		}
+	}
+
 	gasSchedule := context.host.Metering().GasSchedule()
-	options := executor.CompilationOptions{
-		GasLimit:           gasLimit,
-		UnmeteredLocals:    uint64(gasSchedule.WASMOpcodeCost.LocalsUnmetered),
-		MaxMemoryGrow:      uint64(gasSchedule.WASMOpcodeCost.MaxMemoryGrow),
-		MaxMemoryGrowDelta: uint64(gasSchedule.WASMOpcodeCost.MaxMemoryGrowDelta),
-		OpcodeTrace:        false,
-		Metering:           true,
-		RuntimeBreakpoints: true,
+	instrumentedContract, err := instrumentStackHeight(contract, gasSchedule.WASMOpcodeCost.MaxStackHeight)
+	if err != nil {
+		context.instance = nil
+		logRuntime.Trace("instance creation", "from", "bytecode", "error", err)
+		return err
 	}
-	newInstance, err := context.vmExecutor.NewInstanceWithOptions(contract, options)
+
+	options := context.compilationOptions(gasLimit, context.MeteringMode())
+	newInstance, err := context.vmExecutor.NewInstanceWithOptions(instrumentedContract, options)
 	if err != nil {
 		context.instance = nil
+		if !newCode && len(instrumentedContract) > 0 {
+			// the code was validated when deployed, so the executor is at fault
+			context.setInfrastructureError(fmt.Errorf("%w: %v", arwen.ErrDeployedCodeNotCompiled, err))
+		}
 		logRuntime.Trace("instance creation", "from", "bytecode", "error", err)
 		return err
 	}
@@ -219,6 +402,13 @@ func (context *runtimeContext) makeInstanceFromContractByteCode(contract []byte,
 	}
 
 	if newCode {
+		err = context.verifyContractEndpoints(report)
+		if err != nil {
+			context.CleanInstance()
+			logRuntime.Trace("instance creation", "from", "bytecode", "error", err)
+			return err
+		}
+
 		err = context.VerifyContractCode()
 		if err != nil {
 			context.CleanInstance()
@@ -238,10 +428,11 @@ func (context *runtimeContext) useWarmInstanceIfExists(gasLimit uint64, newCode
 		return false
 	}
 	if context.isContractOrCodeHashOnTheStack() {
-		return false
+		return context.useSuspendedInstanceIfPossible(gasLimit)
 	}
 
-	cachedObject, ok := context.warmInstanceCache.Get(context.codeHash)
+	key := warmInstanceKey(context.codeHash, context.MeteringMode())
+	cachedObject, ok := context.warmInstanceCache.Get(key)
 	if !ok {
 		return false
 	}
@@ -254,17 +445,73 @@ func (context *runtimeContext) useWarmInstanceIfExists(gasLimit uint64, newCode
 	ok = instance.Reset()
 	if !ok {
 		// we must remove instance, which cleans it to free the memory
-		context.warmInstanceCache.Remove(context.codeHash)
+		context.warmInstanceCache.Remove(key)
 		return false
 	}
 
+	context.startWarmInstance(instance, gasLimit)
+	logRuntime.Trace("start instance", "from", "warm", "id", context.instance.Id())
+	return true
+}
+
+// useSuspendedInstanceIfPossible runs a reentrant activation on the warm
+// instance of the contract, if a suspended activation lower on the stack runs
+// on it and the instance can save its state; the state is restored when the
+// reentrant activation is popped, so each activation observes only its own
+// memory and globals. Otherwise the reentrant activation gets a fresh instance.
+func (context *runtimeContext) useSuspendedInstanceIfPossible(gasLimit uint64) bool {
+	stackLen := len(context.suspendedStates)
+	if stackLen == 0 {
+		return false
+	}
+
+	key := warmInstanceKey(context.codeHash, context.MeteringMode())
+	cachedObject, ok := context.warmInstanceCache.Peek(key)
+	if !ok {
+		return false
+	}
+	instance, ok := cachedObject.(executor.Instance)
+	if !ok || !context.isInstanceInUse(instance) {
+		return false
+	}
+	stateHandler, ok := instance.(executor.InstanceStateHandler)
+	if !ok {
+		return false
+	}
+
+	state, err := stateHandler.SaveState()
+	if err != nil {
+		logRuntime.Trace("save instance state", "id", instance.Id(), "error", err)
+		return false
+	}
+	if !instance.Reset() {
+		context.restoreSuspendedState(&suspendedInstanceState{instance: instance, state: state})
+		return false
+	}
+
+	context.suspendedStates[stackLen-1] = &suspendedInstanceState{instance: instance, state: state}
+	context.startWarmInstance(instance, gasLimit)
+	logRuntime.Trace("start instance", "from", "suspended", "id", context.instance.Id())
+	return true
+}
+
+func (context *runtimeContext) startWarmInstance(instance executor.Instance, gasLimit uint64) {
 	context.instance = instance
 	context.SetPointsUsed(0)
 	context.instance.SetGasLimit(gasLimit)
 	context.SetRuntimeBreakpointValue(arwen.BreakpointNone)
 	context.verifyCode = false
-	logRuntime.Trace("start instance", "from", "warm", "id", context.instance.Id())
-	return true
+}
+
+func (context *runtimeContext) restoreSuspendedState(suspended *suspendedInstanceState) {
+	stateHandler, ok := suspended.instance.(executor.InstanceStateHandler)
+	if !ok {
+		return
+	}
+	err := stateHandler.RestoreState(suspended.state)
+	if err != nil {
+		logRuntime.Error("restore instance state", "id", suspended.instance.Id(), "error", err)
+	}
 }
 
 // GetSCCode returns the SC code of the current SC.
@@ -286,6 +533,11 @@ func (context *runtimeContext) GetSCCodeSize() uint64 {
 }
 
 func (context *runtimeContext) saveCompiledCode() {
+	if context.MeteringMode() != executor.MeteringEnabled {
+		context.saveWarmInstance()
+		return
+	}
+
 	compiledCode, err := context.instance.Cache()
 	if err != nil {
 		logRuntime.Error("getCompiledCode from instance", "error", err)
@@ -294,17 +546,31 @@ func (context *runtimeContext) saveCompiledCode() {
 
 	blockchain := context.host.Blockchain()
 	blockchain.SaveCompiledCode(context.codeHash, compiledCode)
+	delete(context.unrestorableCodeHashes, string(context.codeHash))
 
 	context.saveWarmInstance()
 }
 
+func (context *runtimeContext) setInfrastructureError(err error) {
+	if context.infrastructureError == nil {
+		context.infrastructureError = err
+	}
+	logRuntime.Error("infrastructure error", "contract", context.codeAddress, "error", err)
+}
+
+// InfrastructureError returns the first failure of the executor met during
+// the current transaction, in any of its calls, or nil if there was none
+func (context *runtimeContext) InfrastructureError() error {
+	return context.infrastructureError
+}
+
 func (context *runtimeContext) saveWarmInstance() {
 	if context.isContractOrCodeHashOnTheStack() {
 		return
 	}
 
 	context.warmInstanceCache.Put(
-		context.codeHash,
+		warmInstanceKey(context.codeHash, context.MeteringMode()),
 		context.instance,
 		1,
 	)
@@ -322,6 +588,12 @@ func (context *runtimeContext) SetMaxInstanceStackSize(maxInstances uint64) {
 	context.maxInstanceStackSize = maxInstances
 }
 
+// SetDeterminismChecks sets how the constructs of new contract code which
+// threaten the determinism of its executions are treated
+func (context *runtimeContext) SetDeterminismChecks(checks arwen.DeterminismChecks) {
+	context.determinismChecks = checks
+}
+
 // InitStateFromContractCallInput initializes the state of the runtime context
 // (and the async context) from the provided ContractCallInput.
 func (context *runtimeContext) InitStateFromContractCallInput(input *vmcommon.ContractCallInput) {
@@ -348,10 +620,11 @@ func (context *runtimeContext) SetCustomCallFunction(callFunction string) {
 // includes the currently running Wasmer instance.
 func (context *runtimeContext) PushState() {
 	newState := &runtimeContext{
-		codeAddress:  context.codeAddress,
-		codeHash:     context.codeHash,
-		callFunction: context.callFunction,
-		readOnly:     context.readOnly,
+		codeAddress:         context.codeAddress,
+		codeHash:            context.codeHash,
+		endpointCallability: context.endpointCallability,
+		callFunction:        context.callFunction,
+		readOnly:            context.readOnly,
 	}
 	newState.SetVMInput(context.vmInput)
 
@@ -379,6 +652,7 @@ func (context *runtimeContext) PopSetActiveState() {
 	context.SetVMInput(prevState.vmInput)
 	context.codeAddress = prevState.codeAddress
 	context.codeHash = prevState.codeHash
+	context.endpointCallability = prevState.endpointCallability
 	context.callFunction = prevState.callFunction
 	context.readOnly = prevState.readOnly
 }
@@ -403,6 +677,7 @@ func (context *runtimeContext) ClearStateStack() {
 // pushInstance pushes the current Wasmer instance on the instance stack (separate from the state stack).
 func (context *runtimeContext) pushInstance() {
 	context.instanceStack = append(context.instanceStack, context.instance)
+	context.suspendedStates = append(context.suspendedStates, nil)
 	logRuntime.Trace("pushing instance", "id", context.instance.Id(), "codeHash", context.codeHash)
 
 }
@@ -417,6 +692,18 @@ func (context *runtimeContext) popInstance() {
 
 	prevInstance := context.instanceStack[instanceStackLen-1]
 	context.instanceStack = context.instanceStack[:instanceStackLen-1]
+	suspended := context.suspendedStates[instanceStackLen-1]
+	context.suspendedStates = context.suspendedStates[:instanceStackLen-1]
+
+	if suspended != nil {
+		// the popped activation ran on the instance of a suspended activation,
+		// which resumes on it with its saved state; nothing is cleaned
+		context.restoreSuspendedState(suspended)
+		context.instance = prevInstance
+		context.cleanEvictedInstances()
+		logRuntime.Trace("pop instance", "id", context.instance.Id(), "restored", suspended.instance.Id())
+		return
+	}
 
 	if prevInstance == context.instance {
 		// The current Wasmer instance was previously pushed on the instance stack,
@@ -436,6 +723,7 @@ func (context *runtimeContext) popInstance() {
 	}
 
 	context.instance = prevInstance
+	context.cleanEvictedInstances()
 	logRuntime.Trace("pop instance", "id", context.instance.Id(), "codeHash", context.codeHash)
 }
 
@@ -537,6 +825,12 @@ func (context *runtimeContext) SetCodeAddress(scAddress []byte) {
 	context.codeAddress = scAddress
 }
 
+// GetCodeAddress returns the address of the contract whose code is executed,
+// which differs from the context address when executing on the same context.
+func (context *runtimeContext) GetCodeAddress() []byte {
+	return context.codeAddress
+}
+
 // GetCurrentTxHash returns the hash of the current transaction, as specified by the current VMInput.
 func (context *runtimeContext) GetCurrentTxHash() []byte {
 	return context.vmInput.CurrentTxHash
@@ -547,6 +841,15 @@ func (context *runtimeContext) GetOriginalTxHash() []byte {
 	return context.vmInput.OriginalTxHash
 }
 
+// GetOriginalCallerAddress returns the caller of the outermost call frame,
+// which is the sender of the transaction executed by the host
+func (context *runtimeContext) GetOriginalCallerAddress() []byte {
+	if len(context.stateStack) == 0 {
+		return context.vmInput.CallerAddr
+	}
+	return context.stateStack[0].vmInput.CallerAddr
+}
+
 // GetPrevTxHash returns the hash of the previous transaction, in the case of async calls, as specified by the current VMInput.
 func (context *runtimeContext) GetPrevTxHash() []byte {
 	return context.vmInput.PrevTxHash
@@ -593,6 +896,16 @@ func (context *runtimeContext) FailExecution(err error) {
 		if errors.Is(err, arwen.ErrNotEnoughGas) {
 			breakpoint = arwen.BreakpointOutOfGas
 		}
+		if errors.Is(err, arwen.ErrInternalVMError) {
+			breakpoint = arwen.BreakpointInternalVMError
+			context.host.Output().SetReturnCode(arwen.InternalVMError)
+		}
+		if errors.Is(err, arwen.ErrMaxReturnDataSizeExceeded) {
+			breakpoint = arwen.BreakpointReturnDataTooLarge
+		}
+		if errors.Is(err, arwen.ErrLogLimitExceeded) {
+			breakpoint = arwen.BreakpointTooManyLogs
+		}
 	} else {
 		message = "execution failed"
 		context.AddError(errors.New(message))
@@ -673,6 +986,103 @@ func (context *runtimeContext) VerifyContractCode() error {
 	return nil
 }
 
+// inspectNewContractCode checks new contract code against the contract limits,
+// the active wasm proposals and the determinism checks before it is compiled;
+// the report is nil if no check of the host needs it
+func (context *runtimeContext) inspectNewContractCode(contract []byte) (*inspect.ContractReport, error) {
+	if !context.verifyCode {
+		return nil, nil
+	}
+	currentEpoch := context.host.Blockchain().CurrentEpoch()
+	disabledFeatures := arwen.DisabledWasmFeatures(context.host.EnableEpochsHandler(), currentEpoch)
+	if !context.verifyEndpoints && !context.contractLimits.IsEnabled() && disabledFeatures == inspect.NoFeatures &&
+		context.determinismChecks == arwen.DeterminismChecksDisabled {
+		return nil, nil
+	}
+
+	err := context.validator.verifyCodeSize(contract, context.contractLimits)
+	if err != nil {
+		logRuntime.Trace("verify contract limits", "error", err)
+		return nil, err
+	}
+
+	report, err := context.validator.inspectCode(contract)
+	if err != nil {
+		logRuntime.Trace("inspect contract code", "error", err)
+		return nil, err
+	}
+
+	err = context.validator.verifyLimits(report, context.contractLimits)
+	if err != nil {
+		logRuntime.Trace("verify contract limits", "error", err)
+		return nil, err
+	}
+
+	err = context.validator.verifyFeatures(report, disabledFeatures)
+	if err != nil {
+		logRuntime.Trace("verify wasm features", "error", err)
+		return nil, err
+	}
+
+	err = context.validator.verifyDeterminism(report, context.determinismChecks)
+	if err != nil {
+		logRuntime.Trace("verify determinism", "error", err)
+		return nil, err
+	}
+
+	return report, nil
+}
+
+// instrumentStackHeight adds the stack height metering to the contract code,
+// if the gas schedule limits the stack height; the code hash, and thus the
+// key of the compiled code, remains that of the original code
+func instrumentStackHeight(contract []byte, maxStackHeight uint32) ([]byte, error) {
+	if maxStackHeight == 0 {
+		return contract, nil
+	}
+	return inspect.InstrumentStackHeight(contract, maxStackHeight)
+}
+
+// readEndpointCallability decodes the callers which the contract allows for
+// its endpoints, once the host enforces them; a contract whose callability
+// section cannot be decoded is treated as declaring no rules, the section
+// being already rejected at deployment when the code is inspected
+func (context *runtimeContext) readEndpointCallability(contract []byte) map[string]inspect.Callability {
+	if !context.host.EnableEpochsHandler().IsEndpointCallabilityFlagEnabled() {
+		return nil
+	}
+
+	rules, err := inspect.ReadCallability(contract)
+	if err != nil {
+		logRuntime.Trace("read endpoint callability", "error", err)
+		return nil
+	}
+	return rules
+}
+
+// EndpointCallability returns the callers which the running contract allows
+// for the given endpoint, and false if the contract restricts no callers of it
+func (context *runtimeContext) EndpointCallability(functionName string) (inspect.Callability, bool) {
+	callability, restricted := context.endpointCallability[functionName]
+	return callability, restricted
+}
+
+// verifyContractEndpoints checks the exports of new contract code against the
+// endpoint rules, if the host was configured to enforce them
+func (context *runtimeContext) verifyContractEndpoints(report *inspect.ContractReport) error {
+	if report == nil || !context.verifyEndpoints {
+		return nil
+	}
+
+	err := context.validator.verifyEndpoints(report)
+	if err != nil {
+		logRuntime.Trace("verify contract endpoints", "error", err)
+		return err
+	}
+
+	return nil
+}
+
 // ElrondAPIErrorShouldFailExecution returns true
 func (context *runtimeContext) ElrondAPIErrorShouldFailExecution() bool {
 	return true
@@ -811,7 +1221,115 @@ func (context *runtimeContext) FunctionNameChecked() (string, error) {
 
 // CallSCFunction will execute the function with given name from the loaded contract.
 func (context *runtimeContext) CallSCFunction(functionName string) error {
-	return context.instance.CallFunction(functionName)
+	err := context.verifyHostFunctionsActivation()
+	if err != nil {
+		logRuntime.Trace("call SC function", "error", err)
+		return err
+	}
+
+	if context.host.IsDebugMode() {
+		defer context.reportLeakedHandles(functionName)
+	}
+
+	if !context.isMemoryGrowthLimited() || context.isMemoryGrowthMeteredByInstance() {
+		return context.instance.CallFunction(functionName)
+	}
+
+	memoryPages := context.memoryPages()
+	err = context.instance.CallFunction(functionName)
+	if err != nil {
+		return err
+	}
+
+	return context.checkMemoryGrowth(memoryPages)
+}
+
+// reportLeakedHandles reports, in debug mode, the managed values which the
+// endpoint left held by its handles when it returned, to help the contract
+// authors find the values kept longer than needed
+func (context *runtimeContext) reportLeakedHandles(functionName string) {
+	counts := context.host.ManagedTypes().LiveHandles()
+	if counts.IsZero() {
+		return
+	}
+
+	address := context.GetContextAddress()
+	logRuntime.Debug("leaked managed handles",
+		"sc", address,
+		"function", functionName,
+		"buffers", counts.ManagedBuffers,
+		"big ints", counts.BigInts,
+		"big floats", counts.BigFloats,
+		"bytes", counts.Bytes)
+
+	leakTracer, ok := context.host.ExecutionTracer().(arwen.ManagedHandleLeakTracer)
+	if ok {
+		leakTracer.TraceLeakedHandles(address, functionName, counts)
+	}
+}
+
+// isMemoryGrowthLimited returns true if the gas schedule prices the memory
+// pages or caps their number
+func (context *runtimeContext) isMemoryGrowthLimited() bool {
+	opcodeCosts := context.host.Metering().GasSchedule().WASMOpcodeCost
+	return opcodeCosts.MemoryGrowPerPage > 0 || opcodeCosts.MaxMemoryPages > 0
+}
+
+// isMemoryGrowthMeteredByInstance returns true if the current instance charges
+// and caps the pages itself, at each memory.grow
+func (context *runtimeContext) isMemoryGrowthMeteredByInstance() bool {
+	meter, ok := context.instance.(executor.MemoryGrowthMeter)
+	return ok && meter.MetersMemoryGrowth()
+}
+
+// memoryPages returns the number of pages of the memory of the current instance
+func (context *runtimeContext) memoryPages() uint32 {
+	if !context.instance.HasMemory() {
+		return 0
+	}
+	return context.instance.GetMemory().Length() / arwen.WASMPageSize
+}
+
+// checkMemoryGrowth charges the pages added by memory.grow during the last
+// call of an instance which does not meter them itself, such as those of the
+// wasmer executor, and caps the size of the memory, regardless of the maximum
+// declared by the module; the flat MemoryGrow opcode cost is still charged by
+// the metering middleware
+func (context *runtimeContext) checkMemoryGrowth(pagesBefore uint32) error {
+	opcodeCosts := context.host.Metering().GasSchedule().WASMOpcodeCost
+	pagesAfter := context.memoryPages()
+	if opcodeCosts.MaxMemoryPages > 0 && pagesAfter > opcodeCosts.MaxMemoryPages {
+		logRuntime.Trace("check memory growth", "pages", pagesAfter, "error", arwen.ErrMemoryLimit)
+		context.SetRuntimeBreakpointValue(arwen.BreakpointMemoryLimit)
+		return arwen.ErrMemoryLimit
+	}
+	if pagesAfter <= pagesBefore {
+		return nil
+	}
+
+	gasToUse := math.MulUint64(uint64(pagesAfter-pagesBefore), uint64(opcodeCosts.MemoryGrowPerPage))
+	err := context.host.Metering().UseGasBounded(gasToUse)
+	if err != nil {
+		logRuntime.Trace("check memory growth", "pages", pagesAfter, "error", err)
+		context.SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+		return err
+	}
+
+	return nil
+}
+
+// verifyHostFunctionsActivation rejects the contracts which import host
+// functions that are not yet active, as they would have been rejected when
+// the current epoch was processed originally
+func (context *runtimeContext) verifyHostFunctionsActivation() error {
+	currentEpoch := context.host.Blockchain().CurrentEpoch()
+	for _, functionName := range context.hostFunctions.InactiveHostFunctions(currentEpoch) {
+		if context.instance.IsFunctionImported(functionName) {
+			return fmt.Errorf("%w: %s", arwen.ErrHostFunctionNotActive, functionName)
+		}
+	}
+
+	return nil
 }
 
 // IsFunctionImported returns true if the WASM module imports the specified function.
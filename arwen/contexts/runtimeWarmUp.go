@@ -0,0 +1,99 @@
+package contexts
+
+import (
+	"sync/atomic"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// WarmUpContract keeps an instance of the contract deployed at the given
+// address in the warm instance cache, compiling its code and saving the
+// compiled code to the node unless the node already holds it; it returns the
+// source of the instance and the code hash of the contract. It must not be
+// called while a contract is executing.
+func (context *runtimeContext) WarmUpContract(address []byte) (arwen.InstanceSource, []byte, error) {
+	codeHash := context.host.Blockchain().GetCodeHash(address)
+	if len(codeHash) == 0 {
+		return arwen.InstanceFromBytecode, nil, arwen.ErrContractNotFound
+	}
+
+	meteringMode := context.meteringModeOf(address)
+	key := warmInstanceKey(codeHash, meteringMode)
+	_, isWarm := context.warmInstanceCache.Get(key)
+	if isWarm {
+		return arwen.InstanceFromWarmCache, codeHash, nil
+	}
+
+	instance, source, err := context.newWarmUpInstance(address, codeHash, meteringMode)
+	if err != nil {
+		logRuntime.Debug("warm up contract", "address", address, "error", err)
+		return source, codeHash, err
+	}
+
+	context.warmInstanceCache.Put(key, instance, 1)
+	atomic.AddUint64(&context.warmedUpContracts, 1)
+	logRuntime.Trace("warm up contract", "address", address, "from", source, "id", instance.Id())
+	return source, codeHash, nil
+}
+
+// newWarmUpInstance creates an instance of the contract like the execution
+// would; as there, the exempted contracts neither use nor save the compiled
+// code of the node, which is metered
+func (context *runtimeContext) newWarmUpInstance(address []byte, codeHash []byte, meteringMode executor.MeteringMode) (executor.Instance, arwen.InstanceSource, error) {
+	blockchain := context.host.Blockchain()
+	gasSchedule := context.host.Metering().GasSchedule()
+	options := context.compilationOptions(0, meteringMode)
+	isMetered := meteringMode == executor.MeteringEnabled
+
+	found, compiledCode := blockchain.GetCompiledCode(codeHash)
+	if found && isMetered {
+		instance, err := context.vmExecutor.NewInstanceFromCompiledCodeWithOptions(compiledCode, options)
+		if err == nil {
+			return instance, arwen.InstanceFromCompiledCode, nil
+		}
+		logRuntime.Debug("warm up contract", "from", "cached compilation", "error", err)
+	}
+
+	code, err := blockchain.GetCode(address)
+	if err != nil {
+		return nil, arwen.InstanceFromBytecode, err
+	}
+	instrumentedCode, err := instrumentStackHeight(code, gasSchedule.WASMOpcodeCost.MaxStackHeight)
+	if err != nil {
+		return nil, arwen.InstanceFromBytecode, err
+	}
+	instance, err := context.vmExecutor.NewInstanceWithOptions(instrumentedCode, options)
+	if err != nil {
+		return nil, arwen.InstanceFromBytecode, err
+	}
+
+	if !isMetered {
+		return instance, arwen.InstanceFromBytecode, nil
+	}
+
+	compiledCode, err = instance.Cache()
+	if err != nil {
+		instance.Clean()
+		return nil, arwen.InstanceFromBytecode, err
+	}
+	blockchain.SaveCompiledCode(codeHash, compiledCode)
+
+	return instance, arwen.InstanceFromBytecode, nil
+}
+
+// CacheStatistics returns the number of warm instances and the counters of
+// the instance creations
+func (context *runtimeContext) CacheStatistics() arwen.CacheStatistics {
+	instanceCreations := make(map[arwen.InstanceSource]uint64, numInstanceSources)
+	for source := 0; source < numInstanceSources; source++ {
+		instanceCreations[arwen.InstanceSource(source)] = atomic.LoadUint64(&context.instanceCreations[source])
+	}
+
+	return arwen.CacheStatistics{
+		WarmInstances:         context.warmInstanceCache.Len(),
+		WarmInstancesCapacity: warmCacheSize,
+		InstanceCreations:     instanceCreations,
+		WarmedUpContracts:     atomic.LoadUint64(&context.warmedUpContracts),
+	}
+}
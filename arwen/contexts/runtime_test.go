@@ -51,6 +51,8 @@ func makeDefaultRuntimeContext(t *testing.T, host arwen.VMHost) *runtimeContext
 		vmType,
 		builtInFunctions.NewBuiltInFunctionContainer(),
 		executor,
+		false,
+		arwen.ContractLimits{},
 	)
 	require.Nil(t, err)
 	require.NotNil(t, runtimeContext)
@@ -224,6 +226,115 @@ func TestRuntimeContext_PushPopInstance(t *testing.T) {
 	require.Equal(t, 1, len(runtimeContext.instanceStack))
 }
 
+type cleanCountingInstance struct {
+	*contextmock.InstanceMock
+	cleaned int
+}
+
+func (instance *cleanCountingInstance) Clean() {
+	instance.cleaned++
+}
+
+func TestRuntimeContext_EvictedInstanceCleanedAfterPop(t *testing.T) {
+	host := InitializeArwenAndWasmer()
+	runtimeContext := makeDefaultRuntimeContext(t, host)
+	defer runtimeContext.ClearWarmInstanceCache()
+
+	codeHash := []byte("codeHash")
+	parentInstance := &cleanCountingInstance{InstanceMock: contextmock.NewInstanceMock(nil)}
+	runtimeContext.instance = parentInstance
+	runtimeContext.warmInstanceCache.Put(codeHash, parentInstance, 1)
+	runtimeContext.pushInstance()
+
+	// a nested call evicts the warm instance of its still running parent
+	childInstance := &cleanCountingInstance{InstanceMock: contextmock.NewInstanceMock(nil)}
+	runtimeContext.instance = childInstance
+	runtimeContext.warmInstanceCache.Remove(codeHash)
+	require.Equal(t, 0, parentInstance.cleaned)
+	require.Len(t, runtimeContext.evictedInstances, 1)
+
+	// the parent resumes on its instance, which is still not cleaned
+	runtimeContext.popInstance()
+	require.Equal(t, parentInstance, runtimeContext.instance)
+	require.Equal(t, 0, parentInstance.cleaned)
+
+	// the next transaction replaces the instance, which is cleaned then
+	runtimeContext.instance = nil
+	runtimeContext.cleanEvictedInstances()
+	require.Equal(t, 1, parentInstance.cleaned)
+	require.Len(t, runtimeContext.evictedInstances, 0)
+}
+
+// globalInstance holds a single global, which its state saves and restores
+type globalInstance struct {
+	*contextmock.InstanceMock
+	global int
+}
+
+func (instance *globalInstance) Reset() bool {
+	instance.global = 0
+	return true
+}
+
+func (instance *globalInstance) SaveState() (executor.InstanceState, error) {
+	return instance.global, nil
+}
+
+func (instance *globalInstance) RestoreState(state executor.InstanceState) error {
+	instance.global = state.(int)
+	return nil
+}
+
+func TestRuntimeContext_ReentrantActivationRestoresGlobals(t *testing.T) {
+	host := InitializeArwenAndWasmer()
+	runtimeContext := makeDefaultRuntimeContext(t, host)
+	defer runtimeContext.ClearWarmInstanceCache()
+
+	codeHash := []byte("codeHash")
+	instance := &globalInstance{InstanceMock: contextmock.NewInstanceMock(nil)}
+	runtimeContext.codeHash = codeHash
+	runtimeContext.instance = instance
+	runtimeContext.warmInstanceCache.Put(warmInstanceKey(codeHash, runtimeContext.MeteringMode()), instance, 1)
+
+	// the parent mutates its global, then calls the same contract
+	instance.global = 7
+	runtimeContext.PushState()
+	require.True(t, runtimeContext.useWarmInstanceIfExists(1000, false))
+	require.Equal(t, instance, runtimeContext.instance)
+	require.Equal(t, 0, instance.global)
+
+	// the child mutates the global of the shared instance, then returns
+	instance.global = 9
+	runtimeContext.PopSetActiveState()
+	require.Equal(t, instance, runtimeContext.instance)
+	require.Equal(t, 7, instance.global)
+	require.Len(t, runtimeContext.suspendedStates, 0)
+
+	// the next call observes the original value of the global
+	require.True(t, runtimeContext.useWarmInstanceIfExists(1000, false))
+	require.Equal(t, 0, instance.global)
+}
+
+func TestRuntimeContext_ReentrantActivationWithoutInstanceState(t *testing.T) {
+	host := InitializeArwenAndWasmer()
+	runtimeContext := makeDefaultRuntimeContext(t, host)
+	defer runtimeContext.ClearWarmInstanceCache()
+
+	codeHash := []byte("codeHash")
+	instance := &cleanCountingInstance{InstanceMock: contextmock.NewInstanceMock(nil)}
+	runtimeContext.codeHash = codeHash
+	runtimeContext.instance = instance
+	runtimeContext.warmInstanceCache.Put(warmInstanceKey(codeHash, runtimeContext.MeteringMode()), instance, 1)
+
+	// an instance which cannot save its state is left to its parent
+	runtimeContext.PushState()
+	require.False(t, runtimeContext.useWarmInstanceIfExists(1000, false))
+
+	runtimeContext.PopSetActiveState()
+	require.Equal(t, instance, runtimeContext.instance)
+	require.Equal(t, 0, instance.cleaned)
+}
+
 func TestRuntimeContext_PushPopState(t *testing.T) {
 	host := &contextmock.VMHostMock{}
 	runtimeContext := makeDefaultRuntimeContext(t, host)
@@ -301,6 +412,8 @@ func TestRuntimeContext_CountContractInstancesOnStack(t *testing.T) {
 		vmType,
 		builtInFunctions.NewBuiltInFunctionContainer(),
 		executor,
+		false,
+		arwen.ContractLimits{},
 	)
 
 	vmInput := vmcommon.VMInput{
@@ -406,6 +519,37 @@ func TestRuntimeContext_Instance(t *testing.T) {
 	require.Nil(t, runtimeContext.instance)
 }
 
+func TestRuntimeContext_UnrestorableCompiledCode(t *testing.T) {
+	host := InitializeArwenAndWasmer()
+	world := worldmock.NewMockWorld()
+	host.BlockchainContext, _ = NewBlockchainContext(host, world)
+	runtimeContext := makeDefaultRuntimeContext(t, host)
+	defer runtimeContext.ClearWarmInstanceCache()
+
+	codeHash := []byte("codeHash")
+	world.CompiledCode[string(codeHash)] = []byte("corrupted compiled code")
+	runtimeContext.codeHash = codeHash
+
+	gasLimit := uint64(100000000)
+	require.False(t, runtimeContext.makeInstanceFromCompiledCode(gasLimit, false))
+	require.Contains(t, runtimeContext.unrestorableCodeHashes, string(codeHash))
+	require.Nil(t, runtimeContext.InfrastructureError())
+
+	// the compilation from bytecode replaces the compiled code of the node
+	contractCode := arwen.GetSCCode(counterWasmCode)
+	err := runtimeContext.makeInstanceFromContractByteCode(contractCode, gasLimit, false)
+	require.Nil(t, err)
+	require.NotContains(t, runtimeContext.unrestorableCodeHashes, string(codeHash))
+	require.NotEqual(t, []byte("corrupted compiled code"), world.CompiledCode[string(codeHash)])
+	require.Nil(t, runtimeContext.InfrastructureError())
+
+	runtimeContext.setInfrastructureError(arwen.ErrDeployedCodeNotCompiled)
+	runtimeContext.setInfrastructureError(arwen.ErrCompiledCodeNotRestored)
+	require.Equal(t, arwen.ErrDeployedCodeNotCompiled, runtimeContext.InfrastructureError())
+	runtimeContext.InitState()
+	require.Nil(t, runtimeContext.InfrastructureError())
+}
+
 func TestRuntimeContext_Breakpoints(t *testing.T) {
 	host := InitializeArwenAndWasmer()
 	runtimeContext := makeDefaultRuntimeContext(t, host)
@@ -2,16 +2,15 @@ package contexts
 
 import (
 	"bytes"
-	"errors"
 
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
-	logger "github.com/ElrondNetwork/elrond-go-logger"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 	"github.com/ElrondNetwork/wasm-vm/math"
 )
 
-var logStorage = logger.GetOrCreate("arwen/storage")
+var logStorage = logging.GetOrCreate(logging.SubsystemStorage, "arwen/storage")
 
 const VMStoragePrefix = "VM@"
 
@@ -23,6 +22,10 @@ type storageContext struct {
 	elrondProtectedKeyPrefix      []byte
 	vmProtectedKeyPrefix          []byte
 	arwenStorageProtectionEnabled bool
+	accesses                      *arwen.StorageAccessSet
+	frameWrites                   storageWrites
+	frameWritesStack              []storageWrites
+	limits                        arwen.StorageLimits
 }
 
 // NewStorageContext creates a new storageContext
@@ -32,7 +35,7 @@ func NewStorageContext(
 	elrondProtectedKeyPrefix []byte,
 ) (*storageContext, error) {
 	if len(elrondProtectedKeyPrefix) == 0 {
-		return nil, errors.New("elrondProtectedKeyPrefix cannot be empty")
+		return nil, arwen.ErrEmptyElrondProtectedKeyPrefix
 	}
 
 	if check.IfNil(host) {
@@ -46,21 +49,81 @@ func NewStorageContext(
 		elrondProtectedKeyPrefix:      elrondProtectedKeyPrefix,
 		vmProtectedKeyPrefix:          append(elrondProtectedKeyPrefix, []byte(VMStoragePrefix)...),
 		arwenStorageProtectionEnabled: true,
+		accesses:                      arwen.NewStorageAccessSet(),
+		frameWrites:                   make(storageWrites),
+		frameWritesStack:              make([]storageWrites, 0),
 	}
 
 	return context, nil
 }
 
-// InitState does nothing
+// storageWrites holds the values written by a call frame, and by the
+// successful frames it called, by address and key, until the frame completes
+type storageWrites map[string]map[string][]byte
+
+func (writes storageWrites) write(address []byte, key []byte, value []byte) {
+	values, ok := writes[string(address)]
+	if !ok {
+		values = make(map[string][]byte)
+		writes[string(address)] = values
+	}
+	values[string(key)] = append(make([]byte, 0, len(value)), value...)
+}
+
+func (writes storageWrites) mergeFrom(other storageWrites) {
+	for address, values := range other {
+		for key, value := range values {
+			writes.write([]byte(address), []byte(key), value)
+		}
+	}
+}
+
+// SetStorageLimits sets the bounds of the keys and the values which the
+// contracts write to storage
+func (context *storageContext) SetStorageLimits(limits arwen.StorageLimits) {
+	context.limits = limits
+}
+
+// InitState forgets the storage keys accessed by the previous execution
 func (context *storageContext) InitState() {
+	context.accesses = arwen.NewStorageAccessSet()
+	context.frameWrites = make(storageWrites)
+}
+
+// GetStorageAccesses returns the storage keys read from the node since the
+// start of the execution, by all its call frames, including the failed ones,
+// and the keys written by the call frames committed so far
+func (context *storageContext) GetStorageAccesses() *arwen.StorageAccessSet {
+	return context.accesses
+}
+
+// CommitWrites flushes the values written by the current call frame, and by
+// the successful frames it called, into the storage updates of the output,
+// and adds their keys to the storage accesses of the execution; the host
+// commits the writes of the execution once it succeeded, before building its
+// VMOutput
+func (context *storageContext) CommitWrites() {
+	for address, values := range context.frameWrites {
+		storageUpdates := context.outputStorageUpdates([]byte(address))
+		for key, value := range values {
+			context.changeStorageUpdate([]byte(key), value, storageUpdates)
+			context.accesses.AddWrite([]byte(address), []byte(key))
+		}
+	}
+	context.frameWrites = make(storageWrites)
 }
 
-// PushState appends the current address to the state stack.
+// PushState appends the current address to the state stack and starts
+// buffering the writes of the new call frame apart.
 func (context *storageContext) PushState() {
 	context.stateStack = append(context.stateStack, context.address)
+	context.frameWritesStack = append(context.frameWritesStack, context.frameWrites)
+	context.frameWrites = make(storageWrites)
 }
 
-// PopSetActiveState removes the latest entry from the state stack and sets it as the current address
+// PopSetActiveState removes the latest entry from the state stack and sets it
+// as the current address, discarding the values written by the call frame
+// which failed
 func (context *storageContext) PopSetActiveState() {
 	stateStackLen := len(context.stateStack)
 	if stateStackLen == 0 {
@@ -71,6 +134,20 @@ func (context *storageContext) PopSetActiveState() {
 	context.stateStack = context.stateStack[:stateStackLen-1]
 
 	context.address = prevAddress
+	context.popFrameWrites()
+}
+
+// PopMergeActiveState removes the latest entry from the state stack and sets
+// it as the current address, flushing the values written by the call frame
+// which succeeded into the buffer of its caller
+func (context *storageContext) PopMergeActiveState() {
+	if len(context.stateStack) == 0 {
+		return
+	}
+
+	childWrites := context.frameWrites
+	context.PopSetActiveState()
+	context.frameWrites.mergeFrom(childWrites)
 }
 
 // PopDiscard removes the latest entry from the state stack
@@ -81,11 +158,19 @@ func (context *storageContext) PopDiscard() {
 	}
 
 	context.stateStack = context.stateStack[:stateStackLen-1]
+	context.frameWritesStack = context.frameWritesStack[:len(context.frameWritesStack)-1]
 }
 
 // ClearStateStack clears the state stack from the current context.
 func (context *storageContext) ClearStateStack() {
 	context.stateStack = make([][]byte, 0)
+	context.frameWritesStack = make([]storageWrites, 0)
+}
+
+func (context *storageContext) popFrameWrites() {
+	stackLen := len(context.frameWritesStack)
+	context.frameWrites = context.frameWritesStack[stackLen-1]
+	context.frameWritesStack = context.frameWritesStack[:stackLen-1]
 }
 
 // SetAddress sets the given address as the address for the current context.
@@ -94,12 +179,66 @@ func (context *storageContext) SetAddress(address []byte) {
 	logStorage.Trace("storage under address set", "address", address)
 }
 
-// GetStorageUpdates returns the storage updates for the account mapped to the given address.
+// GetStorageUpdates returns the storage updates for the account mapped to the
+// given address, including the values buffered by the call frames which have
+// not completed yet.
 func (context *storageContext) GetStorageUpdates(address []byte) map[string]*vmcommon.StorageUpdate {
+	outputUpdates := context.outputStorageUpdates(address)
+	bufferedValues := context.bufferedValues(address)
+	if len(bufferedValues) == 0 {
+		return outputUpdates
+	}
+
+	storageUpdates := make(map[string]*vmcommon.StorageUpdate, len(outputUpdates)+len(bufferedValues))
+	for key, update := range outputUpdates {
+		storageUpdates[key] = update
+	}
+	for key, value := range bufferedValues {
+		storageUpdates[key] = &vmcommon.StorageUpdate{
+			Offset:  []byte(key),
+			Data:    value,
+			Written: true,
+		}
+	}
+	return storageUpdates
+}
+
+func (context *storageContext) outputStorageUpdates(address []byte) map[string]*vmcommon.StorageUpdate {
 	account, _ := context.host.Output().GetOutputAccount(address)
 	return account.StorageUpdates
 }
 
+// bufferedValues returns the values buffered for the account by all the call
+// frames on the stack, the latest write of each key prevailing
+func (context *storageContext) bufferedValues(address []byte) map[string][]byte {
+	values := make(map[string][]byte)
+	for _, frameWrites := range context.frameWritesStack {
+		for key, value := range frameWrites[string(address)] {
+			values[key] = value
+		}
+	}
+	for key, value := range context.frameWrites[string(address)] {
+		values[key] = value
+	}
+	return values
+}
+
+// bufferedValue returns the latest value buffered for the key of the account
+// by the call frames on the stack, if any
+func (context *storageContext) bufferedValue(address []byte, key []byte) ([]byte, bool) {
+	value, ok := context.frameWrites[string(address)][string(key)]
+	if ok {
+		return value, true
+	}
+	for i := len(context.frameWritesStack) - 1; i >= 0; i-- {
+		value, ok = context.frameWritesStack[i][string(address)][string(key)]
+		if ok {
+			return value, true
+		}
+	}
+	return nil, false
+}
+
 // GetStorage returns the storage data mapped to the given key.
 func (context *storageContext) GetStorage(key []byte) ([]byte, bool) {
 	value, usedCache := context.GetStorageUnmetered(key)
@@ -112,8 +251,7 @@ func (context *storageContext) GetStorage(key []byte) ([]byte, bool) {
 
 func (context *storageContext) useGasForValueIfNeeded(value []byte, usedCache bool) {
 	metering := context.host.Metering()
-	enableEpochsHandler := context.host.EnableEpochsHandler()
-	gasFlagSet := enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled()
+	gasFlagSet := context.host.VMVersion().IsStorageAPICostOptimized()
 	if !usedCache || !gasFlagSet {
 		costPerByte := metering.GasSchedule().BaseOperationCost.DataCopyPerByte
 		gasToUse := math.MulUint64(costPerByte, uint64(len(value)))
@@ -128,8 +266,7 @@ func (context *storageContext) useExtraGasForKeyIfNeeded(key []byte, usedCache b
 	if extraBytes <= 0 {
 		return
 	}
-	enableEpochsHandler := context.host.EnableEpochsHandler()
-	gasFlagSet := enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled()
+	gasFlagSet := context.host.VMVersion().IsStorageAPICostOptimized()
 	if !gasFlagSet || !usedCache {
 		gasToUse := math.MulUint64(metering.GasSchedule().BaseOperationCost.DataCopyPerByte, uint64(extraBytes))
 		// TODO replace UseGas with UseGasBounded
@@ -175,18 +312,26 @@ func (context *storageContext) GetStorageFromAddressNoChecks(address []byte, key
 func (context *storageContext) getStorageFromAddressUnmetered(address []byte, key []byte) ([]byte, bool) {
 	var value []byte
 
-	enableEpochsHandler := context.host.EnableEpochsHandler()
-	if context.isElrondReservedKey(key) && enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled() {
-		value, _, _ = context.blockChainHook.GetStorageData(address, key)
+	if context.isElrondReservedKey(key) && context.host.VMVersion().IsStorageAPICostOptimized() {
+		value, _ = context.getStorageDataFromHook(address, key)
 		return value, false
 	}
 
-	storageUpdates := context.GetStorageUpdates(address)
+	value, ok := context.bufferedValue(address, key)
+	if ok {
+		return value, true
+	}
+
+	storageUpdates := context.outputStorageUpdates(address)
 	usedCache := true
 	if storageUpdate, ok := storageUpdates[string(key)]; ok {
 		value = storageUpdate.Data
 	} else {
-		value, _, _ = context.blockChainHook.GetStorageData(address, key)
+		var err error
+		value, err = context.getStorageDataFromHook(address, key)
+		if err != nil {
+			return value, false
+		}
 		storageUpdates[string(key)] = &vmcommon.StorageUpdate{
 			Offset: key,
 			Data:   value,
@@ -197,6 +342,21 @@ func (context *storageContext) getStorageFromAddressUnmetered(address []byte, ke
 	return value, usedCache
 }
 
+// getStorageDataFromHook reads the storage from the node; a failure of the
+// node must not be mistaken for an empty value, so it fails the execution,
+// once the StorageHookErrors flag is enabled
+func (context *storageContext) getStorageDataFromHook(address []byte, key []byte) ([]byte, error) {
+	value, _, err := context.blockChainHook.GetStorageData(address, key)
+	if err != nil && context.host.EnableEpochsHandler().IsStorageHookErrorsFlagEnabled() {
+		logStorage.Trace("get storage data", "address", address, "key", key, "error", err)
+		context.host.Runtime().FailExecution(err)
+		return nil, err
+	}
+
+	context.accesses.AddRead(address, key)
+	return value, nil
+}
+
 // GetStorageUnmetered returns the data under the given key.
 func (context *storageContext) GetStorageUnmetered(key []byte) ([]byte, bool) {
 	return context.getStorageFromAddressUnmetered(context.address, key)
@@ -232,6 +392,13 @@ func (context *storageContext) SetStorage(key []byte, value []byte) (arwen.Stora
 	return context.setStorageToAddress(context.address, key, value)
 }
 
+// DeleteStorage removes the entry at the given key, which is the same as
+// storing an empty value: the deletion reaches the VMOutput as a storage update
+// with empty data and the gas for the released bytes is refunded.
+func (context *storageContext) DeleteStorage(key []byte) (arwen.StorageStatus, error) {
+	return context.setStorageToAddress(context.address, key, nil)
+}
+
 // SetProtectedStorageToAddress sets the given value at the given key, for the specified address. This is only used internaly by arwen!
 func (context *storageContext) SetProtectedStorageToAddress(address []byte, key []byte, value []byte) (arwen.StorageStatus, error) {
 	context.disableStorageProtection()
@@ -252,12 +419,15 @@ func (context *storageContext) setStorageToAddress(address []byte, key []byte, v
 	if err != nil {
 		return arwen.StorageUnchanged, err
 	}
+	err = context.checkStorageLimits(key, value)
+	if err != nil {
+		return arwen.StorageUnchanged, err
+	}
 	metering := context.host.Metering()
 
 	length := len(value)
 
-	storageUpdates := context.GetStorageUpdates(address)
-	oldValue, usedCache := context.getOldValue(storageUpdates, key)
+	oldValue, usedCache := context.getOldValue(address, key)
 
 	gasForKey := context.computeGasForKey(key, usedCache)
 	err = metering.UseGasBounded(gasForKey)
@@ -272,7 +442,7 @@ func (context *storageContext) setStorageToAddress(address []byte, key []byte, v
 	deltaBytes := len(value) - len(oldValue)
 	context.addDeltaBytes(deltaBytes)
 
-	context.changeStorageUpdate(key, value, storageUpdates)
+	context.frameWrites.write(address, key, value)
 
 	if len(oldValue) == 0 {
 		return context.storageAdded(length, key, value)
@@ -312,8 +482,7 @@ func (context *storageContext) setStorageToAddressUnmetered(address []byte, key
 		return arwen.StorageUnchanged, err
 	}
 
-	storageUpdates := context.GetStorageUpdates(address)
-	context.changeStorageUpdate(key, value, storageUpdates)
+	context.frameWrites.write(address, key, value)
 
 	logStorage.Trace("storage modified (unmetered)", "key", key, "value", value)
 	return arwen.StorageModified, nil
@@ -335,6 +504,27 @@ func (context *storageContext) checkReservedAndProtection(key []byte) error {
 	return nil
 }
 
+// checkStorageLimits bounds the writes of the contracts, once the storage
+// limits are enabled; the VM writes its own data with the storage protection
+// disabled, and those writes are not bound
+func (context *storageContext) checkStorageLimits(key []byte, value []byte) error {
+	if context.limits == (arwen.StorageLimits{}) || !context.arwenStorageProtectionEnabled {
+		return nil
+	}
+	if !context.host.EnableEpochsHandler().IsStorageLimitsFlagEnabled() {
+		return nil
+	}
+	if context.limits.MaxKeyLength != 0 && uint64(len(key)) > uint64(context.limits.MaxKeyLength) {
+		logStorage.Trace("storage set", "error", arwen.ErrStorageKeyTooLong, "key length", len(key))
+		return arwen.ErrStorageKeyTooLong
+	}
+	if context.limits.MaxValueLength != 0 && uint64(len(value)) > uint64(context.limits.MaxValueLength) {
+		logStorage.Trace("storage set", "error", arwen.ErrStorageValueTooLong, "value length", len(value))
+		return arwen.ErrStorageValueTooLong
+	}
+	return nil
+}
+
 func (context *storageContext) addDeltaBytes(deltaBytes int) {
 	account, _ := context.host.Output().GetOutputAccount(context.address)
 	if deltaBytes > 0 {
@@ -344,6 +534,10 @@ func (context *storageContext) addDeltaBytes(deltaBytes int) {
 	}
 }
 
+// changeStorageUpdate always replaces the update instead of changing it in
+// place, even when the key was already written by the same call frame: the
+// states pushed on the stack of the output context share the updates with the
+// current one, and would otherwise be altered by the writes they must survive
 func (context *storageContext) changeStorageUpdate(key []byte, value []byte, storageUpdates map[string]*vmcommon.StorageUpdate) {
 	length := len(value)
 	newUpdate := &vmcommon.StorageUpdate{
@@ -406,17 +600,22 @@ func (context *storageContext) storageUnchanged(length int, usedCache bool) (arw
 func (context *storageContext) computeGasForUnchangedValue(length int, usedCache bool) uint64 {
 	metering := context.host.Metering()
 	useGas := uint64(0)
-	enableEpochsHandler := context.host.EnableEpochsHandler()
-	if !usedCache || !enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled() {
+	if !usedCache || !context.host.VMVersion().IsStorageAPICostOptimized() {
 		useGas = math.MulUint64(metering.GasSchedule().BaseOperationCost.DataCopyPerByte, uint64(length))
 	}
 	return useGas
 }
 
-func (context *storageContext) getOldValue(storageUpdates map[string]*vmcommon.StorageUpdate, key []byte) ([]byte, bool) {
+func (context *storageContext) getOldValue(address []byte, key []byte) ([]byte, bool) {
+	bufferedValue, ok := context.bufferedValue(address, key)
+	if ok {
+		return bufferedValue, true
+	}
+
 	var oldValue []byte
 	usedCache := true
 	strKey := string(key)
+	storageUpdates := context.outputStorageUpdates(address)
 	if update, ok := storageUpdates[strKey]; !ok {
 		// if it's not in storageUpdates, GetStorageUnmetered() will use blockchain hook for sure
 		oldValue, _ = context.GetStorageUnmetered(key)
@@ -435,9 +634,8 @@ func (context *storageContext) computeGasForKey(key []byte, usedCache bool) uint
 	metering := context.host.Metering()
 	extraBytes := len(key) - arwen.AddressLen
 	extraKeyLenGas := uint64(0)
-	enableEpochsHandler := context.host.EnableEpochsHandler()
 	if extraBytes > 0 &&
-		(!usedCache || !enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled()) {
+		(!usedCache || !context.host.VMVersion().IsStorageAPICostOptimized()) {
 		extraKeyLenGas = math.MulUint64(metering.GasSchedule().BaseOperationCost.DataCopyPerByte, uint64(extraBytes))
 	}
 	return extraKeyLenGas
@@ -446,8 +644,7 @@ func (context *storageContext) computeGasForKey(key []byte, usedCache bool) uint
 // UseGasForStorageLoad - single spot of gas consumption for storage load
 func (context *storageContext) UseGasForStorageLoad(tracedFunctionName string, loadCost uint64, usedCache bool) {
 	metering := context.host.Metering()
-	enableEpochsHandler := context.host.EnableEpochsHandler()
-	if enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled() && usedCache {
+	if context.host.VMVersion().IsStorageAPICostOptimized() && usedCache {
 		loadCost = metering.GasSchedule().ElrondAPICost.CachedStorageLoad
 	}
 
@@ -456,8 +653,7 @@ func (context *storageContext) UseGasForStorageLoad(tracedFunctionName string, l
 
 // IsUseDifferentGasCostFlagSet - getter for flag
 func (context *storageContext) IsUseDifferentGasCostFlagSet() bool {
-	enableEpochsHandler := context.host.EnableEpochsHandler()
-	return enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled()
+	return context.host.VMVersion().IsStorageAPICostOptimized()
 }
 
 // IsInterfaceNil returns true if there is no value under the interface
@@ -468,3 +664,42 @@ func (context *storageContext) IsInterfaceNil() bool {
 func (context *storageContext) GetVmProtectedPrefix(prefix string) []byte {
 	return append(context.vmProtectedKeyPrefix, []byte(prefix)...)
 }
+
+// GetStorageSize returns the number of bytes stored by the given account, keys
+// and values included, as known by the blockchain hook and changed by the
+// storage updates of the current execution. Without a StorageSizeHook, only
+// the bytes added by the current execution are counted.
+func (context *storageContext) GetStorageSize(address []byte) (uint64, error) {
+	size := int64(0)
+	sizeHook, ok := context.blockChainHook.(arwen.StorageSizeHook)
+	if ok {
+		persistedSize, err := sizeHook.GetStorageSize(address)
+		if err != nil {
+			return 0, err
+		}
+		size = int64(persistedSize)
+	}
+
+	for key, update := range context.GetStorageUpdates(address) {
+		if !update.Written {
+			continue
+		}
+		oldValue, _, err := context.blockChainHook.GetStorageData(address, []byte(key))
+		if err != nil {
+			return 0, err
+		}
+		size += storedEntrySize(key, update.Data) - storedEntrySize(key, oldValue)
+	}
+
+	if size < 0 {
+		return 0, nil
+	}
+	return uint64(size), nil
+}
+
+func storedEntrySize(key string, value []byte) int64 {
+	if len(value) == 0 {
+		return 0
+	}
+	return int64(len(key) + len(value))
+}
@@ -279,6 +279,55 @@ func TestStorageContext_SetStorage(t *testing.T) {
 	require.Equal(t, arwen.ErrStoreElrondReservedKey, err)
 }
 
+func TestStorageContext_GetStorageSize(t *testing.T) {
+	t.Parallel()
+
+	address := []byte("account")
+	mockOutput := &contextmock.OutputContextMock{}
+	mockOutput.OutputAccountMock = mockOutput.NewVMOutputAccount(address)
+
+	mockMetering := &contextmock.MeteringContextMock{}
+	mockMetering.SetGasSchedule(config.MakeGasMapForTests())
+	mockMetering.GasLeftMock = 20000
+
+	host := &contextmock.VMHostMock{
+		OutputContext:            mockOutput,
+		MeteringContext:          mockMetering,
+		RuntimeContext:           &contextmock.RuntimeContextMock{},
+		EnableEpochsHandlerField: &worldmock.EnableEpochsHandlerStub{},
+	}
+	mockWorld := worldmock.NewMockWorld()
+	mockWorld.AcctMap.PutAccount(&worldmock.Account{
+		Address: address,
+		Balance: big.NewInt(0),
+		Storage: map[string][]byte{"old": []byte("12345")},
+	})
+	storageContext, _ := NewStorageContext(host, mockWorld, elrondReservedTestPrefix)
+	storageContext.SetAddress(address)
+
+	size, err := storageContext.GetStorageSize(address)
+	require.Nil(t, err)
+	require.Equal(t, uint64(8), size)
+
+	_, err = storageContext.SetStorage([]byte("key"), []byte("value"))
+	require.Nil(t, err)
+	size, err = storageContext.GetStorageSize(address)
+	require.Nil(t, err)
+	require.Equal(t, uint64(16), size)
+
+	_, err = storageContext.SetStorage([]byte("old"), []byte("1"))
+	require.Nil(t, err)
+	size, err = storageContext.GetStorageSize(address)
+	require.Nil(t, err)
+	require.Equal(t, uint64(12), size)
+
+	_, err = storageContext.SetStorage([]byte("old"), nil)
+	require.Nil(t, err)
+	size, err = storageContext.GetStorageSize(address)
+	require.Nil(t, err)
+	require.Equal(t, uint64(8), size)
+}
+
 func TestStorageConext_SetStorage_GasUsage(t *testing.T) {
 	address := []byte("account")
 	mockOutput := &contextmock.OutputContextMock{}
@@ -352,6 +401,66 @@ func TestStorageConext_SetStorage_GasUsage(t *testing.T) {
 	require.Equal(t, value, storedValue)
 }
 
+func TestStorageContext_DeleteStorage(t *testing.T) {
+	t.Parallel()
+
+	address := []byte("account")
+	mockOutput := &contextmock.OutputContextMock{}
+	account := mockOutput.NewVMOutputAccount(address)
+	mockOutput.OutputAccountMock = account
+
+	releaseCost := 5
+	gasMap := config.MakeGasMapForTests()
+	gasMap["BaseOperationCost"]["ReleasePerByte"] = uint64(releaseCost)
+
+	mockMetering := &contextmock.MeteringContextMock{}
+	mockMetering.SetGasSchedule(gasMap)
+	mockMetering.GasLeftMock = 20000
+
+	host := &contextmock.VMHostMock{
+		OutputContext:   mockOutput,
+		MeteringContext: mockMetering,
+		RuntimeContext:  &contextmock.RuntimeContextMock{},
+		EnableEpochsHandlerField: &worldmock.EnableEpochsHandlerStub{
+			IsStorageAPICostOptimizationFlagEnabledField: true,
+		},
+	}
+	bcHook := &contextmock.BlockchainHookStub{}
+	storageContext, _ := NewStorageContext(host, bcHook, elrondReservedTestPrefix)
+	storageContext.SetAddress(address)
+
+	key := []byte("key")
+	value := []byte("value")
+	_, err := storageContext.SetStorage(key, value)
+	require.Nil(t, err)
+
+	storageStatus, err := storageContext.DeleteStorage(key)
+	require.Nil(t, err)
+	require.Equal(t, arwen.StorageDeleted, storageStatus)
+	require.Equal(t, releaseCost*len(value), int(mockMetering.GasFreedMock))
+	require.Equal(t, uint64(len(value)), account.BytesDeletedFromStorage)
+
+	storageUpdate := storageContext.GetStorageUpdates(address)[string(key)]
+	require.True(t, storageUpdate.Written)
+	require.Len(t, storageUpdate.Data, 0)
+	foundValue, _ := storageContext.GetStorage(key)
+	require.Equal(t, []byte{}, foundValue)
+
+	storageStatus, err = storageContext.DeleteStorage(key)
+	require.Nil(t, err)
+	require.Equal(t, arwen.StorageUnchanged, storageStatus)
+	require.Equal(t, releaseCost*len(value), int(mockMetering.GasFreedMock))
+
+	storageStatus, err = storageContext.DeleteStorage([]byte("missing"))
+	require.Nil(t, err)
+	require.Equal(t, arwen.StorageUnchanged, storageStatus)
+	require.False(t, storageContext.GetStorageUpdates(address)["missing"].Written)
+
+	storageStatus, err = storageContext.DeleteStorage([]byte("RESERVEDkey"))
+	require.Equal(t, arwen.StorageUnchanged, storageStatus)
+	require.Equal(t, arwen.ErrStoreElrondReservedKey, err)
+}
+
 func TestStorageContext_StorageProtection(t *testing.T) {
 	address := []byte("account")
 	mockOutput := &contextmock.OutputContextMock{}
@@ -497,3 +606,116 @@ func TestStorageContext_PopDiscardIfStackIsEmptyShouldNotPanic(t *testing.T) {
 
 	require.Equal(t, 0, len(storageContext.stateStack))
 }
+
+func TestStorageContext_FrameWrites(t *testing.T) {
+	t.Parallel()
+
+	address := []byte("account")
+	mockOutput := &contextmock.OutputContextMock{}
+	mockOutput.OutputAccountMock = mockOutput.NewVMOutputAccount(address)
+
+	mockMetering := &contextmock.MeteringContextMock{}
+	mockMetering.SetGasSchedule(config.MakeGasMapForTests())
+	mockMetering.BlockGasLimitMock = uint64(15000)
+	mockMetering.GasLeftMock = 20000
+
+	host := &contextmock.VMHostMock{
+		OutputContext:            mockOutput,
+		MeteringContext:          mockMetering,
+		RuntimeContext:           &contextmock.RuntimeContextMock{},
+		EnableEpochsHandlerField: &worldmock.EnableEpochsHandlerStub{},
+	}
+	storageContext, _ := NewStorageContext(host, &contextmock.BlockchainHookStub{}, elrondReservedTestPrefix)
+	storageContext.SetAddress(address)
+
+	setStorage := func(key string) {
+		_, err := storageContext.SetStorage([]byte(key), []byte("value"))
+		require.Nil(t, err)
+	}
+
+	setStorage("caller")
+	storageContext.PushState()
+	setStorage("failed")
+	storageContext.PopSetActiveState()
+	storageContext.PushState()
+	setStorage("succeeded")
+	storageContext.PopMergeActiveState()
+
+	// the values are buffered until the writes are committed
+	account := mockOutput.OutputAccountMock
+	for _, key := range []string{"caller", "failed", "succeeded"} {
+		require.False(t, account.StorageUpdates[key].Written)
+	}
+	value, _ := storageContext.GetStorage([]byte("succeeded"))
+	require.Equal(t, []byte("value"), value)
+	value, _ = storageContext.GetStorage([]byte("failed"))
+	require.Empty(t, value)
+	require.True(t, storageContext.GetStorageUpdates(address)["succeeded"].Written)
+
+	accesses := storageContext.GetStorageAccesses()
+	require.False(t, accesses.HasWritten(address, []byte("caller")))
+	require.False(t, accesses.HasWritten(address, []byte("succeeded")))
+
+	storageContext.CommitWrites()
+	require.True(t, accesses.HasWritten(address, []byte("caller")))
+	require.True(t, accesses.HasWritten(address, []byte("succeeded")))
+	require.False(t, accesses.HasWritten(address, []byte("failed")))
+	require.Empty(t, storageContext.frameWritesStack)
+	require.True(t, account.StorageUpdates["caller"].Written)
+	require.True(t, account.StorageUpdates["succeeded"].Written)
+	require.Equal(t, []byte("value"), account.StorageUpdates["succeeded"].Data)
+	require.False(t, account.StorageUpdates["failed"].Written)
+
+	storageContext.InitState()
+	setStorage("uncommitted")
+	require.Empty(t, storageContext.GetStorageAccesses().Writes)
+}
+
+func TestStorageContext_StorageLimits(t *testing.T) {
+	t.Parallel()
+
+	address := []byte("account")
+	mockOutput := &contextmock.OutputContextMock{}
+	mockOutput.OutputAccountMock = mockOutput.NewVMOutputAccount(address)
+
+	mockMetering := &contextmock.MeteringContextMock{}
+	mockMetering.SetGasSchedule(config.MakeGasMapForTests())
+	mockMetering.GasLeftMock = 20000
+
+	host := &contextmock.VMHostMock{
+		OutputContext:   mockOutput,
+		MeteringContext: mockMetering,
+		RuntimeContext:  &contextmock.RuntimeContextMock{},
+	}
+	mockWorld := worldmock.NewMockWorld()
+	mockWorld.CurrentBlockInfo = &worldmock.BlockInfo{BlockEpoch: 4}
+	host.EnableEpochsHandlerField = &worldmock.EnableEpochsHandlerStub{
+		StorageLimitsEnableEpochField: 5,
+		CurrentEpochCalled:            mockWorld.CurrentEpoch,
+	}
+	host.BlockchainContext, _ = NewBlockchainContext(host, mockWorld)
+
+	storageContext, _ := NewStorageContext(host, mockWorld, elrondReservedTestPrefix)
+	storageContext.SetAddress(address)
+	storageContext.SetStorageLimits(arwen.StorageLimits{MaxKeyLength: 4, MaxValueLength: 5})
+
+	_, err := storageContext.SetStorage([]byte("long key"), []byte("long value"))
+	require.Nil(t, err)
+
+	mockWorld.CurrentBlockInfo.BlockEpoch = 5
+	storageStatus, err := storageContext.SetStorage([]byte("long key"), []byte("value"))
+	require.Equal(t, arwen.StorageUnchanged, storageStatus)
+	require.Equal(t, arwen.ErrStorageKeyTooLong, err)
+
+	storageStatus, err = storageContext.SetStorage([]byte("key"), []byte("long value"))
+	require.Equal(t, arwen.StorageUnchanged, storageStatus)
+	require.Equal(t, arwen.ErrStorageValueTooLong, err)
+
+	storageStatus, err = storageContext.SetStorage([]byte("key"), []byte("value"))
+	require.Nil(t, err)
+	require.Equal(t, arwen.StorageAdded, storageStatus)
+
+	vmKey := append(storageContext.GetVmProtectedPrefix("limits"), []byte("key")...)
+	_, err = storageContext.SetProtectedStorage(vmKey, []byte("long value"))
+	require.Nil(t, err)
+}
@@ -2,16 +2,20 @@ package contexts
 
 import (
 	"fmt"
+	"sort"
 	"strings"
 
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
 )
 
 const noArity = -1
 const allowedCharsInFunctionName = "abcdefghijklmnopqrstuvwxyz0123456789_"
 
+const maxLengthOfFunctionName = 256
+
 // wasmValidator is a validator for WASM SmartContracts
 type wasmValidator struct {
 	reserved *reservedFunctions
@@ -49,11 +53,13 @@ func (validator *wasmValidator) verifyFunctions(instance executor.Instance) erro
 }
 
 var protectedFunctions = map[string]bool{
-	"internalVMErrors":  true,
-	"transferValueOnly": true,
-	"writeLog":          true,
-	"signalError":       true,
-	"completedTxEvent":  true}
+	"internalVMErrors":                  true,
+	"transferValueOnly":                 true,
+	"writeLog":                          true,
+	"signalError":                       true,
+	"completedTxEvent":                  true,
+	arwen.AsyncCallbackFailedIdentifier: true,
+	arwen.ContractBuildInfoIdentifier:   true}
 
 func (validator *wasmValidator) verifyProtectedFunctions(instance executor.Instance) error {
 	for _, functionName := range instance.GetFunctionNames() {
@@ -67,9 +73,201 @@ func (validator *wasmValidator) verifyProtectedFunctions(instance executor.Insta
 	return nil
 }
 
-func (validator *wasmValidator) verifyValidFunctionName(functionName string) error {
-	const maxLengthOfFunctionName = 256
+// entryPointNames are the exports called by the VM itself; they must be functions
+var entryPointNames = map[string]bool{
+	arwen.InitFunctionName:     true,
+	arwen.CallbackFunctionName: true,
+}
+
+// inspectCode decodes the sections of new contract code for the checks which
+// the executor instance cannot answer
+func (validator *wasmValidator) inspectCode(code []byte) (*inspect.ContractReport, error) {
+	report, err := inspect.Inspect(code, nil)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", arwen.ErrContractInvalid, err)
+	}
+
+	return report, nil
+}
+
+// verifyCodeSize checks the size of the code before anything else is decoded from it
+func (validator *wasmValidator) verifyCodeSize(code []byte, limits arwen.ContractLimits) error {
+	return verifyLimit(arwen.ErrCodeSizeLimitExceeded, uint64(len(code)), limits.MaxCodeSize)
+}
+
+func (validator *wasmValidator) verifyLimits(report *inspect.ContractReport, limits arwen.ContractLimits) error {
+	err := verifyLimit(arwen.ErrFunctionsLimitExceeded, uint64(report.NumFunctions), uint64(limits.MaxFunctions))
+	if err != nil {
+		return err
+	}
+	err = verifyLimit(arwen.ErrDataSegmentsLimitExceeded, uint64(report.NumDataSegments), uint64(limits.MaxDataSegments))
+	if err != nil {
+		return err
+	}
+	err = verifyLimit(arwen.ErrDataSegmentsSizeLimitExceeded, report.DataSegmentsSize, limits.MaxDataSegmentsSize)
+	if err != nil {
+		return err
+	}
+
+	for _, table := range report.Tables {
+		err = verifyLimit(arwen.ErrTableSizeLimitExceeded, uint64(table.Min), uint64(limits.MaxTableSize))
+		if err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// verifyLimit returns the given error if the value exceeds a nonzero limit
+func verifyLimit(errLimitExceeded error, value uint64, limit uint64) error {
+	if limit == 0 || value <= limit {
+		return nil
+	}
+	return fmt.Errorf("%w: %d > %d", errLimitExceeded, value, limit)
+}
+
+// verifyFeatures rejects the contracts which use any of the disabled wasm proposals
+func (validator *wasmValidator) verifyFeatures(report *inspect.ContractReport, disabled inspect.Feature) error {
+	used := report.Features & disabled
+	if used != inspect.NoFeatures {
+		return fmt.Errorf("%w: %s", arwen.ErrWasmFeatureNotActive, used)
+	}
+
+	return nil
+}
+
+// verifyDeterminism refuses or only logs the contracts whose code holds
+// constructs which threaten the determinism of their executions
+func (validator *wasmValidator) verifyDeterminism(report *inspect.ContractReport, checks arwen.DeterminismChecks) error {
+	if checks == arwen.DeterminismChecksDisabled || len(report.DeterminismHazards) == 0 {
+		return nil
+	}
+
+	descriptions := make([]string, len(report.DeterminismHazards))
+	for i, hazard := range report.DeterminismHazards {
+		descriptions[i] = hazard.Kind.String() + " " + hazard.Name
+	}
+	if checks == arwen.DeterminismChecksReject {
+		return fmt.Errorf("%w: %s", arwen.ErrNonDeterministicContract, strings.Join(descriptions, "; "))
+	}
+
+	for _, description := range descriptions {
+		logRuntime.Warn("non-deterministic construct in contract code", "hazard", description)
+	}
+	return nil
+}
+
+// verifyEndpoints checks all the exports of the contract code and reports
+// every broken rule at once, as an EndpointViolationsError
+func (validator *wasmValidator) verifyEndpoints(report *inspect.ContractReport) error {
+	violations := make([]arwen.EndpointViolation, 0)
+	addViolation := func(kind arwen.EndpointViolationKind, name string) {
+		violations = append(violations, arwen.EndpointViolation{Kind: kind, Name: name})
+	}
+
+	exportedNames := make(map[string]bool)
+	for _, exported := range report.Exports {
+		if exportedNames[exported.Name] {
+			addViolation(arwen.EndpointDuplicated, exported.Name)
+			continue
+		}
+		exportedNames[exported.Name] = true
+
+		if exported.Kind == inspect.ExternalMemory && !entryPointNames[exported.Name] {
+			continue
+		}
+		if exported.Kind != inspect.ExternalFunction {
+			addViolation(arwen.EndpointNotAFunction, exported.Name)
+			continue
+		}
+
+		if validator.reserved.IsReserved(exported.Name) || protectedFunctions[exported.Name] {
+			addViolation(arwen.EndpointNameReserved, exported.Name)
+			continue
+		}
+		if validator.verifyValidFunctionName(exported.Name) != nil {
+			addViolation(arwen.EndpointNameInvalid, exported.Name)
+		}
+	}
+
+	_, hasInit := report.Endpoint(arwen.InitFunctionName)
+	if !hasInit {
+		addViolation(arwen.EndpointInitMissing, arwen.InitFunctionName)
+	}
+
+	for _, name := range sortedCallabilityNames(report.EndpointCallability) {
+		_, isEndpoint := report.Endpoint(name)
+		if !isEndpoint {
+			addViolation(arwen.EndpointCallabilityUnknown, name)
+		}
+	}
+
+	for _, name := range sortedPaymentTokensNames(report.EndpointPaymentTokens) {
+		_, isEndpoint := report.Endpoint(name)
+		if !isEndpoint {
+			addViolation(arwen.EndpointPaymentTokensUnknown, name)
+		}
+	}
+
+	if report.EndpointList != nil {
+		verifyEndpointList(report, addViolation)
+	}
 
+	if len(violations) > 0 {
+		return &arwen.EndpointViolationsError{Violations: violations}
+	}
+	return nil
+}
+
+// verifyEndpointList checks that the endpoints section of the contract lists
+// exactly the functions it exports, apart from the entry points of the VM,
+// which it may list or not
+func verifyEndpointList(report *inspect.ContractReport, addViolation func(arwen.EndpointViolationKind, string)) {
+	listedNames := make(map[string]bool, len(report.EndpointList))
+	for _, name := range report.EndpointList {
+		listedNames[name] = true
+		_, isEndpoint := report.Endpoint(name)
+		if !isEndpoint {
+			addViolation(arwen.EndpointListUnknown, name)
+		}
+	}
+
+	for _, exported := range report.Exports {
+		if exported.Kind != inspect.ExternalFunction || entryPointNames[exported.Name] {
+			continue
+		}
+		if !listedNames[exported.Name] {
+			addViolation(arwen.EndpointNotListed, exported.Name)
+			listedNames[exported.Name] = true
+		}
+	}
+}
+
+// sortedCallabilityNames returns the endpoints named in the callability
+// section in ascending order, so that the violations are reported in the same
+// order on every node
+func sortedCallabilityNames(rules map[string]inspect.Callability) []string {
+	names := make([]string, 0, len(rules))
+	for name := range rules {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// sortedPaymentTokensNames returns the endpoints named in the payment tokens
+// section in ascending order, like sortedCallabilityNames
+func sortedPaymentTokensNames(whitelists map[string]inspect.TokenWhitelist) []string {
+	names := make([]string, 0, len(whitelists))
+	for name := range whitelists {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+func (validator *wasmValidator) verifyValidFunctionName(functionName string) error {
 	errInvalidName := fmt.Errorf("%w: %s", arwen.ErrInvalidFunctionName, functionName)
 
 	if len(functionName) == 0 {
@@ -1,12 +1,15 @@
 package contexts
 
 import (
+	"errors"
 	"strings"
 	"testing"
 
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/elrond-vm-common/builtInFunctions"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/arwen/mock"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
 	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	"github.com/stretchr/testify/require"
@@ -70,3 +73,159 @@ func TestFunctionsProtected(t *testing.T) {
 	err := validator.verifyProtectedFunctions(instance)
 	require.NotNil(t, err)
 }
+
+func TestWASMValidator_VerifyEndpoints(t *testing.T) {
+	validator := newWASMValidator(testImportNames(), builtInFunctions.NewBuiltInFunctionContainer())
+
+	report, err := validator.inspectCode(arwen.GetSCCode(counterWasmCode))
+	require.Nil(t, err)
+	require.Nil(t, validator.verifyEndpoints(report))
+
+	code := []byte{
+		0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+		// type section: () -> ()
+		0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+		// function section: three functions of type 0
+		0x03, 0x04, 0x03, 0x00, 0x00, 0x00,
+		// memory section: min 1
+		0x05, 0x03, 0x01, 0x00, 0x01,
+		// global section: immutable i32 initialized with 0
+		0x06, 0x06, 0x01, 0x7f, 0x00, 0x41, 0x00, 0x0b,
+		// export section
+		0x07, 0x3e, 0x07,
+		0x06, 'm', 'e', 'm', 'o', 'r', 'y', 0x02, 0x00,
+		0x03, 'f', 'o', 'o', 0x00, 0x00,
+		0x03, 'f', 'o', 'o', 0x00, 0x01,
+		0x09, 'a', 's', 'y', 'n', 'c', 'C', 'a', 'l', 'l', 0x00, 0x01,
+		0x04, '2', 'b', 'a', 'd', 0x00, 0x02,
+		0x07, 'c', 'o', 'u', 'n', 't', 'e', 'r', 0x03, 0x00,
+		0x08, 'c', 'a', 'l', 'l', 'B', 'a', 'c', 'k', 0x02, 0x00,
+		// code section: three empty bodies
+		0x0a, 0x0a, 0x03, 0x02, 0x00, 0x0b, 0x02, 0x00, 0x0b, 0x02, 0x00, 0x0b,
+	}
+
+	report, err = validator.inspectCode(code)
+	require.Nil(t, err)
+	err = validator.verifyEndpoints(report)
+	require.True(t, errors.Is(err, arwen.ErrInvalidEndpoints))
+	require.True(t, errors.Is(err, arwen.ErrContractInvalid))
+	require.Equal(t, arwen.ErrInvalidEndpoints.Code, arwen.ErrorCodeOf(err))
+
+	violations, ok := arwen.GetEndpointViolations(err)
+	require.True(t, ok)
+	require.Equal(t, []arwen.EndpointViolation{
+		{Kind: arwen.EndpointDuplicated, Name: "foo"},
+		{Kind: arwen.EndpointNameReserved, Name: "asyncCall"},
+		{Kind: arwen.EndpointNameInvalid, Name: "2bad"},
+		{Kind: arwen.EndpointNotAFunction, Name: "counter"},
+		{Kind: arwen.EndpointNotAFunction, Name: arwen.CallbackFunctionName},
+		{Kind: arwen.EndpointInitMissing, Name: arwen.InitFunctionName},
+	}, violations)
+
+	report, err = validator.inspectCode(arwen.GetSCCode(counterWasmCode))
+	require.Nil(t, err)
+	report.EndpointCallability = map[string]inspect.Callability{
+		"increment": inspect.CallableByOwner,
+		"withdraw":  inspect.CallbackOnly,
+	}
+	violations, ok = arwen.GetEndpointViolations(validator.verifyEndpoints(report))
+	require.True(t, ok)
+	require.Equal(t, []arwen.EndpointViolation{
+		{Kind: arwen.EndpointCallabilityUnknown, Name: "withdraw"},
+	}, violations)
+
+	report, err = validator.inspectCode(arwen.GetSCCode(counterWasmCode))
+	require.Nil(t, err)
+	report.EndpointPaymentTokens = map[string]inspect.TokenWhitelist{
+		"increment": {inspect.EGLDTokenIdentifier: {}},
+		"deposit":   {"TKN-0001": {}},
+	}
+	violations, ok = arwen.GetEndpointViolations(validator.verifyEndpoints(report))
+	require.True(t, ok)
+	require.Equal(t, []arwen.EndpointViolation{
+		{Kind: arwen.EndpointPaymentTokensUnknown, Name: "deposit"},
+	}, violations)
+
+	report, err = validator.inspectCode(arwen.GetSCCode(counterWasmCode))
+	require.Nil(t, err)
+	report.EndpointList = []string{"increment", "decrement", "get"}
+	require.Nil(t, validator.verifyEndpoints(report))
+
+	report.EndpointList = []string{arwen.InitFunctionName, "increment", "withdraw"}
+	violations, ok = arwen.GetEndpointViolations(validator.verifyEndpoints(report))
+	require.True(t, ok)
+	require.Equal(t, []arwen.EndpointViolation{
+		{Kind: arwen.EndpointListUnknown, Name: "withdraw"},
+		{Kind: arwen.EndpointNotListed, Name: "decrement"},
+		{Kind: arwen.EndpointNotListed, Name: "get"},
+	}, violations)
+}
+
+func TestWASMValidator_VerifyLimits(t *testing.T) {
+	validator := newWASMValidator(testImportNames(), builtInFunctions.NewBuiltInFunctionContainer())
+	code := arwen.GetSCCode(counterWasmCode)
+	report, err := validator.inspectCode(code)
+	require.Nil(t, err)
+
+	require.Nil(t, validator.verifyCodeSize(code, arwen.ContractLimits{}))
+	require.Nil(t, validator.verifyLimits(report, arwen.ContractLimits{}))
+	require.Nil(t, validator.verifyLimits(report, arwen.ContractLimits{
+		MaxFunctions:        4,
+		MaxDataSegments:     1,
+		MaxDataSegmentsSize: report.DataSegmentsSize,
+	}))
+
+	err = validator.verifyCodeSize(code, arwen.ContractLimits{MaxCodeSize: uint64(len(code)) - 1})
+	require.True(t, errors.Is(err, arwen.ErrCodeSizeLimitExceeded))
+	require.True(t, errors.Is(err, arwen.ErrContractInvalid))
+
+	err = validator.verifyLimits(report, arwen.ContractLimits{MaxFunctions: 3})
+	require.True(t, errors.Is(err, arwen.ErrFunctionsLimitExceeded))
+	require.Equal(t, arwen.ErrFunctionsLimitExceeded.Code, arwen.ErrorCodeOf(err))
+
+	err = validator.verifyLimits(report, arwen.ContractLimits{MaxDataSegmentsSize: report.DataSegmentsSize - 1})
+	require.True(t, errors.Is(err, arwen.ErrDataSegmentsSizeLimitExceeded))
+
+	report.NumDataSegments = 2
+	report.Tables = []*inspect.Limits{{Min: 10}}
+	err = validator.verifyLimits(report, arwen.ContractLimits{MaxDataSegments: 1})
+	require.True(t, errors.Is(err, arwen.ErrDataSegmentsLimitExceeded))
+	err = validator.verifyLimits(report, arwen.ContractLimits{MaxTableSize: 9})
+	require.True(t, errors.Is(err, arwen.ErrTableSizeLimitExceeded))
+}
+
+func TestWASMValidator_VerifyFeatures(t *testing.T) {
+	validator := newWASMValidator(testImportNames(), builtInFunctions.NewBuiltInFunctionContainer())
+	report := &inspect.ContractReport{
+		Features: inspect.FeatureSignExtension | inspect.FeatureBulkMemory,
+	}
+
+	require.Nil(t, validator.verifyFeatures(report, inspect.NoFeatures))
+	require.Nil(t, validator.verifyFeatures(report, inspect.FeatureReferenceTypes))
+
+	err := validator.verifyFeatures(report, inspect.FeatureBulkMemory|inspect.FeatureReferenceTypes)
+	require.True(t, errors.Is(err, arwen.ErrWasmFeatureNotActive))
+	require.True(t, errors.Is(err, arwen.ErrContractInvalid))
+	require.Equal(t, arwen.ErrWasmFeatureNotActive.Error()+": bulk-memory", err.Error())
+}
+
+func TestWASMValidator_VerifyDeterminism(t *testing.T) {
+	validator := newWASMValidator(testImportNames(), builtInFunctions.NewBuiltInFunctionContainer())
+	report := &inspect.ContractReport{
+		DeterminismHazards: []inspect.DeterminismHazard{
+			{Kind: inspect.HazardClockImport, Name: "wasi_snapshot_preview1.clock_time_get"},
+			{Kind: inspect.HazardMutableExportedGlobal, Name: "counter"},
+		},
+	}
+
+	require.Nil(t, validator.verifyDeterminism(report, arwen.DeterminismChecksDisabled))
+	require.Nil(t, validator.verifyDeterminism(report, arwen.DeterminismChecksWarn))
+
+	err := validator.verifyDeterminism(report, arwen.DeterminismChecksReject)
+	require.True(t, errors.Is(err, arwen.ErrNonDeterministicContract))
+	require.True(t, errors.Is(err, arwen.ErrContractInvalid))
+	require.Equal(t, arwen.ErrNonDeterministicContract.Error()+": clock import wasi_snapshot_preview1.clock_time_get; mutable exported global counter", err.Error())
+
+	report.DeterminismHazards = make([]inspect.DeterminismHazard, 0)
+	require.Nil(t, validator.verifyDeterminism(report, arwen.DeterminismChecksReject))
+}
@@ -0,0 +1,164 @@
+package contexts
+
+import (
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// MarshalVMOutput serializes the whole VMOutput, with its output accounts,
+// storage updates, transfers and logs, in the protobuf format of
+// SerializableVMOutput, so that the integration layers and the recorders
+// exchange the VMOutputs in one stable format
+func MarshalVMOutput(vmOutput *vmcommon.VMOutput) ([]byte, error) {
+	return VMOutputToSerializable(vmOutput).Marshal()
+}
+
+// UnmarshalVMOutput deserializes a VMOutput serialized by MarshalVMOutput
+func UnmarshalVMOutput(data []byte) (*vmcommon.VMOutput, error) {
+	serializedVMOutput := &SerializableVMOutput{}
+	err := serializedVMOutput.Unmarshal(data)
+	if err != nil {
+		return nil, err
+	}
+	return VMOutputFromSerializable(serializedVMOutput), nil
+}
+
+// VMOutputToSerializable converts the whole VMOutput to its protobuf message
+func VMOutputToSerializable(vmOutput *vmcommon.VMOutput) *SerializableVMOutput {
+	if vmOutput == nil {
+		return &SerializableVMOutput{}
+	}
+
+	serializedVMOutput := &SerializableVMOutput{
+		ReturnData:      vmOutput.ReturnData,
+		ReturnCode:      uint64(vmOutput.ReturnCode),
+		ReturnMessage:   vmOutput.ReturnMessage,
+		GasRemaining:    vmOutput.GasRemaining,
+		GasRefund:       vmOutput.GasRefund,
+		OutputAccounts:  make(map[string]*SerializableOutputAccount, len(vmOutput.OutputAccounts)),
+		DeletedAccounts: vmOutput.DeletedAccounts,
+		TouchedAccounts: vmOutput.TouchedAccounts,
+		Logs:            make([]*SerializableLogEntry, 0, len(vmOutput.Logs)),
+	}
+	for address, outputAccount := range vmOutput.OutputAccounts {
+		serializedVMOutput.OutputAccounts[address] = outputAccountToSerializable(outputAccount)
+	}
+	for _, logEntry := range vmOutput.Logs {
+		serializedVMOutput.Logs = append(serializedVMOutput.Logs, &SerializableLogEntry{
+			Identifier: logEntry.Identifier,
+			Address:    logEntry.Address,
+			Topics:     logEntry.Topics,
+			Data:       logEntry.Data,
+		})
+	}
+	return serializedVMOutput
+}
+
+// VMOutputFromSerializable converts a protobuf message built by
+// VMOutputToSerializable back to a VMOutput
+func VMOutputFromSerializable(serializedVMOutput *SerializableVMOutput) *vmcommon.VMOutput {
+	vmOutput := &vmcommon.VMOutput{
+		ReturnData:      serializedVMOutput.ReturnData,
+		ReturnCode:      vmcommon.ReturnCode(serializedVMOutput.ReturnCode),
+		ReturnMessage:   serializedVMOutput.ReturnMessage,
+		GasRemaining:    serializedVMOutput.GasRemaining,
+		GasRefund:       nonNilBigInt(serializedVMOutput.GasRefund),
+		OutputAccounts:  make(map[string]*vmcommon.OutputAccount, len(serializedVMOutput.OutputAccounts)),
+		DeletedAccounts: serializedVMOutput.DeletedAccounts,
+		TouchedAccounts: serializedVMOutput.TouchedAccounts,
+		Logs:            make([]*vmcommon.LogEntry, 0, len(serializedVMOutput.Logs)),
+	}
+	for address, serializedAccount := range serializedVMOutput.OutputAccounts {
+		vmOutput.OutputAccounts[address] = outputAccountFromSerializable(serializedAccount)
+	}
+	for _, serializedLogEntry := range serializedVMOutput.Logs {
+		vmOutput.Logs = append(vmOutput.Logs, &vmcommon.LogEntry{
+			Identifier: serializedLogEntry.Identifier,
+			Address:    serializedLogEntry.Address,
+			Topics:     serializedLogEntry.Topics,
+			Data:       serializedLogEntry.Data,
+		})
+	}
+	return vmOutput
+}
+
+func outputAccountToSerializable(outputAccount *vmcommon.OutputAccount) *SerializableOutputAccount {
+	serializedAccount := &SerializableOutputAccount{
+		Address:                 outputAccount.Address,
+		Nonce:                   outputAccount.Nonce,
+		Balance:                 outputAccount.Balance,
+		StorageUpdates:          make(map[string]*SerializableStorageUpdate, len(outputAccount.StorageUpdates)),
+		Code:                    outputAccount.Code,
+		CodeMetadata:            outputAccount.CodeMetadata,
+		CodeDeployerAddress:     outputAccount.CodeDeployerAddress,
+		BalanceDelta:            outputAccount.BalanceDelta,
+		OutputTransfers:         make([]*SerializableOutputTransfer, 0, len(outputAccount.OutputTransfers)),
+		GasUsed:                 outputAccount.GasUsed,
+		BytesAddedToStorage:     outputAccount.BytesAddedToStorage,
+		BytesDeletedFromStorage: outputAccount.BytesDeletedFromStorage,
+	}
+	for key, storageUpdate := range outputAccount.StorageUpdates {
+		serializedAccount.StorageUpdates[key] = &SerializableStorageUpdate{
+			Offset:  storageUpdate.Offset,
+			Data:    storageUpdate.Data,
+			Written: storageUpdate.Written,
+		}
+	}
+	for _, transfer := range outputAccount.OutputTransfers {
+		serializedAccount.OutputTransfers = append(serializedAccount.OutputTransfers, &SerializableOutputTransfer{
+			Value:         transfer.Value,
+			GasLimit:      transfer.GasLimit,
+			GasLocked:     transfer.GasLocked,
+			Data:          transfer.Data,
+			CallType:      SerializableCallType(transfer.CallType),
+			SenderAddress: transfer.SenderAddress,
+			AsyncData:     transfer.AsyncData,
+		})
+	}
+	return serializedAccount
+}
+
+func outputAccountFromSerializable(serializedAccount *SerializableOutputAccount) *vmcommon.OutputAccount {
+	outputAccount := &vmcommon.OutputAccount{
+		Address:                 serializedAccount.Address,
+		Nonce:                   serializedAccount.Nonce,
+		Balance:                 serializedAccount.Balance,
+		StorageUpdates:          make(map[string]*vmcommon.StorageUpdate, len(serializedAccount.StorageUpdates)),
+		Code:                    serializedAccount.Code,
+		CodeMetadata:            serializedAccount.CodeMetadata,
+		CodeDeployerAddress:     serializedAccount.CodeDeployerAddress,
+		BalanceDelta:            nonNilBigInt(serializedAccount.BalanceDelta),
+		OutputTransfers:         make([]vmcommon.OutputTransfer, 0, len(serializedAccount.OutputTransfers)),
+		GasUsed:                 serializedAccount.GasUsed,
+		BytesAddedToStorage:     serializedAccount.BytesAddedToStorage,
+		BytesDeletedFromStorage: serializedAccount.BytesDeletedFromStorage,
+	}
+	for key, serializedUpdate := range serializedAccount.StorageUpdates {
+		outputAccount.StorageUpdates[key] = &vmcommon.StorageUpdate{
+			Offset:  serializedUpdate.Offset,
+			Data:    serializedUpdate.Data,
+			Written: serializedUpdate.Written,
+		}
+	}
+	for _, serializedTransfer := range serializedAccount.OutputTransfers {
+		outputAccount.OutputTransfers = append(outputAccount.OutputTransfers, vmcommon.OutputTransfer{
+			Value:         nonNilBigInt(serializedTransfer.Value),
+			GasLimit:      serializedTransfer.GasLimit,
+			GasLocked:     serializedTransfer.GasLocked,
+			Data:          serializedTransfer.Data,
+			CallType:      vm.CallType(serializedTransfer.CallType),
+			SenderAddress: serializedTransfer.SenderAddress,
+			AsyncData:     serializedTransfer.AsyncData,
+		})
+	}
+	return outputAccount
+}
+
+func nonNilBigInt(value *big.Int) *big.Int {
+	if value == nil {
+		return big.NewInt(0)
+	}
+	return value
+}
@@ -0,0 +1,136 @@
+package contexts
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+func TestMarshalVMOutput_RoundTrip(t *testing.T) {
+	t.Parallel()
+
+	vmOutput := createSerializationVMOutput()
+
+	serialized, err := MarshalVMOutput(vmOutput)
+	require.Nil(t, err)
+
+	deserialized, err := UnmarshalVMOutput(serialized)
+	require.Nil(t, err)
+	require.True(t, VMOutputToSerializable(vmOutput).Equal(VMOutputToSerializable(deserialized)))
+
+	require.Equal(t, vmOutput.ReturnCode, deserialized.ReturnCode)
+	require.Equal(t, vmOutput.ReturnData, deserialized.ReturnData)
+	require.Len(t, deserialized.Logs, 2)
+	require.Equal(t, []byte("second"), deserialized.Logs[1].Identifier)
+
+	account := deserialized.OutputAccounts["contract"]
+	require.Equal(t, big.NewInt(-5), account.BalanceDelta)
+	require.Equal(t, uint64(7), account.BytesAddedToStorage)
+	require.Equal(t, uint64(3), account.BytesDeletedFromStorage)
+	require.Equal(t, []byte("value"), account.StorageUpdates["key"].Data)
+	require.True(t, account.StorageUpdates["key"].Written)
+
+	require.Len(t, account.OutputTransfers, 1)
+	transfer := account.OutputTransfers[0]
+	require.Equal(t, big.NewInt(10), transfer.Value)
+	require.Equal(t, vm.AsynchronousCall, transfer.CallType)
+	require.Equal(t, []byte("asyncData"), transfer.AsyncData)
+}
+
+func TestMarshalVMOutput_Deterministic(t *testing.T) {
+	t.Parallel()
+
+	vmOutput := createSerializationVMOutput()
+	vmOutput.OutputAccounts["other"] = &vmcommon.OutputAccount{
+		Address:      []byte("other"),
+		BalanceDelta: big.NewInt(5),
+	}
+
+	first, err := MarshalVMOutput(vmOutput)
+	require.Nil(t, err)
+	for i := 0; i < 10; i++ {
+		serialized, err := MarshalVMOutput(vmOutput)
+		require.Nil(t, err)
+		require.Equal(t, first, serialized)
+	}
+}
+
+func TestUnmarshalVMOutput_InvalidData(t *testing.T) {
+	t.Parallel()
+
+	deserialized, err := UnmarshalVMOutput([]byte{0x0a, 0xff})
+	require.Nil(t, deserialized)
+	require.NotNil(t, err)
+}
+
+func TestVMOutputToSerializable_EmptyVMOutput(t *testing.T) {
+	t.Parallel()
+
+	serialized, err := MarshalVMOutput(nil)
+	require.Nil(t, err)
+
+	deserialized, err := UnmarshalVMOutput(serialized)
+	require.Nil(t, err)
+	require.Equal(t, vmcommon.Ok, deserialized.ReturnCode)
+	require.NotNil(t, deserialized.GasRefund)
+	require.Len(t, deserialized.OutputAccounts, 0)
+}
+
+func createSerializationVMOutput() *vmcommon.VMOutput {
+	return &vmcommon.VMOutput{
+		ReturnData:    [][]byte{[]byte("result")},
+		ReturnCode:    vmcommon.UserError,
+		ReturnMessage: "error",
+		GasRemaining:  100,
+		GasRefund:     big.NewInt(20),
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			"contract": {
+				Address:      []byte("contract"),
+				Nonce:        2,
+				Balance:      big.NewInt(1000),
+				BalanceDelta: big.NewInt(-5),
+				StorageUpdates: map[string]*vmcommon.StorageUpdate{
+					"key": {
+						Offset:  []byte("key"),
+						Data:    []byte("value"),
+						Written: true,
+					},
+				},
+				Code:                    []byte("code"),
+				CodeMetadata:            []byte{1, 0},
+				CodeDeployerAddress:     []byte("deployer"),
+				GasUsed:                 50,
+				BytesAddedToStorage:     7,
+				BytesDeletedFromStorage: 3,
+				OutputTransfers: []vmcommon.OutputTransfer{
+					{
+						Value:         big.NewInt(10),
+						GasLimit:      30,
+						GasLocked:     20,
+						Data:          []byte("function@01"),
+						CallType:      vm.AsynchronousCall,
+						SenderAddress: []byte("sender"),
+						AsyncData:     []byte("asyncData"),
+					},
+				},
+			},
+		},
+		DeletedAccounts: [][]byte{[]byte("deleted")},
+		TouchedAccounts: [][]byte{[]byte("touched")},
+		Logs: []*vmcommon.LogEntry{
+			{
+				Identifier: []byte("first"),
+				Address:    []byte("contract"),
+				Topics:     [][]byte{[]byte("topic")},
+				Data:       []byte("data"),
+			},
+			{
+				Identifier: []byte("second"),
+				Address:    []byte("contract"),
+			},
+		},
+	}
+}
@@ -0,0 +1,8 @@
+//go:build !arwendebug
+// +build !arwendebug
+
+package arwen
+
+// DebugAssertions is enabled by building with the arwendebug tag; it makes
+// the panics of the host code abort the process instead of failing the execution
+const DebugAssertions = false
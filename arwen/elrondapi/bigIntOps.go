@@ -10,6 +10,7 @@ import (
 
 const (
 	bigIntNewName                     = "bigIntNew"
+	bigIntDropName                    = "bigIntDrop"
 	bigIntUnsignedByteLengthName      = "bigIntUnsignedByteLength"
 	bigIntSignedByteLengthName        = "bigIntSignedByteLength"
 	bigIntGetUnsignedBytesName        = "bigIntGetUnsignedBytes"
@@ -241,6 +242,21 @@ func (context *ElrondApi) BigIntNew(smallValue int64) int32 {
 	return managedType.NewBigIntFromInt64(smallValue)
 }
 
+// BigIntDrop VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) BigIntDrop(referenceHandle int32) {
+	managedType := context.GetManagedTypesContext()
+	metering := context.GetMeteringContext()
+	runtime := context.GetRuntimeContext()
+
+	gasToUse := metering.GasSchedule().BigIntAPICost.BigIntNew
+	metering.UseGasAndAddTracedGas(bigIntDropName, gasToUse)
+
+	if !managedType.DropBigInt(referenceHandle) {
+		_ = context.WithFault(arwen.ErrNoBigIntUnderThisHandle, runtime.BigIntAPIErrorShouldFailExecution())
+	}
+}
+
 // BigIntUnsignedByteLength VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) BigIntUnsignedByteLength(referenceHandle int32) int32 {
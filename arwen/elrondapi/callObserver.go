@@ -0,0 +1,20 @@
+package elrondapi
+
+import (
+	"time"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ executor.VMHookCallObserver = (*ElrondApi)(nil)
+
+// VMHookCallsObserved returns true if the MetricsSink of the host measures
+// the duration of the host function calls
+func (context *ElrondApi) VMHookCallsObserved() bool {
+	return context.host.Metrics().HostFunctionCallsObserved()
+}
+
+// ObserveVMHookCall reports the duration of a host function call to the MetricsSink of the host
+func (context *ElrondApi) ObserveVMHookCall(hookName string, duration time.Duration) {
+	context.host.Metrics().ObserveHostFunctionCall(hookName, duration)
+}
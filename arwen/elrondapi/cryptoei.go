@@ -75,22 +75,27 @@ func (context *ElrondApi) Sha256(dataOffset int32, length int32, resultOffset in
 // ManagedSha256 VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) ManagedSha256(inputHandle, outputHandle int32) int32 {
-	managedType := context.GetManagedTypesContext()
-	runtime := context.GetRuntimeContext()
-	crypto := context.GetCryptoContext()
-	metering := context.GetMeteringContext()
+	host := context.GetVMHost()
+	return ManagedSha256WithHost(host, inputHandle, outputHandle)
+}
+
+func ManagedSha256WithHost(host arwen.VMHost, inputHandle int32, outputHandle int32) int32 {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	crypto := host.Crypto()
 
 	metering.UseGasAndAddTracedGas(sha256Name, metering.GasSchedule().CryptoAPICost.SHA256)
 
 	inputBytes, err := managedType.GetBytes(inputHandle)
-	if context.WithFault(err, runtime.ManagedBufferAPIErrorShouldFailExecution()) {
+	if WithFaultAndHost(host, err, runtime.ManagedBufferAPIErrorShouldFailExecution()) {
 		return 1
 	}
 	managedType.ConsumeGasForBytes(inputBytes)
 
 	resultBytes, err := crypto.Sha256(inputBytes)
 	if err != nil {
-		context.WithFault(err, runtime.CryptoAPIErrorShouldFailExecution())
+		WithFaultAndHost(host, err, runtime.CryptoAPIErrorShouldFailExecution())
 		return 1
 	}
 
@@ -132,22 +137,27 @@ func (context *ElrondApi) Keccak256(dataOffset int32, length int32, resultOffset
 // ManagedKeccak256 VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) ManagedKeccak256(inputHandle, outputHandle int32) int32 {
-	managedType := context.GetManagedTypesContext()
-	runtime := context.GetRuntimeContext()
-	crypto := context.GetCryptoContext()
-	metering := context.GetMeteringContext()
+	host := context.GetVMHost()
+	return ManagedKeccak256WithHost(host, inputHandle, outputHandle)
+}
+
+func ManagedKeccak256WithHost(host arwen.VMHost, inputHandle int32, outputHandle int32) int32 {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	crypto := host.Crypto()
 
 	metering.UseGasAndAddTracedGas(keccak256Name, metering.GasSchedule().CryptoAPICost.Keccak256)
 
 	inputBytes, err := managedType.GetBytes(inputHandle)
-	if context.WithFault(err, runtime.ManagedBufferAPIErrorShouldFailExecution()) {
+	if WithFaultAndHost(host, err, runtime.ManagedBufferAPIErrorShouldFailExecution()) {
 		return 1
 	}
 	managedType.ConsumeGasForBytes(inputBytes)
 
 	resultBytes, err := crypto.Keccak256(inputBytes)
 	if err != nil {
-		context.WithFault(err, runtime.CryptoAPIErrorShouldFailExecution())
+		WithFaultAndHost(host, err, runtime.CryptoAPIErrorShouldFailExecution())
 		return 1
 	}
 
@@ -10,10 +10,10 @@ import (
 	"github.com/ElrondNetwork/elrond-go-core/core"
 	"github.com/ElrondNetwork/elrond-go-core/data/esdt"
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
-	logger "github.com/ElrondNetwork/elrond-go-logger"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/elrond-vm-common/parsers"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 	"github.com/ElrondNetwork/wasm-vm/math"
 )
 
@@ -25,6 +25,7 @@ const (
 	getExternalBalanceName           = "getExternalBalance"
 	blockHashName                    = "blockHash"
 	transferValueName                = "transferValue"
+	refundGasToCallerName            = "refundGasToCaller"
 	transferESDTExecuteName          = "transferESDTExecute"
 	transferESDTNFTExecuteName       = "transferESDTNFTExecute"
 	multiTransferESDTNFTExecuteName  = "multiTransferESDTNFTExecute"
@@ -32,11 +33,15 @@ const (
 	createAsyncCallName              = "createAsyncCall"
 	setAsyncGroupCallbackName        = "setAsyncGroupCallback"
 	setAsyncContextCallbackName      = "setAsyncContextCallback"
+	createPromiseGroupName           = "createPromiseGroup"
+	createPromiseName                = "createPromise"
 	getArgumentLengthName            = "getArgumentLength"
 	getArgumentName                  = "getArgument"
+	copyArgumentToName               = "copyArgumentTo"
 	getFunctionName                  = "getFunction"
 	getNumArgumentsName              = "getNumArguments"
 	storageStoreName                 = "storageStore"
+	storageDeleteName                = "storageDelete"
 	storageLoadLengthName            = "storageLoadLength"
 	storageLoadName                  = "storageLoad"
 	storageLoadFromAddressName       = "storageLoadFromAddress"
@@ -59,6 +64,9 @@ const (
 	writeEventLogName                = "writeEventLog"
 	returnDataName                   = "returnData"
 	signalErrorName                  = "signalError"
+	signalTypedErrorName             = "signalTypedError"
+	debugPrintName                   = "debugPrint"
+	gasPhaseName                     = "gasPhase"
 	getGasLeftName                   = "getGasLeft"
 	getESDTBalanceName               = "getESDTBalance"
 	getESDTNFTNameLengthName         = "getESDTNFTNameLength"
@@ -101,7 +109,8 @@ const (
 	getPrevTxHashName                = "getPrevTxHash"
 )
 
-var logEEI = logger.GetOrCreate("arwen/eei")
+var logEEI = logging.GetOrCreate(logging.SubsystemHostFunctions, "arwen/eei")
+var logDebugPrint = logging.GetOrCreate(logging.SubsystemContracts, "arwen/debugPrint")
 
 func getESDTTransferFromInputFailIfWrongIndex(host arwen.VMHost, index int32) *vmcommon.ESDTTransfer {
 	esdtTransfers := host.Runtime().GetVMInput().ESDTTransfers
@@ -131,6 +140,15 @@ func (context *ElrondApi) GetGasLeft() int64 {
 	return int64(metering.GasLeft())
 }
 
+// StackHeightExceeded VMHooks implementation.
+// It is imported by the contracts instrumented for the stack height metering,
+// which call it when their nested calls exceed the maximum stack height.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) StackHeightExceeded() {
+	runtime := context.GetRuntimeContext()
+	runtime.FailExecution(arwen.ErrMaxStackReached)
+}
+
 // GetSCAddress VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) GetSCAddress(resultOffset int32) {
@@ -229,6 +247,118 @@ func (context *ElrondApi) SignalError(messageOffset int32, messageLength int32)
 	runtime.SignalUserError(string(message))
 }
 
+// SignalTypedError VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) SignalTypedError(code int64, messageOffset int32, messageLength int32, payloadOffset int32, payloadLength int32) {
+	runtime := context.GetRuntimeContext()
+	metering := context.GetMeteringContext()
+	output := context.GetOutputContext()
+	metering.StartGasTracing(signalTypedErrorName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.SignalError
+	gasToUse += metering.GasSchedule().BaseOperationCost.PersistPerByte * uint64(messageLength)
+	gasToUse += metering.GasSchedule().BaseOperationCost.PersistPerByte * uint64(payloadLength)
+
+	err := metering.UseGasBounded(gasToUse)
+	if err != nil {
+		_ = context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution())
+		return
+	}
+
+	if code <= 0 {
+		_ = context.WithFault(arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return
+	}
+	message, err := runtime.MemLoad(messageOffset, messageLength)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+	payload, err := runtime.MemLoad(payloadOffset, payloadLength)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	output.SetTypedError(big.NewInt(code).Bytes(), payload)
+	runtime.SignalUserError(string(message))
+}
+
+// DebugPrint VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) DebugPrint(messageOffset int32, messageLength int32) {
+	host := context.GetVMHost()
+	runtime := context.GetRuntimeContext()
+	metering := context.GetMeteringContext()
+
+	// the gas does not depend on the debug mode, so that a debugging session
+	// reproduces the gas of the production executions
+	gasToUse := metering.GasSchedule().ElrondAPICost.GetNumArguments
+	metering.UseGasAndAddTracedGas(debugPrintName, gasToUse)
+
+	if !host.IsDebugMode() {
+		return
+	}
+
+	// an invalid message is only reported, since failing the execution only
+	// in debug mode would change its outcome
+	message, err := runtime.MemLoad(messageOffset, messageLength)
+	if err != nil {
+		logDebugPrint.Warn("debugPrint: invalid message", "error", err)
+		return
+	}
+
+	emitDebugMessage(host, message)
+}
+
+// DebugPrintWithTypedArgs - debugPrint with the message already read from memory
+func DebugPrintWithTypedArgs(host arwen.VMHost, message []byte) {
+	metering := host.Metering()
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.GetNumArguments
+	metering.UseGasAndAddTracedGas(debugPrintName, gasToUse)
+
+	if host.IsDebugMode() {
+		emitDebugMessage(host, message)
+	}
+}
+
+func emitDebugMessage(host arwen.VMHost, message []byte) {
+	runtime := host.Runtime()
+	address := runtime.GetContextAddress()
+	logDebugPrint.Info("debugPrint", "address", address, "function", runtime.FunctionName(), "message", string(message))
+
+	debugMessageTracer, ok := host.ExecutionTracer().(arwen.DebugMessageTracer)
+	if ok {
+		debugMessageTracer.TraceDebugMessage(address, message)
+	}
+}
+
+// GasPhase VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) GasPhase(phase int32) {
+	GasPhaseWithHost(context.GetVMHost(), phase)
+}
+
+// GasPhaseWithHost - gasPhase with host instead of pointer context; the gas
+// of the marker does not depend on the tracer, so that tracing an execution
+// reproduces its gas
+func GasPhaseWithHost(host arwen.VMHost, phase int32) {
+	metering := host.Metering()
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.GetNumArguments
+	metering.UseGasAndAddTracedGas(gasPhaseName, gasToUse)
+
+	gasPhase := arwen.GasPhase(phase)
+	if !gasPhase.IsValid() {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidGasPhase, host.Runtime().ElrondAPIErrorShouldFailExecution())
+		return
+	}
+
+	gasPhaseTracer, ok := host.ExecutionTracer().(arwen.GasPhaseTracer)
+	if ok {
+		gasPhaseTracer.TraceGasPhase(gasPhase, metering.GasLeft())
+	}
+}
+
 // GetExternalBalance VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) GetExternalBalance(addressOffset int32, resultOffset int32) {
@@ -564,6 +694,52 @@ func (context *ElrondApi) TransferValue(destOffset int32, valueOffset int32, dat
 	return 0
 }
 
+// RefundGasToCaller VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) RefundGasToCaller(gas int64) int32 {
+	host := context.GetVMHost()
+	return RefundGasToCallerWithHost(host, gas)
+}
+
+// RefundGasToCallerWithHost pays the value of the given gas, at the gas price
+// of the transaction, from the current contract to the caller of the
+// transaction, and logs the refund so that it shows in the receipt
+func RefundGasToCallerWithHost(host arwen.VMHost, gas int64) int32 {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	output := host.Output()
+	metering.StartGasTracing(refundGasToCallerName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.TransferValue
+	metering.UseAndTraceGas(gasToUse)
+
+	if runtime.ReadOnly() {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidCallOnReadOnlyMode, runtime.ElrondAPIErrorShouldFailExecution())
+		return 1
+	}
+	if gas <= 0 || uint64(gas) > metering.GetGasProvided() {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return 1
+	}
+
+	payer := runtime.GetContextAddress()
+	caller := runtime.GetOriginalCallerAddress()
+	value := host.GasValueConverter().ComputeFee(uint64(gas), runtime.GetVMInput().GasPrice)
+	err := output.Transfer(caller, payer, 0, 0, value, nil, nil, vm.DirectCall)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	output.WriteLogWithIdentifier(
+		payer,
+		[][]byte{caller, big.NewInt(gas).Bytes(), value.Bytes()},
+		[]byte{},
+		[]byte(arwen.GasRefundedToCallerIdentifier),
+	)
+
+	return 0
+}
+
 type indirectContractCallArguments struct {
 	dest      []byte
 	value     *big.Int
@@ -1145,6 +1321,59 @@ func CreateAsyncCallWithTypedArgs(host arwen.VMHost,
 	extraGasForCallback int64,
 	callbackClosure []byte) int32 {
 
+	return CreateAsyncCallWithCallbackArgTypes(host,
+		calledSCAddress,
+		value,
+		data,
+		successFunc,
+		errorFunc,
+		gas,
+		extraGasForCallback,
+		callbackClosure,
+		nil)
+}
+
+// CreateAsyncCallWithCallbackArgTypes - createAsyncCall with arguments already
+// read from memory, registering the types expected by the success callback
+func CreateAsyncCallWithCallbackArgTypes(host arwen.VMHost,
+	calledSCAddress []byte,
+	value []byte,
+	data []byte,
+	successFunc []byte,
+	errorFunc []byte,
+	gas int64,
+	extraGasForCallback int64,
+	callbackClosure []byte,
+	callbackArgTypes arwen.CallbackArgTypes) int32 {
+
+	return CreateAsyncCallWithExpiry(host,
+		calledSCAddress,
+		value,
+		data,
+		successFunc,
+		errorFunc,
+		gas,
+		extraGasForCallback,
+		callbackClosure,
+		callbackArgTypes,
+		0)
+}
+
+// CreateAsyncCallWithExpiry - createAsyncCall with arguments already read from
+// memory, expiring after the given number of rounds if the destination does
+// not respond; an expiry of 0 rounds means the async call never expires
+func CreateAsyncCallWithExpiry(host arwen.VMHost,
+	calledSCAddress []byte,
+	value []byte,
+	data []byte,
+	successFunc []byte,
+	errorFunc []byte,
+	gas int64,
+	extraGasForCallback int64,
+	callbackClosure []byte,
+	callbackArgTypes arwen.CallbackArgTypes,
+	expiryRounds int64) int32 {
+
 	metering := host.Metering()
 	runtime := host.Runtime()
 	async := host.Async()
@@ -1154,6 +1383,113 @@ func CreateAsyncCallWithTypedArgs(host arwen.VMHost,
 	gasToUse := metering.GasSchedule().ElrondAPICost.CreateAsyncCall
 	metering.UseAndTraceGas(gasToUse)
 
+	if expiryRounds < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidAsyncCallExpiry, runtime.ElrondAPIErrorShouldFailExecution())
+		return 1
+	}
+
+	asyncCall := &arwen.AsyncCall{
+		Status:           arwen.AsyncCallPending,
+		Destination:      calledSCAddress,
+		Data:             data,
+		ValueBytes:       value,
+		GasLimit:         uint64(gas),
+		SuccessCallback:  string(successFunc),
+		ErrorCallback:    string(errorFunc),
+		GasLocked:        uint64(extraGasForCallback),
+		CallbackClosure:  callbackClosure,
+		CallbackArgTypes: callbackArgTypes,
+	}
+
+	if expiryRounds > 0 {
+		asyncCall.ExpiryRound = math.AddUint64(host.Blockchain().CurrentRound(), uint64(expiryRounds))
+	}
+
+	if asyncCall.HasDefinedAnyCallback() {
+		gasToUse := metering.GasSchedule().ElrondAPICost.SetAsyncCallback
+		metering.UseAndTraceGas(gasToUse)
+	}
+
+	err := async.RegisterAsyncCall("", asyncCall)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	return 0
+}
+
+// CreatePromiseGroup VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) CreatePromiseGroup(groupIDOffset int32, groupIDLength int32) int32 {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	metering.StartGasTracing(createPromiseGroupName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.SetAsyncGroupCallback
+	metering.UseAndTraceGas(gasToUse)
+
+	groupID, err := runtime.MemLoad(groupIDOffset, groupIDLength)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
+	}
+
+	groupHandle, err := host.Async().CreatePromiseGroup(groupID)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
+	}
+
+	return int32(groupHandle)
+}
+
+// CreatePromise VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) CreatePromise(
+	groupHandle int32,
+	destOffset int32,
+	valueOffset int32,
+	dataOffset int32,
+	dataLength int32,
+	successOffset int32,
+	successLength int32,
+	errorOffset int32,
+	errorLength int32,
+	gas int64,
+	extraGasForCallback int64,
+) int32 {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	metering.StartGasTracing(createPromiseName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.CreateAsyncCall
+	metering.UseAndTraceGas(gasToUse)
+
+	calledSCAddress, err := runtime.MemLoad(destOffset, arwen.AddressLen)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
+	}
+
+	value, err := runtime.MemLoad(valueOffset, arwen.BalanceLen)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
+	}
+
+	data, err := runtime.MemLoad(dataOffset, dataLength)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
+	}
+
+	successFunc, err := runtime.MemLoad(successOffset, successLength)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
+	}
+
+	errorFunc, err := runtime.MemLoad(errorOffset, errorLength)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
+	}
+
 	asyncCall := &arwen.AsyncCall{
 		Status:          arwen.AsyncCallPending,
 		Destination:     calledSCAddress,
@@ -1163,20 +1499,19 @@ func CreateAsyncCallWithTypedArgs(host arwen.VMHost,
 		SuccessCallback: string(successFunc),
 		ErrorCallback:   string(errorFunc),
 		GasLocked:       uint64(extraGasForCallback),
-		CallbackClosure: callbackClosure,
 	}
 
 	if asyncCall.HasDefinedAnyCallback() {
-		gasToUse := metering.GasSchedule().ElrondAPICost.SetAsyncCallback
+		gasToUse = metering.GasSchedule().ElrondAPICost.SetAsyncCallback
 		metering.UseAndTraceGas(gasToUse)
 	}
 
-	err := async.RegisterAsyncCall("", asyncCall)
-	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return 1
+	promiseID, err := host.Async().RegisterPromise(arwen.PromiseGroupHandle(groupHandle), asyncCall)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return arwen.InvalidPromiseHandle
 	}
 
-	return 0
+	return int32(promiseID)
 }
 
 // SetAsyncContextCallback VMHooks implementation.
@@ -1577,6 +1912,58 @@ func (context *ElrondApi) GetArgument(id int32, argOffset int32) int32 {
 	return int32(len(args[id]))
 }
 
+// CopyArgumentTo VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) CopyArgumentTo(id int32, offset int32, length int32, destOffset int32) int32 {
+	host := context.GetVMHost()
+	return CopyArgumentToWithHost(host, id, offset, length, destOffset)
+}
+
+// CopyArgumentToWithHost copies at most length bytes of an argument, starting
+// at the given offset in the argument, to the memory of the contract, so that
+// contracts can read large arguments in pieces, into preallocated buffers. It
+// returns the number of bytes copied, which is less than the length requested
+// when the argument ends earlier.
+func CopyArgumentToWithHost(host arwen.VMHost, id int32, offset int32, length int32, destOffset int32) int32 {
+	runtime := host.Runtime()
+	metering := host.Metering()
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.GetArgument
+	metering.UseGasAndAddTracedGas(copyArgumentToName, gasToUse)
+
+	args := runtime.Arguments()
+	if id < 0 || int32(len(args)) <= id {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidArgument, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+	if length < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrNegativeLength, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	argument := args[id]
+	if offset < 0 || int(offset) > len(argument) {
+		_ = WithFaultAndHost(host, arwen.ErrBadBounds, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	end := len(argument)
+	if int(length) < end-int(offset) {
+		end = int(offset) + int(length)
+	}
+	slice := argument[offset:end]
+
+	gasToUse = math.MulUint64(metering.GasSchedule().BaseOperationCost.DataCopyPerByte, uint64(len(slice)))
+	metering.UseAndTraceGas(gasToUse)
+
+	err := runtime.MemStore(destOffset, slice)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	return int32(len(slice))
+}
+
 // GetFunction VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) GetFunction(functionOffset int32) int32 {
@@ -1655,6 +2042,29 @@ func StorageStoreWithTypedArgs(host arwen.VMHost, key []byte, data []byte) int32
 	return int32(storageStatus)
 }
 
+// StorageDelete VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) StorageDelete(keyOffset int32, keyLength int32) int32 {
+	runtime := context.GetRuntimeContext()
+	storage := context.GetStorageContext()
+	metering := context.GetMeteringContext()
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.StorageStore
+	metering.UseGasAndAddTracedGas(storageDeleteName, gasToUse)
+
+	key, err := runtime.MemLoad(keyOffset, keyLength)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	storageStatus, err := storage.DeleteStorage(key)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	return int32(storageStatus)
+}
+
 // StorageLoadLength VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) StorageLoadLength(keyOffset int32, keyLength int32) int32 {
@@ -2140,6 +2550,11 @@ func (context *ElrondApi) WriteLog(dataPointer int32, dataLength int32, topicPtr
 		}
 	}
 
+	err = output.CheckLogSize(uint64(numTopics*arwen.HashLen + dataLength))
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
 	output.WriteLog(runtime.GetContextAddress(), topics, log)
 }
 
@@ -2180,6 +2595,11 @@ func (context *ElrondApi) WriteEventLog(
 	gasToUse = math.AddUint64(gasToUse, gasForData)
 	metering.UseGasAndAddTracedGas(writeEventLogName, gasToUse)
 
+	err = output.CheckLogSize(uint64(topicDataTotalLen + dataLength))
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
 	output.WriteLog(runtime.GetContextAddress(), topics, data)
 }
 
@@ -2342,6 +2762,11 @@ func (context *ElrondApi) Finish(pointer int32, length int32) {
 		return
 	}
 
+	err = output.CheckReturnDataSize(uint64(length))
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
 	data, err := runtime.MemLoad(pointer, length)
 	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
 		return
@@ -2350,6 +2775,58 @@ func (context *ElrondApi) Finish(pointer int32, length int32) {
 	output.Finish(data)
 }
 
+// FinishReserve VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) FinishReserve(length int32) int32 {
+	runtime := context.GetRuntimeContext()
+	output := context.GetOutputContext()
+	metering := context.GetMeteringContext()
+	metering.StartGasTracing(returnDataName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.Finish
+	metering.UseAndTraceGas(gasToUse)
+
+	if length < 0 {
+		_ = context.WithFault(arwen.ErrNegativeLength, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	err := output.ReserveFinish(uint64(length))
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	return 0
+}
+
+// FinishAppend VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) FinishAppend(dataOffset int32, length int32) int32 {
+	runtime := context.GetRuntimeContext()
+	output := context.GetOutputContext()
+	metering := context.GetMeteringContext()
+	metering.StartGasTracing(returnDataName)
+
+	gasToUse := math.MulUint64(metering.GasSchedule().BaseOperationCost.PersistPerByte, uint64(length))
+	err := metering.UseGasBounded(gasToUse)
+	if err != nil {
+		_ = context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	data, err := runtime.MemLoad(dataOffset, length)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	err = output.AppendFinish(data)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	return 0
+}
+
 // ExecuteOnSameContext VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) ExecuteOnSameContext(
@@ -2849,7 +3326,35 @@ func createContract(
 	host arwen.VMHost,
 	_ arwen.RuntimeContext,
 ) ([]byte, error) {
-	contractCreate := &vmcommon.ContractCreateInput{
+	contractCreate := makeContractCreateInput(sender, data, value, metering, gasLimit, code, codeMetadata)
+	return host.CreateNewContract(contractCreate)
+}
+
+func createDeterministicContract(
+	sender []byte,
+	data [][]byte,
+	value *big.Int,
+	metering arwen.MeteringContext,
+	gasLimit int64,
+	code []byte,
+	codeMetadata []byte,
+	salt []byte,
+	host arwen.VMHost,
+) ([]byte, error) {
+	contractCreate := makeContractCreateInput(sender, data, value, metering, gasLimit, code, codeMetadata)
+	return host.CreateNewDeterministicContract(contractCreate, salt)
+}
+
+func makeContractCreateInput(
+	sender []byte,
+	data [][]byte,
+	value *big.Int,
+	metering arwen.MeteringContext,
+	gasLimit int64,
+	code []byte,
+	codeMetadata []byte,
+) *vmcommon.ContractCreateInput {
+	return &vmcommon.ContractCreateInput{
 		VMInput: vmcommon.VMInput{
 			CallerAddr:  sender,
 			Arguments:   data,
@@ -2860,8 +3365,6 @@ func createContract(
 		ContractCode:         code,
 		ContractCodeMetadata: codeMetadata,
 	}
-
-	return host.CreateNewContract(contractCreate)
 }
 
 // GetNumReturnData VMHooks implementation.
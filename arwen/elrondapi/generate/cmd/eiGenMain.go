@@ -37,6 +37,7 @@ func main() {
 
 	writeVMHooks(eiMetadata)
 	writeVMHooksWrapper(eiMetadata)
+	writeInterpreterVMHooks(eiMetadata)
 	writeWasmer1ImportsCgo(eiMetadata)
 	writeWasmer2ImportsCgo(eiMetadata)
 	writeRustVMHooksTrait(eiMetadata)
@@ -64,6 +65,15 @@ func writeVMHooksWrapper(eiMetadata *eapigen.EIMetadata) {
 	eapigen.WriteVMHooksWrapper(out, eiMetadata)
 }
 
+func writeInterpreterVMHooks(eiMetadata *eapigen.EIMetadata) {
+	out, err := os.Create(pathToElrondApiPackage + "../../interpreter/interpreterVMHooks.go")
+	if err != nil {
+		panic(err)
+	}
+	defer out.Close()
+	eapigen.WriteInterpreterVMHooks(out, eiMetadata)
+}
+
 func writeWasmer1ImportsCgo(eiMetadata *eapigen.EIMetadata) {
 	out, err := os.Create(pathToElrondApiPackage + "../../wasmer/wasmerImportsCgo.go")
 	if err != nil {
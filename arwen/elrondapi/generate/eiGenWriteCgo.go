@@ -140,6 +140,9 @@ func (writer *cgoWriter) writeGoExports(out *os.File, eiMetadata *EIMetadata) {
 		}
 		out.WriteString(" {\n")
 		out.WriteString("\tvmHooks := getVMHooksFromContextRawPtr(context)\n")
+		out.WriteString(fmt.Sprintf("\tdefer finishVMHookCall(vmHooks, \"%s\", startVMHookCall(vmHooks))\n",
+			lowerInitial(funcMetadata.Name),
+		))
 		out.WriteString("\t")
 		if funcMetadata.Result != nil {
 			out.WriteString("return ")
@@ -0,0 +1,52 @@
+package elrondapigenerate
+
+import (
+	"fmt"
+	"os"
+)
+
+// WriteInterpreterVMHooks writes the typed adapters through which the
+// interpreter calls the VM hooks, with the arguments taken from its stack.
+func WriteInterpreterVMHooks(out *os.File, eiMetadata *EIMetadata) {
+	out.WriteString(`package interpreter
+
+// Code generated by elrondapi generator. DO NOT EDIT.
+
+// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
+// !!!!!!!!!!!!!!!!!!!!!! AUTO-GENERATED FILE !!!!!!!!!!!!!!!!!!!!!!
+// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
+
+import (
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// vmHookAdapters calls the VM hooks by their import names, with the raw
+// values of the arguments, and returns their results widened to 64 bits
+var vmHookAdapters = map[string]vmHookAdapter{
+`)
+
+	for _, funcMetadata := range eiMetadata.AllFunctions {
+		out.WriteString(fmt.Sprintf("\t\"%s\": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {\n",
+			lowerInitial(funcMetadata.Name),
+		))
+		call := fmt.Sprintf("vmHooks.%s(", upperInitial(funcMetadata.Name))
+		for argIndex, arg := range funcMetadata.Arguments {
+			if argIndex > 0 {
+				call += ", "
+			}
+			call += fmt.Sprintf("%s(arguments[%d])", arg.Type, argIndex)
+		}
+		call += ")"
+
+		if funcMetadata.Result == nil {
+			out.WriteString(fmt.Sprintf("\t\t%s\n", call))
+			out.WriteString("\t\treturn 0\n")
+		} else if funcMetadata.Result.Type == "int32" {
+			out.WriteString(fmt.Sprintf("\t\treturn uint64(uint32(%s))\n", call))
+		} else {
+			out.WriteString(fmt.Sprintf("\t\treturn uint64(%s)\n", call))
+		}
+		out.WriteString("\t},\n")
+	}
+	out.WriteString("}\n")
+}
@@ -12,6 +12,7 @@ import (
 const (
 	mBufferNewName                = "mBufferNew"
 	mBufferNewFromBytesName       = "mBufferNewFromBytes"
+	mBufferDropName               = "mBufferDrop"
 	mBufferGetLengthName          = "mBufferGetLength"
 	mBufferGetBytesName           = "mBufferGetBytes"
 	mBufferGetByteSliceName       = "mBufferGetByteSlice"
@@ -25,6 +26,7 @@ const (
 	mBufferFromBigIntUnsignedName = "mBufferFromBigIntUnsigned"
 	mBufferFromBigIntSignedName   = "mBufferFromBigIntSigned"
 	mBufferStorageStoreName       = "mBufferStorageStore"
+	mBufferStorageDeleteName      = "mBufferStorageDelete"
 	mBufferStorageLoadName        = "mBufferStorageLoad"
 	mBufferGetArgumentName        = "mBufferGetArgument"
 	mBufferFinishName             = "mBufferFinish"
@@ -63,6 +65,24 @@ func (context *ElrondApi) MBufferNewFromBytes(dataOffset int32, dataLength int32
 	return managedType.NewManagedBufferFromBytes(data)
 }
 
+// MBufferDrop VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) MBufferDrop(mBufferHandle int32) int32 {
+	managedType := context.GetManagedTypesContext()
+	runtime := context.GetRuntimeContext()
+	metering := context.GetMeteringContext()
+
+	gasToUse := metering.GasSchedule().ManagedBufferAPICost.MBufferNew
+	metering.UseGasAndAddTracedGas(mBufferDropName, gasToUse)
+
+	if !managedType.DropManagedBuffer(mBufferHandle) {
+		_ = context.WithFault(arwen.ErrNoManagedBufferUnderThisHandle, runtime.ManagedBufferAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	return 0
+}
+
 // MBufferGetLength VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) MBufferGetLength(mBufferHandle int32) int32 {
@@ -509,6 +529,30 @@ func (context *ElrondApi) MBufferStorageStore(keyHandle int32, sourceHandle int3
 	return 0
 }
 
+// MBufferStorageDelete VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) MBufferStorageDelete(keyHandle int32) int32 {
+	managedType := context.GetManagedTypesContext()
+	runtime := context.GetRuntimeContext()
+	storage := context.GetStorageContext()
+	metering := context.GetMeteringContext()
+
+	gasToUse := metering.GasSchedule().ManagedBufferAPICost.MBufferStorageStore
+	metering.UseGasAndAddTracedGas(mBufferStorageDeleteName, gasToUse)
+
+	key, err := managedType.GetBytes(keyHandle)
+	if context.WithFault(err, runtime.ManagedBufferAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	_, err = storage.DeleteStorage(key)
+	if context.WithFault(err, runtime.ManagedBufferAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	return 0
+}
+
 // MBufferStorageLoad VMHooks implementation.
 // @autogenerate(VMHooks)
 func (context *ElrondApi) MBufferStorageLoad(keyHandle int32, destinationHandle int32) int32 {
@@ -596,6 +640,11 @@ func (context *ElrondApi) MBufferFinish(sourceHandle int32) int32 {
 		return 1
 	}
 
+	err = output.CheckReturnDataSize(uint64(len(sourceBytes)))
+	if context.WithFault(err, runtime.ManagedBufferAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
 	output.Finish(sourceBytes)
 	return 0
 }
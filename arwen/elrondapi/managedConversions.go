@@ -2,7 +2,6 @@ package elrondapi
 
 import (
 	"encoding/binary"
-	"errors"
 	"math/big"
 
 	"github.com/ElrondNetwork/wasm-vm/arwen"
@@ -19,7 +18,7 @@ func readESDTTransfer(
 	data []byte,
 ) (*vmcommon.ESDTTransfer, error) {
 	if len(data) != esdtTransferLen {
-		return nil, errors.New("invalid ESDT transfer object encoding")
+		return nil, arwen.ErrInvalidESDTTransferEncoding
 	}
 
 	tokenIdentifierHandle := int32(binary.BigEndian.Uint32(data[0:4]))
@@ -66,7 +65,7 @@ func readESDTTransfers(
 	managedType.ConsumeGasForBytes(managedVecBytes)
 
 	if len(managedVecBytes)%esdtTransferLen != 0 {
-		return nil, errors.New("invalid managed vector of ESDT transfers")
+		return nil, arwen.ErrInvalidManagedESDTTransfersVector
 	}
 
 	numTransfers := len(managedVecBytes) / esdtTransferLen
@@ -1,9 +1,15 @@
 package elrondapi
 
 import (
+	"bytes"
+	"encoding/binary"
 	"encoding/hex"
 	"errors"
+	"math/big"
 
+	"github.com/ElrondNetwork/elrond-go-core/core"
+	"github.com/ElrondNetwork/elrond-go-core/data/esdt"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/elrond-vm-common/builtInFunctions"
 
 	"github.com/ElrondNetwork/wasm-vm/arwen"
@@ -23,12 +29,18 @@ const (
 	managedExecuteOnSameContextName         = "managedExecuteOnSameContext"
 	managedExecuteReadOnlyName              = "managedExecuteReadOnly"
 	managedCreateContractName               = "managedCreateContract"
+	managedCreateDeterministicContractName  = "managedCreateDeterministicContract"
+	managedComputeContractAddressName       = "managedComputeContractAddress"
+	managedComputeDeterministicAddressName  = "managedComputeDeterministicAddress"
 	managedDeployFromSourceContractName     = "managedDeployFromSourceContract"
 	managedUpgradeContractName              = "managedUpgradeContract"
 	managedUpgradeFromSourceContractName    = "managedUpgradeFromSourceContract"
 	managedAsyncCallName                    = "managedAsyncCall"
 	managedCreateAsyncCallName              = "managedCreateAsyncCall"
 	managedGetCallbackClosure               = "managedGetCallbackClosure"
+	managedGetLockedValueName               = "managedGetLockedValue"
+	managedSetAsyncGroupCallbackName        = "managedSetAsyncGroupCallback"
+	managedAddAsyncSubGroupName             = "managedAddAsyncSubGroup"
 	managedGetMultiESDTCallValueName        = "managedGetMultiESDTCallValue"
 	managedGetESDTBalanceName               = "managedGetESDTBalance"
 	managedGetESDTTokenDataName             = "managedGetESDTTokenData"
@@ -41,6 +53,38 @@ const (
 	managedIsESDTLimitedTransferName        = "managedIsESDTLimitedTransfer"
 	managedIsESDTPausedName                 = "managedIsESDTPaused"
 	managedBufferToHexName                  = "managedBufferToHex"
+	managedGetESDTNFTAttributesName         = "managedGetESDTNFTAttributes"
+	managedGetESDTNFTAttributeValueName     = "managedGetESDTNFTAttributeValue"
+	managedGetESDTNFTURIsName               = "managedGetESDTNFTURIs"
+	managedGetESDTNFTCreatorName            = "managedGetESDTNFTCreator"
+	managedGetESDTNFTRoyaltiesName          = "managedGetESDTNFTRoyalties"
+	managedGetESDTTokenTypeOfName           = "managedGetESDTTokenTypeOf"
+	managedTransferNFTWithRoyaltiesName     = "managedTransferNFTWithRoyalties"
+	managedEscrowLockName                   = "managedEscrowLock"
+	managedEscrowClaimName                  = "managedEscrowClaim"
+	managedEscrowReleaseName                = "managedEscrowRelease"
+)
+
+// NFTRoyaltiesPaidIdentifier is the identifier of the log entry recording how
+// managedTransferNFTWithRoyalties split the price of a sold NFT
+const NFTRoyaltiesPaidIdentifier = "NFTRoyaltiesPaid"
+
+// EscrowLockedIdentifier, EscrowClaimedIdentifier and EscrowReleasedIdentifier
+// are the identifiers of the log entries recording the lifetime of an escrow,
+// whose topics are the locker, the beneficiary, the escrow ID and the value
+const (
+	EscrowLockedIdentifier   = "EscrowLocked"
+	EscrowClaimedIdentifier  = "EscrowClaimed"
+	EscrowReleasedIdentifier = "EscrowReleased"
+)
+
+// nftRoyaltiesDenominator is the value of royalties of 100%, the royalties
+// of the NFTs being given in hundredths of a percent
+const nftRoyaltiesDenominator = 10000
+
+const (
+	nftAttributesSeparator     = ';'
+	nftAttributeValueSeparator = ':'
 )
 
 // ManagedSCAddress VMHooks implementation.
@@ -149,6 +193,11 @@ func (context *ElrondApi) ManagedWriteLog(
 	gasToUse = math.AddUint64(gasToUse, gasForData)
 	metering.UseAndTraceGas(gasToUse)
 
+	err = output.CheckLogSize(sumOfTopicByteLengths + dataByteLen)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
 	output.WriteLog(runtime.GetContextAddress(), topics, dataBytes)
 }
 
@@ -343,236 +392,977 @@ func ManagedGetESDTTokenDataWithHost(
 
 }
 
-// ManagedAsyncCall VMHooks implementation.
+// ManagedGetESDTNFTAttributes VMHooks implementation.
 // @autogenerate(VMHooks)
-func (context *ElrondApi) ManagedAsyncCall(
-	destHandle int32,
-	valueHandle int32,
-	functionHandle int32,
-	argumentsHandle int32) {
+func (context *ElrondApi) ManagedGetESDTNFTAttributes(tokenIDHandle int32, nonce int64, attributesHandle int32) int32 {
 	host := context.GetVMHost()
-	ManagedAsyncCallWithHost(
-		host,
-		destHandle,
-		valueHandle,
-		functionHandle,
-		argumentsHandle)
+	return ManagedGetESDTNFTAttributesWithHost(host, tokenIDHandle, nonce, attributesHandle)
 }
 
-func ManagedAsyncCallWithHost(
-	host arwen.VMHost,
-	destHandle int32,
-	valueHandle int32,
-	functionHandle int32,
-	argumentsHandle int32) {
-	runtime := host.Runtime()
-	async := host.Async()
-	metering := host.Metering()
+// ManagedGetESDTNFTAttributesWithHost writes the attributes of an NFT held by
+// the current contract to the given managed buffer
+func ManagedGetESDTNFTAttributesWithHost(host arwen.VMHost, tokenIDHandle int32, nonce int64, attributesHandle int32) int32 {
 	managedType := host.ManagedTypes()
-	metering.StartGasTracing(managedAsyncCallName)
-
-	gasSchedule := metering.GasSchedule()
-	gasToUse := gasSchedule.ElrondAPICost.AsyncCallStep
-	metering.UseAndTraceGas(gasToUse)
 
-	vmInput, err := readDestinationFunctionArguments(host, destHandle, functionHandle, argumentsHandle)
-	if WithFaultAndHost(host, err, host.Runtime().ElrondAPIErrorShouldFailExecution()) {
-		return
+	metaData, ok := getOwnESDTNFTMetaData(host, managedGetESDTNFTAttributesName, tokenIDHandle, nonce)
+	if !ok {
+		return -1
 	}
 
-	data := makeCrossShardCallFromInput(vmInput.function, vmInput.arguments)
+	managedType.ConsumeGasForBytes(metaData.Attributes)
+	managedType.SetBytes(attributesHandle, metaData.Attributes)
+	return 0
+}
 
-	value, err := managedType.GetBigInt(valueHandle)
+// ManagedGetESDTNFTAttributeValue VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedGetESDTNFTAttributeValue(tokenIDHandle int32, nonce int64, keyHandle int32, valueHandle int32) int32 {
+	host := context.GetVMHost()
+	return ManagedGetESDTNFTAttributeValueWithHost(host, tokenIDHandle, nonce, keyHandle, valueHandle)
+}
+
+// ManagedGetESDTNFTAttributeValueWithHost decodes the attributes of an NFT
+// held by the current contract in the standard "key:value;key:value" layout
+// and writes the value of the given key to the value buffer; it returns 1 if
+// the key was found and 0 otherwise
+func ManagedGetESDTNFTAttributeValueWithHost(host arwen.VMHost, tokenIDHandle int32, nonce int64, keyHandle int32, valueHandle int32) int32 {
+	runtime := host.Runtime()
+	managedType := host.ManagedTypes()
+
+	key, err := managedType.GetBytes(keyHandle)
 	if err != nil {
-		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, host.Runtime().ElrondAPIErrorShouldFailExecution())
-		return
+		_ = WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
 	}
 
-	gasToUse = math.MulUint64(gasSchedule.BaseOperationCost.DataCopyPerByte, uint64(len(data)))
-	metering.UseAndTraceGas(gasToUse)
-
-	err = async.RegisterLegacyAsyncCall(vmInput.destination, []byte(data), value.Bytes())
-	if errors.Is(err, arwen.ErrNotEnoughGas) {
-		runtime.SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
-		return
+	metaData, ok := getOwnESDTNFTMetaData(host, managedGetESDTNFTAttributeValueName, tokenIDHandle, nonce)
+	if !ok {
+		return -1
 	}
-	if WithFaultAndHost(host, err, host.Runtime().ElrondAPIErrorShouldFailExecution()) {
-		return
+
+	managedType.ConsumeGasForBytes(metaData.Attributes)
+	value, found := findNFTAttributeValue(metaData.Attributes, key)
+	if !found {
+		managedType.SetBytes(valueHandle, []byte{})
+		return 0
 	}
+
+	managedType.SetBytes(valueHandle, value)
+	return 1
 }
 
-// ManagedCreateAsyncCall VMHooks implementation.
+// ManagedGetESDTNFTURIs VMHooks implementation.
 // @autogenerate(VMHooks)
-func (context *ElrondApi) ManagedCreateAsyncCall(
-	destHandle int32,
-	valueHandle int32,
-	functionHandle int32,
-	argumentsHandle int32,
-	successOffset int32,
-	successLength int32,
-	errorOffset int32,
-	errorLength int32,
-	gas int64,
-	extraGasForCallback int64,
-	callbackClosureHandle int32,
-) int32 {
-
+func (context *ElrondApi) ManagedGetESDTNFTURIs(tokenIDHandle int32, nonce int64, urisHandle int32) int32 {
 	host := context.GetVMHost()
-	runtime := host.Runtime()
+	return ManagedGetESDTNFTURIsWithHost(host, tokenIDHandle, nonce, urisHandle)
+}
+
+// ManagedGetESDTNFTURIsWithHost writes the URIs of an NFT held by the current
+// contract to the given managed vector of managed buffers
+func ManagedGetESDTNFTURIsWithHost(host arwen.VMHost, tokenIDHandle int32, nonce int64, urisHandle int32) int32 {
 	managedType := host.ManagedTypes()
 
-	vmInput, err := readDestinationFunctionArguments(host, destHandle, functionHandle, argumentsHandle)
-	if WithFaultAndHost(host, err, host.Runtime().ElrondAPIErrorShouldFailExecution()) {
-		return 1
+	metaData, ok := getOwnESDTNFTMetaData(host, managedGetESDTNFTURIsName, tokenIDHandle, nonce)
+	if !ok {
+		return -1
 	}
 
-	data := makeCrossShardCallFromInput(vmInput.function, vmInput.arguments)
-
-	value, err := managedType.GetBigInt(valueHandle)
-	if err != nil {
-		_ = context.WithFault(arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
-		return 1
-	}
+	managedType.WriteManagedVecOfManagedBuffers(metaData.URIs, urisHandle)
+	return 0
+}
 
-	successFunc, err := runtime.MemLoad(successOffset, successLength)
-	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return 1
-	}
+// ManagedGetESDTNFTCreator VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedGetESDTNFTCreator(tokenIDHandle int32, nonce int64, creatorHandle int32) int32 {
+	host := context.GetVMHost()
+	return ManagedGetESDTNFTCreatorWithHost(host, tokenIDHandle, nonce, creatorHandle)
+}
 
-	errorFunc, err := runtime.MemLoad(errorOffset, errorLength)
-	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return 1
-	}
+// ManagedGetESDTNFTCreatorWithHost writes the creator address of an NFT held
+// by the current contract to the given managed buffer
+func ManagedGetESDTNFTCreatorWithHost(host arwen.VMHost, tokenIDHandle int32, nonce int64, creatorHandle int32) int32 {
+	managedType := host.ManagedTypes()
 
-	callbackClosure, err := managedType.GetBytes(callbackClosureHandle)
-	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return 1
+	metaData, ok := getOwnESDTNFTMetaData(host, managedGetESDTNFTCreatorName, tokenIDHandle, nonce)
+	if !ok {
+		return -1
 	}
 
-	return CreateAsyncCallWithTypedArgs(host,
-		vmInput.destination,
-		value.Bytes(),
-		[]byte(data),
-		successFunc,
-		errorFunc,
-		gas,
-		extraGasForCallback,
-		callbackClosure)
+	managedType.SetBytes(creatorHandle, metaData.Creator)
+	return 0
 }
 
-// ManagedGetCallbackClosure VMHooks implementation.
+// ManagedGetESDTNFTRoyalties VMHooks implementation.
 // @autogenerate(VMHooks)
-func (context *ElrondApi) ManagedGetCallbackClosure(
-	callbackClosureHandle int32,
-) {
+func (context *ElrondApi) ManagedGetESDTNFTRoyalties(tokenIDHandle int32, nonce int64) int64 {
 	host := context.GetVMHost()
-	GetCallbackClosureWithHost(host, callbackClosureHandle)
+	return ManagedGetESDTNFTRoyaltiesWithHost(host, tokenIDHandle, nonce)
 }
 
-func GetCallbackClosureWithHost(
-	host arwen.VMHost,
-	callbackClosureHandle int32,
-) {
-	runtime := host.Runtime()
-	async := host.Async()
-	metering := host.Metering()
-	managedTypes := host.ManagedTypes()
+// ManagedGetESDTNFTRoyaltiesWithHost returns the royalties of an NFT held by
+// the current contract, in hundredths of a percent
+func ManagedGetESDTNFTRoyaltiesWithHost(host arwen.VMHost, tokenIDHandle int32, nonce int64) int64 {
+	metaData, ok := getOwnESDTNFTMetaData(host, managedGetESDTNFTRoyaltiesName, tokenIDHandle, nonce)
+	if !ok {
+		return -1
+	}
 
-	metering.StartGasTracing(managedGetCallbackClosure)
+	return int64(metaData.Royalties)
+}
 
-	gasToUse := metering.GasSchedule().ElrondAPICost.GetCallbackClosure
-	metering.UseAndTraceGas(gasToUse)
+// ManagedGetESDTTokenTypeOf VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedGetESDTTokenTypeOf(tokenIDHandle int32, nonce int64) int32 {
+	host := context.GetVMHost()
+	return ManagedGetESDTTokenTypeOfWithHost(host, tokenIDHandle, nonce)
+}
 
-	callbackClosure, err := async.GetCallbackClosure()
-	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return
+// ManagedGetESDTTokenTypeOfWithHost returns the type of a token held by the
+// current contract, as the core.ESDTType values
+func ManagedGetESDTTokenTypeOfWithHost(host arwen.VMHost, tokenIDHandle int32, nonce int64) int32 {
+	esdtToken, ok := getOwnESDTToken(host, managedGetESDTTokenTypeOfName, tokenIDHandle, nonce)
+	if !ok {
+		return -1
 	}
 
-	managedTypes.SetBytes(callbackClosureHandle, callbackClosure)
+	return int32(esdtToken.Type)
 }
 
-// ManagedUpgradeFromSourceContract VMHooks implementation.
+// ManagedTransferNFTWithRoyalties VMHooks implementation.
 // @autogenerate(VMHooks)
-func (context *ElrondApi) ManagedUpgradeFromSourceContract(
-	destHandle int32,
-	gas int64,
-	valueHandle int32,
-	addressHandle int32,
-	codeMetadataHandle int32,
-	argumentsHandle int32,
-	resultHandle int32,
-) {
+func (context *ElrondApi) ManagedTransferNFTWithRoyalties(
+	buyerHandle int32,
+	tokenIDHandle int32,
+	nonce int64,
+	amountHandle int32,
+	sellerHandle int32,
+	paymentTokenHandle int32,
+	paymentNonce int64,
+	priceHandle int32,
+) int32 {
 	host := context.GetVMHost()
+	return ManagedTransferNFTWithRoyaltiesWithHost(
+		host,
+		buyerHandle,
+		tokenIDHandle,
+		nonce,
+		amountHandle,
+		sellerHandle,
+		paymentTokenHandle,
+		paymentNonce,
+		priceHandle)
+}
+
+// ManagedTransferNFTWithRoyaltiesWithHost completes the sale of an NFT held by
+// the current contract: it transfers the NFT to the buyer, pays the royalties
+// of the NFT out of the price to its creator and the rest of the price to the
+// seller, then logs the split. The price is paid in EGLD if the payment token
+// is empty, and in the given ESDT otherwise.
+func ManagedTransferNFTWithRoyaltiesWithHost(
+	host arwen.VMHost,
+	buyerHandle int32,
+	tokenIDHandle int32,
+	nonce int64,
+	amountHandle int32,
+	sellerHandle int32,
+	paymentTokenHandle int32,
+	paymentNonce int64,
+	priceHandle int32,
+) int32 {
 	runtime := host.Runtime()
-	metering := host.Metering()
 	managedType := host.ManagedTypes()
-	metering.StartGasTracing(managedUpgradeFromSourceContractName)
-
-	gasToUse := metering.GasSchedule().ElrondAPICost.CreateContract
-	metering.UseAndTraceGas(gasToUse)
+	metering := host.Metering()
+	metering.StartGasTracing(managedTransferNFTWithRoyaltiesName)
 
-	vmInput, err := readDestinationValueArguments(host, destHandle, valueHandle, argumentsHandle)
+	buyer, err := managedType.GetBytes(buyerHandle)
 	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return
+		return -1
 	}
-
-	sourceContractAddress, err := managedType.GetBytes(addressHandle)
+	seller, err := managedType.GetBytes(sellerHandle)
 	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return
+		return -1
 	}
-
-	codeMetadata, err := managedType.GetBytes(codeMetadataHandle)
+	paymentToken, err := managedType.GetBytes(paymentTokenHandle)
 	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return
+		return -1
+	}
+	amount, err := managedType.GetBigInt(amountHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	price, err := managedType.GetBigInt(priceHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	if nonce <= 0 || paymentNonce < 0 || amount.Sign() <= 0 || price.Sign() < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
 	}
 
-	lenReturnData := len(host.Output().ReturnData())
+	metaData, ok := getOwnESDTNFTMetaData(host, managedTransferNFTWithRoyaltiesName, tokenIDHandle, nonce)
+	if !ok {
+		return -1
+	}
+	tokenID, _ := managedType.GetBytes(tokenIDHandle)
 
-	UpgradeFromSourceContractWithTypedArgs(
-		host,
-		sourceContractAddress,
-		vmInput.destination,
-		vmInput.value.Bytes(),
-		vmInput.arguments,
-		gas,
-		codeMetadata,
+	royalties := uint64(metaData.Royalties)
+	if royalties > nftRoyaltiesDenominator {
+		royalties = nftRoyaltiesDenominator
+	}
+	royaltiesAmount := big.NewInt(0).Mul(price, big.NewInt(0).SetUint64(royalties))
+	royaltiesAmount.Div(royaltiesAmount, big.NewInt(nftRoyaltiesDenominator))
+	sellerAmount := big.NewInt(0).Sub(price, royaltiesAmount)
+
+	nftTransfer := &vmcommon.ESDTTransfer{
+		ESDTValue:      amount,
+		ESDTTokenName:  tokenID,
+		ESDTTokenType:  uint32(core.NonFungible),
+		ESDTTokenNonce: uint64(nonce),
+	}
+	if TransferESDTNFTExecuteWithTypedArgs(host, buyer, []*vmcommon.ESDTTransfer{nftTransfer}, 0, nil, nil) != 0 {
+		return -1
+	}
+	if royaltiesAmount.Sign() > 0 && transferSalePayment(host, metaData.Creator, paymentToken, paymentNonce, royaltiesAmount) != 0 {
+		return -1
+	}
+	if sellerAmount.Sign() > 0 && transferSalePayment(host, seller, paymentToken, paymentNonce, sellerAmount) != 0 {
+		return -1
+	}
+
+	host.Output().WriteLogWithIdentifier(
+		runtime.GetContextAddress(),
+		[][]byte{
+			tokenID,
+			big.NewInt(nonce).Bytes(),
+			paymentToken,
+			metaData.Creator,
+			royaltiesAmount.Bytes(),
+			seller,
+			sellerAmount.Bytes(),
+		},
+		buyer,
+		[]byte(NFTRoyaltiesPaidIdentifier),
 	)
-	setReturnDataIfExists(host, lenReturnData, resultHandle)
+	return 0
 }
 
-// ManagedUpgradeContract VMHooks implementation.
+func transferSalePayment(host arwen.VMHost, dest []byte, paymentToken []byte, paymentNonce int64, value *big.Int) int32 {
+	if len(paymentToken) == 0 {
+		return TransferValueExecuteWithTypedArgs(host, dest, value, 0, nil, nil)
+	}
+
+	payment := &vmcommon.ESDTTransfer{
+		ESDTValue:      value,
+		ESDTTokenName:  paymentToken,
+		ESDTTokenType:  uint32(core.Fungible),
+		ESDTTokenNonce: uint64(paymentNonce),
+	}
+	if paymentNonce > 0 {
+		payment.ESDTTokenType = uint32(core.NonFungible)
+	}
+	return TransferESDTNFTExecuteWithTypedArgs(host, dest, []*vmcommon.ESDTTransfer{payment}, 0, nil, nil)
+}
+
+// ManagedEscrowLock VMHooks implementation.
 // @autogenerate(VMHooks)
-func (context *ElrondApi) ManagedUpgradeContract(
-	destHandle int32,
-	gas int64,
-	valueHandle int32,
-	codeHandle int32,
-	codeMetadataHandle int32,
-	argumentsHandle int32,
-	resultHandle int32,
-) {
+func (context *ElrondApi) ManagedEscrowLock(beneficiaryHandle int32, valueHandle int32, escrowIDHandle int32) int32 {
 	host := context.GetVMHost()
+	return ManagedEscrowLockWithHost(host, beneficiaryHandle, valueHandle, escrowIDHandle)
+}
+
+// ManagedEscrowLockWithHost takes the given EGLD value out of the balance of
+// the current contract and keeps it in escrow, in the protected storage of
+// the contract, in favor of a beneficiary contract of the same shard. The ID
+// of the new escrow is written into the buffer at escrowIDHandle; only the
+// beneficiary can settle the escrow, by claiming or by releasing the value.
+func ManagedEscrowLockWithHost(host arwen.VMHost, beneficiaryHandle int32, valueHandle int32, escrowIDHandle int32) int32 {
 	runtime := host.Runtime()
 	metering := host.Metering()
 	managedType := host.ManagedTypes()
-	metering.StartGasTracing(managedUpgradeContractName)
+	storage := host.Storage()
+	output := host.Output()
+	metering.StartGasTracing(managedEscrowLockName)
 
-	gasToUse := metering.GasSchedule().ElrondAPICost.CreateContract
+	gasToUse := metering.GasSchedule().ElrondAPICost.TransferValue
 	metering.UseAndTraceGas(gasToUse)
 
-	vmInput, err := readDestinationValueArguments(host, destHandle, valueHandle, argumentsHandle)
-	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return
+	if runtime.ReadOnly() {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidCallOnReadOnlyMode, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
 	}
 
-	codeMetadata, err := managedType.GetBytes(codeMetadataHandle)
+	beneficiary, err := managedType.GetBytes(beneficiaryHandle)
 	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return
+		return -1
 	}
-
-	code, err := managedType.GetBytes(codeHandle)
+	value, err := managedType.GetBigInt(valueHandle)
 	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
-		return
+		return -1
+	}
+	if value.Sign() <= 0 {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	locker := runtime.GetContextAddress()
+	if len(beneficiary) != arwen.AddressLen ||
+		bytes.Equal(beneficiary, locker) ||
+		!host.AreInSameShard(locker, beneficiary) ||
+		!host.Blockchain().IsSmartContract(beneficiary) {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidEscrowBeneficiary, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+	if host.Blockchain().GetBalanceBigInt(locker).Cmp(value) < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrTransferInsufficientFunds, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	escrowPrefix := storage.GetVmProtectedPrefix(arwen.EscrowKeyPrefix)
+	lastEscrowID, _ := storage.GetStorage(escrowPrefix)
+	escrowID := make([]byte, 8)
+	binary.BigEndian.PutUint64(escrowID, big.NewInt(0).SetBytes(lastEscrowID).Uint64()+1)
+
+	_, err = storage.SetProtectedStorage(escrowPrefix, escrowID)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	escrowKey := arwen.CustomStorageKey(string(escrowPrefix), escrowID)
+	_, err = storage.SetProtectedStorage(escrowKey, append(append([]byte{}, beneficiary...), value.Bytes()...))
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	output.AddTxValueToAccount(locker, big.NewInt(0).Neg(value))
+	managedType.SetBytes(escrowIDHandle, escrowID)
+	writeEscrowLog(host, EscrowLockedIdentifier, locker, beneficiary, escrowID, value)
+	return 0
+}
+
+// ManagedEscrowClaim VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedEscrowClaim(lockerHandle int32, escrowIDHandle int32) int32 {
+	host := context.GetVMHost()
+	return ManagedEscrowClaimWithHost(host, lockerHandle, escrowIDHandle)
+}
+
+// ManagedEscrowClaimWithHost settles an escrow locked in favor of the current
+// contract by paying its value to the current contract
+func ManagedEscrowClaimWithHost(host arwen.VMHost, lockerHandle int32, escrowIDHandle int32) int32 {
+	return settleEscrow(host, managedEscrowClaimName, lockerHandle, escrowIDHandle, true)
+}
+
+// ManagedEscrowRelease VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedEscrowRelease(lockerHandle int32, escrowIDHandle int32) int32 {
+	host := context.GetVMHost()
+	return ManagedEscrowReleaseWithHost(host, lockerHandle, escrowIDHandle)
+}
+
+// ManagedEscrowReleaseWithHost settles an escrow locked in favor of the
+// current contract by giving its value back to the contract which locked it
+func ManagedEscrowReleaseWithHost(host arwen.VMHost, lockerHandle int32, escrowIDHandle int32) int32 {
+	return settleEscrow(host, managedEscrowReleaseName, lockerHandle, escrowIDHandle, false)
+}
+
+func settleEscrow(host arwen.VMHost, traceName string, lockerHandle int32, escrowIDHandle int32, claim bool) int32 {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	storage := host.Storage()
+	output := host.Output()
+	metering.StartGasTracing(traceName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.TransferValue
+	metering.UseAndTraceGas(gasToUse)
+
+	if runtime.ReadOnly() {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidCallOnReadOnlyMode, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	locker, err := managedType.GetBytes(lockerHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	escrowID, err := managedType.GetBytes(escrowIDHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	if !host.AreInSameShard(locker, runtime.GetContextAddress()) {
+		_ = WithFaultAndHost(host, arwen.ErrEscrowNotFound, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	escrowKey := arwen.CustomStorageKey(string(storage.GetVmProtectedPrefix(arwen.EscrowKeyPrefix)), escrowID)
+	escrow, _ := storage.GetStorageFromAddressNoChecks(locker, escrowKey)
+	if len(escrowID) == 0 || len(escrow) <= arwen.AddressLen {
+		_ = WithFaultAndHost(host, arwen.ErrEscrowNotFound, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+	beneficiary := escrow[:arwen.AddressLen]
+	value := big.NewInt(0).SetBytes(escrow[arwen.AddressLen:])
+	if !bytes.Equal(beneficiary, runtime.GetContextAddress()) {
+		_ = WithFaultAndHost(host, arwen.ErrNotEscrowBeneficiary, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	_, err = storage.SetProtectedStorageToAddress(locker, escrowKey, nil)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	identifier := EscrowReleasedIdentifier
+	receiver := locker
+	if claim {
+		identifier = EscrowClaimedIdentifier
+		receiver = beneficiary
+	}
+	output.AddTxValueToAccount(receiver, value)
+	writeEscrowLog(host, identifier, locker, beneficiary, escrowID, value)
+	return 0
+}
+
+func writeEscrowLog(host arwen.VMHost, identifier string, locker []byte, beneficiary []byte, escrowID []byte, value *big.Int) {
+	host.Output().WriteLogWithIdentifier(
+		host.Runtime().GetContextAddress(),
+		[][]byte{locker, beneficiary, escrowID, value.Bytes()},
+		[]byte{},
+		[]byte(identifier),
+	)
+}
+
+func getOwnESDTToken(host arwen.VMHost, traceName string, tokenIDHandle int32, nonce int64) (*esdt.ESDigitalToken, bool) {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	blockchain := host.Blockchain()
+	managedType := host.ManagedTypes()
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.GetExternalBalance
+	metering.UseGasAndAddTracedGas(traceName, gasToUse)
+
+	tokenID, err := managedType.GetBytes(tokenIDHandle)
+	if err != nil {
+		_ = WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution())
+		return nil, false
+	}
+	if nonce < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return nil, false
+	}
+
+	esdtToken, err := blockchain.GetESDTToken(runtime.GetContextAddress(), tokenID, uint64(nonce))
+	if err != nil {
+		_ = WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution())
+		return nil, false
+	}
+
+	return esdtToken, true
+}
+
+func getOwnESDTNFTMetaData(host arwen.VMHost, traceName string, tokenIDHandle int32, nonce int64) (*esdt.MetaData, bool) {
+	esdtToken, ok := getOwnESDTToken(host, traceName, tokenIDHandle, nonce)
+	if !ok {
+		return nil, false
+	}
+	if esdtToken.TokenMetaData == nil {
+		_ = WithFaultAndHost(host, arwen.ErrNilESDTData, host.Runtime().ElrondAPIErrorShouldFailExecution())
+		return nil, false
+	}
+
+	return esdtToken.TokenMetaData, true
+}
+
+// findNFTAttributeValue looks for the given key in attributes encoded as
+// "key:value" pairs separated by semicolons, the layout of the NFT attributes
+// recommended by the standard, such as "metadata:cid/1.json;tags:art,music"
+func findNFTAttributeValue(attributes []byte, key []byte) ([]byte, bool) {
+	for _, pair := range bytes.Split(attributes, []byte{nftAttributesSeparator}) {
+		separatorIndex := bytes.IndexByte(pair, nftAttributeValueSeparator)
+		if separatorIndex < 0 {
+			continue
+		}
+		if bytes.Equal(pair[:separatorIndex], key) {
+			return pair[separatorIndex+1:], true
+		}
+	}
+	return nil, false
+}
+
+// ManagedAsyncCall VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedAsyncCall(
+	destHandle int32,
+	valueHandle int32,
+	functionHandle int32,
+	argumentsHandle int32) {
+	host := context.GetVMHost()
+	ManagedAsyncCallWithHost(
+		host,
+		destHandle,
+		valueHandle,
+		functionHandle,
+		argumentsHandle)
+}
+
+func ManagedAsyncCallWithHost(
+	host arwen.VMHost,
+	destHandle int32,
+	valueHandle int32,
+	functionHandle int32,
+	argumentsHandle int32) {
+	runtime := host.Runtime()
+	async := host.Async()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	metering.StartGasTracing(managedAsyncCallName)
+
+	gasSchedule := metering.GasSchedule()
+	gasToUse := gasSchedule.ElrondAPICost.AsyncCallStep
+	metering.UseAndTraceGas(gasToUse)
+
+	vmInput, err := readDestinationFunctionArguments(host, destHandle, functionHandle, argumentsHandle)
+	if WithFaultAndHost(host, err, host.Runtime().ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	data := makeCrossShardCallFromInput(vmInput.function, vmInput.arguments)
+
+	value, err := managedType.GetBigInt(valueHandle)
+	if err != nil {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, host.Runtime().ElrondAPIErrorShouldFailExecution())
+		return
+	}
+
+	gasToUse = math.MulUint64(gasSchedule.BaseOperationCost.DataCopyPerByte, uint64(len(data)))
+	metering.UseAndTraceGas(gasToUse)
+
+	err = async.RegisterLegacyAsyncCall(vmInput.destination, []byte(data), value.Bytes())
+	if errors.Is(err, arwen.ErrNotEnoughGas) {
+		runtime.SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+		return
+	}
+	if WithFaultAndHost(host, err, host.Runtime().ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+}
+
+// ManagedCreateAsyncCall VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedCreateAsyncCall(
+	destHandle int32,
+	valueHandle int32,
+	functionHandle int32,
+	argumentsHandle int32,
+	successOffset int32,
+	successLength int32,
+	errorOffset int32,
+	errorLength int32,
+	gas int64,
+	extraGasForCallback int64,
+	callbackClosureHandle int32,
+) int32 {
+	return context.createManagedAsyncCall(
+		destHandle,
+		valueHandle,
+		functionHandle,
+		argumentsHandle,
+		successOffset,
+		successLength,
+		errorOffset,
+		errorLength,
+		gas,
+		extraGasForCallback,
+		callbackClosureHandle,
+		nil,
+		0)
+}
+
+// ManagedCreateAsyncCallWithCallbackArgTypes VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedCreateAsyncCallWithCallbackArgTypes(
+	destHandle int32,
+	valueHandle int32,
+	functionHandle int32,
+	argumentsHandle int32,
+	successOffset int32,
+	successLength int32,
+	errorOffset int32,
+	errorLength int32,
+	gas int64,
+	extraGasForCallback int64,
+	callbackClosureHandle int32,
+	callbackArgTypesHandle int32,
+) int32 {
+	callbackArgTypes, ok := context.readCallbackArgTypes(callbackArgTypesHandle)
+	if !ok {
+		return 1
+	}
+
+	return context.createManagedAsyncCall(
+		destHandle,
+		valueHandle,
+		functionHandle,
+		argumentsHandle,
+		successOffset,
+		successLength,
+		errorOffset,
+		errorLength,
+		gas,
+		extraGasForCallback,
+		callbackClosureHandle,
+		callbackArgTypes,
+		0)
+}
+
+// ManagedCreateAsyncCallWithExpiry VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedCreateAsyncCallWithExpiry(
+	destHandle int32,
+	valueHandle int32,
+	functionHandle int32,
+	argumentsHandle int32,
+	successOffset int32,
+	successLength int32,
+	errorOffset int32,
+	errorLength int32,
+	gas int64,
+	extraGasForCallback int64,
+	callbackClosureHandle int32,
+	callbackArgTypesHandle int32,
+	expiryRounds int64,
+) int32 {
+	callbackArgTypes, ok := context.readCallbackArgTypes(callbackArgTypesHandle)
+	if !ok {
+		return 1
+	}
+
+	return context.createManagedAsyncCall(
+		destHandle,
+		valueHandle,
+		functionHandle,
+		argumentsHandle,
+		successOffset,
+		successLength,
+		errorOffset,
+		errorLength,
+		gas,
+		extraGasForCallback,
+		callbackClosureHandle,
+		callbackArgTypes,
+		expiryRounds)
+}
+
+func (context *ElrondApi) readCallbackArgTypes(callbackArgTypesHandle int32) (arwen.CallbackArgTypes, bool) {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	managedType := host.ManagedTypes()
+
+	callbackArgTypesBytes, err := managedType.GetBytes(callbackArgTypesHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return nil, false
+	}
+	managedType.ConsumeGasForBytes(callbackArgTypesBytes)
+
+	callbackArgTypes, err := arwen.ParseCallbackArgTypes(callbackArgTypesBytes)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return nil, false
+	}
+
+	return callbackArgTypes, true
+}
+
+func (context *ElrondApi) createManagedAsyncCall(
+	destHandle int32,
+	valueHandle int32,
+	functionHandle int32,
+	argumentsHandle int32,
+	successOffset int32,
+	successLength int32,
+	errorOffset int32,
+	errorLength int32,
+	gas int64,
+	extraGasForCallback int64,
+	callbackClosureHandle int32,
+	callbackArgTypes arwen.CallbackArgTypes,
+	expiryRounds int64,
+) int32 {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	managedType := host.ManagedTypes()
+
+	vmInput, err := readDestinationFunctionArguments(host, destHandle, functionHandle, argumentsHandle)
+	if WithFaultAndHost(host, err, host.Runtime().ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	data := makeCrossShardCallFromInput(vmInput.function, vmInput.arguments)
+
+	value, err := managedType.GetBigInt(valueHandle)
+	if err != nil {
+		_ = context.WithFault(arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return 1
+	}
+
+	successFunc, err := runtime.MemLoad(successOffset, successLength)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	errorFunc, err := runtime.MemLoad(errorOffset, errorLength)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	callbackClosure, err := managedType.GetBytes(callbackClosureHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	return CreateAsyncCallWithExpiry(host,
+		vmInput.destination,
+		value.Bytes(),
+		[]byte(data),
+		successFunc,
+		errorFunc,
+		gas,
+		extraGasForCallback,
+		callbackClosure,
+		callbackArgTypes,
+		expiryRounds)
+}
+
+// ManagedGetCallbackClosure VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedGetCallbackClosure(
+	callbackClosureHandle int32,
+) {
+	host := context.GetVMHost()
+	GetCallbackClosureWithHost(host, callbackClosureHandle)
+}
+
+func GetCallbackClosureWithHost(
+	host arwen.VMHost,
+	callbackClosureHandle int32,
+) {
+	runtime := host.Runtime()
+	async := host.Async()
+	metering := host.Metering()
+	managedTypes := host.ManagedTypes()
+
+	metering.StartGasTracing(managedGetCallbackClosure)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.GetCallbackClosure
+	metering.UseAndTraceGas(gasToUse)
+
+	callbackClosure, err := async.GetCallbackClosure()
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	managedTypes.SetBytes(callbackClosureHandle, callbackClosure)
+}
+
+// ManagedGetLockedValue VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedGetLockedValue(
+	tokenIDHandle int32,
+	nonce int64,
+	resultHandle int32,
+) {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+
+	metering.StartGasTracing(managedGetLockedValueName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.GetExternalBalance
+	metering.UseAndTraceGas(gasToUse)
+
+	tokenID, err := managedType.GetBytes(tokenIDHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	if nonce < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return
+	}
+
+	lockedValue := host.Async().GetLockedValue(tokenID, uint64(nonce))
+	result := managedType.GetBigIntOrCreate(resultHandle)
+	result.Set(lockedValue)
+}
+
+// ManagedSetAsyncGroupCallback VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedSetAsyncGroupCallback(
+	groupIDHandle int32,
+	successCallbackHandle int32,
+	errorCallbackHandle int32,
+	callbackDataHandle int32,
+	gas int64,
+) int32 {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+
+	metering.StartGasTracing(managedSetAsyncGroupCallbackName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.SetAsyncGroupCallback
+	metering.UseAndTraceGas(gasToUse)
+
+	groupID, err := managedType.GetBytes(groupIDHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	successCallback, err := managedType.GetBytes(successCallbackHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	errorCallback, err := managedType.GetBytes(errorCallbackHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	callbackData, err := managedType.GetBytes(callbackDataHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	if gas < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return 1
+	}
+
+	err = host.Async().SetGroupCallback(string(groupID), string(successCallback), string(errorCallback), callbackData, uint64(gas))
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	return 0
+}
+
+// ManagedAddAsyncSubGroup VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedAddAsyncSubGroup(
+	parentGroupIDHandle int32,
+	groupIDHandle int32,
+) int32 {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+
+	metering.StartGasTracing(managedAddAsyncSubGroupName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.SetAsyncGroupCallback
+	metering.UseAndTraceGas(gasToUse)
+
+	parentGroupID, err := managedType.GetBytes(parentGroupIDHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	groupID, err := managedType.GetBytes(groupIDHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	err = host.Async().AddSubGroup(string(parentGroupID), string(groupID))
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	return 0
+}
+
+// ManagedUpgradeFromSourceContract VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedUpgradeFromSourceContract(
+	destHandle int32,
+	gas int64,
+	valueHandle int32,
+	addressHandle int32,
+	codeMetadataHandle int32,
+	argumentsHandle int32,
+	resultHandle int32,
+) {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	metering.StartGasTracing(managedUpgradeFromSourceContractName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.CreateContract
+	metering.UseAndTraceGas(gasToUse)
+
+	vmInput, err := readDestinationValueArguments(host, destHandle, valueHandle, argumentsHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	sourceContractAddress, err := managedType.GetBytes(addressHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	codeMetadata, err := managedType.GetBytes(codeMetadataHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	lenReturnData := len(host.Output().ReturnData())
+
+	UpgradeFromSourceContractWithTypedArgs(
+		host,
+		sourceContractAddress,
+		vmInput.destination,
+		vmInput.value.Bytes(),
+		vmInput.arguments,
+		gas,
+		codeMetadata,
+	)
+	setReturnDataIfExists(host, lenReturnData, resultHandle)
+}
+
+// ManagedUpgradeContract VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedUpgradeContract(
+	destHandle int32,
+	gas int64,
+	valueHandle int32,
+	codeHandle int32,
+	codeMetadataHandle int32,
+	argumentsHandle int32,
+	resultHandle int32,
+) {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	metering.StartGasTracing(managedUpgradeContractName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.CreateContract
+	metering.UseAndTraceGas(gasToUse)
+
+	vmInput, err := readDestinationValueArguments(host, destHandle, valueHandle, argumentsHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	codeMetadata, err := managedType.GetBytes(codeMetadataHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
+	}
+
+	code, err := managedType.GetBytes(codeHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return
 	}
 
 	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
@@ -739,6 +1529,128 @@ func (context *ElrondApi) ManagedCreateContract(
 	return 0
 }
 
+// ManagedCreateDeterministicContract VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedCreateDeterministicContract(
+	gas int64,
+	valueHandle int32,
+	codeHandle int32,
+	codeMetadataHandle int32,
+	argumentsHandle int32,
+	saltHandle int32,
+	resultAddressHandle int32,
+	resultHandle int32,
+) int32 {
+	host := context.GetVMHost()
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	metering.StartGasTracing(managedCreateDeterministicContractName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.CreateContract
+	metering.UseAndTraceGas(gasToUse)
+
+	sender := runtime.GetContextAddress()
+	value, err := managedType.GetBigInt(valueHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	data, actualLen, err := managedType.ReadManagedVecOfManagedBuffers(argumentsHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	gasToUse = math.MulUint64(metering.GasSchedule().BaseOperationCost.DataCopyPerByte, actualLen)
+	metering.UseAndTraceGas(gasToUse)
+
+	codeMetadata, err := managedType.GetBytes(codeMetadataHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	code, err := managedType.GetBytes(codeHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	salt, err := managedType.GetBytes(saltHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	lenReturnData := len(host.Output().ReturnData())
+	newAddress, err := createDeterministicContract(sender, data, value, metering, gas, code, codeMetadata, salt, host)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	managedType.SetBytes(resultAddressHandle, newAddress)
+	setReturnDataIfExists(host, lenReturnData, resultHandle)
+
+	return 0
+}
+
+// ManagedComputeContractAddress VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedComputeContractAddress(deployerHandle int32, nonce int64, resultHandle int32) int32 {
+	managedType := context.GetManagedTypesContext()
+	blockchain := context.GetBlockchainContext()
+	runtime := context.GetRuntimeContext()
+	metering := context.GetMeteringContext()
+
+	gasToUse := metering.GasSchedule().CryptoAPICost.Keccak256
+	metering.UseGasAndAddTracedGas(managedComputeContractAddressName, gasToUse)
+
+	deployer, err := managedType.GetBytes(deployerHandle)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+	if nonce < 0 {
+		context.WithFault(arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return 1
+	}
+
+	address, err := blockchain.ComputeContractAddress(deployer, uint64(nonce))
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	managedType.SetBytes(resultHandle, address)
+	return 0
+}
+
+// ManagedComputeDeterministicAddress VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedComputeDeterministicAddress(saltHandle int32, codeHashHandle int32, resultHandle int32) int32 {
+	managedType := context.GetManagedTypesContext()
+	blockchain := context.GetBlockchainContext()
+	runtime := context.GetRuntimeContext()
+	metering := context.GetMeteringContext()
+
+	gasToUse := metering.GasSchedule().CryptoAPICost.Keccak256
+	metering.UseGasAndAddTracedGas(managedComputeDeterministicAddressName, gasToUse)
+
+	salt, err := managedType.GetBytes(saltHandle)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	codeHash, err := managedType.GetBytes(codeHashHandle)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+	managedType.ConsumeGasForBytes(salt)
+
+	address, err := blockchain.ComputeDeterministicAddress(runtime.GetContextAddress(), salt, codeHash)
+	if context.WithFault(err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return 1
+	}
+
+	managedType.SetBytes(resultHandle, address)
+	return 0
+}
+
 func setReturnDataIfExists(
 	host arwen.VMHost,
 	oldLen int,
@@ -0,0 +1,25 @@
+package elrondapi
+
+import (
+	"fmt"
+	"runtime/debug"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ executor.VMHookPanicHandler = (*ElrondApi)(nil)
+
+// HandleVMHookPanic converts a panic raised by the host code of a VM hook
+// into an ErrInternalVMError, which stops the contract with the
+// InternalVMError return code. With arwen.DebugAssertions, it panics again.
+func (context *ElrondApi) HandleVMHookPanic(hookName string, panicValue interface{}) {
+	logEEI.Error("VM hook panicked", "hook", hookName, "error", panicValue, "stack", "\n"+string(debug.Stack()))
+	if arwen.DebugAssertions {
+		panic(panicValue)
+	}
+
+	err := fmt.Errorf("%w: %s panicked: %v", arwen.ErrInternalVMError, hookName, panicValue)
+	context.host.Metering().UseGas(context.host.Metering().GasLeft())
+	context.host.Runtime().FailExecution(err)
+}
@@ -0,0 +1,411 @@
+package elrondapi
+
+import (
+	"bytes"
+	"encoding/binary"
+	"math"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/core"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+const (
+	managedRegisterStorageMigrationName = "managedRegisterStorageMigration"
+	managedContinueStorageMigrationName = "managedContinueStorageMigration"
+)
+
+// maxStorageMigrations bounds the migrations a contract can have pending at
+// once, and maxStorageMigrationBatch the keys migrated by a single call of
+// managedContinueStorageMigration
+const (
+	maxStorageMigrations     = 16
+	maxStorageMigrationBatch = 100
+)
+
+// storageMigration moves the values stored under the keys made of a source
+// prefix and a big endian uint32 index, the layout of the indexed storage
+// mappers, to the keys made of the target prefix and the same index,
+// optionally passing each value through a re-encoding endpoint of the
+// contract. The keys of a contract cannot be enumerated by the VM, so a
+// migration covers an explicit range of indexes; nextIndex is the first index
+// not migrated yet.
+type storageMigration struct {
+	sourcePrefix     []byte
+	targetPrefix     []byte
+	reencodeFunction []byte
+	firstIndex       uint64
+	lastIndex        uint64
+	nextIndex        uint64
+}
+
+func (migration *storageMigration) remainingKeys() uint64 {
+	if migration.nextIndex > migration.lastIndex {
+		return 0
+	}
+	return migration.lastIndex - migration.nextIndex + 1
+}
+
+func (migration *storageMigration) encode() []byte {
+	encoded := make([]byte, 0)
+	for _, part := range [][]byte{migration.sourcePrefix, migration.targetPrefix, migration.reencodeFunction} {
+		length := make([]byte, 4)
+		binary.BigEndian.PutUint32(length, uint32(len(part)))
+		encoded = append(encoded, length...)
+		encoded = append(encoded, part...)
+	}
+	for _, index := range []uint64{migration.firstIndex, migration.lastIndex, migration.nextIndex} {
+		encodedIndex := make([]byte, 8)
+		binary.BigEndian.PutUint64(encodedIndex, index)
+		encoded = append(encoded, encodedIndex...)
+	}
+	return encoded
+}
+
+func decodeStorageMigration(encoded []byte) (*storageMigration, error) {
+	parts := make([][]byte, 3)
+	for i := range parts {
+		if len(encoded) < 4 {
+			return nil, arwen.ErrInvalidStorageMigration
+		}
+		length := binary.BigEndian.Uint32(encoded[:4])
+		encoded = encoded[4:]
+		if uint64(len(encoded)) < uint64(length) {
+			return nil, arwen.ErrInvalidStorageMigration
+		}
+		parts[i] = encoded[:length]
+		encoded = encoded[length:]
+	}
+	if len(encoded) != 24 {
+		return nil, arwen.ErrInvalidStorageMigration
+	}
+
+	return &storageMigration{
+		sourcePrefix:     parts[0],
+		targetPrefix:     parts[1],
+		reencodeFunction: parts[2],
+		firstIndex:       binary.BigEndian.Uint64(encoded[0:8]),
+		lastIndex:        binary.BigEndian.Uint64(encoded[8:16]),
+		nextIndex:        binary.BigEndian.Uint64(encoded[16:24]),
+	}, nil
+}
+
+// ManagedRegisterStorageMigration VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedRegisterStorageMigration(
+	sourcePrefixHandle int32,
+	targetPrefixHandle int32,
+	reencodeFunctionHandle int32,
+	firstIndex int64,
+	lastIndex int64,
+) int32 {
+	host := context.GetVMHost()
+	return ManagedRegisterStorageMigrationWithHost(
+		host,
+		sourcePrefixHandle,
+		targetPrefixHandle,
+		reencodeFunctionHandle,
+		firstIndex,
+		lastIndex,
+	)
+}
+
+// ManagedRegisterStorageMigrationWithHost records, in the protected storage
+// of the contract being upgraded, the migration of its values from the keys
+// made of the source prefix and the indexes of the given range to the keys
+// made of the target prefix and the same indexes. An empty re-encoding
+// function keeps the values as they are; otherwise, it names an endpoint of
+// the new code which receives the source key and the value, and finishes the
+// new value. The migration is applied by managedContinueStorageMigration,
+// once the new code is deployed.
+func ManagedRegisterStorageMigrationWithHost(
+	host arwen.VMHost,
+	sourcePrefixHandle int32,
+	targetPrefixHandle int32,
+	reencodeFunctionHandle int32,
+	firstIndex int64,
+	lastIndex int64,
+) int32 {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	managedType := host.ManagedTypes()
+	storage := host.Storage()
+	metering.StartGasTracing(managedRegisterStorageMigrationName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.StorageStore
+	metering.UseAndTraceGas(gasToUse)
+
+	if runtime.ReadOnly() {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidCallOnReadOnlyMode, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+	if runtime.FunctionName() != arwen.UpgradeFunctionName {
+		_ = WithFaultAndHost(host, arwen.ErrStorageMigrationOutsideUpgrade, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	sourcePrefix, err := managedType.GetBytes(sourcePrefixHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	targetPrefix, err := managedType.GetBytes(targetPrefixHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	reencodeFunction, err := managedType.GetBytes(reencodeFunctionHandle)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	migration := &storageMigration{
+		sourcePrefix:     sourcePrefix,
+		targetPrefix:     targetPrefix,
+		reencodeFunction: reencodeFunction,
+		firstIndex:       uint64(firstIndex),
+		lastIndex:        uint64(lastIndex),
+		nextIndex:        uint64(firstIndex),
+	}
+	if firstIndex < 0 || lastIndex < firstIndex || lastIndex > math.MaxUint32 || !isValidStorageMigration(host, migration) {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidStorageMigration, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	migrations, err := loadStorageMigrations(host)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	if len(migrations) >= maxStorageMigrations {
+		_ = WithFaultAndHost(host, arwen.ErrTooManyStorageMigrations, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	err = saveStorageMigration(host, len(migrations), migration)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+	countKey := storage.GetVmProtectedPrefix(arwen.StorageMigrationKeyPrefix)
+	_, err = storage.SetProtectedStorage(countKey, big.NewInt(int64(len(migrations)+1)).Bytes())
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	return 0
+}
+
+// ManagedContinueStorageMigration VMHooks implementation.
+// @autogenerate(VMHooks)
+func (context *ElrondApi) ManagedContinueStorageMigration(maxKeys int64) int64 {
+	host := context.GetVMHost()
+	return ManagedContinueStorageMigrationWithHost(host, maxKeys)
+}
+
+// ManagedContinueStorageMigrationWithHost migrates at most the given number of
+// keys of the pending storage migrations of the current contract, in the order
+// of their registration, and returns the number of keys still to migrate; a
+// zero number only reports the progress. The keys without a value are skipped,
+// and the source keys are deleted once migrated. The records of the migrations
+// are removed when all of them are complete.
+func ManagedContinueStorageMigrationWithHost(host arwen.VMHost, maxKeys int64) int64 {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	metering.StartGasTracing(managedContinueStorageMigrationName)
+
+	gasToUse := metering.GasSchedule().ElrondAPICost.StorageLoad
+	metering.UseAndTraceGas(gasToUse)
+
+	if runtime.ReadOnly() {
+		_ = WithFaultAndHost(host, arwen.ErrInvalidCallOnReadOnlyMode, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+	if runtime.FunctionName() == arwen.UpgradeFunctionName {
+		_ = WithFaultAndHost(host, arwen.ErrStorageMigrationDuringUpgrade, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+	if maxKeys < 0 {
+		_ = WithFaultAndHost(host, arwen.ErrArgOutOfRange, runtime.ElrondAPIErrorShouldFailExecution())
+		return -1
+	}
+
+	migrations, err := loadStorageMigrations(host)
+	if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+		return -1
+	}
+
+	batch := uint64(maxKeys)
+	if batch > maxStorageMigrationBatch {
+		batch = maxStorageMigrationBatch
+	}
+	remaining := uint64(0)
+	for index, migration := range migrations {
+		migrated := false
+		for batch > 0 && migration.remainingKeys() > 0 {
+			err = migrateStorageKey(host, migration, migration.nextIndex)
+			if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+				return -1
+			}
+			migration.nextIndex++
+			batch--
+			migrated = true
+		}
+		if migrated {
+			err = saveStorageMigration(host, index, migration)
+			if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+				return -1
+			}
+		}
+		remaining += migration.remainingKeys()
+	}
+
+	if remaining == 0 && len(migrations) > 0 {
+		err = clearStorageMigrations(host, len(migrations))
+		if WithFaultAndHost(host, err, runtime.ElrondAPIErrorShouldFailExecution()) {
+			return -1
+		}
+	}
+
+	return int64(remaining)
+}
+
+// isValidStorageMigration requires the prefixes to be outside of the keys
+// reserved to the protocol and to the VM, and the re-encoding function, if
+// any, to be an endpoint of the new code; a migration without re-encoding
+// must move the values to other keys
+func isValidStorageMigration(host arwen.VMHost, migration *storageMigration) bool {
+	vmProtectedPrefix := host.Storage().GetVmProtectedPrefix("")
+	for _, prefix := range [][]byte{migration.sourcePrefix, migration.targetPrefix} {
+		if len(prefix) == 0 ||
+			bytes.HasPrefix(prefix, []byte(core.ElrondProtectedKeyPrefix)) ||
+			bytes.HasPrefix(prefix, vmProtectedPrefix) {
+			return false
+		}
+	}
+
+	if len(migration.reencodeFunction) == 0 {
+		return !bytes.Equal(migration.sourcePrefix, migration.targetPrefix)
+	}
+	function := string(migration.reencodeFunction)
+	if function == arwen.InitFunctionName || function == arwen.CallbackFunctionName {
+		return false
+	}
+	return host.Runtime().HasFunction(function)
+}
+
+func storageMigrationKey(host arwen.VMHost, index int) []byte {
+	encodedIndex := make([]byte, 4)
+	binary.BigEndian.PutUint32(encodedIndex, uint32(index))
+	countKey := host.Storage().GetVmProtectedPrefix(arwen.StorageMigrationKeyPrefix)
+	return arwen.CustomStorageKey(string(countKey), encodedIndex)
+}
+
+func loadStorageMigrations(host arwen.VMHost) ([]*storageMigration, error) {
+	storage := host.Storage()
+	countKey := storage.GetVmProtectedPrefix(arwen.StorageMigrationKeyPrefix)
+	encodedCount, _ := storage.GetStorage(countKey)
+	count := int(big.NewInt(0).SetBytes(encodedCount).Uint64())
+	if count > maxStorageMigrations {
+		return nil, arwen.ErrInvalidStorageMigration
+	}
+
+	migrations := make([]*storageMigration, count)
+	for i := range migrations {
+		encoded, _ := storage.GetStorage(storageMigrationKey(host, i))
+		migration, err := decodeStorageMigration(encoded)
+		if err != nil {
+			return nil, err
+		}
+		migrations[i] = migration
+	}
+	return migrations, nil
+}
+
+func saveStorageMigration(host arwen.VMHost, index int, migration *storageMigration) error {
+	_, err := host.Storage().SetProtectedStorage(storageMigrationKey(host, index), migration.encode())
+	return err
+}
+
+func clearStorageMigrations(host arwen.VMHost, count int) error {
+	storage := host.Storage()
+	for i := 0; i < count; i++ {
+		_, err := storage.SetProtectedStorage(storageMigrationKey(host, i), nil)
+		if err != nil {
+			return err
+		}
+	}
+	_, err := storage.SetProtectedStorage(storage.GetVmProtectedPrefix(arwen.StorageMigrationKeyPrefix), nil)
+	return err
+}
+
+func migrateStorageKey(host arwen.VMHost, migration *storageMigration, index uint64) error {
+	storage := host.Storage()
+	metering := host.Metering()
+
+	encodedIndex := make([]byte, 4)
+	binary.BigEndian.PutUint32(encodedIndex, uint32(index))
+	sourceKey := arwen.CustomStorageKey(string(migration.sourcePrefix), encodedIndex)
+	value, usedCache := storage.GetStorage(sourceKey)
+	storage.UseGasForStorageLoad(managedContinueStorageMigrationName, metering.GasSchedule().ElrondAPICost.StorageLoad, usedCache)
+	if len(value) == 0 {
+		return nil
+	}
+
+	var err error
+	if len(migration.reencodeFunction) > 0 {
+		value, err = reencodeStorageValue(host, migration.reencodeFunction, sourceKey, value)
+		if err != nil {
+			return err
+		}
+	}
+
+	targetKey := arwen.CustomStorageKey(string(migration.targetPrefix), encodedIndex)
+	if !bytes.Equal(sourceKey, targetKey) {
+		_, err = storage.DeleteStorage(sourceKey)
+		if err != nil {
+			return err
+		}
+	}
+	_, err = storage.SetStorage(targetKey, value)
+	return err
+}
+
+// reencodeStorageValue calls the re-encoding endpoint of the current contract
+// in read-only mode, with the source key and the value, and returns the single
+// value it finishes; the value is not left in the return data of the contract
+func reencodeStorageValue(host arwen.VMHost, function []byte, key []byte, value []byte) ([]byte, error) {
+	runtime := host.Runtime()
+	metering := host.Metering()
+	output := host.Output()
+
+	contract := runtime.GetContextAddress()
+	input, err := prepareIndirectContractCallInput(
+		host,
+		contract,
+		big.NewInt(0),
+		int64(metering.GasLeft()),
+		contract,
+		function,
+		[][]byte{key, value},
+		0,
+		true,
+	)
+	if err != nil {
+		return nil, err
+	}
+
+	numReturnData := len(output.ReturnData())
+	wasReadOnly := runtime.ReadOnly()
+	runtime.SetReadOnly(true)
+	vmOutput, err := executeOnDestContextFromAPI(host, input)
+	runtime.SetReadOnly(wasReadOnly)
+	if err != nil {
+		return nil, err
+	}
+
+	for len(output.ReturnData()) > numReturnData {
+		output.RemoveReturnData(uint32(numReturnData))
+	}
+	if len(vmOutput.ReturnData) != 1 {
+		return nil, arwen.ErrInvalidMigratedStorageValue
+	}
+	return vmOutput.ReturnData[0], nil
+}
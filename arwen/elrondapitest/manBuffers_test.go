@@ -8,6 +8,7 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	arwenMath "github.com/ElrondNetwork/wasm-vm/math"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	test "github.com/ElrondNetwork/wasm-vm/testcommon"
 
 	twoscomplement "github.com/ElrondNetwork/big-int-util/twos-complement"
@@ -55,6 +56,41 @@ func TestManBuffers_MixedFunctions(t *testing.T) {
 		})
 }
 
+func TestManBuffers_NotActiveBeforeEnableEpoch(t *testing.T) {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.ManagedBufferFunctionsEnableEpochField = 1
+	input := test.CreateTestContractCallInputBuilder().
+		WithGasProvided(100000).
+		WithFunction("mBufferMethod").
+		Build()
+
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(test.GetTestSCCode("managed-buffers", "../../"))).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithInput(input).
+		AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.FunctionNotFound()
+		})
+
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(test.GetTestSCCode("managed-buffers", "../../"))).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithSetup(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub) {
+			stubBlockchainHook.CurrentEpochCalled = func() uint32 {
+				return enableEpochsHandler.ManagedBufferFunctionsEnableEpoch()
+			}
+		}).
+		WithInput(input).
+		AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				ReturnData(managedBuffer, []byte("succ"))
+		})
+}
+
 func TestManBuffers_New(t *testing.T) {
 	test.BuildInstanceCallTest(t).
 		WithContracts(
@@ -0,0 +1,427 @@
+package arwen
+
+import (
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+)
+
+// ExtendEnableEpochsHandler returns the handler itself if it also tells the
+// activation epochs of the VM features; otherwise it returns the handler
+// extended with all the VM features disabled
+func ExtendEnableEpochsHandler(enableEpochsHandler vmcommon.EnableEpochsHandler) EnableEpochsHandler {
+	if check.IfNil(enableEpochsHandler) {
+		return nil
+	}
+	extended, ok := enableEpochsHandler.(EnableEpochsHandler)
+	if ok {
+		return extended
+	}
+	return &vmFeaturesDisabledHandler{EnableEpochsHandler: enableEpochsHandler}
+}
+
+// vmFeaturesDisabledHandler extends a vmcommon.EnableEpochsHandler which does
+// not know the VM features, which are then never active
+type vmFeaturesDisabledHandler struct {
+	vmcommon.EnableEpochsHandler
+}
+
+// ManagedBufferFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ManagedBufferFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsManagedBufferFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsManagedBufferFunctionsFlagEnabled() bool {
+	return false
+}
+
+// PromisesFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) PromisesFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsPromisesFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsPromisesFunctionsFlagEnabled() bool {
+	return false
+}
+
+// ManagedCryptoFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ManagedCryptoFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsManagedCryptoFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsManagedCryptoFunctionsFlagEnabled() bool {
+	return false
+}
+
+// ContractAddressFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ContractAddressFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsContractAddressFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsContractAddressFunctionsFlagEnabled() bool {
+	return false
+}
+
+// StorageDeleteFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) StorageDeleteFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsStorageDeleteFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsStorageDeleteFunctionsFlagEnabled() bool {
+	return false
+}
+
+// DebugPrintFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) DebugPrintFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsDebugPrintFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsDebugPrintFunctionsFlagEnabled() bool {
+	return false
+}
+
+// ChunkedFinishFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ChunkedFinishFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsChunkedFinishFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsChunkedFinishFunctionsFlagEnabled() bool {
+	return false
+}
+
+// NFTMetadataFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) NFTMetadataFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsNFTMetadataFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsNFTMetadataFunctionsFlagEnabled() bool {
+	return false
+}
+
+// NFTRoyaltiesFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) NFTRoyaltiesFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsNFTRoyaltiesFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsNFTRoyaltiesFunctionsFlagEnabled() bool {
+	return false
+}
+
+// ArgumentSliceFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ArgumentSliceFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsArgumentSliceFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsArgumentSliceFunctionsFlagEnabled() bool {
+	return false
+}
+
+// EscrowFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) EscrowFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsEscrowFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsEscrowFunctionsFlagEnabled() bool {
+	return false
+}
+
+// SignExtensionEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) SignExtensionEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsSignExtensionFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsSignExtensionFlagEnabled() bool {
+	return false
+}
+
+// BulkMemoryEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) BulkMemoryEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsBulkMemoryFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsBulkMemoryFlagEnabled() bool {
+	return false
+}
+
+// ReferenceTypesEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ReferenceTypesEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsReferenceTypesFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsReferenceTypesFlagEnabled() bool {
+	return false
+}
+
+// VersionedAsyncContextEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) VersionedAsyncContextEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsVersionedAsyncContextFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsVersionedAsyncContextFlagEnabled() bool {
+	return false
+}
+
+// EndpointCallabilityEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) EndpointCallabilityEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsEndpointCallabilityFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsEndpointCallabilityFlagEnabled() bool {
+	return false
+}
+
+// ContractBuildInfoLogEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ContractBuildInfoLogEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsContractBuildInfoLogFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsContractBuildInfoLogFlagEnabled() bool {
+	return false
+}
+
+// GasPhaseFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) GasPhaseFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsGasPhaseFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsGasPhaseFunctionsFlagEnabled() bool {
+	return false
+}
+
+// ContractDeletionEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ContractDeletionEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsContractDeletionFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsContractDeletionFlagEnabled() bool {
+	return false
+}
+
+// ManagedHandleDropFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ManagedHandleDropFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsManagedHandleDropFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsManagedHandleDropFunctionsFlagEnabled() bool {
+	return false
+}
+
+// AsyncCallSubGroupsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) AsyncCallSubGroupsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsAsyncCallSubGroupsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsAsyncCallSubGroupsFlagEnabled() bool {
+	return false
+}
+
+// AsyncCallsCanonicalOrderEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) AsyncCallsCanonicalOrderEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsAsyncCallsCanonicalOrderFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsAsyncCallsCanonicalOrderFlagEnabled() bool {
+	return false
+}
+
+// PromiseHandleFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) PromiseHandleFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsPromiseHandleFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsPromiseHandleFunctionsFlagEnabled() bool {
+	return false
+}
+
+// ArgumentSchemaEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ArgumentSchemaEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsArgumentSchemaFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsArgumentSchemaFlagEnabled() bool {
+	return false
+}
+
+// AsyncCallValidationEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) AsyncCallValidationEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsAsyncCallValidationFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsAsyncCallValidationFlagEnabled() bool {
+	return false
+}
+
+// GasRefundFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) GasRefundFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsGasRefundFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsGasRefundFunctionsFlagEnabled() bool {
+	return false
+}
+
+// TypedErrorFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) TypedErrorFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsTypedErrorFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsTypedErrorFunctionsFlagEnabled() bool {
+	return false
+}
+
+// StorageLimitsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) StorageLimitsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsStorageLimitsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsStorageLimitsFlagEnabled() bool {
+	return false
+}
+
+// CallbackDeduplicationEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) CallbackDeduplicationEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsCallbackDeduplicationFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsCallbackDeduplicationFlagEnabled() bool {
+	return false
+}
+
+// PaymentTokensEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) PaymentTokensEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsPaymentTokensFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsPaymentTokensFlagEnabled() bool {
+	return false
+}
+
+// StorageMigrationFunctionsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) StorageMigrationFunctionsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsStorageMigrationFunctionsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsStorageMigrationFunctionsFlagEnabled() bool {
+	return false
+}
+
+// MemoryLimitReturnCodeEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) MemoryLimitReturnCodeEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsMemoryLimitReturnCodeFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsMemoryLimitReturnCodeFlagEnabled() bool {
+	return false
+}
+
+// LockedValueLedgerEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) LockedValueLedgerEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsLockedValueLedgerFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsLockedValueLedgerFlagEnabled() bool {
+	return false
+}
+
+// ErrorCodeLogTopicEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) ErrorCodeLogTopicEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsErrorCodeLogTopicFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsErrorCodeLogTopicFlagEnabled() bool {
+	return false
+}
+
+// StorageHookErrorsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) StorageHookErrorsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsStorageHookErrorsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsStorageHookErrorsFlagEnabled() bool {
+	return false
+}
+
+// GuardedAccountsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) GuardedAccountsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsGuardedAccountsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsGuardedAccountsFlagEnabled() bool {
+	return false
+}
+
+// OwnershipTransferLogEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) OwnershipTransferLogEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsOwnershipTransferLogFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsOwnershipTransferLogFlagEnabled() bool {
+	return false
+}
+
+// LegacyCallbackNoOpEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) LegacyCallbackNoOpEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsLegacyCallbackNoOpFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsLegacyCallbackNoOpFlagEnabled() bool {
+	return false
+}
+
+// SortedAccountSetsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) SortedAccountSetsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsSortedAccountSetsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsSortedAccountSetsFlagEnabled() bool {
+	return false
+}
+
+// DetailedContractInvalidErrorsEnableEpoch returns the epoch of a feature which is never active
+func (handler *vmFeaturesDisabledHandler) DetailedContractInvalidErrorsEnableEpoch() uint32 {
+	return config.DisabledEpoch
+}
+
+// IsDetailedContractInvalidErrorsFlagEnabled returns false
+func (handler *vmFeaturesDisabledHandler) IsDetailedContractInvalidErrorsFlagEnabled() bool {
+	return false
+}
@@ -0,0 +1,29 @@
+package arwen
+
+import (
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+// nodeEnableEpochsHandler only knows the activation epochs of the node
+type nodeEnableEpochsHandler struct {
+	vmcommon.EnableEpochsHandler
+}
+
+func TestExtendEnableEpochsHandler(t *testing.T) {
+	require.Nil(t, ExtendEnableEpochsHandler(nil))
+
+	stub := worldmock.EnableEpochsHandlerStubAllFlags()
+	require.True(t, ExtendEnableEpochsHandler(stub) == EnableEpochsHandler(stub))
+
+	extended := ExtendEnableEpochsHandler(&nodeEnableEpochsHandler{EnableEpochsHandler: stub})
+	require.True(t, extended.IsStorageAPICostOptimizationFlagEnabled())
+	require.False(t, extended.IsManagedBufferFunctionsFlagEnabled())
+	require.False(t, extended.IsGuardedAccountsFlagEnabled())
+	require.Equal(t, config.DisabledEpoch, extended.PromisesFunctionsEnableEpoch())
+	require.Nil(t, ValidateConfigUpdate(config.MakeGasMapForTests(), extended))
+}
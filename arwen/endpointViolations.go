@@ -0,0 +1,115 @@
+package arwen
+
+import (
+	"errors"
+	"strings"
+)
+
+// EndpointViolationKind is the rule broken by an export of a deployed contract
+type EndpointViolationKind uint8
+
+const (
+	// EndpointDuplicated means that the name is exported more than once
+	EndpointDuplicated EndpointViolationKind = iota
+
+	// EndpointInitMissing means that the contract does not export the init function
+	EndpointInitMissing
+
+	// EndpointNameInvalid means that the name is empty, too long or contains forbidden characters
+	EndpointNameInvalid
+
+	// EndpointNameReserved means that the name belongs to the protocol, such
+	// as a built-in function, a host function or upgradeContract
+	EndpointNameReserved
+
+	// EndpointNotAFunction means that the export is a table or a global, or
+	// that a name reserved for the entry points of the VM is not exported as a function
+	EndpointNotAFunction
+
+	// EndpointCallabilityUnknown means that the callability section declares
+	// the callers of a function which the contract does not export
+	EndpointCallabilityUnknown
+
+	// EndpointListUnknown means that the endpoints section lists a function
+	// which the contract does not export
+	EndpointListUnknown
+
+	// EndpointNotListed means that the contract exports a function which is
+	// missing from its endpoints section
+	EndpointNotListed
+
+	// EndpointPaymentTokensUnknown means that the payment tokens section
+	// declares the tokens accepted by a function which the contract does not export
+	EndpointPaymentTokensUnknown
+)
+
+// String returns the name of the violated rule
+func (kind EndpointViolationKind) String() string {
+	switch kind {
+	case EndpointDuplicated:
+		return "duplicated endpoint"
+	case EndpointInitMissing:
+		return "missing init function"
+	case EndpointNameInvalid:
+		return "invalid endpoint name"
+	case EndpointNameReserved:
+		return "reserved endpoint name"
+	case EndpointNotAFunction:
+		return "export is not a function"
+	case EndpointCallabilityUnknown:
+		return "callability of unknown endpoint"
+	case EndpointListUnknown:
+		return "listed unknown endpoint"
+	case EndpointNotListed:
+		return "endpoint not listed"
+	case EndpointPaymentTokensUnknown:
+		return "payment tokens of unknown endpoint"
+	}
+	return "unknown violation"
+}
+
+// EndpointViolation is an export of a deployed contract which breaks a rule
+type EndpointViolation struct {
+	Kind EndpointViolationKind
+	Name string
+}
+
+// EndpointViolationsError holds all the endpoint violations found in the
+// code of a contract at deployment; it is an ErrInvalidEndpoints
+type EndpointViolationsError struct {
+	Violations []EndpointViolation
+}
+
+// Error lists the violations after the message of ErrInvalidEndpoints
+func (violationsError *EndpointViolationsError) Error() string {
+	descriptions := make([]string, len(violationsError.Violations))
+	for i, violation := range violationsError.Violations {
+		descriptions[i] = violation.Kind.String() + " " + violation.Name
+	}
+	return ErrInvalidEndpoints.Error() + ": " + strings.Join(descriptions, "; ")
+}
+
+// Unwrap returns ErrInvalidEndpoints, so that the error is also an ErrContractInvalid
+func (violationsError *EndpointViolationsError) Unwrap() error {
+	return ErrInvalidEndpoints
+}
+
+// GetEndpointViolations returns the endpoint violations carried by the given
+// error, or by the errors gathered in a WrappableError, if any
+func GetEndpointViolations(err error) ([]EndpointViolation, bool) {
+	var violationsError *EndpointViolationsError
+	if errors.As(err, &violationsError) {
+		return violationsError.Violations, true
+	}
+
+	wrappable, ok := err.(WrappableError)
+	if !ok {
+		return nil, false
+	}
+	for _, wrappedErr := range wrappable.GetAllErrors() {
+		if errors.As(wrappedErr, &violationsError) {
+			return violationsError.Violations, true
+		}
+	}
+	return nil, false
+}
@@ -1,312 +1,513 @@
 package arwen
 
 import (
-	"errors"
-	"fmt"
-
 	"github.com/ElrondNetwork/wasm-vm/executor"
 )
 
 // ErrNilVMHost signals that the provided VMHost is nil
-var ErrNilVMHost = errors.New("nil VMHost")
+var ErrNilVMHost = NewVMError(ErrorCategoryValidation, 1001, "nil VMHost")
 
 // ErrReturnCodeNotOk signals that the returned code is different than vmcommon.Ok
-var ErrReturnCodeNotOk = errors.New("return code is not ok")
+var ErrReturnCodeNotOk = NewVMError(ErrorCategoryExecutor, 2001, "return code is not ok")
 
 // ErrInvalidCallOnReadOnlyMode signals that an operation is not permitted due to read only mode
-var ErrInvalidCallOnReadOnlyMode = errors.New("operation not permitted in read only mode")
+var ErrInvalidCallOnReadOnlyMode = NewVMError(ErrorCategoryValidation, 1002, "operation not permitted in read only mode")
 
 // ErrNotEnoughGas signals that there is not enough gas for the operation
-var ErrNotEnoughGas = errors.New("not enough gas")
+var ErrNotEnoughGas = NewVMError(ErrorCategoryGas, 6001, "not enough gas")
 
 // ErrUnhandledRuntimeBreakpoint signals that the runtime breakpoint is unhandled
-var ErrUnhandledRuntimeBreakpoint = errors.New("unhandled runtime breakpoint")
+var ErrUnhandledRuntimeBreakpoint = NewVMError(ErrorCategoryExecutor, 2002, "unhandled runtime breakpoint")
 
 // ErrSignalError is given when the smart contract signals an error
-var ErrSignalError = errors.New("error signalled by smartcontract")
+var ErrSignalError = NewVMError(ErrorCategoryExecutor, 2003, "error signalled by smartcontract")
 
 // ErrExecutionFailed signals that the execution failed
-var ErrExecutionFailed = errors.New("execution failed")
+var ErrExecutionFailed = NewVMError(ErrorCategoryExecutor, 2004, "execution failed")
 
 // ErrExecutionPanicked signals that the execution failed irrecoverably
-var ErrExecutionPanicked = errors.New("VM execution panicked")
+var ErrExecutionPanicked = NewVMError(ErrorCategoryExecutor, 2005, "VM execution panicked")
+
+// ErrInternalVMError signals that the execution was interrupted by a bug in the host code
+var ErrInternalVMError = NewVMError(ErrorCategoryExecutor, 2006, "internal VM error")
 
 // ErrExecutionFailedWithTimeout signals that the execution failed with timeout
-var ErrExecutionFailedWithTimeout = errors.New("execution failed with timeout")
+var ErrExecutionFailedWithTimeout = NewVMError(ErrorCategoryExecutor, 2007, "execution failed with timeout")
 
 // ErrMemoryLimit signals that too much memory was allocated by the contract
-var ErrMemoryLimit = errors.New("memory limit reached")
+var ErrMemoryLimit = NewVMError(ErrorCategoryExecutor, 2008, "memory limit reached")
 
 // ErrBadBounds signals that a certain variable is out of bounds
-var ErrBadBounds = errors.New("bad bounds")
+var ErrBadBounds = NewVMError(ErrorCategoryValidation, 1003, "bad bounds")
 
 // ErrBadLowerBounds signals that a certain variable is lower than allowed
-var ErrBadLowerBounds = fmt.Errorf("%w (lower)", ErrBadBounds)
+var ErrBadLowerBounds = NewWrappedVMError(ErrorCategoryValidation, 1004, ErrBadBounds, "(lower)")
 
 // ErrBadUpperBounds signals that a certain variable is higher than allowed
-var ErrBadUpperBounds = fmt.Errorf("%w (upper)", ErrBadBounds)
+var ErrBadUpperBounds = NewWrappedVMError(ErrorCategoryValidation, 1005, ErrBadBounds, "(upper)")
 
 // ErrNegativeLength signals that the given length is less than 0
-var ErrNegativeLength = errors.New("negative length")
+var ErrNegativeLength = NewVMError(ErrorCategoryValidation, 1006, "negative length")
 
 // ErrFailedTransfer signals that the transfer operation has failed
-var ErrFailedTransfer = errors.New("failed transfer")
+var ErrFailedTransfer = NewVMError(ErrorCategoryHook, 3001, "failed transfer")
 
 // ErrTransferInsufficientFunds signals that the transfer has failed due to insufficient funds
-var ErrTransferInsufficientFunds = fmt.Errorf("%w (insufficient funds)", ErrFailedTransfer)
+var ErrTransferInsufficientFunds = NewWrappedVMError(ErrorCategoryHook, 3002, ErrFailedTransfer, "(insufficient funds)")
 
 // ErrTransferNegativeValue signals that the transfer has failed due to the fact that the value is less than 0
-var ErrTransferNegativeValue = fmt.Errorf("%w (negative value)", ErrFailedTransfer)
+var ErrTransferNegativeValue = NewWrappedVMError(ErrorCategoryHook, 3003, ErrFailedTransfer, "(negative value)")
 
 // ErrUpgradeFailed signals that the upgrade encountered an error
-var ErrUpgradeFailed = errors.New("upgrade failed")
+var ErrUpgradeFailed = NewVMError(ErrorCategoryExecutor, 2009, "upgrade failed")
 
 // ErrInvalidUpgradeArguments signals that the upgrade process failed due to invalid arguments
-var ErrInvalidUpgradeArguments = fmt.Errorf("%w (invalid arguments)", ErrUpgradeFailed)
+var ErrInvalidUpgradeArguments = NewWrappedVMError(ErrorCategoryValidation, 1007, ErrUpgradeFailed, "(invalid arguments)")
 
 // ErrInitFuncCalledInRun signals that the init func was called directly, which is forbidden
-var ErrInitFuncCalledInRun = fmt.Errorf("%w (calling init() directly is forbidden)", executor.ErrInvalidFunction)
+var ErrInitFuncCalledInRun = NewWrappedVMError(ErrorCategoryValidation, 1008, executor.ErrInvalidFunction, "(calling init() directly is forbidden)")
 
 // ErrCallBackFuncCalledInRun signals that a callback func was called directly, which is forbidden
-var ErrCallBackFuncCalledInRun = fmt.Errorf("%w (calling callBack() directly is forbidden)", executor.ErrInvalidFunction)
+var ErrCallBackFuncCalledInRun = NewWrappedVMError(ErrorCategoryValidation, 1009, executor.ErrInvalidFunction, "(calling callBack() directly is forbidden)")
 
 // ErrCallBackFuncNotExpected signals that an unexpected callback was received
-var ErrCallBackFuncNotExpected = fmt.Errorf("%w (unexpected callback was received)", executor.ErrInvalidFunction)
+var ErrCallBackFuncNotExpected = NewWrappedVMError(ErrorCategoryValidation, 1010, executor.ErrInvalidFunction, "(unexpected callback was received)")
 
 // ErrInvalidFunctionName signals that the function name is invalid
-var ErrInvalidFunctionName = fmt.Errorf("%w (invalid name)", executor.ErrInvalidFunction)
+var ErrInvalidFunctionName = NewWrappedVMError(ErrorCategoryValidation, 1011, executor.ErrInvalidFunction, "(invalid name)")
 
 // ErrContractInvalid signals that the contract code is invalid
-var ErrContractInvalid = fmt.Errorf("invalid contract code")
+var ErrContractInvalid = NewVMError(ErrorCategoryValidation, 1012, "invalid contract code")
 
 // ErrContractNotFound signals that the contract was not found
-var ErrContractNotFound = fmt.Errorf("%w (not found)", ErrContractInvalid)
+var ErrContractNotFound = NewWrappedVMError(ErrorCategoryExecutor, 2010, ErrContractInvalid, "(not found)")
 
 // ErrMemoryDeclarationMissing signals that a memory declaration is missing
-var ErrMemoryDeclarationMissing = fmt.Errorf("%w (missing memory declaration)", ErrContractInvalid)
+var ErrMemoryDeclarationMissing = NewWrappedVMError(ErrorCategoryValidation, 1013, ErrContractInvalid, "(missing memory declaration)")
 
 // ErrMaxInstancesReached signals that the max number of Wasmer instances has been reached.
-var ErrMaxInstancesReached = fmt.Errorf("%w (max instances reached)", ErrExecutionFailed)
+var ErrMaxInstancesReached = NewWrappedVMError(ErrorCategoryExecutor, 2011, ErrExecutionFailed, "(max instances reached)")
 
 // ErrStoreElrondReservedKey signals that an attempt to write under an reserved key has been made
-var ErrStoreElrondReservedKey = errors.New("cannot write to storage under Elrond reserved key")
+var ErrStoreElrondReservedKey = NewVMError(ErrorCategoryStorage, 5001, "cannot write to storage under Elrond reserved key")
 
 // ErrCannotWriteProtectedKey signals an attempt to write to a protected key, while storage protection is enforced
-var ErrCannotWriteProtectedKey = errors.New("cannot write to protected key")
+var ErrCannotWriteProtectedKey = NewVMError(ErrorCategoryStorage, 5002, "cannot write to protected key")
 
 // ErrCannotWriteInReadOnlyMode signals an attempt to write to storage when running in read-only mode
-var ErrCannotWriteInReadOnlyMode = errors.New("cannot write to storage in read-only mode")
+var ErrCannotWriteInReadOnlyMode = NewVMError(ErrorCategoryStorage, 5003, "cannot write to storage in read-only mode")
 
 // ErrNonPayableFunctionEgld signals that a non-payable function received non-zero call value
-var ErrNonPayableFunctionEgld = errors.New("function does not accept EGLD payment")
+var ErrNonPayableFunctionEgld = NewVMError(ErrorCategoryValidation, 1014, "function does not accept EGLD payment")
 
 // ErrNonPayableFunctionEsdt signals that a non-payable function received non-zero ESDT call value
-var ErrNonPayableFunctionEsdt = errors.New("function does not accept ESDT payment")
+var ErrNonPayableFunctionEsdt = NewVMError(ErrorCategoryValidation, 1015, "function does not accept ESDT payment")
 
 // ErrArgIndexOutOfRange signals that the argument index is out of range
-var ErrArgIndexOutOfRange = errors.New("argument index out of range")
+var ErrArgIndexOutOfRange = NewVMError(ErrorCategoryValidation, 1016, "argument index out of range")
 
 // ErrArgOutOfRange signals that the argument is out of range
-var ErrArgOutOfRange = errors.New("argument out of range")
+var ErrArgOutOfRange = NewVMError(ErrorCategoryValidation, 1017, "argument out of range")
 
 // ErrStorageValueOutOfRange signals that the storage value is out of range
-var ErrStorageValueOutOfRange = errors.New("storage value out of range")
+var ErrStorageValueOutOfRange = NewVMError(ErrorCategoryStorage, 5004, "storage value out of range")
 
 // ErrDivZero signals that an attempt to divide by 0 has been made
-var ErrDivZero = errors.New("division by 0")
+var ErrDivZero = NewVMError(ErrorCategoryValidation, 1018, "division by 0")
 
 // ErrBitwiseNegative signals that an attempt to apply a bitwise operation on negative numbers has been made
-var ErrBitwiseNegative = errors.New("bitwise operations only allowed on positive integers")
+var ErrBitwiseNegative = NewVMError(ErrorCategoryValidation, 1019, "bitwise operations only allowed on positive integers")
 
 // ErrShiftNegative signals that an attempt to apply a bitwise shift operation on negative numbers has been made
-var ErrShiftNegative = errors.New("bitwise shift operations only allowed on positive integers and by a positive amount")
+var ErrShiftNegative = NewVMError(ErrorCategoryValidation, 1020, "bitwise shift operations only allowed on positive integers and by a positive amount")
 
 // ErrAsyncContextUnmodifiableUnlessFirstSCOrFirstCallback signals that the current contract instance cannot modify the AsyncContext
-var ErrAsyncContextUnmodifiableUnlessFirstSCOrFirstCallback = errors.New("AsyncContext can only be modified by the first contract instance with same address or first callback")
+var ErrAsyncContextUnmodifiableUnlessFirstSCOrFirstCallback = NewVMError(ErrorCategoryAsync, 4001, "AsyncContext can only be modified by the first contract instance with same address or first callback")
 
 // ErrAsyncCallGroupExistsAlready signals that an AsyncCallGroup with the same name already exists
-var ErrAsyncCallGroupExistsAlready = errors.New("async call group exists already")
+var ErrAsyncCallGroupExistsAlready = NewVMError(ErrorCategoryAsync, 4002, "async call group exists already")
 
 // ErrAsyncCallGroupDoesNotExist signals that the requested AsyncCallGroup does not exist
-var ErrAsyncCallGroupDoesNotExist = errors.New("async call group does not exist")
+var ErrAsyncCallGroupDoesNotExist = NewVMError(ErrorCategoryAsync, 4003, "async call group does not exist")
 
 // ErrAsyncCallGroupAlreadyComplete signals that no further operations are possible on the AsyncCallGroup because it is complete
-var ErrAsyncCallGroupAlreadyComplete = errors.New("async call group already complete")
+var ErrAsyncCallGroupAlreadyComplete = NewVMError(ErrorCategoryAsync, 4004, "async call group already complete")
 
 // ErrOnlyOneAsyncCallAllowedToAddress signals that there was an attempt to add an AsyncCall to an address already called asynchronously
-var ErrOnlyOneAsyncCallAllowedToAddress = errors.New("only one async call allowed to an address")
+var ErrOnlyOneAsyncCallAllowedToAddress = NewVMError(ErrorCategoryAsync, 4005, "only one async call allowed to an address")
 
 // ErrNilDestinationCallVMOutput signals that the destination call execution returned a nil VMOutput
-var ErrNilDestinationCallVMOutput = errors.New("nil destination call VMOutput")
+var ErrNilDestinationCallVMOutput = NewVMError(ErrorCategoryAsync, 4006, "nil destination call VMOutput")
 
 // ErrNilCallbackVMOutput signals that the callback execution returned a nil VMOutput
-var ErrNilCallbackVMOutput = errors.New("nil callback VMOutput")
+var ErrNilCallbackVMOutput = NewVMError(ErrorCategoryAsync, 4007, "nil callback VMOutput")
 
 // ErrAsyncCallNotFound signals that the requested AsyncCall was not found
-var ErrAsyncCallNotFound = errors.New("async call not found")
+var ErrAsyncCallNotFound = NewVMError(ErrorCategoryAsync, 4008, "async call not found")
 
 // ErrUnknownCallType signals that the call type is not recognized
-var ErrUnknownCallType = errors.New("unknown call type")
+var ErrUnknownCallType = NewVMError(ErrorCategoryAsync, 4009, "unknown call type")
 
 // ErrCannotUseBuiltinAsCallback signals that the specified callback was set to a built-in function, which is forbidden
-var ErrCannotUseBuiltinAsCallback = errors.New("cannot use built-in function as callback")
+var ErrCannotUseBuiltinAsCallback = NewVMError(ErrorCategoryAsync, 4010, "cannot use built-in function as callback")
 
 // ErrInvalidAsyncCallGroupID signals that the AsyncCallGroup identifier is invalid
-var ErrInvalidAsyncCallGroupID = errors.New("invalid async call group identifier")
+var ErrInvalidAsyncCallGroupID = NewVMError(ErrorCategoryAsync, 4011, "invalid async call group identifier")
 
 // ErrOnlyOneLegacyAsyncCallAllowed signals that there was an attempt to create more than one legacy async calls, which is forbidden
-var ErrOnlyOneLegacyAsyncCallAllowed = errors.New("only one legacy async call allowed")
+var ErrOnlyOneLegacyAsyncCallAllowed = NewVMError(ErrorCategoryAsync, 4012, "only one legacy async call allowed")
 
 // ErrLegacyAsyncCallNotFound signals that a legacy async call was expected, but is missing
-var ErrLegacyAsyncCallNotFound = errors.New("legacy async call not found")
+var ErrLegacyAsyncCallNotFound = NewVMError(ErrorCategoryAsync, 4013, "legacy async call not found")
 
 // ErrLegacyAsyncCallInvalid signals that the legacy async call is invalid
-var ErrLegacyAsyncCallInvalid = errors.New("legacy async call invalid")
+var ErrLegacyAsyncCallInvalid = NewVMError(ErrorCategoryAsync, 4014, "legacy async call invalid")
 
 // ErrNoStoredAsyncContextFound signals that no persisted data was found for the AsyncContext to load
-var ErrNoStoredAsyncContextFound = errors.New("no async context found in storage")
+var ErrNoStoredAsyncContextFound = NewVMError(ErrorCategoryAsync, 4015, "no async context found in storage")
 
 // ErrCannotInterpretCallbackArgs signals that the cross-shard callback arguments are invalid
-var ErrCannotInterpretCallbackArgs = errors.New("cannot interpret callback args")
+var ErrCannotInterpretCallbackArgs = NewVMError(ErrorCategoryAsync, 4016, "cannot interpret callback args")
 
 // ErrAsyncContextDoesNotExist signals that the async context does not exist
-var ErrAsyncContextDoesNotExist = errors.New("async context does not exist")
+var ErrAsyncContextDoesNotExist = NewVMError(ErrorCategoryAsync, 4017, "async context does not exist")
 
 // ErrContextCallbackDisabled signals that group callbacks cannot be set nor executed
-var ErrContextCallbackDisabled = errors.New("context callback disabled")
+var ErrContextCallbackDisabled = NewVMError(ErrorCategoryAsync, 4018, "context callback disabled")
 
 // ErrInvalidAccount signals that a certain account does not exist
-var ErrInvalidAccount = errors.New("account does not exist")
+var ErrInvalidAccount = NewVMError(ErrorCategoryHook, 3004, "account does not exist")
 
 // ErrDeploymentOverExistingAccount signals that an attempt to deploy a new SC over an already existing account has been made
-var ErrDeploymentOverExistingAccount = errors.New("cannot deploy over existing account")
+var ErrDeploymentOverExistingAccount = NewVMError(ErrorCategoryHook, 3005, "cannot deploy over existing account")
 
 // ErrAccountNotPayable signals that the value transfer to a non payable contract is not possible
-var ErrAccountNotPayable = errors.New("sending value to non payable contract")
+var ErrAccountNotPayable = NewVMError(ErrorCategoryHook, 3006, "sending value to non payable contract")
 
 // ErrInvalidPublicKeySize signals that the public key size is invalid
-var ErrInvalidPublicKeySize = errors.New("invalid public key size")
+var ErrInvalidPublicKeySize = NewVMError(ErrorCategoryValidation, 1021, "invalid public key size")
 
 // ErrNilCallbackFunction signals that a nil callback function has been provided
-var ErrNilCallbackFunction = errors.New("nil callback function")
+var ErrNilCallbackFunction = NewVMError(ErrorCategoryValidation, 1022, "nil callback function")
 
 // ErrUpgradeNotAllowed signals that an upgrade is not allowed
-var ErrUpgradeNotAllowed = errors.New("upgrade not allowed")
+var ErrUpgradeNotAllowed = NewVMError(ErrorCategoryExecutor, 2012, "upgrade not allowed")
 
 // ErrNilContract signals that the contract is nil
-var ErrNilContract = errors.New("nil contract")
+var ErrNilContract = NewVMError(ErrorCategoryValidation, 1023, "nil contract")
 
 // ErrBuiltinCallOnSameContextDisallowed signals that calling a built-in function on the same context is not allowed
-var ErrBuiltinCallOnSameContextDisallowed = errors.New("calling built-in function on the same context is disallowed")
+var ErrBuiltinCallOnSameContextDisallowed = NewVMError(ErrorCategoryHook, 3007, "calling built-in function on the same context is disallowed")
 
 // ErrSyncExecutionNotInSameShard signals that the sync execution request is not in the same shard
-var ErrSyncExecutionNotInSameShard = errors.New("sync execution request is not in the same shard")
+var ErrSyncExecutionNotInSameShard = NewVMError(ErrorCategoryHook, 3008, "sync execution request is not in the same shard")
 
 // ErrInputAndOutputGasDoesNotMatch is raised when the output gas (gas used + gas locked + gas remaining)
 // is not equal to the input gas
-var ErrInputAndOutputGasDoesNotMatch = errors.New("input and output gas does not match")
+var ErrInputAndOutputGasDoesNotMatch = NewVMError(ErrorCategoryGas, 6002, "input and output gas does not match")
 
 // ErrTransferValueOnESDTCall signals that balance transfer was given in esdt call
-var ErrTransferValueOnESDTCall = errors.New("transfer value on esdt call")
+var ErrTransferValueOnESDTCall = NewVMError(ErrorCategoryHook, 3009, "transfer value on esdt call")
 
 // ErrNoBigIntUnderThisHandle signals that there is no bigInt for the given handle
-var ErrNoBigIntUnderThisHandle = errors.New("no bigInt under the given handle")
+var ErrNoBigIntUnderThisHandle = NewVMError(ErrorCategoryValidation, 1024, "no bigInt under the given handle")
 
 // ErrNoBigFloatUnderThisHandle signals that there is no bigInt for the given handle
-var ErrNoBigFloatUnderThisHandle = errors.New("no bigFloat under the given handle")
+var ErrNoBigFloatUnderThisHandle = NewVMError(ErrorCategoryValidation, 1025, "no bigFloat under the given handle")
 
 // ErrPositiveExponent signals that the exponent is greater or equal to 0
-var ErrPositiveExponent = errors.New("exponent must be negative")
+var ErrPositiveExponent = NewVMError(ErrorCategoryValidation, 1026, "exponent must be negative")
 
 // ErrLengthOfBufferNotCorrect signals that length of the buffer is not correct
-var ErrLengthOfBufferNotCorrect = errors.New("length of buffer is not correct")
+var ErrLengthOfBufferNotCorrect = NewVMError(ErrorCategoryValidation, 1027, "length of buffer is not correct")
 
 // ErrNoEllipticCurveUnderThisHandle singals that there is no elliptic curve for the given handle
-var ErrNoEllipticCurveUnderThisHandle = errors.New("no elliptic curve under the given handle")
+var ErrNoEllipticCurveUnderThisHandle = NewVMError(ErrorCategoryValidation, 1028, "no elliptic curve under the given handle")
 
 // ErrPointNotOnCurve signals that the point to be used is not on curve
-var ErrPointNotOnCurve = errors.New("point is not on curve")
+var ErrPointNotOnCurve = NewVMError(ErrorCategoryValidation, 1029, "point is not on curve")
 
 // ErrNoManagedBufferUnderThisHandle signals that there is no buffer for the given handle
-var ErrNoManagedBufferUnderThisHandle = errors.New("no managed buffer under the given handle")
+var ErrNoManagedBufferUnderThisHandle = NewVMError(ErrorCategoryValidation, 1030, "no managed buffer under the given handle")
 
 // ErrNilHostParameters signals that nil host parameters was provided
-var ErrNilHostParameters = errors.New("nil host parameters")
+var ErrNilHostParameters = NewVMError(ErrorCategoryValidation, 1031, "nil host parameters")
 
 // ErrNilESDTTransferParser signals that nil esdt transfer parser was provided
-var ErrNilESDTTransferParser = errors.New("nil esdt transfer parser")
+var ErrNilESDTTransferParser = NewVMError(ErrorCategoryValidation, 1032, "nil esdt transfer parser")
 
 // ErrNilCallArgsParser signals that nil call arguments parser was provided
-var ErrNilCallArgsParser = errors.New("nil call args parser")
+var ErrNilCallArgsParser = NewVMError(ErrorCategoryValidation, 1033, "nil call args parser")
 
 // ErrNilBuiltInFunctionsContainer signals that nil built in functions container was provided
-var ErrNilBuiltInFunctionsContainer = errors.New("nil built in functions container")
+var ErrNilBuiltInFunctionsContainer = NewVMError(ErrorCategoryValidation, 1034, "nil built in functions container")
 
 // ErrNilBlockChainHook signals that nil blockchain hook was provided
-var ErrNilBlockChainHook = errors.New("nil blockchain hook")
+var ErrNilBlockChainHook = NewVMError(ErrorCategoryHook, 3010, "nil blockchain hook")
 
 // ErrTooManyESDTTransfers signals that too many ESDT transfers are in sc call
-var ErrTooManyESDTTransfers = errors.New("too many ESDT transfers")
+var ErrTooManyESDTTransfers = NewVMError(ErrorCategoryHook, 3011, "too many ESDT transfers")
 
 // ErrInfinityFloatOperation signals that operations with infinity are not allowed
-var ErrInfinityFloatOperation = errors.New("infinity operations are not allowed")
+var ErrInfinityFloatOperation = NewVMError(ErrorCategoryValidation, 1035, "infinity operations are not allowed")
 
 // ErrBigFloatWrongPrecision signals that the precision has a wrong value
-var ErrBigFloatWrongPrecision = errors.New("precision of the big float must be 53")
+var ErrBigFloatWrongPrecision = NewVMError(ErrorCategoryValidation, 1036, "precision of the big float must be 53")
 
 // ErrAllOperandsAreEqualToZero signals that all operands are equal to 0
-var ErrAllOperandsAreEqualToZero = errors.New("all operands are equal to 0")
+var ErrAllOperandsAreEqualToZero = NewVMError(ErrorCategoryValidation, 1037, "all operands are equal to 0")
 
 // ErrExponentTooBigOrTooSmall signals that the exponent is too big or too small
-var ErrExponentTooBigOrTooSmall = errors.New("exponent is either too small or too big")
+var ErrExponentTooBigOrTooSmall = NewVMError(ErrorCategoryValidation, 1038, "exponent is either too small or too big")
 
 // ErrNilEpochNotifier signals that epoch notifier is nil
-var ErrNilEpochNotifier = errors.New("nil epoch notifier")
+var ErrNilEpochNotifier = NewVMError(ErrorCategoryValidation, 1039, "nil epoch notifier")
 
 // ErrNilEnableEpochsHandler signals that enable epochs handler is nil
-var ErrNilEnableEpochsHandler = errors.New("nil enable epochs handler")
+var ErrNilEnableEpochsHandler = NewVMError(ErrorCategoryValidation, 1040, "nil enable epochs handler")
 
 // ErrNoAsyncParentContext signals that load parent was called for an async call
-var ErrNoAsyncParentContext = errors.New("this should not be called for async calls (only callbacks and direct calls)")
+var ErrNoAsyncParentContext = NewVMError(ErrorCategoryAsync, 4019, "this should not be called for async calls (only callbacks and direct calls)")
 
 // ErrInvalidAsyncArgsList signals that an error in the async framework produced an invalid arguments list
-var ErrInvalidAsyncArgsList = errors.New("async call has an invalid arguments list")
+var ErrInvalidAsyncArgsList = NewVMError(ErrorCategoryAsync, 4020, "async call has an invalid arguments list")
 
 // ErrAsyncInit signals an async context initialization error
-var ErrAsyncInit = errors.New("async context initialization error")
+var ErrAsyncInit = NewVMError(ErrorCategoryAsync, 4021, "async context initialization error")
 
 // ErrAsyncNoOutputFromCallback signals that an error happen while producing the output of a callback
-var ErrAsyncNoOutputFromCallback = errors.New("callback VMOutput should not be nil")
+var ErrAsyncNoOutputFromCallback = NewVMError(ErrorCategoryAsync, 4022, "callback VMOutput should not be nil")
 
 // ErrAsyncNoMultiLevel signals that no multi-level async calls are allowed
-var ErrAsyncNoMultiLevel = errors.New("multi-level async calls are not allowed yet")
+var ErrAsyncNoMultiLevel = NewVMError(ErrorCategoryAsync, 4023, "multi-level async calls are not allowed yet")
 
 // ErrAsyncFrameworkPopCallID signals an error in the async fremwork that did not fill the correct call id arguments
-var ErrAsyncFrameworkPopCallID = errors.New("async framework error - PopCallIDsFromArguments")
+var ErrAsyncFrameworkPopCallID = NewVMError(ErrorCategoryAsync, 4024, "async framework error - PopCallIDsFromArguments")
 
 // ErrAsyncNoCallbackForClosure signals that closure can't be obtained
-var ErrAsyncNoCallbackForClosure = errors.New("no callback for closure, cannot call callback directly")
+var ErrAsyncNoCallbackForClosure = NewVMError(ErrorCategoryAsync, 4025, "no callback for closure, cannot call callback directly")
 
 // ErrNotBuiltInNFTCreate signals that function is not of built in NFT create
-var ErrNotBuiltInNFTCreate = errors.New("not built in NFT create")
+var ErrNotBuiltInNFTCreate = NewVMError(ErrorCategoryHook, 3012, "not built in NFT create")
 
 // ErrCallerIsSC signals that caller is a smart contract
-var ErrCallerIsSC = errors.New("caller is a smart contract")
+var ErrCallerIsSC = NewVMError(ErrorCategoryHook, 3013, "caller is a smart contract")
 
 // ErrVMIsClosing signals that vm is closing
-var ErrVMIsClosing = errors.New("vm is closing")
+var ErrVMIsClosing = NewVMError(ErrorCategoryExecutor, 2013, "vm is closing")
 
 // ErrNilESDTData is given when ESDT data is missing
-var ErrNilESDTData = errors.New("nil esdt data")
+var ErrNilESDTData = NewVMError(ErrorCategoryHook, 3014, "nil esdt data")
 
 // ErrInvalidArgument is given when argument is invalid
-var ErrInvalidArgument = errors.New("invalid argument")
+var ErrInvalidArgument = NewVMError(ErrorCategoryValidation, 1041, "invalid argument")
 
 // ErrInvalidTokenIndex is given when argument is invalid
-var ErrInvalidTokenIndex = errors.New("invalid token index")
+var ErrInvalidTokenIndex = NewVMError(ErrorCategoryValidation, 1042, "invalid token index")
 
 // ErrInvalidBuiltInFunctionCall signals that built in function was used in the wrong context
-var ErrInvalidBuiltInFunctionCall = errors.New("invalid built in function call")
+var ErrInvalidBuiltInFunctionCall = NewVMError(ErrorCategoryHook, 3015, "invalid built in function call")
+
+// ErrInvalidGasPhase signals that a contract marked a gas phase unknown to the VM
+var ErrInvalidGasPhase = NewVMError(ErrorCategoryHook, 3016, "invalid gas phase")
+
+// ErrLibraryNotReadable signals that a contract called on the same context is not marked as readable, so it cannot serve as a library
+var ErrLibraryNotReadable = NewVMError(ErrorCategoryHook, 3017, "library contract is not readable")
 
 // ErrCannotWriteOnReadOnly signals that write operation on read only is not allowed
-var ErrCannotWriteOnReadOnly = errors.New("cannot write on read only mode")
+var ErrCannotWriteOnReadOnly = NewVMError(ErrorCategoryValidation, 1043, "cannot write on read only mode")
+
+// ErrHostFunctionNotActive signals that the contract imports a host function which is not active in the current epoch
+var ErrHostFunctionNotActive = NewWrappedVMError(ErrorCategoryExecutor, 2014, executor.ErrFuncNotFound, "(host function not active)")
+
+// ErrMaxReturnDataSizeExceeded signals that a contract tried to return more data than allowed
+var ErrMaxReturnDataSizeExceeded = NewVMError(ErrorCategoryExecutor, 2015, "max return data size exceeded")
+
+// ErrNoFinishReserved signals that a contract appended to a finish without reserving it first
+var ErrNoFinishReserved = NewVMError(ErrorCategoryExecutor, 2016, "no finish reserved")
+
+// ErrFinishReservationExceeded signals that a contract appended more data to a finish than it had reserved
+var ErrFinishReservationExceeded = NewVMError(ErrorCategoryExecutor, 2017, "finish reservation exceeded")
+
+// ErrInvalidEscrowBeneficiary signals that tokens were locked in favor of an account which is not another contract of the same shard
+var ErrInvalidEscrowBeneficiary = NewVMError(ErrorCategoryExecutor, 2018, "invalid escrow beneficiary")
+
+// ErrEscrowNotFound signals that the escrow to settle does not exist or was already settled
+var ErrEscrowNotFound = NewVMError(ErrorCategoryExecutor, 2019, "escrow not found")
+
+// ErrNotEscrowBeneficiary signals that an escrow was settled by a contract other than its beneficiary
+var ErrNotEscrowBeneficiary = NewVMError(ErrorCategoryExecutor, 2020, "not the escrow beneficiary")
+
+// ErrMaxStackReached signals that the nested calls of a contract exceeded the maximum stack height of the gas schedule
+var ErrMaxStackReached = NewVMError(ErrorCategoryExecutor, 2021, "max stack reached")
+
+// ErrMaxManagedAllocationExceeded signals that the managed buffers and big ints of an execution allocated more bytes than allowed
+var ErrMaxManagedAllocationExceeded = NewVMError(ErrorCategoryExecutor, 2022, "max managed allocation exceeded")
+
+// ErrLogLimitExceeded signals that a contract tried to write more logs, or larger logs, than allowed
+var ErrLogLimitExceeded = NewVMError(ErrorCategoryExecutor, 2023, "log limit exceeded")
+
+// ErrMaxNumberOfLogsExceeded signals that a contract tried to write more logs than allowed
+var ErrMaxNumberOfLogsExceeded = NewWrappedVMError(ErrorCategoryExecutor, 2024, ErrLogLimitExceeded, "(max number of logs)")
+
+// ErrMaxLogSizeExceeded signals that a contract tried to write a log larger than allowed
+var ErrMaxLogSizeExceeded = NewWrappedVMError(ErrorCategoryExecutor, 2025, ErrLogLimitExceeded, "(max log size)")
+
+// ErrStorageMigrationOutsideUpgrade signals that a contract registered a storage migration outside of its upgrade
+var ErrStorageMigrationOutsideUpgrade = NewVMError(ErrorCategoryExecutor, 2026, "storage migrations can only be registered during an upgrade")
+
+// ErrInvalidStorageMigration signals that a registered storage migration has invalid prefixes, indexes or re-encoding endpoint
+var ErrInvalidStorageMigration = NewVMError(ErrorCategoryExecutor, 2027, "invalid storage migration")
+
+// ErrTooManyStorageMigrations signals that a contract registered more storage migrations than can be pending at once
+var ErrTooManyStorageMigrations = NewVMError(ErrorCategoryExecutor, 2028, "too many storage migrations")
+
+// ErrStorageMigrationDuringUpgrade signals that a contract tried to migrate its storage before its new code was deployed
+var ErrStorageMigrationDuringUpgrade = NewVMError(ErrorCategoryExecutor, 2029, "storage migrations cannot run during an upgrade")
+
+// ErrInvalidMigratedStorageValue signals that the re-encoding endpoint of a storage migration did not finish exactly one value
+var ErrInvalidMigratedStorageValue = NewVMError(ErrorCategoryExecutor, 2030, "invalid migrated storage value")
+
+// ErrInvalidESDTTransferEncoding signals that a serialized ESDT transfer does not have the expected length
+var ErrInvalidESDTTransferEncoding = NewVMError(ErrorCategoryValidation, 1044, "invalid ESDT transfer object encoding")
+
+// ErrInvalidManagedESDTTransfersVector signals that a managed vector does not hold whole ESDT transfers
+var ErrInvalidManagedESDTTransfersVector = NewVMError(ErrorCategoryValidation, 1045, "invalid managed vector of ESDT transfers")
+
+// ErrInvalidManagedBufferHandlesVector signals that a managed vector does not hold whole managed buffer handles
+var ErrInvalidManagedBufferHandlesVector = NewVMError(ErrorCategoryValidation, 1046, "invalid managed vector of managed buffer handles")
+
+// ErrInvalidVMVersionSchedule signals that the versions of a schedule are not in ascending order of their start epochs
+var ErrInvalidVMVersionSchedule = NewVMError(ErrorCategoryValidation, 1047, "invalid VM version schedule")
+
+// ErrInvalidCallIDLength signals that the call ID of an async context is longer than an address
+var ErrInvalidCallIDLength = NewVMError(ErrorCategoryAsync, 4026, "callID must be 32 bytes")
+
+// ErrEmptyElrondProtectedKeyPrefix signals that the storage context was created without a protected key prefix
+var ErrEmptyElrondProtectedKeyPrefix = NewVMError(ErrorCategoryStorage, 5005, "elrondProtectedKeyPrefix cannot be empty")
+
+// ErrStorageKeyTooLong signals an attempt to write to storage under a key longer than the storage limits allow
+var ErrStorageKeyTooLong = NewVMError(ErrorCategoryStorage, 5006, "storage key too long")
+
+// ErrStorageValueTooLong signals an attempt to write to storage a value longer than the storage limits allow
+var ErrStorageValueTooLong = NewVMError(ErrorCategoryStorage, 5007, "storage value too long")
+
+// ErrInvalidCallbackArgType signals that an async call registered an unknown callback argument type
+var ErrInvalidCallbackArgType = NewVMError(ErrorCategoryValidation, 1048, "invalid callback argument type")
+
+// ErrCallbackArgsDecode signals that the values finished by the destination of an async call do not match the registered callback argument types
+var ErrCallbackArgsDecode = NewVMError(ErrorCategoryAsync, 4027, "cannot decode callback arguments")
+
+// ErrInvalidAsyncCallExpiry signals that an async call was created with a negative expiry
+var ErrInvalidAsyncCallExpiry = NewVMError(ErrorCategoryValidation, 1049, "invalid async call expiry")
+
+// ErrAsyncCallExpired signals that the destination of an async call did not respond before its expiry round
+var ErrAsyncCallExpired = NewVMError(ErrorCategoryAsync, 4028, "async call expired")
+
+// ErrAsyncCallNotExpired signals that a timeout callback was received for an async call which has not expired
+var ErrAsyncCallNotExpired = NewVMError(ErrorCategoryAsync, 4029, "async call has not expired")
+
+// ErrMaxAsyncCallsPerGroupExceeded signals that a contract registered more async calls in a group than allowed
+var ErrMaxAsyncCallsPerGroupExceeded = NewVMError(ErrorCategoryAsync, 4030, "max async calls per group exceeded")
+
+// ErrMaxAsyncCallGroupsExceeded signals that a contract registered more async call groups than allowed
+var ErrMaxAsyncCallGroupsExceeded = NewVMError(ErrorCategoryAsync, 4031, "max async call groups exceeded")
+
+// ErrMaxOutputTransfersExceeded signals that a transaction generated more output transfers than allowed
+var ErrMaxOutputTransfersExceeded = NewVMError(ErrorCategoryAsync, 4032, "max output transfers exceeded")
+
+// ErrUnknownAsyncContextVersion signals that a persisted AsyncContext was written in a format newer than this VM understands
+var ErrUnknownAsyncContextVersion = NewVMError(ErrorCategoryAsync, 4033, "unknown async context version")
+
+// ErrAsyncSubGroupsDisabled signals that the callbacks and the sub-groups of async call groups are not enabled yet
+var ErrAsyncSubGroupsDisabled = NewVMError(ErrorCategoryAsync, 4034, "async call sub-groups disabled")
+
+// ErrInvalidAsyncCallGroup signals that an async call group cannot take the requested callback or sub-group
+var ErrInvalidAsyncCallGroup = NewVMError(ErrorCategoryAsync, 4035, "invalid async call group")
+
+// ErrInvalidPromiseGroupID signals that the identifier of a promise group is empty, too long or contains forbidden characters
+var ErrInvalidPromiseGroupID = NewVMError(ErrorCategoryAsync, 4036, "invalid promise group identifier")
+
+// ErrPromiseGroupExistsAlready signals that a contract created a promise group with the identifier of an existing group
+var ErrPromiseGroupExistsAlready = NewVMError(ErrorCategoryAsync, 4037, "promise group exists already")
+
+// ErrInvalidPromiseGroupHandle signals that a contract used a promise group handle which it did not create in the current execution
+var ErrInvalidPromiseGroupHandle = NewVMError(ErrorCategoryAsync, 4038, "invalid promise group handle")
+
+// ErrInvalidEndpoints signals that the exports of a deployed contract break the endpoint rules
+var ErrInvalidEndpoints = NewWrappedVMError(ErrorCategoryValidation, 1050, ErrContractInvalid, "(invalid endpoints)")
+
+// ErrContractLimitExceeded signals that the code of a deployed contract exceeds one of the configured contract limits
+var ErrContractLimitExceeded = NewWrappedVMError(ErrorCategoryValidation, 1051, ErrContractInvalid, "(limit exceeded)")
+
+// ErrCodeSizeLimitExceeded signals that the code of a deployed contract is larger than allowed
+var ErrCodeSizeLimitExceeded = NewWrappedVMError(ErrorCategoryValidation, 1052, ErrContractLimitExceeded, "(code size)")
+
+// ErrFunctionsLimitExceeded signals that a deployed contract defines more functions than allowed
+var ErrFunctionsLimitExceeded = NewWrappedVMError(ErrorCategoryValidation, 1053, ErrContractLimitExceeded, "(number of functions)")
+
+// ErrDataSegmentsLimitExceeded signals that a deployed contract has more data segments than allowed
+var ErrDataSegmentsLimitExceeded = NewWrappedVMError(ErrorCategoryValidation, 1054, ErrContractLimitExceeded, "(number of data segments)")
+
+// ErrDataSegmentsSizeLimitExceeded signals that the data segments of a deployed contract hold more bytes than allowed
+var ErrDataSegmentsSizeLimitExceeded = NewWrappedVMError(ErrorCategoryValidation, 1055, ErrContractLimitExceeded, "(size of data segments)")
+
+// ErrTableSizeLimitExceeded signals that a table of a deployed contract has more initial elements than allowed
+var ErrTableSizeLimitExceeded = NewWrappedVMError(ErrorCategoryValidation, 1056, ErrContractLimitExceeded, "(table size)")
+
+// ErrWasmFeatureNotActive signals that a deployed contract uses a wasm proposal which is not yet active
+var ErrWasmFeatureNotActive = NewWrappedVMError(ErrorCategoryValidation, 1057, ErrContractInvalid, "(wasm feature not active)")
+
+// ErrInvalidAddressLength signals that an address does not have the length of the addresses of the chain
+var ErrInvalidAddressLength = NewVMError(ErrorCategoryValidation, 1058, "invalid address length")
+
+// ErrExecutionDenied signals that the execution policy of the host refused to execute or to deploy a contract
+var ErrExecutionDenied = NewVMError(ErrorCategoryValidation, 1059, "execution denied")
+
+// ErrSCExecutionPaused signals that the execution of the contracts is paused
+var ErrSCExecutionPaused = NewVMError(ErrorCategoryValidation, 1060, "smart contract execution paused")
+
+// ErrSenderNonceOverflow signals that the nonce of the sender of a transaction cannot be incremented anymore
+var ErrSenderNonceOverflow = NewVMError(ErrorCategoryValidation, 1061, "sender nonce overflow")
+
+// ErrDuplicatedTransaction signals that a transaction with the same hash was already executed in the current block
+var ErrDuplicatedTransaction = NewVMError(ErrorCategoryValidation, 1062, "duplicated transaction")
+
+// ErrEndpointNotCallable signals that the caller is not among the callers which the contract allows for the called endpoint
+var ErrEndpointNotCallable = NewWrappedVMError(ErrorCategoryValidation, 1063, executor.ErrInvalidFunction, "(caller not allowed by the callability of the endpoint)")
+
+// ErrRegisteredCallbackCalledInRun signals that a function registered as the callback of an async call was called directly, which is forbidden
+var ErrRegisteredCallbackCalledInRun = NewWrappedVMError(ErrorCategoryValidation, 1064, executor.ErrInvalidFunction, "(calling a registered callback directly is forbidden)")
+
+// ErrNonDeterministicContract signals that a deployed contract imports or uses constructs which threaten the determinism of its executions
+var ErrNonDeterministicContract = NewWrappedVMError(ErrorCategoryValidation, 1065, ErrContractInvalid, "(non-deterministic construct)")
+
+// ErrInvalidOutputTransfersEncoding signals that encoded output transfers are truncated or malformed
+var ErrInvalidOutputTransfersEncoding = NewVMError(ErrorCategoryValidation, 1066, "invalid output transfers encoding")
+
+// ErrEndpointArgumentsMismatch signals that the arguments of a call do not match the argument schema declared by the contract for the endpoint
+var ErrEndpointArgumentsMismatch = NewVMError(ErrorCategoryValidation, 1067, "endpoint arguments do not match their schema")
+
+// ErrInvalidVMConfiguration signals VM host parameters which are missing, out of range, or inconsistent with each other
+var ErrInvalidVMConfiguration = NewVMError(ErrorCategoryValidation, 1068, "invalid VM configuration")
+
+// ErrEndpointPaymentNotAccepted signals that a call transfers a token which is missing from the token whitelist declared by the contract for the endpoint
+var ErrEndpointPaymentNotAccepted = NewVMError(ErrorCategoryValidation, 1069, "payment not accepted by the endpoint")
+
+// ErrInvalidAsyncCallDestination signals that an async call was created towards an address which does not have the length of the addresses of the chain
+var ErrInvalidAsyncCallDestination = NewVMError(ErrorCategoryAsync, 4039, "invalid async call destination")
+
+// ErrInvalidAsyncCallFunction signals that the call data of an async call names a function which no contract can export
+var ErrInvalidAsyncCallFunction = NewVMError(ErrorCategoryAsync, 4040, "invalid async call function name")
+
+// ErrAsyncCallDataTooLarge signals that the call data of an async call exceeds the limit of the host
+var ErrAsyncCallDataTooLarge = NewVMError(ErrorCategoryAsync, 4041, "async call data too large")
+
+// ErrLockedValueExceeded signals that a callback would release more value than the ledger of locked values holds for its token
+var ErrLockedValueExceeded = NewVMError(ErrorCategoryAsync, 4042, "locked value exceeded")
+
+// ErrCompiledCodeNotRestored signals that the compiled code kept by the node for a contract could not be loaded by the executor
+var ErrCompiledCodeNotRestored = NewVMError(ErrorCategoryInfrastructure, 7001, "compiled code could not be restored")
+
+// ErrDeployedCodeNotCompiled signals that the executor failed to compile the code of a contract which was validated when deployed
+var ErrDeployedCodeNotCompiled = NewVMError(ErrorCategoryInfrastructure, 7002, "deployed code could not be compiled")
@@ -0,0 +1,46 @@
+package arwen
+
+// GasPhase is a logical phase of an endpoint, which the contract marks with
+// the gasPhase host function so that the gas tracers can attribute the gas
+// used between two markers to the phase of the first one
+type GasPhase int32
+
+const (
+	// GasPhaseArguments is the loading and checking of the arguments
+	GasPhaseArguments GasPhase = iota
+
+	// GasPhaseBody is the logic of the endpoint
+	GasPhaseBody
+
+	// GasPhaseStorage is the reading and writing of the storage
+	GasPhaseStorage
+
+	// GasPhaseFinish is the writing of the results
+	GasPhaseFinish
+
+	// GasPhaseAsync is the registration of the async calls and their callbacks
+	GasPhaseAsync
+)
+
+var gasPhaseNames = map[GasPhase]string{
+	GasPhaseArguments: "arguments",
+	GasPhaseBody:      "body",
+	GasPhaseStorage:   "storage",
+	GasPhaseFinish:    "finish",
+	GasPhaseAsync:     "async",
+}
+
+// IsValid returns true if the phase is known to the VM
+func (phase GasPhase) IsValid() bool {
+	_, ok := gasPhaseNames[phase]
+	return ok
+}
+
+// String returns the name of the phase
+func (phase GasPhase) String() string {
+	name, ok := gasPhaseNames[phase]
+	if !ok {
+		return "unknown"
+	}
+	return name
+}
@@ -0,0 +1,16 @@
+package arwen
+
+import "math/big"
+
+var _ GasValueConverter = (*FullPriceGasValueConverter)(nil)
+
+// FullPriceGasValueConverter is the GasValueConverter of the hosts which are
+// not given the one of the fee module: all the gas is paid at the gas price
+type FullPriceGasValueConverter struct {
+}
+
+// ComputeFee returns the gas multiplied by the gas price
+func (converter *FullPriceGasValueConverter) ComputeFee(gasUsed uint64, gasPrice uint64) *big.Int {
+	fee := big.NewInt(0).SetUint64(gasUsed)
+	return fee.Mul(fee, big.NewInt(0).SetUint64(gasPrice))
+}
@@ -8,10 +8,13 @@ import (
 	"path/filepath"
 
 	logger "github.com/ElrondNetwork/elrond-go-logger"
+	"github.com/ElrondNetwork/wasm-vm/logging"
 	"github.com/ElrondNetwork/wasm-vm/math"
 	"github.com/pelletier/go-toml"
 )
 
+var logHelpers = logging.GetOrCreate(logging.SubsystemHost, "arwen/helpers")
+
 // Zero is the big integer 0
 var Zero = big.NewInt(0)
 
@@ -106,7 +109,7 @@ func GetTestSCCodeModule(scName string, moduleName string, prefixToTestSCs strin
 func OpenFile(relativePath string) (*os.File, error) {
 	path, err := filepath.Abs(relativePath)
 	if err != nil {
-		fmt.Printf("cannot create absolute path for the provided file: %s", err.Error())
+		logHelpers.Error("cannot create absolute path for the provided file", "error", err)
 		return nil, err
 	}
 	f, err := os.Open(filepath.Clean(path))
@@ -126,7 +129,7 @@ func LoadTomlFileToMap(relativePath string) (map[string]interface{}, error) {
 
 	fileinfo, err := f.Stat()
 	if err != nil {
-		fmt.Printf("cannot stat file: %s", err.Error())
+		logHelpers.Error("cannot stat file", "error", err)
 		return nil, err
 	}
 
@@ -135,20 +138,20 @@ func LoadTomlFileToMap(relativePath string) (map[string]interface{}, error) {
 
 	_, err = f.Read(buffer)
 	if err != nil {
-		fmt.Printf("cannot read from file: %s", err.Error())
+		logHelpers.Error("cannot read from file", "error", err)
 		return nil, err
 	}
 
 	defer func() {
 		err = f.Close()
 		if err != nil {
-			fmt.Printf("cannot close file: %s", err.Error())
+			logHelpers.Error("cannot close file", "error", err)
 		}
 	}()
 
 	loadedTree, err := toml.Load(string(buffer))
 	if err != nil {
-		fmt.Printf("cannot interpret file contents as toml: %s", err.Error())
+		logHelpers.Error("cannot interpret file contents as toml", "error", err)
 		return nil, err
 	}
 
@@ -2,8 +2,10 @@ package host
 
 import (
 	"context"
+	"math/big"
 	"runtime/debug"
 	"sync"
+	"sync/atomic"
 	"time"
 
 	"github.com/ElrondNetwork/elrond-go-core/core/check"
@@ -18,11 +20,16 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/crypto"
 	"github.com/ElrondNetwork/wasm-vm/crypto/factory"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/interpreter"
+	"github.com/ElrondNetwork/wasm-vm/logging"
+	"github.com/ElrondNetwork/wasm-vm/metrics"
+	"github.com/ElrondNetwork/wasm-vm/policy"
+	"github.com/ElrondNetwork/wasm-vm/tracing"
 	"github.com/ElrondNetwork/wasm-vm/wasmer"
 )
 
-var log = logger.GetOrCreate("arwen/host")
-var logGasTrace = logger.GetOrCreate("gasTrace")
+var log = logging.GetOrCreate(logging.SubsystemHost, "arwen/host")
+var logGasTrace = logging.GetOrCreate(logging.SubsystemGas, "gasTrace")
 
 // MaximumRuntimeInstanceStackSize specifies the maximum number of allowed Wasmer
 // instances on the InstanceStack of the RuntimeContext
@@ -30,7 +37,7 @@ var MaximumRuntimeInstanceStackSize = uint64(10)
 
 var _ arwen.VMHost = (*vmHost)(nil)
 
-const minExecutionTimeout = time.Second
+const minExecutionTimeout = time.Duration(arwen.MinSCExecutionTimeoutMilliseconds) * time.Millisecond
 const internalVMErrors = "internalVMErrors"
 
 var defaultVMExecutorFactory executor.ExecutorAbstractFactory = wasmer.ExecutorFactory()
@@ -56,8 +63,31 @@ type vmHost struct {
 	builtInFuncContainer vmcommon.BuiltInFunctionContainer
 	esdtTransferParser   vmcommon.ESDTTransferParser
 	callArgsParser       arwen.CallArgsParser
-	enableEpochsHandler  vmcommon.EnableEpochsHandler
+	enableEpochsHandler  arwen.EnableEpochsHandler
+	vmVersionSchedule    arwen.VMVersionSchedule
+	currentVMVersion     arwen.VMVersion
 	activationEpochMap   map[uint32]struct{}
+	metricsSink          arwen.MetricsSink
+	gasValueConverter    arwen.GasValueConverter
+	executionTracer      arwen.ExecutionTracer
+	executionPolicy      arwen.ExecutionPolicy
+	guardedAccounts      arwen.GuardedAccountsHandler
+	debugMode            bool
+	executionPaused      uint32
+	nonceHandling        arwen.NonceHandling
+	asyncLimits          arwen.AsyncLimits
+	restrictLibraryCalls bool
+	maxReturnDataSize    uint64
+
+	mutStorageAccesses sync.RWMutex
+	storageAccesses    *arwen.StorageAccessSet
+
+	mutExecutedTxHashes   sync.Mutex
+	executedTxHashes      map[string]struct{}
+	executedTxHashesBlock uint64
+
+	shadowHost              arwen.VMHost
+	shadowDivergenceHandler arwen.ShadowDivergenceHandler
 }
 
 // NewArwenVM creates a new Arwen vmHost
@@ -84,7 +114,12 @@ func NewArwenVM(
 	if check.IfNil(hostParameters.EnableEpochsHandler) {
 		return nil, arwen.ErrNilEnableEpochsHandler
 	}
+	err := hostParameters.VMVersionSchedule.Check()
+	if err != nil {
+		return nil, err
+	}
 
+	enableEpochsHandler := arwen.ExtendEnableEpochsHandler(hostParameters.EnableEpochsHandler)
 	cryptoHook := factory.NewVMCrypto()
 	host := &vmHost{
 		cryptoHook:           cryptoHook,
@@ -99,18 +134,47 @@ func NewArwenVM(
 		esdtTransferParser:   hostParameters.ESDTTransferParser,
 		callArgsParser:       parsers.NewCallArgsParser(),
 		executionTimeout:     minExecutionTimeout,
-		enableEpochsHandler:  hostParameters.EnableEpochsHandler,
+		enableEpochsHandler:  enableEpochsHandler,
+		vmVersionSchedule:    hostParameters.VMVersionSchedule,
+		currentVMVersion:     arwen.NewCurrentVMVersion(enableEpochsHandler),
+		metricsSink:          hostParameters.MetricsSink,
+		gasValueConverter:    hostParameters.GasValueConverter,
+		executionTracer:      hostParameters.ExecutionTracer,
+		executionPolicy:      hostParameters.ExecutionPolicy,
+		guardedAccounts:      hostParameters.GuardedAccounts,
+		debugMode:            hostParameters.DebugMode,
+		nonceHandling:        hostParameters.NonceHandling,
+		asyncLimits:          hostParameters.AsyncLimits,
+		restrictLibraryCalls: hostParameters.RestrictLibraryCalls,
+		maxReturnDataSize:    hostParameters.MaxReturnDataSize,
+	}
+	if check.IfNil(host.metricsSink) {
+		host.metricsSink = metrics.NewDisabledSink()
+	}
+	if host.gasValueConverter == nil {
+		host.gasValueConverter = &arwen.FullPriceGasValueConverter{}
+	}
+	if check.IfNil(host.executionTracer) {
+		host.executionTracer = tracing.NewDisabledTracer()
+	}
+	if check.IfNil(host.executionPolicy) {
+		host.executionPolicy = policy.NewDisabledPolicy()
+	}
+	host.SetExecutionPaused(hostParameters.ExecutionPaused)
+	if !check.IfNil(hostParameters.VMLogger) {
+		logging.SetVMLogger(hostParameters.VMLogger)
 	}
 	newExecutionTimeout := time.Duration(hostParameters.TimeOutForSCExecutionInMilliseconds) * time.Millisecond
 	if newExecutionTimeout > minExecutionTimeout {
 		host.executionTimeout = newExecutionTimeout
 	}
 
-	var err error
-	host.blockchainContext, err = contexts.NewBlockchainContext(host, blockChainHook)
+	blockchainContext, err := contexts.NewBlockchainContext(host, blockChainHook)
 	if err != nil {
 		return nil, err
 	}
+	blockchainContext.SetCodeCache(hostParameters.CodeCache)
+	host.blockchainContext = blockchainContext
 	vmExecutor, err := host.createExecutor(hostParameters)
 	if err != nil {
 		return nil, err
@@ -120,6 +184,8 @@ func NewArwenVM(
 		hostParameters.VMType,
 		host.builtInFuncContainer,
 		vmExecutor,
+		hostParameters.StrictEndpointValidation,
+		hostParameters.ContractLimits,
 	)
 	if err != nil {
 		return nil, err
@@ -134,6 +200,8 @@ func NewArwenVM(
 	if err != nil {
 		return nil, err
 	}
+	host.setOutputLimits(host.meteringContext.GasSchedule())
+	host.outputContext.SetTransferBundling(hostParameters.BundleOutputTransfers)
 
 	host.storageContext, err = contexts.NewStorageContext(
 		host,
@@ -143,22 +211,41 @@ func NewArwenVM(
 	if err != nil {
 		return nil, err
 	}
+	host.storageContext.SetStorageLimits(hostParameters.StorageLimits)
 
-	host.asyncContext, err = contexts.NewAsyncContext(host, host.callArgsParser, host.esdtTransferParser, &marshal.GogoProtoMarshalizer{})
+	host.asyncContext, err = contexts.NewAsyncContext(
+		host,
+		host.callArgsParser,
+		host.esdtTransferParser,
+		&marshal.GogoProtoMarshalizer{},
+		hostParameters.AsyncLimits,
+	)
 	if err != nil {
 		return nil, err
 	}
+	host.asyncContext.SetProtectRegisteredCallbacks(hostParameters.ProtectRegisteredCallbacks)
 
 	host.managedTypesContext, err = contexts.NewManagedTypesContext(host)
 	if err != nil {
 		return nil, err
 	}
+	host.managedTypesContext.SetMaxAllocation(hostParameters.MaxManagedAllocation)
 
 	host.runtimeContext.SetMaxInstanceStackSize(MaximumRuntimeInstanceStackSize)
+	host.runtimeContext.SetDeterminismChecks(hostParameters.DeterminismChecks)
+	host.runtimeContext.SetMeteringExemptContracts(hostParameters.MeteringExemptContracts)
 
 	host.initContexts()
 	hostParameters.EpochNotifier.RegisterNotifyHandler(host)
 
+	if hostParameters.ShadowVMExecutor != nil {
+		host.shadowHost, err = newShadowHost(blockChainHook, hostParameters)
+		if err != nil {
+			return nil, err
+		}
+		host.shadowDivergenceHandler = hostParameters.ShadowDivergenceHandler
+	}
+
 	return host, nil
 }
 
@@ -170,6 +257,9 @@ func (host *vmHost) createExecutor(hostParameters *arwen.VMHostParameters) (exec
 		return nil, err
 	}
 	vmExecutorFactory := defaultVMExecutorFactory
+	if hostParameters.VMExecutorKind == arwen.InterpreterExecutor {
+		vmExecutorFactory = interpreter.ExecutorFactory()
+	}
 	if hostParameters.OverrideVMExecutor != nil {
 		vmExecutorFactory = hostParameters.OverrideVMExecutor
 	}
@@ -240,10 +330,103 @@ func (host *vmHost) Storage() arwen.StorageContext {
 }
 
 // EnableEpochsHandler returns the enableEpochsHandler instance of the host
-func (host *vmHost) EnableEpochsHandler() vmcommon.EnableEpochsHandler {
+func (host *vmHost) EnableEpochsHandler() arwen.EnableEpochsHandler {
 	return host.enableEpochsHandler
 }
 
+// VMVersion returns the execution semantics scheduled for the epoch of the
+// current block, defaulting to the current semantics
+func (host *vmHost) VMVersion() arwen.VMVersion {
+	version, ok := host.vmVersionSchedule.VersionForEpoch(host.Blockchain().CurrentEpoch())
+	if !ok {
+		return host.currentVMVersion
+	}
+	return version
+}
+
+// Metrics returns the MetricsSink which receives the measurements of the host
+func (host *vmHost) Metrics() arwen.MetricsSink {
+	return host.metricsSink
+}
+
+// GasValueConverter returns the GasValueConverter by which the host prices the gas refunded by contracts
+func (host *vmHost) GasValueConverter() arwen.GasValueConverter {
+	return host.gasValueConverter
+}
+
+// ExecutionTracer returns the ExecutionTracer which is notified of the calls executed by the host
+func (host *vmHost) ExecutionTracer() arwen.ExecutionTracer {
+	return host.executionTracer
+}
+
+// IsDebugMode returns true if the host runs the contracts in debug mode,
+// where the debugging host functions take effect
+func (host *vmHost) IsDebugMode() bool {
+	return host.debugMode
+}
+
+// SetExecutionPaused pauses or resumes the execution of all the contracts;
+// while paused, the executions and the deployments return SCExecutionPaused,
+// but the transfers which do not run contract code still take place. It can
+// be called by the embedder at any time, such as from an admin endpoint.
+func (host *vmHost) SetExecutionPaused(paused bool) {
+	value := uint32(0)
+	if paused {
+		value = 1
+	}
+	atomic.StoreUint32(&host.executionPaused, value)
+}
+
+// IsExecutionPaused returns true if the execution of the contracts is paused
+func (host *vmHost) IsExecutionPaused() bool {
+	return atomic.LoadUint32(&host.executionPaused) == 1
+}
+
+// WarmUp keeps an instance of each of the contracts deployed at the given
+// addresses in the warm instance cache, compiling those whose compiled code
+// the node does not hold yet, so that their first executions skip the
+// compilation; it is meant for the most called contracts, when the node
+// starts. The warm instance cache keeps only the most recently used
+// instances, so warming up more contracts than its capacity evicts the first
+// ones. The results follow the order of the addresses.
+func (host *vmHost) WarmUp(addresses [][]byte) []*arwen.WarmUpResult {
+	host.mutExecution.Lock()
+	defer host.mutExecution.Unlock()
+
+	results := make([]*arwen.WarmUpResult, 0, len(addresses))
+	for _, address := range addresses {
+		result := &arwen.WarmUpResult{Address: address}
+		if host.closingInstance {
+			result.Err = arwen.ErrVMIsClosing
+		} else {
+			result.Source, result.CodeHash, result.Err = host.runtimeContext.WarmUpContract(address)
+		}
+		results = append(results, result)
+	}
+
+	// reading the code of the contracts fills the output accounts
+	host.InitState()
+
+	return results
+}
+
+// CacheStatistics returns the statistics of the caches of the runtime; it
+// can be called by the embedder at any time, such as from an admin endpoint
+func (host *vmHost) CacheStatistics() arwen.CacheStatistics {
+	return host.runtimeContext.CacheStatistics()
+}
+
+// HostFunctionCatalog describes the host functions which contracts may
+// import, with their signatures, their enable epochs and their current gas
+// costs, for the tools documenting the API of the VM
+func (host *vmHost) HostFunctionCatalog() []arwen.HostFunctionInfo {
+	host.mutExecution.RLock()
+	defer host.mutExecution.RUnlock()
+
+	functionNames := host.runtimeContext.GetVMExecutor().FunctionNames()
+	return arwen.NewHostFunctionCatalog(functionNames, host.enableEpochsHandler, host.gasSchedule)
+}
+
 // ManagedTypes returns the ManagedTypeContext instance of the host
 func (host *vmHost) ManagedTypes() arwen.ManagedTypesContext {
 	return host.managedTypesContext
@@ -284,6 +467,9 @@ func (host *vmHost) Close() error {
 	host.closingInstance = true
 	host.mutExecution.Unlock()
 
+	if host.shadowHost != nil {
+		return host.shadowHost.Close()
+	}
 	return nil
 }
 
@@ -293,6 +479,10 @@ func (host *vmHost) Reset() {
 	host.close()
 	// keep closingInstance flag to false
 	host.mutExecution.Unlock()
+
+	if host.shadowHost != nil {
+		host.shadowHost.Reset()
+	}
 }
 
 func (host *vmHost) initContexts() {
@@ -320,6 +510,10 @@ func (host *vmHost) ClearContextStateStack() {
 
 // GasScheduleChange applies a new gas schedule to the host
 func (host *vmHost) GasScheduleChange(newGasSchedule config.GasScheduleMap) {
+	if host.shadowHost != nil {
+		host.shadowHost.GasScheduleChange(newGasSchedule)
+	}
+
 	host.mutExecution.Lock()
 	defer host.mutExecution.Unlock()
 
@@ -330,10 +524,77 @@ func (host *vmHost) GasScheduleChange(newGasSchedule config.GasScheduleMap) {
 		return
 	}
 
+	host.applyGasSchedule(newGasSchedule, gasCostConfig)
+}
+
+// UpdateConfig replaces the gas schedule and the enable epochs handler of the
+// host between two executions: it waits for the running executions to end,
+// and the next executions see the whole new configuration. The warm
+// instances are discarded, and so are the compiled codes if their metering
+// changed. An invalid configuration is rejected, keeping the current one.
+func (host *vmHost) UpdateConfig(newGasSchedule config.GasScheduleMap, enableEpochsHandler vmcommon.EnableEpochsHandler) error {
+	newEnableEpochsHandler := arwen.ExtendEnableEpochsHandler(enableEpochsHandler)
+	err := arwen.ValidateConfigUpdate(newGasSchedule, newEnableEpochsHandler)
+	if err != nil {
+		return err
+	}
+	gasCostConfig, err := config.CreateGasConfig(newGasSchedule)
+	if err != nil {
+		return err
+	}
+	if host.shadowHost != nil {
+		err = host.shadowHost.UpdateConfig(newGasSchedule, newEnableEpochsHandler)
+		if err != nil {
+			return err
+		}
+	}
+
+	host.mutExecution.Lock()
+	defer host.mutExecution.Unlock()
+
+	if host.closingInstance {
+		return arwen.ErrVMIsClosing
+	}
+
+	host.gasSchedule = newGasSchedule
+	host.applyGasSchedule(newGasSchedule, gasCostConfig)
+
+	host.enableEpochsHandler = newEnableEpochsHandler
+	host.currentVMVersion = arwen.NewCurrentVMVersion(newEnableEpochsHandler)
+	host.runtimeContext.SetEnableEpochsHandler(newEnableEpochsHandler)
+
+	log.Debug("VM configuration updated")
+	return nil
+}
+
+// applyGasSchedule must be called while no execution is running
+func (host *vmHost) applyGasSchedule(newGasSchedule config.GasScheduleMap, gasCostConfig *config.GasCost) {
+	previousMaxStackHeight := host.meteringContext.GasSchedule().WASMOpcodeCost.MaxStackHeight
 	host.runtimeContext.GetVMExecutor().SetOpcodeCosts(gasCostConfig.WASMOpcodeCost)
 
 	host.meteringContext.SetGasSchedule(newGasSchedule)
+	host.setOutputLimits(gasCostConfig)
 	host.runtimeContext.ClearWarmInstanceCache()
+
+	// the compiled codes embed the stack height metering of the previous limit
+	if gasCostConfig.WASMOpcodeCost.MaxStackHeight != previousMaxStackHeight {
+		host.Blockchain().ClearCompiledCodes()
+	}
+}
+
+// setOutputLimits applies the output limits of the gas schedule; the maximum
+// return data size of the host parameters, if any, still bounds the one of
+// the gas schedule
+func (host *vmHost) setOutputLimits(gasSchedule *config.GasCost) {
+	apiCosts := gasSchedule.ElrondAPICost
+
+	maxReturnDataSize := apiCosts.MaxReturnDataSize
+	if host.maxReturnDataSize > 0 && (maxReturnDataSize == 0 || host.maxReturnDataSize < maxReturnDataSize) {
+		maxReturnDataSize = host.maxReturnDataSize
+	}
+
+	host.outputContext.SetMaxReturnDataSize(maxReturnDataSize)
+	host.outputContext.SetLogLimits(apiCosts.MaxNumberOfLogs, apiCosts.MaxLogSize)
 }
 
 // GetGasScheduleMap returns the currently stored gas schedule
@@ -350,6 +611,27 @@ func (host *vmHost) RunSmartContractCreate(input *vmcommon.ContractCreateInput)
 		return nil, arwen.ErrVMIsClosing
 	}
 
+	err = host.checkTransaction(&input.VMInput)
+	if err != nil {
+		return nil, err
+	}
+
+	if host.shadowHost != nil {
+		shadowInput := copyContractCreateInput(input)
+		defer func() {
+			host.runShadowCreate(shadowInput, vmOutput, err)
+		}()
+	}
+
+	startTime := time.Now()
+	defer func() {
+		duration := time.Since(startTime)
+		host.observeExecution(arwen.ExecutionCreate, input.GasProvided, vmOutput, err, duration)
+		host.observeContractExecution(func() ([]byte, error) {
+			return host.Crypto().Sha256(input.ContractCode)
+		}, input.GasProvided, vmOutput, err, duration)
+	}()
+
 	host.setGasTracerEnabledIfLogIsTrace()
 	ctx, cancel := context.WithTimeout(context.Background(), host.executionTimeout)
 	defer cancel()
@@ -373,7 +655,11 @@ func (host *vmHost) RunSmartContractCreate(input *vmcommon.ContractCreateInput)
 			close(done)
 		}()
 
-		vmOutput = host.doRunSmartContractCreate(input)
+		vmOutput = host.retryAfterInfrastructureError(func() *vmcommon.VMOutput {
+			return host.doRunSmartContractCreate(input)
+		})
+		host.recordSenderNonce(&input.VMInput, vmOutput)
+		host.recordStorageAccesses(vmOutput)
 		logsFromErrors := host.createLogEntryFromErrors(input.CallerAddr, input.CallerAddr, "_init")
 		if logsFromErrors != nil {
 			vmOutput.Logs = append(vmOutput.Logs, logsFromErrors)
@@ -407,6 +693,32 @@ func (host *vmHost) RunSmartContractCall(input *vmcommon.ContractCallInput) (vmO
 		return nil, arwen.ErrVMIsClosing
 	}
 
+	err = host.checkTransaction(&input.VMInput)
+	if err != nil {
+		return nil, err
+	}
+
+	err = host.checkGuardedTransaction(&input.VMInput)
+	if err != nil {
+		return nil, err
+	}
+
+	if host.shadowHost != nil {
+		shadowInput := copyContractCallInput(input)
+		defer func() {
+			host.runShadowCall(shadowInput, vmOutput, err)
+		}()
+	}
+
+	startTime := time.Now()
+	defer func() {
+		duration := time.Since(startTime)
+		host.observeExecution(executionKindOfCall(input.Function), input.GasProvided, vmOutput, err, duration)
+		host.observeContractExecution(func() ([]byte, error) {
+			return host.Blockchain().GetCodeHash(input.RecipientAddr), nil
+		}, input.GasProvided, vmOutput, err, duration)
+	}()
+
 	host.setGasTracerEnabledIfLogIsTrace()
 	ctx, cancel := context.WithTimeout(context.Background(), host.executionTimeout)
 	defer cancel()
@@ -429,14 +741,18 @@ func (host *vmHost) RunSmartContractCall(input *vmcommon.ContractCallInput) (vmO
 			close(done)
 		}()
 
-		switch input.Function {
-		case arwen.UpgradeFunctionName:
-			vmOutput = host.doRunSmartContractUpgrade(input)
-		case arwen.DeleteFunctionName:
-			vmOutput = host.doRunSmartContractDelete(input)
-		default:
-			vmOutput = host.doRunSmartContractCall(input)
-		}
+		vmOutput = host.retryAfterInfrastructureError(func() *vmcommon.VMOutput {
+			switch input.Function {
+			case arwen.UpgradeFunctionName:
+				return host.doRunSmartContractUpgrade(input)
+			case arwen.DeleteFunctionName:
+				return host.doRunSmartContractDelete(input)
+			default:
+				return host.doRunSmartContractCall(input)
+			}
+		})
+		host.recordSenderNonce(&input.VMInput, vmOutput)
+		host.recordStorageAccesses(vmOutput)
 
 		logsFromErrors := host.createLogEntryFromErrors(input.CallerAddr, input.RecipientAddr, input.Function)
 		if logsFromErrors != nil {
@@ -469,6 +785,58 @@ func (host *vmHost) RunSmartContractCall(input *vmcommon.ContractCallInput) (vmO
 	return
 }
 
+func executionKindOfCall(function string) arwen.ExecutionKind {
+	switch function {
+	case arwen.UpgradeFunctionName:
+		return arwen.ExecutionUpgrade
+	case arwen.DeleteFunctionName:
+		return arwen.ExecutionDelete
+	}
+	return arwen.ExecutionCall
+}
+
+// observeExecution reports a finished execution to the MetricsSink; failed
+// executions are also counted by the category of their first VMError
+func (host *vmHost) observeExecution(kind arwen.ExecutionKind, gasProvided uint64, vmOutput *vmcommon.VMOutput, err error, duration time.Duration) {
+	if err != nil || vmOutput == nil {
+		host.metricsSink.ObserveExecution(kind, vmcommon.ExecutionFailed, gasProvided, duration)
+		host.metricsSink.ObserveTrap(arwen.ErrorCategoryOf(err))
+		return
+	}
+
+	host.metricsSink.ObserveExecution(kind, vmOutput.ReturnCode, gasUsedBy(gasProvided, vmOutput), duration)
+	if vmOutput.ReturnCode != vmcommon.Ok {
+		host.metricsSink.ObserveTrap(arwen.ErrorCategoryOf(host.runtimeContext.GetAllErrors()))
+	}
+}
+
+// observeContractExecution reports a finished execution by the code hash of
+// the executed contract, if the MetricsSink observes the contracts; the hash
+// is only computed in that case
+func (host *vmHost) observeContractExecution(codeHashOf func() ([]byte, error), gasProvided uint64, vmOutput *vmcommon.VMOutput, err error, duration time.Duration) {
+	contractMetricsSink, ok := host.metricsSink.(arwen.ContractMetricsSink)
+	if !ok || !contractMetricsSink.ContractsObserved() {
+		return
+	}
+	codeHash, hashErr := codeHashOf()
+	if hashErr != nil {
+		return
+	}
+
+	if err != nil || vmOutput == nil {
+		contractMetricsSink.ObserveContractExecution(codeHash, vmcommon.ExecutionFailed, gasProvided, duration)
+		return
+	}
+	contractMetricsSink.ObserveContractExecution(codeHash, vmOutput.ReturnCode, gasUsedBy(gasProvided, vmOutput), duration)
+}
+
+func gasUsedBy(gasProvided uint64, vmOutput *vmcommon.VMOutput) uint64 {
+	if gasProvided > vmOutput.GasRemaining {
+		return gasProvided - vmOutput.GasRemaining
+	}
+	return 0
+}
+
 func (host *vmHost) createLogEntryFromErrors(sndAddress, rcvAddress []byte, function string) *vmcommon.LogEntry {
 	formattedErrors := host.runtimeContext.GetAllErrors()
 	if formattedErrors == nil {
@@ -482,6 +850,13 @@ func (host *vmHost) createLogEntryFromErrors(sndAddress, rcvAddress []byte, func
 		Data:       []byte(formattedErrors.Error()),
 	}
 
+	// the code of the first VMError is added as the last topic, so that the
+	// failure can be categorized without parsing the error messages
+	if host.enableEpochsHandler.IsErrorCodeLogTopicFlagEnabled() {
+		errorCode := big.NewInt(int64(arwen.ErrorCodeOf(formattedErrors))).Bytes()
+		logFromError.Topics = append(logFromError.Topics, errorCode)
+	}
+
 	return logFromError
 }
 
@@ -518,6 +893,9 @@ func (host *vmHost) SetBuiltInFunctionsContainer(builtInFuncs vmcommon.BuiltInFu
 		return
 	}
 	host.builtInFuncContainer = builtInFuncs
+	if host.shadowHost != nil {
+		host.shadowHost.SetBuiltInFunctionsContainer(builtInFuncs)
+	}
 }
 
 // EpochConfirmed is called whenever a new epoch is confirmed
@@ -556,7 +934,8 @@ func (host *vmHost) CheckExecuteReadOnly() bool {
 
 func (host *vmHost) setGasTracerEnabledIfLogIsTrace() {
 	host.Metering().SetGasTracing(false)
-	if logGasTrace.GetLevel() == logger.LogTrace {
+	_, tracesHostFunctionGas := host.executionTracer.(arwen.HostFunctionGasTracer)
+	if logGasTrace.GetLevel() == logger.LogTrace || tracesHostFunctionGas {
 		host.Metering().SetGasTracing(true)
 	}
 }
@@ -39,6 +39,15 @@ func (host *vmHost) handleBreakpoint(breakpointValue arwen.BreakpointValue) erro
 	if breakpointValue == arwen.BreakpointMemoryLimit {
 		return arwen.ErrMemoryLimit
 	}
+	if breakpointValue == arwen.BreakpointInternalVMError {
+		return arwen.ErrInternalVMError
+	}
+	if breakpointValue == arwen.BreakpointReturnDataTooLarge {
+		return arwen.ErrMaxReturnDataSizeExceeded
+	}
+	if breakpointValue == arwen.BreakpointTooManyLogs {
+		return arwen.ErrLogLimitExceeded
+	}
 
 	return arwen.ErrUnhandledRuntimeBreakpoint
 }
@@ -14,6 +14,7 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/arwen/contexts"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
 	"github.com/ElrondNetwork/wasm-vm/math"
 )
 
@@ -85,12 +86,17 @@ func (host *vmHost) performCodeDeployment(input arwen.CodeDeployInput) (*vmcommo
 		return nil, err
 	}
 
+	err = host.checkDeploymentAllowed(input)
+	if err != nil {
+		return nil, err
+	}
+
 	runtime.MustVerifyNextContractCode()
 
 	err = runtime.StartWasmerInstance(input.ContractCode, metering.GetGasForExecution(), true)
 	if err != nil {
 		log.Trace("performCodeDeployment/StartWasmerInstance", "err", err)
-		return nil, arwen.ErrContractInvalid
+		return nil, host.contractInvalidError(err)
 	}
 
 	err = host.callInitFunction()
@@ -99,11 +105,17 @@ func (host *vmHost) performCodeDeployment(input arwen.CodeDeployInput) (*vmcommo
 	}
 
 	output.DeployCode(input)
+	host.Storage().CommitWrites()
 	if host.enableEpochsHandler.IsRemoveNonUpdatedStorageFlagEnabled() {
 		output.RemoveNonUpdatedStorage()
 	}
 
 	vmOutput := output.GetVMOutput()
+	err = host.checkOutputTransfersLimit(vmOutput)
+	if err != nil {
+		return nil, err
+	}
+
 	return vmOutput, nil
 }
 
@@ -172,16 +184,65 @@ func (host *vmHost) checkGasForGetCode(input *vmcommon.ContractCallInput, meteri
 
 // doRunSmartContractDelete delete a contract directly
 func (host *vmHost) doRunSmartContractDelete(input *vmcommon.ContractCallInput) *vmcommon.VMOutput {
-	output := host.Output()
+	host.InitState()
+	host.Runtime().InitStateFromContractCallInput(input)
+	host.Metering().InitStateFromContractCallInput(&input.VMInput)
+
+	vmOutput, err := host.deleteContract(input)
+	if err != nil {
+		log.Trace("doRunSmartContractDelete", "error", err)
+		return host.Output().CreateVMOutputInCaseOfError(err)
+	}
+
+	return vmOutput
+}
+
+// deleteContract removes a contract on the request of its owner, recording
+// it among the deleted accounts; the node removes its code and storage
+// together with the account. The blockchain hook cannot enumerate the
+// storage of an account, so the VM neither clears the storage of the deleted
+// contract nor refunds its release: both are left to the node, which knows
+// the data trie of the account
+func (host *vmHost) deleteContract(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
 	err := host.checkUpgradePermission(input)
 	if err != nil {
-		log.Trace("doRunSmartContractDelete", "error", arwen.ErrUpgradeNotAllowed)
-		return output.CreateVMOutputInCaseOfError(err)
+		return nil, err
 	}
 
-	vmOutput := output.GetVMOutput()
+	if host.enableEpochsHandler.IsContractDeletionFlagEnabled() {
+		err = host.releaseDeletedContract(input.RecipientAddr, input.CallerAddr)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	vmOutput := host.Output().GetVMOutput()
 	vmOutput.DeletedAccounts = append(vmOutput.DeletedAccounts, input.RecipientAddr)
-	return vmOutput
+	return vmOutput, nil
+}
+
+// releaseDeletedContract sends the remaining balance of a deleted contract to
+// its owner and refunds the release of its code; the storage entries of the
+// contract are not known to the VM, so their release is left to the node
+func (host *vmHost) releaseDeletedContract(address []byte, owner []byte) error {
+	blockchain := host.Blockchain()
+	metering := host.Metering()
+
+	balance := blockchain.GetBalanceBigInt(address)
+	if balance.Sign() > 0 && !bytes.Equal(address, owner) {
+		err := host.Output().Transfer(owner, address, 0, 0, balance, nil, nil, vm.DirectCall)
+		if err != nil {
+			return err
+		}
+	}
+
+	codeSize, err := blockchain.GetCodeSize(address)
+	if err != nil {
+		return err
+	}
+	metering.FreeGas(math.MulUint64(metering.GasSchedule().BaseOperationCost.ReleasePerByte, uint64(codeSize)))
+
+	return nil
 }
 
 func (host *vmHost) doRunSmartContractCall(input *vmcommon.ContractCallInput) *vmcommon.VMOutput {
@@ -196,6 +257,10 @@ func (host *vmHost) doRunSmartContractCall(input *vmcommon.ContractCallInput) *v
 	_, _, metering, output, runtime, async, storage := host.GetContexts()
 
 	var vmOutput *vmcommon.VMOutput
+	host.executionTracer.BeginCall(input)
+	defer func() {
+		host.executionTracer.EndCall(vmOutput)
+	}()
 	defer func() {
 		if vmOutput == nil || vmOutput.ReturnCode == vmcommon.ExecutionFailed {
 			host.Runtime().CleanInstance()
@@ -228,6 +293,27 @@ func (host *vmHost) doRunSmartContractCall(input *vmcommon.ContractCallInput) *v
 		return vmOutput
 	}
 
+	err = host.checkExecutionAllowed()
+	if err != nil {
+		log.Trace("doRunSmartContractCall", "error", err)
+		vmOutput = output.CreateVMOutputInCaseOfError(err)
+		return vmOutput
+	}
+
+	err = host.verifyEndpointArguments(contract)
+	if err != nil {
+		log.Trace("doRunSmartContractCall", "error", err)
+		vmOutput = output.CreateVMOutputInCaseOfError(err)
+		return vmOutput
+	}
+
+	err = host.verifyEndpointPayment(contract)
+	if err != nil {
+		log.Trace("doRunSmartContractCall", "error", err)
+		vmOutput = output.CreateVMOutputInCaseOfError(err)
+		return vmOutput
+	}
+
 	err = metering.DeductInitialGasForExecution(contract)
 	if err != nil {
 		log.Trace("doRunSmartContractCall initial gas", "error", arwen.ErrNotEnoughGas)
@@ -248,10 +334,17 @@ func (host *vmHost) doRunSmartContractCall(input *vmcommon.ContractCallInput) *v
 		return vmOutput
 	}
 
+	storage.CommitWrites()
 	if host.enableEpochsHandler.IsRemoveNonUpdatedStorageFlagEnabled() {
 		output.RemoveNonUpdatedStorage()
 	}
 	vmOutput = output.GetVMOutput()
+	err = host.checkOutputTransfersLimit(vmOutput)
+	if err != nil {
+		log.Trace("doRunSmartContractCall", "error", err)
+		vmOutput = output.CreateVMOutputInCaseOfError(err)
+		return vmOutput
+	}
 
 	log.Trace("doRunSmartContractCall finished",
 		"retCode", vmOutput.ReturnCode,
@@ -366,8 +459,10 @@ func (host *vmHost) executeOnDestContextNoBuiltinFunction(input *vmcommon.Contra
 	storage.PushState()
 	storage.SetAddress(runtime.GetContextAddress())
 
+	host.executionTracer.BeginCall(input)
 	defer func() {
 		vmOutput = host.finishExecuteOnDestContext(err)
+		host.executionTracer.EndCall(vmOutput)
 		if err == nil && vmOutput.ReturnCode != vmcommon.Ok {
 			err = arwen.ErrExecutionFailed
 		}
@@ -421,12 +516,13 @@ func (host *vmHost) finishExecuteOnDestContext(executeErr error) *vmcommon.VMOut
 
 	// Restore the previous context states
 	managedTypes.PopSetActiveState()
-	storage.PopSetActiveState()
 
 	if vmOutput.ReturnCode == vmcommon.Ok {
+		storage.PopMergeActiveState()
 		metering.PopMergeActiveState()
 		output.PopMergeActiveState()
 	} else {
+		storage.PopSetActiveState()
 		metering.PopSetActiveState()
 		output.PopSetActiveState()
 	}
@@ -458,14 +554,22 @@ func (host *vmHost) ExecuteOnSameContext(input *vmcommon.ContractCallInput) erro
 	if host.IsBuiltinFunctionName(input.Function) {
 		return arwen.ErrBuiltinCallOnSameContextDisallowed
 	}
+	if host.restrictLibraryCalls {
+		err := host.verifyLibrary(input.RecipientAddr)
+		if err != nil {
+			return err
+		}
+	}
 
-	managedTypes, blockchain, metering, output, runtime, _, _ := host.GetContexts()
+	managedTypes, blockchain, metering, output, runtime, _, storage := host.GetContexts()
 
-	// Back up the states of the contexts (except Storage and Async, which aren't affected
-	// by ExecuteOnSameContext())
+	// Back up the states of the contexts (except Async, which isn't affected by
+	// ExecuteOnSameContext()); the Storage keeps its address, but buffers the
+	// writes of the library apart, to discard them if it fails
 	managedTypes.PushState()
 	managedTypes.InitState()
 	output.PushState()
+	storage.PushState()
 
 	librarySCAddress := make([]byte, len(input.RecipientAddr))
 	copy(librarySCAddress, input.RecipientAddr)
@@ -501,12 +605,29 @@ func (host *vmHost) ExecuteOnSameContext(input *vmcommon.ContractCallInput) erro
 	return err
 }
 
+// verifyLibrary checks that the contract whose code is about to run on the
+// context of its caller is readable; such a contract already lets the other
+// contracts read its storage, and thereby serves as a library
+func (host *vmHost) verifyLibrary(address []byte) error {
+	account, err := host.Blockchain().GetUserAccount(address)
+	if err != nil || check.IfNil(account) {
+		return arwen.ErrContractNotFound
+	}
+
+	metadata := vmcommon.CodeMetadataFromBytes(account.GetCodeMetadata())
+	if !metadata.Readable {
+		return arwen.ErrLibraryNotReadable
+	}
+	return nil
+}
+
 func (host *vmHost) finishExecuteOnSameContext(executeErr error) {
-	managedTypes, blockchain, metering, output, runtime, _, _ := host.GetContexts()
+	managedTypes, blockchain, metering, output, runtime, _, storage := host.GetContexts()
 
 	if output.ReturnCode() != vmcommon.Ok || executeErr != nil {
 		// Execution failed: restore contexts as if the execution didn't happen.
 		managedTypes.PopSetActiveState()
+		storage.PopSetActiveState()
 		metering.PopSetActiveState()
 		output.PopSetActiveState()
 		blockchain.PopSetActiveState()
@@ -519,6 +640,7 @@ func (host *vmHost) finishExecuteOnSameContext(executeErr error) {
 	// GasUsed for all accounts.
 	vmOutput := output.GetVMOutput()
 
+	storage.PopMergeActiveState()
 	metering.PopMergeActiveState()
 	output.PopDiscard()
 	blockchain.PopDiscard()
@@ -550,7 +672,29 @@ func (host *vmHost) IsBuiltinFunctionCall(data []byte) bool {
 }
 
 // CreateNewContract creates a new contract indirectly (from another Smart Contract)
-func (host *vmHost) CreateNewContract(input *vmcommon.ContractCreateInput) (newContractAddress []byte, err error) {
+func (host *vmHost) CreateNewContract(input *vmcommon.ContractCreateInput) ([]byte, error) {
+	return host.createNewContract(input, func() ([]byte, error) {
+		return host.Blockchain().NewAddress(input.CallerAddr)
+	})
+}
+
+// CreateNewDeterministicContract creates a new contract indirectly, at the
+// address derived from the deployer, the salt and the hash of the code, which
+// does not depend on the nonce of the deployer
+func (host *vmHost) CreateNewDeterministicContract(input *vmcommon.ContractCreateInput, salt []byte) ([]byte, error) {
+	return host.createNewContract(input, func() ([]byte, error) {
+		codeHash, err := host.Crypto().Sha256(input.ContractCode)
+		if err != nil {
+			return nil, err
+		}
+		return host.Blockchain().ComputeDeterministicAddress(input.CallerAddr, salt, codeHash)
+	})
+}
+
+func (host *vmHost) createNewContract(
+	input *vmcommon.ContractCreateInput,
+	computeAddress func() ([]byte, error),
+) (newContractAddress []byte, err error) {
 	newContractAddress = nil
 	err = nil
 
@@ -578,7 +722,7 @@ func (host *vmHost) CreateNewContract(input *vmcommon.ContractCreateInput) (newC
 		return
 	}
 
-	newContractAddress, err = blockchain.NewAddress(input.CallerAddr)
+	newContractAddress, err = computeAddress()
 	if err != nil {
 		return
 	}
@@ -589,6 +733,11 @@ func (host *vmHost) CreateNewContract(input *vmcommon.ContractCreateInput) (newC
 	}
 
 	codeDeployInput.ContractAddress = newContractAddress
+	err = host.checkDeploymentAllowed(codeDeployInput)
+	if err != nil {
+		return
+	}
+
 	output.DeployCode(codeDeployInput)
 
 	defer func() {
@@ -622,6 +771,75 @@ func (host *vmHost) CreateNewContract(input *vmcommon.ContractCreateInput) (newC
 	return
 }
 
+// checkExecutionAllowed refuses the execution while paused, and otherwise asks
+// the execution policy whether the contract referred by the runtime may be
+// executed, before instantiating its code
+func (host *vmHost) checkExecutionAllowed() error {
+	if host.IsExecutionPaused() {
+		return arwen.ErrSCExecutionPaused
+	}
+
+	address := host.Runtime().GetCodeAddress()
+	codeHash := host.Blockchain().GetCodeHash(address)
+	if !host.executionPolicy.IsExecutionAllowed(address, codeHash) {
+		log.Debug("execution denied by the execution policy", "address", address)
+		return arwen.ErrExecutionDenied
+	}
+
+	return nil
+}
+
+// checkDeploymentAllowed refuses the deployment while paused, and otherwise
+// asks the execution policy whether the code may be deployed at the address
+// of the input
+func (host *vmHost) checkDeploymentAllowed(input arwen.CodeDeployInput) error {
+	if host.IsExecutionPaused() {
+		return arwen.ErrSCExecutionPaused
+	}
+
+	if !host.executionPolicy.IsDeploymentAllowed(input.ContractAddress, input.ContractCode) {
+		log.Debug("deployment denied by the execution policy", "address", input.ContractAddress)
+		return arwen.ErrExecutionDenied
+	}
+
+	return nil
+}
+
+// checkOutputTransfersLimit refuses the VMOutput of a transaction which
+// generated more output transfers than allowed by the async limits
+func (host *vmHost) checkOutputTransfersLimit(vmOutput *vmcommon.VMOutput) error {
+	if host.asyncLimits.MaxOutputTransfers == 0 {
+		return nil
+	}
+
+	numTransfers := 0
+	for _, outputAccount := range vmOutput.OutputAccounts {
+		numTransfers += len(outputAccount.OutputTransfers)
+	}
+	if numTransfers > int(host.asyncLimits.MaxOutputTransfers) {
+		log.Debug("too many output transfers", "transfers", numTransfers)
+		return arwen.ErrMaxOutputTransfersExceeded
+	}
+
+	return nil
+}
+
+// contractInvalidError hides the reason for which new code could not be
+// instantiated; once the DetailedContractInvalidErrors flag is enabled, the
+// endpoint violations, the exceeded contract limits and the inactive wasm
+// features are kept for the caller
+func (host *vmHost) contractInvalidError(err error) error {
+	if !host.enableEpochsHandler.IsDetailedContractInvalidErrorsFlagEnabled() {
+		return arwen.ErrContractInvalid
+	}
+	if errors.Is(err, arwen.ErrInvalidEndpoints) ||
+		errors.Is(err, arwen.ErrContractLimitExceeded) ||
+		errors.Is(err, arwen.ErrWasmFeatureNotActive) {
+		return err
+	}
+	return arwen.ErrContractInvalid
+}
+
 func (host *vmHost) checkUpgradePermission(vmInput *vmcommon.ContractCallInput) error {
 	contract, err := host.Blockchain().GetUserAccount(vmInput.RecipientAddr)
 	if err != nil {
@@ -672,12 +890,17 @@ func (host *vmHost) executeUpgrade(input *vmcommon.ContractCallInput) error {
 		return err
 	}
 
+	err = host.checkDeploymentAllowed(codeDeployInput)
+	if err != nil {
+		return err
+	}
+
 	runtime.MustVerifyNextContractCode()
 
 	err = runtime.StartWasmerInstance(codeDeployInput.ContractCode, metering.GetGasForExecution(), true)
 	if err != nil {
 		log.Trace("performCodeDeployment/StartWasmerInstance", "err", err)
-		return arwen.ErrContractInvalid
+		return host.contractInvalidError(err)
 	}
 
 	err = host.callInitFunction()
@@ -694,8 +917,12 @@ func (host *vmHost) executeUpgrade(input *vmcommon.ContractCallInput) error {
 }
 
 func (host *vmHost) executeDelete(input *vmcommon.ContractCallInput) error {
-	host.doRunSmartContractDelete(input)
-	return nil
+	_, err := host.deleteContract(input)
+	if !host.enableEpochsHandler.IsContractDeletionFlagEnabled() {
+		// the indirect deletions which were not allowed used to be ignored
+		return nil
+	}
+	return err
 }
 
 // execute executes an indirect call to a smart contract, assuming there is an
@@ -738,6 +965,21 @@ func (host *vmHost) execute(input *vmcommon.ContractCallInput) error {
 		return err
 	}
 
+	err = host.checkExecutionAllowed()
+	if err != nil {
+		return err
+	}
+
+	err = host.verifyEndpointArguments(contract)
+	if err != nil {
+		return err
+	}
+
+	err = host.verifyEndpointPayment(contract)
+	if err != nil {
+		return err
+	}
+
 	err = metering.DeductInitialGasForExecution(contract)
 	if err != nil {
 		return err
@@ -773,6 +1015,11 @@ func (host *vmHost) callSCMethodIndirect() error {
 		return err
 	}
 
+	err = host.verifyEndpointCallability(functionName)
+	if err != nil {
+		return err
+	}
+
 	err = host.Runtime().CallSCFunction(functionName)
 	if err != nil {
 		err = host.handleBreakpointIfAny(err)
@@ -866,6 +1113,7 @@ func (host *vmHost) callBuiltinFunction(input *vmcommon.ContractCallInput) (*vmc
 		return nil, nil, arwen.ErrInvalidCallOnReadOnlyMode
 	}
 
+	previousOwner := host.ownerBeforeBuiltinFunction(input)
 	vmOutput, err := host.Blockchain().ProcessBuiltInFunction(input)
 	if err != nil {
 		metering.UseGas(input.GasProvided)
@@ -887,10 +1135,46 @@ func (host *vmHost) callBuiltinFunction(input *vmcommon.ContractCallInput) (*vmc
 	metering.TrackGasUsedByBuiltinFunction(input, vmOutput, newVMInput)
 
 	host.addESDTTransferToVMOutputSCIntraShardCall(input, vmOutput)
+	addOwnershipTransferLog(input, previousOwner, vmOutput)
 
 	return newVMInput, vmOutput, nil
 }
 
+// ownerBeforeBuiltinFunction returns the owner of the contract whose owner is
+// about to be changed by the ChangeOwnerAddress builtin function; it is nil
+// for the other builtin functions, for the contracts of other shards and
+// until the OwnershipTransferLog flag is enabled
+func (host *vmHost) ownerBeforeBuiltinFunction(input *vmcommon.ContractCallInput) []byte {
+	if !host.enableEpochsHandler.IsOwnershipTransferLogFlagEnabled() {
+		return nil
+	}
+	if input.Function != core.BuiltInFunctionChangeOwnerAddress || !host.AreInSameShard(input.CallerAddr, input.RecipientAddr) {
+		return nil
+	}
+
+	account, err := host.Blockchain().GetUserAccount(input.RecipientAddr)
+	if err != nil || check.IfNil(account) {
+		return nil
+	}
+	return account.GetOwnerAddress()
+}
+
+// addOwnershipTransferLog records the change of the owner of an intra-shard
+// contract in a log entry of the contract, whose topics are the previous and
+// the new owner
+func addOwnershipTransferLog(input *vmcommon.ContractCallInput, previousOwner []byte, vmOutput *vmcommon.VMOutput) {
+	if previousOwner == nil || vmOutput.ReturnCode != vmcommon.Ok || len(input.Arguments) == 0 {
+		return
+	}
+
+	vmOutput.Logs = append(vmOutput.Logs, &vmcommon.LogEntry{
+		Identifier: []byte(arwen.OwnershipTransferLogIdentifier),
+		Address:    input.RecipientAddr,
+		Topics:     [][]byte{previousOwner, input.Arguments[0]},
+		Data:       []byte{},
+	})
+}
+
 // add output transfer of esdt transfer when sc calling another sc intra shard to log the transfer information
 func (host *vmHost) addESDTTransferToVMOutputSCIntraShardCall(
 	input *vmcommon.ContractCallInput,
@@ -1036,6 +1320,15 @@ func (host *vmHost) callSCMethodAsynchronousCallBack() error {
 
 	async.SetCallbackParentCall(asyncCall)
 
+	if !asyncCall.IsRejected() {
+		// a success callback with unexpected arguments is routed to the error callback
+		vmInput := runtime.GetVMInput()
+		vmInput.Arguments = asyncCall.NormalizeCallbackArguments(vmInput.Arguments)
+		if asyncCall.IsRejected() {
+			vmInput.ReturnCallAfterError = true
+		}
+	}
+
 	if asyncCall.HasCallback() {
 		callbackName := asyncCall.GetCallbackName()
 		runtime.SetCustomCallFunction(callbackName)
@@ -1138,6 +1431,120 @@ func (host *vmHost) verifyAllowedFunctionCall() error {
 		return arwen.ErrCallBackFuncCalledInRun
 	}
 
+	return host.verifyEndpointCallability(functionName)
+}
+
+// verifyEndpointCallability rejects the direct calls of the functions which
+// the contract registered as callbacks of its async calls, and the callers
+// which the contract does not allow for the called endpoint; callbacks are
+// always allowed, so an endpoint which allows no callers can only run as the
+// callback of a call of the contract
+func (host *vmHost) verifyEndpointCallability(functionName string) error {
+	runtime := host.Runtime()
+	vmInput := runtime.GetVMInput()
+	if vmInput.CallType == vm.AsynchronousCallBack {
+		return nil
+	}
+
+	if host.Async().IsRegisteredCallback(functionName) {
+		return arwen.ErrRegisteredCallbackCalledInRun
+	}
+
+	callability, restricted := runtime.EndpointCallability(functionName)
+	if !restricted {
+		return nil
+	}
+
+	if callability.Has(inspect.CallableByOwner) {
+		owner, err := host.Blockchain().GetOwnerAddress()
+		if err == nil && len(owner) > 0 && bytes.Equal(owner, vmInput.CallerAddr) {
+			return nil
+		}
+	}
+
+	isCallerSC := host.Blockchain().IsSmartContract(vmInput.CallerAddr)
+	if isCallerSC && callability.Has(inspect.CallableBySC) {
+		return nil
+	}
+	if !isCallerSC && callability.Has(inspect.CallableByUser) {
+		return nil
+	}
+
+	return arwen.ErrEndpointNotCallable
+}
+
+// verifyEndpointArguments checks the arguments of the call against the
+// schema which the contract declares for the called endpoint, once the host
+// enforces the schemas, so that malformed calls are rejected before the
+// contract is instantiated; the arguments of the callbacks are produced by
+// the VM and are not checked, and a schema section which cannot be decoded is
+// treated as declaring no schemas, being already rejected at deployment
+func (host *vmHost) verifyEndpointArguments(contract []byte) error {
+	if !host.enableEpochsHandler.IsArgumentSchemaFlagEnabled() {
+		return nil
+	}
+
+	runtime := host.Runtime()
+	if runtime.GetVMInput().CallType == vm.AsynchronousCallBack {
+		return nil
+	}
+
+	schemas, err := inspect.ReadArgumentSchemas(contract)
+	if err != nil {
+		log.Trace("read argument schemas", "error", err)
+		return nil
+	}
+	schema, declared := schemas[runtime.FunctionName()]
+	if !declared {
+		return nil
+	}
+
+	err = schema.Validate(runtime.Arguments())
+	if err != nil {
+		return fmt.Errorf("%w: %v", arwen.ErrEndpointArgumentsMismatch, err)
+	}
+
+	return nil
+}
+
+// verifyEndpointPayment checks the EGLD and the tokens transferred with the
+// call against the whitelist which the contract declares for the called
+// endpoint, once the host enforces the whitelists; the call fails before the
+// contract is instantiated, so the payment is returned to the sender like
+// that of any failed call. The payments of the callbacks are the results of
+// the calls of the contract and are not checked, and neither are those of
+// the contracts whose payment tokens section cannot be decoded, which is
+// rejected at deployment.
+func (host *vmHost) verifyEndpointPayment(contract []byte) error {
+	if !host.enableEpochsHandler.IsPaymentTokensFlagEnabled() {
+		return nil
+	}
+
+	runtime := host.Runtime()
+	vmInput := runtime.GetVMInput()
+	if vmInput.CallType == vm.AsynchronousCallBack {
+		return nil
+	}
+
+	whitelists, err := inspect.ReadPaymentTokens(contract)
+	if err != nil {
+		log.Trace("read payment tokens", "error", err)
+		return nil
+	}
+	whitelist, declared := whitelists[runtime.FunctionName()]
+	if !declared {
+		return nil
+	}
+
+	tokenIdentifiers := make([][]byte, len(vmInput.ESDTTransfers))
+	for i, transfer := range vmInput.ESDTTransfers {
+		tokenIdentifiers[i] = transfer.ESDTTokenName
+	}
+	err = whitelist.Validate(vmInput.CallValue, tokenIdentifiers)
+	if err != nil {
+		return fmt.Errorf("%w: %v", arwen.ErrEndpointPaymentNotAccepted, err)
+	}
+
 	return nil
 }
 
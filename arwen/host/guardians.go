@@ -0,0 +1,27 @@
+package host
+
+import (
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/guardian"
+)
+
+// checkGuardedTransaction rejects the direct calls of guarded accounts which
+// are not co-signed by their active guardian, and the co-signed calls of the
+// accounts which are not guarded, once the GuardedAccounts flag is enabled
+func (host *vmHost) checkGuardedTransaction(input *vmcommon.VMInput) error {
+	if host.guardedAccounts == nil || !host.enableEpochsHandler.IsGuardedAccountsFlagEnabled() {
+		return nil
+	}
+	if input.CallType != vm.DirectCall {
+		return nil
+	}
+
+	options, guardianAddr, guardianSignature := host.guardedAccounts.GetGuardianCoSignature(input.CurrentTxHash)
+	coSignature := &guardian.CoSignature{
+		Options:           options,
+		GuardianAddr:      guardianAddr,
+		GuardianSignature: guardianSignature,
+	}
+	return guardian.CheckCoSignature(host.guardedAccounts, input.CallerAddr, coSignature)
+}
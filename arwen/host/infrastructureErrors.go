@@ -0,0 +1,25 @@
+package host
+
+import (
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// retryAfterInfrastructureError runs the transaction, and runs it once more
+// if the executor failed on it, since such failures do not depend on the
+// contract and would otherwise fail the transaction on this node only. The
+// warm instances are discarded before the second attempt, while the compiled
+// code which the executor could not load is already ignored by the runtime.
+// The output of the second attempt is returned, whatever its outcome.
+func (host *vmHost) retryAfterInfrastructureError(run func() *vmcommon.VMOutput) *vmcommon.VMOutput {
+	vmOutput := run()
+
+	infrastructureErr := host.Runtime().InfrastructureError()
+	if infrastructureErr == nil {
+		return vmOutput
+	}
+
+	log.Warn("retrying transaction after infrastructure error", "error", infrastructureErr)
+	host.metricsSink.ObserveInfrastructureError(true)
+	host.Runtime().ClearWarmInstanceCache()
+	return run()
+}
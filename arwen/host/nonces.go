@@ -0,0 +1,101 @@
+package host
+
+import (
+	"math"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/contexts"
+)
+
+// checkTransaction validates a transaction sent by a user of this shard,
+// unless the NonceHandling of the host leaves the nonces to the embedder; the
+// nonce of the sender must not overflow and the hash of the transaction must
+// not have been executed already in the current block.
+func (host *vmHost) checkTransaction(input *vmcommon.VMInput) error {
+	if host.nonceHandling == arwen.NonceHandlingDisabled {
+		return nil
+	}
+
+	sender, ok := host.getUserTransactionSender(input)
+	if !ok {
+		return nil
+	}
+	if sender.GetNonce() == math.MaxUint64 {
+		return arwen.ErrSenderNonceOverflow
+	}
+
+	host.mutExecutedTxHashes.Lock()
+	defer host.mutExecutedTxHashes.Unlock()
+
+	host.resetExecutedTxHashesOnNewBlock()
+	_, executed := host.executedTxHashes[string(input.CurrentTxHash)]
+	if executed {
+		return arwen.ErrDuplicatedTransaction
+	}
+
+	return nil
+}
+
+// recordSenderNonce marks the transaction as executed and records the
+// incremented nonce of its sender in the VMOutput, whatever the outcome of
+// the execution, when the NonceHandling of the host is NonceHandlingEnabled
+func (host *vmHost) recordSenderNonce(input *vmcommon.VMInput, vmOutput *vmcommon.VMOutput) {
+	if host.nonceHandling != arwen.NonceHandlingEnabled || vmOutput == nil {
+		return
+	}
+
+	sender, ok := host.getUserTransactionSender(input)
+	if !ok {
+		return
+	}
+
+	if len(input.CurrentTxHash) > 0 {
+		host.mutExecutedTxHashes.Lock()
+		host.resetExecutedTxHashesOnNewBlock()
+		host.executedTxHashes[string(input.CurrentTxHash)] = struct{}{}
+		host.mutExecutedTxHashes.Unlock()
+	}
+
+	if vmOutput.OutputAccounts == nil {
+		vmOutput.OutputAccounts = make(map[string]*vmcommon.OutputAccount)
+	}
+	outputAccount, ok := vmOutput.OutputAccounts[string(input.CallerAddr)]
+	if !ok {
+		outputAccount = contexts.NewVMOutputAccount(input.CallerAddr)
+		vmOutput.OutputAccounts[string(input.CallerAddr)] = outputAccount
+	}
+	outputAccount.Nonce = sender.GetNonce() + 1
+}
+
+// getUserTransactionSender returns the account of the sender of a direct
+// call made by a user; the senders of the other shards are not found, so
+// their nonces are left to their own shards
+func (host *vmHost) getUserTransactionSender(input *vmcommon.VMInput) (vmcommon.UserAccountHandler, bool) {
+	if input.CallType != vm.DirectCall {
+		return nil, false
+	}
+
+	blockchain := host.Blockchain()
+	if blockchain.IsSmartContract(input.CallerAddr) {
+		return nil, false
+	}
+
+	sender, err := blockchain.GetUserAccount(input.CallerAddr)
+	if err != nil || arwen.IfNil(sender) {
+		return nil, false
+	}
+
+	return sender, true
+}
+
+func (host *vmHost) resetExecutedTxHashesOnNewBlock() {
+	currentBlock := host.Blockchain().CurrentNonce()
+	if host.executedTxHashes != nil && host.executedTxHashesBlock == currentBlock {
+		return
+	}
+
+	host.executedTxHashes = make(map[string]struct{})
+	host.executedTxHashesBlock = currentBlock
+}
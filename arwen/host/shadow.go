@@ -0,0 +1,121 @@
+package host
+
+import (
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// shadowBlockchainHook keeps the compiled code of the shadow executor away
+// from the node: the compiled code of an executor cannot be restored by
+// another one, and it must not replace the compiled code of the canonical
+// executor either
+type shadowBlockchainHook struct {
+	vmcommon.BlockchainHook
+}
+
+// GetCompiledCode never finds compiled code, so the shadow executor always
+// compiles the contracts from their bytecode
+func (hook *shadowBlockchainHook) GetCompiledCode(_ []byte) (bool, []byte) {
+	return false, nil
+}
+
+// SaveCompiledCode discards the compiled code of the shadow executor
+func (hook *shadowBlockchainHook) SaveCompiledCode(_ []byte, _ []byte) {
+}
+
+// newShadowHost creates the host which executes the transactions again on
+// the shadow executor. It reads the same blockchain hook as the canonical
+// host, but it has its own metrics and no tracer, so that the execution is
+// only observed once.
+func newShadowHost(blockChainHook vmcommon.BlockchainHook, hostParameters *arwen.VMHostParameters) (arwen.VMHost, error) {
+	shadowParameters := *hostParameters
+	shadowParameters.OverrideVMExecutor = hostParameters.ShadowVMExecutor
+	shadowParameters.ShadowVMExecutor = nil
+	shadowParameters.ShadowDivergenceHandler = nil
+	shadowParameters.MetricsSink = nil
+	shadowParameters.ExecutionTracer = nil
+
+	return NewArwenVM(&shadowBlockchainHook{BlockchainHook: blockChainHook}, &shadowParameters)
+}
+
+// runShadowCall executes the call on the shadow host and compares its
+// outcome with the canonical one, which it leaves untouched
+func (host *vmHost) runShadowCall(input *vmcommon.ContractCallInput, vmOutput *vmcommon.VMOutput, err error) {
+	shadowOutput, shadowErr := host.shadowHost.RunSmartContractCall(input)
+	host.compareWithShadow(executionKindOfCall(input.Function), &input.VMInput, input.RecipientAddr, input.Function, vmOutput, err, shadowOutput, shadowErr)
+}
+
+// runShadowCreate executes the deployment on the shadow host and compares
+// its outcome with the canonical one, which it leaves untouched
+func (host *vmHost) runShadowCreate(input *vmcommon.ContractCreateInput, vmOutput *vmcommon.VMOutput, err error) {
+	shadowOutput, shadowErr := host.shadowHost.RunSmartContractCreate(input)
+	host.compareWithShadow(arwen.ExecutionCreate, &input.VMInput, nil, arwen.InitFunctionName, vmOutput, err, shadowOutput, shadowErr)
+}
+
+func (host *vmHost) compareWithShadow(
+	kind arwen.ExecutionKind,
+	input *vmcommon.VMInput,
+	recipient []byte,
+	function string,
+	vmOutput *vmcommon.VMOutput,
+	err error,
+	shadowOutput *vmcommon.VMOutput,
+	shadowErr error,
+) {
+	canonicalLine, shadowLine, differs := arwen.FirstDifferentLine(describeOutcome(vmOutput, err), describeOutcome(shadowOutput, shadowErr))
+	if !differs {
+		return
+	}
+
+	log.Warn("shadow execution diverged",
+		"kind", kind,
+		"function", function,
+		"canonical", canonicalLine,
+		"shadow", shadowLine)
+	if check.IfNil(host.shadowDivergenceHandler) {
+		return
+	}
+	host.shadowDivergenceHandler.HandleShadowDivergence(&arwen.ShadowDivergence{
+		Kind:      kind,
+		Caller:    input.CallerAddr,
+		Recipient: recipient,
+		Function:  function,
+		Canonical: canonicalLine,
+		Shadow:    shadowLine,
+	})
+}
+
+func describeOutcome(vmOutput *vmcommon.VMOutput, err error) []string {
+	errorLine := "error: none"
+	if err != nil {
+		errorLine = "error: " + err.Error()
+	}
+	return append([]string{errorLine}, arwen.DescribeVMOutput(vmOutput)...)
+}
+
+// copyVMInput copies the slices of the input which the host may change while
+// executing it, so that the shadow execution starts from the original input
+func copyVMInput(input vmcommon.VMInput) vmcommon.VMInput {
+	inputCopy := input
+	inputCopy.Arguments = make([][]byte, len(input.Arguments))
+	copy(inputCopy.Arguments, input.Arguments)
+	inputCopy.ESDTTransfers = make([]*vmcommon.ESDTTransfer, len(input.ESDTTransfers))
+	for i, transfer := range input.ESDTTransfers {
+		transferCopy := *transfer
+		inputCopy.ESDTTransfers[i] = &transferCopy
+	}
+	return inputCopy
+}
+
+func copyContractCallInput(input *vmcommon.ContractCallInput) *vmcommon.ContractCallInput {
+	inputCopy := *input
+	inputCopy.VMInput = copyVMInput(input.VMInput)
+	return &inputCopy
+}
+
+func copyContractCreateInput(input *vmcommon.ContractCreateInput) *vmcommon.ContractCreateInput {
+	inputCopy := *input
+	inputCopy.VMInput = copyVMInput(input.VMInput)
+	return &inputCopy
+}
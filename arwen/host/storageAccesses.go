@@ -0,0 +1,37 @@
+package host
+
+import (
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// StorageAccesses returns the storage keys read and written by the last
+// execution of the host, for a scheduler which decides what transactions it
+// can execute in parallel through arwen.FindStorageConflicts
+func (host *vmHost) StorageAccesses() *arwen.StorageAccessSet {
+	host.mutStorageAccesses.RLock()
+	defer host.mutStorageAccesses.RUnlock()
+
+	if host.storageAccesses == nil {
+		return arwen.NewStorageAccessSet()
+	}
+	return host.storageAccesses
+}
+
+// recordStorageAccesses keeps the keys read from the node by all the call
+// frames of the execution and those written by the frames which completed
+// successfully, provided that the execution itself succeeded; the writes are
+// committed before the VMOutput is built, and dropped here if it failed later
+func (host *vmHost) recordStorageAccesses(vmOutput *vmcommon.VMOutput) {
+	accesses := host.storageContext.GetStorageAccesses()
+	if vmOutput == nil || vmOutput.ReturnCode != vmcommon.Ok {
+		accesses = &arwen.StorageAccessSet{
+			Reads:  accesses.Reads,
+			Writes: make(map[string]map[string]struct{}),
+		}
+	}
+
+	host.mutStorageAccesses.Lock()
+	host.storageAccesses = accesses
+	host.mutStorageAccesses.Unlock()
+}
@@ -0,0 +1,121 @@
+package arwen
+
+import (
+	"reflect"
+	"sort"
+	"strings"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// HostFunctionInfo describes a host function which contracts can import
+type HostFunctionInfo struct {
+	Name string
+
+	// AliasOf is the host function of which Name is a former name, empty for
+	// the current names; an alias has the signature and the costs of its target
+	AliasOf string
+
+	// Arguments and Results are the WASM value types of the signature of the
+	// function, nil for the functions which are not VM hooks
+	Arguments []string
+	Results   []string
+
+	// EnableEpoch is the epoch from which contracts may import the function,
+	// 0 for the functions available from genesis
+	EnableEpoch uint32
+
+	// GasCostName is the entry of the gas schedule holding the base cost of
+	// the function, as "Section.Name", and GasCost is its current value; the
+	// functions charging only costs which depend on their arguments have none
+	GasCostName string
+	GasCost     uint64
+}
+
+// NewHostFunctionCatalog describes the host functions registered in an
+// executor, which are the imports its contracts are allowed, ordered by name
+func NewHostFunctionCatalog(
+	functionNames vmcommon.FunctionNames,
+	enableEpochsHandler EnableEpochsHandler,
+	gasSchedule config.GasScheduleMap,
+) []HostFunctionInfo {
+	enableEpochsByName := make(map[string]uint32)
+	for _, group := range hostFunctionsGroups(enableEpochsHandler) {
+		for _, name := range group.names {
+			enableEpochsByName[name] = group.enableEpoch
+		}
+	}
+	signatures := vmHookSignatures()
+	aliasTargets := executor.HostFunctionAliasTargets(executor.HostFunctionAliases)
+
+	catalog := make([]HostFunctionInfo, 0, len(functionNames))
+	for name := range functionNames {
+		info := HostFunctionInfo{
+			Name:        name,
+			AliasOf:     aliasTargets[name],
+			EnableEpoch: enableEpochsByName[name],
+		}
+		implementationName := name
+		if len(info.AliasOf) > 0 {
+			implementationName = info.AliasOf
+		}
+		signature, ok := signatures[implementationName]
+		if ok {
+			info.Arguments, info.Results = wasmValueTypes(signature)
+		}
+		info.GasCostName, info.GasCost = hostFunctionGasCost(implementationName, gasSchedule)
+		catalog = append(catalog, info)
+	}
+
+	sort.Slice(catalog, func(i, j int) bool {
+		return catalog[i].Name < catalog[j].Name
+	})
+	return catalog
+}
+
+// vmHookSignatures returns the methods of executor.VMHooks by the names under
+// which contracts import them
+func vmHookSignatures() map[string]reflect.Type {
+	vmHooksType := reflect.TypeOf((*executor.VMHooks)(nil)).Elem()
+	signatures := make(map[string]reflect.Type, vmHooksType.NumMethod())
+	for i := 0; i < vmHooksType.NumMethod(); i++ {
+		method := vmHooksType.Method(i)
+		importName := strings.ToLower(method.Name[0:1]) + method.Name[1:]
+		signatures[importName] = method.Type
+	}
+	return signatures
+}
+
+func wasmValueTypes(signature reflect.Type) ([]string, []string) {
+	arguments := make([]string, signature.NumIn())
+	for i := range arguments {
+		arguments[i] = wasmValueType(signature.In(i))
+	}
+	results := make([]string, signature.NumOut())
+	for i := range results {
+		results[i] = wasmValueType(signature.Out(i))
+	}
+	return arguments, results
+}
+
+func wasmValueType(goType reflect.Type) string {
+	switch goType.Kind() {
+	case reflect.Int32:
+		return "i32"
+	case reflect.Int64:
+		return "i64"
+	default:
+		return goType.Kind().String()
+	}
+}
+
+func hostFunctionGasCost(name string, gasSchedule config.GasScheduleMap) (string, uint64) {
+	gasCostName, ok := hostFunctionGasCosts[name]
+	if !ok {
+		return "", 0
+	}
+	parts := strings.SplitN(gasCostName, ".", 2)
+	return gasCostName, gasSchedule[parts[0]][parts[1]]
+}
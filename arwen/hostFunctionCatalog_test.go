@@ -0,0 +1,76 @@
+package arwen
+
+import (
+	"strings"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+func TestNewHostFunctionCatalog(t *testing.T) {
+	functionNames := vmcommon.FunctionNames{
+		"mBufferNew":       {},
+		"getGasLeft":       {},
+		"bigIntAdd":        {},
+		"finishAppend":     {},
+		"notAVMHookImport": {},
+	}
+	enableEpochsHandler := &worldmock.EnableEpochsHandlerStub{
+		ManagedBufferFunctionsEnableEpochField: 4,
+		ChunkedFinishFunctionsEnableEpochField: 7,
+	}
+	gasSchedule := config.MakeGasMapForTests()
+	gasSchedule["BigIntAPICost"]["BigIntAdd"] = 42
+
+	catalog := NewHostFunctionCatalog(functionNames, enableEpochsHandler, gasSchedule)
+	require.Equal(t, []HostFunctionInfo{
+		{
+			Name:        "bigIntAdd",
+			Arguments:   []string{"i32", "i32", "i32"},
+			Results:     []string{},
+			GasCostName: "BigIntAPICost.BigIntAdd",
+			GasCost:     42,
+		},
+		{
+			Name:        "finishAppend",
+			Arguments:   []string{"i32", "i32"},
+			Results:     []string{"i32"},
+			EnableEpoch: 7,
+		},
+		{
+			Name:        "getGasLeft",
+			Arguments:   []string{},
+			Results:     []string{"i64"},
+			GasCostName: "ElrondAPICost.GetGasLeft",
+			GasCost:     config.GasValueForTests,
+		},
+		{
+			Name:        "mBufferNew",
+			Arguments:   []string{},
+			Results:     []string{"i32"},
+			EnableEpoch: 4,
+			GasCostName: "ManagedBufferAPICost.MBufferNew",
+			GasCost:     config.GasValueForTests,
+		},
+		{
+			Name: "notAVMHookImport",
+		},
+	}, catalog)
+}
+
+func TestHostFunctionGasCosts_EntriesExist(t *testing.T) {
+	signatures := vmHookSignatures()
+	gasSchedule := config.MakeGasMapForTests()
+	for name, gasCostName := range hostFunctionGasCosts {
+		_, ok := signatures[name]
+		require.True(t, ok, name)
+
+		parts := strings.SplitN(gasCostName, ".", 2)
+		require.Len(t, parts, 2, name)
+		_, ok = gasSchedule[parts[0]][parts[1]]
+		require.True(t, ok, gasCostName)
+	}
+}
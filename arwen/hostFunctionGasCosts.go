@@ -0,0 +1,287 @@
+package arwen
+
+// hostFunctionGasCosts names, by host function, the entry of the gas schedule
+// holding the base cost charged by the function when it is called, before the
+// costs which depend on its arguments; the functions which charge only costs
+// depending on their arguments have no entry
+var hostFunctionGasCosts = map[string]string{
+	"getGasLeft":                                 "ElrondAPICost.GetGasLeft",
+	"getSCAddress":                               "ElrondAPICost.GetSCAddress",
+	"getOwnerAddress":                            "ElrondAPICost.GetOwnerAddress",
+	"getShardOfAddress":                          "ElrondAPICost.GetShardOfAddress",
+	"isSmartContract":                            "ElrondAPICost.IsSmartContract",
+	"signalError":                                "ElrondAPICost.SignalError",
+	"signalTypedError":                           "ElrondAPICost.SignalError",
+	"debugPrint":                                 "ElrondAPICost.GetNumArguments",
+	"gasPhase":                                   "ElrondAPICost.GetNumArguments",
+	"getExternalBalance":                         "ElrondAPICost.GetExternalBalance",
+	"getBlockHash":                               "ElrondAPICost.GetBlockHash",
+	"getESDTBalance":                             "ElrondAPICost.GetExternalBalance",
+	"getESDTNFTNameLength":                       "ElrondAPICost.GetExternalBalance",
+	"getESDTNFTAttributeLength":                  "ElrondAPICost.GetExternalBalance",
+	"getESDTNFTURILength":                        "ElrondAPICost.GetExternalBalance",
+	"getESDTTokenData":                           "ElrondAPICost.GetExternalBalance",
+	"getESDTLocalRoles":                          "ElrondAPICost.StorageLoad",
+	"validateTokenIdentifier":                    "ElrondAPICost.GetArgument",
+	"transferValue":                              "ElrondAPICost.TransferValue",
+	"refundGasToCaller":                          "ElrondAPICost.TransferValue",
+	"transferValueExecute":                       "ElrondAPICost.TransferValue",
+	"transferESDTExecute":                        "ElrondAPICost.TransferValue",
+	"transferESDTNFTExecute":                     "ElrondAPICost.TransferValue",
+	"multiTransferESDTNFTExecute":                "ElrondAPICost.TransferValue",
+	"createAsyncCall":                            "ElrondAPICost.CreateAsyncCall",
+	"setAsyncContextCallback":                    "ElrondAPICost.SetAsyncContextCallback",
+	"createPromiseGroup":                         "ElrondAPICost.SetAsyncGroupCallback",
+	"createPromise":                              "ElrondAPICost.CreateAsyncCall",
+	"upgradeContract":                            "ElrondAPICost.CreateContract",
+	"upgradeFromSourceContract":                  "ElrondAPICost.CreateContract",
+	"deleteContract":                             "ElrondAPICost.CreateContract",
+	"asyncCall":                                  "ElrondAPICost.AsyncCallStep",
+	"getArgumentLength":                          "ElrondAPICost.GetArgument",
+	"getArgument":                                "ElrondAPICost.GetArgument",
+	"copyArgumentTo":                             "ElrondAPICost.GetArgument",
+	"getFunction":                                "ElrondAPICost.GetFunction",
+	"getNumArguments":                            "ElrondAPICost.GetNumArguments",
+	"storageStore":                               "ElrondAPICost.StorageStore",
+	"storageDelete":                              "ElrondAPICost.StorageStore",
+	"storageLoadLength":                          "ElrondAPICost.StorageLoad",
+	"storageLoadFromAddress":                     "ElrondAPICost.StorageLoad",
+	"storageLoad":                                "ElrondAPICost.StorageLoad",
+	"setStorageLock":                             "ElrondAPICost.Int64StorageStore",
+	"getStorageLock":                             "ElrondAPICost.StorageLoad",
+	"isStorageLocked":                            "ElrondAPICost.StorageLoad",
+	"clearStorageLock":                           "ElrondAPICost.Int64StorageStore",
+	"getCaller":                                  "ElrondAPICost.GetCaller",
+	"checkNoPayment":                             "ElrondAPICost.GetCallValue",
+	"getCallValue":                               "ElrondAPICost.GetCallValue",
+	"getESDTValue":                               "ElrondAPICost.GetCallValue",
+	"getESDTValueByIndex":                        "ElrondAPICost.GetCallValue",
+	"getESDTTokenName":                           "ElrondAPICost.GetCallValue",
+	"getESDTTokenNameByIndex":                    "ElrondAPICost.GetCallValue",
+	"getESDTTokenNonce":                          "ElrondAPICost.GetCallValue",
+	"getESDTTokenNonceByIndex":                   "ElrondAPICost.GetCallValue",
+	"getCurrentESDTNFTNonce":                     "ElrondAPICost.StorageLoad",
+	"getESDTTokenType":                           "ElrondAPICost.GetCallValue",
+	"getESDTTokenTypeByIndex":                    "ElrondAPICost.GetCallValue",
+	"getNumESDTTransfers":                        "ElrondAPICost.GetCallValue",
+	"getCallValueTokenName":                      "ElrondAPICost.GetCallValue",
+	"getCallValueTokenNameByIndex":               "ElrondAPICost.GetCallValue",
+	"writeLog":                                   "ElrondAPICost.Log",
+	"writeEventLog":                              "ElrondAPICost.Log",
+	"getBlockTimestamp":                          "ElrondAPICost.GetBlockTimeStamp",
+	"getBlockNonce":                              "ElrondAPICost.GetBlockNonce",
+	"getBlockRound":                              "ElrondAPICost.GetBlockRound",
+	"getBlockEpoch":                              "ElrondAPICost.GetBlockEpoch",
+	"getBlockRandomSeed":                         "ElrondAPICost.GetBlockRandomSeed",
+	"getStateRootHash":                           "ElrondAPICost.GetStateRootHash",
+	"getPrevBlockTimestamp":                      "ElrondAPICost.GetBlockTimeStamp",
+	"getPrevBlockNonce":                          "ElrondAPICost.GetBlockNonce",
+	"getPrevBlockRound":                          "ElrondAPICost.GetBlockRound",
+	"getPrevBlockEpoch":                          "ElrondAPICost.GetBlockEpoch",
+	"getPrevBlockRandomSeed":                     "ElrondAPICost.GetBlockRandomSeed",
+	"finish":                                     "ElrondAPICost.Finish",
+	"finishReserve":                              "ElrondAPICost.Finish",
+	"executeOnSameContext":                       "ElrondAPICost.ExecuteOnSameContext",
+	"executeOnDestContext":                       "ElrondAPICost.ExecuteOnDestContext",
+	"executeReadOnly":                            "ElrondAPICost.ExecuteReadOnly",
+	"createContract":                             "ElrondAPICost.CreateContract",
+	"deployFromSourceContract":                   "ElrondAPICost.CreateContract",
+	"getNumReturnData":                           "ElrondAPICost.GetNumReturnData",
+	"getReturnDataSize":                          "ElrondAPICost.GetReturnDataSize",
+	"getReturnData":                              "ElrondAPICost.GetReturnData",
+	"cleanReturnData":                            "ElrondAPICost.CleanReturnData",
+	"deleteFromReturnData":                       "ElrondAPICost.DeleteFromReturnData",
+	"getOriginalTxHash":                          "ElrondAPICost.GetOriginalTxHash",
+	"getCurrentTxHash":                           "ElrondAPICost.GetCurrentTxHash",
+	"getPrevTxHash":                              "ElrondAPICost.GetPrevTxHash",
+	"managedSCAddress":                           "ElrondAPICost.GetSCAddress",
+	"managedOwnerAddress":                        "ElrondAPICost.GetOwnerAddress",
+	"managedCaller":                              "ElrondAPICost.GetCaller",
+	"managedSignalError":                         "ElrondAPICost.SignalError",
+	"managedWriteLog":                            "ElrondAPICost.Log",
+	"managedGetOriginalTxHash":                   "ElrondAPICost.GetOriginalTxHash",
+	"managedGetStateRootHash":                    "ElrondAPICost.GetStateRootHash",
+	"managedGetBlockRandomSeed":                  "ElrondAPICost.GetBlockRandomSeed",
+	"managedGetPrevBlockRandomSeed":              "ElrondAPICost.GetBlockRandomSeed",
+	"managedGetReturnData":                       "ElrondAPICost.GetReturnData",
+	"managedGetMultiESDTCallValue":               "ElrondAPICost.GetCallValue",
+	"managedGetESDTBalance":                      "ElrondAPICost.GetExternalBalance",
+	"managedGetESDTTokenData":                    "ElrondAPICost.GetExternalBalance",
+	"managedGetESDTNFTAttributes":                "ElrondAPICost.GetExternalBalance",
+	"managedGetESDTNFTAttributeValue":            "ElrondAPICost.GetExternalBalance",
+	"managedGetESDTNFTURIs":                      "ElrondAPICost.GetExternalBalance",
+	"managedGetESDTNFTCreator":                   "ElrondAPICost.GetExternalBalance",
+	"managedGetESDTNFTRoyalties":                 "ElrondAPICost.GetExternalBalance",
+	"managedGetESDTTokenTypeOf":                  "ElrondAPICost.GetExternalBalance",
+	"managedTransferNFTWithRoyalties":            "ElrondAPICost.GetExternalBalance",
+	"managedEscrowLock":                          "ElrondAPICost.TransferValue",
+	"managedEscrowClaim":                         "ElrondAPICost.TransferValue",
+	"managedEscrowRelease":                       "ElrondAPICost.TransferValue",
+	"managedRegisterStorageMigration":            "ElrondAPICost.StorageStore",
+	"managedContinueStorageMigration":            "ElrondAPICost.StorageLoad",
+	"managedAsyncCall":                           "ElrondAPICost.AsyncCallStep",
+	"managedCreateAsyncCall":                     "ElrondAPICost.CreateAsyncCall",
+	"managedCreateAsyncCallWithCallbackArgTypes": "ElrondAPICost.CreateAsyncCall",
+	"managedCreateAsyncCallWithExpiry":           "ElrondAPICost.CreateAsyncCall",
+	"managedGetCallbackClosure":                  "ElrondAPICost.GetCallbackClosure",
+	"managedGetLockedValue":                      "ElrondAPICost.GetExternalBalance",
+	"managedSetAsyncGroupCallback":               "ElrondAPICost.SetAsyncGroupCallback",
+	"managedAddAsyncSubGroup":                    "ElrondAPICost.SetAsyncGroupCallback",
+	"managedUpgradeFromSourceContract":           "ElrondAPICost.CreateContract",
+	"managedUpgradeContract":                     "ElrondAPICost.CreateContract",
+	"managedDeleteContract":                      "ElrondAPICost.CreateContract",
+	"managedDeployFromSourceContract":            "ElrondAPICost.CreateContract",
+	"managedCreateContract":                      "ElrondAPICost.CreateContract",
+	"managedCreateDeterministicContract":         "ElrondAPICost.CreateContract",
+	"managedComputeContractAddress":              "CryptoAPICost.Keccak256",
+	"managedComputeDeterministicAddress":         "CryptoAPICost.Keccak256",
+	"managedExecuteReadOnly":                     "ElrondAPICost.ExecuteReadOnly",
+	"managedExecuteOnSameContext":                "ElrondAPICost.ExecuteOnSameContext",
+	"managedExecuteOnDestContext":                "ElrondAPICost.ExecuteOnDestContext",
+	"managedMultiTransferESDTNFTExecute":         "ElrondAPICost.TransferValue",
+	"managedTransferValueExecute":                "ElrondAPICost.TransferValue",
+	"managedIsESDTFrozen":                        "ElrondAPICost.GetExternalBalance",
+	"managedIsESDTLimitedTransfer":               "ElrondAPICost.GetExternalBalance",
+	"managedIsESDTPaused":                        "ElrondAPICost.GetExternalBalance",
+	"managedBufferToHex":                         "ManagedBufferAPICost.MBufferSetBytes",
+	"bigFloatNewFromParts":                       "BigFloatAPICost.BigFloatNewFromParts",
+	"bigFloatNewFromFrac":                        "BigFloatAPICost.BigFloatNewFromParts",
+	"bigFloatNewFromSci":                         "BigFloatAPICost.BigFloatNewFromParts",
+	"bigFloatAdd":                                "BigFloatAPICost.BigFloatAdd",
+	"bigFloatSub":                                "BigFloatAPICost.BigFloatSub",
+	"bigFloatMul":                                "BigFloatAPICost.BigFloatMul",
+	"bigFloatDiv":                                "BigFloatAPICost.BigFloatDiv",
+	"bigFloatNeg":                                "BigFloatAPICost.BigFloatNeg",
+	"bigFloatClone":                              "BigFloatAPICost.BigFloatClone",
+	"bigFloatCmp":                                "BigFloatAPICost.BigFloatCmp",
+	"bigFloatAbs":                                "BigFloatAPICost.BigFloatAbs",
+	"bigFloatSign":                               "BigFloatAPICost.BigFloatAbs",
+	"bigFloatSqrt":                               "BigFloatAPICost.BigFloatSqrt",
+	"bigFloatPow":                                "BigFloatAPICost.BigFloatPow",
+	"bigFloatFloor":                              "BigFloatAPICost.BigFloatFloor",
+	"bigFloatCeil":                               "BigFloatAPICost.BigFloatCeil",
+	"bigFloatTruncate":                           "BigFloatAPICost.BigFloatTruncate",
+	"bigFloatSetInt64":                           "BigFloatAPICost.BigFloatSetInt64",
+	"bigFloatIsInt":                              "BigFloatAPICost.BigFloatIsInt",
+	"bigFloatSetBigInt":                          "BigFloatAPICost.BigFloatSetBigInt",
+	"bigFloatGetConstPi":                         "BigFloatAPICost.BigFloatGetConst",
+	"bigFloatGetConstE":                          "BigFloatAPICost.BigFloatGetConst",
+	"bigIntGetUnsignedArgument":                  "BigIntAPICost.BigIntGetUnsignedArgument",
+	"bigIntGetSignedArgument":                    "BigIntAPICost.BigIntGetSignedArgument",
+	"bigIntStorageStoreUnsigned":                 "BigIntAPICost.BigIntStorageStoreUnsigned",
+	"bigIntStorageLoadUnsigned":                  "BigIntAPICost.BigIntStorageLoadUnsigned",
+	"bigIntGetCallValue":                         "BigIntAPICost.BigIntGetCallValue",
+	"bigIntGetESDTCallValue":                     "BigIntAPICost.BigIntGetCallValue",
+	"bigIntGetESDTCallValueByIndex":              "BigIntAPICost.BigIntGetCallValue",
+	"bigIntGetExternalBalance":                   "BigIntAPICost.BigIntGetExternalBalance",
+	"bigIntGetESDTExternalBalance":               "BigIntAPICost.BigIntGetExternalBalance",
+	"bigIntNew":                                  "BigIntAPICost.BigIntNew",
+	"bigIntDrop":                                 "BigIntAPICost.BigIntNew",
+	"bigIntUnsignedByteLength":                   "BigIntAPICost.BigIntUnsignedByteLength",
+	"bigIntSignedByteLength":                     "BigIntAPICost.BigIntSignedByteLength",
+	"bigIntGetUnsignedBytes":                     "BigIntAPICost.BigIntGetUnsignedBytes",
+	"bigIntGetSignedBytes":                       "BigIntAPICost.BigIntGetSignedBytes",
+	"bigIntSetUnsignedBytes":                     "BigIntAPICost.BigIntSetUnsignedBytes",
+	"bigIntSetSignedBytes":                       "BigIntAPICost.BigIntSetSignedBytes",
+	"bigIntIsInt64":                              "BigIntAPICost.BigIntIsInt64",
+	"bigIntGetInt64":                             "BigIntAPICost.BigIntGetInt64",
+	"bigIntSetInt64":                             "BigIntAPICost.BigIntSetInt64",
+	"bigIntAdd":                                  "BigIntAPICost.BigIntAdd",
+	"bigIntSub":                                  "BigIntAPICost.BigIntSub",
+	"bigIntMul":                                  "BigIntAPICost.BigIntMul",
+	"bigIntTDiv":                                 "BigIntAPICost.BigIntTDiv",
+	"bigIntTMod":                                 "BigIntAPICost.BigIntTMod",
+	"bigIntEDiv":                                 "BigIntAPICost.BigIntEDiv",
+	"bigIntEMod":                                 "BigIntAPICost.BigIntEMod",
+	"bigIntSqrt":                                 "BigIntAPICost.BigIntSqrt",
+	"bigIntPow":                                  "BigIntAPICost.BigIntPow",
+	"bigIntLog2":                                 "BigIntAPICost.BigIntLog",
+	"bigIntAbs":                                  "BigIntAPICost.BigIntAbs",
+	"bigIntNeg":                                  "BigIntAPICost.BigIntNeg",
+	"bigIntSign":                                 "BigIntAPICost.BigIntSign",
+	"bigIntCmp":                                  "BigIntAPICost.BigIntCmp",
+	"bigIntNot":                                  "BigIntAPICost.BigIntNot",
+	"bigIntAnd":                                  "BigIntAPICost.BigIntAnd",
+	"bigIntOr":                                   "BigIntAPICost.BigIntOr",
+	"bigIntXor":                                  "BigIntAPICost.BigIntXor",
+	"bigIntShr":                                  "BigIntAPICost.BigIntShr",
+	"bigIntShl":                                  "BigIntAPICost.BigIntShl",
+	"bigIntFinishUnsigned":                       "BigIntAPICost.BigIntFinishUnsigned",
+	"bigIntFinishSigned":                         "BigIntAPICost.BigIntFinishSigned",
+	"bigIntToString":                             "BigIntAPICost.BigIntFinishSigned",
+	"mBufferNew":                                 "ManagedBufferAPICost.MBufferNew",
+	"mBufferNewFromBytes":                        "ManagedBufferAPICost.MBufferNewFromBytes",
+	"mBufferDrop":                                "ManagedBufferAPICost.MBufferNew",
+	"mBufferGetLength":                           "ManagedBufferAPICost.MBufferGetLength",
+	"mBufferGetBytes":                            "ManagedBufferAPICost.MBufferGetBytes",
+	"mBufferGetByteSlice":                        "ManagedBufferAPICost.MBufferGetByteSlice",
+	"mBufferCopyByteSlice":                       "ManagedBufferAPICost.MBufferCopyByteSlice",
+	"mBufferEq":                                  "ManagedBufferAPICost.MBufferCopyByteSlice",
+	"mBufferSetBytes":                            "ManagedBufferAPICost.MBufferSetBytes",
+	"mBufferSetByteSlice":                        "ManagedBufferAPICost.MBufferSetBytes",
+	"mBufferAppend":                              "ManagedBufferAPICost.MBufferAppend",
+	"mBufferAppendBytes":                         "ManagedBufferAPICost.MBufferAppendBytes",
+	"mBufferToBigIntUnsigned":                    "ManagedBufferAPICost.MBufferToBigIntUnsigned",
+	"mBufferToBigIntSigned":                      "ManagedBufferAPICost.MBufferToBigIntSigned",
+	"mBufferFromBigIntUnsigned":                  "ManagedBufferAPICost.MBufferFromBigIntUnsigned",
+	"mBufferFromBigIntSigned":                    "ManagedBufferAPICost.MBufferFromBigIntSigned",
+	"mBufferToBigFloat":                          "ManagedBufferAPICost.MBufferToBigFloat",
+	"mBufferFromBigFloat":                        "ManagedBufferAPICost.MBufferFromBigFloat",
+	"mBufferStorageStore":                        "ManagedBufferAPICost.MBufferStorageStore",
+	"mBufferStorageDelete":                       "ManagedBufferAPICost.MBufferStorageStore",
+	"mBufferStorageLoad":                         "ManagedBufferAPICost.MBufferStorageLoad",
+	"mBufferStorageLoadFromAddress":              "ElrondAPICost.StorageLoad",
+	"mBufferGetArgument":                         "ManagedBufferAPICost.MBufferGetArgument",
+	"mBufferFinish":                              "ManagedBufferAPICost.MBufferFinish",
+	"mBufferSetRandom":                           "ManagedBufferAPICost.MBufferSetRandom",
+	"smallIntGetUnsignedArgument":                "ElrondAPICost.Int64GetArgument",
+	"smallIntGetSignedArgument":                  "ElrondAPICost.Int64GetArgument",
+	"smallIntFinishUnsigned":                     "ElrondAPICost.Int64Finish",
+	"smallIntFinishSigned":                       "ElrondAPICost.Int64Finish",
+	"smallIntStorageStoreUnsigned":               "ElrondAPICost.Int64StorageStore",
+	"smallIntStorageStoreSigned":                 "ElrondAPICost.Int64StorageStore",
+	"smallIntStorageLoadUnsigned":                "ElrondAPICost.Int64StorageLoad",
+	"smallIntStorageLoadSigned":                  "ElrondAPICost.Int64StorageLoad",
+	"int64getArgument":                           "ElrondAPICost.Int64GetArgument",
+	"int64finish":                                "ElrondAPICost.Int64Finish",
+	"int64storageStore":                          "ElrondAPICost.Int64StorageStore",
+	"int64storageLoad":                           "ElrondAPICost.Int64StorageLoad",
+	"sha256":                                     "CryptoAPICost.SHA256",
+	"managedSha256":                              "CryptoAPICost.SHA256",
+	"keccak256":                                  "CryptoAPICost.Keccak256",
+	"managedKeccak256":                           "CryptoAPICost.Keccak256",
+	"ripemd160":                                  "CryptoAPICost.Ripemd160",
+	"managedRipemd160":                           "CryptoAPICost.Ripemd160",
+	"verifyBLS":                                  "CryptoAPICost.VerifyBLS",
+	"managedVerifyBLS":                           "CryptoAPICost.VerifyBLS",
+	"verifyEd25519":                              "CryptoAPICost.VerifyEd25519",
+	"managedVerifyEd25519":                       "CryptoAPICost.VerifyEd25519",
+	"verifyCustomSecp256k1":                      "CryptoAPICost.VerifySecp256k1",
+	"managedVerifyCustomSecp256k1":               "CryptoAPICost.VerifySecp256k1",
+	"verifySecp256k1":                            "CryptoAPICost.VerifySecp256k1",
+	"managedVerifySecp256k1":                     "CryptoAPICost.VerifySecp256k1",
+	"encodeSecp256k1DerSignature":                "CryptoAPICost.EncodeDERSig",
+	"managedEncodeSecp256k1DerSignature":         "CryptoAPICost.EncodeDERSig",
+	"addEC":                                      "CryptoAPICost.AddECC",
+	"doubleEC":                                   "CryptoAPICost.DoubleECC",
+	"isOnCurveEC":                                "CryptoAPICost.IsOnCurveECC",
+	"scalarBaseMultEC":                           "CryptoAPICost.ScalarMultECC",
+	"managedScalarBaseMultEC":                    "CryptoAPICost.ScalarMultECC",
+	"scalarMultEC":                               "CryptoAPICost.ScalarMultECC",
+	"managedScalarMultEC":                        "CryptoAPICost.ScalarMultECC",
+	"marshalEC":                                  "CryptoAPICost.MarshalECC",
+	"managedMarshalEC":                           "CryptoAPICost.MarshalECC",
+	"marshalCompressedEC":                        "CryptoAPICost.MarshalCompressedECC",
+	"managedMarshalCompressedEC":                 "CryptoAPICost.MarshalCompressedECC",
+	"unmarshalEC":                                "CryptoAPICost.UnmarshalECC",
+	"managedUnmarshalEC":                         "CryptoAPICost.UnmarshalECC",
+	"unmarshalCompressedEC":                      "CryptoAPICost.UnmarshalCompressedECC",
+	"managedUnmarshalCompressedEC":               "CryptoAPICost.UnmarshalCompressedECC",
+	"generateKeyEC":                              "CryptoAPICost.GenerateKeyECC",
+	"managedGenerateKeyEC":                       "CryptoAPICost.GenerateKeyECC",
+	"createEC":                                   "CryptoAPICost.EllipticCurveNew",
+	"managedCreateEC":                            "CryptoAPICost.EllipticCurveNew",
+	"getCurveLengthEC":                           "BigIntAPICost.BigIntGetInt64",
+	"getPrivKeyByteLengthEC":                     "BigIntAPICost.BigIntGetInt64",
+	"ellipticCurveGetValues":                     "BigIntAPICost.BigIntGetInt64",
+}
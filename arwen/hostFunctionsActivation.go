@@ -0,0 +1,278 @@
+package arwen
+
+import (
+	"sort"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// ManagedBufferHostFunctions are the host functions enabled by
+// the ManagedBufferFunctionsEnableEpoch of the EnableEpochsHandler
+var ManagedBufferHostFunctions = []string{
+	"mBufferNew",
+	"mBufferNewFromBytes",
+	"mBufferGetLength",
+	"mBufferGetBytes",
+	"mBufferGetByteSlice",
+	"mBufferCopyByteSlice",
+	"mBufferEq",
+	"mBufferSetBytes",
+	"mBufferSetByteSlice",
+	"mBufferAppend",
+	"mBufferAppendBytes",
+	"mBufferToBigIntUnsigned",
+	"mBufferToBigIntSigned",
+	"mBufferFromBigIntUnsigned",
+	"mBufferFromBigIntSigned",
+	"mBufferToBigFloat",
+	"mBufferFromBigFloat",
+	"mBufferStorageStore",
+	"mBufferStorageLoad",
+	"mBufferStorageLoadFromAddress",
+	"mBufferGetArgument",
+	"mBufferFinish",
+	"mBufferSetRandom",
+	"managedBufferToHex",
+}
+
+// PromisesHostFunctions are the host functions enabled by
+// the PromisesFunctionsEnableEpoch of the EnableEpochsHandler
+var PromisesHostFunctions = []string{
+	"createAsyncCall",
+	"setAsyncContextCallback",
+	"managedCreateAsyncCall",
+	"managedCreateAsyncCallWithCallbackArgTypes",
+	"managedCreateAsyncCallWithExpiry",
+	"managedGetCallbackClosure",
+}
+
+// PromiseHandleHostFunctions are the host functions enabled by
+// the PromiseHandleFunctionsEnableEpoch of the EnableEpochsHandler
+var PromiseHandleHostFunctions = []string{
+	"createPromiseGroup",
+	"createPromise",
+}
+
+// ManagedCryptoHostFunctions are the host functions enabled by
+// the ManagedCryptoFunctionsEnableEpoch of the EnableEpochsHandler
+var ManagedCryptoHostFunctions = []string{
+	"managedSha256",
+	"managedKeccak256",
+	"managedRipemd160",
+	"managedVerifyBLS",
+	"managedVerifyEd25519",
+	"managedVerifyCustomSecp256k1",
+	"managedVerifySecp256k1",
+	"managedEncodeSecp256k1DerSignature",
+	"managedScalarBaseMultEC",
+	"managedScalarMultEC",
+	"managedMarshalEC",
+	"managedMarshalCompressedEC",
+	"managedUnmarshalEC",
+	"managedUnmarshalCompressedEC",
+	"managedGenerateKeyEC",
+	"managedCreateEC",
+}
+
+// ContractAddressHostFunctions are the host functions enabled by
+// the ContractAddressFunctionsEnableEpoch of the EnableEpochsHandler
+var ContractAddressHostFunctions = []string{
+	"managedComputeContractAddress",
+	"managedComputeDeterministicAddress",
+	"managedCreateDeterministicContract",
+}
+
+// StorageDeleteHostFunctions are the host functions enabled by
+// the StorageDeleteFunctionsEnableEpoch of the EnableEpochsHandler
+var StorageDeleteHostFunctions = []string{
+	"storageDelete",
+	"mBufferStorageDelete",
+}
+
+// DebugPrintHostFunctions are the host functions enabled by
+// the DebugPrintFunctionsEnableEpoch of the EnableEpochsHandler
+var DebugPrintHostFunctions = []string{
+	"debugPrint",
+}
+
+// GasPhaseHostFunctions are the host functions enabled by
+// the GasPhaseFunctionsEnableEpoch of the EnableEpochsHandler
+var GasPhaseHostFunctions = []string{
+	"gasPhase",
+}
+
+// ManagedHandleDropHostFunctions are the host functions enabled by
+// the ManagedHandleDropFunctionsEnableEpoch of the EnableEpochsHandler
+var ManagedHandleDropHostFunctions = []string{
+	"mBufferDrop",
+	"bigIntDrop",
+}
+
+// ChunkedFinishHostFunctions are the host functions enabled by
+// the ChunkedFinishFunctionsEnableEpoch of the EnableEpochsHandler
+var ChunkedFinishHostFunctions = []string{
+	"finishReserve",
+	"finishAppend",
+}
+
+// NFTMetadataHostFunctions are the host functions enabled by
+// the NFTMetadataFunctionsEnableEpoch of the EnableEpochsHandler
+var NFTMetadataHostFunctions = []string{
+	"managedGetESDTNFTAttributes",
+	"managedGetESDTNFTAttributeValue",
+	"managedGetESDTNFTURIs",
+	"managedGetESDTNFTCreator",
+	"managedGetESDTNFTRoyalties",
+	"managedGetESDTTokenTypeOf",
+}
+
+// NFTRoyaltiesHostFunctions are the host functions enabled by
+// the NFTRoyaltiesFunctionsEnableEpoch of the EnableEpochsHandler
+var NFTRoyaltiesHostFunctions = []string{
+	"managedTransferNFTWithRoyalties",
+}
+
+// ArgumentSliceHostFunctions are the host functions enabled by
+// the ArgumentSliceFunctionsEnableEpoch of the EnableEpochsHandler
+var ArgumentSliceHostFunctions = []string{
+	"copyArgumentTo",
+}
+
+// EscrowHostFunctions are the host functions enabled by
+// the EscrowFunctionsEnableEpoch of the EnableEpochsHandler
+var EscrowHostFunctions = []string{
+	"managedEscrowLock",
+	"managedEscrowClaim",
+	"managedEscrowRelease",
+}
+
+// GasRefundHostFunctions are the host functions enabled by
+// the GasRefundFunctionsEnableEpoch of the EnableEpochsHandler
+var GasRefundHostFunctions = []string{
+	"refundGasToCaller",
+}
+
+// TypedErrorHostFunctions are the host functions enabled by
+// the TypedErrorFunctionsEnableEpoch of the EnableEpochsHandler
+var TypedErrorHostFunctions = []string{
+	"signalTypedError",
+}
+
+// StorageMigrationHostFunctions are the host functions enabled by
+// the StorageMigrationFunctionsEnableEpoch of the EnableEpochsHandler
+var StorageMigrationHostFunctions = []string{
+	"managedRegisterStorageMigration",
+	"managedContinueStorageMigration",
+}
+
+// LockedValueHostFunctions are the host functions enabled by
+// the LockedValueLedgerEnableEpoch of the EnableEpochsHandler
+var LockedValueHostFunctions = []string{
+	"managedGetLockedValue",
+}
+
+// AsyncCallSubGroupsHostFunctions are the host functions enabled by
+// the AsyncCallSubGroupsEnableEpoch of the EnableEpochsHandler
+var AsyncCallSubGroupsHostFunctions = []string{
+	"managedSetAsyncGroupCallback",
+	"managedAddAsyncSubGroup",
+}
+
+// HostFunctionsActivation is the table of the epochs from which each group of
+// host functions is active, built once for the enable epochs of a host, so
+// that instantiating a contract does not gather the inactive host functions
+// again; the names are kept in the order of their enable epochs, so the
+// functions inactive in an epoch are a suffix of the table
+type HostFunctionsActivation struct {
+	names  []string
+	epochs []uint32
+}
+
+type hostFunctionsGroup struct {
+	enableEpoch uint32
+	names       []string
+}
+
+// NewHostFunctionsActivation builds the activation table of the host
+// functions for the enable epochs of the given handler
+func NewHostFunctionsActivation(enableEpochsHandler EnableEpochsHandler) *HostFunctionsActivation {
+	groups := hostFunctionsGroups(enableEpochsHandler)
+	sort.SliceStable(groups, func(i, j int) bool {
+		return groups[i].enableEpoch < groups[j].enableEpoch
+	})
+
+	activation := &HostFunctionsActivation{}
+	for _, group := range groups {
+		for _, name := range group.names {
+			activation.names = append(activation.names, name)
+			activation.epochs = append(activation.epochs, group.enableEpoch)
+		}
+	}
+	return activation
+}
+
+// InactiveHostFunctions returns the names of the host functions which are not
+// yet available to contracts in the given epoch; the returned slice is shared
+// and must not be modified
+func (activation *HostFunctionsActivation) InactiveHostFunctions(epoch uint32) []string {
+	firstInactive := sort.Search(len(activation.epochs), func(i int) bool {
+		return activation.epochs[i] > epoch
+	})
+	return activation.names[firstInactive:]
+}
+
+// InactiveHostFunctions returns the names of the host functions which are not
+// yet available to contracts in the given epoch
+func InactiveHostFunctions(enableEpochsHandler EnableEpochsHandler, epoch uint32) []string {
+	return NewHostFunctionsActivation(enableEpochsHandler).InactiveHostFunctions(epoch)
+}
+
+// hostFunctionsGroups lists the host functions added after genesis, with the
+// epochs from which they are active; the aliases of a host function are
+// active from the same epoch as the function
+func hostFunctionsGroups(enableEpochsHandler EnableEpochsHandler) []hostFunctionsGroup {
+	groups := []hostFunctionsGroup{
+		{enableEpochsHandler.ManagedBufferFunctionsEnableEpoch(), ManagedBufferHostFunctions},
+		{enableEpochsHandler.PromisesFunctionsEnableEpoch(), PromisesHostFunctions},
+		{enableEpochsHandler.PromiseHandleFunctionsEnableEpoch(), PromiseHandleHostFunctions},
+		{enableEpochsHandler.ManagedCryptoFunctionsEnableEpoch(), ManagedCryptoHostFunctions},
+		{enableEpochsHandler.ContractAddressFunctionsEnableEpoch(), ContractAddressHostFunctions},
+		{enableEpochsHandler.StorageDeleteFunctionsEnableEpoch(), StorageDeleteHostFunctions},
+		{enableEpochsHandler.DebugPrintFunctionsEnableEpoch(), DebugPrintHostFunctions},
+		{enableEpochsHandler.GasPhaseFunctionsEnableEpoch(), GasPhaseHostFunctions},
+		{enableEpochsHandler.ManagedHandleDropFunctionsEnableEpoch(), ManagedHandleDropHostFunctions},
+		{enableEpochsHandler.ChunkedFinishFunctionsEnableEpoch(), ChunkedFinishHostFunctions},
+		{enableEpochsHandler.NFTMetadataFunctionsEnableEpoch(), NFTMetadataHostFunctions},
+		{enableEpochsHandler.NFTRoyaltiesFunctionsEnableEpoch(), NFTRoyaltiesHostFunctions},
+		{enableEpochsHandler.ArgumentSliceFunctionsEnableEpoch(), ArgumentSliceHostFunctions},
+		{enableEpochsHandler.EscrowFunctionsEnableEpoch(), EscrowHostFunctions},
+		{enableEpochsHandler.GasRefundFunctionsEnableEpoch(), GasRefundHostFunctions},
+		{enableEpochsHandler.TypedErrorFunctionsEnableEpoch(), TypedErrorHostFunctions},
+		{enableEpochsHandler.StorageMigrationFunctionsEnableEpoch(), StorageMigrationHostFunctions},
+		{enableEpochsHandler.LockedValueLedgerEnableEpoch(), LockedValueHostFunctions},
+		{enableEpochsHandler.AsyncCallSubGroupsEnableEpoch(), AsyncCallSubGroupsHostFunctions},
+	}
+	return withHostFunctionAliases(groups, executor.HostFunctionAliases)
+}
+
+// withHostFunctionAliases adds the aliases to the groups of their targets;
+// the aliases of the host functions available from genesis are left out
+func withHostFunctionAliases(groups []hostFunctionsGroup, aliases []executor.HostFunctionAlias) []hostFunctionsGroup {
+	groupOfName := make(map[string]int)
+	for i, group := range groups {
+		for _, name := range group.names {
+			groupOfName[name] = i
+		}
+	}
+
+	for _, alias := range aliases {
+		i, ok := groupOfName[alias.Target]
+		if !ok {
+			continue
+		}
+		names := make([]string, 0, len(groups[i].names)+1)
+		names = append(names, groups[i].names...)
+		groups[i].names = append(names, alias.Alias)
+	}
+	return groups
+}
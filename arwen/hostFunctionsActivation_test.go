@@ -0,0 +1,66 @@
+package arwen
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+func TestHostFunctionsActivation_InactiveHostFunctions(t *testing.T) {
+	enableEpochsHandler := &worldmock.EnableEpochsHandlerStub{
+		ManagedBufferFunctionsEnableEpochField: 5,
+		DebugPrintFunctionsEnableEpochField:    2,
+		ChunkedFinishFunctionsEnableEpochField: 5,
+	}
+	activation := NewHostFunctionsActivation(enableEpochsHandler)
+
+	inactive := activation.InactiveHostFunctions(0)
+	require.Len(t, inactive, len(ManagedBufferHostFunctions)+len(DebugPrintHostFunctions)+len(ChunkedFinishHostFunctions))
+	require.Contains(t, inactive, "debugPrint")
+
+	inactive = activation.InactiveHostFunctions(2)
+	require.Len(t, inactive, len(ManagedBufferHostFunctions)+len(ChunkedFinishHostFunctions))
+	require.NotContains(t, inactive, "debugPrint")
+	require.Contains(t, inactive, "mBufferNew")
+	require.Contains(t, inactive, "finishReserve")
+
+	require.Empty(t, activation.InactiveHostFunctions(5))
+	require.Empty(t, NewHostFunctionsActivation(worldmock.EnableEpochsHandlerStubNoFlags()).InactiveHostFunctions(0))
+}
+
+func TestHostFunctionsActivation_SameAsInactiveHostFunctions(t *testing.T) {
+	enableEpochsHandler := &worldmock.EnableEpochsHandlerStub{
+		PromisesFunctionsEnableEpochField:     3,
+		NFTMetadataFunctionsEnableEpochField:  1,
+		NFTRoyaltiesFunctionsEnableEpochField: 7,
+	}
+	activation := NewHostFunctionsActivation(enableEpochsHandler)
+	for epoch := uint32(0); epoch < 10; epoch++ {
+		require.ElementsMatch(t, InactiveHostFunctions(enableEpochsHandler, epoch), activation.InactiveHostFunctions(epoch))
+	}
+}
+
+func BenchmarkHostFunctionsActivation_InactiveHostFunctions(b *testing.B) {
+	activation := NewHostFunctionsActivation(&worldmock.EnableEpochsHandlerStub{ManagedBufferFunctionsEnableEpochField: 10})
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		_ = activation.InactiveHostFunctions(uint32(i % 20))
+	}
+}
+
+func TestHostFunctionsActivation_Aliases(t *testing.T) {
+	groups := []hostFunctionsGroup{
+		{3, []string{"mBufferNew", "mBufferEq"}},
+		{5, []string{"debugPrint"}},
+	}
+	aliases := []executor.HostFunctionAlias{
+		{Alias: "mBufferEquals", Target: "mBufferEq"},
+		{Alias: "oldGetGasLeft", Target: "getGasLeft"},
+	}
+
+	groups = withHostFunctionAliases(groups, aliases)
+	require.Equal(t, []string{"mBufferNew", "mBufferEq", "mBufferEquals"}, groups[0].names)
+	require.Equal(t, []string{"debugPrint"}, groups[1].names)
+}
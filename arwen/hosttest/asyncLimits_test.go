@@ -0,0 +1,87 @@
+package hosttest
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestAsyncLimits_Unlimited(t *testing.T) {
+	runFanOutAsyncCalls(t, arwen.AsyncLimits{}, 3, 4, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok()
+		require.Equal(t, 12, len(verify.VmOutput.OutputAccounts[string(test.ChildAddress)].OutputTransfers))
+	})
+}
+
+func TestAsyncLimits_WithinLimits(t *testing.T) {
+	asyncLimits := arwen.AsyncLimits{
+		MaxCallsPerGroup:   4,
+		MaxCallGroups:      3,
+		MaxOutputTransfers: 12,
+	}
+	runFanOutAsyncCalls(t, asyncLimits, 3, 4, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok()
+	})
+}
+
+func TestAsyncLimits_MaxCallsPerGroupExceeded(t *testing.T) {
+	asyncLimits := arwen.AsyncLimits{MaxCallsPerGroup: 3}
+	runFanOutAsyncCalls(t, asyncLimits, 3, 4, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.ExecutionFailed().
+			ReturnMessage(arwen.ErrMaxAsyncCallsPerGroupExceeded.Error())
+	})
+}
+
+func TestAsyncLimits_MaxCallGroupsExceeded(t *testing.T) {
+	asyncLimits := arwen.AsyncLimits{MaxCallGroups: 2}
+	runFanOutAsyncCalls(t, asyncLimits, 3, 4, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.ExecutionFailed().
+			ReturnMessage(arwen.ErrMaxAsyncCallGroupsExceeded.Error())
+	})
+}
+
+func TestAsyncLimits_MaxOutputTransfersExceeded(t *testing.T) {
+	asyncLimits := arwen.AsyncLimits{MaxOutputTransfers: 11}
+	runFanOutAsyncCalls(t, asyncLimits, 3, 4, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.ExecutionFailed().
+			ReturnMessage(arwen.ErrMaxOutputTransfersExceeded.Error())
+	})
+}
+
+func runFanOutAsyncCalls(
+	t *testing.T,
+	asyncLimits arwen.AsyncLimits,
+	numGroups int64,
+	callsPerGroup int64,
+	assertResults test.AssertResultsFunc,
+) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 100000
+	testConfig.GasProvidedToChild = 10
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContractOnShard(test.ParentAddress, 0).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.FanOutAsyncCallsMock),
+			test.CreateMockContractOnShard(test.ChildAddress, 1).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithMethods(),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("fanOutAsyncCalls").
+			WithArguments(big.NewInt(numGroups).Bytes(), big.NewInt(callsPerGroup).Bytes()).
+			Build()).
+		WithAsyncLimits(asyncLimits).
+		AndAssertResults(assertResults)
+	require.Nil(t, err)
+}
@@ -0,0 +1,208 @@
+package hosttest
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+func TestAttackers_Reentrancy_StoppedByMaxInstances(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 100000
+	testConfig.GasUsedByParent = 100
+	testConfig.GasUsedByChild = 100
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.ReentrancyAttackerMock),
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.ReentrancyVictimMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(contracts.AttackerAttackFunction).
+			WithGasProvided(testConfig.GasProvided).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.ExecutionFailed().
+				HasRuntimeErrors(arwen.ErrMaxInstancesReached.Error()).
+				GasRemaining(0)
+		})
+	if err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestAttackers_InfiniteLoop_OutOfGas(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.InfiniteLoopMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(contracts.AttackerLoopFunction).
+			WithGasProvided(testConfig.GasProvided).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.OutOfGas().
+				GasRemaining(0)
+		})
+	if err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestAttackers_GasGriefing_CallbackRunsWithLockedGas(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.GasGriefingParentMock),
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.InfiniteLoopMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(contracts.AttackerGriefFunction).
+			WithGasProvided(testConfig.GasProvided).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+			setAsyncCosts(host, testConfig.GasLockCost)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				HasRuntimeErrors(arwen.ErrNotEnoughGas.Error()).
+				ReturnData(contracts.AttackerGriefedCallbackData)
+		})
+	if err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestAttackers_StorageBomb_OutOfGas(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.StorageBombMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(contracts.AttackerStorageBombFunction).
+			WithGasProvided(testConfig.GasProvided).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+			host.Metering().GasSchedule().ElrondAPICost.StorageStore = 10
+			host.Metering().GasSchedule().BaseOperationCost.StorePerByte = 1
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.OutOfGas().
+				HasRuntimeErrors(arwen.ErrNotEnoughGas.Error()).
+				GasRemaining(0).
+				Storage()
+		})
+	if err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestAttackers_HugeFinish_MaxReturnDataSizeExceeded(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 100000
+
+	runHugeFinish(t, testConfig, func(host arwen.VMHost) {
+		host.Output().SetMaxReturnDataSize(uint64(4 * len(contracts.AttackerFinishChunk)))
+	}, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.ReturnCode(arwen.ReturnDataTooLarge).
+			ReturnMessage(arwen.ErrMaxReturnDataSizeExceeded.Error()).
+			GasRemaining(0)
+	})
+}
+
+func TestAttackers_HugeFinish_GasScheduleMaxReturnDataSize(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 100000
+
+	runHugeFinish(t, testConfig, func(host arwen.VMHost) {
+		gasSchedule := host.GetGasScheduleMap()
+		gasSchedule["ElrondAPICost"]["MaxReturnDataSize"] = uint64(4 * len(contracts.AttackerFinishChunk))
+		host.GasScheduleChange(gasSchedule)
+		setZeroCodeCosts(host)
+		host.Metering().GasSchedule().ManagedBufferAPICost.MBufferFinish = 1
+	}, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.ReturnCode(arwen.ReturnDataTooLarge).
+			ReturnMessage(arwen.ErrMaxReturnDataSizeExceeded.Error()).
+			GasRemaining(0)
+	})
+}
+
+func TestAttackers_HugeFinish_OutOfGas(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 10000
+
+	runHugeFinish(t, testConfig, func(host arwen.VMHost) {
+		host.Metering().GasSchedule().BaseOperationCost.PersistPerByte = 1
+	}, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.OutOfGas().
+			HasRuntimeErrors(arwen.ErrNotEnoughGas.Error()).
+			GasRemaining(0)
+	})
+}
+
+func runHugeFinish(
+	t *testing.T,
+	testConfig *test.TestConfig,
+	setup func(host arwen.VMHost),
+	assertResults test.AssertResultsFunc,
+) {
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.HugeFinishMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(contracts.AttackerHugeFinishFunction).
+			WithGasProvided(testConfig.GasProvided).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+			host.Metering().GasSchedule().ManagedBufferAPICost.MBufferFinish = 1
+			setup(host)
+		}).
+		AndAssertResults(assertResults)
+	if err != nil {
+		t.Fatal(err)
+	}
+}
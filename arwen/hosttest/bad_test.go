@@ -1,12 +1,16 @@
 package hosttest
 
 import (
+	"math/big"
 	"testing"
 
 	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/config"
 	"github.com/ElrondNetwork/wasm-vm/executor"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
 )
 
 func TestBadContract_NoPanic_Memoryfault(t *testing.T) {
@@ -65,6 +69,50 @@ func TestBadContract_NoPanic_BadGetOwner1(t *testing.T) {
 		})
 }
 
+func TestBadContract_ErrorCodeLogTopic(t *testing.T) {
+	var errorCode arwen.ErrorCode
+	runBadGetOwner1 := func(enableEpochsHandler arwen.EnableEpochsHandler) [][]byte {
+		var topics [][]byte
+		test.BuildInstanceCallTest(t).
+			WithContracts(
+				test.CreateInstanceContract(test.ParentAddress).
+					WithCode(test.GetTestSCCode("bad-misc", "../../")).
+					WithBalance(1000)).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithRecipientAddr(test.ParentAddress).
+				WithGasProvided(test.GasProvided).
+				WithFunction("badGetOwner1").
+				Build()).
+			WithEnableEpochsHandler(enableEpochsHandler).
+			WithWasmerSIGSEGVPassthrough(false).
+			AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+				verify.
+					ExecutionFailed().
+					HasRuntimeErrors(arwen.ErrBadBounds.Error())
+
+				logs := verify.VmOutput.Logs
+				require.NotEmpty(t, logs)
+				errorsLog := logs[len(logs)-1]
+				require.Equal(t, []byte("internalVMErrors"), errorsLog.Identifier)
+				require.Equal(t, test.ParentAddress, errorsLog.Topics[0])
+				require.Equal(t, []byte("badGetOwner1"), errorsLog.Topics[1])
+				topics = errorsLog.Topics
+				errorCode = arwen.ErrorCodeOf(verify.AllErrors)
+			})
+		return topics
+	}
+
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubNoFlags()
+	topics := runBadGetOwner1(enableEpochsHandler)
+	require.NotEqual(t, arwen.ErrorCodeUnknown, errorCode)
+	require.Equal(t, [][]byte{test.ParentAddress, []byte("badGetOwner1"), big.NewInt(int64(errorCode)).Bytes()}, topics)
+
+	enableEpochsHandler = worldmock.EnableEpochsHandlerStubNoFlags()
+	enableEpochsHandler.ErrorCodeLogTopicEnableEpochField = config.DisabledEpoch
+	topics = runBadGetOwner1(enableEpochsHandler)
+	require.Equal(t, [][]byte{test.ParentAddress, []byte("badGetOwner1")}, topics)
+}
+
 func TestBadContract_NoPanic_BadBigIntStorageStore1(t *testing.T) {
 	test.BuildInstanceCallTest(t).
 		WithContracts(
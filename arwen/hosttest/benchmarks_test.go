@@ -0,0 +1,136 @@
+package hosttest
+
+import (
+	"math/big"
+	"os"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+const benchmarkGasProvided = uint64(1e15)
+
+var hostFunctionBenchmarks = []test.NamedBenchmark{
+	{Name: "HostFunction/StorageStore", Benchmark: benchmarkHostFunction("benchStorageStore")},
+	{Name: "HostFunction/StorageLoad", Benchmark: benchmarkHostFunction("benchStorageLoad")},
+	{Name: "HostFunction/Finish", Benchmark: benchmarkHostFunction("benchFinish")},
+	{Name: "HostFunction/BigIntOps", Benchmark: benchmarkHostFunction("benchBigIntOps")},
+	{Name: "HostFunction/Sha256", Benchmark: benchmarkHostFunction("benchSha256")},
+	{Name: "HostFunction/CreateAsyncCall", Benchmark: benchmarkHostFunction("benchAsyncCall")},
+}
+
+var contractCallBenchmarks = []test.NamedBenchmark{
+	{Name: "ContractCall/PromisesAsyncCall", Benchmark: benchmarkPromisesAsyncCall([]byte{0})},
+	{Name: "ContractCall/PromisesAsyncCallChildFails", Benchmark: benchmarkPromisesAsyncCall([]byte{1})},
+}
+
+func BenchmarkHostFunctions(b *testing.B) {
+	for _, namedBenchmark := range hostFunctionBenchmarks {
+		b.Run(namedBenchmark.Name, namedBenchmark.Benchmark)
+	}
+}
+
+func BenchmarkContractCalls(b *testing.B) {
+	for _, namedBenchmark := range contractCallBenchmarks {
+		b.Run(namedBenchmark.Name, namedBenchmark.Benchmark)
+	}
+}
+
+// TestBenchmarks_ExportReport runs all the benchmarks and writes their results
+// in the JSON file named by the ARWEN_BENCHMARK_REPORT environment variable
+func TestBenchmarks_ExportReport(t *testing.T) {
+	reportPath := os.Getenv(test.BenchmarkReportEnvVar)
+	if len(reportPath) == 0 {
+		t.Skip("no benchmark report requested")
+	}
+
+	benchmarks := append(append([]test.NamedBenchmark{}, hostFunctionBenchmarks...), contractCallBenchmarks...)
+	results := test.RunBenchmarks(benchmarks)
+	require.Len(t, results, len(benchmarks))
+	for _, result := range results {
+		require.True(t, result.Iterations > 0, result.Name)
+	}
+
+	err := test.WriteBenchmarkReport(reportPath, results)
+	require.Nil(t, err)
+}
+
+// benchmarkHostFunction measures a mock contract method which calls its host
+// function b.N times within a single execution
+func benchmarkHostFunction(function string) func(b *testing.B) {
+	return func(b *testing.B) {
+		reps := big.NewInt(int64(b.N)).Bytes()
+
+		_, err := test.BuildMockInstanceCallTest(b).
+			WithContracts(
+				test.CreateMockContractOnShard(test.ParentAddress, 0).
+					WithBalance(1000).
+					WithMethods(
+						contracts.BenchStorageStore,
+						contracts.BenchStorageLoad,
+						contracts.BenchFinish,
+						contracts.BenchBigIntOps,
+						contracts.BenchSha256,
+						contracts.BenchAsyncCall,
+					),
+				test.CreateMockContractOnShard(test.ChildAddress, 1).
+					WithBalance(1000).
+					WithMethods()).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithRecipientAddr(test.ParentAddress).
+				WithGasProvided(benchmarkGasProvided).
+				WithFunction(function).
+				WithArguments(reps).
+				Build()).
+			WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+				world.SelfShardID = 0
+				err := world.AcctMap.GetAccount(test.ParentAddress).SaveKeyValue(contracts.BenchmarkKey, contracts.BenchmarkValue)
+				require.Nil(b, err)
+				b.ResetTimer()
+			}).
+			AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+				b.StopTimer()
+				verify.Ok()
+			})
+		require.Nil(b, err)
+	}
+}
+
+// benchmarkPromisesAsyncCall measures b.N complete executions of the promises
+// parent contract, each one calling the child contract and its callback
+func benchmarkPromisesAsyncCall(childBehavior []byte) func(b *testing.B) {
+	return func(b *testing.B) {
+		instanceContracts := []*test.InstanceTestSmartContract{
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(test.GetTestSCCode("async-promises-parent", "../../")).
+				WithBalance(1000),
+			test.CreateInstanceContract(test.ChildAddress).
+				WithCode(test.GetTestSCCode("async-call-child", "../../")).
+				WithBalance(1000),
+		}
+
+		host := test.NewTestHostBuilder(b).
+			WithBlockchainHook(test.BlockchainHookStubForContracts(instanceContracts)).
+			Build()
+		defer host.Reset()
+
+		input := test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(parentPerformAsyncCall).
+			WithGasProvided(1000000).
+			WithArguments(childBehavior, big.NewInt(2000).Bytes(), big.NewInt(1000).Bytes()).
+			Build()
+
+		b.ResetTimer()
+		for i := 0; i < b.N; i++ {
+			vmOutput, err := host.RunSmartContractCall(input)
+			require.Nil(b, err)
+			require.Equal(b, vmcommon.Ok, vmOutput.ReturnCode)
+		}
+	}
+}
@@ -0,0 +1,68 @@
+package hosttest
+
+import (
+	"math/big"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestCallbackOutOfGas_CalleeEffectsKept_CallbackEffectsReverted(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.CallbackOutOfGasParentMock),
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.CallbackOutOfGasChildMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(contracts.CallbackOutOfGasFunction).
+			WithGasProvided(testConfig.GasProvided).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+			setAsyncCosts(host, testConfig.GasLockCost)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				HasRuntimeErrors(arwen.ErrNotEnoughGas.Error()).
+				BalanceDelta(test.ParentAddress, -contracts.CallbackOutOfGasValue).
+				BalanceDelta(test.ChildAddress, contracts.CallbackOutOfGasValue).
+				ReturnData(test.ChildFinish).
+				Storage(
+					test.CreateStoreEntry(test.ParentAddress).WithKey(test.ParentKeyA).WithValue(test.ParentDataA),
+					test.CreateStoreEntry(test.ChildAddress).WithKey(test.ChildKey).WithValue(test.ChildData),
+				)
+
+			var callbackFailedLog *vmcommon.LogEntry
+			for _, logEntry := range verify.VmOutput.Logs {
+				if string(logEntry.Identifier) == arwen.AsyncCallbackFailedIdentifier {
+					require.Nil(t, callbackFailedLog, "callback failure logged twice")
+					callbackFailedLog = logEntry
+				}
+			}
+			require.NotNil(t, callbackFailedLog, "callback failure not logged")
+			require.Equal(t, test.ParentAddress, callbackFailedLog.Address)
+			require.Len(t, callbackFailedLog.Topics, 4)
+			require.Equal(t, test.ChildAddress, callbackFailedLog.Topics[0])
+			require.NotEmpty(t, callbackFailedLog.Topics[1])
+			require.Equal(t, []byte(contracts.CallbackOutOfGasCallback), callbackFailedLog.Topics[2])
+			require.Equal(t, big.NewInt(int64(vmcommon.OutOfGas)).Bytes(), callbackFailedLog.Topics[3])
+			require.Equal(t, []byte(arwen.ErrNotEnoughGas.Error()), callbackFailedLog.Data)
+		})
+	if err != nil {
+		t.Fatal(err)
+	}
+}
@@ -0,0 +1,228 @@
+package hosttest
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+var crowdfundingDeposit = int64(100)
+
+func TestCrowdfunding_FundEGLD(t *testing.T) {
+	input := test.CreateTestContractCallInputBuilder().
+		WithCallerAddr(test.UserAddress).
+		WithFunction(contracts.CrowdfundingFundFunction).
+		WithCallValue(crowdfundingDeposit)
+
+	previousDeposit := map[string][]byte{
+		string(contracts.CrowdfundingDepositKey(test.UserAddress)): big.NewInt(20).Bytes(),
+	}
+	runCrowdfundingTest(t, input, previousDeposit, nil, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok().
+			BalanceDelta(test.ParentAddress, crowdfundingDeposit).
+			Storage(
+				test.CreateStoreEntry(test.ParentAddress).
+					WithKey(contracts.CrowdfundingDepositKey(test.UserAddress)).
+					WithValue(big.NewInt(120).Bytes()),
+			)
+	})
+}
+
+func TestCrowdfunding_FundESDT(t *testing.T) {
+	input := test.CreateTestContractCallInputBuilder().
+		WithCallerAddr(test.UserAddress).
+		WithFunction(contracts.CrowdfundingFundFunction).
+		WithESDTTokenName(test.ESDTTestTokenName).
+		WithESDTValue(big.NewInt(5))
+
+	runCrowdfundingTest(t, input, nil, nil, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok().
+			Storage(
+				test.CreateStoreEntry(test.ParentAddress).
+					WithKey(contracts.CrowdfundingESDTDepositKey(test.UserAddress, test.ESDTTestTokenName)).
+					WithValue(big.NewInt(5).Bytes()),
+			)
+	})
+}
+
+func TestCrowdfunding_FundWithoutPayment(t *testing.T) {
+	input := test.CreateTestContractCallInputBuilder().
+		WithCallerAddr(test.UserAddress).
+		WithFunction(contracts.CrowdfundingFundFunction)
+
+	runCrowdfundingTest(t, input, nil, nil, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.UserError().
+			ReturnMessage("no payment")
+	})
+}
+
+func TestCrowdfunding_ForwardToVault_NotPayable(t *testing.T) {
+	input := forwardToVaultInput().
+		WithCallValue(5)
+
+	runCrowdfundingTest(t, input, fundedDeposit(), nil, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.ExecutionFailed().
+			ReturnMessage(arwen.ErrNonPayableFunctionEgld.Error())
+	})
+}
+
+func TestCrowdfunding_ForwardToVault_NothingToForward(t *testing.T) {
+	runCrowdfundingTest(t, forwardToVaultInput(), nil, nil, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.UserError().
+			ReturnMessage("nothing to forward")
+	})
+}
+
+func TestCrowdfunding_ForwardToVault_Accepted(t *testing.T) {
+	runCrowdfundingTest(t, forwardToVaultInput(), fundedDeposit(), nil, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok().
+			BalanceDelta(test.ParentAddress, -crowdfundingDeposit).
+			BalanceDelta(test.VaultAddress, crowdfundingDeposit).
+			ReturnData([]byte("forwarded")).
+			Storage(
+				test.CreateStoreEntry(test.ParentAddress).
+					WithKey(contracts.CrowdfundingDepositKey(test.UserAddress)).
+					WithValue([]byte{}),
+				test.CreateStoreEntry(test.ParentAddress).
+					WithKey(contracts.CrowdfundingForwardedKey(test.UserAddress)).
+					WithValue(big.NewInt(crowdfundingDeposit).Bytes()),
+				test.CreateStoreEntry(test.VaultAddress).
+					WithKey(contracts.VaultBalanceKey).
+					WithValue(big.NewInt(crowdfundingDeposit).Bytes()),
+			)
+	})
+}
+
+func TestCrowdfunding_ForwardToVault_ExcessSentBack(t *testing.T) {
+	vaultStorage := map[string][]byte{
+		string(contracts.VaultBalanceKey):  big.NewInt(10).Bytes(),
+		string(contracts.VaultCapacityKey): big.NewInt(70).Bytes(),
+	}
+	accepted := int64(60)
+	excess := crowdfundingDeposit - accepted
+
+	runCrowdfundingTest(t, forwardToVaultInput(), fundedDeposit(), vaultStorage, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok().
+			BalanceDelta(test.ParentAddress, -crowdfundingDeposit).
+			BalanceDelta(test.VaultAddress, accepted).
+			BalanceDelta(test.UserAddress, excess).
+			ReturnData([]byte("forwarded")).
+			Storage(
+				test.CreateStoreEntry(test.ParentAddress).
+					WithKey(contracts.CrowdfundingDepositKey(test.UserAddress)).
+					WithValue([]byte{}),
+				test.CreateStoreEntry(test.ParentAddress).
+					WithKey(contracts.CrowdfundingForwardedKey(test.UserAddress)).
+					WithValue(big.NewInt(accepted).Bytes()),
+				test.CreateStoreEntry(test.VaultAddress).
+					WithKey(contracts.VaultBalanceKey).
+					WithValue(big.NewInt(70).Bytes()),
+			).
+			Transfers(
+				test.CreateTransferEntry(test.VaultAddress, test.ParentAddress).
+					WithData(nil).
+					WithValue(big.NewInt(excess)),
+				test.CreateTransferEntry(test.ParentAddress, test.UserAddress).
+					WithData(contracts.CrowdfundingRefundData).
+					WithValue(big.NewInt(excess)),
+			)
+	})
+}
+
+func TestCrowdfunding_ForwardToVault_RejectedAndRefunded(t *testing.T) {
+	vaultStorage := map[string][]byte{
+		string(contracts.VaultClosedKey): {1},
+	}
+
+	runCrowdfundingTest(t, forwardToVaultInput(), fundedDeposit(), vaultStorage, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok().
+			HasRuntimeErrors("vault closed").
+			BalanceDelta(test.ParentAddress, -crowdfundingDeposit).
+			BalanceDelta(test.UserAddress, crowdfundingDeposit).
+			ReturnData([]byte("refunded")).
+			Storage(
+				test.CreateStoreEntry(test.ParentAddress).
+					WithKey(contracts.CrowdfundingDepositKey(test.UserAddress)).
+					WithValue([]byte{}),
+			).
+			Transfers(
+				test.CreateTransferEntry(test.ParentAddress, test.UserAddress).
+					WithData(contracts.CrowdfundingRefundData).
+					WithValue(big.NewInt(crowdfundingDeposit)),
+			)
+	})
+}
+
+func TestCrowdfunding_VaultRejectsESDT(t *testing.T) {
+	input := test.CreateTestContractCallInputBuilder().
+		WithCallerAddr(test.UserAddress).
+		WithRecipientAddr(test.VaultAddress).
+		WithFunction(contracts.VaultDepositFunction).
+		WithESDTTokenName(test.ESDTTestTokenName).
+		WithESDTValue(big.NewInt(5))
+
+	runCrowdfundingTest(t, input, nil, nil, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.ExecutionFailed().
+			ReturnMessage(arwen.ErrNonPayableFunctionEsdt.Error())
+	})
+}
+
+func forwardToVaultInput() *test.ContractCallInputBuilder {
+	return test.CreateTestContractCallInputBuilder().
+		WithCallerAddr(test.UserAddress).
+		WithFunction(contracts.CrowdfundingForwardFunction).
+		WithArguments(test.UserAddress)
+}
+
+func fundedDeposit() map[string][]byte {
+	return map[string][]byte{
+		string(contracts.CrowdfundingDepositKey(test.UserAddress)): big.NewInt(crowdfundingDeposit).Bytes(),
+	}
+}
+
+func runCrowdfundingTest(
+	t *testing.T,
+	input *test.ContractCallInputBuilder,
+	crowdfundingStorage map[string][]byte,
+	vaultStorage map[string][]byte,
+	assertResults test.AssertResultsFunc,
+) {
+	testConfig := makeTestConfig()
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(
+					contracts.CrowdfundingFundMock,
+					contracts.CrowdfundingForwardToVaultMock,
+					contracts.CrowdfundingCallbacksMock,
+				),
+			test.CreateMockContract(test.VaultAddress).
+				WithBalance(0).
+				WithConfig(testConfig).
+				WithMethods(contracts.VaultDepositMock),
+		).
+		WithInput(input.
+			WithGasProvided(testConfig.GasProvided).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+			setAsyncCosts(host, testConfig.GasLockCost)
+			for key, value := range crowdfundingStorage {
+				world.AcctMap.GetAccount(test.ParentAddress).Storage[key] = value
+			}
+			for key, value := range vaultStorage {
+				world.AcctMap.GetAccount(test.VaultAddress).Storage[key] = value
+			}
+		}).
+		AndAssertResults(assertResults)
+	if err != nil {
+		t.Fatal(err)
+	}
+}
@@ -6,6 +6,9 @@ import (
 
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	testcommon "github.com/ElrondNetwork/wasm-vm/testcommon"
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -146,3 +149,176 @@ func TestElrondEI_int64getArgument(t *testing.T) {
 	i64val12345 = big.NewInt(0).SetBytes(data[2])
 	assert.Equal(t, big.NewInt(12345), i64val12345)
 }
+
+func TestElrondEI_CopyArgumentTo(t *testing.T) {
+	testConfig := makeTestConfig()
+	argument := []byte("0123456789")
+	destOffset := int32(16)
+
+	_, err := testcommon.BuildMockInstanceCallTest(t).
+		WithContracts(
+			testcommon.CreateMockContract(testcommon.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						for _, slice := range [][2]int32{{2, 3}, {7, 10}, {10, 5}} {
+							copied := elrondapi.CopyArgumentToWithHost(host, 0, slice[0], slice[1], destOffset)
+							data, _ := host.Runtime().MemLoad(destOffset, copied)
+							host.Output().Finish(big.NewInt(int64(copied)).Bytes())
+							host.Output().Finish(data)
+						}
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(testcommon.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(testcommon.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			WithArguments(argument).
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *testcommon.VMOutputVerifier) {
+			verify.
+				Ok().
+				ReturnData(
+					[]byte{3}, []byte("234"),
+					[]byte{3}, []byte("789"),
+					[]byte{}, []byte{},
+				)
+		})
+	require.Nil(t, err)
+}
+
+func TestElrondEI_CopyArgumentTo_OutOfBounds(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	_, err := testcommon.BuildMockInstanceCallTest(t).
+		WithContracts(
+			testcommon.CreateMockContract(testcommon.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						elrondapi.CopyArgumentToWithHost(host, 0, 11, 1, 0)
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(testcommon.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(testcommon.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			WithArguments([]byte("0123456789")).
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *testcommon.VMOutputVerifier) {
+			verify.
+				ExecutionFailed().
+				ReturnMessage(arwen.ErrBadBounds.Error())
+		})
+	require.Nil(t, err)
+}
+
+func gasRefundContract(testConfig *testcommon.TestConfig, gas int64) testcommon.MockTestSmartContract {
+	return gasRefundContractOnShard(testConfig, gas, 0)
+}
+
+func gasRefundContractOnShard(testConfig *testcommon.TestConfig, gas int64, shardID uint32) testcommon.MockTestSmartContract {
+	return testcommon.CreateMockContractOnShard(testcommon.ParentAddress, shardID).
+		WithBalance(testConfig.ParentBalance).
+		WithConfig(testConfig).
+		WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+			parentInstance.AddMockMethod("refund", func() *mock.InstanceMock {
+				elrondapi.RefundGasToCallerWithHost(parentInstance.Host, gas)
+				return parentInstance
+			})
+		})
+}
+
+func gasRefundInput(testConfig *testcommon.TestConfig) *vmcommon.ContractCallInput {
+	input := testcommon.CreateTestContractCallInputBuilder().
+		WithRecipientAddr(testcommon.ParentAddress).
+		WithGasProvided(testConfig.GasProvided).
+		WithFunction("refund").
+		Build()
+	input.GasPrice = 2
+	return input
+}
+
+func TestElrondEI_RefundGasToCaller(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	vmOutput, err := testcommon.BuildMockInstanceCallTest(t).
+		WithContracts(gasRefundContract(testConfig, 100)).
+		WithInput(gasRefundInput(testConfig)).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *testcommon.VMOutputVerifier) {
+			verify.
+				Ok().
+				BalanceDelta(testcommon.ParentAddress, -200).
+				BalanceDelta(testcommon.UserAddress, 200)
+		})
+	require.Nil(t, err)
+
+	require.Len(t, vmOutput.Logs, 2)
+	require.Equal(t, []byte(arwen.GasRefundedToCallerIdentifier), vmOutput.Logs[1].Identifier)
+	require.Equal(t, testcommon.ParentAddress, vmOutput.Logs[1].Address)
+	require.Equal(t, [][]byte{
+		testcommon.UserAddress,
+		big.NewInt(100).Bytes(),
+		big.NewInt(200).Bytes(),
+	}, vmOutput.Logs[1].Topics)
+}
+
+func TestElrondEI_RefundGasToCaller_CrossShard(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	_, err := testcommon.BuildMockInstanceCallTest(t).
+		WithContracts(gasRefundContractOnShard(testConfig, 100, 1)).
+		WithInput(gasRefundInput(testConfig)).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			world.SelfShardID = 1
+			world.AcctMap.CreateAccount(testcommon.UserAddress, world)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *testcommon.VMOutputVerifier) {
+			verify.
+				Ok().
+				BalanceDelta(testcommon.ParentAddress, -200).
+				BalanceDelta(testcommon.UserAddress, 200).
+				Transfers(
+					testcommon.CreateTransferEntry(testcommon.ParentAddress, testcommon.UserAddress).
+						WithValue(big.NewInt(200)),
+				)
+		})
+	require.Nil(t, err)
+}
+
+func TestElrondEI_RefundGasToCaller_OverGasProvided(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	_, err := testcommon.BuildMockInstanceCallTest(t).
+		WithContracts(gasRefundContract(testConfig, int64(testConfig.GasProvided)+1)).
+		WithInput(gasRefundInput(testConfig)).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *testcommon.VMOutputVerifier) {
+			verify.
+				ExecutionFailed().
+				ReturnMessage(arwen.ErrArgOutOfRange.Error())
+		})
+	require.Nil(t, err)
+}
+
+func TestElrondEI_RefundGasToCaller_InsufficientFunds(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.ParentBalance = 10
+
+	_, err := testcommon.BuildMockInstanceCallTest(t).
+		WithContracts(gasRefundContract(testConfig, 100)).
+		WithInput(gasRefundInput(testConfig)).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *testcommon.VMOutputVerifier) {
+			verify.
+				ExecutionFailed().
+				ReturnMessage(arwen.ErrTransferInsufficientFunds.Error())
+		})
+	require.Nil(t, err)
+}
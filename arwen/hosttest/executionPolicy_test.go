@@ -0,0 +1,93 @@
+package hosttest
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/hashing/blake2b"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	"github.com/ElrondNetwork/wasm-vm/policy"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestExecutionPolicy_CallDeniedByAddress(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+	denyList, err := policy.NewDenyListPolicy(blake2b.NewBlake2b())
+	require.Nil(t, err)
+
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(code, nil)).
+		WithExecutionPolicy(denyList).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	input := test.DefaultTestContractCallInput()
+	input.GasProvided = 1000000
+	input.Function = get
+
+	denyList.DenyAddress(input.RecipientAddr)
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).
+		ReturnCode(arwen.ExecutionDenied).
+		ReturnMessage(arwen.ErrExecutionDenied.Error())
+
+	denyList.AllowAddress(input.RecipientAddr)
+	vmOutput, err = host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+}
+
+func TestExecutionPolicy_DeploymentDeniedByCodeHash(t *testing.T) {
+	hasher := blake2b.NewBlake2b()
+	code := test.GetTestSCCode("init-correct", "../../")
+	denyList, err := policy.NewDenyListPolicy(hasher)
+	require.Nil(t, err)
+	denyList.DenyCodeHash(hasher.Compute(string(code)))
+
+	test.BuildInstanceCreatorTest(t).
+		WithExecutionPolicy(denyList).
+		WithInput(test.CreateTestContractCreateInputBuilder().
+			WithGasProvided(1000).
+			WithContractCode(code).
+			WithCallValue(88).
+			WithArguments([]byte{0}).
+			Build()).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.ReturnCode(arwen.ExecutionDenied).
+				ReturnMessage(arwen.ErrExecutionDenied.Error())
+		})
+}
+
+func TestExecutionPolicy_ExecutionPaused(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(code, nil)).
+		WithExecutionPaused(true).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+	require.True(t, host.IsExecutionPaused())
+
+	input := test.DefaultTestContractCallInput()
+	input.GasProvided = 1000000
+	input.Function = get
+
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).
+		ReturnCode(arwen.SCExecutionPaused).
+		ReturnMessage(arwen.ErrSCExecutionPaused.Error())
+
+	host.SetExecutionPaused(false)
+	require.False(t, host.IsExecutionPaused())
+	vmOutput, err = host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+
+	host.SetExecutionPaused(true)
+	vmOutput, err = host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).
+		ReturnCode(arwen.SCExecutionPaused)
+}
@@ -63,6 +63,14 @@ func Test_WarmInstancesFuzzyMemoryUsage(t *testing.T) {
 	runMemoryUsageFuzzyBenchmark(t, 7593, 10)
 }
 
+func Test_RunERC20MintBenchmark(t *testing.T) {
+	if testing.Short() {
+		t.Skip("not a short test")
+	}
+
+	runERC20MintBenchmark(t, 1000, 10)
+}
+
 func runERC20Benchmark(tb testing.TB, nTransfers int, nRuns int, failTransaction bool) {
 
 	totalTokenSupply := big.NewInt(int64(nTransfers * nRuns))
@@ -218,6 +226,39 @@ func runMemoryUsageBenchmark(tb testing.TB, nContracts int, nTransfers int) {
 	}
 }
 
+func runERC20MintBenchmark(tb testing.TB, nContracts int, nMints int) {
+	mockWorld, ownerAccount, host, err := prepare(tb)
+	require.Nil(tb, err)
+
+	defer func() {
+		host.Reset()
+	}()
+
+	deployNContracts(tb, nContracts, mockWorld, ownerAccount, host, big.NewInt(0))
+
+	// every contract has its own code, so minting on each of them in turn
+	// keeps replacing the warm instances and the compiled modules
+	for i := 0; i < nMints; i++ {
+		start := time.Now()
+		for j := 0; j < nContracts; j++ {
+			mintInput := createTransferInput(j)
+			mintInput.Function = "mint"
+
+			vmOutput, err := host.RunSmartContractCall(mintInput)
+			require.Nil(tb, err)
+			require.NotNil(tb, vmOutput)
+			require.Equal(tb, vmcommon.Ok, vmOutput.ReturnCode)
+
+			_ = mockWorld.UpdateAccounts(vmOutput.OutputAccounts, nil)
+		}
+		elapsedTime := time.Since(start)
+		logBenchmark.Trace("Executing ERC20 mints", "batch", i, "contracts", nContracts, "time", elapsedTime.String())
+	}
+	for j := 0; j < nContracts; j++ {
+		verifyTransfers(tb, mockWorld, big.NewInt(int64(nMints)), createAddress(j))
+	}
+}
+
 func prepare(tb testing.TB) (*worldmock.MockWorld, *worldmock.Account, arwen.VMHost, error) {
 	mockWorld := worldmock.NewMockWorld()
 	ownerAccount := &worldmock.Account{
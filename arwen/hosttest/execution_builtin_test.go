@@ -3,6 +3,7 @@ package hosttest
 import (
 	"encoding/hex"
 	"errors"
+	"fmt"
 	"math/big"
 	"testing"
 
@@ -15,6 +16,7 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/config"
 	"github.com/ElrondNetwork/wasm-vm/executor"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
 	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	test "github.com/ElrondNetwork/wasm-vm/testcommon"
 	"github.com/ElrondNetwork/wasm-vm/wasmer"
@@ -263,6 +265,97 @@ func TestESDT_GettersAPI_ExecuteAfterBuiltinCall(t *testing.T) {
 	require.Equal(t, initialESDTTokenBalance-uint64(esdtValue), parentESDTBalance)
 }
 
+func TestExecution_ChangeOwnerAddress_OwnerUpdatedMidExecution(t *testing.T) {
+	ownershipLogs := runChangeChildOwner(t, worldmock.EnableEpochsHandlerStubNoFlags())
+	require.Len(t, ownershipLogs, 1)
+	require.Equal(t, test.ChildAddress, ownershipLogs[0].Address)
+	require.Equal(t, [][]byte{test.ParentAddress, test.UserAddress}, ownershipLogs[0].Topics)
+}
+
+func TestExecution_ChangeOwnerAddress_NoLogBeforeActivation(t *testing.T) {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubNoFlags()
+	enableEpochsHandler.OwnershipTransferLogEnableEpochField = config.DisabledEpoch
+	ownershipLogs := runChangeChildOwner(t, enableEpochsHandler)
+	require.Empty(t, ownershipLogs)
+}
+
+// runChangeChildOwner changes the owner of the child from the parent to the
+// user, between two reads of the owner, and returns the ownership logs
+func runChangeChildOwner(t *testing.T, enableEpochsHandler arwen.EnableEpochsHandler) []*vmcommon.LogEntry {
+	testConfig := makeTestConfig()
+	ownershipLogs := make([]*vmcommon.LogEntry, 0)
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *contextmock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("changeChildOwner", func() *contextmock.InstanceMock {
+						host := parentInstance.Host
+						instance := contextmock.GetMockInstance(host)
+
+						input := test.DefaultTestContractCallInput()
+						input.CallerAddr = test.ParentAddress
+						input.RecipientAddr = test.ChildAddress
+						input.GasProvided = testConfig.GasProvidedToChild
+
+						for _, function := range []string{"owner", core.BuiltInFunctionChangeOwnerAddress, "owner"} {
+							input.Function = function
+							input.Arguments = nil
+							if function == core.BuiltInFunctionChangeOwnerAddress {
+								input.Arguments = [][]byte{test.UserAddress}
+							}
+							returnValue := contracts.ExecuteOnDestContextInMockContracts(host, input)
+							if returnValue != 0 {
+								host.Runtime().FailExecution(fmt.Errorf("Return value %d", returnValue))
+								return instance
+							}
+						}
+						return instance
+					})
+				}),
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithOwnerAddress(test.ParentAddress).
+				WithMethods(func(childInstance *contextmock.InstanceMock, config interface{}) {
+					childInstance.AddMockMethod("owner", func() *contextmock.InstanceMock {
+						host := childInstance.Host
+						instance := contextmock.GetMockInstance(host)
+						owner, err := host.Blockchain().GetOwnerAddress()
+						if err != nil {
+							host.Runtime().FailExecution(err)
+							return instance
+						}
+						host.Output().Finish(owner)
+						return instance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("changeChildOwner").
+			Build()).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			createMockBuiltinFunctions(t, host, world)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				ReturnData(test.ParentAddress, test.UserAddress)
+			require.Equal(t, test.UserAddress, world.AcctMap.GetAccount(test.ChildAddress).OwnerAddress)
+
+			for _, logEntry := range verify.VmOutput.Logs {
+				if string(logEntry.Identifier) == arwen.OwnershipTransferLogIdentifier {
+					ownershipLogs = append(ownershipLogs, logEntry)
+				}
+			}
+		})
+	return ownershipLogs
+}
+
 func dummyProcessBuiltInFunction(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
 	outputAccounts := make(map[string]*vmcommon.OutputAccount)
 	outputAccounts[string(test.ParentAddress)] = &vmcommon.OutputAccount{
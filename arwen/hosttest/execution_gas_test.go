@@ -164,6 +164,46 @@ func TestGasUsed_TwoContracts_ExecuteOnSameCtx(t *testing.T) {
 	}
 }
 
+func TestGasUsed_TwoContracts_ExecuteOnSameCtx_RestrictedToReadableLibraries(t *testing.T) {
+	testConfig := makeTestConfig()
+	readable := []byte{vmcommon.MetadataReadable, 0}
+
+	for _, codeMetadata := range [][]byte{nil, readable} {
+		test.BuildMockInstanceCallTest(t).
+			WithContracts(
+				test.CreateMockContract(test.ParentAddress).
+					WithBalance(testConfig.ParentBalance).
+					WithConfig(testConfig).
+					WithMethods(contracts.ExecOnSameCtxParentMock),
+				test.CreateMockContract(test.ChildAddress).
+					WithBalance(testConfig.ChildBalance).
+					WithConfig(testConfig).
+					WithCodeMetadata(codeMetadata).
+					WithMethods(contracts.WasteGasChildMock),
+			).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithRecipientAddr(test.ParentAddress).
+				WithGasProvided(testConfig.GasProvided).
+				WithFunction("execOnSameCtx").
+				WithArguments(test.ChildAddress, []byte("wasteGas"), []byte{1}).
+				Build()).
+			WithRestrictLibraryCalls(true).
+			WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+				setZeroCodeCosts(host)
+			}).
+			AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+				if codeMetadata == nil {
+					verify.ExecutionFailed().
+						ReturnMessage("Return value -1").
+						HasRuntimeErrors(arwen.ErrLibraryNotReadable.Error())
+					return
+				}
+				verify.Ok().
+					GasUsed(test.ParentAddress, testConfig.GasUsedByParent+testConfig.GasUsedByChild)
+			})
+	}
+}
+
 func TestGasUsed_TwoContracts_ExecuteOnDestCtx(t *testing.T) {
 	testConfig := makeTestConfig()
 
@@ -595,7 +635,12 @@ func testGasUsed_AsyncCall_CrossShard_InitCall(t *testing.T, isLegacy bool) {
 			if !isLegacy {
 				expectedStorages = append(expectedStorages,
 					test.CreateStoreEntry(test.ParentAddress).WithKey(
-						host.Storage().GetVmProtectedPrefix(arwen.AsyncDataPrefix)).IgnoreValue())
+						host.Storage().GetVmProtectedPrefix(arwen.AsyncDataPrefix)).IgnoreValue(),
+					// the locked EGLD is kept under the empty token
+					// identifier, prefixed by its length
+					test.CreateStoreEntry(test.ParentAddress).WithKey(
+						append(host.Storage().GetVmProtectedPrefix(arwen.LockedValueDataPrefix), 0, 0, 0, 0)).
+						WithValue(big.NewInt(testConfig.TransferFromParentToChild).Bytes()))
 			}
 		}).
 		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
@@ -1741,6 +1786,101 @@ func TestGasUsed_AsyncCall_Groups(t *testing.T) {
 		})
 }
 
+func TestGasUsed_AsyncCall_SubGroups(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 10_000
+	testConfig.GasLockCost = 10
+	testConfig.GasProvidedToCallback = 150
+
+	groupCallbackReturnData := func(groupName string, failed bool) []byte {
+		callbackName := test.TestCallbackPrefix + groupName
+		if failed {
+			callbackName += contracts.AsyncGroupErrorSuffix
+		}
+		return []byte(callbackName + test.TestReturnDataSuffix)
+	}
+
+	expectedWorkflowReturnData := func(failingFunction string) [][]byte {
+		expectedReturnData := make([][]byte, 0)
+		workflowFailed := false
+		for _, groupConfig := range contracts.AsyncSubGroupsConfig {
+			groupFailed := false
+			for g := 1; g < len(groupConfig); g++ {
+				if groupConfig[g] == failingFunction {
+					groupFailed = true
+					continue
+				}
+				expectedReturnData = append(expectedReturnData, []byte(groupConfig[g]+test.TestReturnDataSuffix))
+			}
+			expectedReturnData = append(expectedReturnData, groupCallbackReturnData(groupConfig[0], groupFailed))
+			workflowFailed = workflowFailed || groupFailed
+		}
+		expectedReturnData = append(expectedReturnData, groupCallbackReturnData(contracts.AsyncWorkflowGroupID, workflowFailed))
+		return expectedReturnData
+	}
+
+	for _, failingFunction := range []string{"", "payVendorB"} {
+		expectedReturnData := expectedWorkflowReturnData(failingFunction)
+
+		test.BuildMockInstanceCallTest(t).
+			WithContracts(
+				test.CreateMockContract(test.ParentAddress).
+					WithBalance(testConfig.ParentBalance).
+					WithConfig(testConfig).
+					WithMethods(contracts.ForwardAsyncCallSubGroupsMock, contracts.CallBackSubGroupsMock),
+				test.CreateMockContract(test.ChildAddress).
+					WithBalance(testConfig.ChildBalance).
+					WithConfig(testConfig).
+					WithMethods(contracts.ChildAsyncSubGroupsMock),
+			).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithRecipientAddr(test.ParentAddress).
+				WithGasProvided(testConfig.GasProvided).
+				WithFunction("forwardSubGroupsAsyncCall").
+				WithArguments(test.ChildAddress, []byte(failingFunction)).
+				Build()).
+			WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+				setZeroCodeCosts(host)
+				setAsyncCosts(host, testConfig.GasLockCost)
+			}).
+			AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+				verify.Ok().
+					ReturnData(expectedReturnData...)
+			})
+	}
+}
+
+func TestGasUsed_AsyncCall_SubGroupsDisabled(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 10_000
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.AsyncCallSubGroupsEnableEpochField = 1
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(instanceMock *contextmock.InstanceMock, config interface{}) {
+					instanceMock.AddMockMethod("addSubGroup", func() *contextmock.InstanceMock {
+						host := instanceMock.Host
+						err := host.Async().AddSubGroup(contracts.AsyncWorkflowGroupID, "vendors")
+						require.Equal(t, arwen.ErrAsyncSubGroupsDisabled, err)
+						return contextmock.GetMockInstance(host)
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("addSubGroup").
+			Build()).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok()
+		})
+}
+
 func TestGasUsed_TransferAndExecute_CrossShard(t *testing.T) {
 	testConfig := makeTestConfig()
 
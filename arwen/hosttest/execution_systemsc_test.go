@@ -0,0 +1,126 @@
+package hosttest
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestExecution_AsyncCallToESDTSystemSC_IssueToken(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.ESDTTokensToTransfer = 1000
+
+	world := worldmock.NewMockWorld()
+	world.InitSystemSmartContracts()
+
+	parentSC := test.CreateMockContract(test.ParentAddress).
+		WithBalance(testConfig.ParentBalance).
+		WithConfig(testConfig).
+		WithMethods(contracts.IssueTokenMock, contracts.IssueTokenCallBackMock)
+
+	setup := func(host arwen.VMHost, world *worldmock.MockWorld) {
+		world.SelfShardID = 0
+		setZeroCodeCosts(host)
+		setAsyncCosts(host, testConfig.GasLockCost)
+	}
+
+	vmOutput, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(parentSC).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("issueToken").
+			WithArguments([]byte("TestToken"), []byte("TST")).
+			WithCurrentTxHash([]byte("txhash")).
+			Build()).
+		WithSetup(setup).
+		AndAssertResultsWithWorld(world, true, nil, nil, func(startNode *test.TestCallNode, world *worldmock.MockWorld, verify *test.VMOutputVerifier, expectedErrorsForRound []string) {
+			verify.Ok()
+		})
+	require.Nil(t, err)
+
+	err = world.UpdateAccounts(vmOutput.OutputAccounts, nil)
+	require.Nil(t, err)
+
+	callbackInputs, err := test.CreateCallbacksForSystemSCAsyncCalls(world, vmOutput)
+	require.Nil(t, err)
+	require.Len(t, callbackInputs, 1)
+
+	issuedToken := callbackInputs[0].Arguments[1]
+	require.Contains(t, string(issuedToken), "TST-")
+
+	balance, err := world.AcctMap.GetAccount(test.ParentAddress).GetTokenBalanceUint64(issuedToken, 0)
+	require.Nil(t, err)
+	require.Equal(t, testConfig.ESDTTokensToTransfer, balance)
+
+	vmOutput, err = test.BuildMockInstanceCallTest(t).
+		WithContracts(parentSC).
+		WithInput(callbackInputs[0]).
+		WithSetup(setup).
+		AndAssertResultsWithWorld(world, false, nil, nil, func(startNode *test.TestCallNode, world *worldmock.MockWorld, verify *test.VMOutputVerifier, expectedErrorsForRound []string) {
+			verify.Ok()
+		})
+	require.Nil(t, err)
+
+	err = world.UpdateAccounts(vmOutput.OutputAccounts, nil)
+	require.Nil(t, err)
+	require.Equal(t, issuedToken, world.AcctMap.GetAccount(test.ParentAddress).StorageValue(string(contracts.IssuedTokenKey)))
+}
+
+func TestExecution_SystemSC_UnknownFunction(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	world.InitSystemSmartContracts()
+
+	input := test.CreateTestContractCallInputBuilder().
+		WithRecipientAddr(worldmock.StakingSCAddress).
+		WithFunction("missing").
+		Build()
+
+	_, err := world.ProcessSystemSCCall(input)
+	require.ErrorIs(t, err, worldmock.ErrSystemSCFunctionNotFound)
+}
+
+func TestExecution_SystemSC_StakeAndUnStakeThroughVMOutput(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	world.InitSystemSmartContracts()
+	caller := world.AcctMap.CreateAccount(test.UserAddress, world)
+	caller.Balance = big.NewInt(100)
+
+	input := test.CreateTestContractCallInputBuilder().
+		WithCallerAddr(test.UserAddress).
+		WithRecipientAddr(worldmock.StakingSCAddress).
+		WithFunction("stake").
+		WithCallValue(40).
+		Build()
+	vmOutput, err := world.ProcessSystemSCCall(input)
+	require.Nil(t, err)
+	require.Equal(t, big.NewInt(100), caller.Balance)
+	require.Empty(t, world.AcctMap.GetAccount(worldmock.StakingSCAddress).Storage)
+	require.Equal(t, big.NewInt(-40), vmOutput.OutputAccounts[string(test.UserAddress)].BalanceDelta)
+	require.Equal(t, big.NewInt(40), vmOutput.OutputAccounts[string(worldmock.StakingSCAddress)].BalanceDelta)
+
+	err = world.UpdateAccounts(vmOutput.OutputAccounts, nil)
+	require.Nil(t, err)
+	require.Equal(t, big.NewInt(60), caller.Balance)
+
+	input = test.CreateTestContractCallInputBuilder().
+		WithCallerAddr(test.UserAddress).
+		WithRecipientAddr(worldmock.StakingSCAddress).
+		WithFunction("unStake").
+		WithArguments(big.NewInt(15).Bytes()).
+		Build()
+	vmOutput, err = world.ProcessSystemSCCall(input)
+	require.Nil(t, err)
+	require.Equal(t, big.NewInt(60), caller.Balance)
+	require.Equal(t, [][]byte{big.NewInt(25).Bytes()}, vmOutput.ReturnData)
+
+	err = world.UpdateAccounts(vmOutput.OutputAccounts, nil)
+	require.Nil(t, err)
+	require.Equal(t, big.NewInt(75), caller.Balance)
+	require.Equal(t, big.NewInt(25), world.AcctMap.GetAccount(worldmock.StakingSCAddress).Balance)
+}
@@ -15,12 +15,16 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
 	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/ElrondNetwork/wasm-vm/executor"
 	executorwrapper "github.com/ElrondNetwork/wasm-vm/executor/wrapper"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
+	"github.com/ElrondNetwork/wasm-vm/interpreter"
 	arwenMath "github.com/ElrondNetwork/wasm-vm/math"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
 	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
 	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/ElrondNetwork/wasm-vm/tracing"
 	"github.com/ElrondNetwork/wasm-vm/wasmer"
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -160,6 +164,79 @@ func TestExecution_DeployWASM_WrongMethods(t *testing.T) {
 		})
 }
 
+func TestExecution_DeployWASM_StrictEndpointValidation(t *testing.T) {
+	test.BuildInstanceCreatorTest(t).
+		WithStrictEndpointValidation().
+		WithInput(test.CreateTestContractCreateInputBuilder().
+			WithGasProvided(1000).
+			WithContractCode(test.GetTestSCCode("breakpoint", "../../")).
+			Build()).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.ContractInvalid().
+				ReturnMessage(arwen.ErrInvalidEndpoints.Error() + ": missing init function init")
+		})
+
+	test.BuildInstanceCreatorTest(t).
+		WithStrictEndpointValidation().
+		WithInput(test.CreateTestContractCreateInputBuilder().
+			WithGasProvided(1000).
+			WithContractCode(test.GetTestSCCode("init-correct", "../../")).
+			WithCallValue(88).
+			WithArguments([]byte{0}).
+			Build()).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok()
+		})
+}
+
+func TestExecution_DeployWASM_ContractInvalidDetailsInactive(t *testing.T) {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.DetailedContractInvalidErrorsEnableEpochField = config.DisabledEpoch
+
+	test.BuildInstanceCreatorTest(t).
+		WithStrictEndpointValidation().
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithInput(test.CreateTestContractCreateInputBuilder().
+			WithGasProvided(1000).
+			WithContractCode(test.GetTestSCCode("breakpoint", "../../")).
+			Build()).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.ContractInvalid().
+				ReturnMessage(arwen.ErrContractInvalid.Error())
+		})
+}
+
+func TestExecution_DeployWASM_ContractLimits(t *testing.T) {
+	code := test.GetTestSCCode("init-correct", "../../")
+	test.BuildInstanceCreatorTest(t).
+		WithContractLimits(arwen.ContractLimits{MaxCodeSize: uint64(len(code)) - 1}).
+		WithInput(test.CreateTestContractCreateInputBuilder().
+			WithGasProvided(1000).
+			WithContractCode(code).
+			Build()).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.ContractInvalid().
+				ReturnMessageContains(arwen.ErrCodeSizeLimitExceeded.Error())
+		})
+
+	test.BuildInstanceCreatorTest(t).
+		WithContractLimits(arwen.ContractLimits{MaxCodeSize: uint64(len(code)), MaxFunctions: 1}).
+		WithInput(test.CreateTestContractCreateInputBuilder().
+			WithGasProvided(1000).
+			WithContractCode(code).
+			WithCallValue(88).
+			WithArguments([]byte{0}).
+			Build()).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok()
+		})
+}
+
 func TestExecution_DeployWASM_Successful(t *testing.T) {
 	input := test.CreateTestContractCreateInputBuilder().
 		WithGasProvided(1000).
@@ -180,6 +257,58 @@ func TestExecution_DeployWASM_Successful(t *testing.T) {
 		})
 }
 
+func TestExecution_DeployWASM_BuildInfo(t *testing.T) {
+	buildInfoEntries := [][]byte{
+		[]byte("framework"), []byte("elrond-wasm 0.11.0"),
+		[]byte("rustc"), []byte("rustc 1.52.0-nightly"),
+		[]byte("flags"), []byte("release opt-level=3"),
+	}
+	payload := append([]byte{byte(len(inspect.BuildInfoSectionName))}, inspect.BuildInfoSectionName...)
+	payload = append(payload, byte(len(buildInfoEntries)/2))
+	for _, entry := range buildInfoEntries {
+		payload = append(payload, byte(len(entry)))
+		payload = append(payload, entry...)
+	}
+	code := test.GetTestSCCode("init-correct", "../../")
+	code = append(code, 0x00, byte(len(payload)))
+	code = append(code, payload...)
+
+	input := test.CreateTestContractCreateInputBuilder().
+		WithGasProvided(1000).
+		WithContractCode(code).
+		WithCallValue(88).
+		WithArguments([]byte{0}).
+		Build()
+	test.BuildInstanceCreatorTest(t).
+		WithInput(input).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				ReturnData([]byte("init successful")).
+				Code(newAddress, code).
+				Logs(vmcommon.LogEntry{
+					Address:    newAddress,
+					Identifier: []byte(arwen.ContractBuildInfoIdentifier),
+					Topics: [][]byte{
+						[]byte("elrond-wasm 0.11.0"),
+						[]byte("rustc 1.52.0-nightly"),
+						[]byte("release opt-level=3"),
+					},
+				})
+		})
+
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.ContractBuildInfoLogEnableEpochField = 1
+	test.BuildInstanceCreatorTest(t).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithInput(input).
+		WithAddress(newAddress).
+		AndAssertResults(func(blockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				Logs()
+		})
+}
+
 func TestExecution_DeployWASM_Popcnt(t *testing.T) {
 	test.BuildInstanceCreatorTest(t).
 		WithInput(test.CreateTestContractCreateInputBuilder().
@@ -436,6 +565,96 @@ func TestExecution_CallGetUserAccountErr(t *testing.T) {
 		})
 }
 
+func TestExecution_GetStorageDataFault(t *testing.T) {
+	key := []byte("testKey")
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(0).
+				WithConfig(nil).
+				WithMethods(contracts.LoadStore)).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(test.GasProvided).
+			WithFunction("loadStore").
+			WithArguments(key).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			accountHandler, _ := world.GetUserAccount(test.ParentAddress)
+			(accountHandler.(*worldmock.Account)).Storage[string(key)] = []byte("testValue")
+			world.InjectHookFault(worldmock.GetStorageDataHookCall, 1, nil)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.ExecutionFailed().
+				ReturnMessage(worldmock.ErrInjectedHookFault.Error()).
+				HasRuntimeErrors(worldmock.ErrInjectedHookFault.Error())
+			require.Equal(t, uint64(1), world.NumHookCalls(worldmock.GetStorageDataHookCall))
+		})
+}
+
+func TestExecution_GetStorageDataFault_FlagDisabled(t *testing.T) {
+	key := []byte("testKey")
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubNoFlags()
+	enableEpochsHandler.StorageHookErrorsEnableEpochField = config.DisabledEpoch
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(0).
+				WithConfig(nil).
+				WithMethods(contracts.LoadStore)).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(test.GasProvided).
+			WithFunction("loadStore").
+			WithArguments(key).
+			Build()).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			accountHandler, _ := world.GetUserAccount(test.ParentAddress)
+			(accountHandler.(*worldmock.Account)).Storage[string(key)] = []byte("testValue")
+			world.InjectHookFault(worldmock.GetStorageDataHookCall, 1, nil)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok()
+			require.Len(t, verify.VmOutput.ReturnData, 1)
+			require.Empty(t, verify.VmOutput.ReturnData[0])
+			require.Equal(t, uint64(1), world.NumHookCalls(worldmock.GetStorageDataHookCall))
+		})
+}
+
+func TestExecution_GetUserAccountFaultAtEveryCall(t *testing.T) {
+	runCallWithFault := func(atCall uint64) *worldmock.MockWorld {
+		world := worldmock.NewMockWorld()
+		_, _ = test.BuildMockInstanceCallTest(t).
+			WithContracts(
+				test.CreateMockContract(test.ParentAddress).
+					WithBalance(1000).
+					WithConfig(nil).
+					WithMethods(contracts.LoadStore)).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithRecipientAddr(test.ParentAddress).
+				WithGasProvided(test.GasProvided).
+				WithFunction("loadStore").
+				WithArguments([]byte("testKey")).
+				Build()).
+			WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+				world.InjectHookFault(worldmock.GetUserAccountHookCall, atCall, nil)
+			}).
+			AndAssertResultsWithWorld(world, true, nil, nil, func(_ *test.TestCallNode, _ *worldmock.MockWorld, verify *test.VMOutputVerifier, _ []string) {
+				require.NotEqual(t, arwen.ErrExecutionPanicked.Error(), verify.VmOutput.ReturnMessage)
+			})
+		return world
+	}
+
+	world := runCallWithFault(math.MaxUint64)
+	numCalls := world.NumHookCalls(worldmock.GetUserAccountHookCall)
+	require.NotZero(t, numCalls)
+
+	for atCall := uint64(1); atCall <= numCalls; atCall++ {
+		runCallWithFault(atCall)
+	}
+}
+
 func TestExecution_NotEnoughGasForGetCode(t *testing.T) {
 	test.BuildInstanceCallTest(t).
 		WithInput(test.CreateTestContractCallInputBuilder().
@@ -549,6 +768,76 @@ func TestExecution_ChangeWasmerAPICosts(t *testing.T) {
 	require.NotEqual(t, gasRemainingBeforeChange, gasRemainingAfterChange)
 }
 
+func TestExecution_UpdateConfig(t *testing.T) {
+	contractCode := test.GetTestSCCode("managed-buffers", "../../")
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.ManagedBufferFunctionsEnableEpochField = 1
+
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(contractCode, big.NewInt(0))).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	input := test.CreateTestContractCallInputBuilder().
+		WithGasProvided(100000).
+		WithFunction("mBufferMethod").
+		Build()
+
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).FunctionNotFound()
+
+	gasSchedule := config.MakeGasMapForTests()
+	gasSchedule["ElrondAPICost"]["Finish"] += 1
+	newEnableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	err = host.UpdateConfig(gasSchedule, newEnableEpochsHandler)
+	require.Nil(t, err)
+	require.Equal(t, newEnableEpochsHandler, host.EnableEpochsHandler())
+	require.Equal(t, gasSchedule, host.GetGasScheduleMap())
+
+	vmOutput, err = host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+
+	err = host.UpdateConfig(nil, enableEpochsHandler)
+	require.True(t, errors.Is(err, arwen.ErrInvalidVMConfiguration))
+	require.Equal(t, newEnableEpochsHandler, host.EnableEpochsHandler())
+	require.Equal(t, gasSchedule, host.GetGasScheduleMap())
+}
+
+func TestExecution_HostFunctionCatalog(t *testing.T) {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.EscrowFunctionsEnableEpochField = 3
+
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(nil, big.NewInt(0))).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	functionNames := host.Runtime().GetVMExecutor().FunctionNames()
+	catalog := host.HostFunctionCatalog()
+	require.Len(t, catalog, len(functionNames))
+
+	withoutGasCost := 0
+	for _, info := range catalog {
+		_, ok := functionNames[info.Name]
+		require.True(t, ok, info.Name)
+		require.NotNil(t, info.Arguments, info.Name)
+		if info.GasCostName == "" {
+			withoutGasCost++
+		}
+		if info.Name == "managedEscrowLock" {
+			require.Equal(t, uint32(3), info.EnableEpoch)
+			require.Equal(t, "ElrondAPICost.TransferValue", info.GasCostName)
+		}
+	}
+	require.Equal(t, 2, withoutGasCost)
+}
+
 func TestExecution_CallSCMethod_Init(t *testing.T) {
 	test.BuildInstanceCallTest(t).
 		WithContracts(
@@ -579,6 +868,190 @@ func TestExecution_CallSCMethod_Callback(t *testing.T) {
 		})
 }
 
+// counterCodeWithCallability is the counter contract, with a callability
+// section restricting increment to the owner, decrement to the smart
+// contracts and get to the callbacks
+func counterCodeWithCallability() []byte {
+	entries := []byte{0x03}
+	for _, rule := range []struct {
+		name        string
+		callability inspect.Callability
+	}{
+		{"increment", inspect.CallableByOwner},
+		{"decrement", inspect.CallableBySC},
+		{"get", inspect.CallbackOnly},
+	} {
+		entries = append(entries, byte(len(rule.name)))
+		entries = append(entries, rule.name...)
+		entries = append(entries, byte(rule.callability))
+	}
+
+	content := append([]byte{byte(len(inspect.CallabilitySectionName))}, inspect.CallabilitySectionName...)
+	content = append(content, entries...)
+
+	code := append([]byte{}, test.GetTestSCCode("counter", "../../")...)
+	code = append(code, 0x00, byte(len(content)))
+	return append(code, content...)
+}
+
+func TestExecution_CallSCMethod_EndpointCallability(t *testing.T) {
+	code := counterCodeWithCallability()
+	runCallabilityTest := func(caller []byte, function string, callerIsSC bool, allowed bool) {
+		test.BuildInstanceCallTest(t).
+			WithContracts(
+				test.CreateInstanceContract(test.ParentAddress).
+					WithCode(code)).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithCallerAddr(caller).
+				WithGasProvided(100000).
+				WithFunction(function).
+				Build()).
+			WithSetup(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub) {
+				stubBlockchainHook.IsSmartContractCalled = func(address []byte) bool {
+					return callerIsSC
+				}
+			}).
+			AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+				if allowed {
+					verify.Ok()
+					return
+				}
+				verify.UserError().
+					ReturnMessage(arwen.ErrEndpointNotCallable.Error())
+			})
+	}
+
+	// the instance test contracts are owned by the parent
+	runCallabilityTest(test.UserAddress, "increment", false, false)
+	runCallabilityTest(test.ChildAddress, "increment", true, false)
+	runCallabilityTest(test.ParentAddress, "increment", true, true)
+
+	runCallabilityTest(test.UserAddress, "decrement", false, false)
+	runCallabilityTest(test.ChildAddress, "decrement", true, true)
+
+	runCallabilityTest(test.UserAddress, "get", false, false)
+	runCallabilityTest(test.ChildAddress, "get", true, false)
+}
+
+func counterCodeWithArgumentSchema() []byte {
+	entries := []byte{0x02}
+	for _, schema := range []struct {
+		name     string
+		encoding []byte
+	}{
+		// no arguments
+		{increment, []byte{0x00, 0x00, 0x00}},
+		// a single argument of at most one byte
+		{"decrement", []byte{0x01, 0x01, 0x01, 0x01}},
+	} {
+		entries = append(entries, byte(len(schema.name)))
+		entries = append(entries, schema.name...)
+		entries = append(entries, schema.encoding...)
+	}
+
+	content := append([]byte{byte(len(inspect.ArgumentSchemaSectionName))}, inspect.ArgumentSchemaSectionName...)
+	content = append(content, entries...)
+
+	code := append([]byte{}, test.GetTestSCCode("counter", "../../")...)
+	code = append(code, 0x00, byte(len(content)))
+	return append(code, content...)
+}
+
+func TestExecution_CallSCMethod_EndpointArgumentSchema(t *testing.T) {
+	code := counterCodeWithArgumentSchema()
+	runArgumentSchemaTest := func(function string, arguments [][]byte, allowed bool) {
+		test.BuildInstanceCallTest(t).
+			WithContracts(
+				test.CreateInstanceContract(test.ParentAddress).
+					WithCode(code)).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithGasProvided(100000).
+				WithFunction(function).
+				WithArguments(arguments...).
+				Build()).
+			AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+				if allowed {
+					verify.Ok()
+					return
+				}
+				verify.ReturnCode(vmcommon.FunctionWrongSignature).
+					ReturnMessageContains(arwen.ErrEndpointArgumentsMismatch.Error()).
+					GasRemaining(0)
+			})
+	}
+
+	runArgumentSchemaTest(increment, nil, true)
+	runArgumentSchemaTest(increment, [][]byte{{1}}, false)
+	runArgumentSchemaTest("decrement", [][]byte{{1}}, true)
+	runArgumentSchemaTest("decrement", nil, false)
+	runArgumentSchemaTest("decrement", [][]byte{{1, 2}}, false)
+	// the endpoints without a schema check their arguments themselves
+	runArgumentSchemaTest("get", nil, true)
+}
+
+func counterCodeWithPaymentTokens() []byte {
+	entries := []byte{0x02}
+	for _, whitelist := range []struct {
+		name   string
+		tokens []string
+	}{
+		{increment, []string{inspect.EGLDTokenIdentifier}},
+		{"decrement", []string{"TKN-0001"}},
+	} {
+		entries = append(entries, byte(len(whitelist.name)))
+		entries = append(entries, whitelist.name...)
+		entries = append(entries, byte(len(whitelist.tokens)))
+		for _, token := range whitelist.tokens {
+			entries = append(entries, byte(len(token)))
+			entries = append(entries, token...)
+		}
+	}
+
+	content := append([]byte{byte(len(inspect.PaymentTokensSectionName))}, inspect.PaymentTokensSectionName...)
+	content = append(content, entries...)
+
+	code := append([]byte{}, test.GetTestSCCode("counter", "../../")...)
+	code = append(code, 0x00, byte(len(content)))
+	return append(code, content...)
+}
+
+func TestExecution_CallSCMethod_EndpointPaymentTokens(t *testing.T) {
+	code := counterCodeWithPaymentTokens()
+	runPaymentTokensTest := func(function string, callValue int64, token []byte, allowed bool) {
+		inputBuilder := test.CreateTestContractCallInputBuilder().
+			WithGasProvided(100000).
+			WithFunction(function).
+			WithCallValue(callValue)
+		if token != nil {
+			inputBuilder.WithESDTTokenName(token).WithESDTValue(big.NewInt(1))
+		}
+
+		test.BuildInstanceCallTest(t).
+			WithContracts(
+				test.CreateInstanceContract(test.ParentAddress).
+					WithCode(code)).
+			WithInput(inputBuilder.Build()).
+			AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+				if allowed {
+					verify.Ok()
+					return
+				}
+				verify.ReturnCode(vmcommon.UserError).
+					ReturnMessageContains(arwen.ErrEndpointPaymentNotAccepted.Error()).
+					GasRemaining(0)
+			})
+	}
+
+	runPaymentTokensTest(increment, 0, nil, true)
+	runPaymentTokensTest(increment, 10, nil, true)
+	runPaymentTokensTest(increment, 0, []byte("TKN-0001"), false)
+	runPaymentTokensTest("decrement", 0, []byte("TKN-0001"), true)
+	runPaymentTokensTest("decrement", 0, []byte("TKN-0002"), false)
+	runPaymentTokensTest("decrement", 10, nil, false)
+	// the endpoints without a whitelist check their payments themselves
+	runPaymentTokensTest("get", 10, []byte("TKN-0002"), true)
+}
+
 func TestExecution_CallSCMethod_MissingFunction(t *testing.T) {
 	test.BuildInstanceCallTest(t).
 		WithContracts(
@@ -615,6 +1088,79 @@ func TestExecution_Call_Successful(t *testing.T) {
 		})
 }
 
+func TestExecution_Call_StorageAccesses(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(code, big.NewInt(0))).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	runAndGetAccesses := func(function string) *arwen.StorageAccessSet {
+		input := test.CreateTestContractCallInputBuilder().
+			WithGasProvided(100000).
+			WithFunction(function).
+			Build()
+		vmOutput, err := host.RunSmartContractCall(input)
+		test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+		return host.StorageAccesses()
+	}
+
+	firstIncrement := runAndGetAccesses(increment)
+	require.True(t, firstIncrement.HasRead(test.ParentAddress, counterKey))
+	require.True(t, firstIncrement.HasWritten(test.ParentAddress, counterKey))
+	secondIncrement := runAndGetAccesses(increment)
+	firstGet := runAndGetAccesses(get)
+	require.False(t, firstGet.HasWritten(test.ParentAddress, counterKey))
+	secondGet := runAndGetAccesses(get)
+
+	conflicts := arwen.FindStorageConflicts(firstIncrement, secondIncrement)
+	require.Equal(t, []arwen.StorageConflict{
+		{Address: test.ParentAddress, Key: counterKey, Kind: arwen.ReadAfterWrite},
+		{Address: test.ParentAddress, Key: counterKey, Kind: arwen.WriteAfterRead},
+		{Address: test.ParentAddress, Key: counterKey, Kind: arwen.WriteAfterWrite},
+	}, conflicts)
+
+	conflicts = arwen.FindStorageConflicts(firstIncrement, firstGet)
+	require.Equal(t, []arwen.StorageConflict{
+		{Address: test.ParentAddress, Key: counterKey, Kind: arwen.ReadAfterWrite},
+	}, conflicts)
+
+	require.Empty(t, arwen.FindStorageConflicts(firstGet, secondGet))
+}
+
+func TestExecution_Call_CoverageRecorded(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+	executorFactory, recorder := executorwrapper.CoverageRecorderExecutorFactory(wasmer.ExecutorFactory())
+	err := recorder.AddContract("counter.wasm", code)
+	require.Nil(t, err)
+
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(code)).
+		WithExecutorFactory(executorFactory).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithGasProvided(100000).
+			WithFunction(increment).
+			Build()).
+		AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok()
+		})
+
+	require.Equal(t, uint64(1), recorder.FunctionHits("counter.wasm", increment))
+	require.Equal(t, uint64(0), recorder.FunctionHits("counter.wasm", "decrement"))
+
+	var report bytes.Buffer
+	err = recorder.WriteLCOV(&report)
+	require.Nil(t, err)
+	require.Contains(t, report.String(), "SF:counter.wasm\n")
+	require.Contains(t, report.String(), "FNDA:1,increment\n")
+	require.Contains(t, report.String(), "FNDA:0,decrement\n")
+	require.Contains(t, report.String(), "FNF:4\nFNH:1\nend_of_record\n")
+}
+
 func TestExecution_CachingCompiledCode(t *testing.T) {
 	world := worldmock.NewMockWorld()
 	host := test.NewTestHostBuilder(t).
@@ -1917,6 +2463,172 @@ func TestExecution_ExecuteOnDestContext_Successful(t *testing.T) {
 		})
 }
 
+func TestExecution_ExecuteOnDestContext_CallGraphRecorded(t *testing.T) {
+	recorder := tracing.NewCallGraphRecorder()
+
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(test.GetTestSCCode("exec-dest-ctx-parent", "../../")).
+				WithBalance(1000),
+			test.CreateInstanceContract(test.ChildAddress).
+				WithCode(test.GetTestSCCode("exec-dest-ctx-child", "../../")).
+				WithBalance(1000),
+		).
+		WithExecutionTracer(recorder).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(parentFunctionChildCall).
+			WithGasProvided(test.GasProvided).
+			Build()).
+		AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok()
+			calls := recorder.Calls()
+			require.Len(t, calls, 1)
+			require.Equal(t, test.ParentAddress, calls[0].Contract)
+			require.Equal(t, parentFunctionChildCall, calls[0].Function)
+			require.Equal(t, test.GasProvided-verify.VmOutput.GasRemaining, calls[0].GasUsed)
+
+			require.Len(t, calls[0].Children, 1)
+			childCall := calls[0].Children[0]
+			require.Equal(t, test.ChildAddress, childCall.Contract)
+			require.Equal(t, vmcommon.Ok, childCall.ReturnCode)
+			require.Less(t, childCall.GasUsed, calls[0].GasUsed)
+			require.Len(t, childCall.Children, 0)
+		})
+
+	var dot bytes.Buffer
+	err := recorder.WriteDOT(&dot)
+	require.Nil(t, err)
+	require.Contains(t, dot.String(), "n1 -> n2 [label=\"sync x1")
+}
+
+func TestExecution_ExecuteOnDestContext_OutOfGasAtEveryVMHookCall(t *testing.T) {
+	injector := runParentChildCallWithOutOfGasFault(t, executorwrapper.OutOfGasFault{}, func(verify *test.VMOutputVerifier) {
+		verify.Ok()
+	})
+	numVMHookCalls := injector.NumVMHookCalls()
+	require.NotZero(t, numVMHookCalls)
+
+	for atVMHookCall := uint64(1); atVMHookCall <= numVMHookCalls; atVMHookCall++ {
+		fault := executorwrapper.OutOfGasFault{AtVMHookCall: atVMHookCall}
+		injector = runParentChildCallWithOutOfGasFault(t, fault, requireRolledBackOutOfGas)
+		require.True(t, injector.FaultInjected(), "VM hook call %d", atVMHookCall)
+	}
+}
+
+func TestExecution_ExecuteOnDestContext_OutOfGasAtOpcodeCount(t *testing.T) {
+	for _, atOpcodeCount := range []uint64{1, 50, 100} {
+		fault := executorwrapper.OutOfGasFault{AtOpcodeCount: atOpcodeCount}
+		injector := runParentChildCallWithOutOfGasFault(t, fault, requireRolledBackOutOfGas)
+		require.True(t, injector.FaultInjected(), "opcode count %d", atOpcodeCount)
+	}
+}
+
+func runParentChildCallWithOutOfGasFault(
+	t *testing.T,
+	fault executorwrapper.OutOfGasFault,
+	assertResults func(verify *test.VMOutputVerifier),
+) *executorwrapper.OutOfGasInjector {
+	executorFactory, injector := executorwrapper.OutOfGasInjectorExecutorFactory(wasmer.ExecutorFactory(), fault)
+
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(test.GetTestSCCode("exec-dest-ctx-parent", "../../")).
+				WithBalance(1000),
+			test.CreateInstanceContract(test.ChildAddress).
+				WithCode(test.GetTestSCCode("exec-dest-ctx-child", "../../")).
+				WithBalance(1000),
+		).
+		WithExecutorFactory(executorFactory).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(parentFunctionChildCall).
+			WithGasProvided(test.GasProvided).
+			Build()).
+		AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			assertResults(verify)
+		})
+
+	return injector
+}
+
+func requireRolledBackOutOfGas(verify *test.VMOutputVerifier) {
+	verify.OutOfGas().
+		GasRemaining(0)
+	require.Empty(verify.T, verify.VmOutput.OutputAccounts)
+	require.Empty(verify.T, verify.VmOutput.ReturnData)
+	require.Empty(verify.T, verify.VmOutput.Logs)
+}
+
+type shadowDivergenceRecorder struct {
+	divergences []*arwen.ShadowDivergence
+}
+
+func (recorder *shadowDivergenceRecorder) HandleShadowDivergence(divergence *arwen.ShadowDivergence) {
+	recorder.divergences = append(recorder.divergences, divergence)
+}
+
+func (recorder *shadowDivergenceRecorder) IsInterfaceNil() bool {
+	return recorder == nil
+}
+
+func TestExecution_ShadowExecutor_SameExecutor(t *testing.T) {
+	recorder := &shadowDivergenceRecorder{}
+	runParentChildCallWithShadowExecutor(t, wasmer.ExecutorFactory(), recorder)
+	require.Empty(t, recorder.divergences)
+}
+
+func TestExecution_ShadowExecutor_Interpreter(t *testing.T) {
+	recorder := &shadowDivergenceRecorder{}
+	runParentChildCallWithShadowExecutor(t, interpreter.ExecutorFactory(), recorder)
+	require.Empty(t, recorder.divergences)
+}
+
+func TestExecution_ShadowExecutor_Divergence(t *testing.T) {
+	recorder := &shadowDivergenceRecorder{}
+	fault := executorwrapper.OutOfGasFault{AtVMHookCall: 1}
+	shadowFactory, injector := executorwrapper.OutOfGasInjectorExecutorFactory(wasmer.ExecutorFactory(), fault)
+	runParentChildCallWithShadowExecutor(t, shadowFactory, recorder)
+
+	require.True(t, injector.FaultInjected())
+	require.Len(t, recorder.divergences, 1)
+	divergence := recorder.divergences[0]
+	require.Equal(t, arwen.ExecutionCall, divergence.Kind)
+	require.Equal(t, test.ParentAddress, divergence.Recipient)
+	require.Equal(t, parentFunctionChildCall, divergence.Function)
+	require.NotEqual(t, divergence.Canonical, divergence.Shadow)
+	require.Contains(t, divergence.Shadow, vmcommon.OutOfGas.String())
+}
+
+// runParentChildCallWithShadowExecutor checks that the canonical outcome of
+// the call is the same, whatever the outcome on the shadow executor
+func runParentChildCallWithShadowExecutor(
+	t *testing.T,
+	shadowFactory executor.ExecutorAbstractFactory,
+	handler arwen.ShadowDivergenceHandler,
+) {
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(test.GetTestSCCode("exec-dest-ctx-parent", "../../")).
+				WithBalance(1000),
+			test.CreateInstanceContract(test.ChildAddress).
+				WithCode(test.GetTestSCCode("exec-dest-ctx-child", "../../")).
+				WithBalance(1000),
+		).
+		WithShadowExecutor(shadowFactory, handler).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithFunction(parentFunctionChildCall).
+			WithGasProvided(test.GasProvided).
+			Build()).
+		AndAssertResults(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.Ok()
+		})
+}
+
 func TestExecution_ExecuteOnDestContext_Successful_ChildReturns(t *testing.T) {
 	// Call parentFunctionChildCall() of the parent SC, which will call the child
 	// SC and pass some arguments using executeOnDestContext().
@@ -3278,34 +3990,186 @@ func TestExecution_Opcodes_MemoryGrow(t *testing.T) {
 	maxGrows := uint32(math.MaxUint32)
 	maxDelta := uint32(10)
 	argDelta := int64(10)
-	runMemGrowTest(t, maxGrows, maxDelta, argDelta, 10, vmcommon.Ok)
+	runMemGrowTest(t, nil, maxGrows, maxDelta, argDelta, 10, vmcommon.Ok)
 }
 
 func TestExecution_Opcodes_MemoryGrow_Limit(t *testing.T) {
 	maxGrows := uint32(10)
 	maxDelta := uint32(10)
-	runMemGrowTest(t, maxGrows, maxDelta, int64(maxDelta), int64(maxGrows-1), vmcommon.Ok)
-	runMemGrowTest(t, maxGrows, maxDelta, int64(maxDelta), int64(maxGrows), vmcommon.Ok)
-	runMemGrowTest(t, maxGrows, maxDelta, int64(maxDelta), int64(maxGrows+1), vmcommon.ExecutionFailed)
+	runMemGrowTest(t, nil, maxGrows, maxDelta, int64(maxDelta), int64(maxGrows-1), vmcommon.Ok)
+	runMemGrowTest(t, nil, maxGrows, maxDelta, int64(maxDelta), int64(maxGrows), vmcommon.Ok)
+	runMemGrowTest(t, memoryLimitReturnCodeDisabled(), maxGrows, maxDelta, int64(maxDelta), int64(maxGrows+1), vmcommon.ExecutionFailed)
+	runMemGrowTest(t, nil, maxGrows, maxDelta, int64(maxDelta), int64(maxGrows+1), arwen.MemoryLimitExceeded)
 }
 
 func TestExecution_Opcodes_MemoryGrowDelta(t *testing.T) {
 	maxGrows := uint32(10)
 	maxDelta := uint32(10)
-	runMemGrowTest(t, maxGrows, maxDelta, int64(maxDelta-1), 1, vmcommon.Ok)
-	runMemGrowTest(t, maxGrows, maxDelta, int64(maxDelta), 1, vmcommon.Ok)
-	runMemGrowTest(t, maxGrows, maxDelta, int64(maxDelta+1), 1, vmcommon.ExecutionFailed)
+	runMemGrowTest(t, nil, maxGrows, maxDelta, int64(maxDelta-1), 1, vmcommon.Ok)
+	runMemGrowTest(t, nil, maxGrows, maxDelta, int64(maxDelta), 1, vmcommon.Ok)
+	runMemGrowTest(t, memoryLimitReturnCodeDisabled(), maxGrows, maxDelta, int64(maxDelta+1), 1, vmcommon.ExecutionFailed)
+	runMemGrowTest(t, nil, maxGrows, maxDelta, int64(maxDelta+1), 1, arwen.MemoryLimitExceeded)
+}
+
+func memoryLimitReturnCodeDisabled() arwen.EnableEpochsHandler {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.MemoryLimitReturnCodeEnableEpochField = config.DisabledEpoch
+	return enableEpochsHandler
+}
+
+func TestExecution_Opcodes_MemoryGrowPerPage(t *testing.T) {
+	unpricedGasRemaining := runMemGrowPricingTest(t, 0, 0, 3, vmcommon.Ok)
+	pricedGasRemaining := runMemGrowPricingTest(t, 7, 0, 3, vmcommon.Ok)
+	require.Equal(t, unpricedGasRemaining-3*7, pricedGasRemaining)
+
+	_ = runMemGrowPricingTest(t, 80000, 0, 3, vmcommon.OutOfGas)
+}
+
+func TestExecution_Opcodes_MaxMemoryPages(t *testing.T) {
+	_ = runMemGrowPricingTest(t, 0, 100, 10, vmcommon.Ok)
+	_ = runMemGrowPricingTest(t, 0, 10, 10, arwen.MemoryLimitExceeded)
+}
+
+func runMemGrowPricingTest(
+	t *testing.T,
+	memoryGrowPerPage uint32,
+	maxMemoryPages uint32,
+	argMemGrowDelta int64,
+	expectedRetCode vmcommon.ReturnCode,
+) uint64 {
+	repsBytes := arwen.PadBytesLeft(big.NewInt(1).Bytes(), 8)
+	deltaBytes := arwen.PadBytesLeft(big.NewInt(argMemGrowDelta).Bytes(), 8)
+
+	gasRemaining := uint64(0)
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(codeOpcodes)).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithGasProvided(80000).
+			WithFunction("memGrowDelta").
+			WithArguments(repsBytes, deltaBytes).
+			Build()).
+		WithSetup(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub) {
+			gasSchedule := host.Metering().GasSchedule()
+			gasSchedule.WASMOpcodeCost.MemoryGrowPerPage = memoryGrowPerPage
+			gasSchedule.WASMOpcodeCost.MaxMemoryPages = maxMemoryPages
+		}).
+		AndAssertResults(func(host arwen.VMHost, _ *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.ReturnCode(expectedRetCode)
+			gasRemaining = verify.VmOutput.GasRemaining
+		})
+
+	return gasRemaining
+}
+
+var codeStackRecursion []byte = test.GetTestSCCode("stack-recursion", "../../")
+
+func TestExecution_MaxStackHeight(t *testing.T) {
+	// each frame of the recursion costs one slot and one for its parameter
+	runStackRecursionTest(t, 0, 100, vmcommon.Ok, "")
+	runStackRecursionTest(t, 1000, 499, vmcommon.Ok, "")
+	runStackRecursionTest(t, 1000, 500, vmcommon.ExecutionFailed, arwen.ErrMaxStackReached.Error())
+}
+
+func TestExecution_MaxStackHeight_DeepRecursionFailsCleanly(t *testing.T) {
+	runStackRecursionTest(t, 10000, 1000000, vmcommon.ExecutionFailed, arwen.ErrMaxStackReached.Error())
+	runStackRecursionTest(t, 10000, 10, vmcommon.Ok, "")
+}
+
+func runStackRecursionTest(
+	t *testing.T,
+	maxStackHeight uint32,
+	depth int64,
+	expectedRetCode vmcommon.ReturnCode,
+	expectedMessage string,
+) {
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(codeStackRecursion)).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithGasProvided(100000000).
+			WithFunction("recurse").
+			WithArguments(big.NewInt(depth).Bytes()).
+			Build()).
+		WithSetup(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub) {
+			host.Metering().GasSchedule().WASMOpcodeCost.MaxStackHeight = maxStackHeight
+		}).
+		AndAssertResults(func(host arwen.VMHost, _ *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.ReturnCode(expectedRetCode)
+			if expectedRetCode == vmcommon.Ok {
+				verify.ReturnData(big.NewInt(depth).Bytes())
+				return
+			}
+			verify.ReturnMessage(expectedMessage)
+		})
+}
+
+var codeStorageBomb []byte = test.GetTestSCCode("storage-bomb", "../../")
+
+func TestExecution_StorageLimits(t *testing.T) {
+	limits := arwen.StorageLimits{MaxKeyLength: 128, MaxValueLength: 1024}
+
+	// before the limits are enabled, the keys and the values are unbounded
+	runStorageBombTest(t, limits, 4, "storeValue", 100000, vmcommon.Ok, "")
+	runStorageBombTest(t, limits, 4, "storeKey", 100000, vmcommon.Ok, "")
+
+	runStorageBombTest(t, limits, 5, "storeValue", 1024, vmcommon.Ok, "")
+	runStorageBombTest(t, limits, 5, "storeValue", 1025, vmcommon.ExecutionFailed, arwen.ErrStorageValueTooLong.Error())
+	runStorageBombTest(t, limits, 5, "storeKey", 128, vmcommon.Ok, "")
+	runStorageBombTest(t, limits, 5, "storeKey", 129, vmcommon.ExecutionFailed, arwen.ErrStorageKeyTooLong.Error())
+
+	runStorageBombTest(t, arwen.StorageLimits{}, 5, "storeValue", 100000, vmcommon.Ok, "")
+}
+
+func runStorageBombTest(
+	t *testing.T,
+	limits arwen.StorageLimits,
+	currentEpoch uint32,
+	function string,
+	length int64,
+	expectedRetCode vmcommon.ReturnCode,
+	expectedMessage string,
+) {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.StorageLimitsEnableEpochField = 5
+
+	test.BuildInstanceCallTest(t).
+		WithContracts(
+			test.CreateInstanceContract(test.ParentAddress).
+				WithCode(codeStorageBomb)).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithStorageLimits(limits).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithGasProvided(100000000).
+			WithFunction(function).
+			WithArguments(big.NewInt(length).Bytes()).
+			Build()).
+		WithSetup(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub) {
+			stubBlockchainHook.CurrentEpochCalled = func() uint32 {
+				return currentEpoch
+			}
+		}).
+		AndAssertResults(func(host arwen.VMHost, _ *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
+			verify.ReturnCode(expectedRetCode)
+			if expectedRetCode == vmcommon.Ok {
+				return
+			}
+			verify.ReturnMessage(expectedMessage)
+		})
 }
 
 func BenchmarkOpcodeMemoryGrow(b *testing.B) {
 	maxGrows := uint32(math.MaxUint32)
 	maxDelta := uint32(10)
 	argDelta := int64(10)
-	runMemGrowTest(b, maxGrows, maxDelta, argDelta, int64(b.N), vmcommon.Ok)
+	runMemGrowTest(b, nil, maxGrows, maxDelta, argDelta, int64(b.N), vmcommon.Ok)
 }
 
 func runMemGrowTest(
 	tb testing.TB,
+	enableEpochsHandler arwen.EnableEpochsHandler,
 	maxMemGrow uint32,
 	maxMemGrowDelta uint32,
 	argMemGrowDelta int64,
@@ -3327,6 +4191,7 @@ func runMemGrowTest(
 			WithFunction("memGrowDelta").
 			WithArguments(repsBytes, deltaBytes).
 			Build()).
+		WithEnableEpochsHandler(enableEpochsHandler).
 		WithSetup(func(host arwen.VMHost, stubBlockchainHook *contextmock.BlockchainHookStub) {
 			gasSchedule := host.Metering().GasSchedule()
 			gasSchedule.WASMOpcodeCost.MaxMemoryGrow = maxMemGrow
@@ -3334,7 +4199,7 @@ func runMemGrowTest(
 		}).
 		AndAssertResults(func(host arwen.VMHost, _ *contextmock.BlockchainHookStub, verify *test.VMOutputVerifier) {
 			verify.ReturnCode(expectedRetCode)
-			if expectedRetCode == vmcommon.ExecutionFailed {
+			if expectedRetCode != vmcommon.Ok {
 				vmOutput := verify.VmOutput
 				require.Len(tb, vmOutput.Logs, 1)
 				require.Contains(tb, string(vmOutput.Logs[0].Data), arwen.ErrMemoryLimit.Error())
@@ -3517,7 +4382,7 @@ func makeBytecodeWithLocals(numLocals uint64) []byte {
 // modifyERC20BytecodeWithCustomTransferEvent rewrites the bytecode of the ERC20
 // contract to change the first bytes of its transferEvent bytes
 func modifyERC20BytecodeWithCustomTransferEvent(erc20Bytecode []byte, replaceBytes []byte) {
-	transferEventBytecodeOffset := 0x144B
+	transferEventBytecodeOffset := 0x1598
 
 	for i, b := range replaceBytes {
 		erc20Bytecode[transferEventBytecodeOffset+i] = b
@@ -5,6 +5,7 @@ import (
 
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	"github.com/ElrondNetwork/wasm-vm/testcommon"
 )
 
@@ -42,3 +43,20 @@ func TestForbiddenOps_FloatingPoints(t *testing.T) {
 			verify.ContractInvalid()
 		})
 }
+
+func TestForbiddenOps_InactiveBulkMemory(t *testing.T) {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.BulkMemoryEnableEpochField = 1
+
+	testcommon.BuildInstanceCreatorTest(t).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		WithInput(testcommon.CreateTestContractCreateInputBuilder().
+			WithGasProvided(1000).
+			WithContractCode(testcommon.GetTestSCCodeModule("forbidden-opcodes/memory-fill", "memory-fill", "../../")).
+			Build()).
+		WithAddress(newAddress).
+		AndAssertResults(func(_ *contextmock.BlockchainHookStub, verify *testcommon.VMOutputVerifier) {
+			verify.ContractInvalid().
+				ReturnMessageContains(arwen.ErrWasmFeatureNotActive.Error())
+		})
+}
@@ -0,0 +1,96 @@
+package hosttest
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/ElrondNetwork/wasm-vm/guardian"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+var guardianAddress = []byte("guardian________________________")
+
+func newGuardedAccountsWorld(isGuarded bool) *worldmock.MockWorld {
+	world := worldmock.NewMockWorld()
+	world.AcctMap.PutAccount(&worldmock.Account{
+		Address:        test.UserAddress,
+		IsGuarded:      isGuarded,
+		ActiveGuardian: guardianAddress,
+	})
+	return world
+}
+
+func newGuardedAccountsHost(t *testing.T, world *worldmock.MockWorld, enableEpochsHandler arwen.EnableEpochsHandler) arwen.VMHost {
+	code := test.GetTestSCCode("counter", "../../")
+	return test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(code, nil)).
+		WithGuardedAccounts(world).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		Build()
+}
+
+func TestGuardians_GuardedAccount(t *testing.T) {
+	world := newGuardedAccountsWorld(true)
+	host := newGuardedAccountsHost(t, world, worldmock.EnableEpochsHandlerStubNoFlags())
+	defer func() {
+		host.Reset()
+	}()
+
+	input := nonceTestInput()
+	vmOutput, err := host.RunSmartContractCall(input)
+	require.Nil(t, vmOutput)
+	require.Equal(t, guardian.ErrMissingGuardianCoSignature, err)
+
+	world.SetGuardianCoSignature(input.CurrentTxHash, &worldmock.GuardianCoSignature{
+		Options:           guardian.TxOptionGuarded,
+		GuardianAddr:      []byte("other___________________________"),
+		GuardianSignature: []byte("signature"),
+	})
+	vmOutput, err = host.RunSmartContractCall(input)
+	require.Nil(t, vmOutput)
+	require.Equal(t, guardian.ErrGuardianMismatch, err)
+
+	world.SetGuardianCoSignature(input.CurrentTxHash, &worldmock.GuardianCoSignature{
+		Options:           guardian.TxOptionGuarded,
+		GuardianAddr:      guardianAddress,
+		GuardianSignature: []byte("signature"),
+	})
+	vmOutput, err = host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+}
+
+func TestGuardians_UnguardedAccount(t *testing.T) {
+	world := newGuardedAccountsWorld(false)
+	host := newGuardedAccountsHost(t, world, worldmock.EnableEpochsHandlerStubNoFlags())
+	defer func() {
+		host.Reset()
+	}()
+
+	input := nonceTestInput()
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+
+	world.SetGuardianCoSignature(input.CurrentTxHash, &worldmock.GuardianCoSignature{
+		Options:           guardian.TxOptionGuarded,
+		GuardianAddr:      guardianAddress,
+		GuardianSignature: []byte("signature"),
+	})
+	vmOutput, err = host.RunSmartContractCall(input)
+	require.Nil(t, vmOutput)
+	require.Equal(t, guardian.ErrGuardedOptionOnUnguardedAccount, err)
+}
+
+func TestGuardians_FlagDisabled(t *testing.T) {
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubNoFlags()
+	enableEpochsHandler.GuardedAccountsEnableEpochField = config.DisabledEpoch
+	host := newGuardedAccountsHost(t, newGuardedAccountsWorld(true), enableEpochsHandler)
+	defer func() {
+		host.Reset()
+	}()
+
+	vmOutput, err := host.RunSmartContractCall(nonceTestInput())
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+}
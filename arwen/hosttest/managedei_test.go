@@ -4,6 +4,7 @@ import (
 	"bytes"
 	"crypto/ed25519"
 	"crypto/elliptic"
+	"encoding/binary"
 	"encoding/hex"
 	"math/big"
 	"strings"
@@ -301,6 +302,145 @@ func Test_BigIntToString(t *testing.T) {
 		})
 }
 
+func Test_ManagedSha256(t *testing.T) {
+	testConfig := baseTestConfig
+
+	asBytes := []byte{1, 2, 3}
+	asSha256, _ := hashing.NewHasher().Sha256(asBytes)
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+
+						managedTypes := host.ManagedTypes()
+						sourceHandle := managedTypes.NewManagedBufferFromBytes(asBytes)
+						destHandle := managedTypes.NewManagedBuffer()
+
+						elrondapi.ManagedSha256WithHost(
+							host,
+							sourceHandle,
+							destHandle)
+
+						bytesResult, _ := managedTypes.GetBytes(destHandle)
+						if !bytes.Equal(bytesResult, asSha256) {
+							host.Runtime().SignalUserError("assert failed")
+							return parentInstance
+						}
+
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				Ok()
+		})
+}
+
+func Test_ManagedAllocationBudget(t *testing.T) {
+	runManagedAllocationTest(t, 0, vmcommon.Ok)
+	runManagedAllocationTest(t, 10000, vmcommon.Ok)
+	runManagedAllocationTest(t, 9999, vmcommon.ExecutionFailed)
+}
+
+// runManagedAllocationTest allocates 10000 bytes in managed buffers and big
+// ints, under the given allocation budget
+func runManagedAllocationTest(t *testing.T, maxAllocation uint64, expectedRetCode vmcommon.ReturnCode) {
+	testConfig := baseTestConfig
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						managedTypes := parentInstance.Host.ManagedTypes()
+
+						chunk := make([]byte, 1000)
+						handle := managedTypes.NewManagedBufferFromBytes(chunk)
+						for i := 0; i < 8; i++ {
+							managedTypes.AppendBytes(handle, chunk)
+						}
+						managedTypes.NewBigInt(big.NewInt(0).SetBytes(chunk))
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			host.ManagedTypes().SetMaxAllocation(maxAllocation)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			if expectedRetCode == vmcommon.Ok {
+				verify.Ok()
+				return
+			}
+			verify.
+				ReturnCode(expectedRetCode).
+				ReturnMessage(arwen.ErrMaxManagedAllocationExceeded.Error())
+		})
+}
+
+func Test_ManagedKeccak256(t *testing.T) {
+	testConfig := baseTestConfig
+
+	asBytes := []byte{1, 2, 3}
+	asKeccak256, _ := hashing.NewHasher().Keccak256(asBytes)
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+
+						managedTypes := host.ManagedTypes()
+						sourceHandle := managedTypes.NewManagedBufferFromBytes(asBytes)
+						destHandle := managedTypes.NewManagedBuffer()
+
+						elrondapi.ManagedKeccak256WithHost(
+							host,
+							sourceHandle,
+							destHandle)
+
+						bytesResult, _ := managedTypes.GetBytes(destHandle)
+						if !bytes.Equal(bytesResult, asKeccak256) {
+							host.Runtime().SignalUserError("assert failed")
+							return parentInstance
+						}
+
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				Ok()
+		})
+}
+
 func Test_ManagedRipemd160(t *testing.T) {
 	testConfig := baseTestConfig
 
@@ -1134,6 +1274,152 @@ func Test_ManagedDeleteContract_CrossShard(t *testing.T) {
 		})
 }
 
+func Test_DeleteContract_ReleasesBalanceAndCode(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithCodeMetadata([]byte{vmcommon.MetadataUpgradeable, 0}).
+				WithOwnerAddress(test.ParentAddress).
+				WithMethods(contracts.WasteGasChildMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithCallerAddr(test.ParentAddress).
+			WithRecipientAddr(test.ChildAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction(arwen.DeleteFunctionName).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			host.Metering().GasSchedule().BaseOperationCost.ReleasePerByte = 1
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				GasRemaining(testConfig.GasProvided).
+				DeletedAccounts(test.ChildAddress).
+				BalanceDelta(test.ChildAddress, -testConfig.ChildBalance).
+				BalanceDelta(test.ParentAddress, testConfig.ChildBalance)
+			// the code of the mock contracts is their address
+			require.Equal(t, big.NewInt(int64(len(test.ChildAddress))), verify.VmOutput.GasRefund)
+		})
+}
+
+func Test_DeleteContract_NotOwner(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithCodeMetadata([]byte{vmcommon.MetadataUpgradeable, 0}).
+				WithOwnerAddress(test.ParentAddress).
+				WithMethods(contracts.WasteGasChildMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithCallerAddr(test.UserAddress).
+			WithRecipientAddr(test.ChildAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction(arwen.DeleteFunctionName).
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.ReturnMessage(arwen.ErrUpgradeNotAllowed.Error()).
+				DeletedAccounts()
+		})
+}
+
+func Test_ManagedAsyncSubGroups(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 10_000
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						managedTypes := host.ManagedTypes()
+						vmHooks := elrondapi.NewElrondApi(host)
+
+						workflowHandle := managedTypes.NewManagedBufferFromBytes([]byte("workflow"))
+						vendorsHandle := managedTypes.NewManagedBufferFromBytes([]byte("vendors"))
+						require.Equal(t, int32(0), vmHooks.ManagedAddAsyncSubGroup(workflowHandle, vendorsHandle))
+
+						vendors, exists := host.Async().GetCallGroup("vendors")
+						require.True(t, exists)
+						require.Equal(t, "workflow", vendors.ParentIdentifier)
+
+						successHandle := managedTypes.NewManagedBufferFromBytes([]byte("groupCallback"))
+						errorHandle := managedTypes.NewManagedBuffer()
+						dataHandle := managedTypes.NewManagedBufferFromBytes([]byte{1, 2})
+						require.Equal(t, int32(0), vmHooks.ManagedSetAsyncGroupCallback(workflowHandle, successHandle, errorHandle, dataHandle, 100))
+
+						workflow, exists := host.Async().GetCallGroup("workflow")
+						require.True(t, exists)
+						require.Equal(t, "groupCallback", workflow.Callback)
+						require.Equal(t, []byte{1, 2}, workflow.CallbackData)
+
+						return parentInstance
+					})
+					parentInstance.AddMockMethod("groupCallback", func() *mock.InstanceMock {
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+			setAsyncCosts(host, testConfig.GasLockCost)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok()
+		})
+}
+
+func Test_ManagedAsyncSubGroups_Disabled(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 10_000
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.AsyncCallSubGroupsEnableEpochField = 1
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						managedTypes := host.ManagedTypes()
+						vmHooks := elrondapi.NewElrondApi(host)
+
+						workflowHandle := managedTypes.NewManagedBufferFromBytes([]byte("workflow"))
+						vendorsHandle := managedTypes.NewManagedBufferFromBytes([]byte("vendors"))
+						vmHooks.ManagedAddAsyncSubGroup(workflowHandle, vendorsHandle)
+
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			Build()).
+		WithEnableEpochsHandler(enableEpochsHandler).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.ReturnMessage(arwen.ErrAsyncSubGroupsDisabled.Error())
+		})
+}
+
 func TestElrondEI_NFTNonceOverflow(t *testing.T) {
 	testConfig := makeTestConfig()
 
@@ -1208,3 +1494,513 @@ func TestElrondEI_NFTNonceOverflow(t *testing.T) {
 				ReturnData(big.NewInt(tokenValue).Bytes())
 		})
 }
+
+func TestElrondEI_ManagedGetESDTNFTMetadata(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	nonce := uint64(7)
+	attributes := []byte("metadata:cid/7.json;tags:art,music")
+	uris := [][]byte{[]byte("https://uri/7"), []byte("https://uri/7/alt")}
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						managed := host.ManagedTypes()
+						tokenIDHandle := managed.NewManagedBufferFromBytes(test.ESDTTestTokenName)
+
+						attributesHandle := managed.NewManagedBuffer()
+						elrondapi.ManagedGetESDTNFTAttributesWithHost(host, tokenIDHandle, int64(nonce), attributesHandle)
+						attributesBytes, _ := managed.GetBytes(attributesHandle)
+						host.Output().Finish(attributesBytes)
+
+						keyHandle := managed.NewManagedBufferFromBytes([]byte("tags"))
+						valueHandle := managed.NewManagedBuffer()
+						found := elrondapi.ManagedGetESDTNFTAttributeValueWithHost(host, tokenIDHandle, int64(nonce), keyHandle, valueHandle)
+						value, _ := managed.GetBytes(valueHandle)
+						host.Output().Finish(big.NewInt(int64(found)).Bytes())
+						host.Output().Finish(value)
+
+						missingKeyHandle := managed.NewManagedBufferFromBytes([]byte("name"))
+						found = elrondapi.ManagedGetESDTNFTAttributeValueWithHost(host, tokenIDHandle, int64(nonce), missingKeyHandle, valueHandle)
+						host.Output().Finish(big.NewInt(int64(found)).Bytes())
+
+						urisHandle := managed.NewManagedBuffer()
+						elrondapi.ManagedGetESDTNFTURIsWithHost(host, tokenIDHandle, int64(nonce), urisHandle)
+						urisBytes, _ := managed.GetBytes(urisHandle)
+						host.Output().Finish(big.NewInt(int64(len(urisBytes) / 4)).Bytes())
+
+						creatorHandle := managed.NewManagedBuffer()
+						elrondapi.ManagedGetESDTNFTCreatorWithHost(host, tokenIDHandle, int64(nonce), creatorHandle)
+						creator, _ := managed.GetBytes(creatorHandle)
+						host.Output().Finish(creator)
+
+						royalties := elrondapi.ManagedGetESDTNFTRoyaltiesWithHost(host, tokenIDHandle, int64(nonce))
+						host.Output().Finish(big.NewInt(royalties).Bytes())
+
+						tokenType := elrondapi.ManagedGetESDTTokenTypeOfWithHost(host, tokenIDHandle, int64(nonce))
+						host.Output().Finish(big.NewInt(int64(tokenType)).Bytes())
+
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			createMockBuiltinFunctions(t, host, world)
+			setZeroCodeCosts(host)
+			world.BuiltinFuncs.SetTokenData(
+				test.ParentAddress,
+				test.ESDTTestTokenName,
+				nonce,
+				&esdt.ESDigitalToken{
+					Value:      big.NewInt(1),
+					Type:       uint32(core.NonFungible),
+					Properties: esdtconvert.MakeESDTUserMetadataBytes(false),
+					TokenMetaData: &esdt.MetaData{
+						Nonce:      nonce,
+						Name:       []byte("nft"),
+						Creator:    test.UserAddress,
+						Royalties:  250,
+						Attributes: attributes,
+						URIs:       uris,
+					},
+				})
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				Ok().
+				ReturnData(
+					attributes,
+					[]byte{1},
+					[]byte("art,music"),
+					[]byte{},
+					[]byte{2},
+					test.UserAddress,
+					big.NewInt(250).Bytes(),
+					big.NewInt(int64(core.NonFungible)).Bytes(),
+				)
+		})
+}
+
+func TestElrondEI_ManagedGetESDTNFTMetadata_FungibleToken(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("testFunction", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						managed := host.ManagedTypes()
+						tokenIDHandle := managed.NewManagedBufferFromBytes(test.ESDTTestTokenName)
+
+						royalties := elrondapi.ManagedGetESDTNFTRoyaltiesWithHost(host, tokenIDHandle, 0)
+						host.Output().Finish(big.NewInt(royalties).Bytes())
+
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("testFunction").
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			createMockBuiltinFunctions(t, host, world)
+			setZeroCodeCosts(host)
+			world.BuiltinFuncs.SetTokenData(
+				test.ParentAddress,
+				test.ESDTTestTokenName,
+				0,
+				&esdt.ESDigitalToken{
+					Value:      big.NewInt(100),
+					Type:       uint32(core.Fungible),
+					Properties: esdtconvert.MakeESDTUserMetadataBytes(false),
+				})
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				ExecutionFailed().
+				ReturnMessage(arwen.ErrNilESDTData.Error())
+		})
+}
+
+func TestElrondEI_ManagedTransferNFTWithRoyalties(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	nonce := uint64(3)
+	price := int64(1000)
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("sell", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						managed := host.ManagedTypes()
+
+						result := elrondapi.ManagedTransferNFTWithRoyaltiesWithHost(
+							host,
+							managed.NewManagedBufferFromBytes(test.UserAddress),
+							managed.NewManagedBufferFromBytes(test.ESDTTestTokenName),
+							int64(nonce),
+							managed.NewBigIntFromInt64(1),
+							managed.NewManagedBufferFromBytes(test.VaultAddress),
+							managed.NewManagedBuffer(),
+							0,
+							managed.NewBigIntFromInt64(price))
+						host.Output().Finish(big.NewInt(int64(result)).Bytes())
+
+						return parentInstance
+					})
+				}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("sell").
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			createMockBuiltinFunctions(t, host, world)
+			setZeroCodeCosts(host)
+			world.BuiltinFuncs.SetTokenData(
+				test.ParentAddress,
+				test.ESDTTestTokenName,
+				nonce,
+				&esdt.ESDigitalToken{
+					Value:      big.NewInt(1),
+					Type:       uint32(core.NonFungible),
+					Properties: esdtconvert.MakeESDTUserMetadataBytes(false),
+					TokenMetaData: &esdt.MetaData{
+						Nonce:     nonce,
+						Creator:   test.ThirdPartyAddress,
+						Royalties: 250,
+					},
+				})
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				Ok().
+				ReturnData([]byte{}).
+				BalanceDelta(test.ThirdPartyAddress, 25).
+				BalanceDelta(test.VaultAddress, 975)
+
+			var royaltiesLog *vmcommon.LogEntry
+			for _, logEntry := range verify.VmOutput.Logs {
+				if string(logEntry.Identifier) == elrondapi.NFTRoyaltiesPaidIdentifier {
+					royaltiesLog = logEntry
+				}
+			}
+			require.NotNil(t, royaltiesLog)
+			require.Equal(t, test.UserAddress, royaltiesLog.Data)
+			require.Equal(t, [][]byte{
+				test.ESDTTestTokenName,
+				big.NewInt(int64(nonce)).Bytes(),
+				{},
+				test.ThirdPartyAddress,
+				big.NewInt(25).Bytes(),
+				test.VaultAddress,
+				big.NewInt(975).Bytes(),
+			}, royaltiesLog.Topics)
+		})
+}
+
+// escrowContractPair builds a locker contract whose "lock" endpoint locks 300
+// in favor of the beneficiary contract, then calls the given endpoint of the
+// beneficiary; the beneficiary settles the escrow with its "claim" and
+// "release" endpoints
+func escrowContractPair(testConfig *test.TestConfig, beneficiaryFunction string) []test.MockTestSmartContract {
+	return []test.MockTestSmartContract{
+		test.CreateMockContract(test.ParentAddress).
+			WithBalance(testConfig.ParentBalance).
+			WithConfig(testConfig).
+			WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+				parentInstance.AddMockMethod("lock", func() *mock.InstanceMock {
+					host := parentInstance.Host
+					managedTypes := host.ManagedTypes()
+
+					beneficiaryHandle := managedTypes.NewManagedBufferFromBytes(test.ChildAddress)
+					valueHandle := managedTypes.NewBigIntFromInt64(300)
+					escrowIDHandle := managedTypes.NewManagedBuffer()
+					if elrondapi.ManagedEscrowLockWithHost(host, beneficiaryHandle, valueHandle, escrowIDHandle) != 0 {
+						return parentInstance
+					}
+
+					escrowID, _ := managedTypes.GetBytes(escrowIDHandle)
+					elrondapi.ExecuteOnDestContextWithTypedArgs(
+						host,
+						int64(testConfig.GasProvidedToChild),
+						big.NewInt(0),
+						[]byte(beneficiaryFunction),
+						test.ChildAddress,
+						[][]byte{test.ParentAddress, escrowID})
+					return parentInstance
+				})
+			}),
+		test.CreateMockContract(test.ChildAddress).
+			WithBalance(testConfig.ChildBalance).
+			WithConfig(testConfig).
+			WithMethods(func(childInstance *mock.InstanceMock, config interface{}) {
+				settle := func(settleEscrow func(arwen.VMHost, int32, int32) int32) func() *mock.InstanceMock {
+					return func() *mock.InstanceMock {
+						host := childInstance.Host
+						managedTypes := host.ManagedTypes()
+						arguments := host.Runtime().Arguments()
+
+						lockerHandle := managedTypes.NewManagedBufferFromBytes(arguments[0])
+						escrowIDHandle := managedTypes.NewManagedBufferFromBytes(arguments[1])
+						settleEscrow(host, lockerHandle, escrowIDHandle)
+						return childInstance
+					}
+				}
+				childInstance.AddMockMethod("claim", settle(elrondapi.ManagedEscrowClaimWithHost))
+				childInstance.AddMockMethod("release", settle(elrondapi.ManagedEscrowReleaseWithHost))
+			}),
+	}
+}
+
+func escrowTestConfig() *test.TestConfig {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 100000
+	testConfig.GasProvidedToChild = 50000
+	return testConfig
+}
+
+func TestElrondEI_ManagedEscrow_Claim(t *testing.T) {
+	testConfig := escrowTestConfig()
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(escrowContractPair(testConfig, "claim")...).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("lock").
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				Ok().
+				BalanceDelta(test.ParentAddress, -300).
+				BalanceDelta(test.ChildAddress, 300)
+		})
+	require.Nil(t, err)
+}
+
+func TestElrondEI_ManagedEscrow_Release(t *testing.T) {
+	testConfig := escrowTestConfig()
+
+	vmOutput, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(escrowContractPair(testConfig, "release")...).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("lock").
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				Ok().
+				BalanceDelta(test.ParentAddress, 0)
+		})
+	require.Nil(t, err)
+
+	identifiers := make([]string, 0)
+	for _, logEntry := range vmOutput.Logs {
+		identifiers = append(identifiers, string(logEntry.Identifier))
+	}
+	require.Contains(t, identifiers, elrondapi.EscrowLockedIdentifier)
+	require.Contains(t, identifiers, elrondapi.EscrowReleasedIdentifier)
+}
+
+func TestElrondEI_ManagedEscrow_OnlyBeneficiarySettles(t *testing.T) {
+	testConfig := escrowTestConfig()
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+					parentInstance.AddMockMethod("lockAndClaim", func() *mock.InstanceMock {
+						host := parentInstance.Host
+						managedTypes := host.ManagedTypes()
+
+						beneficiaryHandle := managedTypes.NewManagedBufferFromBytes(test.ChildAddress)
+						valueHandle := managedTypes.NewBigIntFromInt64(300)
+						escrowIDHandle := managedTypes.NewManagedBuffer()
+						elrondapi.ManagedEscrowLockWithHost(host, beneficiaryHandle, valueHandle, escrowIDHandle)
+
+						lockerHandle := managedTypes.NewManagedBufferFromBytes(test.ParentAddress)
+						elrondapi.ManagedEscrowClaimWithHost(host, lockerHandle, escrowIDHandle)
+						return parentInstance
+					})
+				}),
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithMethods(func(childInstance *mock.InstanceMock, config interface{}) {}),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("lockAndClaim").
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				ExecutionFailed().
+				ReturnMessage(arwen.ErrNotEscrowBeneficiary.Error())
+		})
+	require.Nil(t, err)
+}
+
+// storageMigrationContract builds an upgradeable contract whose init, run by
+// its upgrade, registers the migration of the keys "old" followed by the
+// indexes from 1 to 3 to the keys "new" followed by the same indexes, through
+// its "double" endpoint, which finishes each value repeated twice; its
+// "migrate" endpoint migrates at most two keys and finishes the number of
+// keys left. Its "register" endpoint attempts the registration outside of an
+// upgrade.
+func storageMigrationContract(testConfig *test.TestConfig) test.MockTestSmartContract {
+	return test.CreateMockContract(test.ParentAddress).
+		WithBalance(testConfig.ParentBalance).
+		WithConfig(testConfig).
+		WithCodeMetadata([]byte{vmcommon.MetadataUpgradeable, 0}).
+		WithOwnerAddress(test.UserAddress).
+		WithMethods(func(parentInstance *mock.InstanceMock, config interface{}) {
+			register := func() *mock.InstanceMock {
+				host := parentInstance.Host
+				managedTypes := host.ManagedTypes()
+
+				elrondapi.ManagedRegisterStorageMigrationWithHost(
+					host,
+					managedTypes.NewManagedBufferFromBytes([]byte("old")),
+					managedTypes.NewManagedBufferFromBytes([]byte("new")),
+					managedTypes.NewManagedBufferFromBytes([]byte("double")),
+					1,
+					3)
+				return parentInstance
+			}
+			parentInstance.AddMockMethod("init", register)
+			parentInstance.AddMockMethod("register", register)
+			parentInstance.AddMockMethod("migrate", func() *mock.InstanceMock {
+				host := parentInstance.Host
+				remaining := elrondapi.ManagedContinueStorageMigrationWithHost(host, 2)
+				if remaining >= 0 {
+					host.Output().Finish(big.NewInt(remaining).Bytes())
+				}
+				return parentInstance
+			})
+			parentInstance.AddMockMethod("double", func() *mock.InstanceMock {
+				host := parentInstance.Host
+				value := host.Runtime().Arguments()[1]
+				host.Output().Finish(append(append([]byte{}, value...), value...))
+				return parentInstance
+			})
+		})
+}
+
+func indexedStorageKey(prefix string, index uint32) string {
+	encodedIndex := make([]byte, 4)
+	binary.BigEndian.PutUint32(encodedIndex, index)
+	return prefix + string(encodedIndex)
+}
+
+func TestElrondEI_ManagedStorageMigration(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 100000
+
+	world := worldmock.NewMockWorld()
+	contract := storageMigrationContract(testConfig)
+	setup := func(host arwen.VMHost, world *worldmock.MockWorld) {
+		setZeroCodeCosts(host)
+	}
+	migrateInput := test.CreateTestContractCallInputBuilder().
+		WithRecipientAddr(test.ParentAddress).
+		WithGasProvided(testConfig.GasProvided).
+		WithFunction("migrate").
+		Build()
+
+	vmOutput, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(contract).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithCallerAddr(test.UserAddress).
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction(arwen.UpgradeFunctionName).
+			WithArguments(test.ParentAddress, []byte{vmcommon.MetadataUpgradeable, 0}).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+			account := world.AcctMap.GetAccount(test.ParentAddress)
+			_ = account.SaveKeyValue([]byte(indexedStorageKey("old", 1)), []byte("a"))
+			_ = account.SaveKeyValue([]byte(indexedStorageKey("old", 3)), []byte("c"))
+		}).
+		AndAssertResultsWithWorld(world, true, nil, nil, func(startNode *test.TestCallNode, world *worldmock.MockWorld, verify *test.VMOutputVerifier, expectedErrorsForRound []string) {
+			verify.Ok()
+		})
+	require.Nil(t, err)
+	require.Nil(t, world.UpdateAccounts(vmOutput.OutputAccounts, nil))
+
+	vmOutput, err = test.BuildMockInstanceCallTest(t).
+		WithContracts(contract).
+		WithInput(migrateInput).
+		WithSetup(setup).
+		AndAssertResultsWithWorld(world, false, nil, nil, func(startNode *test.TestCallNode, world *worldmock.MockWorld, verify *test.VMOutputVerifier, expectedErrorsForRound []string) {
+			verify.
+				Ok().
+				ReturnData([]byte{1})
+		})
+	require.Nil(t, err)
+	require.Nil(t, world.UpdateAccounts(vmOutput.OutputAccounts, nil))
+
+	account := world.AcctMap.GetAccount(test.ParentAddress)
+	require.Equal(t, []byte("aa"), account.StorageValue(indexedStorageKey("new", 1)))
+	require.Empty(t, account.StorageValue(indexedStorageKey("old", 1)))
+	require.Equal(t, []byte("c"), account.StorageValue(indexedStorageKey("old", 3)))
+
+	vmOutput, err = test.BuildMockInstanceCallTest(t).
+		WithContracts(contract).
+		WithInput(migrateInput).
+		WithSetup(setup).
+		AndAssertResultsWithWorld(world, false, nil, nil, func(startNode *test.TestCallNode, world *worldmock.MockWorld, verify *test.VMOutputVerifier, expectedErrorsForRound []string) {
+			verify.
+				Ok().
+				ReturnData([]byte{})
+		})
+	require.Nil(t, err)
+	require.Nil(t, world.UpdateAccounts(vmOutput.OutputAccounts, nil))
+
+	account = world.AcctMap.GetAccount(test.ParentAddress)
+	require.Equal(t, []byte("cc"), account.StorageValue(indexedStorageKey("new", 3)))
+	require.Empty(t, account.StorageValue(indexedStorageKey("old", 3)))
+	require.Empty(t, account.StorageValue(indexedStorageKey("new", 2)))
+}
+
+func TestElrondEI_ManagedStorageMigration_RegisterOutsideUpgrade(t *testing.T) {
+	testConfig := makeTestConfig()
+	testConfig.GasProvided = 100000
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(storageMigrationContract(testConfig)).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("register").
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				ExecutionFailed().
+				ReturnMessage(arwen.ErrStorageMigrationOutsideUpgrade.Error())
+		})
+	require.Nil(t, err)
+}
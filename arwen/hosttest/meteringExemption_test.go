@@ -0,0 +1,58 @@
+package hosttest
+
+import (
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/ElrondNetwork/wasm-vm/tracing"
+	"github.com/stretchr/testify/require"
+)
+
+func runCounterGetTwice(t *testing.T, exemptContracts [][]byte) ([]*vmcommon.VMOutput, map[string][]byte, *tracing.CallGraphRecorder) {
+	code := test.GetTestSCCode("counter", "../../")
+	compiledCodes := make(map[string][]byte)
+	recorder := tracing.NewCallGraphRecorder()
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(blockchainHookStubForWarmUp(code, compiledCodes)).
+		WithMeteringExemptContracts(exemptContracts).
+		WithExecutionTracer(recorder).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	vmOutputs := make([]*vmcommon.VMOutput, 0, 2)
+	for i := 0; i < 2; i++ {
+		input := test.DefaultTestContractCallInput()
+		input.GasProvided = 1000000
+		input.Function = get
+		vmOutput, err := host.RunSmartContractCall(input)
+		test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+		vmOutputs = append(vmOutputs, vmOutput)
+	}
+
+	statistics := host.CacheStatistics()
+	require.Equal(t, uint64(1), statistics.InstanceCreations[arwen.InstanceFromBytecode])
+	require.Equal(t, uint64(1), statistics.InstanceCreations[arwen.InstanceFromWarmCache])
+	return vmOutputs, compiledCodes, recorder
+}
+
+func TestMeteringExemption_SystemContractRunsUnmetered(t *testing.T) {
+	meteredOutputs, compiledCodes, recorder := runCounterGetTwice(t, nil)
+	require.Contains(t, compiledCodes, string(counterCodeHash))
+	require.False(t, recorder.Calls()[0].Unmetered)
+
+	exemptOutputs, compiledCodes, recorder := runCounterGetTwice(t, [][]byte{test.ParentAddress})
+	require.Empty(t, compiledCodes)
+	for i, exemptOutput := range exemptOutputs {
+		require.Greater(t, exemptOutput.GasRemaining, meteredOutputs[i].GasRemaining)
+		require.Equal(t, meteredOutputs[i].ReturnData, exemptOutput.ReturnData)
+		require.True(t, recorder.Calls()[i].Unmetered)
+	}
+	require.Equal(t, exemptOutputs[0].GasRemaining, exemptOutputs[1].GasRemaining)
+
+	graph := recorder.Graph()
+	require.True(t, graph.Nodes[1].Unmetered)
+}
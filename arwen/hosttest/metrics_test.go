@@ -0,0 +1,98 @@
+package hosttest
+
+import (
+	"bytes"
+	"fmt"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/metrics"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestMetrics_ExecutionsTrapsAndHostFunctions(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+
+	metricsSink, err := metrics.NewPrometheusSink(metrics.PrometheusSinkArgs{
+		ObserveHostFunctions: true,
+	})
+	require.Nil(t, err)
+
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(code, nil)).
+		WithMetricsSink(metricsSink).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+	require.Equal(t, metricsSink, host.Metrics())
+
+	input := test.DefaultTestContractCallInput()
+	input.GasProvided = 1000000
+	input.Function = get
+	for i := 0; i < 3; i++ {
+		vmOutput, err := host.RunSmartContractCall(input)
+		test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+	}
+
+	input.Function = "missingFunction"
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).ReturnCode(vmcommon.FunctionNotFound)
+
+	output := &bytes.Buffer{}
+	_, err = metricsSink.WriteTo(output)
+	require.Nil(t, err)
+
+	exported := output.String()
+	require.Contains(t, exported, `arwen_executions_total{kind="call",return_code="ok"} 3`)
+	require.Contains(t, exported, fmt.Sprintf(`arwen_executions_total{kind="call",return_code="%s"} 1`, vmcommon.FunctionNotFound.String()))
+	require.Contains(t, exported, `arwen_execution_duration_seconds_count{kind="call"} 4`)
+	require.Contains(t, exported, `arwen_gas_consumed_total{kind="call"} `)
+	require.Contains(t, exported, `arwen_instance_creations_total{source="bytecode",cache_hit="false"} `)
+	require.Contains(t, exported, `arwen_traps_total{category=`)
+	require.Contains(t, exported, `arwen_host_function_duration_seconds_count{host_function=`)
+}
+
+func TestMetrics_UnrestorableCompiledCode(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+
+	metricsSink, err := metrics.NewPrometheusSink(metrics.PrometheusSinkArgs{})
+	require.Nil(t, err)
+
+	blockchainHook := test.BlockchainHookStubForCall(code, nil)
+	blockchainHook.GetUserAccountCalled = func(address []byte) (vmcommon.UserAccountHandler, error) {
+		if bytes.Equal(address, test.ParentAddress) {
+			return &contextmock.StubAccount{CodeHash: []byte("counterCodeHash")}, nil
+		}
+		return nil, test.ErrAccountNotFound
+	}
+	blockchainHook.GetCompiledCodeCalled = func(codeHash []byte) (bool, []byte) {
+		return true, []byte("corrupted compiled code")
+	}
+
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(blockchainHook).
+		WithMetricsSink(metricsSink).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	// the contract is compiled from its bytecode instead, without a retry
+	input := test.DefaultTestContractCallInput()
+	input.GasProvided = 1000000
+	input.Function = get
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+
+	output := &bytes.Buffer{}
+	_, err = metricsSink.WriteTo(output)
+	require.Nil(t, err)
+
+	exported := output.String()
+	require.Contains(t, exported, `arwen_infrastructure_errors_total{retried="false"} 1`)
+	require.NotContains(t, exported, `arwen_infrastructure_errors_total{retried="true"}`)
+	require.Contains(t, exported, `arwen_instance_creations_total{source="bytecode",cache_hit="false"} 1`)
+}
@@ -0,0 +1,106 @@
+package hosttest
+
+import (
+	"bytes"
+	"math"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func blockchainHookStubForNonces(senderNonce uint64, currentBlock *uint64) *contextmock.BlockchainHookStub {
+	code := test.GetTestSCCode("counter", "../../")
+	stubBlockchainHook := test.BlockchainHookStubForCall(code, nil)
+	getContractAccount := stubBlockchainHook.GetUserAccountCalled
+	stubBlockchainHook.GetUserAccountCalled = func(address []byte) (vmcommon.UserAccountHandler, error) {
+		if bytes.Equal(address, test.UserAddress) {
+			return &contextmock.StubAccount{
+				Address: test.UserAddress,
+				Nonce:   senderNonce,
+			}, nil
+		}
+		return getContractAccount(address)
+	}
+	stubBlockchainHook.IsSmartContractCalled = func(address []byte) bool {
+		return bytes.Equal(address, test.ParentAddress)
+	}
+	stubBlockchainHook.CurrentNonceCalled = func() uint64 {
+		return *currentBlock
+	}
+	return stubBlockchainHook
+}
+
+func nonceTestInput() *vmcommon.ContractCallInput {
+	input := test.DefaultTestContractCallInput()
+	input.GasProvided = 1000000
+	input.Function = get
+	input.CurrentTxHash = []byte("txHash")
+	return input
+}
+
+func TestNonces_SenderNonceRecorded(t *testing.T) {
+	currentBlock := uint64(10)
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(blockchainHookStubForNonces(7, &currentBlock)).
+		WithNonceHandling(arwen.NonceHandlingEnabled).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	input := nonceTestInput()
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+	require.Equal(t, uint64(8), vmOutput.OutputAccounts[string(test.UserAddress)].Nonce)
+
+	vmOutput, err = host.RunSmartContractCall(input)
+	require.Nil(t, vmOutput)
+	require.Equal(t, arwen.ErrDuplicatedTransaction, err)
+
+	input.CurrentTxHash = []byte("otherTxHash")
+	vmOutput, err = host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+
+	currentBlock++
+	input.CurrentTxHash = []byte("txHash")
+	vmOutput, err = host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+}
+
+func TestNonces_CheckOnly(t *testing.T) {
+	currentBlock := uint64(10)
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(blockchainHookStubForNonces(7, &currentBlock)).
+		WithNonceHandling(arwen.NonceHandlingCheck).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	input := nonceTestInput()
+	for i := 0; i < 2; i++ {
+		vmOutput, err := host.RunSmartContractCall(input)
+		test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+		senderAccount, ok := vmOutput.OutputAccounts[string(test.UserAddress)]
+		require.True(t, !ok || senderAccount.Nonce == 0)
+	}
+}
+
+func TestNonces_SenderNonceOverflow(t *testing.T) {
+	currentBlock := uint64(10)
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(blockchainHookStubForNonces(math.MaxUint64, &currentBlock)).
+		WithNonceHandling(arwen.NonceHandlingEnabled).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	vmOutput, err := host.RunSmartContractCall(nonceTestInput())
+	require.Nil(t, vmOutput)
+	require.Equal(t, arwen.ErrSenderNonceOverflow, err)
+}
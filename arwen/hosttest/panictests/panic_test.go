@@ -2,6 +2,7 @@ package panictests
 
 import (
 	"math/big"
+	"strings"
 	"sync"
 	"testing"
 	"time"
@@ -39,14 +40,16 @@ func TestExecution_PanicInGoWithSilentWasmer_SIGSEGV(t *testing.T) {
 		WithFunction(increment).
 		Build()
 
-	// Ensure that no more panic
+	// Ensure that the panic in the VM hook is converted into an internal VM error
 	defer func() {
 		r := recover()
 		require.Nil(t, r)
 	}()
 
-	_, err := host.RunSmartContractCall(input)
-	require.Equal(t, err, arwen.ErrExecutionPanicked)
+	vmOutput, err := host.RunSmartContractCall(input)
+	require.Nil(t, err)
+	require.Equal(t, arwen.InternalVMError, vmOutput.ReturnCode)
+	require.True(t, strings.HasPrefix(vmOutput.ReturnMessage, arwen.ErrInternalVMError.Error()))
 }
 
 func TestExecution_PanicInGoWithSilentWasmer_SIGFPE(t *testing.T) {
@@ -72,15 +75,16 @@ func TestExecution_PanicInGoWithSilentWasmer_SIGFPE(t *testing.T) {
 		WithFunction(increment).
 		Build()
 
-	// Ensure that host.RunSmartContractCall() still panics, but the panic is a
-	// wrapped error.
+	// Ensure that the panic in the VM hook is converted into an internal VM error
 	defer func() {
 		r := recover()
 		require.Nil(t, r)
 	}()
 
-	_, err := host.RunSmartContractCall(input)
-	require.Equal(t, err, arwen.ErrExecutionPanicked)
+	vmOutput, err := host.RunSmartContractCall(input)
+	require.Nil(t, err)
+	require.Equal(t, arwen.InternalVMError, vmOutput.ReturnCode)
+	require.True(t, strings.HasPrefix(vmOutput.ReturnMessage, arwen.ErrInternalVMError.Error()))
 }
 
 func TestExecution_PanicInGoWithSilentWasmer_Timeout(t *testing.T) {
@@ -0,0 +1,57 @@
+package hosttest
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+func runReadOnlyCallTest(t *testing.T, function string, assertResults func(*worldmock.MockWorld, *test.VMOutputVerifier)) {
+	testConfig := makeTestConfig()
+
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.ExecReadOnlyParentMock),
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.ReadOnlyChildMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("execReadOnly").
+			WithArguments(test.ChildAddress, []byte(function)).
+			Build()).
+		AndAssertResults(assertResults)
+}
+
+func TestExecution_ExecuteReadOnly_ReturnsFinishData(t *testing.T) {
+	runReadOnlyCallTest(t, "view", func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+		verify.Ok().
+			ReturnData(contracts.ReadOnlyViewResult)
+	})
+}
+
+func TestExecution_ExecuteReadOnly_ForbidsStateChanges(t *testing.T) {
+	forbiddenChanges := map[string]string{
+		"write":       arwen.ErrCannotWriteOnReadOnly.Error(),
+		"transfer":    arwen.ErrInvalidCallOnReadOnlyMode.Error(),
+		"asyncCall":   arwen.ErrInvalidCallOnReadOnlyMode.Error(),
+		"nestedWrite": arwen.ErrCannotWriteOnReadOnly.Error(),
+	}
+	for function, expectedError := range forbiddenChanges {
+		runReadOnlyCallTest(t, function, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.ExecutionFailed().
+				ReturnMessage("Return value -1").
+				HasRuntimeErrors(expectedError).
+				Storage()
+		})
+	}
+}
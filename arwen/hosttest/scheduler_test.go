@@ -0,0 +1,71 @@
+package hosttest
+
+import (
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestBlockScheduler_ExecutesEndpointsAtScheduledBlocks(t *testing.T) {
+	testConfig := makeTestConfig()
+
+	firstKey := []byte("first")
+	secondKey := []byte("second")
+
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.StoreBlockNonce, contracts.LoadStore)).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("loadStore").
+			WithArguments(secondKey).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			scheduler := test.NewBlockScheduler(host, world)
+			scheduler.RoundsPerEpoch = 4
+
+			blocksSeen := make([]uint64, 0)
+			scheduler.AddBlockListener(func(blockNonce uint64) {
+				blocksSeen = append(blocksSeen, blockNonce)
+			})
+
+			require.Nil(t, scheduler.ScheduleEndpoint(5, test.UserAddress, test.ParentAddress, "storeBlockNonce", testConfig.GasProvided, secondKey))
+			require.Nil(t, scheduler.ScheduleEndpoint(3, test.UserAddress, test.ParentAddress, "storeBlockNonce", testConfig.GasProvided, firstKey))
+			require.Nil(t, scheduler.ScheduleEndpoint(3, test.UserAddress, test.ParentAddress, "storeBlockNonce", testConfig.GasProvided))
+			require.Equal(t, 3, scheduler.NumScheduledCalls())
+
+			results := scheduler.AdvanceBlocks(4)
+			require.Equal(t, []uint64{1, 2, 3, 4}, blocksSeen)
+			require.Len(t, results, 2)
+			require.Nil(t, results[0].Err)
+			require.Equal(t, vmcommon.Ok, results[0].VMOutput.ReturnCode)
+			require.Equal(t, vmcommon.UserError, results[1].VMOutput.ReturnCode)
+			require.Equal(t, 1, scheduler.NumScheduledCalls())
+			require.Equal(t, []byte{3}, world.AcctMap.GetAccount(test.ParentAddress).StorageValue(string(firstKey)))
+			require.Equal(t, uint32(1), world.CurrentEpoch())
+
+			err := scheduler.ScheduleEndpoint(4, test.UserAddress, test.ParentAddress, "storeBlockNonce", testConfig.GasProvided, firstKey)
+			require.Equal(t, test.ErrCallScheduledInThePast, err)
+
+			results = scheduler.AdvanceToBlock(6)
+			require.Len(t, results, 1)
+			require.Equal(t, uint64(5), results[0].Call.BlockNonce)
+			require.Equal(t, 0, scheduler.NumScheduledCalls())
+			require.Equal(t, uint64(6), world.CurrentNonce())
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.
+				Ok().
+				ReturnData([]byte{5})
+		})
+	require.Nil(t, err)
+}
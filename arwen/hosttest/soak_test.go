@@ -0,0 +1,40 @@
+package hosttest
+
+import (
+	"testing"
+	"time"
+
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func Test_SoakRandomBlocks(t *testing.T) {
+	if testing.Short() {
+		t.Skip("not a short test")
+	}
+
+	config := test.SoakConfig{
+		Seed:               time.Now().UnixNano(),
+		NumBlocks:          1000,
+		MaxTxsPerBlock:     30,
+		NumUsers:           12,
+		NumTokens:          3,
+		CheckpointInterval: 50,
+	}
+	t.Logf("soak seed %d", config.Seed)
+
+	warmCheckpoints := test.NewSoakHarness(t, config).Run()
+	rerunCheckpoints := test.NewSoakHarness(t, config).Run()
+	config.RestartHostAtCheckpoints = true
+	restartedCheckpoints := test.NewSoakHarness(t, config).Run()
+
+	require.Len(t, warmCheckpoints, 20)
+	require.Len(t, rerunCheckpoints, len(warmCheckpoints))
+	require.Len(t, restartedCheckpoints, len(warmCheckpoints))
+	for i, checkpoint := range warmCheckpoints {
+		require.True(t, checkpoint.Equal(rerunCheckpoints[i]),
+			"nondeterministic execution before block %d, seed %d", checkpoint.BlockNonce, config.Seed)
+		require.True(t, checkpoint.Equal(restartedCheckpoints[i]),
+			"restarted host diverged before block %d, seed %d", checkpoint.BlockNonce, config.Seed)
+	}
+}
@@ -122,6 +122,83 @@ func computeExpectedGasForGetStorage(key []byte, value []byte) uint64 {
 	return expectedUsedGas
 }
 
+const legacyVersionEndEpoch = uint32(100)
+
+// versionedStorageLoads are synthetic storage loads on both sides of the end
+// epoch of the legacy VM version; they verify that the version is selected
+// by the epoch of the block, not that recorded transactions replay identically
+var versionedStorageLoads = []struct {
+	name  string
+	epoch uint32
+	key   []byte
+}{
+	{name: "genesis small key", epoch: 0, key: smallKey},
+	{name: "legacy big key", epoch: legacyVersionEndEpoch - 1, key: bigKey},
+	{name: "current small key", epoch: legacyVersionEndEpoch, key: smallKey},
+	{name: "current big key", epoch: legacyVersionEndEpoch + 1, key: bigKey},
+}
+
+func TestGasUsed_LoadStorage_VersionSchedule(t *testing.T) {
+	schedule := arwen.VMVersionSchedule{
+		{StartEpoch: 0, Version: arwen.NewLegacyVMVersion()},
+		{StartEpoch: legacyVersionEndEpoch, Version: arwen.NewCurrentVMVersion(worldmock.EnableEpochsHandlerStubAllFlags())},
+	}
+
+	for _, tx := range versionedStorageLoads {
+		t.Run(tx.name, func(t *testing.T) {
+			testConfig := makeTestConfig()
+			value := []byte("testValue")
+
+			expectedUsedGas := computeExpectedGasForGetStorage(tx.key, value)
+			if tx.epoch < legacyVersionEndEpoch {
+				expectedUsedGas = computeExpectedGasForLegacyGetStorage(tx.key, value)
+			}
+
+			test.BuildMockInstanceCallTest(t).
+				WithContracts(
+					test.CreateMockContract(test.ParentAddress).
+						WithBalance(0).
+						WithConfig(nil).
+						WithMethods(contracts.LoadStore)).
+				WithInput(test.CreateTestContractCallInputBuilder().
+					WithRecipientAddr(test.ParentAddress).
+					WithGasProvided(testConfig.GasProvided).
+					WithFunction("loadStore").
+					WithArguments(tx.key).
+					Build()).
+				WithVMVersionSchedule(schedule).
+				WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+					world.SetCurrentBlockInfo(1, 1, tx.epoch, 0, nil)
+					setZeroCodeCosts(host)
+					host.Metering().GasSchedule().ElrondAPICost.StorageLoad = storageLoadGas
+					host.Metering().GasSchedule().ElrondAPICost.CachedStorageLoad = cachedStorageLoadGas
+					host.Metering().GasSchedule().BaseOperationCost.DataCopyPerByte = dataCopyGas
+					host.Metering().GasSchedule().BaseOperationCost.PersistPerByte = 0
+
+					accountHandler, _ := world.GetUserAccount(test.ParentAddress)
+					(accountHandler.(*worldmock.Account)).Storage[string(tx.key)] = value
+				}).
+				AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+					verify.
+						Ok().
+						GasRemaining(testConfig.GasProvided-expectedUsedGas).
+						GasUsed(test.ParentAddress, expectedUsedGas).
+						ReturnData(value)
+				})
+		})
+	}
+}
+
+// the legacy semantics price the second, cached, load the same as the first one
+func computeExpectedGasForLegacyGetStorage(key []byte, value []byte) uint64 {
+	extraBytesForKey := len(key) - arwen.AddressLen
+	if extraBytesForKey < 0 {
+		extraBytesForKey = 0
+	}
+
+	return 2 * (storageLoadGas + uint64(len(value))*dataCopyGas + uint64(extraBytesForKey)*dataCopyGas)
+}
+
 func TestGasUsed_SetStorage_FlagEnabled(t *testing.T) {
 	setStorage(t, smallKey)
 }
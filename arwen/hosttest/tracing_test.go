@@ -0,0 +1,196 @@
+package hosttest
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/ElrondNetwork/wasm-vm/tracing"
+	"github.com/stretchr/testify/require"
+)
+
+func TestTracing_GasFlamegraph(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+	tracer := tracing.NewTracer(tracing.Config{GasFlamegraph: true})
+
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(test.BlockchainHookStubForCall(code, nil)).
+		WithExecutionTracer(tracer).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+	require.Equal(t, tracer, host.ExecutionTracer())
+
+	input := test.DefaultTestContractCallInput()
+	input.GasProvided = 1000000
+	input.Function = get
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+
+	contractFrame := tracing.FormatAddress(input.RecipientAddr) + "::" + get
+	stacks := tracer.GasFlamegraph().FoldedStacks()
+	require.NotZero(t, stacks[contractFrame])
+	require.NotZero(t, stacks[contractFrame+";smallIntFinishSigned"])
+
+	totalGas := uint64(0)
+	for _, gas := range stacks {
+		totalGas += gas
+	}
+	require.Equal(t, input.GasProvided-vmOutput.GasRemaining, totalGas)
+
+	var folded bytes.Buffer
+	err = tracer.GasFlamegraph().WriteFolded(&folded)
+	require.Nil(t, err)
+	for _, line := range strings.Split(strings.TrimSpace(folded.String()), "\n") {
+		require.True(t, strings.HasPrefix(line, contractFrame), line)
+	}
+}
+
+func TestTracing_DebugPrint(t *testing.T) {
+	runDebugPrint := func(debugMode bool) (*tracing.Tracer, uint64) {
+		tracer := tracing.NewTracer(tracing.Config{DebugMessages: true})
+		gasRemaining := uint64(0)
+		test.BuildMockInstanceCallTest(t).
+			WithContracts(
+				test.CreateMockContract(test.ParentAddress).
+					WithBalance(0).
+					WithMethods(func(instance *contextmock.InstanceMock, config interface{}) {
+						instance.AddMockMethod("print", func() *contextmock.InstanceMock {
+							elrondapi.DebugPrintWithTypedArgs(instance.Host, []byte("reached print"))
+							return instance
+						})
+					})).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithRecipientAddr(test.ParentAddress).
+				WithGasProvided(test.GasProvided).
+				WithFunction("print").
+				Build()).
+			WithExecutionTracer(tracer).
+			WithDebugMode(debugMode).
+			AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+				verify.Ok()
+				gasRemaining = verify.VmOutput.GasRemaining
+			})
+		return tracer, gasRemaining
+	}
+
+	tracer, gasRemainingInDebugMode := runDebugPrint(true)
+	require.Len(t, tracer.DebugMessages(), 1)
+	require.Equal(t, test.ParentAddress, tracer.DebugMessages()[0].Address)
+	require.Equal(t, []byte("reached print"), tracer.DebugMessages()[0].Message)
+
+	tracer, gasRemaining := runDebugPrint(false)
+	require.Empty(t, tracer.DebugMessages())
+	require.Equal(t, gasRemainingInDebugMode, gasRemaining)
+}
+
+func TestTracing_GasPhases(t *testing.T) {
+	tracer := tracing.NewTracer(tracing.Config{GasFlamegraph: true})
+	input := test.CreateTestContractCallInputBuilder().
+		WithRecipientAddr(test.ParentAddress).
+		WithGasProvided(test.GasProvided).
+		WithFunction("phases").
+		Build()
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(0).
+				WithMethods(func(instance *contextmock.InstanceMock, config interface{}) {
+					instance.AddMockMethod("phases", func() *contextmock.InstanceMock {
+						host := instance.Host
+						elrondapi.GasPhaseWithHost(host, int32(arwen.GasPhaseBody))
+						elrondapi.GasPhaseWithHost(host, int32(arwen.GasPhaseStorage))
+						elrondapi.StorageStoreWithTypedArgs(host, test.ParentKeyA, test.ParentDataA)
+						elrondapi.GasPhaseWithHost(host, int32(arwen.GasPhaseFinish))
+						host.Output().Finish([]byte("phases"))
+						return instance
+					})
+				})).
+		WithInput(input).
+		WithExecutionTracer(tracer).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok().
+				ReturnData([]byte("phases"))
+
+			contractFrame := tracing.FormatAddress(test.ParentAddress) + "::phases"
+			stacks := tracer.GasFlamegraph().FoldedStacks()
+			require.NotZero(t, stacks[contractFrame+";[body];gasPhase"])
+			require.NotZero(t, stacks[contractFrame+";[storage];storageStore"])
+			require.NotZero(t, stacks[contractFrame+";[storage];gasPhase"])
+
+			totalGas := uint64(0)
+			for _, gas := range stacks {
+				totalGas += gas
+			}
+			require.Equal(t, input.GasProvided-verify.VmOutput.GasRemaining, totalGas)
+		})
+}
+
+func TestTracing_GasPhases_InvalidPhase(t *testing.T) {
+	test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(0).
+				WithMethods(func(instance *contextmock.InstanceMock, config interface{}) {
+					instance.AddMockMethod("phases", func() *contextmock.InstanceMock {
+						elrondapi.GasPhaseWithHost(instance.Host, 42)
+						return instance
+					})
+				})).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(test.GasProvided).
+			WithFunction("phases").
+			Build()).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.ExecutionFailed().
+				HasRuntimeErrors(arwen.ErrInvalidGasPhase.Error())
+		})
+}
+
+func TestTracing_LeakedHandles(t *testing.T) {
+	runLeak := func(debugMode bool) *tracing.Tracer {
+		tracer := tracing.NewTracer(tracing.Config{LeakedHandles: true})
+		test.BuildMockInstanceCallTest(t).
+			WithContracts(
+				test.CreateMockContract(test.ParentAddress).
+					WithBalance(0).
+					WithMethods(func(instance *contextmock.InstanceMock, config interface{}) {
+						instance.AddMockMethod("leak", func() *contextmock.InstanceMock {
+							managedTypes := instance.Host.ManagedTypes()
+							dropped := managedTypes.NewManagedBufferFromBytes([]byte("abc"))
+							managedTypes.NewManagedBufferFromBytes([]byte("leaks"))
+							managedTypes.NewBigIntFromInt64(7)
+							managedTypes.DropManagedBuffer(dropped)
+							return instance
+						})
+					})).
+			WithInput(test.CreateTestContractCallInputBuilder().
+				WithRecipientAddr(test.ParentAddress).
+				WithGasProvided(test.GasProvided).
+				WithFunction("leak").
+				Build()).
+			WithExecutionTracer(tracer).
+			WithDebugMode(debugMode).
+			AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+				verify.Ok()
+			})
+		return tracer
+	}
+
+	tracer := runLeak(true)
+	require.Len(t, tracer.LeakedHandles(), 1)
+	leaked := tracer.LeakedHandles()[0]
+	require.Equal(t, test.ParentAddress, leaked.Address)
+	require.Equal(t, "leak", leaked.Function)
+	require.Equal(t, arwen.ManagedHandleCounts{ManagedBuffers: 1, BigInts: 1, Bytes: 13}, leaked.ManagedHandleCounts)
+
+	tracer = runLeak(false)
+	require.Empty(t, tracer.LeakedHandles())
+}
@@ -0,0 +1,170 @@
+package hosttest
+
+import (
+	"math/big"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/contexts"
+	"github.com/ElrondNetwork/wasm-vm/mock/contracts"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestVMOutput_InsertionOrderSerializesIdentically(t *testing.T) {
+	addresses := [][]byte{test.ParentAddress, test.ChildAddress, test.UserAddress}
+	keys := [][]byte{[]byte("a"), []byte("b"), []byte("c")}
+	reversedAddresses := [][]byte{test.UserAddress, test.ChildAddress, test.ParentAddress}
+	reversedKeys := [][]byte{[]byte("c"), []byte("b"), []byte("a")}
+
+	first := makeVMOutputInOrder(addresses, keys)
+	second := makeVMOutputInOrder(reversedAddresses, reversedKeys)
+
+	unsortedFirst, err := contexts.MarshalVMOutput(first)
+	require.Nil(t, err)
+	unsortedSecond, err := contexts.MarshalVMOutput(second)
+	require.Nil(t, err)
+	require.NotEqual(t, unsortedFirst, unsortedSecond)
+
+	arwen.SortVMOutput(first)
+	arwen.SortVMOutput(second)
+	serializedFirst, err := contexts.MarshalVMOutput(first)
+	require.Nil(t, err)
+	serializedSecond, err := contexts.MarshalVMOutput(second)
+	require.Nil(t, err)
+	require.Equal(t, serializedFirst, serializedSecond)
+}
+
+// makeVMOutputInOrder fills the output accounts, their storage updates and
+// the account sets of a VMOutput in the given order of addresses and keys,
+// touching the first address twice
+func makeVMOutputInOrder(addresses [][]byte, keys [][]byte) *vmcommon.VMOutput {
+	vmOutput := &vmcommon.VMOutput{
+		ReturnCode:      vmcommon.Ok,
+		GasRefund:       big.NewInt(0),
+		OutputAccounts:  make(map[string]*vmcommon.OutputAccount),
+		DeletedAccounts: make([][]byte, 0),
+		TouchedAccounts: make([][]byte, 0),
+	}
+	for _, address := range addresses {
+		outputAccount := &vmcommon.OutputAccount{
+			Address:        address,
+			BalanceDelta:   big.NewInt(1),
+			StorageUpdates: make(map[string]*vmcommon.StorageUpdate),
+		}
+		for _, key := range keys {
+			outputAccount.StorageUpdates[string(key)] = &vmcommon.StorageUpdate{Offset: key, Data: address, Written: true}
+		}
+		vmOutput.OutputAccounts[string(address)] = outputAccount
+		vmOutput.DeletedAccounts = append(vmOutput.DeletedAccounts, address)
+		vmOutput.TouchedAccounts = append(vmOutput.TouchedAccounts, address)
+	}
+	vmOutput.TouchedAccounts = append(vmOutput.TouchedAccounts, addresses[0])
+	return vmOutput
+}
+
+func TestVMOutput_IdenticalExecutionsSerializeIdentically(t *testing.T) {
+	first := runSetStorageOnDestCtx(t)
+	for i := 0; i < 10; i++ {
+		require.Equal(t, first, runSetStorageOnDestCtx(t))
+	}
+}
+
+func TestVMOutput_AsyncCallsSerializeIdentically(t *testing.T) {
+	var serializedOutputs [][]byte
+	for i := 0; i < 5; i++ {
+		runFanOutAsyncCalls(t, arwen.AsyncLimits{}, 3, 4, func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok()
+			serialized, err := contexts.MarshalVMOutput(verify.VmOutput)
+			require.Nil(t, err)
+			serializedOutputs = append(serializedOutputs, serialized)
+		})
+	}
+
+	for _, serialized := range serializedOutputs[1:] {
+		require.Equal(t, serializedOutputs[0], serialized)
+	}
+}
+
+func TestDeterminismChecker_RepeatedExecutions(t *testing.T) {
+	scAddress := test.MakeTestSCAddress("counter")
+	code := test.GetTestSCCode("counter", "../../")
+	newWorld := func() *worldmock.MockWorld {
+		world := worldmock.NewMockWorld()
+		world.AcctMap.CreateSmartContractAccount(test.ParentAddress, scAddress, code, world)
+		return world
+	}
+	input := test.CreateTestContractCallInputBuilder().
+		WithRecipientAddr(scAddress).
+		WithGasProvided(100000).
+		WithFunction(increment).
+		Build()
+
+	for _, config := range []test.DeterminismCheckConfig{
+		{Repetitions: 4, NumHosts: 1, WarmInstances: true},
+		{Repetitions: 4, NumHosts: 1, WarmInstances: false},
+		{Repetitions: 3, NumHosts: 3, WarmInstances: true},
+	} {
+		checker := test.NewDeterminismChecker(t, config, newWorld)
+		records := checker.Run(input)
+		require.Len(t, records, config.Repetitions*config.NumHosts)
+		require.Contains(t, records[0].VMOutput, "return code: ok")
+		require.NotEmpty(t, records[0].CallGraph)
+		require.Empty(t, test.FindDivergences(records))
+
+		checker.RequireDeterministic(input)
+	}
+}
+
+func TestDeterminismChecker_FindDivergences(t *testing.T) {
+	records := []*test.ExecutionRecord{
+		{VMOutput: []string{"return code: ok", "gas remaining: 10"}, CallGraph: "{}"},
+		{Repetition: 1, VMOutput: []string{"return code: ok", "gas remaining: 10"}, CallGraph: "{}"},
+		{Repetition: 2, VMOutput: []string{"return code: ok", "gas remaining: 9"}, CallGraph: "{}"},
+		{Host: 1, VMOutput: []string{"return code: ok", "gas remaining: 10", "return data 0: 01"}, CallGraph: "{}", Error: "failed"},
+	}
+
+	divergences := test.FindDivergences(records)
+	require.Len(t, divergences, 3)
+	require.Equal(t, &test.Divergence{Repetition: 2, Field: "VMOutput", Expected: "gas remaining: 10", Actual: "gas remaining: 9"}, divergences[0])
+	require.Equal(t, &test.Divergence{Host: 1, Field: "error", Expected: "", Actual: "failed"}, divergences[1])
+	require.Equal(t, &test.Divergence{Host: 1, Field: "VMOutput", Expected: "<missing>", Actual: "return data 0: 01"}, divergences[2])
+}
+
+func runSetStorageOnDestCtx(t *testing.T) []byte {
+	testConfig := makeTestConfig()
+
+	var serialized []byte
+	_, err := test.BuildMockInstanceCallTest(t).
+		WithContracts(
+			test.CreateMockContract(test.ParentAddress).
+				WithBalance(testConfig.ParentBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.ParentSetStorageMock),
+			test.CreateMockContract(test.ChildAddress).
+				WithBalance(testConfig.ChildBalance).
+				WithConfig(testConfig).
+				WithMethods(contracts.ChildSetStorageMock),
+		).
+		WithInput(test.CreateTestContractCallInputBuilder().
+			WithRecipientAddr(test.ParentAddress).
+			WithGasProvided(testConfig.GasProvided).
+			WithFunction("parentSetStorage").
+			WithArguments([]byte{1}).
+			Build()).
+		WithSetup(func(host arwen.VMHost, world *worldmock.MockWorld) {
+			setZeroCodeCosts(host)
+		}).
+		AndAssertResults(func(world *worldmock.MockWorld, verify *test.VMOutputVerifier) {
+			verify.Ok()
+			require.Len(t, verify.VmOutput.OutputAccounts, 2)
+
+			var err error
+			serialized, err = contexts.MarshalVMOutput(verify.VmOutput)
+			require.Nil(t, err)
+		})
+	require.Nil(t, err)
+	return serialized
+}
@@ -0,0 +1,106 @@
+package hosttest
+
+import (
+	"bytes"
+	"math/big"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+var counterCodeHash = []byte("counterCodeHash.................")
+
+func blockchainHookStubForWarmUp(code []byte, compiledCodes map[string][]byte) *contextmock.BlockchainHookStub {
+	stubBlockchainHook := &contextmock.BlockchainHookStub{}
+	stubBlockchainHook.GetUserAccountCalled = func(scAddress []byte) (vmcommon.UserAccountHandler, error) {
+		if bytes.Equal(scAddress, test.ParentAddress) {
+			return &contextmock.StubAccount{
+				Address:  test.ParentAddress,
+				Balance:  big.NewInt(0),
+				CodeHash: counterCodeHash,
+			}, nil
+		}
+		return nil, test.ErrAccountNotFound
+	}
+	stubBlockchainHook.GetCodeCalled = func(account vmcommon.UserAccountHandler) []byte {
+		return code
+	}
+	stubBlockchainHook.GetCompiledCodeCalled = func(codeHash []byte) (bool, []byte) {
+		compiledCode, found := compiledCodes[string(codeHash)]
+		return found, compiledCode
+	}
+	stubBlockchainHook.SaveCompiledCodeCalled = func(codeHash []byte, compiledCode []byte) {
+		compiledCodes[string(codeHash)] = compiledCode
+	}
+	return stubBlockchainHook
+}
+
+func TestWarmUp_CompilesAndKeepsWarmInstances(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+	compiledCodes := make(map[string][]byte)
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(blockchainHookStubForWarmUp(code, compiledCodes)).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	unknownAddress := test.MakeTestSCAddress("unknown")
+	results := host.WarmUp([][]byte{test.ParentAddress, unknownAddress})
+	require.Len(t, results, 2)
+	require.Equal(t, test.ParentAddress, results[0].Address)
+	require.Equal(t, counterCodeHash, results[0].CodeHash)
+	require.Equal(t, arwen.InstanceFromBytecode, results[0].Source)
+	require.Nil(t, results[0].Err)
+	require.Equal(t, unknownAddress, results[1].Address)
+	require.ErrorIs(t, results[1].Err, arwen.ErrContractNotFound)
+	require.Contains(t, compiledCodes, string(counterCodeHash))
+
+	results = host.WarmUp([][]byte{test.ParentAddress})
+	require.Equal(t, arwen.InstanceFromWarmCache, results[0].Source)
+	require.Nil(t, results[0].Err)
+
+	statistics := host.CacheStatistics()
+	require.Equal(t, 1, statistics.WarmInstances)
+	require.Equal(t, uint64(1), statistics.WarmedUpContracts)
+	require.Zero(t, statistics.InstanceCreations[arwen.InstanceFromWarmCache])
+	require.Zero(t, statistics.CacheHitRatio())
+
+	input := test.DefaultTestContractCallInput()
+	input.GasProvided = 1000000
+	input.Function = get
+	vmOutput, err := host.RunSmartContractCall(input)
+	test.NewVMOutputVerifier(t, vmOutput, err).Ok()
+
+	statistics = host.CacheStatistics()
+	require.Equal(t, uint64(1), statistics.InstanceCreations[arwen.InstanceFromWarmCache])
+	require.Zero(t, statistics.InstanceCreations[arwen.InstanceFromBytecode])
+	require.Equal(t, 1.0, statistics.CacheHitRatio())
+}
+
+func TestWarmUp_FromCompiledCode(t *testing.T) {
+	code := test.GetTestSCCode("counter", "../../")
+	compiledCodes := make(map[string][]byte)
+	host := test.NewTestHostBuilder(t).
+		WithBlockchainHook(blockchainHookStubForWarmUp(code, compiledCodes)).
+		Build()
+	defer func() {
+		host.Reset()
+	}()
+
+	results := host.WarmUp([][]byte{test.ParentAddress})
+	require.Equal(t, arwen.InstanceFromBytecode, results[0].Source)
+
+	// the reset clears the warm instances, but the node keeps the compiled code
+	host.Reset()
+	require.Zero(t, host.CacheStatistics().WarmInstances)
+
+	results = host.WarmUp([][]byte{test.ParentAddress})
+	require.Equal(t, arwen.InstanceFromCompiledCode, results[0].Source)
+	require.Nil(t, results[0].Err)
+	require.Equal(t, 1, host.CacheStatistics().WarmInstances)
+}
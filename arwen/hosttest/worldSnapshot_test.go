@@ -0,0 +1,64 @@
+package hosttest
+
+import (
+	"errors"
+	"io/ioutil"
+	"math/big"
+	"path/filepath"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+func TestWorldSnapshot_ExportImportRoundTrip(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	user := world.AcctMap.CreateAccount(test.UserAddress, world)
+	user.Nonce = 7
+	user.Balance = big.NewInt(1000)
+
+	contract := world.AcctMap.CreateSmartContractAccount(test.UserAddress, test.ParentAddress, []byte("contract code"), world)
+	contract.SetCodeAndMetadata(contract.Code, &vmcommon.CodeMetadata{Upgradeable: true, Readable: true})
+	contract.Balance = big.NewInt(50)
+	contract.DeveloperReward = big.NewInt(3)
+	contract.Storage["reserve"] = []byte{0x01, 0x02}
+	contract.Storage["deleted"] = []byte{}
+	world.AcctMap.CreateAccount(test.ChildAddress, world)
+
+	path := filepath.Join(t.TempDir(), "snapshot.json")
+	err := world.ExportAccountsToFile(path, test.ParentAddress, test.UserAddress)
+	require.Nil(t, err)
+
+	imported := worldmock.NewMockWorld()
+	err = imported.ImportAccountsFromFile(path)
+	require.Nil(t, err)
+	require.Len(t, imported.AcctMap, 2)
+
+	importedContract := imported.AcctMap.GetAccount(test.ParentAddress)
+	require.Equal(t, contract.Code, importedContract.Code)
+	require.Equal(t, contract.CodeHash, importedContract.CodeHash)
+	require.Equal(t, contract.CodeMetadata, importedContract.CodeMetadata)
+	require.Equal(t, test.UserAddress, importedContract.OwnerAddress)
+	require.Equal(t, big.NewInt(3), importedContract.DeveloperReward)
+	require.True(t, importedContract.IsSmartContract)
+	require.Equal(t, map[string][]byte{"reserve": {0x01, 0x02}}, importedContract.Storage)
+
+	delete(world.AcctMap, string(test.ChildAddress))
+	require.Equal(t, world.ComputeStateHash(), imported.ComputeStateHash())
+}
+
+func TestWorldSnapshot_ImportInvalidSnapshot(t *testing.T) {
+	world := worldmock.NewMockWorld()
+
+	_, err := world.ExportAccounts(test.ParentAddress)
+	require.True(t, errors.Is(err, worldmock.ErrInvalidSnapshot))
+
+	path := filepath.Join(t.TempDir(), "snapshot.json")
+	err = ioutil.WriteFile(path, []byte(`{"accounts": [{"address": "0102", "balance": "10", "storage": {"zz": "01"}}]}`), 0644)
+	require.Nil(t, err)
+	err = world.ImportAccountsFromFile(path)
+	require.True(t, errors.Is(err, worldmock.ErrInvalidSnapshot))
+	require.Empty(t, world.AcctMap)
+}
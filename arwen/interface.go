@@ -4,6 +4,7 @@ import (
 	"crypto/elliptic"
 	"io"
 	"math/big"
+	"time"
 
 	"github.com/ElrondNetwork/elrond-go-core/data/esdt"
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
@@ -11,6 +12,7 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/config"
 	"github.com/ElrondNetwork/wasm-vm/crypto"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
 )
 
 // StateStack defines the functionality for working with a state stack
@@ -28,6 +30,93 @@ type CallArgsParser interface {
 	IsInterfaceNil() bool
 }
 
+// EnableEpochsHandler is the vmcommon.EnableEpochsHandler of the node, also
+// telling from which epochs the features added to the VM after genesis are
+// active; an epoch of 0 activates a feature from genesis
+type EnableEpochsHandler interface {
+	vmcommon.EnableEpochsHandler
+	ManagedBufferFunctionsEnableEpoch() uint32
+	IsManagedBufferFunctionsFlagEnabled() bool
+	PromisesFunctionsEnableEpoch() uint32
+	IsPromisesFunctionsFlagEnabled() bool
+	ManagedCryptoFunctionsEnableEpoch() uint32
+	IsManagedCryptoFunctionsFlagEnabled() bool
+	ContractAddressFunctionsEnableEpoch() uint32
+	IsContractAddressFunctionsFlagEnabled() bool
+	StorageDeleteFunctionsEnableEpoch() uint32
+	IsStorageDeleteFunctionsFlagEnabled() bool
+	DebugPrintFunctionsEnableEpoch() uint32
+	IsDebugPrintFunctionsFlagEnabled() bool
+	ChunkedFinishFunctionsEnableEpoch() uint32
+	IsChunkedFinishFunctionsFlagEnabled() bool
+	NFTMetadataFunctionsEnableEpoch() uint32
+	IsNFTMetadataFunctionsFlagEnabled() bool
+	NFTRoyaltiesFunctionsEnableEpoch() uint32
+	IsNFTRoyaltiesFunctionsFlagEnabled() bool
+	ArgumentSliceFunctionsEnableEpoch() uint32
+	IsArgumentSliceFunctionsFlagEnabled() bool
+	EscrowFunctionsEnableEpoch() uint32
+	IsEscrowFunctionsFlagEnabled() bool
+	SignExtensionEnableEpoch() uint32
+	IsSignExtensionFlagEnabled() bool
+	BulkMemoryEnableEpoch() uint32
+	IsBulkMemoryFlagEnabled() bool
+	ReferenceTypesEnableEpoch() uint32
+	IsReferenceTypesFlagEnabled() bool
+	VersionedAsyncContextEnableEpoch() uint32
+	IsVersionedAsyncContextFlagEnabled() bool
+	EndpointCallabilityEnableEpoch() uint32
+	IsEndpointCallabilityFlagEnabled() bool
+	ContractBuildInfoLogEnableEpoch() uint32
+	IsContractBuildInfoLogFlagEnabled() bool
+	GasPhaseFunctionsEnableEpoch() uint32
+	IsGasPhaseFunctionsFlagEnabled() bool
+	ContractDeletionEnableEpoch() uint32
+	IsContractDeletionFlagEnabled() bool
+	ManagedHandleDropFunctionsEnableEpoch() uint32
+	IsManagedHandleDropFunctionsFlagEnabled() bool
+	AsyncCallSubGroupsEnableEpoch() uint32
+	IsAsyncCallSubGroupsFlagEnabled() bool
+	AsyncCallsCanonicalOrderEnableEpoch() uint32
+	IsAsyncCallsCanonicalOrderFlagEnabled() bool
+	PromiseHandleFunctionsEnableEpoch() uint32
+	IsPromiseHandleFunctionsFlagEnabled() bool
+	ArgumentSchemaEnableEpoch() uint32
+	IsArgumentSchemaFlagEnabled() bool
+	AsyncCallValidationEnableEpoch() uint32
+	IsAsyncCallValidationFlagEnabled() bool
+	GasRefundFunctionsEnableEpoch() uint32
+	IsGasRefundFunctionsFlagEnabled() bool
+	TypedErrorFunctionsEnableEpoch() uint32
+	IsTypedErrorFunctionsFlagEnabled() bool
+	StorageLimitsEnableEpoch() uint32
+	IsStorageLimitsFlagEnabled() bool
+	CallbackDeduplicationEnableEpoch() uint32
+	IsCallbackDeduplicationFlagEnabled() bool
+	PaymentTokensEnableEpoch() uint32
+	IsPaymentTokensFlagEnabled() bool
+	StorageMigrationFunctionsEnableEpoch() uint32
+	IsStorageMigrationFunctionsFlagEnabled() bool
+	MemoryLimitReturnCodeEnableEpoch() uint32
+	IsMemoryLimitReturnCodeFlagEnabled() bool
+	LockedValueLedgerEnableEpoch() uint32
+	IsLockedValueLedgerFlagEnabled() bool
+	ErrorCodeLogTopicEnableEpoch() uint32
+	IsErrorCodeLogTopicFlagEnabled() bool
+	StorageHookErrorsEnableEpoch() uint32
+	IsStorageHookErrorsFlagEnabled() bool
+	GuardedAccountsEnableEpoch() uint32
+	IsGuardedAccountsFlagEnabled() bool
+	OwnershipTransferLogEnableEpoch() uint32
+	IsOwnershipTransferLogFlagEnabled() bool
+	LegacyCallbackNoOpEnableEpoch() uint32
+	IsLegacyCallbackNoOpFlagEnabled() bool
+	SortedAccountSetsEnableEpoch() uint32
+	IsSortedAccountSetsFlagEnabled() bool
+	DetailedContractInvalidErrorsEnableEpoch() uint32
+	IsDetailedContractInvalidErrorsFlagEnabled() bool
+}
+
 // VMHost defines the functionality for working with the VM
 type VMHost interface {
 	vmcommon.VMExecutionHandler
@@ -39,10 +128,23 @@ type VMHost interface {
 	Output() OutputContext
 	Metering() MeteringContext
 	Storage() StorageContext
-	EnableEpochsHandler() vmcommon.EnableEpochsHandler
+	EnableEpochsHandler() EnableEpochsHandler
+	VMVersion() VMVersion
+	Metrics() MetricsSink
+	GasValueConverter() GasValueConverter
+	ExecutionTracer() ExecutionTracer
+	IsDebugMode() bool
+	SetExecutionPaused(paused bool)
+	IsExecutionPaused() bool
+	WarmUp(addresses [][]byte) []*WarmUpResult
+	CacheStatistics() CacheStatistics
+	UpdateConfig(gasSchedule config.GasScheduleMap, enableEpochsHandler vmcommon.EnableEpochsHandler) error
+	StorageAccesses() *StorageAccessSet
+	HostFunctionCatalog() []HostFunctionInfo
 
 	ExecuteESDTTransfer(destination []byte, sender []byte, esdtTransfers []*vmcommon.ESDTTransfer, callType vm.CallType) (*vmcommon.VMOutput, uint64, error)
 	CreateNewContract(input *vmcommon.ContractCreateInput) ([]byte, error)
+	CreateNewDeterministicContract(input *vmcommon.ContractCreateInput, salt []byte) ([]byte, error)
 	ExecuteOnSameContext(input *vmcommon.ContractCallInput) error
 	ExecuteOnDestContext(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, bool, error)
 	IsBuiltinFunctionName(functionName string) bool
@@ -64,6 +166,8 @@ type BlockchainContext interface {
 	StateStack
 
 	NewAddress(creatorAddress []byte) ([]byte, error)
+	ComputeContractAddress(deployerAddress []byte, nonce uint64) ([]byte, error)
+	ComputeDeterministicAddress(deployerAddress []byte, salt []byte, codeHash []byte) ([]byte, error)
 	AccountExists(addr []byte) bool
 	GetBalance(addr []byte) []byte
 	GetBalanceBigInt(addr []byte) *big.Int
@@ -111,6 +215,7 @@ type RuntimeContext interface {
 	SetVMInput(vmInput *vmcommon.ContractCallInput)
 	GetContextAddress() []byte
 	SetCodeAddress(scAddress []byte)
+	GetCodeAddress() []byte
 	GetSCCode() ([]byte, error)
 	GetSCCodeSize() uint64
 	GetVMType() []byte
@@ -118,6 +223,7 @@ type RuntimeContext interface {
 	Arguments() [][]byte
 	GetCurrentTxHash() []byte
 	GetOriginalTxHash() []byte
+	GetOriginalCallerAddress() []byte
 	ExtractCodeUpgradeFromArgs() ([]byte, []byte, error)
 	SignalUserError(message string)
 	FailExecution(err error)
@@ -127,11 +233,17 @@ type RuntimeContext interface {
 	GetInstanceStackSize() uint64
 	CountSameContractInstancesOnStack(address []byte) uint64
 	IsFunctionImported(name string) bool
+	EndpointCallability(functionName string) (inspect.Callability, bool)
 	ReadOnly() bool
 	SetReadOnly(readOnly bool)
 	StartWasmerInstance(contract []byte, gasLimit uint64, newCode bool) error
 	ClearWarmInstanceCache()
+	InfrastructureError() error
 	SetMaxInstanceStackSize(uint64)
+	SetDeterminismChecks(checks DeterminismChecks)
+	SetMeteringExemptContracts(addresses [][]byte)
+	SetEnableEpochsHandler(enableEpochsHandler EnableEpochsHandler)
+	MeteringMode() executor.MeteringMode
 	VerifyContractCode() error
 	GetInstance() executor.Instance
 	FunctionNameChecked() (string, error)
@@ -149,6 +261,8 @@ type RuntimeContext interface {
 	ManagedBufferAPIErrorShouldFailExecution() bool
 	CleanInstance()
 	NumRunningInstances() (int, int)
+	WarmUpContract(address []byte) (InstanceSource, []byte, error)
+	CacheStatistics() CacheStatistics
 
 	AddError(err error, otherInfo ...string)
 	GetAllErrors() error
@@ -198,6 +312,10 @@ type ManagedTypesContext interface {
 	InsertSlice(mBufferHandle int32, startPosition int32, slice []byte) ([]byte, error)
 	ReadManagedVecOfManagedBuffers(managedVecHandle int32) ([][]byte, uint64, error)
 	WriteManagedVecOfManagedBuffers(data [][]byte, destinationHandle int32)
+	SetMaxAllocation(maxAllocation uint64)
+	DropManagedBuffer(mBufferHandle int32) bool
+	DropBigInt(handle int32) bool
+	LiveHandles() ManagedHandleCounts
 }
 
 // OutputContext defines the functionality needed for interacting with the output context
@@ -211,6 +329,7 @@ type OutputContext interface {
 	GetOutputAccounts() map[string]*vmcommon.OutputAccount
 	DeleteOutputAccount(address []byte)
 	WriteLog(address []byte, topics [][]byte, data []byte)
+	WriteLogWithIdentifier(address []byte, topics [][]byte, data []byte, identifier []byte)
 	TransferValueOnly(destination []byte, sender []byte, value *big.Int, checkPayable bool) error
 	Transfer(destination []byte, sender []byte, gasLimit uint64, gasLocked uint64, value *big.Int, asyncData []byte, input []byte, callType vm.CallType) error
 	TransferESDT(destination []byte, sender []byte, transfers []*vmcommon.ESDTTransfer, callInput *vmcommon.ContractCallInput) (uint64, error)
@@ -226,6 +345,14 @@ type OutputContext interface {
 	Finish(data []byte)
 	PrependFinish(data []byte)
 	DeleteFirstReturnData()
+	SetMaxReturnDataSize(maxReturnDataSize uint64)
+	CheckReturnDataSize(length uint64) error
+	SetLogLimits(maxNumberOfLogs uint64, maxLogSize uint64)
+	CheckLogSize(size uint64) error
+	SetTransferBundling(bundle bool)
+	SetTypedError(code []byte, payload []byte)
+	ReserveFinish(length uint64) error
+	AppendFinish(data []byte) error
 	GetVMOutput() *vmcommon.VMOutput
 	RemoveNonUpdatedStorage()
 	AddTxValueToAccount(address []byte, value *big.Int)
@@ -299,12 +426,25 @@ type StorageContext interface {
 	GetStorage(key []byte) ([]byte, bool)
 	GetStorageUnmetered(key []byte) ([]byte, bool)
 	SetStorage(key []byte, value []byte) (StorageStatus, error)
+	DeleteStorage(key []byte) (StorageStatus, error)
 	SetProtectedStorage(key []byte, value []byte) (StorageStatus, error)
 	SetProtectedStorageToAddress(address []byte, key []byte, value []byte) (StorageStatus, error)
 	SetProtectedStorageToAddressUnmetered(address []byte, key []byte, value []byte) (StorageStatus, error)
 	UseGasForStorageLoad(tracedFunctionName string, blockChainLoadCost uint64, usedCache bool)
 	IsUseDifferentGasCostFlagSet() bool
 	GetVmProtectedPrefix(prefix string) []byte
+	GetStorageSize(address []byte) (uint64, error)
+	GetStorageAccesses() *StorageAccessSet
+	CommitWrites()
+	PopMergeActiveState()
+	SetStorageLimits(limits StorageLimits)
+}
+
+// StorageSizeHook is implemented by the blockchain hooks which know the
+// number of bytes stored by each account, keys and values included, so that
+// the node can apply rent-style policies to the storage of the accounts
+type StorageSizeHook interface {
+	GetStorageSize(address []byte) (uint64, error)
 }
 
 // AsyncCallInfoHandler defines the functionality for working with AsyncCallInfo
@@ -325,6 +465,10 @@ type AsyncContext interface {
 	IsComplete() bool
 	GetCallGroup(groupID string) (*AsyncCallGroup, bool)
 	SetContextCallback(callbackName string, data []byte, gas uint64) error
+	SetGroupCallback(groupID string, successCallback string, errorCallback string, data []byte, gas uint64) error
+	AddSubGroup(parentGroupID string, groupID string) error
+	CreatePromiseGroup(groupID []byte) (PromiseGroupHandle, error)
+	RegisterPromise(groupHandle PromiseGroupHandle, call *AsyncCall) (PromiseID, error)
 	HasCallback() bool
 	GetCallerAddress() []byte
 	GetCallerCallID() []byte
@@ -342,6 +486,8 @@ type AsyncContext interface {
 	GetCallID() []byte
 	GetCallbackAsyncInitiatorCallID() []byte
 	IsCrossShard() bool
+	SetProtectRegisteredCallbacks(protect bool)
+	IsRegisteredCallback(functionName string) bool
 
 	Clone() AsyncContext
 
@@ -370,6 +516,7 @@ type AsyncContext interface {
 
 	SetCallbackParentCall(asyncCall *AsyncCall)
 	GetCallbackClosure() ([]byte, error)
+	GetLockedValue(tokenIdentifier []byte, nonce uint64) *big.Int
 
 	GetAsyncCallByCallID(callID []byte) AsyncCallLocation
 	LoadParentContextFromStackOrStorage() (AsyncContext, error)
@@ -402,3 +549,111 @@ type GasTracing interface {
 	GetGasTrace() map[string]map[string][]uint64
 	IsInterfaceNil() bool
 }
+
+// ExecutionTracer is notified of the beginning and of the end of every
+// contract call, top-level and nested alike, so that the structure of an
+// execution can be recorded
+type ExecutionTracer interface {
+	BeginCall(input *vmcommon.ContractCallInput)
+	EndCall(vmOutput *vmcommon.VMOutput)
+	IsInterfaceNil() bool
+}
+
+// HostFunctionGasTracer can be implemented by an ExecutionTracer which needs
+// the gas used by the host functions; implementing it turns on the gas
+// tracing of the MeteringContext
+type HostFunctionGasTracer interface {
+	TraceHostFunctionGas(functionName string, usedGas uint64)
+}
+
+// GasPhaseTracer can be implemented by an ExecutionTracer which attributes
+// the gas used by a call to the phases marked by the contract; it receives
+// the gas left to the running instance when the phase begins
+type GasPhaseTracer interface {
+	TraceGasPhase(phase GasPhase, gasLeft uint64)
+}
+
+// MeteringModeTracer can be implemented by an ExecutionTracer which records
+// whether the instances of the called contracts charge the gas of their
+// opcodes; it is called for every instance started for an execution
+type MeteringModeTracer interface {
+	TraceMeteringMode(address []byte, mode executor.MeteringMode)
+}
+
+// ManagedHandleLeakTracer can be implemented by an ExecutionTracer which
+// collects, in debug mode, the managed values left held by the handles of
+// each endpoint when it returns, instead of being dropped by the contract
+type ManagedHandleLeakTracer interface {
+	TraceLeakedHandles(address []byte, function string, counts ManagedHandleCounts)
+}
+
+// DebugMessageTracer can be implemented by an ExecutionTracer which collects
+// the messages printed by the contracts in debug mode
+type DebugMessageTracer interface {
+	TraceDebugMessage(address []byte, message []byte)
+}
+
+// ExecutionPolicy lets the embedder deny the execution of contracts, by
+// address or by code hash, such as to freeze a compromised contract; it is
+// consulted before instantiating the code of a contract
+type ExecutionPolicy interface {
+	IsExecutionAllowed(address []byte, codeHash []byte) bool
+	IsDeploymentAllowed(address []byte, code []byte) bool
+	IsInterfaceNil() bool
+}
+
+// GuardedAccountsHandler provides the guardian state of the accounts and the
+// guardian fields of the transactions, by hash, so that the host rejects the
+// transactions of guarded accounts which are not co-signed by their guardian
+type GuardedAccountsHandler interface {
+	IsGuardedAccount(address []byte) bool
+	GetActiveGuardian(address []byte) ([]byte, error)
+	GetGuardianCoSignature(txHash []byte) (options uint32, guardianAddr []byte, guardianSignature []byte)
+}
+
+// CodeCache keeps the code of the contracts by code hash, between the
+// blockchain hook and the executor; it may be shared by several hosts, so it
+// must be safe for concurrent use
+type CodeCache interface {
+	GetCode(codeHash []byte) ([]byte, bool)
+	PutCode(codeHash []byte, code []byte)
+	IsInterfaceNil() bool
+}
+
+// ShadowDivergenceHandler is notified of the transactions whose outcome on
+// the shadow executor of a host differs from their outcome on its canonical
+// executor; it is called after the canonical execution has ended
+type ShadowDivergenceHandler interface {
+	HandleShadowDivergence(divergence *ShadowDivergence)
+	IsInterfaceNil() bool
+}
+
+// GasValueConverter converts an amount of gas into the EGLD value paid for
+// it, the way the fee module computes the fee of a transaction
+type GasValueConverter interface {
+	ComputeFee(gasUsed uint64, gasPrice uint64) *big.Int
+}
+
+// MetricsSink receives the measurements taken by the VM while executing
+// contracts, so that node operators can export them to their monitoring
+type MetricsSink interface {
+	ObserveExecution(kind ExecutionKind, returnCode vmcommon.ReturnCode, gasUsed uint64, duration time.Duration)
+	ObserveInstanceCreation(source InstanceSource)
+	ObserveTrap(category ErrorCategory)
+	ObserveHostFunctionCall(hookName string, duration time.Duration)
+	ObserveCodeCacheLookup(hit bool)
+	ObserveInfrastructureError(retried bool)
+	HostFunctionCallsObserved() bool
+	IsInterfaceNil() bool
+}
+
+// ContractMetricsSink can be implemented by a MetricsSink which also
+// aggregates the measurements by the code of the executed contracts; it is
+// notified of every transaction, under the code hash of the called or of the
+// deployed contract, and of every instance started for an execution, while
+// ContractsObserved returns true
+type ContractMetricsSink interface {
+	ContractsObserved() bool
+	ObserveContractExecution(codeHash []byte, returnCode vmcommon.ReturnCode, gasUsed uint64, duration time.Duration)
+	ObserveContractInstanceCreation(codeHash []byte, source InstanceSource)
+}
@@ -0,0 +1,15 @@
+package arwen
+
+// ManagedHandleCounts are the managed values still held by the handles of a
+// call frame, with the bytes of their buffers and big ints
+type ManagedHandleCounts struct {
+	ManagedBuffers int
+	BigInts        int
+	BigFloats      int
+	Bytes          uint64
+}
+
+// IsZero returns true if no handle is held
+func (counts ManagedHandleCounts) IsZero() bool {
+	return counts.ManagedBuffers == 0 && counts.BigInts == 0 && counts.BigFloats == 0
+}
@@ -40,6 +40,16 @@ func (b *BlockchainContextMock) NewAddress(creatorAddress []byte) ([]byte, error
 	return creatorAddress, nil
 }
 
+// ComputeContractAddress -
+func (b *BlockchainContextMock) ComputeContractAddress(deployerAddress []byte, _ uint64) ([]byte, error) {
+	return deployerAddress, nil
+}
+
+// ComputeDeterministicAddress -
+func (b *BlockchainContextMock) ComputeDeterministicAddress(deployerAddress []byte, _ []byte, _ []byte) ([]byte, error) {
+	return deployerAddress, nil
+}
+
 // AccountExists -
 func (b *BlockchainContextMock) AccountExists(_ []byte) bool {
 	return true
@@ -0,0 +1,133 @@
+package arwen
+
+import (
+	"bytes"
+	"encoding/binary"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// AddressedOutputTransfer is an output transfer of a VMOutput, together with
+// the address of the output account receiving it
+type AddressedOutputTransfer struct {
+	Destination []byte
+	Transfer    vmcommon.OutputTransfer
+}
+
+// CanonicalOutputTransfers returns all the output transfers of a VMOutput,
+// including the generated async calls, in their canonical order: the output
+// accounts in ascending order of their addresses, and the transfers of each
+// account in their order of registration. The async calls are registered in
+// the ascending order of the identifiers of their groups and, within a group,
+// in the order in which the contract registered them.
+func CanonicalOutputTransfers(vmOutput *vmcommon.VMOutput) []AddressedOutputTransfer {
+	transfers := make([]AddressedOutputTransfer, 0)
+	for _, account := range SortedOutputAccounts(vmOutput.OutputAccounts) {
+		for _, transfer := range account.OutputTransfers {
+			transfers = append(transfers, AddressedOutputTransfer{
+				Destination: account.Address,
+				Transfer:    transfer,
+			})
+		}
+	}
+	return transfers
+}
+
+// EncodeOutputTransfers serializes the output transfers in the given order,
+// each field preceded by its length where it has no fixed length, so that
+// the implementations consuming the VMOutputs hash identical bytes
+func EncodeOutputTransfers(transfers []AddressedOutputTransfer) []byte {
+	buffer := &bytes.Buffer{}
+	writeUint64(buffer, uint64(len(transfers)))
+	for _, addressed := range transfers {
+		transfer := addressed.Transfer
+		value := transfer.Value
+		if value == nil {
+			value = big.NewInt(0)
+		}
+
+		writeBytes(buffer, addressed.Destination)
+		writeBytes(buffer, transfer.SenderAddress)
+		writeBytes(buffer, value.Bytes())
+		writeUint64(buffer, transfer.GasLimit)
+		writeUint64(buffer, transfer.GasLocked)
+		writeUint64(buffer, uint64(transfer.CallType))
+		writeBytes(buffer, transfer.Data)
+		writeBytes(buffer, transfer.AsyncData)
+	}
+	return buffer.Bytes()
+}
+
+// DecodeOutputTransfers deserializes the output transfers encoded by
+// EncodeOutputTransfers, in their encoded order
+func DecodeOutputTransfers(encoded []byte) ([]AddressedOutputTransfer, error) {
+	reader := &outputTransfersReader{data: encoded}
+	count := reader.readUint64()
+	if reader.failed || count > uint64(len(encoded)) {
+		return nil, ErrInvalidOutputTransfersEncoding
+	}
+
+	transfers := make([]AddressedOutputTransfer, 0, count)
+	for i := uint64(0); i < count; i++ {
+		addressed := AddressedOutputTransfer{}
+		addressed.Destination = reader.readBytes()
+		addressed.Transfer.SenderAddress = reader.readBytes()
+		addressed.Transfer.Value = big.NewInt(0).SetBytes(reader.readBytes())
+		addressed.Transfer.GasLimit = reader.readUint64()
+		addressed.Transfer.GasLocked = reader.readUint64()
+		addressed.Transfer.CallType = vm.CallType(reader.readUint64())
+		addressed.Transfer.Data = reader.readBytes()
+		addressed.Transfer.AsyncData = reader.readBytes()
+		if reader.failed {
+			return nil, ErrInvalidOutputTransfersEncoding
+		}
+		transfers = append(transfers, addressed)
+	}
+
+	if len(reader.data) > 0 {
+		return nil, ErrInvalidOutputTransfersEncoding
+	}
+	return transfers, nil
+}
+
+func writeUint64(buffer *bytes.Buffer, value uint64) {
+	encoded := make([]byte, 8)
+	binary.BigEndian.PutUint64(encoded, value)
+	buffer.Write(encoded)
+}
+
+func writeBytes(buffer *bytes.Buffer, data []byte) {
+	writeUint64(buffer, uint64(len(data)))
+	buffer.Write(data)
+}
+
+type outputTransfersReader struct {
+	data   []byte
+	failed bool
+}
+
+func (reader *outputTransfersReader) readUint64() uint64 {
+	if reader.failed || len(reader.data) < 8 {
+		reader.failed = true
+		return 0
+	}
+
+	value := binary.BigEndian.Uint64(reader.data[:8])
+	reader.data = reader.data[8:]
+	return value
+}
+
+func (reader *outputTransfersReader) readBytes() []byte {
+	length := reader.readUint64()
+	if reader.failed || length > uint64(len(reader.data)) {
+		reader.failed = true
+		return nil
+	}
+
+	data := make([]byte, length)
+	copy(data, reader.data[:length])
+	reader.data = reader.data[length:]
+	return data
+}
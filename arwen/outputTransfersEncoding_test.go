@@ -0,0 +1,88 @@
+package arwen
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+func makeTransfersVMOutput(addresses []string) *vmcommon.VMOutput {
+	outputAccounts := make(map[string]*vmcommon.OutputAccount)
+	for _, address := range addresses {
+		outputAccounts[address] = &vmcommon.OutputAccount{
+			Address: []byte(address),
+			OutputTransfers: []vmcommon.OutputTransfer{
+				{
+					Value:         big.NewInt(10),
+					GasLimit:      1000,
+					GasLocked:     100,
+					Data:          []byte("first@" + address),
+					CallType:      vm.AsynchronousCall,
+					SenderAddress: []byte("parent"),
+				},
+				{
+					Data:          []byte("second@" + address),
+					CallType:      vm.DirectCall,
+					SenderAddress: []byte("parent"),
+				},
+			},
+		}
+	}
+	return &vmcommon.VMOutput{OutputAccounts: outputAccounts}
+}
+
+func TestCanonicalOutputTransfers(t *testing.T) {
+	transfers := CanonicalOutputTransfers(makeTransfersVMOutput([]string{"c", "a", "b"}))
+	require.Len(t, transfers, 6)
+	for i, expected := range []string{"first@a", "second@a", "first@b", "second@b", "first@c", "second@c"} {
+		require.Equal(t, []byte(expected), transfers[i].Transfer.Data)
+		require.Equal(t, []byte(expected[len(expected)-1:]), transfers[i].Destination)
+	}
+
+	encoded := EncodeOutputTransfers(transfers)
+	for i := 0; i < 5; i++ {
+		otherTransfers := CanonicalOutputTransfers(makeTransfersVMOutput([]string{"b", "c", "a"}))
+		require.Equal(t, encoded, EncodeOutputTransfers(otherTransfers))
+	}
+}
+
+func TestEncodeOutputTransfers_RoundTrip(t *testing.T) {
+	transfers := CanonicalOutputTransfers(makeTransfersVMOutput([]string{"a", "b"}))
+	transfers[0].Transfer.AsyncData = []byte("callID")
+
+	decoded, err := DecodeOutputTransfers(EncodeOutputTransfers(transfers))
+	require.Nil(t, err)
+	require.Len(t, decoded, len(transfers))
+	for i, transfer := range transfers {
+		require.Equal(t, transfer.Destination, decoded[i].Destination)
+		require.Equal(t, transfer.Transfer.SenderAddress, decoded[i].Transfer.SenderAddress)
+		require.Equal(t, transfer.Transfer.GasLimit, decoded[i].Transfer.GasLimit)
+		require.Equal(t, transfer.Transfer.GasLocked, decoded[i].Transfer.GasLocked)
+		require.Equal(t, transfer.Transfer.CallType, decoded[i].Transfer.CallType)
+		require.Equal(t, transfer.Transfer.Data, decoded[i].Transfer.Data)
+		require.Equal(t, transfer.Transfer.AsyncData, decoded[i].Transfer.AsyncData)
+	}
+	require.Equal(t, 0, big.NewInt(10).Cmp(decoded[0].Transfer.Value))
+	require.Equal(t, 0, decoded[1].Transfer.Value.Sign())
+
+	decoded, err = DecodeOutputTransfers(EncodeOutputTransfers(nil))
+	require.Nil(t, err)
+	require.Len(t, decoded, 0)
+}
+
+func TestDecodeOutputTransfers_InvalidEncoding(t *testing.T) {
+	encoded := EncodeOutputTransfers(CanonicalOutputTransfers(makeTransfersVMOutput([]string{"a"})))
+
+	for _, length := range []int{0, 7, 8, len(encoded) - 1} {
+		decoded, err := DecodeOutputTransfers(encoded[:length])
+		require.Nil(t, decoded)
+		require.Equal(t, ErrInvalidOutputTransfersEncoding, err)
+	}
+
+	decoded, err := DecodeOutputTransfers(append(encoded, 0))
+	require.Nil(t, decoded)
+	require.Equal(t, ErrInvalidOutputTransfersEncoding, err)
+}
@@ -0,0 +1,53 @@
+package arwen
+
+// MaxPromiseGroupIDLength is the maximum length of the identifier of a
+// promise group created by a contract
+const MaxPromiseGroupIDLength = 32
+
+// PromiseGroupHandle identifies, within an execution, a promise group created
+// by the contract with the createPromiseGroup host function; the handles are
+// positive, in the order in which the groups were created
+type PromiseGroupHandle int32
+
+// PromiseID identifies, within an execution, an async call registered by the
+// contract in a promise group with the createPromise host function; the
+// identifiers are positive, in the order in which the calls were registered
+type PromiseID int32
+
+// InvalidPromiseHandle is returned by the host functions instead of a
+// PromiseGroupHandle or a PromiseID when they fail
+const InvalidPromiseHandle = -1
+
+// ValidatePromiseGroupID checks that the identifier of a promise group is not
+// empty, not longer than MaxPromiseGroupIDLength, and only made of ASCII
+// letters, digits, '_' and '-', so that the groups of unrelated modules of a
+// contract cannot collide through identifiers differing in invisible bytes
+func ValidatePromiseGroupID(groupID []byte) error {
+	if len(groupID) == 0 || len(groupID) > MaxPromiseGroupIDLength {
+		return ErrInvalidPromiseGroupID
+	}
+	if string(groupID) == LegacyAsyncCallGroupID {
+		return ErrInvalidPromiseGroupID
+	}
+
+	for _, character := range groupID {
+		if !isPromiseGroupIDCharacter(character) {
+			return ErrInvalidPromiseGroupID
+		}
+	}
+
+	return nil
+}
+
+func isPromiseGroupIDCharacter(character byte) bool {
+	switch {
+	case character >= 'a' && character <= 'z':
+		return true
+	case character >= 'A' && character <= 'Z':
+		return true
+	case character >= '0' && character <= '9':
+		return true
+	default:
+		return character == '_' || character == '-'
+	}
+}
@@ -0,0 +1,18 @@
+package arwen
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestValidatePromiseGroupID(t *testing.T) {
+	for _, groupID := range []string{"testgroup", "a", "Vendors_2", "module-a_payments", strings.Repeat("x", MaxPromiseGroupIDLength)} {
+		require.Nil(t, ValidatePromiseGroupID([]byte(groupID)), groupID)
+	}
+
+	for _, groupID := range []string{"", "test group", "group\x00", "grüppe", "a.b", LegacyAsyncCallGroupID, strings.Repeat("x", MaxPromiseGroupIDLength+1)} {
+		require.Equal(t, ErrInvalidPromiseGroupID, ValidatePromiseGroupID([]byte(groupID)), groupID)
+	}
+}
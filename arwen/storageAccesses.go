@@ -0,0 +1,146 @@
+package arwen
+
+import (
+	"bytes"
+	"sort"
+)
+
+// StorageConflictKind tells how two transactions touch the same storage key
+type StorageConflictKind uint8
+
+const (
+	// ReadAfterWrite means that the later transaction reads a key written by the earlier one
+	ReadAfterWrite StorageConflictKind = iota
+
+	// WriteAfterRead means that the later transaction writes a key read by the earlier one
+	WriteAfterRead
+
+	// WriteAfterWrite means that both transactions write the key
+	WriteAfterWrite
+)
+
+// String returns the name of the kind of conflict
+func (kind StorageConflictKind) String() string {
+	switch kind {
+	case ReadAfterWrite:
+		return "read after write"
+	case WriteAfterRead:
+		return "write after read"
+	case WriteAfterWrite:
+		return "write after write"
+	default:
+		return "unknown"
+	}
+}
+
+// StorageConflict is a storage key through which two transactions depend on
+// each other, so that they cannot be executed in parallel
+type StorageConflict struct {
+	Address []byte
+	Key     []byte
+	Kind    StorageConflictKind
+}
+
+// StorageAccessSet holds the storage keys read from the node by a transaction
+// and those written by it, by address. The reads of the failed call frames are
+// kept, as the outcome of the transaction depended on them, while their writes
+// are discarded with their output.
+type StorageAccessSet struct {
+	Reads  map[string]map[string]struct{}
+	Writes map[string]map[string]struct{}
+}
+
+// NewStorageAccessSet creates an empty StorageAccessSet
+func NewStorageAccessSet() *StorageAccessSet {
+	return &StorageAccessSet{
+		Reads:  make(map[string]map[string]struct{}),
+		Writes: make(map[string]map[string]struct{}),
+	}
+}
+
+// AddRead records that the key of the account was read from the node
+func (set *StorageAccessSet) AddRead(address []byte, key []byte) {
+	addStorageAccess(set.Reads, address, key)
+}
+
+// AddWrite records that the key of the account was written
+func (set *StorageAccessSet) AddWrite(address []byte, key []byte) {
+	addStorageAccess(set.Writes, address, key)
+}
+
+// AddWritesFrom records the keys written in the other set
+func (set *StorageAccessSet) AddWritesFrom(other *StorageAccessSet) {
+	for address, keys := range other.Writes {
+		for key := range keys {
+			addStorageAccess(set.Writes, []byte(address), []byte(key))
+		}
+	}
+}
+
+// HasRead returns true if the key of the account was read from the node
+func (set *StorageAccessSet) HasRead(address []byte, key []byte) bool {
+	return hasStorageAccess(set.Reads, address, key)
+}
+
+// HasWritten returns true if the key of the account was written
+func (set *StorageAccessSet) HasWritten(address []byte, key []byte) bool {
+	return hasStorageAccess(set.Writes, address, key)
+}
+
+func addStorageAccess(accesses map[string]map[string]struct{}, address []byte, key []byte) {
+	keys, ok := accesses[string(address)]
+	if !ok {
+		keys = make(map[string]struct{})
+		accesses[string(address)] = keys
+	}
+	keys[string(key)] = struct{}{}
+}
+
+func hasStorageAccess(accesses map[string]map[string]struct{}, address []byte, key []byte) bool {
+	_, ok := accesses[string(address)][string(key)]
+	return ok
+}
+
+// FindStorageConflicts returns the hazards which forbid executing the later
+// transaction in parallel with the earlier one, ordered by address, key and
+// kind; no conflicts means that the order of their executions does not change
+// their outputs, as far as the storage is concerned
+func FindStorageConflicts(earlier *StorageAccessSet, later *StorageAccessSet) []StorageConflict {
+	conflicts := make([]StorageConflict, 0)
+	conflicts = appendStorageConflicts(conflicts, earlier.Writes, later.Reads, ReadAfterWrite)
+	conflicts = appendStorageConflicts(conflicts, earlier.Reads, later.Writes, WriteAfterRead)
+	conflicts = appendStorageConflicts(conflicts, earlier.Writes, later.Writes, WriteAfterWrite)
+
+	sort.Slice(conflicts, func(i, j int) bool {
+		addressOrder := bytes.Compare(conflicts[i].Address, conflicts[j].Address)
+		if addressOrder != 0 {
+			return addressOrder < 0
+		}
+		keyOrder := bytes.Compare(conflicts[i].Key, conflicts[j].Key)
+		if keyOrder != 0 {
+			return keyOrder < 0
+		}
+		return conflicts[i].Kind < conflicts[j].Kind
+	})
+	return conflicts
+}
+
+func appendStorageConflicts(
+	conflicts []StorageConflict,
+	first map[string]map[string]struct{},
+	second map[string]map[string]struct{},
+	kind StorageConflictKind,
+) []StorageConflict {
+	for address, keys := range first {
+		for key := range keys {
+			if _, ok := second[address][key]; ok {
+				conflicts = append(conflicts, StorageConflict{
+					Address: []byte(address),
+					Key:     []byte(key),
+					Kind:    kind,
+				})
+			}
+		}
+	}
+	return conflicts
+}
@@ -0,0 +1,46 @@
+package arwen
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestStorageAccessSet_AddWritesFrom(t *testing.T) {
+	frame := NewStorageAccessSet()
+	frame.AddWrite([]byte("alice"), []byte("written"))
+	frame.AddRead([]byte("alice"), []byte("read"))
+
+	accesses := NewStorageAccessSet()
+	accesses.AddWrite([]byte("bob"), []byte("written"))
+	accesses.AddWritesFrom(frame)
+
+	require.True(t, accesses.HasWritten([]byte("alice"), []byte("written")))
+	require.True(t, accesses.HasWritten([]byte("bob"), []byte("written")))
+	require.False(t, accesses.HasRead([]byte("alice"), []byte("read")))
+	require.False(t, accesses.HasWritten([]byte("alice"), []byte("read")))
+}
+
+func TestFindStorageConflicts(t *testing.T) {
+	earlier := NewStorageAccessSet()
+	earlier.AddRead([]byte("alice"), []byte("b"))
+	earlier.AddWrite([]byte("alice"), []byte("a"))
+	earlier.AddWrite([]byte("bob"), []byte("a"))
+	earlier.AddRead([]byte("bob"), []byte("unrelated"))
+
+	later := NewStorageAccessSet()
+	later.AddWrite([]byte("alice"), []byte("b"))
+	later.AddRead([]byte("alice"), []byte("a"))
+	later.AddWrite([]byte("bob"), []byte("a"))
+	later.AddRead([]byte("carol"), []byte("unrelated"))
+
+	conflicts := FindStorageConflicts(earlier, later)
+	require.Equal(t, []StorageConflict{
+		{Address: []byte("alice"), Key: []byte("a"), Kind: ReadAfterWrite},
+		{Address: []byte("alice"), Key: []byte("b"), Kind: WriteAfterRead},
+		{Address: []byte("bob"), Key: []byte("a"), Kind: WriteAfterWrite},
+	}, conflicts)
+	require.Equal(t, "write after read", conflicts[1].Kind.String())
+
+	require.Empty(t, FindStorageConflicts(later, NewStorageAccessSet()))
+}
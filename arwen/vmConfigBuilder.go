@@ -0,0 +1,312 @@
+package arwen
+
+import (
+	"fmt"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// MinSCExecutionTimeoutMilliseconds is the shortest time the host lets a
+// contract execute before interrupting it
+const MinSCExecutionTimeoutMilliseconds = 1000
+
+// DefaultBlockGasLimit is the block gas limit of the VMConfigBuilder
+const DefaultBlockGasLimit = uint64(1_500_000_000)
+
+// DefaultElrondProtectedKeyPrefix is the prefix of the storage keys reserved
+// to the protocol, of the VMConfigBuilder
+const DefaultElrondProtectedKeyPrefix = "ELROND"
+
+// DefaultVMConfigType is the VM type of the VMConfigBuilder
+var DefaultVMConfigType = []byte{5, 0}
+
+// VMConfigBuilder assembles the VMHostParameters of a host and checks that
+// they are consistent with each other before the host is created. Unless
+// configured otherwise, the VM has:
+//   - the VM type DefaultVMConfigType and the block gas limit DefaultBlockGasLimit;
+//   - the storage keys prefixed by DefaultElrondProtectedKeyPrefix reserved;
+//   - the wasmer executor, and an execution timeout of MinSCExecutionTimeoutMilliseconds;
+//   - no tracer, no metrics, and no contract, async or return data limits.
+//
+// The gas schedule, the built-in functions container, the ESDT transfer
+// parser, the epoch notifier and the enable epochs handler have no default.
+type VMConfigBuilder struct {
+	parameters VMHostParameters
+}
+
+// NewVMConfigBuilder creates a VMConfigBuilder holding the defaults
+func NewVMConfigBuilder() *VMConfigBuilder {
+	return &VMConfigBuilder{
+		parameters: VMHostParameters{
+			VMType:                              DefaultVMConfigType,
+			BlockGasLimit:                       DefaultBlockGasLimit,
+			ElrondProtectedKeyPrefix:            []byte(DefaultElrondProtectedKeyPrefix),
+			TimeOutForSCExecutionInMilliseconds: MinSCExecutionTimeoutMilliseconds,
+		},
+	}
+}
+
+// WithVMType sets the VM type of the contracts executed by the host
+func (builder *VMConfigBuilder) WithVMType(vmType []byte) *VMConfigBuilder {
+	builder.parameters.VMType = vmType
+	return builder
+}
+
+// WithBlockGasLimit sets the gas limit of the blocks
+func (builder *VMConfigBuilder) WithBlockGasLimit(blockGasLimit uint64) *VMConfigBuilder {
+	builder.parameters.BlockGasLimit = blockGasLimit
+	return builder
+}
+
+// WithGasSchedule sets the gas costs of the VM
+func (builder *VMConfigBuilder) WithGasSchedule(gasSchedule config.GasScheduleMap) *VMConfigBuilder {
+	builder.parameters.GasSchedule = gasSchedule
+	return builder
+}
+
+// WithProtocolComponents sets the components of the node with which the host works
+func (builder *VMConfigBuilder) WithProtocolComponents(
+	builtInFuncContainer vmcommon.BuiltInFunctionContainer,
+	esdtTransferParser vmcommon.ESDTTransferParser,
+	epochNotifier vmcommon.EpochNotifier,
+	enableEpochsHandler vmcommon.EnableEpochsHandler,
+) *VMConfigBuilder {
+	builder.parameters.BuiltInFuncContainer = builtInFuncContainer
+	builder.parameters.ESDTTransferParser = esdtTransferParser
+	builder.parameters.EpochNotifier = epochNotifier
+	builder.parameters.EnableEpochsHandler = enableEpochsHandler
+	return builder
+}
+
+// WithVMVersionSchedule sets the VM versions of the blocks, by epoch
+func (builder *VMConfigBuilder) WithVMVersionSchedule(schedule VMVersionSchedule) *VMConfigBuilder {
+	builder.parameters.VMVersionSchedule = schedule
+	return builder
+}
+
+// WithContractLimits sets the thresholds checked on the code of the deployed contracts
+func (builder *VMConfigBuilder) WithContractLimits(limits ContractLimits) *VMConfigBuilder {
+	builder.parameters.ContractLimits = limits
+	return builder
+}
+
+// WithAsyncLimits sets the bounds of the fan-out of the contracts
+func (builder *VMConfigBuilder) WithAsyncLimits(limits AsyncLimits) *VMConfigBuilder {
+	builder.parameters.AsyncLimits = limits
+	return builder
+}
+
+// WithStorageLimits sets the bounds of the keys and the values which contracts write to storage
+func (builder *VMConfigBuilder) WithStorageLimits(limits StorageLimits) *VMConfigBuilder {
+	builder.parameters.StorageLimits = limits
+	return builder
+}
+
+// WithMaxReturnDataSize sets the maximum size of the return data of a transaction
+func (builder *VMConfigBuilder) WithMaxReturnDataSize(maxReturnDataSize uint64) *VMConfigBuilder {
+	builder.parameters.MaxReturnDataSize = maxReturnDataSize
+	return builder
+}
+
+// WithMaxManagedAllocation sets the maximum number of bytes held by the managed types of an execution
+func (builder *VMConfigBuilder) WithMaxManagedAllocation(maxManagedAllocation uint64) *VMConfigBuilder {
+	builder.parameters.MaxManagedAllocation = maxManagedAllocation
+	return builder
+}
+
+// WithOutputTransferBundling makes the VM merge the plain transfers sent by
+// the same sender to the same destination in the output of a transaction
+func (builder *VMConfigBuilder) WithOutputTransferBundling(bundle bool) *VMConfigBuilder {
+	builder.parameters.BundleOutputTransfers = bundle
+	return builder
+}
+
+// WithGasValueConverter sets how the VM values the gas which contracts refund
+// to the callers of transactions, for instance a fees.FeeComputer; by
+// default, the gas is valued at the full gas price
+func (builder *VMConfigBuilder) WithGasValueConverter(converter GasValueConverter) *VMConfigBuilder {
+	builder.parameters.GasValueConverter = converter
+	return builder
+}
+
+// WithExecutorKind selects the executor of the VM, which WithExecutor overrides
+func (builder *VMConfigBuilder) WithExecutorKind(kind ExecutorKind) *VMConfigBuilder {
+	builder.parameters.VMExecutorKind = kind
+	return builder
+}
+
+// WithExecutor replaces the executor of the VM, whatever its kind
+func (builder *VMConfigBuilder) WithExecutor(executorFactory executor.ExecutorAbstractFactory) *VMConfigBuilder {
+	builder.parameters.OverrideVMExecutor = executorFactory
+	return builder
+}
+
+// WithShadowExecutor makes the host execute every transaction a second time
+// on the executor of the given factory, after the canonical execution,
+// passing the transactions whose outcome differs to the handler, which may be
+// nil; the outcome of the shadow execution is discarded
+func (builder *VMConfigBuilder) WithShadowExecutor(executorFactory executor.ExecutorAbstractFactory, handler ShadowDivergenceHandler) *VMConfigBuilder {
+	builder.parameters.ShadowVMExecutor = executorFactory
+	builder.parameters.ShadowDivergenceHandler = handler
+	return builder
+}
+
+// WithExecutionTimeout sets the time the host lets a contract execute before interrupting it
+func (builder *VMConfigBuilder) WithExecutionTimeout(milliseconds uint32) *VMConfigBuilder {
+	builder.parameters.TimeOutForSCExecutionInMilliseconds = milliseconds
+	return builder
+}
+
+// WithTracing sets the tracer and the metrics sink notified of the executions
+func (builder *VMConfigBuilder) WithTracing(tracer ExecutionTracer, metricsSink MetricsSink) *VMConfigBuilder {
+	builder.parameters.ExecutionTracer = tracer
+	builder.parameters.MetricsSink = metricsSink
+	return builder
+}
+
+// WithDebugMode sets whether the host checks its invariants after every execution
+func (builder *VMConfigBuilder) WithDebugMode(debugMode bool) *VMConfigBuilder {
+	builder.parameters.DebugMode = debugMode
+	return builder
+}
+
+// Build validates the configuration and returns the VMHostParameters for
+// creating the host; the builder may be changed and built again afterwards
+func (builder *VMConfigBuilder) Build() (*VMHostParameters, error) {
+	parameters := builder.parameters
+	err := ValidateVMHostParameters(&parameters)
+	if err != nil {
+		return nil, err
+	}
+
+	return &parameters, nil
+}
+
+// ValidateVMHostParameters checks that the parameters hold all the components
+// of a host, that their values are in range, and that the features they
+// enable have what they depend on configured
+func ValidateVMHostParameters(parameters *VMHostParameters) error {
+	if parameters == nil {
+		return ErrNilHostParameters
+	}
+	err := validateProtocolComponents(parameters)
+	if err != nil {
+		return err
+	}
+
+	if len(parameters.VMType) != 2 {
+		return fmt.Errorf("%w: the VM type must have 2 bytes, not %d", ErrInvalidVMConfiguration, len(parameters.VMType))
+	}
+	if parameters.BlockGasLimit == 0 {
+		return fmt.Errorf("%w: the block gas limit is 0", ErrInvalidVMConfiguration)
+	}
+	if len(parameters.ElrondProtectedKeyPrefix) == 0 {
+		return fmt.Errorf("%w: %v", ErrInvalidVMConfiguration, ErrEmptyElrondProtectedKeyPrefix)
+	}
+	if parameters.VMExecutorKind > InterpreterExecutor {
+		return fmt.Errorf("%w: unknown executor kind %d", ErrInvalidVMConfiguration, parameters.VMExecutorKind)
+	}
+	timeout := parameters.TimeOutForSCExecutionInMilliseconds
+	if timeout < MinSCExecutionTimeoutMilliseconds {
+		return fmt.Errorf("%w: the execution timeout of %d ms is below the minimum of %d ms",
+			ErrInvalidVMConfiguration, timeout, MinSCExecutionTimeoutMilliseconds)
+	}
+
+	err = ValidateConfigUpdate(parameters.GasSchedule, ExtendEnableEpochsHandler(parameters.EnableEpochsHandler))
+	if err != nil {
+		return err
+	}
+	err = parameters.VMVersionSchedule.Check()
+	if err != nil {
+		return fmt.Errorf("%w: %v", ErrInvalidVMConfiguration, err)
+	}
+
+	limits := parameters.AsyncLimits
+	if limits.MaxOutputTransfers != 0 && limits.MaxCallsPerGroup > limits.MaxOutputTransfers {
+		return fmt.Errorf("%w: %d async calls per group exceed the %d output transfers of a transaction",
+			ErrInvalidVMConfiguration, limits.MaxCallsPerGroup, limits.MaxOutputTransfers)
+	}
+	contractLimits := parameters.ContractLimits
+	if contractLimits.MaxCodeSize != 0 && contractLimits.MaxDataSegmentsSize > contractLimits.MaxCodeSize {
+		return fmt.Errorf("%w: the data segments size of %d bytes exceeds the code size of %d bytes",
+			ErrInvalidVMConfiguration, contractLimits.MaxDataSegmentsSize, contractLimits.MaxCodeSize)
+	}
+
+	return nil
+}
+
+func validateProtocolComponents(parameters *VMHostParameters) error {
+	if check.IfNil(parameters.BuiltInFuncContainer) {
+		return ErrNilBuiltInFunctionsContainer
+	}
+	if check.IfNil(parameters.ESDTTransferParser) {
+		return ErrNilESDTTransferParser
+	}
+	if check.IfNil(parameters.EpochNotifier) {
+		return ErrNilEpochNotifier
+	}
+	if check.IfNil(parameters.EnableEpochsHandler) {
+		return ErrNilEnableEpochsHandler
+	}
+	return nil
+}
+
+// ValidateConfigUpdate checks the gas schedule and the activation epochs of a
+// host, alone and against each other; they are checked both when the host is
+// created and when they replace those of a running host
+func ValidateConfigUpdate(gasSchedule config.GasScheduleMap, enableEpochsHandler EnableEpochsHandler) error {
+	if check.IfNil(enableEpochsHandler) {
+		return ErrNilEnableEpochsHandler
+	}
+	err := validateGasSchedule(gasSchedule, enableEpochsHandler)
+	if err != nil {
+		return err
+	}
+	return validateEnableEpochs(enableEpochsHandler)
+}
+
+func validateGasSchedule(gasSchedule config.GasScheduleMap, enableEpochsHandler EnableEpochsHandler) error {
+	if gasSchedule == nil {
+		return fmt.Errorf("%w: no gas schedule", ErrInvalidVMConfiguration)
+	}
+
+	// the callbacks of the async calls are paid for by the gas locked when
+	// the calls are created
+	if isPromisesEnabled(enableEpochsHandler) && gasSchedule["ElrondAPICost"]["AsyncCallbackGasLock"] == 0 {
+		return fmt.Errorf("%w: promises are enabled but the gas schedule locks no gas for the callbacks", ErrInvalidVMConfiguration)
+	}
+
+	_, err := config.CreateGasConfig(gasSchedule)
+	if err != nil {
+		return fmt.Errorf("%w: gas schedule: %v", ErrInvalidVMConfiguration, err)
+	}
+	return nil
+}
+
+func isPromisesEnabled(enableEpochsHandler EnableEpochsHandler) bool {
+	return enableEpochsHandler.PromisesFunctionsEnableEpoch() != config.DisabledEpoch ||
+		enableEpochsHandler.PromiseHandleFunctionsEnableEpoch() != config.DisabledEpoch
+}
+
+// the groups of host functions and the features which extend the promises
+// cannot be enabled before them
+func validateEnableEpochs(enableEpochsHandler EnableEpochsHandler) error {
+	promisesEpoch := enableEpochsHandler.PromisesFunctionsEnableEpoch()
+	dependentEpochs := []struct {
+		name  string
+		epoch uint32
+	}{
+		{"PromiseHandleFunctionsEnableEpoch", enableEpochsHandler.PromiseHandleFunctionsEnableEpoch()},
+		{"AsyncCallSubGroupsEnableEpoch", enableEpochsHandler.AsyncCallSubGroupsEnableEpoch()},
+	}
+	for _, dependent := range dependentEpochs {
+		if dependent.epoch < promisesEpoch {
+			return fmt.Errorf("%w: %s %d precedes PromisesFunctionsEnableEpoch %d",
+				ErrInvalidVMConfiguration, dependent.name, dependent.epoch, promisesEpoch)
+		}
+	}
+	return nil
+}
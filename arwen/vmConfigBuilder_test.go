@@ -0,0 +1,123 @@
+package arwen
+
+import (
+	"errors"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/elrond-vm-common/builtInFunctions"
+	"github.com/ElrondNetwork/elrond-vm-common/parsers"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+type epochNotifierStub struct{}
+
+func (notifier *epochNotifierStub) RegisterNotifyHandler(_ vmcommon.EpochSubscriberHandler) {}
+
+func (notifier *epochNotifierStub) IsInterfaceNil() bool {
+	return notifier == nil
+}
+
+func newConfiguredVMConfigBuilder() *VMConfigBuilder {
+	builder := NewVMConfigBuilder().WithGasSchedule(config.MakeGasMapForTests())
+	return withEnableEpochsHandler(builder, worldmock.EnableEpochsHandlerStubAllFlags())
+}
+
+func withEnableEpochsHandler(builder *VMConfigBuilder, enableEpochsHandler EnableEpochsHandler) *VMConfigBuilder {
+	esdtTransferParser, _ := parsers.NewESDTTransferParser(worldmock.WorldMarshalizer)
+	return builder.WithProtocolComponents(
+		builtInFunctions.NewBuiltInFunctionContainer(),
+		esdtTransferParser,
+		&epochNotifierStub{},
+		enableEpochsHandler)
+}
+
+func TestVMConfigBuilder_Defaults(t *testing.T) {
+	parameters, err := newConfiguredVMConfigBuilder().Build()
+	require.Nil(t, err)
+	require.Equal(t, DefaultVMConfigType, parameters.VMType)
+	require.Equal(t, DefaultBlockGasLimit, parameters.BlockGasLimit)
+	require.Equal(t, []byte(DefaultElrondProtectedKeyPrefix), parameters.ElrondProtectedKeyPrefix)
+	require.Equal(t, uint32(MinSCExecutionTimeoutMilliseconds), parameters.TimeOutForSCExecutionInMilliseconds)
+	require.Nil(t, parameters.OverrideVMExecutor)
+	require.Equal(t, WasmerExecutor, parameters.VMExecutorKind)
+
+	_, err = NewVMConfigBuilder().WithGasSchedule(config.MakeGasMapForTests()).Build()
+	require.Equal(t, ErrNilBuiltInFunctionsContainer, err)
+
+	_, err = withEnableEpochsHandler(newConfiguredVMConfigBuilder(), nil).Build()
+	require.Equal(t, ErrNilEnableEpochsHandler, err)
+}
+
+func TestVMConfigBuilder_BuildReturnsCopies(t *testing.T) {
+	builder := newConfiguredVMConfigBuilder()
+	first, err := builder.Build()
+	require.Nil(t, err)
+
+	second, err := builder.WithBlockGasLimit(42).Build()
+	require.Nil(t, err)
+	require.Equal(t, DefaultBlockGasLimit, first.BlockGasLimit)
+	require.Equal(t, uint64(42), second.BlockGasLimit)
+}
+
+func TestVMConfigBuilder_InvalidConfigurations(t *testing.T) {
+	noCallbackGasLock := config.MakeGasMap(1, 0)
+
+	testCases := []struct {
+		name      string
+		configure func(builder *VMConfigBuilder)
+		message   string
+	}{
+		{"VM type", func(builder *VMConfigBuilder) { builder.WithVMType([]byte{5}) }, "VM type"},
+		{"block gas limit", func(builder *VMConfigBuilder) { builder.WithBlockGasLimit(0) }, "block gas limit"},
+		{"execution timeout", func(builder *VMConfigBuilder) { builder.WithExecutionTimeout(10) }, "execution timeout"},
+		{"gas schedule", func(builder *VMConfigBuilder) { builder.WithGasSchedule(nil) }, "no gas schedule"},
+		{"callback gas lock", func(builder *VMConfigBuilder) { builder.WithGasSchedule(noCallbackGasLock) }, "locks no gas"},
+		{"promise handles before promises", func(builder *VMConfigBuilder) {
+			withEnableEpochsHandler(builder, &worldmock.EnableEpochsHandlerStub{
+				PromisesFunctionsEnableEpochField:      10,
+				PromiseHandleFunctionsEnableEpochField: 5,
+			})
+		}, "PromiseHandleFunctionsEnableEpoch"},
+		{"sub-groups without promises", func(builder *VMConfigBuilder) {
+			withEnableEpochsHandler(builder, &worldmock.EnableEpochsHandlerStub{
+				PromisesFunctionsEnableEpochField:      config.DisabledEpoch,
+				PromiseHandleFunctionsEnableEpochField: config.DisabledEpoch,
+			})
+		}, "AsyncCallSubGroupsEnableEpoch"},
+		{"async limits", func(builder *VMConfigBuilder) {
+			builder.WithAsyncLimits(AsyncLimits{MaxCallsPerGroup: 10, MaxOutputTransfers: 5})
+		}, "output transfers"},
+		{"contract limits", func(builder *VMConfigBuilder) {
+			builder.WithContractLimits(ContractLimits{MaxCodeSize: 100, MaxDataSegmentsSize: 200})
+		}, "data segments size"},
+		{"executor kind", func(builder *VMConfigBuilder) { builder.WithExecutorKind(ExecutorKind(7)) }, "executor kind"},
+	}
+
+	for _, testCase := range testCases {
+		builder := newConfiguredVMConfigBuilder()
+		testCase.configure(builder)
+		parameters, err := builder.Build()
+		require.Nil(t, parameters, testCase.name)
+		require.True(t, errors.Is(err, ErrInvalidVMConfiguration), testCase.name)
+		require.Contains(t, err.Error(), testCase.message, testCase.name)
+	}
+}
+
+func TestVMConfigBuilder_PromisesDisabledGasScheduleChecked(t *testing.T) {
+	builder := newConfiguredVMConfigBuilder().WithGasSchedule(config.MakeGasMap(1, 0))
+	parameters, err := withEnableEpochsHandler(builder, &worldmock.EnableEpochsHandlerStub{
+		PromisesFunctionsEnableEpochField:      config.DisabledEpoch,
+		PromiseHandleFunctionsEnableEpochField: config.DisabledEpoch,
+		AsyncCallSubGroupsEnableEpochField:     config.DisabledEpoch,
+	}).Build()
+	require.Nil(t, parameters)
+	require.True(t, errors.Is(err, ErrInvalidVMConfiguration))
+	require.Contains(t, err.Error(), "AsyncCallbackGasLock")
+}
+
+func TestValidateVMHostParameters_Nil(t *testing.T) {
+	require.Equal(t, ErrNilHostParameters, ValidateVMHostParameters(nil))
+}
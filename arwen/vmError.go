@@ -0,0 +1,158 @@
+package arwen
+
+import (
+	"errors"
+)
+
+// ErrorCategory groups the errors of the VM by the component which produced them
+type ErrorCategory uint8
+
+const (
+	// ErrorCategoryUnknown is the category of the errors which are not VMErrors
+	ErrorCategoryUnknown ErrorCategory = iota
+
+	// ErrorCategoryValidation is the category of the errors caused by invalid
+	// contracts, arguments or host parameters
+	ErrorCategoryValidation
+
+	// ErrorCategoryExecutor is the category of the errors raised while
+	// instantiating or running contracts on the executor
+	ErrorCategoryExecutor
+
+	// ErrorCategoryHook is the category of the errors related to the
+	// blockchain hook, accounts, transfers and built-in functions
+	ErrorCategoryHook
+
+	// ErrorCategoryAsync is the category of the errors of async calls and callbacks
+	ErrorCategoryAsync
+
+	// ErrorCategoryStorage is the category of the errors of the contract storage
+	ErrorCategoryStorage
+
+	// ErrorCategoryGas is the category of the errors of gas metering
+	ErrorCategoryGas
+
+	// ErrorCategoryInfrastructure is the category of the failures of the node
+	// or of the executor, such as unreadable compiled code, which do not depend
+	// on the contract and may not happen again on another attempt
+	ErrorCategoryInfrastructure
+)
+
+// ErrorCode is the stable numeric identifier of a VMError; the codes of a
+// category start at 1000 times the value of the category, and a code is
+// never reassigned once released
+type ErrorCode uint32
+
+// ErrorCodeUnknown is the code of the errors which are not VMErrors
+const ErrorCodeUnknown ErrorCode = 0
+
+// String returns the name of the category
+func (category ErrorCategory) String() string {
+	switch category {
+	case ErrorCategoryValidation:
+		return "validation"
+	case ErrorCategoryExecutor:
+		return "executor"
+	case ErrorCategoryHook:
+		return "hook"
+	case ErrorCategoryAsync:
+		return "async"
+	case ErrorCategoryStorage:
+		return "storage"
+	case ErrorCategoryGas:
+		return "gas"
+	case ErrorCategoryInfrastructure:
+		return "infrastructure"
+	}
+	return "unknown"
+}
+
+// VMError is an error of the VM which carries a category and a stable code,
+// so that embedders can handle failures without matching error messages
+type VMError struct {
+	Category ErrorCategory
+	Code     ErrorCode
+	message  string
+	source   error
+}
+
+// NewVMError creates a VMError with the given category, code and message
+func NewVMError(category ErrorCategory, code ErrorCode, message string) *VMError {
+	return &VMError{
+		Category: category,
+		Code:     code,
+		message:  message,
+	}
+}
+
+// NewWrappedVMError creates a VMError which refines the source error; its
+// message is the message of the source followed by the detail
+func NewWrappedVMError(category ErrorCategory, code ErrorCode, source error, detail string) *VMError {
+	return &VMError{
+		Category: category,
+		Code:     code,
+		message:  source.Error() + " " + detail,
+		source:   source,
+	}
+}
+
+// Error returns the message of the error
+func (vmError *VMError) Error() string {
+	return vmError.message
+}
+
+// Source returns the error refined by this one, if any
+func (vmError *VMError) Source() error {
+	return vmError.source
+}
+
+// Unwrap returns the source of the error, so that errors.Is matches the
+// errors refined by this one as well
+func (vmError *VMError) Unwrap() error {
+	return vmError.source
+}
+
+// GetVMError returns the first VMError in the chain of the given error
+func GetVMError(err error) (*VMError, bool) {
+	var vmError *VMError
+	if errors.As(err, &vmError) {
+		return vmError, true
+	}
+
+	wrappable, ok := err.(WrappableError)
+	if !ok {
+		return nil, false
+	}
+	for _, wrappedErr := range wrappable.GetAllErrors() {
+		if errors.As(wrappedErr, &vmError) {
+			return vmError, true
+		}
+	}
+	return nil, false
+}
+
+// ErrorCodeOf returns the code of the first VMError in the chain of the
+// given error, or ErrorCodeUnknown if there is none
+func ErrorCodeOf(err error) ErrorCode {
+	vmError, ok := GetVMError(err)
+	if !ok {
+		return ErrorCodeUnknown
+	}
+	return vmError.Code
+}
+
+// ErrorCategoryOf returns the category of the first VMError in the chain of
+// the given error, or ErrorCategoryUnknown if there is none
+func ErrorCategoryOf(err error) ErrorCategory {
+	vmError, ok := GetVMError(err)
+	if !ok {
+		return ErrorCategoryUnknown
+	}
+	return vmError.Category
+}
+
+// IsInfrastructureError returns true if the first VMError in the chain of the
+// given error is a failure of the node or of the executor, not of the contract
+func IsInfrastructureError(err error) bool {
+	return ErrorCategoryOf(err) == ErrorCategoryInfrastructure
+}
@@ -0,0 +1,62 @@
+package arwen
+
+import (
+	"errors"
+	"fmt"
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/stretchr/testify/require"
+)
+
+func TestVMError_MessagesAndChaining(t *testing.T) {
+	require.Equal(t, "bad bounds (lower)", ErrBadLowerBounds.Error())
+	require.Equal(t, ErrBadBounds, ErrBadLowerBounds.Source())
+	require.True(t, errors.Is(ErrBadLowerBounds, ErrBadBounds))
+	require.False(t, errors.Is(ErrBadBounds, ErrBadLowerBounds))
+
+	require.Equal(t, "invalid function (calling init() directly is forbidden)", ErrInitFuncCalledInRun.Error())
+	require.True(t, errors.Is(ErrInitFuncCalledInRun, executor.ErrInvalidFunction))
+	require.Nil(t, ErrNotEnoughGas.Source())
+}
+
+func TestVMError_CodesAndCategories(t *testing.T) {
+	require.Equal(t, ErrorCategoryGas, ErrorCategoryOf(ErrNotEnoughGas))
+	require.Equal(t, ErrorCode(6001), ErrorCodeOf(ErrNotEnoughGas))
+	require.Equal(t, ErrorCategoryAsync, ErrorCategoryOf(ErrAsyncCallNotFound))
+	require.Equal(t, ErrorCategoryStorage, ErrorCategoryOf(ErrCannotWriteProtectedKey))
+	require.Equal(t, "storage", ErrorCategoryStorage.String())
+
+	wrapped := fmt.Errorf("executing contract: %w", ErrContractNotFound)
+	require.Equal(t, ErrorCategoryExecutor, ErrorCategoryOf(wrapped))
+	require.Equal(t, ErrContractNotFound.Code, ErrorCodeOf(wrapped))
+
+	wrappable := WrapError(executor.ErrFuncNotFound).WrapWithError(ErrMemoryLimit)
+	require.Equal(t, ErrMemoryLimit.Code, ErrorCodeOf(wrappable))
+
+	require.Equal(t, "infrastructure", ErrorCategoryInfrastructure.String())
+	require.True(t, IsInfrastructureError(fmt.Errorf("%w: artifact", ErrCompiledCodeNotRestored)))
+	require.False(t, IsInfrastructureError(ErrContractInvalid))
+
+	require.Equal(t, ErrorCodeUnknown, ErrorCodeOf(errors.New("plain error")))
+	require.Equal(t, ErrorCategoryUnknown, ErrorCategoryOf(nil))
+}
+
+func TestVMError_CodesAreUnique(t *testing.T) {
+	vmErrors := []*VMError{
+		ErrNilVMHost, ErrNotEnoughGas, ErrSignalError, ErrExecutionFailed,
+		ErrBadBounds, ErrBadLowerBounds, ErrBadUpperBounds, ErrFailedTransfer,
+		ErrContractInvalid, ErrContractNotFound, ErrMaxInstancesReached,
+		ErrStoreElrondReservedKey, ErrAsyncInit, ErrInvalidCallIDLength,
+		ErrEmptyElrondProtectedKeyPrefix, ErrInvalidVMVersionSchedule,
+		ErrHostFunctionNotActive, ErrInputAndOutputGasDoesNotMatch,
+		ErrCompiledCodeNotRestored, ErrDeployedCodeNotCompiled,
+	}
+
+	codes := make(map[ErrorCode]*VMError)
+	for _, vmError := range vmErrors {
+		require.Nil(t, codes[vmError.Code], vmError.Error())
+		require.Equal(t, ErrorCode(vmError.Category)*1000, vmError.Code/1000*1000, vmError.Error())
+		codes[vmError.Code] = vmError
+	}
+}
@@ -0,0 +1,79 @@
+package arwen
+
+import (
+	"encoding/hex"
+	"fmt"
+	"strings"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// DescribeVMOutput renders the VMOutput one fact per line, with the accounts,
+// the storage updates and the output transfers in their canonical order, so
+// that two VMOutputs can be compared line by line
+func DescribeVMOutput(vmOutput *vmcommon.VMOutput) []string {
+	if vmOutput == nil {
+		return []string{"nil VMOutput"}
+	}
+
+	lines := []string{
+		fmt.Sprintf("return code: %s", vmOutput.ReturnCode),
+		fmt.Sprintf("return message: %s", vmOutput.ReturnMessage),
+		fmt.Sprintf("gas remaining: %d", vmOutput.GasRemaining),
+		fmt.Sprintf("gas refund: %s", vmOutput.GasRefund),
+	}
+	for i, data := range vmOutput.ReturnData {
+		lines = append(lines, fmt.Sprintf("return data %d: %s", i, hex.EncodeToString(data)))
+	}
+
+	for _, account := range SortedOutputAccounts(vmOutput.OutputAccounts) {
+		address := hex.EncodeToString(account.Address)
+		lines = append(lines,
+			fmt.Sprintf("account %s: nonce %d, balance %s, balance delta %s, gas used %d", address, account.Nonce, account.Balance, account.BalanceDelta, account.GasUsed),
+			fmt.Sprintf("account %s: code %s, code metadata %s", address, hex.EncodeToString(account.Code), hex.EncodeToString(account.CodeMetadata)),
+			fmt.Sprintf("account %s: storage bytes added %d, deleted %d", address, account.BytesAddedToStorage, account.BytesDeletedFromStorage))
+		for _, update := range SortedStorageUpdates(account.StorageUpdates) {
+			lines = append(lines, fmt.Sprintf("account %s: storage %s = %s", address, hex.EncodeToString(update.Offset), hex.EncodeToString(update.Data)))
+		}
+	}
+
+	for i, addressed := range CanonicalOutputTransfers(vmOutput) {
+		lines = append(lines, fmt.Sprintf("output transfer %d: %s", i, hex.EncodeToString(EncodeOutputTransfers([]AddressedOutputTransfer{addressed}))))
+	}
+
+	for i, log := range vmOutput.Logs {
+		topics := make([]string, len(log.Topics))
+		for t, topic := range log.Topics {
+			topics[t] = hex.EncodeToString(topic)
+		}
+		lines = append(lines, fmt.Sprintf("log %d: address %s, identifier %s, topics [%s], data %s",
+			i, hex.EncodeToString(log.Address), log.Identifier, strings.Join(topics, " "), hex.EncodeToString(log.Data)))
+	}
+
+	for _, address := range vmOutput.DeletedAccounts {
+		lines = append(lines, fmt.Sprintf("deleted account %s", hex.EncodeToString(address)))
+	}
+	for _, address := range vmOutput.TouchedAccounts {
+		lines = append(lines, fmt.Sprintf("touched account %s", hex.EncodeToString(address)))
+	}
+	return lines
+}
+
+// FirstDifferentLine returns the first pair of lines which differ between two
+// descriptions, a line missing from the shorter one being shown as
+// <missing>, and false if the descriptions are the same
+func FirstDifferentLine(expected []string, actual []string) (string, string, bool) {
+	for i := 0; i < len(expected) || i < len(actual); i++ {
+		expectedLine, actualLine := "<missing>", "<missing>"
+		if i < len(expected) {
+			expectedLine = expected[i]
+		}
+		if i < len(actual) {
+			actualLine = actual[i]
+		}
+		if expectedLine != actualLine {
+			return expectedLine, actualLine, true
+		}
+	}
+	return "", "", false
+}
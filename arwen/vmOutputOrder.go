@@ -0,0 +1,66 @@
+package arwen
+
+import (
+	"bytes"
+	"sort"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// SortedOutputAccounts returns the output accounts of a VMOutput in ascending
+// order of their addresses, so that whoever walks over them, to apply or to
+// serialize them, does so in the same order on every node and on every run
+func SortedOutputAccounts(outputAccounts map[string]*vmcommon.OutputAccount) []*vmcommon.OutputAccount {
+	addresses := make([]string, 0, len(outputAccounts))
+	for address := range outputAccounts {
+		addresses = append(addresses, address)
+	}
+	sort.Strings(addresses)
+
+	accounts := make([]*vmcommon.OutputAccount, 0, len(addresses))
+	for _, address := range addresses {
+		accounts = append(accounts, outputAccounts[address])
+	}
+	return accounts
+}
+
+// SortedStorageUpdates returns the storage updates of an output account in
+// ascending order of their keys
+func SortedStorageUpdates(storageUpdates map[string]*vmcommon.StorageUpdate) []*vmcommon.StorageUpdate {
+	updates := make([]*vmcommon.StorageUpdate, 0, len(storageUpdates))
+	for _, update := range storageUpdates {
+		updates = append(updates, update)
+	}
+	sort.Slice(updates, func(i, j int) bool {
+		return bytes.Compare(updates[i].Offset, updates[j].Offset) < 0
+	})
+	return updates
+}
+
+// SortVMOutput puts the lists of accounts of a VMOutput which are sets rather
+// than sequences, the deleted and the touched accounts, in ascending order and
+// without duplicates
+func SortVMOutput(vmOutput *vmcommon.VMOutput) {
+	vmOutput.DeletedAccounts = sortedUniqueAddresses(vmOutput.DeletedAccounts)
+	vmOutput.TouchedAccounts = sortedUniqueAddresses(vmOutput.TouchedAccounts)
+}
+
+func sortedUniqueAddresses(addresses [][]byte) [][]byte {
+	if len(addresses) == 0 {
+		return addresses
+	}
+
+	sorted := make([][]byte, len(addresses))
+	copy(sorted, addresses)
+	sort.Slice(sorted, func(i, j int) bool {
+		return bytes.Compare(sorted[i], sorted[j]) < 0
+	})
+
+	unique := sorted[:1]
+	for _, address := range sorted[1:] {
+		if !bytes.Equal(address, unique[len(unique)-1]) {
+			unique = append(unique, address)
+		}
+	}
+	return unique
+}
@@ -0,0 +1,43 @@
+package arwen
+
+import (
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+func TestSortedOutputAccounts(t *testing.T) {
+	outputAccounts := make(map[string]*vmcommon.OutputAccount)
+	for _, address := range []string{"c", "a", "d", "b"} {
+		outputAccounts[address] = &vmcommon.OutputAccount{
+			Address: []byte(address),
+			StorageUpdates: map[string]*vmcommon.StorageUpdate{
+				"y": {Offset: []byte("y")},
+				"x": {Offset: []byte("x")},
+			},
+		}
+	}
+
+	accounts := SortedOutputAccounts(outputAccounts)
+	require.Len(t, accounts, 4)
+	for i, address := range []string{"a", "b", "c", "d"} {
+		require.Equal(t, []byte(address), accounts[i].Address)
+	}
+
+	updates := SortedStorageUpdates(accounts[0].StorageUpdates)
+	require.Equal(t, []byte("x"), updates[0].Offset)
+	require.Equal(t, []byte("y"), updates[1].Offset)
+}
+
+func TestSortVMOutput(t *testing.T) {
+	vmOutput := &vmcommon.VMOutput{
+		DeletedAccounts: [][]byte{[]byte("b"), []byte("a"), []byte("b")},
+		TouchedAccounts: make([][]byte, 0),
+	}
+
+	SortVMOutput(vmOutput)
+	require.Equal(t, [][]byte{[]byte("a"), []byte("b")}, vmOutput.DeletedAccounts)
+	require.NotNil(t, vmOutput.TouchedAccounts)
+	require.Len(t, vmOutput.TouchedAccounts, 0)
+}
@@ -0,0 +1,145 @@
+package arwen
+
+import (
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/ElrondNetwork/wasm-vm/math"
+)
+
+// LegacyVMVersionName is the name of the VM semantics before the async gas
+// locking accounted for recompilation and before cached storage reads became cheaper
+const LegacyVMVersionName = "legacy"
+
+// CurrentVMVersionName is the name of the current VM semantics
+const CurrentVMVersionName = "current"
+
+// VMVersion encapsulates the execution semantics which changed over the
+// history of the chain, so that old blocks are replayed as first executed
+type VMVersion interface {
+	Name() string
+	ComputeExtraGasLockedForAsync(gasSchedule *config.GasCost, codeSize uint64) uint64
+	IsStorageAPICostOptimized() bool
+	MemoryLimitReturnCode() vmcommon.ReturnCode
+	IsInterfaceNil() bool
+}
+
+type legacyVMVersion struct {
+}
+
+// NewLegacyVMVersion creates the VMVersion which locks only the execution
+// gas for async callbacks and always prices storage reads in full
+func NewLegacyVMVersion() VMVersion {
+	return &legacyVMVersion{}
+}
+
+// Name returns LegacyVMVersionName
+func (version *legacyVMVersion) Name() string {
+	return LegacyVMVersionName
+}
+
+// ComputeExtraGasLockedForAsync returns the minimum amount needed to execute the callback
+func (version *legacyVMVersion) ComputeExtraGasLockedForAsync(gasSchedule *config.GasCost, _ uint64) uint64 {
+	apiGasSchedule := gasSchedule.ElrondAPICost
+	return math.AddUint64(apiGasSchedule.AsyncCallStep, apiGasSchedule.AsyncCallbackGasLock)
+}
+
+// IsStorageAPICostOptimized returns false
+func (version *legacyVMVersion) IsStorageAPICostOptimized() bool {
+	return false
+}
+
+// MemoryLimitReturnCode returns ExecutionFailed, as memory limits were not told apart
+func (version *legacyVMVersion) MemoryLimitReturnCode() vmcommon.ReturnCode {
+	return vmcommon.ExecutionFailed
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (version *legacyVMVersion) IsInterfaceNil() bool {
+	return version == nil
+}
+
+type currentVMVersion struct {
+	enableEpochsHandler EnableEpochsHandler
+}
+
+// NewCurrentVMVersion creates the VMVersion of the current semantics, in
+// which the storage pricing and the memory limit return code still follow
+// the enable epochs flags
+func NewCurrentVMVersion(enableEpochsHandler EnableEpochsHandler) VMVersion {
+	return &currentVMVersion{
+		enableEpochsHandler: enableEpochsHandler,
+	}
+}
+
+// Name returns CurrentVMVersionName
+func (version *currentVMVersion) Name() string {
+	return CurrentVMVersionName
+}
+
+// ComputeExtraGasLockedForAsync returns the gas required to compile the
+// contract again, on top of the minimum amount needed to execute the callback
+func (version *currentVMVersion) ComputeExtraGasLockedForAsync(gasSchedule *config.GasCost, codeSize uint64) uint64 {
+	baseGasSchedule := gasSchedule.BaseOperationCost
+	apiGasSchedule := gasSchedule.ElrondAPICost
+	costPerByte := baseGasSchedule.AoTPreparePerByte
+
+	// Exact amount of gas required to compile this SC again, to execute the callback
+	compilationGasLock := math.MulUint64(codeSize, costPerByte)
+
+	// Minimum amount required to execute the callback
+	executionGasLock := math.AddUint64(apiGasSchedule.AsyncCallStep, apiGasSchedule.AsyncCallbackGasLock)
+	return math.AddUint64(compilationGasLock, executionGasLock)
+}
+
+// IsStorageAPICostOptimized returns whether cached storage reads are priced lower
+func (version *currentVMVersion) IsStorageAPICostOptimized() bool {
+	return version.enableEpochsHandler.IsStorageAPICostOptimizationFlagEnabled()
+}
+
+// MemoryLimitReturnCode returns MemoryLimitExceeded once the MemoryLimitReturnCode
+// flag is enabled, and ExecutionFailed before
+func (version *currentVMVersion) MemoryLimitReturnCode() vmcommon.ReturnCode {
+	if !version.enableEpochsHandler.IsMemoryLimitReturnCodeFlagEnabled() {
+		return vmcommon.ExecutionFailed
+	}
+	return MemoryLimitExceeded
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (version *currentVMVersion) IsInterfaceNil() bool {
+	return version == nil
+}
+
+// VMVersionActivation marks the epoch from which a VMVersion is used
+type VMVersionActivation struct {
+	StartEpoch uint32
+	Version    VMVersion
+}
+
+// VMVersionSchedule lists the VM versions in ascending order of their start epochs
+type VMVersionSchedule []VMVersionActivation
+
+// Check verifies that the schedule is ordered and holds no nil versions
+func (schedule VMVersionSchedule) Check() error {
+	for i, activation := range schedule {
+		if activation.Version == nil || activation.Version.IsInterfaceNil() {
+			return ErrInvalidVMVersionSchedule
+		}
+		if i > 0 && activation.StartEpoch <= schedule[i-1].StartEpoch {
+			return ErrInvalidVMVersionSchedule
+		}
+	}
+	return nil
+}
+
+// VersionForEpoch returns the VM version of the blocks of the given epoch, if scheduled
+func (schedule VMVersionSchedule) VersionForEpoch(epoch uint32) (VMVersion, bool) {
+	var version VMVersion
+	for _, activation := range schedule {
+		if activation.StartEpoch > epoch {
+			break
+		}
+		version = activation.Version
+	}
+	return version, version != nil
+}
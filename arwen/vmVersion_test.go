@@ -0,0 +1,65 @@
+package arwen
+
+import (
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+func TestVMVersionSchedule_VersionForEpoch(t *testing.T) {
+	t.Parallel()
+
+	legacy := NewLegacyVMVersion()
+	current := NewCurrentVMVersion(worldmock.EnableEpochsHandlerStubAllFlags())
+	schedule := VMVersionSchedule{
+		{StartEpoch: 5, Version: legacy},
+		{StartEpoch: 10, Version: current},
+	}
+	require.Nil(t, schedule.Check())
+
+	_, ok := schedule.VersionForEpoch(4)
+	require.False(t, ok)
+
+	version, ok := schedule.VersionForEpoch(5)
+	require.True(t, ok)
+	require.Equal(t, LegacyVMVersionName, version.Name())
+
+	version, _ = schedule.VersionForEpoch(9)
+	require.Equal(t, LegacyVMVersionName, version.Name())
+
+	version, _ = schedule.VersionForEpoch(10)
+	require.Equal(t, CurrentVMVersionName, version.Name())
+
+	unordered := VMVersionSchedule{schedule[1], schedule[0]}
+	require.Equal(t, ErrInvalidVMVersionSchedule, unordered.Check())
+	require.Equal(t, ErrInvalidVMVersionSchedule, VMVersionSchedule{{StartEpoch: 0}}.Check())
+}
+
+func TestVMVersion_ComputeExtraGasLockedForAsync(t *testing.T) {
+	t.Parallel()
+
+	gasSchedule, err := config.CreateGasConfig(config.MakeGasMapForTests())
+	require.Nil(t, err)
+	gasSchedule.BaseOperationCost.AoTPreparePerByte = 2
+	gasSchedule.ElrondAPICost.AsyncCallStep = 10
+	gasSchedule.ElrondAPICost.AsyncCallbackGasLock = 100
+
+	legacy := NewLegacyVMVersion()
+	require.Equal(t, uint64(110), legacy.ComputeExtraGasLockedForAsync(gasSchedule, 50))
+	require.False(t, legacy.IsStorageAPICostOptimized())
+	require.Equal(t, vmcommon.ExecutionFailed, legacy.MemoryLimitReturnCode())
+
+	current := NewCurrentVMVersion(worldmock.EnableEpochsHandlerStubAllFlags())
+	require.Equal(t, uint64(210), current.ComputeExtraGasLockedForAsync(gasSchedule, 50))
+	require.True(t, current.IsStorageAPICostOptimized())
+	require.Equal(t, MemoryLimitExceeded, current.MemoryLimitReturnCode())
+
+	enableEpochsHandler := worldmock.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.MemoryLimitReturnCodeEnableEpochField = 2
+	enableEpochsHandler.CurrentEpochField = 1
+	current = NewCurrentVMVersion(enableEpochsHandler)
+	require.Equal(t, vmcommon.ExecutionFailed, current.MemoryLimitReturnCode())
+}
@@ -0,0 +1,48 @@
+package arwen
+
+// WarmUpResult is the outcome of warming up one contract with VMHost.WarmUp
+type WarmUpResult struct {
+	Address  []byte
+	CodeHash []byte
+
+	// Source tells how the warm instance was obtained: InstanceFromWarmCache
+	// if the contract was already warm, InstanceFromCompiledCode if the node
+	// had its compiled code, InstanceFromBytecode if it was compiled now
+	Source InstanceSource
+
+	// Err is the reason why the contract could not be warmed up, if any
+	Err error
+}
+
+// CacheStatistics describes the caches of the runtime, so that the operators
+// can verify the effectiveness of a warm-up
+type CacheStatistics struct {
+	WarmInstances         int
+	WarmInstancesCapacity int
+
+	// InstanceCreations counts the instances started by the executions since
+	// the host was created, by the source they were obtained from; the
+	// instances created by the warm-ups are not counted
+	InstanceCreations map[InstanceSource]uint64
+
+	// WarmedUpContracts counts the contracts added to the warm instance cache
+	// by the warm-ups
+	WarmedUpContracts uint64
+}
+
+// CacheHitRatio returns the share of the instance creations which did not
+// compile the contract, or 0 if there were none
+func (statistics CacheStatistics) CacheHitRatio() float64 {
+	total := uint64(0)
+	hits := uint64(0)
+	for source, count := range statistics.InstanceCreations {
+		total += count
+		if source.IsCacheHit() {
+			hits += count
+		}
+	}
+	if total == 0 {
+		return 0
+	}
+	return float64(hits) / float64(total)
+}
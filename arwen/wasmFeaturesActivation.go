@@ -0,0 +1,19 @@
+package arwen
+
+import "github.com/ElrondNetwork/wasm-vm/inspect"
+
+// DisabledWasmFeatures returns the wasm proposals which the contracts
+// deployed in the given epoch are not yet allowed to use
+func DisabledWasmFeatures(enableEpochsHandler EnableEpochsHandler, epoch uint32) inspect.Feature {
+	disabled := inspect.NoFeatures
+	if epoch < enableEpochsHandler.SignExtensionEnableEpoch() {
+		disabled |= inspect.FeatureSignExtension
+	}
+	if epoch < enableEpochsHandler.BulkMemoryEnableEpoch() {
+		disabled |= inspect.FeatureBulkMemory
+	}
+	if epoch < enableEpochsHandler.ReferenceTypesEnableEpoch() {
+		disabled |= inspect.FeatureReferenceTypes
+	}
+	return disabled
+}
@@ -2,3 +2,6 @@ package arwendebug
 
 // DefaultGasPrice is the default gas price for debugging
 const DefaultGasPrice = 200000000000
+
+// DefaultBlockTimeDelta is the number of seconds between the blocks produced by a debugging Session
+const DefaultBlockTimeDelta = 6
@@ -44,3 +44,6 @@ var ErrInvalidArgumentEncoding = errors.New("invalid contract argument encoding"
 
 // ErrAccountDoesntExist signals an error
 var ErrAccountDoesntExist = errors.New("account does not exist")
+
+// ErrNothingToUndo signals an error
+var ErrNothingToUndo = errors.New("no step to undo")
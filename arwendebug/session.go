@@ -0,0 +1,190 @@
+package arwendebug
+
+import (
+	"io"
+
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+)
+
+// Session is an in-memory debugging world, meant as the backend of an
+// interactive debugging tool: unlike the DebugFacade, it does not persist the
+// world between the requests, and it can undo its steps. The fields of the
+// requests which select a database, a world or an outcome are ignored.
+type Session struct {
+	world   *world
+	history []*sessionSnapshot
+}
+
+// sessionSnapshot is the state of the world before a step of a Session
+type sessionSnapshot struct {
+	accounts                   worldmock.AccountMap
+	previousBlockInfo          *worldmock.BlockInfo
+	currentBlockInfo           *worldmock.BlockInfo
+	blockhashes                [][]byte
+	lastCreatedContractAddress []byte
+}
+
+// NewSession creates a new Session with an empty world
+func NewSession() (*Session, error) {
+	world, err := newWorld(newWorldDataModel("session"))
+	if err != nil {
+		return nil, err
+	}
+
+	return &Session{
+		world:   world,
+		history: make([]*sessionSnapshot, 0),
+	}, nil
+}
+
+// CreateAccount creates or replaces an account
+func (s *Session) CreateAccount(request CreateAccountRequest) (*CreateAccountResponse, error) {
+	err := request.digest()
+	if err != nil {
+		return nil, err
+	}
+
+	s.takeSnapshot()
+	return s.world.createAccount(request), nil
+}
+
+// DeploySmartContract deploys a smart contract
+func (s *Session) DeploySmartContract(request DeployRequest) (*DeployResponse, error) {
+	err := request.digest()
+	if err != nil {
+		return nil, err
+	}
+
+	s.takeSnapshot()
+	response := s.world.deploySmartContract(request)
+	return response, nil
+}
+
+// UpgradeSmartContract upgrades a smart contract
+func (s *Session) UpgradeSmartContract(request UpgradeRequest) (*UpgradeResponse, error) {
+	err := request.digest()
+	if err != nil {
+		return nil, err
+	}
+
+	s.takeSnapshot()
+	response := s.world.upgradeSmartContract(request)
+	return response, nil
+}
+
+// RunSmartContract calls an endpoint of a smart contract
+func (s *Session) RunSmartContract(request RunRequest) (*RunResponse, error) {
+	err := request.digest()
+	if err != nil {
+		return nil, err
+	}
+
+	s.takeSnapshot()
+	response := s.world.runSmartContract(request)
+	return response, nil
+}
+
+// QuerySmartContract calls an endpoint of a smart contract without changing
+// the world, so it is not a step which can be undone
+func (s *Session) QuerySmartContract(request QueryRequest) (*QueryResponse, error) {
+	err := request.digest()
+	if err != nil {
+		return nil, err
+	}
+
+	response := s.world.querySmartContract(request)
+	return response, nil
+}
+
+// GetAccount returns the account with the given address
+func (s *Session) GetAccount(address []byte) (*worldmock.Account, error) {
+	account := s.world.blockchainHook.AcctMap.GetAccount(address)
+	if account == nil {
+		return nil, ErrAccountDoesntExist
+	}
+	return account, nil
+}
+
+// GetStorage returns the value stored under the key by the account with the
+// given address, empty if nothing is stored
+func (s *Session) GetStorage(address []byte, key []byte) ([]byte, error) {
+	account, err := s.GetAccount(address)
+	if err != nil {
+		return nil, err
+	}
+	return account.StorageValue(string(key)), nil
+}
+
+// CurrentBlock returns the block in which the next steps are executed
+func (s *Session) CurrentBlock() worldmock.BlockInfo {
+	if s.world.blockchainHook.CurrentBlockInfo == nil {
+		return worldmock.BlockInfo{}
+	}
+	return *s.world.blockchainHook.CurrentBlockInfo
+}
+
+// AdvanceBlocks moves the world the given number of blocks forward, within
+// the same epoch, DefaultBlockTimeDelta seconds apart
+func (s *Session) AdvanceBlocks(count uint64) {
+	s.takeSnapshot()
+
+	previous := s.CurrentBlock()
+	current := previous
+	current.BlockNonce += count
+	current.BlockRound += count
+	current.BlockTimestamp += count * DefaultBlockTimeDelta
+
+	s.world.blockchainHook.PreviousBlockInfo = &previous
+	s.world.blockchainHook.CurrentBlockInfo = &current
+}
+
+// NumSteps returns the number of steps which can be undone
+func (s *Session) NumSteps() int {
+	return len(s.history)
+}
+
+// Undo restores the world as it was before the last step
+func (s *Session) Undo() error {
+	if len(s.history) == 0 {
+		return ErrNothingToUndo
+	}
+
+	snapshot := s.history[len(s.history)-1]
+	s.history = s.history[:len(s.history)-1]
+
+	blockchainHook := s.world.blockchainHook
+	blockchainHook.AcctMap = snapshot.accounts
+	blockchainHook.PreviousBlockInfo = snapshot.previousBlockInfo
+	blockchainHook.CurrentBlockInfo = snapshot.currentBlockInfo
+	blockchainHook.Blockhashes = snapshot.blockhashes
+	blockchainHook.LastCreatedContractAddress = snapshot.lastCreatedContractAddress
+	return nil
+}
+
+// Close releases the VM of the session
+func (s *Session) Close() error {
+	vmAsClose, ok := s.world.vm.(io.Closer)
+	if !ok {
+		return nil
+	}
+	return vmAsClose.Close()
+}
+
+func (s *Session) takeSnapshot() {
+	blockchainHook := s.world.blockchainHook
+	s.history = append(s.history, &sessionSnapshot{
+		accounts:                   blockchainHook.AcctMap.Clone(),
+		previousBlockInfo:          cloneBlockInfo(blockchainHook.PreviousBlockInfo),
+		currentBlockInfo:           cloneBlockInfo(blockchainHook.CurrentBlockInfo),
+		blockhashes:                append([][]byte{}, blockchainHook.Blockhashes...),
+		lastCreatedContractAddress: blockchainHook.LastCreatedContractAddress,
+	})
+}
+
+func cloneBlockInfo(blockInfo *worldmock.BlockInfo) *worldmock.BlockInfo {
+	if blockInfo == nil {
+		return nil
+	}
+	clone := *blockInfo
+	return &clone
+}
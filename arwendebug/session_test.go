@@ -0,0 +1,89 @@
+package arwendebug
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestSession_DeployCallAndUndo(t *testing.T) {
+	session, err := NewSession()
+	require.Nil(t, err)
+	defer func() {
+		_ = session.Close()
+	}()
+
+	alice := newDummyAddress("alice")
+	_, err = session.CreateAccount(CreateAccountRequest{AddressHex: alice.hex, Balance: "42"})
+	require.Nil(t, err)
+
+	deployResponse, err := session.DeploySmartContract(DeployRequest{
+		ContractRequestBase: ContractRequestBase{ImpersonatedHex: alice.hex, GasLimit: gasLimit},
+		CodePath:            wasmCounterPath,
+	})
+	require.Nil(t, err)
+	require.Nil(t, deployResponse.Error)
+	contractAddress := deployResponse.ContractAddress
+
+	callCounter := RunRequest{
+		ContractRequestBase: ContractRequestBase{ImpersonatedHex: alice.hex, GasLimit: gasLimit},
+		ContractAddressHex:  deployResponse.ContractAddressHex,
+		Function:            "increment",
+	}
+	_, err = session.RunSmartContract(callCounter)
+	require.Nil(t, err)
+	_, err = session.RunSmartContract(callCounter)
+	require.Nil(t, err)
+	require.Equal(t, 4, session.NumSteps())
+
+	counter, err := session.GetStorage(contractAddress, []byte("COUNTER"))
+	require.Nil(t, err)
+	require.Equal(t, []byte{3}, counter)
+
+	callCounter.Function = "get"
+	queryResponse, err := session.QuerySmartContract(QueryRequest{RunRequest: callCounter})
+	require.Nil(t, err)
+	require.Equal(t, int64(3), queryResponse.getFirstResultAsInt64())
+	require.Equal(t, 4, session.NumSteps())
+
+	err = session.Undo()
+	require.Nil(t, err)
+	counter, err = session.GetStorage(contractAddress, []byte("COUNTER"))
+	require.Nil(t, err)
+	require.Equal(t, []byte{2}, counter)
+
+	err = session.Undo()
+	require.Nil(t, err)
+	err = session.Undo()
+	require.Nil(t, err)
+	_, err = session.GetAccount(contractAddress)
+	require.Equal(t, ErrAccountDoesntExist, err)
+
+	err = session.Undo()
+	require.Nil(t, err)
+	_, err = session.GetAccount(alice.raw)
+	require.Equal(t, ErrAccountDoesntExist, err)
+
+	err = session.Undo()
+	require.Equal(t, ErrNothingToUndo, err)
+}
+
+func TestSession_AdvanceBlocks(t *testing.T) {
+	session, err := NewSession()
+	require.Nil(t, err)
+	defer func() {
+		_ = session.Close()
+	}()
+
+	session.AdvanceBlocks(3)
+	session.AdvanceBlocks(2)
+	block := session.CurrentBlock()
+	require.Equal(t, uint64(5), block.BlockNonce)
+	require.Equal(t, uint64(5), block.BlockRound)
+	require.Equal(t, uint64(5*DefaultBlockTimeDelta), block.BlockTimestamp)
+	require.Equal(t, uint64(3), session.world.blockchainHook.PreviousBlockInfo.BlockNonce)
+
+	err = session.Undo()
+	require.Nil(t, err)
+	require.Equal(t, uint64(3), session.CurrentBlock().BlockNonce)
+}
@@ -34,6 +34,7 @@ type ArwenTestExecutor struct {
 	scenarioTraceGas   []bool
 	fileResolver       fr.FileResolver
 	exprReconstructor  er.ExprReconstructor
+	customEnableEpochs bool
 }
 
 var _ mc.TestExecutor = (*ArwenTestExecutor)(nil)
@@ -84,7 +85,7 @@ func (ae *ArwenTestExecutor) InitVM(mandosGasSchedule mj.GasSchedule) error {
 			ElrondProtectedKeyPrefix: []byte(ElrondProtectedKeyPrefix),
 			ESDTTransferParser:       esdtTransferParser,
 			EpochNotifier:            &mock.EpochNotifierStub{},
-			EnableEpochsHandler:      worldhook.EnableEpochsHandlerStubAllFlags(),
+			EnableEpochsHandler:      ae.newEnableEpochsHandler(),
 			WasmerSIGSEGVPassthrough: false,
 		})
 	if err != nil {
@@ -96,6 +97,14 @@ func (ae *ArwenTestExecutor) InitVM(mandosGasSchedule mj.GasSchedule) error {
 	return nil
 }
 
+// newEnableEpochsHandler enables all the flags, with the VM features activated
+// from genesis, following the epoch of the world
+func (ae *ArwenTestExecutor) newEnableEpochsHandler() *worldhook.EnableEpochsHandlerStub {
+	enableEpochsHandler := worldhook.EnableEpochsHandlerStubAllFlags()
+	enableEpochsHandler.CurrentEpochCalled = ae.World.CurrentEpoch
+	return enableEpochsHandler
+}
+
 // GetVM yields a reference to the VMExecutionHandler used.
 func (ae *ArwenTestExecutor) GetVM() vmi.VMExecutionHandler {
 	return ae.vm
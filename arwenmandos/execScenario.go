@@ -14,6 +14,14 @@ import (
 // Reset clears state/world.
 // Is called in RunAllJSONScenariosInDirectory, but not in RunSingleJSONScenario.
 func (ae *ArwenTestExecutor) Reset() {
+	ae.resetWorld()
+	if !check.IfNil(ae.vmHost) {
+		ae.resetEnableEpochs()
+	}
+}
+
+// resetWorld clears the world, keeping the activation epochs of the VM features
+func (ae *ArwenTestExecutor) resetWorld() {
 	if !check.IfNil(ae.vmHost) {
 		ae.vmHost.Reset()
 	}
@@ -38,6 +46,11 @@ func (ae *ArwenTestExecutor) ExecuteScenario(scenario *mj.Scenario, fileResolver
 		return err
 	}
 
+	err = ae.applyEnableEpochs(scenario.EnableEpochs)
+	if err != nil {
+		return err
+	}
+
 	txIndex := 0
 	for _, generalStep := range scenario.Steps {
 		setGasTraceInMetering(ae, true)
@@ -45,6 +58,10 @@ func (ae *ArwenTestExecutor) ExecuteScenario(scenario *mj.Scenario, fileResolver
 		if err != nil {
 			return err
 		}
+		err = ae.CheckInvariants(scenario.Steps[:txIndex+1])
+		if err != nil {
+			return err
+		}
 		setGasTraceInMetering(ae, false)
 		txIndex++
 	}
@@ -70,6 +87,8 @@ func (ae *ArwenTestExecutor) ExecuteStep(generalStep mj.Step) error {
 		_, err = ae.ExecuteTxStep(step)
 	case *mj.DumpStateStep:
 		err = ae.DumpWorld()
+	case *mj.AdvanceBlocksStep:
+		ae.ExecuteAdvanceBlocksStep(step)
 	}
 
 	logGasTrace(ae)
@@ -139,6 +158,53 @@ func (ae *ArwenTestExecutor) ExecuteSetStateStep(step *mj.SetStateStep) error {
 	return nil
 }
 
+// ExecuteAdvanceBlocksStep executes an AdvanceBlocksStep.
+func (ae *ArwenTestExecutor) ExecuteAdvanceBlocksStep(step *mj.AdvanceBlocksStep) {
+	if len(step.Comment) > 0 {
+		log.Trace("AdvanceBlocksStep", "comment", step.Comment)
+	}
+
+	increments := convertBlockInfo(&step.BlockInfo, nil)
+	ae.World.AdvanceBlocks(*increments)
+}
+
+// applyEnableEpochs replaces the activation epochs of the VM features with
+// those of the scenario, if it has any; otherwise the scenario keeps those in
+// use, so that external steps run with the epochs of the including scenario
+func (ae *ArwenTestExecutor) applyEnableEpochs(mandosEnableEpochs []*mj.EnableEpoch) error {
+	if len(mandosEnableEpochs) == 0 {
+		return nil
+	}
+
+	enableEpochsHandler := ae.newEnableEpochsHandler()
+	err := convertEnableEpochs(mandosEnableEpochs, enableEpochsHandler)
+	if err != nil {
+		return err
+	}
+	err = ae.vmHost.UpdateConfig(ae.vmHost.GetGasScheduleMap(), enableEpochsHandler)
+	if err != nil {
+		return err
+	}
+
+	ae.customEnableEpochs = true
+	return nil
+}
+
+// resetEnableEpochs activates all the VM features again, after a scenario
+// which specified its own activation epochs
+func (ae *ArwenTestExecutor) resetEnableEpochs() {
+	if !ae.customEnableEpochs {
+		return
+	}
+
+	err := ae.vmHost.UpdateConfig(ae.vmHost.GetGasScheduleMap(), ae.newEnableEpochsHandler())
+	if err != nil {
+		log.Error("could not reset the enable epochs", "error", err)
+		return
+	}
+	ae.customEnableEpochs = false
+}
+
 // ExecuteTxStep executes a TxStep.
 func (ae *ArwenTestExecutor) ExecuteTxStep(step *mj.TxStep) (*vmi.VMOutput, error) {
 	log.Trace("ExecuteTxStep", "id", step.TxIdent)
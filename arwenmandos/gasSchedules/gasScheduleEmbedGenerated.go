@@ -798,6 +798,12 @@ const (
     SetAsyncGroupCallback   = 100000
     SetAsyncContextCallback = 100000
     GetCallbackClosure      = 100000
+
+    # Charged per byte of the call data of each async call or promise created
+    # by a contract, once the async calls are validated when created; a zero
+    # or missing cost is not charged.
+    AsyncCallDataPerByte = 0
+
     ExecuteReadOnly      = 160000
     CreateContract       = 300000
     GetReturnData        = 100
@@ -806,6 +812,15 @@ const (
     CleanReturnData      = 100
     DeleteFromReturnData = 100
 
+    # Output limits of each contract execution rather than gas costs; a zero
+    # or missing limit is not enforced. MaxReturnDataSize bounds the total
+    # bytes finished by the contract and stops it with ReturnDataTooLarge.
+    # MaxNumberOfLogs bounds the logs written by the contract and MaxLogSize
+    # the bytes of the topics and data of each log; both stop it with TooManyLogs.
+    MaxReturnDataSize = 0
+    MaxNumberOfLogs   = 0
+    MaxLogSize        = 0
+
 [EthAPICost]
     UseGas              = 100
     GetAddress          = 100000
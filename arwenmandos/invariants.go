@@ -0,0 +1,96 @@
+package arwenmandos
+
+import (
+	"errors"
+	"fmt"
+
+	mjwrite "github.com/ElrondNetwork/wasm-vm/mandos-go/json/write"
+	mj "github.com/ElrondNetwork/wasm-vm/mandos-go/model"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+)
+
+// InvariantViolationError is returned when an invariant of the world does not
+// hold after a step. It carries the shortest sequence of steps found which
+// still breaks the invariant when replayed on a cleared world.
+type InvariantViolationError struct {
+	Violation      *worldmock.InvariantViolation
+	MinimizedSteps []mj.Step
+}
+
+// Error returns the violation, followed by the minimized steps as a scenario
+func (violationError *InvariantViolationError) Error() string {
+	minimizedScenario := &mj.Scenario{
+		Name:  "minimized violation of invariant " + violationError.Violation.Name,
+		Steps: violationError.MinimizedSteps,
+	}
+	return fmt.Sprintf("%s, reproduced by:\n%s",
+		violationError.Violation.Error(),
+		mjwrite.ScenarioToJSONString(minimizedScenario))
+}
+
+// Unwrap returns the violation reported by the world
+func (violationError *InvariantViolationError) Unwrap() error {
+	return violationError.Violation
+}
+
+// CheckInvariants checks the invariants of the world after the given steps.
+// On a violation, the steps are minimized by replaying them on a cleared world,
+// which therefore does not keep the state reached by the given steps.
+func (ae *ArwenTestExecutor) CheckInvariants(steps []mj.Step) error {
+	err := ae.World.CheckInvariants()
+	if err == nil {
+		return nil
+	}
+
+	var violation *worldmock.InvariantViolation
+	if !errors.As(err, &violation) {
+		return err
+	}
+
+	return &InvariantViolationError{
+		Violation:      violation,
+		MinimizedSteps: ae.minimizeViolatingSteps(steps, violation.Name),
+	}
+}
+
+// minimizeViolatingSteps drops, one at a time, the steps without which the
+// invariant still breaks. The checks and the dumps are dropped from the start,
+// since they do not change the world.
+func (ae *ArwenTestExecutor) minimizeViolatingSteps(steps []mj.Step, invariantName string) []mj.Step {
+	minimized := make([]mj.Step, 0, len(steps))
+	for _, step := range steps {
+		switch step.(type) {
+		case *mj.CheckStateStep, *mj.DumpStateStep:
+			continue
+		}
+		minimized = append(minimized, step)
+	}
+
+	for i := len(minimized) - 1; i >= 0; i-- {
+		candidate := append(append([]mj.Step{}, minimized[:i]...), minimized[i+1:]...)
+		if ae.replayBreaksInvariant(candidate, invariantName) {
+			minimized = candidate
+		}
+	}
+	return minimized
+}
+
+// replayBreaksInvariant executes the steps on a cleared world and returns true
+// if the invariant breaks before any step fails; external steps report the
+// violations of their own steps as errors
+func (ae *ArwenTestExecutor) replayBreaksInvariant(steps []mj.Step, invariantName string) bool {
+	ae.resetWorld()
+	for _, step := range steps {
+		err := ae.ExecuteStep(step)
+		if err == nil {
+			err = ae.World.CheckInvariants()
+		}
+		if err == nil {
+			continue
+		}
+
+		var violation *worldmock.InvariantViolation
+		return errors.As(err, &violation) && violation.Name == invariantName
+	}
+	return false
+}
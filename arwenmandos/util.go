@@ -3,7 +3,9 @@ package arwenmandos
 import (
 	"errors"
 	"fmt"
+	"math"
 	"math/big"
+	"reflect"
 
 	"github.com/ElrondNetwork/wasm-vm/mandos-go/esdtconvert"
 	er "github.com/ElrondNetwork/wasm-vm/mandos-go/expression/reconstructor"
@@ -87,6 +89,21 @@ func convertNewAddressMocks(testNAMs []*mj.NewAddressMock) []*worldmock.NewAddre
 	return result
 }
 
+func convertEnableEpochs(mandosEnableEpochs []*mj.EnableEpoch, enableEpochsHandler *worldmock.EnableEpochsHandlerStub) error {
+	enableEpochsHandlerValue := reflect.ValueOf(enableEpochsHandler).Elem()
+	for _, mandosEnableEpoch := range mandosEnableEpochs {
+		field := enableEpochsHandlerValue.FieldByName(mandosEnableEpoch.Name + "Field")
+		if !field.IsValid() || field.Kind() != reflect.Uint32 {
+			return fmt.Errorf("unknown enable epoch: %s", mandosEnableEpoch.Name)
+		}
+		if mandosEnableEpoch.Epoch.Value > math.MaxUint32 {
+			return fmt.Errorf("enable epoch %s is too large", mandosEnableEpoch.Name)
+		}
+		field.SetUint(mandosEnableEpoch.Epoch.Value)
+	}
+	return nil
+}
+
 func convertBlockInfo(testBlockInfo *mj.BlockInfo, currentInfo *worldmock.BlockInfo) *worldmock.BlockInfo {
 	if testBlockInfo == nil {
 		return currentInfo
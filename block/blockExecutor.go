@@ -0,0 +1,325 @@
+package block
+
+import (
+	"errors"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	logger "github.com/ElrondNetwork/elrond-go-logger"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/fees"
+	"github.com/ElrondNetwork/wasm-vm/math"
+	"github.com/ElrondNetwork/wasm-vm/receipts"
+)
+
+var log = logger.GetOrCreate("arwen/block")
+
+// ErrNilStateApplier signals that a nil StateApplier was provided
+var ErrNilStateApplier = errors.New("nil state applier")
+
+// ErrNilBlockContext signals that a block was executed without its context
+var ErrNilBlockContext = errors.New("nil block context")
+
+// ErrInvalidTransaction signals that a transaction is neither a call nor a deployment, or is both
+var ErrInvalidTransaction = errors.New("transaction must have exactly one of call or create input")
+
+// Transaction is a single transaction of a block, either a contract call or a
+// contract deployment
+type Transaction struct {
+	Hash   []byte
+	// Nonce is the nonce of the sender; the transactions of a sender are
+	// executed by ascending nonce, whatever the ordering strategy
+	Nonce  uint64
+	Call   *vmcommon.ContractCallInput
+	Create *vmcommon.ContractCreateInput
+}
+
+// BlockContext describes the block in which the transactions are executed
+type BlockContext struct {
+	Nonce      uint64
+	Round      uint64
+	Epoch      uint32
+	TimeStamp  uint64
+	RandomSeed []byte
+	// GasLimit is the gas pool of the block, the maximum gas used by all the
+	// transactions of the block together; 0 means unlimited
+	GasLimit   uint64
+}
+
+// BlockResult holds the outcome of executing a block
+type BlockResult struct {
+	Receipts              []*Receipt
+	// NotExecuted are the transactions which provided more gas than what was
+	// left in the gas pool of the block when their turn came, followed by
+	// the later transactions of their senders; they have no receipts and
+	// left the state untouched, so that the proposer may include them in a
+	// later block
+	NotExecuted           []*Transaction
+	ReceiptsRootHash      []byte
+	StateDiff             *StateDiff
+	StateRootHash         []byte
+	TotalGasUsed          uint64
+	TotalFees             *big.Int
+	DeveloperRewards      map[string]*big.Int
+	TotalDeveloperRewards *big.Int
+}
+
+// ArgsNewBlockExecutor holds the arguments for creating a BlockExecutor
+type ArgsNewBlockExecutor struct {
+	Host          arwen.VMHost
+	State         StateApplier
+	// BlockInfo is optional and, if set, receives the block context before
+	// the transactions are executed
+	BlockInfo     BlockInfoHandler
+	FeeComputer   *fees.FeeComputer
+	// PromiseExpiry is optional and, if set, follows the async calls created
+	// with an expiry; the timeout callbacks of the expired ones are executed
+	// at the beginning of each block, before the transactions
+	PromiseExpiry *PromiseExpiryTracker
+	// Orderer is optional and, if set, decides the order in which the
+	// transactions of each block are executed; otherwise they are executed
+	// in the given order
+	Orderer       TransactionOrderer
+}
+
+// BlockExecutor executes all the transactions of a block on a VM host, one
+// after the other, applying the output of each before running the next
+type BlockExecutor struct {
+	host          arwen.VMHost
+	state         StateApplier
+	blockInfo     BlockInfoHandler
+	feeComputer   *fees.FeeComputer
+	promiseExpiry *PromiseExpiryTracker
+	orderer       TransactionOrderer
+}
+
+// NewBlockExecutor creates a new BlockExecutor
+func NewBlockExecutor(args ArgsNewBlockExecutor) (*BlockExecutor, error) {
+	if check.IfNil(args.Host) {
+		return nil, arwen.ErrNilVMHost
+	}
+	if args.State == nil {
+		return nil, ErrNilStateApplier
+	}
+	if args.FeeComputer == nil {
+		return nil, fees.ErrNilFeeComputer
+	}
+
+	return &BlockExecutor{
+		host:          args.Host,
+		state:         args.State,
+		blockInfo:     args.BlockInfo,
+		feeComputer:   args.FeeComputer,
+		promiseExpiry: args.PromiseExpiry,
+		orderer:       args.Orderer,
+	}, nil
+}
+
+// ExecuteBlock runs the transactions in order and returns their receipts,
+// together with the aggregated state diff, gas, fees and developer rewards.
+// An error is returned only if the block itself cannot be executed; failed
+// transactions are reported in their receipts, while the transactions which
+// do not fit in the remaining gas pool of the block are skipped and returned
+// as not executed, together with the following transactions of their sender.
+// The transactions are executed in the order decided by the ordering strategy,
+// if any, but always by ascending nonce for each sender.
+func (executor *BlockExecutor) ExecuteBlock(txs []*Transaction, blockContext *BlockContext) (*BlockResult, error) {
+	if blockContext == nil {
+		return nil, ErrNilBlockContext
+	}
+
+	err := checkTransactions(txs)
+	if err != nil {
+		return nil, err
+	}
+
+	txs, err = orderTransactions(executor.orderer, txs)
+	if err != nil {
+		return nil, err
+	}
+
+	if executor.blockInfo != nil {
+		executor.blockInfo.SetCurrentBlockInfo(blockContext.Nonce, blockContext.Round, blockContext.Epoch, blockContext.TimeStamp, blockContext.RandomSeed)
+	}
+
+	result := &BlockResult{
+		Receipts:              make([]*Receipt, 0, len(txs)),
+		NotExecuted:           make([]*Transaction, 0),
+		StateDiff:             NewStateDiff(),
+		TotalFees:             big.NewInt(0),
+		DeveloperRewards:      make(map[string]*big.Int),
+		TotalDeveloperRewards: big.NewInt(0),
+	}
+
+	for _, tx := range executor.timeoutCallbacks(blockContext) {
+		receipt, err := executor.executeTransaction(tx, result)
+		if err != nil {
+			return nil, err
+		}
+		result.Receipts = append(result.Receipts, receipt)
+		result.StateRootHash = receipt.StateRootHash
+	}
+
+	skippedSenders := make(map[string]bool)
+	for _, tx := range txs {
+		if skippedSenders[tx.sender()] || !fitsInGasPool(tx, result, blockContext) {
+			skippedSenders[tx.sender()] = true
+			result.NotExecuted = append(result.NotExecuted, tx)
+			continue
+		}
+
+		receipt, err := executor.executeTransaction(tx, result)
+		if err != nil {
+			return nil, err
+		}
+		result.Receipts = append(result.Receipts, receipt)
+		result.StateRootHash = receipt.StateRootHash
+	}
+
+	result.ReceiptsRootHash, err = ComputeReceiptsRootHash(result.Receipts)
+	if err != nil {
+		return nil, err
+	}
+
+	log.Trace("ExecuteBlock",
+		"nonce", blockContext.Nonce,
+		"txs", len(txs),
+		"gas used", result.TotalGasUsed,
+		"not executed", len(result.NotExecuted),
+		"fees", result.TotalFees)
+
+	return result, nil
+}
+
+func checkTransactions(txs []*Transaction) error {
+	for _, tx := range txs {
+		_, err := tx.vmInput()
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// fitsInGasPool tells whether the gas provided to a transaction is available
+// in the gas pool of the block, which is left with the block gas limit minus
+// the gas used so far; only the gas used is taken from the pool, so the
+// remaining gas of a transaction is available to the next ones
+func fitsInGasPool(tx *Transaction, result *BlockResult, blockContext *BlockContext) bool {
+	if blockContext.GasLimit == 0 {
+		return true
+	}
+
+	vmInput, _ := tx.vmInput()
+	remainingGas := math.SubUint64(blockContext.GasLimit, result.TotalGasUsed)
+	return vmInput.GasProvided <= remainingGas
+}
+
+func (executor *BlockExecutor) executeTransaction(tx *Transaction, result *BlockResult) (*Receipt, error) {
+	vmInput, err := tx.vmInput()
+	if err != nil {
+		return nil, err
+	}
+	if len(vmInput.CurrentTxHash) == 0 {
+		vmInput.CurrentTxHash = tx.Hash
+		vmInput.OriginalTxHash = tx.Hash
+	}
+
+	var vmOutput *vmcommon.VMOutput
+	var execution *receipts.Receipt
+	if tx.Call != nil {
+		vmOutput, err = executor.host.RunSmartContractCall(tx.Call)
+		if err == nil {
+			execution, err = receipts.NewCallReceipt(tx.Call, vmOutput)
+		}
+	} else {
+		vmOutput, err = executor.host.RunSmartContractCreate(tx.Create)
+		if err == nil {
+			execution, err = receipts.NewCreateReceipt(tx.Create, vmOutput)
+		}
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	stateRootHash, err := executor.state.ApplyVMOutput(vmOutput)
+	if err != nil {
+		return nil, err
+	}
+	result.StateDiff.Merge(vmOutput)
+
+	if executor.promiseExpiry != nil {
+		executor.promiseExpiry.TrackExecution(vmInput, vmOutput)
+	}
+
+	distribution := executor.feeComputer.ComputeDistribution(vmInput, vmOutput)
+	result.TotalGasUsed = math.AddUint64(result.TotalGasUsed, distribution.GasUsed)
+	result.TotalFees.Add(result.TotalFees, distribution.TotalFee)
+	for address, reward := range distribution.DeveloperRewards {
+		accumulated, found := result.DeveloperRewards[address]
+		if !found {
+			accumulated = big.NewInt(0)
+			result.DeveloperRewards[address] = accumulated
+		}
+		accumulated.Add(accumulated, reward)
+	}
+	result.TotalDeveloperRewards.Add(result.TotalDeveloperRewards, distribution.TotalDeveloperRewards)
+
+	return &Receipt{
+		TxHash:            tx.Hash,
+		ReturnCode:        vmOutput.ReturnCode,
+		ReturnMessage:     vmOutput.ReturnMessage,
+		GasUsed:           distribution.GasUsed,
+		CumulativeGasUsed: result.TotalGasUsed,
+		Fee:               distribution.TotalFee,
+		NumLogs:           len(vmOutput.Logs),
+		StateRootHash:     stateRootHash,
+		Execution:         execution,
+	}, nil
+}
+
+func (executor *BlockExecutor) timeoutCallbacks(blockContext *BlockContext) []*Transaction {
+	if executor.promiseExpiry == nil {
+		return nil
+	}
+
+	expired := executor.promiseExpiry.PopExpired(blockContext.Round)
+	callbacks := make([]*Transaction, len(expired))
+	for i, promise := range expired {
+		callbackInput := promise.TimeoutCallbackInput()
+		callbacks[i] = &Transaction{
+			Hash: callbackInput.CurrentTxHash,
+			Call: callbackInput,
+		}
+	}
+	return callbacks
+}
+
+func (tx *Transaction) vmInput() (*vmcommon.VMInput, error) {
+	if (tx.Call == nil) == (tx.Create == nil) {
+		return nil, ErrInvalidTransaction
+	}
+	if tx.Call != nil {
+		return &tx.Call.VMInput, nil
+	}
+	return &tx.Create.VMInput, nil
+}
+
+func (tx *Transaction) sender() string {
+	vmInput, _ := tx.vmInput()
+	return string(vmInput.CallerAddr)
+}
+
+// receiver returns the called contract, or the sender for a deployment
+func (tx *Transaction) receiver() []byte {
+	if tx.Call != nil {
+		return tx.Call.RecipientAddr
+	}
+	return tx.Create.CallerAddr
+}
+
+func (tx *Transaction) gasPrice() uint64 {
+	vmInput, _ := tx.vmInput()
+	return vmInput.GasPrice
+}
@@ -0,0 +1,291 @@
+package block
+
+import (
+	"bytes"
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/fees"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+var testAddress = []byte("contract________________________")
+
+func makeVMOutput(balanceDelta int64, key string, value string) *vmcommon.VMOutput {
+	return &vmcommon.VMOutput{
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			string(testAddress): {
+				Address:      testAddress,
+				BalanceDelta: big.NewInt(balanceDelta),
+				StorageUpdates: map[string]*vmcommon.StorageUpdate{
+					key: {Offset: []byte(key), Data: []byte(value)},
+				},
+				GasUsed: 10,
+			},
+		},
+	}
+}
+
+func TestNewBlockExecutor_NilArguments(t *testing.T) {
+	executor, err := NewBlockExecutor(ArgsNewBlockExecutor{State: worldmock.NewMockWorld()})
+	require.Nil(t, executor)
+	require.Equal(t, arwen.ErrNilVMHost, err)
+}
+
+func makeTx(hash string, sender string, nonce uint64, gasProvided uint64, gasPrice uint64) *Transaction {
+	return &Transaction{
+		Hash:  []byte(hash),
+		Nonce: nonce,
+		Call: &vmcommon.ContractCallInput{
+			VMInput: vmcommon.VMInput{
+				CallerAddr:  []byte(sender),
+				GasProvided: gasProvided,
+				GasPrice:    gasPrice,
+				CallValue:   big.NewInt(0),
+			},
+			RecipientAddr: testAddress,
+			Function:      "call",
+		},
+	}
+}
+
+// newStubBlockExecutor creates a BlockExecutor on a host which uses half of
+// the gas provided and records the order of the executed transactions
+func newStubBlockExecutor(t *testing.T, orderer TransactionOrderer) (*BlockExecutor, *[]string) {
+	executed := make([]string, 0)
+	host := &contextmock.VMHostStub{
+		RunSmartContractCallCalled: func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+			executed = append(executed, string(input.CurrentTxHash))
+			return &vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: input.GasProvided / 2}, nil
+		},
+	}
+	feeComputer, err := fees.NewFeeComputer(fees.FeeConfig{GasPriceModifier: fees.GasPriceModifierDenominator})
+	require.Nil(t, err)
+	executor, err := NewBlockExecutor(ArgsNewBlockExecutor{
+		Host:        host,
+		State:       worldmock.NewMockWorld(),
+		FeeComputer: feeComputer,
+		Orderer:     orderer,
+	})
+	require.Nil(t, err)
+	return executor, &executed
+}
+
+func TestBlockExecutor_GasPool(t *testing.T) {
+	executor, _ := newStubBlockExecutor(t, nil)
+
+	// the second transaction provides more than the 600 gas left by the first
+	// one, while the third still fits in the pool
+	txs := []*Transaction{
+		makeTx("tx1", "alice", 0, 800, 0),
+		makeTx("tx2", "bob", 0, 700, 0),
+		makeTx("tx3", "carol", 0, 600, 0),
+	}
+
+	result, err := executor.ExecuteBlock(txs, &BlockContext{GasLimit: 1000})
+	require.Nil(t, err)
+	require.Equal(t, []*Transaction{txs[1]}, result.NotExecuted)
+	require.Len(t, result.Receipts, 2)
+	require.Equal(t, []byte("tx1"), result.Receipts[0].TxHash)
+	require.Equal(t, uint64(400), result.Receipts[0].CumulativeGasUsed)
+	require.Equal(t, []byte("tx3"), result.Receipts[1].TxHash)
+	require.Equal(t, uint64(700), result.Receipts[1].CumulativeGasUsed)
+	require.Equal(t, uint64(700), result.TotalGasUsed)
+
+	result, err = executor.ExecuteBlock(txs, &BlockContext{})
+	require.Nil(t, err)
+	require.Empty(t, result.NotExecuted)
+	require.Len(t, result.Receipts, 3)
+	require.Equal(t, uint64(1050), result.TotalGasUsed)
+
+	// the later transactions of a sender are not executed either
+	txs = []*Transaction{
+		makeTx("tx1", "alice", 0, 800, 0),
+		makeTx("tx2", "bob", 0, 700, 0),
+		makeTx("tx3", "bob", 1, 100, 0),
+	}
+	result, err = executor.ExecuteBlock(txs, &BlockContext{GasLimit: 1000})
+	require.Nil(t, err)
+	require.Equal(t, []*Transaction{txs[1], txs[2]}, result.NotExecuted)
+	require.Len(t, result.Receipts, 1)
+}
+
+func TestBlockExecutor_OrderingStrategies(t *testing.T) {
+	// the nonces of alice are given out of order, and her transaction with
+	// the lowest nonce pays the lowest gas price; only carol calls a contract
+	// in the shard of the block
+	txs := []*Transaction{
+		makeTx("alice-1", "alice", 1, 100, 5),
+		makeTx("bob-0", "bob", 0, 200, 2),
+		makeTx("alice-0", "alice", 0, 300, 1),
+		makeTx("carol-0", "carol", 0, 400, 3),
+		makeTx("alice-2", "alice", 2, 500, 4),
+	}
+	localAddress := []byte("local")
+	txs[3].Call.RecipientAddr = localAddress
+	shards := &contextmock.BlockchainHookStub{
+		GetShardOfAddressCalled: func(address []byte) uint32 {
+			if bytes.Equal(address, localAddress) {
+				return 1
+			}
+			return 0
+		},
+	}
+
+	strategies := map[string]struct {
+		orderer  TransactionOrderer
+		expected []string
+	}{
+		"given":          {nil, []string{"alice-0", "bob-0", "alice-1", "carol-0", "alice-2"}},
+		"sender grouped": {&SenderGroupingOrderer{}, []string{"alice-0", "alice-1", "alice-2", "bob-0", "carol-0"}},
+		"gas price":      {&GasPriceOrderer{}, []string{"alice-0", "alice-1", "carol-0", "bob-0", "alice-2"}},
+		"shard batched":  {&ShardBatchOrderer{Shards: shards, SelfID: 1}, []string{"carol-0", "alice-0", "bob-0", "alice-1", "alice-2"}},
+	}
+
+	var expectedReceipts map[string]*Receipt
+	for name, strategy := range strategies {
+		executor, executed := newStubBlockExecutor(t, strategy.orderer)
+		result, err := executor.ExecuteBlock(txs, &BlockContext{})
+		require.Nil(t, err, name)
+		require.Equal(t, strategy.expected, *executed, name)
+
+		// the ordering never changes the outcome of a single transaction
+		receipts := make(map[string]*Receipt)
+		for _, receipt := range result.Receipts {
+			receipts[string(receipt.TxHash)] = &Receipt{
+				TxHash:     receipt.TxHash,
+				ReturnCode: receipt.ReturnCode,
+				GasUsed:    receipt.GasUsed,
+				Fee:        receipt.Fee,
+			}
+		}
+		if expectedReceipts == nil {
+			expectedReceipts = receipts
+		}
+		require.Equal(t, expectedReceipts, receipts, name)
+	}
+}
+
+type droppingOrderer struct {
+}
+
+func (orderer *droppingOrderer) OrderTransactions(txs []*Transaction) []*Transaction {
+	return txs[1:]
+}
+
+func TestBlockExecutor_InvalidOrderingStrategy(t *testing.T) {
+	executor, executed := newStubBlockExecutor(t, &droppingOrderer{})
+	txs := []*Transaction{makeTx("tx1", "alice", 0, 100, 1), makeTx("tx2", "bob", 0, 100, 1)}
+
+	result, err := executor.ExecuteBlock(txs, &BlockContext{})
+	require.Nil(t, result)
+	require.Equal(t, ErrInvalidTransactionOrder, err)
+	require.Empty(t, *executed)
+}
+
+func TestStateDiff_Merge(t *testing.T) {
+	diff := NewStateDiff()
+	diff.Merge(makeVMOutput(5, "a", "1"))
+	diff.Merge(makeVMOutput(-2, "a", "2"))
+	diff.Merge(makeVMOutput(0, "b", "3"))
+
+	require.Equal(t, []string{string(testAddress)}, diff.SortedAddresses())
+	account := diff.Accounts[string(testAddress)]
+	require.Equal(t, big.NewInt(3), account.BalanceDelta)
+	require.Equal(t, uint64(30), account.GasUsed)
+	require.Equal(t, []byte("2"), account.StorageUpdates["a"].Data)
+	require.Equal(t, []byte("3"), account.StorageUpdates["b"].Data)
+}
+
+func TestStateDiff_MarshalRoundTrip(t *testing.T) {
+	diff := NewStateDiff()
+	diff.Merge(makeVMOutput(5, "a", "1"))
+	diff.DeletedAccounts = append(diff.DeletedAccounts, []byte("deleted"))
+
+	serialized, err := diff.Marshal()
+	require.Nil(t, err)
+
+	deserialized, err := UnmarshalStateDiff(serialized)
+	require.Nil(t, err)
+	require.Equal(t, diff.SortedAddresses(), deserialized.SortedAddresses())
+	require.Equal(t, [][]byte{[]byte("deleted")}, deserialized.DeletedAccounts)
+
+	account := deserialized.Accounts[string(testAddress)]
+	require.Equal(t, big.NewInt(5), account.BalanceDelta)
+	require.Equal(t, uint64(10), account.GasUsed)
+	require.Equal(t, []byte("1"), account.StorageUpdates["a"].Data)
+}
+
+func TestComputeReceiptsRootHash_DependsOnOrder(t *testing.T) {
+	first := &Receipt{TxHash: []byte("tx1"), ReturnCode: vmcommon.Ok, GasUsed: 100, Fee: big.NewInt(1000)}
+	second := &Receipt{TxHash: []byte("tx2"), ReturnCode: vmcommon.UserError, ReturnMessage: "fail"}
+
+	rootHash, err := ComputeReceiptsRootHash([]*Receipt{first, second})
+	require.Nil(t, err)
+
+	sameRootHash, err := ComputeReceiptsRootHash([]*Receipt{first, second})
+	require.Nil(t, err)
+	require.Equal(t, rootHash, sameRootHash)
+
+	swappedRootHash, err := ComputeReceiptsRootHash([]*Receipt{second, first})
+	require.Nil(t, err)
+	require.NotEqual(t, rootHash, swappedRootHash)
+}
+
+func TestPromiseExpiryTracker_TimeoutCallbacks(t *testing.T) {
+	destination := []byte("destination_____________________")
+	expiryLog := func(callID string, expiryRound int64) *vmcommon.LogEntry {
+		return &vmcommon.LogEntry{
+			Identifier: []byte(arwen.AsyncCallExpiryIdentifier),
+			Address:    testAddress,
+			Topics: [][]byte{
+				destination,
+				[]byte(callID),
+				[]byte("contextCallID"),
+				big.NewInt(expiryRound).Bytes(),
+				big.NewInt(1000).Bytes(),
+			},
+		}
+	}
+
+	tracker := NewPromiseExpiryTracker()
+	tracker.TrackExecution(&vmcommon.VMInput{}, &vmcommon.VMOutput{
+		ReturnCode: vmcommon.Ok,
+		Logs: []*vmcommon.LogEntry{
+			expiryLog("first", 5),
+			expiryLog("second", 8),
+			expiryLog("third", 5),
+			{Identifier: []byte("other"), Address: testAddress},
+		},
+	})
+	require.Equal(t, 3, tracker.NumPending())
+
+	tracker.TrackExecution(&vmcommon.VMInput{
+		CallType:       vm.AsynchronousCallBack,
+		AsyncArguments: &vmcommon.AsyncArguments{CallerCallID: []byte("third")},
+	}, &vmcommon.VMOutput{ReturnCode: vmcommon.UserError})
+	require.Equal(t, 2, tracker.NumPending())
+
+	require.Empty(t, tracker.PopExpired(4))
+	expired := tracker.PopExpired(6)
+	require.Len(t, expired, 1)
+	require.Equal(t, []byte("first"), expired[0].CallID)
+	require.Equal(t, uint64(1000), expired[0].GasLocked)
+	require.Equal(t, 1, tracker.NumPending())
+
+	callbackInput := expired[0].TimeoutCallbackInput()
+	require.Equal(t, vm.AsynchronousCallBack, callbackInput.CallType)
+	require.Equal(t, destination, callbackInput.CallerAddr)
+	require.Equal(t, testAddress, callbackInput.RecipientAddr)
+	require.Equal(t, uint64(1000), callbackInput.GasProvided)
+	require.Equal(t, arwen.AsyncCallTimeout, vmcommon.ReturnCode(big.NewInt(0).SetBytes(callbackInput.Arguments[0]).Uint64()))
+	require.Equal(t, []byte("first"), callbackInput.AsyncArguments.CallerCallID)
+	require.Equal(t, []byte("contextCallID"), callbackInput.AsyncArguments.CallbackAsyncInitiatorCallID)
+	require.Len(t, callbackInput.AsyncArguments.CallID, 32)
+	require.Equal(t, callbackInput.AsyncArguments.CallID, expired[0].TimeoutCallbackInput().AsyncArguments.CallID)
+}
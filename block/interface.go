@@ -0,0 +1,29 @@
+package block
+
+import (
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// StateApplier persists the output of each transaction before the next one
+// is executed and returns the resulting state root hash; an output is applied
+// atomically, either whole or, when ApplyVMOutput returns an error, not at all
+type StateApplier interface {
+	ApplyVMOutput(vmOutput *vmcommon.VMOutput) ([]byte, error)
+}
+
+// TransactionOrderer is an ordering strategy for the transactions of a block;
+// it must return each of the given transactions exactly once
+type TransactionOrderer interface {
+	OrderTransactions(txs []*Transaction) []*Transaction
+}
+
+// ShardResolver tells the shard of an address, as the blockchain hook does
+type ShardResolver interface {
+	GetShardOfAddress(address []byte) uint32
+}
+
+// BlockInfoHandler is implemented by the blockchain hooks which can be told
+// about the block being executed
+type BlockInfoHandler interface {
+	SetCurrentBlockInfo(nonce uint64, round uint64, epoch uint32, timeStamp uint64, randomSeed []byte)
+}
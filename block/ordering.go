@@ -0,0 +1,133 @@
+package block
+
+import (
+	"errors"
+	"sort"
+)
+
+// ErrInvalidTransactionOrder signals that an ordering strategy did not return
+// a permutation of the transactions of the block
+var ErrInvalidTransactionOrder = errors.New("ordering strategy must return each transaction of the block exactly once")
+
+// SenderGroupingOrderer executes the transactions of each sender together,
+// the senders taken in the order of their first transaction in the block
+type SenderGroupingOrderer struct {
+}
+
+// OrderTransactions groups the transactions by sender
+func (orderer *SenderGroupingOrderer) OrderTransactions(txs []*Transaction) []*Transaction {
+	groups := make(map[string][]*Transaction)
+	senders := make([]string, 0)
+	for _, tx := range txs {
+		sender := tx.sender()
+		_, found := groups[sender]
+		if !found {
+			senders = append(senders, sender)
+		}
+		groups[sender] = append(groups[sender], tx)
+	}
+
+	ordered := make([]*Transaction, 0, len(txs))
+	for _, sender := range senders {
+		ordered = append(ordered, groups[sender]...)
+	}
+	return ordered
+}
+
+// GasPriceOrderer executes the transactions paying a higher gas price first;
+// the transactions with the same gas price keep their order in the block
+type GasPriceOrderer struct {
+}
+
+// OrderTransactions sorts the transactions by descending gas price
+func (orderer *GasPriceOrderer) OrderTransactions(txs []*Transaction) []*Transaction {
+	ordered := append(make([]*Transaction, 0, len(txs)), txs...)
+	sort.SliceStable(ordered, func(i, j int) bool {
+		return ordered[i].gasPrice() > ordered[j].gasPrice()
+	})
+	return ordered
+}
+
+// ShardBatchOrderer executes the transactions in batches by the shard of
+// their receiver, the intra-shard batch first and then the others by
+// ascending shard ID; the transactions of a batch keep their order in the block
+type ShardBatchOrderer struct {
+	Shards ShardResolver
+	// SelfID is the shard in which the block is executed
+	SelfID uint32
+}
+
+// OrderTransactions batches the transactions by the shard of their receiver
+func (orderer *ShardBatchOrderer) OrderTransactions(txs []*Transaction) []*Transaction {
+	batchKey := func(tx *Transaction) uint64 {
+		shardID := orderer.Shards.GetShardOfAddress(tx.receiver())
+		if shardID == orderer.SelfID {
+			return 0
+		}
+		return uint64(shardID) + 1
+	}
+
+	ordered := append(make([]*Transaction, 0, len(txs)), txs...)
+	sort.SliceStable(ordered, func(i, j int) bool {
+		return batchKey(ordered[i]) < batchKey(ordered[j])
+	})
+	return ordered
+}
+
+// orderTransactions applies the ordering strategy, if any, and then makes
+// sure that the transactions of each sender are executed by ascending nonce:
+// the strategy decides which positions in the block belong to each sender,
+// while the transactions of the sender fill their positions in nonce order
+func orderTransactions(orderer TransactionOrderer, txs []*Transaction) ([]*Transaction, error) {
+	ordered := txs
+	if orderer != nil {
+		ordered = orderer.OrderTransactions(txs)
+		if !isPermutation(txs, ordered) {
+			return nil, ErrInvalidTransactionOrder
+		}
+	}
+
+	positions := make(map[string][]int)
+	senders := make([]string, 0)
+	for index, tx := range ordered {
+		sender := tx.sender()
+		_, found := positions[sender]
+		if !found {
+			senders = append(senders, sender)
+		}
+		positions[sender] = append(positions[sender], index)
+	}
+
+	sequenced := make([]*Transaction, len(ordered))
+	for _, sender := range senders {
+		senderTxs := make([]*Transaction, 0, len(positions[sender]))
+		for _, index := range positions[sender] {
+			senderTxs = append(senderTxs, ordered[index])
+		}
+		sort.SliceStable(senderTxs, func(i, j int) bool {
+			return senderTxs[i].Nonce < senderTxs[j].Nonce
+		})
+		for i, index := range positions[sender] {
+			sequenced[index] = senderTxs[i]
+		}
+	}
+	return sequenced, nil
+}
+
+func isPermutation(txs []*Transaction, ordered []*Transaction) bool {
+	if len(txs) != len(ordered) {
+		return false
+	}
+
+	remaining := make(map[*Transaction]int, len(txs))
+	for _, tx := range txs {
+		remaining[tx]++
+	}
+	for _, tx := range ordered {
+		if remaining[tx] == 0 {
+			return false
+		}
+		remaining[tx]--
+	}
+	return true
+}
@@ -0,0 +1,140 @@
+package block
+
+import (
+	"bytes"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/contexts"
+	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
+)
+
+const numAsyncCallExpiryTopics = 5
+
+var timeoutCallIDSuffix = []byte("timeout")
+
+// PendingPromise is a cross-shard async call which expects the response of
+// its destination before the expiry round
+type PendingPromise struct {
+	Contract           []byte
+	Destination        []byte
+	CallID             []byte
+	AsyncContextCallID []byte
+	ExpiryRound        uint64
+	GasLocked          uint64
+}
+
+// TimeoutCallbackInput creates the callback which informs the contract that
+// the destination of the async call did not respond before the expiry round
+func (promise *PendingPromise) TimeoutCallbackInput() *vmcommon.ContractCallInput {
+	parentCallID := make([]byte, len(promise.CallID))
+	copy(parentCallID, promise.CallID)
+	callbackCallID := contexts.GenerateNewCallID(hashing.NewHasher(), parentCallID, timeoutCallIDSuffix)
+
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr: promise.Destination,
+			Arguments:  [][]byte{
+				contexts.ReturnCodeToBytes(arwen.AsyncCallTimeout),
+				[]byte(arwen.ErrAsyncCallExpired.Error()),
+			},
+			CallValue:            big.NewInt(0),
+			CallType:             vm.AsynchronousCallBack,
+			GasProvided:          promise.GasLocked,
+			CurrentTxHash:        callbackCallID,
+			OriginalTxHash:       callbackCallID,
+			ReturnCallAfterError: true,
+			AsyncArguments:       &vmcommon.AsyncArguments{
+				CallID:                       callbackCallID,
+				CallerCallID:                 promise.CallID,
+				CallbackAsyncInitiatorCallID: promise.AsyncContextCallID,
+			},
+		},
+		RecipientAddr: promise.Contract,
+		Function:      arwen.CallbackFunctionName,
+	}
+}
+
+// PromiseExpiryTracker follows the cross-shard async calls created with an
+// expiry, from the log entries announcing them until their callback is
+// executed, and hands out those which reached their expiry round
+type PromiseExpiryTracker struct {
+	pending []*PendingPromise
+}
+
+// NewPromiseExpiryTracker creates a PromiseExpiryTracker without pending promises
+func NewPromiseExpiryTracker() *PromiseExpiryTracker {
+	return &PromiseExpiryTracker{
+		pending: make([]*PendingPromise, 0),
+	}
+}
+
+// TrackExecution records the promises announced by a successful execution
+// and forgets the promise answered by it, if the execution was a callback
+func (tracker *PromiseExpiryTracker) TrackExecution(vmInput *vmcommon.VMInput, vmOutput *vmcommon.VMOutput) {
+	if vmInput.CallType == vm.AsynchronousCallBack && vmInput.AsyncArguments != nil {
+		tracker.resolve(vmInput.AsyncArguments.CallerCallID)
+	}
+
+	if vmOutput.ReturnCode != vmcommon.Ok {
+		return
+	}
+
+	for _, logEntry := range vmOutput.Logs {
+		promise, ok := promiseFromLogEntry(logEntry)
+		if ok {
+			tracker.pending = append(tracker.pending, promise)
+		}
+	}
+}
+
+// NumPending returns the number of promises still waiting for a response
+func (tracker *PromiseExpiryTracker) NumPending() int {
+	return len(tracker.pending)
+}
+
+// PopExpired removes and returns the promises which reached their expiry
+// round, in the order in which they were announced
+func (tracker *PromiseExpiryTracker) PopExpired(currentRound uint64) []*PendingPromise {
+	expired := make([]*PendingPromise, 0)
+	stillPending := make([]*PendingPromise, 0, len(tracker.pending))
+	for _, promise := range tracker.pending {
+		if currentRound >= promise.ExpiryRound {
+			expired = append(expired, promise)
+			continue
+		}
+		stillPending = append(stillPending, promise)
+	}
+
+	tracker.pending = stillPending
+	return expired
+}
+
+func (tracker *PromiseExpiryTracker) resolve(callID []byte) {
+	for i, promise := range tracker.pending {
+		if bytes.Equal(promise.CallID, callID) {
+			tracker.pending = append(tracker.pending[:i], tracker.pending[i+1:]...)
+			return
+		}
+	}
+}
+
+func promiseFromLogEntry(logEntry *vmcommon.LogEntry) (*PendingPromise, bool) {
+	if string(logEntry.Identifier) != arwen.AsyncCallExpiryIdentifier {
+		return nil, false
+	}
+	if len(logEntry.Topics) != numAsyncCallExpiryTopics {
+		return nil, false
+	}
+
+	return &PendingPromise{
+		Contract:           logEntry.Address,
+		Destination:        logEntry.Topics[0],
+		CallID:             logEntry.Topics[1],
+		AsyncContextCallID: logEntry.Topics[2],
+		ExpiryRound:        big.NewInt(0).SetBytes(logEntry.Topics[3]).Uint64(),
+		GasLocked:          big.NewInt(0).SetBytes(logEntry.Topics[4]).Uint64(),
+	}, true
+}
@@ -0,0 +1,73 @@
+package block
+
+import (
+	"encoding/binary"
+	"math/big"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/receipts"
+	"github.com/ElrondNetwork/wasm-vm/trie"
+)
+
+// Receipt summarizes the execution of a single transaction of a block
+type Receipt struct {
+	TxHash            []byte
+	ReturnCode        vmcommon.ReturnCode
+	ReturnMessage     string
+	GasUsed           uint64
+	// CumulativeGasUsed is the gas used by the block up to and including
+	// this transaction
+	CumulativeGasUsed uint64
+	Fee               *big.Int
+	NumLogs           int
+	StateRootHash     []byte
+	// Execution is the standardized receipt of the execution, with its
+	// sender, receiver, logs bloom and generated transactions; it is not
+	// part of the encoding of the receipts trie
+	Execution         *receipts.Receipt
+}
+
+// Encode serializes the receipt deterministically, for the receipts trie
+func (receipt *Receipt) Encode() []byte {
+	fee := receipt.Fee
+	if fee == nil {
+		fee = big.NewInt(0)
+	}
+
+	encoded := make([]byte, 0)
+	encoded = appendField(encoded, receipt.TxHash)
+	encoded = appendField(encoded, big.NewInt(int64(receipt.ReturnCode)).Bytes())
+	encoded = appendField(encoded, []byte(receipt.ReturnMessage))
+	encoded = appendField(encoded, big.NewInt(0).SetUint64(receipt.GasUsed).Bytes())
+	encoded = appendField(encoded, big.NewInt(0).SetUint64(receipt.CumulativeGasUsed).Bytes())
+	encoded = appendField(encoded, fee.Bytes())
+	encoded = appendField(encoded, big.NewInt(int64(receipt.NumLogs)).Bytes())
+	return appendField(encoded, receipt.StateRootHash)
+}
+
+// ComputeReceiptsRootHash returns the root of a trie holding the encoded
+// receipts under their index in the block
+func ComputeReceiptsRootHash(receipts []*Receipt) ([]byte, error) {
+	receiptsTrie, err := trie.NewPatriciaTrie(trie.NewMemoryKVStore(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	for index, receipt := range receipts {
+		key := make([]byte, 4)
+		binary.BigEndian.PutUint32(key, uint32(index))
+		err = receiptsTrie.Update(key, receipt.Encode())
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return receiptsTrie.RootHash()
+}
+
+func appendField(buffer []byte, field []byte) []byte {
+	length := make([]byte, 4)
+	binary.BigEndian.PutUint32(length, uint32(len(field)))
+	buffer = append(buffer, length...)
+	return append(buffer, field...)
+}
@@ -0,0 +1,98 @@
+package block
+
+import (
+	"math/big"
+	"sort"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen/contexts"
+)
+
+// StateDiff is the aggregated change of state produced by all the
+// transactions of a block
+type StateDiff struct {
+	Accounts        map[string]*vmcommon.OutputAccount
+	DeletedAccounts [][]byte
+}
+
+// NewStateDiff creates an empty StateDiff
+func NewStateDiff() *StateDiff {
+	return &StateDiff{
+		Accounts:        make(map[string]*vmcommon.OutputAccount),
+		DeletedAccounts: make([][]byte, 0),
+	}
+}
+
+// Merge adds the changes of the VMOutput on top of the diff: balance deltas
+// are summed, while nonces, code and storage values are overwritten by the
+// later transaction
+func (diff *StateDiff) Merge(vmOutput *vmcommon.VMOutput) {
+	for address, outputAccount := range vmOutput.OutputAccounts {
+		diffAccount, found := diff.Accounts[address]
+		if !found {
+			diffAccount = &vmcommon.OutputAccount{
+				Address:        outputAccount.Address,
+				BalanceDelta:   big.NewInt(0),
+				StorageUpdates: make(map[string]*vmcommon.StorageUpdate),
+			}
+			diff.Accounts[address] = diffAccount
+		}
+
+		if outputAccount.BalanceDelta != nil {
+			diffAccount.BalanceDelta = big.NewInt(0).Add(diffAccount.BalanceDelta, outputAccount.BalanceDelta)
+		}
+		if outputAccount.Balance != nil {
+			diffAccount.Balance = outputAccount.Balance
+		}
+		if outputAccount.Nonce > diffAccount.Nonce {
+			diffAccount.Nonce = outputAccount.Nonce
+		}
+		if len(outputAccount.Code) > 0 {
+			diffAccount.Code = outputAccount.Code
+			diffAccount.CodeMetadata = outputAccount.CodeMetadata
+			diffAccount.CodeDeployerAddress = outputAccount.CodeDeployerAddress
+		}
+		for key, storageUpdate := range outputAccount.StorageUpdates {
+			diffAccount.StorageUpdates[key] = storageUpdate
+		}
+		diffAccount.GasUsed += outputAccount.GasUsed
+		diffAccount.BytesAddedToStorage += outputAccount.BytesAddedToStorage
+		diffAccount.BytesDeletedFromStorage += outputAccount.BytesDeletedFromStorage
+	}
+
+	diff.DeletedAccounts = append(diff.DeletedAccounts, vmOutput.DeletedAccounts...)
+}
+
+// SortedAddresses returns the addresses of the changed accounts in ascending order
+func (diff *StateDiff) SortedAddresses() []string {
+	addresses := make([]string, 0, len(diff.Accounts))
+	for address := range diff.Accounts {
+		addresses = append(addresses, address)
+	}
+	sort.Strings(addresses)
+	return addresses
+}
+
+// Marshal serializes the diff in the protobuf format of the VMOutputs, as a
+// VMOutput holding only the changed and the deleted accounts
+func (diff *StateDiff) Marshal() ([]byte, error) {
+	return contexts.MarshalVMOutput(&vmcommon.VMOutput{
+		OutputAccounts:  diff.Accounts,
+		DeletedAccounts: diff.DeletedAccounts,
+	})
+}
+
+// UnmarshalStateDiff deserializes a diff serialized by StateDiff.Marshal()
+func UnmarshalStateDiff(data []byte) (*StateDiff, error) {
+	vmOutput, err := contexts.UnmarshalVMOutput(data)
+	if err != nil {
+		return nil, err
+	}
+
+	diff := NewStateDiff()
+	diff.Accounts = vmOutput.OutputAccounts
+	if vmOutput.DeletedAccounts != nil {
+		diff.DeletedAccounts = vmOutput.DeletedAccounts
+	}
+	return diff, nil
+}
@@ -0,0 +1,167 @@
+package build
+
+import (
+	"fmt"
+	"io/ioutil"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sync"
+)
+
+// DefaultTarget is the target for which the contracts are compiled
+const DefaultTarget = "wasm32-unknown-unknown"
+
+// DefaultProfile is the cargo profile with which the contracts are compiled
+const DefaultProfile = "release"
+
+// DefaultRustFlags strips the symbols of the built code, as erdpy does
+const DefaultRustFlags = "-C link-arg=-s"
+
+// PostProcessor transforms the code built by cargo before it is written to
+// the output of the contract, as wasm-opt does
+type PostProcessor func(contract Contract, code []byte) ([]byte, error)
+
+// Options configure how a Builder invokes cargo; the empty fields take the
+// default values
+type Options struct {
+	CargoPath      string
+	Target         string
+	Profile        string
+	RustFlags      string
+	PostProcessors []PostProcessor
+}
+
+// Builder compiles the contract crates to wasm with cargo and writes their
+// code to their output directories, in place of the checked-in binaries
+type Builder struct {
+	options    Options
+	runCommand func(command *exec.Cmd) ([]byte, error)
+
+	mutex sync.Mutex
+	built map[string]error
+}
+
+// NewBuilder creates a Builder with the given options
+func NewBuilder(options Options) *Builder {
+	if len(options.CargoPath) == 0 {
+		options.CargoPath = "cargo"
+	}
+	if len(options.Target) == 0 {
+		options.Target = DefaultTarget
+	}
+	if len(options.Profile) == 0 {
+		options.Profile = DefaultProfile
+	}
+	if len(options.RustFlags) == 0 {
+		options.RustFlags = DefaultRustFlags
+	}
+
+	return &Builder{
+		options: options,
+		runCommand: func(command *exec.Cmd) ([]byte, error) {
+			return command.CombinedOutput()
+		},
+		built: make(map[string]error),
+	}
+}
+
+// Build compiles the contract, applies the post-processors to its code in
+// order, and writes the code to the output of the contract, which it returns
+func (builder *Builder) Build(contract Contract) ([]byte, error) {
+	command := builder.cargoCommand(contract)
+	output, err := builder.runCommand(command)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %s: %v\n%s", ErrBuildFailed, contract.Name, err, output)
+	}
+
+	artifactPath := contract.artifactPath(builder.options.Target, builder.options.Profile)
+	code, err := ioutil.ReadFile(filepath.Clean(artifactPath))
+	if err != nil {
+		return nil, fmt.Errorf("%w: %s: %v", ErrMissingArtifact, contract.Name, err)
+	}
+
+	for _, postProcessor := range builder.options.PostProcessors {
+		code, err = postProcessor(contract, code)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %s: %v", ErrPostProcessingFailed, contract.Name, err)
+		}
+	}
+
+	outputPath := contract.OutputPath()
+	err = os.MkdirAll(filepath.Dir(outputPath), 0755)
+	if err != nil {
+		return nil, err
+	}
+	err = ioutil.WriteFile(outputPath, code, 0644)
+	if err != nil {
+		return nil, err
+	}
+
+	return code, nil
+}
+
+// BuildOnce builds the contract unless the Builder has already built it,
+// and returns the result of the first build
+func (builder *Builder) BuildOnce(contract Contract) error {
+	builder.mutex.Lock()
+	defer builder.mutex.Unlock()
+
+	outputPath := contract.OutputPath()
+	err, ok := builder.built[outputPath]
+	if ok {
+		return err
+	}
+
+	_, err = builder.Build(contract)
+	builder.built[outputPath] = err
+	return err
+}
+
+// BuildAll builds the given contracts in order, stopping at the first one
+// which fails to build
+func (builder *Builder) BuildAll(contracts []Contract) error {
+	for _, contract := range contracts {
+		_, err := builder.Build(contract)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (builder *Builder) cargoCommand(contract Contract) *exec.Cmd {
+	arguments := []string{
+		"build",
+		"--target", builder.options.Target,
+		"--target-dir", contract.targetDir(),
+	}
+	arguments = append(arguments, profileArguments(builder.options.Profile)...)
+
+	command := exec.Command(builder.options.CargoPath, arguments...)
+	command.Dir = contract.WasmCrateDir
+	command.Env = append(os.Environ(), "RUSTFLAGS="+builder.options.RustFlags)
+	return command
+}
+
+// profileArguments selects the profile with the flags understood by the
+// cargo versions which predate --profile, for the built-in profiles
+func profileArguments(profile string) []string {
+	switch profile {
+	case "release":
+		return []string{"--release"}
+	case "dev":
+		return nil
+	default:
+		return []string{"--profile", profile}
+	}
+}
+
+// profileDirName returns the directory of the target directory in which
+// cargo writes the artifacts of the profile
+func profileDirName(profile string) string {
+	if profile == "dev" {
+		return "debug"
+	}
+	return profile
+}
@@ -0,0 +1,116 @@
+package build
+
+import (
+	"errors"
+	"io/ioutil"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func createTestContract(t *testing.T) Contract {
+	dir := t.TempDir()
+	wasmCrateDir := filepath.Join(dir, WasmCrateDirName)
+	require.Nil(t, os.MkdirAll(wasmCrateDir, 0755))
+	require.Nil(t, ioutil.WriteFile(filepath.Join(dir, cargoManifestName),
+		[]byte("[package]\nname = \"adder\"\nversion = \"0.0.0\"\n"), 0644))
+	require.Nil(t, ioutil.WriteFile(filepath.Join(wasmCrateDir, cargoManifestName),
+		[]byte("[package]\nname = \"adder-wasm\"\n\n[dependencies.adder]\npath = \"..\"\n"), 0644))
+
+	contract, err := ReadContract(dir)
+	require.Nil(t, err)
+	return contract
+}
+
+func TestBuilder_Build(t *testing.T) {
+	contract := createTestContract(t)
+	builtCode := append(append([]byte{}, wasmHeader...), makeCustomSection("name", []byte{0x01})...)
+
+	var commands []*exec.Cmd
+	builder := NewBuilder(Options{
+		PostProcessors: []PostProcessor{
+			StripCustomSections(),
+			func(_ Contract, code []byte) ([]byte, error) {
+				return append(code, makeCustomSection("extra", nil)...), nil
+			},
+		},
+	})
+	builder.runCommand = func(command *exec.Cmd) ([]byte, error) {
+		commands = append(commands, command)
+		artifactPath := contract.artifactPath(DefaultTarget, DefaultProfile)
+		require.Nil(t, os.MkdirAll(filepath.Dir(artifactPath), 0755))
+		require.Nil(t, ioutil.WriteFile(artifactPath, builtCode, 0644))
+		return nil, nil
+	}
+
+	code, err := builder.Build(contract)
+	require.Nil(t, err)
+	expectedCode := append(append([]byte{}, wasmHeader...), makeCustomSection("extra", nil)...)
+	require.Equal(t, expectedCode, code)
+	output, err := ioutil.ReadFile(contract.OutputPath())
+	require.Nil(t, err)
+	require.Equal(t, expectedCode, output)
+
+	require.Len(t, commands, 1)
+	require.Equal(t, contract.WasmCrateDir, commands[0].Dir)
+	require.Equal(t, []string{
+		"cargo", "build",
+		"--target", DefaultTarget,
+		"--target-dir", filepath.Join(contract.WasmCrateDir, "target"),
+		"--release",
+	}, commands[0].Args)
+	require.Contains(t, commands[0].Env, "RUSTFLAGS="+DefaultRustFlags)
+
+	require.Nil(t, builder.BuildOnce(contract))
+	require.Nil(t, builder.BuildOnce(contract))
+	require.Len(t, commands, 2)
+}
+
+func TestBuilder_BuildFailures(t *testing.T) {
+	contract := createTestContract(t)
+
+	builder := NewBuilder(Options{Profile: "dev"})
+	builder.runCommand = func(command *exec.Cmd) ([]byte, error) {
+		return []byte("error[E0425]"), errors.New("exit status 101")
+	}
+	_, err := builder.Build(contract)
+	require.True(t, errors.Is(err, ErrBuildFailed))
+	require.Contains(t, err.Error(), "error[E0425]")
+
+	builder.runCommand = func(command *exec.Cmd) ([]byte, error) {
+		require.NotContains(t, command.Args, "--release")
+		return nil, nil
+	}
+	_, err = builder.Build(contract)
+	require.True(t, errors.Is(err, ErrMissingArtifact))
+	require.NoFileExists(t, contract.OutputPath())
+}
+
+func TestStripCustomSections(t *testing.T) {
+	typeSection := []byte{0x01, 0x04, 0x01, 0x60, 0x00, 0x00}
+	code := append(append([]byte{}, wasmHeader...), makeCustomSection("name", []byte{0x01, 0x02})...)
+	code = append(code, typeSection...)
+	code = append(code, makeCustomSection("buildinfo", []byte("{}"))...)
+	code = append(code, makeCustomSection("producers", nil)...)
+
+	stripped, err := StripCustomSections("buildinfo")(Contract{}, code)
+	require.Nil(t, err)
+	expected := append(append([]byte{}, wasmHeader...), typeSection...)
+	expected = append(expected, makeCustomSection("buildinfo", []byte("{}"))...)
+	require.Equal(t, expected, stripped)
+
+	_, err = StripCustomSections()(Contract{}, []byte("not wasm"))
+	require.Equal(t, ErrMalformedModule, err)
+
+	_, err = StripCustomSections()(Contract{}, append(append([]byte{}, wasmHeader...), 0x01, 0x10, 0x00))
+	require.Equal(t, ErrMalformedModule, err)
+}
+
+func makeCustomSection(name string, payload []byte) []byte {
+	content := append([]byte{byte(len(name))}, name...)
+	content = append(content, payload...)
+	return append([]byte{customSectionID, byte(len(content))}, content...)
+}
@@ -0,0 +1,166 @@
+package build
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+)
+
+const cargoManifestName = "Cargo.toml"
+
+// WasmCrateDirName is the directory under a contract crate holding the crate
+// which compiles the contract to a cdylib, as laid out by elrond-wasm
+const WasmCrateDirName = "wasm"
+
+// OutputDirName is the directory under a contract crate holding its built code
+const OutputDirName = "output"
+
+// Contract is a contract crate of the test contracts: the crate of the
+// contract, and the crate under its wasm directory which cargo compiles to
+// wasm; the built code is kept in output/<Name>.wasm
+type Contract struct {
+	Name          string
+	Dir           string
+	WasmCrateName string
+	WasmCrateDir  string
+}
+
+// OutputPath returns the path of the built code of the contract
+func (contract Contract) OutputPath() string {
+	return filepath.Join(contract.Dir, OutputDirName, contract.Name+".wasm")
+}
+
+// targetDir returns the directory where cargo builds the wasm crate; each
+// wasm crate is a workspace of its own, so it has its own target directory
+func (contract Contract) targetDir() string {
+	return filepath.Join(contract.WasmCrateDir, "target")
+}
+
+// artifactPath returns the path of the wasm file which cargo writes for the
+// target and the profile
+func (contract Contract) artifactPath(target string, profile string) string {
+	fileName := strings.ReplaceAll(contract.WasmCrateName, "-", "_") + ".wasm"
+	return filepath.Join(contract.targetDir(), target, profileDirName(profile), fileName)
+}
+
+// ReadContract reads the contract crate in the given directory, which must
+// hold both a Cargo.toml and the wasm crate of the contract
+func ReadContract(dir string) (Contract, error) {
+	wasmCrateDir := filepath.Join(dir, WasmCrateDirName)
+	if !fileExists(filepath.Join(wasmCrateDir, cargoManifestName)) || !fileExists(filepath.Join(dir, cargoManifestName)) {
+		return Contract{}, fmt.Errorf("%w: %s", ErrNotAContract, dir)
+	}
+
+	name, err := readPackageName(filepath.Join(dir, cargoManifestName))
+	if err != nil {
+		return Contract{}, err
+	}
+	wasmCrateName, err := readPackageName(filepath.Join(wasmCrateDir, cargoManifestName))
+	if err != nil {
+		return Contract{}, err
+	}
+
+	return Contract{
+		Name:          name,
+		Dir:           dir,
+		WasmCrateName: wasmCrateName,
+		WasmCrateDir:  wasmCrateDir,
+	}, nil
+}
+
+// ContractOfOutput returns the contract crate whose built code is at the
+// given path
+func ContractOfOutput(outputPath string) (Contract, error) {
+	outputDir := filepath.Dir(outputPath)
+	if filepath.Base(outputDir) != OutputDirName {
+		return Contract{}, fmt.Errorf("%w: %s", ErrNotAContract, outputPath)
+	}
+
+	contract, err := ReadContract(filepath.Dir(outputDir))
+	if err != nil {
+		return Contract{}, err
+	}
+	if filepath.Base(outputPath) != contract.Name+".wasm" {
+		return Contract{}, fmt.Errorf("%w: %s", ErrNotAContract, outputPath)
+	}
+	return contract, nil
+}
+
+// FindContracts returns the contract crates under the given directory,
+// ordered by their directories; the crates which are not built to wasm on
+// their own, such as the libraries shared by the contracts, are left out
+func FindContracts(root string) ([]Contract, error) {
+	contracts := make([]Contract, 0)
+	err := filepath.Walk(root, func(path string, info os.FileInfo, err error) error {
+		if err != nil {
+			return err
+		}
+		if !info.IsDir() {
+			return nil
+		}
+		switch info.Name() {
+		case WasmCrateDirName, OutputDirName, "target", ".git":
+			return filepath.SkipDir
+		}
+
+		contract, err := ReadContract(path)
+		if err == nil {
+			contracts = append(contracts, contract)
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	return contracts, nil
+}
+
+// readPackageName reads the name of the package from the [package] section
+// of a Cargo.toml
+func readPackageName(manifestPath string) (string, error) {
+	file, err := os.Open(filepath.Clean(manifestPath))
+	if err != nil {
+		return "", err
+	}
+	defer func() {
+		_ = file.Close()
+	}()
+
+	section := ""
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		if strings.HasPrefix(line, "[") {
+			section = line
+			continue
+		}
+		if section != "[package]" {
+			continue
+		}
+
+		parts := strings.SplitN(line, "=", 2)
+		if len(parts) != 2 || strings.TrimSpace(parts[0]) != "name" {
+			continue
+		}
+		name, err := strconv.Unquote(strings.TrimSpace(parts[1]))
+		if err != nil || len(name) == 0 {
+			return "", fmt.Errorf("%w: %s", ErrMissingPackageName, manifestPath)
+		}
+		return name, nil
+	}
+	err = scanner.Err()
+	if err != nil {
+		return "", err
+	}
+
+	return "", fmt.Errorf("%w: %s", ErrMissingPackageName, manifestPath)
+}
+
+func fileExists(path string) bool {
+	info, err := os.Stat(path)
+	return err == nil && !info.IsDir()
+}
@@ -0,0 +1,62 @@
+package build
+
+import (
+	"errors"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+const testContractsDir = "../test/contracts"
+
+func TestFindContracts(t *testing.T) {
+	contracts, err := FindContracts(testContractsDir)
+	require.Nil(t, err)
+
+	names := make([]string, len(contracts))
+	for i, contract := range contracts {
+		names[i] = contract.Name
+		require.FileExists(t, contract.OutputPath())
+	}
+	require.Equal(t, []string{
+		"exchange",
+		"child-simple",
+		"parent-simple",
+		"first-contract",
+		"second-contract",
+		"third-contract",
+		"rawrs",
+	}, names)
+
+	exchange := contracts[0]
+	require.Equal(t, filepath.Join(testContractsDir, "exchange"), exchange.Dir)
+	require.Equal(t, "exchange-wasm", exchange.WasmCrateName)
+	require.Equal(t, filepath.Join(testContractsDir, "exchange", "wasm"), exchange.WasmCrateDir)
+	require.Equal(t,
+		filepath.Join(testContractsDir, "exchange", "wasm", "target", "wasm32-unknown-unknown", "release", "exchange_wasm.wasm"),
+		exchange.artifactPath(DefaultTarget, DefaultProfile))
+	require.Equal(t,
+		filepath.Join(testContractsDir, "exchange", "wasm", "target", "wasm32-unknown-unknown", "debug", "exchange_wasm.wasm"),
+		exchange.artifactPath(DefaultTarget, "dev"))
+}
+
+func TestReadContract_NotAContract(t *testing.T) {
+	_, err := ReadContract(filepath.Join(testContractsDir, "promises", "promises-common"))
+	require.True(t, errors.Is(err, ErrNotAContract))
+
+	_, err = ReadContract(filepath.Join(testContractsDir, "stack-recursion"))
+	require.True(t, errors.Is(err, ErrNotAContract))
+}
+
+func TestContractOfOutput(t *testing.T) {
+	contract, err := ContractOfOutput(filepath.Join(testContractsDir, "rawrs", "output", "rawrs.wasm"))
+	require.Nil(t, err)
+	require.Equal(t, "rawrs", contract.Name)
+
+	_, err = ContractOfOutput(filepath.Join(testContractsDir, "rawrs", "output", "other.wasm"))
+	require.True(t, errors.Is(err, ErrNotAContract))
+
+	_, err = ContractOfOutput(filepath.Join(testContractsDir, "stack-recursion", "output", "stack-recursion.wasm"))
+	require.True(t, errors.Is(err, ErrNotAContract))
+}
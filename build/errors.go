@@ -0,0 +1,21 @@
+package build
+
+import "errors"
+
+// ErrNotAContract signals a directory or an output path which does not belong to a contract crate
+var ErrNotAContract = errors.New("not a contract crate")
+
+// ErrMissingPackageName signals a Cargo.toml which does not name its package
+var ErrMissingPackageName = errors.New("missing package name in Cargo.toml")
+
+// ErrBuildFailed signals that cargo could not build a contract
+var ErrBuildFailed = errors.New("contract build failed")
+
+// ErrMissingArtifact signals that cargo built a contract without writing its wasm file
+var ErrMissingArtifact = errors.New("missing wasm artifact")
+
+// ErrPostProcessingFailed signals a post-processor which could not transform the code of a contract
+var ErrPostProcessingFailed = errors.New("post-processing failed")
+
+// ErrMalformedModule signals code which is not a wasm module
+var ErrMalformedModule = errors.New("malformed wasm module")
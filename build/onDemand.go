@@ -0,0 +1,71 @@
+package build
+
+import (
+	"errors"
+	"os"
+
+	fr "github.com/ElrondNetwork/wasm-vm/mandos-go/fileresolver"
+)
+
+// RebuildContractsVariable is the environment variable which, when set to a
+// non-empty value, makes the tests and the scenarios rebuild the contracts
+// whose code they load, instead of loading the checked-in binaries
+const RebuildContractsVariable = "ARWEN_REBUILD_TEST_CONTRACTS"
+
+var onDemandBuilder = NewBuilder(Options{})
+
+// RebuildRequested returns true if RebuildContractsVariable is set
+func RebuildRequested() bool {
+	return len(os.Getenv(RebuildContractsVariable)) > 0
+}
+
+// EnsureBuilt rebuilds, once per process, the contract whose code is at the
+// given output path, if RebuildContractsVariable is set; the paths which are
+// not the output of a contract crate, such as the hand-written wasm of the
+// test contracts, are left as they are
+func EnsureBuilt(outputPath string) error {
+	if !RebuildRequested() {
+		return nil
+	}
+
+	contract, err := ContractOfOutput(outputPath)
+	if errors.Is(err, ErrNotAContract) {
+		return nil
+	}
+	if err != nil {
+		return err
+	}
+
+	return onDemandBuilder.BuildOnce(contract)
+}
+
+var _ fr.FileResolver = (*rebuildingFileResolver)(nil)
+
+// rebuildingFileResolver rebuilds the contracts which the scenarios load
+// before handing their code to the parser
+type rebuildingFileResolver struct {
+	fr.FileResolver
+}
+
+// NewRebuildingFileResolver wraps a file resolver of the scenarios, so that
+// it calls EnsureBuilt for the files it resolves
+func NewRebuildingFileResolver(resolver fr.FileResolver) fr.FileResolver {
+	return &rebuildingFileResolver{FileResolver: resolver}
+}
+
+// Clone creates new instance of the same type.
+func (resolver *rebuildingFileResolver) Clone() fr.FileResolver {
+	return &rebuildingFileResolver{FileResolver: resolver.FileResolver.Clone()}
+}
+
+// ResolveFileValue rebuilds the contract whose code is in the file, if
+// requested, then reads the file
+func (resolver *rebuildingFileResolver) ResolveFileValue(value string) ([]byte, error) {
+	if len(value) > 0 {
+		err := EnsureBuilt(resolver.ResolveAbsolutePath(value))
+		if err != nil {
+			return nil, err
+		}
+	}
+	return resolver.FileResolver.ResolveFileValue(value)
+}
@@ -0,0 +1,112 @@
+package build
+
+import (
+	"bytes"
+	"fmt"
+	"io/ioutil"
+	"os"
+	"os/exec"
+	"path/filepath"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+var wasmHeader = []byte{0x00, 'a', 's', 'm', 0x01, 0x00, 0x00, 0x00}
+
+const customSectionID = 0
+
+// WasmOpt returns a post-processor which optimizes the code with the wasm-opt
+// tool of binaryen, found in the PATH, passing it the given arguments; they
+// default to -Oz, as with erdpy
+func WasmOpt(arguments ...string) PostProcessor {
+	if len(arguments) == 0 {
+		arguments = []string{"-Oz"}
+	}
+
+	return func(contract Contract, code []byte) ([]byte, error) {
+		dir, err := ioutil.TempDir("", "wasm-opt-"+contract.Name)
+		if err != nil {
+			return nil, err
+		}
+		defer func() {
+			_ = os.RemoveAll(dir)
+		}()
+
+		inputPath := filepath.Join(dir, "input.wasm")
+		outputPath := filepath.Join(dir, "output.wasm")
+		err = ioutil.WriteFile(inputPath, code, 0644)
+		if err != nil {
+			return nil, err
+		}
+
+		commandArguments := append([]string{inputPath, "-o", outputPath}, arguments...)
+		output, err := exec.Command("wasm-opt", commandArguments...).CombinedOutput()
+		if err != nil {
+			return nil, fmt.Errorf("wasm-opt: %v\n%s", err, output)
+		}
+
+		return ioutil.ReadFile(filepath.Clean(outputPath))
+	}
+}
+
+// StripCustomSections returns a post-processor which removes the custom
+// sections of the code, such as the names and the producers written by the
+// compiler, except those with the given names, such as the build info or the
+// argument schemas read by the VM
+func StripCustomSections(keptSections ...string) PostProcessor {
+	kept := make(map[string]bool, len(keptSections))
+	for _, name := range keptSections {
+		kept[name] = true
+	}
+
+	return func(_ Contract, code []byte) ([]byte, error) {
+		if !bytes.HasPrefix(code, wasmHeader) {
+			return nil, ErrMalformedModule
+		}
+
+		stripped := make([]byte, 0, len(code))
+		stripped = append(stripped, wasmHeader...)
+		reader := wasmreader.NewReader(code[len(wasmHeader):])
+		for reader.HasMore() {
+			sectionStart := reader.Offset()
+			section, err := readSection(reader)
+			if err != nil {
+				return nil, ErrMalformedModule
+			}
+
+			if section.id == customSectionID {
+				name, err := wasmreader.NewReader(section.content).ReadName()
+				if err != nil {
+					return nil, ErrMalformedModule
+				}
+				if !kept[name] {
+					continue
+				}
+			}
+			stripped = append(stripped, reader.Since(sectionStart)...)
+		}
+
+		return stripped, nil
+	}
+}
+
+type wasmSection struct {
+	id      byte
+	content []byte
+}
+
+func readSection(reader *wasmreader.Reader) (wasmSection, error) {
+	id, err := reader.ReadByte()
+	if err != nil {
+		return wasmSection{}, err
+	}
+	size, err := reader.ReadU32()
+	if err != nil {
+		return wasmSection{}, err
+	}
+	content, err := reader.ReadBytes(size)
+	if err != nil {
+		return wasmSection{}, err
+	}
+	return wasmSection{id: id, content: content}, nil
+}
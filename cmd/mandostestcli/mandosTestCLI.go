@@ -8,6 +8,7 @@ import (
 	"strings"
 
 	am "github.com/ElrondNetwork/wasm-vm/arwenmandos"
+	"github.com/ElrondNetwork/wasm-vm/build"
 	mc "github.com/ElrondNetwork/wasm-vm/mandos-go/controller"
 )
 
@@ -66,7 +67,7 @@ func MandosTestCLI() {
 	case isDir:
 		runner := mc.NewScenarioRunner(
 			executor,
-			mc.NewDefaultFileResolver(),
+			build.NewRebuildingFileResolver(mc.NewDefaultFileResolver()),
 		)
 		err = runner.RunAllJSONScenariosInDirectory(
 			jsonFilePath,
@@ -77,13 +78,13 @@ func MandosTestCLI() {
 	case strings.HasSuffix(jsonFilePath, ".scen.json"):
 		runner := mc.NewScenarioRunner(
 			executor,
-			mc.NewDefaultFileResolver(),
+			build.NewRebuildingFileResolver(mc.NewDefaultFileResolver()),
 		)
 		err = runner.RunSingleJSONScenario(jsonFilePath, options)
 	default:
 		runner := mc.NewTestRunner(
 			executor,
-			mc.NewDefaultFileResolver(),
+			build.NewRebuildingFileResolver(mc.NewDefaultFileResolver()),
 		)
 		err = runner.RunSingleJSONTest(jsonFilePath)
 	}
@@ -0,0 +1,54 @@
+package main
+
+import (
+	"fmt"
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"strings"
+
+	mc "github.com/ElrondNetwork/wasm-vm/mandos-go/controller"
+	"github.com/ElrondNetwork/wasm-vm/mandos-go/replayconvert"
+)
+
+const defaultScenariosDir = "test/scenarios"
+
+func main() {
+	if len(os.Args) < 2 || len(os.Args) > 3 {
+		fmt.Println("Usage: replaytomandos <trace.json> [output dir, default " + defaultScenariosDir + "]")
+		os.Exit(1)
+	}
+
+	outputDir := defaultScenariosDir
+	if len(os.Args) == 3 {
+		outputDir = os.Args[2]
+	}
+
+	scenarioPath, err := convertTrace(os.Args[1], outputDir)
+	if err != nil {
+		fmt.Printf("Error converting trace: %s\n", err.Error())
+		os.Exit(1)
+	}
+	fmt.Printf("Scenario written to %s\n", scenarioPath)
+}
+
+func convertTrace(tracePath string, outputDir string) (string, error) {
+	data, err := ioutil.ReadFile(tracePath)
+	if err != nil {
+		return "", err
+	}
+
+	trace, err := replayconvert.ParseTrace(data)
+	if err != nil {
+		return "", err
+	}
+
+	scenario, err := replayconvert.TraceToScenario(trace)
+	if err != nil {
+		return "", err
+	}
+
+	name := strings.TrimSuffix(filepath.Base(tracePath), filepath.Ext(tracePath))
+	scenarioPath := filepath.Join(outputDir, name+".scen.json")
+	return scenarioPath, mc.WriteMandosScenario(scenario, scenarioPath)
+}
@@ -0,0 +1,125 @@
+package codecache
+
+import (
+	"container/list"
+	"sync"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.CodeCache = (*CodeCache)(nil)
+
+type cachedCode struct {
+	codeHash string
+	code     []byte
+}
+
+// CodeCache is a CodeCache holding the most recently used contract codes, up
+// to a total number of bytes. A single CodeCache can be shared by the hosts of
+// all the shards and threads of a node, so that the code of the contracts
+// deployed many times, such as the pairs created by a factory, is read from
+// the blockchain hook once; the cached codes must not be modified.
+type CodeCache struct {
+	mutCodes      sync.Mutex
+	maxSize       uint64
+	size          uint64
+	codes         map[string]*list.Element
+	evictionOrder *list.List
+}
+
+// NewCodeCache creates a new CodeCache holding up to maxSize bytes of code
+func NewCodeCache(maxSize uint64) (*CodeCache, error) {
+	if maxSize == 0 {
+		return nil, ErrInvalidMaxSize
+	}
+
+	return &CodeCache{
+		maxSize:       maxSize,
+		codes:         make(map[string]*list.Element),
+		evictionOrder: list.New(),
+	}, nil
+}
+
+// GetCode returns the code with the given code hash, if cached
+func (cache *CodeCache) GetCode(codeHash []byte) ([]byte, bool) {
+	cache.mutCodes.Lock()
+	defer cache.mutCodes.Unlock()
+
+	element, found := cache.codes[string(codeHash)]
+	if !found {
+		return nil, false
+	}
+
+	cache.evictionOrder.MoveToFront(element)
+	return element.Value.(*cachedCode).code, true
+}
+
+// PutCode caches a copy of the code with the given code hash, forgetting the
+// least recently used codes if needed; codes larger than the cache are ignored
+func (cache *CodeCache) PutCode(codeHash []byte, code []byte) {
+	codeSize := uint64(len(code))
+	if len(codeHash) == 0 || codeSize == 0 || codeSize > cache.maxSize {
+		return
+	}
+
+	cache.mutCodes.Lock()
+	defer cache.mutCodes.Unlock()
+
+	element, found := cache.codes[string(codeHash)]
+	if found {
+		cache.evictionOrder.MoveToFront(element)
+		return
+	}
+
+	for cache.size+codeSize > cache.maxSize {
+		cache.removeLeastRecentCode()
+	}
+
+	cache.codes[string(codeHash)] = cache.evictionOrder.PushFront(&cachedCode{
+		codeHash: string(codeHash),
+		code:     append([]byte{}, code...),
+	})
+	cache.size += codeSize
+}
+
+// NumCodes returns the number of codes currently cached
+func (cache *CodeCache) NumCodes() int {
+	cache.mutCodes.Lock()
+	defer cache.mutCodes.Unlock()
+
+	return len(cache.codes)
+}
+
+// Size returns the number of bytes of the codes currently cached
+func (cache *CodeCache) Size() uint64 {
+	cache.mutCodes.Lock()
+	defer cache.mutCodes.Unlock()
+
+	return cache.size
+}
+
+// Clear forgets all the cached codes
+func (cache *CodeCache) Clear() {
+	cache.mutCodes.Lock()
+	defer cache.mutCodes.Unlock()
+
+	cache.codes = make(map[string]*list.Element)
+	cache.evictionOrder.Init()
+	cache.size = 0
+}
+
+func (cache *CodeCache) removeLeastRecentCode() {
+	leastRecent := cache.evictionOrder.Back()
+	if leastRecent == nil {
+		return
+	}
+
+	removed := cache.evictionOrder.Remove(leastRecent).(*cachedCode)
+	delete(cache.codes, removed.codeHash)
+	cache.size -= uint64(len(removed.code))
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (cache *CodeCache) IsInterfaceNil() bool {
+	return cache == nil
+}
@@ -0,0 +1,64 @@
+package codecache
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestNewCodeCache(t *testing.T) {
+	cache, err := NewCodeCache(0)
+	require.Nil(t, cache)
+	require.Equal(t, ErrInvalidMaxSize, err)
+
+	cache, err = NewCodeCache(10)
+	require.Nil(t, err)
+	require.False(t, cache.IsInterfaceNil())
+}
+
+func TestCodeCache_GetPutCode(t *testing.T) {
+	cache, _ := NewCodeCache(10)
+
+	_, found := cache.GetCode([]byte("hashA"))
+	require.False(t, found)
+
+	code := []byte("codeA")
+	cache.PutCode([]byte("hashA"), code)
+	code[0] = 'X'
+	cached, found := cache.GetCode([]byte("hashA"))
+	require.True(t, found)
+	require.Equal(t, []byte("codeA"), cached)
+
+	// the same code is cached once
+	cache.PutCode([]byte("hashA"), []byte("codeA"))
+	require.Equal(t, 1, cache.NumCodes())
+	require.Equal(t, uint64(5), cache.Size())
+
+	// empty codes and codes larger than the cache are not cached
+	cache.PutCode([]byte("hashB"), nil)
+	cache.PutCode([]byte("hashC"), []byte("code larger than the cache"))
+	require.Equal(t, 1, cache.NumCodes())
+
+	cache.Clear()
+	require.Equal(t, 0, cache.NumCodes())
+	require.Equal(t, uint64(0), cache.Size())
+}
+
+func TestCodeCache_EvictsLeastRecentCodes(t *testing.T) {
+	cache, _ := NewCodeCache(10)
+
+	cache.PutCode([]byte("hashA"), []byte("aaaa"))
+	cache.PutCode([]byte("hashB"), []byte("bbbb"))
+	_, _ = cache.GetCode([]byte("hashA"))
+
+	cache.PutCode([]byte("hashC"), []byte("cccccc"))
+	require.Equal(t, 2, cache.NumCodes())
+	require.Equal(t, uint64(10), cache.Size())
+
+	_, found := cache.GetCode([]byte("hashB"))
+	require.False(t, found)
+	_, found = cache.GetCode([]byte("hashA"))
+	require.True(t, found)
+	_, found = cache.GetCode([]byte("hashC"))
+	require.True(t, found)
+}
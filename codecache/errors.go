@@ -0,0 +1,6 @@
+package codecache
+
+import "errors"
+
+// ErrInvalidMaxSize signals that the code cache could not hold any code
+var ErrInvalidMaxSize = errors.New("invalid max size of the code cache")
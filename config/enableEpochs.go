@@ -0,0 +1,4 @@
+package config
+
+// DisabledEpoch is the activation epoch of a feature which is never enabled
+const DisabledEpoch = ^uint32(0)
@@ -69,12 +69,16 @@ type ElrondAPICost struct {
 	SetAsyncGroupCallback   uint64
 	SetAsyncContextCallback uint64
 	GetCallbackClosure      uint64
+	AsyncCallDataPerByte    uint64
 	CreateContract          uint64
 	GetReturnData           uint64
 	GetNumReturnData        uint64
 	GetReturnDataSize       uint64
 	CleanReturnData         uint64
 	DeleteFromReturnData    uint64
+	MaxReturnDataSize       uint64
+	MaxNumberOfLogs         uint64
+	MaxLogSize              uint64
 }
 
 // TODO remove this struct
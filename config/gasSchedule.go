@@ -118,6 +118,19 @@ func CreateGasConfig(gasMap GasScheduleMap) (*GasCost, error) {
 	return gasCost, nil
 }
 
+// optionalGasCostFields may be left out of a gas schedule; a zero value
+// disables them, so that older gas schedules keep their semantics
+var optionalGasCostFields = map[string]bool{
+	"MemoryGrowPerPage": true,
+	"MaxMemoryPages":    true,
+	"MaxStackHeight":    true,
+	"MaxReturnDataSize": true,
+	"MaxNumberOfLogs":   true,
+	"MaxLogSize":        true,
+
+	"AsyncCallDataPerByte": true,
+}
+
 func checkForZeroUint64Fields(arg interface{}) error {
 	v := reflect.ValueOf(arg)
 	for i := 0; i < v.NumField(); i++ {
@@ -125,6 +138,9 @@ func checkForZeroUint64Fields(arg interface{}) error {
 		if field.Kind() != reflect.Uint64 && field.Kind() != reflect.Uint32 {
 			continue
 		}
+		if optionalGasCostFields[v.Type().Field(i).Name] {
+			continue
+		}
 		if field.Uint() == 0 {
 			name := v.Type().Field(i).Name
 			return fmt.Errorf("gas cost for operation %s has been set to 0 or is not set", name)
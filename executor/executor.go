@@ -1,18 +1,84 @@
 package executor
 
 import (
+	"time"
+
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 )
 
+// MeteringMode tells whether an executor instance charges the gas of the
+// executed opcodes. It is passed to the executor as a bool, so that
+// CompilationOptions keeps the layout expected by the executor.
+type MeteringMode bool
+
+const (
+	// MeteringEnabled charges the gas of every executed opcode
+	MeteringEnabled MeteringMode = true
+
+	// MeteringDisabled executes the opcodes without charging their gas, for
+	// the system contracts exempted from metering; the host functions still
+	// charge their gas, and the execution timeout still applies
+	MeteringDisabled MeteringMode = false
+)
+
+// String returns the name of the metering mode shown in the traces
+func (mode MeteringMode) String() string {
+	if mode == MeteringEnabled {
+		return "metered"
+	}
+	return "unmetered"
+}
+
 // CompilationOptions contains configurations for instantiating an executor instance.
+// MemoryGrowPerPage and MaxMemoryPages come after the fields read by the wasmer
+// executor, and are only applied by the instances implementing MemoryGrowthMeter.
 type CompilationOptions struct {
 	GasLimit           uint64
 	UnmeteredLocals    uint64
 	MaxMemoryGrow      uint64
 	MaxMemoryGrowDelta uint64
 	OpcodeTrace        bool
-	Metering           bool
+	Metering           MeteringMode
 	RuntimeBreakpoints bool
+	MemoryGrowPerPage  uint64
+	MaxMemoryPages     uint64
+}
+
+// MemoryGrowthMeter is implemented by the executor instances which charge
+// MemoryGrowPerPage and enforce MaxMemoryPages at each memory.grow, stopping
+// at an out of gas or a memory limit breakpoint; the host checks the memory
+// of the other instances once each call returns.
+type MemoryGrowthMeter interface {
+	MetersMemoryGrowth() bool
+}
+
+// InstanceState is the opaque state of an activation, saved by an
+// InstanceStateHandler and restored only by the instance which saved it.
+type InstanceState interface{}
+
+// InstanceStateHandler is implemented by the executor instances which can save
+// the memory, the globals, the stack and the gas of a suspended activation and
+// restore them later, so that a nested call of the same contract may run on
+// the instance of its caller. The wasmer C API exposes neither the internal
+// globals nor a way to shrink the memory, so wasmer instances do not implement
+// it and nested calls keep running on fresh instances.
+type InstanceStateHandler interface {
+	SaveState() (InstanceState, error)
+	RestoreState(state InstanceState) error
+}
+
+// VMHookPanicHandler is implemented by the VMHooks which convert the panics
+// raised while executing a VM hook into VM errors.
+type VMHookPanicHandler interface {
+	HandleVMHookPanic(hookName string, panicValue interface{})
+}
+
+// VMHookCallObserver is implemented by the VMHooks which measure the duration
+// of the VM hook calls; the executor only times the calls while
+// VMHookCallsObserved returns true.
+type VMHookCallObserver interface {
+	VMHookCallsObserved() bool
+	ObserveVMHookCall(hookName string, duration time.Duration)
 }
 
 // Executor defines the functionality needed to create any executor instance.
@@ -13,3 +13,6 @@ var ErrFunctionNonvoidSignature = fmt.Errorf("%w (nonvoid signature)", ErrInvali
 
 // ErrFuncNotFound signals that the the function does not exist
 var ErrFuncNotFound = fmt.Errorf("%w (not found)", ErrInvalidFunction)
+
+// ErrInstanceStateNotSupported signals an instance which cannot save and restore its state
+var ErrInstanceStateNotSupported = errors.New("instance state not supported")
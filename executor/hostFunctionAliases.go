@@ -0,0 +1,24 @@
+package executor
+
+// HostFunctionAlias is a former import name of a VM hook, under which the
+// executor registers the current implementation as well, so that the
+// contracts deployed before the hook was renamed keep resolving their imports
+type HostFunctionAlias struct {
+	Alias  string
+	Target string
+}
+
+// HostFunctionAliases are the former import names of the renamed VM hooks. An
+// alias is never removed, since the deployed contracts may import it for as
+// long as they exist, and its signature is the signature of its target, so a
+// hook whose signature changes needs a new implementation, not an alias.
+var HostFunctionAliases = []HostFunctionAlias{}
+
+// HostFunctionAliasTargets returns the VM hooks by their aliases
+func HostFunctionAliasTargets(aliases []HostFunctionAlias) map[string]string {
+	targets := make(map[string]string, len(aliases))
+	for _, alias := range aliases {
+		targets[alias.Alias] = alias.Target
+	}
+	return targets
+}
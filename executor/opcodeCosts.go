@@ -453,4 +453,7 @@ type WASMOpcodeCost struct {
 	LocalsUnmetered        uint32
 	MaxMemoryGrow          uint32
 	MaxMemoryGrowDelta     uint32
+	MemoryGrowPerPage      uint32
+	MaxMemoryPages         uint32
+	MaxStackHeight         uint32
 }
@@ -19,11 +19,15 @@ type VMHooks interface {
 
 type MainVMHooks interface {
 	GetGasLeft() int64
+	StackHeightExceeded()
 	GetSCAddress(resultOffset int32)
 	GetOwnerAddress(resultOffset int32)
 	GetShardOfAddress(addressOffset int32) int32
 	IsSmartContract(addressOffset int32) int32
 	SignalError(messageOffset int32, messageLength int32)
+	SignalTypedError(code int64, messageOffset int32, messageLength int32, payloadOffset int32, payloadLength int32)
+	DebugPrint(messageOffset int32, messageLength int32)
+	GasPhase(phase int32)
 	GetExternalBalance(addressOffset int32, resultOffset int32)
 	GetBlockHash(nonce int64, resultOffset int32) int32
 	GetESDTBalance(addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64, resultOffset int32) int32
@@ -34,21 +38,26 @@ type MainVMHooks interface {
 	GetESDTLocalRoles(tokenIdHandle int32) int64
 	ValidateTokenIdentifier(tokenIdHandle int32) int32
 	TransferValue(destOffset int32, valueOffset int32, dataOffset int32, length int32) int32
+	RefundGasToCaller(gas int64) int32
 	TransferValueExecute(destOffset int32, valueOffset int32, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32
 	TransferESDTExecute(destOffset int32, tokenIDOffset int32, tokenIDLen int32, valueOffset int32, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32
 	TransferESDTNFTExecute(destOffset int32, tokenIDOffset int32, tokenIDLen int32, valueOffset int32, nonce int64, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32
 	MultiTransferESDTNFTExecute(destOffset int32, numTokenTransfers int32, tokenTransfersArgsLengthOffset int32, tokenTransferDataOffset int32, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32
 	CreateAsyncCall(destOffset int32, valueOffset int32, dataOffset int32, dataLength int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64) int32
 	SetAsyncContextCallback(callback int32, callbackLength int32, data int32, dataLength int32, gas int64) int32
+	CreatePromiseGroup(groupIDOffset int32, groupIDLength int32) int32
+	CreatePromise(groupHandle int32, destOffset int32, valueOffset int32, dataOffset int32, dataLength int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64) int32
 	UpgradeContract(destOffset int32, gasLimit int64, valueOffset int32, codeOffset int32, codeMetadataOffset int32, length int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32)
 	UpgradeFromSourceContract(destOffset int32, gasLimit int64, valueOffset int32, sourceContractAddressOffset int32, codeMetadataOffset int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32)
 	DeleteContract(destOffset int32, gasLimit int64, numArguments int32, argumentsLengthOffset int32, dataOffset int32)
 	AsyncCall(destOffset int32, valueOffset int32, dataOffset int32, length int32)
 	GetArgumentLength(id int32) int32
 	GetArgument(id int32, argOffset int32) int32
+	CopyArgumentTo(id int32, offset int32, length int32, destOffset int32) int32
 	GetFunction(functionOffset int32) int32
 	GetNumArguments() int32
 	StorageStore(keyOffset int32, keyLength int32, dataOffset int32, dataLength int32) int32
+	StorageDelete(keyOffset int32, keyLength int32) int32
 	StorageLoadLength(keyOffset int32, keyLength int32) int32
 	StorageLoadFromAddress(addressOffset int32, keyOffset int32, keyLength int32, dataOffset int32) int32
 	StorageLoad(keyOffset int32, keyLength int32, dataOffset int32) int32
@@ -85,6 +94,8 @@ type MainVMHooks interface {
 	GetPrevBlockEpoch() int64
 	GetPrevBlockRandomSeed(pointer int32)
 	Finish(pointer int32, length int32)
+	FinishReserve(length int32) int32
+	FinishAppend(dataOffset int32, length int32) int32
 	ExecuteOnSameContext(gasLimit int64, addressOffset int32, valueOffset int32, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32
 	ExecuteOnDestContext(gasLimit int64, addressOffset int32, valueOffset int32, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32
 	ExecuteReadOnly(gasLimit int64, addressOffset int32, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32
@@ -114,14 +125,34 @@ type ManagedVMHooks interface {
 	ManagedGetMultiESDTCallValue(multiCallValueHandle int32)
 	ManagedGetESDTBalance(addressHandle int32, tokenIDHandle int32, nonce int64, valueHandle int32)
 	ManagedGetESDTTokenData(addressHandle int32, tokenIDHandle int32, nonce int64, valueHandle int32, propertiesHandle int32, hashHandle int32, nameHandle int32, attributesHandle int32, creatorHandle int32, royaltiesHandle int32, urisHandle int32)
+	ManagedGetESDTNFTAttributes(tokenIDHandle int32, nonce int64, attributesHandle int32) int32
+	ManagedGetESDTNFTAttributeValue(tokenIDHandle int32, nonce int64, keyHandle int32, valueHandle int32) int32
+	ManagedGetESDTNFTURIs(tokenIDHandle int32, nonce int64, urisHandle int32) int32
+	ManagedGetESDTNFTCreator(tokenIDHandle int32, nonce int64, creatorHandle int32) int32
+	ManagedGetESDTNFTRoyalties(tokenIDHandle int32, nonce int64) int64
+	ManagedGetESDTTokenTypeOf(tokenIDHandle int32, nonce int64) int32
+	ManagedTransferNFTWithRoyalties(buyerHandle int32, tokenIDHandle int32, nonce int64, amountHandle int32, sellerHandle int32, paymentTokenHandle int32, paymentNonce int64, priceHandle int32) int32
+	ManagedEscrowLock(beneficiaryHandle int32, valueHandle int32, escrowIDHandle int32) int32
+	ManagedEscrowClaim(lockerHandle int32, escrowIDHandle int32) int32
+	ManagedEscrowRelease(lockerHandle int32, escrowIDHandle int32) int32
+	ManagedRegisterStorageMigration(sourcePrefixHandle int32, targetPrefixHandle int32, reencodeFunctionHandle int32, firstIndex int64, lastIndex int64) int32
+	ManagedContinueStorageMigration(maxKeys int64) int64
 	ManagedAsyncCall(destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32)
 	ManagedCreateAsyncCall(destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32) int32
+	ManagedCreateAsyncCallWithCallbackArgTypes(destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32, callbackArgTypesHandle int32) int32
+	ManagedCreateAsyncCallWithExpiry(destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32, callbackArgTypesHandle int32, expiryRounds int64) int32
 	ManagedGetCallbackClosure(callbackClosureHandle int32)
+	ManagedGetLockedValue(tokenIDHandle int32, nonce int64, resultHandle int32)
+	ManagedSetAsyncGroupCallback(groupIDHandle int32, successCallbackHandle int32, errorCallbackHandle int32, callbackDataHandle int32, gas int64) int32
+	ManagedAddAsyncSubGroup(parentGroupIDHandle int32, groupIDHandle int32) int32
 	ManagedUpgradeFromSourceContract(destHandle int32, gas int64, valueHandle int32, addressHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultHandle int32)
 	ManagedUpgradeContract(destHandle int32, gas int64, valueHandle int32, codeHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultHandle int32)
 	ManagedDeleteContract(destHandle int32, gasLimit int64, argumentsHandle int32)
 	ManagedDeployFromSourceContract(gas int64, valueHandle int32, addressHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultAddressHandle int32, resultHandle int32) int32
 	ManagedCreateContract(gas int64, valueHandle int32, codeHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultAddressHandle int32, resultHandle int32) int32
+	ManagedCreateDeterministicContract(gas int64, valueHandle int32, codeHandle int32, codeMetadataHandle int32, argumentsHandle int32, saltHandle int32, resultAddressHandle int32, resultHandle int32) int32
+	ManagedComputeContractAddress(deployerHandle int32, nonce int64, resultHandle int32) int32
+	ManagedComputeDeterministicAddress(saltHandle int32, codeHashHandle int32, resultHandle int32) int32
 	ManagedExecuteReadOnly(gas int64, addressHandle int32, functionHandle int32, argumentsHandle int32, resultHandle int32) int32
 	ManagedExecuteOnSameContext(gas int64, addressHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, resultHandle int32) int32
 	ManagedExecuteOnDestContext(gas int64, addressHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, resultHandle int32) int32
@@ -169,6 +200,7 @@ type BigIntVMHooks interface {
 	BigIntGetExternalBalance(addressOffset int32, result int32)
 	BigIntGetESDTExternalBalance(addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64, resultHandle int32)
 	BigIntNew(smallValue int64) int32
+	BigIntDrop(referenceHandle int32)
 	BigIntUnsignedByteLength(referenceHandle int32) int32
 	BigIntSignedByteLength(referenceHandle int32) int32
 	BigIntGetUnsignedBytes(referenceHandle int32, byteOffset int32) int32
@@ -206,6 +238,7 @@ type BigIntVMHooks interface {
 type ManagedBufferVMHooks interface {
 	MBufferNew() int32
 	MBufferNewFromBytes(dataOffset int32, dataLength int32) int32
+	MBufferDrop(mBufferHandle int32) int32
 	MBufferGetLength(mBufferHandle int32) int32
 	MBufferGetBytes(mBufferHandle int32, resultOffset int32) int32
 	MBufferGetByteSlice(sourceHandle int32, startingPosition int32, sliceLength int32, resultOffset int32) int32
@@ -222,6 +255,7 @@ type ManagedBufferVMHooks interface {
 	MBufferToBigFloat(mBufferHandle int32, bigFloatHandle int32) int32
 	MBufferFromBigFloat(mBufferHandle int32, bigFloatHandle int32) int32
 	MBufferStorageStore(keyHandle int32, sourceHandle int32) int32
+	MBufferStorageDelete(keyHandle int32) int32
 	MBufferStorageLoad(keyHandle int32, destinationHandle int32) int32
 	MBufferStorageLoadFromAddress(addressHandle int32, keyHandle int32, destinationHandle int32)
 	MBufferGetArgument(id int32, destinationHandle int32) int32
@@ -0,0 +1,158 @@
+package executorwrapper
+
+import (
+	"fmt"
+	"io"
+	"sort"
+	"strings"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
+)
+
+// FunctionCallObserver can be implemented by an ExecutorLogger which needs to
+// know the functions called on the instances.
+type FunctionCallObserver interface {
+	ObserveFunctionCall(instance *WrapperInstance, functionName string)
+}
+
+// CoverageRecorder is an ExecutorLogger which counts the calls of the
+// exported functions of the registered contracts, endpoints and callbacks
+// alike, and reports them in the lcov format. The executor does not expose the
+// functions and blocks executed inside an instance, so only the exported
+// functions are measured.
+type CoverageRecorder struct {
+	logger    ExecutorLogger
+	contracts []*contractCoverage
+}
+
+type contractCoverage struct {
+	path      string
+	endpoints []*inspect.Export
+	names     string
+	hits      map[string]uint64
+}
+
+// NewCoverageRecorder creates a new CoverageRecorder, which forwards all the
+// events to the given logger.
+func NewCoverageRecorder(logger ExecutorLogger) *CoverageRecorder {
+	return &CoverageRecorder{
+		logger:    logger,
+		contracts: make([]*contractCoverage, 0),
+	}
+}
+
+// CoverageRecorderExecutorFactory yields a WrapperExecutor factory which records the coverage of the contracts.
+func CoverageRecorderExecutorFactory(wrappedFactory executor.ExecutorAbstractFactory) (*WrapperExecutorFactory, *CoverageRecorder) {
+	recorder := NewCoverageRecorder(&NoLogger{})
+	return NewWrappedExecutorFactory(recorder, wrappedFactory), recorder
+}
+
+// AddContract registers the code of a contract, under the path reported as its
+// source file. The instances are matched to the contracts by the names of
+// their exported functions.
+func (recorder *CoverageRecorder) AddContract(path string, code []byte) error {
+	report, err := inspect.Inspect(code, nil)
+	if err != nil {
+		return err
+	}
+
+	endpoints := report.Endpoints()
+	names := make([]string, len(endpoints))
+	for i, endpoint := range endpoints {
+		names[i] = endpoint.Name
+	}
+
+	recorder.contracts = append(recorder.contracts, &contractCoverage{
+		path:      path,
+		endpoints: endpoints,
+		names:     joinSortedNames(names),
+		hits:      make(map[string]uint64),
+	})
+	return nil
+}
+
+// SetCurrentInstance forwards the call to the wrapped logger.
+func (recorder *CoverageRecorder) SetCurrentInstance(instance executor.Instance) {
+	recorder.logger.SetCurrentInstance(instance)
+}
+
+// LogExecutorEvent forwards the call to the wrapped logger.
+func (recorder *CoverageRecorder) LogExecutorEvent(description string) {
+	recorder.logger.LogExecutorEvent(description)
+}
+
+// LogVMHookCallBefore forwards the call to the wrapped logger.
+func (recorder *CoverageRecorder) LogVMHookCallBefore(callInfo string) {
+	recorder.logger.LogVMHookCallBefore(callInfo)
+}
+
+// LogVMHookCallAfter forwards the call to the wrapped logger.
+func (recorder *CoverageRecorder) LogVMHookCallAfter(callInfo string) {
+	recorder.logger.LogVMHookCallAfter(callInfo)
+}
+
+// ObserveFunctionCall counts the call of the function, if the instance belongs
+// to a registered contract.
+func (recorder *CoverageRecorder) ObserveFunctionCall(instance *WrapperInstance, functionName string) {
+	contract := recorder.findContract(instance)
+	if contract == nil {
+		return
+	}
+	contract.hits[functionName]++
+}
+
+// FunctionHits returns the number of calls of the function of the contract
+// registered under the given path.
+func (recorder *CoverageRecorder) FunctionHits(path string, functionName string) uint64 {
+	for _, contract := range recorder.contracts {
+		if contract.path == path {
+			return contract.hits[functionName]
+		}
+	}
+	return 0
+}
+
+// WriteLCOV writes the coverage of all the registered contracts in the lcov
+// format, with one function record per exported function. The index of the
+// function in the wasm binary stands for its line number.
+func (recorder *CoverageRecorder) WriteLCOV(writer io.Writer) error {
+	var sb strings.Builder
+	for _, contract := range recorder.contracts {
+		sb.WriteString("TN:\n")
+		sb.WriteString(fmt.Sprintf("SF:%s\n", contract.path))
+		numHit := 0
+		for _, endpoint := range contract.endpoints {
+			sb.WriteString(fmt.Sprintf("FN:%d,%s\n", endpoint.Index, endpoint.Name))
+		}
+		for _, endpoint := range contract.endpoints {
+			hits := contract.hits[endpoint.Name]
+			if hits > 0 {
+				numHit++
+			}
+			sb.WriteString(fmt.Sprintf("FNDA:%d,%s\n", hits, endpoint.Name))
+		}
+		sb.WriteString(fmt.Sprintf("FNF:%d\n", len(contract.endpoints)))
+		sb.WriteString(fmt.Sprintf("FNH:%d\n", numHit))
+		sb.WriteString("end_of_record\n")
+	}
+
+	_, err := io.WriteString(writer, sb.String())
+	return err
+}
+
+func (recorder *CoverageRecorder) findContract(instance *WrapperInstance) *contractCoverage {
+	names := joinSortedNames(instance.wrappedInstance.GetFunctionNames())
+	for _, contract := range recorder.contracts {
+		if contract.names == names {
+			return contract
+		}
+	}
+	return nil
+}
+
+func joinSortedNames(names []string) string {
+	sorted := append([]string{}, names...)
+	sort.Strings(sorted)
+	return strings.Join(sorted, ",")
+}
@@ -0,0 +1,125 @@
+package executorwrapper
+
+import (
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// InstanceCallObserver can be implemented by an ExecutorLogger which needs to
+// know the instance that is running when VM hooks are called.
+type InstanceCallObserver interface {
+	BeforeInstanceCall(instance *WrapperInstance)
+	AfterInstanceCall(instance *WrapperInstance)
+}
+
+// OutOfGasFault configures the point at which an OutOfGasInjector forces the
+// running instance to run out of gas. A zero value disables the trigger.
+type OutOfGasFault struct {
+	// AtVMHookCall is the 1-based index of the VM hook call, counted over the
+	// whole execution, before which all the gas of the running instance is spent.
+	AtVMHookCall uint64
+
+	// AtOpcodeCount caps the gas limit of each instance call, in metering
+	// points. The points are the opcodes executed by the instance when every
+	// opcode costs 1 and the VM hooks are free, as in config.MakeGasMapForTests()
+	// with the API costs set to 0.
+	AtOpcodeCount uint64
+}
+
+// OutOfGasInjector is an ExecutorLogger which interrupts the execution with an
+// out of gas error at the point configured by an OutOfGasFault, so that tests
+// can check the rollback at every interruption point of a call.
+type OutOfGasInjector struct {
+	logger         ExecutorLogger
+	fault          OutOfGasFault
+	instanceStack  []*WrapperInstance
+	numVMHookCalls uint64
+	faultInjected  bool
+}
+
+// NewOutOfGasInjector creates a new OutOfGasInjector, which forwards all the
+// events to the given logger.
+func NewOutOfGasInjector(logger ExecutorLogger, fault OutOfGasFault) *OutOfGasInjector {
+	return &OutOfGasInjector{
+		logger:        logger,
+		fault:         fault,
+		instanceStack: make([]*WrapperInstance, 0),
+	}
+}
+
+// OutOfGasInjectorExecutorFactory yields a WrapperExecutor factory which injects the given out of gas fault.
+func OutOfGasInjectorExecutorFactory(
+	wrappedFactory executor.ExecutorAbstractFactory,
+	fault OutOfGasFault,
+) (*WrapperExecutorFactory, *OutOfGasInjector) {
+	injector := NewOutOfGasInjector(&NoLogger{}, fault)
+	return NewWrappedExecutorFactory(injector, wrappedFactory), injector
+}
+
+// SetCurrentInstance forwards the call to the wrapped logger.
+func (injector *OutOfGasInjector) SetCurrentInstance(instance executor.Instance) {
+	injector.logger.SetCurrentInstance(instance)
+}
+
+// LogExecutorEvent forwards the call to the wrapped logger.
+func (injector *OutOfGasInjector) LogExecutorEvent(description string) {
+	injector.logger.LogExecutorEvent(description)
+}
+
+// LogVMHookCallBefore counts the VM hook calls and spends all the gas of the
+// running instance before the configured one.
+func (injector *OutOfGasInjector) LogVMHookCallBefore(callInfo string) {
+	injector.numVMHookCalls++
+	if injector.numVMHookCalls == injector.fault.AtVMHookCall {
+		injector.exhaustRunningInstance()
+	}
+	injector.logger.LogVMHookCallBefore(callInfo)
+}
+
+// LogVMHookCallAfter forwards the call to the wrapped logger.
+func (injector *OutOfGasInjector) LogVMHookCallAfter(callInfo string) {
+	injector.logger.LogVMHookCallAfter(callInfo)
+}
+
+// BeforeInstanceCall caps the gas limit of the instance to the configured opcode count.
+func (injector *OutOfGasInjector) BeforeInstanceCall(instance *WrapperInstance) {
+	injector.instanceStack = append(injector.instanceStack, instance)
+	if injector.isGasLimitCapped(instance) {
+		instance.wrappedInstance.SetGasLimit(injector.fault.AtOpcodeCount)
+	}
+}
+
+// AfterInstanceCall restores the gas limit of the instance, for the VM to account the gas correctly.
+func (injector *OutOfGasInjector) AfterInstanceCall(instance *WrapperInstance) {
+	if injector.isGasLimitCapped(instance) {
+		if instance.GetPointsUsed() >= injector.fault.AtOpcodeCount {
+			injector.faultInjected = true
+		}
+		instance.wrappedInstance.SetGasLimit(instance.GetGasLimit())
+	}
+	injector.instanceStack = injector.instanceStack[:len(injector.instanceStack)-1]
+}
+
+// NumVMHookCalls returns the number of VM hook calls observed so far.
+func (injector *OutOfGasInjector) NumVMHookCalls() uint64 {
+	return injector.numVMHookCalls
+}
+
+// FaultInjected returns whether the configured fault was reached during the execution.
+func (injector *OutOfGasInjector) FaultInjected() bool {
+	return injector.faultInjected
+}
+
+func (injector *OutOfGasInjector) isGasLimitCapped(instance *WrapperInstance) bool {
+	return injector.fault.AtOpcodeCount > 0 && injector.fault.AtOpcodeCount < instance.GetGasLimit()
+}
+
+func (injector *OutOfGasInjector) exhaustRunningInstance() {
+	numInstances := len(injector.instanceStack)
+	if numInstances == 0 {
+		return
+	}
+
+	instance := injector.instanceStack[numInstances-1]
+	instance.SetPointsUsed(instance.GetGasLimit() + 1)
+	injector.faultInjected = true
+}
@@ -40,6 +40,7 @@ func (wexec *WrapperExecutor) NewInstanceWithOptions(
 	return &WrapperInstance{
 		logger:          wexec.logger,
 		wrappedInstance: wrappedInstance,
+		gasLimit:        options.GasLimit,
 	}, nil
 }
 
@@ -57,6 +58,7 @@ func (wexec *WrapperExecutor) NewInstanceFromCompiledCodeWithOptions(
 	return &WrapperInstance{
 		logger:          wexec.logger,
 		wrappedInstance: wrappedInstance,
+		gasLimit:        options.GasLimit,
 	}, nil
 }
 
@@ -11,6 +11,7 @@ import (
 type WrapperInstance struct {
 	logger          ExecutorLogger
 	wrappedInstance executor.Instance
+	gasLimit        uint64
 }
 
 // GetPointsUsed wraps the call to the underlying instance.
@@ -24,9 +25,15 @@ func (inst *WrapperInstance) SetPointsUsed(points uint64) {
 
 }
 
+// GetGasLimit returns the gas limit last set on the underlying instance.
+func (inst *WrapperInstance) GetGasLimit() uint64 {
+	return inst.gasLimit
+}
+
 // SetGasLimit wraps the call to the underlying instance.
 func (inst *WrapperInstance) SetGasLimit(gasLimit uint64) {
 	inst.logger.LogExecutorEvent(fmt.Sprintf("SetGasLimit: %d", gasLimit))
+	inst.gasLimit = gasLimit
 	inst.wrappedInstance.SetGasLimit(gasLimit)
 }
 
@@ -43,6 +50,30 @@ func (inst *WrapperInstance) GetBreakpointValue() uint64 {
 	return result
 }
 
+// MetersMemoryGrowth returns true if the underlying instance meters memory.grow.
+func (inst *WrapperInstance) MetersMemoryGrowth() bool {
+	meter, ok := inst.wrappedInstance.(executor.MemoryGrowthMeter)
+	return ok && meter.MetersMemoryGrowth()
+}
+
+// SaveState wraps the call to the underlying instance, if it can save its state.
+func (inst *WrapperInstance) SaveState() (executor.InstanceState, error) {
+	handler, ok := inst.wrappedInstance.(executor.InstanceStateHandler)
+	if !ok {
+		return nil, executor.ErrInstanceStateNotSupported
+	}
+	return handler.SaveState()
+}
+
+// RestoreState wraps the call to the underlying instance, if it can restore its state.
+func (inst *WrapperInstance) RestoreState(state executor.InstanceState) error {
+	handler, ok := inst.wrappedInstance.(executor.InstanceStateHandler)
+	if !ok {
+		return executor.ErrInstanceStateNotSupported
+	}
+	return handler.RestoreState(state)
+}
+
 // Cache wraps the call to the underlying instance.
 func (inst *WrapperInstance) Cache() ([]byte, error) {
 	return inst.wrappedInstance.Cache()
@@ -56,6 +87,18 @@ func (inst *WrapperInstance) Clean() {
 // CallFunction wraps the call to the underlying instance.
 func (inst *WrapperInstance) CallFunction(functionName string) error {
 	inst.logger.LogExecutorEvent(fmt.Sprintf("CallFunction(%s):", functionName))
+	functionObserver, isFunctionObserver := inst.logger.(FunctionCallObserver)
+	if isFunctionObserver {
+		functionObserver.ObserveFunctionCall(inst, functionName)
+	}
+
+	observer, isObserver := inst.logger.(InstanceCallObserver)
+	if !isObserver {
+		return inst.wrappedInstance.CallFunction(functionName)
+	}
+
+	observer.BeforeInstanceCall(inst)
+	defer observer.AfterInstanceCall(inst)
 	return inst.wrappedInstance.CallFunction(functionName)
 }
 
@@ -27,6 +27,14 @@ func (w *WrapperVMHooks) GetGasLeft() int64 {
 	return result
 }
 
+// StackHeightExceeded VM hook wrapper
+func (w *WrapperVMHooks) StackHeightExceeded() {
+	callInfo := "StackHeightExceeded()"
+	w.logger.LogVMHookCallBefore(callInfo)
+	w.wrappedVMHooks.StackHeightExceeded()
+	w.logger.LogVMHookCallAfter(callInfo)
+}
+
 // GetSCAddress VM hook wrapper
 func (w *WrapperVMHooks) GetSCAddress(resultOffset int32) {
 	callInfo := fmt.Sprintf("GetSCAddress(%d)", resultOffset)
@@ -69,6 +77,30 @@ func (w *WrapperVMHooks) SignalError(messageOffset int32, messageLength int32) {
 	w.logger.LogVMHookCallAfter(callInfo)
 }
 
+// SignalTypedError VM hook wrapper
+func (w *WrapperVMHooks) SignalTypedError(code int64, messageOffset int32, messageLength int32, payloadOffset int32, payloadLength int32) {
+	callInfo := fmt.Sprintf("SignalTypedError(%d, %d, %d, %d, %d)", code, messageOffset, messageLength, payloadOffset, payloadLength)
+	w.logger.LogVMHookCallBefore(callInfo)
+	w.wrappedVMHooks.SignalTypedError(code, messageOffset, messageLength, payloadOffset, payloadLength)
+	w.logger.LogVMHookCallAfter(callInfo)
+}
+
+// DebugPrint VM hook wrapper
+func (w *WrapperVMHooks) DebugPrint(messageOffset int32, messageLength int32) {
+	callInfo := fmt.Sprintf("DebugPrint(%d, %d)", messageOffset, messageLength)
+	w.logger.LogVMHookCallBefore(callInfo)
+	w.wrappedVMHooks.DebugPrint(messageOffset, messageLength)
+	w.logger.LogVMHookCallAfter(callInfo)
+}
+
+// GasPhase VM hook wrapper
+func (w *WrapperVMHooks) GasPhase(phase int32) {
+	callInfo := fmt.Sprintf("GasPhase(%d)", phase)
+	w.logger.LogVMHookCallBefore(callInfo)
+	w.wrappedVMHooks.GasPhase(phase)
+	w.logger.LogVMHookCallAfter(callInfo)
+}
+
 // GetExternalBalance VM hook wrapper
 func (w *WrapperVMHooks) GetExternalBalance(addressOffset int32, resultOffset int32) {
 	callInfo := fmt.Sprintf("GetExternalBalance(%d, %d)", addressOffset, resultOffset)
@@ -158,6 +190,15 @@ func (w *WrapperVMHooks) TransferValue(destOffset int32, valueOffset int32, data
 	return result
 }
 
+// RefundGasToCaller VM hook wrapper
+func (w *WrapperVMHooks) RefundGasToCaller(gas int64) int32 {
+	callInfo := fmt.Sprintf("RefundGasToCaller(%d)", gas)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.RefundGasToCaller(gas)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // TransferValueExecute VM hook wrapper
 func (w *WrapperVMHooks) TransferValueExecute(destOffset int32, valueOffset int32, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	callInfo := fmt.Sprintf("TransferValueExecute(%d, %d, %d, %d, %d, %d, %d, %d)", destOffset, valueOffset, gasLimit, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
@@ -212,6 +253,24 @@ func (w *WrapperVMHooks) SetAsyncContextCallback(callback int32, callbackLength
 	return result
 }
 
+// CreatePromiseGroup VM hook wrapper
+func (w *WrapperVMHooks) CreatePromiseGroup(groupIDOffset int32, groupIDLength int32) int32 {
+	callInfo := fmt.Sprintf("CreatePromiseGroup(%d, %d)", groupIDOffset, groupIDLength)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.CreatePromiseGroup(groupIDOffset, groupIDLength)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// CreatePromise VM hook wrapper
+func (w *WrapperVMHooks) CreatePromise(groupHandle int32, destOffset int32, valueOffset int32, dataOffset int32, dataLength int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64) int32 {
+	callInfo := fmt.Sprintf("CreatePromise(%d, %d, %d, %d, %d, %d, %d, %d, %d, %d, %d)", groupHandle, destOffset, valueOffset, dataOffset, dataLength, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.CreatePromise(groupHandle, destOffset, valueOffset, dataOffset, dataLength, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // UpgradeContract VM hook wrapper
 func (w *WrapperVMHooks) UpgradeContract(destOffset int32, gasLimit int64, valueOffset int32, codeOffset int32, codeMetadataOffset int32, length int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) {
 	callInfo := fmt.Sprintf("UpgradeContract(%d, %d, %d, %d, %d, %d, %d, %d, %d)", destOffset, gasLimit, valueOffset, codeOffset, codeMetadataOffset, length, numArguments, argumentsLengthOffset, dataOffset)
@@ -262,6 +321,15 @@ func (w *WrapperVMHooks) GetArgument(id int32, argOffset int32) int32 {
 	return result
 }
 
+// CopyArgumentTo VM hook wrapper
+func (w *WrapperVMHooks) CopyArgumentTo(id int32, offset int32, length int32, destOffset int32) int32 {
+	callInfo := fmt.Sprintf("CopyArgumentTo(%d, %d, %d, %d)", id, offset, length, destOffset)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.CopyArgumentTo(id, offset, length, destOffset)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // GetFunction VM hook wrapper
 func (w *WrapperVMHooks) GetFunction(functionOffset int32) int32 {
 	callInfo := fmt.Sprintf("GetFunction(%d)", functionOffset)
@@ -289,6 +357,15 @@ func (w *WrapperVMHooks) StorageStore(keyOffset int32, keyLength int32, dataOffs
 	return result
 }
 
+// StorageDelete VM hook wrapper
+func (w *WrapperVMHooks) StorageDelete(keyOffset int32, keyLength int32) int32 {
+	callInfo := fmt.Sprintf("StorageDelete(%d, %d)", keyOffset, keyLength)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.StorageDelete(keyOffset, keyLength)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // StorageLoadLength VM hook wrapper
 func (w *WrapperVMHooks) StorageLoadLength(keyOffset int32, keyLength int32) int32 {
 	callInfo := fmt.Sprintf("StorageLoadLength(%d, %d)", keyOffset, keyLength)
@@ -605,6 +682,24 @@ func (w *WrapperVMHooks) Finish(pointer int32, length int32) {
 	w.logger.LogVMHookCallAfter(callInfo)
 }
 
+// FinishReserve VM hook wrapper
+func (w *WrapperVMHooks) FinishReserve(length int32) int32 {
+	callInfo := fmt.Sprintf("FinishReserve(%d)", length)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.FinishReserve(length)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// FinishAppend VM hook wrapper
+func (w *WrapperVMHooks) FinishAppend(dataOffset int32, length int32) int32 {
+	callInfo := fmt.Sprintf("FinishAppend(%d, %d)", dataOffset, length)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.FinishAppend(dataOffset, length)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // ExecuteOnSameContext VM hook wrapper
 func (w *WrapperVMHooks) ExecuteOnSameContext(gasLimit int64, addressOffset int32, valueOffset int32, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	callInfo := fmt.Sprintf("ExecuteOnSameContext(%d, %d, %d, %d, %d, %d, %d, %d)", gasLimit, addressOffset, valueOffset, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
@@ -821,6 +916,114 @@ func (w *WrapperVMHooks) ManagedGetESDTTokenData(addressHandle int32, tokenIDHan
 	w.logger.LogVMHookCallAfter(callInfo)
 }
 
+// ManagedGetESDTNFTAttributes VM hook wrapper
+func (w *WrapperVMHooks) ManagedGetESDTNFTAttributes(tokenIDHandle int32, nonce int64, attributesHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedGetESDTNFTAttributes(%d, %d, %d)", tokenIDHandle, nonce, attributesHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedGetESDTNFTAttributes(tokenIDHandle, nonce, attributesHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedGetESDTNFTAttributeValue VM hook wrapper
+func (w *WrapperVMHooks) ManagedGetESDTNFTAttributeValue(tokenIDHandle int32, nonce int64, keyHandle int32, valueHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedGetESDTNFTAttributeValue(%d, %d, %d, %d)", tokenIDHandle, nonce, keyHandle, valueHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedGetESDTNFTAttributeValue(tokenIDHandle, nonce, keyHandle, valueHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedGetESDTNFTURIs VM hook wrapper
+func (w *WrapperVMHooks) ManagedGetESDTNFTURIs(tokenIDHandle int32, nonce int64, urisHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedGetESDTNFTURIs(%d, %d, %d)", tokenIDHandle, nonce, urisHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedGetESDTNFTURIs(tokenIDHandle, nonce, urisHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedGetESDTNFTCreator VM hook wrapper
+func (w *WrapperVMHooks) ManagedGetESDTNFTCreator(tokenIDHandle int32, nonce int64, creatorHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedGetESDTNFTCreator(%d, %d, %d)", tokenIDHandle, nonce, creatorHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedGetESDTNFTCreator(tokenIDHandle, nonce, creatorHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedGetESDTNFTRoyalties VM hook wrapper
+func (w *WrapperVMHooks) ManagedGetESDTNFTRoyalties(tokenIDHandle int32, nonce int64) int64 {
+	callInfo := fmt.Sprintf("ManagedGetESDTNFTRoyalties(%d, %d)", tokenIDHandle, nonce)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedGetESDTNFTRoyalties(tokenIDHandle, nonce)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedGetESDTTokenTypeOf VM hook wrapper
+func (w *WrapperVMHooks) ManagedGetESDTTokenTypeOf(tokenIDHandle int32, nonce int64) int32 {
+	callInfo := fmt.Sprintf("ManagedGetESDTTokenTypeOf(%d, %d)", tokenIDHandle, nonce)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedGetESDTTokenTypeOf(tokenIDHandle, nonce)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedTransferNFTWithRoyalties VM hook wrapper
+func (w *WrapperVMHooks) ManagedTransferNFTWithRoyalties(buyerHandle int32, tokenIDHandle int32, nonce int64, amountHandle int32, sellerHandle int32, paymentTokenHandle int32, paymentNonce int64, priceHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedTransferNFTWithRoyalties(%d, %d, %d, %d, %d, %d, %d, %d)", buyerHandle, tokenIDHandle, nonce, amountHandle, sellerHandle, paymentTokenHandle, paymentNonce, priceHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedTransferNFTWithRoyalties(buyerHandle, tokenIDHandle, nonce, amountHandle, sellerHandle, paymentTokenHandle, paymentNonce, priceHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedEscrowLock VM hook wrapper
+func (w *WrapperVMHooks) ManagedEscrowLock(beneficiaryHandle int32, valueHandle int32, escrowIDHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedEscrowLock(%d, %d, %d)", beneficiaryHandle, valueHandle, escrowIDHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedEscrowLock(beneficiaryHandle, valueHandle, escrowIDHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedEscrowClaim VM hook wrapper
+func (w *WrapperVMHooks) ManagedEscrowClaim(lockerHandle int32, escrowIDHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedEscrowClaim(%d, %d)", lockerHandle, escrowIDHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedEscrowClaim(lockerHandle, escrowIDHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedEscrowRelease VM hook wrapper
+func (w *WrapperVMHooks) ManagedEscrowRelease(lockerHandle int32, escrowIDHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedEscrowRelease(%d, %d)", lockerHandle, escrowIDHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedEscrowRelease(lockerHandle, escrowIDHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedRegisterStorageMigration VM hook wrapper
+func (w *WrapperVMHooks) ManagedRegisterStorageMigration(sourcePrefixHandle int32, targetPrefixHandle int32, reencodeFunctionHandle int32, firstIndex int64, lastIndex int64) int32 {
+	callInfo := fmt.Sprintf("ManagedRegisterStorageMigration(%d, %d, %d, %d, %d)", sourcePrefixHandle, targetPrefixHandle, reencodeFunctionHandle, firstIndex, lastIndex)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedRegisterStorageMigration(sourcePrefixHandle, targetPrefixHandle, reencodeFunctionHandle, firstIndex, lastIndex)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedContinueStorageMigration VM hook wrapper
+func (w *WrapperVMHooks) ManagedContinueStorageMigration(maxKeys int64) int64 {
+	callInfo := fmt.Sprintf("ManagedContinueStorageMigration(%d)", maxKeys)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedContinueStorageMigration(maxKeys)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // ManagedAsyncCall VM hook wrapper
 func (w *WrapperVMHooks) ManagedAsyncCall(destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32) {
 	callInfo := fmt.Sprintf("ManagedAsyncCall(%d, %d, %d, %d)", destHandle, valueHandle, functionHandle, argumentsHandle)
@@ -838,6 +1041,24 @@ func (w *WrapperVMHooks) ManagedCreateAsyncCall(destHandle int32, valueHandle in
 	return result
 }
 
+// ManagedCreateAsyncCallWithCallbackArgTypes VM hook wrapper
+func (w *WrapperVMHooks) ManagedCreateAsyncCallWithCallbackArgTypes(destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32, callbackArgTypesHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedCreateAsyncCallWithCallbackArgTypes(%d, %d, %d, %d, %d, %d, %d, %d, %d, %d, %d, %d)", destHandle, valueHandle, functionHandle, argumentsHandle, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback, callbackClosureHandle, callbackArgTypesHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedCreateAsyncCallWithCallbackArgTypes(destHandle, valueHandle, functionHandle, argumentsHandle, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback, callbackClosureHandle, callbackArgTypesHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedCreateAsyncCallWithExpiry VM hook wrapper
+func (w *WrapperVMHooks) ManagedCreateAsyncCallWithExpiry(destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32, callbackArgTypesHandle int32, expiryRounds int64) int32 {
+	callInfo := fmt.Sprintf("ManagedCreateAsyncCallWithExpiry(%d, %d, %d, %d, %d, %d, %d, %d, %d, %d, %d, %d, %d)", destHandle, valueHandle, functionHandle, argumentsHandle, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback, callbackClosureHandle, callbackArgTypesHandle, expiryRounds)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedCreateAsyncCallWithExpiry(destHandle, valueHandle, functionHandle, argumentsHandle, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback, callbackClosureHandle, callbackArgTypesHandle, expiryRounds)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // ManagedGetCallbackClosure VM hook wrapper
 func (w *WrapperVMHooks) ManagedGetCallbackClosure(callbackClosureHandle int32) {
 	callInfo := fmt.Sprintf("ManagedGetCallbackClosure(%d)", callbackClosureHandle)
@@ -846,6 +1067,32 @@ func (w *WrapperVMHooks) ManagedGetCallbackClosure(callbackClosureHandle int32)
 	w.logger.LogVMHookCallAfter(callInfo)
 }
 
+// ManagedGetLockedValue VM hook wrapper
+func (w *WrapperVMHooks) ManagedGetLockedValue(tokenIDHandle int32, nonce int64, resultHandle int32) {
+	callInfo := fmt.Sprintf("ManagedGetLockedValue(%d, %d, %d)", tokenIDHandle, nonce, resultHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	w.wrappedVMHooks.ManagedGetLockedValue(tokenIDHandle, nonce, resultHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+}
+
+// ManagedSetAsyncGroupCallback VM hook wrapper
+func (w *WrapperVMHooks) ManagedSetAsyncGroupCallback(groupIDHandle int32, successCallbackHandle int32, errorCallbackHandle int32, callbackDataHandle int32, gas int64) int32 {
+	callInfo := fmt.Sprintf("ManagedSetAsyncGroupCallback(%d, %d, %d, %d, %d)", groupIDHandle, successCallbackHandle, errorCallbackHandle, callbackDataHandle, gas)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedSetAsyncGroupCallback(groupIDHandle, successCallbackHandle, errorCallbackHandle, callbackDataHandle, gas)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedAddAsyncSubGroup VM hook wrapper
+func (w *WrapperVMHooks) ManagedAddAsyncSubGroup(parentGroupIDHandle int32, groupIDHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedAddAsyncSubGroup(%d, %d)", parentGroupIDHandle, groupIDHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedAddAsyncSubGroup(parentGroupIDHandle, groupIDHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // ManagedUpgradeFromSourceContract VM hook wrapper
 func (w *WrapperVMHooks) ManagedUpgradeFromSourceContract(destHandle int32, gas int64, valueHandle int32, addressHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultHandle int32) {
 	callInfo := fmt.Sprintf("ManagedUpgradeFromSourceContract(%d, %d, %d, %d, %d, %d, %d)", destHandle, gas, valueHandle, addressHandle, codeMetadataHandle, argumentsHandle, resultHandle)
@@ -888,6 +1135,33 @@ func (w *WrapperVMHooks) ManagedCreateContract(gas int64, valueHandle int32, cod
 	return result
 }
 
+// ManagedCreateDeterministicContract VM hook wrapper
+func (w *WrapperVMHooks) ManagedCreateDeterministicContract(gas int64, valueHandle int32, codeHandle int32, codeMetadataHandle int32, argumentsHandle int32, saltHandle int32, resultAddressHandle int32, resultHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedCreateDeterministicContract(%d, %d, %d, %d, %d, %d, %d, %d)", gas, valueHandle, codeHandle, codeMetadataHandle, argumentsHandle, saltHandle, resultAddressHandle, resultHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedCreateDeterministicContract(gas, valueHandle, codeHandle, codeMetadataHandle, argumentsHandle, saltHandle, resultAddressHandle, resultHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedComputeContractAddress VM hook wrapper
+func (w *WrapperVMHooks) ManagedComputeContractAddress(deployerHandle int32, nonce int64, resultHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedComputeContractAddress(%d, %d, %d)", deployerHandle, nonce, resultHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedComputeContractAddress(deployerHandle, nonce, resultHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
+// ManagedComputeDeterministicAddress VM hook wrapper
+func (w *WrapperVMHooks) ManagedComputeDeterministicAddress(saltHandle int32, codeHashHandle int32, resultHandle int32) int32 {
+	callInfo := fmt.Sprintf("ManagedComputeDeterministicAddress(%d, %d, %d)", saltHandle, codeHashHandle, resultHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.ManagedComputeDeterministicAddress(saltHandle, codeHashHandle, resultHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // ManagedExecuteReadOnly VM hook wrapper
 func (w *WrapperVMHooks) ManagedExecuteReadOnly(gas int64, addressHandle int32, functionHandle int32, argumentsHandle int32, resultHandle int32) int32 {
 	callInfo := fmt.Sprintf("ManagedExecuteReadOnly(%d, %d, %d, %d, %d)", gas, addressHandle, functionHandle, argumentsHandle, resultHandle)
@@ -1233,6 +1507,14 @@ func (w *WrapperVMHooks) BigIntNew(smallValue int64) int32 {
 	return result
 }
 
+// BigIntDrop VM hook wrapper
+func (w *WrapperVMHooks) BigIntDrop(referenceHandle int32) {
+	callInfo := fmt.Sprintf("BigIntDrop(%d)", referenceHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	w.wrappedVMHooks.BigIntDrop(referenceHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+}
+
 // BigIntUnsignedByteLength VM hook wrapper
 func (w *WrapperVMHooks) BigIntUnsignedByteLength(referenceHandle int32) int32 {
 	callInfo := fmt.Sprintf("BigIntUnsignedByteLength(%d)", referenceHandle)
@@ -1516,6 +1798,15 @@ func (w *WrapperVMHooks) MBufferNewFromBytes(dataOffset int32, dataLength int32)
 	return result
 }
 
+// MBufferDrop VM hook wrapper
+func (w *WrapperVMHooks) MBufferDrop(mBufferHandle int32) int32 {
+	callInfo := fmt.Sprintf("MBufferDrop(%d)", mBufferHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.MBufferDrop(mBufferHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // MBufferGetLength VM hook wrapper
 func (w *WrapperVMHooks) MBufferGetLength(mBufferHandle int32) int32 {
 	callInfo := fmt.Sprintf("MBufferGetLength(%d)", mBufferHandle)
@@ -1660,6 +1951,15 @@ func (w *WrapperVMHooks) MBufferStorageStore(keyHandle int32, sourceHandle int32
 	return result
 }
 
+// MBufferStorageDelete VM hook wrapper
+func (w *WrapperVMHooks) MBufferStorageDelete(keyHandle int32) int32 {
+	callInfo := fmt.Sprintf("MBufferStorageDelete(%d)", keyHandle)
+	w.logger.LogVMHookCallBefore(callInfo)
+	result := w.wrappedVMHooks.MBufferStorageDelete(keyHandle)
+	w.logger.LogVMHookCallAfter(callInfo)
+	return result
+}
+
 // MBufferStorageLoad VM hook wrapper
 func (w *WrapperVMHooks) MBufferStorageLoad(keyHandle int32, destinationHandle int32) int32 {
 	callInfo := fmt.Sprintf("MBufferStorageLoad(%d, %d)", keyHandle, destinationHandle)
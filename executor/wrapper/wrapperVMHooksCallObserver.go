@@ -0,0 +1,27 @@
+package executorwrapper
+
+import (
+	"time"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ executor.VMHookCallObserver = (*WrapperVMHooks)(nil)
+
+// VMHookCallsObserved forwards the question to the wrapped VMHooks, if they observe their calls.
+func (w *WrapperVMHooks) VMHookCallsObserved() bool {
+	callObserver, ok := w.wrappedVMHooks.(executor.VMHookCallObserver)
+	if !ok {
+		return false
+	}
+	return callObserver.VMHookCallsObserved()
+}
+
+// ObserveVMHookCall forwards the duration of the call to the wrapped VMHooks, if they observe their calls.
+func (w *WrapperVMHooks) ObserveVMHookCall(hookName string, duration time.Duration) {
+	callObserver, ok := w.wrappedVMHooks.(executor.VMHookCallObserver)
+	if !ok {
+		return
+	}
+	callObserver.ObserveVMHookCall(hookName, duration)
+}
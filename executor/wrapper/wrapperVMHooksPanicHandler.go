@@ -0,0 +1,17 @@
+package executorwrapper
+
+import (
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ executor.VMHookPanicHandler = (*WrapperVMHooks)(nil)
+
+// HandleVMHookPanic forwards the panic to the wrapped VMHooks, if they handle panics.
+func (w *WrapperVMHooks) HandleVMHookPanic(hookName string, panicValue interface{}) {
+	w.logger.LogExecutorEvent("VM hook panicked: " + hookName)
+	panicHandler, ok := w.wrappedVMHooks.(executor.VMHookPanicHandler)
+	if !ok {
+		panic(panicValue)
+	}
+	panicHandler.HandleVMHookPanic(hookName, panicValue)
+}
@@ -0,0 +1,140 @@
+package fees
+
+import (
+	"math/big"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// AccountsHandler is the part of the world state on which fee movements are applied
+type AccountsHandler interface {
+	UpdateBalanceWithDelta(address []byte, balanceDelta *big.Int) error
+	AddToDeveloperReward(address []byte, value *big.Int) error
+}
+
+// FeeAccountant applies the fee distribution of each transaction to the
+// world state and keeps the totals over all the applied transactions
+type FeeAccountant struct {
+	computer        *FeeComputer
+	accounts        AccountsHandler
+	proposerAddress []byte
+
+	totalFees             *big.Int
+	totalRefunded         *big.Int
+	totalProposerRewards  *big.Int
+	totalDeveloperRewards *big.Int
+	totalBurned           *big.Int
+}
+
+// NewFeeAccountant creates a new FeeAccountant, which rewards the given proposer
+func NewFeeAccountant(computer *FeeComputer, accounts AccountsHandler, proposerAddress []byte) (*FeeAccountant, error) {
+	if computer == nil {
+		return nil, ErrNilFeeComputer
+	}
+	if accounts == nil {
+		return nil, ErrNilAccountsHandler
+	}
+
+	return &FeeAccountant{
+		computer:              computer,
+		accounts:              accounts,
+		proposerAddress:       proposerAddress,
+		totalFees:             big.NewInt(0),
+		totalRefunded:         big.NewInt(0),
+		totalProposerRewards:  big.NewInt(0),
+		totalDeveloperRewards: big.NewInt(0),
+		totalBurned:           big.NewInt(0),
+	}, nil
+}
+
+// ApplyFees refunds the unused gas to the sender, rewards the proposer and the
+// developers of the executed contracts and accounts for the burned amount.
+// The sender is expected to have already paid GasProvided * GasPrice upfront.
+func (accountant *FeeAccountant) ApplyFees(vmInput *vmcommon.VMInput, vmOutput *vmcommon.VMOutput) (*FeeDistribution, error) {
+	return accountant.applyFees(vmInput.CallerAddr, vmInput, vmOutput)
+}
+
+// ApplyRelayedFees is like ApplyFees, but the unused gas is refunded to the
+// relayer, who paid for it upfront, instead of the caller of the inner call
+func (accountant *FeeAccountant) ApplyRelayedFees(relayerAddr []byte, vmInput *vmcommon.VMInput, vmOutput *vmcommon.VMOutput) (*FeeDistribution, error) {
+	return accountant.applyFees(relayerAddr, vmInput, vmOutput)
+}
+
+// ApplySponsoredFees is like ApplyFees, but the fee is charged to a sponsor
+// which did not pay for the gas upfront; nothing is refunded to the caller,
+// who pays no fee at all
+func (accountant *FeeAccountant) ApplySponsoredFees(sponsorAddr []byte, vmInput *vmcommon.VMInput, vmOutput *vmcommon.VMOutput) (*FeeDistribution, error) {
+	distribution := accountant.computer.ComputeDistribution(vmInput, vmOutput)
+	distribution.Refund = big.NewInt(0)
+	sponsorCharge := big.NewInt(0).Neg(distribution.TotalFee)
+	err := accountant.distribute(sponsorAddr, sponsorCharge, distribution)
+	if err != nil {
+		return nil, err
+	}
+	return distribution, nil
+}
+
+func (accountant *FeeAccountant) applyFees(payerAddr []byte, vmInput *vmcommon.VMInput, vmOutput *vmcommon.VMOutput) (*FeeDistribution, error) {
+	distribution := accountant.computer.ComputeDistribution(vmInput, vmOutput)
+	err := accountant.distribute(payerAddr, distribution.Refund, distribution)
+	if err != nil {
+		return nil, err
+	}
+	return distribution, nil
+}
+
+func (accountant *FeeAccountant) distribute(payerAddr []byte, payerDelta *big.Int, distribution *FeeDistribution) error {
+	if payerDelta.Sign() != 0 {
+		err := accountant.accounts.UpdateBalanceWithDelta(payerAddr, payerDelta)
+		if err != nil {
+			return err
+		}
+	}
+
+	if distribution.ProposerReward.Sign() > 0 {
+		err := accountant.accounts.UpdateBalanceWithDelta(accountant.proposerAddress, distribution.ProposerReward)
+		if err != nil {
+			return err
+		}
+	}
+
+	for address, reward := range distribution.DeveloperRewards {
+		err := accountant.accounts.AddToDeveloperReward([]byte(address), reward)
+		if err != nil {
+			return err
+		}
+	}
+
+	accountant.totalFees.Add(accountant.totalFees, distribution.TotalFee)
+	accountant.totalRefunded.Add(accountant.totalRefunded, distribution.Refund)
+	accountant.totalProposerRewards.Add(accountant.totalProposerRewards, distribution.ProposerReward)
+	accountant.totalDeveloperRewards.Add(accountant.totalDeveloperRewards, distribution.TotalDeveloperRewards)
+	accountant.totalBurned.Add(accountant.totalBurned, distribution.Burned)
+
+	return nil
+}
+
+// TotalFees returns the sum of the fees of all the applied transactions
+func (accountant *FeeAccountant) TotalFees() *big.Int {
+	return big.NewInt(0).Set(accountant.totalFees)
+}
+
+// TotalRefunded returns the sum of the refunds of unused gas
+func (accountant *FeeAccountant) TotalRefunded() *big.Int {
+	return big.NewInt(0).Set(accountant.totalRefunded)
+}
+
+// TotalProposerRewards returns the sum of the rewards given to the proposer
+func (accountant *FeeAccountant) TotalProposerRewards() *big.Int {
+	return big.NewInt(0).Set(accountant.totalProposerRewards)
+}
+
+// TotalDeveloperRewards returns the sum of the rewards given to developers
+func (accountant *FeeAccountant) TotalDeveloperRewards() *big.Int {
+	return big.NewInt(0).Set(accountant.totalDeveloperRewards)
+}
+
+// TotalBurned returns the sum of the burned fees
+func (accountant *FeeAccountant) TotalBurned() *big.Int {
+	return big.NewInt(0).Set(accountant.totalBurned)
+}
@@ -0,0 +1,139 @@
+package fees
+
+import (
+	"bytes"
+	"errors"
+	"math/big"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/math"
+)
+
+// ErrInvalidGasPriceModifier signals that the gas price modifier is not in the
+// (0, GasPriceModifierDenominator] interval
+var ErrInvalidGasPriceModifier = errors.New("invalid gas price modifier")
+
+// ErrInvalidPercentage signals that a fee percentage is over 100
+var ErrInvalidPercentage = errors.New("invalid fee percentage")
+
+// ErrNilFeeComputer signals that a nil FeeComputer was provided
+var ErrNilFeeComputer = errors.New("nil fee computer")
+
+// ErrNilAccountsHandler signals that a nil AccountsHandler was provided
+var ErrNilAccountsHandler = errors.New("nil accounts handler")
+
+// DefaultDeveloperPercentage is the part of the processing fee of a contract
+// which is given to its developer
+const DefaultDeveloperPercentage = 30
+
+// GasPriceModifierDenominator is the fixed-point scale of the gas price
+// modifier: a modifier equal to it keeps the full gas price
+const GasPriceModifierDenominator = 1_000_000
+
+// FeeConfig holds the parameters of the gas to fee conversion
+type FeeConfig struct {
+	// MinGasLimit is the gas of a transaction which is paid at the full gas
+	// price; the gas used above it is processing gas
+	MinGasLimit uint64
+	// GasPriceModifier scales the gas price for the processing gas, in
+	// fractions of GasPriceModifierDenominator
+	GasPriceModifier uint64
+	// DeveloperPercentage is the part of the processing fee of each contract
+	// given to its developer
+	DeveloperPercentage uint64
+	// BurnPercentage is the part of the fee left after the developer rewards
+	// which is burned instead of given to the block proposer
+	BurnPercentage uint64
+}
+
+// FeeDistribution describes how the gas paid for a transaction is split
+type FeeDistribution struct {
+	GasUsed               uint64
+	TotalFee              *big.Int
+	Refund                *big.Int
+	ProposerReward        *big.Int
+	Burned                *big.Int
+	DeveloperRewards      map[string]*big.Int
+	TotalDeveloperRewards *big.Int
+}
+
+// FeeComputer converts the gas consumption of a transaction into fees
+type FeeComputer struct {
+	config FeeConfig
+}
+
+// NewFeeComputer creates a new FeeComputer
+func NewFeeComputer(config FeeConfig) (*FeeComputer, error) {
+	if config.GasPriceModifier == 0 || config.GasPriceModifier > GasPriceModifierDenominator {
+		return nil, ErrInvalidGasPriceModifier
+	}
+	if config.DeveloperPercentage > 100 || config.BurnPercentage > 100 {
+		return nil, ErrInvalidPercentage
+	}
+
+	return &FeeComputer{
+		config: config,
+	}, nil
+}
+
+// ComputeFee returns the fee for the given amount of gas used
+func (computer *FeeComputer) ComputeFee(gasUsed uint64, gasPrice uint64) *big.Int {
+	fullPriceGas := gasUsed
+	if fullPriceGas > computer.config.MinGasLimit {
+		fullPriceGas = computer.config.MinGasLimit
+	}
+
+	fee := multiplyGas(fullPriceGas, gasPrice)
+	return fee.Add(fee, computer.computeProcessingFee(gasUsed-fullPriceGas, gasPrice))
+}
+
+// ComputeDistribution splits the gas paid upfront by the sender of the input
+// into the refund of the unused gas, the developer rewards of the contracts
+// which were executed, the burned amount and the reward of the block proposer
+func (computer *FeeComputer) ComputeDistribution(vmInput *vmcommon.VMInput, vmOutput *vmcommon.VMOutput) *FeeDistribution {
+	gasUsed := math.SubUint64(vmInput.GasProvided, vmOutput.GasRemaining)
+	upfrontPayment := multiplyGas(vmInput.GasProvided, vmInput.GasPrice)
+
+	distribution := &FeeDistribution{
+		GasUsed:               gasUsed,
+		TotalFee:              computer.ComputeFee(gasUsed, vmInput.GasPrice),
+		DeveloperRewards:      make(map[string]*big.Int),
+		TotalDeveloperRewards: big.NewInt(0),
+	}
+	distribution.Refund = big.NewInt(0).Sub(upfrontPayment, distribution.TotalFee)
+
+	for address, outputAccount := range vmOutput.OutputAccounts {
+		if outputAccount.GasUsed == 0 || bytes.Equal(outputAccount.Address, vmInput.CallerAddr) {
+			continue
+		}
+
+		reward := computer.computeProcessingFee(outputAccount.GasUsed, vmInput.GasPrice)
+		reward.Mul(reward, big.NewInt(0).SetUint64(computer.config.DeveloperPercentage))
+		reward.Div(reward, big.NewInt(100))
+		if reward.Sign() == 0 {
+			continue
+		}
+
+		distribution.DeveloperRewards[address] = reward
+		distribution.TotalDeveloperRewards.Add(distribution.TotalDeveloperRewards, reward)
+	}
+
+	remaining := big.NewInt(0).Sub(distribution.TotalFee, distribution.TotalDeveloperRewards)
+	distribution.Burned = big.NewInt(0).Mul(remaining, big.NewInt(0).SetUint64(computer.config.BurnPercentage))
+	distribution.Burned.Div(distribution.Burned, big.NewInt(100))
+	distribution.ProposerReward = remaining.Sub(remaining, distribution.Burned)
+
+	return distribution
+}
+
+// computeProcessingFee scales the gas price by the modifier first, rounding it
+// down, like the node does
+func (computer *FeeComputer) computeProcessingFee(gas uint64, gasPrice uint64) *big.Int {
+	processingGasPrice := big.NewInt(0).Mul(big.NewInt(0).SetUint64(gasPrice), big.NewInt(0).SetUint64(computer.config.GasPriceModifier))
+	processingGasPrice.Div(processingGasPrice, big.NewInt(GasPriceModifierDenominator))
+	return processingGasPrice.Mul(processingGasPrice, big.NewInt(0).SetUint64(gas))
+}
+
+func multiplyGas(gas uint64, gasPrice uint64) *big.Int {
+	return big.NewInt(0).Mul(big.NewInt(0).SetUint64(gas), big.NewInt(0).SetUint64(gasPrice))
+}
@@ -0,0 +1,102 @@
+package fees
+
+import (
+	"math/big"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+var senderAddress = []byte("sender__________________________")
+var proposerAddress = []byte("proposer________________________")
+var contractAddress = []byte("contract________________________")
+var ownerAddress = []byte("owner___________________________")
+
+func makeFeeConfig() FeeConfig {
+	return FeeConfig{
+		MinGasLimit:         1000,
+		GasPriceModifier:    GasPriceModifierDenominator / 2,
+		DeveloperPercentage: DefaultDeveloperPercentage,
+		BurnPercentage:      10,
+	}
+}
+
+func TestNewFeeComputer_InvalidConfig(t *testing.T) {
+	config := makeFeeConfig()
+	config.GasPriceModifier = 0
+	computer, err := NewFeeComputer(config)
+	require.Nil(t, computer)
+	require.Equal(t, ErrInvalidGasPriceModifier, err)
+
+	config.GasPriceModifier = GasPriceModifierDenominator + 1
+	computer, err = NewFeeComputer(config)
+	require.Nil(t, computer)
+	require.Equal(t, ErrInvalidGasPriceModifier, err)
+
+	config = makeFeeConfig()
+	config.BurnPercentage = 101
+	computer, err = NewFeeComputer(config)
+	require.Nil(t, computer)
+	require.Equal(t, ErrInvalidPercentage, err)
+}
+
+func TestFeeComputer_ComputeFee(t *testing.T) {
+	computer, _ := NewFeeComputer(makeFeeConfig())
+
+	require.Equal(t, big.NewInt(500*10), computer.ComputeFee(500, 10))
+	require.Equal(t, big.NewInt(1000*10+2000*10/2), computer.ComputeFee(3000, 10))
+	require.Equal(t, big.NewInt(1000*3+2000*1), computer.ComputeFee(3000, 3))
+}
+
+func TestFeeAccountant_ApplyFees(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	world.AcctMap.CreateAccount(senderAddress, world).Balance = big.NewInt(1_000_000)
+	world.AcctMap.CreateAccount(proposerAddress, world)
+	contract := world.AcctMap.CreateAccount(contractAddress, world)
+	contract.OwnerAddress = ownerAddress
+	contract.IsSmartContract = true
+
+	computer, _ := NewFeeComputer(makeFeeConfig())
+	accountant, err := NewFeeAccountant(computer, world, proposerAddress)
+	require.Nil(t, err)
+
+	vmInput := &vmcommon.VMInput{
+		CallerAddr:  senderAddress,
+		GasProvided: 10000,
+		GasPrice:    10,
+	}
+	err = world.UpdateWorldStateBefore(senderAddress, vmInput.GasProvided, vmInput.GasPrice)
+	require.Nil(t, err)
+
+	vmOutput := &vmcommon.VMOutput{
+		GasRemaining: 4000,
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			string(contractAddress): {
+				Address: contractAddress,
+				GasUsed: 4000,
+			},
+		},
+	}
+	distribution, err := accountant.ApplyFees(vmInput, vmOutput)
+	require.Nil(t, err)
+
+	// 1000 gas at full price, 5000 gas at half price
+	require.Equal(t, uint64(6000), distribution.GasUsed)
+	require.Equal(t, big.NewInt(35000), distribution.TotalFee)
+	require.Equal(t, big.NewInt(65000), distribution.Refund)
+	require.Equal(t, big.NewInt(6000), distribution.TotalDeveloperRewards)
+	require.Equal(t, big.NewInt(2900), distribution.Burned)
+	require.Equal(t, big.NewInt(26100), distribution.ProposerReward)
+
+	require.Equal(t, big.NewInt(1_000_000-35000), world.AcctMap.GetAccount(senderAddress).Balance)
+	require.Equal(t, big.NewInt(26100), world.AcctMap.GetAccount(proposerAddress).Balance)
+	require.Equal(t, big.NewInt(6000), world.AcctMap.GetAccount(contractAddress).DeveloperReward)
+
+	require.Equal(t, big.NewInt(35000), accountant.TotalFees())
+	require.Equal(t, big.NewInt(2900), accountant.TotalBurned())
+	burnedAndRewarded := big.NewInt(0).Add(accountant.TotalBurned(), accountant.TotalProposerRewards())
+	burnedAndRewarded.Add(burnedAndRewarded, accountant.TotalDeveloperRewards())
+	require.Equal(t, accountant.TotalFees(), burnedAndRewarded)
+}
@@ -320,4 +320,7 @@ func (pfe *fuzzDelegationExecutor) checkInvariants(t *testing.T) {
 
 	err = pfe.validateDelegationCapInvariant()
 	require.Nil(t, err)
+
+	err = pfe.arwenTestExecutor.CheckInvariants(pfe.generatedScenario.Steps)
+	require.Nil(t, err)
 }
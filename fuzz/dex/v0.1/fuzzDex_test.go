@@ -121,6 +121,8 @@ func TestFuzzDex_v0_1(t *testing.T) {
 		generateRandomEvent(t, pfe, r, &stats)
 		err := pfe.increaseBlockNonce(1)
 		require.Nil(t, err)
+		err = pfe.arwenTestExecutor.CheckInvariants(pfe.generatedScenario.Steps)
+		require.Nil(t, err)
 	}
 
 	printStatistics(&stats, pfe)
@@ -0,0 +1,85 @@
+package fuzzutil
+
+import (
+	"math/big"
+)
+
+// ArgumentKind is the shape of an argument generated for an endpoint call.
+type ArgumentKind uint8
+
+const (
+	// ArgumentBigUint is an unsigned integer in [0, Max], encoded big endian without leading zeros
+	ArgumentBigUint ArgumentKind = iota
+
+	// ArgumentBytes is a byte slice of at most MaxLength bytes
+	ArgumentBytes
+
+	// ArgumentChoice is one of the Choices, such as the addresses of the fuzzed accounts
+	ArgumentChoice
+)
+
+// ArgumentSpec describes how to generate an argument of an endpoint.
+type ArgumentSpec struct {
+	Kind      ArgumentKind
+	Max       *big.Int
+	MaxLength int
+	Choices   [][]byte
+}
+
+// EndpointSpec describes an endpoint which the fuzzer may call: the weight
+// of its selection, the shapes of its arguments and the largest payment.
+// A nil MaxPayment makes the endpoint non-payable.
+type EndpointSpec struct {
+	Name       string
+	Weight     int
+	Arguments  []ArgumentSpec
+	MaxPayment *big.Int
+}
+
+// EndpointCall is a call generated from the EndpointSpecs.
+type EndpointCall struct {
+	Endpoint  string
+	Arguments [][]byte
+	Payment   *big.Int
+}
+
+// EndpointCall selects one of the endpoints and generates its arguments and
+// its payment.
+func (u *Unstructured) EndpointCall(specs []EndpointSpec) *EndpointCall {
+	weights := make([]int, len(specs))
+	for i, spec := range specs {
+		weights[i] = spec.Weight
+	}
+	spec := specs[u.Choose(weights...)]
+
+	call := &EndpointCall{
+		Endpoint:  spec.Name,
+		Arguments: make([][]byte, len(spec.Arguments)),
+		Payment:   big.NewInt(0),
+	}
+	for i, argumentSpec := range spec.Arguments {
+		call.Arguments[i] = u.argument(argumentSpec)
+	}
+	if spec.MaxPayment != nil {
+		call.Payment = u.BigInt(spec.MaxPayment)
+	}
+	return call
+}
+
+func (u *Unstructured) argument(spec ArgumentSpec) []byte {
+	switch spec.Kind {
+	case ArgumentBytes:
+		return u.Bytes(spec.MaxLength)
+	case ArgumentChoice:
+		if len(spec.Choices) == 0 {
+			return nil
+		}
+		return spec.Choices[u.Intn(len(spec.Choices))]
+	}
+
+	max := spec.Max
+	if max == nil {
+		max = big.NewInt(0)
+	}
+	return u.BigInt(max).Bytes()
+}
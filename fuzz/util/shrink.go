@@ -0,0 +1,66 @@
+package fuzzutil
+
+import (
+	"io/ioutil"
+
+	mjwrite "github.com/ElrondNetwork/wasm-vm/mandos-go/json/write"
+	mj "github.com/ElrondNetwork/wasm-vm/mandos-go/model"
+)
+
+// Shrink minimizes a failing input of an Unstructured: it first removes
+// chunks of bytes, halving their size down to single bytes, and then lowers
+// the remaining bytes, keeping every change after which fails still returns
+// true. fails must be deterministic for the result to be reproducible.
+func Shrink(input []byte, fails func(input []byte) bool) []byte {
+	shrunk := append([]byte{}, input...)
+
+	for chunk := len(shrunk) / 2; chunk >= 1; chunk /= 2 {
+		for i := 0; i+chunk <= len(shrunk); {
+			candidate := append(append([]byte{}, shrunk[:i]...), shrunk[i+chunk:]...)
+			if fails(candidate) {
+				shrunk = candidate
+			} else {
+				i += chunk
+			}
+		}
+	}
+
+	for i := range shrunk {
+		for shrunk[i] > 0 {
+			candidate := append([]byte{}, shrunk...)
+			candidate[i] = 0
+			if !fails(candidate) {
+				candidate[i] = shrunk[i] / 2
+				if !fails(candidate) {
+					break
+				}
+			}
+			shrunk = candidate
+		}
+	}
+
+	return shrunk
+}
+
+// ShrinkToScenario shrinks a failing input of a fuzzer and saves the scenario
+// generated by the minimal input at the given path, to be replayed by the
+// scenario runner. run executes the fuzzer on an input and returns the
+// generated scenario, along with the failure, if any.
+func ShrinkToScenario(
+	input []byte,
+	run func(input []byte) (*mj.Scenario, error),
+	scenarioPath string,
+) ([]byte, error) {
+	shrunk := Shrink(input, func(candidate []byte) bool {
+		_, err := run(candidate)
+		return err != nil
+	})
+
+	scenario, _ := run(shrunk)
+	serialized := mjwrite.ScenarioToJSONString(scenario)
+	err := ioutil.WriteFile(scenarioPath, []byte(serialized), 0644)
+	if err != nil {
+		return nil, err
+	}
+	return shrunk, nil
+}
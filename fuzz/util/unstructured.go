@@ -0,0 +1,126 @@
+package fuzzutil
+
+import (
+	"math/big"
+	"math/rand"
+)
+
+// Unstructured derives structured fuzz inputs from a sequence of bytes, in
+// the manner of the arbitrary crate: every choice consumes the next bytes and
+// an exhausted input yields the smallest choices. A shorter input, or an input
+// with smaller bytes, therefore produces a simpler case, which allows Shrink
+// to minimize the failing cases.
+type Unstructured struct {
+	data   []byte
+	offset int
+}
+
+// NewUnstructured creates an Unstructured which consumes the given bytes.
+func NewUnstructured(data []byte) *Unstructured {
+	return &Unstructured{
+		data: data,
+	}
+}
+
+// RandomInput generates the given number of random bytes, to be consumed by
+// an Unstructured.
+func RandomInput(r *rand.Rand, length int) []byte {
+	input := make([]byte, length)
+	_, _ = r.Read(input)
+	return input
+}
+
+// NewWorkerRand creates the random source of a worker of a concurrent fuzzing
+// session. Each worker gets its own source, since rand.Rand is not safe for
+// concurrent use, and the sources only depend on the seed of the session and
+// on the index of the worker, so that every worker can be replayed.
+func NewWorkerRand(seed int64, worker int) *rand.Rand {
+	return rand.New(rand.NewSource(int64(splitMix64(uint64(seed) + uint64(worker)))))
+}
+
+// splitMix64 spreads consecutive seeds over unrelated random sequences
+func splitMix64(x uint64) uint64 {
+	x += 0x9e3779b97f4a7c15
+	x = (x ^ (x >> 30)) * 0xbf58476d1ce4e5b9
+	x = (x ^ (x >> 27)) * 0x94d049bb133111eb
+	return x ^ (x >> 31)
+}
+
+// IsEmpty returns true if all the bytes were consumed.
+func (u *Unstructured) IsEmpty() bool {
+	return u.offset >= len(u.data)
+}
+
+// Byte consumes one byte, or yields 0 if the input is exhausted.
+func (u *Unstructured) Byte() byte {
+	if u.IsEmpty() {
+		return 0
+	}
+	b := u.data[u.offset]
+	u.offset++
+	return b
+}
+
+// Bool consumes one byte and yields true if it is odd.
+func (u *Unstructured) Bool() bool {
+	return u.Byte()&1 == 1
+}
+
+// Intn yields an integer in [0, n), consuming only the bytes needed to cover
+// the range. It panics if n is not positive, like rand.Intn.
+func (u *Unstructured) Intn(n int) int {
+	if n <= 0 {
+		panic("invalid argument to Intn")
+	}
+
+	value := uint64(0)
+	for limit := uint64(n - 1); limit > 0; limit >>= 8 {
+		value = value<<8 | uint64(u.Byte())
+	}
+	return int(value % uint64(n))
+}
+
+// Bytes yields a slice of at most maxLength bytes.
+func (u *Unstructured) Bytes(maxLength int) []byte {
+	length := u.Intn(maxLength + 1)
+	result := make([]byte, length)
+	for i := range result {
+		result[i] = u.Byte()
+	}
+	return result
+}
+
+// BigInt yields an integer in [0, max], consuming only the bytes needed to
+// cover the range.
+func (u *Unstructured) BigInt(max *big.Int) *big.Int {
+	if max.Sign() <= 0 {
+		return big.NewInt(0)
+	}
+
+	limit := big.NewInt(0).Add(max, big.NewInt(1))
+	numBytes := (limit.BitLen() + 7) / 8
+	value := make([]byte, numBytes)
+	for i := range value {
+		value[i] = u.Byte()
+	}
+	return big.NewInt(0).Mod(big.NewInt(0).SetBytes(value), limit)
+}
+
+// Choose yields the index of one of the weights, with a probability
+// proportional to its weight; an exhausted input chooses the first one.
+func (u *Unstructured) Choose(weights ...int) int {
+	weightSum := 0
+	for _, weight := range weights {
+		weightSum += weight
+	}
+
+	randomNum := u.Intn(weightSum)
+	cumulative := 0
+	for i, weight := range weights {
+		cumulative += weight
+		if randomNum < cumulative {
+			return i
+		}
+	}
+	return len(weights) - 1
+}
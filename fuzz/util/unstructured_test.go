@@ -0,0 +1,67 @@
+package fuzzutil
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestUnstructured_ExhaustedInputYieldsSmallestChoices(t *testing.T) {
+	u := NewUnstructured([]byte{7, 1, 2})
+	require.Equal(t, 7, u.Intn(10))
+	require.Equal(t, 258, u.Intn(1000))
+	require.True(t, u.IsEmpty())
+
+	require.Equal(t, 0, u.Intn(10))
+	require.False(t, u.Bool())
+	require.Empty(t, u.Bytes(5))
+	require.Equal(t, big.NewInt(0), u.BigInt(big.NewInt(1000)))
+	require.Equal(t, 0, u.Choose(1, 2, 3))
+}
+
+func TestUnstructured_EndpointCall(t *testing.T) {
+	specs := []EndpointSpec{
+		{Name: "add", Weight: 1, Arguments: []ArgumentSpec{{Kind: ArgumentBigUint, Max: big.NewInt(100)}}},
+		{
+			Name:   "transfer",
+			Weight: 3,
+			Arguments: []ArgumentSpec{
+				{Kind: ArgumentChoice, Choices: [][]byte{[]byte("alice"), []byte("bob")}},
+				{Kind: ArgumentBytes, MaxLength: 4},
+			},
+			MaxPayment: big.NewInt(1000),
+		},
+	}
+
+	call := NewUnstructured(nil).EndpointCall(specs)
+	require.Equal(t, "add", call.Endpoint)
+	require.Equal(t, [][]byte{{}}, call.Arguments)
+	require.Equal(t, big.NewInt(0), call.Payment)
+
+	call = NewUnstructured([]byte{2, 1, 2, 0xaa, 0xbb, 0x01, 0x02}).EndpointCall(specs)
+	require.Equal(t, "transfer", call.Endpoint)
+	require.Equal(t, [][]byte{[]byte("bob"), {0xaa, 0xbb}}, call.Arguments)
+	require.Equal(t, big.NewInt(258), call.Payment)
+}
+
+func TestShrink(t *testing.T) {
+	// fails whenever the input contains a byte of at least 10 followed by a 3
+	fails := func(input []byte) bool {
+		for i := 0; i+1 < len(input); i++ {
+			if input[i] >= 10 && input[i+1] == 3 {
+				return true
+			}
+		}
+		return false
+	}
+
+	input := []byte{1, 2, 200, 3, 4, 5, 6, 7, 8, 9}
+	shrunk := Shrink(input, fails)
+	require.Equal(t, []byte{12, 3}, shrunk)
+}
+
+func TestNewWorkerRand_Deterministic(t *testing.T) {
+	require.Equal(t, NewWorkerRand(42, 3).Int63(), NewWorkerRand(42, 3).Int63())
+	require.NotEqual(t, NewWorkerRand(42, 3).Int63(), NewWorkerRand(42, 4).Int63())
+}
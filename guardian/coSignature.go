@@ -0,0 +1,62 @@
+package guardian
+
+import (
+	"bytes"
+	"errors"
+)
+
+// TxOptionGuarded is the transaction option bit which flags a transaction as
+// co-signed by the guardian of its sender
+const TxOptionGuarded uint32 = 1 << 1
+
+// ErrMissingGuardianCoSignature signals that a guarded account sent a transaction not co-signed by its guardian
+var ErrMissingGuardianCoSignature = errors.New("transaction from guarded account is not co-signed by the guardian")
+
+// ErrGuardianMismatch signals that a transaction was co-signed by an address which is not the active guardian
+var ErrGuardianMismatch = errors.New("transaction co-signed by an address which is not the active guardian")
+
+// ErrGuardedOptionOnUnguardedAccount signals that the guarded option was set for an account which is not guarded
+var ErrGuardedOptionOnUnguardedAccount = errors.New("guarded transaction option set for an unguarded account")
+
+// GuardedAccountsHandler provides the guardian state of the accounts
+type GuardedAccountsHandler interface {
+	IsGuardedAccount(address []byte) bool
+	GetActiveGuardian(address []byte) ([]byte, error)
+}
+
+// CoSignature holds the guardian related fields of a transaction
+type CoSignature struct {
+	Options           uint32
+	GuardianAddr      []byte
+	GuardianSignature []byte
+}
+
+// IsGuarded returns whether the transaction is flagged as co-signed
+func (coSignature *CoSignature) IsGuarded() bool {
+	return coSignature != nil && coSignature.Options&TxOptionGuarded != 0
+}
+
+// CheckCoSignature verifies that the transactions of guarded accounts are
+// co-signed by their active guardian, and that only those are flagged as such
+func CheckCoSignature(accounts GuardedAccountsHandler, sender []byte, coSignature *CoSignature) error {
+	if !accounts.IsGuardedAccount(sender) {
+		if coSignature.IsGuarded() {
+			return ErrGuardedOptionOnUnguardedAccount
+		}
+		return nil
+	}
+
+	if !coSignature.IsGuarded() || len(coSignature.GuardianSignature) == 0 {
+		return ErrMissingGuardianCoSignature
+	}
+
+	activeGuardian, err := accounts.GetActiveGuardian(sender)
+	if err != nil {
+		return err
+	}
+	if !bytes.Equal(activeGuardian, coSignature.GuardianAddr) {
+		return ErrGuardianMismatch
+	}
+
+	return nil
+}
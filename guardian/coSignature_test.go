@@ -0,0 +1,75 @@
+package guardian
+
+import (
+	"math/big"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/config"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+var userAddress = []byte("user____________________________")
+var guardianAddress = []byte("guardian________________________")
+
+func makeGuardianCall(function string, arguments ...[]byte) *vmcommon.ContractCallInput {
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:  userAddress,
+			Arguments:   arguments,
+			CallValue:   big.NewInt(0),
+			GasProvided: 1000,
+		},
+		RecipientAddr: userAddress,
+		Function:      function,
+	}
+}
+
+func TestGuardian_GuardAndCheckCoSignature(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	require.Nil(t, world.InitBuiltinFunctions(config.MakeGasMapForTests()))
+	world.AcctMap.CreateAccount(userAddress, world)
+
+	_, err := world.ProcessBuiltInFunction(makeGuardianCall(worldmock.GuardAccountFunctionName))
+	require.Equal(t, worldmock.ErrNoActiveGuardian, err)
+
+	_, err = world.ProcessBuiltInFunction(makeGuardianCall(worldmock.SetGuardianFunctionName, userAddress, []byte("uid")))
+	require.Equal(t, worldmock.ErrGuardianIsAccount, err)
+
+	vmOutput, err := world.ProcessBuiltInFunction(makeGuardianCall(worldmock.SetGuardianFunctionName, guardianAddress, []byte("uid")))
+	require.Nil(t, err)
+	require.Equal(t, vmcommon.Ok, vmOutput.ReturnCode)
+	require.Equal(t, guardianAddress, world.AcctMap.GetAccount(userAddress).PendingGuardian)
+
+	_, err = world.GetActiveGuardian(userAddress)
+	require.Equal(t, worldmock.ErrNoActiveGuardian, err)
+
+	world.SetCurrentBlockInfo(1, 1, worldmock.DefaultGuardianActivationEpochs, 0, nil)
+	activeGuardian, err := world.GetActiveGuardian(userAddress)
+	require.Nil(t, err)
+	require.Equal(t, guardianAddress, activeGuardian)
+
+	require.Nil(t, CheckCoSignature(world, userAddress, nil))
+	require.Equal(t, ErrGuardedOptionOnUnguardedAccount, CheckCoSignature(world, userAddress, &CoSignature{Options: TxOptionGuarded}))
+
+	_, err = world.ProcessBuiltInFunction(makeGuardianCall(worldmock.GuardAccountFunctionName))
+	require.Nil(t, err)
+	require.True(t, world.IsGuardedAccount(userAddress))
+
+	require.Equal(t, ErrMissingGuardianCoSignature, CheckCoSignature(world, userAddress, nil))
+	require.Equal(t, ErrGuardianMismatch, CheckCoSignature(world, userAddress, &CoSignature{
+		Options:           TxOptionGuarded,
+		GuardianAddr:      []byte("other___________________________"),
+		GuardianSignature: []byte("signature"),
+	}))
+	require.Nil(t, CheckCoSignature(world, userAddress, &CoSignature{
+		Options:           TxOptionGuarded,
+		GuardianAddr:      guardianAddress,
+		GuardianSignature: []byte("signature"),
+	}))
+
+	_, err = world.ProcessBuiltInFunction(makeGuardianCall(worldmock.UnGuardAccountFunctionName))
+	require.Nil(t, err)
+	require.False(t, world.IsGuardedAccount(userAddress))
+}
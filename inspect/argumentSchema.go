@@ -0,0 +1,173 @@
+package inspect
+
+import (
+	"errors"
+	"fmt"
+	"math"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrMalformedArgumentSchema signals an argument schema section which cannot be decoded
+var ErrMalformedArgumentSchema = errors.New("malformed argschema section")
+
+// ErrArgumentsMismatchSchema signals arguments which the argument schema of the endpoint does not allow
+var ErrArgumentsMismatchSchema = errors.New("arguments do not match the argument schema")
+
+// ArgumentSchemaSectionName is the name of the custom section in which a
+// contract declares the arguments accepted by its endpoints; it holds a vector
+// of entries, each made of the name of an endpoint, the minimum and maximum
+// numbers of arguments, and a vector of the maximum sizes of the arguments
+const ArgumentSchemaSectionName = "argschema"
+
+// UnboundedArguments is the maximum number of arguments of the endpoints
+// which accept any number of arguments
+const UnboundedArguments = math.MaxUint32
+
+// UnboundedArgumentSize is the maximum size of the arguments which may be of
+// any size
+const UnboundedArgumentSize = 0
+
+// ArgumentSchema is the arguments accepted by an endpoint: their number, and
+// the maximum size of each argument by position; the arguments past the
+// sizes declared may be of any size
+type ArgumentSchema struct {
+	MinArguments     uint32
+	MaxArguments     uint32
+	MaxArgumentSizes []uint32
+}
+
+// Validate checks the arguments of a call of the endpoint against the schema
+func (schema ArgumentSchema) Validate(arguments [][]byte) error {
+	numArguments := uint64(len(arguments))
+	if numArguments < uint64(schema.MinArguments) || numArguments > uint64(schema.MaxArguments) {
+		return fmt.Errorf("%w: %d arguments, expected between %d and %d",
+			ErrArgumentsMismatchSchema, numArguments, schema.MinArguments, schema.MaxArguments)
+	}
+
+	for i, argument := range arguments {
+		if i >= len(schema.MaxArgumentSizes) {
+			break
+		}
+		maxSize := schema.MaxArgumentSizes[i]
+		if maxSize != UnboundedArgumentSize && uint64(len(argument)) > uint64(maxSize) {
+			return fmt.Errorf("%w: argument %d has %d bytes, expected at most %d",
+				ErrArgumentsMismatchSchema, i, len(argument), maxSize)
+		}
+	}
+
+	return nil
+}
+
+// ReadArgumentSchemas decodes the argument schema section of a contract
+// binary, skipping over all the other sections; the result is nil if the
+// contract declares no argument schema section, in which case the arguments
+// of its endpoints are only checked by the endpoints themselves
+func ReadArgumentSchemas(code []byte) (map[string]ArgumentSchema, error) {
+	reader := wasmreader.NewReader(code)
+	err := (&moduleInspector{}).readHeader(reader)
+	if err != nil {
+		return nil, err
+	}
+
+	var schemas map[string]ArgumentSchema
+	for reader.HasMore() {
+		sectionID, err := reader.ReadByte()
+		if err != nil {
+			return nil, err
+		}
+		size, err := reader.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		content, err := reader.ReadBytes(size)
+		if err != nil {
+			return nil, err
+		}
+		if sectionID != sectionCustom {
+			continue
+		}
+
+		sectionReader := wasmreader.NewReader(content)
+		name, err := sectionReader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: custom section: %v", ErrMalformedSection, err)
+		}
+		if name != ArgumentSchemaSectionName {
+			continue
+		}
+		if schemas != nil {
+			return nil, fmt.Errorf("%w: duplicated section", ErrMalformedArgumentSchema)
+		}
+		schemas, err = readArgumentSchemaEntries(sectionReader)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return schemas, nil
+}
+
+func readArgumentSchemaEntries(reader *wasmreader.Reader) (map[string]ArgumentSchema, error) {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrMalformedArgumentSchema, err)
+	}
+
+	schemas := make(map[string]ArgumentSchema)
+	for i := uint32(0); i < count; i++ {
+		name, schema, err := readArgumentSchemaEntry(reader)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformedArgumentSchema, err)
+		}
+		if schema.MinArguments > schema.MaxArguments {
+			return nil, fmt.Errorf("%w: minimum above maximum for %s", ErrMalformedArgumentSchema, name)
+		}
+		if uint64(len(schema.MaxArgumentSizes)) > uint64(schema.MaxArguments) {
+			return nil, fmt.Errorf("%w: more sizes than arguments for %s", ErrMalformedArgumentSchema, name)
+		}
+		_, duplicated := schemas[name]
+		if duplicated {
+			return nil, fmt.Errorf("%w: duplicated endpoint %s", ErrMalformedArgumentSchema, name)
+		}
+		schemas[name] = schema
+	}
+	if reader.HasMore() {
+		return nil, fmt.Errorf("%w: trailing bytes", ErrMalformedArgumentSchema)
+	}
+
+	return schemas, nil
+}
+
+func readArgumentSchemaEntry(reader *wasmreader.Reader) (string, ArgumentSchema, error) {
+	schema := ArgumentSchema{}
+	name, err := reader.ReadName()
+	if err != nil {
+		return "", schema, err
+	}
+	schema.MinArguments, err = reader.ReadU32()
+	if err != nil {
+		return "", schema, err
+	}
+	schema.MaxArguments, err = reader.ReadU32()
+	if err != nil {
+		return "", schema, err
+	}
+
+	numSizes, err := reader.ReadU32()
+	if err != nil {
+		return "", schema, err
+	}
+	if uint64(numSizes) > uint64(reader.Remaining()) {
+		return "", schema, fmt.Errorf("%d sizes for %s exceed the section", numSizes, name)
+	}
+	schema.MaxArgumentSizes = make([]uint32, numSizes)
+	for i := range schema.MaxArgumentSizes {
+		schema.MaxArgumentSizes[i], err = reader.ReadU32()
+		if err != nil {
+			return "", schema, err
+		}
+	}
+
+	return name, schema, nil
+}
@@ -0,0 +1,83 @@
+package inspect
+
+import (
+	"bytes"
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+var argumentSchemaEntries = []byte{
+	0x02,
+	// transfer: 2 arguments, an address of at most 32 bytes and an amount of any size
+	0x08, 't', 'r', 'a', 'n', 's', 'f', 'e', 'r', 0x02, 0x02, 0x02, 0x20, 0x00,
+	// batch: at least 1 argument, any number
+	0x05, 'b', 'a', 't', 'c', 'h', 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00,
+}
+
+func TestReadArgumentSchemas(t *testing.T) {
+	code := makeModule(
+		makeCustomSection("name", []byte{0x01, 0x02}),
+		makeCustomSection(ArgumentSchemaSectionName, argumentSchemaEntries),
+	)
+
+	schemas, err := ReadArgumentSchemas(code)
+	require.Nil(t, err)
+	require.Equal(t, map[string]ArgumentSchema{
+		"transfer": {MinArguments: 2, MaxArguments: 2, MaxArgumentSizes: []uint32{32, UnboundedArgumentSize}},
+		"batch":    {MinArguments: 1, MaxArguments: UnboundedArguments, MaxArgumentSizes: []uint32{}},
+	}, schemas)
+
+	report, err := Inspect(code, nil)
+	require.Nil(t, err)
+	require.Equal(t, schemas, report.EndpointArguments)
+
+	schemas, err = ReadArgumentSchemas(makeModule(makeCustomSection("name", []byte{0x01})))
+	require.Nil(t, err)
+	require.Nil(t, schemas)
+}
+
+func TestReadArgumentSchemas_MalformedSection(t *testing.T) {
+	section := makeCustomSection(ArgumentSchemaSectionName, argumentSchemaEntries)
+	_, err := ReadArgumentSchemas(makeModule(section, section))
+	require.True(t, errors.Is(err, ErrMalformedArgumentSchema))
+
+	for _, entries := range [][]byte{
+		// minimum above maximum
+		{0x01, 0x01, 'f', 0x02, 0x01, 0x00},
+		// more sizes than arguments
+		{0x01, 0x01, 'f', 0x00, 0x01, 0x02, 0x01, 0x01},
+		// duplicated endpoint
+		{0x02, 0x01, 'f', 0x00, 0x00, 0x00, 0x01, 'f', 0x00, 0x00, 0x00},
+		// truncated sizes
+		{0x01, 0x01, 'f', 0x00, 0x05, 0x05},
+		// trailing bytes
+		{0x01, 0x01, 'f', 0x00, 0x00, 0x00, 0x00},
+	} {
+		_, err = ReadArgumentSchemas(makeModule(makeCustomSection(ArgumentSchemaSectionName, entries)))
+		require.True(t, errors.Is(err, ErrMalformedArgumentSchema), "entries %x", entries)
+
+		_, err = Inspect(makeModule(makeCustomSection(ArgumentSchemaSectionName, entries)), nil)
+		require.True(t, errors.Is(err, ErrMalformedSection), "entries %x", entries)
+	}
+}
+
+func TestArgumentSchema_Validate(t *testing.T) {
+	schema := ArgumentSchema{MinArguments: 1, MaxArguments: 3, MaxArgumentSizes: []uint32{4, UnboundedArgumentSize}}
+
+	require.Nil(t, schema.Validate([][]byte{{1, 2, 3, 4}}))
+	require.Nil(t, schema.Validate([][]byte{{}, bytes.Repeat([]byte{1}, 100), bytes.Repeat([]byte{1}, 100)}))
+
+	for _, arguments := range [][][]byte{
+		{},
+		{{1}, {2}, {3}, {4}},
+		{{1, 2, 3, 4, 5}},
+	} {
+		err := schema.Validate(arguments)
+		require.True(t, errors.Is(err, ErrArgumentsMismatchSchema))
+	}
+
+	unbounded := ArgumentSchema{MaxArguments: UnboundedArguments}
+	require.Nil(t, unbounded.Validate(make([][]byte, 1000)))
+}
@@ -0,0 +1,120 @@
+package inspect
+
+import (
+	"errors"
+	"fmt"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrMalformedBuildInfo signals a build info section which cannot be decoded
+var ErrMalformedBuildInfo = errors.New("malformed buildinfo section")
+
+// BuildInfoSectionName is the name of the custom section in which a contract
+// records how it was built, as written by the build_info! macro of the Rust
+// test contracts; it holds a vector of key and value name pairs
+const BuildInfoSectionName = "buildinfo"
+
+const (
+	buildInfoFrameworkKey = "framework"
+	buildInfoRustcKey     = "rustc"
+	buildInfoFlagsKey     = "flags"
+)
+
+// BuildInfo is the provenance of a contract binary: the version of the
+// contract framework, the version of the compiler and the flags of the build
+type BuildInfo struct {
+	Framework string
+	Rustc     string
+	Flags     string
+}
+
+// ReadBuildInfo decodes the build info section of a contract binary,
+// skipping over all the other sections; the result is nil if the contract
+// declares no build info section
+func ReadBuildInfo(code []byte) (*BuildInfo, error) {
+	reader := wasmreader.NewReader(code)
+	err := (&moduleInspector{}).readHeader(reader)
+	if err != nil {
+		return nil, err
+	}
+
+	var buildInfo *BuildInfo
+	for reader.HasMore() {
+		sectionID, err := reader.ReadByte()
+		if err != nil {
+			return nil, err
+		}
+		size, err := reader.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		content, err := reader.ReadBytes(size)
+		if err != nil {
+			return nil, err
+		}
+		if sectionID != sectionCustom {
+			continue
+		}
+
+		sectionReader := wasmreader.NewReader(content)
+		name, err := sectionReader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: custom section: %v", ErrMalformedSection, err)
+		}
+		if name != BuildInfoSectionName {
+			continue
+		}
+		if buildInfo != nil {
+			return nil, fmt.Errorf("%w: duplicated section", ErrMalformedBuildInfo)
+		}
+		buildInfo, err = readBuildInfoEntries(sectionReader)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return buildInfo, nil
+}
+
+// readBuildInfoEntries decodes the key and value pairs of the section; the
+// keys unknown to this version of the VM are skipped, so that the frameworks
+// may record more about their builds
+func readBuildInfoEntries(reader *wasmreader.Reader) (*BuildInfo, error) {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrMalformedBuildInfo, err)
+	}
+
+	buildInfo := &BuildInfo{}
+	fields := map[string]*string{
+		buildInfoFrameworkKey: &buildInfo.Framework,
+		buildInfoRustcKey:     &buildInfo.Rustc,
+		buildInfoFlagsKey:     &buildInfo.Flags,
+	}
+	seen := make(map[string]bool)
+	for i := uint32(0); i < count; i++ {
+		key, err := reader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformedBuildInfo, err)
+		}
+		value, err := reader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformedBuildInfo, err)
+		}
+		if seen[key] {
+			return nil, fmt.Errorf("%w: duplicated key %s", ErrMalformedBuildInfo, key)
+		}
+		seen[key] = true
+
+		field, ok := fields[key]
+		if ok {
+			*field = value
+		}
+	}
+	if reader.HasMore() {
+		return nil, fmt.Errorf("%w: trailing bytes", ErrMalformedBuildInfo)
+	}
+
+	return buildInfo, nil
+}
@@ -0,0 +1,60 @@
+package inspect
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+var buildInfoEntries = []byte{
+	0x04,
+	0x09, 'f', 'r', 'a', 'm', 'e', 'w', 'o', 'r', 'k', 0x06, 'e', 'w', ' ', '0', '.', '1',
+	0x05, 'r', 'u', 's', 't', 'c', 0x04, '1', '.', '5', '2',
+	0x06, 'l', 'i', 'n', 'k', 'e', 'r', 0x03, 'l', 'l', 'd',
+	0x05, 'f', 'l', 'a', 'g', 's', 0x07, 'r', 'e', 'l', 'e', 'a', 's', 'e',
+}
+
+func TestReadBuildInfo(t *testing.T) {
+	code := makeModule(makeCustomSection("name", []byte{0x00}), makeCustomSection(BuildInfoSectionName, buildInfoEntries))
+	buildInfo, err := ReadBuildInfo(code)
+	require.Nil(t, err)
+	require.Equal(t, &BuildInfo{Framework: "ew 0.1", Rustc: "1.52", Flags: "release"}, buildInfo)
+
+	buildInfo, err = ReadBuildInfo(makeModule(makeCustomSection(BuildInfoSectionName, []byte{0x00})))
+	require.Nil(t, err)
+	require.Equal(t, &BuildInfo{}, buildInfo)
+
+	buildInfo, err = ReadBuildInfo(makeModule(makeCustomSection("name", []byte{0x00})))
+	require.Nil(t, err)
+	require.Nil(t, buildInfo)
+}
+
+func TestReadBuildInfo_MalformedSection(t *testing.T) {
+	malformedSections := map[string][]byte{
+		"duplicated key": {0x02, 0x01, 'k', 0x00, 0x01, 'k', 0x00},
+		"missing value":  {0x01, 0x01, 'k'},
+		"trailing bytes": {0x01, 0x01, 'k', 0x00, 0x00},
+	}
+	for description, entries := range malformedSections {
+		_, err := ReadBuildInfo(makeModule(makeCustomSection(BuildInfoSectionName, entries)))
+		require.True(t, errors.Is(err, ErrMalformedBuildInfo), description)
+	}
+
+	section := makeCustomSection(BuildInfoSectionName, []byte{0x00})
+	_, err := ReadBuildInfo(makeModule(section, section))
+	require.True(t, errors.Is(err, ErrMalformedBuildInfo))
+}
+
+func TestInspect_BuildInfo(t *testing.T) {
+	report, err := Inspect(makeModule(makeCustomSection(BuildInfoSectionName, buildInfoEntries)), nil)
+	require.Nil(t, err)
+	require.Equal(t, &BuildInfo{Framework: "ew 0.1", Rustc: "1.52", Flags: "release"}, report.BuildInfo)
+
+	report, err = Inspect(makeModule(makeCustomSection("name", []byte{0x01})), nil)
+	require.Nil(t, err)
+	require.Nil(t, report.BuildInfo)
+
+	_, err = Inspect(makeModule(makeCustomSection(BuildInfoSectionName, []byte{0x01, 0x01, 'k'})), nil)
+	require.True(t, errors.Is(err, ErrMalformedSection))
+}
@@ -0,0 +1,148 @@
+package inspect
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrMalformedCallability signals a callability section which cannot be decoded
+var ErrMalformedCallability = errors.New("malformed callability section")
+
+// CallabilitySectionName is the name of the custom section in which a contract
+// declares the callers allowed to call each of its endpoints; it holds a
+// vector of entries, each made of the name of an endpoint and a Callability byte
+const CallabilitySectionName = "callability"
+
+// Callability is the set of callers allowed to call an endpoint directly. An
+// endpoint which allows no callers can only run as a callback.
+type Callability uint8
+
+const (
+	// CallableByUser allows the calls from user accounts
+	CallableByUser Callability = 1 << iota
+
+	// CallableBySC allows the calls from smart contracts, both synchronous and asynchronous
+	CallableBySC
+
+	// CallableByOwner allows the calls from the owner of the contract
+	CallableByOwner
+)
+
+// CallbackOnly is the Callability of the endpoints which can only run as callbacks
+const CallbackOnly Callability = 0
+
+const allCallers = CallableByUser | CallableBySC | CallableByOwner
+
+var callabilityNames = []struct {
+	callability Callability
+	name        string
+}{
+	{CallableByUser, "user"},
+	{CallableBySC, "sc"},
+	{CallableByOwner, "owner"},
+}
+
+// Has returns true if the set contains all the given callers
+func (callability Callability) Has(other Callability) bool {
+	return callability&other == other
+}
+
+// String returns the names of the callers in the set
+func (callability Callability) String() string {
+	names := make([]string, 0, len(callabilityNames))
+	for _, callabilityName := range callabilityNames {
+		if callability.Has(callabilityName.callability) {
+			names = append(names, callabilityName.name)
+		}
+	}
+	if len(names) == 0 {
+		return "callback"
+	}
+	return strings.Join(names, ",")
+}
+
+// ReadCallability decodes the callability section of a contract binary,
+// skipping over all the other sections; the result is nil if the contract
+// declares no callability section, in which case all its endpoints may be
+// called by anyone
+func ReadCallability(code []byte) (map[string]Callability, error) {
+	reader := wasmreader.NewReader(code)
+	err := (&moduleInspector{}).readHeader(reader)
+	if err != nil {
+		return nil, err
+	}
+
+	var rules map[string]Callability
+	for reader.HasMore() {
+		sectionID, err := reader.ReadByte()
+		if err != nil {
+			return nil, err
+		}
+		size, err := reader.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		content, err := reader.ReadBytes(size)
+		if err != nil {
+			return nil, err
+		}
+		if sectionID != sectionCustom {
+			continue
+		}
+
+		sectionReader := wasmreader.NewReader(content)
+		name, err := sectionReader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: custom section: %v", ErrMalformedSection, err)
+		}
+		if name != CallabilitySectionName {
+			continue
+		}
+		if rules != nil {
+			return nil, fmt.Errorf("%w: duplicated section", ErrMalformedCallability)
+		}
+		rules, err = readCallabilityEntries(sectionReader)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return rules, nil
+}
+
+func readCallabilityEntries(reader *wasmreader.Reader) (map[string]Callability, error) {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrMalformedCallability, err)
+	}
+
+	rules := make(map[string]Callability)
+	for i := uint32(0); i < count; i++ {
+		name, err := reader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformedCallability, err)
+		}
+		value, err := reader.ReadByte()
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformedCallability, err)
+		}
+
+		callability := Callability(value)
+		if callability&^allCallers != 0 {
+			return nil, fmt.Errorf("%w: unknown callers 0x%02x for %s", ErrMalformedCallability, value, name)
+		}
+		_, duplicated := rules[name]
+		if duplicated {
+			return nil, fmt.Errorf("%w: duplicated endpoint %s", ErrMalformedCallability, name)
+		}
+		rules[name] = callability
+	}
+	if reader.HasMore() {
+		return nil, fmt.Errorf("%w: trailing bytes", ErrMalformedCallability)
+	}
+
+	return rules, nil
+}
@@ -0,0 +1,79 @@
+package inspect
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func makeCustomSection(name string, content []byte) []byte {
+	payload := append([]byte{byte(len(name))}, name...)
+	payload = append(payload, content...)
+	return append([]byte{sectionCustom, byte(len(payload))}, payload...)
+}
+
+func makeModule(sections ...[]byte) []byte {
+	module := append([]byte{}, wasmMagic...)
+	module = append(module, wasmVersion...)
+	for _, section := range sections {
+		module = append(module, section...)
+	}
+	return module
+}
+
+var callabilityEntries = []byte{
+	0x02,
+	0x10, 's', 'u', 'c', 'c', 'e', 's', 's', '_', 'c', 'a', 'l', 'l', 'b', 'a', 'c', 'k', 0x00,
+	0x05, 'c', 'l', 'a', 'i', 'm', byte(CallableByUser | CallableByOwner),
+}
+
+func TestReadCallability(t *testing.T) {
+	code := makeModule(
+		// type section: () -> ()
+		[]byte{0x01, 0x04, 0x01, 0x60, 0x00, 0x00},
+		makeCustomSection("name", []byte{0x01, 0x02}),
+		makeCustomSection(CallabilitySectionName, callabilityEntries),
+	)
+
+	rules, err := ReadCallability(code)
+	require.Nil(t, err)
+	require.Equal(t, map[string]Callability{
+		"success_callback": CallbackOnly,
+		"claim":            CallableByUser | CallableByOwner,
+	}, rules)
+	require.Equal(t, "callback", rules["success_callback"].String())
+	require.Equal(t, "user,owner", rules["claim"].String())
+
+	report, err := Inspect(code, nil)
+	require.Nil(t, err)
+	require.Equal(t, rules, report.EndpointCallability)
+
+	rules, err = ReadCallability(makeModule(makeCustomSection("name", []byte{0x01})))
+	require.Nil(t, err)
+	require.Nil(t, rules)
+}
+
+func TestReadCallability_MalformedSection(t *testing.T) {
+	_, err := ReadCallability([]byte("not wasm"))
+	require.Equal(t, ErrInvalidMagic, err)
+
+	section := makeCustomSection(CallabilitySectionName, callabilityEntries)
+	_, err = ReadCallability(makeModule(section, section))
+	require.True(t, errors.Is(err, ErrMalformedCallability))
+
+	unknownCallers := []byte{0x01, 0x01, 'f', 0x08}
+	_, err = ReadCallability(makeModule(makeCustomSection(CallabilitySectionName, unknownCallers)))
+	require.True(t, errors.Is(err, ErrMalformedCallability))
+
+	duplicatedEndpoint := []byte{0x02, 0x01, 'f', 0x01, 0x01, 'f', 0x02}
+	_, err = ReadCallability(makeModule(makeCustomSection(CallabilitySectionName, duplicatedEndpoint)))
+	require.True(t, errors.Is(err, ErrMalformedCallability))
+
+	truncated := []byte{0x02, 0x01, 'f', 0x01}
+	_, err = ReadCallability(makeModule(makeCustomSection(CallabilitySectionName, truncated)))
+	require.True(t, errors.Is(err, ErrMalformedCallability))
+
+	_, err = Inspect(makeModule(makeCustomSection(CallabilitySectionName, truncated)), nil)
+	require.True(t, errors.Is(err, ErrMalformedSection))
+}
@@ -0,0 +1,143 @@
+package inspect
+
+import "fmt"
+
+// DeterminismHazardKind is a construct of a contract binary which may let
+// the nodes of the network compute different results for the same transaction
+type DeterminismHazardKind uint8
+
+const (
+	// HazardClockImport is the import of a WASI function reading a clock
+	HazardClockImport DeterminismHazardKind = iota
+
+	// HazardRandomImport is the import of a WASI function reading random bytes
+	HazardRandomImport
+
+	// HazardMutableExportedGlobal is a mutable global exported by the
+	// contract, which the embedder could change between the executions
+	HazardMutableExportedGlobal
+
+	// HazardMemorySizeBranch is a branch on the result of memory.size, the
+	// pattern of the allocators of some older toolchains, whose behaviour
+	// then depends on how much memory the executor reserved
+	HazardMemorySizeBranch
+)
+
+// String returns the name of the hazard
+func (kind DeterminismHazardKind) String() string {
+	switch kind {
+	case HazardClockImport:
+		return "clock import"
+	case HazardRandomImport:
+		return "random import"
+	case HazardMutableExportedGlobal:
+		return "mutable exported global"
+	case HazardMemorySizeBranch:
+		return "branch on memory size"
+	}
+	return "unknown hazard"
+}
+
+// DeterminismHazard is a construct of a contract binary which threatens the
+// determinism of its executions; the name is that of the import, of the
+// export or of the function in which the hazard was found
+type DeterminismHazard struct {
+	Kind DeterminismHazardKind
+	Name string
+}
+
+// wasiModules are the module names under which the WASI functions are imported
+var wasiModules = map[string]bool{
+	"wasi_snapshot_preview1": true,
+	"wasi_unstable":          true,
+}
+
+// wasiHazards are the WASI functions whose results differ between the nodes
+var wasiHazards = map[string]DeterminismHazardKind{
+	"clock_time_get": HazardClockImport,
+	"clock_res_get":  HazardClockImport,
+	"random_get":     HazardRandomImport,
+}
+
+// importHazard returns the hazard of an imported function, if any
+func importHazard(imported *Import) (DeterminismHazardKind, bool) {
+	if imported.Kind != ExternalFunction || !wasiModules[imported.Module] {
+		return 0, false
+	}
+	kind, ok := wasiHazards[imported.Name]
+	return kind, ok
+}
+
+const (
+	opcodeBrIf       = 0x0d
+	opcodeBrTable    = 0x0e
+	opcodeSelect     = 0x1b
+	opcodeMemorySize = 0x3f
+	opcodeI32Eqz     = 0x45
+	opcodeI32GeU     = 0x4f
+	globalMutable    = 0x01
+)
+
+type memorySizeBranchState uint8
+
+const (
+	afterNothing memorySizeBranchState = iota
+	afterMemorySize
+	afterMemorySizeAndConst
+	afterMemorySizeComparison
+)
+
+// memorySizeBranchDetector follows the opcodes of a function body to find
+// memory.size whose result decides a branch, either directly or through an
+// i32 comparison, optionally against a constant
+type memorySizeBranchDetector struct {
+	state memorySizeBranchState
+	found bool
+}
+
+func (detector *memorySizeBranchDetector) observe(opcode byte) {
+	isComparison := opcode >= opcodeI32Eqz && opcode <= opcodeI32GeU
+	isBranch := opcode == opcodeIf || opcode == opcodeBrIf || opcode == opcodeBrTable || opcode == opcodeSelect
+
+	switch {
+	case opcode == opcodeMemorySize:
+		detector.state = afterMemorySize
+	case detector.state == afterMemorySize && opcode == opcodeI32Const:
+		detector.state = afterMemorySizeAndConst
+	case (detector.state == afterMemorySize || detector.state == afterMemorySizeAndConst) && isComparison:
+		detector.state = afterMemorySizeComparison
+	case (detector.state == afterMemorySize || detector.state == afterMemorySizeComparison) && isBranch:
+		detector.found = true
+		detector.state = afterNothing
+	default:
+		detector.state = afterNothing
+	}
+}
+
+// findDeterminismHazards lists the hazards of the imports and of the exported
+// globals, after those found in the function bodies
+func (inspector *moduleInspector) findDeterminismHazards() error {
+	for _, imported := range inspector.report.Imports {
+		kind, ok := importHazard(imported)
+		if ok {
+			inspector.addDeterminismHazard(kind, imported.Module+"."+imported.Name)
+		}
+	}
+
+	for _, exported := range inspector.report.Exports {
+		if exported.Kind != ExternalGlobal {
+			continue
+		}
+		if exported.Index >= uint32(len(inspector.globalMutability)) {
+			return fmt.Errorf("%w: exported global %s has index %d out of range", ErrMalformedSection, exported.Name, exported.Index)
+		}
+		if inspector.globalMutability[exported.Index] {
+			inspector.addDeterminismHazard(HazardMutableExportedGlobal, exported.Name)
+		}
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) addDeterminismHazard(kind DeterminismHazardKind, name string) {
+	inspector.report.DeterminismHazards = append(inspector.report.DeterminismHazards, DeterminismHazard{Kind: kind, Name: name})
+}
@@ -0,0 +1,108 @@
+package inspect
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func makeSection(id byte, content ...byte) []byte {
+	return append([]byte{id, byte(len(content))}, content...)
+}
+
+func makeName(name string) []byte {
+	return append([]byte{byte(len(name))}, name...)
+}
+
+func concatBytes(parts ...[]byte) []byte {
+	joined := make([]byte, 0)
+	for _, part := range parts {
+		joined = append(joined, part...)
+	}
+	return joined
+}
+
+// makeHazardsModule builds a module with the given imports of type () -> (),
+// the given global and export sections and a single function with the given body
+func makeHazardsModule(imports [][2]string, globals []byte, exports []byte, body ...byte) []byte {
+	importEntries := []byte{byte(len(imports))}
+	for _, imported := range imports {
+		importEntries = concatBytes(importEntries, makeName(imported[0]), makeName(imported[1]), []byte{0x00, 0x00})
+	}
+	body = append([]byte{0x00}, body...)
+
+	return makeModule(
+		makeSection(sectionType, 0x01, 0x60, 0x00, 0x00),
+		makeSection(sectionImport, importEntries...),
+		makeSection(sectionFunction, 0x01, 0x00),
+		makeSection(sectionMemory, 0x01, 0x00, 0x01),
+		makeSection(sectionGlobal, globals...),
+		makeSection(sectionExport, exports...),
+		makeSection(sectionCode, concatBytes([]byte{0x01, byte(len(body))}, body)...),
+	)
+}
+
+// a mutable and an immutable global of type i32, both initialized to 0
+var hazardsGlobals = []byte{
+	0x02,
+	0x7f, 0x01, 0x41, 0x00, 0x0b,
+	0x7f, 0x00, 0x41, 0x00, 0x0b,
+}
+
+func TestInspect_DeterminismHazards(t *testing.T) {
+	exports := concatBytes(
+		[]byte{0x03},
+		makeName("counter"), []byte{0x03, 0x00},
+		makeName("constant"), []byte{0x03, 0x01},
+		makeName("run"), []byte{0x00, 0x02},
+	)
+	imports := [][2]string{
+		{"wasi_snapshot_preview1", "random_get"},
+		{"wasi_unstable", "clock_time_get"},
+	}
+	// memory.size, i32.const 1, i32.gt_u, if, end, end
+	code := makeHazardsModule(imports, hazardsGlobals, exports, 0x3f, 0x00, 0x41, 0x01, 0x4b, 0x04, 0x40, 0x0b, 0x0b)
+
+	report, err := Inspect(code, nil)
+	require.Nil(t, err)
+	require.Equal(t, []DeterminismHazard{
+		{Kind: HazardMemorySizeBranch, Name: "function 2"},
+		{Kind: HazardRandomImport, Name: "wasi_snapshot_preview1.random_get"},
+		{Kind: HazardClockImport, Name: "wasi_unstable.clock_time_get"},
+		{Kind: HazardMutableExportedGlobal, Name: "counter"},
+	}, report.DeterminismHazards)
+	require.Equal(t, "branch on memory size", report.DeterminismHazards[0].Kind.String())
+
+	// memory.size, i32.eqz, br_if 0, end
+	report, err = Inspect(makeHazardsModule(nil, []byte{0x00}, []byte{0x00}, 0x3f, 0x00, 0x45, 0x0d, 0x00, 0x0b), nil)
+	require.Nil(t, err)
+	require.Equal(t, []DeterminismHazard{{Kind: HazardMemorySizeBranch, Name: "function 0"}}, report.DeterminismHazards)
+}
+
+func TestInspect_DeterminismHazards_HarmlessConstructs(t *testing.T) {
+	exports := concatBytes([]byte{0x01}, makeName("constant"), []byte{0x03, 0x01})
+	imports := [][2]string{
+		{"wasi_snapshot_preview1", "fd_write"},
+		{"env", "random_get"},
+	}
+	bodies := [][]byte{
+		// memory.size, drop, end
+		{0x3f, 0x00, 0x1a, 0x0b},
+		// memory.size, i32.const 1, i32.add, drop, end
+		{0x3f, 0x00, 0x41, 0x01, 0x6a, 0x1a, 0x0b},
+		// memory.size, drop, i32.const 1, if, end, end
+		{0x3f, 0x00, 0x1a, 0x41, 0x01, 0x04, 0x40, 0x0b, 0x0b},
+	}
+	for _, body := range bodies {
+		report, err := Inspect(makeHazardsModule(imports, hazardsGlobals, exports, body...), nil)
+		require.Nil(t, err)
+		require.Empty(t, report.DeterminismHazards)
+	}
+}
+
+func TestInspect_DeterminismHazards_GlobalOutOfRange(t *testing.T) {
+	exports := concatBytes([]byte{0x01}, makeName("missing"), []byte{0x03, 0x05})
+	_, err := Inspect(makeHazardsModule(nil, hazardsGlobals, exports, 0x0b), nil)
+	require.True(t, errors.Is(err, ErrMalformedSection))
+}
@@ -0,0 +1,42 @@
+package inspect
+
+import (
+	"errors"
+	"fmt"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrMalformedEndpointList signals an endpoints section which cannot be decoded
+var ErrMalformedEndpointList = errors.New("malformed endpoints section")
+
+// EndpointListSectionName is the name of the custom section in which a
+// contract lists the functions it exports as endpoints, as written by the
+// endpoints! macro of the Rust test contracts; it holds a vector of names
+const EndpointListSectionName = "endpoints"
+
+func readEndpointListEntries(reader *wasmreader.Reader) ([]string, error) {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrMalformedEndpointList, err)
+	}
+
+	names := make([]string, 0, count)
+	listed := make(map[string]bool)
+	for i := uint32(0); i < count; i++ {
+		name, err := reader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformedEndpointList, err)
+		}
+		if listed[name] {
+			return nil, fmt.Errorf("%w: duplicated endpoint %s", ErrMalformedEndpointList, name)
+		}
+		listed[name] = true
+		names = append(names, name)
+	}
+	if reader.HasMore() {
+		return nil, fmt.Errorf("%w: trailing bytes", ErrMalformedEndpointList)
+	}
+
+	return names, nil
+}
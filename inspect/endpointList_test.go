@@ -0,0 +1,45 @@
+package inspect
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestInspect_EndpointList(t *testing.T) {
+	entries := []byte{
+		0x03,
+		0x06, 'a', 'n', 's', 'w', 'e', 'r',
+		0x04, 'e', 'c', 'h', 'o',
+		0x04, 'f', 'a', 'i', 'l',
+	}
+	report, err := Inspect(makeModule(makeCustomSection(EndpointListSectionName, entries)), nil)
+	require.Nil(t, err)
+	require.Equal(t, []string{"answer", "echo", "fail"}, report.EndpointList)
+
+	report, err = Inspect(makeModule(makeCustomSection(EndpointListSectionName, []byte{0x00})), nil)
+	require.Nil(t, err)
+	require.NotNil(t, report.EndpointList)
+	require.Empty(t, report.EndpointList)
+
+	report, err = Inspect(makeModule(makeCustomSection("name", []byte{0x01})), nil)
+	require.Nil(t, err)
+	require.Nil(t, report.EndpointList)
+}
+
+func TestInspect_EndpointList_MalformedSection(t *testing.T) {
+	malformedSections := map[string][]byte{
+		"duplicated endpoint": {0x02, 0x01, 'f', 0x01, 'f'},
+		"truncated":           {0x02, 0x01, 'f'},
+		"trailing bytes":      {0x01, 0x01, 'f', 0x00},
+	}
+	for description, entries := range malformedSections {
+		_, err := Inspect(makeModule(makeCustomSection(EndpointListSectionName, entries)), nil)
+		require.True(t, errors.Is(err, ErrMalformedSection), description)
+	}
+
+	section := makeCustomSection(EndpointListSectionName, []byte{0x01, 0x01, 'f'})
+	_, err := Inspect(makeModule(section, section), nil)
+	require.True(t, errors.Is(err, ErrMalformedSection))
+}
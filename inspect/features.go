@@ -0,0 +1,284 @@
+package inspect
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrInvalidOpcode signals an instruction which is not part of any supported wasm proposal
+var ErrInvalidOpcode = errors.New("invalid opcode")
+
+// Feature is a set of wasm proposals, beyond the MVP, used by a contract
+type Feature uint8
+
+const (
+	// FeatureSignExtension is the proposal of the sign-extension operators
+	FeatureSignExtension Feature = 1 << iota
+
+	// FeatureBulkMemory is the proposal of the bulk memory operations,
+	// passive data and element segments, and of the data count section
+	FeatureBulkMemory
+
+	// FeatureReferenceTypes is the proposal of the reference types, of the
+	// table instructions and of the multiple tables
+	FeatureReferenceTypes
+)
+
+// NoFeatures is the empty set of proposals, used by MVP contracts
+const NoFeatures Feature = 0
+
+var featureNames = []struct {
+	feature Feature
+	name    string
+}{
+	{FeatureSignExtension, "sign-extension"},
+	{FeatureBulkMemory, "bulk-memory"},
+	{FeatureReferenceTypes, "reference-types"},
+}
+
+// Has returns true if the set contains all the given proposals
+func (feature Feature) Has(other Feature) bool {
+	return feature&other == other
+}
+
+// String returns the names of the proposals in the set
+func (feature Feature) String() string {
+	names := make([]string, 0, len(featureNames))
+	for _, featureName := range featureNames {
+		if feature.Has(featureName.feature) {
+			names = append(names, featureName.name)
+		}
+	}
+	if len(names) == 0 {
+		return "none"
+	}
+	return strings.Join(names, ",")
+}
+
+const (
+	valueTypeI32       = 0x7f
+	valueTypeI64       = 0x7e
+	valueTypeF32       = 0x7d
+	valueTypeF64       = 0x7c
+	valueTypeV128      = 0x7b
+	valueTypeFuncRef   = 0x70
+	valueTypeExternRef = 0x6f
+	blockTypeEmpty     = 0x40
+)
+
+const (
+	prefixMisc = 0xfc
+	prefixSIMD = 0xfd
+)
+
+func isValueType(b byte) bool {
+	switch b {
+	case valueTypeI32, valueTypeI64, valueTypeF32, valueTypeF64, valueTypeV128, valueTypeFuncRef, valueTypeExternRef:
+		return true
+	}
+	return false
+}
+
+// featuresOfValueType returns the proposals needed to use a value type in
+// function signatures, locals and globals
+func featuresOfValueType(valueType byte) Feature {
+	if valueType == valueTypeFuncRef || valueType == valueTypeExternRef {
+		return FeatureReferenceTypes
+	}
+	return NoFeatures
+}
+
+// scanInstructions decodes the instructions of a function body or of a
+// constant expression and returns the proposals they use; the observer is
+// given every opcode, in order
+func scanInstructions(reader *wasmreader.Reader, observe func(opcode byte)) (Feature, error) {
+	features := NoFeatures
+	for reader.HasMore() {
+		opcode, err := reader.ReadByte()
+		if err != nil {
+			return NoFeatures, err
+		}
+		observe(opcode)
+
+		opcodeFeatures, err := scanInstruction(reader, opcode)
+		if err != nil {
+			return NoFeatures, err
+		}
+		features |= opcodeFeatures
+	}
+	return features, nil
+}
+
+func scanInstruction(reader *wasmreader.Reader, opcode byte) (Feature, error) {
+	switch {
+	case opcode <= 0x01, opcode == 0x05, opcode == opcodeEnd, opcode == 0x0f, opcode == 0x1a, opcode == 0x1b:
+		return NoFeatures, nil
+	case opcode >= 0x02 && opcode <= 0x04:
+		return NoFeatures, skipBlockType(reader)
+	case opcode == 0x0c, opcode == 0x0d, opcode == 0x10, opcode >= 0x20 && opcode <= 0x24:
+		_, err := reader.ReadU32()
+		return NoFeatures, err
+	case opcode == 0x0e:
+		return NoFeatures, skipBranchTable(reader)
+	case opcode == 0x11:
+		return scanCallIndirect(reader)
+	case opcode == 0x1c:
+		count, err := reader.ReadU32()
+		if err != nil {
+			return NoFeatures, err
+		}
+		return FeatureReferenceTypes, reader.Skip(count)
+	case opcode == 0x25, opcode == 0x26:
+		_, err := reader.ReadU32()
+		return FeatureReferenceTypes, err
+	case opcode >= 0x28 && opcode <= 0x3e:
+		return NoFeatures, skipMemoryArgument(reader)
+	case opcode == 0x3f, opcode == 0x40:
+		return NoFeatures, reader.Skip(1)
+	case opcode == opcodeI32Const:
+		return NoFeatures, reader.SkipSigned(32)
+	case opcode == opcodeI64Const:
+		return NoFeatures, reader.SkipSigned(64)
+	case opcode == opcodeF32Const:
+		return NoFeatures, reader.Skip(4)
+	case opcode == opcodeF64Const:
+		return NoFeatures, reader.Skip(8)
+	case opcode >= 0x45 && opcode <= 0xbf:
+		return NoFeatures, nil
+	case opcode >= 0xc0 && opcode <= 0xc4:
+		return FeatureSignExtension, nil
+	case opcode == opcodeRefNull:
+		return FeatureReferenceTypes, reader.Skip(1)
+	case opcode == 0xd1:
+		return FeatureReferenceTypes, nil
+	case opcode == opcodeRefFunc:
+		_, err := reader.ReadU32()
+		return FeatureReferenceTypes, err
+	case opcode == prefixMisc:
+		return scanMiscInstruction(reader)
+	case opcode == prefixSIMD:
+		return NoFeatures, skipSIMDInstruction(reader)
+	}
+	return NoFeatures, fmt.Errorf("%w: 0x%02x", ErrInvalidOpcode, opcode)
+}
+
+// skipBlockType skips the type of a block, loop or if, which is either empty,
+// a value type or the index of a function type
+func skipBlockType(reader *wasmreader.Reader) error {
+	next, err := reader.PeekByte()
+	if err != nil {
+		return err
+	}
+	if next == blockTypeEmpty || isValueType(next) {
+		return reader.Skip(1)
+	}
+	return reader.SkipSigned(33)
+}
+
+func skipBranchTable(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint64(0); i <= uint64(count); i++ {
+		_, err = reader.ReadU32()
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// scanCallIndirect skips the immediates of call_indirect; a table other than
+// the first one requires the reference types
+func scanCallIndirect(reader *wasmreader.Reader) (Feature, error) {
+	_, err := reader.ReadU32()
+	if err != nil {
+		return NoFeatures, err
+	}
+	tableIndex, err := reader.ReadU32()
+	if err != nil {
+		return NoFeatures, err
+	}
+	if tableIndex != 0 {
+		return FeatureReferenceTypes, nil
+	}
+	return NoFeatures, nil
+}
+
+// skipMemoryArgument skips the alignment and the offset of a memory access
+func skipMemoryArgument(reader *wasmreader.Reader) error {
+	return skipIndices(reader, 2)
+}
+
+func skipIndices(reader *wasmreader.Reader, count int) error {
+	for i := 0; i < count; i++ {
+		_, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// scanMiscInstruction decodes the instructions prefixed by 0xfc: the
+// saturating conversions of the MVP extensions, the bulk memory operations
+// and the table instructions of the reference types
+func scanMiscInstruction(reader *wasmreader.Reader) (Feature, error) {
+	subOpcode, err := reader.ReadU32()
+	if err != nil {
+		return NoFeatures, err
+	}
+
+	switch subOpcode {
+	case 0, 1, 2, 3, 4, 5, 6, 7:
+		return NoFeatures, nil
+	case 8:
+		_, err = reader.ReadU32()
+		if err == nil {
+			err = reader.Skip(1)
+		}
+		return FeatureBulkMemory, err
+	case 9, 13:
+		_, err = reader.ReadU32()
+		return FeatureBulkMemory, err
+	case 10:
+		return FeatureBulkMemory, reader.Skip(2)
+	case 11:
+		return FeatureBulkMemory, reader.Skip(1)
+	case 12, 14:
+		return FeatureBulkMemory, skipIndices(reader, 2)
+	case 15, 16, 17:
+		_, err = reader.ReadU32()
+		return FeatureReferenceTypes, err
+	}
+	return NoFeatures, fmt.Errorf("%w: 0x%02x %d", ErrInvalidOpcode, prefixMisc, subOpcode)
+}
+
+// skipSIMDInstruction skips the instructions prefixed by 0xfd; SIMD is not
+// gated, but its immediates must be decoded to reach the next instructions
+func skipSIMDInstruction(reader *wasmreader.Reader) error {
+	subOpcode, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+
+	switch {
+	case subOpcode <= 0x0b, subOpcode == 0x5c, subOpcode == 0x5d:
+		return skipMemoryArgument(reader)
+	case subOpcode == 0x0c, subOpcode == 0x0d:
+		return reader.Skip(16)
+	case subOpcode >= 0x15 && subOpcode <= 0x22:
+		return reader.Skip(1)
+	case subOpcode >= 0x54 && subOpcode <= 0x5b:
+		err = skipMemoryArgument(reader)
+		if err != nil {
+			return err
+		}
+		return reader.Skip(1)
+	}
+	return nil
+}
@@ -0,0 +1,811 @@
+package inspect
+
+import (
+	"bytes"
+	"errors"
+	"fmt"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrInvalidMagic signals that the contract binary does not start with the wasm magic number
+var ErrInvalidMagic = errors.New("invalid wasm magic number")
+
+// ErrUnsupportedVersion signals a wasm binary version other than 1
+var ErrUnsupportedVersion = errors.New("unsupported wasm binary version")
+
+// ErrMalformedSection signals a section whose content does not match its declared size or kind
+var ErrMalformedSection = errors.New("malformed wasm section")
+
+// HostFunctionsModule is the module from which contracts import the host functions
+const HostFunctionsModule = "env"
+
+var wasmMagic = []byte{0x00, 0x61, 0x73, 0x6d}
+var wasmVersion = []byte{0x01, 0x00, 0x00, 0x00}
+
+const (
+	sectionCustom    = 0
+	sectionType      = 1
+	sectionImport    = 2
+	sectionFunction  = 3
+	sectionTable     = 4
+	sectionMemory    = 5
+	sectionGlobal    = 6
+	sectionExport    = 7
+	sectionStart     = 8
+	sectionElement   = 9
+	sectionCode      = 10
+	sectionData      = 11
+	sectionDataCount = 12
+)
+
+const functionTypeForm = 0x60
+
+// ExternalKind is the kind of an imported or exported entity
+type ExternalKind byte
+
+const (
+	// ExternalFunction is an imported or exported function
+	ExternalFunction ExternalKind = 0
+
+	// ExternalTable is an imported or exported table
+	ExternalTable ExternalKind = 1
+
+	// ExternalMemory is an imported or exported linear memory
+	ExternalMemory ExternalKind = 2
+
+	// ExternalGlobal is an imported or exported global
+	ExternalGlobal ExternalKind = 3
+)
+
+// String returns the name of the kind
+func (kind ExternalKind) String() string {
+	switch kind {
+	case ExternalFunction:
+		return "function"
+	case ExternalTable:
+		return "table"
+	case ExternalMemory:
+		return "memory"
+	case ExternalGlobal:
+		return "global"
+	}
+	return "unknown"
+}
+
+// Limits are the initial and optional maximum size of a memory, in pages, or of a table
+type Limits struct {
+	Min    uint32
+	Max    uint32
+	HasMax bool
+}
+
+// FunctionType is the signature of a function, as counts of parameters and results
+type FunctionType struct {
+	NumParams  int
+	NumResults int
+}
+
+// Import is an entity imported by the contract; a function import is Known
+// if it is a host function provided by the VM
+type Import struct {
+	Module string
+	Name   string
+	Kind   ExternalKind
+	Type   *FunctionType
+	Known  bool
+}
+
+// Export is an entity exported by the contract; exported functions are the endpoints
+type Export struct {
+	Name       string
+	Kind       ExternalKind
+	Index      uint32
+	Type       *FunctionType
+	IsReexport bool
+	BodySize   uint32
+}
+
+// CustomSection is a custom section of the contract binary, such as the name section
+type CustomSection struct {
+	Name string
+	Size int
+}
+
+// ContractReport is the structured description of a contract binary
+type ContractReport struct {
+	CodeSize          int
+	Imports           []*Import
+	Exports           []*Export
+	Memories          []*Limits
+	Tables            []*Limits
+	NumFunctions      int
+	CodeSectionSize   int
+	NumDataSegments   int
+	DataSegmentsSize  uint64
+	CustomSections    []*CustomSection
+	HasStartFunction  bool
+	StartFunctionName string
+	Features          Feature
+
+	// EndpointCallability holds the rules of the callability section, or is
+	// nil if the contract declares none
+	EndpointCallability map[string]Callability
+
+	// EndpointArguments holds the schemas of the argschema section, or is nil
+	// if the contract declares none
+	EndpointArguments map[string]ArgumentSchema
+
+	// EndpointPaymentTokens holds the whitelists of the paytokens section, or
+	// is nil if the contract declares none
+	EndpointPaymentTokens map[string]TokenWhitelist
+
+	// EndpointList holds the names of the endpoints section, in their order
+	// in the binary, or is nil if the contract declares none
+	EndpointList []string
+
+	// BuildInfo holds the provenance recorded in the buildinfo section, or is
+	// nil if the contract declares none
+	BuildInfo *BuildInfo
+
+	// DeterminismHazards holds the constructs which threaten the determinism
+	// of the executions of the contract; it is empty for most contracts
+	DeterminismHazards []DeterminismHazard
+}
+
+// ImportedFunctions returns the function imports, in their order in the binary
+func (report *ContractReport) ImportedFunctions() []*Import {
+	functions := make([]*Import, 0, len(report.Imports))
+	for _, imported := range report.Imports {
+		if imported.Kind == ExternalFunction {
+			functions = append(functions, imported)
+		}
+	}
+	return functions
+}
+
+// UnknownImports returns the names of the imports which are not host functions of the VM
+func (report *ContractReport) UnknownImports() []string {
+	unknown := make([]string, 0)
+	for _, imported := range report.Imports {
+		if !imported.Known {
+			unknown = append(unknown, imported.Module+"."+imported.Name)
+		}
+	}
+	return unknown
+}
+
+// Endpoints returns the exported functions, in their order in the binary
+func (report *ContractReport) Endpoints() []*Export {
+	endpoints := make([]*Export, 0, len(report.Exports))
+	for _, exported := range report.Exports {
+		if exported.Kind == ExternalFunction {
+			endpoints = append(endpoints, exported)
+		}
+	}
+	return endpoints
+}
+
+// Endpoint returns the exported function with the given name, if any
+func (report *ContractReport) Endpoint(name string) (*Export, bool) {
+	for _, endpoint := range report.Endpoints() {
+		if endpoint.Name == name {
+			return endpoint, true
+		}
+	}
+	return nil, false
+}
+
+type moduleInspector struct {
+	report           *ContractReport
+	hostFunctions    vmcommon.FunctionNames
+	types            []*FunctionType
+	functionTypes    []*FunctionType
+	bodySizes        []uint32
+	globalMutability []bool
+	numImportedFuncs uint32
+	startFunction    uint32
+}
+
+// Inspect decodes the sections of a contract binary without compiling it.
+// The function imports are checked against the given host functions, such as
+// those returned by the executor; with no host functions, all imports are unknown.
+func Inspect(code []byte, hostFunctions vmcommon.FunctionNames) (*ContractReport, error) {
+	inspector := &moduleInspector{
+		report: &ContractReport{
+			CodeSize:           len(code),
+			Imports:            make([]*Import, 0),
+			Exports:            make([]*Export, 0),
+			Memories:           make([]*Limits, 0),
+			Tables:             make([]*Limits, 0),
+			CustomSections:     make([]*CustomSection, 0),
+			DeterminismHazards: make([]DeterminismHazard, 0),
+		},
+		hostFunctions: hostFunctions,
+	}
+
+	reader := wasmreader.NewReader(code)
+	err := inspector.readHeader(reader)
+	if err != nil {
+		return nil, err
+	}
+
+	for reader.HasMore() {
+		err = inspector.readSection(reader)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	err = inspector.resolveFunctions()
+	if err != nil {
+		return nil, err
+	}
+	err = inspector.findDeterminismHazards()
+	if err != nil {
+		return nil, err
+	}
+	return inspector.report, nil
+}
+
+func (inspector *moduleInspector) readHeader(reader *wasmreader.Reader) error {
+	magic, err := reader.ReadBytes(uint32(len(wasmMagic)))
+	if err != nil || !bytes.Equal(magic, wasmMagic) {
+		return ErrInvalidMagic
+	}
+	version, err := reader.ReadBytes(uint32(len(wasmVersion)))
+	if err != nil || !bytes.Equal(version, wasmVersion) {
+		return ErrUnsupportedVersion
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readSection(reader *wasmreader.Reader) error {
+	sectionID, err := reader.ReadByte()
+	if err != nil {
+		return err
+	}
+	size, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	content, err := reader.ReadBytes(size)
+	if err != nil {
+		return err
+	}
+
+	sectionReader := wasmreader.NewReader(content)
+	switch sectionID {
+	case sectionCustom:
+		err = inspector.readCustomSection(sectionReader)
+	case sectionType:
+		err = inspector.readTypeSection(sectionReader)
+	case sectionImport:
+		err = inspector.readImportSection(sectionReader)
+	case sectionFunction:
+		err = inspector.readFunctionSection(sectionReader)
+	case sectionTable:
+		err = inspector.readTableSection(sectionReader)
+	case sectionMemory:
+		err = inspector.readMemorySection(sectionReader)
+	case sectionExport:
+		err = inspector.readExportSection(sectionReader)
+	case sectionStart:
+		inspector.report.HasStartFunction = true
+		inspector.startFunction, err = sectionReader.ReadU32()
+	case sectionCode:
+		inspector.report.CodeSectionSize = int(size)
+		err = inspector.readCodeSection(sectionReader)
+	case sectionData:
+		err = inspector.readDataSection(sectionReader)
+	case sectionGlobal:
+		err = inspector.readGlobalSection(sectionReader)
+	case sectionElement:
+		err = inspector.readElementSection(sectionReader)
+	case sectionDataCount:
+		inspector.report.Features |= FeatureBulkMemory
+		_, err = sectionReader.ReadU32()
+	default:
+		return fmt.Errorf("%w: unknown section id %d", ErrMalformedSection, sectionID)
+	}
+	if err != nil {
+		return fmt.Errorf("%w: section id %d: %v", ErrMalformedSection, sectionID, err)
+	}
+	if sectionID != sectionCustom && sectionReader.HasMore() {
+		return fmt.Errorf("%w: section id %d has trailing bytes", ErrMalformedSection, sectionID)
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readCustomSection(reader *wasmreader.Reader) error {
+	name, err := reader.ReadName()
+	if err != nil {
+		return err
+	}
+	inspector.report.CustomSections = append(inspector.report.CustomSections, &CustomSection{
+		Name: name,
+		Size: reader.Remaining(),
+	})
+
+	switch name {
+	case CallabilitySectionName:
+		if inspector.report.EndpointCallability != nil {
+			return fmt.Errorf("%w: duplicated section", ErrMalformedCallability)
+		}
+		inspector.report.EndpointCallability, err = readCallabilityEntries(reader)
+		return err
+	case ArgumentSchemaSectionName:
+		if inspector.report.EndpointArguments != nil {
+			return fmt.Errorf("%w: duplicated section", ErrMalformedArgumentSchema)
+		}
+		inspector.report.EndpointArguments, err = readArgumentSchemaEntries(reader)
+		return err
+	case PaymentTokensSectionName:
+		if inspector.report.EndpointPaymentTokens != nil {
+			return fmt.Errorf("%w: duplicated section", ErrMalformedPaymentTokens)
+		}
+		inspector.report.EndpointPaymentTokens, err = readPaymentTokensEntries(reader)
+		return err
+	case EndpointListSectionName:
+		if inspector.report.EndpointList != nil {
+			return fmt.Errorf("%w: duplicated section", ErrMalformedEndpointList)
+		}
+		inspector.report.EndpointList, err = readEndpointListEntries(reader)
+		return err
+	case BuildInfoSectionName:
+		if inspector.report.BuildInfo != nil {
+			return fmt.Errorf("%w: duplicated section", ErrMalformedBuildInfo)
+		}
+		inspector.report.BuildInfo, err = readBuildInfoEntries(reader)
+		return err
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readTypeSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		form, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		if form != functionTypeForm {
+			return fmt.Errorf("unexpected type form 0x%02x", form)
+		}
+
+		numParams, err := inspector.readValueTypes(reader)
+		if err != nil {
+			return err
+		}
+		numResults, err := inspector.readValueTypes(reader)
+		if err != nil {
+			return err
+		}
+
+		inspector.types = append(inspector.types, &FunctionType{
+			NumParams:  int(numParams),
+			NumResults: int(numResults),
+		})
+	}
+	return nil
+}
+
+// readValueTypes reads a vector of value types and returns its length
+func (inspector *moduleInspector) readValueTypes(reader *wasmreader.Reader) (uint32, error) {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return 0, err
+	}
+	valueTypes, err := reader.ReadBytes(count)
+	if err != nil {
+		return 0, err
+	}
+	for _, valueType := range valueTypes {
+		inspector.report.Features |= featuresOfValueType(valueType)
+	}
+	return count, nil
+}
+
+func (inspector *moduleInspector) readImportSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		imported := &Import{}
+		imported.Module, err = reader.ReadName()
+		if err != nil {
+			return err
+		}
+		imported.Name, err = reader.ReadName()
+		if err != nil {
+			return err
+		}
+		kind, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		imported.Kind = ExternalKind(kind)
+
+		err = inspector.readImportDescription(reader, imported)
+		if err != nil {
+			return err
+		}
+		inspector.report.Imports = append(inspector.report.Imports, imported)
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readImportDescription(reader *wasmreader.Reader, imported *Import) error {
+	switch imported.Kind {
+	case ExternalFunction:
+		typeIndex, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		functionType, err := inspector.getType(typeIndex)
+		if err != nil {
+			return err
+		}
+		imported.Type = functionType
+		imported.Known = inspector.isHostFunction(imported)
+		inspector.functionTypes = append(inspector.functionTypes, functionType)
+		inspector.numImportedFuncs++
+		return nil
+	case ExternalTable:
+		return inspector.readTable(reader)
+	case ExternalMemory:
+		limits, err := readLimits(reader)
+		if err != nil {
+			return err
+		}
+		inspector.report.Memories = append(inspector.report.Memories, limits)
+		return nil
+	case ExternalGlobal:
+		return inspector.readGlobalType(reader)
+	}
+	return fmt.Errorf("unknown import kind %d", imported.Kind)
+}
+
+func (inspector *moduleInspector) isHostFunction(imported *Import) bool {
+	if imported.Module != HostFunctionsModule {
+		return false
+	}
+	_, ok := inspector.hostFunctions[imported.Name]
+	return ok
+}
+
+func (inspector *moduleInspector) readFunctionSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		typeIndex, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		functionType, err := inspector.getType(typeIndex)
+		if err != nil {
+			return err
+		}
+		inspector.functionTypes = append(inspector.functionTypes, functionType)
+	}
+	inspector.report.NumFunctions = int(count)
+	return nil
+}
+
+func (inspector *moduleInspector) readTableSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		err = inspector.readTable(reader)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// readTable decodes the element type and the limits of a table; tables of
+// external references, as well as multiple tables, require the reference types
+func (inspector *moduleInspector) readTable(reader *wasmreader.Reader) error {
+	elementType, err := reader.ReadByte()
+	if err != nil {
+		return err
+	}
+	limits, err := readLimits(reader)
+	if err != nil {
+		return err
+	}
+
+	inspector.report.Tables = append(inspector.report.Tables, limits)
+	if elementType == valueTypeExternRef || len(inspector.report.Tables) > 1 {
+		inspector.report.Features |= FeatureReferenceTypes
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readGlobalSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		err = inspector.readGlobalType(reader)
+		if err != nil {
+			return err
+		}
+		err = skipConstExpr(reader)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// readGlobalType decodes the value type and the mutability of a global
+func (inspector *moduleInspector) readGlobalType(reader *wasmreader.Reader) error {
+	valueType, err := reader.ReadByte()
+	if err != nil {
+		return err
+	}
+	inspector.report.Features |= featuresOfValueType(valueType)
+	mutability, err := reader.ReadByte()
+	if err != nil {
+		return err
+	}
+	inspector.globalMutability = append(inspector.globalMutability, mutability == globalMutable)
+	return nil
+}
+
+// readElementSection decodes the element segments; all the segment kinds
+// other than the active segments of the MVP require the bulk memory, and
+// those initialized by expressions or declared without a table also require
+// the reference types
+func (inspector *moduleInspector) readElementSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		flags, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		if flags > 7 {
+			return fmt.Errorf("unknown element segment flags %d", flags)
+		}
+		if flags != 0 {
+			inspector.report.Features |= FeatureBulkMemory
+		}
+		if flags&0x04 != 0 || flags == 3 {
+			inspector.report.Features |= FeatureReferenceTypes
+		}
+
+		err = inspector.readElementSegment(reader, flags)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readElementSegment(reader *wasmreader.Reader, flags uint32) error {
+	isPassiveOrDeclarative := flags&0x01 != 0
+	hasTableIndex := flags&0x02 != 0
+	usesExpressions := flags&0x04 != 0
+
+	var err error
+	if hasTableIndex && !isPassiveOrDeclarative {
+		_, err = reader.ReadU32()
+		if err != nil {
+			return err
+		}
+	}
+	if !isPassiveOrDeclarative {
+		err = skipConstExpr(reader)
+		if err != nil {
+			return err
+		}
+	}
+	if isPassiveOrDeclarative || hasTableIndex {
+		err = reader.Skip(1)
+		if err != nil {
+			return err
+		}
+	}
+
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		if usesExpressions {
+			err = skipConstExpr(reader)
+		} else {
+			_, err = reader.ReadU32()
+		}
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readMemorySection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		limits, err := readLimits(reader)
+		if err != nil {
+			return err
+		}
+		inspector.report.Memories = append(inspector.report.Memories, limits)
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readExportSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		exported := &Export{}
+		exported.Name, err = reader.ReadName()
+		if err != nil {
+			return err
+		}
+		kind, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		exported.Kind = ExternalKind(kind)
+		exported.Index, err = reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		inspector.report.Exports = append(inspector.report.Exports, exported)
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readCodeSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		bodySize, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		body, err := reader.ReadBytes(bodySize)
+		if err != nil {
+			return err
+		}
+		err = inspector.readFunctionBody(wasmreader.NewReader(body), inspector.numImportedFuncs+i)
+		if err != nil {
+			return fmt.Errorf("function body %d: %w", i, err)
+		}
+		inspector.bodySizes = append(inspector.bodySizes, bodySize)
+	}
+	return nil
+}
+
+// readFunctionBody decodes the locals and the instructions of a function, to
+// find the wasm proposals they use and whether they branch on memory.size
+func (inspector *moduleInspector) readFunctionBody(reader *wasmreader.Reader, functionIndex uint32) error {
+	numLocalGroups, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < numLocalGroups; i++ {
+		_, err = reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		valueType, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		inspector.report.Features |= featuresOfValueType(valueType)
+	}
+
+	detector := &memorySizeBranchDetector{}
+	features, err := scanInstructions(reader, detector.observe)
+	if err != nil {
+		return err
+	}
+	inspector.report.Features |= features
+	if detector.found {
+		inspector.addDeterminismHazard(HazardMemorySizeBranch, fmt.Sprintf("function %d", functionIndex))
+	}
+	return nil
+}
+
+func (inspector *moduleInspector) readDataSection(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		flags, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		if flags != 0 {
+			inspector.report.Features |= FeatureBulkMemory
+		}
+		switch flags {
+		case 0:
+			err = skipConstExpr(reader)
+		case 1:
+		case 2:
+			_, err = reader.ReadU32()
+			if err == nil {
+				err = skipConstExpr(reader)
+			}
+		default:
+			return fmt.Errorf("unknown data segment flags %d", flags)
+		}
+		if err != nil {
+			return err
+		}
+
+		size, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		err = reader.Skip(size)
+		if err != nil {
+			return err
+		}
+		inspector.report.DataSegmentsSize += uint64(size)
+	}
+	inspector.report.NumDataSegments = int(count)
+	return nil
+}
+
+func (inspector *moduleInspector) getType(typeIndex uint32) (*FunctionType, error) {
+	if typeIndex >= uint32(len(inspector.types)) {
+		return nil, fmt.Errorf("type index %d out of range", typeIndex)
+	}
+	return inspector.types[typeIndex], nil
+}
+
+// resolveFunctions attaches the signatures and body sizes to the exported
+// functions, once all the sections are known
+func (inspector *moduleInspector) resolveFunctions() error {
+	if len(inspector.bodySizes) != inspector.report.NumFunctions {
+		return fmt.Errorf("%w: %d function bodies for %d functions", ErrMalformedSection, len(inspector.bodySizes), inspector.report.NumFunctions)
+	}
+
+	for _, exported := range inspector.report.Exports {
+		if exported.Kind != ExternalFunction {
+			continue
+		}
+		if exported.Index >= uint32(len(inspector.functionTypes)) {
+			return fmt.Errorf("%w: exported function %s has index %d out of range", ErrMalformedSection, exported.Name, exported.Index)
+		}
+
+		exported.Type = inspector.functionTypes[exported.Index]
+		exported.IsReexport = exported.Index < inspector.numImportedFuncs
+		if !exported.IsReexport {
+			exported.BodySize = inspector.bodySizes[exported.Index-inspector.numImportedFuncs]
+		}
+		if inspector.report.HasStartFunction && exported.Index == inspector.startFunction {
+			inspector.report.StartFunctionName = exported.Name
+		}
+	}
+	return nil
+}
@@ -0,0 +1,168 @@
+package inspect
+
+import (
+	"errors"
+	"io/ioutil"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+	"github.com/stretchr/testify/require"
+)
+
+var counterHostFunctions = vmcommon.FunctionNames{
+	"int64storageStore": {},
+	"int64storageLoad":  {},
+}
+
+func TestInspect_CounterContract(t *testing.T) {
+	code, err := ioutil.ReadFile("../test/contracts/counter/output/counter.wasm")
+	require.Nil(t, err)
+
+	report, err := Inspect(code, counterHostFunctions)
+	require.Nil(t, err)
+	require.Equal(t, len(code), report.CodeSize)
+
+	importedFunctions := report.ImportedFunctions()
+	require.Len(t, importedFunctions, 3)
+	require.Equal(t, "int64storageStore", importedFunctions[0].Name)
+	require.Equal(t, HostFunctionsModule, importedFunctions[0].Module)
+	require.True(t, importedFunctions[0].Known)
+	require.Equal(t, []string{"env.int64finish"}, report.UnknownImports())
+
+	endpointNames := make([]string, 0)
+	for _, endpoint := range report.Endpoints() {
+		endpointNames = append(endpointNames, endpoint.Name)
+		require.Equal(t, 0, endpoint.Type.NumParams)
+		require.False(t, endpoint.IsReexport)
+		require.True(t, endpoint.BodySize > 0)
+	}
+	require.Equal(t, []string{"init", "increment", "decrement", "get"}, endpointNames)
+
+	memoryExport := report.Exports[0]
+	require.Equal(t, "memory", memoryExport.Name)
+	require.Equal(t, ExternalMemory, memoryExport.Kind)
+
+	require.Len(t, report.Memories, 1)
+	require.Equal(t, 1, report.NumDataSegments)
+	require.True(t, report.DataSegmentsSize > 0)
+	require.Equal(t, 4, report.NumFunctions)
+	require.Equal(t, NoFeatures, report.Features)
+}
+
+func TestInspect_SectionsOfHandmadeModule(t *testing.T) {
+	code := []byte{
+		0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+		// type section: (i32) -> (), () -> (i64)
+		0x01, 0x09, 0x02, 0x60, 0x01, 0x7f, 0x00, 0x60, 0x00, 0x01, 0x7e,
+		// import section: env.finish (type 0), other.mystery (type 0)
+		0x02, 0x1e, 0x02,
+		0x03, 'e', 'n', 'v', 0x06, 'f', 'i', 'n', 'i', 's', 'h', 0x00, 0x00,
+		0x05, 'o', 't', 'h', 'e', 'r', 0x07, 'm', 'y', 's', 't', 'e', 'r', 'y', 0x00, 0x00,
+		// function section: one function of type 1
+		0x03, 0x02, 0x01, 0x01,
+		// table section: funcref, min 5
+		0x04, 0x04, 0x01, 0x70, 0x00, 0x05,
+		// memory section: min 2, max 16
+		0x05, 0x04, 0x01, 0x01, 0x02, 0x10,
+		// export section: "get" function 2, "reexported" function 0
+		0x07, 0x14, 0x02,
+		0x03, 'g', 'e', 't', 0x00, 0x02,
+		0x0a, 'r', 'e', 'e', 'x', 'p', 'o', 'r', 't', 'e', 'd', 0x00, 0x00,
+		// code section: one body of 4 bytes
+		0x0a, 0x06, 0x01, 0x04, 0x00, 0x42, 0x07, 0x0b,
+		// data section: one active segment of 3 bytes at offset 8
+		0x0b, 0x09, 0x01, 0x00, 0x41, 0x08, 0x0b, 0x03, 'a', 'b', 'c',
+		// custom section "name" with 2 bytes of payload
+		0x00, 0x07, 0x04, 'n', 'a', 'm', 'e', 0x01, 0x02,
+	}
+
+	report, err := Inspect(code, vmcommon.FunctionNames{"finish": {}})
+	require.Nil(t, err)
+
+	require.Len(t, report.Imports, 2)
+	require.True(t, report.Imports[0].Known)
+	require.Equal(t, &FunctionType{NumParams: 1, NumResults: 0}, report.Imports[0].Type)
+	require.Equal(t, []string{"other.mystery"}, report.UnknownImports())
+
+	get, ok := report.Endpoint("get")
+	require.True(t, ok)
+	require.Equal(t, &FunctionType{NumParams: 0, NumResults: 1}, get.Type)
+	require.Equal(t, uint32(4), get.BodySize)
+	require.False(t, get.IsReexport)
+
+	reexported, ok := report.Endpoint("reexported")
+	require.True(t, ok)
+	require.True(t, reexported.IsReexport)
+
+	_, ok = report.Endpoint("missing")
+	require.False(t, ok)
+
+	require.Equal(t, []*Limits{{Min: 2, Max: 16, HasMax: true}}, report.Memories)
+	require.Equal(t, []*Limits{{Min: 5}}, report.Tables)
+	require.Equal(t, 1, report.NumDataSegments)
+	require.Equal(t, uint64(3), report.DataSegmentsSize)
+	require.Equal(t, []*CustomSection{{Name: "name", Size: 2}}, report.CustomSections)
+}
+
+func TestInspect_FeaturesOfHandmadeModule(t *testing.T) {
+	header := []byte{
+		0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+		// type section: () -> ()
+		0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+		// function section: one function of type 0
+		0x03, 0x02, 0x01, 0x00,
+		// memory section: min 1
+		0x05, 0x03, 0x01, 0x00, 0x01,
+	}
+	withBody := func(body ...byte) []byte {
+		code := append([]byte{}, header...)
+		code = append(code, 0x0a, byte(len(body)+2), 0x01, byte(len(body)))
+		return append(code, body...)
+	}
+
+	// i32.const 0, drop
+	report, err := Inspect(withBody(0x00, 0x41, 0x00, 0x1a, 0x0b), nil)
+	require.Nil(t, err)
+	require.Equal(t, NoFeatures, report.Features)
+
+	// i32.const 0, i32.extend8_s, drop, then memory.fill of 1 byte at 0
+	report, err = Inspect(withBody(
+		0x00,
+		0x41, 0x00, 0xc0, 0x1a,
+		0x41, 0x00, 0x41, 0x00, 0x41, 0x01, 0xfc, 0x0b, 0x00,
+		0x0b), nil)
+	require.Nil(t, err)
+	require.Equal(t, FeatureSignExtension|FeatureBulkMemory, report.Features)
+	require.Equal(t, "sign-extension,bulk-memory", report.Features.String())
+
+	// a local of type externref, ref.null func, drop
+	report, err = Inspect(withBody(0x01, 0x01, 0x6f, 0xd0, 0x70, 0x1a, 0x0b), nil)
+	require.Nil(t, err)
+	require.Equal(t, FeatureReferenceTypes, report.Features)
+
+	// the opcode 0x06 belongs to no supported proposal
+	_, err = Inspect(withBody(0x00, 0x06, 0x0b), nil)
+	require.True(t, errors.Is(err, ErrMalformedSection))
+	require.Contains(t, err.Error(), ErrInvalidOpcode.Error())
+}
+
+func TestInspect_InvalidBinaries(t *testing.T) {
+	_, err := Inspect([]byte("not wasm"), nil)
+	require.Equal(t, ErrInvalidMagic, err)
+
+	_, err = Inspect([]byte{0x00, 0x61, 0x73, 0x6d, 0x02, 0x00, 0x00, 0x00}, nil)
+	require.Equal(t, ErrUnsupportedVersion, err)
+
+	truncated := []byte{0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x10, 0x01}
+	_, err = Inspect(truncated, nil)
+	require.True(t, errors.Is(err, wasmreader.ErrUnexpectedEnd))
+
+	missingBodies := []byte{
+		0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+		0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+		0x03, 0x02, 0x01, 0x00,
+	}
+	_, err = Inspect(missingBodies, nil)
+	require.True(t, errors.Is(err, ErrMalformedSection))
+}
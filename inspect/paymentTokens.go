@@ -0,0 +1,155 @@
+package inspect
+
+import (
+	"errors"
+	"fmt"
+	"math/big"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrMalformedPaymentTokens signals a payment tokens section which cannot be decoded
+var ErrMalformedPaymentTokens = errors.New("malformed paytokens section")
+
+// ErrPaymentNotAccepted signals a payment which the endpoint does not accept
+var ErrPaymentNotAccepted = errors.New("payment not accepted by the endpoint")
+
+// PaymentTokensSectionName is the name of the custom section in which a
+// contract declares the tokens accepted by its payable endpoints; it holds a
+// vector of entries, each made of the name of an endpoint and a vector of the
+// identifiers of the tokens it accepts
+const PaymentTokensSectionName = "paytokens"
+
+// EGLDTokenIdentifier stands for the EGLD transferred as call value in the
+// payment tokens section
+const EGLDTokenIdentifier = "EGLD"
+
+// TokenWhitelist is the set of tokens which an endpoint accepts as payment,
+// by token identifier, so the NFTs of a collection are accepted by the
+// identifier of the collection. An empty whitelist accepts no payment.
+type TokenWhitelist map[string]struct{}
+
+// Accepts returns true if the whitelist contains the given token identifier
+func (whitelist TokenWhitelist) Accepts(tokenIdentifier []byte) bool {
+	_, accepted := whitelist[string(tokenIdentifier)]
+	return accepted
+}
+
+// Validate checks the payment of a call of the endpoint against the
+// whitelist: the EGLD call value, if not zero, and the identifiers of the
+// transferred tokens
+func (whitelist TokenWhitelist) Validate(callValue *big.Int, tokenIdentifiers [][]byte) error {
+	if callValue != nil && callValue.Sign() > 0 && !whitelist.Accepts([]byte(EGLDTokenIdentifier)) {
+		return fmt.Errorf("%w: %s", ErrPaymentNotAccepted, EGLDTokenIdentifier)
+	}
+
+	for _, tokenIdentifier := range tokenIdentifiers {
+		if !whitelist.Accepts(tokenIdentifier) {
+			return fmt.Errorf("%w: %s", ErrPaymentNotAccepted, tokenIdentifier)
+		}
+	}
+
+	return nil
+}
+
+// ReadPaymentTokens decodes the payment tokens section of a contract binary,
+// skipping over all the other sections; the result is nil if the contract
+// declares no payment tokens section, in which case the payments of its
+// endpoints are only checked by the endpoints themselves
+func ReadPaymentTokens(code []byte) (map[string]TokenWhitelist, error) {
+	reader := wasmreader.NewReader(code)
+	err := (&moduleInspector{}).readHeader(reader)
+	if err != nil {
+		return nil, err
+	}
+
+	var whitelists map[string]TokenWhitelist
+	for reader.HasMore() {
+		sectionID, err := reader.ReadByte()
+		if err != nil {
+			return nil, err
+		}
+		size, err := reader.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		content, err := reader.ReadBytes(size)
+		if err != nil {
+			return nil, err
+		}
+		if sectionID != sectionCustom {
+			continue
+		}
+
+		sectionReader := wasmreader.NewReader(content)
+		name, err := sectionReader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: custom section: %v", ErrMalformedSection, err)
+		}
+		if name != PaymentTokensSectionName {
+			continue
+		}
+		if whitelists != nil {
+			return nil, fmt.Errorf("%w: duplicated section", ErrMalformedPaymentTokens)
+		}
+		whitelists, err = readPaymentTokensEntries(sectionReader)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return whitelists, nil
+}
+
+func readPaymentTokensEntries(reader *wasmreader.Reader) (map[string]TokenWhitelist, error) {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrMalformedPaymentTokens, err)
+	}
+
+	whitelists := make(map[string]TokenWhitelist)
+	for i := uint32(0); i < count; i++ {
+		name, err := reader.ReadName()
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformedPaymentTokens, err)
+		}
+		whitelist, err := readTokenWhitelist(reader)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %s: %v", ErrMalformedPaymentTokens, name, err)
+		}
+		_, duplicated := whitelists[name]
+		if duplicated {
+			return nil, fmt.Errorf("%w: duplicated endpoint %s", ErrMalformedPaymentTokens, name)
+		}
+		whitelists[name] = whitelist
+	}
+	if reader.HasMore() {
+		return nil, fmt.Errorf("%w: trailing bytes", ErrMalformedPaymentTokens)
+	}
+
+	return whitelists, nil
+}
+
+func readTokenWhitelist(reader *wasmreader.Reader) (TokenWhitelist, error) {
+	numTokens, err := reader.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+
+	whitelist := make(TokenWhitelist)
+	for i := uint32(0); i < numTokens; i++ {
+		tokenIdentifier, err := reader.ReadName()
+		if err != nil {
+			return nil, err
+		}
+		if len(tokenIdentifier) == 0 {
+			return nil, errors.New("empty token identifier")
+		}
+		if whitelist.Accepts([]byte(tokenIdentifier)) {
+			return nil, fmt.Errorf("duplicated token %s", tokenIdentifier)
+		}
+		whitelist[tokenIdentifier] = struct{}{}
+	}
+
+	return whitelist, nil
+}
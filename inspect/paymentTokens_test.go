@@ -0,0 +1,85 @@
+package inspect
+
+import (
+	"errors"
+	"math/big"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+var paymentTokensEntries = []byte{
+	0x02,
+	// deposit: EGLD and TKN-0001
+	0x07, 'd', 'e', 'p', 'o', 's', 'i', 't', 0x02,
+	0x04, 'E', 'G', 'L', 'D',
+	0x08, 'T', 'K', 'N', '-', '0', '0', '0', '1',
+	// view: no payment
+	0x04, 'v', 'i', 'e', 'w', 0x00,
+}
+
+func TestReadPaymentTokens(t *testing.T) {
+	code := makeModule(
+		makeCustomSection("name", []byte{0x01, 0x02}),
+		makeCustomSection(PaymentTokensSectionName, paymentTokensEntries),
+	)
+
+	whitelists, err := ReadPaymentTokens(code)
+	require.Nil(t, err)
+	require.Equal(t, map[string]TokenWhitelist{
+		"deposit": {EGLDTokenIdentifier: {}, "TKN-0001": {}},
+		"view":    {},
+	}, whitelists)
+
+	report, err := Inspect(code, nil)
+	require.Nil(t, err)
+	require.Equal(t, whitelists, report.EndpointPaymentTokens)
+
+	whitelists, err = ReadPaymentTokens(makeModule(makeCustomSection("name", []byte{0x01})))
+	require.Nil(t, err)
+	require.Nil(t, whitelists)
+}
+
+func TestReadPaymentTokens_MalformedSection(t *testing.T) {
+	section := makeCustomSection(PaymentTokensSectionName, paymentTokensEntries)
+	_, err := ReadPaymentTokens(makeModule(section, section))
+	require.True(t, errors.Is(err, ErrMalformedPaymentTokens))
+
+	for _, entries := range [][]byte{
+		// empty token identifier
+		{0x01, 0x01, 'f', 0x01, 0x00},
+		// duplicated token
+		{0x01, 0x01, 'f', 0x02, 0x01, 'T', 0x01, 'T'},
+		// duplicated endpoint
+		{0x02, 0x01, 'f', 0x00, 0x01, 'f', 0x00},
+		// truncated tokens
+		{0x01, 0x01, 'f', 0x05, 0x01, 'T'},
+		// trailing bytes
+		{0x01, 0x01, 'f', 0x00, 0x00},
+	} {
+		_, err = ReadPaymentTokens(makeModule(makeCustomSection(PaymentTokensSectionName, entries)))
+		require.True(t, errors.Is(err, ErrMalformedPaymentTokens), "entries %x", entries)
+
+		_, err = Inspect(makeModule(makeCustomSection(PaymentTokensSectionName, entries)), nil)
+		require.True(t, errors.Is(err, ErrMalformedSection), "entries %x", entries)
+	}
+}
+
+func TestTokenWhitelist_Validate(t *testing.T) {
+	whitelist := TokenWhitelist{EGLDTokenIdentifier: {}, "TKN-0001": {}}
+
+	require.Nil(t, whitelist.Validate(nil, nil))
+	require.Nil(t, whitelist.Validate(big.NewInt(10), [][]byte{[]byte("TKN-0001"), []byte("TKN-0001")}))
+
+	err := whitelist.Validate(big.NewInt(0), [][]byte{[]byte("TKN-0001"), []byte("TKN-0002")})
+	require.True(t, errors.Is(err, ErrPaymentNotAccepted))
+
+	notPayable := TokenWhitelist{}
+	require.Nil(t, notPayable.Validate(big.NewInt(0), nil))
+	err = notPayable.Validate(big.NewInt(1), nil)
+	require.True(t, errors.Is(err, ErrPaymentNotAccepted))
+
+	tokensOnly := TokenWhitelist{"TKN-0001": {}}
+	err = tokensOnly.Validate(big.NewInt(1), [][]byte{[]byte("TKN-0001")})
+	require.True(t, errors.Is(err, ErrPaymentNotAccepted))
+}
@@ -0,0 +1,850 @@
+package inspect
+
+import (
+	"fmt"
+	"math"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// StackHeightExceededFunction is the host function imported by the
+// instrumented contracts, which they call when a call would exceed the
+// maximum stack height; it is expected to fail the execution
+const StackHeightExceededFunction = "stackHeightExceeded"
+
+const (
+	opcodeUnreachable  = 0x00
+	opcodeIf           = 0x04
+	opcodeCall         = 0x10
+	opcodeCallIndirect = 0x11
+	opcodeGlobalSet    = 0x24
+	opcodeLocalGet     = 0x20
+	opcodeI32GtU       = 0x4b
+	opcodeI32Add       = 0x6a
+	opcodeI32Sub       = 0x6b
+	globalMutable      = 0x01
+	nameSectionName    = "name"
+)
+
+// sectionOrder is the position of each known section in a binary; the data
+// count section comes before the code section despite its greater id
+var sectionOrder = map[byte]int{
+	sectionType:      1,
+	sectionImport:    2,
+	sectionFunction:  3,
+	sectionTable:     4,
+	sectionMemory:    5,
+	sectionGlobal:    6,
+	sectionExport:    7,
+	sectionStart:     8,
+	sectionElement:   9,
+	sectionDataCount: 10,
+	sectionCode:      11,
+	sectionData:      12,
+}
+
+type rawSection struct {
+	id      byte
+	content []byte
+}
+
+// stackHeightInstrumenter rewrites a contract binary so that every call
+// between its own functions adds the frame of the callee to a stack height
+// kept in a new global, and fails once it exceeds the limit
+type stackHeightInstrumenter struct {
+	maxStackHeight     uint32
+	sections           []*rawSection
+	types              []*FunctionType
+	functionTypes      []uint32
+	numImportedFuncs   uint32
+	numImportedGlobals uint32
+	frameCosts         []uint32
+	maxFrameCost       uint32
+	exportedFunctions  []uint32
+	thunks             map[uint32]uint32
+	trapType           uint32
+	heightGlobal       uint32
+}
+
+// InstrumentStackHeight returns a copy of the contract binary whose calls
+// between its own functions count the frames they push, as one slot for the
+// call plus one for each of the parameters and locals of the callee, and
+// call the StackHeightExceededFunction host function once the frames of the
+// nested calls outgrow maxStackHeight. The endpoints reset the count when
+// called by the VM, so that a warm instance does not inherit the count of a
+// failed execution. Calls to host functions are not counted. The name
+// section is dropped, since the function indices change.
+func InstrumentStackHeight(code []byte, maxStackHeight uint32) ([]byte, error) {
+	instrumenter := &stackHeightInstrumenter{
+		maxStackHeight: maxStackHeight,
+		sections:       make([]*rawSection, 0),
+		types:          make([]*FunctionType, 0),
+		functionTypes:  make([]uint32, 0),
+		frameCosts:     make([]uint32, 0),
+		thunks:         make(map[uint32]uint32),
+	}
+
+	err := instrumenter.readSections(code)
+	if err != nil {
+		return nil, err
+	}
+	err = instrumenter.prepare()
+	if err != nil {
+		return nil, err
+	}
+	return instrumenter.write()
+}
+
+func (instrumenter *stackHeightInstrumenter) readSections(code []byte) error {
+	reader := wasmreader.NewReader(code)
+	err := (&moduleInspector{}).readHeader(reader)
+	if err != nil {
+		return err
+	}
+
+	for reader.HasMore() {
+		sectionID, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		size, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		content, err := reader.ReadBytes(size)
+		if err != nil {
+			return err
+		}
+
+		err = instrumenter.readSection(sectionID, content)
+		if err != nil {
+			return fmt.Errorf("%w: section id %d: %v", ErrMalformedSection, sectionID, err)
+		}
+		instrumenter.sections = append(instrumenter.sections, &rawSection{id: sectionID, content: content})
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) readSection(sectionID byte, content []byte) error {
+	reader := wasmreader.NewReader(content)
+	switch sectionID {
+	case sectionType:
+		return instrumenter.readTypes(reader)
+	case sectionImport:
+		return instrumenter.readImports(reader)
+	case sectionFunction:
+		return instrumenter.readFunctions(reader)
+	case sectionCode:
+		return instrumenter.readFrameCosts(reader)
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) readTypes(reader *wasmreader.Reader) error {
+	inspector := &moduleInspector{report: &ContractReport{}}
+	err := inspector.readTypeSection(reader)
+	if err != nil {
+		return err
+	}
+	instrumenter.types = inspector.types
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) readImports(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		for j := 0; j < 2; j++ {
+			_, err = reader.ReadName()
+			if err != nil {
+				return err
+			}
+		}
+		kind, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+
+		switch ExternalKind(kind) {
+		case ExternalFunction:
+			typeIndex, err := reader.ReadU32()
+			if err != nil {
+				return err
+			}
+			instrumenter.functionTypes = append(instrumenter.functionTypes, typeIndex)
+			instrumenter.numImportedFuncs++
+		case ExternalTable:
+			err = reader.Skip(1)
+			if err == nil {
+				_, err = readLimits(reader)
+			}
+		case ExternalMemory:
+			_, err = readLimits(reader)
+		case ExternalGlobal:
+			err = reader.Skip(2)
+			instrumenter.numImportedGlobals++
+		default:
+			return fmt.Errorf("unknown import kind %d", kind)
+		}
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) readFunctions(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		typeIndex, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		instrumenter.functionTypes = append(instrumenter.functionTypes, typeIndex)
+	}
+	return nil
+}
+
+// readFrameCosts counts the locals of every function body; the parameters
+// are added once all the types are known
+func (instrumenter *stackHeightInstrumenter) readFrameCosts(reader *wasmreader.Reader) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		bodySize, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		body, err := reader.ReadBytes(bodySize)
+		if err != nil {
+			return err
+		}
+
+		numLocals, _, err := readLocals(wasmreader.NewReader(body))
+		if err != nil {
+			return fmt.Errorf("function body %d: %w", i, err)
+		}
+		instrumenter.frameCosts = append(instrumenter.frameCosts, clampFrameCost(numLocals))
+	}
+	return nil
+}
+
+// readLocals decodes the local declarations of a function body and returns
+// the number of locals and the length of the declarations
+func readLocals(reader *wasmreader.Reader) (uint64, int, error) {
+	numLocalGroups, err := reader.ReadU32()
+	if err != nil {
+		return 0, 0, err
+	}
+	numLocals := uint64(0)
+	for i := uint32(0); i < numLocalGroups; i++ {
+		groupSize, err := reader.ReadU32()
+		if err != nil {
+			return 0, 0, err
+		}
+		err = reader.Skip(1)
+		if err != nil {
+			return 0, 0, err
+		}
+		numLocals += uint64(groupSize)
+	}
+	return numLocals, reader.Offset(), nil
+}
+
+func clampFrameCost(cost uint64) uint32 {
+	if cost > math.MaxInt32 {
+		return math.MaxInt32
+	}
+	return uint32(cost)
+}
+
+// prepare completes the frame costs and assigns the indices of the new
+// function type, global and endpoint thunks
+func (instrumenter *stackHeightInstrumenter) prepare() error {
+	numDefinedFuncs := uint32(len(instrumenter.functionTypes)) - instrumenter.numImportedFuncs
+	if uint32(len(instrumenter.frameCosts)) != numDefinedFuncs {
+		return fmt.Errorf("%w: %d function bodies for %d functions", ErrMalformedSection, len(instrumenter.frameCosts), numDefinedFuncs)
+	}
+
+	for i := range instrumenter.frameCosts {
+		functionType, err := instrumenter.getType(instrumenter.functionTypes[instrumenter.numImportedFuncs+uint32(i)])
+		if err != nil {
+			return err
+		}
+		cost := clampFrameCost(1 + uint64(functionType.NumParams) + uint64(instrumenter.frameCosts[i]))
+		instrumenter.frameCosts[i] = cost
+		if cost > instrumenter.maxFrameCost {
+			instrumenter.maxFrameCost = cost
+		}
+	}
+	for _, typeIndex := range instrumenter.functionTypes[:instrumenter.numImportedFuncs] {
+		_, err := instrumenter.getType(typeIndex)
+		if err != nil {
+			return err
+		}
+	}
+
+	instrumenter.trapType = uint32(len(instrumenter.types))
+	instrumenter.heightGlobal = instrumenter.numImportedGlobals + instrumenter.numDefinedGlobals()
+
+	err := instrumenter.readExportedFunctions()
+	if err != nil {
+		return err
+	}
+	nextFunction := instrumenter.numImportedFuncs + 1 + numDefinedFuncs
+	for _, function := range instrumenter.exportedFunctions {
+		_, ok := instrumenter.thunks[function]
+		if ok || function < instrumenter.numImportedFuncs {
+			continue
+		}
+		instrumenter.thunks[function] = nextFunction
+		nextFunction++
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) getType(typeIndex uint32) (*FunctionType, error) {
+	if typeIndex >= uint32(len(instrumenter.types)) {
+		return nil, fmt.Errorf("type index %d out of range", typeIndex)
+	}
+	return instrumenter.types[typeIndex], nil
+}
+
+func (instrumenter *stackHeightInstrumenter) numDefinedGlobals() uint32 {
+	section := instrumenter.findSection(sectionGlobal)
+	if section == nil {
+		return 0
+	}
+	count, _ := wasmreader.NewReader(section.content).readU32()
+	return count
+}
+
+func (instrumenter *stackHeightInstrumenter) findSection(sectionID byte) *rawSection {
+	for _, section := range instrumenter.sections {
+		if section.id == sectionID {
+			return section
+		}
+	}
+	return nil
+}
+
+// readExportedFunctions collects the exported functions, in the order of
+// their first export, so that the thunks are laid out deterministically
+func (instrumenter *stackHeightInstrumenter) readExportedFunctions() error {
+	section := instrumenter.findSection(sectionExport)
+	if section == nil {
+		return nil
+	}
+
+	reader := wasmreader.NewReader(section.content)
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		_, err = reader.ReadName()
+		if err != nil {
+			return err
+		}
+		kind, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		index, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		if ExternalKind(kind) != ExternalFunction {
+			continue
+		}
+		if index >= uint32(len(instrumenter.functionTypes)) {
+			return fmt.Errorf("%w: exported function has index %d out of range", ErrMalformedSection, index)
+		}
+		instrumenter.exportedFunctions = append(instrumenter.exportedFunctions, index)
+	}
+	return nil
+}
+
+// shiftFunction maps an original function index to its index in the
+// instrumented binary, where the new import precedes the defined functions
+func (instrumenter *stackHeightInstrumenter) shiftFunction(index uint32) uint32 {
+	if index < instrumenter.numImportedFuncs {
+		return index
+	}
+	return index + 1
+}
+
+func (instrumenter *stackHeightInstrumenter) trapFunction() uint32 {
+	return instrumenter.numImportedFuncs
+}
+
+func (instrumenter *stackHeightInstrumenter) write() ([]byte, error) {
+	writer := &wasmWriter{}
+	writer.writeBytes(wasmMagic)
+	writer.writeBytes(wasmVersion)
+
+	missing := []byte{sectionType, sectionImport, sectionGlobal}
+	for _, section := range instrumenter.sections {
+		if section.id != sectionCustom {
+			missing = instrumenter.writeMissingSections(writer, missing, sectionOrder[section.id])
+		}
+
+		content, keep, err := instrumenter.rewriteSection(section)
+		if err != nil {
+			return nil, fmt.Errorf("%w: section id %d: %v", ErrMalformedSection, section.id, err)
+		}
+		if keep {
+			writer.writeSection(section.id, content)
+		}
+	}
+	instrumenter.writeMissingSections(writer, missing, len(sectionOrder)+1)
+
+	return writer.data, nil
+}
+
+// writeMissingSections writes empty versions of the sections which are to be
+// extended but are absent from the binary, once the position of the next
+// section reaches them, and returns those still missing
+func (instrumenter *stackHeightInstrumenter) writeMissingSections(writer *wasmWriter, missing []byte, nextOrder int) []byte {
+	stillMissing := make([]byte, 0, len(missing))
+	for _, sectionID := range missing {
+		if instrumenter.findSection(sectionID) != nil {
+			continue
+		}
+		if sectionOrder[sectionID] >= nextOrder {
+			stillMissing = append(stillMissing, sectionID)
+			continue
+		}
+
+		content, _, _ := instrumenter.rewriteSection(&rawSection{id: sectionID, content: []byte{0x00}})
+		writer.writeSection(sectionID, content)
+	}
+	return stillMissing
+}
+
+func (instrumenter *stackHeightInstrumenter) rewriteSection(section *rawSection) ([]byte, bool, error) {
+	reader := wasmreader.NewReader(section.content)
+	writer := &wasmWriter{}
+
+	var err error
+	switch section.id {
+	case sectionCustom:
+		name, err := reader.ReadName()
+		return section.content, err == nil && name != nameSectionName, nil
+	case sectionType:
+		err = appendToVector(reader, writer, 1, []byte{functionTypeForm, 0x00, 0x00})
+	case sectionImport:
+		trapImport := &wasmWriter{}
+		trapImport.writeName(HostFunctionsModule)
+		trapImport.writeName(StackHeightExceededFunction)
+		trapImport.writeByte(byte(ExternalFunction))
+		trapImport.writeU32(instrumenter.trapType)
+		err = appendToVector(reader, writer, 1, trapImport.data)
+	case sectionFunction:
+		err = appendToVector(reader, writer, uint32(len(instrumenter.thunks)), instrumenter.thunkTypes())
+	case sectionGlobal:
+		err = instrumenter.rewriteGlobals(reader, writer)
+	case sectionExport:
+		err = instrumenter.rewriteExports(reader, writer)
+	case sectionStart:
+		var start uint32
+		start, err = reader.ReadU32()
+		writer.writeU32(instrumenter.shiftFunction(start))
+	case sectionElement:
+		err = instrumenter.rewriteElements(reader, writer)
+	case sectionCode:
+		err = instrumenter.rewriteCode(reader, writer)
+	default:
+		return section.content, true, nil
+	}
+	return writer.data, true, err
+}
+
+// appendToVector copies a vector of entries and appends the given number of
+// entries, already encoded
+func appendToVector(reader *wasmreader.Reader, writer *wasmWriter, numAppended uint32, appended []byte) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	writer.writeU32(count + numAppended)
+	writer.writeBytes(reader.Rest())
+	writer.writeBytes(appended)
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) thunkTypes() []byte {
+	writer := &wasmWriter{}
+	for _, function := range instrumenter.thunkedFunctions() {
+		writer.writeU32(instrumenter.functionTypes[function])
+	}
+	return writer.data
+}
+
+// thunkedFunctions returns the exported functions which get a thunk, in the
+// order of their thunks
+func (instrumenter *stackHeightInstrumenter) thunkedFunctions() []uint32 {
+	functions := make([]uint32, len(instrumenter.thunks))
+	firstThunk := uint32(len(instrumenter.functionTypes)) + 1
+	for function, thunk := range instrumenter.thunks {
+		functions[thunk-firstThunk] = function
+	}
+	return functions
+}
+
+func (instrumenter *stackHeightInstrumenter) rewriteGlobals(reader *wasmreader.Reader, writer *wasmWriter) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	writer.writeU32(count + 1)
+	for i := uint32(0); i < count; i++ {
+		globalType, err := reader.ReadBytes(2)
+		if err != nil {
+			return err
+		}
+		writer.writeBytes(globalType)
+		err = instrumenter.rewriteConstExpr(reader, writer)
+		if err != nil {
+			return err
+		}
+	}
+
+	writer.writeBytes([]byte{valueTypeI32, globalMutable, opcodeI32Const, 0x00, opcodeEnd})
+	return nil
+}
+
+// rewriteExports points the exported functions to their thunks
+func (instrumenter *stackHeightInstrumenter) rewriteExports(reader *wasmreader.Reader, writer *wasmWriter) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	writer.writeU32(count)
+	for i := uint32(0); i < count; i++ {
+		name, err := reader.ReadName()
+		if err != nil {
+			return err
+		}
+		kind, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		index, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+
+		if ExternalKind(kind) == ExternalFunction {
+			thunk, ok := instrumenter.thunks[index]
+			if ok {
+				index = thunk
+			}
+		}
+		writer.writeName(name)
+		writer.writeByte(kind)
+		writer.writeU32(index)
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) rewriteElements(reader *wasmreader.Reader, writer *wasmWriter) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	writer.writeU32(count)
+	for i := uint32(0); i < count; i++ {
+		flags, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		if flags > 7 {
+			return fmt.Errorf("unknown element segment flags %d", flags)
+		}
+		writer.writeU32(flags)
+
+		err = instrumenter.rewriteElementSegment(reader, writer, flags)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) rewriteElementSegment(reader *wasmreader.Reader, writer *wasmWriter, flags uint32) error {
+	isPassiveOrDeclarative := flags&0x01 != 0
+	hasTableIndex := flags&0x02 != 0
+	usesExpressions := flags&0x04 != 0
+
+	if hasTableIndex && !isPassiveOrDeclarative {
+		tableIndex, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		writer.writeU32(tableIndex)
+	}
+	if !isPassiveOrDeclarative {
+		err := instrumenter.rewriteConstExpr(reader, writer)
+		if err != nil {
+			return err
+		}
+	}
+	if isPassiveOrDeclarative || hasTableIndex {
+		elementKind, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+		writer.writeByte(elementKind)
+	}
+
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	writer.writeU32(count)
+	for i := uint32(0); i < count; i++ {
+		if usesExpressions {
+			err = instrumenter.rewriteConstExpr(reader, writer)
+			if err != nil {
+				return err
+			}
+			continue
+		}
+
+		function, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		writer.writeU32(instrumenter.shiftFunction(function))
+	}
+	return nil
+}
+
+// rewriteConstExpr copies a constant expression, shifting the functions it references
+func (instrumenter *stackHeightInstrumenter) rewriteConstExpr(reader *wasmreader.Reader, writer *wasmWriter) error {
+	start := reader.Offset()
+	err := skipConstExpr(reader)
+	if err != nil {
+		return err
+	}
+
+	exprReader := wasmreader.NewReader(reader.Since(start))
+	for exprReader.HasMore() {
+		err = instrumenter.rewriteInstruction(exprReader, writer)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) rewriteCode(reader *wasmreader.Reader, writer *wasmWriter) error {
+	count, err := reader.ReadU32()
+	if err != nil {
+		return err
+	}
+	writer.writeU32(count + uint32(len(instrumenter.thunks)))
+	for i := uint32(0); i < count; i++ {
+		bodySize, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		body, err := reader.ReadBytes(bodySize)
+		if err != nil {
+			return err
+		}
+
+		rewrittenBody, err := instrumenter.rewriteFunctionBody(body)
+		if err != nil {
+			return fmt.Errorf("function body %d: %w", i, err)
+		}
+		writer.writeU32(uint32(len(rewrittenBody)))
+		writer.writeBytes(rewrittenBody)
+	}
+
+	for _, function := range instrumenter.thunkedFunctions() {
+		thunkBody := instrumenter.thunkBody(function)
+		writer.writeU32(uint32(len(thunkBody)))
+		writer.writeBytes(thunkBody)
+	}
+	return nil
+}
+
+func (instrumenter *stackHeightInstrumenter) rewriteFunctionBody(body []byte) ([]byte, error) {
+	reader := wasmreader.NewReader(body)
+	_, localsLength, err := readLocals(reader)
+	if err != nil {
+		return nil, err
+	}
+
+	writer := &wasmWriter{}
+	writer.writeBytes(body[:localsLength])
+	for reader.HasMore() {
+		err = instrumenter.rewriteInstruction(reader, writer)
+		if err != nil {
+			return nil, err
+		}
+	}
+	return writer.data, nil
+}
+
+// rewriteInstruction copies an instruction, shifting the functions it
+// references and surrounding the calls between defined functions with the
+// accounting of the stack height
+func (instrumenter *stackHeightInstrumenter) rewriteInstruction(reader *wasmreader.Reader, writer *wasmWriter) error {
+	start := reader.Offset()
+	opcode, err := reader.ReadByte()
+	if err != nil {
+		return err
+	}
+
+	switch opcode {
+	case opcodeCall, opcodeRefFunc:
+		function, err := reader.ReadU32()
+		if err != nil {
+			return err
+		}
+		if opcode == opcodeRefFunc || function < instrumenter.numImportedFuncs {
+			writer.writeByte(opcode)
+			writer.writeU32(instrumenter.shiftFunction(function))
+			return nil
+		}
+		if function >= uint32(len(instrumenter.functionTypes)) {
+			return fmt.Errorf("called function %d out of range", function)
+		}
+
+		call := &wasmWriter{}
+		call.writeByte(opcodeCall)
+		call.writeU32(instrumenter.shiftFunction(function))
+		instrumenter.writeMeteredCall(writer, instrumenter.frameCosts[function-instrumenter.numImportedFuncs], call.data)
+		return nil
+	case opcodeCallIndirect:
+		_, err = scanInstruction(reader, opcode)
+		if err != nil {
+			return err
+		}
+		instrumenter.writeMeteredCall(writer, instrumenter.maxFrameCost, reader.Since(start))
+		return nil
+	}
+
+	_, err = scanInstruction(reader, opcode)
+	if err != nil {
+		return err
+	}
+	writer.writeBytes(reader.Since(start))
+	return nil
+}
+
+// writeMeteredCall adds the frame cost to the stack height, fails if the
+// height exceeds the limit, makes the call and then removes the frame cost;
+// the added instructions leave the operands of the call untouched
+func (instrumenter *stackHeightInstrumenter) writeMeteredCall(writer *wasmWriter, frameCost uint32, call []byte) {
+	writer.writeGlobalGet(instrumenter.heightGlobal)
+	writer.writeI32Const(frameCost)
+	writer.writeByte(opcodeI32Add)
+	writer.writeGlobalSet(instrumenter.heightGlobal)
+
+	writer.writeGlobalGet(instrumenter.heightGlobal)
+	writer.writeI32Const(instrumenter.maxStackHeight)
+	writer.writeByte(opcodeI32GtU)
+	writer.writeBytes([]byte{opcodeIf, blockTypeEmpty})
+	writer.writeByte(opcodeCall)
+	writer.writeU32(instrumenter.trapFunction())
+	writer.writeByte(opcodeUnreachable)
+	writer.writeByte(opcodeEnd)
+
+	writer.writeBytes(call)
+
+	writer.writeGlobalGet(instrumenter.heightGlobal)
+	writer.writeI32Const(frameCost)
+	writer.writeByte(opcodeI32Sub)
+	writer.writeGlobalSet(instrumenter.heightGlobal)
+}
+
+// thunkBody resets the stack height and forwards the parameters to the
+// exported function
+func (instrumenter *stackHeightInstrumenter) thunkBody(function uint32) []byte {
+	functionType := instrumenter.types[instrumenter.functionTypes[function]]
+
+	writer := &wasmWriter{}
+	writer.writeU32(0)
+	writer.writeI32Const(0)
+	writer.writeGlobalSet(instrumenter.heightGlobal)
+	for i := 0; i < functionType.NumParams; i++ {
+		writer.writeByte(opcodeLocalGet)
+		writer.writeU32(uint32(i))
+	}
+	writer.writeByte(opcodeCall)
+	writer.writeU32(instrumenter.shiftFunction(function))
+	writer.writeByte(opcodeEnd)
+	return writer.data
+}
+
+// wasmWriter encodes the primitive values of the wasm binary format
+type wasmWriter struct {
+	data []byte
+}
+
+func (writer *wasmWriter) writeByte(value byte) {
+	writer.data = append(writer.data, value)
+}
+
+func (writer *wasmWriter) writeBytes(value []byte) {
+	writer.data = append(writer.data, value...)
+}
+
+func (writer *wasmWriter) writeU32(value uint32) {
+	for {
+		b := byte(value & 0x7f)
+		value >>= 7
+		if value == 0 {
+			writer.writeByte(b)
+			return
+		}
+		writer.writeByte(b | 0x80)
+	}
+}
+
+// writeI32Const encodes an i32.const whose immediate has the bits of the given value
+func (writer *wasmWriter) writeI32Const(value uint32) {
+	writer.writeByte(opcodeI32Const)
+	signed := int64(int32(value))
+	for {
+		b := byte(signed & 0x7f)
+		signed >>= 7
+		if (signed == 0 && b&0x40 == 0) || (signed == -1 && b&0x40 != 0) {
+			writer.writeByte(b)
+			return
+		}
+		writer.writeByte(b | 0x80)
+	}
+}
+
+func (writer *wasmWriter) writeGlobalGet(index uint32) {
+	writer.writeByte(opcodeGlobalGet)
+	writer.writeU32(index)
+}
+
+func (writer *wasmWriter) writeGlobalSet(index uint32) {
+	writer.writeByte(opcodeGlobalSet)
+	writer.writeU32(index)
+}
+
+func (writer *wasmWriter) writeName(name string) {
+	writer.writeU32(uint32(len(name)))
+	writer.writeBytes([]byte(name))
+}
+
+func (writer *wasmWriter) writeSection(sectionID byte, content []byte) {
+	writer.writeByte(sectionID)
+	writer.writeU32(uint32(len(content)))
+	writer.writeBytes(content)
+}
@@ -0,0 +1,88 @@
+package inspect
+
+import (
+	"errors"
+	"io/ioutil"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+func TestInstrumentStackHeight_RecursiveContract(t *testing.T) {
+	code, err := ioutil.ReadFile("../test/contracts/stack-recursion/output/stack-recursion.wasm")
+	require.Nil(t, err)
+
+	instrumented, err := InstrumentStackHeight(code, 1000)
+	require.Nil(t, err)
+
+	hostFunctions := vmcommon.FunctionNames{
+		"int64getArgument":          {},
+		"int64finish":               {},
+		StackHeightExceededFunction: {},
+	}
+	report, err := Inspect(instrumented, hostFunctions)
+	require.Nil(t, err)
+	require.Empty(t, report.UnknownImports())
+
+	importedFunctions := report.ImportedFunctions()
+	require.Len(t, importedFunctions, 3)
+	require.Equal(t, StackHeightExceededFunction, importedFunctions[2].Name)
+	require.Equal(t, &FunctionType{NumParams: 0, NumResults: 0}, importedFunctions[2].Type)
+
+	// the two functions of the contract and the thunk of its endpoint
+	require.Equal(t, 3, report.NumFunctions)
+	endpoint, ok := report.Endpoint("recurse")
+	require.True(t, ok)
+	require.Equal(t, uint32(5), endpoint.Index)
+	require.False(t, endpoint.IsReexport)
+
+	again, err := InstrumentStackHeight(code, 1000)
+	require.Nil(t, err)
+	require.Equal(t, instrumented, again)
+}
+
+func TestInstrumentStackHeight_AddsMissingSections(t *testing.T) {
+	code := []byte{
+		0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+		// function section: one function of type 0, without a type section
+		0x03, 0x02, 0x01, 0x00,
+		// export section: "run" function 0
+		0x07, 0x07, 0x01, 0x03, 'r', 'u', 'n', 0x00, 0x00,
+		// code section: one empty body
+		0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b,
+		// custom section "name"
+		0x00, 0x07, 0x04, 'n', 'a', 'm', 'e', 0x01, 0x02,
+	}
+
+	_, err := InstrumentStackHeight(code, 1000)
+	require.NotNil(t, err)
+
+	// type section: () -> ()
+	code = append(code[:8], append([]byte{0x01, 0x04, 0x01, 0x60, 0x00, 0x00}, code[8:]...)...)
+	instrumented, err := InstrumentStackHeight(code, 1000)
+	require.Nil(t, err)
+
+	report, err := Inspect(instrumented, nil)
+	require.Nil(t, err)
+	require.Equal(t, []string{HostFunctionsModule + "." + StackHeightExceededFunction}, report.UnknownImports())
+	require.Equal(t, 2, report.NumFunctions)
+	require.Empty(t, report.CustomSections)
+
+	endpoint, ok := report.Endpoint("run")
+	require.True(t, ok)
+	require.Equal(t, uint32(2), endpoint.Index)
+}
+
+func TestInstrumentStackHeight_InvalidBinaries(t *testing.T) {
+	_, err := InstrumentStackHeight([]byte("not wasm"), 1000)
+	require.Equal(t, ErrInvalidMagic, err)
+
+	missingBodies := []byte{
+		0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+		0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+		0x03, 0x02, 0x01, 0x00,
+	}
+	_, err = InstrumentStackHeight(missingBodies, 1000)
+	require.True(t, errors.Is(err, ErrMalformedSection))
+}
@@ -0,0 +1,77 @@
+package inspect
+
+import (
+	"errors"
+	"fmt"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// ErrInvalidConstExpr signals an unsupported instruction in a constant expression
+var ErrInvalidConstExpr = errors.New("invalid constant expression")
+
+const (
+	opcodeEnd       = 0x0b
+	opcodeGlobalGet = 0x23
+	opcodeI32Const  = 0x41
+	opcodeI64Const  = 0x42
+	opcodeF32Const  = 0x43
+	opcodeF64Const  = 0x44
+	opcodeRefNull   = 0xd0
+	opcodeRefFunc   = 0xd2
+)
+
+// readLimits decodes the limits of a memory or table
+func readLimits(reader *wasmreader.Reader) (*Limits, error) {
+	flags, err := reader.ReadByte()
+	if err != nil {
+		return nil, err
+	}
+
+	limits := &Limits{}
+	limits.Min, err = reader.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	if flags&0x01 != 0 {
+		limits.HasMax = true
+		limits.Max, err = reader.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+	}
+	return limits, nil
+}
+
+// skipConstExpr skips the constant expression used to initialize globals and
+// to place element and data segments
+func skipConstExpr(reader *wasmreader.Reader) error {
+	for {
+		opcode, err := reader.ReadByte()
+		if err != nil {
+			return err
+		}
+
+		switch opcode {
+		case opcodeEnd:
+			return nil
+		case opcodeI32Const:
+			err = reader.SkipSigned(32)
+		case opcodeI64Const:
+			err = reader.SkipSigned(64)
+		case opcodeF32Const:
+			err = reader.Skip(4)
+		case opcodeF64Const:
+			err = reader.Skip(8)
+		case opcodeGlobalGet, opcodeRefFunc:
+			_, err = reader.ReadU32()
+		case opcodeRefNull:
+			err = reader.Skip(1)
+		default:
+			return fmt.Errorf("%w: opcode 0x%02x", ErrInvalidConstExpr, opcode)
+		}
+		if err != nil {
+			return err
+		}
+	}
+}
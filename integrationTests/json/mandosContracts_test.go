@@ -32,6 +32,13 @@ func TestCErc20(t *testing.T) {
 		CheckNoError()
 }
 
+func TestCErc20Mintable(t *testing.T) {
+	MandosTest(t).
+		Folder("contracts/erc20/mandos").
+		Run().
+		CheckNoError()
+}
+
 func TestDigitalCash(t *testing.T) {
 	MandosTest(t).
 		Folder("digital-cash").
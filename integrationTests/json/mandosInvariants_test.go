@@ -0,0 +1,42 @@
+package vmjsonintegrationtest
+
+import (
+	"errors"
+	"fmt"
+	"math/big"
+	"testing"
+
+	am "github.com/ElrondNetwork/wasm-vm/arwenmandos"
+	mj "github.com/ElrondNetwork/wasm-vm/mandos-go/model"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+func TestMandos_InvariantViolation_MinimizedSteps(t *testing.T) {
+	sumBelowEight := func(world *worldmock.MockWorld) error {
+		for _, account := range world.AcctMap {
+			sum := big.NewInt(0).SetBytes(account.StorageValue("sum"))
+			if sum.Cmp(big.NewInt(8)) >= 0 {
+				return fmt.Errorf("sum is %s", sum)
+			}
+		}
+		return nil
+	}
+
+	mandosTest := MandosTest(t).
+		Folder("adder/mandos").
+		File("adder.scen.json").
+		WithInvariant("sum below 8", sumBelowEight).
+		Run()
+
+	var violationError *am.InvariantViolationError
+	require.True(t, errors.As(mandosTest.CurrentError(), &violationError))
+	require.Equal(t, "sum below 8", violationError.Violation.Name)
+
+	// the query does not change the world, so only the state, the deployment
+	// and the call which reaches the sum of 8 are kept
+	require.Len(t, violationError.MinimizedSteps, 3)
+	require.IsType(t, &mj.SetStateStep{}, violationError.MinimizedSteps[0])
+	require.IsType(t, &mj.TxStep{}, violationError.MinimizedSteps[1])
+	require.IsType(t, &mj.TxStep{}, violationError.MinimizedSteps[2])
+}
@@ -10,8 +10,10 @@ import (
 
 	logger "github.com/ElrondNetwork/elrond-go-logger"
 	am "github.com/ElrondNetwork/wasm-vm/arwenmandos"
+	"github.com/ElrondNetwork/wasm-vm/build"
 	executorwrapper "github.com/ElrondNetwork/wasm-vm/executor/wrapper"
 	mc "github.com/ElrondNetwork/wasm-vm/mandos-go/controller"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
 	"github.com/ElrondNetwork/wasm-vm/wasmer"
 	"github.com/stretchr/testify/require"
 )
@@ -35,6 +37,8 @@ type MandosTestBuilder struct {
 	singleFile     string
 	exclusions     []string
 	executorLogger *executorwrapper.StringLogger
+	invariantNames []string
+	invariants     []worldmock.WorldInvariant
 	currentError   error
 }
 
@@ -67,6 +71,12 @@ func (mtb *MandosTestBuilder) WithExecutorLogs() *MandosTestBuilder {
 	return mtb
 }
 
+func (mtb *MandosTestBuilder) WithInvariant(name string, invariant worldmock.WorldInvariant) *MandosTestBuilder {
+	mtb.invariantNames = append(mtb.invariantNames, name)
+	mtb.invariants = append(mtb.invariants, invariant)
+	return mtb
+}
+
 func (mtb *MandosTestBuilder) Run() *MandosTestBuilder {
 	executor, err := am.NewArwenTestExecutor()
 	require.Nil(mtb.t, err)
@@ -78,9 +88,13 @@ func (mtb *MandosTestBuilder) Run() *MandosTestBuilder {
 			wasmer.ExecutorFactory())
 	}
 
+	for i, invariant := range mtb.invariants {
+		executor.World.AddInvariant(mtb.invariantNames[i], invariant)
+	}
+
 	runner := mc.NewScenarioRunner(
 		executor,
-		mc.NewDefaultFileResolver(),
+		build.NewRebuildingFileResolver(mc.NewDefaultFileResolver()),
 	)
 
 	if len(mtb.singleFile) > 0 {
@@ -114,6 +128,10 @@ func (mtb *MandosTestBuilder) RequireError(expectedErrorMsg string) *MandosTestB
 	return mtb
 }
 
+func (mtb *MandosTestBuilder) CurrentError() error {
+	return mtb.currentError
+}
+
 func (mtb *MandosTestBuilder) CheckLog(expectedLogs string) *MandosTestBuilder {
 	require.NotNil(mtb.t, mtb.executorLogger)
 	actualLog := mtb.executorLogger.String()
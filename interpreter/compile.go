@@ -0,0 +1,272 @@
+package interpreter
+
+import (
+	"fmt"
+	"math"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+// functionEnd is the block index of the end instruction closing a function body
+const functionEnd = math.MaxUint32
+
+// instruction is an instruction of a compiled function body: the index is
+// that of the local, the global, the function, the type, the block, the
+// branch table or the branch depth the instruction refers to, and the value
+// holds the constants and the offsets of the memory accesses
+type instruction struct {
+	opcode uint16
+	index  uint32
+	value  uint64
+}
+
+// blockInfo is a block, a loop or an if of a compiled function body, with
+// the positions of its else and of its end in the compiled code
+type blockInfo struct {
+	opcode    byte
+	signature *functionType
+	params    int
+	results   int
+	elsePC    int
+	endPC     int
+	hasElse   bool
+}
+
+// compileFunction decodes the body of a function into instructions, checking
+// the indices they hold and the types of their operands, and resolving the
+// targets of the structured control instructions, so that the branches need
+// no search at runtime
+func (m *module) compileFunction(fn *function) error {
+	fnType := m.types[fn.typeIndex]
+	numLocals := uint32(len(fnType.params) + len(fn.locals))
+	r := wasmreader.NewReader(fn.body)
+	control := make([]uint32, 0)
+	fn.code = make([]instruction, 0, len(fn.body))
+
+	for r.HasMore() {
+		opcode, err := r.ReadByte()
+		if err != nil {
+			return err
+		}
+
+		pc := len(fn.code)
+		instr := instruction{opcode: uint16(opcode)}
+		switch {
+		case opcode == opUnreachable, opcode == opNop, opcode == opReturn, opcode == opDrop, opcode == opSelect:
+		case opcode == opBlock, opcode == opLoop, opcode == opIf:
+			info, err := m.readBlockType(r)
+			if err != nil {
+				return err
+			}
+			info.opcode = opcode
+			instr.index = uint32(len(fn.blocks))
+			fn.blocks = append(fn.blocks, info)
+			control = append(control, instr.index)
+		case opcode == opElse:
+			if len(control) == 0 || fn.blocks[control[len(control)-1]].opcode != opIf {
+				return fmt.Errorf("%w: else outside of an if", ErrInvalidModule)
+			}
+			instr.index = control[len(control)-1]
+			block := &fn.blocks[instr.index]
+			if block.hasElse {
+				return fmt.Errorf("%w: if with two else", ErrInvalidModule)
+			}
+			block.hasElse = true
+			block.elsePC = pc
+		case opcode == opEnd:
+			if len(control) == 0 {
+				if r.HasMore() {
+					return fmt.Errorf("%w: instructions after the end of the function", ErrInvalidModule)
+				}
+				instr.index = functionEnd
+				break
+			}
+			instr.index = control[len(control)-1]
+			control = control[:len(control)-1]
+			fn.blocks[instr.index].endPC = pc
+		case opcode == opBr, opcode == opBrIf:
+			instr.index, err = readDepth(r, len(control))
+		case opcode == opBrTable:
+			instr.index = uint32(len(fn.tables))
+			var table []uint32
+			table, err = readBranchTable(r, len(control))
+			fn.tables = append(fn.tables, table)
+		case opcode == opCall:
+			instr.index, err = r.ReadU32()
+			if err == nil && instr.index >= uint32(m.numFunctions()) {
+				err = fmt.Errorf("%w: call of unknown function %d", ErrInvalidModule, instr.index)
+			}
+		case opcode == opCallIndirect:
+			err = m.readCallIndirect(r, &instr)
+		case opcode >= opLocalGet && opcode <= opLocalTee:
+			instr.index, err = r.ReadU32()
+			if err == nil && instr.index >= numLocals {
+				err = fmt.Errorf("%w: unknown local %d", ErrInvalidModule, instr.index)
+			}
+		case opcode == opGlobalGet, opcode == opGlobalSet:
+			instr.index, err = r.ReadU32()
+			if err == nil {
+				err = m.checkGlobalAccess(opcode, instr.index)
+			}
+		case opcode >= opI32Load && opcode <= opI64Store32:
+			err = m.readMemoryArgument(r, &instr)
+		case opcode == opMemorySize, opcode == opMemoryGrow:
+			err = m.readMemoryIndex(r)
+		case opcode == opI32Const:
+			var value int64
+			value, err = r.ReadSigned(32)
+			instr.value = uint64(uint32(value))
+		case opcode == opI64Const:
+			var value int64
+			value, err = r.ReadSigned(64)
+			instr.value = uint64(value)
+		case opcode == opF32Const:
+			var bits uint32
+			bits, err = r.ReadFixedU32()
+			instr.value = uint64(bits)
+		case opcode == opF64Const:
+			instr.value, err = r.ReadFixedU64()
+		case opcode >= opI32Eqz && opcode <= opI64Extend32S:
+		case opcode == opPrefixMisc:
+			var subOpcode uint32
+			subOpcode, err = r.ReadU32()
+			if err == nil && subOpcode >= numOpcodes-opMiscBase {
+				err = fmt.Errorf("%w: opcode 0xfc 0x%02x", ErrUnsupportedFeature, subOpcode)
+			}
+			instr.opcode = uint16(opMiscBase + subOpcode)
+		default:
+			err = fmt.Errorf("%w: opcode 0x%02x", ErrUnsupportedFeature, opcode)
+		}
+		if err != nil {
+			return err
+		}
+
+		fn.code = append(fn.code, instr)
+	}
+
+	if len(fn.code) == 0 || fn.code[len(fn.code)-1].index != functionEnd || fn.code[len(fn.code)-1].opcode != opEnd {
+		return fmt.Errorf("%w: function body without end", ErrInvalidModule)
+	}
+	return m.checkOperandTypes(fn)
+}
+
+// readBlockType decodes the type of a block, a loop or an if, which is
+// either empty, a value type or the index of a function type
+func (m *module) readBlockType(r *wasmreader.Reader) (blockInfo, error) {
+	next, err := r.PeekByte()
+	if err != nil {
+		return blockInfo{}, err
+	}
+	if next == blockTypeEmpty {
+		_, err = r.ReadByte()
+		return blockInfo{signature: &functionType{}}, err
+	}
+	if next&0xc0 == 0x40 {
+		var resultType valueType
+		resultType, err = readValueType(r)
+		return blockInfo{signature: &functionType{results: []valueType{resultType}}, results: 1}, err
+	}
+
+	typeIndex, err := r.ReadSigned(33)
+	if err != nil {
+		return blockInfo{}, err
+	}
+	if typeIndex < 0 || typeIndex >= int64(len(m.types)) {
+		return blockInfo{}, fmt.Errorf("%w: block of unknown type %d", ErrInvalidModule, typeIndex)
+	}
+	blockType := m.types[typeIndex]
+	return blockInfo{signature: blockType, params: len(blockType.params), results: len(blockType.results)}, nil
+}
+
+// readDepth decodes the depth of a branch, which may target any of the open
+// blocks or the function body itself
+func readDepth(r *wasmreader.Reader, numOpenBlocks int) (uint32, error) {
+	depth, err := r.ReadU32()
+	if err != nil {
+		return 0, err
+	}
+	if depth > uint32(numOpenBlocks) {
+		return 0, fmt.Errorf("%w: branch of depth %d out of %d blocks", ErrInvalidModule, depth, numOpenBlocks)
+	}
+	return depth, nil
+}
+
+// readBranchTable decodes the depths of a br_table, the default one last
+func readBranchTable(r *wasmreader.Reader, numOpenBlocks int) ([]uint32, error) {
+	count, err := r.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	if count >= uint32(r.Remaining()) {
+		return nil, wasmreader.ErrUnexpectedEnd
+	}
+	table := make([]uint32, count+1)
+	for i := range table {
+		table[i], err = readDepth(r, numOpenBlocks)
+		if err != nil {
+			return nil, err
+		}
+	}
+	return table, nil
+}
+
+func (m *module) readCallIndirect(r *wasmreader.Reader, instr *instruction) error {
+	var err error
+	instr.index, err = r.ReadU32()
+	if err != nil {
+		return err
+	}
+	if instr.index >= uint32(len(m.types)) {
+		return fmt.Errorf("%w: indirect call of unknown type %d", ErrInvalidModule, instr.index)
+	}
+	tableIndex, err := r.ReadByte()
+	if err != nil {
+		return err
+	}
+	if tableIndex != 0 {
+		return fmt.Errorf("%w: indirect call through table %d", ErrUnsupportedFeature, tableIndex)
+	}
+	if m.table == nil {
+		return fmt.Errorf("%w: indirect call without a table", ErrInvalidModule)
+	}
+	return nil
+}
+
+func (m *module) checkGlobalAccess(opcode byte, index uint32) error {
+	if index >= uint32(len(m.globals)) {
+		return fmt.Errorf("%w: unknown global %d", ErrInvalidModule, index)
+	}
+	if opcode == opGlobalSet && !m.globals[index].mutable {
+		return fmt.Errorf("%w: immutable global %d set", ErrInvalidModule, index)
+	}
+	return nil
+}
+
+// readMemoryArgument decodes the alignment, which the interpreter ignores,
+// and the offset of a load or a store
+func (m *module) readMemoryArgument(r *wasmreader.Reader, instr *instruction) error {
+	if m.memory == nil {
+		return fmt.Errorf("%w: memory access without a memory", ErrInvalidModule)
+	}
+	_, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	offset, err := r.ReadU32()
+	instr.value = uint64(offset)
+	return err
+}
+
+func (m *module) readMemoryIndex(r *wasmreader.Reader) error {
+	if m.memory == nil {
+		return fmt.Errorf("%w: memory instruction without a memory", ErrInvalidModule)
+	}
+	memoryIndex, err := r.ReadByte()
+	if err != nil {
+		return err
+	}
+	if memoryIndex != 0 {
+		return fmt.Errorf("%w: memory %d", ErrUnsupportedFeature, memoryIndex)
+	}
+	return nil
+}
@@ -0,0 +1,67 @@
+package interpreter
+
+import (
+	"errors"
+	"fmt"
+)
+
+// ErrInvalidBytecode signals that the contract code is empty or is not a wasm module
+var ErrInvalidBytecode = errors.New("invalid bytecode")
+
+// ErrInvalidModule signals a wasm module whose sections are malformed or inconsistent
+var ErrInvalidModule = errors.New("invalid wasm module")
+
+// ErrUnsupportedFeature signals a construct which the interpreter does not execute
+var ErrUnsupportedFeature = errors.New("unsupported wasm feature")
+
+// ErrUnknownImport signals an import which is not a VM hook of the executor
+var ErrUnknownImport = errors.New("unknown import")
+
+// ErrImportSignatureMismatch signals an import whose type differs from the signature of its VM hook
+var ErrImportSignatureMismatch = errors.New("import signature mismatch")
+
+// ErrMissingVMHookAdapter signals a VM hook without an adapter generated for the interpreter
+var ErrMissingVMHookAdapter = errors.New("missing VM hook adapter")
+
+// ErrMemoryLimitExceeded signals a memory grown beyond its maximum
+var ErrMemoryLimitExceeded = errors.New("memory limit exceeded")
+
+// ErrInstanceCleaned signals the use of an instance after it was cleaned
+var ErrInstanceCleaned = errors.New("instance already cleaned")
+
+// ErrInvalidInstanceState signals the restore of a state saved by another instance
+var ErrInvalidInstanceState = errors.New("invalid instance state")
+
+// ErrTrap signals that the execution of the contract was aborted
+var ErrTrap = errors.New("trap")
+
+// ErrUnreachable signals the execution of the unreachable instruction
+var ErrUnreachable = fmt.Errorf("%w (unreachable)", ErrTrap)
+
+// ErrMemoryAccessOutOfBounds signals a load or a store outside the memory
+var ErrMemoryAccessOutOfBounds = fmt.Errorf("%w (memory access out of bounds)", ErrTrap)
+
+// ErrIntegerDivideByZero signals an integer division or remainder by zero
+var ErrIntegerDivideByZero = fmt.Errorf("%w (integer divide by zero)", ErrTrap)
+
+// ErrIntegerOverflow signals a signed division or a conversion whose result is out of range
+var ErrIntegerOverflow = fmt.Errorf("%w (integer overflow)", ErrTrap)
+
+// ErrInvalidConversion signals the conversion of a NaN to an integer
+var ErrInvalidConversion = fmt.Errorf("%w (invalid conversion to integer)", ErrTrap)
+
+// ErrUndefinedElement signals an indirect call through an empty or missing table element
+var ErrUndefinedElement = fmt.Errorf("%w (undefined element)", ErrTrap)
+
+// ErrIndirectCallTypeMismatch signals an indirect call of a function of another type
+var ErrIndirectCallTypeMismatch = fmt.Errorf("%w (indirect call type mismatch)", ErrTrap)
+
+// ErrCallStackExhausted signals calls nested deeper than the interpreter allows
+var ErrCallStackExhausted = fmt.Errorf("%w (call stack exhausted)", ErrTrap)
+
+// ErrMalformedCode signals a function body which does not type check, found while executing it
+var ErrMalformedCode = fmt.Errorf("%w (malformed code)", ErrTrap)
+
+// ErrBreakpoint signals that the execution stopped at a breakpoint set by a
+// VM hook or by the metering; the host reads the reason from the breakpoint value
+var ErrBreakpoint = fmt.Errorf("%w (breakpoint)", ErrTrap)
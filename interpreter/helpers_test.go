@@ -0,0 +1,181 @@
+package interpreter
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/stretchr/testify/require"
+)
+
+// testImport is a function imported from the "env" module by a test contract
+type testImport struct {
+	name    string
+	params  []valueType
+	results []valueType
+}
+
+// testFunction is a function defined by a test contract, exported under its
+// name unless the name is empty; the final end of its body is added
+type testFunction struct {
+	name    string
+	params  []valueType
+	results []valueType
+	locals  []valueType
+	body    []byte
+}
+
+// testModule describes a test contract: its imports, its functions, a number
+// of mutable i32 globals initialized to 0, and optionally one page of memory
+// exported as "memory"
+type testModule struct {
+	imports   []testImport
+	functions []testFunction
+	globals   int
+	memory    bool
+}
+
+func (tm testModule) bytes() []byte {
+	var types, imports, functions, exports, codes [][]byte
+	for _, imported := range tm.imports {
+		entry := appendName(nil, importModule)
+		entry = appendName(entry, imported.name)
+		entry = append(entry, externalFunction)
+		entry = appendU32(entry, uint32(len(types)))
+		imports = append(imports, entry)
+		types = append(types, encodeFunctionType(imported.params, imported.results))
+	}
+	for i, fn := range tm.functions {
+		functions = append(functions, appendU32(nil, uint32(len(types))))
+		types = append(types, encodeFunctionType(fn.params, fn.results))
+		if fn.name != "" {
+			entry := appendName(nil, fn.name)
+			entry = append(entry, externalFunction)
+			entry = appendU32(entry, uint32(len(tm.imports)+i))
+			exports = append(exports, entry)
+		}
+
+		body := appendU32(nil, uint32(len(fn.locals)))
+		for _, local := range fn.locals {
+			body = append(body, 1, byte(local))
+		}
+		body = append(body, fn.body...)
+		body = append(body, opEnd)
+		codes = append(codes, append(appendU32(nil, uint32(len(body))), body...))
+	}
+	if tm.memory {
+		exports = append(exports, append(appendName(nil, "memory"), externalMemory, 0))
+	}
+
+	code := append([]byte{}, wasmMagic...)
+	code = append(code, wasmVersion...)
+	code = appendSection(code, sectionType, types)
+	code = appendSection(code, sectionImport, imports)
+	code = appendSection(code, sectionFunction, functions)
+	if tm.memory {
+		code = appendSection(code, sectionMemory, [][]byte{{0x00, 0x01}})
+	}
+	var globals [][]byte
+	for i := 0; i < tm.globals; i++ {
+		globals = append(globals, []byte{byte(valueTypeI32), 1, opI32Const, 0, opEnd})
+	}
+	code = appendSection(code, sectionGlobal, globals)
+	code = appendSection(code, sectionExport, exports)
+	code = appendSection(code, sectionCode, codes)
+	return code
+}
+
+func encodeFunctionType(params []valueType, results []valueType) []byte {
+	encoded := []byte{functionTypeForm}
+	encoded = appendU32(encoded, uint32(len(params)))
+	for _, param := range params {
+		encoded = append(encoded, byte(param))
+	}
+	encoded = appendU32(encoded, uint32(len(results)))
+	for _, result := range results {
+		encoded = append(encoded, byte(result))
+	}
+	return encoded
+}
+
+// appendSection appends a section holding the given entries, unless there is none
+func appendSection(code []byte, id byte, entries [][]byte) []byte {
+	if len(entries) == 0 {
+		return code
+	}
+	content := appendU32(nil, uint32(len(entries)))
+	for _, entry := range entries {
+		content = append(content, entry...)
+	}
+	code = append(code, id)
+	code = appendU32(code, uint32(len(content)))
+	return append(code, content...)
+}
+
+func appendName(encoded []byte, name string) []byte {
+	encoded = appendU32(encoded, uint32(len(name)))
+	return append(encoded, name...)
+}
+
+func appendU32(encoded []byte, value uint32) []byte {
+	for {
+		next := byte(value & 0x7f)
+		value >>= 7
+		if value == 0 {
+			return append(encoded, next)
+		}
+		encoded = append(encoded, next|0x80)
+	}
+}
+
+// i32Const encodes the instruction pushing the given constant
+func i32Const(value int32) []byte {
+	encoded := []byte{opI32Const}
+	for {
+		next := byte(value & 0x7f)
+		value >>= 7
+		if (value == 0 && next&0x40 == 0) || (value == -1 && next&0x40 != 0) {
+			return append(encoded, next)
+		}
+		encoded = append(encoded, next|0x80)
+	}
+}
+
+func concat(parts ...[]byte) []byte {
+	var joined []byte
+	for _, part := range parts {
+		joined = append(joined, part...)
+	}
+	return joined
+}
+
+func newTestExecutor(t *testing.T, vmHooks executor.VMHooks) *InterpreterExecutor {
+	testExecutor, err := ExecutorFactory().CreateExecutor(executor.ExecutorFactoryArgs{VMHooks: vmHooks})
+	require.Nil(t, err)
+	return testExecutor.(*InterpreterExecutor)
+}
+
+func newTestInstance(t *testing.T, tm testModule, options executor.CompilationOptions) *InterpreterInstance {
+	instance, err := newTestExecutor(t, nil).NewInstanceWithOptions(tm.bytes(), options)
+	require.Nil(t, err)
+	return instance.(*InterpreterInstance)
+}
+
+// testVMHooks implements the VM hooks called by the test contracts; calling
+// any other VM hook panics
+type testVMHooks struct {
+	executor.VMHooks
+	gasLeft  int64
+	finished []int64
+}
+
+func (hooks *testVMHooks) GetGasLeft() int64 {
+	return hooks.gasLeft
+}
+
+func (hooks *testVMHooks) Int64finish(value int64) {
+	hooks.finished = append(hooks.finished, value)
+}
+
+func (hooks *testVMHooks) GetShardOfAddress(addressOffset int32) int32 {
+	return addressOffset - 1
+}
@@ -0,0 +1,9 @@
+// Package interpreter executes the contracts by interpreting their wasm
+// instructions, without compiling them to native code, for the platforms
+// which forbid the JIT compilation of the wasmer executor, and as a
+// reference to compare the wasmer executor against.
+package interpreter
+
+import "github.com/ElrondNetwork/wasm-vm/logging"
+
+var logInterpreter = logging.GetOrCreate(logging.SubsystemExecutor, "arwen/interpreter")
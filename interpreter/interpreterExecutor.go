@@ -0,0 +1,112 @@
+package interpreter
+
+import (
+	"fmt"
+	"unsafe"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ executor.Executor = (*InterpreterExecutor)(nil)
+
+// InterpreterExecutor oversees the creation of interpreter instances and execution.
+type InterpreterExecutor struct {
+	vmHookSignatures  map[string]*vmHook
+	boundVMHooks      map[string]*boundVMHook
+	eiFunctionNames   vmcommon.FunctionNames
+	vmHooks           executor.VMHooks
+	vmHooksPtr        uintptr
+	opcodeCosts       *opcodeCosts
+	localAllocateCost uint64
+}
+
+// CreateExecutor creates a new interpreter executor.
+func CreateExecutor() (*InterpreterExecutor, error) {
+	signatures, err := vmHookSignatures(executor.HostFunctionAliases)
+	if err != nil {
+		return nil, err
+	}
+	return &InterpreterExecutor{
+		vmHookSignatures: signatures,
+		boundVMHooks:     make(map[string]*boundVMHook),
+		eiFunctionNames:  functionNamesOf(signatures),
+		opcodeCosts:      newOpcodeCosts(nil),
+	}, nil
+}
+
+// SetOpcodeCosts sets the gas costs of the instructions executed by the
+// instances of the executor; unlike the costs of the wasmer executor, they
+// are not global.
+func (interpreterExecutor *InterpreterExecutor) SetOpcodeCosts(opcodeCosts *executor.WASMOpcodeCost) {
+	interpreterExecutor.opcodeCosts = newOpcodeCosts(opcodeCosts)
+	interpreterExecutor.localAllocateCost = 0
+	if opcodeCosts != nil {
+		interpreterExecutor.localAllocateCost = uint64(opcodeCosts.LocalAllocate)
+	}
+}
+
+// FunctionNames returns the import names of the VM hooks.
+func (interpreterExecutor *InterpreterExecutor) FunctionNames() vmcommon.FunctionNames {
+	return interpreterExecutor.eiFunctionNames
+}
+
+// NewInstanceWithOptions creates a new interpreter instance from WASM
+// bytecode, respecting the provided options
+func (interpreterExecutor *InterpreterExecutor) NewInstanceWithOptions(
+	contractCode []byte,
+	options executor.CompilationOptions,
+) (executor.Instance, error) {
+	decodedModule, err := decodeModule(contractCode)
+	if err != nil {
+		return nil, err
+	}
+
+	instance, err := newInstance(interpreterExecutor, decodedModule, contractCode, options)
+	if err != nil {
+		return nil, err
+	}
+	instance.SetVMHooksPtr(interpreterExecutor.vmHooksPtr)
+	return instance, nil
+}
+
+// NewInstanceFromCompiledCodeWithOptions creates a new interpreter instance
+// from the code returned by Cache, which is the bytecode; the code
+// compiled by other executors is rejected
+func (interpreterExecutor *InterpreterExecutor) NewInstanceFromCompiledCodeWithOptions(
+	compiledCode []byte,
+	options executor.CompilationOptions,
+) (executor.Instance, error) {
+	return interpreterExecutor.NewInstanceWithOptions(compiledCode, options)
+}
+
+// initVMHooks inits the VM hooks, binding their adapters once for all the
+// instances of the executor
+func (interpreterExecutor *InterpreterExecutor) initVMHooks(vmHooks executor.VMHooks) {
+	interpreterExecutor.vmHooks = vmHooks
+	interpreterExecutor.vmHooksPtr = uintptr(unsafe.Pointer(&interpreterExecutor.vmHooks))
+	interpreterExecutor.boundVMHooks = make(map[string]*boundVMHook)
+	if vmHooks == nil {
+		return
+	}
+
+	for name, hook := range interpreterExecutor.vmHookSignatures {
+		interpreterExecutor.boundVMHooks[name] = &boundVMHook{
+			vmHook:  hook,
+			vmHooks: vmHooks,
+		}
+	}
+}
+
+// boundVMHook returns the VM hook imported by a contract, after checking
+// that the import has the signature of the VM hook
+func (interpreterExecutor *InterpreterExecutor) boundVMHook(imported *importedFunction) (*boundVMHook, error) {
+	if imported.module != importModule {
+		return nil, fmt.Errorf("%w: %s.%s", ErrUnknownImport, imported.module, imported.name)
+	}
+	hook, ok := interpreterExecutor.boundVMHooks[imported.name]
+	if !ok {
+		return nil, fmt.Errorf("%w: %s", ErrUnknownImport, imported.name)
+	}
+	return hook, nil
+}
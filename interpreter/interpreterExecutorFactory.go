@@ -0,0 +1,27 @@
+package interpreter
+
+import "github.com/ElrondNetwork/wasm-vm/executor"
+
+// InterpreterExecutorFactory builds interpreter Executors.
+type InterpreterExecutorFactory struct{}
+
+// ExecutorFactory returns the interpreter executor factory.
+func ExecutorFactory() *InterpreterExecutorFactory {
+	return &InterpreterExecutorFactory{}
+}
+
+// CreateExecutor creates a new Executor instance; the options of the wasmer
+// executor are ignored.
+func (ief *InterpreterExecutorFactory) CreateExecutor(args executor.ExecutorFactoryArgs) (executor.Executor, error) {
+	executor, err := CreateExecutor()
+	if err != nil {
+		return nil, err
+	}
+	executor.initVMHooks(args.VMHooks)
+	if args.OpcodeCosts != nil {
+		// opcode costs are sometimes not initialized at this point in certain tests
+		executor.SetOpcodeCosts(args.OpcodeCosts)
+	}
+
+	return executor, nil
+}
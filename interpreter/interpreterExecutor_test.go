@@ -0,0 +1,108 @@
+package interpreter
+
+import (
+	"errors"
+	"math"
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/stretchr/testify/require"
+)
+
+var i64 = []valueType{valueTypeI64}
+
+func TestInterpreterExecutor_FunctionNames(t *testing.T) {
+	functionNames := newTestExecutor(t, nil).FunctionNames()
+	require.Contains(t, functionNames, "getGasLeft")
+	require.Contains(t, functionNames, "int64finish")
+	require.NotContains(t, functionNames, "GetGasLeft")
+}
+
+func TestInterpreterExecutor_VMHooks(t *testing.T) {
+	tm := testModule{
+		imports: []testImport{
+			{name: "getGasLeft", results: i64},
+			{name: "int64finish", params: i64},
+		},
+		functions: []testFunction{{name: "finishGasLeft", body: []byte{opCall, 0, opCall, 1}}},
+	}
+	vmHooks := &testVMHooks{gasLeft: 1234}
+	testExecutor := newTestExecutor(t, vmHooks)
+	instance, err := testExecutor.NewInstanceWithOptions(tm.bytes(), executor.CompilationOptions{})
+	require.Nil(t, err)
+	require.True(t, instance.IsFunctionImported("getGasLeft"))
+	require.False(t, instance.IsFunctionImported("finishGasLeft"))
+	require.Equal(t, testExecutor.vmHooksPtr, instance.GetVMHooksPtr())
+
+	err = instance.CallFunction("finishGasLeft")
+	require.Nil(t, err)
+	require.Equal(t, []int64{1234}, vmHooks.finished)
+}
+
+func TestInterpreterExecutor_VMHookAdapters(t *testing.T) {
+	require.Len(t, vmHookAdapters, vmHooksType.NumMethod())
+
+	vmHooks := &testVMHooks{gasLeft: -1}
+	require.Equal(t, uint64(math.MaxUint64), vmHookAdapters["getGasLeft"](vmHooks, nil))
+
+	result := vmHookAdapters["int64finish"](vmHooks, []uint64{math.MaxUint64})
+	require.Zero(t, result)
+	require.Equal(t, []int64{-1}, vmHooks.finished)
+
+	result = vmHookAdapters["getShardOfAddress"](vmHooks, []uint64{0xFFFFFFFF00000000})
+	require.Equal(t, uint64(math.MaxUint32), result)
+	result = vmHookAdapters["getShardOfAddress"](vmHooks, []uint64{0xFFFFFFFF00000005})
+	require.Equal(t, uint64(4), result)
+}
+
+func TestInterpreterExecutor_InvalidImports(t *testing.T) {
+	testExecutor := newTestExecutor(t, &testVMHooks{})
+
+	unknownImport := testModule{imports: []testImport{{name: "noSuchHook"}}}
+	_, err := testExecutor.NewInstanceWithOptions(unknownImport.bytes(), executor.CompilationOptions{})
+	require.True(t, errors.Is(err, ErrUnknownImport))
+
+	wrongSignature := testModule{imports: []testImport{{name: "getGasLeft", results: i32}}}
+	_, err = testExecutor.NewInstanceWithOptions(wrongSignature.bytes(), executor.CompilationOptions{})
+	require.True(t, errors.Is(err, ErrImportSignatureMismatch))
+}
+
+func TestInterpreterExecutor_InvalidBytecode(t *testing.T) {
+	testExecutor := newTestExecutor(t, nil)
+
+	_, err := testExecutor.NewInstanceWithOptions([]byte{1, 2, 3, 4, 5, 6, 7, 8}, executor.CompilationOptions{})
+	require.True(t, errors.Is(err, ErrInvalidBytecode))
+
+	truncated := factorialModule.bytes()
+	truncated = truncated[:len(truncated)-3]
+	_, err = testExecutor.NewInstanceWithOptions(truncated, executor.CompilationOptions{})
+	require.NotNil(t, err)
+
+	branchTooDeep := testModule{functions: []testFunction{{name: "branch", body: []byte{opBr, 5}}}}
+	_, err = testExecutor.NewInstanceWithOptions(branchTooDeep.bytes(), executor.CompilationOptions{})
+	require.True(t, errors.Is(err, ErrInvalidModule))
+}
+
+func TestInterpreterExecutor_OperandTypes(t *testing.T) {
+	testExecutor := newTestExecutor(t, nil)
+
+	invalidFunctions := map[string]testFunction{
+		"mismatch":     {body: concat([]byte{opI64Const, 1}, i32Const(2), []byte{opI32Add, opDrop})},
+		"underflow":    {body: concat(i32Const(1), []byte{opI32Add, opDrop})},
+		"missingValue": {results: i32},
+		"leftover":     {body: i32Const(1)},
+		"ifWithoutElse": {
+			body: concat(i32Const(1), []byte{opIf, byte(valueTypeI32)}, i32Const(2), []byte{opEnd, opDrop}),
+		},
+		"wrongLocal": {locals: i64, body: concat(i32Const(1), []byte{opLocalSet, 0})},
+	}
+	for name, fn := range invalidFunctions {
+		tm := testModule{functions: []testFunction{fn}}
+		_, err := testExecutor.NewInstanceWithOptions(tm.bytes(), executor.CompilationOptions{})
+		require.True(t, errors.Is(err, ErrInvalidModule), name)
+	}
+
+	unreachableCode := testModule{functions: []testFunction{{body: []byte{opUnreachable, opI32Add, opDrop}}}}
+	_, err := testExecutor.NewInstanceWithOptions(unreachableCode.bytes(), executor.CompilationOptions{})
+	require.Nil(t, err)
+}
@@ -0,0 +1,380 @@
+package interpreter
+
+import (
+	"fmt"
+	"math"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ executor.Instance = (*InterpreterInstance)(nil)
+var _ executor.MemoryGrowthMeter = (*InterpreterInstance)(nil)
+var _ executor.InstanceStateHandler = (*InterpreterInstance)(nil)
+
+// emptyElement marks the table elements which no element segment initialized
+const emptyElement = math.MaxUint32
+
+// InterpreterInstance is a contract instance executed by the interpreter: its
+// memory, its globals, its table and its stack are held by the Go heap, and
+// its instructions are executed one by one, without any native code
+type InterpreterInstance struct {
+	executor *InterpreterExecutor
+	module   *module
+	code     []byte
+	options  executor.CompilationOptions
+	vmHooks  []*boundVMHook
+
+	memory  *Memory
+	globals []uint64
+	table   []uint32
+	stack   []uint64
+
+	callDepth       int
+	memoryGrowCount uint64
+	pointsUsed      uint64
+	gasLimit        uint64
+	breakpointValue uint64
+	vmHooksPtr      uintptr
+	alreadyCleaned  bool
+}
+
+// newInstance links the imports of a decoded module to the VM hooks of the
+// executor, initializes its state and runs its start function, if any
+func newInstance(
+	interpreterExecutor *InterpreterExecutor,
+	decodedModule *module,
+	code []byte,
+	options executor.CompilationOptions,
+) (*InterpreterInstance, error) {
+	instance := &InterpreterInstance{
+		executor: interpreterExecutor,
+		module:   decodedModule,
+		code:     code,
+		options:  options,
+		vmHooks:  make([]*boundVMHook, len(decodedModule.imports)),
+		stack:    make([]uint64, 0, 64),
+		gasLimit: options.GasLimit,
+	}
+
+	for i, imported := range decodedModule.imports {
+		hook, err := interpreterExecutor.boundVMHook(imported)
+		if err != nil {
+			return nil, err
+		}
+		if !decodedModule.types[imported.typeIndex].equals(hook.signature) {
+			return nil, fmt.Errorf("%w: %s", ErrImportSignatureMismatch, imported.name)
+		}
+		instance.vmHooks[i] = hook
+	}
+
+	err := instance.initializeState()
+	if err != nil {
+		return nil, err
+	}
+	if decodedModule.hasStart {
+		err = instance.run(decodedModule.startFunction)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return instance, nil
+}
+
+// initializeState sets the memory, the globals and the table of the instance
+// to their initial values, then copies the element and the data segments
+func (instance *InterpreterInstance) initializeState() error {
+	m := instance.module
+	if m.memory != nil {
+		if instance.memory == nil {
+			instance.memory = newMemory(m.memory)
+		} else {
+			instance.memory.reset(m.memory.min)
+		}
+	}
+
+	instance.globals = make([]uint64, len(m.globals))
+	for i, global := range m.globals {
+		instance.globals[i] = instance.evaluate(global.init)
+	}
+
+	if m.table != nil {
+		instance.table = make([]uint32, m.table.min)
+		for i := range instance.table {
+			instance.table[i] = emptyElement
+		}
+	}
+	for _, element := range m.elements {
+		offset := uint64(uint32(instance.evaluate(element.offset)))
+		if offset+uint64(len(element.functions)) > uint64(len(instance.table)) {
+			return fmt.Errorf("%w: element segment out of the table", ErrInvalidModule)
+		}
+		copy(instance.table[offset:], element.functions)
+	}
+
+	for _, segment := range m.data {
+		offset := uint64(uint32(instance.evaluate(segment.offset)))
+		data, err := instance.memory.bytesAt(offset, uint64(len(segment.data)))
+		if err != nil {
+			return fmt.Errorf("%w: data segment out of the memory", ErrInvalidModule)
+		}
+		copy(data, segment.data)
+	}
+	return nil
+}
+
+func (instance *InterpreterInstance) evaluate(expr constExpr) uint64 {
+	if expr.isGlobal {
+		return instance.globals[expr.globalIndex]
+	}
+	return expr.value
+}
+
+// run calls a function from outside the contract; the runtime errors of the
+// interpreter, raised by the function bodies which do not type check, become
+// traps, while the panics of the VM hooks are raised again
+func (instance *InterpreterInstance) run(functionIndex uint32) (err error) {
+	fnType := instance.module.functionType(functionIndex)
+	if len(fnType.params) != 0 {
+		return executor.ErrFunctionNonvoidSignature
+	}
+
+	defer func() {
+		panicValue := recover()
+		if panicValue == nil {
+			return
+		}
+		hookPanic, ok := panicValue.(hostPanic)
+		if ok {
+			panic(hookPanic.value)
+		}
+		logInterpreter.Trace("runtime error", "id", instance.Id(), "error", panicValue)
+		err = fmt.Errorf("%w: %v", ErrMalformedCode, panicValue)
+	}()
+
+	instance.stack = instance.stack[:0]
+	instance.callDepth = 0
+	instance.memoryGrowCount = 0
+	return instance.callFunction(functionIndex)
+}
+
+// Clean releases the memory of the instance
+func (instance *InterpreterInstance) Clean() {
+	logInterpreter.Trace("cleaning instance", "id", instance.Id())
+	if instance.alreadyCleaned {
+		logInterpreter.Trace("clean: already cleaned instance", "id", instance.Id())
+		return
+	}
+
+	if instance.memory != nil {
+		instance.memory.Destroy()
+	}
+	instance.globals = nil
+	instance.table = nil
+	instance.stack = nil
+	instance.alreadyCleaned = true
+	logInterpreter.Trace("cleaned instance", "id", instance.Id())
+}
+
+// GetPointsUsed returns the internal instance gas counter
+func (instance *InterpreterInstance) GetPointsUsed() uint64 {
+	return instance.pointsUsed
+}
+
+// SetPointsUsed sets the internal instance gas counter
+func (instance *InterpreterInstance) SetPointsUsed(points uint64) {
+	instance.pointsUsed = points
+}
+
+// SetGasLimit sets the gas limit for the instance
+func (instance *InterpreterInstance) SetGasLimit(gasLimit uint64) {
+	instance.gasLimit = gasLimit
+}
+
+// SetBreakpointValue sets the breakpoint value for the instance
+func (instance *InterpreterInstance) SetBreakpointValue(value uint64) {
+	instance.breakpointValue = value
+}
+
+// GetBreakpointValue returns the breakpoint value
+func (instance *InterpreterInstance) GetBreakpointValue() uint64 {
+	return instance.breakpointValue
+}
+
+// Cache returns the bytecode of the instance, since the interpreter compiles
+// no native code; NewInstanceFromCompiledCodeWithOptions decodes it again
+func (instance *InterpreterInstance) Cache() ([]byte, error) {
+	cached := make([]byte, len(instance.code))
+	copy(cached, instance.code)
+	return cached, nil
+}
+
+// IsFunctionImported returns true if the instance imports the specified function
+func (instance *InterpreterInstance) IsFunctionImported(name string) bool {
+	for _, imported := range instance.module.imports {
+		if imported.name == name {
+			return true
+		}
+	}
+	return false
+}
+
+// CallFunction executes given function from loaded contract.
+func (instance *InterpreterInstance) CallFunction(functionName string) error {
+	if instance.alreadyCleaned {
+		return ErrInstanceCleaned
+	}
+	functionIndex, ok := instance.module.exports[functionName]
+	if !ok {
+		return executor.ErrFuncNotFound
+	}
+	return instance.run(functionIndex)
+}
+
+// HasFunction checks if loaded contract has a function (endpoint) with given name.
+func (instance *InterpreterInstance) HasFunction(functionName string) bool {
+	_, ok := instance.module.exports[functionName]
+	return ok
+}
+
+// GetFunctionNames loads a list of contract function (endpoint) names. Required for validating reserved names.
+func (instance *InterpreterInstance) GetFunctionNames() []string {
+	var functionNames []string
+	for functionName := range instance.module.exports {
+		functionNames = append(functionNames, functionName)
+	}
+	return functionNames
+}
+
+// ValidateVoidFunction checks that no function (endpoint) of the given contract has any parameters or returns any result.
+// All arguments and results should be transferred via the import functions.
+func (instance *InterpreterInstance) ValidateVoidFunction(functionName string) error {
+	functionIndex, ok := instance.module.exports[functionName]
+	if !ok {
+		return fmt.Errorf("%w: %s", executor.ErrFuncNotFound, functionName)
+	}
+	fnType := instance.module.functionType(functionIndex)
+	if len(fnType.params) != 0 || len(fnType.results) != 0 {
+		return fmt.Errorf("%w: %s", executor.ErrFunctionNonvoidSignature, functionName)
+	}
+	return nil
+}
+
+// MetersMemoryGrowth returns true, as the interpreter charges and caps the
+// pages at each memory.grow
+func (instance *InterpreterInstance) MetersMemoryGrowth() bool {
+	return true
+}
+
+// HasMemory checks whether the instance has an exported memory.
+func (instance *InterpreterInstance) HasMemory() bool {
+	return instance.memory != nil && instance.module.memoryExported
+}
+
+// GetMemory returns the memory for the instance
+func (instance *InterpreterInstance) GetMemory() executor.Memory {
+	if !instance.HasMemory() {
+		return nil
+	}
+	return instance.memory
+}
+
+// IsInterfaceNil returns true if underlying object is nil
+func (instance *InterpreterInstance) IsInterfaceNil() bool {
+	return instance == nil
+}
+
+// Reset resets the instance memories, globals and table
+func (instance *InterpreterInstance) Reset() bool {
+	if instance.alreadyCleaned {
+		logInterpreter.Trace("reset: already cleaned instance", "id", instance.Id())
+		return false
+	}
+
+	err := instance.initializeState()
+	ok := err == nil
+
+	logInterpreter.Trace("reset: warm instance", "id", instance.Id(), "ok", ok)
+	return ok
+}
+
+// instanceState is a copy of the state of a suspended activation of an instance
+type instanceState struct {
+	instance        *InterpreterInstance
+	memory          []byte
+	globals         []uint64
+	table           []uint32
+	stack           []uint64
+	callDepth       int
+	memoryGrowCount uint64
+	pointsUsed      uint64
+	gasLimit        uint64
+	breakpointValue uint64
+}
+
+// SaveState copies the memory, the globals, the table, the stack and the gas
+// counters of the instance, so that another activation may reuse it meanwhile
+func (instance *InterpreterInstance) SaveState() (executor.InstanceState, error) {
+	if instance.alreadyCleaned {
+		return nil, ErrInstanceCleaned
+	}
+
+	state := &instanceState{
+		instance:        instance,
+		globals:         append([]uint64(nil), instance.globals...),
+		table:           append([]uint32(nil), instance.table...),
+		stack:           append([]uint64(nil), instance.stack...),
+		callDepth:       instance.callDepth,
+		memoryGrowCount: instance.memoryGrowCount,
+		pointsUsed:      instance.pointsUsed,
+		gasLimit:        instance.gasLimit,
+		breakpointValue: instance.breakpointValue,
+	}
+	if instance.memory != nil {
+		state.memory = append([]byte(nil), instance.memory.data...)
+	}
+	return state, nil
+}
+
+// RestoreState brings the instance back to a state saved by SaveState; the
+// stack is copied into the slice of the instance, and the memory keeps its
+// identity, since the suspended activation still refers to both
+func (instance *InterpreterInstance) RestoreState(state executor.InstanceState) error {
+	if instance.alreadyCleaned {
+		return ErrInstanceCleaned
+	}
+	saved, ok := state.(*instanceState)
+	if !ok || saved.instance != instance {
+		return ErrInvalidInstanceState
+	}
+
+	if instance.memory != nil {
+		instance.memory.data = append(instance.memory.data[:0], saved.memory...)
+	}
+	instance.globals = append(instance.globals[:0], saved.globals...)
+	instance.table = append(instance.table[:0], saved.table...)
+	instance.stack = append(instance.stack[:0], saved.stack...)
+	instance.callDepth = saved.callDepth
+	instance.memoryGrowCount = saved.memoryGrowCount
+	instance.pointsUsed = saved.pointsUsed
+	instance.gasLimit = saved.gasLimit
+	instance.breakpointValue = saved.breakpointValue
+	return nil
+}
+
+// SetVMHooksPtr sets the pointer to the VM hooks of the executor; the
+// interpreter calls the VM hooks of its executor, so it only keeps the
+// pointer for GetVMHooksPtr
+func (instance *InterpreterInstance) SetVMHooksPtr(vmHooksPtr uintptr) {
+	instance.vmHooksPtr = vmHooksPtr
+}
+
+// GetVMHooksPtr returns the pointer to the VM hooks of the executor
+func (instance *InterpreterInstance) GetVMHooksPtr() uintptr {
+	return instance.vmHooksPtr
+}
+
+// Id returns an identifier for the instance, unique at runtime
+func (instance *InterpreterInstance) Id() string {
+	return fmt.Sprintf("%p", instance)
+}
@@ -0,0 +1,276 @@
+package interpreter
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/stretchr/testify/require"
+)
+
+var i32 = []valueType{valueTypeI32}
+
+// factorialModule computes the factorial of 5 in a loop, into its global
+var factorialModule = testModule{
+	globals: 1,
+	functions: []testFunction{{
+		name:   "factorial",
+		locals: []valueType{valueTypeI32, valueTypeI32},
+		body: concat(
+			i32Const(5), []byte{opLocalSet, 0},
+			i32Const(1), []byte{opLocalSet, 1},
+			[]byte{opBlock, blockTypeEmpty, opLoop, blockTypeEmpty},
+			[]byte{opLocalGet, 0, opI32Eqz, opBrIf, 1},
+			[]byte{opLocalGet, 1, opLocalGet, 0, opI32Mul, opLocalSet, 1},
+			[]byte{opLocalGet, 0}, i32Const(1), []byte{opI32Sub, opLocalSet, 0},
+			[]byte{opBr, 0, opEnd, opEnd},
+			[]byte{opLocalGet, 1, opGlobalSet, 0},
+		),
+	}},
+}
+
+// memoryModule stores a value in its memory and loads it back into its
+// global, accesses its memory out of bounds, and grows its memory twice
+var memoryModule = testModule{
+	globals: 1,
+	memory:  true,
+	functions: []testFunction{
+		{
+			name: "storeAndLoad",
+			body: concat(
+				i32Const(8), i32Const(42), []byte{opI32Store, 2, 0},
+				i32Const(8), []byte{opI32Load, 2, 0, opGlobalSet, 0},
+			),
+		},
+		{
+			name: "outOfBounds",
+			body: concat(i32Const(pageSize-2), []byte{opI32Load, 2, 0, opDrop}),
+		},
+		{
+			name: "growTwice",
+			body: concat(
+				i32Const(1), []byte{opMemoryGrow, 0, opGlobalSet, 0},
+				i32Const(1), []byte{opMemoryGrow, 0, opDrop},
+			),
+		},
+	},
+}
+
+func TestInterpreterInstance_Loop(t *testing.T) {
+	instance := newTestInstance(t, factorialModule, executor.CompilationOptions{})
+	err := instance.CallFunction("factorial")
+	require.Nil(t, err)
+	require.Equal(t, uint64(120), instance.globals[0])
+	require.Empty(t, instance.stack)
+}
+
+func TestInterpreterInstance_Call(t *testing.T) {
+	tm := testModule{
+		globals: 1,
+		functions: []testFunction{
+			{
+				name: "addTwice",
+				body: concat(i32Const(20), []byte{opCall, 1, opCall, 1, opGlobalSet, 0}),
+			},
+			{
+				params:  i32,
+				results: i32,
+				body:    concat([]byte{opLocalGet, 0}, i32Const(11), []byte{opI32Add}),
+			},
+		},
+	}
+	instance := newTestInstance(t, tm, executor.CompilationOptions{})
+	err := instance.CallFunction("addTwice")
+	require.Nil(t, err)
+	require.Equal(t, uint64(42), instance.globals[0])
+}
+
+func TestInterpreterInstance_Traps(t *testing.T) {
+	tm := testModule{
+		functions: []testFunction{
+			{name: "unreachable", body: []byte{opUnreachable}},
+			{name: "divideByZero", body: concat(i32Const(1), i32Const(0), []byte{opI32DivS, opDrop})},
+			{name: "overflow", body: concat(i32Const(-1<<31), i32Const(-1), []byte{opI32DivS, opDrop})},
+			{name: "recursion", body: []byte{opCall, 3}},
+		},
+	}
+	instance := newTestInstance(t, tm, executor.CompilationOptions{})
+
+	expectedErrors := map[string]error{
+		"unreachable":  ErrUnreachable,
+		"divideByZero": ErrIntegerDivideByZero,
+		"overflow":     ErrIntegerOverflow,
+		"recursion":    ErrCallStackExhausted,
+	}
+	for name, expectedError := range expectedErrors {
+		err := instance.CallFunction(name)
+		require.Equal(t, expectedError, err, name)
+		require.True(t, errors.Is(err, ErrTrap), name)
+	}
+}
+
+func TestInterpreterInstance_Metering(t *testing.T) {
+	tm := testModule{
+		functions: []testFunction{{name: "constant", body: concat(i32Const(1), []byte{opDrop})}},
+	}
+	testExecutor := newTestExecutor(t, nil)
+	testExecutor.opcodeCosts = &opcodeCosts{}
+	for i := range testExecutor.opcodeCosts {
+		testExecutor.opcodeCosts[i] = 1
+	}
+	options := executor.CompilationOptions{GasLimit: 100, Metering: executor.MeteringEnabled}
+	instance, err := testExecutor.NewInstanceWithOptions(tm.bytes(), options)
+	require.Nil(t, err)
+
+	err = instance.CallFunction("constant")
+	require.Nil(t, err)
+	require.Equal(t, uint64(3), instance.GetPointsUsed())
+
+	instance.SetPointsUsed(0)
+	instance.SetGasLimit(2)
+	err = instance.CallFunction("constant")
+	require.Equal(t, ErrBreakpoint, err)
+	require.Equal(t, uint64(breakpointOutOfGas), instance.GetBreakpointValue())
+}
+
+func TestInterpreterInstance_Memory(t *testing.T) {
+	instance := newTestInstance(t, memoryModule, executor.CompilationOptions{})
+	require.True(t, instance.HasMemory())
+
+	err := instance.CallFunction("storeAndLoad")
+	require.Nil(t, err)
+	require.Equal(t, uint64(42), instance.globals[0])
+	require.Equal(t, byte(42), instance.GetMemory().Data()[8])
+
+	err = instance.CallFunction("outOfBounds")
+	require.Equal(t, ErrMemoryAccessOutOfBounds, err)
+}
+
+func TestInterpreterInstance_MemoryGrowLimit(t *testing.T) {
+	options := executor.CompilationOptions{MaxMemoryGrow: 1, MaxMemoryGrowDelta: 1}
+	instance := newTestInstance(t, memoryModule, options)
+
+	err := instance.CallFunction("growTwice")
+	require.Equal(t, ErrBreakpoint, err)
+	require.Equal(t, uint64(breakpointMemoryLimit), instance.GetBreakpointValue())
+	require.Equal(t, uint64(1), instance.globals[0])
+	require.Equal(t, uint32(2*pageSize), instance.GetMemory().Length())
+}
+
+func TestInterpreterInstance_MaxMemoryPages(t *testing.T) {
+	instance := newTestInstance(t, memoryModule, executor.CompilationOptions{MaxMemoryPages: 2})
+
+	err := instance.CallFunction("growTwice")
+	require.Equal(t, ErrBreakpoint, err)
+	require.Equal(t, uint64(breakpointMemoryLimit), instance.GetBreakpointValue())
+	require.Equal(t, uint64(1), instance.globals[0])
+	require.Equal(t, uint32(2*pageSize), instance.GetMemory().Length())
+}
+
+func TestInterpreterInstance_MemoryGrowPerPage(t *testing.T) {
+	testExecutor := newTestExecutor(t, nil)
+	testExecutor.opcodeCosts = &opcodeCosts{}
+	options := executor.CompilationOptions{GasLimit: 100, Metering: executor.MeteringEnabled, MemoryGrowPerPage: 10}
+	instance, err := testExecutor.NewInstanceWithOptions(memoryModule.bytes(), options)
+	require.Nil(t, err)
+	require.True(t, instance.(executor.MemoryGrowthMeter).MetersMemoryGrowth())
+
+	err = instance.CallFunction("growTwice")
+	require.Nil(t, err)
+	require.Equal(t, uint64(20), instance.GetPointsUsed())
+
+	options.GasLimit = 15
+	instance, err = testExecutor.NewInstanceWithOptions(memoryModule.bytes(), options)
+	require.Nil(t, err)
+	err = instance.CallFunction("growTwice")
+	require.Equal(t, ErrBreakpoint, err)
+	require.Equal(t, uint64(breakpointOutOfGas), instance.GetBreakpointValue())
+}
+
+func TestInterpreterInstance_Reset(t *testing.T) {
+	instance := newTestInstance(t, memoryModule, executor.CompilationOptions{})
+	err := instance.CallFunction("storeAndLoad")
+	require.Nil(t, err)
+
+	require.True(t, instance.Reset())
+	require.Equal(t, uint64(0), instance.globals[0])
+	require.Equal(t, byte(0), instance.GetMemory().Data()[8])
+
+	instance.Clean()
+	require.False(t, instance.Reset())
+	require.Equal(t, ErrInstanceCleaned, instance.CallFunction("storeAndLoad"))
+}
+
+func TestInterpreterInstance_SaveAndRestoreState(t *testing.T) {
+	instance := newTestInstance(t, memoryModule, executor.CompilationOptions{})
+	err := instance.CallFunction("storeAndLoad")
+	require.Nil(t, err)
+	instance.SetPointsUsed(100)
+
+	state, err := instance.SaveState()
+	require.Nil(t, err)
+
+	require.True(t, instance.Reset())
+	instance.SetPointsUsed(0)
+	err = instance.CallFunction("growTwice")
+	require.Nil(t, err)
+	require.Equal(t, uint64(1), instance.globals[0])
+	require.Equal(t, uint32(3*pageSize), instance.GetMemory().Length())
+
+	memory := instance.GetMemory()
+	err = instance.RestoreState(state)
+	require.Nil(t, err)
+	require.Equal(t, uint64(42), instance.globals[0])
+	require.True(t, memory == instance.GetMemory())
+	require.Equal(t, uint32(pageSize), instance.GetMemory().Length())
+	require.Equal(t, byte(42), instance.GetMemory().Data()[8])
+	require.Equal(t, uint64(100), instance.GetPointsUsed())
+
+	otherInstance := newTestInstance(t, memoryModule, executor.CompilationOptions{})
+	require.Equal(t, ErrInvalidInstanceState, otherInstance.RestoreState(state))
+
+	instance.Clean()
+	require.Equal(t, ErrInstanceCleaned, instance.RestoreState(state))
+}
+
+func TestInterpreterInstance_Cache(t *testing.T) {
+	testExecutor := newTestExecutor(t, nil)
+	instance, err := testExecutor.NewInstanceWithOptions(factorialModule.bytes(), executor.CompilationOptions{})
+	require.Nil(t, err)
+
+	cached, err := instance.Cache()
+	require.Nil(t, err)
+	cachedInstance, err := testExecutor.NewInstanceFromCompiledCodeWithOptions(cached, executor.CompilationOptions{})
+	require.Nil(t, err)
+	require.True(t, cachedInstance.HasFunction("factorial"))
+	require.Nil(t, cachedInstance.CallFunction("factorial"))
+}
+
+func TestInterpreterInstance_ValidateVoidFunction(t *testing.T) {
+	tm := testModule{
+		functions: []testFunction{
+			{name: "void"},
+			{name: "withParams", params: i32},
+			{name: "withResults", results: i32, body: i32Const(0)},
+		},
+	}
+	instance := newTestInstance(t, tm, executor.CompilationOptions{})
+	require.ElementsMatch(t, []string{"void", "withParams", "withResults"}, instance.GetFunctionNames())
+
+	require.Nil(t, instance.ValidateVoidFunction("void"))
+	err := instance.ValidateVoidFunction("withParams")
+	require.True(t, errors.Is(err, executor.ErrFunctionNonvoidSignature))
+	err = instance.ValidateVoidFunction("withResults")
+	require.True(t, errors.Is(err, executor.ErrFunctionNonvoidSignature))
+	err = instance.ValidateVoidFunction("missing")
+	require.True(t, errors.Is(err, executor.ErrFuncNotFound))
+
+	require.Equal(t, executor.ErrFunctionNonvoidSignature, instance.CallFunction("withParams"))
+	require.Equal(t, executor.ErrFuncNotFound, instance.CallFunction("missing"))
+}
+
+func TestInterpreterInstance_BreakpointValues(t *testing.T) {
+	require.Equal(t, uint64(arwen.BreakpointOutOfGas), uint64(breakpointOutOfGas))
+	require.Equal(t, uint64(arwen.BreakpointMemoryLimit), uint64(breakpointMemoryLimit))
+}
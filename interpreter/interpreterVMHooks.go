@@ -0,0 +1,957 @@
+package interpreter
+
+// Code generated by elrondapi generator. DO NOT EDIT.
+
+// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
+// !!!!!!!!!!!!!!!!!!!!!! AUTO-GENERATED FILE !!!!!!!!!!!!!!!!!!!!!!
+// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
+
+import (
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// vmHookAdapters calls the VM hooks by their import names, with the raw
+// values of the arguments, and returns their results widened to 64 bits
+var vmHookAdapters = map[string]vmHookAdapter{
+	"getGasLeft": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetGasLeft())
+	},
+	"stackHeightExceeded": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.StackHeightExceeded()
+		return 0
+	},
+	"getSCAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetSCAddress(int32(arguments[0]))
+		return 0
+	},
+	"getOwnerAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetOwnerAddress(int32(arguments[0]))
+		return 0
+	},
+	"getShardOfAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetShardOfAddress(int32(arguments[0]))))
+	},
+	"isSmartContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.IsSmartContract(int32(arguments[0]))))
+	},
+	"signalError": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.SignalError(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"signalTypedError": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.SignalTypedError(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))
+		return 0
+	},
+	"debugPrint": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.DebugPrint(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"gasPhase": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GasPhase(int32(arguments[0]))
+		return 0
+	},
+	"getExternalBalance": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetExternalBalance(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"getBlockHash": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetBlockHash(int64(arguments[0]), int32(arguments[1]))))
+	},
+	"getESDTBalance": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTBalance(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int64(arguments[3]), int32(arguments[4]))))
+	},
+	"getESDTNFTNameLength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTNFTNameLength(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int64(arguments[3]))))
+	},
+	"getESDTNFTAttributeLength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTNFTAttributeLength(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int64(arguments[3]))))
+	},
+	"getESDTNFTURILength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTNFTURILength(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int64(arguments[3]))))
+	},
+	"getESDTTokenData": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTTokenData(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int64(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]), int32(arguments[9]), int32(arguments[10]), int32(arguments[11]))))
+	},
+	"getESDTLocalRoles": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetESDTLocalRoles(int32(arguments[0])))
+	},
+	"validateTokenIdentifier": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ValidateTokenIdentifier(int32(arguments[0]))))
+	},
+	"transferValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.TransferValue(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"refundGasToCaller": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.RefundGasToCaller(int64(arguments[0]))))
+	},
+	"transferValueExecute": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.TransferValueExecute(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]))))
+	},
+	"transferESDTExecute": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.TransferESDTExecute(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int64(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]), int32(arguments[9]))))
+	},
+	"transferESDTNFTExecute": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.TransferESDTNFTExecute(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int64(arguments[4]), int64(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]), int32(arguments[9]), int32(arguments[10]))))
+	},
+	"multiTransferESDTNFTExecute": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MultiTransferESDTNFTExecute(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int64(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]), int32(arguments[9]))))
+	},
+	"createAsyncCall": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.CreateAsyncCall(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int64(arguments[8]), int64(arguments[9]))))
+	},
+	"setAsyncContextCallback": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.SetAsyncContextCallback(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int64(arguments[4]))))
+	},
+	"createPromiseGroup": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.CreatePromiseGroup(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"createPromise": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.CreatePromise(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]), int64(arguments[9]), int64(arguments[10]))))
+	},
+	"upgradeContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.UpgradeContract(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]))
+		return 0
+	},
+	"upgradeFromSourceContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.UpgradeFromSourceContract(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]))
+		return 0
+	},
+	"deleteContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.DeleteContract(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))
+		return 0
+	},
+	"asyncCall": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.AsyncCall(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))
+		return 0
+	},
+	"getArgumentLength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetArgumentLength(int32(arguments[0]))))
+	},
+	"getArgument": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetArgument(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"copyArgumentTo": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.CopyArgumentTo(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"getFunction": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetFunction(int32(arguments[0]))))
+	},
+	"getNumArguments": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetNumArguments()))
+	},
+	"storageStore": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.StorageStore(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"storageDelete": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.StorageDelete(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"storageLoadLength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.StorageLoadLength(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"storageLoadFromAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.StorageLoadFromAddress(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"storageLoad": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.StorageLoad(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"setStorageLock": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.SetStorageLock(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]))))
+	},
+	"getStorageLock": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetStorageLock(int32(arguments[0]), int32(arguments[1])))
+	},
+	"isStorageLocked": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.IsStorageLocked(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"clearStorageLock": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ClearStorageLock(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"getCaller": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetCaller(int32(arguments[0]))
+		return 0
+	},
+	"checkNoPayment": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.CheckNoPayment()
+		return 0
+	},
+	"getCallValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetCallValue(int32(arguments[0]))))
+	},
+	"getESDTValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTValue(int32(arguments[0]))))
+	},
+	"getESDTValueByIndex": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTValueByIndex(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"getESDTTokenName": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTTokenName(int32(arguments[0]))))
+	},
+	"getESDTTokenNameByIndex": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTTokenNameByIndex(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"getESDTTokenNonce": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetESDTTokenNonce())
+	},
+	"getESDTTokenNonceByIndex": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetESDTTokenNonceByIndex(int32(arguments[0])))
+	},
+	"getCurrentESDTNFTNonce": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetCurrentESDTNFTNonce(int32(arguments[0]), int32(arguments[1]), int32(arguments[2])))
+	},
+	"getESDTTokenType": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTTokenType()))
+	},
+	"getESDTTokenTypeByIndex": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetESDTTokenTypeByIndex(int32(arguments[0]))))
+	},
+	"getNumESDTTransfers": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetNumESDTTransfers()))
+	},
+	"getCallValueTokenName": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetCallValueTokenName(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"getCallValueTokenNameByIndex": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetCallValueTokenNameByIndex(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"writeLog": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.WriteLog(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))
+		return 0
+	},
+	"writeEventLog": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.WriteEventLog(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))
+		return 0
+	},
+	"getBlockTimestamp": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetBlockTimestamp())
+	},
+	"getBlockNonce": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetBlockNonce())
+	},
+	"getBlockRound": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetBlockRound())
+	},
+	"getBlockEpoch": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetBlockEpoch())
+	},
+	"getBlockRandomSeed": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetBlockRandomSeed(int32(arguments[0]))
+		return 0
+	},
+	"getStateRootHash": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetStateRootHash(int32(arguments[0]))
+		return 0
+	},
+	"getPrevBlockTimestamp": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetPrevBlockTimestamp())
+	},
+	"getPrevBlockNonce": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetPrevBlockNonce())
+	},
+	"getPrevBlockRound": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetPrevBlockRound())
+	},
+	"getPrevBlockEpoch": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.GetPrevBlockEpoch())
+	},
+	"getPrevBlockRandomSeed": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetPrevBlockRandomSeed(int32(arguments[0]))
+		return 0
+	},
+	"finish": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.Finish(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"finishReserve": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.FinishReserve(int32(arguments[0]))))
+	},
+	"finishAppend": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.FinishAppend(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"executeOnSameContext": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ExecuteOnSameContext(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]))))
+	},
+	"executeOnDestContext": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ExecuteOnDestContext(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]))))
+	},
+	"executeReadOnly": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ExecuteReadOnly(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]))))
+	},
+	"createContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.CreateContract(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]))))
+	},
+	"deployFromSourceContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.DeployFromSourceContract(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]))))
+	},
+	"getNumReturnData": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetNumReturnData()))
+	},
+	"getReturnDataSize": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetReturnDataSize(int32(arguments[0]))))
+	},
+	"getReturnData": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetReturnData(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"cleanReturnData": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.CleanReturnData()
+		return 0
+	},
+	"deleteFromReturnData": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.DeleteFromReturnData(int32(arguments[0]))
+		return 0
+	},
+	"getOriginalTxHash": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetOriginalTxHash(int32(arguments[0]))
+		return 0
+	},
+	"getCurrentTxHash": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetCurrentTxHash(int32(arguments[0]))
+		return 0
+	},
+	"getPrevTxHash": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.GetPrevTxHash(int32(arguments[0]))
+		return 0
+	},
+	"managedSCAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedSCAddress(int32(arguments[0]))
+		return 0
+	},
+	"managedOwnerAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedOwnerAddress(int32(arguments[0]))
+		return 0
+	},
+	"managedCaller": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedCaller(int32(arguments[0]))
+		return 0
+	},
+	"managedSignalError": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedSignalError(int32(arguments[0]))
+		return 0
+	},
+	"managedWriteLog": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedWriteLog(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"managedGetOriginalTxHash": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetOriginalTxHash(int32(arguments[0]))
+		return 0
+	},
+	"managedGetStateRootHash": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetStateRootHash(int32(arguments[0]))
+		return 0
+	},
+	"managedGetBlockRandomSeed": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetBlockRandomSeed(int32(arguments[0]))
+		return 0
+	},
+	"managedGetPrevBlockRandomSeed": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetPrevBlockRandomSeed(int32(arguments[0]))
+		return 0
+	},
+	"managedGetReturnData": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetReturnData(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"managedGetMultiESDTCallValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetMultiESDTCallValue(int32(arguments[0]))
+		return 0
+	},
+	"managedGetESDTBalance": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetESDTBalance(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]), int32(arguments[3]))
+		return 0
+	},
+	"managedGetESDTTokenData": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetESDTTokenData(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int32(arguments[8]), int32(arguments[9]), int32(arguments[10]))
+		return 0
+	},
+	"managedGetESDTNFTAttributes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedGetESDTNFTAttributes(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]))))
+	},
+	"managedGetESDTNFTAttributeValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedGetESDTNFTAttributeValue(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"managedGetESDTNFTURIs": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedGetESDTNFTURIs(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]))))
+	},
+	"managedGetESDTNFTCreator": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedGetESDTNFTCreator(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]))))
+	},
+	"managedGetESDTNFTRoyalties": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.ManagedGetESDTNFTRoyalties(int32(arguments[0]), int64(arguments[1])))
+	},
+	"managedGetESDTTokenTypeOf": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedGetESDTTokenTypeOf(int32(arguments[0]), int64(arguments[1]))))
+	},
+	"managedTransferNFTWithRoyalties": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedTransferNFTWithRoyalties(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int64(arguments[6]), int32(arguments[7]))))
+	},
+	"managedEscrowLock": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedEscrowLock(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"managedEscrowClaim": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedEscrowClaim(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"managedEscrowRelease": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedEscrowRelease(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"managedRegisterStorageMigration": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedRegisterStorageMigration(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int64(arguments[3]), int64(arguments[4]))))
+	},
+	"managedContinueStorageMigration": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.ManagedContinueStorageMigration(int64(arguments[0])))
+	},
+	"managedAsyncCall": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedAsyncCall(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))
+		return 0
+	},
+	"managedCreateAsyncCall": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedCreateAsyncCall(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int64(arguments[8]), int64(arguments[9]), int32(arguments[10]))))
+	},
+	"managedCreateAsyncCallWithCallbackArgTypes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedCreateAsyncCallWithCallbackArgTypes(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int64(arguments[8]), int64(arguments[9]), int32(arguments[10]), int32(arguments[11]))))
+	},
+	"managedCreateAsyncCallWithExpiry": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedCreateAsyncCallWithExpiry(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]), int64(arguments[8]), int64(arguments[9]), int32(arguments[10]), int32(arguments[11]), int64(arguments[12]))))
+	},
+	"managedGetCallbackClosure": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetCallbackClosure(int32(arguments[0]))
+		return 0
+	},
+	"managedGetLockedValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedGetLockedValue(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"managedSetAsyncGroupCallback": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedSetAsyncGroupCallback(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int64(arguments[4]))))
+	},
+	"managedAddAsyncSubGroup": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedAddAsyncSubGroup(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"managedUpgradeFromSourceContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedUpgradeFromSourceContract(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]))
+		return 0
+	},
+	"managedUpgradeContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedUpgradeContract(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]))
+		return 0
+	},
+	"managedDeleteContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedDeleteContract(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"managedDeployFromSourceContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedDeployFromSourceContract(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]))))
+	},
+	"managedCreateContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedCreateContract(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]))))
+	},
+	"managedCreateDeterministicContract": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedCreateDeterministicContract(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]), int32(arguments[7]))))
+	},
+	"managedComputeContractAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedComputeContractAddress(int32(arguments[0]), int64(arguments[1]), int32(arguments[2]))))
+	},
+	"managedComputeDeterministicAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedComputeDeterministicAddress(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"managedExecuteReadOnly": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedExecuteReadOnly(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedExecuteOnSameContext": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedExecuteOnSameContext(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]))))
+	},
+	"managedExecuteOnDestContext": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedExecuteOnDestContext(int64(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]))))
+	},
+	"managedMultiTransferESDTNFTExecute": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedMultiTransferESDTNFTExecute(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedTransferValueExecute": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedTransferValueExecute(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedIsESDTFrozen": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedIsESDTFrozen(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]))))
+	},
+	"managedIsESDTLimitedTransfer": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedIsESDTLimitedTransfer(int32(arguments[0]))))
+	},
+	"managedIsESDTPaused": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedIsESDTPaused(int32(arguments[0]))))
+	},
+	"managedBufferToHex": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.ManagedBufferToHex(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatNewFromParts": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigFloatNewFromParts(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"bigFloatNewFromFrac": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigFloatNewFromFrac(int64(arguments[0]), int64(arguments[1]))))
+	},
+	"bigFloatNewFromSci": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigFloatNewFromSci(int64(arguments[0]), int64(arguments[1]))))
+	},
+	"bigFloatAdd": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatAdd(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigFloatSub": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatSub(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigFloatMul": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatMul(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigFloatDiv": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatDiv(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigFloatNeg": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatNeg(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatClone": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatClone(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatCmp": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigFloatCmp(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"bigFloatAbs": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatAbs(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatSign": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigFloatSign(int32(arguments[0]))))
+	},
+	"bigFloatSqrt": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatSqrt(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatPow": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatPow(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigFloatFloor": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatFloor(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatCeil": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatCeil(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatTruncate": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatTruncate(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatSetInt64": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatSetInt64(int32(arguments[0]), int64(arguments[1]))
+		return 0
+	},
+	"bigFloatIsInt": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigFloatIsInt(int32(arguments[0]))))
+	},
+	"bigFloatSetBigInt": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatSetBigInt(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigFloatGetConstPi": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatGetConstPi(int32(arguments[0]))
+		return 0
+	},
+	"bigFloatGetConstE": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigFloatGetConstE(int32(arguments[0]))
+		return 0
+	},
+	"bigIntGetUnsignedArgument": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntGetUnsignedArgument(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntGetSignedArgument": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntGetSignedArgument(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntStorageStoreUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntStorageStoreUnsigned(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"bigIntStorageLoadUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntStorageLoadUnsigned(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"bigIntGetCallValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntGetCallValue(int32(arguments[0]))
+		return 0
+	},
+	"bigIntGetESDTCallValue": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntGetESDTCallValue(int32(arguments[0]))
+		return 0
+	},
+	"bigIntGetESDTCallValueByIndex": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntGetESDTCallValueByIndex(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntGetExternalBalance": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntGetExternalBalance(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntGetESDTExternalBalance": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntGetESDTExternalBalance(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int64(arguments[3]), int32(arguments[4]))
+		return 0
+	},
+	"bigIntNew": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntNew(int64(arguments[0]))))
+	},
+	"bigIntDrop": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntDrop(int32(arguments[0]))
+		return 0
+	},
+	"bigIntUnsignedByteLength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntUnsignedByteLength(int32(arguments[0]))))
+	},
+	"bigIntSignedByteLength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntSignedByteLength(int32(arguments[0]))))
+	},
+	"bigIntGetUnsignedBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntGetUnsignedBytes(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"bigIntGetSignedBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntGetSignedBytes(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"bigIntSetUnsignedBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntSetUnsignedBytes(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntSetSignedBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntSetSignedBytes(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntIsInt64": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntIsInt64(int32(arguments[0]))))
+	},
+	"bigIntGetInt64": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.BigIntGetInt64(int32(arguments[0])))
+	},
+	"bigIntSetInt64": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntSetInt64(int32(arguments[0]), int64(arguments[1]))
+		return 0
+	},
+	"bigIntAdd": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntAdd(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntSub": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntSub(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntMul": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntMul(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntTDiv": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntTDiv(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntTMod": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntTMod(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntEDiv": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntEDiv(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntEMod": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntEMod(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntSqrt": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntSqrt(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntPow": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntPow(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntLog2": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntLog2(int32(arguments[0]))))
+	},
+	"bigIntAbs": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntAbs(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntNeg": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntNeg(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntSign": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntSign(int32(arguments[0]))))
+	},
+	"bigIntCmp": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.BigIntCmp(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"bigIntNot": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntNot(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"bigIntAnd": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntAnd(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntOr": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntOr(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntXor": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntXor(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntShr": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntShr(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntShl": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntShl(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"bigIntFinishUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntFinishUnsigned(int32(arguments[0]))
+		return 0
+	},
+	"bigIntFinishSigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntFinishSigned(int32(arguments[0]))
+		return 0
+	},
+	"bigIntToString": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.BigIntToString(int32(arguments[0]), int32(arguments[1]))
+		return 0
+	},
+	"mBufferNew": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferNew()))
+	},
+	"mBufferNewFromBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferNewFromBytes(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferDrop": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferDrop(int32(arguments[0]))))
+	},
+	"mBufferGetLength": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferGetLength(int32(arguments[0]))))
+	},
+	"mBufferGetBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferGetBytes(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferGetByteSlice": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferGetByteSlice(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"mBufferCopyByteSlice": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferCopyByteSlice(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"mBufferEq": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferEq(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferSetBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferSetBytes(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"mBufferSetByteSlice": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferSetByteSlice(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"mBufferAppend": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferAppend(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferAppendBytes": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferAppendBytes(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"mBufferToBigIntUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferToBigIntUnsigned(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferToBigIntSigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferToBigIntSigned(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferFromBigIntUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferFromBigIntUnsigned(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferFromBigIntSigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferFromBigIntSigned(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferToBigFloat": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferToBigFloat(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferFromBigFloat": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferFromBigFloat(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferStorageStore": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferStorageStore(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferStorageDelete": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferStorageDelete(int32(arguments[0]))))
+	},
+	"mBufferStorageLoad": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferStorageLoad(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferStorageLoadFromAddress": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.MBufferStorageLoadFromAddress(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))
+		return 0
+	},
+	"mBufferGetArgument": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferGetArgument(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"mBufferFinish": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferFinish(int32(arguments[0]))))
+	},
+	"mBufferSetRandom": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MBufferSetRandom(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"smallIntGetUnsignedArgument": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.SmallIntGetUnsignedArgument(int32(arguments[0])))
+	},
+	"smallIntGetSignedArgument": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.SmallIntGetSignedArgument(int32(arguments[0])))
+	},
+	"smallIntFinishUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.SmallIntFinishUnsigned(int64(arguments[0]))
+		return 0
+	},
+	"smallIntFinishSigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.SmallIntFinishSigned(int64(arguments[0]))
+		return 0
+	},
+	"smallIntStorageStoreUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.SmallIntStorageStoreUnsigned(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]))))
+	},
+	"smallIntStorageStoreSigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.SmallIntStorageStoreSigned(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]))))
+	},
+	"smallIntStorageLoadUnsigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.SmallIntStorageLoadUnsigned(int32(arguments[0]), int32(arguments[1])))
+	},
+	"smallIntStorageLoadSigned": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.SmallIntStorageLoadSigned(int32(arguments[0]), int32(arguments[1])))
+	},
+	"int64getArgument": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.Int64getArgument(int32(arguments[0])))
+	},
+	"int64finish": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.Int64finish(int64(arguments[0]))
+		return 0
+	},
+	"int64storageStore": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.Int64storageStore(int32(arguments[0]), int32(arguments[1]), int64(arguments[2]))))
+	},
+	"int64storageLoad": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(vmHooks.Int64storageLoad(int32(arguments[0]), int32(arguments[1])))
+	},
+	"sha256": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.Sha256(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"managedSha256": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedSha256(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"keccak256": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.Keccak256(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"managedKeccak256": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedKeccak256(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"ripemd160": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.Ripemd160(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"managedRipemd160": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedRipemd160(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"verifyBLS": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.VerifyBLS(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"managedVerifyBLS": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedVerifyBLS(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"verifyEd25519": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.VerifyEd25519(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"managedVerifyEd25519": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedVerifyEd25519(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"verifyCustomSecp256k1": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.VerifyCustomSecp256k1(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]))))
+	},
+	"managedVerifyCustomSecp256k1": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedVerifyCustomSecp256k1(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"verifySecp256k1": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.VerifySecp256k1(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedVerifySecp256k1": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedVerifySecp256k1(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"encodeSecp256k1DerSignature": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.EncodeSecp256k1DerSignature(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedEncodeSecp256k1DerSignature": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedEncodeSecp256k1DerSignature(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"addEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.AddEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]))
+		return 0
+	},
+	"doubleEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		vmHooks.DoubleEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))
+		return 0
+	},
+	"isOnCurveEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.IsOnCurveEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]))))
+	},
+	"scalarBaseMultEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ScalarBaseMultEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedScalarBaseMultEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedScalarBaseMultEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"scalarMultEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ScalarMultEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]), int32(arguments[6]))))
+	},
+	"managedScalarMultEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedScalarMultEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]))))
+	},
+	"marshalEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MarshalEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"managedMarshalEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedMarshalEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"marshalCompressedEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.MarshalCompressedEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"managedMarshalCompressedEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedMarshalCompressedEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"unmarshalEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.UnmarshalEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedUnmarshalEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedUnmarshalEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"unmarshalCompressedEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.UnmarshalCompressedEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]))))
+	},
+	"managedUnmarshalCompressedEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedUnmarshalCompressedEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"generateKeyEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GenerateKeyEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"managedGenerateKeyEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedGenerateKeyEC(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]))))
+	},
+	"createEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.CreateEC(int32(arguments[0]), int32(arguments[1]))))
+	},
+	"managedCreateEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.ManagedCreateEC(int32(arguments[0]))))
+	},
+	"getCurveLengthEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetCurveLengthEC(int32(arguments[0]))))
+	},
+	"getPrivKeyByteLengthEC": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.GetPrivKeyByteLengthEC(int32(arguments[0]))))
+	},
+	"ellipticCurveGetValues": func(vmHooks executor.VMHooks, arguments []uint64) uint64 {
+		return uint64(uint32(vmHooks.EllipticCurveGetValues(int32(arguments[0]), int32(arguments[1]), int32(arguments[2]), int32(arguments[3]), int32(arguments[4]), int32(arguments[5]))))
+	},
+}
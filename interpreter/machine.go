@@ -0,0 +1,747 @@
+package interpreter
+
+import (
+	"encoding/binary"
+	"math"
+	"math/bits"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+// maxCallDepth bounds the nested calls of the contract functions, and with
+// them the stack of the goroutine running the interpreter
+const maxCallDepth = 16384
+
+// The breakpoint values set by the interpreter, which are those of
+// arwen.BreakpointOutOfGas and arwen.BreakpointMemoryLimit
+const (
+	breakpointOutOfGas    = 4
+	breakpointMemoryLimit = 5
+)
+
+// label is the target of the branches out of a block, a loop, an if or the
+// whole function body: the height of the stack at its entry, the number of
+// values it carries and the instruction following it
+type label struct {
+	height       int
+	arity        int
+	continuation int
+	isLoop       bool
+}
+
+func (instance *InterpreterInstance) push(value uint64) {
+	instance.stack = append(instance.stack, value)
+}
+
+func (instance *InterpreterInstance) pop() uint64 {
+	last := len(instance.stack) - 1
+	value := instance.stack[last]
+	instance.stack = instance.stack[:last]
+	return value
+}
+
+// popOperands pops the two operands of a binary instruction, in their order
+func (instance *InterpreterInstance) popOperands() (uint64, uint64) {
+	b := instance.pop()
+	a := instance.pop()
+	return a, b
+}
+
+// useGas charges the points of the given cost and stops the execution at
+// an out of gas breakpoint once they exceed the gas limit
+func (instance *InterpreterInstance) useGas(cost uint64) error {
+	instance.pointsUsed += cost
+	if instance.pointsUsed > instance.gasLimit {
+		instance.breakpointValue = breakpointOutOfGas
+		return ErrBreakpoint
+	}
+	return nil
+}
+
+// callFunction calls a function of the function index space, with its
+// arguments on the stack, which it replaces with the results
+func (instance *InterpreterInstance) callFunction(index uint32) error {
+	if int(index) < len(instance.vmHooks) {
+		return instance.callVMHook(instance.vmHooks[index])
+	}
+	if instance.callDepth >= maxCallDepth {
+		return ErrCallStackExhausted
+	}
+
+	fn := instance.module.functions[int(index)-len(instance.vmHooks)]
+	fnType := instance.module.types[fn.typeIndex]
+	numParams := len(fnType.params)
+	base := len(instance.stack) - numParams
+	locals := make([]uint64, numParams+len(fn.locals))
+	copy(locals, instance.stack[base:])
+	instance.stack = instance.stack[:base]
+
+	if instance.metered() && uint64(len(fn.locals)) > instance.options.UnmeteredLocals {
+		meteredLocals := uint64(len(fn.locals)) - instance.options.UnmeteredLocals
+		err := instance.useGas(meteredLocals * instance.executor.localAllocateCost)
+		if err != nil {
+			return err
+		}
+	}
+
+	instance.callDepth++
+	err := instance.execute(fn, locals, base, len(fnType.results))
+	instance.callDepth--
+	return err
+}
+
+func (instance *InterpreterInstance) metered() bool {
+	return instance.options.Metering == executor.MeteringEnabled
+}
+
+// branch moves the values carried to the target label and returns the
+// labels left open and the instruction to continue with; a branch to a loop
+// keeps the loop open, for its next iteration
+func (instance *InterpreterInstance) branch(labels []label, depth uint32) ([]label, int) {
+	targetIndex := len(labels) - 1 - int(depth)
+	target := labels[targetIndex]
+	instance.moveResults(target.height, target.arity)
+	if target.isLoop {
+		return labels[:targetIndex+1], target.continuation
+	}
+	return labels[:targetIndex], target.continuation
+}
+
+// moveResults drops the values between the given height and the top values
+func (instance *InterpreterInstance) moveResults(height int, arity int) {
+	top := len(instance.stack) - arity
+	if top == height {
+		return
+	}
+	copy(instance.stack[height:], instance.stack[top:])
+	instance.stack = instance.stack[:height+arity]
+}
+
+// memoryAt returns the bytes accessed by a load or a store at the address
+// popped from the stack plus the offset of the instruction
+func (instance *InterpreterInstance) memoryAt(address uint64, offset uint64, length uint64) ([]byte, error) {
+	return instance.memory.bytesAt(uint64(uint32(address))+offset, length)
+}
+
+// execute runs the compiled body of a function, the stack below base
+// belonging to its callers
+func (instance *InterpreterInstance) execute(fn *function, locals []uint64, base int, arity int) error {
+	code := fn.code
+	costs := instance.executor.opcodeCosts
+	metered := instance.metered()
+	labels := make([]label, 1, 8)
+	labels[0] = label{height: base, arity: arity, continuation: len(code)}
+
+	pc := 0
+	for pc < len(code) {
+		instr := &code[pc]
+		pc++
+		if metered {
+			err := instance.useGas(costs[instr.opcode])
+			if err != nil {
+				return err
+			}
+		}
+
+		switch instr.opcode {
+		case opUnreachable:
+			return ErrUnreachable
+		case opNop:
+		case opBlock, opLoop:
+			block := &fn.blocks[instr.index]
+			height := len(instance.stack) - block.params
+			if instr.opcode == opLoop {
+				labels = append(labels, label{height: height, arity: block.params, continuation: pc, isLoop: true})
+			} else {
+				labels = append(labels, label{height: height, arity: block.results, continuation: block.endPC + 1})
+			}
+		case opIf:
+			block := &fn.blocks[instr.index]
+			condition := instance.pop()
+			if condition == 0 && !block.hasElse {
+				pc = block.endPC + 1
+				break
+			}
+			height := len(instance.stack) - block.params
+			labels = append(labels, label{height: height, arity: block.results, continuation: block.endPC + 1})
+			if condition == 0 {
+				pc = block.elsePC + 1
+			}
+		case opElse:
+			labels = labels[:len(labels)-1]
+			pc = fn.blocks[instr.index].endPC + 1
+		case opEnd:
+			labels = labels[:len(labels)-1]
+		case opBr:
+			labels, pc = instance.branch(labels, instr.index)
+		case opBrIf:
+			if instance.pop() != 0 {
+				labels, pc = instance.branch(labels, instr.index)
+			}
+		case opBrTable:
+			table := fn.tables[instr.index]
+			selected := instance.pop()
+			if selected >= uint64(len(table)-1) {
+				selected = uint64(len(table) - 1)
+			}
+			labels, pc = instance.branch(labels, table[selected])
+		case opReturn:
+			instance.moveResults(base, arity)
+			return nil
+		case opCall:
+			err := instance.callFunction(instr.index)
+			if err != nil {
+				return err
+			}
+		case opCallIndirect:
+			err := instance.callIndirect(instr.index, instance.pop())
+			if err != nil {
+				return err
+			}
+		case opDrop:
+			instance.pop()
+		case opSelect:
+			condition := instance.pop()
+			a, b := instance.popOperands()
+			if condition != 0 {
+				instance.push(a)
+			} else {
+				instance.push(b)
+			}
+
+		case opLocalGet:
+			instance.push(locals[instr.index])
+		case opLocalSet:
+			locals[instr.index] = instance.pop()
+		case opLocalTee:
+			locals[instr.index] = instance.stack[len(instance.stack)-1]
+		case opGlobalGet:
+			instance.push(instance.globals[instr.index])
+		case opGlobalSet:
+			instance.globals[instr.index] = instance.pop()
+
+		case opI32Load, opF32Load:
+			data, err := instance.memoryAt(instance.pop(), instr.value, 4)
+			if err != nil {
+				return err
+			}
+			instance.push(uint64(binary.LittleEndian.Uint32(data)))
+		case opI64Load, opF64Load:
+			data, err := instance.memoryAt(instance.pop(), instr.value, 8)
+			if err != nil {
+				return err
+			}
+			instance.push(binary.LittleEndian.Uint64(data))
+		case opI32Load8S, opI32Load8U, opI64Load8S, opI64Load8U:
+			data, err := instance.memoryAt(instance.pop(), instr.value, 1)
+			if err != nil {
+				return err
+			}
+			switch instr.opcode {
+			case opI32Load8S:
+				instance.push(fromI32(int32(int8(data[0]))))
+			case opI64Load8S:
+				instance.push(uint64(int64(int8(data[0]))))
+			default:
+				instance.push(uint64(data[0]))
+			}
+		case opI32Load16S, opI32Load16U, opI64Load16S, opI64Load16U:
+			data, err := instance.memoryAt(instance.pop(), instr.value, 2)
+			if err != nil {
+				return err
+			}
+			value := binary.LittleEndian.Uint16(data)
+			switch instr.opcode {
+			case opI32Load16S:
+				instance.push(fromI32(int32(int16(value))))
+			case opI64Load16S:
+				instance.push(uint64(int64(int16(value))))
+			default:
+				instance.push(uint64(value))
+			}
+		case opI64Load32S, opI64Load32U:
+			data, err := instance.memoryAt(instance.pop(), instr.value, 4)
+			if err != nil {
+				return err
+			}
+			value := binary.LittleEndian.Uint32(data)
+			if instr.opcode == opI64Load32S {
+				instance.push(uint64(int64(int32(value))))
+			} else {
+				instance.push(uint64(value))
+			}
+		case opI32Store, opF32Store, opI64Store32:
+			value := instance.pop()
+			data, err := instance.memoryAt(instance.pop(), instr.value, 4)
+			if err != nil {
+				return err
+			}
+			binary.LittleEndian.PutUint32(data, uint32(value))
+		case opI64Store, opF64Store:
+			value := instance.pop()
+			data, err := instance.memoryAt(instance.pop(), instr.value, 8)
+			if err != nil {
+				return err
+			}
+			binary.LittleEndian.PutUint64(data, value)
+		case opI32Store8, opI64Store8:
+			value := instance.pop()
+			data, err := instance.memoryAt(instance.pop(), instr.value, 1)
+			if err != nil {
+				return err
+			}
+			data[0] = byte(value)
+		case opI32Store16, opI64Store16:
+			value := instance.pop()
+			data, err := instance.memoryAt(instance.pop(), instr.value, 2)
+			if err != nil {
+				return err
+			}
+			binary.LittleEndian.PutUint16(data, uint16(value))
+		case opMemorySize:
+			instance.push(uint64(instance.memory.pages()))
+		case opMemoryGrow:
+			err := instance.growMemory()
+			if err != nil {
+				return err
+			}
+
+		case opI32Const, opI64Const, opF32Const, opF64Const:
+			instance.push(instr.value)
+
+		case opI32Eqz:
+			instance.push(boolValue(uint32(instance.pop()) == 0))
+		case opI64Eqz:
+			instance.push(boolValue(instance.pop() == 0))
+		case opI32Clz:
+			instance.push(uint64(bits.LeadingZeros32(uint32(instance.pop()))))
+		case opI32Ctz:
+			instance.push(uint64(bits.TrailingZeros32(uint32(instance.pop()))))
+		case opI32Popcnt:
+			instance.push(uint64(bits.OnesCount32(uint32(instance.pop()))))
+		case opI64Clz:
+			instance.push(uint64(bits.LeadingZeros64(instance.pop())))
+		case opI64Ctz:
+			instance.push(uint64(bits.TrailingZeros64(instance.pop())))
+		case opI64Popcnt:
+			instance.push(uint64(bits.OnesCount64(instance.pop())))
+
+		case opI32Eq, opI32Ne, opI32LtS, opI32LtU, opI32GtS, opI32GtU, opI32LeS, opI32LeU, opI32GeS, opI32GeU,
+			opI32Add, opI32Sub, opI32Mul, opI32DivS, opI32DivU, opI32RemS, opI32RemU,
+			opI32And, opI32Or, opI32Xor, opI32Shl, opI32ShrS, opI32ShrU, opI32Rotl, opI32Rotr:
+			a, b := instance.popOperands()
+			result, err := executeI32Binary(instr.opcode, uint32(a), uint32(b))
+			if err != nil {
+				return err
+			}
+			instance.push(uint64(result))
+		case opI64Eq, opI64Ne, opI64LtS, opI64LtU, opI64GtS, opI64GtU, opI64LeS, opI64LeU, opI64GeS, opI64GeU,
+			opI64Add, opI64Sub, opI64Mul, opI64DivS, opI64DivU, opI64RemS, opI64RemU,
+			opI64And, opI64Or, opI64Xor, opI64Shl, opI64ShrS, opI64ShrU, opI64Rotl, opI64Rotr:
+			a, b := instance.popOperands()
+			result, err := executeI64Binary(instr.opcode, a, b)
+			if err != nil {
+				return err
+			}
+			instance.push(result)
+
+		case opF32Eq, opF32Ne, opF32Lt, opF32Gt, opF32Le, opF32Ge,
+			opF32Add, opF32Sub, opF32Mul, opF32Div, opF32Min, opF32Max, opF32Copysign:
+			a, b := instance.popOperands()
+			instance.push(executeF32Binary(instr.opcode, a, b))
+		case opF64Eq, opF64Ne, opF64Lt, opF64Gt, opF64Le, opF64Ge,
+			opF64Add, opF64Sub, opF64Mul, opF64Div, opF64Min, opF64Max, opF64Copysign:
+			a, b := instance.popOperands()
+			instance.push(executeF64Binary(instr.opcode, a, b))
+		case opF32Abs, opF32Neg, opF32Ceil, opF32Floor, opF32Trunc, opF32Nearest, opF32Sqrt,
+			opF64Abs, opF64Neg, opF64Ceil, opF64Floor, opF64Trunc, opF64Nearest, opF64Sqrt:
+			instance.push(executeFloatUnary(instr.opcode, instance.pop()))
+
+		default:
+			result, err := executeConversion(instr.opcode, instance.pop())
+			if err != nil {
+				return err
+			}
+			instance.push(result)
+		}
+	}
+	return nil
+}
+
+// callIndirect calls the function of the table element, after checking that
+// it has the expected type
+func (instance *InterpreterInstance) callIndirect(typeIndex uint32, element uint64) error {
+	if element >= uint64(len(instance.table)) {
+		return ErrUndefinedElement
+	}
+	functionIndex := instance.table[element]
+	if functionIndex == emptyElement {
+		return ErrUndefinedElement
+	}
+	expectedType := instance.module.types[typeIndex]
+	if !expectedType.equals(instance.module.functionType(functionIndex)) {
+		return ErrIndirectCallTypeMismatch
+	}
+	return instance.callFunction(functionIndex)
+}
+
+// growMemory executes memory.grow, which pushes the previous number of pages,
+// or -1 if the memory cannot grow; like the wasmer executor, the interpreter
+// stops at a memory limit breakpoint once the contract grows its memory more
+// times, or by more pages at once, than the compilation options allow; it
+// also stops there before the memory exceeds MaxMemoryPages, and charges
+// MemoryGrowPerPage for each page added
+func (instance *InterpreterInstance) growMemory() error {
+	pages := uint32(instance.pop())
+	instance.memoryGrowCount++
+	maxMemoryGrow := instance.options.MaxMemoryGrow
+	maxMemoryGrowDelta := instance.options.MaxMemoryGrowDelta
+	if (maxMemoryGrow > 0 && instance.memoryGrowCount > maxMemoryGrow) ||
+		(maxMemoryGrowDelta > 0 && uint64(pages) > maxMemoryGrowDelta) {
+		instance.breakpointValue = breakpointMemoryLimit
+		return ErrBreakpoint
+	}
+
+	previousPages := instance.memory.pages()
+	maxMemoryPages := instance.options.MaxMemoryPages
+	if maxMemoryPages > 0 && uint64(previousPages)+uint64(pages) > maxMemoryPages {
+		instance.breakpointValue = breakpointMemoryLimit
+		return ErrBreakpoint
+	}
+
+	err := instance.memory.Grow(pages)
+	if err != nil {
+		instance.push(fromI32(-1))
+		return nil
+	}
+	instance.push(uint64(previousPages))
+
+	if !instance.metered() {
+		return nil
+	}
+	return instance.useGas(uint64(pages) * instance.options.MemoryGrowPerPage)
+}
+
+func executeI32Binary(opcode uint16, a uint32, b uint32) (uint32, error) {
+	switch opcode {
+	case opI32Eq:
+		return uint32(boolValue(a == b)), nil
+	case opI32Ne:
+		return uint32(boolValue(a != b)), nil
+	case opI32LtS:
+		return uint32(boolValue(int32(a) < int32(b))), nil
+	case opI32LtU:
+		return uint32(boolValue(a < b)), nil
+	case opI32GtS:
+		return uint32(boolValue(int32(a) > int32(b))), nil
+	case opI32GtU:
+		return uint32(boolValue(a > b)), nil
+	case opI32LeS:
+		return uint32(boolValue(int32(a) <= int32(b))), nil
+	case opI32LeU:
+		return uint32(boolValue(a <= b)), nil
+	case opI32GeS:
+		return uint32(boolValue(int32(a) >= int32(b))), nil
+	case opI32GeU:
+		return uint32(boolValue(a >= b)), nil
+	case opI32Add:
+		return a + b, nil
+	case opI32Sub:
+		return a - b, nil
+	case opI32Mul:
+		return a * b, nil
+	case opI32DivS:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		if int32(a) == math.MinInt32 && int32(b) == -1 {
+			return 0, ErrIntegerOverflow
+		}
+		return uint32(int32(a) / int32(b)), nil
+	case opI32DivU:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		return a / b, nil
+	case opI32RemS:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		if int32(b) == -1 {
+			return 0, nil
+		}
+		return uint32(int32(a) % int32(b)), nil
+	case opI32RemU:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		return a % b, nil
+	case opI32And:
+		return a & b, nil
+	case opI32Or:
+		return a | b, nil
+	case opI32Xor:
+		return a ^ b, nil
+	case opI32Shl:
+		return a << (b & 31), nil
+	case opI32ShrS:
+		return uint32(int32(a) >> (b & 31)), nil
+	case opI32ShrU:
+		return a >> (b & 31), nil
+	case opI32Rotl:
+		return bits.RotateLeft32(a, int(b&31)), nil
+	case opI32Rotr:
+		return bits.RotateLeft32(a, -int(b&31)), nil
+	}
+	return 0, ErrMalformedCode
+}
+
+func executeI64Binary(opcode uint16, a uint64, b uint64) (uint64, error) {
+	switch opcode {
+	case opI64Eq:
+		return boolValue(a == b), nil
+	case opI64Ne:
+		return boolValue(a != b), nil
+	case opI64LtS:
+		return boolValue(int64(a) < int64(b)), nil
+	case opI64LtU:
+		return boolValue(a < b), nil
+	case opI64GtS:
+		return boolValue(int64(a) > int64(b)), nil
+	case opI64GtU:
+		return boolValue(a > b), nil
+	case opI64LeS:
+		return boolValue(int64(a) <= int64(b)), nil
+	case opI64LeU:
+		return boolValue(a <= b), nil
+	case opI64GeS:
+		return boolValue(int64(a) >= int64(b)), nil
+	case opI64GeU:
+		return boolValue(a >= b), nil
+	case opI64Add:
+		return a + b, nil
+	case opI64Sub:
+		return a - b, nil
+	case opI64Mul:
+		return a * b, nil
+	case opI64DivS:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		if int64(a) == math.MinInt64 && int64(b) == -1 {
+			return 0, ErrIntegerOverflow
+		}
+		return uint64(int64(a) / int64(b)), nil
+	case opI64DivU:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		return a / b, nil
+	case opI64RemS:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		if int64(b) == -1 {
+			return 0, nil
+		}
+		return uint64(int64(a) % int64(b)), nil
+	case opI64RemU:
+		if b == 0 {
+			return 0, ErrIntegerDivideByZero
+		}
+		return a % b, nil
+	case opI64And:
+		return a & b, nil
+	case opI64Or:
+		return a | b, nil
+	case opI64Xor:
+		return a ^ b, nil
+	case opI64Shl:
+		return a << (b & 63), nil
+	case opI64ShrS:
+		return uint64(int64(a) >> (b & 63)), nil
+	case opI64ShrU:
+		return a >> (b & 63), nil
+	case opI64Rotl:
+		return bits.RotateLeft64(a, int(b&63)), nil
+	case opI64Rotr:
+		return bits.RotateLeft64(a, -int(b&63)), nil
+	}
+	return 0, ErrMalformedCode
+}
+
+func executeF32Binary(opcode uint16, a uint64, b uint64) uint64 {
+	x, y := asF32(a), asF32(b)
+	switch opcode {
+	case opF32Eq:
+		return boolValue(x == y)
+	case opF32Ne:
+		return boolValue(x != y)
+	case opF32Lt:
+		return boolValue(x < y)
+	case opF32Gt:
+		return boolValue(x > y)
+	case opF32Le:
+		return boolValue(x <= y)
+	case opF32Ge:
+		return boolValue(x >= y)
+	case opF32Add:
+		return fromF32(x + y)
+	case opF32Sub:
+		return fromF32(x - y)
+	case opF32Mul:
+		return fromF32(x * y)
+	case opF32Div:
+		return fromF32(x / y)
+	case opF32Min:
+		return fromF32(float32(wasmMin(float64(x), float64(y))))
+	case opF32Max:
+		return fromF32(float32(wasmMax(float64(x), float64(y))))
+	}
+	return a&^f32SignBit | b&f32SignBit
+}
+
+func executeF64Binary(opcode uint16, a uint64, b uint64) uint64 {
+	x, y := asF64(a), asF64(b)
+	switch opcode {
+	case opF64Eq:
+		return boolValue(x == y)
+	case opF64Ne:
+		return boolValue(x != y)
+	case opF64Lt:
+		return boolValue(x < y)
+	case opF64Gt:
+		return boolValue(x > y)
+	case opF64Le:
+		return boolValue(x <= y)
+	case opF64Ge:
+		return boolValue(x >= y)
+	case opF64Add:
+		return fromF64(x + y)
+	case opF64Sub:
+		return fromF64(x - y)
+	case opF64Mul:
+		return fromF64(x * y)
+	case opF64Div:
+		return fromF64(x / y)
+	case opF64Min:
+		return fromF64(wasmMin(x, y))
+	case opF64Max:
+		return fromF64(wasmMax(x, y))
+	}
+	return a&^f64SignBit | b&f64SignBit
+}
+
+// executeFloatUnary executes the unary float instructions; the operations
+// on f32 values are exact in f64, but for the square root, which is rounded
+// correctly all the same
+func executeFloatUnary(opcode uint16, a uint64) uint64 {
+	switch opcode {
+	case opF32Abs:
+		return a &^ f32SignBit
+	case opF32Neg:
+		return a ^ f32SignBit
+	case opF32Ceil:
+		return fromF32(float32(math.Ceil(float64(asF32(a)))))
+	case opF32Floor:
+		return fromF32(float32(math.Floor(float64(asF32(a)))))
+	case opF32Trunc:
+		return fromF32(float32(math.Trunc(float64(asF32(a)))))
+	case opF32Nearest:
+		return fromF32(float32(math.RoundToEven(float64(asF32(a)))))
+	case opF32Sqrt:
+		return fromF32(float32(math.Sqrt(float64(asF32(a)))))
+	case opF64Abs:
+		return a &^ f64SignBit
+	case opF64Neg:
+		return a ^ f64SignBit
+	case opF64Ceil:
+		return fromF64(math.Ceil(asF64(a)))
+	case opF64Floor:
+		return fromF64(math.Floor(asF64(a)))
+	case opF64Trunc:
+		return fromF64(math.Trunc(asF64(a)))
+	case opF64Nearest:
+		return fromF64(math.RoundToEven(asF64(a)))
+	}
+	return fromF64(math.Sqrt(asF64(a)))
+}
+
+// executeConversion executes the conversions between the value types, the
+// sign extensions and the non-trapping conversions; the reinterpretations
+// keep the bits as they are
+func executeConversion(opcode uint16, a uint64) (uint64, error) {
+	switch opcode {
+	case opI32WrapI64:
+		return uint64(uint32(a)), nil
+	case opI32TruncF32S:
+		return truncToI32(float64(asF32(a)))
+	case opI32TruncF32U:
+		return truncToU32(float64(asF32(a)))
+	case opI32TruncF64S:
+		return truncToI32(asF64(a))
+	case opI32TruncF64U:
+		return truncToU32(asF64(a))
+	case opI64ExtendI32S:
+		return uint64(int64(asI32(a))), nil
+	case opI64ExtendI32U:
+		return uint64(uint32(a)), nil
+	case opI64TruncF32S:
+		return truncToI64(float64(asF32(a)))
+	case opI64TruncF32U:
+		return truncToU64(float64(asF32(a)))
+	case opI64TruncF64S:
+		return truncToI64(asF64(a))
+	case opI64TruncF64U:
+		return truncToU64(asF64(a))
+	case opF32ConvertI32S:
+		return fromF32(float32(asI32(a))), nil
+	case opF32ConvertI32U:
+		return fromF32(float32(uint32(a))), nil
+	case opF32ConvertI64S:
+		return fromF32(float32(int64(a))), nil
+	case opF32ConvertI64U:
+		return fromF32(float32(a)), nil
+	case opF32DemoteF64:
+		return fromF32(float32(asF64(a))), nil
+	case opF64ConvertI32S:
+		return fromF64(float64(asI32(a))), nil
+	case opF64ConvertI32U:
+		return fromF64(float64(uint32(a))), nil
+	case opF64ConvertI64S:
+		return fromF64(float64(int64(a))), nil
+	case opF64ConvertI64U:
+		return fromF64(float64(a)), nil
+	case opF64PromoteF32:
+		return fromF64(float64(asF32(a))), nil
+	case opI32ReinterpretF32, opI64ReinterpretF64, opF32ReinterpretI32, opF64ReinterpretI64:
+		return a, nil
+	case opI32Extend8S:
+		return fromI32(int32(int8(a))), nil
+	case opI32Extend16S:
+		return fromI32(int32(int16(a))), nil
+	case opI64Extend8S:
+		return uint64(int64(int8(a))), nil
+	case opI64Extend16S:
+		return uint64(int64(int16(a))), nil
+	case opI64Extend32S:
+		return uint64(int64(int32(a))), nil
+	case opI32TruncSatF32S:
+		return truncSatToI32(float64(asF32(a))), nil
+	case opI32TruncSatF32U:
+		return truncSatToU32(float64(asF32(a))), nil
+	case opI32TruncSatF64S:
+		return truncSatToI32(asF64(a)), nil
+	case opI32TruncSatF64U:
+		return truncSatToU32(asF64(a)), nil
+	case opI64TruncSatF32S:
+		return truncSatToI64(float64(asF32(a))), nil
+	case opI64TruncSatF32U:
+		return truncSatToU64(float64(asF32(a))), nil
+	case opI64TruncSatF64S:
+		return truncSatToI64(asF64(a)), nil
+	case opI64TruncSatF64U:
+		return truncSatToU64(asF64(a)), nil
+	}
+	return 0, ErrMalformedCode
+}
@@ -0,0 +1,95 @@
+package interpreter
+
+import (
+	"fmt"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ executor.Memory = (*Memory)(nil)
+
+// pageSize is the size of a page of wasm memory
+const pageSize = 65536
+
+// maxMemoryPages bounds the memories below 4 GiB, so that their length is
+// always representable by Length
+const maxMemoryPages = 65535
+
+// Memory is the linear memory of an interpreter instance, held by the Go heap
+type Memory struct {
+	data     []byte
+	maxPages uint32
+}
+
+func newMemory(memoryLimits *limits) *Memory {
+	maxPages := uint32(maxMemoryPages)
+	if memoryLimits.hasMax && memoryLimits.max < maxPages {
+		maxPages = memoryLimits.max
+	}
+	return &Memory{
+		data:     make([]byte, uint64(memoryLimits.min)*pageSize),
+		maxPages: maxPages,
+	}
+}
+
+// Length returns the size of the memory, in bytes
+func (memory *Memory) Length() uint32 {
+	return uint32(len(memory.data))
+}
+
+// Data returns the bytes of the memory; the slice is replaced when the memory grows
+func (memory *Memory) Data() []byte {
+	return memory.data
+}
+
+// Grow adds the given number of pages to the memory, filled with zeros
+func (memory *Memory) Grow(pages uint32) error {
+	currentPages := memory.pages()
+	if uint64(currentPages)+uint64(pages) > uint64(memory.maxPages) {
+		return fmt.Errorf("%w: %d pages added to %d, over the maximum of %d",
+			ErrMemoryLimitExceeded, pages, currentPages, memory.maxPages)
+	}
+	if pages == 0 {
+		return nil
+	}
+
+	grown := make([]byte, uint64(currentPages+pages)*pageSize)
+	copy(grown, memory.data)
+	memory.data = grown
+	return nil
+}
+
+// Destroy releases the bytes of the memory
+func (memory *Memory) Destroy() {
+	memory.data = nil
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (memory *Memory) IsInterfaceNil() bool {
+	return memory == nil
+}
+
+func (memory *Memory) pages() uint32 {
+	return uint32(len(memory.data) / pageSize)
+}
+
+// reset restores the memory to its initial size, filled with zeros
+func (memory *Memory) reset(initialPages uint32) {
+	size := uint64(initialPages) * pageSize
+	if uint64(len(memory.data)) != size {
+		memory.data = make([]byte, size)
+		return
+	}
+	for i := range memory.data {
+		memory.data[i] = 0
+	}
+}
+
+// bytesAt returns the bytes of the memory accessed at the address, or an
+// error if they are not all inside the memory
+func (memory *Memory) bytesAt(address uint64, length uint64) ([]byte, error) {
+	if address+length > uint64(len(memory.data)) {
+		return nil, ErrMemoryAccessOutOfBounds
+	}
+	return memory.data[address : address+length], nil
+}
@@ -0,0 +1,735 @@
+package interpreter
+
+import (
+	"bytes"
+	"fmt"
+
+	"github.com/ElrondNetwork/wasm-vm/wasmreader"
+)
+
+var wasmMagic = []byte{0x00, 0x61, 0x73, 0x6d}
+var wasmVersion = []byte{0x01, 0x00, 0x00, 0x00}
+
+// importModule is the module under which the contracts import the VM hooks
+const importModule = "env"
+
+// maxLocals bounds the locals of a function, so that a contract cannot make
+// every call allocate an unreasonable frame
+const maxLocals = 50000
+
+const (
+	sectionCustom    = 0
+	sectionType      = 1
+	sectionImport    = 2
+	sectionFunction  = 3
+	sectionTable     = 4
+	sectionMemory    = 5
+	sectionGlobal    = 6
+	sectionExport    = 7
+	sectionStart     = 8
+	sectionElement   = 9
+	sectionCode      = 10
+	sectionData      = 11
+	sectionDataCount = 12
+)
+
+const (
+	externalFunction = 0x00
+	externalTable    = 0x01
+	externalMemory   = 0x02
+	externalGlobal   = 0x03
+)
+
+type valueType byte
+
+const (
+	valueTypeI32 valueType = 0x7f
+	valueTypeI64 valueType = 0x7e
+	valueTypeF32 valueType = 0x7d
+	valueTypeF64 valueType = 0x7c
+)
+
+const (
+	functionTypeForm = 0x60
+	elementTypeFunc  = 0x70
+	blockTypeEmpty   = 0x40
+)
+
+func readValueType(r *wasmreader.Reader) (valueType, error) {
+	b, err := r.ReadByte()
+	if err != nil {
+		return 0, err
+	}
+	switch valueType(b) {
+	case valueTypeI32, valueTypeI64, valueTypeF32, valueTypeF64:
+		return valueType(b), nil
+	}
+	return 0, fmt.Errorf("%w: value type 0x%02x", ErrUnsupportedFeature, b)
+}
+
+type functionType struct {
+	params  []valueType
+	results []valueType
+}
+
+func (ft *functionType) equals(other *functionType) bool {
+	return ft == other || (equalValueTypes(ft.params, other.params) && equalValueTypes(ft.results, other.results))
+}
+
+func equalValueTypes(types []valueType, others []valueType) bool {
+	if len(types) != len(others) {
+		return false
+	}
+	for i := range types {
+		if types[i] != others[i] {
+			return false
+		}
+	}
+	return true
+}
+
+type limits struct {
+	min    uint32
+	max    uint32
+	hasMax bool
+}
+
+type importedFunction struct {
+	module    string
+	name      string
+	typeIndex uint32
+}
+
+// function is a function defined by the module, with its body compiled
+type function struct {
+	typeIndex uint32
+	locals    []valueType
+	body      []byte
+	code      []instruction
+	blocks    []blockInfo
+	tables    [][]uint32
+}
+
+// constExpr is the constant expression initializing a global or placing a
+// segment: either a constant or the value of a previous global
+type constExpr struct {
+	value       uint64
+	globalIndex uint32
+	isGlobal    bool
+}
+
+type globalDefinition struct {
+	valueType valueType
+	mutable   bool
+	init      constExpr
+}
+
+type elementSegment struct {
+	offset    constExpr
+	functions []uint32
+}
+
+type dataSegment struct {
+	offset constExpr
+	data   []byte
+}
+
+// module is a decoded wasm module; the functions imported by the module come
+// first in the function index space, followed by the functions it defines
+type module struct {
+	types          []*functionType
+	imports        []*importedFunction
+	functions      []*function
+	table          *limits
+	memory         *limits
+	globals        []*globalDefinition
+	exports        map[string]uint32
+	memoryExported bool
+	startFunction  uint32
+	hasStart       bool
+	elements       []*elementSegment
+	data           []*dataSegment
+}
+
+// decodeModule decodes a wasm module of the MVP, with the sign-extension and
+// the non-trapping conversion operators, and compiles its functions
+func decodeModule(code []byte) (*module, error) {
+	if len(code) < 8 || !bytes.Equal(code[:4], wasmMagic) {
+		return nil, ErrInvalidBytecode
+	}
+	if !bytes.Equal(code[4:8], wasmVersion) {
+		return nil, fmt.Errorf("%w: unsupported version", ErrInvalidModule)
+	}
+
+	m := &module{
+		exports: make(map[string]uint32),
+	}
+	functionTypes := make([]uint32, 0)
+	r := wasmreader.NewReader(code[8:])
+	lastSectionOrder := 0
+	for r.HasMore() {
+		sectionID, err := r.ReadByte()
+		if err != nil {
+			return nil, err
+		}
+		size, err := r.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		content, err := r.ReadBytes(size)
+		if err != nil {
+			return nil, err
+		}
+		if sectionID == sectionCustom {
+			continue
+		}
+		if sectionOrder(sectionID) <= lastSectionOrder {
+			return nil, fmt.Errorf("%w: section %d out of order", ErrInvalidModule, sectionID)
+		}
+		lastSectionOrder = sectionOrder(sectionID)
+
+		sectionReader := wasmreader.NewReader(content)
+		switch sectionID {
+		case sectionType:
+			err = m.decodeTypes(sectionReader)
+		case sectionImport:
+			err = m.decodeImports(sectionReader)
+		case sectionFunction:
+			functionTypes, err = readIndices(sectionReader)
+		case sectionTable:
+			err = m.decodeTable(sectionReader)
+		case sectionMemory:
+			err = m.decodeMemory(sectionReader)
+		case sectionGlobal:
+			err = m.decodeGlobals(sectionReader)
+		case sectionExport:
+			err = m.decodeExports(sectionReader)
+		case sectionStart:
+			m.startFunction, err = sectionReader.ReadU32()
+			m.hasStart = true
+		case sectionElement:
+			err = m.decodeElements(sectionReader)
+		case sectionCode:
+			err = m.decodeCode(sectionReader, functionTypes)
+		case sectionData:
+			err = m.decodeData(sectionReader)
+		case sectionDataCount:
+			_, err = sectionReader.ReadU32()
+		default:
+			err = fmt.Errorf("%w: unknown section %d", ErrInvalidModule, sectionID)
+		}
+		if err != nil {
+			return nil, err
+		}
+		if sectionReader.HasMore() {
+			return nil, fmt.Errorf("%w: trailing bytes in section %d", ErrInvalidModule, sectionID)
+		}
+	}
+
+	if len(m.functions) != len(functionTypes) {
+		return nil, fmt.Errorf("%w: %d functions declared, %d bodies", ErrInvalidModule, len(functionTypes), len(m.functions))
+	}
+	err := m.checkIndices()
+	if err != nil {
+		return nil, err
+	}
+	return m, m.compileFunctions()
+}
+
+// sectionOrder returns the rank of a section in the module, which is its ID,
+// except for the data count section, placed before the code section
+func sectionOrder(sectionID byte) int {
+	if sectionID == sectionDataCount {
+		return 2*sectionCode - 1
+	}
+	return 2 * int(sectionID)
+}
+
+func (m *module) numFunctions() int {
+	return len(m.imports) + len(m.functions)
+}
+
+// functionType returns the type of a function of the function index space
+func (m *module) functionType(index uint32) *functionType {
+	if int(index) < len(m.imports) {
+		return m.types[m.imports[index].typeIndex]
+	}
+	return m.types[m.functions[int(index)-len(m.imports)].typeIndex]
+}
+
+func (m *module) decodeTypes(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		form, err := r.ReadByte()
+		if err != nil {
+			return err
+		}
+		if form != functionTypeForm {
+			return fmt.Errorf("%w: type form 0x%02x", ErrInvalidModule, form)
+		}
+		params, err := readValueTypes(r)
+		if err != nil {
+			return err
+		}
+		results, err := readValueTypes(r)
+		if err != nil {
+			return err
+		}
+		m.types = append(m.types, &functionType{params: params, results: results})
+	}
+	return nil
+}
+
+func readValueTypes(r *wasmreader.Reader) ([]valueType, error) {
+	count, err := r.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	if count > uint32(r.Remaining()) {
+		return nil, wasmreader.ErrUnexpectedEnd
+	}
+	types := make([]valueType, count)
+	for i := range types {
+		types[i], err = readValueType(r)
+		if err != nil {
+			return nil, err
+		}
+	}
+	return types, nil
+}
+
+func (m *module) decodeImports(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		moduleName, err := r.ReadName()
+		if err != nil {
+			return err
+		}
+		name, err := r.ReadName()
+		if err != nil {
+			return err
+		}
+		kind, err := r.ReadByte()
+		if err != nil {
+			return err
+		}
+		if kind != externalFunction {
+			return fmt.Errorf("%w: import of %s.%s of kind %d", ErrUnsupportedFeature, moduleName, name, kind)
+		}
+		typeIndex, err := r.ReadU32()
+		if err != nil {
+			return err
+		}
+		m.imports = append(m.imports, &importedFunction{
+			module:    moduleName,
+			name:      name,
+			typeIndex: typeIndex,
+		})
+	}
+	return nil
+}
+
+// readIndices decodes a vector of indices, each taking at least one byte
+func readIndices(r *wasmreader.Reader) ([]uint32, error) {
+	count, err := r.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	if count > uint32(r.Remaining()) {
+		return nil, wasmreader.ErrUnexpectedEnd
+	}
+	indices := make([]uint32, count)
+	for i := range indices {
+		indices[i], err = r.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+	}
+	return indices, nil
+}
+
+func readLimits(r *wasmreader.Reader) (*limits, error) {
+	flags, err := r.ReadByte()
+	if err != nil {
+		return nil, err
+	}
+	if flags > 1 {
+		return nil, fmt.Errorf("%w: limits flags 0x%02x", ErrUnsupportedFeature, flags)
+	}
+
+	result := &limits{}
+	result.min, err = r.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	if flags == 1 {
+		result.hasMax = true
+		result.max, err = r.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		if result.max < result.min {
+			return nil, fmt.Errorf("%w: maximum below minimum", ErrInvalidModule)
+		}
+	}
+	return result, nil
+}
+
+func (m *module) decodeTable(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	if count > 1 {
+		return fmt.Errorf("%w: %d tables", ErrUnsupportedFeature, count)
+	}
+	if count == 0 {
+		return nil
+	}
+	elementType, err := r.ReadByte()
+	if err != nil {
+		return err
+	}
+	if elementType != elementTypeFunc {
+		return fmt.Errorf("%w: table of type 0x%02x", ErrUnsupportedFeature, elementType)
+	}
+	m.table, err = readLimits(r)
+	return err
+}
+
+func (m *module) decodeMemory(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	if count > 1 {
+		return fmt.Errorf("%w: %d memories", ErrUnsupportedFeature, count)
+	}
+	if count == 0 {
+		return nil
+	}
+	m.memory, err = readLimits(r)
+	if err != nil {
+		return err
+	}
+	if m.memory.min > maxMemoryPages || (m.memory.hasMax && m.memory.max > maxMemoryPages) {
+		return fmt.Errorf("%w: memory of more than %d pages", ErrInvalidModule, maxMemoryPages)
+	}
+	return nil
+}
+
+func (m *module) decodeGlobals(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		globalType, err := readValueType(r)
+		if err != nil {
+			return err
+		}
+		mutability, err := r.ReadByte()
+		if err != nil {
+			return err
+		}
+		if mutability > 1 {
+			return fmt.Errorf("%w: global mutability 0x%02x", ErrInvalidModule, mutability)
+		}
+		init, err := readConstExpr(r)
+		if err != nil {
+			return err
+		}
+		if init.isGlobal && init.globalIndex >= uint32(len(m.globals)) {
+			return fmt.Errorf("%w: global %d initialized from a later global", ErrInvalidModule, i)
+		}
+		m.globals = append(m.globals, &globalDefinition{
+			valueType: globalType,
+			mutable:   mutability == 1,
+			init:      init,
+		})
+	}
+	return nil
+}
+
+func (m *module) decodeExports(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		name, err := r.ReadName()
+		if err != nil {
+			return err
+		}
+		kind, err := r.ReadByte()
+		if err != nil {
+			return err
+		}
+		index, err := r.ReadU32()
+		if err != nil {
+			return err
+		}
+		switch kind {
+		case externalFunction:
+			if _, exists := m.exports[name]; exists {
+				return fmt.Errorf("%w: duplicate export %s", ErrInvalidModule, name)
+			}
+			m.exports[name] = index
+		case externalMemory:
+			m.memoryExported = true
+		case externalTable, externalGlobal:
+		default:
+			return fmt.Errorf("%w: export %s of kind %d", ErrInvalidModule, name, kind)
+		}
+	}
+	return nil
+}
+
+func (m *module) decodeElements(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		flags, err := r.ReadU32()
+		if err != nil {
+			return err
+		}
+		if flags != 0 {
+			return fmt.Errorf("%w: element segment flags %d", ErrUnsupportedFeature, flags)
+		}
+		offset, err := readConstExpr(r)
+		if err != nil {
+			return err
+		}
+		functions, err := readIndices(r)
+		if err != nil {
+			return err
+		}
+		m.elements = append(m.elements, &elementSegment{
+			offset:    offset,
+			functions: functions,
+		})
+	}
+	return nil
+}
+
+func (m *module) decodeCode(r *wasmreader.Reader, functionTypes []uint32) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	if count != uint32(len(functionTypes)) {
+		return fmt.Errorf("%w: %d functions declared, %d bodies", ErrInvalidModule, len(functionTypes), count)
+	}
+	for i := uint32(0); i < count; i++ {
+		size, err := r.ReadU32()
+		if err != nil {
+			return err
+		}
+		content, err := r.ReadBytes(size)
+		if err != nil {
+			return err
+		}
+		bodyReader := wasmreader.NewReader(content)
+		locals, err := readLocals(bodyReader)
+		if err != nil {
+			return err
+		}
+		m.functions = append(m.functions, &function{
+			typeIndex: functionTypes[i],
+			locals:    locals,
+			body:      bodyReader.Rest(),
+		})
+	}
+	return nil
+}
+
+func readLocals(r *wasmreader.Reader) ([]valueType, error) {
+	count, err := r.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	locals := make([]valueType, 0)
+	for i := uint32(0); i < count; i++ {
+		numLocals, err := r.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		localType, err := readValueType(r)
+		if err != nil {
+			return nil, err
+		}
+		if uint64(len(locals))+uint64(numLocals) > maxLocals {
+			return nil, fmt.Errorf("%w: more than %d locals", ErrUnsupportedFeature, maxLocals)
+		}
+		for j := uint32(0); j < numLocals; j++ {
+			locals = append(locals, localType)
+		}
+	}
+	return locals, nil
+}
+
+func (m *module) decodeData(r *wasmreader.Reader) error {
+	count, err := r.ReadU32()
+	if err != nil {
+		return err
+	}
+	for i := uint32(0); i < count; i++ {
+		flags, err := r.ReadU32()
+		if err != nil {
+			return err
+		}
+		if flags != 0 {
+			return fmt.Errorf("%w: data segment flags %d", ErrUnsupportedFeature, flags)
+		}
+		offset, err := readConstExpr(r)
+		if err != nil {
+			return err
+		}
+		length, err := r.ReadU32()
+		if err != nil {
+			return err
+		}
+		data, err := r.ReadBytes(length)
+		if err != nil {
+			return err
+		}
+		m.data = append(m.data, &dataSegment{
+			offset: offset,
+			data:   data,
+		})
+	}
+	return nil
+}
+
+// readConstExpr decodes a constant expression made of a single constant or
+// global.get, followed by end
+func readConstExpr(r *wasmreader.Reader) (constExpr, error) {
+	expr := constExpr{}
+	opcode, err := r.ReadByte()
+	if err != nil {
+		return expr, err
+	}
+
+	switch opcode {
+	case opI32Const:
+		var value int64
+		value, err = r.ReadSigned(32)
+		expr.value = uint64(uint32(value))
+	case opI64Const:
+		var value int64
+		value, err = r.ReadSigned(64)
+		expr.value = uint64(value)
+	case opF32Const:
+		var bits uint32
+		bits, err = r.ReadFixedU32()
+		expr.value = uint64(bits)
+	case opF64Const:
+		expr.value, err = r.ReadFixedU64()
+	case opGlobalGet:
+		expr.globalIndex, err = r.ReadU32()
+		expr.isGlobal = true
+	default:
+		return expr, fmt.Errorf("%w: opcode 0x%02x in constant expression", ErrInvalidModule, opcode)
+	}
+	if err != nil {
+		return expr, err
+	}
+
+	end, err := r.ReadByte()
+	if err != nil {
+		return expr, err
+	}
+	if end != opEnd {
+		return expr, fmt.Errorf("%w: constant expression of more than one instruction", ErrUnsupportedFeature)
+	}
+	return expr, nil
+}
+
+// checkIndices checks the indices which the sections hold into each other,
+// so that the instances never have to
+func (m *module) checkIndices() error {
+	numTypes := uint32(len(m.types))
+	for _, imported := range m.imports {
+		if imported.typeIndex >= numTypes {
+			return fmt.Errorf("%w: import %s of unknown type %d", ErrInvalidModule, imported.name, imported.typeIndex)
+		}
+	}
+	for i, fn := range m.functions {
+		if fn.typeIndex >= numTypes {
+			return fmt.Errorf("%w: function %d of unknown type %d", ErrInvalidModule, i, fn.typeIndex)
+		}
+	}
+
+	numFunctions := uint32(m.numFunctions())
+	for name, index := range m.exports {
+		if index >= numFunctions {
+			return fmt.Errorf("%w: export %s of unknown function %d", ErrInvalidModule, name, index)
+		}
+	}
+	if m.hasStart {
+		if m.startFunction >= numFunctions {
+			return fmt.Errorf("%w: unknown start function %d", ErrInvalidModule, m.startFunction)
+		}
+		startType := m.functionType(m.startFunction)
+		if len(startType.params) != 0 || len(startType.results) != 0 {
+			return fmt.Errorf("%w: start function with a nonvoid signature", ErrInvalidModule)
+		}
+	}
+
+	if len(m.elements) > 0 && m.table == nil {
+		return fmt.Errorf("%w: element segments without a table", ErrInvalidModule)
+	}
+	for _, element := range m.elements {
+		for _, index := range element.functions {
+			if index >= numFunctions {
+				return fmt.Errorf("%w: element of unknown function %d", ErrInvalidModule, index)
+			}
+		}
+		err := m.checkOffset(element.offset)
+		if err != nil {
+			return err
+		}
+	}
+
+	if len(m.data) > 0 && m.memory == nil {
+		return fmt.Errorf("%w: data segments without a memory", ErrInvalidModule)
+	}
+	for _, segment := range m.data {
+		err := m.checkOffset(segment.offset)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (m *module) checkOffset(offset constExpr) error {
+	if !offset.isGlobal {
+		return nil
+	}
+	if offset.globalIndex >= uint32(len(m.globals)) {
+		return fmt.Errorf("%w: offset of unknown global %d", ErrInvalidModule, offset.globalIndex)
+	}
+	if m.globals[offset.globalIndex].valueType != valueTypeI32 {
+		return fmt.Errorf("%w: offset of a global which is not i32", ErrInvalidModule)
+	}
+	return nil
+}
+
+func (m *module) compileFunctions() error {
+	for i, fn := range m.functions {
+		err := m.compileFunction(fn)
+		if err != nil {
+			return fmt.Errorf("function %d: %w", len(m.imports)+i, err)
+		}
+	}
+	return nil
+}
@@ -0,0 +1,163 @@
+package interpreter
+
+import "math"
+
+const (
+	f32SignBit = 0x80000000
+	f64SignBit = 0x8000000000000000
+)
+
+func boolValue(condition bool) uint64 {
+	if condition {
+		return 1
+	}
+	return 0
+}
+
+func asI32(value uint64) int32 {
+	return int32(uint32(value))
+}
+
+func fromI32(value int32) uint64 {
+	return uint64(uint32(value))
+}
+
+func asF32(value uint64) float32 {
+	return math.Float32frombits(uint32(value))
+}
+
+func fromF32(value float32) uint64 {
+	return uint64(math.Float32bits(value))
+}
+
+func asF64(value uint64) float64 {
+	return math.Float64frombits(value)
+}
+
+func fromF64(value float64) uint64 {
+	return math.Float64bits(value)
+}
+
+// wasmMin is the minimum of wasm, which is NaN if either operand is, and
+// which orders -0 below +0
+func wasmMin(a float64, b float64) float64 {
+	if math.IsNaN(a) || math.IsNaN(b) {
+		return math.NaN()
+	}
+	if a == 0 && b == 0 {
+		if math.Signbit(a) {
+			return a
+		}
+		return b
+	}
+	if a < b {
+		return a
+	}
+	return b
+}
+
+// wasmMax is the maximum of wasm, which is NaN if either operand is, and
+// which orders -0 below +0
+func wasmMax(a float64, b float64) float64 {
+	if math.IsNaN(a) || math.IsNaN(b) {
+		return math.NaN()
+	}
+	if a == 0 && b == 0 {
+		if math.Signbit(a) {
+			return b
+		}
+		return a
+	}
+	if a > b {
+		return a
+	}
+	return b
+}
+
+func truncToI32(value float64) (uint64, error) {
+	if math.IsNaN(value) {
+		return 0, ErrInvalidConversion
+	}
+	truncated := math.Trunc(value)
+	if truncated < math.MinInt32 || truncated > math.MaxInt32 {
+		return 0, ErrIntegerOverflow
+	}
+	return fromI32(int32(truncated)), nil
+}
+
+func truncToU32(value float64) (uint64, error) {
+	if math.IsNaN(value) {
+		return 0, ErrInvalidConversion
+	}
+	truncated := math.Trunc(value)
+	if truncated < 0 || truncated > math.MaxUint32 {
+		return 0, ErrIntegerOverflow
+	}
+	return uint64(uint32(truncated)), nil
+}
+
+func truncToI64(value float64) (uint64, error) {
+	if math.IsNaN(value) {
+		return 0, ErrInvalidConversion
+	}
+	truncated := math.Trunc(value)
+	if truncated < -(1<<63) || truncated >= 1<<63 {
+		return 0, ErrIntegerOverflow
+	}
+	return uint64(int64(truncated)), nil
+}
+
+func truncToU64(value float64) (uint64, error) {
+	if math.IsNaN(value) {
+		return 0, ErrInvalidConversion
+	}
+	truncated := math.Trunc(value)
+	if truncated < 0 || truncated >= 1<<64 {
+		return 0, ErrIntegerOverflow
+	}
+	return uint64(truncated), nil
+}
+
+func truncSatToI32(value float64) uint64 {
+	switch {
+	case math.IsNaN(value):
+		return 0
+	case value <= math.MinInt32:
+		return fromI32(math.MinInt32)
+	case value >= math.MaxInt32:
+		return fromI32(math.MaxInt32)
+	}
+	return fromI32(int32(value))
+}
+
+func truncSatToU32(value float64) uint64 {
+	switch {
+	case math.IsNaN(value), value <= 0:
+		return 0
+	case value >= math.MaxUint32:
+		return math.MaxUint32
+	}
+	return uint64(uint32(value))
+}
+
+func truncSatToI64(value float64) uint64 {
+	switch {
+	case math.IsNaN(value):
+		return 0
+	case value <= -(1 << 63):
+		return 1 << 63
+	case value >= 1<<63:
+		return math.MaxInt64
+	}
+	return uint64(int64(value))
+}
+
+func truncSatToU64(value float64) uint64 {
+	switch {
+	case math.IsNaN(value), value <= 0:
+		return 0
+	case value >= 1<<64:
+		return math.MaxUint64
+	}
+	return uint64(value)
+}
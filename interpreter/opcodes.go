@@ -0,0 +1,402 @@
+package interpreter
+
+import "github.com/ElrondNetwork/wasm-vm/executor"
+
+// The opcodes of the instructions executed by the interpreter; the
+// non-trapping conversions, encoded after the 0xfc prefix, are numbered from
+// opMiscBase, so that every instruction has its own opcode
+const (
+	opUnreachable       = 0x00
+	opNop               = 0x01
+	opBlock             = 0x02
+	opLoop              = 0x03
+	opIf                = 0x04
+	opElse              = 0x05
+	opEnd               = 0x0b
+	opBr                = 0x0c
+	opBrIf              = 0x0d
+	opBrTable           = 0x0e
+	opReturn            = 0x0f
+	opCall              = 0x10
+	opCallIndirect      = 0x11
+	opDrop              = 0x1a
+	opSelect            = 0x1b
+	opLocalGet          = 0x20
+	opLocalSet          = 0x21
+	opLocalTee          = 0x22
+	opGlobalGet         = 0x23
+	opGlobalSet         = 0x24
+	opI32Load           = 0x28
+	opI64Load           = 0x29
+	opF32Load           = 0x2a
+	opF64Load           = 0x2b
+	opI32Load8S         = 0x2c
+	opI32Load8U         = 0x2d
+	opI32Load16S        = 0x2e
+	opI32Load16U        = 0x2f
+	opI64Load8S         = 0x30
+	opI64Load8U         = 0x31
+	opI64Load16S        = 0x32
+	opI64Load16U        = 0x33
+	opI64Load32S        = 0x34
+	opI64Load32U        = 0x35
+	opI32Store          = 0x36
+	opI64Store          = 0x37
+	opF32Store          = 0x38
+	opF64Store          = 0x39
+	opI32Store8         = 0x3a
+	opI32Store16        = 0x3b
+	opI64Store8         = 0x3c
+	opI64Store16        = 0x3d
+	opI64Store32        = 0x3e
+	opMemorySize        = 0x3f
+	opMemoryGrow        = 0x40
+	opI32Const          = 0x41
+	opI64Const          = 0x42
+	opF32Const          = 0x43
+	opF64Const          = 0x44
+	opI32Eqz            = 0x45
+	opI32Eq             = 0x46
+	opI32Ne             = 0x47
+	opI32LtS            = 0x48
+	opI32LtU            = 0x49
+	opI32GtS            = 0x4a
+	opI32GtU            = 0x4b
+	opI32LeS            = 0x4c
+	opI32LeU            = 0x4d
+	opI32GeS            = 0x4e
+	opI32GeU            = 0x4f
+	opI64Eqz            = 0x50
+	opI64Eq             = 0x51
+	opI64Ne             = 0x52
+	opI64LtS            = 0x53
+	opI64LtU            = 0x54
+	opI64GtS            = 0x55
+	opI64GtU            = 0x56
+	opI64LeS            = 0x57
+	opI64LeU            = 0x58
+	opI64GeS            = 0x59
+	opI64GeU            = 0x5a
+	opF32Eq             = 0x5b
+	opF32Ne             = 0x5c
+	opF32Lt             = 0x5d
+	opF32Gt             = 0x5e
+	opF32Le             = 0x5f
+	opF32Ge             = 0x60
+	opF64Eq             = 0x61
+	opF64Ne             = 0x62
+	opF64Lt             = 0x63
+	opF64Gt             = 0x64
+	opF64Le             = 0x65
+	opF64Ge             = 0x66
+	opI32Clz            = 0x67
+	opI32Ctz            = 0x68
+	opI32Popcnt         = 0x69
+	opI32Add            = 0x6a
+	opI32Sub            = 0x6b
+	opI32Mul            = 0x6c
+	opI32DivS           = 0x6d
+	opI32DivU           = 0x6e
+	opI32RemS           = 0x6f
+	opI32RemU           = 0x70
+	opI32And            = 0x71
+	opI32Or             = 0x72
+	opI32Xor            = 0x73
+	opI32Shl            = 0x74
+	opI32ShrS           = 0x75
+	opI32ShrU           = 0x76
+	opI32Rotl           = 0x77
+	opI32Rotr           = 0x78
+	opI64Clz            = 0x79
+	opI64Ctz            = 0x7a
+	opI64Popcnt         = 0x7b
+	opI64Add            = 0x7c
+	opI64Sub            = 0x7d
+	opI64Mul            = 0x7e
+	opI64DivS           = 0x7f
+	opI64DivU           = 0x80
+	opI64RemS           = 0x81
+	opI64RemU           = 0x82
+	opI64And            = 0x83
+	opI64Or             = 0x84
+	opI64Xor            = 0x85
+	opI64Shl            = 0x86
+	opI64ShrS           = 0x87
+	opI64ShrU           = 0x88
+	opI64Rotl           = 0x89
+	opI64Rotr           = 0x8a
+	opF32Abs            = 0x8b
+	opF32Neg            = 0x8c
+	opF32Ceil           = 0x8d
+	opF32Floor          = 0x8e
+	opF32Trunc          = 0x8f
+	opF32Nearest        = 0x90
+	opF32Sqrt           = 0x91
+	opF32Add            = 0x92
+	opF32Sub            = 0x93
+	opF32Mul            = 0x94
+	opF32Div            = 0x95
+	opF32Min            = 0x96
+	opF32Max            = 0x97
+	opF32Copysign       = 0x98
+	opF64Abs            = 0x99
+	opF64Neg            = 0x9a
+	opF64Ceil           = 0x9b
+	opF64Floor          = 0x9c
+	opF64Trunc          = 0x9d
+	opF64Nearest        = 0x9e
+	opF64Sqrt           = 0x9f
+	opF64Add            = 0xa0
+	opF64Sub            = 0xa1
+	opF64Mul            = 0xa2
+	opF64Div            = 0xa3
+	opF64Min            = 0xa4
+	opF64Max            = 0xa5
+	opF64Copysign       = 0xa6
+	opI32WrapI64        = 0xa7
+	opI32TruncF32S      = 0xa8
+	opI32TruncF32U      = 0xa9
+	opI32TruncF64S      = 0xaa
+	opI32TruncF64U      = 0xab
+	opI64ExtendI32S     = 0xac
+	opI64ExtendI32U     = 0xad
+	opI64TruncF32S      = 0xae
+	opI64TruncF32U      = 0xaf
+	opI64TruncF64S      = 0xb0
+	opI64TruncF64U      = 0xb1
+	opF32ConvertI32S    = 0xb2
+	opF32ConvertI32U    = 0xb3
+	opF32ConvertI64S    = 0xb4
+	opF32ConvertI64U    = 0xb5
+	opF32DemoteF64      = 0xb6
+	opF64ConvertI32S    = 0xb7
+	opF64ConvertI32U    = 0xb8
+	opF64ConvertI64S    = 0xb9
+	opF64ConvertI64U    = 0xba
+	opF64PromoteF32     = 0xbb
+	opI32ReinterpretF32 = 0xbc
+	opI64ReinterpretF64 = 0xbd
+	opF32ReinterpretI32 = 0xbe
+	opF64ReinterpretI64 = 0xbf
+	opI32Extend8S       = 0xc0
+	opI32Extend16S      = 0xc1
+	opI64Extend8S       = 0xc2
+	opI64Extend16S      = 0xc3
+	opI64Extend32S      = 0xc4
+	opPrefixMisc        = 0xfc
+
+	opMiscBase        = 0x100
+	opI32TruncSatF32S = opMiscBase + 0
+	opI32TruncSatF32U = opMiscBase + 1
+	opI32TruncSatF64S = opMiscBase + 2
+	opI32TruncSatF64U = opMiscBase + 3
+	opI64TruncSatF32S = opMiscBase + 4
+	opI64TruncSatF32U = opMiscBase + 5
+	opI64TruncSatF64S = opMiscBase + 6
+	opI64TruncSatF64U = opMiscBase + 7
+	numOpcodes        = opMiscBase + 8
+)
+
+// opcodeCosts are the gas costs of the instructions, by opcode
+type opcodeCosts [numOpcodes]uint64
+
+// newOpcodeCosts arranges the costs of the gas schedule by opcode; the
+// instructions which the interpreter does not execute have no cost
+func newOpcodeCosts(costs *executor.WASMOpcodeCost) *opcodeCosts {
+	result := &opcodeCosts{}
+	if costs == nil {
+		return result
+	}
+
+	byOpcode := map[int]uint32{
+		opUnreachable:       costs.Unreachable,
+		opNop:               costs.Nop,
+		opBlock:             costs.Block,
+		opLoop:              costs.Loop,
+		opIf:                costs.If,
+		opElse:              costs.Else,
+		opEnd:               costs.End,
+		opBr:                costs.Br,
+		opBrIf:              costs.BrIf,
+		opBrTable:           costs.BrTable,
+		opReturn:            costs.Return,
+		opCall:              costs.Call,
+		opCallIndirect:      costs.CallIndirect,
+		opDrop:              costs.Drop,
+		opSelect:            costs.Select,
+		opLocalGet:          costs.LocalGet,
+		opLocalSet:          costs.LocalSet,
+		opLocalTee:          costs.LocalTee,
+		opGlobalGet:         costs.GlobalGet,
+		opGlobalSet:         costs.GlobalSet,
+		opI32Load:           costs.I32Load,
+		opI64Load:           costs.I64Load,
+		opF32Load:           costs.F32Load,
+		opF64Load:           costs.F64Load,
+		opI32Load8S:         costs.I32Load8S,
+		opI32Load8U:         costs.I32Load8U,
+		opI32Load16S:        costs.I32Load16S,
+		opI32Load16U:        costs.I32Load16U,
+		opI64Load8S:         costs.I64Load8S,
+		opI64Load8U:         costs.I64Load8U,
+		opI64Load16S:        costs.I64Load16S,
+		opI64Load16U:        costs.I64Load16U,
+		opI64Load32S:        costs.I64Load32S,
+		opI64Load32U:        costs.I64Load32U,
+		opI32Store:          costs.I32Store,
+		opI64Store:          costs.I64Store,
+		opF32Store:          costs.F32Store,
+		opF64Store:          costs.F64Store,
+		opI32Store8:         costs.I32Store8,
+		opI32Store16:        costs.I32Store16,
+		opI64Store8:         costs.I64Store8,
+		opI64Store16:        costs.I64Store16,
+		opI64Store32:        costs.I64Store32,
+		opMemorySize:        costs.MemorySize,
+		opMemoryGrow:        costs.MemoryGrow,
+		opI32Const:          costs.I32Const,
+		opI64Const:          costs.I64Const,
+		opF32Const:          costs.F32Const,
+		opF64Const:          costs.F64Const,
+		opI32Eqz:            costs.I32Eqz,
+		opI32Eq:             costs.I32Eq,
+		opI32Ne:             costs.I32Ne,
+		opI32LtS:            costs.I32LtS,
+		opI32LtU:            costs.I32LtU,
+		opI32GtS:            costs.I32GtS,
+		opI32GtU:            costs.I32GtU,
+		opI32LeS:            costs.I32LeS,
+		opI32LeU:            costs.I32LeU,
+		opI32GeS:            costs.I32GeS,
+		opI32GeU:            costs.I32GeU,
+		opI64Eqz:            costs.I64Eqz,
+		opI64Eq:             costs.I64Eq,
+		opI64Ne:             costs.I64Ne,
+		opI64LtS:            costs.I64LtS,
+		opI64LtU:            costs.I64LtU,
+		opI64GtS:            costs.I64GtS,
+		opI64GtU:            costs.I64GtU,
+		opI64LeS:            costs.I64LeS,
+		opI64LeU:            costs.I64LeU,
+		opI64GeS:            costs.I64GeS,
+		opI64GeU:            costs.I64GeU,
+		opF32Eq:             costs.F32Eq,
+		opF32Ne:             costs.F32Ne,
+		opF32Lt:             costs.F32Lt,
+		opF32Gt:             costs.F32Gt,
+		opF32Le:             costs.F32Le,
+		opF32Ge:             costs.F32Ge,
+		opF64Eq:             costs.F64Eq,
+		opF64Ne:             costs.F64Ne,
+		opF64Lt:             costs.F64Lt,
+		opF64Gt:             costs.F64Gt,
+		opF64Le:             costs.F64Le,
+		opF64Ge:             costs.F64Ge,
+		opI32Clz:            costs.I32Clz,
+		opI32Ctz:            costs.I32Ctz,
+		opI32Popcnt:         costs.I32Popcnt,
+		opI32Add:            costs.I32Add,
+		opI32Sub:            costs.I32Sub,
+		opI32Mul:            costs.I32Mul,
+		opI32DivS:           costs.I32DivS,
+		opI32DivU:           costs.I32DivU,
+		opI32RemS:           costs.I32RemS,
+		opI32RemU:           costs.I32RemU,
+		opI32And:            costs.I32And,
+		opI32Or:             costs.I32Or,
+		opI32Xor:            costs.I32Xor,
+		opI32Shl:            costs.I32Shl,
+		opI32ShrS:           costs.I32ShrS,
+		opI32ShrU:           costs.I32ShrU,
+		opI32Rotl:           costs.I32Rotl,
+		opI32Rotr:           costs.I32Rotr,
+		opI64Clz:            costs.I64Clz,
+		opI64Ctz:            costs.I64Ctz,
+		opI64Popcnt:         costs.I64Popcnt,
+		opI64Add:            costs.I64Add,
+		opI64Sub:            costs.I64Sub,
+		opI64Mul:            costs.I64Mul,
+		opI64DivS:           costs.I64DivS,
+		opI64DivU:           costs.I64DivU,
+		opI64RemS:           costs.I64RemS,
+		opI64RemU:           costs.I64RemU,
+		opI64And:            costs.I64And,
+		opI64Or:             costs.I64Or,
+		opI64Xor:            costs.I64Xor,
+		opI64Shl:            costs.I64Shl,
+		opI64ShrS:           costs.I64ShrS,
+		opI64ShrU:           costs.I64ShrU,
+		opI64Rotl:           costs.I64Rotl,
+		opI64Rotr:           costs.I64Rotr,
+		opF32Abs:            costs.F32Abs,
+		opF32Neg:            costs.F32Neg,
+		opF32Ceil:           costs.F32Ceil,
+		opF32Floor:          costs.F32Floor,
+		opF32Trunc:          costs.F32Trunc,
+		opF32Nearest:        costs.F32Nearest,
+		opF32Sqrt:           costs.F32Sqrt,
+		opF32Add:            costs.F32Add,
+		opF32Sub:            costs.F32Sub,
+		opF32Mul:            costs.F32Mul,
+		opF32Div:            costs.F32Div,
+		opF32Min:            costs.F32Min,
+		opF32Max:            costs.F32Max,
+		opF32Copysign:       costs.F32Copysign,
+		opF64Abs:            costs.F64Abs,
+		opF64Neg:            costs.F64Neg,
+		opF64Ceil:           costs.F64Ceil,
+		opF64Floor:          costs.F64Floor,
+		opF64Trunc:          costs.F64Trunc,
+		opF64Nearest:        costs.F64Nearest,
+		opF64Sqrt:           costs.F64Sqrt,
+		opF64Add:            costs.F64Add,
+		opF64Sub:            costs.F64Sub,
+		opF64Mul:            costs.F64Mul,
+		opF64Div:            costs.F64Div,
+		opF64Min:            costs.F64Min,
+		opF64Max:            costs.F64Max,
+		opF64Copysign:       costs.F64Copysign,
+		opI32WrapI64:        costs.I32WrapI64,
+		opI32TruncF32S:      costs.I32TruncF32S,
+		opI32TruncF32U:      costs.I32TruncF32U,
+		opI32TruncF64S:      costs.I32TruncF64S,
+		opI32TruncF64U:      costs.I32TruncF64U,
+		opI64ExtendI32S:     costs.I64ExtendI32S,
+		opI64ExtendI32U:     costs.I64ExtendI32U,
+		opI64TruncF32S:      costs.I64TruncF32S,
+		opI64TruncF32U:      costs.I64TruncF32U,
+		opI64TruncF64S:      costs.I64TruncF64S,
+		opI64TruncF64U:      costs.I64TruncF64U,
+		opF32ConvertI32S:    costs.F32ConvertI32S,
+		opF32ConvertI32U:    costs.F32ConvertI32U,
+		opF32ConvertI64S:    costs.F32ConvertI64S,
+		opF32ConvertI64U:    costs.F32ConvertI64U,
+		opF32DemoteF64:      costs.F32DemoteF64,
+		opF64ConvertI32S:    costs.F64ConvertI32S,
+		opF64ConvertI32U:    costs.F64ConvertI32U,
+		opF64ConvertI64S:    costs.F64ConvertI64S,
+		opF64ConvertI64U:    costs.F64ConvertI64U,
+		opF64PromoteF32:     costs.F64PromoteF32,
+		opI32ReinterpretF32: costs.I32ReinterpretF32,
+		opI64ReinterpretF64: costs.I64ReinterpretF64,
+		opF32ReinterpretI32: costs.F32ReinterpretI32,
+		opF64ReinterpretI64: costs.F64ReinterpretI64,
+		opI32Extend8S:       costs.I32Extend8S,
+		opI32Extend16S:      costs.I32Extend16S,
+		opI64Extend8S:       costs.I64Extend8S,
+		opI64Extend16S:      costs.I64Extend16S,
+		opI64Extend32S:      costs.I64Extend32S,
+		opI32TruncSatF32S:   costs.I32TruncSatF32S,
+		opI32TruncSatF32U:   costs.I32TruncSatF32U,
+		opI32TruncSatF64S:   costs.I32TruncSatF64S,
+		opI32TruncSatF64U:   costs.I32TruncSatF64U,
+		opI64TruncSatF32S:   costs.I64TruncSatF32S,
+		opI64TruncSatF32U:   costs.I64TruncSatF32U,
+		opI64TruncSatF64S:   costs.I64TruncSatF64S,
+		opI64TruncSatF64U:   costs.I64TruncSatF64U,
+	}
+	for opcode, cost := range byOpcode {
+		result[opcode] = uint64(cost)
+	}
+	return result
+}
@@ -0,0 +1,402 @@
+package interpreter
+
+import (
+	"fmt"
+)
+
+// valueTypeUnknown is the type of the operands popped from the stack of the
+// unreachable code, which matches every type
+const valueTypeUnknown valueType = 0
+
+// controlFrame is a block, a loop, an if or the function body itself, open
+// while the types of the operands of a function body are checked
+type controlFrame struct {
+	opcode      byte
+	signature   *functionType
+	height      int
+	unreachable bool
+}
+
+// labelTypes are the types of the operands of the branches to the frame:
+// the params of a loop, which restart it, or the results of the others
+func (frame *controlFrame) labelTypes() []valueType {
+	if frame.opcode == opLoop {
+		return frame.signature.params
+	}
+	return frame.signature.results
+}
+
+// operandTypes tracks the types of the operands of a function body, as the
+// validation algorithm of the wasm specification does
+type operandTypes struct {
+	stack  []valueType
+	frames []*controlFrame
+}
+
+// checkOperandTypes checks that the instructions of a compiled function body
+// find operands of the expected types on the stack, and that the blocks and
+// the function leave their results; like the wasmer executor, the
+// interpreter rejects the contracts failing these checks when compiling them
+func (m *module) checkOperandTypes(fn *function) error {
+	fnType := m.types[fn.typeIndex]
+	locals := make([]valueType, 0, len(fnType.params)+len(fn.locals))
+	locals = append(locals, fnType.params...)
+	locals = append(locals, fn.locals...)
+
+	operands := &operandTypes{}
+	operands.pushFrame(opBlock, &functionType{results: fnType.results})
+	for pc, instr := range fn.code {
+		err := operands.check(m, fn, locals, instr)
+		if err != nil {
+			return fmt.Errorf("instruction %d: %w", pc, err)
+		}
+	}
+	return nil
+}
+
+func (operands *operandTypes) check(m *module, fn *function, locals []valueType, instr instruction) error {
+	opcode := instr.opcode
+	switch {
+	case opcode == opUnreachable:
+		operands.setUnreachable()
+	case opcode == opNop:
+	case opcode == opBlock, opcode == opLoop:
+		signature := fn.blocks[instr.index].signature
+		err := operands.popTypes(signature.params)
+		if err != nil {
+			return err
+		}
+		operands.pushFrame(byte(opcode), signature)
+	case opcode == opIf:
+		signature := fn.blocks[instr.index].signature
+		err := operands.popTypes(withCondition(signature.params))
+		if err != nil {
+			return err
+		}
+		operands.pushFrame(opIf, signature)
+	case opcode == opElse:
+		frame, err := operands.popFrame()
+		if err != nil {
+			return err
+		}
+		operands.pushFrame(opElse, frame.signature)
+	case opcode == opEnd:
+		frame, err := operands.popFrame()
+		if err != nil {
+			return err
+		}
+		if frame.opcode == opIf && !equalValueTypes(frame.signature.params, frame.signature.results) {
+			return fmt.Errorf("%w: if without else whose results differ from its params", ErrInvalidModule)
+		}
+		operands.pushTypes(frame.signature.results)
+	case opcode == opBr:
+		err := operands.popTypes(operands.label(instr.index).labelTypes())
+		if err != nil {
+			return err
+		}
+		operands.setUnreachable()
+	case opcode == opBrIf:
+		labelTypes := operands.label(instr.index).labelTypes()
+		err := operands.popTypes(withCondition(labelTypes))
+		if err != nil {
+			return err
+		}
+		operands.pushTypes(labelTypes)
+	case opcode == opBrTable:
+		return operands.checkBranchTable(fn.tables[instr.index])
+	case opcode == opReturn:
+		err := operands.popTypes(operands.frames[0].signature.results)
+		if err != nil {
+			return err
+		}
+		operands.setUnreachable()
+	case opcode == opCall:
+		signature := m.functionType(instr.index)
+		return operands.checkCall(signature.params, signature.results)
+	case opcode == opCallIndirect:
+		signature := m.types[instr.index]
+		return operands.checkCall(withCondition(signature.params), signature.results)
+	case opcode == opDrop:
+		_, err := operands.pop()
+		return err
+	case opcode == opSelect:
+		return operands.checkSelect()
+	case opcode == opLocalGet:
+		operands.push(locals[instr.index])
+	case opcode == opLocalSet:
+		return operands.popExpected(locals[instr.index])
+	case opcode == opLocalTee:
+		return operands.checkUnary(locals[instr.index], locals[instr.index])
+	case opcode == opGlobalGet:
+		operands.push(m.globals[instr.index].valueType)
+	case opcode == opGlobalSet:
+		return operands.popExpected(m.globals[instr.index].valueType)
+	case opcode >= opI32Load && opcode <= opI64Load32U:
+		return operands.checkUnary(valueTypeI32, memoryValueType(opcode))
+	case opcode >= opI32Store && opcode <= opI64Store32:
+		return operands.popTypes([]valueType{valueTypeI32, memoryValueType(opcode)})
+	case opcode == opMemorySize:
+		operands.push(valueTypeI32)
+	case opcode == opMemoryGrow:
+		return operands.checkUnary(valueTypeI32, valueTypeI32)
+	case opcode == opI32Const:
+		operands.push(valueTypeI32)
+	case opcode == opI64Const:
+		operands.push(valueTypeI64)
+	case opcode == opF32Const:
+		operands.push(valueTypeF32)
+	case opcode == opF64Const:
+		operands.push(valueTypeF64)
+	default:
+		operand, numOperands, result, ok := numericSignature(opcode)
+		if !ok {
+			return fmt.Errorf("%w: opcode 0x%02x without operand types", ErrInvalidModule, opcode)
+		}
+		for i := 0; i < numOperands; i++ {
+			err := operands.popExpected(operand)
+			if err != nil {
+				return err
+			}
+		}
+		operands.push(result)
+	}
+	return nil
+}
+
+func (operands *operandTypes) checkBranchTable(table []uint32) error {
+	err := operands.popExpected(valueTypeI32)
+	if err != nil {
+		return err
+	}
+	defaultTypes := operands.label(table[len(table)-1]).labelTypes()
+	for _, depth := range table {
+		if !equalValueTypes(operands.label(depth).labelTypes(), defaultTypes) {
+			return fmt.Errorf("%w: br_table targets of different types", ErrInvalidModule)
+		}
+	}
+	err = operands.popTypes(defaultTypes)
+	if err != nil {
+		return err
+	}
+	operands.setUnreachable()
+	return nil
+}
+
+func (operands *operandTypes) checkCall(params []valueType, results []valueType) error {
+	err := operands.popTypes(params)
+	if err != nil {
+		return err
+	}
+	operands.pushTypes(results)
+	return nil
+}
+
+// withCondition appends the i32 operand which the if, the br_if and the
+// call_indirect pop above their other operands
+func withCondition(types []valueType) []valueType {
+	operands := make([]valueType, 0, len(types)+1)
+	operands = append(operands, types...)
+	return append(operands, valueTypeI32)
+}
+
+func (operands *operandTypes) checkSelect() error {
+	err := operands.popExpected(valueTypeI32)
+	if err != nil {
+		return err
+	}
+	second, err := operands.pop()
+	if err != nil {
+		return err
+	}
+	first, err := operands.pop()
+	if err != nil {
+		return err
+	}
+	if first != second && first != valueTypeUnknown && second != valueTypeUnknown {
+		return fmt.Errorf("%w: select of operands of types 0x%02x and 0x%02x", ErrInvalidModule, first, second)
+	}
+	if first == valueTypeUnknown {
+		first = second
+	}
+	operands.push(first)
+	return nil
+}
+
+func (operands *operandTypes) checkUnary(operand valueType, result valueType) error {
+	err := operands.popExpected(operand)
+	if err != nil {
+		return err
+	}
+	operands.push(result)
+	return nil
+}
+
+func (operands *operandTypes) push(operand valueType) {
+	operands.stack = append(operands.stack, operand)
+}
+
+func (operands *operandTypes) pushTypes(types []valueType) {
+	operands.stack = append(operands.stack, types...)
+}
+
+// pop pops an operand of the current frame; the stack of the unreachable
+// code holds any operands
+func (operands *operandTypes) pop() (valueType, error) {
+	frame := operands.frames[len(operands.frames)-1]
+	if len(operands.stack) == frame.height {
+		if frame.unreachable {
+			return valueTypeUnknown, nil
+		}
+		return 0, fmt.Errorf("%w: operand stack underflow", ErrInvalidModule)
+	}
+	operand := operands.stack[len(operands.stack)-1]
+	operands.stack = operands.stack[:len(operands.stack)-1]
+	return operand, nil
+}
+
+func (operands *operandTypes) popExpected(expected valueType) error {
+	operand, err := operands.pop()
+	if err != nil {
+		return err
+	}
+	if operand != expected && operand != valueTypeUnknown {
+		return fmt.Errorf("%w: operand of type 0x%02x where 0x%02x is expected", ErrInvalidModule, operand, expected)
+	}
+	return nil
+}
+
+// popTypes pops operands of the given types, the last of them first
+func (operands *operandTypes) popTypes(types []valueType) error {
+	for i := len(types) - 1; i >= 0; i-- {
+		err := operands.popExpected(types[i])
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// pushFrame opens a frame, whose operands start with its params
+func (operands *operandTypes) pushFrame(opcode byte, signature *functionType) {
+	operands.frames = append(operands.frames, &controlFrame{
+		opcode:    opcode,
+		signature: signature,
+		height:    len(operands.stack),
+	})
+	operands.pushTypes(signature.params)
+}
+
+// popFrame closes the current frame, which must leave exactly its results
+func (operands *operandTypes) popFrame() (*controlFrame, error) {
+	frame := operands.frames[len(operands.frames)-1]
+	err := operands.popTypes(frame.signature.results)
+	if err != nil {
+		return nil, err
+	}
+	if len(operands.stack) != frame.height {
+		return nil, fmt.Errorf("%w: %d operands left at the end of a block", ErrInvalidModule, len(operands.stack)-frame.height)
+	}
+	operands.frames = operands.frames[:len(operands.frames)-1]
+	return frame, nil
+}
+
+// label returns the frame targeted by a branch of the given depth
+func (operands *operandTypes) label(depth uint32) *controlFrame {
+	return operands.frames[len(operands.frames)-1-int(depth)]
+}
+
+// setUnreachable marks the rest of the current frame as unreachable, after
+// an instruction which never falls through
+func (operands *operandTypes) setUnreachable() {
+	frame := operands.frames[len(operands.frames)-1]
+	operands.stack = operands.stack[:frame.height]
+	frame.unreachable = true
+}
+
+// memoryValueType is the type of the value loaded or stored by a memory
+// instruction
+func memoryValueType(opcode uint16) valueType {
+	switch {
+	case opcode == opI64Load, opcode >= opI64Load8S && opcode <= opI64Load32U:
+		return valueTypeI64
+	case opcode == opI64Store, opcode >= opI64Store8 && opcode <= opI64Store32:
+		return valueTypeI64
+	case opcode == opF32Load, opcode == opF32Store:
+		return valueTypeF32
+	case opcode == opF64Load, opcode == opF64Store:
+		return valueTypeF64
+	}
+	return valueTypeI32
+}
+
+// numericSignature is the signature of a numeric instruction, whose one or
+// two operands have the same type
+func numericSignature(opcode uint16) (operand valueType, numOperands int, result valueType, ok bool) {
+	switch {
+	case opcode == opI32Eqz:
+		return valueTypeI32, 1, valueTypeI32, true
+	case opcode >= opI32Eq && opcode <= opI32GeU:
+		return valueTypeI32, 2, valueTypeI32, true
+	case opcode == opI64Eqz:
+		return valueTypeI64, 1, valueTypeI32, true
+	case opcode >= opI64Eq && opcode <= opI64GeU:
+		return valueTypeI64, 2, valueTypeI32, true
+	case opcode >= opF32Eq && opcode <= opF32Ge:
+		return valueTypeF32, 2, valueTypeI32, true
+	case opcode >= opF64Eq && opcode <= opF64Ge:
+		return valueTypeF64, 2, valueTypeI32, true
+	case opcode >= opI32Clz && opcode <= opI32Popcnt:
+		return valueTypeI32, 1, valueTypeI32, true
+	case opcode >= opI32Add && opcode <= opI32Rotr:
+		return valueTypeI32, 2, valueTypeI32, true
+	case opcode >= opI64Clz && opcode <= opI64Popcnt:
+		return valueTypeI64, 1, valueTypeI64, true
+	case opcode >= opI64Add && opcode <= opI64Rotr:
+		return valueTypeI64, 2, valueTypeI64, true
+	case opcode >= opF32Abs && opcode <= opF32Sqrt:
+		return valueTypeF32, 1, valueTypeF32, true
+	case opcode >= opF32Add && opcode <= opF32Copysign:
+		return valueTypeF32, 2, valueTypeF32, true
+	case opcode >= opF64Abs && opcode <= opF64Sqrt:
+		return valueTypeF64, 1, valueTypeF64, true
+	case opcode >= opF64Add && opcode <= opF64Copysign:
+		return valueTypeF64, 2, valueTypeF64, true
+	case opcode == opI32WrapI64:
+		return valueTypeI64, 1, valueTypeI32, true
+	case opcode == opI32TruncF32S, opcode == opI32TruncF32U, opcode == opI32ReinterpretF32:
+		return valueTypeF32, 1, valueTypeI32, true
+	case opcode == opI32TruncF64S, opcode == opI32TruncF64U:
+		return valueTypeF64, 1, valueTypeI32, true
+	case opcode == opI64ExtendI32S, opcode == opI64ExtendI32U:
+		return valueTypeI32, 1, valueTypeI64, true
+	case opcode == opI64TruncF32S, opcode == opI64TruncF32U:
+		return valueTypeF32, 1, valueTypeI64, true
+	case opcode == opI64TruncF64S, opcode == opI64TruncF64U, opcode == opI64ReinterpretF64:
+		return valueTypeF64, 1, valueTypeI64, true
+	case opcode == opF32ConvertI32S, opcode == opF32ConvertI32U, opcode == opF32ReinterpretI32:
+		return valueTypeI32, 1, valueTypeF32, true
+	case opcode == opF32ConvertI64S, opcode == opF32ConvertI64U:
+		return valueTypeI64, 1, valueTypeF32, true
+	case opcode == opF32DemoteF64:
+		return valueTypeF64, 1, valueTypeF32, true
+	case opcode == opF64ConvertI32S, opcode == opF64ConvertI32U:
+		return valueTypeI32, 1, valueTypeF64, true
+	case opcode == opF64ConvertI64S, opcode == opF64ConvertI64U, opcode == opF64ReinterpretI64:
+		return valueTypeI64, 1, valueTypeF64, true
+	case opcode == opF64PromoteF32:
+		return valueTypeF32, 1, valueTypeF64, true
+	case opcode == opI32Extend8S, opcode == opI32Extend16S:
+		return valueTypeI32, 1, valueTypeI32, true
+	case opcode >= opI64Extend8S && opcode <= opI64Extend32S:
+		return valueTypeI64, 1, valueTypeI64, true
+	case opcode == opI32TruncSatF32S, opcode == opI32TruncSatF32U:
+		return valueTypeF32, 1, valueTypeI32, true
+	case opcode == opI32TruncSatF64S, opcode == opI32TruncSatF64U:
+		return valueTypeF64, 1, valueTypeI32, true
+	case opcode == opI64TruncSatF32S, opcode == opI64TruncSatF32U:
+		return valueTypeF32, 1, valueTypeI64, true
+	case opcode == opI64TruncSatF64S, opcode == opI64TruncSatF64U:
+		return valueTypeF64, 1, valueTypeI64, true
+	}
+	return 0, 0, 0, false
+}
@@ -0,0 +1,167 @@
+package interpreter
+
+import (
+	"fmt"
+	"reflect"
+	"time"
+	"unicode"
+	"unicode/utf8"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var vmHooksType = reflect.TypeOf((*executor.VMHooks)(nil)).Elem()
+
+// vmHookAdapter calls a method of the VMHooks with the values of the
+// arguments taken from the stack, and returns the value of its result
+type vmHookAdapter func(vmHooks executor.VMHooks, arguments []uint64) uint64
+
+// vmHook is a method of the VMHooks, under the import name of the contracts,
+// which is its name starting with a lowercase letter
+type vmHook struct {
+	name      string
+	signature *functionType
+	adapter   vmHookAdapter
+}
+
+// vmHookSignatures lists the VM hooks by their import names, including the
+// aliases of the renamed ones, with the wasm signatures of their methods
+func vmHookSignatures(aliases []executor.HostFunctionAlias) (map[string]*vmHook, error) {
+	hooks := make(map[string]*vmHook, vmHooksType.NumMethod())
+	for i := 0; i < vmHooksType.NumMethod(); i++ {
+		method := vmHooksType.Method(i)
+		signature, err := signatureOfMethod(method.Type)
+		if err != nil {
+			return nil, fmt.Errorf("VM hook %s: %w", method.Name, err)
+		}
+		name := importName(method.Name)
+		adapter, ok := vmHookAdapters[name]
+		if !ok {
+			return nil, fmt.Errorf("VM hook %s: %w", method.Name, ErrMissingVMHookAdapter)
+		}
+		hooks[name] = &vmHook{
+			name:      name,
+			signature: signature,
+			adapter:   adapter,
+		}
+	}
+
+	for _, alias := range aliases {
+		target, ok := hooks[alias.Target]
+		if !ok {
+			return nil, fmt.Errorf("%w: alias %s of %s", ErrUnknownImport, alias.Alias, alias.Target)
+		}
+		hooks[alias.Alias] = &vmHook{
+			name:      target.name,
+			signature: target.signature,
+			adapter:   target.adapter,
+		}
+	}
+	return hooks, nil
+}
+
+func importName(methodName string) string {
+	first, size := utf8.DecodeRuneInString(methodName)
+	return string(unicode.ToLower(first)) + methodName[size:]
+}
+
+func signatureOfMethod(methodType reflect.Type) (*functionType, error) {
+	signature := &functionType{
+		params:  make([]valueType, methodType.NumIn()),
+		results: make([]valueType, methodType.NumOut()),
+	}
+	var err error
+	for i := range signature.params {
+		signature.params[i], err = valueTypeOfKind(methodType.In(i).Kind())
+		if err != nil {
+			return nil, err
+		}
+	}
+	for i := range signature.results {
+		signature.results[i], err = valueTypeOfKind(methodType.Out(i).Kind())
+		if err != nil {
+			return nil, err
+		}
+	}
+	return signature, nil
+}
+
+func valueTypeOfKind(kind reflect.Kind) (valueType, error) {
+	switch kind {
+	case reflect.Int32:
+		return valueTypeI32, nil
+	case reflect.Int64:
+		return valueTypeI64, nil
+	}
+	return 0, fmt.Errorf("%w: parameter of kind %s", ErrImportSignatureMismatch, kind)
+}
+
+func functionNamesOf(hooks map[string]*vmHook) vmcommon.FunctionNames {
+	names := make(vmcommon.FunctionNames, len(hooks))
+	var empty struct{}
+	for name := range hooks {
+		names[name] = empty
+	}
+	return names
+}
+
+// boundVMHook is a VM hook bound to the VMHooks of an executor
+type boundVMHook struct {
+	*vmHook
+	vmHooks executor.VMHooks
+}
+
+// hostPanic carries the panic of a VM hook through the recovery of the
+// interpreter, which only converts its own runtime errors into traps
+type hostPanic struct {
+	value interface{}
+}
+
+// callVMHook calls a VM hook with the arguments taken from the stack of the
+// instance and pushes its results; like the VM hooks of the wasmer executor,
+// it reports the duration of the call to the VMHooks observing them, and it
+// hands the panics to the VMHooks converting them into VM errors
+func (instance *InterpreterInstance) callVMHook(hook *boundVMHook) error {
+	base := len(instance.stack) - len(hook.signature.params)
+	arguments := instance.stack[base:]
+	instance.stack = instance.stack[:base]
+
+	result := instance.invokeVMHook(hook, arguments)
+	if len(hook.signature.results) > 0 {
+		instance.push(result)
+	}
+
+	if instance.options.RuntimeBreakpoints && instance.breakpointValue != 0 {
+		return ErrBreakpoint
+	}
+	return nil
+}
+
+func (instance *InterpreterInstance) invokeVMHook(hook *boundVMHook, arguments []uint64) (result uint64) {
+	vmHooks := hook.vmHooks
+	startTime := time.Time{}
+	callObserver, isObserver := vmHooks.(executor.VMHookCallObserver)
+	if isObserver && callObserver.VMHookCallsObserved() {
+		startTime = time.Now()
+	}
+
+	defer func() {
+		if !startTime.IsZero() {
+			callObserver.ObserveVMHookCall(hook.name, time.Since(startTime))
+		}
+
+		panicValue := recover()
+		if panicValue == nil {
+			return
+		}
+		panicHandler, ok := vmHooks.(executor.VMHookPanicHandler)
+		if !ok {
+			panic(hostPanic{value: panicValue})
+		}
+		panicHandler.HandleVMHookPanic(hook.name, panicValue)
+		result = 0
+	}()
+
+	return hook.adapter(vmHooks, arguments)
+}
@@ -0,0 +1,164 @@
+package logging
+
+import (
+	"sync/atomic"
+
+	logger "github.com/ElrondNetwork/elrond-go-logger"
+)
+
+// Subsystem tags the events logged by the VM with the part of the VM emitting them
+type Subsystem string
+
+const (
+	// SubsystemHost tags the events of the VM host and of the execution flow
+	SubsystemHost Subsystem = "host"
+
+	// SubsystemExecutor tags the events of the executors and of the contract instances
+	SubsystemExecutor Subsystem = "executor"
+
+	// SubsystemRuntime tags the events of the runtime context
+	SubsystemRuntime Subsystem = "runtime"
+
+	// SubsystemAsync tags the events of the asynchronous calls and callbacks
+	SubsystemAsync Subsystem = "async"
+
+	// SubsystemStorage tags the events of the contract storage
+	SubsystemStorage Subsystem = "storage"
+
+	// SubsystemGas tags the events of the gas metering and of the gas traces
+	SubsystemGas Subsystem = "gas"
+
+	// SubsystemOutput tags the events of the output context
+	SubsystemOutput Subsystem = "output"
+
+	// SubsystemBlockchain tags the events of the blockchain context
+	SubsystemBlockchain Subsystem = "blockchain"
+
+	// SubsystemManagedTypes tags the events of the managed types context
+	SubsystemManagedTypes Subsystem = "managedTypes"
+
+	// SubsystemHostFunctions tags the events of the host functions called by the contracts
+	SubsystemHostFunctions Subsystem = "hostFunctions"
+
+	// SubsystemContracts tags the messages printed by the contracts in debug mode
+	SubsystemContracts Subsystem = "contracts"
+)
+
+// VMLogger receives the events logged by the VM, so that embedders can route
+// them into their own logging stack
+type VMLogger interface {
+	LogEvent(severity logger.LogLevel, subsystem Subsystem, message string, args ...interface{})
+	IsEnabled(severity logger.LogLevel, subsystem Subsystem) bool
+	IsInterfaceNil() bool
+}
+
+// vmLoggerHolder wraps the VMLogger, since an atomic.Value cannot hold nil
+type vmLoggerHolder struct {
+	vmLogger VMLogger
+}
+
+var currentVMLogger atomic.Value
+
+// SetVMLogger routes the events logged by the VM to the given VMLogger; a nil
+// VMLogger routes them back to the elrond-go-logger. Like the loggers, the
+// VMLogger is shared by all the VM hosts of the process.
+func SetVMLogger(vmLogger VMLogger) {
+	if vmLogger != nil && vmLogger.IsInterfaceNil() {
+		vmLogger = nil
+	}
+	currentVMLogger.Store(vmLoggerHolder{vmLogger: vmLogger})
+}
+
+func getVMLogger() VMLogger {
+	holder, ok := currentVMLogger.Load().(vmLoggerHolder)
+	if !ok {
+		return nil
+	}
+	return holder.vmLogger
+}
+
+// Logger is the logger of a subsystem of the VM. It emits its events to the
+// VMLogger, if one is set, and to the elrond-go-logger of its name otherwise.
+type Logger struct {
+	subsystem Subsystem
+	fallback  logger.Logger
+}
+
+// GetOrCreate creates the Logger of a subsystem, falling back to the
+// elrond-go-logger with the given name
+func GetOrCreate(subsystem Subsystem, name string) *Logger {
+	return &Logger{
+		subsystem: subsystem,
+		fallback:  logger.GetOrCreate(name),
+	}
+}
+
+// Subsystem returns the subsystem which tags the events of the Logger
+func (l *Logger) Subsystem() Subsystem {
+	return l.subsystem
+}
+
+// Trace logs an event with the trace severity
+func (l *Logger) Trace(message string, args ...interface{}) {
+	if !l.logToVMLogger(logger.LogTrace, message, args...) {
+		l.fallback.Trace(message, args...)
+	}
+}
+
+// Debug logs an event with the debug severity
+func (l *Logger) Debug(message string, args ...interface{}) {
+	if !l.logToVMLogger(logger.LogDebug, message, args...) {
+		l.fallback.Debug(message, args...)
+	}
+}
+
+// Info logs an event with the info severity
+func (l *Logger) Info(message string, args ...interface{}) {
+	if !l.logToVMLogger(logger.LogInfo, message, args...) {
+		l.fallback.Info(message, args...)
+	}
+}
+
+// Warn logs an event with the warning severity
+func (l *Logger) Warn(message string, args ...interface{}) {
+	if !l.logToVMLogger(logger.LogWarning, message, args...) {
+		l.fallback.Warn(message, args...)
+	}
+}
+
+// Error logs an event with the error severity
+func (l *Logger) Error(message string, args ...interface{}) {
+	if !l.logToVMLogger(logger.LogError, message, args...) {
+		l.fallback.Error(message, args...)
+	}
+}
+
+// GetLevel returns the lowest severity logged for the subsystem, so that the
+// callers can skip building expensive events
+func (l *Logger) GetLevel() logger.LogLevel {
+	vmLogger := getVMLogger()
+	if vmLogger == nil {
+		return l.fallback.GetLevel()
+	}
+
+	for _, severity := range []logger.LogLevel{logger.LogTrace, logger.LogDebug, logger.LogInfo, logger.LogWarning, logger.LogError} {
+		if vmLogger.IsEnabled(severity, l.subsystem) {
+			return severity
+		}
+	}
+	return logger.LogNone
+}
+
+// logToVMLogger emits the event to the VMLogger and returns true, or returns
+// false if no VMLogger is set
+func (l *Logger) logToVMLogger(severity logger.LogLevel, message string, args ...interface{}) bool {
+	vmLogger := getVMLogger()
+	if vmLogger == nil {
+		return false
+	}
+
+	if vmLogger.IsEnabled(severity, l.subsystem) {
+		vmLogger.LogEvent(severity, l.subsystem, message, args...)
+	}
+	return true
+}
@@ -0,0 +1,66 @@
+package logging
+
+import (
+	"testing"
+
+	logger "github.com/ElrondNetwork/elrond-go-logger"
+	"github.com/stretchr/testify/require"
+)
+
+type loggedEvent struct {
+	severity  logger.LogLevel
+	subsystem Subsystem
+	message   string
+	args      []interface{}
+}
+
+type vmLoggerStub struct {
+	minSeverity logger.LogLevel
+	events      []loggedEvent
+}
+
+func (stub *vmLoggerStub) LogEvent(severity logger.LogLevel, subsystem Subsystem, message string, args ...interface{}) {
+	stub.events = append(stub.events, loggedEvent{severity, subsystem, message, args})
+}
+
+func (stub *vmLoggerStub) IsEnabled(severity logger.LogLevel, subsystem Subsystem) bool {
+	return subsystem != SubsystemGas && severity >= stub.minSeverity
+}
+
+func (stub *vmLoggerStub) IsInterfaceNil() bool {
+	return stub == nil
+}
+
+func TestLogger_RoutesToVMLogger(t *testing.T) {
+	vmLogger := &vmLoggerStub{minSeverity: logger.LogDebug}
+	SetVMLogger(vmLogger)
+	defer SetVMLogger(nil)
+
+	storageLog := GetOrCreate(SubsystemStorage, "test/storage")
+	gasLog := GetOrCreate(SubsystemGas, "test/gas")
+
+	storageLog.Trace("skipped")
+	storageLog.Debug("loaded", "key", "COUNTER")
+	storageLog.Error("failed")
+	gasLog.Error("skipped")
+
+	require.Equal(t, []loggedEvent{
+		{logger.LogDebug, SubsystemStorage, "loaded", []interface{}{"key", "COUNTER"}},
+		{logger.LogError, SubsystemStorage, "failed", nil},
+	}, vmLogger.events)
+	require.Equal(t, logger.LogDebug, storageLog.GetLevel())
+	require.Equal(t, logger.LogNone, gasLog.GetLevel())
+}
+
+func TestLogger_FallsBackWithoutVMLogger(t *testing.T) {
+	var nilVMLogger *vmLoggerStub
+	SetVMLogger(nilVMLogger)
+	require.Nil(t, getVMLogger())
+
+	log := GetOrCreate(SubsystemAsync, "test/async")
+	require.Equal(t, SubsystemAsync, log.Subsystem())
+	require.Equal(t, logger.GetOrCreate("test/async").GetLevel(), log.GetLevel())
+	require.NotPanics(t, func() {
+		log.Trace("not routed")
+	})
+}
@@ -55,6 +55,11 @@ func (p *Parser) ParseScenarioFile(jsonString []byte) (*mj.Scenario, error) {
 			if err != nil {
 				return nil, fmt.Errorf("bad scenario gasSchedule: %w", err)
 			}
+		case "enableEpochs":
+			scenario.EnableEpochs, err = p.processEnableEpochs(kvp.Value)
+			if err != nil {
+				return nil, fmt.Errorf("bad scenario enableEpochs: %w", err)
+			}
 		case "steps":
 			scenario.Steps, err = p.processScenarioStepList(kvp.Value)
 			if err != nil {
@@ -86,6 +91,25 @@ func (p *Parser) parseGasSchedule(value oj.OJsonObject) (mj.GasSchedule, error)
 	}
 }
 
+func (p *Parser) processEnableEpochs(value oj.OJsonObject) ([]*mj.EnableEpoch, error) {
+	enableEpochsMap, isMap := value.(*oj.OJsonMap)
+	if !isMap {
+		return nil, errors.New("enableEpochs is not a map")
+	}
+	var enableEpochs []*mj.EnableEpoch
+	for _, kvp := range enableEpochsMap.OrderedKV {
+		epoch, err := p.processUint64(kvp.Value)
+		if err != nil {
+			return nil, fmt.Errorf("bad epoch of %s: %w", kvp.Key, err)
+		}
+		enableEpochs = append(enableEpochs, &mj.EnableEpoch{
+			Name:  kvp.Key,
+			Epoch: epoch,
+		})
+	}
+	return enableEpochs, nil
+}
+
 func (p *Parser) processScenarioStepList(obj interface{}) ([]mj.Step, error) {
 	listRaw, listOk := obj.(*oj.OJsonList)
 	if !listOk {
@@ -249,6 +273,28 @@ func (p *Parser) processScenarioStep(stepObj oj.OJsonObject) (mj.Step, error) {
 			}
 		}
 		return step, nil
+	case mj.StepNameAdvanceBlocks:
+		step := &mj.AdvanceBlocksStep{}
+		incrementsMap := oj.NewMap()
+		for _, kvp := range stepMap.OrderedKV {
+			switch kvp.Key {
+			case "step":
+			case "comment":
+				step.Comment, err = p.parseString(kvp.Value)
+				if err != nil {
+					return nil, fmt.Errorf("bad advance blocks step comment: %w", err)
+				}
+			default:
+				incrementsMap.Put(kvp.Key, kvp.Value)
+			}
+		}
+		var increments *mj.BlockInfo
+		increments, err = p.processBlockInfo(incrementsMap)
+		if err != nil {
+			return nil, fmt.Errorf("invalid advance blocks step: %w", err)
+		}
+		step.BlockInfo = *increments
+		return step, nil
 	case mj.StepNameScCall:
 		return p.parseTxStep(mj.ScCall, stepMap)
 	case mj.StepNameScDeploy:
@@ -55,3 +55,43 @@ func TestParseScenario(t *testing.T) {
 	require.Equal(t, "scCall", step.StepTypeName())
 	require.Equal(t, true, step.(*mj.TxStep).DisplayLogs)
 }
+
+func TestParseAdvanceBlocksStep(t *testing.T) {
+	snippet := `
+	{
+		"step": "advanceBlocks",
+		"comment": "next epoch",
+		"blockNonce": "1",
+		"blockEpoch": "2"
+	}`
+
+	p := Parser{}
+	step, parseErr := p.ParseScenarioStep(snippet)
+	require.Nil(t, parseErr)
+	require.Equal(t, "advanceBlocks", step.StepTypeName())
+
+	advanceBlocksStep := step.(*mj.AdvanceBlocksStep)
+	require.Equal(t, "next epoch", advanceBlocksStep.Comment)
+	require.Equal(t, uint64(1), advanceBlocksStep.BlockNonce.Value)
+	require.Equal(t, uint64(2), advanceBlocksStep.BlockEpoch.Value)
+	require.True(t, advanceBlocksStep.BlockRound.OriginalEmpty())
+	require.Nil(t, advanceBlocksStep.BlockRandomSeed)
+
+	_, parseErr = p.ParseScenarioStep(`{"step": "advanceBlocks", "blockHeight": "1"}`)
+	require.EqualError(t, parseErr, "invalid advance blocks step: unknown block info field: blockHeight")
+}
+
+func TestParseScenarioEnableEpochs(t *testing.T) {
+	p := Parser{}
+	scenario, parseErr := p.ParseScenarioFile([]byte(`
+	{
+		"enableEpochs": {
+			"PromisesFunctionsEnableEpoch": "3"
+		},
+		"steps": []
+	}`))
+	require.Nil(t, parseErr)
+	require.Len(t, scenario.EnableEpochs, 1)
+	require.Equal(t, "PromisesFunctionsEnableEpoch", scenario.EnableEpochs[0].Name)
+	require.Equal(t, uint64(3), scenario.EnableEpochs[0].Epoch.Value)
+}
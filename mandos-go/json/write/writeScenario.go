@@ -37,6 +37,10 @@ func ScenarioToOrderedJSON(scenario *mj.Scenario) oj.OJsonObject {
 		scenarioOJ.Put("gasSchedule", gasScheduleToOJ(scenario.GasSchedule))
 	}
 
+	if len(scenario.EnableEpochs) > 0 {
+		scenarioOJ.Put("enableEpochs", enableEpochsToOJ(scenario.EnableEpochs))
+	}
+
 	var stepOJList []oj.OJsonObject
 
 	for _, generalStep := range scenario.Steps {
@@ -82,6 +86,14 @@ func ScenarioToOrderedJSON(scenario *mj.Scenario) oj.OJsonObject {
 			if len(step.Comment) > 0 {
 				stepOJ.Put("comment", stringToOJ(step.Comment))
 			}
+		case *mj.AdvanceBlocksStep:
+			if len(step.Comment) > 0 {
+				stepOJ.Put("comment", stringToOJ(step.Comment))
+			}
+			incrementsOJ := blockInfoToOJ(&step.BlockInfo).(*oj.OJsonMap)
+			for _, kvp := range incrementsOJ.OrderedKV {
+				stepOJ.Put(kvp.Key, kvp.Value)
+			}
 		case *mj.TxStep:
 			if len(step.TxIdent) > 0 {
 				stepOJ.Put("id", stringToOJ(step.TxIdent))
@@ -183,6 +195,14 @@ func blockInfoToOJ(blockInfo *mj.BlockInfo) oj.OJsonObject {
 	return blockInfoOJ
 }
 
+func enableEpochsToOJ(enableEpochs []*mj.EnableEpoch) oj.OJsonObject {
+	enableEpochsOJ := oj.NewMap()
+	for _, enableEpoch := range enableEpochs {
+		enableEpochsOJ.Put(enableEpoch.Name, uint64ToOJ(enableEpoch.Epoch))
+	}
+	return enableEpochsOJ
+}
+
 func gasScheduleToOJ(gasSchedule mj.GasSchedule) oj.OJsonObject {
 	switch gasSchedule {
 	case mj.GasScheduleDefault:
@@ -8,7 +8,18 @@ type Scenario struct {
 	TraceGas    bool
 	IsNewTest   bool
 	GasSchedule GasSchedule
-	Steps       []Step
+
+	// EnableEpochs overrides the activation epochs of the VM features for the
+	// duration of the scenario; the features not listed are active from genesis
+	EnableEpochs []*EnableEpoch
+
+	Steps []Step
+}
+
+// EnableEpoch is the activation epoch of a VM feature, named as in the VM configuration
+type EnableEpoch struct {
+	Name  string
+	Epoch JSONUint64
 }
 
 // Step is the basic block of a scenario.
@@ -70,6 +81,15 @@ type DumpStateStep struct {
 	Comment string
 }
 
+// AdvanceBlocksStep is a step where the blockchain mock moves to a later block:
+// the current block becomes the previous one, and the new current block has the
+// nonce, round, epoch and timestamp of the old one incremented by the given
+// amounts. The random seed is replaced only if specified.
+type AdvanceBlocksStep struct {
+	Comment string
+	BlockInfo
+}
+
 // TxStep is a step where a transaction is executed.
 type TxStep struct {
 	TxIdent        string
@@ -83,6 +103,7 @@ var _ Step = (*ExternalStepsStep)(nil)
 var _ Step = (*SetStateStep)(nil)
 var _ Step = (*CheckStateStep)(nil)
 var _ Step = (*DumpStateStep)(nil)
+var _ Step = (*AdvanceBlocksStep)(nil)
 var _ Step = (*TxStep)(nil)
 
 // StepNameExternalSteps is a json step type name.
@@ -117,6 +138,14 @@ func (*DumpStateStep) StepTypeName() string {
 	return StepNameDumpState
 }
 
+// StepNameAdvanceBlocks is a json step type name.
+const StepNameAdvanceBlocks = "advanceBlocks"
+
+// StepTypeName type as string
+func (*AdvanceBlocksStep) StepTypeName() string {
+	return StepNameAdvanceBlocks
+}
+
 // StepNameScCall is a json step type name.
 const StepNameScCall = "scCall"
 
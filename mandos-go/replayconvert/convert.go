@@ -0,0 +1,296 @@
+package replayconvert
+
+import (
+	"fmt"
+	"math/big"
+	"sort"
+
+	mj "github.com/ElrondNetwork/wasm-vm/mandos-go/model"
+	oj "github.com/ElrondNetwork/wasm-vm/mandos-go/orderedjson"
+)
+
+// TraceToScenario converts a replay trace to an equivalent scenario: a
+// setState step with the recorded accounts and the addresses of the deployed
+// contracts, one transaction step for each recorded transaction, expecting
+// its recorded output, and a final checkState step if the trace has a post
+// state. The remaining gas is not checked, since it depends on the gas
+// schedule of the network which executed the transactions.
+func TraceToScenario(trace *Trace) (*mj.Scenario, error) {
+	setState := &mj.SetStateStep{
+		Comment: "state recorded before the first transaction",
+	}
+	for _, account := range trace.Accounts {
+		mandosAccount, err := convertAccount(account)
+		if err != nil {
+			return nil, err
+		}
+		setState.Accounts = append(setState.Accounts, mandosAccount)
+	}
+
+	steps := []mj.Step{setState}
+	for i, tx := range trace.Transactions {
+		if tx.Output == nil {
+			return nil, fmt.Errorf("%w: transaction %d has no output", ErrInvalidTrace, i)
+		}
+		if tx.IsDeploy() {
+			if len(tx.DeployedAddress) == 0 {
+				return nil, fmt.Errorf("%w: deploy transaction %d has no deployed address", ErrInvalidTrace, i)
+			}
+			setState.NewAddressMocks = append(setState.NewAddressMocks, &mj.NewAddressMock{
+				CreatorAddress: bytesFromString(tx.Sender),
+				CreatorNonce:   uint64Value(tx.Nonce),
+				NewAddress:     bytesFromString(tx.DeployedAddress),
+			})
+		}
+
+		if tx.Block != nil {
+			steps = append(steps, &mj.SetStateStep{
+				CurrentBlockInfo: convertBlock(tx.Block),
+			})
+		}
+		steps = append(steps, convertTransaction(i, tx))
+	}
+
+	if len(trace.PostState) > 0 {
+		checkState, err := convertPostState(trace.PostState)
+		if err != nil {
+			return nil, err
+		}
+		steps = append(steps, checkState)
+	}
+
+	return &mj.Scenario{
+		Name:     trace.Name,
+		Comment:  trace.Comment,
+		CheckGas: false,
+		Steps:    steps,
+	}, nil
+}
+
+func convertAccount(account *TraceAccount) (*mj.Account, error) {
+	storageKeys, err := sortedStorageKeys(account.Storage)
+	if err != nil {
+		return nil, err
+	}
+
+	var storage []*mj.StorageKeyValuePair
+	for _, key := range storageKeys {
+		value := account.Storage[key]
+		if len(value) == 0 {
+			continue
+		}
+		decodedKey, _ := decodeHex(key)
+		storage = append(storage, &mj.StorageKeyValuePair{
+			Key:   bytesFromString(decodedKey),
+			Value: bytesFromTree(value),
+		})
+	}
+
+	return &mj.Account{
+		Address: bytesFromString(account.Address),
+		Nonce:   uint64Value(account.Nonce),
+		Balance: bigIntValue(account.Balance),
+		Storage: storage,
+		Code:    bytesFromString(account.Code),
+		Owner:   bytesFromString(account.Owner),
+	}, nil
+}
+
+func convertPostState(accounts []*TraceAccount) (*mj.CheckStateStep, error) {
+	checkAccounts := &mj.CheckAccounts{
+		MoreAccountsAllowed: true,
+	}
+	for _, account := range accounts {
+		storageKeys, err := sortedStorageKeys(account.Storage)
+		if err != nil {
+			return nil, err
+		}
+
+		var checkStorage []*mj.CheckStorageKeyValuePair
+		for _, key := range storageKeys {
+			decodedKey, _ := decodeHex(key)
+			checkStorage = append(checkStorage, &mj.CheckStorageKeyValuePair{
+				Key:        bytesFromString(decodedKey),
+				CheckValue: checkBytes(account.Storage[key]),
+			})
+		}
+
+		checkAccounts.Accounts = append(checkAccounts.Accounts, &mj.CheckAccount{
+			Address:            bytesFromString(account.Address),
+			Nonce:              checkUint64(account.Nonce),
+			Balance:            checkBigInt(account.Balance),
+			Username:           mj.JSONCheckBytesUnspecified(),
+			ExplicitStorage:    true,
+			MoreStorageAllowed: true,
+			CheckStorage:       checkStorage,
+			Code:               mj.JSONCheckBytesUnspecified(),
+			Owner:              mj.JSONCheckBytesUnspecified(),
+			AsyncCallData:      mj.JSONCheckBytesUnspecified(),
+			IgnoreESDT:         true,
+			DeveloperReward:    mj.JSONCheckBigIntUnspecified(),
+		})
+	}
+
+	return &mj.CheckStateStep{
+		Comment:       "state recorded after the last transaction",
+		CheckAccounts: checkAccounts,
+	}, nil
+}
+
+func convertBlock(block *TraceBlock) *mj.BlockInfo {
+	blockInfo := &mj.BlockInfo{
+		BlockTimestamp: uint64Value(block.Timestamp),
+		BlockNonce:     uint64Value(block.Nonce),
+		BlockRound:     uint64Value(block.Round),
+		BlockEpoch:     uint64Value(block.Epoch),
+	}
+	if len(block.RandomSeed) > 0 {
+		randomSeed := bytesFromTree(block.RandomSeed)
+		blockInfo.BlockRandomSeed = &randomSeed
+	}
+	return blockInfo
+}
+
+func convertTransaction(index int, tx *TraceTransaction) *mj.TxStep {
+	mandosTx := &mj.Transaction{
+		Type:      mj.ScCall,
+		Nonce:     uint64Value(tx.Nonce),
+		EGLDValue: bigIntValue(tx.Value),
+		From:      bytesFromString(tx.Sender),
+		To:        bytesFromString(tx.Receiver),
+		Function:  tx.Function,
+		GasLimit:  uint64Value(tx.GasLimit),
+		GasPrice:  uint64Value(tx.GasPrice),
+	}
+	if tx.IsDeploy() {
+		mandosTx.Type = mj.ScDeploy
+		mandosTx.Code = bytesFromString(tx.Code)
+	}
+	for _, argument := range tx.Arguments {
+		mandosTx.Arguments = append(mandosTx.Arguments, bytesFromTree(argument))
+	}
+
+	txIdent := fmt.Sprintf("%d", index+1)
+	if len(tx.Hash) > 0 {
+		txIdent = hexString(tx.Hash)
+	}
+
+	return &mj.TxStep{
+		TxIdent:        txIdent,
+		Tx:             mandosTx,
+		ExpectedResult: convertOutput(tx.Output),
+	}
+}
+
+func convertOutput(output *TraceOutput) *mj.TransactionResult {
+	result := &mj.TransactionResult{
+		Out:     checkValueList(output.ReturnData),
+		Status:  checkBigInt(big.NewInt(0).SetUint64(output.ReturnCode)),
+		Message: checkString(output.ReturnMessage),
+		Gas:     mj.JSONCheckUint64Unspecified(),
+		Refund:  checkBigIntStar(),
+		Logs:    mj.LogList{IsStar: true},
+	}
+	if output.GasRefund != nil {
+		result.Refund = checkBigInt(output.GasRefund)
+	}
+
+	if output.Logs != nil {
+		result.Logs = mj.LogList{}
+		for _, log := range output.Logs {
+			result.Logs.List = append(result.Logs.List, &mj.LogEntry{
+				Address:  checkBytes(log.Address),
+				Endpoint: checkString(log.Identifier),
+				Topics:   checkValueList(log.Topics),
+				Data:     checkBytes(log.Data),
+			})
+		}
+	}
+
+	return result
+}
+
+func sortedStorageKeys(storage map[string]HexBytes) ([]string, error) {
+	keys := make([]string, 0, len(storage))
+	for key := range storage {
+		_, err := decodeHex(key)
+		if err != nil {
+			return nil, fmt.Errorf("%w: storage key %s: %v", ErrInvalidTrace, key, err)
+		}
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys, nil
+}
+
+func bytesFromString(value []byte) mj.JSONBytesFromString {
+	return mj.NewJSONBytesFromString(value, hexString(value))
+}
+
+func bytesFromTree(value []byte) mj.JSONBytesFromTree {
+	return mj.JSONBytesFromTree{
+		Value:    value,
+		Original: &oj.OJsonString{Value: hexString(value)},
+	}
+}
+
+func uint64Value(value uint64) mj.JSONUint64 {
+	return mj.JSONUint64{
+		Value:    value,
+		Original: fmt.Sprintf("%d", value),
+	}
+}
+
+func bigIntValue(value *big.Int) mj.JSONBigInt {
+	if value == nil {
+		value = big.NewInt(0)
+	}
+	return mj.JSONBigInt{
+		Value:    value,
+		Original: value.String(),
+	}
+}
+
+func checkBytes(value []byte) mj.JSONCheckBytes {
+	return mj.JSONCheckBytesReconstructed(value, hexString(value))
+}
+
+func checkString(value string) mj.JSONCheckBytes {
+	if len(value) == 0 {
+		return mj.JSONCheckBytesReconstructed(nil, "")
+	}
+	return mj.JSONCheckBytesReconstructed([]byte(value), "str:"+value)
+}
+
+func checkValueList(values []HexBytes) mj.JSONCheckValueList {
+	checkList := mj.JSONCheckValueList{}
+	for _, value := range values {
+		checkList.Values = append(checkList.Values, checkBytes(value))
+	}
+	return checkList
+}
+
+func checkUint64(value uint64) mj.JSONCheckUint64 {
+	return mj.JSONCheckUint64{
+		Value:    value,
+		Original: fmt.Sprintf("%d", value),
+	}
+}
+
+func checkBigInt(value *big.Int) mj.JSONCheckBigInt {
+	if value == nil {
+		value = big.NewInt(0)
+	}
+	return mj.JSONCheckBigInt{
+		Value:    value,
+		Original: value.String(),
+	}
+}
+
+func checkBigIntStar() mj.JSONCheckBigInt {
+	return mj.JSONCheckBigInt{
+		Value:    big.NewInt(0),
+		IsStar:   true,
+		Original: "*",
+	}
+}
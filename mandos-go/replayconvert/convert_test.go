@@ -0,0 +1,137 @@
+package replayconvert
+
+import (
+	"errors"
+	"math/big"
+	"strings"
+	"testing"
+
+	mjparse "github.com/ElrondNetwork/wasm-vm/mandos-go/json/parse"
+	mjwrite "github.com/ElrondNetwork/wasm-vm/mandos-go/json/write"
+	mj "github.com/ElrondNetwork/wasm-vm/mandos-go/model"
+	"github.com/stretchr/testify/require"
+)
+
+var userAddress = "0x" + strings.Repeat("a1", 32)
+var contractAddress = "0x" + strings.Repeat("00", 8) + strings.Repeat("c1", 24)
+var deployedAddress = "0x" + strings.Repeat("00", 8) + strings.Repeat("c2", 24)
+
+var recordedTrace = `{
+	"name": "recorded counter calls",
+	"accounts": [
+		{"address": "` + userAddress + `", "nonce": 7, "balance": 100000000000000000000},
+		{
+			"address": "` + contractAddress + `",
+			"balance": 0,
+			"code": "0061736d01000000",
+			"owner": "` + userAddress + `",
+			"storage": {"0x636f756e746572": "0x05", "0x656d707479": ""}
+		}
+	],
+	"transactions": [
+		{
+			"hash": "0x1234",
+			"sender": "` + userAddress + `",
+			"receiver": "` + contractAddress + `",
+			"nonce": 7,
+			"value": 0,
+			"function": "increment",
+			"arguments": ["0x02"],
+			"gasLimit": 5000000,
+			"gasPrice": 1000000000,
+			"block": {"nonce": 10, "round": 11, "epoch": 1, "timestamp": 1600000000},
+			"output": {
+				"returnCode": 0,
+				"returnData": ["0x07"],
+				"logs": [{"address": "` + contractAddress + `", "identifier": "increment", "topics": ["0x02"], "data": ""}]
+			}
+		},
+		{
+			"sender": "` + userAddress + `",
+			"nonce": 8,
+			"code": "0061736d01000000",
+			"deployedAddress": "` + deployedAddress + `",
+			"gasLimit": 5000000,
+			"gasPrice": 1000000000,
+			"output": {"returnCode": 10, "returnMessage": "invalid contract code", "gasRefund": 0}
+		}
+	],
+	"postState": [
+		{"address": "` + contractAddress + `", "balance": 0, "storage": {"0x636f756e746572": "0x07"}}
+	]
+}`
+
+func TestTraceToScenario(t *testing.T) {
+	trace, err := ParseTrace([]byte(recordedTrace))
+	require.Nil(t, err)
+
+	scenario, err := TraceToScenario(trace)
+	require.Nil(t, err)
+
+	serialized := mjwrite.ScenarioToJSONString(scenario)
+	parser := mjparse.Parser{}
+	parsed, err := parser.ParseScenarioFile([]byte(serialized))
+	require.Nil(t, err)
+	require.Equal(t, "recorded counter calls", parsed.Name)
+	require.False(t, parsed.CheckGas)
+	require.Len(t, parsed.Steps, 5)
+
+	setState := parsed.Steps[0].(*mj.SetStateStep)
+	require.Len(t, setState.Accounts, 2)
+	contract := mj.FindAccount(setState.Accounts, trace.Accounts[1].Address)
+	require.NotNil(t, contract)
+	require.Len(t, contract.Storage, 1)
+	require.Equal(t, []byte("counter"), contract.Storage[0].Key.Value)
+	require.Equal(t, []byte{5}, contract.Storage[0].Value.Value)
+	require.Equal(t, []byte(trace.Accounts[0].Address), contract.Owner.Value)
+	require.Len(t, setState.NewAddressMocks, 1)
+	require.Equal(t, uint64(8), setState.NewAddressMocks[0].CreatorNonce.Value)
+
+	blockStep := parsed.Steps[1].(*mj.SetStateStep)
+	require.Equal(t, uint64(1600000000), blockStep.CurrentBlockInfo.BlockTimestamp.Value)
+
+	call := parsed.Steps[2].(*mj.TxStep)
+	require.Equal(t, "0x1234", call.TxIdent)
+	require.Equal(t, mj.ScCall, call.Tx.Type)
+	require.Equal(t, "increment", call.Tx.Function)
+	require.Equal(t, [][]byte{{2}}, mj.JSONBytesFromTreeValues(call.Tx.Arguments))
+	require.True(t, call.ExpectedResult.Out.CheckList([][]byte{{7}}))
+	require.True(t, call.ExpectedResult.Status.Check(big.NewInt(0)))
+	require.True(t, call.ExpectedResult.Refund.IsStar)
+	require.Len(t, call.ExpectedResult.Logs.List, 1)
+	require.True(t, call.ExpectedResult.Logs.List[0].Endpoint.Check([]byte("increment")))
+
+	deploy := parsed.Steps[3].(*mj.TxStep)
+	require.Equal(t, "2", deploy.TxIdent)
+	require.Equal(t, mj.ScDeploy, deploy.Tx.Type)
+	require.True(t, deploy.ExpectedResult.Status.Check(big.NewInt(10)))
+	require.True(t, deploy.ExpectedResult.Message.Check([]byte("invalid contract code")))
+	require.True(t, deploy.ExpectedResult.Refund.Check(big.NewInt(0)))
+	require.True(t, deploy.ExpectedResult.Logs.IsStar)
+
+	checkState := parsed.Steps[4].(*mj.CheckStateStep)
+	require.True(t, checkState.CheckAccounts.MoreAccountsAllowed)
+	require.Len(t, checkState.CheckAccounts.Accounts, 1)
+	require.True(t, checkState.CheckAccounts.Accounts[0].MoreStorageAllowed)
+	require.True(t, checkState.CheckAccounts.Accounts[0].CheckStorage[0].CheckValue.Check([]byte{7}))
+}
+
+func TestTraceToScenario_InvalidTraces(t *testing.T) {
+	_, err := ParseTrace([]byte(`{"accounts": [{"address": "not hex"}]}`))
+	require.True(t, errors.Is(err, ErrInvalidTrace))
+
+	trace, err := ParseTrace([]byte(`{"transactions": [{"sender": "0x01", "receiver": "0x02"}]}`))
+	require.Nil(t, err)
+	_, err = TraceToScenario(trace)
+	require.True(t, errors.Is(err, ErrInvalidTrace))
+
+	trace, err = ParseTrace([]byte(`{"transactions": [{"sender": "0x01", "code": "0x00", "output": {}}]}`))
+	require.Nil(t, err)
+	_, err = TraceToScenario(trace)
+	require.True(t, errors.Is(err, ErrInvalidTrace))
+
+	trace, err = ParseTrace([]byte(`{"accounts": [{"address": "0x01", "storage": {"key": "0x00"}}]}`))
+	require.Nil(t, err)
+	_, err = TraceToScenario(trace)
+	require.True(t, errors.Is(err, ErrInvalidTrace))
+}
@@ -0,0 +1,131 @@
+package replayconvert
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"math/big"
+	"strings"
+)
+
+// ErrInvalidTrace signals a replay trace which cannot be converted to a scenario
+var ErrInvalidTrace = errors.New("invalid replay trace")
+
+// HexBytes is a byte slice encoded in JSON as a hex string, with or without
+// the 0x prefix
+type HexBytes []byte
+
+// MarshalJSON encodes the bytes as a 0x prefixed hex string
+func (hb HexBytes) MarshalJSON() ([]byte, error) {
+	return json.Marshal(hexString(hb))
+}
+
+// UnmarshalJSON decodes a hex string, with or without the 0x prefix
+func (hb *HexBytes) UnmarshalJSON(data []byte) error {
+	var str string
+	err := json.Unmarshal(data, &str)
+	if err != nil {
+		return err
+	}
+
+	decoded, err := decodeHex(str)
+	if err != nil {
+		return err
+	}
+	*hb = decoded
+	return nil
+}
+
+func decodeHex(str string) ([]byte, error) {
+	return hex.DecodeString(strings.TrimPrefix(str, "0x"))
+}
+
+func hexString(value []byte) string {
+	if len(value) == 0 {
+		return ""
+	}
+	return "0x" + hex.EncodeToString(value)
+}
+
+// Trace is a recording of transactions executed on a real network, together
+// with the state of the accounts they touched before the first of them. The
+// balances and values are JSON numbers, to keep their full precision.
+type Trace struct {
+	Name         string              `json:"name"`
+	Comment      string              `json:"comment"`
+	Accounts     []*TraceAccount     `json:"accounts"`
+	Transactions []*TraceTransaction `json:"transactions"`
+	PostState    []*TraceAccount     `json:"postState"`
+}
+
+// TraceAccount is the state of an account, with its storage mapped from hex
+// encoded keys to hex encoded values
+type TraceAccount struct {
+	Address HexBytes            `json:"address"`
+	Nonce   uint64              `json:"nonce"`
+	Balance *big.Int            `json:"balance"`
+	Code    HexBytes            `json:"code"`
+	Owner   HexBytes            `json:"owner"`
+	Storage map[string]HexBytes `json:"storage"`
+}
+
+// TraceTransaction is a recorded transaction and the output it produced. A
+// transaction without a receiver deploys its Code at the DeployedAddress.
+type TraceTransaction struct {
+	Hash            HexBytes     `json:"hash"`
+	Sender          HexBytes     `json:"sender"`
+	Receiver        HexBytes     `json:"receiver"`
+	Nonce           uint64       `json:"nonce"`
+	Value           *big.Int     `json:"value"`
+	Function        string       `json:"function"`
+	Arguments       []HexBytes   `json:"arguments"`
+	Code            HexBytes     `json:"code"`
+	DeployedAddress HexBytes     `json:"deployedAddress"`
+	GasLimit        uint64       `json:"gasLimit"`
+	GasPrice        uint64       `json:"gasPrice"`
+	Block           *TraceBlock  `json:"block"`
+	Output          *TraceOutput `json:"output"`
+}
+
+// IsDeploy returns true if the transaction deploys a contract
+func (tx *TraceTransaction) IsDeploy() bool {
+	return len(tx.Receiver) == 0
+}
+
+// TraceBlock holds the information of the block in which a transaction was executed
+type TraceBlock struct {
+	Nonce      uint64   `json:"nonce"`
+	Round      uint64   `json:"round"`
+	Epoch      uint64   `json:"epoch"`
+	Timestamp  uint64   `json:"timestamp"`
+	RandomSeed HexBytes `json:"randomSeed"`
+}
+
+// TraceOutput is the recorded outcome of a transaction. When Logs is absent
+// from the trace, the logs of the replayed transaction are not checked.
+type TraceOutput struct {
+	ReturnCode    uint64      `json:"returnCode"`
+	ReturnMessage string      `json:"returnMessage"`
+	ReturnData    []HexBytes  `json:"returnData"`
+	GasRefund     *big.Int    `json:"gasRefund"`
+	Logs          []*TraceLog `json:"logs"`
+}
+
+// TraceLog is a log entry produced by a transaction
+type TraceLog struct {
+	Address    HexBytes   `json:"address"`
+	Identifier string     `json:"identifier"`
+	Topics     []HexBytes `json:"topics"`
+	Data       HexBytes   `json:"data"`
+}
+
+// ParseTrace decodes a JSON replay trace
+func ParseTrace(data []byte) (*Trace, error) {
+	trace := &Trace{}
+	err := json.Unmarshal(data, trace)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidTrace, err)
+	}
+	return trace, nil
+}
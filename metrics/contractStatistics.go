@@ -0,0 +1,189 @@
+package metrics
+
+import (
+	"bytes"
+	"encoding/hex"
+	"fmt"
+	"sort"
+	"sync"
+	"time"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.ContractMetricsSink = (*ContractStatisticsRegistry)(nil)
+
+// OtherContractsCodeHash is the code hash under which a ContractStatisticsRegistry
+// aggregates the contracts which exceed its maximum number of contracts
+const OtherContractsCodeHash = "other"
+
+// ContractStatistics are the measurements of the executions of a contract code
+type ContractStatistics struct {
+	CodeHash          []byte
+	Calls             uint64
+	Failures          uint64
+	GasUsed           uint64
+	Duration          time.Duration
+	InstanceCreations uint64
+	CacheHits         uint64
+}
+
+// AverageGasUsed returns the gas used by an execution of the contract, on average
+func (statistics ContractStatistics) AverageGasUsed() uint64 {
+	if statistics.Calls == 0 {
+		return 0
+	}
+	return statistics.GasUsed / statistics.Calls
+}
+
+// AverageDuration returns the duration of an execution of the contract, on average
+func (statistics ContractStatistics) AverageDuration() time.Duration {
+	if statistics.Calls == 0 {
+		return 0
+	}
+	return statistics.Duration / time.Duration(statistics.Calls)
+}
+
+// ContractStatisticsRegistry keeps the ContractStatistics of the contracts
+// executed by the VM for as long as the node runs, by code hash. It keeps at
+// most a given number of contracts, in the order in which they are first
+// executed, and aggregates the others under OtherContractsCodeHash.
+type ContractStatisticsRegistry struct {
+	mutex        sync.Mutex
+	maxContracts int
+	contracts    map[string]*ContractStatistics
+	others       *ContractStatistics
+}
+
+// NewContractStatisticsRegistry creates a ContractStatisticsRegistry keeping
+// the statistics of up to maxContracts contracts
+func NewContractStatisticsRegistry(maxContracts int) *ContractStatisticsRegistry {
+	return &ContractStatisticsRegistry{
+		maxContracts: maxContracts,
+		contracts:    make(map[string]*ContractStatistics),
+		others:       &ContractStatistics{CodeHash: []byte(OtherContractsCodeHash)},
+	}
+}
+
+// ContractsObserved returns true, as the registry is only created to observe the contracts
+func (registry *ContractStatisticsRegistry) ContractsObserved() bool {
+	return true
+}
+
+// ObserveContractExecution counts an execution of the contract, with its outcome, gas and duration
+func (registry *ContractStatisticsRegistry) ObserveContractExecution(codeHash []byte, returnCode vmcommon.ReturnCode, gasUsed uint64, duration time.Duration) {
+	registry.mutex.Lock()
+	defer registry.mutex.Unlock()
+
+	statistics := registry.statisticsOf(codeHash)
+	statistics.Calls++
+	if returnCode != vmcommon.Ok {
+		statistics.Failures++
+	}
+	statistics.GasUsed += gasUsed
+	statistics.Duration += duration
+}
+
+// ObserveContractInstanceCreation counts an instance of the contract, and whether it was obtained from a cache
+func (registry *ContractStatisticsRegistry) ObserveContractInstanceCreation(codeHash []byte, source arwen.InstanceSource) {
+	registry.mutex.Lock()
+	defer registry.mutex.Unlock()
+
+	statistics := registry.statisticsOf(codeHash)
+	statistics.InstanceCreations++
+	if source.IsCacheHit() {
+		statistics.CacheHits++
+	}
+}
+
+func (registry *ContractStatisticsRegistry) statisticsOf(codeHash []byte) *ContractStatistics {
+	statistics, ok := registry.contracts[string(codeHash)]
+	if ok {
+		return statistics
+	}
+	if len(registry.contracts) >= registry.maxContracts {
+		return registry.others
+	}
+
+	statistics = &ContractStatistics{CodeHash: append([]byte{}, codeHash...)}
+	registry.contracts[string(codeHash)] = statistics
+	return statistics
+}
+
+// Statistics returns the statistics of the contract code, and false if it was never observed
+func (registry *ContractStatisticsRegistry) Statistics(codeHash []byte) (ContractStatistics, bool) {
+	registry.mutex.Lock()
+	defer registry.mutex.Unlock()
+
+	statistics, ok := registry.contracts[string(codeHash)]
+	if !ok {
+		return ContractStatistics{}, false
+	}
+	return *statistics, true
+}
+
+// AllStatistics returns the statistics of all the observed contracts, the
+// most called first, followed by those aggregated under OtherContractsCodeHash
+// if there are any
+func (registry *ContractStatisticsRegistry) AllStatistics() []ContractStatistics {
+	registry.mutex.Lock()
+	defer registry.mutex.Unlock()
+
+	all := make([]ContractStatistics, 0, len(registry.contracts)+1)
+	for _, statistics := range registry.contracts {
+		all = append(all, *statistics)
+	}
+	sort.Slice(all, func(i, j int) bool {
+		if all[i].Calls != all[j].Calls {
+			return all[i].Calls > all[j].Calls
+		}
+		return bytes.Compare(all[i].CodeHash, all[j].CodeHash) < 0
+	})
+
+	if registry.others.Calls > 0 || registry.others.InstanceCreations > 0 {
+		all = append(all, *registry.others)
+	}
+	return all
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (registry *ContractStatisticsRegistry) IsInterfaceNil() bool {
+	return registry == nil
+}
+
+func (registry *ContractStatisticsRegistry) writeTo(buffer *bytes.Buffer, namespace string) {
+	all := registry.AllStatistics()
+	series := []struct {
+		name   string
+		help   string
+		metric string
+		value  func(statistics ContractStatistics) string
+	}{
+		{"contract_calls_total", "Number of executions of a contract code.", "counter",
+			func(statistics ContractStatistics) string { return fmt.Sprint(statistics.Calls) }},
+		{"contract_failures_total", "Number of failed executions of a contract code.", "counter",
+			func(statistics ContractStatistics) string { return fmt.Sprint(statistics.Failures) }},
+		{"contract_gas_consumed_total", "Gas consumed by the executions of a contract code.", "counter",
+			func(statistics ContractStatistics) string { return fmt.Sprint(statistics.GasUsed) }},
+		{"contract_execution_duration_seconds_total", "Duration of the executions of a contract code.", "counter",
+			func(statistics ContractStatistics) string { return formatFloat(statistics.Duration.Seconds()) }},
+		{"contract_instance_cache_hits_total", "Number of instances of a contract code obtained from a cache.", "counter",
+			func(statistics ContractStatistics) string { return fmt.Sprint(statistics.CacheHits) }},
+	}
+
+	for _, serie := range series {
+		fullName := namespace + "_" + serie.name
+		writeHeader(buffer, fullName, serie.help, serie.metric)
+		for _, statistics := range all {
+			fmt.Fprintf(buffer, "%s{%s} %s\n", fullName, formatLabels("code_hash", codeHashLabel(statistics.CodeHash)), serie.value(statistics))
+		}
+	}
+}
+
+func codeHashLabel(codeHash []byte) string {
+	if string(codeHash) == OtherContractsCodeHash {
+		return OtherContractsCodeHash
+	}
+	return hex.EncodeToString(codeHash)
+}
@@ -0,0 +1,91 @@
+package metrics
+
+import (
+	"bytes"
+	"testing"
+	"time"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/stretchr/testify/require"
+)
+
+func TestContractStatisticsRegistry_Averages(t *testing.T) {
+	registry := NewContractStatisticsRegistry(10)
+	codeHash := []byte("code")
+
+	registry.ObserveContractExecution(codeHash, vmcommon.Ok, 100, 2*time.Millisecond)
+	registry.ObserveContractExecution(codeHash, vmcommon.UserError, 50, 4*time.Millisecond)
+	registry.ObserveContractInstanceCreation(codeHash, arwen.InstanceFromBytecode)
+	registry.ObserveContractInstanceCreation(codeHash, arwen.InstanceFromWarmCache)
+
+	statistics, ok := registry.Statistics(codeHash)
+	require.True(t, ok)
+	require.Equal(t, uint64(2), statistics.Calls)
+	require.Equal(t, uint64(1), statistics.Failures)
+	require.Equal(t, uint64(75), statistics.AverageGasUsed())
+	require.Equal(t, 3*time.Millisecond, statistics.AverageDuration())
+	require.Equal(t, uint64(2), statistics.InstanceCreations)
+	require.Equal(t, uint64(1), statistics.CacheHits)
+
+	_, ok = registry.Statistics([]byte("missing"))
+	require.False(t, ok)
+	require.Equal(t, uint64(0), ContractStatistics{}.AverageGasUsed())
+	require.Equal(t, time.Duration(0), ContractStatistics{}.AverageDuration())
+}
+
+func TestContractStatisticsRegistry_AllStatisticsOverflow(t *testing.T) {
+	registry := NewContractStatisticsRegistry(2)
+
+	registry.ObserveContractExecution([]byte("first"), vmcommon.Ok, 1, time.Millisecond)
+	registry.ObserveContractExecution([]byte("second"), vmcommon.Ok, 1, time.Millisecond)
+	registry.ObserveContractExecution([]byte("second"), vmcommon.Ok, 1, time.Millisecond)
+	registry.ObserveContractExecution([]byte("third"), vmcommon.Ok, 1, time.Millisecond)
+	registry.ObserveContractExecution([]byte("fourth"), vmcommon.OutOfGas, 1, time.Millisecond)
+
+	_, ok := registry.Statistics([]byte("third"))
+	require.False(t, ok)
+
+	all := registry.AllStatistics()
+	require.Len(t, all, 3)
+	require.Equal(t, []byte("second"), all[0].CodeHash)
+	require.Equal(t, []byte("first"), all[1].CodeHash)
+	require.Equal(t, []byte(OtherContractsCodeHash), all[2].CodeHash)
+	require.Equal(t, uint64(2), all[2].Calls)
+	require.Equal(t, uint64(1), all[2].Failures)
+}
+
+func TestPrometheusSink_ContractStatistics(t *testing.T) {
+	sink, err := NewPrometheusSink(PrometheusSinkArgs{Namespace: "vm"})
+	require.Nil(t, err)
+	require.False(t, sink.ContractsObserved())
+	require.Nil(t, sink.ContractStatistics())
+	sink.ObserveContractExecution([]byte{0xab}, vmcommon.Ok, 100, time.Second)
+
+	sink, err = NewPrometheusSink(PrometheusSinkArgs{
+		Namespace:           "vm",
+		MaxTrackedContracts: 1,
+	})
+	require.Nil(t, err)
+	require.True(t, sink.ContractsObserved())
+
+	sink.ObserveContractExecution([]byte{0xab}, vmcommon.Ok, 100, time.Second)
+	sink.ObserveContractExecution([]byte{0xcd}, vmcommon.UserError, 20, time.Second)
+	sink.ObserveContractInstanceCreation([]byte{0xab}, arwen.InstanceFromCompiledCode)
+
+	statistics, ok := sink.ContractStatistics().Statistics([]byte{0xab})
+	require.True(t, ok)
+	require.Equal(t, uint64(1), statistics.CacheHits)
+
+	output := &bytes.Buffer{}
+	_, err = sink.WriteTo(output)
+	require.Nil(t, err)
+
+	exported := output.String()
+	require.Contains(t, exported, "# TYPE vm_contract_calls_total counter\n")
+	require.Contains(t, exported, `vm_contract_calls_total{code_hash="ab"} 1`)
+	require.Contains(t, exported, `vm_contract_failures_total{code_hash="other"} 1`)
+	require.Contains(t, exported, `vm_contract_gas_consumed_total{code_hash="ab"} 100`)
+	require.Contains(t, exported, `vm_contract_execution_duration_seconds_total{code_hash="other"} 1`)
+	require.Contains(t, exported, `vm_contract_instance_cache_hits_total{code_hash="ab"} 1`)
+}
@@ -0,0 +1,53 @@
+package metrics
+
+import (
+	"time"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.MetricsSink = (*DisabledSink)(nil)
+
+// DisabledSink is the MetricsSink used when the node does not collect metrics; it discards every measurement
+type DisabledSink struct {
+}
+
+// NewDisabledSink creates a new DisabledSink
+func NewDisabledSink() *DisabledSink {
+	return &DisabledSink{}
+}
+
+// ObserveExecution does nothing
+func (sink *DisabledSink) ObserveExecution(_ arwen.ExecutionKind, _ vmcommon.ReturnCode, _ uint64, _ time.Duration) {
+}
+
+// ObserveInstanceCreation does nothing
+func (sink *DisabledSink) ObserveInstanceCreation(_ arwen.InstanceSource) {
+}
+
+// ObserveTrap does nothing
+func (sink *DisabledSink) ObserveTrap(_ arwen.ErrorCategory) {
+}
+
+// ObserveHostFunctionCall does nothing
+func (sink *DisabledSink) ObserveHostFunctionCall(_ string, _ time.Duration) {
+}
+
+// ObserveCodeCacheLookup does nothing
+func (sink *DisabledSink) ObserveCodeCacheLookup(_ bool) {
+}
+
+// ObserveInfrastructureError does nothing
+func (sink *DisabledSink) ObserveInfrastructureError(_ bool) {
+}
+
+// HostFunctionCallsObserved returns false, so that the VM hooks are not timed
+func (sink *DisabledSink) HostFunctionCallsObserved() bool {
+	return false
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (sink *DisabledSink) IsInterfaceNil() bool {
+	return sink == nil
+}
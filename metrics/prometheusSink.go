@@ -0,0 +1,309 @@
+package metrics
+
+import (
+	"bytes"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.MetricsSink = (*PrometheusSink)(nil)
+var _ arwen.ContractMetricsSink = (*PrometheusSink)(nil)
+
+// DefaultNamespace prefixes the names of the metrics exported by a PrometheusSink
+const DefaultNamespace = "arwen"
+
+// DefaultDurationBuckets are the upper bounds, in seconds, of the buckets of
+// the duration histograms; they span from the cheapest host functions to the
+// slowest contract executions
+var DefaultDurationBuckets = []float64{1e-7, 1e-6, 1e-5, 1e-4, 1e-3, 1e-2, 1e-1, 1}
+
+// ErrUnsortedBuckets signals that the upper bounds of the histogram buckets are not in increasing order
+var ErrUnsortedBuckets = errors.New("histogram buckets are not sorted")
+
+const contentTypeTextFormat = "text/plain; version=0.0.4; charset=utf-8"
+
+var labelValueEscaper = strings.NewReplacer(`\`, `\\`, `"`, `\"`, "\n", `\n`)
+
+// PrometheusSinkArgs holds the configuration of a PrometheusSink
+type PrometheusSinkArgs struct {
+	Namespace            string
+	DurationBuckets      []float64
+	ObserveHostFunctions bool
+
+	// MaxTrackedContracts enables the statistics by contract code, kept for
+	// up to that many contracts; 0 disables them
+	MaxTrackedContracts int
+}
+
+// PrometheusSink is a MetricsSink which aggregates the measurements of the VM
+// in counters and histograms and exposes them in the Prometheus text format,
+// either through WriteTo or as an http.Handler serving the metrics endpoint
+type PrometheusSink struct {
+	mutex                sync.Mutex
+	namespace            string
+	durationBuckets      []float64
+	observeHostFunctions bool
+
+	executions           map[string]uint64
+	executionDurations   map[string]*histogram
+	gasConsumed          map[string]uint64
+	instanceCreations    map[string]uint64
+	traps                map[string]uint64
+	hostFunctionDuration map[string]*histogram
+	codeCacheLookups     map[string]uint64
+	infrastructureErrors map[string]uint64
+	contracts            *ContractStatisticsRegistry
+}
+
+// NewPrometheusSink creates a new PrometheusSink; empty arguments are replaced
+// by DefaultNamespace and DefaultDurationBuckets
+func NewPrometheusSink(args PrometheusSinkArgs) (*PrometheusSink, error) {
+	namespace := args.Namespace
+	if len(namespace) == 0 {
+		namespace = DefaultNamespace
+	}
+	durationBuckets := args.DurationBuckets
+	if len(durationBuckets) == 0 {
+		durationBuckets = DefaultDurationBuckets
+	}
+	if !sort.Float64sAreSorted(durationBuckets) {
+		return nil, ErrUnsortedBuckets
+	}
+
+	var contracts *ContractStatisticsRegistry
+	if args.MaxTrackedContracts > 0 {
+		contracts = NewContractStatisticsRegistry(args.MaxTrackedContracts)
+	}
+
+	return &PrometheusSink{
+		namespace:            namespace,
+		durationBuckets:      durationBuckets,
+		observeHostFunctions: args.ObserveHostFunctions,
+		executions:           make(map[string]uint64),
+		executionDurations:   make(map[string]*histogram),
+		gasConsumed:          make(map[string]uint64),
+		instanceCreations:    make(map[string]uint64),
+		traps:                make(map[string]uint64),
+		hostFunctionDuration: make(map[string]*histogram),
+		codeCacheLookups:     make(map[string]uint64),
+		infrastructureErrors: make(map[string]uint64),
+		contracts:            contracts,
+	}, nil
+}
+
+// ObserveExecution counts an execution of the VM, with its gas and duration
+func (sink *PrometheusSink) ObserveExecution(kind arwen.ExecutionKind, returnCode vmcommon.ReturnCode, gasUsed uint64, duration time.Duration) {
+	sink.mutex.Lock()
+	defer sink.mutex.Unlock()
+
+	kindLabels := formatLabels("kind", kind.String())
+	sink.executions[formatLabels("kind", kind.String(), "return_code", returnCode.String())]++
+	sink.gasConsumed[kindLabels] += gasUsed
+	sink.observeDuration(sink.executionDurations, kindLabels, duration)
+}
+
+// ObserveInstanceCreation counts the executor instances by the way in which they were obtained
+func (sink *PrometheusSink) ObserveInstanceCreation(source arwen.InstanceSource) {
+	sink.mutex.Lock()
+	defer sink.mutex.Unlock()
+
+	sink.instanceCreations[formatLabels("source", source.String(), "cache_hit", strconv.FormatBool(source.IsCacheHit()))]++
+}
+
+// ObserveTrap counts the failed executions by the category of their error
+func (sink *PrometheusSink) ObserveTrap(category arwen.ErrorCategory) {
+	sink.mutex.Lock()
+	defer sink.mutex.Unlock()
+
+	sink.traps[formatLabels("category", category.String())]++
+}
+
+// ObserveHostFunctionCall records the duration of a call of a host function
+func (sink *PrometheusSink) ObserveHostFunctionCall(hookName string, duration time.Duration) {
+	sink.mutex.Lock()
+	defer sink.mutex.Unlock()
+
+	sink.observeDuration(sink.hostFunctionDuration, formatLabels("host_function", hookName), duration)
+}
+
+// ObserveCodeCacheLookup counts the lookups of contract code in the code cache, by their outcome
+func (sink *PrometheusSink) ObserveCodeCacheLookup(hit bool) {
+	sink.mutex.Lock()
+	defer sink.mutex.Unlock()
+
+	sink.codeCacheLookups[formatLabels("cache_hit", strconv.FormatBool(hit))]++
+}
+
+// ObserveInfrastructureError counts the failures of the node or of the
+// executor, by whether the transaction had to be executed again
+func (sink *PrometheusSink) ObserveInfrastructureError(retried bool) {
+	sink.mutex.Lock()
+	defer sink.mutex.Unlock()
+
+	sink.infrastructureErrors[formatLabels("retried", strconv.FormatBool(retried))]++
+}
+
+// HostFunctionCallsObserved returns true if the sink was configured to time the host functions
+func (sink *PrometheusSink) HostFunctionCallsObserved() bool {
+	return sink.observeHostFunctions
+}
+
+// ContractsObserved returns true if the sink was configured to keep the statistics by contract code
+func (sink *PrometheusSink) ContractsObserved() bool {
+	return sink.contracts != nil
+}
+
+// ObserveContractExecution records an execution in the statistics of the contract code
+func (sink *PrometheusSink) ObserveContractExecution(codeHash []byte, returnCode vmcommon.ReturnCode, gasUsed uint64, duration time.Duration) {
+	if sink.contracts != nil {
+		sink.contracts.ObserveContractExecution(codeHash, returnCode, gasUsed, duration)
+	}
+}
+
+// ObserveContractInstanceCreation records an instance in the statistics of the contract code
+func (sink *PrometheusSink) ObserveContractInstanceCreation(codeHash []byte, source arwen.InstanceSource) {
+	if sink.contracts != nil {
+		sink.contracts.ObserveContractInstanceCreation(codeHash, source)
+	}
+}
+
+// ContractStatistics returns the statistics by contract code, nil unless
+// PrometheusSinkArgs.MaxTrackedContracts enabled them
+func (sink *PrometheusSink) ContractStatistics() *ContractStatisticsRegistry {
+	return sink.contracts
+}
+
+func (sink *PrometheusSink) observeDuration(histograms map[string]*histogram, labels string, duration time.Duration) {
+	durationHistogram, ok := histograms[labels]
+	if !ok {
+		durationHistogram = newHistogram(sink.durationBuckets)
+		histograms[labels] = durationHistogram
+	}
+	durationHistogram.observe(duration.Seconds())
+}
+
+// WriteTo writes all the metrics in the Prometheus text exposition format
+func (sink *PrometheusSink) WriteTo(writer io.Writer) (int64, error) {
+	sink.mutex.Lock()
+	buffer := &bytes.Buffer{}
+	sink.writeCounters(buffer, "executions_total", "Number of executions of the VM.", sink.executions)
+	sink.writeCounters(buffer, "gas_consumed_total", "Gas consumed by the executions of the VM.", sink.gasConsumed)
+	sink.writeHistograms(buffer, "execution_duration_seconds", "Duration of the executions of the VM.", sink.executionDurations)
+	sink.writeCounters(buffer, "instance_creations_total", "Number of executor instances, by the way in which they were obtained.", sink.instanceCreations)
+	sink.writeCounters(buffer, "traps_total", "Number of failed executions, by error category.", sink.traps)
+	sink.writeHistograms(buffer, "host_function_duration_seconds", "Duration of the calls of host functions.", sink.hostFunctionDuration)
+	sink.writeCounters(buffer, "code_cache_lookups_total", "Number of lookups of contract code in the code cache, by outcome.", sink.codeCacheLookups)
+	sink.writeCounters(buffer, "infrastructure_errors_total", "Number of failures of the node or of the executor, by whether the transaction was executed again.", sink.infrastructureErrors)
+	sink.mutex.Unlock()
+
+	if sink.contracts != nil {
+		sink.contracts.writeTo(buffer, sink.namespace)
+	}
+
+	return buffer.WriteTo(writer)
+}
+
+// ServeHTTP answers a scrape of the metrics endpoint
+func (sink *PrometheusSink) ServeHTTP(writer http.ResponseWriter, _ *http.Request) {
+	writer.Header().Set("Content-Type", contentTypeTextFormat)
+	_, _ = sink.WriteTo(writer)
+}
+
+func (sink *PrometheusSink) writeCounters(buffer *bytes.Buffer, name string, help string, counters map[string]uint64) {
+	fullName := sink.namespace + "_" + name
+	writeHeader(buffer, fullName, help, "counter")
+	for _, labels := range sortedKeys(counters) {
+		fmt.Fprintf(buffer, "%s{%s} %d\n", fullName, labels, counters[labels])
+	}
+}
+
+func (sink *PrometheusSink) writeHistograms(buffer *bytes.Buffer, name string, help string, histograms map[string]*histogram) {
+	fullName := sink.namespace + "_" + name
+	writeHeader(buffer, fullName, help, "histogram")
+
+	labelSets := make([]string, 0, len(histograms))
+	for labels := range histograms {
+		labelSets = append(labelSets, labels)
+	}
+	sort.Strings(labelSets)
+
+	for _, labels := range labelSets {
+		histograms[labels].writeTo(buffer, fullName, labels)
+	}
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (sink *PrometheusSink) IsInterfaceNil() bool {
+	return sink == nil
+}
+
+// histogram counts observations in cumulative buckets, as Prometheus expects them
+type histogram struct {
+	upperBounds []float64
+	counts      []uint64
+	count       uint64
+	sum         float64
+}
+
+func newHistogram(upperBounds []float64) *histogram {
+	return &histogram{
+		upperBounds: upperBounds,
+		counts:      make([]uint64, len(upperBounds)),
+	}
+}
+
+func (h *histogram) observe(value float64) {
+	h.count++
+	h.sum += value
+	for i, upperBound := range h.upperBounds {
+		if value <= upperBound {
+			h.counts[i]++
+		}
+	}
+}
+
+func (h *histogram) writeTo(buffer *bytes.Buffer, name string, labels string) {
+	for i, upperBound := range h.upperBounds {
+		fmt.Fprintf(buffer, "%s_bucket{%s,le=\"%s\"} %d\n", name, labels, formatFloat(upperBound), h.counts[i])
+	}
+	fmt.Fprintf(buffer, "%s_bucket{%s,le=\"+Inf\"} %d\n", name, labels, h.count)
+	fmt.Fprintf(buffer, "%s_sum{%s} %s\n", name, labels, formatFloat(h.sum))
+	fmt.Fprintf(buffer, "%s_count{%s} %d\n", name, labels, h.count)
+}
+
+func writeHeader(buffer *bytes.Buffer, name string, help string, metricType string) {
+	fmt.Fprintf(buffer, "# HELP %s %s\n", name, help)
+	fmt.Fprintf(buffer, "# TYPE %s %s\n", name, metricType)
+}
+
+// formatLabels renders alternating label names and values, in the given order
+func formatLabels(namesAndValues ...string) string {
+	labels := make([]string, 0, len(namesAndValues)/2)
+	for i := 0; i+1 < len(namesAndValues); i += 2 {
+		labels = append(labels, fmt.Sprintf("%s=\"%s\"", namesAndValues[i], labelValueEscaper.Replace(namesAndValues[i+1])))
+	}
+	return strings.Join(labels, ",")
+}
+
+func formatFloat(value float64) string {
+	return strconv.FormatFloat(value, 'g', -1, 64)
+}
+
+func sortedKeys(counters map[string]uint64) []string {
+	keys := make([]string, 0, len(counters))
+	for key := range counters {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys
+}
@@ -0,0 +1,80 @@
+package metrics
+
+import (
+	"bytes"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/stretchr/testify/require"
+)
+
+func TestNewPrometheusSink_UnsortedBuckets(t *testing.T) {
+	sink, err := NewPrometheusSink(PrometheusSinkArgs{
+		DurationBuckets: []float64{1, 0.5},
+	})
+	require.Nil(t, sink)
+	require.Equal(t, ErrUnsortedBuckets, err)
+}
+
+func TestPrometheusSink_WriteTo(t *testing.T) {
+	sink, err := NewPrometheusSink(PrometheusSinkArgs{
+		Namespace:       "vm",
+		DurationBuckets: []float64{0.001, 0.1},
+	})
+	require.Nil(t, err)
+	require.False(t, sink.HostFunctionCallsObserved())
+
+	sink.ObserveExecution(arwen.ExecutionCall, vmcommon.Ok, 100, 2*time.Millisecond)
+	sink.ObserveExecution(arwen.ExecutionCall, vmcommon.Ok, 50, time.Second)
+	sink.ObserveExecution(arwen.ExecutionCreate, vmcommon.OutOfGas, 30, time.Microsecond)
+	sink.ObserveInstanceCreation(arwen.InstanceFromBytecode)
+	sink.ObserveInstanceCreation(arwen.InstanceFromWarmCache)
+	sink.ObserveInstanceCreation(arwen.InstanceFromWarmCache)
+	sink.ObserveTrap(arwen.ErrorCategoryGas)
+	sink.ObserveHostFunctionCall("weird\"name", time.Microsecond)
+	sink.ObserveCodeCacheLookup(true)
+	sink.ObserveCodeCacheLookup(false)
+	sink.ObserveCodeCacheLookup(true)
+	sink.ObserveInfrastructureError(true)
+
+	output := &bytes.Buffer{}
+	_, err = sink.WriteTo(output)
+	require.Nil(t, err)
+
+	exported := output.String()
+	require.Contains(t, exported, "# TYPE vm_executions_total counter\n")
+	require.Contains(t, exported, `vm_executions_total{kind="call",return_code="ok"} 2`)
+	require.Contains(t, exported, `vm_gas_consumed_total{kind="call"} 150`)
+	require.Contains(t, exported, `vm_gas_consumed_total{kind="create"} 30`)
+	require.Contains(t, exported, "# TYPE vm_execution_duration_seconds histogram\n")
+	require.Contains(t, exported, `vm_execution_duration_seconds_bucket{kind="call",le="0.001"} 0`)
+	require.Contains(t, exported, `vm_execution_duration_seconds_bucket{kind="call",le="0.1"} 1`)
+	require.Contains(t, exported, `vm_execution_duration_seconds_bucket{kind="call",le="+Inf"} 2`)
+	require.Contains(t, exported, `vm_execution_duration_seconds_sum{kind="call"} 1.002`)
+	require.Contains(t, exported, `vm_execution_duration_seconds_count{kind="create"} 1`)
+	require.Contains(t, exported, `vm_instance_creations_total{source="bytecode",cache_hit="false"} 1`)
+	require.Contains(t, exported, `vm_instance_creations_total{source="warm_cache",cache_hit="true"} 2`)
+	require.Contains(t, exported, `vm_traps_total{category="gas"} 1`)
+	require.Contains(t, exported, `vm_host_function_duration_seconds_count{host_function="weird\"name"} 1`)
+	require.Contains(t, exported, `vm_code_cache_lookups_total{cache_hit="true"} 2`)
+	require.Contains(t, exported, `vm_code_cache_lookups_total{cache_hit="false"} 1`)
+	require.Contains(t, exported, `vm_infrastructure_errors_total{retried="true"} 1`)
+}
+
+func TestPrometheusSink_ServeHTTP(t *testing.T) {
+	sink, err := NewPrometheusSink(PrometheusSinkArgs{
+		ObserveHostFunctions: true,
+	})
+	require.Nil(t, err)
+	require.True(t, sink.HostFunctionCallsObserved())
+
+	sink.ObserveTrap(arwen.ErrorCategoryAsync)
+
+	recorder := httptest.NewRecorder()
+	sink.ServeHTTP(recorder, httptest.NewRequest("GET", "/metrics", nil))
+	require.Equal(t, contentTypeTextFormat, recorder.Header().Get("Content-Type"))
+	require.Contains(t, recorder.Body.String(), `arwen_traps_total{category="async"} 1`)
+}
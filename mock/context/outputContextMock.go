@@ -162,11 +162,58 @@ func (o *OutputContextMock) DeleteFirstReturnData() {
 	}
 }
 
+// SetMaxReturnDataSize mocked method
+func (o *OutputContextMock) SetMaxReturnDataSize(_ uint64) {
+}
+
+// CheckReturnDataSize mocked method
+func (o *OutputContextMock) CheckReturnDataSize(_ uint64) error {
+	return o.Err
+}
+
+// SetLogLimits mocked method
+func (o *OutputContextMock) SetLogLimits(_ uint64, _ uint64) {
+}
+
+// CheckLogSize mocked method
+func (o *OutputContextMock) CheckLogSize(_ uint64) error {
+	return o.Err
+}
+
+// SetTransferBundling mocked method
+func (o *OutputContextMock) SetTransferBundling(_ bool) {
+}
+
+// SetTypedError mocked method
+func (o *OutputContextMock) SetTypedError(_ []byte, _ []byte) {
+}
+
+// ReserveFinish mocked method
+func (o *OutputContextMock) ReserveFinish(_ uint64) error {
+	o.ReturnDataMock = append(o.ReturnDataMock, make([]byte, 0))
+	return o.Err
+}
+
+// AppendFinish mocked method
+func (o *OutputContextMock) AppendFinish(data []byte) error {
+	if len(o.ReturnDataMock) == 0 {
+		return arwen.ErrNoFinishReserved
+	}
+	last := len(o.ReturnDataMock) - 1
+	o.ReturnDataMock[last] = append(o.ReturnDataMock[last], data...)
+	return o.Err
+}
+
 // WriteLog mocked method
 func (o *OutputContextMock) WriteLog(_ []byte, _ [][]byte, _ []byte) {
 	return
 }
 
+// WriteLogWithIdentifier mocked method
+func (o *OutputContextMock) WriteLogWithIdentifier(_ []byte, _ [][]byte, _ []byte, _ []byte) {
+	return
+}
+
 // TransferValueOnly mocked method
 func (o *OutputContextMock) TransferValueOnly(_ []byte, _ []byte, _ *big.Int, _ bool) error {
 	return o.TransferResult
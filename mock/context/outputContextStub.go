@@ -24,6 +24,7 @@ type OutputContextStub struct {
 	GetOutputAccountCalled            func(address []byte) (*vmcommon.OutputAccount, bool)
 	DeleteOutputAccountCalled         func(address []byte)
 	WriteLogCalled                    func(address []byte, topics [][]byte, data []byte)
+	WriteLogWithIdentifierCalled      func(address []byte, topics [][]byte, data []byte, identifier []byte)
 	TransferCalled                    func(destination []byte, sender []byte, gasLimit uint64, gasLocked uint64, value *big.Int, asyncData []byte, input []byte) error
 	TransferESDTCalled                func(destination []byte, sender []byte, transfers []*vmcommon.ESDTTransfer, input *vmcommon.ContractCallInput) (uint64, error)
 	GetRefundCalled                   func() uint64
@@ -38,6 +39,14 @@ type OutputContextStub struct {
 	FinishCalled                      func(data []byte)
 	PrependFinishCalled               func(data []byte)
 	DeleteFirstReturnDataCalled       func()
+	SetMaxReturnDataSizeCalled        func(maxReturnDataSize uint64)
+	CheckReturnDataSizeCalled         func(length uint64) error
+	SetLogLimitsCalled                func(maxNumberOfLogs uint64, maxLogSize uint64)
+	CheckLogSizeCalled                func(size uint64) error
+	SetTransferBundlingCalled         func(bundle bool)
+	SetTypedErrorCalled               func(code []byte, payload []byte)
+	ReserveFinishCalled               func(length uint64) error
+	AppendFinishCalled                func(data []byte) error
 	GetVMOutputCalled                 func() *vmcommon.VMOutput
 	AddTxValueToAccountCalled         func(address []byte, value *big.Int)
 	DeployCodeCalled                  func(input arwen.CodeDeployInput)
@@ -141,6 +150,13 @@ func (o *OutputContextStub) WriteLog(address []byte, topics [][]byte, data []byt
 	return
 }
 
+// WriteLogWithIdentifier mocked method
+func (o *OutputContextStub) WriteLogWithIdentifier(address []byte, topics [][]byte, data []byte, identifier []byte) {
+	if o.WriteLogWithIdentifierCalled != nil {
+		o.WriteLogWithIdentifierCalled(address, topics, data, identifier)
+	}
+}
+
 // TransferValueOnly mocked method
 func (o *OutputContextStub) TransferValueOnly(destination []byte, sender []byte, value *big.Int, checkPayable bool) error {
 	if o.TransferValueOnlyCalled != nil {
@@ -255,6 +271,66 @@ func (o *OutputContextStub) DeleteFirstReturnData() {
 	}
 }
 
+// SetMaxReturnDataSize mocked method
+func (o *OutputContextStub) SetMaxReturnDataSize(maxReturnDataSize uint64) {
+	if o.SetMaxReturnDataSizeCalled != nil {
+		o.SetMaxReturnDataSizeCalled(maxReturnDataSize)
+	}
+}
+
+// CheckReturnDataSize mocked method
+func (o *OutputContextStub) CheckReturnDataSize(length uint64) error {
+	if o.CheckReturnDataSizeCalled != nil {
+		return o.CheckReturnDataSizeCalled(length)
+	}
+	return nil
+}
+
+// SetLogLimits mocked method
+func (o *OutputContextStub) SetLogLimits(maxNumberOfLogs uint64, maxLogSize uint64) {
+	if o.SetLogLimitsCalled != nil {
+		o.SetLogLimitsCalled(maxNumberOfLogs, maxLogSize)
+	}
+}
+
+// CheckLogSize mocked method
+func (o *OutputContextStub) CheckLogSize(size uint64) error {
+	if o.CheckLogSizeCalled != nil {
+		return o.CheckLogSizeCalled(size)
+	}
+	return nil
+}
+
+// SetTransferBundling mocked method
+func (o *OutputContextStub) SetTransferBundling(bundle bool) {
+	if o.SetTransferBundlingCalled != nil {
+		o.SetTransferBundlingCalled(bundle)
+	}
+}
+
+// SetTypedError mocked method
+func (o *OutputContextStub) SetTypedError(code []byte, payload []byte) {
+	if o.SetTypedErrorCalled != nil {
+		o.SetTypedErrorCalled(code, payload)
+	}
+}
+
+// ReserveFinish mocked method
+func (o *OutputContextStub) ReserveFinish(length uint64) error {
+	if o.ReserveFinishCalled != nil {
+		return o.ReserveFinishCalled(length)
+	}
+	return nil
+}
+
+// AppendFinish mocked method
+func (o *OutputContextStub) AppendFinish(data []byte) error {
+	if o.AppendFinishCalled != nil {
+		return o.AppendFinishCalled(data)
+	}
+	return nil
+}
+
 // GetVMOutput mocked method
 func (o *OutputContextStub) GetVMOutput() *vmcommon.VMOutput {
 	if o.GetVMOutputCalled != nil {
@@ -4,6 +4,7 @@ import (
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
 )
 
 var _ arwen.RuntimeContext = (*RuntimeContextMock)(nil)
@@ -111,6 +112,23 @@ func (r *RuntimeContextMock) GetInstanceStackSize() uint64 {
 func (r *RuntimeContextMock) SetMaxInstanceStackSize(uint64) {
 }
 
+// SetDeterminismChecks mocked method
+func (r *RuntimeContextMock) SetDeterminismChecks(_ arwen.DeterminismChecks) {
+}
+
+// SetMeteringExemptContracts mocked method
+func (r *RuntimeContextMock) SetMeteringExemptContracts(_ [][]byte) {
+}
+
+// SetEnableEpochsHandler mocked method
+func (r *RuntimeContextMock) SetEnableEpochsHandler(_ arwen.EnableEpochsHandler) {
+}
+
+// MeteringMode mocked method
+func (r *RuntimeContextMock) MeteringMode() executor.MeteringMode {
+	return executor.MeteringEnabled
+}
+
 // ClearInstanceStack mocked method
 func (r *RuntimeContextMock) ClearInstanceStack() {
 }
@@ -145,6 +163,11 @@ func (r *RuntimeContextMock) SetCodeAddress(scAddress []byte) {
 	r.SCAddress = scAddress
 }
 
+// GetCodeAddress mocked method
+func (r *RuntimeContextMock) GetCodeAddress() []byte {
+	return r.SCAddress
+}
+
 // GetSCCode mocked method
 func (r *RuntimeContextMock) GetSCCode() ([]byte, error) {
 	return r.SCCode, r.Err
@@ -175,6 +198,11 @@ func (r *RuntimeContextMock) GetOriginalTxHash() []byte {
 	return r.OriginalTxHash
 }
 
+// GetOriginalCallerAddress mocked method
+func (r *RuntimeContextMock) GetOriginalCallerAddress() []byte {
+	return r.VMInput.CallerAddr
+}
+
 // ExtractCodeUpgradeFromArgs mocked method
 func (r *RuntimeContextMock) ExtractCodeUpgradeFromArgs() ([]byte, []byte, error) {
 	arguments := r.VMInput.Arguments
@@ -241,6 +269,11 @@ func (r *RuntimeContextMock) GetInstance() executor.Instance {
 func (r *RuntimeContextMock) ClearWarmInstanceCache() {
 }
 
+// InfrastructureError mocked method
+func (r *RuntimeContextMock) InfrastructureError() error {
+	return nil
+}
+
 // FunctionNameChecked mocked method
 func (r *RuntimeContextMock) FunctionNameChecked() (string, error) {
 	if r.Err != nil {
@@ -325,6 +358,11 @@ func (r *RuntimeContextMock) IsFunctionImported(_ string) bool {
 	return true
 }
 
+// EndpointCallability mocked method
+func (r *RuntimeContextMock) EndpointCallability(_ string) (inspect.Callability, bool) {
+	return inspect.CallbackOnly, false
+}
+
 // AddError mocked method
 func (r *RuntimeContextMock) AddError(_ error, _ ...string) {
 }
@@ -353,6 +391,16 @@ func (r *RuntimeContextMock) NumRunningInstances() (int, int) {
 	return 0, 0
 }
 
+// WarmUpContract mocked method
+func (r *RuntimeContextMock) WarmUpContract(_ []byte) (arwen.InstanceSource, []byte, error) {
+	return arwen.InstanceFromWarmCache, nil, nil
+}
+
+// CacheStatistics mocked method
+func (r *RuntimeContextMock) CacheStatistics() arwen.CacheStatistics {
+	return arwen.CacheStatistics{}
+}
+
 // CleanInstance mocked method
 func (r *RuntimeContextMock) CleanInstance() {
 }
@@ -4,6 +4,7 @@ import (
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/ElrondNetwork/wasm-vm/inspect"
 )
 
 // making sure we implement all functions of RuntimeContext
@@ -26,6 +27,8 @@ type RuntimeContextWrapper struct {
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	SetCodeAddressFunc func(scAddress []byte)
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	GetCodeAddressFunc func() []byte
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	GetSCCodeFunc func() ([]byte, error)
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	GetSCCodeSizeFunc func() uint64
@@ -40,6 +43,8 @@ type RuntimeContextWrapper struct {
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	GetOriginalTxHashFunc func() []byte
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	GetOriginalCallerAddressFunc func() []byte
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	ExtractCodeUpgradeFromArgsFunc func() ([]byte, []byte, error)
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	SignalUserErrorFunc func(message string)
@@ -66,6 +71,8 @@ type RuntimeContextWrapper struct {
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	IsFunctionImportedFunc func(name string) bool
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	EndpointCallabilityFunc func(functionName string) (inspect.Callability, bool)
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	ReadOnlyFunc func() bool
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	SetReadOnlyFunc func(readOnly bool)
@@ -74,8 +81,18 @@ type RuntimeContextWrapper struct {
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	ClearWarmInstanceCacheFunc func()
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	InfrastructureErrorFunc func() error
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	SetMaxInstanceStackSizeFunc func(maxInstanceStackSize uint64)
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	SetDeterminismChecksFunc func(checks arwen.DeterminismChecks)
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	SetMeteringExemptContractsFunc func(addresses [][]byte)
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	SetEnableEpochsHandlerFunc func(enableEpochsHandler arwen.EnableEpochsHandler)
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	MeteringModeFunc func() executor.MeteringMode
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	VerifyContractCodeFunc func() error
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	GetInstanceFunc func() executor.Instance
@@ -125,6 +142,10 @@ type RuntimeContextWrapper struct {
 	CleanInstanceFunc func()
 	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
 	NumRunningInstancesFunc func() (int, int)
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	WarmUpContractFunc func(address []byte) (arwen.InstanceSource, []byte, error)
+	// function that will be called by the corresponding RuntimeContext function implementation (by default this will call the same wrapped context function)
+	CacheStatisticsFunc func() arwen.CacheStatistics
 }
 
 // NewRuntimeContextWrapper builds a new runtimeContextWrapper that by default will delagate all calls to the provided RuntimeContext
@@ -160,6 +181,10 @@ func NewRuntimeContextWrapper(inputRuntimeContext *arwen.RuntimeContext) *Runtim
 		runtimeWrapper.runtimeContext.SetCodeAddress(scAddress)
 	}
 
+	runtimeWrapper.GetCodeAddressFunc = func() []byte {
+		return runtimeWrapper.runtimeContext.GetCodeAddress()
+	}
+
 	runtimeWrapper.GetSCCodeFunc = func() ([]byte, error) {
 		return runtimeWrapper.runtimeContext.GetSCCode()
 	}
@@ -188,6 +213,10 @@ func NewRuntimeContextWrapper(inputRuntimeContext *arwen.RuntimeContext) *Runtim
 		return runtimeWrapper.runtimeContext.GetOriginalTxHash()
 	}
 
+	runtimeWrapper.GetOriginalCallerAddressFunc = func() []byte {
+		return runtimeWrapper.runtimeContext.GetOriginalCallerAddress()
+	}
+
 	runtimeWrapper.ExtractCodeUpgradeFromArgsFunc = func() ([]byte, []byte, error) {
 		return runtimeWrapper.runtimeContext.ExtractCodeUpgradeFromArgs()
 	}
@@ -220,6 +249,10 @@ func NewRuntimeContextWrapper(inputRuntimeContext *arwen.RuntimeContext) *Runtim
 		return runtimeWrapper.runtimeContext.IsFunctionImported(name)
 	}
 
+	runtimeWrapper.EndpointCallabilityFunc = func(functionName string) (inspect.Callability, bool) {
+		return runtimeWrapper.runtimeContext.EndpointCallability(functionName)
+	}
+
 	runtimeWrapper.ReadOnlyFunc = func() bool {
 		return runtimeWrapper.runtimeContext.ReadOnly()
 	}
@@ -236,10 +269,30 @@ func NewRuntimeContextWrapper(inputRuntimeContext *arwen.RuntimeContext) *Runtim
 		runtimeWrapper.runtimeContext.ClearWarmInstanceCache()
 	}
 
+	runtimeWrapper.InfrastructureErrorFunc = func() error {
+		return runtimeWrapper.runtimeContext.InfrastructureError()
+	}
+
 	runtimeWrapper.SetMaxInstanceStackSizeFunc = func(maxInstanceStackSize uint64) {
 		runtimeWrapper.runtimeContext.SetMaxInstanceStackSize(maxInstanceStackSize)
 	}
 
+	runtimeWrapper.SetDeterminismChecksFunc = func(checks arwen.DeterminismChecks) {
+		runtimeWrapper.runtimeContext.SetDeterminismChecks(checks)
+	}
+
+	runtimeWrapper.SetMeteringExemptContractsFunc = func(addresses [][]byte) {
+		runtimeWrapper.runtimeContext.SetMeteringExemptContracts(addresses)
+	}
+
+	runtimeWrapper.SetEnableEpochsHandlerFunc = func(enableEpochsHandler arwen.EnableEpochsHandler) {
+		runtimeWrapper.runtimeContext.SetEnableEpochsHandler(enableEpochsHandler)
+	}
+
+	runtimeWrapper.MeteringModeFunc = func() executor.MeteringMode {
+		return runtimeWrapper.runtimeContext.MeteringMode()
+	}
+
 	runtimeWrapper.VerifyContractCodeFunc = func() error {
 		return runtimeWrapper.runtimeContext.VerifyContractCode()
 	}
@@ -336,6 +389,14 @@ func NewRuntimeContextWrapper(inputRuntimeContext *arwen.RuntimeContext) *Runtim
 		return runtimeWrapper.runtimeContext.NumRunningInstances()
 	}
 
+	runtimeWrapper.WarmUpContractFunc = func(address []byte) (arwen.InstanceSource, []byte, error) {
+		return runtimeWrapper.runtimeContext.WarmUpContract(address)
+	}
+
+	runtimeWrapper.CacheStatisticsFunc = func() arwen.CacheStatistics {
+		return runtimeWrapper.runtimeContext.CacheStatistics()
+	}
+
 	return runtimeWrapper
 }
 
@@ -374,6 +435,11 @@ func (contextWrapper *RuntimeContextWrapper) SetCodeAddress(scAddress []byte) {
 	contextWrapper.SetCodeAddressFunc(scAddress)
 }
 
+// GetCodeAddress calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) GetCodeAddress() []byte {
+	return contextWrapper.GetCodeAddressFunc()
+}
+
 // GetSCCode calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
 func (contextWrapper *RuntimeContextWrapper) GetSCCode() ([]byte, error) {
 	return contextWrapper.GetSCCodeFunc()
@@ -409,6 +475,11 @@ func (contextWrapper *RuntimeContextWrapper) GetOriginalTxHash() []byte {
 	return contextWrapper.GetOriginalTxHashFunc()
 }
 
+// GetOriginalCallerAddress calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) GetOriginalCallerAddress() []byte {
+	return contextWrapper.GetOriginalCallerAddressFunc()
+}
+
 // ExtractCodeUpgradeFromArgs calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
 func (contextWrapper *RuntimeContextWrapper) ExtractCodeUpgradeFromArgs() ([]byte, []byte, error) {
 	return contextWrapper.ExtractCodeUpgradeFromArgsFunc()
@@ -454,6 +525,11 @@ func (contextWrapper *RuntimeContextWrapper) IsFunctionImported(name string) boo
 	return contextWrapper.IsFunctionImportedFunc(name)
 }
 
+// EndpointCallability calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) EndpointCallability(functionName string) (inspect.Callability, bool) {
+	return contextWrapper.EndpointCallabilityFunc(functionName)
+}
+
 // ReadOnly calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
 func (contextWrapper *RuntimeContextWrapper) ReadOnly() bool {
 	return contextWrapper.ReadOnlyFunc()
@@ -474,11 +550,36 @@ func (contextWrapper *RuntimeContextWrapper) ClearWarmInstanceCache() {
 	contextWrapper.ClearWarmInstanceCacheFunc()
 }
 
+// InfrastructureError calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) InfrastructureError() error {
+	return contextWrapper.InfrastructureErrorFunc()
+}
+
 // SetMaxInstanceStackSize calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
 func (contextWrapper *RuntimeContextWrapper) SetMaxInstanceStackSize(maxInstanceStackSize uint64) {
 	contextWrapper.SetMaxInstanceStackSizeFunc(maxInstanceStackSize)
 }
 
+// SetDeterminismChecks calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) SetDeterminismChecks(checks arwen.DeterminismChecks) {
+	contextWrapper.SetDeterminismChecksFunc(checks)
+}
+
+// SetMeteringExemptContracts calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) SetMeteringExemptContracts(addresses [][]byte) {
+	contextWrapper.SetMeteringExemptContractsFunc(addresses)
+}
+
+// SetEnableEpochsHandler calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) SetEnableEpochsHandler(enableEpochsHandler arwen.EnableEpochsHandler) {
+	contextWrapper.SetEnableEpochsHandlerFunc(enableEpochsHandler)
+}
+
+// MeteringMode calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) MeteringMode() executor.MeteringMode {
+	return contextWrapper.MeteringModeFunc()
+}
+
 // VerifyContractCode calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
 func (contextWrapper *RuntimeContextWrapper) VerifyContractCode() error {
 	return contextWrapper.VerifyContractCodeFunc()
@@ -618,3 +719,13 @@ func (contextWrapper *RuntimeContextWrapper) CleanInstance() {
 func (contextWrapper *RuntimeContextWrapper) NumRunningInstances() (int, int) {
 	return contextWrapper.NumRunningInstancesFunc()
 }
+
+// WarmUpContract calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) WarmUpContract(address []byte) (arwen.InstanceSource, []byte, error) {
+	return contextWrapper.WarmUpContractFunc(address)
+}
+
+// CacheStatistics calls corresponding xxxFunc function, that by default in turn calls the original method of the wrapped RuntimeContext
+func (contextWrapper *RuntimeContextWrapper) CacheStatistics() arwen.CacheStatistics {
+	return contextWrapper.CacheStatisticsFunc()
+}
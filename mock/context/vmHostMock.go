@@ -6,6 +6,9 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/config"
 	"github.com/ElrondNetwork/wasm-vm/crypto"
+	"github.com/ElrondNetwork/wasm-vm/metrics"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/ElrondNetwork/wasm-vm/tracing"
 )
 
 var _ arwen.VMHost = (*VMHostMock)(nil)
@@ -23,8 +26,14 @@ type VMHostMock struct {
 	OutputContext            arwen.OutputContext
 	MeteringContext          arwen.MeteringContext
 	StorageContext           arwen.StorageContext
-	EnableEpochsHandlerField vmcommon.EnableEpochsHandler
+	EnableEpochsHandlerField arwen.EnableEpochsHandler
+	VMVersionField           arwen.VMVersion
 	ManagedTypesContext      arwen.ManagedTypesContext
+	MetricsSink              arwen.MetricsSink
+	GasValueConverterField   arwen.GasValueConverter
+	ExecutionTracerField     arwen.ExecutionTracer
+	DebugModeField           bool
+	ExecutionPausedField     bool
 
 	IsBuiltinFunc bool
 
@@ -70,11 +79,87 @@ func (host *VMHostMock) Storage() arwen.StorageContext {
 	return host.StorageContext
 }
 
-// EnableEpochsHandler mocked method
-func (host *VMHostMock) EnableEpochsHandler() vmcommon.EnableEpochsHandler {
+// EnableEpochsHandler mocked method; without a handler, the features of the
+// VM are active from genesis
+func (host *VMHostMock) EnableEpochsHandler() arwen.EnableEpochsHandler {
+	if host.EnableEpochsHandlerField == nil {
+		return worldmock.EnableEpochsHandlerStubNoFlags()
+	}
 	return host.EnableEpochsHandlerField
 }
 
+// VMVersion mocked method
+func (host *VMHostMock) VMVersion() arwen.VMVersion {
+	if host.VMVersionField != nil {
+		return host.VMVersionField
+	}
+	return arwen.NewCurrentVMVersion(host.EnableEpochsHandler())
+}
+
+// Metrics mocked method
+func (host *VMHostMock) Metrics() arwen.MetricsSink {
+	if host.MetricsSink != nil {
+		return host.MetricsSink
+	}
+	return metrics.NewDisabledSink()
+}
+
+// GasValueConverter mocked method
+func (host *VMHostMock) GasValueConverter() arwen.GasValueConverter {
+	if host.GasValueConverterField != nil {
+		return host.GasValueConverterField
+	}
+	return &arwen.FullPriceGasValueConverter{}
+}
+
+// ExecutionTracer mocked method
+func (host *VMHostMock) ExecutionTracer() arwen.ExecutionTracer {
+	if host.ExecutionTracerField != nil {
+		return host.ExecutionTracerField
+	}
+	return tracing.NewDisabledTracer()
+}
+
+// IsDebugMode mocked method
+func (host *VMHostMock) IsDebugMode() bool {
+	return host.DebugModeField
+}
+
+// SetExecutionPaused mocked method
+func (host *VMHostMock) SetExecutionPaused(paused bool) {
+	host.ExecutionPausedField = paused
+}
+
+// IsExecutionPaused mocked method
+func (host *VMHostMock) IsExecutionPaused() bool {
+	return host.ExecutionPausedField
+}
+
+// WarmUp mocked method
+func (host *VMHostMock) WarmUp(_ [][]byte) []*arwen.WarmUpResult {
+	return nil
+}
+
+// CacheStatistics mocked method
+func (host *VMHostMock) CacheStatistics() arwen.CacheStatistics {
+	return arwen.CacheStatistics{}
+}
+
+// UpdateConfig mocked method
+func (host *VMHostMock) UpdateConfig(_ config.GasScheduleMap, _ vmcommon.EnableEpochsHandler) error {
+	return nil
+}
+
+// StorageAccesses mocked method
+func (host *VMHostMock) StorageAccesses() *arwen.StorageAccessSet {
+	return arwen.NewStorageAccessSet()
+}
+
+// HostFunctionCatalog mocked method
+func (host *VMHostMock) HostFunctionCatalog() []arwen.HostFunctionInfo {
+	return nil
+}
+
 // ManagedTypes mocked method
 func (host *VMHostMock) ManagedTypes() arwen.ManagedTypesContext {
 	return host.ManagedTypesContext
@@ -112,6 +197,11 @@ func (host *VMHostMock) CreateNewContract(_ *vmcommon.ContractCreateInput) ([]by
 	return nil, nil
 }
 
+// CreateNewDeterministicContract mocked method
+func (host *VMHostMock) CreateNewDeterministicContract(_ *vmcommon.ContractCreateInput, _ []byte) ([]byte, error) {
+	return nil, nil
+}
+
 // ExecuteOnSameContext mocked method
 func (host *VMHostMock) ExecuteOnSameContext(_ *vmcommon.ContractCallInput) error {
 	return nil
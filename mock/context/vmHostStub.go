@@ -6,6 +6,9 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/config"
 	"github.com/ElrondNetwork/wasm-vm/crypto"
+	"github.com/ElrondNetwork/wasm-vm/metrics"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/ElrondNetwork/wasm-vm/tracing"
 )
 
 var _ arwen.VMHost = (*VMHostStub)(nil)
@@ -25,17 +28,30 @@ type VMHostStub struct {
 	MeteringCalled            func() arwen.MeteringContext
 	AsyncCalled               func() arwen.AsyncContext
 	StorageCalled             func() arwen.StorageContext
-	EnableEpochsHandlerCalled func() vmcommon.EnableEpochsHandler
+	EnableEpochsHandlerCalled func() arwen.EnableEpochsHandler
+	VMVersionCalled           func() arwen.VMVersion
+	MetricsCalled             func() arwen.MetricsSink
+	GasValueConverterCalled   func() arwen.GasValueConverter
+	ExecutionTracerCalled     func() arwen.ExecutionTracer
+	IsDebugModeCalled         func() bool
+	SetExecutionPausedCalled  func(paused bool)
+	IsExecutionPausedCalled   func() bool
+	WarmUpCalled              func(addresses [][]byte) []*arwen.WarmUpResult
+	CacheStatisticsCalled     func() arwen.CacheStatistics
+	UpdateConfigCalled        func(gasSchedule config.GasScheduleMap, enableEpochsHandler vmcommon.EnableEpochsHandler) error
+	StorageAccessesCalled     func() *arwen.StorageAccessSet
+	HostFunctionCatalogCalled func() []arwen.HostFunctionInfo
 	GetContextsCalled         func() (arwen.ManagedTypesContext, arwen.BlockchainContext, arwen.MeteringContext, arwen.OutputContext, arwen.RuntimeContext, arwen.AsyncContext, arwen.StorageContext)
 	ManagedTypesCalled        func() arwen.ManagedTypesContext
 
-	ExecuteESDTTransferCalled   func(destination []byte, sender []byte, transfers []*vmcommon.ESDTTransfer, callType vm.CallType) (*vmcommon.VMOutput, uint64, error)
-	CreateNewContractCalled     func(input *vmcommon.ContractCreateInput) ([]byte, error)
-	ExecuteOnSameContextCalled  func(input *vmcommon.ContractCallInput) error
-	ExecuteOnDestContextCalled  func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, bool, error)
-	IsBuiltinFunctionNameCalled func(functionName string) bool
-	IsBuiltinFunctionCallCalled func(data []byte) bool
-	AreInSameShardCalled        func(left []byte, right []byte) bool
+	ExecuteESDTTransferCalled            func(destination []byte, sender []byte, transfers []*vmcommon.ESDTTransfer, callType vm.CallType) (*vmcommon.VMOutput, uint64, error)
+	CreateNewContractCalled              func(input *vmcommon.ContractCreateInput) ([]byte, error)
+	CreateNewDeterministicContractCalled func(input *vmcommon.ContractCreateInput, salt []byte) ([]byte, error)
+	ExecuteOnSameContextCalled           func(input *vmcommon.ContractCallInput) error
+	ExecuteOnDestContextCalled           func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, bool, error)
+	IsBuiltinFunctionNameCalled          func(functionName string) bool
+	IsBuiltinFunctionCallCalled          func(data []byte) bool
+	AreInSameShardCalled                 func(left []byte, right []byte) bool
 
 	RunSmartContractCallCalled   func(input *vmcommon.ContractCallInput) (vmOutput *vmcommon.VMOutput, err error)
 	RunSmartContractCreateCalled func(input *vmcommon.ContractCreateInput) (vmOutput *vmcommon.VMOutput, err error)
@@ -158,11 +174,107 @@ func (vhs *VMHostStub) Storage() arwen.StorageContext {
 	return nil
 }
 
-// EnableEpochsHandler mocked method
-func (vhs *VMHostStub) EnableEpochsHandler() vmcommon.EnableEpochsHandler {
+// EnableEpochsHandler mocked method; without a handler, the features of the
+// VM are active from genesis
+func (vhs *VMHostStub) EnableEpochsHandler() arwen.EnableEpochsHandler {
 	if vhs.EnableEpochsHandlerCalled != nil {
 		return vhs.EnableEpochsHandlerCalled()
 	}
+	return worldmock.EnableEpochsHandlerStubNoFlags()
+}
+
+// VMVersion mocked method
+func (vhs *VMHostStub) VMVersion() arwen.VMVersion {
+	if vhs.VMVersionCalled != nil {
+		return vhs.VMVersionCalled()
+	}
+	return arwen.NewCurrentVMVersion(vhs.EnableEpochsHandler())
+}
+
+// Metrics mocked method
+func (vhs *VMHostStub) Metrics() arwen.MetricsSink {
+	if vhs.MetricsCalled != nil {
+		return vhs.MetricsCalled()
+	}
+	return metrics.NewDisabledSink()
+}
+
+// GasValueConverter mocked method
+func (vhs *VMHostStub) GasValueConverter() arwen.GasValueConverter {
+	if vhs.GasValueConverterCalled != nil {
+		return vhs.GasValueConverterCalled()
+	}
+	return &arwen.FullPriceGasValueConverter{}
+}
+
+// ExecutionTracer mocked method
+func (vhs *VMHostStub) ExecutionTracer() arwen.ExecutionTracer {
+	if vhs.ExecutionTracerCalled != nil {
+		return vhs.ExecutionTracerCalled()
+	}
+	return tracing.NewDisabledTracer()
+}
+
+// IsDebugMode mocked method
+func (vhs *VMHostStub) IsDebugMode() bool {
+	if vhs.IsDebugModeCalled != nil {
+		return vhs.IsDebugModeCalled()
+	}
+	return false
+}
+
+// SetExecutionPaused mocked method
+func (vhs *VMHostStub) SetExecutionPaused(paused bool) {
+	if vhs.SetExecutionPausedCalled != nil {
+		vhs.SetExecutionPausedCalled(paused)
+	}
+}
+
+// IsExecutionPaused mocked method
+func (vhs *VMHostStub) IsExecutionPaused() bool {
+	if vhs.IsExecutionPausedCalled != nil {
+		return vhs.IsExecutionPausedCalled()
+	}
+	return false
+}
+
+// WarmUp mocked method
+func (vhs *VMHostStub) WarmUp(addresses [][]byte) []*arwen.WarmUpResult {
+	if vhs.WarmUpCalled != nil {
+		return vhs.WarmUpCalled(addresses)
+	}
+	return nil
+}
+
+// CacheStatistics mocked method
+func (vhs *VMHostStub) CacheStatistics() arwen.CacheStatistics {
+	if vhs.CacheStatisticsCalled != nil {
+		return vhs.CacheStatisticsCalled()
+	}
+	return arwen.CacheStatistics{}
+}
+
+// UpdateConfig mocked method
+func (vhs *VMHostStub) UpdateConfig(gasSchedule config.GasScheduleMap, enableEpochsHandler vmcommon.EnableEpochsHandler) error {
+	if vhs.UpdateConfigCalled != nil {
+		return vhs.UpdateConfigCalled(gasSchedule, enableEpochsHandler)
+	}
+	return nil
+}
+
+// StorageAccesses mocked method
+func (vhs *VMHostStub) StorageAccesses() *arwen.StorageAccessSet {
+	if vhs.StorageAccessesCalled != nil {
+		return vhs.StorageAccessesCalled()
+	}
+	return arwen.NewStorageAccessSet()
+}
+
+// HostFunctionCatalog mocked method
+func (vhs *VMHostStub) HostFunctionCatalog() []arwen.HostFunctionInfo {
+	if vhs.HostFunctionCatalogCalled != nil {
+		return vhs.HostFunctionCatalogCalled()
+	}
 	return nil
 }
 
@@ -190,6 +302,14 @@ func (vhs *VMHostStub) CreateNewContract(input *vmcommon.ContractCreateInput) ([
 	return nil, nil
 }
 
+// CreateNewDeterministicContract mocked method
+func (vhs *VMHostStub) CreateNewDeterministicContract(input *vmcommon.ContractCreateInput, salt []byte) ([]byte, error) {
+	if vhs.CreateNewDeterministicContractCalled != nil {
+		return vhs.CreateNewDeterministicContractCalled(input, salt)
+	}
+	return nil, nil
+}
+
 // ExecuteOnSameContext mocked method
 func (vhs *VMHostStub) ExecuteOnSameContext(input *vmcommon.ContractCallInput) error {
 	if vhs.ExecuteOnSameContextCalled != nil {
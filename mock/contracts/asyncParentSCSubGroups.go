@@ -0,0 +1,133 @@
+package contracts
+
+import (
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-vm-common/txDataBuilder"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+// AsyncWorkflowGroupID is the group aggregating the sub-groups of AsyncSubGroupsConfig
+const AsyncWorkflowGroupID = "workflow"
+
+// AsyncGroupErrorSuffix ends the names of the error callbacks of the groups
+const AsyncGroupErrorSuffix = "_error"
+
+// AsyncSubGroupsConfig defines the sub-groups and async calls generated by the mock smart contract method below
+var AsyncSubGroupsConfig = [][]string{
+	{"vendors", "payVendorA", "payVendorB", "payVendorC"},
+	{"auditors", "notifyAuditorA", "notifyAuditorB"},
+}
+
+// ForwardAsyncCallSubGroupsMock is an exposed mock contract method; the
+// optional second argument names an async call to be failed by the child
+func ForwardAsyncCallSubGroupsMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod("forwardSubGroupsAsyncCall", func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		t := instance.T
+		arguments := host.Runtime().Arguments()
+		destination := arguments[0]
+		failingFunction := ""
+		if len(arguments) > 1 {
+			failingFunction = string(arguments[1])
+		}
+		value := big.NewInt(testConfig.TransferFromParentToChild).Bytes()
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByParent)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		async := host.Async()
+		for _, groupConfig := range AsyncSubGroupsConfig {
+			groupName := groupConfig[0]
+			err = async.AddSubGroup(AsyncWorkflowGroupID, groupName)
+			require.Nil(t, err)
+
+			err = async.SetGroupCallback(
+				groupName,
+				test.TestCallbackPrefix+groupName,
+				test.TestCallbackPrefix+groupName+AsyncGroupErrorSuffix,
+				nil,
+				testConfig.GasProvidedToCallback)
+			require.Nil(t, err)
+
+			for g := 1; g < len(groupConfig); g++ {
+				callData := txDataBuilder.NewBuilder()
+				functionName := groupConfig[g]
+				callData.Func(functionName)
+				callData.Bool(functionName == failingFunction)
+
+				err = async.RegisterAsyncCall(groupName, &arwen.AsyncCall{
+					Status:      arwen.AsyncCallPending,
+					Destination: destination,
+					Data:        callData.ToBytes(),
+					ValueBytes:  value,
+					GasLimit:    testConfig.GasProvidedToChild,
+				})
+				require.Nil(t, err)
+			}
+		}
+
+		err = async.SetGroupCallback(
+			AsyncWorkflowGroupID,
+			test.TestCallbackPrefix+AsyncWorkflowGroupID,
+			test.TestCallbackPrefix+AsyncWorkflowGroupID+AsyncGroupErrorSuffix,
+			nil,
+			testConfig.GasProvidedToCallback)
+		require.Nil(t, err)
+
+		return instance
+	})
+}
+
+// CallBackSubGroupsMock is an exposed mock contract method
+func CallBackSubGroupsMock(instanceMock *mock.InstanceMock, config interface{}) {
+	testConfig := config.(*test.TestConfig)
+	groupNames := []string{AsyncWorkflowGroupID}
+	for _, groupConfig := range AsyncSubGroupsConfig {
+		groupNames = append(groupNames, groupConfig[0])
+	}
+
+	for _, groupName := range groupNames {
+		for _, callbackName := range []string{
+			test.TestCallbackPrefix + groupName,
+			test.TestCallbackPrefix + groupName + AsyncGroupErrorSuffix,
+		} {
+			instanceMock.AddMockMethod(callbackName,
+				test.WasteGasWithReturnDataMockMethod(
+					instanceMock,
+					testConfig.GasUsedByCallback,
+					[]byte(callbackName+test.TestReturnDataSuffix)))
+		}
+	}
+}
+
+// ChildAsyncSubGroupsMock is an exposed mock contract method
+func ChildAsyncSubGroupsMock(instanceMock *mock.InstanceMock, config interface{}) {
+	testConfig := config.(*test.TestConfig)
+	for _, groupConfig := range AsyncSubGroupsConfig {
+		for g := 1; g < len(groupConfig); g++ {
+			functionName := groupConfig[g]
+			wasteGas := test.WasteGasWithReturnDataMockMethod(
+				instanceMock,
+				testConfig.GasUsedByChild,
+				[]byte(functionName+test.TestReturnDataSuffix))
+			instanceMock.AddMockMethod(functionName, func() *mock.InstanceMock {
+				host := instanceMock.Host
+				arguments := host.Runtime().Arguments()
+				if len(arguments) > 0 && big.NewInt(0).SetBytes(arguments[0]).Sign() != 0 {
+					host.Runtime().SignalUserError(functionName + " failed")
+					return mock.GetMockInstance(host)
+				}
+				return wasteGas()
+			})
+		}
+	}
+}
@@ -0,0 +1,50 @@
+package contracts
+
+import (
+	"fmt"
+	"math/big"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+// AsyncStressFunction is the function called by the async calls created by the fanOutAsyncCalls mock method
+const AsyncStressFunction = "asyncStressFunction"
+
+// FanOutAsyncCallsMock is an exposed mock contract method, which registers as
+// many groups of async calls to the child as requested by its arguments
+func FanOutAsyncCallsMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod("fanOutAsyncCalls", func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		arguments := host.Runtime().Arguments()
+		if len(arguments) != 2 {
+			host.Runtime().SignalUserError("needs 2 arguments")
+			return instance
+		}
+
+		numGroups := big.NewInt(0).SetBytes(arguments[0]).Int64()
+		callsPerGroup := big.NewInt(0).SetBytes(arguments[1]).Int64()
+		for g := int64(0); g < numGroups; g++ {
+			groupID := fmt.Sprintf("stressGroup%d", g)
+			for c := int64(0); c < callsPerGroup; c++ {
+				err := host.Async().RegisterAsyncCall(groupID, &arwen.AsyncCall{
+					Status:      arwen.AsyncCallPending,
+					Destination: test.ChildAddress,
+					Data:        []byte(AsyncStressFunction),
+					ValueBytes:  big.NewInt(0).Bytes(),
+					GasLimit:    testConfig.GasProvidedToChild,
+				})
+				if err != nil {
+					host.Runtime().FailExecution(err)
+					return instance
+				}
+			}
+		}
+
+		return instance
+	})
+}
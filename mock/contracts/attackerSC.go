@@ -0,0 +1,219 @@
+package contracts
+
+import (
+	"encoding/binary"
+	"math/big"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+// AttackerAttackFunction starts the reentrancy attack against the victim
+var AttackerAttackFunction = "attack"
+
+// AttackerReceiveFunction is called by the victim when paying the attacker, which re-enters the victim
+var AttackerReceiveFunction = "receiveFunds"
+
+// VictimWithdrawFunction is the endpoint of the victim paying its caller before any bookkeeping
+var VictimWithdrawFunction = "withdraw"
+
+// VictimWithdrawValue is the EGLD paid by the victim on each withdrawal
+var VictimWithdrawValue = int64(10)
+
+// AttackerLoopFunction is the endpoint looping until it runs out of gas
+var AttackerLoopFunction = "loopForever"
+
+// AttackerGriefFunction is the endpoint calling asynchronously the looping endpoint of the child
+var AttackerGriefFunction = "griefChild"
+
+// AttackerGriefedCallback is the callback of the async call to the looping endpoint
+var AttackerGriefedCallback = "griefedCallBack"
+
+// AttackerGriefedCallbackData is finished by the callback of the async call to the looping endpoint
+var AttackerGriefedCallbackData = []byte("callback executed")
+
+// AttackerStorageBombFunction is the endpoint writing new storage keys until it runs out of gas
+var AttackerStorageBombFunction = "storageBomb"
+
+// AttackerHugeFinishFunction is the endpoint finishing data until it exceeds the return data limits
+var AttackerHugeFinishFunction = "hugeFinish"
+
+// AttackerFinishChunk is the data finished repeatedly by the huge finish endpoint
+var AttackerFinishChunk = make([]byte, 1024)
+
+// AttackerStorageBombKey is the storage key written by the storage bomb on the given iteration
+func AttackerStorageBombKey(index uint64) []byte {
+	key := make([]byte, 32)
+	copy(key, "bomb")
+	binary.BigEndian.PutUint64(key[24:], index)
+	return key
+}
+
+// ReentrancyAttackerMock is an exposed mock contract method; the attacker
+// withdraws from the victim, and withdraws again each time it gets paid,
+// re-entering the victim before its withdrawal completes
+func ReentrancyAttackerMock(instanceMock *mock.InstanceMock, config interface{}) {
+	withdrawFromVictim := func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByParent)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		_ = elrondapi.ExecuteOnDestContextWithTypedArgs(
+			host,
+			int64(host.Metering().GasLeft()),
+			big.NewInt(0),
+			[]byte(VictimWithdrawFunction),
+			testConfig.GetChildAddress(),
+			nil)
+
+		return instance
+	}
+
+	instanceMock.AddMockMethod(AttackerAttackFunction, withdrawFromVictim)
+	instanceMock.AddMockMethod(AttackerReceiveFunction, withdrawFromVictim)
+}
+
+// ReentrancyVictimMock is an exposed mock contract method; the victim pays
+// its caller by calling it, which lets the caller re-enter the victim
+func ReentrancyVictimMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(VictimWithdrawFunction, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByChild)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		_ = elrondapi.ExecuteOnDestContextWithTypedArgs(
+			host,
+			int64(host.Metering().GasLeft()),
+			big.NewInt(VictimWithdrawValue),
+			[]byte(AttackerReceiveFunction),
+			host.Runtime().GetVMInput().CallerAddr,
+			nil)
+
+		return instance
+	})
+}
+
+// InfiniteLoopMock is an exposed mock contract method; it never returns on
+// its own, spending gas on each iteration like the opcodes of a wasm loop
+func InfiniteLoopMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(AttackerLoopFunction, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		for useLoopIterationGas(host) {
+		}
+
+		return instance
+	})
+}
+
+// GasGriefingParentMock is an exposed mock contract method; it calls the
+// looping endpoint of the child asynchronously, relying on the locked gas
+// to run its callback after the child spends all the gas given to it
+func GasGriefingParentMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(AttackerGriefFunction, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByParent)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		err = host.Async().RegisterAsyncCall("griefing", &arwen.AsyncCall{
+			Status:          arwen.AsyncCallPending,
+			Destination:     testConfig.GetChildAddress(),
+			Data:            []byte(AttackerLoopFunction),
+			ValueBytes:      nil,
+			SuccessCallback: AttackerGriefedCallback,
+			ErrorCallback:   AttackerGriefedCallback,
+			GasLimit:        testConfig.GasProvidedToChild,
+			GasLocked:       testConfig.GasToLock,
+		})
+		if err != nil {
+			host.Runtime().FailExecution(err)
+		}
+
+		return instance
+	})
+
+	instanceMock.AddMockMethod(AttackerGriefedCallback, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByCallback)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		host.Output().Finish(AttackerGriefedCallbackData)
+		return instance
+	})
+}
+
+// StorageBombMock is an exposed mock contract method; it writes new storage
+// keys until the storage costs exhaust its gas
+func StorageBombMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(AttackerStorageBombFunction, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		value := make([]byte, 32)
+		for index := uint64(0); useLoopIterationGas(host); index++ {
+			if elrondapi.StorageStoreWithTypedArgs(host, AttackerStorageBombKey(index), value) < 0 {
+				break
+			}
+		}
+
+		return instance
+	})
+}
+
+// HugeFinishMock is an exposed mock contract method; it finishes the same
+// chunk over and over, until it is stopped by the return data limits or by
+// the gas spent on persisting the finished bytes
+func HugeFinishMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(AttackerHugeFinishFunction, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		vmHooks := elrondapi.NewElrondApi(host)
+		handle := host.ManagedTypes().NewManagedBufferFromBytes(AttackerFinishChunk)
+		for useLoopIterationGas(host) {
+			if vmHooks.MBufferFinish(handle) != 0 {
+				break
+			}
+		}
+
+		return instance
+	})
+}
+
+// useLoopIterationGas charges a single unit of gas for an iteration of the
+// loops of the attacker contracts, returning false once the gas is exhausted
+func useLoopIterationGas(host arwen.VMHost) bool {
+	err := host.Metering().UseGasBounded(1)
+	if err != nil {
+		host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+		return false
+	}
+	return true
+}
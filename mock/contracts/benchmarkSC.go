@@ -0,0 +1,119 @@
+package contracts
+
+import (
+	"encoding/binary"
+	"math/big"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+// BenchmarkKey is the storage key read by the benchStorageLoad mock method
+var BenchmarkKey = []byte("benchmarkKey")
+
+// BenchmarkValue is the value written and hashed by the benchmark mock methods
+var BenchmarkValue = []byte("benchmarkValue")
+
+// BenchmarkAsyncFunction is the function called by the async calls created by the benchAsyncCall mock method
+const BenchmarkAsyncFunction = "benchmarkAsyncFunction"
+
+// benchmarkStep calls the measured host function once; the index counts the
+// calls already made in the current execution
+type benchmarkStep func(host arwen.VMHost, vmHooks *elrondapi.ElrondApi, index int64) int32
+
+// addBenchmarkMethod registers a mock method which calls the benchmark step
+// as many times as requested by its only argument, stopping at the first failure
+func addBenchmarkMethod(instanceMock *mock.InstanceMock, name string, step benchmarkStep) {
+	instanceMock.AddMockMethod(name, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		arguments := host.Runtime().Arguments()
+		if len(arguments) != 1 {
+			host.Runtime().SignalUserError("needs 1 argument")
+			return instance
+		}
+
+		vmHooks := elrondapi.NewElrondApi(host)
+		reps := big.NewInt(0).SetBytes(arguments[0]).Int64()
+		for i := int64(0); i < reps; i++ {
+			if step(host, vmHooks, i) != 0 {
+				return instance
+			}
+		}
+
+		return instance
+	})
+}
+
+func benchmarkIndexKey(index int64) []byte {
+	key := make([]byte, len(BenchmarkKey)+8)
+	copy(key, BenchmarkKey)
+	binary.BigEndian.PutUint64(key[len(BenchmarkKey):], uint64(index))
+	return key
+}
+
+// BenchStorageStore is an exposed mock contract method
+func BenchStorageStore(instanceMock *mock.InstanceMock, config interface{}) {
+	addBenchmarkMethod(instanceMock, "benchStorageStore", func(host arwen.VMHost, _ *elrondapi.ElrondApi, index int64) int32 {
+		if elrondapi.StorageStoreWithTypedArgs(host, benchmarkIndexKey(index), BenchmarkValue) < 0 {
+			return 1
+		}
+		return 0
+	})
+}
+
+// BenchStorageLoad is an exposed mock contract method
+func BenchStorageLoad(instanceMock *mock.InstanceMock, config interface{}) {
+	addBenchmarkMethod(instanceMock, "benchStorageLoad", func(host arwen.VMHost, _ *elrondapi.ElrondApi, _ int64) int32 {
+		_ = elrondapi.StorageLoadWithWithTypedArgs(host, BenchmarkKey)
+		return 0
+	})
+}
+
+// BenchFinish is an exposed mock contract method
+func BenchFinish(instanceMock *mock.InstanceMock, config interface{}) {
+	addBenchmarkMethod(instanceMock, "benchFinish", func(host arwen.VMHost, vmHooks *elrondapi.ElrondApi, _ int64) int32 {
+		handle := host.ManagedTypes().NewManagedBufferFromBytes(BenchmarkValue)
+		return vmHooks.MBufferFinish(handle)
+	})
+}
+
+// BenchBigIntOps is an exposed mock contract method
+func BenchBigIntOps(instanceMock *mock.InstanceMock, config interface{}) {
+	addBenchmarkMethod(instanceMock, "benchBigIntOps", func(host arwen.VMHost, vmHooks *elrondapi.ElrondApi, index int64) int32 {
+		managedTypes := host.ManagedTypes()
+		op1 := managedTypes.NewBigIntFromInt64(index)
+		op2 := managedTypes.NewBigIntFromInt64(index + 1)
+		vmHooks.BigIntAdd(op1, op1, op2)
+		vmHooks.BigIntMul(op2, op1, op2)
+		return 0
+	})
+}
+
+// BenchSha256 is an exposed mock contract method
+func BenchSha256(instanceMock *mock.InstanceMock, config interface{}) {
+	addBenchmarkMethod(instanceMock, "benchSha256", func(host arwen.VMHost, vmHooks *elrondapi.ElrondApi, _ int64) int32 {
+		managedTypes := host.ManagedTypes()
+		input := managedTypes.NewManagedBufferFromBytes(BenchmarkValue)
+		output := managedTypes.NewManagedBuffer()
+		return vmHooks.ManagedSha256(input, output)
+	})
+}
+
+// BenchAsyncCall is an exposed mock contract method
+func BenchAsyncCall(instanceMock *mock.InstanceMock, config interface{}) {
+	addBenchmarkMethod(instanceMock, "benchAsyncCall", func(host arwen.VMHost, _ *elrondapi.ElrondApi, _ int64) int32 {
+		return elrondapi.CreateAsyncCallWithTypedArgs(host,
+			test.ChildAddress,
+			big.NewInt(0).Bytes(),
+			[]byte(BenchmarkAsyncFunction),
+			nil,
+			nil,
+			0,
+			0,
+			nil)
+	})
+}
@@ -0,0 +1,102 @@
+package contracts
+
+import (
+	"math/big"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+// CallbackOutOfGasFunction is the endpoint calling the child asynchronously with an exhausting callback
+var CallbackOutOfGasFunction = "callChildWithExhaustingCallback"
+
+// CallbackOutOfGasChildFunction is the endpoint of the child called by CallbackOutOfGasFunction
+var CallbackOutOfGasChildFunction = "storeAndFinish"
+
+// CallbackOutOfGasCallback writes to storage, then runs out of gas
+var CallbackOutOfGasCallback = "exhaustingCallBack"
+
+// CallbackOutOfGasValue is the EGLD transferred to the child by the async call
+var CallbackOutOfGasValue = int64(7)
+
+// CallbackOutOfGasParentMock is an exposed mock contract method; the parent
+// writes to its storage and calls the child asynchronously, providing a
+// callback which writes to storage and then spends all its gas
+func CallbackOutOfGasParentMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(CallbackOutOfGasFunction, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByParent)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		_, err = host.Storage().SetStorage(test.ParentKeyA, test.ParentDataA)
+		if err != nil {
+			host.Runtime().FailExecution(err)
+			return instance
+		}
+
+		err = host.Async().RegisterAsyncCall("callbackOutOfGas", &arwen.AsyncCall{
+			Status:          arwen.AsyncCallPending,
+			Destination:     testConfig.GetChildAddress(),
+			Data:            []byte(CallbackOutOfGasChildFunction),
+			ValueBytes:      big.NewInt(CallbackOutOfGasValue).Bytes(),
+			SuccessCallback: CallbackOutOfGasCallback,
+			ErrorCallback:   CallbackOutOfGasCallback,
+			GasLimit:        testConfig.GasProvidedToChild,
+			GasLocked:       testConfig.GasToLock,
+		})
+		if err != nil {
+			host.Runtime().FailExecution(err)
+		}
+
+		return instance
+	})
+
+	instanceMock.AddMockMethod(CallbackOutOfGasCallback, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		_, err := host.Storage().SetStorage(test.CallbackKey, test.CallbackData)
+		if err != nil {
+			host.Runtime().FailExecution(err)
+			return instance
+		}
+		host.Output().Finish(test.CallbackData)
+
+		for useLoopIterationGas(host) {
+		}
+
+		return instance
+	})
+}
+
+// CallbackOutOfGasChildMock is an exposed mock contract method; the child
+// writes to its storage and finishes
+func CallbackOutOfGasChildMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(CallbackOutOfGasChildFunction, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByChild)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		_, err = host.Storage().SetStorage(test.ChildKey, test.ChildData)
+		if err != nil {
+			host.Runtime().FailExecution(err)
+			return instance
+		}
+		host.Output().Finish(test.ChildFinish)
+
+		return instance
+	})
+}
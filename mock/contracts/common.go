@@ -85,6 +85,11 @@ func ExecuteOnSameContextInMockContracts(host arwen.VMHost, input *vmcommon.Cont
 	return elrondapi.ExecuteOnSameContextWithTypedArgs(host, int64(input.GasProvided), input.CallValue, []byte(input.Function), input.RecipientAddr, input.Arguments)
 }
 
+// ExecuteReadOnlyInMockContracts - calls the corresponding method in elrond api
+func ExecuteReadOnlyInMockContracts(host arwen.VMHost, input *vmcommon.ContractCallInput) int32 {
+	return elrondapi.ExecuteReadOnlyWithTypedArguments(host, int64(input.GasProvided), []byte(input.Function), input.RecipientAddr, input.Arguments)
+}
+
 // ExecuteOnDestContextInMockContracts - calls the corresponding method in elrond api
 func ExecuteOnDestContextInMockContracts(host arwen.VMHost, input *vmcommon.ContractCallInput) int32 {
 	return elrondapi.ExecuteOnDestContextWithTypedArgs(host, int64(input.GasProvided), input.CallValue, []byte(input.Function), input.RecipientAddr, input.Arguments)
@@ -0,0 +1,287 @@
+package contracts
+
+import (
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+// CrowdfundingFundFunction is the payable endpoint receiving the deposits of the funders
+var CrowdfundingFundFunction = "fund"
+
+// CrowdfundingForwardFunction is the endpoint moving the deposit of a funder to the vault
+var CrowdfundingForwardFunction = "forwardToVault"
+
+// CrowdfundingForwardedCallback is called when the vault accepted a forwarded deposit
+var CrowdfundingForwardedCallback = "forwardedToVault"
+
+// CrowdfundingRefundCallback is called when the vault rejected a forwarded deposit
+var CrowdfundingRefundCallback = "refundFunder"
+
+// VaultDepositFunction is the endpoint of the vault accepting EGLD deposits
+var VaultDepositFunction = "deposit"
+
+// VaultBalanceKey holds the EGLD accepted by the vault
+var VaultBalanceKey = []byte("vaultBalance")
+
+// VaultCapacityKey holds the most EGLD the vault accepts, if set
+var VaultCapacityKey = []byte("vaultCapacity")
+
+// VaultClosedKey makes the vault reject all deposits, if set
+var VaultClosedKey = []byte("vaultClosed")
+
+// CrowdfundingRefundData is the data of the transfers refunding a funder
+var CrowdfundingRefundData = []byte("refund")
+
+// CrowdfundingDepositKey is the storage key of the EGLD deposited by a funder
+func CrowdfundingDepositKey(funder []byte) []byte {
+	return append([]byte("deposit"), funder...)
+}
+
+// CrowdfundingESDTDepositKey is the storage key of the tokens deposited by a funder
+func CrowdfundingESDTDepositKey(funder []byte, tokenName []byte) []byte {
+	key := append([]byte("esdtDeposit"), funder...)
+	return append(key, tokenName...)
+}
+
+// CrowdfundingForwardedKey is the storage key of the EGLD of a funder kept by the vault
+func CrowdfundingForwardedKey(funder []byte) []byte {
+	return append([]byte("forwarded"), funder...)
+}
+
+// CrowdfundingFundMock is an exposed mock contract method; it accepts both
+// EGLD and ESDT and records them per funder, but rejects calls without payment
+func CrowdfundingFundMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(CrowdfundingFundFunction, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByParent)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		vmInput := host.Runtime().GetVMInput()
+		if vmInput.CallValue.Sign() == 0 && len(vmInput.ESDTTransfers) == 0 {
+			host.Runtime().SignalUserError("no payment")
+			return instance
+		}
+
+		funder := vmInput.CallerAddr
+		if vmInput.CallValue.Sign() > 0 {
+			addToStoredValue(host, CrowdfundingDepositKey(funder), vmInput.CallValue)
+		}
+		for _, transfer := range vmInput.ESDTTransfers {
+			addToStoredValue(host, CrowdfundingESDTDepositKey(funder, transfer.ESDTTokenName), transfer.ESDTValue)
+		}
+
+		return instance
+	})
+}
+
+// CrowdfundingForwardToVaultMock is an exposed mock contract method; it is not
+// payable and moves the whole EGLD deposit of a funder to the vault through an
+// async call, keeping the funder and the deposit in the callback closure
+func CrowdfundingForwardToVaultMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(CrowdfundingForwardFunction, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByParent)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		if host.Runtime().GetVMInput().CallValue.Sign() > 0 {
+			host.Runtime().FailExecution(arwen.ErrNonPayableFunctionEgld)
+			return instance
+		}
+
+		arguments := host.Runtime().Arguments()
+		if len(arguments) != 1 || len(arguments[0]) != arwen.AddressLen {
+			host.Runtime().SignalUserError("wrong num of arguments")
+			return instance
+		}
+
+		funder := arguments[0]
+		deposit, _ := host.Storage().GetStorage(CrowdfundingDepositKey(funder))
+		if len(deposit) == 0 {
+			host.Runtime().SignalUserError("nothing to forward")
+			return instance
+		}
+
+		_, err = host.Storage().DeleteStorage(CrowdfundingDepositKey(funder))
+		if err != nil {
+			host.Runtime().FailExecution(err)
+			return instance
+		}
+
+		closure := append(append([]byte{}, funder...), deposit...)
+		err = host.Async().RegisterAsyncCall("crowdfunding", &arwen.AsyncCall{
+			Status:          arwen.AsyncCallPending,
+			Destination:     testConfig.GetVaultAddress(),
+			Data:            []byte(VaultDepositFunction),
+			ValueBytes:      deposit,
+			SuccessCallback: CrowdfundingForwardedCallback,
+			ErrorCallback:   CrowdfundingRefundCallback,
+			GasLimit:        testConfig.GasProvidedToChild,
+			GasLocked:       testConfig.GasToLock,
+			CallbackClosure: closure,
+		})
+		if err != nil {
+			host.Runtime().FailExecution(err)
+		}
+
+		return instance
+	})
+}
+
+// CrowdfundingCallbacksMock is an exposed mock contract method; the success
+// callback refunds to the funder the EGLD sent back by the vault, while the
+// error callback refunds the entire deposit
+func CrowdfundingCallbacksMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(CrowdfundingForwardedCallback, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		funder, deposit, ok := crowdfundingCallbackPrologue(host, config)
+		if !ok {
+			return instance
+		}
+
+		backTransfer := host.Runtime().GetVMInput().CallValue
+		forwarded := big.NewInt(0).Sub(deposit, backTransfer)
+		_, err := host.Storage().SetStorage(CrowdfundingForwardedKey(funder), forwarded.Bytes())
+		if err != nil {
+			host.Runtime().FailExecution(err)
+			return instance
+		}
+
+		if backTransfer.Sign() > 0 && !refundFunder(host, funder, backTransfer) {
+			return instance
+		}
+
+		host.Output().Finish([]byte("forwarded"))
+		return instance
+	})
+
+	instanceMock.AddMockMethod(CrowdfundingRefundCallback, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		funder, deposit, ok := crowdfundingCallbackPrologue(host, config)
+		if !ok {
+			return instance
+		}
+
+		if !refundFunder(host, funder, deposit) {
+			return instance
+		}
+
+		host.Output().Finish([]byte("refunded"))
+		return instance
+	})
+}
+
+// VaultDepositMock is an exposed mock contract method; it accepts EGLD up to
+// the capacity of the vault and sends the rest back to the caller, but rejects
+// ESDT and all deposits while the vault is closed
+func VaultDepositMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod(VaultDepositFunction, func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByChild)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		vmInput := host.Runtime().GetVMInput()
+		if len(vmInput.ESDTTransfers) > 0 {
+			host.Runtime().FailExecution(arwen.ErrNonPayableFunctionEsdt)
+			return instance
+		}
+
+		closed, _ := host.Storage().GetStorage(VaultClosedKey)
+		if len(closed) > 0 {
+			host.Runtime().SignalUserError("vault closed")
+			return instance
+		}
+
+		balanceBytes, _ := host.Storage().GetStorage(VaultBalanceKey)
+		balance := big.NewInt(0).SetBytes(balanceBytes)
+		accepted := big.NewInt(0).Set(vmInput.CallValue)
+		capacity, _ := host.Storage().GetStorage(VaultCapacityKey)
+		if len(capacity) > 0 {
+			room := big.NewInt(0).Sub(big.NewInt(0).SetBytes(capacity), balance)
+			if room.Sign() < 0 {
+				room.SetInt64(0)
+			}
+			if accepted.Cmp(room) > 0 {
+				accepted.Set(room)
+			}
+		}
+
+		_, err = host.Storage().SetStorage(VaultBalanceKey, balance.Add(balance, accepted).Bytes())
+		if err != nil {
+			host.Runtime().FailExecution(err)
+			return instance
+		}
+
+		excess := big.NewInt(0).Sub(vmInput.CallValue, accepted)
+		if excess.Sign() > 0 {
+			err = host.Output().Transfer(vmInput.CallerAddr, host.Runtime().GetContextAddress(), 0, 0, excess, nil, nil, vm.DirectCall)
+			if err != nil {
+				host.Runtime().SignalUserError(err.Error())
+			}
+		}
+
+		return instance
+	})
+}
+
+func crowdfundingCallbackPrologue(host arwen.VMHost, config interface{}) ([]byte, *big.Int, bool) {
+	testConfig := config.(*test.TestConfig)
+	err := host.Metering().UseGasBounded(testConfig.GasUsedByCallback)
+	if err != nil {
+		host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+		return nil, nil, false
+	}
+
+	closure, err := host.Async().GetCallbackClosure()
+	if err != nil || len(closure) < arwen.AddressLen {
+		host.Runtime().SignalUserError("can't get closure")
+		return nil, nil, false
+	}
+
+	funder := closure[:arwen.AddressLen]
+	deposit := big.NewInt(0).SetBytes(closure[arwen.AddressLen:])
+	return funder, deposit, true
+}
+
+func refundFunder(host arwen.VMHost, funder []byte, value *big.Int) bool {
+	err := host.Output().Transfer(funder, host.Runtime().GetContextAddress(), 0, 0, value, nil, CrowdfundingRefundData, vm.DirectCall)
+	if err != nil {
+		host.Runtime().SignalUserError(err.Error())
+		return false
+	}
+	return true
+}
+
+func addToStoredValue(host arwen.VMHost, key []byte, value *big.Int) {
+	storedBytes, _ := host.Storage().GetStorage(key)
+	stored := big.NewInt(0).SetBytes(storedBytes)
+	_, err := host.Storage().SetStorage(key, stored.Add(stored, value).Bytes())
+	if err != nil {
+		host.Runtime().FailExecution(err)
+	}
+}
@@ -0,0 +1,103 @@
+package contracts
+
+import (
+	"fmt"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+)
+
+// ReadOnlyViewResult is the data finished by the view method of ReadOnlyChildMock
+var ReadOnlyViewResult = []byte("view result")
+
+// ExecReadOnlyParentMock is an exposed mock contract method
+func ExecReadOnlyParentMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod("execReadOnly", func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		host.Metering().UseGas(testConfig.GasUsedByParent)
+
+		arguments := host.Runtime().Arguments()
+		if len(arguments) != 2 {
+			host.Runtime().SignalUserError("need 2 arguments")
+			return instance
+		}
+
+		input := test.DefaultTestContractCallInput()
+		input.GasProvided = testConfig.GasProvidedToChild
+		input.CallerAddr = instance.Address
+		input.RecipientAddr = arguments[0]
+		input.Function = string(arguments[1])
+
+		returnValue := ExecuteReadOnlyInMockContracts(host, input)
+		if returnValue != 0 {
+			host.Runtime().FailExecution(fmt.Errorf("Return value %d", returnValue))
+		}
+
+		return instance
+	})
+}
+
+// ReadOnlyChildMock is an exposed mock contract method; besides a view, it
+// has methods which attempt the state changes forbidden in read-only mode
+func ReadOnlyChildMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod("view", func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		host.Output().Finish(ReadOnlyViewResult)
+		return instance
+	})
+
+	instanceMock.AddMockMethod("write", func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		_, err := host.Storage().SetStorage(test.ChildKey, TestStorageValue1)
+		if err != nil {
+			host.Runtime().FailExecution(err)
+		}
+		return instance
+	})
+
+	instanceMock.AddMockMethod("transfer", func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		runtime := host.Runtime()
+		err := host.Output().Transfer(test.UserAddress, runtime.GetContextAddress(), 0, 0, big.NewInt(0), nil, []byte("call"), vm.DirectCall)
+		if err != nil {
+			runtime.FailExecution(err)
+		}
+		return instance
+	})
+
+	instanceMock.AddMockMethod("asyncCall", func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		err := host.Async().RegisterLegacyAsyncCall(test.UserAddress, []byte("call"), big.NewInt(0).Bytes())
+		if err != nil {
+			host.Runtime().FailExecution(err)
+		}
+		return instance
+	})
+
+	instanceMock.AddMockMethod("nestedWrite", func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		runtime := host.Runtime()
+
+		input := test.DefaultTestContractCallInput()
+		input.GasProvided = runtime.GetVMInput().GasProvided / 2
+		input.CallerAddr = runtime.GetContextAddress()
+		input.RecipientAddr = runtime.GetContextAddress()
+		input.Function = "write"
+
+		returnValue := ExecuteOnDestContextInMockContracts(host, input)
+		if returnValue != 0 {
+			runtime.FailExecution(arwen.ErrExecutionFailed)
+		}
+		return instance
+	})
+}
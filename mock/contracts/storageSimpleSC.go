@@ -1,6 +1,8 @@
 package contracts
 
 import (
+	"math/big"
+
 	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
 	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
 )
@@ -70,3 +72,23 @@ func SetStore(instanceMock *mock.InstanceMock, config interface{}) {
 		return instance
 	})
 }
+
+// StoreBlockNonce is an exposed mock contract method
+func StoreBlockNonce(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod("storeBlockNonce", func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+
+		arguments := host.Runtime().Arguments()
+		if len(arguments) != 1 {
+			host.Runtime().SignalUserError("needs 1 argument")
+			return instance
+		}
+
+		key := arguments[0]
+		nonce := big.NewInt(0).SetUint64(host.Blockchain().CurrentNonce())
+		elrondapi.StorageStoreWithTypedArgs(host, key, nonce.Bytes())
+
+		return instance
+	})
+}
@@ -0,0 +1,79 @@
+package contracts
+
+import (
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-vm-common/txDataBuilder"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/elrondapi"
+	mock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	test "github.com/ElrondNetwork/wasm-vm/testcommon"
+	"github.com/stretchr/testify/require"
+)
+
+// IssuedTokenKey is the storage key where IssueTokenCallBackMock saves the issued token identifier
+var IssuedTokenKey = []byte("issuedToken")
+
+// IssueTokenMock is an exposed mock contract method
+func IssueTokenMock(instanceMock *mock.InstanceMock, config interface{}) {
+	instanceMock.AddMockMethod("issueToken", func() *mock.InstanceMock {
+		testConfig := config.(*test.TestConfig)
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		t := instance.T
+		arguments := host.Runtime().Arguments()
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByParent)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		callData := txDataBuilder.NewBuilder()
+		callData.Func("issue")
+		// token name and ticker
+		callData.Bytes(arguments[0])
+		callData.Bytes(arguments[1])
+		// supply and decimals
+		callData.BigInt(big.NewInt(int64(testConfig.ESDTTokensToTransfer)))
+		callData.BigInt(big.NewInt(0))
+
+		err = host.Async().RegisterAsyncCall("issueGroup", &arwen.AsyncCall{
+			Status:          arwen.AsyncCallPending,
+			Destination:     worldmock.ESDTSCAddress,
+			Data:            callData.ToBytes(),
+			ValueBytes:      big.NewInt(0).Bytes(),
+			GasLimit:        testConfig.GasProvidedToChild,
+			SuccessCallback: testConfig.SuccessCallback,
+			ErrorCallback:   testConfig.ErrorCallback,
+		})
+		require.Nil(t, err)
+
+		return instance
+	})
+}
+
+// IssueTokenCallBackMock is an exposed mock contract method
+func IssueTokenCallBackMock(instanceMock *mock.InstanceMock, config interface{}) {
+	testConfig := config.(*test.TestConfig)
+	instanceMock.AddMockMethod(testConfig.SuccessCallback, func() *mock.InstanceMock {
+		host := instanceMock.Host
+		instance := mock.GetMockInstance(host)
+		arguments := host.Runtime().Arguments()
+
+		err := host.Metering().UseGasBounded(testConfig.GasUsedByCallback)
+		if err != nil {
+			host.Runtime().SetRuntimeBreakpointValue(arwen.BreakpointOutOfGas)
+			return instance
+		}
+
+		if len(arguments) != 2 || big.NewInt(0).SetBytes(arguments[0]).Sign() != 0 {
+			host.Runtime().SignalUserError("issue failed")
+			return instance
+		}
+
+		elrondapi.StorageStoreWithTypedArgs(host, IssuedTokenKey, arguments[1])
+		return instance
+	})
+}
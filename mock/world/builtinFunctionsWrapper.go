@@ -57,8 +57,16 @@ func NewBuiltinFunctionsWrapper(
 		return nil, err
 	}
 
+	container := builtinFuncFactory.BuiltInFunctionContainer()
+	for _, function := range []string{SetGuardianFunctionName, GuardAccountFunctionName, UnGuardAccountFunctionName} {
+		err = container.Add(function, NewGuardianBuiltinFunction(function, world, DefaultGuardianActivationEpochs))
+		if err != nil {
+			return nil, err
+		}
+	}
+
 	builtinFuncsWrapper := &BuiltinFunctionsWrapper{
-		Container:       builtinFuncFactory.BuiltInFunctionContainer(),
+		Container:       container,
 		MapDNSAddresses: argsBuiltIn.MapDNSAddresses,
 		World:           world,
 	}
@@ -43,6 +43,50 @@ type EnableEpochsHandlerStub struct {
 	RefactorContextEnableEpochField                      uint32
 	RemoveNonUpdatedStorageEnableEpochField              uint32
 	StorageAPICostOptimizationEnableEpochField           uint32
+
+	ManagedBufferFunctionsEnableEpochField        uint32
+	PromisesFunctionsEnableEpochField             uint32
+	ManagedCryptoFunctionsEnableEpochField        uint32
+	ContractAddressFunctionsEnableEpochField      uint32
+	StorageDeleteFunctionsEnableEpochField        uint32
+	DebugPrintFunctionsEnableEpochField           uint32
+	ChunkedFinishFunctionsEnableEpochField        uint32
+	NFTMetadataFunctionsEnableEpochField          uint32
+	NFTRoyaltiesFunctionsEnableEpochField         uint32
+	ArgumentSliceFunctionsEnableEpochField        uint32
+	EscrowFunctionsEnableEpochField               uint32
+	SignExtensionEnableEpochField                 uint32
+	BulkMemoryEnableEpochField                    uint32
+	ReferenceTypesEnableEpochField                uint32
+	VersionedAsyncContextEnableEpochField         uint32
+	EndpointCallabilityEnableEpochField           uint32
+	ContractBuildInfoLogEnableEpochField          uint32
+	GasPhaseFunctionsEnableEpochField             uint32
+	ContractDeletionEnableEpochField              uint32
+	ManagedHandleDropFunctionsEnableEpochField    uint32
+	AsyncCallSubGroupsEnableEpochField            uint32
+	AsyncCallsCanonicalOrderEnableEpochField      uint32
+	PromiseHandleFunctionsEnableEpochField        uint32
+	ArgumentSchemaEnableEpochField                uint32
+	AsyncCallValidationEnableEpochField           uint32
+	GasRefundFunctionsEnableEpochField            uint32
+	TypedErrorFunctionsEnableEpochField           uint32
+	StorageLimitsEnableEpochField                 uint32
+	CallbackDeduplicationEnableEpochField         uint32
+	PaymentTokensEnableEpochField                 uint32
+	StorageMigrationFunctionsEnableEpochField     uint32
+	MemoryLimitReturnCodeEnableEpochField         uint32
+	LockedValueLedgerEnableEpochField             uint32
+	ErrorCodeLogTopicEnableEpochField             uint32
+	StorageHookErrorsEnableEpochField             uint32
+	GuardedAccountsEnableEpochField               uint32
+	OwnershipTransferLogEnableEpochField          uint32
+	LegacyCallbackNoOpEnableEpochField            uint32
+	SortedAccountSetsEnableEpochField             uint32
+	DetailedContractInvalidErrorsEnableEpochField uint32
+
+	CurrentEpochField  uint32
+	CurrentEpochCalled func() uint32
 }
 
 // IsGlobalMintBurnFlagEnabled -
@@ -264,7 +308,8 @@ func EnableEpochsHandlerStubAllFlags() *EnableEpochsHandlerStub {
 	}
 }
 
-// EnableEpochsHandlerStubNoFlags creates a new EnableEpochsHandlerStub with all flags disabled
+// EnableEpochsHandlerStubNoFlags creates a new EnableEpochsHandlerStub with all the flags of the
+// node disabled; the features of the VM keep their zero enable epochs, so they are active
 func EnableEpochsHandlerStubNoFlags() *EnableEpochsHandlerStub {
 	return &EnableEpochsHandlerStub{}
 }
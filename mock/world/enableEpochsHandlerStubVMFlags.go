@@ -0,0 +1,411 @@
+package worldmock
+
+// currentEpoch is the epoch in which the flags of the features of the VM are
+// read: the epoch returned by CurrentEpochCalled, usually that of the
+// blockchain hook, or else CurrentEpochField
+func (stub *EnableEpochsHandlerStub) currentEpoch() uint32 {
+	if stub.CurrentEpochCalled != nil {
+		return stub.CurrentEpochCalled()
+	}
+	return stub.CurrentEpochField
+}
+
+// ManagedBufferFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ManagedBufferFunctionsEnableEpoch() uint32 {
+	return stub.ManagedBufferFunctionsEnableEpochField
+}
+
+// IsManagedBufferFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsManagedBufferFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ManagedBufferFunctionsEnableEpochField
+}
+
+// PromisesFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) PromisesFunctionsEnableEpoch() uint32 {
+	return stub.PromisesFunctionsEnableEpochField
+}
+
+// IsPromisesFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsPromisesFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.PromisesFunctionsEnableEpochField
+}
+
+// ManagedCryptoFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ManagedCryptoFunctionsEnableEpoch() uint32 {
+	return stub.ManagedCryptoFunctionsEnableEpochField
+}
+
+// IsManagedCryptoFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsManagedCryptoFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ManagedCryptoFunctionsEnableEpochField
+}
+
+// ContractAddressFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ContractAddressFunctionsEnableEpoch() uint32 {
+	return stub.ContractAddressFunctionsEnableEpochField
+}
+
+// IsContractAddressFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsContractAddressFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ContractAddressFunctionsEnableEpochField
+}
+
+// StorageDeleteFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) StorageDeleteFunctionsEnableEpoch() uint32 {
+	return stub.StorageDeleteFunctionsEnableEpochField
+}
+
+// IsStorageDeleteFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsStorageDeleteFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.StorageDeleteFunctionsEnableEpochField
+}
+
+// DebugPrintFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) DebugPrintFunctionsEnableEpoch() uint32 {
+	return stub.DebugPrintFunctionsEnableEpochField
+}
+
+// IsDebugPrintFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsDebugPrintFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.DebugPrintFunctionsEnableEpochField
+}
+
+// ChunkedFinishFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ChunkedFinishFunctionsEnableEpoch() uint32 {
+	return stub.ChunkedFinishFunctionsEnableEpochField
+}
+
+// IsChunkedFinishFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsChunkedFinishFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ChunkedFinishFunctionsEnableEpochField
+}
+
+// NFTMetadataFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) NFTMetadataFunctionsEnableEpoch() uint32 {
+	return stub.NFTMetadataFunctionsEnableEpochField
+}
+
+// IsNFTMetadataFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsNFTMetadataFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.NFTMetadataFunctionsEnableEpochField
+}
+
+// NFTRoyaltiesFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) NFTRoyaltiesFunctionsEnableEpoch() uint32 {
+	return stub.NFTRoyaltiesFunctionsEnableEpochField
+}
+
+// IsNFTRoyaltiesFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsNFTRoyaltiesFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.NFTRoyaltiesFunctionsEnableEpochField
+}
+
+// ArgumentSliceFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ArgumentSliceFunctionsEnableEpoch() uint32 {
+	return stub.ArgumentSliceFunctionsEnableEpochField
+}
+
+// IsArgumentSliceFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsArgumentSliceFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ArgumentSliceFunctionsEnableEpochField
+}
+
+// EscrowFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) EscrowFunctionsEnableEpoch() uint32 {
+	return stub.EscrowFunctionsEnableEpochField
+}
+
+// IsEscrowFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsEscrowFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.EscrowFunctionsEnableEpochField
+}
+
+// SignExtensionEnableEpoch -
+func (stub *EnableEpochsHandlerStub) SignExtensionEnableEpoch() uint32 {
+	return stub.SignExtensionEnableEpochField
+}
+
+// IsSignExtensionFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsSignExtensionFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.SignExtensionEnableEpochField
+}
+
+// BulkMemoryEnableEpoch -
+func (stub *EnableEpochsHandlerStub) BulkMemoryEnableEpoch() uint32 {
+	return stub.BulkMemoryEnableEpochField
+}
+
+// IsBulkMemoryFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsBulkMemoryFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.BulkMemoryEnableEpochField
+}
+
+// ReferenceTypesEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ReferenceTypesEnableEpoch() uint32 {
+	return stub.ReferenceTypesEnableEpochField
+}
+
+// IsReferenceTypesFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsReferenceTypesFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ReferenceTypesEnableEpochField
+}
+
+// VersionedAsyncContextEnableEpoch -
+func (stub *EnableEpochsHandlerStub) VersionedAsyncContextEnableEpoch() uint32 {
+	return stub.VersionedAsyncContextEnableEpochField
+}
+
+// IsVersionedAsyncContextFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsVersionedAsyncContextFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.VersionedAsyncContextEnableEpochField
+}
+
+// EndpointCallabilityEnableEpoch -
+func (stub *EnableEpochsHandlerStub) EndpointCallabilityEnableEpoch() uint32 {
+	return stub.EndpointCallabilityEnableEpochField
+}
+
+// IsEndpointCallabilityFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsEndpointCallabilityFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.EndpointCallabilityEnableEpochField
+}
+
+// ContractBuildInfoLogEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ContractBuildInfoLogEnableEpoch() uint32 {
+	return stub.ContractBuildInfoLogEnableEpochField
+}
+
+// IsContractBuildInfoLogFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsContractBuildInfoLogFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ContractBuildInfoLogEnableEpochField
+}
+
+// GasPhaseFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) GasPhaseFunctionsEnableEpoch() uint32 {
+	return stub.GasPhaseFunctionsEnableEpochField
+}
+
+// IsGasPhaseFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsGasPhaseFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.GasPhaseFunctionsEnableEpochField
+}
+
+// ContractDeletionEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ContractDeletionEnableEpoch() uint32 {
+	return stub.ContractDeletionEnableEpochField
+}
+
+// IsContractDeletionFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsContractDeletionFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ContractDeletionEnableEpochField
+}
+
+// ManagedHandleDropFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ManagedHandleDropFunctionsEnableEpoch() uint32 {
+	return stub.ManagedHandleDropFunctionsEnableEpochField
+}
+
+// IsManagedHandleDropFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsManagedHandleDropFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ManagedHandleDropFunctionsEnableEpochField
+}
+
+// AsyncCallSubGroupsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) AsyncCallSubGroupsEnableEpoch() uint32 {
+	return stub.AsyncCallSubGroupsEnableEpochField
+}
+
+// IsAsyncCallSubGroupsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsAsyncCallSubGroupsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.AsyncCallSubGroupsEnableEpochField
+}
+
+// AsyncCallsCanonicalOrderEnableEpoch -
+func (stub *EnableEpochsHandlerStub) AsyncCallsCanonicalOrderEnableEpoch() uint32 {
+	return stub.AsyncCallsCanonicalOrderEnableEpochField
+}
+
+// IsAsyncCallsCanonicalOrderFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsAsyncCallsCanonicalOrderFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.AsyncCallsCanonicalOrderEnableEpochField
+}
+
+// PromiseHandleFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) PromiseHandleFunctionsEnableEpoch() uint32 {
+	return stub.PromiseHandleFunctionsEnableEpochField
+}
+
+// IsPromiseHandleFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsPromiseHandleFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.PromiseHandleFunctionsEnableEpochField
+}
+
+// ArgumentSchemaEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ArgumentSchemaEnableEpoch() uint32 {
+	return stub.ArgumentSchemaEnableEpochField
+}
+
+// IsArgumentSchemaFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsArgumentSchemaFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ArgumentSchemaEnableEpochField
+}
+
+// AsyncCallValidationEnableEpoch -
+func (stub *EnableEpochsHandlerStub) AsyncCallValidationEnableEpoch() uint32 {
+	return stub.AsyncCallValidationEnableEpochField
+}
+
+// IsAsyncCallValidationFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsAsyncCallValidationFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.AsyncCallValidationEnableEpochField
+}
+
+// GasRefundFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) GasRefundFunctionsEnableEpoch() uint32 {
+	return stub.GasRefundFunctionsEnableEpochField
+}
+
+// IsGasRefundFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsGasRefundFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.GasRefundFunctionsEnableEpochField
+}
+
+// TypedErrorFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) TypedErrorFunctionsEnableEpoch() uint32 {
+	return stub.TypedErrorFunctionsEnableEpochField
+}
+
+// IsTypedErrorFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsTypedErrorFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.TypedErrorFunctionsEnableEpochField
+}
+
+// StorageLimitsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) StorageLimitsEnableEpoch() uint32 {
+	return stub.StorageLimitsEnableEpochField
+}
+
+// IsStorageLimitsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsStorageLimitsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.StorageLimitsEnableEpochField
+}
+
+// CallbackDeduplicationEnableEpoch -
+func (stub *EnableEpochsHandlerStub) CallbackDeduplicationEnableEpoch() uint32 {
+	return stub.CallbackDeduplicationEnableEpochField
+}
+
+// IsCallbackDeduplicationFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsCallbackDeduplicationFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.CallbackDeduplicationEnableEpochField
+}
+
+// PaymentTokensEnableEpoch -
+func (stub *EnableEpochsHandlerStub) PaymentTokensEnableEpoch() uint32 {
+	return stub.PaymentTokensEnableEpochField
+}
+
+// IsPaymentTokensFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsPaymentTokensFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.PaymentTokensEnableEpochField
+}
+
+// StorageMigrationFunctionsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) StorageMigrationFunctionsEnableEpoch() uint32 {
+	return stub.StorageMigrationFunctionsEnableEpochField
+}
+
+// IsStorageMigrationFunctionsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsStorageMigrationFunctionsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.StorageMigrationFunctionsEnableEpochField
+}
+
+// MemoryLimitReturnCodeEnableEpoch -
+func (stub *EnableEpochsHandlerStub) MemoryLimitReturnCodeEnableEpoch() uint32 {
+	return stub.MemoryLimitReturnCodeEnableEpochField
+}
+
+// IsMemoryLimitReturnCodeFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsMemoryLimitReturnCodeFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.MemoryLimitReturnCodeEnableEpochField
+}
+
+// LockedValueLedgerEnableEpoch -
+func (stub *EnableEpochsHandlerStub) LockedValueLedgerEnableEpoch() uint32 {
+	return stub.LockedValueLedgerEnableEpochField
+}
+
+// IsLockedValueLedgerFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsLockedValueLedgerFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.LockedValueLedgerEnableEpochField
+}
+
+// ErrorCodeLogTopicEnableEpoch -
+func (stub *EnableEpochsHandlerStub) ErrorCodeLogTopicEnableEpoch() uint32 {
+	return stub.ErrorCodeLogTopicEnableEpochField
+}
+
+// IsErrorCodeLogTopicFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsErrorCodeLogTopicFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.ErrorCodeLogTopicEnableEpochField
+}
+
+// StorageHookErrorsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) StorageHookErrorsEnableEpoch() uint32 {
+	return stub.StorageHookErrorsEnableEpochField
+}
+
+// IsStorageHookErrorsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsStorageHookErrorsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.StorageHookErrorsEnableEpochField
+}
+
+// GuardedAccountsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) GuardedAccountsEnableEpoch() uint32 {
+	return stub.GuardedAccountsEnableEpochField
+}
+
+// IsGuardedAccountsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsGuardedAccountsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.GuardedAccountsEnableEpochField
+}
+
+// OwnershipTransferLogEnableEpoch -
+func (stub *EnableEpochsHandlerStub) OwnershipTransferLogEnableEpoch() uint32 {
+	return stub.OwnershipTransferLogEnableEpochField
+}
+
+// IsOwnershipTransferLogFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsOwnershipTransferLogFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.OwnershipTransferLogEnableEpochField
+}
+
+// LegacyCallbackNoOpEnableEpoch -
+func (stub *EnableEpochsHandlerStub) LegacyCallbackNoOpEnableEpoch() uint32 {
+	return stub.LegacyCallbackNoOpEnableEpochField
+}
+
+// IsLegacyCallbackNoOpFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsLegacyCallbackNoOpFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.LegacyCallbackNoOpEnableEpochField
+}
+
+// SortedAccountSetsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) SortedAccountSetsEnableEpoch() uint32 {
+	return stub.SortedAccountSetsEnableEpochField
+}
+
+// IsSortedAccountSetsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsSortedAccountSetsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.SortedAccountSetsEnableEpochField
+}
+
+// DetailedContractInvalidErrorsEnableEpoch -
+func (stub *EnableEpochsHandlerStub) DetailedContractInvalidErrorsEnableEpoch() uint32 {
+	return stub.DetailedContractInvalidErrorsEnableEpochField
+}
+
+// IsDetailedContractInvalidErrorsFlagEnabled -
+func (stub *EnableEpochsHandlerStub) IsDetailedContractInvalidErrorsFlagEnabled() bool {
+	return stub.currentEpoch() >= stub.DetailedContractInvalidErrorsEnableEpochField
+}
@@ -0,0 +1,221 @@
+package worldmock
+
+import (
+	"bytes"
+	"errors"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+)
+
+// SetGuardianFunctionName is the builtin function which sets a pending guardian for the caller
+const SetGuardianFunctionName = "SetGuardian"
+
+// GuardAccountFunctionName is the builtin function which guards the caller with its active guardian
+const GuardAccountFunctionName = "GuardAccount"
+
+// UnGuardAccountFunctionName is the builtin function which removes the guard of the caller
+const UnGuardAccountFunctionName = "UnGuardAccount"
+
+// DefaultGuardianActivationEpochs is the number of epochs after which a pending guardian becomes active
+const DefaultGuardianActivationEpochs = 20
+
+const numSetGuardianArgs = 2
+
+// ErrGuardianCallNotOnSelf signals that a guardian builtin function was not called by an account on itself
+var ErrGuardianCallNotOnSelf = errors.New("guardian builtin functions must be called by an account on itself")
+
+// ErrInvalidGuardianArguments signals a wrong number of arguments for a guardian builtin function
+var ErrInvalidGuardianArguments = errors.New("invalid arguments for guardian builtin function")
+
+// ErrGuardianIsAccount signals an attempt to set an account as its own guardian
+var ErrGuardianIsAccount = errors.New("an account cannot be its own guardian")
+
+// ErrNoActiveGuardian signals that the account does not have an active guardian
+var ErrNoActiveGuardian = errors.New("no active guardian")
+
+// ErrAccountAlreadyGuarded signals an attempt to guard an account which is already guarded
+var ErrAccountAlreadyGuarded = errors.New("account already guarded")
+
+// ErrAccountNotGuarded signals an attempt to remove the guard of an account which is not guarded
+var ErrAccountNotGuarded = errors.New("account not guarded")
+
+// ErrGuardianCallValue signals that value was transferred to a guardian builtin function
+var ErrGuardianCallValue = errors.New("guardian builtin functions do not accept value")
+
+// IsGuardianBuiltinFunction returns whether the function is one of the guardian builtin functions
+func IsGuardianBuiltinFunction(function string) bool {
+	return function == SetGuardianFunctionName ||
+		function == GuardAccountFunctionName ||
+		function == UnGuardAccountFunctionName
+}
+
+// ActivatePendingGuardian turns the pending guardian into the active one, if
+// its activation epoch has been reached
+func (a *Account) ActivatePendingGuardian(currentEpoch uint32) {
+	if len(a.PendingGuardian) == 0 || currentEpoch < a.PendingGuardianActivationEpoch {
+		return
+	}
+
+	a.ActiveGuardian = a.PendingGuardian
+	a.ActiveGuardianServiceUID = a.PendingGuardianServiceUID
+	a.PendingGuardian = nil
+	a.PendingGuardianServiceUID = nil
+	a.PendingGuardianActivationEpoch = 0
+}
+
+// GetActiveGuardian returns the active guardian of an account
+func (b *MockWorld) GetActiveGuardian(address []byte) ([]byte, error) {
+	account := b.AcctMap.GetAccount(address)
+	if account == nil {
+		return nil, ErrNoActiveGuardian
+	}
+
+	account.ActivatePendingGuardian(b.CurrentEpoch())
+	if len(account.ActiveGuardian) == 0 {
+		return nil, ErrNoActiveGuardian
+	}
+	return account.ActiveGuardian, nil
+}
+
+// IsGuardedAccount returns whether the transactions of an account must be
+// co-signed by its guardian
+func (b *MockWorld) IsGuardedAccount(address []byte) bool {
+	account := b.AcctMap.GetAccount(address)
+	return account != nil && account.IsGuarded
+}
+
+// GuardianCoSignature holds the guardian related fields of a transaction
+type GuardianCoSignature struct {
+	Options           uint32
+	GuardianAddr      []byte
+	GuardianSignature []byte
+}
+
+// SetGuardianCoSignature sets the guardian fields of the transaction with the given hash
+func (b *MockWorld) SetGuardianCoSignature(txHash []byte, coSignature *GuardianCoSignature) {
+	if b.GuardianCoSignatures == nil {
+		b.GuardianCoSignatures = make(map[string]*GuardianCoSignature)
+	}
+	b.GuardianCoSignatures[string(txHash)] = coSignature
+}
+
+// GetGuardianCoSignature returns the guardian fields of the transaction with
+// the given hash, which are empty unless set
+func (b *MockWorld) GetGuardianCoSignature(txHash []byte) (uint32, []byte, []byte) {
+	coSignature, ok := b.GuardianCoSignatures[string(txHash)]
+	if !ok {
+		return 0, nil, nil
+	}
+	return coSignature.Options, coSignature.GuardianAddr, coSignature.GuardianSignature
+}
+
+// GuardianBuiltinFunction implements the SetGuardian, GuardAccount and
+// UnGuardAccount builtin functions on the accounts of a MockWorld
+type GuardianBuiltinFunction struct {
+	function         string
+	world            *MockWorld
+	activationEpochs uint32
+}
+
+// NewGuardianBuiltinFunction creates the guardian builtin function with the given name
+func NewGuardianBuiltinFunction(function string, world *MockWorld, activationEpochs uint32) *GuardianBuiltinFunction {
+	return &GuardianBuiltinFunction{
+		function:         function,
+		world:            world,
+		activationEpochs: activationEpochs,
+	}
+}
+
+// ProcessBuiltinFunction -
+func (gbf *GuardianBuiltinFunction) ProcessBuiltinFunction(
+	acntSnd, _ vmcommon.UserAccountHandler,
+	vmInput *vmcommon.ContractCallInput,
+) (*vmcommon.VMOutput, error) {
+	if !bytes.Equal(vmInput.CallerAddr, vmInput.RecipientAddr) {
+		return nil, ErrGuardianCallNotOnSelf
+	}
+	if vmInput.CallValue != nil && vmInput.CallValue.Sign() != 0 {
+		return nil, ErrGuardianCallValue
+	}
+
+	account, ok := acntSnd.(*Account)
+	if !ok || account == nil {
+		return nil, ErrGuardianCallNotOnSelf
+	}
+	currentEpoch := gbf.world.CurrentEpoch()
+	account.ActivatePendingGuardian(currentEpoch)
+
+	var err error
+	switch gbf.function {
+	case SetGuardianFunctionName:
+		err = gbf.setGuardian(account, vmInput.Arguments, currentEpoch)
+	case GuardAccountFunctionName:
+		err = gbf.guardAccount(account, vmInput.Arguments)
+	case UnGuardAccountFunctionName:
+		err = gbf.unGuardAccount(account, vmInput.Arguments)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	return &vmcommon.VMOutput{
+		ReturnCode:   vmcommon.Ok,
+		GasRemaining: vmInput.GasProvided,
+	}, nil
+}
+
+func (gbf *GuardianBuiltinFunction) setGuardian(account *Account, arguments [][]byte, currentEpoch uint32) error {
+	if len(arguments) != numSetGuardianArgs {
+		return ErrInvalidGuardianArguments
+	}
+	if bytes.Equal(arguments[0], account.Address) {
+		return ErrGuardianIsAccount
+	}
+
+	account.PendingGuardian = arguments[0]
+	account.PendingGuardianServiceUID = arguments[1]
+	account.PendingGuardianActivationEpoch = currentEpoch + gbf.activationEpochs
+	account.ActivatePendingGuardian(currentEpoch)
+	return nil
+}
+
+func (gbf *GuardianBuiltinFunction) guardAccount(account *Account, arguments [][]byte) error {
+	if len(arguments) != 0 {
+		return ErrInvalidGuardianArguments
+	}
+	if account.IsGuarded {
+		return ErrAccountAlreadyGuarded
+	}
+	if len(account.ActiveGuardian) == 0 {
+		return ErrNoActiveGuardian
+	}
+
+	account.IsGuarded = true
+	return nil
+}
+
+func (gbf *GuardianBuiltinFunction) unGuardAccount(account *Account, arguments [][]byte) error {
+	if len(arguments) != 0 {
+		return ErrInvalidGuardianArguments
+	}
+	if !account.IsGuarded {
+		return ErrAccountNotGuarded
+	}
+
+	account.IsGuarded = false
+	return nil
+}
+
+// SetNewGasConfig -
+func (gbf *GuardianBuiltinFunction) SetNewGasConfig(_ *vmcommon.GasCost) {
+}
+
+// IsActive -
+func (gbf *GuardianBuiltinFunction) IsActive() bool {
+	return true
+}
+
+// IsInterfaceNil -
+func (gbf *GuardianBuiltinFunction) IsInterfaceNil() bool {
+	return gbf == nil
+}
@@ -0,0 +1,308 @@
+package worldmock
+
+import (
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/core"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
+	"github.com/ElrondNetwork/wasm-vm/mandos-go/esdtconvert"
+)
+
+// ESDTSCAddress is the address of the ESDT system smart contract
+var ESDTSCAddress = makeSystemSCAddress(2)
+
+// StakingSCAddress is the address of the staking system smart contract
+var StakingSCAddress = makeSystemSCAddress(1)
+
+// GovernanceSCAddress is the address of the governance system smart contract
+var GovernanceSCAddress = makeSystemSCAddress(3)
+
+// ErrSystemSCNotFound signals that the called address is not a registered system smart contract
+var ErrSystemSCNotFound = errors.New("system smart contract not found")
+
+// ErrSystemSCFunctionNotFound signals that the system smart contract has no handler for the called function
+var ErrSystemSCFunctionNotFound = errors.New("system smart contract function not found")
+
+// ErrSystemSCInvalidArguments signals that a system smart contract was called with the wrong arguments
+var ErrSystemSCInvalidArguments = errors.New("invalid arguments for system smart contract")
+
+const (
+	issueFunctionName      = "issue"
+	stakeFunctionName      = "stake"
+	unStakeFunctionName    = "unStake"
+	proposalFunctionName   = "proposal"
+	voteFunctionName       = "vote"
+	stakedKeyPrefix        = "staked_"
+	proposalKeyPrefix      = "proposal_"
+	voteKeyPrefix          = "vote_"
+	tokenIdentifierRandLen = 3
+)
+
+// SystemSCHandler processes a call to a single function of a mocked system smart contract.
+type SystemSCHandler func(world *MockWorld, sc *SystemSCMock, input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error)
+
+// SystemSCMock holds the functions exposed by a mocked system smart contract.
+type SystemSCMock struct {
+	Address   []byte
+	Name      string
+	Functions map[string]SystemSCHandler
+}
+
+// NewSystemSCMock creates a mocked system smart contract without any functions.
+func NewSystemSCMock(name string, address []byte) *SystemSCMock {
+	return &SystemSCMock{
+		Address:   address,
+		Name:      name,
+		Functions: make(map[string]SystemSCHandler),
+	}
+}
+
+// WithFunction registers a handler for the given function name.
+func (sc *SystemSCMock) WithFunction(name string, handler SystemSCHandler) *SystemSCMock {
+	sc.Functions[name] = handler
+	return sc
+}
+
+// RegisterSystemSC adds a mocked system smart contract to the world, along
+// with its account, placed on the metachain.
+func (b *MockWorld) RegisterSystemSC(sc *SystemSCMock) {
+	if b.SystemSCs == nil {
+		b.SystemSCs = make(map[string]*SystemSCMock)
+	}
+	b.SystemSCs[string(sc.Address)] = sc
+
+	account := b.AcctMap.GetAccount(sc.Address)
+	if account == nil {
+		account = b.AcctMap.CreateAccount(sc.Address, b)
+	}
+	account.IsSmartContract = true
+	account.ShardID = core.MetachainShardId
+}
+
+// InitSystemSmartContracts registers the default mocks of the ESDT, staking
+// and governance system smart contracts.
+func (b *MockWorld) InitSystemSmartContracts() {
+	b.RegisterSystemSC(NewESDTSystemSCMock())
+	b.RegisterSystemSC(NewStakingSystemSCMock())
+	b.RegisterSystemSC(NewGovernanceSystemSCMock())
+}
+
+// IsSystemSmartContract returns true if the address belongs to a registered system smart contract mock.
+func (b *MockWorld) IsSystemSmartContract(address []byte) bool {
+	_, found := b.SystemSCs[string(address)]
+	return found
+}
+
+// ProcessSystemSCCall executes the call on the mocked system smart contract it
+// is addressed to, as the metachain would. The world is left unchanged: every
+// effect of the call is held by the returned VMOutput, to be applied by the
+// caller.
+func (b *MockWorld) ProcessSystemSCCall(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	// custom error
+	if b.Err != nil {
+		return nil, b.Err
+	}
+
+	sc, found := b.SystemSCs[string(input.RecipientAddr)]
+	if !found {
+		return nil, fmt.Errorf("%w: %s", ErrSystemSCNotFound, hex.EncodeToString(input.RecipientAddr))
+	}
+
+	handler, found := sc.Functions[input.Function]
+	if !found {
+		return nil, fmt.Errorf("%w: %s.%s", ErrSystemSCFunctionNotFound, sc.Name, input.Function)
+	}
+
+	vmOutput, err := handler(b, sc, input)
+	if err != nil {
+		return &vmcommon.VMOutput{
+			ReturnCode:    vmcommon.UserError,
+			ReturnMessage: err.Error(),
+			GasRemaining:  0,
+		}, nil
+	}
+
+	return vmOutput, nil
+}
+
+// NewESDTSystemSCMock creates a mock of the ESDT system smart contract, which
+// issues fungible tokens directly into the balance of the caller.
+func NewESDTSystemSCMock() *SystemSCMock {
+	return NewSystemSCMock("esdt", ESDTSCAddress).
+		WithFunction(issueFunctionName, issueHandler)
+}
+
+// NewStakingSystemSCMock creates a mock of the staking system smart contract,
+// which keeps the staked value of each caller in its own storage.
+func NewStakingSystemSCMock() *SystemSCMock {
+	return NewSystemSCMock("staking", StakingSCAddress).
+		WithFunction(stakeFunctionName, stakeHandler).
+		WithFunction(unStakeFunctionName, unStakeHandler)
+}
+
+// NewGovernanceSystemSCMock creates a mock of the governance system smart
+// contract, which records proposals and votes in its own storage.
+func NewGovernanceSystemSCMock() *SystemSCMock {
+	return NewSystemSCMock("governance", GovernanceSCAddress).
+		WithFunction(proposalFunctionName, proposalHandler).
+		WithFunction(voteFunctionName, voteHandler)
+}
+
+// issue@name@ticker@supply@decimals
+func issueHandler(world *MockWorld, sc *SystemSCMock, input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	if len(input.Arguments) < 4 {
+		return nil, ErrSystemSCInvalidArguments
+	}
+
+	ticker := input.Arguments[1]
+	supply := big.NewInt(0).SetBytes(input.Arguments[2])
+	nonce := world.AcctMap.GetAccount(sc.Address).Nonce + 1
+	tokenIdentifier := makeTokenIdentifier(ticker, input.CallerAddr, nonce)
+
+	vmOutput := makeSystemSCOutput(input, [][]byte{tokenIdentifier})
+	getSystemSCOutputAccount(vmOutput, sc.Address).Nonce = nonce
+
+	if world.AcctMap.GetAccount(input.CallerAddr) != nil {
+		tokenStorage := make(map[string][]byte)
+		err := esdtconvert.SetTokenBalance(tokenIdentifier, 0, supply, tokenStorage)
+		if err != nil {
+			return nil, err
+		}
+		caller := getSystemSCOutputAccount(vmOutput, input.CallerAddr)
+		for key, value := range tokenStorage {
+			addStorageUpdate(caller, []byte(key), value)
+		}
+	}
+
+	return vmOutput, nil
+}
+
+func makeTokenIdentifier(ticker []byte, caller []byte, nonce uint64) []byte {
+	seed := append(append([]byte{}, caller...), big.NewInt(0).SetUint64(nonce).Bytes()...)
+	hash, _ := hashing.NewHasher().Sha256(seed)
+	suffix := hex.EncodeToString(hash[:tokenIdentifierRandLen])
+
+	return []byte(string(ticker) + "-" + suffix)
+}
+
+// stake, with the staked value as call value, moved from the caller to the
+// staking system smart contract
+func stakeHandler(world *MockWorld, sc *SystemSCMock, input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	if input.CallValue == nil || input.CallValue.Sign() <= 0 {
+		return nil, ErrSystemSCInvalidArguments
+	}
+
+	key := []byte(stakedKeyPrefix + string(input.CallerAddr))
+	staked := big.NewInt(0).SetBytes(world.AcctMap.GetAccount(sc.Address).StorageValue(string(key)))
+	staked.Add(staked, input.CallValue)
+
+	vmOutput := makeSystemSCOutput(input, [][]byte{staked.Bytes()})
+	storeInSystemSC(sc, vmOutput, key, staked.Bytes())
+	transferFromSystemSC(vmOutput, sc.Address, input.CallerAddr, big.NewInt(0).Neg(input.CallValue))
+	return vmOutput, nil
+}
+
+// unStake@value
+func unStakeHandler(world *MockWorld, sc *SystemSCMock, input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	if len(input.Arguments) != 1 {
+		return nil, ErrSystemSCInvalidArguments
+	}
+
+	key := []byte(stakedKeyPrefix + string(input.CallerAddr))
+	staked := big.NewInt(0).SetBytes(world.AcctMap.GetAccount(sc.Address).StorageValue(string(key)))
+	value := big.NewInt(0).SetBytes(input.Arguments[0])
+	if staked.Cmp(value) < 0 {
+		return nil, ErrInsufficientFunds
+	}
+	staked.Sub(staked, value)
+
+	vmOutput := makeSystemSCOutput(input, [][]byte{staked.Bytes()})
+	storeInSystemSC(sc, vmOutput, key, staked.Bytes())
+	transferFromSystemSC(vmOutput, sc.Address, input.CallerAddr, value)
+	return vmOutput, nil
+}
+
+// proposal@commitHash
+func proposalHandler(world *MockWorld, sc *SystemSCMock, input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	if len(input.Arguments) != 1 {
+		return nil, ErrSystemSCInvalidArguments
+	}
+
+	key := []byte(proposalKeyPrefix + string(input.Arguments[0]))
+	vmOutput := makeSystemSCOutput(input, [][]byte{input.Arguments[0]})
+	storeInSystemSC(sc, vmOutput, key, input.CallerAddr)
+	return vmOutput, nil
+}
+
+// vote@commitHash@voteType
+func voteHandler(world *MockWorld, sc *SystemSCMock, input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	if len(input.Arguments) != 2 {
+		return nil, ErrSystemSCInvalidArguments
+	}
+
+	proposalKey := proposalKeyPrefix + string(input.Arguments[0])
+	if len(world.AcctMap.GetAccount(sc.Address).StorageValue(proposalKey)) == 0 {
+		return nil, ErrSystemSCInvalidArguments
+	}
+
+	key := []byte(voteKeyPrefix + string(input.Arguments[0]) + "_" + string(input.CallerAddr))
+	vmOutput := makeSystemSCOutput(input, nil)
+	storeInSystemSC(sc, vmOutput, key, input.Arguments[1])
+	return vmOutput, nil
+}
+
+func makeSystemSCOutput(input *vmcommon.ContractCallInput, returnData [][]byte) *vmcommon.VMOutput {
+	return &vmcommon.VMOutput{
+		ReturnCode:     vmcommon.Ok,
+		ReturnData:     returnData,
+		GasRemaining:   input.GasProvided,
+		OutputAccounts: make(map[string]*vmcommon.OutputAccount),
+	}
+}
+
+func storeInSystemSC(sc *SystemSCMock, vmOutput *vmcommon.VMOutput, key []byte, value []byte) {
+	addStorageUpdate(getSystemSCOutputAccount(vmOutput, sc.Address), key, value)
+}
+
+// transferFromSystemSC moves the value from the system smart contract to the
+// receiver, or from the receiver to the system smart contract if negative
+func transferFromSystemSC(vmOutput *vmcommon.VMOutput, systemSCAddress []byte, receiver []byte, value *big.Int) {
+	systemSC := getSystemSCOutputAccount(vmOutput, systemSCAddress)
+	systemSC.BalanceDelta = big.NewInt(0).Sub(systemSC.BalanceDelta, value)
+
+	receiverAccount := getSystemSCOutputAccount(vmOutput, receiver)
+	receiverAccount.BalanceDelta = big.NewInt(0).Add(receiverAccount.BalanceDelta, value)
+}
+
+func getSystemSCOutputAccount(vmOutput *vmcommon.VMOutput, address []byte) *vmcommon.OutputAccount {
+	outputAccount, found := vmOutput.OutputAccounts[string(address)]
+	if !found {
+		outputAccount = &vmcommon.OutputAccount{
+			Address:        address,
+			BalanceDelta:   big.NewInt(0),
+			StorageUpdates: make(map[string]*vmcommon.StorageUpdate),
+		}
+		vmOutput.OutputAccounts[string(address)] = outputAccount
+	}
+	return outputAccount
+}
+
+func addStorageUpdate(outputAccount *vmcommon.OutputAccount, key []byte, value []byte) {
+	outputAccount.StorageUpdates[string(key)] = &vmcommon.StorageUpdate{
+		Offset: key,
+		Data:   value,
+	}
+}
+
+func makeSystemSCAddress(identifier byte) []byte {
+	address := make([]byte, 32)
+	address[9] = 1
+	address[29] = identifier
+	address[30] = 255
+	address[31] = 255
+	return address
+}
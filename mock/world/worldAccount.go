@@ -36,6 +36,13 @@ type Account struct {
 	ShardID         uint32
 	IsSmartContract bool
 	MockWorld       *MockWorld
+
+	ActiveGuardian                 []byte
+	ActiveGuardianServiceUID       []byte
+	PendingGuardian                []byte
+	PendingGuardianServiceUID      []byte
+	PendingGuardianActivationEpoch uint32
+	IsGuarded                      bool
 }
 
 var storageDefaultValue = []byte{}
@@ -49,6 +56,19 @@ func (a *Account) StorageValue(key string) []byte {
 	return value
 }
 
+// StorageSize yields the number of bytes stored by the account, keys and
+// values included; empty values are not stored
+func (a *Account) StorageSize() uint64 {
+	size := uint64(0)
+	for key, value := range a.Storage {
+		if len(value) == 0 {
+			continue
+		}
+		size += uint64(len(key) + len(value))
+	}
+	return size
+}
+
 // SetCodeAndMetadata changes the account code, as well as all fields depending on it:
 // CodeHash, IsSmartContract, CodeMetadata.
 // The code metadata must be given explicitly.
@@ -267,6 +287,13 @@ func (a *Account) Clone() *Account {
 		ShardID:         a.ShardID,
 		IsSmartContract: a.IsSmartContract,
 		MockWorld:       a.MockWorld,
+
+		ActiveGuardian:                 cloneBytes(a.ActiveGuardian),
+		ActiveGuardianServiceUID:       cloneBytes(a.ActiveGuardianServiceUID),
+		PendingGuardian:                cloneBytes(a.PendingGuardian),
+		PendingGuardianServiceUID:      cloneBytes(a.PendingGuardianServiceUID),
+		PendingGuardianActivationEpoch: a.PendingGuardianActivationEpoch,
+		IsGuarded:                      a.IsGuarded,
 	}
 }
 
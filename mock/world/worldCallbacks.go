@@ -23,8 +23,9 @@ var zero = big.NewInt(0)
 // It looks up the explicit new address mocks, if none found generates one using a fake but realistic algorithm.
 func (b *MockWorld) NewAddress(creatorAddress []byte, creatorNonce uint64, _ []byte) ([]byte, error) {
 	// custom error
-	if b.Err != nil {
-		return nil, b.Err
+	err := b.hookCallError(NewAddressHookCall)
+	if err != nil {
+		return nil, err
 	}
 
 	// explicit new address mocks
@@ -43,12 +44,28 @@ func (b *MockWorld) NewAddress(creatorAddress []byte, creatorNonce uint64, _ []b
 	return result, nil
 }
 
+// GetStorageSize yields the number of bytes stored by an account, keys and values included.
+func (b *MockWorld) GetStorageSize(accountAddress []byte) (uint64, error) {
+	// custom error
+	err := b.hookCallError(GetStorageDataHookCall)
+	if err != nil {
+		return 0, err
+	}
+
+	acct := b.AcctMap.GetAccount(accountAddress)
+	if acct == nil {
+		return 0, nil
+	}
+	return acct.StorageSize(), nil
+}
+
 // GetStorageData yields the storage value for a certain account and storage key.
 // Should return an empty byte array if the key is missing from the account storage
 func (b *MockWorld) GetStorageData(accountAddress []byte, key []byte) ([]byte, uint32, error) {
 	// custom error
-	if b.Err != nil {
-		return nil, 0, b.Err
+	err := b.hookCallError(GetStorageDataHookCall)
+	if err != nil {
+		return nil, 0, err
 	}
 
 	acct := b.AcctMap.GetAccount(accountAddress)
@@ -70,8 +87,9 @@ func (b *MockWorld) GetStorageData(accountAddress []byte, key []byte) ([]byte, u
 // GetBlockhash should return the hash of the nth previous blockchain.
 // Offset specifies how many blocks we need to look back.
 func (b *MockWorld) GetBlockhash(nonce uint64) ([]byte, error) {
-	if b.Err != nil {
-		return nil, b.Err
+	err := b.hookCallError(GetBlockhashHookCall)
+	if err != nil {
+		return nil, err
 	}
 	currentNonce := b.CurrentNonce()
 	if nonce > currentNonce {
@@ -172,8 +190,9 @@ func (b *MockWorld) CurrentEpoch() uint32 {
 // ProcessBuiltInFunction -
 func (b *MockWorld) ProcessBuiltInFunction(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
 	// custom error
-	if b.Err != nil {
-		return nil, b.Err
+	err := b.hookCallError(ProcessBuiltInFunctionHookCall)
+	if err != nil {
+		return nil, err
 	}
 
 	if b.BuiltinFuncs == nil {
@@ -186,8 +205,9 @@ func (b *MockWorld) ProcessBuiltInFunction(input *vmcommon.ContractCallInput) (*
 // GetESDTToken -
 func (b *MockWorld) GetESDTToken(address []byte, tokenIdentifier []byte, nonce uint64) (*esdt.ESDigitalToken, error) {
 	// custom error
-	if b.Err != nil {
-		return nil, b.Err
+	err := b.hookCallError(GetESDTTokenHookCall)
+	if err != nil {
+		return nil, err
 	}
 
 	if b.BuiltinFuncs == nil {
@@ -214,8 +234,9 @@ func (b *MockWorld) GetAllState(accountAddress []byte) (map[string][]byte, error
 // GetUserAccount retrieves account info from map, or error if not found.
 func (b *MockWorld) GetUserAccount(address []byte) (vmcommon.UserAccountHandler, error) {
 	// custom error
-	if b.Err != nil {
-		return nil, b.Err
+	err := b.hookCallError(GetUserAccountHookCall)
+	if err != nil {
+		return nil, err
 	}
 
 	account := b.AcctMap.GetAccount(address)
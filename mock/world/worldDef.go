@@ -49,21 +49,28 @@ type MockWorld struct {
 	IsPausedValue              bool
 	IsLimitedTransferValue     bool
 	ProvidedBlockchainHook     vmcommon.BlockchainHook
+	SystemSCs                  map[string]*SystemSCMock
+	HookFaults                 map[HookCall]*HookFault
+	GuardianCoSignatures       map[string]*GuardianCoSignature
+
+	invariants []namedInvariant
 }
 
 // NewMockWorld creates a new MockWorld instance
 func NewMockWorld() *MockWorld {
 	accountMap := NewAccountMap()
 	world := &MockWorld{
-		SelfShardID:       0,
-		AcctMap:           accountMap,
-		AccountsAdapter:   nil,
-		PreviousBlockInfo: nil,
-		CurrentBlockInfo:  nil,
-		Blockhashes:       nil,
-		NewAddressMocks:   nil,
-		CompiledCode:      make(map[string][]byte),
-		BuiltinFuncs:      nil,
+		SelfShardID:          0,
+		AcctMap:              accountMap,
+		AccountsAdapter:      nil,
+		PreviousBlockInfo:    nil,
+		CurrentBlockInfo:     nil,
+		Blockhashes:          nil,
+		NewAddressMocks:      nil,
+		CompiledCode:         make(map[string][]byte),
+		BuiltinFuncs:         nil,
+		SystemSCs:            make(map[string]*SystemSCMock),
+		GuardianCoSignatures: make(map[string]*GuardianCoSignature),
 	}
 	world.AccountsAdapter = NewMockAccountsAdapter(world)
 
@@ -96,6 +103,7 @@ func (b *MockWorld) Clear() {
 	b.Blockhashes = nil
 	b.NewAddressMocks = nil
 	b.CompiledCode = make(map[string][]byte)
+	b.SystemSCs = make(map[string]*SystemSCMock)
 }
 
 // SetCurrentBlockHash -
@@ -106,6 +114,30 @@ func (b *MockWorld) SetCurrentBlockHash(blockHash []byte) {
 	b.Blockhashes = [][]byte{blockHash}
 }
 
+// AdvanceBlocks moves the mock to a later block: the current block becomes the
+// previous one, and the new current block has the nonce, round, epoch and
+// timestamp of the old one incremented by those of the given increments. The
+// random seed is kept, unless the increments hold one. The block hashes are
+// kept as well, so they are relative to the new current nonce.
+func (b *MockWorld) AdvanceBlocks(increments BlockInfo) {
+	current := BlockInfo{}
+	if b.CurrentBlockInfo != nil {
+		current = *b.CurrentBlockInfo
+	}
+
+	previous := current
+	b.PreviousBlockInfo = &previous
+
+	current.BlockNonce += increments.BlockNonce
+	current.BlockRound += increments.BlockRound
+	current.BlockEpoch += increments.BlockEpoch
+	current.BlockTimestamp += increments.BlockTimestamp
+	if increments.RandomSeed != nil {
+		current.RandomSeed = increments.RandomSeed
+	}
+	b.CurrentBlockInfo = &current
+}
+
 // NumberOfShards -
 func (b *MockWorld) NumberOfShards() uint32 {
 	maxShardID := uint32(0)
@@ -0,0 +1,89 @@
+package worldmock
+
+import "errors"
+
+// HookCall identifies a blockchain hook call of the MockWorld on which a fault can be injected
+type HookCall string
+
+const (
+	// NewAddressHookCall identifies NewAddress
+	NewAddressHookCall HookCall = "NewAddress"
+
+	// GetStorageDataHookCall identifies GetStorageData
+	GetStorageDataHookCall HookCall = "GetStorageData"
+
+	// GetBlockhashHookCall identifies GetBlockhash
+	GetBlockhashHookCall HookCall = "GetBlockhash"
+
+	// GetUserAccountHookCall identifies GetUserAccount
+	GetUserAccountHookCall HookCall = "GetUserAccount"
+
+	// ProcessBuiltInFunctionHookCall identifies ProcessBuiltInFunction
+	ProcessBuiltInFunctionHookCall HookCall = "ProcessBuiltInFunction"
+
+	// GetESDTTokenHookCall identifies GetESDTToken
+	GetESDTTokenHookCall HookCall = "GetESDTToken"
+)
+
+// ErrInjectedHookFault is the default error returned by the blockchain hook calls with an injected fault
+var ErrInjectedHookFault = errors.New("injected blockchain hook fault")
+
+// HookFault makes a blockchain hook call of the MockWorld fail with Err
+type HookFault struct {
+	// AtCall is the 1-based index of the failing call, counted from the
+	// injection of the fault; 0 makes all the calls fail
+	AtCall uint64
+	Err    error
+
+	numCalls uint64
+}
+
+// InjectHookFault makes the AtCall-th call of the given blockchain hook fail
+// with err, or with ErrInjectedHookFault if err is nil.
+func (b *MockWorld) InjectHookFault(hookCall HookCall, atCall uint64, err error) {
+	if err == nil {
+		err = ErrInjectedHookFault
+	}
+	if b.HookFaults == nil {
+		b.HookFaults = make(map[HookCall]*HookFault)
+	}
+
+	b.HookFaults[hookCall] = &HookFault{
+		AtCall: atCall,
+		Err:    err,
+	}
+}
+
+// ClearHookFaults removes all the injected blockchain hook faults
+func (b *MockWorld) ClearHookFaults() {
+	b.HookFaults = nil
+}
+
+// NumHookCalls returns how many times the given blockchain hook was called
+// since a fault was injected on it
+func (b *MockWorld) NumHookCalls(hookCall HookCall) uint64 {
+	fault, ok := b.HookFaults[hookCall]
+	if !ok {
+		return 0
+	}
+	return fault.numCalls
+}
+
+// hookCallError returns the custom error of the MockWorld, or the error of
+// the fault injected on the given blockchain hook, if this call must fail
+func (b *MockWorld) hookCallError(hookCall HookCall) error {
+	if b.Err != nil {
+		return b.Err
+	}
+
+	fault, ok := b.HookFaults[hookCall]
+	if !ok {
+		return nil
+	}
+
+	fault.numCalls++
+	if fault.AtCall == 0 || fault.AtCall == fault.numCalls {
+		return fault.Err
+	}
+	return nil
+}
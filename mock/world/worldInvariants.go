@@ -0,0 +1,55 @@
+package worldmock
+
+import "fmt"
+
+// WorldInvariant is a property of the MockWorld which must hold after every
+// scenario step and every fuzz iteration; it returns an error describing the
+// violation, or nil
+type WorldInvariant func(world *MockWorld) error
+
+type namedInvariant struct {
+	name  string
+	check WorldInvariant
+}
+
+// InvariantViolation is the error returned by CheckInvariants for the first
+// invariant which does not hold
+type InvariantViolation struct {
+	Name string
+	Err  error
+}
+
+// Error returns the name of the invariant and the description of the violation
+func (violation *InvariantViolation) Error() string {
+	return fmt.Sprintf("invariant %s violated: %v", violation.Name, violation.Err)
+}
+
+// Unwrap returns the error returned by the invariant
+func (violation *InvariantViolation) Unwrap() error {
+	return violation.Err
+}
+
+// AddInvariant registers an invariant of the world; the invariants are kept
+// when the world is cleared
+func (b *MockWorld) AddInvariant(name string, invariant WorldInvariant) {
+	b.invariants = append(b.invariants, namedInvariant{
+		name:  name,
+		check: invariant,
+	})
+}
+
+// CheckInvariants checks the registered invariants, in the order of their
+// registration, and returns an *InvariantViolation for the first one which
+// does not hold
+func (b *MockWorld) CheckInvariants() error {
+	for _, invariant := range b.invariants {
+		err := invariant.check(b)
+		if err != nil {
+			return &InvariantViolation{
+				Name: invariant.name,
+				Err:  err,
+			}
+		}
+	}
+	return nil
+}
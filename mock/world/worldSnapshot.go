@@ -0,0 +1,216 @@
+package worldmock
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io/ioutil"
+	"math/big"
+	"sort"
+)
+
+// ErrInvalidSnapshot signals that a snapshot cannot be imported into the world
+var ErrInvalidSnapshot = errors.New("invalid account snapshot")
+
+// AccountSnapshot is the portable form of an account, its code and its full
+// storage. The byte fields are hex encoded and the balances are in decimal,
+// so that the storage pairs have the same form as those returned by the
+// proxy API of a node, from which a snapshot can be assembled.
+type AccountSnapshot struct {
+	Address         string            `json:"address"`
+	Nonce           uint64            `json:"nonce"`
+	Balance         string            `json:"balance"`
+	Code            string            `json:"code,omitempty"`
+	CodeHash        string            `json:"codeHash,omitempty"`
+	CodeMetadata    string            `json:"codeMetadata,omitempty"`
+	OwnerAddress    string            `json:"ownerAddress,omitempty"`
+	Username        string            `json:"username,omitempty"`
+	DeveloperReward string            `json:"developerReward,omitempty"`
+	Storage         map[string]string `json:"storage"`
+}
+
+// WorldSnapshot holds the snapshots of a set of accounts, in ascending order
+// of their addresses
+type WorldSnapshot struct {
+	Accounts []*AccountSnapshot `json:"accounts"`
+}
+
+// ExportAccounts captures the given accounts, or all the accounts of the
+// world if none is given; empty storage values are left out, as absent keys
+func (b *MockWorld) ExportAccounts(addresses ...[]byte) (*WorldSnapshot, error) {
+	if len(addresses) == 0 {
+		for address := range b.AcctMap {
+			addresses = append(addresses, []byte(address))
+		}
+	}
+	sort.Slice(addresses, func(i, j int) bool {
+		return string(addresses[i]) < string(addresses[j])
+	})
+
+	snapshot := &WorldSnapshot{
+		Accounts: make([]*AccountSnapshot, 0, len(addresses)),
+	}
+	for _, address := range addresses {
+		account := b.AcctMap.GetAccount(address)
+		if account == nil {
+			return nil, fmt.Errorf("%w: account %s not found", ErrInvalidSnapshot, hex.EncodeToString(address))
+		}
+		snapshot.Accounts = append(snapshot.Accounts, newAccountSnapshot(account))
+	}
+	return snapshot, nil
+}
+
+// ImportAccounts creates the accounts of the snapshot in the world, replacing
+// the existing accounts with the same addresses
+func (b *MockWorld) ImportAccounts(snapshot *WorldSnapshot) error {
+	accounts := make([]*Account, 0, len(snapshot.Accounts))
+	for _, accountSnapshot := range snapshot.Accounts {
+		account, err := accountSnapshot.toAccount(b)
+		if err != nil {
+			return err
+		}
+		accounts = append(accounts, account)
+	}
+
+	for _, account := range accounts {
+		b.AcctMap.PutAccount(account)
+	}
+	return nil
+}
+
+// ExportAccountsToFile writes the snapshot of the given accounts, or of all
+// the accounts of the world, to a JSON file
+func (b *MockWorld) ExportAccountsToFile(path string, addresses ...[]byte) error {
+	snapshot, err := b.ExportAccounts(addresses...)
+	if err != nil {
+		return err
+	}
+
+	serialized, err := json.MarshalIndent(snapshot, "", "    ")
+	if err != nil {
+		return err
+	}
+	return ioutil.WriteFile(path, serialized, 0644)
+}
+
+// ImportAccountsFromFile imports the accounts of a JSON snapshot file
+func (b *MockWorld) ImportAccountsFromFile(path string) error {
+	serialized, err := ioutil.ReadFile(path)
+	if err != nil {
+		return err
+	}
+
+	snapshot := &WorldSnapshot{}
+	err = json.Unmarshal(serialized, snapshot)
+	if err != nil {
+		return fmt.Errorf("%w: %s", ErrInvalidSnapshot, err.Error())
+	}
+	return b.ImportAccounts(snapshot)
+}
+
+func newAccountSnapshot(account *Account) *AccountSnapshot {
+	accountSnapshot := &AccountSnapshot{
+		Address:      hex.EncodeToString(account.Address),
+		Nonce:        account.Nonce,
+		Balance:      bigIntString(account.Balance),
+		Code:         hex.EncodeToString(account.Code),
+		CodeHash:     hex.EncodeToString(account.CodeHash),
+		CodeMetadata: hex.EncodeToString(account.CodeMetadata),
+		OwnerAddress: hex.EncodeToString(account.OwnerAddress),
+		Username:     hex.EncodeToString(account.Username),
+		Storage:      make(map[string]string),
+	}
+	if account.DeveloperReward != nil && account.DeveloperReward.Sign() != 0 {
+		accountSnapshot.DeveloperReward = account.DeveloperReward.String()
+	}
+
+	for key, value := range account.Storage {
+		if len(value) > 0 {
+			accountSnapshot.Storage[hex.EncodeToString([]byte(key))] = hex.EncodeToString(value)
+		}
+	}
+	return accountSnapshot
+}
+
+func (accountSnapshot *AccountSnapshot) toAccount(world *MockWorld) (*Account, error) {
+	decoded := make(map[string][]byte)
+	fields := map[string]string{
+		"address":      accountSnapshot.Address,
+		"code":         accountSnapshot.Code,
+		"codeHash":     accountSnapshot.CodeHash,
+		"codeMetadata": accountSnapshot.CodeMetadata,
+		"ownerAddress": accountSnapshot.OwnerAddress,
+		"username":     accountSnapshot.Username,
+	}
+	for name, field := range fields {
+		value, err := hex.DecodeString(field)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %s of account %s is not hex", ErrInvalidSnapshot, name, accountSnapshot.Address)
+		}
+		decoded[name] = value
+	}
+	if len(decoded["address"]) == 0 {
+		return nil, fmt.Errorf("%w: missing address", ErrInvalidSnapshot)
+	}
+
+	balance, err := parseBigInt(accountSnapshot.Balance)
+	if err != nil {
+		return nil, fmt.Errorf("%w: balance of account %s", ErrInvalidSnapshot, accountSnapshot.Address)
+	}
+	developerReward, err := parseBigInt(accountSnapshot.DeveloperReward)
+	if err != nil {
+		return nil, fmt.Errorf("%w: developer reward of account %s", ErrInvalidSnapshot, accountSnapshot.Address)
+	}
+
+	account := &Account{
+		Exists:          true,
+		Address:         decoded["address"],
+		Nonce:           accountSnapshot.Nonce,
+		Balance:         balance,
+		BalanceDelta:    big.NewInt(0),
+		Storage:         make(map[string][]byte),
+		OwnerAddress:    decoded["ownerAddress"],
+		Username:        decoded["username"],
+		DeveloperReward: developerReward,
+		ShardID:         world.GetShardOfAddress(decoded["address"]),
+		MockWorld:       world,
+	}
+	if len(decoded["code"]) > 0 {
+		account.Code = decoded["code"]
+		account.CodeHash = decoded["codeHash"]
+		account.CodeMetadata = decoded["codeMetadata"]
+		account.IsSmartContract = true
+	}
+
+	for key, value := range accountSnapshot.Storage {
+		decodedKey, err := hex.DecodeString(key)
+		if err != nil {
+			return nil, fmt.Errorf("%w: storage key %s of account %s is not hex", ErrInvalidSnapshot, key, accountSnapshot.Address)
+		}
+		decodedValue, err := hex.DecodeString(value)
+		if err != nil {
+			return nil, fmt.Errorf("%w: storage value of key %s of account %s is not hex", ErrInvalidSnapshot, key, accountSnapshot.Address)
+		}
+		account.Storage[string(decodedKey)] = decodedValue
+	}
+	return account, nil
+}
+
+func bigIntString(value *big.Int) string {
+	if value == nil {
+		return "0"
+	}
+	return value.String()
+}
+
+func parseBigInt(value string) (*big.Int, error) {
+	if len(value) == 0 {
+		return big.NewInt(0), nil
+	}
+	parsed, ok := big.NewInt(0).SetString(value, 10)
+	if !ok {
+		return nil, ErrInvalidSnapshot
+	}
+	return parsed, nil
+}
@@ -0,0 +1,67 @@
+package worldmock
+
+import (
+	"encoding/binary"
+	"sort"
+
+	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
+)
+
+// ComputeStateHash returns a hash of the state of all the accounts of the
+// world: their nonces, balances, code, owners and storage. Two worlds have
+// the same state hash only if they hold the same accounts in the same state,
+// regardless of the order in which they were updated. Empty storage values
+// are treated as absent keys.
+func (b *MockWorld) ComputeStateHash() []byte {
+	addresses := make([]string, 0, len(b.AcctMap))
+	for address := range b.AcctMap {
+		addresses = append(addresses, address)
+	}
+	sort.Strings(addresses)
+
+	encoded := make([]byte, 0)
+	for _, address := range addresses {
+		encoded = appendAccountState(encoded, b.AcctMap[address])
+	}
+
+	hash, _ := hashing.NewHasher().Sha256(encoded)
+	return hash
+}
+
+func appendAccountState(encoded []byte, account *Account) []byte {
+	encoded = appendLengthPrefixed(encoded, account.Address)
+	encoded = appendUint64(encoded, account.Nonce)
+	if account.Balance != nil {
+		encoded = appendLengthPrefixed(encoded, account.Balance.Bytes())
+	} else {
+		encoded = appendLengthPrefixed(encoded, nil)
+	}
+	encoded = appendLengthPrefixed(encoded, account.Code)
+	encoded = appendLengthPrefixed(encoded, account.OwnerAddress)
+
+	keys := make([]string, 0, len(account.Storage))
+	for key, value := range account.Storage {
+		if len(value) > 0 {
+			keys = append(keys, key)
+		}
+	}
+	sort.Strings(keys)
+
+	encoded = appendUint64(encoded, uint64(len(keys)))
+	for _, key := range keys {
+		encoded = appendLengthPrefixed(encoded, []byte(key))
+		encoded = appendLengthPrefixed(encoded, account.Storage[key])
+	}
+	return encoded
+}
+
+func appendUint64(encoded []byte, value uint64) []byte {
+	var buffer [8]byte
+	binary.BigEndian.PutUint64(buffer[:], value)
+	return append(encoded, buffer[:]...)
+}
+
+func appendLengthPrefixed(encoded []byte, field []byte) []byte {
+	encoded = appendUint64(encoded, uint64(len(field)))
+	return append(encoded, field...)
+}
@@ -27,6 +27,19 @@ func (b *MockWorld) UpdateBalanceWithDelta(address []byte, balanceDelta *big.Int
 	return nil
 }
 
+// AddToDeveloperReward increases the developer reward of a contract
+func (b *MockWorld) AddToDeveloperReward(address []byte, value *big.Int) error {
+	acct := b.AcctMap.GetAccount(address)
+	if acct == nil {
+		return errors.New("method AddToDeveloperReward expects an existing address")
+	}
+	if acct.DeveloperReward == nil {
+		acct.DeveloperReward = big.NewInt(0)
+	}
+	acct.AddToDeveloperReward(value)
+	return nil
+}
+
 // UpdateWorldStateBefore performs gas payment, before transaction
 func (b *MockWorld) UpdateWorldStateBefore(
 	fromAddr []byte,
@@ -113,3 +126,50 @@ func (b *MockWorld) CommitChanges() error {
 func (b *MockWorld) RollbackChanges() error {
 	return b.AccountsAdapter.RevertToSnapshot(0)
 }
+
+// ApplyVMOutput updates the world state from the output of a transaction and
+// returns the current state root hash. The output is checked before any
+// account is touched, so that an output which cannot be applied whole leaves
+// the world unchanged.
+func (b *MockWorld) ApplyVMOutput(vmOutput *vmcommon.VMOutput) ([]byte, error) {
+	err := b.checkOutputAccounts(vmOutput.OutputAccounts)
+	if err != nil {
+		return nil, err
+	}
+
+	err = b.UpdateAccounts(vmOutput.OutputAccounts, vmOutput.DeletedAccounts)
+	if err != nil {
+		return nil, err
+	}
+	return b.StateRootHash, nil
+}
+
+func (b *MockWorld) checkOutputAccounts(outputAccounts map[string]*vmcommon.OutputAccount) error {
+	for _, modAcct := range outputAccounts {
+		newBalance := modAcct.Balance
+		if modAcct.BalanceDelta != nil {
+			newBalance = big.NewInt(0).Set(modAcct.BalanceDelta)
+			acct := b.AcctMap.GetAccount(modAcct.Address)
+			if acct != nil && acct.Balance != nil {
+				newBalance.Add(newBalance, acct.Balance)
+			}
+		}
+		if newBalance != nil && newBalance.Sign() < 0 {
+			return errors.New("method ApplyVMOutput would leave a negative balance")
+		}
+	}
+	return nil
+}
+
+// SetCurrentBlockInfo replaces the info of the current block
+func (b *MockWorld) SetCurrentBlockInfo(nonce uint64, round uint64, epoch uint32, timeStamp uint64, randomSeed []byte) {
+	var seed [48]byte
+	copy(seed[:], randomSeed)
+	b.CurrentBlockInfo = &BlockInfo{
+		BlockTimestamp: timeStamp,
+		BlockNonce:     nonce,
+		BlockRound:     round,
+		BlockEpoch:     epoch,
+		RandomSeed:     &seed,
+	}
+}
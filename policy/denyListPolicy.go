@@ -0,0 +1,108 @@
+package policy
+
+import (
+	"sync"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.ExecutionPolicy = (*DenyListPolicy)(nil)
+
+// CodeHasher computes the code hash of the deployed code, the same way the
+// code hashes of the accounts are computed
+type CodeHasher interface {
+	Compute(s string) []byte
+	IsInterfaceNil() bool
+}
+
+// DenyListPolicy is an ExecutionPolicy which denies the contracts whose
+// address or code hash was added to it. The lists can be changed while the
+// VM is running, so that a compromised contract can be frozen at once; a
+// denied code hash also prevents the deployment of the same code elsewhere.
+type DenyListPolicy struct {
+	mutLists         sync.RWMutex
+	deniedAddresses  map[string]struct{}
+	deniedCodeHashes map[string]struct{}
+	hasher           CodeHasher
+}
+
+// NewDenyListPolicy creates a new DenyListPolicy, which denies nothing yet
+func NewDenyListPolicy(hasher CodeHasher) (*DenyListPolicy, error) {
+	if check.IfNil(hasher) {
+		return nil, ErrNilCodeHasher
+	}
+
+	return &DenyListPolicy{
+		deniedAddresses:  make(map[string]struct{}),
+		deniedCodeHashes: make(map[string]struct{}),
+		hasher:           hasher,
+	}, nil
+}
+
+// DenyAddress denies the execution of the contract at the given address and
+// the deployment of code at that address
+func (policy *DenyListPolicy) DenyAddress(address []byte) {
+	policy.mutLists.Lock()
+	policy.deniedAddresses[string(address)] = struct{}{}
+	policy.mutLists.Unlock()
+}
+
+// AllowAddress removes the address from the deny list
+func (policy *DenyListPolicy) AllowAddress(address []byte) {
+	policy.mutLists.Lock()
+	delete(policy.deniedAddresses, string(address))
+	policy.mutLists.Unlock()
+}
+
+// DenyCodeHash denies the execution and the deployment of the code with the given hash
+func (policy *DenyListPolicy) DenyCodeHash(codeHash []byte) {
+	policy.mutLists.Lock()
+	policy.deniedCodeHashes[string(codeHash)] = struct{}{}
+	policy.mutLists.Unlock()
+}
+
+// AllowCodeHash removes the code hash from the deny list
+func (policy *DenyListPolicy) AllowCodeHash(codeHash []byte) {
+	policy.mutLists.Lock()
+	delete(policy.deniedCodeHashes, string(codeHash))
+	policy.mutLists.Unlock()
+}
+
+// IsExecutionAllowed returns false if either the address or the code hash of
+// the contract is denied
+func (policy *DenyListPolicy) IsExecutionAllowed(address []byte, codeHash []byte) bool {
+	policy.mutLists.RLock()
+	defer policy.mutLists.RUnlock()
+
+	return !policy.isDenied(address, codeHash)
+}
+
+// IsDeploymentAllowed returns false if either the address or the hash of the
+// code to be deployed is denied
+func (policy *DenyListPolicy) IsDeploymentAllowed(address []byte, code []byte) bool {
+	codeHash := policy.hasher.Compute(string(code))
+
+	policy.mutLists.RLock()
+	defer policy.mutLists.RUnlock()
+
+	return !policy.isDenied(address, codeHash)
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (policy *DenyListPolicy) IsInterfaceNil() bool {
+	return policy == nil
+}
+
+func (policy *DenyListPolicy) isDenied(address []byte, codeHash []byte) bool {
+	_, addressDenied := policy.deniedAddresses[string(address)]
+	if addressDenied {
+		return true
+	}
+	if len(codeHash) == 0 {
+		return false
+	}
+
+	_, codeHashDenied := policy.deniedCodeHashes[string(codeHash)]
+	return codeHashDenied
+}
@@ -0,0 +1,60 @@
+package policy
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/hashing/blake2b"
+	"github.com/stretchr/testify/require"
+)
+
+func TestNewDenyListPolicy_NilHasher(t *testing.T) {
+	denyList, err := NewDenyListPolicy(nil)
+	require.Nil(t, denyList)
+	require.Equal(t, ErrNilCodeHasher, err)
+}
+
+func TestDenyListPolicy_DenyAddress(t *testing.T) {
+	denyList, err := NewDenyListPolicy(blake2b.NewBlake2b())
+	require.Nil(t, err)
+
+	address := []byte("contract")
+	require.True(t, denyList.IsExecutionAllowed(address, []byte("hash")))
+	require.True(t, denyList.IsDeploymentAllowed(address, []byte("code")))
+
+	denyList.DenyAddress(address)
+	require.False(t, denyList.IsExecutionAllowed(address, []byte("hash")))
+	require.False(t, denyList.IsExecutionAllowed(address, nil))
+	require.False(t, denyList.IsDeploymentAllowed(address, []byte("code")))
+	require.True(t, denyList.IsExecutionAllowed([]byte("other contract"), []byte("hash")))
+
+	denyList.AllowAddress(address)
+	require.True(t, denyList.IsExecutionAllowed(address, []byte("hash")))
+	require.True(t, denyList.IsDeploymentAllowed(address, []byte("code")))
+}
+
+func TestDenyListPolicy_DenyCodeHash(t *testing.T) {
+	hasher := blake2b.NewBlake2b()
+	denyList, err := NewDenyListPolicy(hasher)
+	require.Nil(t, err)
+
+	code := []byte("code")
+	codeHash := hasher.Compute(string(code))
+	denyList.DenyCodeHash(codeHash)
+
+	require.False(t, denyList.IsExecutionAllowed([]byte("contract"), codeHash))
+	require.False(t, denyList.IsDeploymentAllowed([]byte("contract"), code))
+	require.True(t, denyList.IsExecutionAllowed([]byte("contract"), []byte("other hash")))
+	require.True(t, denyList.IsExecutionAllowed([]byte("contract"), nil))
+	require.True(t, denyList.IsDeploymentAllowed([]byte("contract"), []byte("other code")))
+
+	denyList.AllowCodeHash(codeHash)
+	require.True(t, denyList.IsExecutionAllowed([]byte("contract"), codeHash))
+	require.True(t, denyList.IsDeploymentAllowed([]byte("contract"), code))
+}
+
+func TestDisabledPolicy_AllowsEverything(t *testing.T) {
+	disabled := NewDisabledPolicy()
+	require.False(t, disabled.IsInterfaceNil())
+	require.True(t, disabled.IsExecutionAllowed([]byte("contract"), []byte("hash")))
+	require.True(t, disabled.IsDeploymentAllowed([]byte("contract"), []byte("code")))
+}
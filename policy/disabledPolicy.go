@@ -0,0 +1,30 @@
+package policy
+
+import "github.com/ElrondNetwork/wasm-vm/arwen"
+
+var _ arwen.ExecutionPolicy = (*DisabledPolicy)(nil)
+
+// DisabledPolicy is the ExecutionPolicy used when the embedder does not deny
+// any contract; it allows every execution and deployment
+type DisabledPolicy struct {
+}
+
+// NewDisabledPolicy creates a new DisabledPolicy
+func NewDisabledPolicy() *DisabledPolicy {
+	return &DisabledPolicy{}
+}
+
+// IsExecutionAllowed returns true
+func (policy *DisabledPolicy) IsExecutionAllowed(_ []byte, _ []byte) bool {
+	return true
+}
+
+// IsDeploymentAllowed returns true
+func (policy *DisabledPolicy) IsDeploymentAllowed(_ []byte, _ []byte) bool {
+	return true
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (policy *DisabledPolicy) IsInterfaceNil() bool {
+	return policy == nil
+}
@@ -0,0 +1,6 @@
+package policy
+
+import "errors"
+
+// ErrNilCodeHasher signals that a nil code hasher has been provided
+var ErrNilCodeHasher = errors.New("nil code hasher")
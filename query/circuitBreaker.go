@@ -0,0 +1,201 @@
+package query
+
+import (
+	"crypto/sha256"
+	"errors"
+	"sync"
+	"time"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// ErrInvalidFailureTTL signals that the failures would not be cached for any time
+var ErrInvalidFailureTTL = errors.New("invalid failure TTL")
+
+// ErrInvalidMaxCachedFailures signals that no failures could be cached
+var ErrInvalidMaxCachedFailures = errors.New("invalid max cached failures")
+
+// CircuitBreakerConfig holds the settings of a CircuitBreaker
+type CircuitBreakerConfig struct {
+	// FailureTTL is how long a failed query is answered from the cache
+	FailureTTL time.Duration
+	// MaxCachedFailures bounds the number of failed queries remembered; when
+	// it is reached, the failure closest to expiring is forgotten first
+	MaxCachedFailures int
+}
+
+type failureKey struct {
+	contract     string
+	function     string
+	argumentHash [sha256.Size]byte
+}
+
+type cachedFailure struct {
+	vmOutput  *vmcommon.VMOutput
+	err       error
+	expiresAt time.Time
+}
+
+// CircuitBreaker runs the smart contract queries of gateways on the VM host,
+// answering the repeated identical queries which recently failed from a cache
+// of their failures instead of executing them again. Identical queries call
+// the same function of the same contract with the same arguments; the
+// failures caused by running out of gas are not cached, because they depend
+// on the gas provided to the query and not on its arguments.
+type CircuitBreaker struct {
+	host   arwen.VMHost
+	config CircuitBreakerConfig
+	now    func() time.Time
+
+	mutFailures sync.Mutex
+	failures    map[failureKey]*cachedFailure
+}
+
+// NewCircuitBreaker creates a new CircuitBreaker
+func NewCircuitBreaker(host arwen.VMHost, config CircuitBreakerConfig) (*CircuitBreaker, error) {
+	if check.IfNil(host) {
+		return nil, arwen.ErrNilVMHost
+	}
+	if config.FailureTTL <= 0 {
+		return nil, ErrInvalidFailureTTL
+	}
+	if config.MaxCachedFailures <= 0 {
+		return nil, ErrInvalidMaxCachedFailures
+	}
+
+	return &CircuitBreaker{
+		host:     host,
+		config:   config,
+		now:      time.Now,
+		failures: make(map[failureKey]*cachedFailure),
+	}, nil
+}
+
+// RunSmartContractCall executes the query, unless an identical query failed
+// less than the failure TTL ago, in which case the same failure is returned
+func (breaker *CircuitBreaker) RunSmartContractCall(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	key := newFailureKey(input)
+
+	failure, found := breaker.getFailure(key)
+	if found {
+		return copyFailedOutput(failure.vmOutput), failure.err
+	}
+
+	vmOutput, err := breaker.host.RunSmartContractCall(input)
+	if isCacheableFailure(vmOutput, err) {
+		breaker.putFailure(key, &cachedFailure{
+			vmOutput:  copyFailedOutput(vmOutput),
+			err:       err,
+			expiresAt: breaker.now().Add(breaker.config.FailureTTL),
+		})
+	}
+
+	return vmOutput, err
+}
+
+// NumCachedFailures returns the number of failed queries currently remembered
+func (breaker *CircuitBreaker) NumCachedFailures() int {
+	breaker.mutFailures.Lock()
+	defer breaker.mutFailures.Unlock()
+
+	breaker.removeExpiredFailures()
+	return len(breaker.failures)
+}
+
+// Reset forgets all the failed queries, for instance after a new block
+// changed the state on which they failed
+func (breaker *CircuitBreaker) Reset() {
+	breaker.mutFailures.Lock()
+	breaker.failures = make(map[failureKey]*cachedFailure)
+	breaker.mutFailures.Unlock()
+}
+
+func (breaker *CircuitBreaker) getFailure(key failureKey) (*cachedFailure, bool) {
+	breaker.mutFailures.Lock()
+	defer breaker.mutFailures.Unlock()
+
+	failure, found := breaker.failures[key]
+	if !found {
+		return nil, false
+	}
+	if !breaker.now().Before(failure.expiresAt) {
+		delete(breaker.failures, key)
+		return nil, false
+	}
+
+	return failure, true
+}
+
+func (breaker *CircuitBreaker) putFailure(key failureKey, failure *cachedFailure) {
+	breaker.mutFailures.Lock()
+	defer breaker.mutFailures.Unlock()
+
+	_, exists := breaker.failures[key]
+	if !exists && len(breaker.failures) >= breaker.config.MaxCachedFailures {
+		breaker.removeExpiredFailures()
+	}
+	if !exists && len(breaker.failures) >= breaker.config.MaxCachedFailures {
+		breaker.removeEarliestFailure()
+	}
+
+	breaker.failures[key] = failure
+}
+
+func (breaker *CircuitBreaker) removeExpiredFailures() {
+	now := breaker.now()
+	for key, failure := range breaker.failures {
+		if !now.Before(failure.expiresAt) {
+			delete(breaker.failures, key)
+		}
+	}
+}
+
+func (breaker *CircuitBreaker) removeEarliestFailure() {
+	var earliestKey failureKey
+	var earliest *cachedFailure
+	for key, failure := range breaker.failures {
+		if earliest == nil || failure.expiresAt.Before(earliest.expiresAt) {
+			earliestKey = key
+			earliest = failure
+		}
+	}
+	if earliest != nil {
+		delete(breaker.failures, earliestKey)
+	}
+}
+
+func newFailureKey(input *vmcommon.ContractCallInput) failureKey {
+	return failureKey{
+		contract:     string(input.RecipientAddr),
+		function:     input.Function,
+		argumentHash: hashArguments(input.Arguments),
+	}
+}
+
+func isCacheableFailure(vmOutput *vmcommon.VMOutput, err error) bool {
+	if err != nil {
+		return true
+	}
+	if vmOutput == nil {
+		return false
+	}
+
+	return vmOutput.ReturnCode != vmcommon.Ok && vmOutput.ReturnCode != vmcommon.OutOfGas
+}
+
+// copyFailedOutput copies the fields describing the failure, which are the
+// only ones a failed query returns, so that the callers cannot alter the
+// cached output
+func copyFailedOutput(vmOutput *vmcommon.VMOutput) *vmcommon.VMOutput {
+	if vmOutput == nil {
+		return nil
+	}
+
+	return &vmcommon.VMOutput{
+		ReturnCode:    vmOutput.ReturnCode,
+		ReturnMessage: vmOutput.ReturnMessage,
+		GasRemaining:  vmOutput.GasRemaining,
+	}
+}
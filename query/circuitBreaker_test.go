@@ -0,0 +1,115 @@
+package query
+
+import (
+	"testing"
+	"time"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	"github.com/stretchr/testify/require"
+)
+
+var contractAddress = []byte("contract________________________")
+
+func makeQuery(function string, arguments ...[]byte) *vmcommon.ContractCallInput {
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			Arguments:   arguments,
+			GasProvided: 1000,
+		},
+		RecipientAddr: contractAddress,
+		Function:      function,
+	}
+}
+
+// newStubHost creates a host on which the queries fail with the return code
+// given in their function name, counting the executed queries
+func newStubHost(executed *int) *contextmock.VMHostStub {
+	return &contextmock.VMHostStub{
+		RunSmartContractCallCalled: func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+			*executed++
+			switch input.Function {
+			case "fail":
+				return &vmcommon.VMOutput{ReturnCode: vmcommon.UserError, ReturnMessage: "query failed"}, nil
+			case "outOfGas":
+				return &vmcommon.VMOutput{ReturnCode: vmcommon.OutOfGas}, nil
+			default:
+				return &vmcommon.VMOutput{ReturnCode: vmcommon.Ok, ReturnData: [][]byte{[]byte("result")}}, nil
+			}
+		},
+	}
+}
+
+func TestNewCircuitBreaker(t *testing.T) {
+	breaker, err := NewCircuitBreaker(nil, CircuitBreakerConfig{FailureTTL: time.Second, MaxCachedFailures: 1})
+	require.Nil(t, breaker)
+	require.Equal(t, arwen.ErrNilVMHost, err)
+
+	executed := 0
+	breaker, err = NewCircuitBreaker(newStubHost(&executed), CircuitBreakerConfig{MaxCachedFailures: 1})
+	require.Nil(t, breaker)
+	require.Equal(t, ErrInvalidFailureTTL, err)
+
+	breaker, err = NewCircuitBreaker(newStubHost(&executed), CircuitBreakerConfig{FailureTTL: time.Second})
+	require.Nil(t, breaker)
+	require.Equal(t, ErrInvalidMaxCachedFailures, err)
+}
+
+func TestCircuitBreaker_ShortCircuitsRepeatedFailures(t *testing.T) {
+	executed := 0
+	breaker, err := NewCircuitBreaker(newStubHost(&executed), CircuitBreakerConfig{FailureTTL: time.Minute, MaxCachedFailures: 10})
+	require.Nil(t, err)
+	now := time.Unix(1000, 0)
+	breaker.now = func() time.Time { return now }
+
+	for i := 0; i < 3; i++ {
+		vmOutput, err := breaker.RunSmartContractCall(makeQuery("fail", []byte{1}))
+		require.Nil(t, err)
+		require.Equal(t, vmcommon.UserError, vmOutput.ReturnCode)
+		require.Equal(t, "query failed", vmOutput.ReturnMessage)
+	}
+	require.Equal(t, 1, executed)
+	require.Equal(t, 1, breaker.NumCachedFailures())
+
+	// other arguments, or the same bytes split differently, are other queries
+	_, _ = breaker.RunSmartContractCall(makeQuery("fail", []byte{2}))
+	_, _ = breaker.RunSmartContractCall(makeQuery("fail", []byte{1}, []byte{}))
+	require.Equal(t, 3, executed)
+
+	// neither the successful queries nor those out of gas are cached
+	for i := 0; i < 2; i++ {
+		_, _ = breaker.RunSmartContractCall(makeQuery("get", []byte{1}))
+		_, _ = breaker.RunSmartContractCall(makeQuery("outOfGas", []byte{1}))
+	}
+	require.Equal(t, 7, executed)
+	require.Equal(t, 3, breaker.NumCachedFailures())
+
+	now = now.Add(time.Minute)
+	_, _ = breaker.RunSmartContractCall(makeQuery("fail", []byte{1}))
+	require.Equal(t, 8, executed)
+	require.Equal(t, 1, breaker.NumCachedFailures())
+
+	breaker.Reset()
+	require.Equal(t, 0, breaker.NumCachedFailures())
+}
+
+func TestCircuitBreaker_MaxCachedFailures(t *testing.T) {
+	executed := 0
+	breaker, err := NewCircuitBreaker(newStubHost(&executed), CircuitBreakerConfig{FailureTTL: time.Minute, MaxCachedFailures: 2})
+	require.Nil(t, err)
+	now := time.Unix(1000, 0)
+	breaker.now = func() time.Time { return now }
+
+	for i := byte(0); i < 3; i++ {
+		_, _ = breaker.RunSmartContractCall(makeQuery("fail", []byte{i}))
+		now = now.Add(time.Second)
+	}
+	require.Equal(t, 2, breaker.NumCachedFailures())
+
+	// the earliest failure was forgotten, the latest ones are still cached
+	_, _ = breaker.RunSmartContractCall(makeQuery("fail", []byte{2}))
+	require.Equal(t, 3, executed)
+	_, _ = breaker.RunSmartContractCall(makeQuery("fail", []byte{0}))
+	require.Equal(t, 4, executed)
+}
@@ -0,0 +1,23 @@
+package query
+
+import (
+	"crypto/sha256"
+	"encoding/binary"
+)
+
+// hashArguments hashes the arguments of a query; each argument is preceded by
+// its length, so that different splits of the same bytes into arguments are
+// different queries
+func hashArguments(arguments [][]byte) [sha256.Size]byte {
+	hasher := sha256.New()
+	lengthBytes := make([]byte, 8)
+	for _, argument := range arguments {
+		binary.BigEndian.PutUint64(lengthBytes, uint64(len(argument)))
+		_, _ = hasher.Write(lengthBytes)
+		_, _ = hasher.Write(argument)
+	}
+
+	var hash [sha256.Size]byte
+	copy(hash[:], hasher.Sum(nil))
+	return hash
+}
@@ -0,0 +1,245 @@
+package query
+
+import (
+	"container/list"
+	"crypto/sha256"
+	"errors"
+	"sync"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// ErrNilAccounts signals that a nil AccountsReader was provided
+var ErrNilAccounts = errors.New("nil accounts reader")
+
+// ErrNilStateVersionHandler signals that a nil StateVersionHandler was provided
+var ErrNilStateVersionHandler = errors.New("nil state version handler")
+
+// ErrInvalidMaxCachedResults signals that no results could be cached
+var ErrInvalidMaxCachedResults = errors.New("invalid max cached results")
+
+// AccountsReader reads the accounts of the world state, outside of any execution
+type AccountsReader interface {
+	GetUserAccount(address []byte) (vmcommon.UserAccountHandler, error)
+}
+
+// StateVersionHandler provides the version of the world state on which the
+// queries are executed, such as its root hash; the version changes whenever
+// any account changes
+type StateVersionHandler interface {
+	GetStateVersion() []byte
+}
+
+// ArgsNewResultCache holds the arguments needed to create a ResultCache
+type ArgsNewResultCache struct {
+	Host             arwen.VMHost
+	Accounts         AccountsReader
+	StateVersion     StateVersionHandler
+	MaxCachedResults int
+}
+
+type resultKey struct {
+	contract     string
+	codeHash     string
+	caller       string
+	function     string
+	argumentHash [sha256.Size]byte
+	stateVersion string
+}
+
+type cachedResult struct {
+	key           resultKey
+	returnData    [][]byte
+	gasUsed       uint64
+	returnMessage string
+}
+
+// ResultCache runs the read-only queries of gateways on the VM host and
+// caches their successful results, so that the identical queries of hot view
+// functions are answered without executing the contract again. Identical
+// queries call the same function with the same arguments of the same code of
+// the same contract, from the same caller, on the same version of the state;
+// results are only reused while the state version is the current one, and the
+// embedder can also invalidate the results of the accounts it knows changed.
+// Only the return data and the gas are kept, as queries return nothing else.
+type ResultCache struct {
+	host             arwen.VMHost
+	accounts         AccountsReader
+	stateVersion     StateVersionHandler
+	maxCachedResults int
+
+	mutResults          sync.Mutex
+	currentStateVersion string
+	results             map[resultKey]*list.Element
+	evictionOrder       *list.List
+}
+
+// NewResultCache creates a new ResultCache
+func NewResultCache(args ArgsNewResultCache) (*ResultCache, error) {
+	if check.IfNil(args.Host) {
+		return nil, arwen.ErrNilVMHost
+	}
+	if args.Accounts == nil {
+		return nil, ErrNilAccounts
+	}
+	if args.StateVersion == nil {
+		return nil, ErrNilStateVersionHandler
+	}
+	if args.MaxCachedResults <= 0 {
+		return nil, ErrInvalidMaxCachedResults
+	}
+
+	return &ResultCache{
+		host:             args.Host,
+		accounts:         args.Accounts,
+		stateVersion:     args.StateVersion,
+		maxCachedResults: args.MaxCachedResults,
+		results:          make(map[resultKey]*list.Element),
+		evictionOrder:    list.New(),
+	}, nil
+}
+
+// RunSmartContractCall answers the query from the cache if an identical
+// query succeeded on the current state with no more gas than provided now,
+// and executes it otherwise, caching its result if it succeeds
+func (cache *ResultCache) RunSmartContractCall(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+	key, ok := cache.newResultKey(input)
+	if !ok {
+		return cache.host.RunSmartContractCall(input)
+	}
+
+	result, found := cache.getResult(key)
+	if found && result.gasUsed <= input.GasProvided {
+		return result.toVMOutput(input.GasProvided), nil
+	}
+
+	vmOutput, err := cache.host.RunSmartContractCall(input)
+	if err == nil && vmOutput != nil && vmOutput.ReturnCode == vmcommon.Ok && vmOutput.GasRemaining <= input.GasProvided {
+		cache.putResult(&cachedResult{
+			key:           key,
+			returnData:    copyReturnData(vmOutput.ReturnData),
+			gasUsed:       input.GasProvided - vmOutput.GasRemaining,
+			returnMessage: vmOutput.ReturnMessage,
+		})
+	}
+
+	return vmOutput, err
+}
+
+// InvalidateAccounts forgets the cached results of the queries on the given
+// contracts, for the embedders which know that they changed without a new
+// version of the state
+func (cache *ResultCache) InvalidateAccounts(addresses ...[]byte) {
+	cache.mutResults.Lock()
+	defer cache.mutResults.Unlock()
+
+	invalidated := make(map[string]bool, len(addresses))
+	for _, address := range addresses {
+		invalidated[string(address)] = true
+	}
+	for key, element := range cache.results {
+		if invalidated[key.contract] {
+			cache.evictionOrder.Remove(element)
+			delete(cache.results, key)
+		}
+	}
+}
+
+// NumCachedResults returns the number of results currently cached
+func (cache *ResultCache) NumCachedResults() int {
+	cache.mutResults.Lock()
+	defer cache.mutResults.Unlock()
+
+	return len(cache.results)
+}
+
+// newResultKey identifies the query; a query on an account which cannot be
+// read, or which has no code, is not cached
+func (cache *ResultCache) newResultKey(input *vmcommon.ContractCallInput) (resultKey, bool) {
+	account, err := cache.accounts.GetUserAccount(input.RecipientAddr)
+	if err != nil || check.IfNil(account) || len(account.GetCodeHash()) == 0 {
+		return resultKey{}, false
+	}
+
+	return resultKey{
+		contract:     string(input.RecipientAddr),
+		codeHash:     string(account.GetCodeHash()),
+		caller:       string(input.CallerAddr),
+		function:     input.Function,
+		argumentHash: hashArguments(input.Arguments),
+		stateVersion: string(cache.stateVersion.GetStateVersion()),
+	}, true
+}
+
+func (cache *ResultCache) getResult(key resultKey) (*cachedResult, bool) {
+	cache.mutResults.Lock()
+	defer cache.mutResults.Unlock()
+
+	cache.dropStaleResults(key.stateVersion)
+	element, found := cache.results[key]
+	if !found {
+		return nil, false
+	}
+
+	cache.evictionOrder.MoveToFront(element)
+	return element.Value.(*cachedResult), true
+}
+
+func (cache *ResultCache) putResult(result *cachedResult) {
+	cache.mutResults.Lock()
+	defer cache.mutResults.Unlock()
+
+	// the state may have changed while the query was executed
+	if result.key.stateVersion != cache.currentStateVersion {
+		return
+	}
+
+	element, found := cache.results[result.key]
+	if found {
+		element.Value = result
+		cache.evictionOrder.MoveToFront(element)
+		return
+	}
+
+	if len(cache.results) >= cache.maxCachedResults {
+		leastRecent := cache.evictionOrder.Back()
+		cache.evictionOrder.Remove(leastRecent)
+		delete(cache.results, leastRecent.Value.(*cachedResult).key)
+	}
+	cache.results[result.key] = cache.evictionOrder.PushFront(result)
+}
+
+// dropStaleResults forgets all the results once the state has a new version,
+// since they were computed on an older state
+func (cache *ResultCache) dropStaleResults(stateVersion string) {
+	if stateVersion == cache.currentStateVersion {
+		return
+	}
+
+	cache.currentStateVersion = stateVersion
+	cache.results = make(map[resultKey]*list.Element)
+	cache.evictionOrder.Init()
+}
+
+func (result *cachedResult) toVMOutput(gasProvided uint64) *vmcommon.VMOutput {
+	return &vmcommon.VMOutput{
+		ReturnCode:      vmcommon.Ok,
+		ReturnData:      copyReturnData(result.returnData),
+		ReturnMessage:   result.returnMessage,
+		GasRemaining:    gasProvided - result.gasUsed,
+		OutputAccounts:  make(map[string]*vmcommon.OutputAccount),
+		DeletedAccounts: make([][]byte, 0),
+		TouchedAccounts: make([][]byte, 0),
+		Logs:            make([]*vmcommon.LogEntry, 0),
+	}
+}
+
+func copyReturnData(returnData [][]byte) [][]byte {
+	copied := make([][]byte, len(returnData))
+	for i, data := range returnData {
+		copied[i] = append([]byte{}, data...)
+	}
+	return copied
+}
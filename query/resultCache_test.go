@@ -0,0 +1,169 @@
+package query
+
+import (
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	"github.com/stretchr/testify/require"
+)
+
+type accountsReaderStub struct {
+	codeHash []byte
+}
+
+func (stub *accountsReaderStub) GetUserAccount(address []byte) (vmcommon.UserAccountHandler, error) {
+	return &contextmock.StubAccount{Address: address, CodeHash: stub.codeHash}, nil
+}
+
+type stateVersionStub struct {
+	version []byte
+}
+
+func (stub *stateVersionStub) GetStateVersion() []byte {
+	return stub.version
+}
+
+// newGasUsingStubHost creates a host on which the successful queries use
+// 100 gas, counting the executed queries
+func newGasUsingStubHost(executed *int) *contextmock.VMHostStub {
+	host := newStubHost(executed)
+	runQuery := host.RunSmartContractCallCalled
+	host.RunSmartContractCallCalled = func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+		vmOutput, err := runQuery(input)
+		if vmOutput.ReturnCode == vmcommon.Ok {
+			vmOutput.GasRemaining = input.GasProvided - 100
+		}
+		return vmOutput, err
+	}
+	return host
+}
+
+func newTestResultCache(executed *int, maxCachedResults int) (*ResultCache, *accountsReaderStub, *stateVersionStub) {
+	accounts := &accountsReaderStub{codeHash: []byte("code hash")}
+	stateVersion := &stateVersionStub{version: []byte("root hash")}
+	cache, _ := NewResultCache(ArgsNewResultCache{
+		Host:             newGasUsingStubHost(executed),
+		Accounts:         accounts,
+		StateVersion:     stateVersion,
+		MaxCachedResults: maxCachedResults,
+	})
+	return cache, accounts, stateVersion
+}
+
+func TestNewResultCache(t *testing.T) {
+	executed := 0
+	args := ArgsNewResultCache{
+		Host:             newStubHost(&executed),
+		Accounts:         &accountsReaderStub{},
+		StateVersion:     &stateVersionStub{},
+		MaxCachedResults: 1,
+	}
+
+	invalidArgs := args
+	invalidArgs.Host = nil
+	cache, err := NewResultCache(invalidArgs)
+	require.Nil(t, cache)
+	require.Equal(t, arwen.ErrNilVMHost, err)
+
+	invalidArgs = args
+	invalidArgs.Accounts = nil
+	cache, err = NewResultCache(invalidArgs)
+	require.Nil(t, cache)
+	require.Equal(t, ErrNilAccounts, err)
+
+	invalidArgs = args
+	invalidArgs.StateVersion = nil
+	cache, err = NewResultCache(invalidArgs)
+	require.Nil(t, cache)
+	require.Equal(t, ErrNilStateVersionHandler, err)
+
+	invalidArgs = args
+	invalidArgs.MaxCachedResults = 0
+	cache, err = NewResultCache(invalidArgs)
+	require.Nil(t, cache)
+	require.Equal(t, ErrInvalidMaxCachedResults, err)
+
+	cache, err = NewResultCache(args)
+	require.NotNil(t, cache)
+	require.Nil(t, err)
+}
+
+func TestResultCache_CachesSuccessfulQueries(t *testing.T) {
+	executed := 0
+	cache, accounts, stateVersion := newTestResultCache(&executed, 10)
+
+	for i := 0; i < 3; i++ {
+		vmOutput, err := cache.RunSmartContractCall(makeQuery("get", []byte{1}))
+		require.Nil(t, err)
+		require.Equal(t, vmcommon.Ok, vmOutput.ReturnCode)
+		require.Equal(t, [][]byte{[]byte("result")}, vmOutput.ReturnData)
+		require.Equal(t, uint64(900), vmOutput.GasRemaining)
+	}
+	require.Equal(t, 1, executed)
+	require.Equal(t, 1, cache.NumCachedResults())
+
+	// the cached query is charged the same gas, whatever gas is provided
+	query := makeQuery("get", []byte{1})
+	query.GasProvided = 500
+	vmOutput, _ := cache.RunSmartContractCall(query)
+	require.Equal(t, uint64(400), vmOutput.GasRemaining)
+	require.Equal(t, 1, executed)
+
+	// the failed queries are not cached
+	_, _ = cache.RunSmartContractCall(makeQuery("fail", []byte{1}))
+	_, _ = cache.RunSmartContractCall(makeQuery("fail", []byte{1}))
+	require.Equal(t, 3, executed)
+
+	// other arguments, or other code, are other queries
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{1}, []byte{}))
+	accounts.codeHash = []byte("upgraded code hash")
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{1}))
+	require.Equal(t, 5, executed)
+	require.Equal(t, 3, cache.NumCachedResults())
+
+	// a new state version invalidates all the results
+	stateVersion.version = []byte("new root hash")
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{1}))
+	require.Equal(t, 6, executed)
+	require.Equal(t, 1, cache.NumCachedResults())
+
+	cache.InvalidateAccounts(contractAddress)
+	require.Equal(t, 0, cache.NumCachedResults())
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{1}))
+	require.Equal(t, 7, executed)
+}
+
+func TestResultCache_NotEnoughGas(t *testing.T) {
+	executed := 0
+	cache, _, _ := newTestResultCache(&executed, 10)
+
+	_, _ = cache.RunSmartContractCall(makeQuery("get"))
+	require.Equal(t, 1, executed)
+
+	// the query is executed again when it was given less gas than it used
+	query := makeQuery("get")
+	query.GasProvided = 50
+	_, _ = cache.RunSmartContractCall(query)
+	require.Equal(t, 2, executed)
+}
+
+func TestResultCache_MaxCachedResults(t *testing.T) {
+	executed := 0
+	cache, _, _ := newTestResultCache(&executed, 2)
+
+	for i := byte(0); i < 2; i++ {
+		_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{i}))
+	}
+	// the result of the first query is now the most recently used
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{0}))
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{2}))
+	require.Equal(t, 3, executed)
+	require.Equal(t, 2, cache.NumCachedResults())
+
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{0}))
+	require.Equal(t, 3, executed)
+	_, _ = cache.RunSmartContractCall(makeQuery("get", []byte{1}))
+	require.Equal(t, 4, executed)
+}
@@ -0,0 +1,82 @@
+package receipts
+
+import (
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"golang.org/x/crypto/sha3"
+)
+
+// LogsBloomLength is the length in bytes of the logs bloom of a receipt
+const LogsBloomLength = 256
+
+const logsBloomBits = LogsBloomLength * 8
+const logsBloomHashesPerEntry = 3
+
+// LogsBloom is a bloom filter of the addresses, identifiers and topics of the
+// logs of an execution, built as the logs bloom of the Ethereum receipts: an
+// entry sets the 3 bits given by the first 3 pairs of bytes of its keccak256
+// hash, so a node can skip the receipts which surely lack a topic it searches
+type LogsBloom [LogsBloomLength]byte
+
+// NewLogsBloom creates the logs bloom of the given log entries
+func NewLogsBloom(logs []*vmcommon.LogEntry) *LogsBloom {
+	bloom := &LogsBloom{}
+	for _, logEntry := range logs {
+		if logEntry == nil {
+			continue
+		}
+		bloom.Add(logEntry.Address)
+		bloom.Add(logEntry.Identifier)
+		for _, topic := range logEntry.Topics {
+			bloom.Add(topic)
+		}
+	}
+	return bloom
+}
+
+// LogsBloomFromBytes reads a logs bloom serialized in a receipt
+func LogsBloomFromBytes(serialized []byte) (*LogsBloom, error) {
+	if len(serialized) != LogsBloomLength {
+		return nil, ErrInvalidLogsBloomLength
+	}
+
+	bloom := &LogsBloom{}
+	copy(bloom[:], serialized)
+	return bloom, nil
+}
+
+// Add sets the bits of the given entry
+func (bloom *LogsBloom) Add(entry []byte) {
+	for _, bit := range logsBloomBitsOf(entry) {
+		bloom[LogsBloomLength-1-bit/8] |= 1 << (bit % 8)
+	}
+}
+
+// MayContain returns false if the given entry was surely not added to the
+// bloom, and true if it might have been
+func (bloom *LogsBloom) MayContain(entry []byte) bool {
+	for _, bit := range logsBloomBitsOf(entry) {
+		if bloom[LogsBloomLength-1-bit/8]&(1<<(bit%8)) == 0 {
+			return false
+		}
+	}
+	return true
+}
+
+// Bytes returns the logs bloom as serialized in a receipt
+func (bloom *LogsBloom) Bytes() []byte {
+	serialized := make([]byte, LogsBloomLength)
+	copy(serialized, bloom[:])
+	return serialized
+}
+
+func logsBloomBitsOf(entry []byte) [logsBloomHashesPerEntry]uint {
+	hasher := sha3.NewLegacyKeccak256()
+	_, _ = hasher.Write(entry)
+	hash := hasher.Sum(nil)
+
+	var bits [logsBloomHashesPerEntry]uint
+	for i := range bits {
+		bits[i] = (uint(hash[2*i])<<8 | uint(hash[2*i+1])) % logsBloomBits
+	}
+	return bits
+}
@@ -0,0 +1,55 @@
+package receipts
+
+import (
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+func TestLogsBloom_AddedEntries(t *testing.T) {
+	logs := []*vmcommon.LogEntry{
+		{
+			Address:    []byte("contract"),
+			Identifier: []byte("transferEvent"),
+			Topics:     [][]byte{[]byte("sender"), []byte("receiver")},
+		},
+		nil,
+	}
+
+	bloom := NewLogsBloom(logs)
+	require.True(t, bloom.MayContain([]byte("contract")))
+	require.True(t, bloom.MayContain([]byte("transferEvent")))
+	require.True(t, bloom.MayContain([]byte("sender")))
+	require.True(t, bloom.MayContain([]byte("receiver")))
+	require.False(t, bloom.MayContain([]byte("other contract")))
+
+	require.Equal(t, &LogsBloom{}, NewLogsBloom(nil))
+}
+
+func TestLogsBloom_BitsOfEntry(t *testing.T) {
+	bloom := &LogsBloom{}
+	bloom.Add([]byte("topic"))
+
+	bitsSet := 0
+	for _, b := range bloom {
+		for ; b != 0; b &= b - 1 {
+			bitsSet++
+		}
+	}
+	require.LessOrEqual(t, bitsSet, logsBloomHashesPerEntry)
+	require.Greater(t, bitsSet, 0)
+}
+
+func TestLogsBloomFromBytes(t *testing.T) {
+	bloom := &LogsBloom{}
+	bloom.Add([]byte("topic"))
+
+	deserialized, err := LogsBloomFromBytes(bloom.Bytes())
+	require.Nil(t, err)
+	require.Equal(t, bloom, deserialized)
+
+	deserialized, err = LogsBloomFromBytes([]byte("short"))
+	require.Nil(t, deserialized)
+	require.Equal(t, ErrInvalidLogsBloomLength, err)
+}
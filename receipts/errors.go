@@ -0,0 +1,12 @@
+package receipts
+
+import "errors"
+
+// ErrNilVMInput signals that a nil VM input has been provided
+var ErrNilVMInput = errors.New("nil VM input")
+
+// ErrNilVMOutput signals that a nil VM output has been provided
+var ErrNilVMOutput = errors.New("nil VM output")
+
+// ErrInvalidLogsBloomLength signals that a logs bloom has an invalid length
+var ErrInvalidLogsBloomLength = errors.New("invalid logs bloom length")
@@ -0,0 +1,147 @@
+package receipts
+
+import (
+	"bytes"
+	"encoding/json"
+	"math/big"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// NewCallReceipt creates the receipt of a contract call, from its input and
+// the VMOutput it produced
+func NewCallReceipt(input *vmcommon.ContractCallInput, vmOutput *vmcommon.VMOutput) (*Receipt, error) {
+	if input == nil {
+		return nil, ErrNilVMInput
+	}
+	return NewReceipt(&input.VMInput, input.RecipientAddr, vmOutput)
+}
+
+// NewCreateReceipt creates the receipt of a contract deployment, from its
+// input and the VMOutput it produced; the receiver is the new contract, or
+// none if the deployment failed
+func NewCreateReceipt(input *vmcommon.ContractCreateInput, vmOutput *vmcommon.VMOutput) (*Receipt, error) {
+	if input == nil {
+		return nil, ErrNilVMInput
+	}
+	if vmOutput == nil {
+		return nil, ErrNilVMOutput
+	}
+
+	var receiver []byte
+	for _, account := range arwen.SortedOutputAccounts(vmOutput.OutputAccounts) {
+		if len(account.Code) > 0 && bytes.Equal(account.CodeDeployerAddress, input.CallerAddr) {
+			receiver = account.Address
+			break
+		}
+	}
+	return NewReceipt(&input.VMInput, receiver, vmOutput)
+}
+
+// NewReceipt creates the receipt of a transaction executed by the VM, given
+// its input, its receiver and the VMOutput it produced. The generated
+// transactions are the transfers of the VMOutput, sorted by receiver, which
+// include the asynchronous calls and the callbacks. The gas refunds are those
+// logged by the contracts which refunded gas to the caller, in the order of
+// the logs.
+func NewReceipt(input *vmcommon.VMInput, receiver []byte, vmOutput *vmcommon.VMOutput) (*Receipt, error) {
+	if input == nil {
+		return nil, ErrNilVMInput
+	}
+	if vmOutput == nil {
+		return nil, ErrNilVMOutput
+	}
+
+	gasUsed := uint64(0)
+	if input.GasProvided > vmOutput.GasRemaining {
+		gasUsed = input.GasProvided - vmOutput.GasRemaining
+	}
+
+	receipt := &Receipt{
+		TxHash:                input.CurrentTxHash,
+		Sender:                input.CallerAddr,
+		Receiver:              receiver,
+		Value:                 bigIntBytes(input.CallValue),
+		GasProvided:           input.GasProvided,
+		GasUsed:               gasUsed,
+		ReturnCode:            uint64(vmOutput.ReturnCode),
+		ReturnMessage:         vmOutput.ReturnMessage,
+		LogsBloom:             NewLogsBloom(vmOutput.Logs).Bytes(),
+		GeneratedTransactions: make([]*GeneratedTransaction, 0),
+		GasRefunds:            make([]*GasRefund, 0),
+	}
+
+	for _, account := range arwen.SortedOutputAccounts(vmOutput.OutputAccounts) {
+		for _, transfer := range account.OutputTransfers {
+			receipt.GeneratedTransactions = append(receipt.GeneratedTransactions, &GeneratedTransaction{
+				Sender:    transfer.SenderAddress,
+				Receiver:  account.Address,
+				Value:     bigIntBytes(transfer.Value),
+				Data:      transfer.Data,
+				GasLimit:  transfer.GasLimit,
+				GasLocked: transfer.GasLocked,
+				CallType:  uint64(transfer.CallType),
+			})
+		}
+	}
+
+	for _, logEntry := range vmOutput.Logs {
+		gasRefund, ok := gasRefundFromLog(logEntry)
+		if ok {
+			receipt.GasRefunds = append(receipt.GasRefunds, gasRefund)
+		}
+	}
+
+	return receipt, nil
+}
+
+// gasRefundFromLog returns the gas refund recorded by a log written by the
+// refundGasToCaller host function, and false for the other logs
+func gasRefundFromLog(logEntry *vmcommon.LogEntry) (*GasRefund, bool) {
+	if logEntry == nil || string(logEntry.Identifier) != arwen.GasRefundedToCallerIdentifier {
+		return nil, false
+	}
+	if len(logEntry.Topics) != 3 {
+		return nil, false
+	}
+
+	return &GasRefund{
+		Payer:    logEntry.Address,
+		Receiver: logEntry.Topics[0],
+		Gas:      big.NewInt(0).SetBytes(logEntry.Topics[1]).Uint64(),
+		Value:    logEntry.Topics[2],
+	}, true
+}
+
+// MayContainLogEntry returns false if none of the logs of the receipt has the
+// given address, identifier or topic, and true if one of them might have it
+func (receipt *Receipt) MayContainLogEntry(entry []byte) bool {
+	bloom, err := LogsBloomFromBytes(receipt.LogsBloom)
+	if err != nil {
+		return false
+	}
+	return bloom.MayContain(entry)
+}
+
+// ToJSON serializes the receipt as JSON, with the byte fields in base64
+func (receipt *Receipt) ToJSON() ([]byte, error) {
+	return json.Marshal(receipt)
+}
+
+// ReceiptFromJSON deserializes a receipt serialized by ToJSON
+func ReceiptFromJSON(serialized []byte) (*Receipt, error) {
+	receipt := &Receipt{}
+	err := json.Unmarshal(serialized, receipt)
+	if err != nil {
+		return nil, err
+	}
+	return receipt, nil
+}
+
+func bigIntBytes(value *big.Int) []byte {
+	if value == nil {
+		return nil
+	}
+	return value.Bytes()
+}
@@ -0,0 +1,225 @@
+package receipts
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/stretchr/testify/require"
+)
+
+var sender = []byte("sender")
+var contract = []byte("contract")
+var otherContract = []byte("otherContract")
+
+func TestNewReceipt_NilArguments(t *testing.T) {
+	receipt, err := NewReceipt(nil, contract, &vmcommon.VMOutput{})
+	require.Nil(t, receipt)
+	require.Equal(t, ErrNilVMInput, err)
+
+	receipt, err = NewReceipt(&vmcommon.VMInput{}, contract, nil)
+	require.Nil(t, receipt)
+	require.Equal(t, ErrNilVMOutput, err)
+
+	receipt, err = NewCallReceipt(nil, &vmcommon.VMOutput{})
+	require.Nil(t, receipt)
+	require.Equal(t, ErrNilVMInput, err)
+
+	receipt, err = NewCreateReceipt(nil, &vmcommon.VMOutput{})
+	require.Nil(t, receipt)
+	require.Equal(t, ErrNilVMInput, err)
+}
+
+func TestNewCallReceipt(t *testing.T) {
+	receipt, err := NewCallReceipt(createCallInput(), createVMOutput())
+	require.Nil(t, err)
+
+	require.Equal(t, []byte("txHash"), receipt.TxHash)
+	require.Equal(t, sender, receipt.Sender)
+	require.Equal(t, contract, receipt.Receiver)
+	require.Equal(t, big.NewInt(10).Bytes(), receipt.Value)
+	require.Equal(t, uint64(1000), receipt.GasProvided)
+	require.Equal(t, uint64(600), receipt.GasUsed)
+	require.Equal(t, uint64(vmcommon.UserError), receipt.ReturnCode)
+	require.Equal(t, "user error", receipt.ReturnMessage)
+	require.Len(t, receipt.LogsBloom, LogsBloomLength)
+	require.True(t, receipt.MayContainLogEntry([]byte("event")))
+	require.True(t, receipt.MayContainLogEntry([]byte("topic")))
+	require.False(t, receipt.MayContainLogEntry([]byte("missing topic")))
+
+	require.Len(t, receipt.GeneratedTransactions, 3)
+	require.Equal(t, &GeneratedTransaction{
+		Sender:   contract,
+		Receiver: contract,
+		Value:    big.NewInt(0).Bytes(),
+		Data:     []byte("callBack@00"),
+		GasLimit: 50,
+		CallType: uint64(vm.AsynchronousCallBack),
+	}, receipt.GeneratedTransactions[0])
+	require.Equal(t, &GeneratedTransaction{
+		Sender:    contract,
+		Receiver:  otherContract,
+		Value:     big.NewInt(5).Bytes(),
+		Data:      []byte("doSomething@01"),
+		GasLimit:  100,
+		GasLocked: 50,
+		CallType:  uint64(vm.AsynchronousCall),
+	}, receipt.GeneratedTransactions[1])
+	require.Equal(t, []byte("transfer"), receipt.GeneratedTransactions[2].Data)
+
+	require.Equal(t, []*GasRefund{{
+		Payer:    contract,
+		Receiver: sender,
+		Gas:      300,
+		Value:    big.NewInt(3000).Bytes(),
+	}}, receipt.GasRefunds)
+}
+
+func TestNewCallReceipt_GasRemainingOverGasProvided(t *testing.T) {
+	vmOutput := createVMOutput()
+	vmOutput.GasRemaining = 2000
+
+	receipt, err := NewCallReceipt(createCallInput(), vmOutput)
+	require.Nil(t, err)
+	require.Equal(t, uint64(0), receipt.GasUsed)
+}
+
+func TestNewCreateReceipt(t *testing.T) {
+	input := &vmcommon.ContractCreateInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:  sender,
+			CallValue:   big.NewInt(0),
+			GasProvided: 1000,
+		},
+		ContractCode: []byte("code"),
+	}
+	vmOutput := &vmcommon.VMOutput{
+		ReturnCode:   vmcommon.Ok,
+		GasRemaining: 100,
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			string(sender): {
+				Address: sender,
+			},
+			string(contract): {
+				Address:             contract,
+				Code:                []byte("code"),
+				CodeDeployerAddress: sender,
+			},
+		},
+	}
+
+	receipt, err := NewCreateReceipt(input, vmOutput)
+	require.Nil(t, err)
+	require.Equal(t, contract, receipt.Receiver)
+	require.Equal(t, uint64(900), receipt.GasUsed)
+	require.Equal(t, uint64(vmcommon.Ok), receipt.ReturnCode)
+
+	vmOutput.OutputAccounts = make(map[string]*vmcommon.OutputAccount)
+	receipt, err = NewCreateReceipt(input, vmOutput)
+	require.Nil(t, err)
+	require.Nil(t, receipt.Receiver)
+}
+
+func TestReceipt_ProtobufSerialization(t *testing.T) {
+	receipt, err := NewCallReceipt(createCallInput(), createVMOutput())
+	require.Nil(t, err)
+
+	serialized, err := receipt.Marshal()
+	require.Nil(t, err)
+
+	deserialized := &Receipt{}
+	err = deserialized.Unmarshal(serialized)
+	require.Nil(t, err)
+	require.True(t, receipt.Equal(deserialized))
+	require.True(t, deserialized.MayContainLogEntry([]byte("topic")))
+}
+
+func TestReceipt_JSONSerialization(t *testing.T) {
+	receipt, err := NewCallReceipt(createCallInput(), createVMOutput())
+	require.Nil(t, err)
+
+	serialized, err := receipt.ToJSON()
+	require.Nil(t, err)
+	require.Contains(t, string(serialized), `"ReturnMessage":"user error"`)
+
+	deserialized, err := ReceiptFromJSON(serialized)
+	require.Nil(t, err)
+	require.True(t, receipt.Equal(deserialized))
+
+	deserialized, err = ReceiptFromJSON([]byte("not json"))
+	require.Nil(t, deserialized)
+	require.NotNil(t, err)
+}
+
+func createCallInput() *vmcommon.ContractCallInput {
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:    sender,
+			CallValue:     big.NewInt(10),
+			GasProvided:   1000,
+			CurrentTxHash: []byte("txHash"),
+		},
+		RecipientAddr: contract,
+		Function:      "doSomething",
+	}
+}
+
+func createVMOutput() *vmcommon.VMOutput {
+	return &vmcommon.VMOutput{
+		ReturnCode:    vmcommon.UserError,
+		ReturnMessage: "user error",
+		GasRemaining:  400,
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			string(otherContract): {
+				Address: otherContract,
+				OutputTransfers: []vmcommon.OutputTransfer{
+					{
+						Value:         big.NewInt(5),
+						GasLimit:      100,
+						GasLocked:     50,
+						Data:          []byte("doSomething@01"),
+						CallType:      vm.AsynchronousCall,
+						SenderAddress: contract,
+					},
+					{
+						Value:         big.NewInt(1),
+						Data:          []byte("transfer"),
+						CallType:      vm.DirectCall,
+						SenderAddress: contract,
+					},
+				},
+			},
+			string(contract): {
+				Address: contract,
+				OutputTransfers: []vmcommon.OutputTransfer{
+					{
+						Value:         big.NewInt(0),
+						GasLimit:      50,
+						Data:          []byte("callBack@00"),
+						CallType:      vm.AsynchronousCallBack,
+						SenderAddress: contract,
+					},
+				},
+			},
+		},
+		Logs: []*vmcommon.LogEntry{
+			{
+				Address:    contract,
+				Identifier: []byte("event"),
+				Topics:     [][]byte{[]byte("topic")},
+			},
+			{
+				Address:    contract,
+				Identifier: []byte(arwen.GasRefundedToCallerIdentifier),
+				Topics:     [][]byte{sender, big.NewInt(300).Bytes(), big.NewInt(3000).Bytes()},
+			},
+			{
+				Address:    contract,
+				Identifier: []byte(arwen.GasRefundedToCallerIdentifier),
+				Topics:     [][]byte{sender},
+			},
+		},
+	}
+}
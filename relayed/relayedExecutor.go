@@ -0,0 +1,109 @@
+package relayed
+
+import (
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/elrond-vm-common/parsers"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// RelayedOutput is the result of executing the inner call of a relayed transaction
+type RelayedOutput struct {
+	*vmcommon.VMOutput
+	Version     Version
+	RelayerAddr []byte
+	InnerTx     *Transaction
+	CallInput   *vmcommon.ContractCallInput
+}
+
+// RelayedExecutor unwraps relayed transactions and runs their inner calls
+type RelayedExecutor struct {
+	host arwen.VMHost
+}
+
+// NewRelayedExecutor creates a new RelayedExecutor
+func NewRelayedExecutor(host arwen.VMHost) (*RelayedExecutor, error) {
+	if check.IfNil(host) {
+		return nil, arwen.ErrNilVMHost
+	}
+
+	return &RelayedExecutor{
+		host: host,
+	}, nil
+}
+
+// Execute runs the inner call of the relayed transaction. The contract sees
+// the inner sender as its caller, while the relayer remains responsible for
+// the gas, as returned in the output.
+func (executor *RelayedExecutor) Execute(relayedTx *Transaction, txHash []byte) (*RelayedOutput, error) {
+	innerTx, version, err := UnwrapTransaction(relayedTx)
+	if err != nil {
+		return nil, err
+	}
+
+	err = executor.checkInnerNonce(innerTx)
+	if err != nil {
+		return nil, err
+	}
+
+	callInput, err := NewInnerCallInput(innerTx, txHash)
+	if err != nil {
+		return nil, err
+	}
+
+	vmOutput, err := executor.host.RunSmartContractCall(callInput)
+	if err != nil {
+		return nil, err
+	}
+
+	return &RelayedOutput{
+		VMOutput:    vmOutput,
+		Version:     version,
+		RelayerAddr: relayedTx.SndAddr,
+		InnerTx:     innerTx,
+		CallInput:   callInput,
+	}, nil
+}
+
+// checkInnerNonce verifies that the inner transaction is the next one of its
+// sender, so that the relayer cannot replay it
+func (executor *RelayedExecutor) checkInnerNonce(innerTx *Transaction) error {
+	account, err := executor.host.Blockchain().GetUserAccount(innerTx.SndAddr)
+	if err != nil {
+		return err
+	}
+
+	accountNonce := uint64(0)
+	if !arwen.IfNil(account) {
+		accountNonce = account.GetNonce()
+	}
+	if innerTx.Nonce != accountNonce {
+		return ErrRelayedTxNonceMismatch
+	}
+	return nil
+}
+
+// NewInnerCallInput creates the contract call input for the inner transaction
+// of a relayed transaction, with the inner sender as caller
+func NewInnerCallInput(innerTx *Transaction, txHash []byte) (*vmcommon.ContractCallInput, error) {
+	function, arguments, err := parsers.NewCallArgsParser().ParseData(string(innerTx.Data))
+	if err != nil {
+		return nil, err
+	}
+
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:     innerTx.SndAddr,
+			Arguments:      arguments,
+			CallValue:      innerTx.Value,
+			CallType:       vm.DirectCall,
+			GasPrice:       innerTx.GasPrice,
+			GasProvided:    innerTx.GasLimit,
+			CurrentTxHash:  txHash,
+			OriginalTxHash: txHash,
+		},
+		RecipientAddr: innerTx.RcvAddr,
+		Function:      function,
+	}, nil
+}
@@ -0,0 +1,46 @@
+package relayed
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/arwen/contexts"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+func newTestRelayedExecutor(t *testing.T, world *worldmock.MockWorld) *RelayedExecutor {
+	host := &contextmock.VMHostMock{}
+	blockchainContext, err := contexts.NewBlockchainContext(host, world)
+	require.Nil(t, err)
+	host.BlockchainContext = blockchainContext
+
+	executor, err := NewRelayedExecutor(host)
+	require.Nil(t, err)
+	return executor
+}
+
+func TestRelayedExecutor_InnerNonceMismatch(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	world.AcctMap.PutAccount(&worldmock.Account{
+		Address: userAddress,
+		Nonce:   8,
+	})
+	executor := newTestRelayedExecutor(t, world)
+
+	innerTx := makeInnerTx()
+	_, err := executor.Execute(makeRelayedTxV1(t, innerTx), []byte("txHash"))
+	require.Equal(t, ErrRelayedTxNonceMismatch, err)
+
+	innerTx.Nonce = 9
+	_, err = executor.Execute(makeRelayedTxV1(t, innerTx), []byte("txHash"))
+	require.Equal(t, ErrRelayedTxNonceMismatch, err)
+}
+
+func TestRelayedExecutor_InnerSenderNotFound(t *testing.T) {
+	executor := newTestRelayedExecutor(t, worldmock.NewMockWorld())
+
+	_, err := executor.Execute(makeRelayedTxV1(t, makeInnerTx()), []byte("txHash"))
+	require.NotNil(t, err)
+	require.NotEqual(t, ErrRelayedTxNonceMismatch, err)
+}
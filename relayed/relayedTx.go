@@ -0,0 +1,180 @@
+package relayed
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-vm-common/parsers"
+)
+
+// RelayedTxV1Function is the data prefix of a relayed transaction which holds
+// the whole inner transaction, JSON encoded
+const RelayedTxV1Function = "relayedTx"
+
+// RelayedTxV2Function is the data prefix of a relayed transaction which holds
+// only the receiver, nonce, data and signature of the inner transaction
+const RelayedTxV2Function = "relayedTxV2"
+
+const numArgsRelayedTxV2 = 4
+
+// ErrNotRelayedTransaction signals that the data of a transaction is not a relayed envelope
+var ErrNotRelayedTransaction = errors.New("not a relayed transaction")
+
+// ErrInvalidRelayedTxArguments signals that a relayed envelope has the wrong number of arguments
+var ErrInvalidRelayedTxArguments = errors.New("invalid arguments for relayed transaction")
+
+// ErrRelayedTxSenderMismatch signals that the inner sender is not the receiver of the relayed transaction
+var ErrRelayedTxSenderMismatch = errors.New("inner transaction sender does not match relayed transaction receiver")
+
+// ErrRelayedTxGasLimitTooLow signals that the inner transaction asks for more gas than the relayer provides
+var ErrRelayedTxGasLimitTooLow = errors.New("relayed transaction gas limit lower than inner gas limit")
+
+// ErrRelayedTxGasPriceMismatch signals that the inner and the relayed transactions have different gas prices
+var ErrRelayedTxGasPriceMismatch = errors.New("inner transaction gas price does not match relayed transaction")
+
+// ErrRelayedTxValueMismatch signals that the inner and the relayed v1 transactions carry different values
+var ErrRelayedTxValueMismatch = errors.New("inner transaction value does not match relayed transaction")
+
+// ErrRelayedTxNonceMismatch signals that the nonce of the inner transaction is not the nonce of its sender
+var ErrRelayedTxNonceMismatch = errors.New("inner transaction nonce does not match sender account")
+
+// ErrRelayedTxValueNotAllowed signals that a relayed v2 transaction carries value
+var ErrRelayedTxValueNotAllowed = errors.New("relayed v2 transaction must not carry value")
+
+// ErrNestedRelayedTx signals that the inner transaction is itself a relayed transaction
+var ErrNestedRelayedTx = errors.New("nested relayed transactions are not allowed")
+
+// ErrMissingInnerSignature signals that the inner transaction is not signed
+var ErrMissingInnerSignature = errors.New("missing inner transaction signature")
+
+// Version identifies the envelope format of a relayed transaction
+type Version int
+
+const (
+	// V1 envelopes hold the JSON encoded inner transaction
+	V1 Version = iota + 1
+	// V2 envelopes hold the inner receiver, nonce, data and signature
+	V2
+)
+
+// Transaction is the minimal transaction model needed to unwrap relayed
+// transactions; the JSON field names follow the node transaction format
+type Transaction struct {
+	Nonce     uint64   `json:"nonce"`
+	Value     *big.Int `json:"value"`
+	RcvAddr   []byte   `json:"receiver"`
+	SndAddr   []byte   `json:"sender"`
+	GasPrice  uint64   `json:"gasPrice"`
+	GasLimit  uint64   `json:"gasLimit"`
+	Data      []byte   `json:"data,omitempty"`
+	Signature []byte   `json:"signature,omitempty"`
+}
+
+// IsRelayedTransaction returns whether the data of the transaction is a
+// relayed envelope of either version
+func IsRelayedTransaction(tx *Transaction) bool {
+	function, _, err := parsers.NewCallArgsParser().ParseData(string(tx.Data))
+	if err != nil {
+		return false
+	}
+	return function == RelayedTxV1Function || function == RelayedTxV2Function
+}
+
+// UnwrapTransaction extracts the inner transaction of a relayed transaction.
+// The relayer is the sender of the relayed transaction and pays for the gas,
+// while the receiver of the relayed transaction must be the inner sender.
+func UnwrapTransaction(relayedTx *Transaction) (*Transaction, Version, error) {
+	function, arguments, err := parsers.NewCallArgsParser().ParseData(string(relayedTx.Data))
+	if err != nil {
+		return nil, 0, err
+	}
+
+	var innerTx *Transaction
+	var version Version
+	switch function {
+	case RelayedTxV1Function:
+		innerTx, err = unwrapV1(relayedTx, arguments)
+		version = V1
+	case RelayedTxV2Function:
+		innerTx, err = unwrapV2(relayedTx, arguments)
+		version = V2
+	default:
+		return nil, 0, ErrNotRelayedTransaction
+	}
+	if err != nil {
+		return nil, 0, err
+	}
+
+	err = checkInnerTransaction(relayedTx, innerTx)
+	if err != nil {
+		return nil, 0, err
+	}
+
+	return innerTx, version, nil
+}
+
+func unwrapV1(relayedTx *Transaction, arguments [][]byte) (*Transaction, error) {
+	if len(arguments) != 1 {
+		return nil, ErrInvalidRelayedTxArguments
+	}
+
+	innerTx := &Transaction{}
+	err := json.Unmarshal(arguments[0], innerTx)
+	if err != nil {
+		return nil, err
+	}
+	if innerTx.Value == nil {
+		innerTx.Value = big.NewInt(0)
+	}
+
+	if innerTx.GasLimit > relayedTx.GasLimit {
+		return nil, ErrRelayedTxGasLimitTooLow
+	}
+	if innerTx.GasPrice != relayedTx.GasPrice {
+		return nil, ErrRelayedTxGasPriceMismatch
+	}
+	relayedValue := relayedTx.Value
+	if relayedValue == nil {
+		relayedValue = big.NewInt(0)
+	}
+	if innerTx.Value.Cmp(relayedValue) != 0 {
+		return nil, ErrRelayedTxValueMismatch
+	}
+
+	return innerTx, nil
+}
+
+func unwrapV2(relayedTx *Transaction, arguments [][]byte) (*Transaction, error) {
+	if len(arguments) != numArgsRelayedTxV2 {
+		return nil, ErrInvalidRelayedTxArguments
+	}
+	if relayedTx.Value != nil && relayedTx.Value.Sign() != 0 {
+		return nil, ErrRelayedTxValueNotAllowed
+	}
+
+	return &Transaction{
+		Nonce:     big.NewInt(0).SetBytes(arguments[1]).Uint64(),
+		Value:     big.NewInt(0),
+		RcvAddr:   arguments[0],
+		SndAddr:   relayedTx.RcvAddr,
+		GasPrice:  relayedTx.GasPrice,
+		GasLimit:  relayedTx.GasLimit,
+		Data:      arguments[2],
+		Signature: arguments[3],
+	}, nil
+}
+
+func checkInnerTransaction(relayedTx *Transaction, innerTx *Transaction) error {
+	if !bytes.Equal(innerTx.SndAddr, relayedTx.RcvAddr) {
+		return ErrRelayedTxSenderMismatch
+	}
+	if len(innerTx.Signature) == 0 {
+		return ErrMissingInnerSignature
+	}
+	if IsRelayedTransaction(innerTx) {
+		return ErrNestedRelayedTx
+	}
+	return nil
+}
@@ -0,0 +1,141 @@
+package relayed
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"math/big"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+var relayerAddress = []byte("relayer_________________________")
+var userAddress = []byte("user____________________________")
+var contractAddress = []byte("contract________________________")
+
+func makeRelayedTxV1(t *testing.T, innerTx *Transaction) *Transaction {
+	encodedInnerTx, err := json.Marshal(innerTx)
+	require.Nil(t, err)
+
+	return &Transaction{
+		Value:    innerTx.Value,
+		RcvAddr:  userAddress,
+		SndAddr:  relayerAddress,
+		GasPrice: innerTx.GasPrice,
+		GasLimit: innerTx.GasLimit + 50000,
+		Data:     []byte(RelayedTxV1Function + "@" + hex.EncodeToString(encodedInnerTx)),
+	}
+}
+
+func makeInnerTx() *Transaction {
+	return &Transaction{
+		Nonce:     7,
+		Value:     big.NewInt(10),
+		RcvAddr:   contractAddress,
+		SndAddr:   userAddress,
+		GasPrice:  1000,
+		GasLimit:  100000,
+		Data:      []byte("transfer@0a@0b"),
+		Signature: []byte("signature"),
+	}
+}
+
+func TestUnwrapTransaction_V1(t *testing.T) {
+	innerTx := makeInnerTx()
+	relayedTx := makeRelayedTxV1(t, innerTx)
+	require.True(t, IsRelayedTransaction(relayedTx))
+
+	unwrapped, version, err := UnwrapTransaction(relayedTx)
+	require.Nil(t, err)
+	require.Equal(t, V1, version)
+	require.Equal(t, innerTx, unwrapped)
+
+	callInput, err := NewInnerCallInput(unwrapped, []byte("txHash"))
+	require.Nil(t, err)
+	require.Equal(t, userAddress, callInput.CallerAddr)
+	require.Equal(t, contractAddress, callInput.RecipientAddr)
+	require.Equal(t, "transfer", callInput.Function)
+	require.Equal(t, [][]byte{{10}, {11}}, callInput.Arguments)
+	require.Equal(t, uint64(100000), callInput.GasProvided)
+}
+
+func TestUnwrapTransaction_V1_Invalid(t *testing.T) {
+	innerTx := makeInnerTx()
+	innerTx.SndAddr = contractAddress
+	_, _, err := UnwrapTransaction(makeRelayedTxV1(t, innerTx))
+	require.Equal(t, ErrRelayedTxSenderMismatch, err)
+
+	innerTx = makeInnerTx()
+	innerTx.Signature = nil
+	_, _, err = UnwrapTransaction(makeRelayedTxV1(t, innerTx))
+	require.Equal(t, ErrMissingInnerSignature, err)
+
+	innerTx = makeInnerTx()
+	relayedTx := makeRelayedTxV1(t, innerTx)
+	relayedTx.GasLimit = innerTx.GasLimit - 1
+	_, _, err = UnwrapTransaction(relayedTx)
+	require.Equal(t, ErrRelayedTxGasLimitTooLow, err)
+
+	innerTx = makeInnerTx()
+	relayedTx = makeRelayedTxV1(t, innerTx)
+	relayedTx.Value = big.NewInt(0)
+	_, _, err = UnwrapTransaction(relayedTx)
+	require.Equal(t, ErrRelayedTxValueMismatch, err)
+
+	innerTx = makeInnerTx()
+	relayedTx = makeRelayedTxV1(t, innerTx)
+	relayedTx.Value = big.NewInt(11)
+	_, _, err = UnwrapTransaction(relayedTx)
+	require.Equal(t, ErrRelayedTxValueMismatch, err)
+
+	innerTx = makeInnerTx()
+	innerTx.Value = nil
+	relayedTx = makeRelayedTxV1(t, innerTx)
+	relayedTx.Value = nil
+	unwrapped, _, err := UnwrapTransaction(relayedTx)
+	require.Nil(t, err)
+	require.Equal(t, big.NewInt(0), unwrapped.Value)
+
+	innerTx = makeInnerTx()
+	nestedTx := makeRelayedTxV1(t, innerTx)
+	nestedTx.SndAddr = userAddress
+	nestedTx.Signature = []byte("signature")
+	_, _, err = UnwrapTransaction(makeRelayedTxV1(t, nestedTx))
+	require.Equal(t, ErrNestedRelayedTx, err)
+}
+
+func TestUnwrapTransaction_V2(t *testing.T) {
+	relayedTx := &Transaction{
+		Value:    big.NewInt(0),
+		RcvAddr:  userAddress,
+		SndAddr:  relayerAddress,
+		GasPrice: 1000,
+		GasLimit: 200000,
+		Data: []byte(RelayedTxV2Function +
+			"@" + hex.EncodeToString(contractAddress) +
+			"@07" +
+			"@" + hex.EncodeToString([]byte("transfer@0a")) +
+			"@" + hex.EncodeToString([]byte("signature"))),
+	}
+
+	unwrapped, version, err := UnwrapTransaction(relayedTx)
+	require.Nil(t, err)
+	require.Equal(t, V2, version)
+	require.Equal(t, userAddress, unwrapped.SndAddr)
+	require.Equal(t, contractAddress, unwrapped.RcvAddr)
+	require.Equal(t, uint64(7), unwrapped.Nonce)
+	require.Equal(t, []byte("transfer@0a"), unwrapped.Data)
+	require.Equal(t, relayedTx.GasLimit, unwrapped.GasLimit)
+
+	relayedTx.Value = big.NewInt(1)
+	_, _, err = UnwrapTransaction(relayedTx)
+	require.Equal(t, ErrRelayedTxValueNotAllowed, err)
+}
+
+func TestUnwrapTransaction_NotRelayed(t *testing.T) {
+	tx := makeInnerTx()
+	require.False(t, IsRelayedTransaction(tx))
+
+	_, _, err := UnwrapTransaction(tx)
+	require.Equal(t, ErrNotRelayedTransaction, err)
+}
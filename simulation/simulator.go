@@ -0,0 +1,189 @@
+package simulation
+
+import (
+	"errors"
+	"math/big"
+	"sort"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/elrond-vm-common/parsers"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+// ErrInvalidAsyncData signals that the async data of a generated async call
+// or callback cannot be decoded
+var ErrInvalidAsyncData = errors.New("invalid async data of output transfer")
+
+// argumentsParser decodes the data and the async data of the output transfers
+type argumentsParser interface {
+	ParseData(data string) (string, [][]byte, error)
+	ParseArguments(data string) ([][]byte, error)
+}
+
+// SimulationResult is the outcome of simulating a call: its VMOutput, which
+// is not applied, the accounts the call would touch and the cross-shard
+// async calls and callbacks it would generate
+type SimulationResult struct {
+	*vmcommon.VMOutput
+	// TouchedAccounts are the caller, the receiver and the accounts changed
+	// or deleted by the call, in ascending order of their addresses
+	TouchedAccounts [][]byte
+	// AsyncCalls are the inputs of the generated cross-shard async calls and
+	// callbacks, in the order of their receivers; each can be simulated in
+	// turn, on the shard of its receiver
+	AsyncCalls      []*vmcommon.ContractCallInput
+}
+
+// Simulator executes calls without persisting their effects, for the tools
+// which explore what a sequence of calls would do
+type Simulator struct {
+	host           arwen.VMHost
+	callArgsParser argumentsParser
+}
+
+// NewSimulator creates a new Simulator
+func NewSimulator(host arwen.VMHost) (*Simulator, error) {
+	if check.IfNil(host) {
+		return nil, arwen.ErrNilVMHost
+	}
+
+	return &Simulator{
+		host:           host,
+		callArgsParser: parsers.NewCallArgsParser(),
+	}, nil
+}
+
+// Simulate executes the call fully and returns its result. The VMOutput is
+// only returned, so that the world state stays as it was; the embedder
+// decides whether to apply it to a scratch state before simulating the
+// generated async calls.
+func (simulator *Simulator) Simulate(input *vmcommon.ContractCallInput) (*SimulationResult, error) {
+	vmOutput, err := simulator.host.RunSmartContractCall(input)
+	if err != nil {
+		return nil, err
+	}
+
+	asyncCalls, err := simulator.generatedAsyncCalls(input, vmOutput)
+	if err != nil {
+		return nil, err
+	}
+
+	return &SimulationResult{
+		VMOutput:        vmOutput,
+		TouchedAccounts: touchedAccounts(input, vmOutput),
+		AsyncCalls:      asyncCalls,
+	}, nil
+}
+
+func touchedAccounts(input *vmcommon.ContractCallInput, vmOutput *vmcommon.VMOutput) [][]byte {
+	touched := map[string]bool{
+		string(input.CallerAddr):    true,
+		string(input.RecipientAddr): true,
+	}
+	for address := range vmOutput.OutputAccounts {
+		touched[address] = true
+	}
+	for _, address := range vmOutput.DeletedAccounts {
+		touched[string(address)] = true
+	}
+
+	addresses := make([]string, 0, len(touched))
+	for address := range touched {
+		addresses = append(addresses, address)
+	}
+	sort.Strings(addresses)
+
+	accounts := make([][]byte, len(addresses))
+	for i, address := range addresses {
+		accounts[i] = []byte(address)
+	}
+	return accounts
+}
+
+func (simulator *Simulator) generatedAsyncCalls(input *vmcommon.ContractCallInput, vmOutput *vmcommon.VMOutput) ([]*vmcommon.ContractCallInput, error) {
+	asyncCalls := make([]*vmcommon.ContractCallInput, 0)
+	for _, account := range arwen.SortedOutputAccounts(vmOutput.OutputAccounts) {
+		for _, transfer := range account.OutputTransfers {
+			if transfer.CallType != vm.AsynchronousCall && transfer.CallType != vm.AsynchronousCallBack {
+				continue
+			}
+
+			asyncCall, err := simulator.newAsyncCallInput(input, account.Address, transfer)
+			if err != nil {
+				return nil, err
+			}
+			asyncCalls = append(asyncCalls, asyncCall)
+		}
+	}
+	return asyncCalls, nil
+}
+
+// newAsyncCallInput creates the input with which the shard of the receiver
+// would execute the async call or the callback sent by the output transfer
+func (simulator *Simulator) newAsyncCallInput(
+	input *vmcommon.ContractCallInput,
+	receiver []byte,
+	transfer vmcommon.OutputTransfer,
+) (*vmcommon.ContractCallInput, error) {
+	function, arguments, err := simulator.callArgsParser.ParseData(string(transfer.Data))
+	if err != nil {
+		return nil, err
+	}
+	asyncArguments, err := simulator.newAsyncArguments(transfer)
+	if err != nil {
+		return nil, err
+	}
+	if transfer.CallType == vm.AsynchronousCallBack {
+		function = arwen.CallbackFunctionName
+	}
+	callValue := big.NewInt(0)
+	if transfer.Value != nil {
+		callValue.Set(transfer.Value)
+	}
+
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:     transfer.SenderAddress,
+			Arguments:      arguments,
+			CallValue:      callValue,
+			CallType:       transfer.CallType,
+			GasPrice:       input.GasPrice,
+			GasProvided:    transfer.GasLimit,
+			GasLocked:      transfer.GasLocked,
+			OriginalTxHash: input.OriginalTxHash,
+			PrevTxHash:     input.CurrentTxHash,
+			AsyncArguments: asyncArguments,
+		},
+		RecipientAddr: receiver,
+		Function:      function,
+	}, nil
+}
+
+// newAsyncArguments decodes the async data of the output transfer, which
+// holds the call ID and the caller call ID of an async call, followed, for a
+// callback, by the call ID of the initiator and the accumulated gas
+func (simulator *Simulator) newAsyncArguments(transfer vmcommon.OutputTransfer) (*vmcommon.AsyncArguments, error) {
+	// the async data starts with a @, so the first parsed argument is always empty
+	asyncData, err := simulator.callArgsParser.ParseArguments(string(transfer.AsyncData))
+	if err != nil {
+		return nil, err
+	}
+	if len(asyncData) < 3 {
+		return nil, ErrInvalidAsyncData
+	}
+
+	asyncArguments := &vmcommon.AsyncArguments{
+		CallID:       asyncData[1],
+		CallerCallID: asyncData[2],
+	}
+	if transfer.CallType == vm.AsynchronousCallBack {
+		if len(asyncData) < 5 {
+			return nil, ErrInvalidAsyncData
+		}
+		asyncArguments.CallbackAsyncInitiatorCallID = asyncData[3]
+		asyncArguments.GasAccumulated = big.NewInt(0).SetBytes(asyncData[4]).Uint64()
+	}
+	return asyncArguments, nil
+}
@@ -0,0 +1,172 @@
+package simulation
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/elrond-vm-common/txDataBuilder"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	"github.com/stretchr/testify/require"
+)
+
+var userAddress = []byte("user____________________________")
+var contractAddress = []byte("contract________________________")
+var remoteAddress = []byte("remote__________________________")
+
+func makeAsyncData(arguments ...[]byte) []byte {
+	asyncData := txDataBuilder.NewBuilder()
+	for _, argument := range arguments {
+		asyncData.Bytes(argument)
+	}
+	return asyncData.ToBytes()
+}
+
+// newStubHost creates a host on which the user calls the contract, which
+// sends a cross-shard async call to the remote contract, which responds with
+// a cross-shard callback
+func newStubHost() *contextmock.VMHostStub {
+	return &contextmock.VMHostStub{
+		RunSmartContractCallCalled: func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+			if input.CallType == vm.AsynchronousCall {
+				return &vmcommon.VMOutput{
+					ReturnCode: vmcommon.Ok,
+					OutputAccounts: map[string]*vmcommon.OutputAccount{
+						string(contractAddress): {
+							Address: contractAddress,
+							OutputTransfers: []vmcommon.OutputTransfer{{
+								SenderAddress: remoteAddress,
+								GasLimit:      300,
+								CallType:      vm.AsynchronousCallBack,
+								AsyncData:     makeAsyncData([]byte("callbackID"), []byte("asyncID"), []byte("contextID"), big.NewInt(50).Bytes()),
+								Data:          []byte("<callback>@@726573756c74"),
+							}},
+						},
+					},
+				}, nil
+			}
+
+			return &vmcommon.VMOutput{
+				ReturnCode: vmcommon.Ok,
+				OutputAccounts: map[string]*vmcommon.OutputAccount{
+					string(contractAddress): {
+						Address:        contractAddress,
+						StorageUpdates: map[string]*vmcommon.StorageUpdate{"key": {Offset: []byte("key"), Data: []byte("value")}},
+					},
+					string(remoteAddress): {
+						Address: remoteAddress,
+						OutputTransfers: []vmcommon.OutputTransfer{
+							{
+								SenderAddress: contractAddress,
+								Value:         big.NewInt(10),
+								CallType:      vm.DirectCall,
+							},
+							{
+								SenderAddress: contractAddress,
+								Value:         big.NewInt(5),
+								GasLimit:      1000,
+								GasLocked:     200,
+								CallType:      vm.AsynchronousCall,
+								AsyncData:     makeAsyncData([]byte("asyncID"), []byte("contextID")),
+								Data:          []byte("remoteFunction@01"),
+							},
+						},
+					},
+				},
+			}, nil
+		},
+	}
+}
+
+func TestNewSimulator_NilHost(t *testing.T) {
+	simulator, err := NewSimulator(nil)
+	require.Nil(t, simulator)
+	require.Equal(t, arwen.ErrNilVMHost, err)
+}
+
+func TestSimulator_Simulate_FollowsAsyncCalls(t *testing.T) {
+	simulator, err := NewSimulator(newStubHost())
+	require.Nil(t, err)
+
+	input := &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:     userAddress,
+			CallValue:      big.NewInt(0),
+			GasPrice:       2,
+			GasProvided:    5000,
+			CurrentTxHash:  []byte("txHash"),
+			OriginalTxHash: []byte("txHash"),
+		},
+		RecipientAddr: contractAddress,
+		Function:      "call",
+	}
+	result, err := simulator.Simulate(input)
+	require.Nil(t, err)
+	require.Equal(t, vmcommon.Ok, result.ReturnCode)
+	require.Equal(t, [][]byte{contractAddress, remoteAddress, userAddress}, result.TouchedAccounts)
+
+	require.Len(t, result.AsyncCalls, 1)
+	asyncCall := result.AsyncCalls[0]
+	require.Equal(t, contractAddress, asyncCall.CallerAddr)
+	require.Equal(t, remoteAddress, asyncCall.RecipientAddr)
+	require.Equal(t, "remoteFunction", asyncCall.Function)
+	require.Equal(t, [][]byte{{1}}, asyncCall.Arguments)
+	require.Equal(t, big.NewInt(5), asyncCall.CallValue)
+	require.Equal(t, vm.AsynchronousCall, asyncCall.CallType)
+	require.Equal(t, uint64(2), asyncCall.GasPrice)
+	require.Equal(t, uint64(1000), asyncCall.GasProvided)
+	require.Equal(t, uint64(200), asyncCall.GasLocked)
+	require.Equal(t, []byte("txHash"), asyncCall.PrevTxHash)
+	require.Equal(t, &vmcommon.AsyncArguments{
+		CallID:       []byte("asyncID"),
+		CallerCallID: []byte("contextID"),
+	}, asyncCall.AsyncArguments)
+
+	// the generated async call is simulated in turn, as its shard would execute it
+	result, err = simulator.Simulate(asyncCall)
+	require.Nil(t, err)
+	require.Equal(t, [][]byte{contractAddress, remoteAddress}, result.TouchedAccounts)
+
+	require.Len(t, result.AsyncCalls, 1)
+	callback := result.AsyncCalls[0]
+	require.Equal(t, remoteAddress, callback.CallerAddr)
+	require.Equal(t, contractAddress, callback.RecipientAddr)
+	require.Equal(t, arwen.CallbackFunctionName, callback.Function)
+	require.Equal(t, [][]byte{{}, []byte("result")}, callback.Arguments)
+	require.Equal(t, vm.AsynchronousCallBack, callback.CallType)
+	require.Equal(t, uint64(300), callback.GasProvided)
+	require.Equal(t, &vmcommon.AsyncArguments{
+		CallID:                       []byte("callbackID"),
+		CallerCallID:                 []byte("asyncID"),
+		CallbackAsyncInitiatorCallID: []byte("contextID"),
+		GasAccumulated:               50,
+	}, callback.AsyncArguments)
+}
+
+func TestSimulator_Simulate_InvalidAsyncData(t *testing.T) {
+	host := &contextmock.VMHostStub{
+		RunSmartContractCallCalled: func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+			return &vmcommon.VMOutput{
+				OutputAccounts: map[string]*vmcommon.OutputAccount{
+					string(remoteAddress): {
+						Address: remoteAddress,
+						OutputTransfers: []vmcommon.OutputTransfer{{
+							SenderAddress: contractAddress,
+							CallType:      vm.AsynchronousCall,
+							AsyncData:     makeAsyncData([]byte("asyncID")),
+							Data:          []byte("remoteFunction"),
+						}},
+					},
+				},
+			}, nil
+		},
+	}
+	simulator, err := NewSimulator(host)
+	require.Nil(t, err)
+
+	result, err := simulator.Simulate(&vmcommon.ContractCallInput{RecipientAddr: contractAddress})
+	require.Nil(t, result)
+	require.Equal(t, ErrInvalidAsyncData, err)
+}
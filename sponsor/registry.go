@@ -0,0 +1,51 @@
+package sponsor
+
+import (
+	"errors"
+	"sync"
+)
+
+// ErrInvalidSponsorAddress signals that an empty address was registered as sponsor
+var ErrInvalidSponsorAddress = errors.New("invalid sponsor address")
+
+// Registry holds the contracts which may be asked to pay the gas of the calls
+// of other accounts
+type Registry struct {
+	mutSponsors sync.RWMutex
+	sponsors    map[string]struct{}
+}
+
+// NewRegistry creates an empty Registry
+func NewRegistry() *Registry {
+	return &Registry{
+		sponsors: make(map[string]struct{}),
+	}
+}
+
+// Register adds a sponsor contract
+func (registry *Registry) Register(sponsorAddr []byte) error {
+	if len(sponsorAddr) == 0 {
+		return ErrInvalidSponsorAddress
+	}
+
+	registry.mutSponsors.Lock()
+	registry.sponsors[string(sponsorAddr)] = struct{}{}
+	registry.mutSponsors.Unlock()
+	return nil
+}
+
+// Unregister removes a sponsor contract
+func (registry *Registry) Unregister(sponsorAddr []byte) {
+	registry.mutSponsors.Lock()
+	delete(registry.sponsors, string(sponsorAddr))
+	registry.mutSponsors.Unlock()
+}
+
+// IsRegistered returns whether the given contract is a registered sponsor
+func (registry *Registry) IsRegistered(sponsorAddr []byte) bool {
+	registry.mutSponsors.RLock()
+	defer registry.mutSponsors.RUnlock()
+
+	_, found := registry.sponsors[string(sponsorAddr)]
+	return found
+}
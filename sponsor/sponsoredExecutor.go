@@ -0,0 +1,204 @@
+package sponsor
+
+import (
+	"errors"
+	"math/big"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/fees"
+)
+
+// ApproveGasSponsorshipFunction is the endpoint of the sponsor contracts
+// which approves paying the gas of a call; it receives the caller, the
+// receiver, the function, the gas limit and the gas price of the call, and
+// approves it by returning a nonzero number
+const ApproveGasSponsorshipFunction = "approveGasSponsorship"
+
+// GasSponsoredIdentifier is the identifier of the log added to the output of
+// a sponsored call, with the sponsor as address and the caller, the receiver,
+// the gas used and the fee paid by the sponsor as topics
+const GasSponsoredIdentifier = "GasSponsored"
+
+// ErrNilFeeAccountant signals that a nil FeeAccountant was provided
+var ErrNilFeeAccountant = errors.New("nil fee accountant")
+
+// ErrNilRegistry signals that a nil Registry was provided
+var ErrNilRegistry = errors.New("nil sponsor registry")
+
+// ErrNilAccounts signals that a nil AccountsReader was provided
+var ErrNilAccounts = errors.New("nil accounts reader")
+
+// ErrInvalidApprovalGasLimit signals that no gas was allowed for the approval calls
+var ErrInvalidApprovalGasLimit = errors.New("invalid approval gas limit")
+
+// ErrSponsorNotRegistered signals that a call names a sponsor which is not in the registry
+var ErrSponsorNotRegistered = errors.New("sponsor not registered")
+
+// ErrSponsorshipNotApproved signals that the sponsor refused to pay for the call
+var ErrSponsorshipNotApproved = errors.New("gas sponsorship not approved")
+
+// ErrSponsorInsufficientBalance signals that the sponsor cannot pay for all the gas of the call
+var ErrSponsorInsufficientBalance = errors.New("insufficient sponsor balance")
+
+// AccountsReader reads the accounts of the world state, outside of any execution
+type AccountsReader interface {
+	GetUserAccount(address []byte) (vmcommon.UserAccountHandler, error)
+}
+
+// SponsoredOutput is the result of executing a call whose gas was paid by a sponsor
+type SponsoredOutput struct {
+	*vmcommon.VMOutput
+	SponsorAddr []byte
+	Fees        *fees.FeeDistribution
+}
+
+// ArgsNewSponsoredExecutor holds the arguments for creating a SponsoredExecutor
+type ArgsNewSponsoredExecutor struct {
+	Host       arwen.VMHost
+	Accountant *fees.FeeAccountant
+	Registry   *Registry
+	Accounts   AccountsReader
+	// ApprovalGasLimit is the gas given to the approval endpoint of the sponsor
+	ApprovalGasLimit uint64
+}
+
+// SponsoredExecutor runs calls whose gas is paid by a registered sponsor
+// contract instead of their caller
+type SponsoredExecutor struct {
+	host             arwen.VMHost
+	accountant       *fees.FeeAccountant
+	registry         *Registry
+	accounts         AccountsReader
+	approvalGasLimit uint64
+}
+
+// NewSponsoredExecutor creates a new SponsoredExecutor
+func NewSponsoredExecutor(args ArgsNewSponsoredExecutor) (*SponsoredExecutor, error) {
+	if check.IfNil(args.Host) {
+		return nil, arwen.ErrNilVMHost
+	}
+	if args.Accountant == nil {
+		return nil, ErrNilFeeAccountant
+	}
+	if args.Registry == nil {
+		return nil, ErrNilRegistry
+	}
+	if args.Accounts == nil {
+		return nil, ErrNilAccounts
+	}
+	if args.ApprovalGasLimit == 0 {
+		return nil, ErrInvalidApprovalGasLimit
+	}
+
+	return &SponsoredExecutor{
+		host:             args.Host,
+		accountant:       args.Accountant,
+		registry:         args.Registry,
+		accounts:         args.Accounts,
+		approvalGasLimit: args.ApprovalGasLimit,
+	}, nil
+}
+
+// Execute asks the sponsor to approve paying for the gas of the call, runs
+// the call and charges its fee to the sponsor. The caller is expected not to
+// have paid for the gas upfront. The approval is a synchronous call to the
+// sponsor, whose output is discarded, so that approving changes no state.
+func (executor *SponsoredExecutor) Execute(input *vmcommon.ContractCallInput, sponsorAddr []byte) (*SponsoredOutput, error) {
+	if !executor.registry.IsRegistered(sponsorAddr) {
+		return nil, ErrSponsorNotRegistered
+	}
+
+	err := executor.requestApproval(input, sponsorAddr)
+	if err != nil {
+		return nil, err
+	}
+
+	err = executor.checkSponsorBalance(input, sponsorAddr)
+	if err != nil {
+		return nil, err
+	}
+
+	vmOutput, err := executor.host.RunSmartContractCall(input)
+	if err != nil {
+		return nil, err
+	}
+
+	distribution, err := executor.accountant.ApplySponsoredFees(sponsorAddr, &input.VMInput, vmOutput)
+	if err != nil {
+		return nil, err
+	}
+
+	vmOutput.Logs = append(vmOutput.Logs, &vmcommon.LogEntry{
+		Identifier: []byte(GasSponsoredIdentifier),
+		Address:    sponsorAddr,
+		Topics: [][]byte{
+			input.CallerAddr,
+			input.RecipientAddr,
+			big.NewInt(0).SetUint64(distribution.GasUsed).Bytes(),
+			distribution.TotalFee.Bytes(),
+		},
+		Data: input.CurrentTxHash,
+	})
+
+	return &SponsoredOutput{
+		VMOutput:    vmOutput,
+		SponsorAddr: sponsorAddr,
+		Fees:        distribution,
+	}, nil
+}
+
+func (executor *SponsoredExecutor) requestApproval(input *vmcommon.ContractCallInput, sponsorAddr []byte) error {
+	approvalInput := &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr: input.CallerAddr,
+			Arguments: [][]byte{
+				input.CallerAddr,
+				input.RecipientAddr,
+				[]byte(input.Function),
+				big.NewInt(0).SetUint64(input.GasProvided).Bytes(),
+				big.NewInt(0).SetUint64(input.GasPrice).Bytes(),
+			},
+			CallValue:      big.NewInt(0),
+			CallType:       vm.DirectCall,
+			GasPrice:       input.GasPrice,
+			GasProvided:    executor.approvalGasLimit,
+			CurrentTxHash:  input.CurrentTxHash,
+			OriginalTxHash: input.OriginalTxHash,
+		},
+		RecipientAddr: sponsorAddr,
+		Function:      ApproveGasSponsorshipFunction,
+	}
+
+	approvalOutput, err := executor.host.RunSmartContractCall(approvalInput)
+	if err != nil {
+		return err
+	}
+	if approvalOutput.ReturnCode != vmcommon.Ok || len(approvalOutput.ReturnData) == 0 {
+		return ErrSponsorshipNotApproved
+	}
+	if big.NewInt(0).SetBytes(approvalOutput.ReturnData[0]).Sign() == 0 {
+		return ErrSponsorshipNotApproved
+	}
+
+	return nil
+}
+
+func (executor *SponsoredExecutor) checkSponsorBalance(input *vmcommon.ContractCallInput, sponsorAddr []byte) error {
+	account, err := executor.accounts.GetUserAccount(sponsorAddr)
+	if err != nil {
+		return err
+	}
+	if arwen.IfNil(account) {
+		return ErrSponsorInsufficientBalance
+	}
+
+	maxFee := big.NewInt(0).Mul(big.NewInt(0).SetUint64(input.GasProvided), big.NewInt(0).SetUint64(input.GasPrice))
+	if account.GetBalance().Cmp(maxFee) < 0 {
+		return ErrSponsorInsufficientBalance
+	}
+
+	return nil
+}
@@ -0,0 +1,130 @@
+package sponsor
+
+import (
+	"math/big"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/fees"
+	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+var userAddress = []byte("user____________________________")
+var sponsorAddress = []byte("sponsor_________________________")
+var contractAddress = []byte("contract________________________")
+var proposerAddress = []byte("proposer________________________")
+
+func makeSponsoredExecutor(t *testing.T, world *worldmock.MockWorld, approval []byte) (*SponsoredExecutor, *[]*vmcommon.ContractCallInput) {
+	computer, err := fees.NewFeeComputer(fees.FeeConfig{
+		MinGasLimit:      1000,
+		GasPriceModifier: fees.GasPriceModifierDenominator,
+	})
+	require.Nil(t, err)
+	accountant, err := fees.NewFeeAccountant(computer, world, proposerAddress)
+	require.Nil(t, err)
+
+	registry := NewRegistry()
+	require.Nil(t, registry.Register(sponsorAddress))
+
+	executedCalls := make([]*vmcommon.ContractCallInput, 0)
+	host := &contextmock.VMHostStub{
+		RunSmartContractCallCalled: func(input *vmcommon.ContractCallInput) (*vmcommon.VMOutput, error) {
+			executedCalls = append(executedCalls, input)
+			if input.Function == ApproveGasSponsorshipFunction {
+				return &vmcommon.VMOutput{ReturnCode: vmcommon.Ok, ReturnData: [][]byte{approval}}, nil
+			}
+			return &vmcommon.VMOutput{
+				ReturnCode:     vmcommon.Ok,
+				GasRemaining:   input.GasProvided - 600,
+				OutputAccounts: make(map[string]*vmcommon.OutputAccount),
+			}, nil
+		},
+	}
+
+	executor, err := NewSponsoredExecutor(ArgsNewSponsoredExecutor{
+		Host:             host,
+		Accountant:       accountant,
+		Registry:         registry,
+		Accounts:         world,
+		ApprovalGasLimit: 5000,
+	})
+	require.Nil(t, err)
+	return executor, &executedCalls
+}
+
+func makeSponsoredWorld(sponsorBalance int64) *worldmock.MockWorld {
+	world := worldmock.NewMockWorld()
+	world.AcctMap.CreateAccount(userAddress, world).Balance = big.NewInt(0)
+	world.AcctMap.CreateAccount(sponsorAddress, world).Balance = big.NewInt(sponsorBalance)
+	world.AcctMap.CreateAccount(proposerAddress, world)
+	return world
+}
+
+func makeUserCall() *vmcommon.ContractCallInput {
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:    userAddress,
+			CallValue:     big.NewInt(0),
+			GasPrice:      10,
+			GasProvided:   2000,
+			CurrentTxHash: []byte("txHash"),
+		},
+		RecipientAddr: contractAddress,
+		Function:      "doSomething",
+	}
+}
+
+func TestSponsoredExecutor_Execute(t *testing.T) {
+	world := makeSponsoredWorld(100000)
+	executor, executedCalls := makeSponsoredExecutor(t, world, []byte{1})
+
+	output, err := executor.Execute(makeUserCall(), sponsorAddress)
+	require.Nil(t, err)
+	require.Len(t, *executedCalls, 2)
+
+	approvalInput := (*executedCalls)[0]
+	require.Equal(t, sponsorAddress, approvalInput.RecipientAddr)
+	require.Equal(t, userAddress, approvalInput.CallerAddr)
+	require.Equal(t, uint64(5000), approvalInput.GasProvided)
+	require.Equal(t, []byte("doSomething"), approvalInput.Arguments[2])
+
+	require.Equal(t, uint64(600), output.Fees.GasUsed)
+	require.Equal(t, big.NewInt(6000), output.Fees.TotalFee)
+	require.Equal(t, big.NewInt(0), output.Fees.Refund)
+	require.Equal(t, big.NewInt(100000-6000), world.AcctMap.GetAccount(sponsorAddress).Balance)
+	require.Equal(t, big.NewInt(0), world.AcctMap.GetAccount(userAddress).Balance)
+	require.Equal(t, big.NewInt(6000), world.AcctMap.GetAccount(proposerAddress).Balance)
+
+	require.Len(t, output.Logs, 1)
+	require.Equal(t, []byte(GasSponsoredIdentifier), output.Logs[0].Identifier)
+	require.Equal(t, sponsorAddress, output.Logs[0].Address)
+	require.Equal(t, [][]byte{userAddress, contractAddress, big.NewInt(600).Bytes(), big.NewInt(6000).Bytes()}, output.Logs[0].Topics)
+}
+
+func TestSponsoredExecutor_Rejected(t *testing.T) {
+	world := makeSponsoredWorld(100000)
+	executor, executedCalls := makeSponsoredExecutor(t, world, []byte{0})
+
+	output, err := executor.Execute(makeUserCall(), sponsorAddress)
+	require.Nil(t, output)
+	require.Equal(t, ErrSponsorshipNotApproved, err)
+	require.Len(t, *executedCalls, 1)
+	require.Equal(t, big.NewInt(100000), world.AcctMap.GetAccount(sponsorAddress).Balance)
+
+	output, err = executor.Execute(makeUserCall(), contractAddress)
+	require.Nil(t, output)
+	require.Equal(t, ErrSponsorNotRegistered, err)
+	require.Len(t, *executedCalls, 1)
+}
+
+func TestSponsoredExecutor_InsufficientSponsorBalance(t *testing.T) {
+	world := makeSponsoredWorld(19999)
+	executor, executedCalls := makeSponsoredExecutor(t, world, []byte{1})
+
+	output, err := executor.Execute(makeUserCall(), sponsorAddress)
+	require.Nil(t, output)
+	require.Equal(t, ErrSponsorInsufficientBalance, err)
+	require.Len(t, *executedCalls, 1)
+}
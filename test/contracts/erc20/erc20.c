@@ -6,6 +6,7 @@ byte sender[32]        = {0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 byte recipient[32]     = {0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0};
 byte caller[32]        = {0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0};
 byte currentKey[32]    = {0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0};
+byte zeroAddress[32]   = {0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0};
 
 byte approveEvent[32]  = {0x71,0x34,0x69,0x2B,0x23,0x0B,0x9E,0x1F,0xFA,0x39,0x09,0x89,0x04,0x72,0x21,0x34,0x15,0x96,0x52,0xB0,0x9C,0x5B,0xC4,0x1D,0x88,0xD6,0x69,0x87,0x79,0xD2,0x28,0xFF};
 byte transferEvent[32] = {0xF0,0x99,0xCD,0x8B,0xDE,0x55,0x78,0x14,0x84,0x2A,0x31,0x21,0xE8,0xDD,0xFD,0x43,0x3A,0x53,0x9B,0x8C,0x9F,0x14,0xBF,0x31,0xEB,0xF1,0x08,0xD1,0x2E,0x61,0x96,0xE9};
@@ -318,5 +319,54 @@ void transferFrom() {
   int64finish(1); 
 }
 
+// owner creates new tokens and gives them to an account
+// the total supply grows by the same amount; the operation is logged
+// as a transfer from the zero address
+void mint() {
+  if (getNumArguments() != 2) {
+    byte message[] = "wrong args num";
+    signalError(message, 14);
+    return;
+  }
+
+  // only the owner of the contract can mint
+  getCaller(caller);
+  getOwnerAddress(sender);
+  for (int i = 0; i < 32; i++) {
+    if (caller[i] != sender[i]) {
+      byte message[] = "only owner can mint";
+      signalError(message, 19);
+      return;
+    }
+  }
+
+  // 1st argument: recipient
+  getArgument(0, recipient);
+
+  // 2nd argument: amount
+  bigInt amount = bigIntNew(0);
+  bigIntGetUnsignedArgument(1, amount);
+
+  // load & update total supply
+  computeTotalSupplyKey(currentKey);
+  bigInt totalSupply = bigIntNew(0);
+  bigIntStorageLoadUnsigned(currentKey, 32, totalSupply);
+  bigIntAdd(totalSupply, totalSupply, amount);
+  bigIntStorageStoreUnsigned(currentKey, 32, totalSupply);
+
+  // load & update receiver balance
+  computeBalanceKey(currentKey, recipient);
+  bigInt receiverBalance = bigIntNew(0);
+  bigIntStorageLoadUnsigned(currentKey, 32, receiverBalance);
+  bigIntAdd(receiverBalance, receiverBalance, amount);
+  bigIntStorageStoreUnsigned(currentKey, 32, receiverBalance);
+
+  // log operation
+  saveLogWith3Topics(transferEvent, zeroAddress, recipient, amount);
+
+  // return "true"
+  int64finish(1); 
+}
+
 void _main(void) {
 }
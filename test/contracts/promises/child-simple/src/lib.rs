@@ -3,32 +3,44 @@
 #![allow(unused_attributes)]
 #![feature(lang_items)]
 
-use elrond_wasm::api::{EndpointArgumentApi, EndpointFinishApi, ErrorApi};
 use elrond_wasm_node::ArwenApiImpl;
 
+use promises_common::{build_info, endpoints};
+
 pub static EEI: ArwenApiImpl = ArwenApiImpl{};
 
-#[no_mangle]
-pub extern "C" fn answer() {
-    EEI.finish_u64(42);
+endpoints! {
+    module: implementation;
+    answer,
+    answer_wrong,
+    echo(1),
+    fail,
 }
 
-#[no_mangle]
-pub extern "C" fn answer_wrong() {
-    EEI.finish_u64(24);
-}
+build_info!();
 
-// receives u64 as argument and returns it back
-#[no_mangle]
-pub extern "C" fn echo() {
-    EEI.check_num_arguments(1);
+mod implementation {
+    use elrond_wasm::api::{EndpointArgumentApi, EndpointFinishApi, ErrorApi};
+    use promises_common::{in_gas_phase, GasPhase};
 
-    let arg = EEI.get_argument_u64(0);
+    use super::EEI;
 
-    EEI.finish_u64(arg);
-}
+    pub fn answer() {
+        EEI.finish_u64(42);
+    }
+
+    pub fn answer_wrong() {
+        EEI.finish_u64(24);
+    }
+
+    // receives u64 as argument and returns it back
+    pub fn echo() {
+        let arg = EEI.get_argument_u64(0);
+
+        in_gas_phase(GasPhase::Finish, || EEI.finish_u64(arg));
+    }
 
-#[no_mangle]
-pub extern "C" fn fail() {
-    EEI.signal_error(&b"fail"[..]);
+    pub fn fail() {
+        EEI.signal_error(&b"fail"[..]);
+    }
 }
@@ -0,0 +1,43 @@
+#![no_std]
+
+use promises_common::*;
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::arch::wasm32::unreachable()
+}
+
+const STORAGE_KEY_PREFIX: &[u8] = b"promise";
+const MAX_CALL_DATA_LEN: usize = 64;
+
+endpoints! {
+    module: implementation;
+    call_child,
+    callback(..),
+}
+
+build_info!();
+
+mod implementation {
+    use super::*;
+
+    pub fn call_child() {
+        let mut key = [0u8; 16];
+        let key = write_storage_key(&[STORAGE_KEY_PREFIX, b".group"], &mut key);
+
+        let mut data = [0u8; MAX_CALL_DATA_LEN];
+        let data = CallDataWriter::new(&mut data, b"echo").argument_u64(7).build();
+
+        if let Some(group) = create_promise_group(key) {
+            let _ = create_promise(group, &CHILD_ADDRESS, &ZERO, data, b"callback", b"callback", GAS_5M);
+        }
+    }
+
+    pub fn callback() {
+        in_gas_phase(GasPhase::Async, || {
+            let mut argument = [0u8; 8];
+            let _ = decode_hex_argument_into(b"07", &mut argument);
+            let _ = CHILD_ADDRESS.shard(3);
+        });
+    }
+}
@@ -0,0 +1,27 @@
+use std::env;
+use std::process::Command;
+
+// Passes the compiler version and the settings of the build to the buildinfo
+// section, which cannot read them from the environment of the compilation.
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let rustc_version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_string())
+        .unwrap_or_default();
+
+    let flags = format!(
+        "profile={} opt-level={} debug={}",
+        env::var("PROFILE").unwrap_or_default(),
+        env::var("OPT_LEVEL").unwrap_or_default(),
+        env::var("DEBUG").unwrap_or_default(),
+    );
+
+    println!("cargo:rustc-env=PROMISES_COMMON_RUSTC_VERSION={}", rustc_version);
+    println!("cargo:rustc-env=PROMISES_COMMON_BUILD_FLAGS={}", flags);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+}
@@ -0,0 +1,378 @@
+#[cfg(feature = "alloc")]
+use elrond_wasm::Address;
+
+pub const ADDRESS_LEN: usize = 32;
+
+pub const BECH32_HRP: &str = "erd";
+pub const BECH32_ADDRESS_LEN: usize = 62;
+
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+pub const ZERO_ADDRESS: [u8; ADDRESS_LEN] = [0u8; ADDRESS_LEN];
+pub const SYSTEM_ACCOUNT_ADDRESS: [u8; ADDRESS_LEN] = [0xFFu8; ADDRESS_LEN];
+pub const ESDT_SYSTEM_SC_ADDRESS: [u8; ADDRESS_LEN] =
+    address_from_bech32("erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u");
+
+const SC_ADDRESS_ZEROS_LEN: usize = 8;
+const SC_ADDRESS_PREFIX_LEN: usize = 10;
+const METACHAIN_SC_ZEROS_LEN: usize = 5;
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_DATA_LEN: usize = 52;
+const BECH32_CHECKSUM_LEN: usize = 6;
+const BECH32_GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// Decodes an `erd1...` address. Being a `const fn`, it can initialize the
+/// address constants of the contracts; an invalid address, or one with a wrong
+/// checksum, fails the compilation of such a constant.
+pub const fn address_from_bech32(bech32: &str) -> [u8; ADDRESS_LEN] {
+    let bech32 = bech32.as_bytes();
+    let hrp = BECH32_HRP.as_bytes();
+    if bech32.len() != BECH32_ADDRESS_LEN {
+        panic!("invalid bech32 address length");
+    }
+
+    let mut checksum = bech32_hrp_checksum();
+    let mut i = 0;
+    while i < hrp.len() {
+        if bech32[i] != hrp[i] {
+            panic!("invalid bech32 address prefix");
+        }
+        i += 1;
+    }
+    if bech32[hrp.len()] != b'1' {
+        panic!("invalid bech32 address separator");
+    }
+
+    let mut address = [0u8; ADDRESS_LEN];
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    let mut written = 0;
+    i = hrp.len() + 1;
+    while i < BECH32_ADDRESS_LEN {
+        let value = bech32_char_value(bech32[i]);
+        checksum = bech32_polymod_step(checksum, value);
+
+        if i < hrp.len() + 1 + BECH32_DATA_LEN {
+            accumulator = (accumulator << 5) | value as u32;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                address[written] = (accumulator >> bits) as u8;
+                written += 1;
+            }
+        }
+        i += 1;
+    }
+
+    if accumulator & ((1 << bits) - 1) != 0 {
+        panic!("invalid bech32 address padding");
+    }
+    if checksum != 1 {
+        panic!("invalid bech32 address checksum");
+    }
+    address
+}
+
+/// Encodes an address as `erd1...`; the bytes returned are ASCII.
+pub const fn address_to_bech32(address: &[u8; ADDRESS_LEN]) -> [u8; BECH32_ADDRESS_LEN] {
+    let hrp = BECH32_HRP.as_bytes();
+    let mut bech32 = [0u8; BECH32_ADDRESS_LEN];
+    let mut i = 0;
+    while i < hrp.len() {
+        bech32[i] = hrp[i];
+        i += 1;
+    }
+    bech32[hrp.len()] = b'1';
+
+    let mut checksum = bech32_hrp_checksum();
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    let mut position = hrp.len() + 1;
+    i = 0;
+    while position < hrp.len() + 1 + BECH32_DATA_LEN {
+        if bits < 5 {
+            // the last group is padded with zero bits
+            accumulator <<= 8;
+            if i < ADDRESS_LEN {
+                accumulator |= address[i] as u32;
+            }
+            i += 1;
+            bits += 8;
+        }
+        bits -= 5;
+        let value = ((accumulator >> bits) & 0x1F) as u8;
+        checksum = bech32_polymod_step(checksum, value);
+        bech32[position] = BECH32_CHARSET[value as usize];
+        position += 1;
+    }
+
+    i = 0;
+    while i < BECH32_CHECKSUM_LEN {
+        checksum = bech32_polymod_step(checksum, 0);
+        i += 1;
+    }
+    checksum ^= 1;
+
+    i = 0;
+    while i < BECH32_CHECKSUM_LEN {
+        let value = (checksum >> (5 * (BECH32_CHECKSUM_LEN - 1 - i))) & 0x1F;
+        bech32[position] = BECH32_CHARSET[value as usize];
+        position += 1;
+        i += 1;
+    }
+    bech32
+}
+
+/// Decodes an address given as 64 hex digits, with or without `0x`.
+pub const fn address_from_hex(hex: &str) -> [u8; ADDRESS_LEN] {
+    let hex = hex.as_bytes();
+    let mut offset = 0;
+    if hex.len() == 2 * ADDRESS_LEN + 2 && hex[0] == b'0' && (hex[1] == b'x' || hex[1] == b'X') {
+        offset = 2;
+    }
+    if hex.len() != offset + 2 * ADDRESS_LEN {
+        panic!("invalid hex address length");
+    }
+
+    let mut address = [0u8; ADDRESS_LEN];
+    let mut i = 0;
+    while i < ADDRESS_LEN {
+        let high = hex_digit_value(hex[offset + 2 * i]);
+        let low = hex_digit_value(hex[offset + 2 * i + 1]);
+        address[i] = (high << 4) | low;
+        i += 1;
+    }
+    address
+}
+
+/// Smart contract addresses start with 8 zero bytes, followed by the VM type.
+pub const fn is_smart_contract_address(address: &[u8; ADDRESS_LEN]) -> bool {
+    let mut i = 0;
+    while i < SC_ADDRESS_ZEROS_LEN {
+        if address[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+pub const fn is_system_account_address(address: &[u8; ADDRESS_LEN]) -> bool {
+    let mut i = 0;
+    while i < ADDRESS_LEN {
+        if address[i] != 0xFF {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Computes the shard of an address as the node does: the system smart
+/// contracts belong to the metachain, and the others to the shard given by
+/// the last bytes of the address.
+pub const fn address_shard(address: &[u8; ADDRESS_LEN], num_shards: u32) -> u32 {
+    if is_metachain_sc_address(address) {
+        return METACHAIN_SHARD_ID;
+    }
+    if num_shards <= 1 {
+        return 0;
+    }
+
+    let bytes_needed = if num_shards <= 1 << 8 {
+        1
+    } else if num_shards <= 1 << 16 {
+        2
+    } else if num_shards <= 1 << 24 {
+        3
+    } else {
+        4
+    };
+    let mut value: u32 = 0;
+    let mut i = ADDRESS_LEN - bytes_needed;
+    while i < ADDRESS_LEN {
+        value = (value << 8) | address[i] as u32;
+        i += 1;
+    }
+
+    let num_bits = u32::BITS - (num_shards - 1).leading_zeros();
+    let mask_high = ((1u64 << num_bits) - 1) as u32;
+    let mask_low = ((1u64 << (num_bits - 1)) - 1) as u32;
+    let shard = value & mask_high;
+    if shard > num_shards - 1 {
+        return value & mask_low;
+    }
+    shard
+}
+
+/// Helpers of the `Address` type, for the addresses received from the API.
+pub trait AddressHelpers {
+    fn to_address_bytes(&self) -> [u8; ADDRESS_LEN];
+
+    fn to_bech32(&self) -> [u8; BECH32_ADDRESS_LEN] {
+        address_to_bech32(&self.to_address_bytes())
+    }
+
+    fn shard(&self, num_shards: u32) -> u32 {
+        address_shard(&self.to_address_bytes(), num_shards)
+    }
+
+    fn is_smart_contract(&self) -> bool {
+        is_smart_contract_address(&self.to_address_bytes())
+    }
+
+    fn is_system_account(&self) -> bool {
+        is_system_account_address(&self.to_address_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AddressHelpers for Address {
+    fn to_address_bytes(&self) -> [u8; ADDRESS_LEN] {
+        let mut address = [0u8; ADDRESS_LEN];
+        address.copy_from_slice(self.as_ref());
+        address
+    }
+}
+
+impl AddressHelpers for [u8; ADDRESS_LEN] {
+    fn to_address_bytes(&self) -> [u8; ADDRESS_LEN] {
+        *self
+    }
+}
+
+const fn is_metachain_sc_address(address: &[u8; ADDRESS_LEN]) -> bool {
+    if !is_smart_contract_address(address) {
+        return false;
+    }
+    let mut i = SC_ADDRESS_PREFIX_LEN;
+    while i < SC_ADDRESS_PREFIX_LEN + METACHAIN_SC_ZEROS_LEN {
+        if address[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn bech32_hrp_checksum() -> u32 {
+    let hrp = BECH32_HRP.as_bytes();
+    let mut checksum = 1;
+    let mut i = 0;
+    while i < hrp.len() {
+        checksum = bech32_polymod_step(checksum, hrp[i] >> 5);
+        i += 1;
+    }
+    checksum = bech32_polymod_step(checksum, 0);
+    i = 0;
+    while i < hrp.len() {
+        checksum = bech32_polymod_step(checksum, hrp[i] & 0x1F);
+        i += 1;
+    }
+    checksum
+}
+
+const fn bech32_polymod_step(checksum: u32, value: u8) -> u32 {
+    let top = checksum >> 25;
+    let mut checksum = ((checksum & 0x1FFFFFF) << 5) ^ value as u32;
+    let mut i = 0;
+    while i < BECH32_GENERATORS.len() {
+        if (top >> i) & 1 == 1 {
+            checksum ^= BECH32_GENERATORS[i];
+        }
+        i += 1;
+    }
+    checksum
+}
+
+const fn bech32_char_value(c: u8) -> u8 {
+    let mut i = 0;
+    while i < BECH32_CHARSET.len() {
+        if BECH32_CHARSET[i] == c {
+            return i as u8;
+        }
+        i += 1;
+    }
+    panic!("invalid bech32 address character")
+}
+
+const fn hex_digit_value(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("invalid hex address digit"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE_BECH32: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+    const ALICE_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+    const BOB_BECH32: &str = "erd1spyavw0956vq68xj8y4tenjpq2wd5a9p2c6j8gsz7ztyrnpxrruqzu66jx";
+    const BOB_HEX: &str = "8049d639e5a6980d1cd2392abcce41029cda74a1563523a202f09641cc2618f8";
+    const ESDT_SYSTEM_SC_BECH32: &str = "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
+    const ESDT_SYSTEM_SC_HEX: &str = "000000000000000000010000000000000000000000000000000000000002ffff";
+
+    #[test]
+    fn test_bech32_round_trip() {
+        for (bech32, hex) in [
+            (ALICE_BECH32, ALICE_HEX),
+            (BOB_BECH32, BOB_HEX),
+            (ESDT_SYSTEM_SC_BECH32, ESDT_SYSTEM_SC_HEX),
+        ] {
+            let address = address_from_hex(hex);
+            assert_eq!(address_from_bech32(bech32), address);
+            assert_eq!(&address_to_bech32(&address)[..], bech32.as_bytes());
+        }
+        assert_eq!(ESDT_SYSTEM_SC_ADDRESS, address_from_hex(ESDT_SYSTEM_SC_HEX));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid bech32 address checksum")]
+    fn test_bech32_bad_checksum() {
+        address_from_bech32("erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6tq");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid bech32 address padding")]
+    fn test_bech32_bad_padding() {
+        address_from_bech32("erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8spycr6th");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid bech32 address prefix")]
+    fn test_bech32_bad_prefix() {
+        address_from_bech32("xrd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th");
+    }
+
+    #[test]
+    fn test_address_shard_metachain() {
+        assert_eq!(address_shard(&ESDT_SYSTEM_SC_ADDRESS, 1), METACHAIN_SHARD_ID);
+        assert_eq!(address_shard(&ESDT_SYSTEM_SC_ADDRESS, 3), METACHAIN_SHARD_ID);
+        assert_ne!(address_shard(&crate::PARENT_ADDRESS, 3), METACHAIN_SHARD_ID);
+    }
+
+    #[test]
+    fn test_address_shard_single_shard() {
+        let mut address = address_from_hex(ALICE_HEX);
+        for last_byte in [0x00, 0x01, 0x02, 0xFF] {
+            address[ADDRESS_LEN - 1] = last_byte;
+            assert_eq!(address_shard(&address, 1), 0);
+        }
+    }
+
+    #[test]
+    fn test_address_shard_three_shards() {
+        let mut address = address_from_hex(ALICE_HEX);
+        // the last two bits give the shard, unless they make 3, which is not a
+        // shard: then only the last bit does
+        for (last_byte, shard) in [(0x00, 0), (0x01, 1), (0x02, 2), (0x03, 1), (0x04, 0), (0x07, 1), (0xFE, 2)] {
+            address[ADDRESS_LEN - 1] = last_byte;
+            assert_eq!(address_shard(&address, 3), shard, "last byte {:#04x}", last_byte);
+        }
+    }
+}
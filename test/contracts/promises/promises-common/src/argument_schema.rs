@@ -0,0 +1,55 @@
+use crate::section::{leb128_len, names_len, write_leb128, write_names};
+
+/// The name of the custom section declaring the arguments of the endpoints of
+/// a contract, which the VM checks before instantiating the contract. It holds
+/// a vector of entries, each made of the name of an endpoint, the minimum and
+/// maximum numbers of its arguments, and a vector of the maximum sizes of the
+/// arguments, all numbers as unsigned LEB128.
+pub const ARGUMENT_SCHEMA_SECTION_NAME: &str = "argschema";
+
+/// The maximum number of arguments of the endpoints taking any number of them.
+pub const UNBOUNDED_ARGUMENTS: u32 = u32::MAX;
+
+/// The numbers of arguments accepted by an endpoint; the sizes of the
+/// arguments are not declared, so they may be of any size.
+#[derive(Clone, Copy)]
+pub struct ArgumentCount {
+    pub min: u32,
+    pub max: u32,
+}
+
+/// The size of the argument schema section of the given endpoints.
+pub const fn argument_schema_section_len(names: &[&str], counts: &[ArgumentCount]) -> usize {
+    let mut len = leb128_len(names.len()) + names_len(names);
+    let mut i = 0;
+    while i < counts.len() {
+        // the vector of sizes is empty
+        len += leb128_len(counts[i].min as usize) + leb128_len(counts[i].max as usize) + 1;
+        i += 1;
+    }
+    len
+}
+
+/// Encodes the argument schema section of the given endpoints; `N` must be
+/// their `argument_schema_section_len`.
+pub const fn argument_schema_section<const N: usize>(names: &[&str], counts: &[ArgumentCount]) -> [u8; N] {
+    if names.len() != counts.len() {
+        panic!("wrong number of argument counts");
+    }
+
+    let (mut section, mut offset) = write_leb128([0u8; N], 0, names.len());
+    let mut i = 0;
+    while i < names.len() {
+        let (written, next) = write_names(section, offset, &[names[i]]);
+        let (written, next) = write_leb128(written, next, counts[i].min as usize);
+        let (written, next) = write_leb128(written, next, counts[i].max as usize);
+        let (written, next) = write_leb128(written, next, 0);
+        section = written;
+        offset = next;
+        i += 1;
+    }
+    if offset != N {
+        panic!("wrong argument schema section length");
+    }
+    section
+}
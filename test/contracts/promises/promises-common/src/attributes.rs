@@ -0,0 +1,119 @@
+pub const ATTRIBUTES_SEPARATOR: u8 = b';';
+pub const ATTRIBUTE_VALUE_SEPARATOR: u8 = b':';
+pub const TAGS_SEPARATOR: u8 = b',';
+
+pub const METADATA_KEY: &[u8] = b"metadata";
+pub const TAGS_KEY: &[u8] = b"tags";
+
+/// Finds the value of a key in NFT attributes in the standard
+/// `key:value;key:value` layout, as the host function
+/// managedGetESDTNFTAttributeValue does.
+pub fn attribute_value<'a>(attributes: &'a [u8], key: &[u8]) -> Option<&'a [u8]> {
+    for pair in attributes.split(|byte| *byte == ATTRIBUTES_SEPARATOR) {
+        if let Some(separator) = pair.iter().position(|byte| *byte == ATTRIBUTE_VALUE_SEPARATOR) {
+            if &pair[..separator] == key {
+                return Some(&pair[separator + 1..]);
+            }
+        }
+    }
+    None
+}
+
+/// The IPFS CID of the metadata file, from `metadata:CID/file.json`.
+pub fn metadata_cid(attributes: &[u8]) -> Option<&[u8]> {
+    let metadata = attribute_value(attributes, METADATA_KEY)?;
+    let end = metadata.iter().position(|byte| *byte == b'/').unwrap_or(metadata.len());
+    Some(&metadata[..end])
+}
+
+/// The tags of the NFT, from `tags:tag1,tag2`; none if the key is missing.
+pub fn attribute_tags(attributes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    attribute_value(attributes, TAGS_KEY)
+        .unwrap_or(&[])
+        .split(|byte| *byte == TAGS_SEPARATOR)
+        .filter(|tag| !tag.is_empty())
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AttributesError {
+    InputTooShort,
+    InvalidBool,
+}
+
+impl AttributesError {
+    pub fn message(&self) -> &'static [u8] {
+        match self {
+            AttributesError::InputTooShort => b"attributes too short",
+            AttributesError::InvalidBool => b"attributes bool not 0 or 1",
+        }
+    }
+}
+
+/// Reads NFT attributes in the nested encoding of the contracts: the numbers
+/// as big endian bytes of fixed length, and the byte strings prefixed by their
+/// length as a u32.
+pub struct AttributesReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> AttributesReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        AttributesReader { data, position: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position == self.data.len()
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, AttributesError> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, AttributesError> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(AttributesError::InvalidBool),
+        }
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, AttributesError> {
+        let mut bytes = [0u8; 2];
+        bytes.copy_from_slice(self.read_slice(2)?);
+        Ok(u16::from_be_bytes(bytes))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, AttributesError> {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(self.read_slice(4)?);
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, AttributesError> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.read_slice(8)?);
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    pub fn read_bytes(&mut self) -> Result<&'a [u8], AttributesError> {
+        let length = self.read_u32()? as usize;
+        self.read_slice(length)
+    }
+
+    /// The bytes not read yet, such as a top encoded last field.
+    pub fn read_remaining(&mut self) -> &'a [u8] {
+        let remaining = &self.data[self.position..];
+        self.position = self.data.len();
+        remaining
+    }
+
+    fn read_slice(&mut self, length: usize) -> Result<&'a [u8], AttributesError> {
+        if self.data.len() - self.position < length {
+            return Err(AttributesError::InputTooShort);
+        }
+        let slice = &self.data[self.position..self.position + length];
+        self.position += length;
+        Ok(slice)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::section::{leb128_len, names_len, write_leb128, write_names};
+
+/// The name of the custom section recording how a contract was built. It
+/// holds a vector of key and value pairs, in the wasm encoding: the number of
+/// pairs, then the key and the value of each pair as names.
+pub const BUILD_INFO_SECTION_NAME: &str = "buildinfo";
+
+/// The contract framework the test contracts are built on; it follows the
+/// version of the elrond-wasm dependency in Cargo.toml.
+pub const FRAMEWORK_VERSION: &str = "elrond-wasm 0.11.0";
+
+/// The output of `rustc --version` for the compiler which built this crate,
+/// as found by the build script.
+pub const RUSTC_VERSION: &str = env!("PROMISES_COMMON_RUSTC_VERSION");
+
+/// The profile, optimization level and debug setting of the build.
+pub const BUILD_FLAGS: &str = env!("PROMISES_COMMON_BUILD_FLAGS");
+
+const BUILD_INFO: [&str; 6] = [
+    "framework",
+    FRAMEWORK_VERSION,
+    "rustc",
+    RUSTC_VERSION,
+    "flags",
+    BUILD_FLAGS,
+];
+
+#[doc(hidden)]
+pub const BUILD_INFO_SECTION_LEN: usize = leb128_len(BUILD_INFO.len() / 2) + names_len(&BUILD_INFO);
+
+#[doc(hidden)]
+pub const BUILD_INFO_SECTION: [u8; BUILD_INFO_SECTION_LEN] = build_info_section();
+
+const fn build_info_section() -> [u8; BUILD_INFO_SECTION_LEN] {
+    let (section, offset) = write_leb128([0u8; BUILD_INFO_SECTION_LEN], 0, BUILD_INFO.len() / 2);
+    let (section, offset) = write_names(section, offset, &BUILD_INFO);
+    if offset != BUILD_INFO_SECTION_LEN {
+        panic!("wrong buildinfo section length");
+    }
+    section
+}
+
+/// Writes the buildinfo section to the contract binary, which the VM logs
+/// when the contract is deployed:
+///
+/// ```ignore
+/// build_info!();
+/// ```
+#[macro_export]
+macro_rules! build_info {
+    () => {
+        #[used]
+        #[link_section = "buildinfo"]
+        static __BUILD_INFO_SECTION: [u8; $crate::BUILD_INFO_SECTION_LEN] = $crate::BUILD_INFO_SECTION;
+    };
+}
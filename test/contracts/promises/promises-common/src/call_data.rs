@@ -0,0 +1,243 @@
+#[cfg(feature = "alloc")]
+use elrond_wasm::Vec;
+
+pub const CALL_DATA_SEPARATOR: u8 = b'@';
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Writes call data in the `function@arg1@arg2` format into a buffer, the way a
+/// CallDataBuilder does, for the contracts built without an allocator; it
+/// panics when the buffer is too small.
+pub struct CallDataWriter<'a> {
+    buffer: &'a mut [u8],
+    length: usize,
+}
+
+impl<'a> CallDataWriter<'a> {
+    pub fn new(buffer: &'a mut [u8], function: &[u8]) -> Self {
+        buffer[..function.len()].copy_from_slice(function);
+        CallDataWriter {
+            buffer,
+            length: function.len(),
+        }
+    }
+
+    pub fn argument(mut self, argument: &[u8]) -> Self {
+        self.buffer[self.length] = CALL_DATA_SEPARATOR;
+        self.length += 1;
+        for byte in argument {
+            self.buffer[self.length] = HEX_DIGITS[(byte >> 4) as usize];
+            self.buffer[self.length + 1] = HEX_DIGITS[(byte & 0x0F) as usize];
+            self.length += 2;
+        }
+        self
+    }
+
+    /// Adds the number as big endian bytes without leading zeros, like
+    /// `CallDataBuilder::argument_u64`.
+    pub fn argument_u64(self, value: u64) -> Self {
+        let bytes = value.to_be_bytes();
+        let leading_zeros = (value.leading_zeros() / 8) as usize;
+        self.argument(&bytes[leading_zeros..])
+    }
+
+    pub fn build(self) -> &'a [u8] {
+        &self.buffer[..self.length]
+    }
+}
+
+/// Builds call data in the `function@arg1@arg2` format, with the arguments in
+/// lowercase hex, as built on the host side by txDataBuilder and parsed by the
+/// CallArgsParser of elrond-vm-common.
+#[cfg(feature = "alloc")]
+pub struct CallDataBuilder {
+    data: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl CallDataBuilder {
+    pub fn new(function: &[u8]) -> Self {
+        let mut data = Vec::with_capacity(function.len());
+        data.extend_from_slice(function);
+        CallDataBuilder { data }
+    }
+
+    pub fn argument(mut self, argument: &[u8]) -> Self {
+        self.data.reserve(1 + 2 * argument.len());
+        self.data.push(CALL_DATA_SEPARATOR);
+        for byte in argument {
+            self.data.push(HEX_DIGITS[(byte >> 4) as usize]);
+            self.data.push(HEX_DIGITS[(byte & 0x0F) as usize]);
+        }
+        self
+    }
+
+    /// Adds the number as big endian bytes without leading zeros, so that zero
+    /// is an empty argument, as the contracts encode numbers.
+    pub fn argument_u64(self, value: u64) -> Self {
+        let bytes = value.to_be_bytes();
+        let leading_zeros = (value.leading_zeros() / 8) as usize;
+        self.argument(&bytes[leading_zeros..])
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+
+    pub fn build(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq)]
+pub struct CallData {
+    pub function: Vec<u8>,
+    pub arguments: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CallDataError {
+    EmptyFunction,
+    OddArgumentLength,
+    InvalidHexDigit,
+}
+
+impl CallDataError {
+    pub fn message(&self) -> &'static [u8] {
+        match self {
+            CallDataError::EmptyFunction => b"call data without function",
+            CallDataError::OddArgumentLength => b"call data argument of odd length",
+            CallDataError::InvalidHexDigit => b"call data argument not in hex",
+        }
+    }
+}
+
+/// Parses call data built by a CallDataBuilder or by txDataBuilder.
+#[cfg(feature = "alloc")]
+pub fn parse_call_data(data: &[u8]) -> Result<CallData, CallDataError> {
+    let mut tokens = data.split(|byte| *byte == CALL_DATA_SEPARATOR);
+
+    let function = tokens.next().unwrap_or(&[]);
+    if function.is_empty() {
+        return Err(CallDataError::EmptyFunction);
+    }
+
+    let mut arguments = Vec::new();
+    for token in tokens {
+        arguments.push(decode_hex_argument(token)?);
+    }
+
+    let mut function_name = Vec::with_capacity(function.len());
+    function_name.extend_from_slice(function);
+    Ok(CallData {
+        function: function_name,
+        arguments,
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn decode_hex_argument(token: &[u8]) -> Result<Vec<u8>, CallDataError> {
+    if token.len() % 2 != 0 {
+        return Err(CallDataError::OddArgumentLength);
+    }
+
+    let mut argument = Vec::with_capacity(token.len() / 2);
+    for pair in token.chunks(2) {
+        argument.push((hex_digit(pair[0])? << 4) | hex_digit(pair[1])?);
+    }
+    Ok(argument)
+}
+
+/// Decodes a hex argument of call data into the buffer, returning the
+/// argument; it panics when the buffer is shorter than half the token, and
+/// needs no allocator, unlike parse_call_data.
+pub fn decode_hex_argument_into<'a>(token: &[u8], buffer: &'a mut [u8]) -> Result<&'a [u8], CallDataError> {
+    if token.len() % 2 != 0 {
+        return Err(CallDataError::OddArgumentLength);
+    }
+
+    let length = token.len() / 2;
+    for (i, pair) in token.chunks(2).enumerate() {
+        buffer[i] = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+    }
+    Ok(&buffer[..length])
+}
+
+fn hex_digit(c: u8) -> Result<u8, CallDataError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(CallDataError::InvalidHexDigit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_call_data_writer_round_trip() {
+        let mut buffer = [0u8; 64];
+        let data = CallDataWriter::new(&mut buffer, b"transfer")
+            .argument(&[0x0a, 0xff])
+            .argument(&[])
+            .argument_u64(0x0102)
+            .build();
+        assert_eq!(data, b"transfer@0aff@@0102");
+
+        let mut tokens = data.split(|byte| *byte == CALL_DATA_SEPARATOR);
+        assert_eq!(tokens.next(), Some(&b"transfer"[..]));
+        let mut argument = [0u8; 8];
+        assert_eq!(decode_hex_argument_into(tokens.next().unwrap(), &mut argument), Ok(&[0x0a, 0xff][..]));
+        assert_eq!(decode_hex_argument_into(tokens.next().unwrap(), &mut argument), Ok(&[][..]));
+        assert_eq!(decode_hex_argument_into(tokens.next().unwrap(), &mut argument), Ok(&[0x01, 0x02][..]));
+        assert_eq!(tokens.next(), None);
+    }
+
+    #[test]
+    fn test_decode_hex_argument_into_uppercase() {
+        let mut argument = [0u8; 2];
+        assert_eq!(decode_hex_argument_into(b"0AfF", &mut argument), Ok(&[0x0a, 0xff][..]));
+    }
+
+    #[test]
+    fn test_decode_hex_argument_into_odd_length() {
+        let mut argument = [0u8; 2];
+        assert_eq!(decode_hex_argument_into(b"0a0", &mut argument), Err(CallDataError::OddArgumentLength));
+    }
+
+    #[test]
+    fn test_decode_hex_argument_into_bad_digit() {
+        let mut argument = [0u8; 2];
+        assert_eq!(decode_hex_argument_into(b"0g", &mut argument), Err(CallDataError::InvalidHexDigit));
+        assert_eq!(decode_hex_argument_into(b"@0", &mut argument), Err(CallDataError::InvalidHexDigit));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_call_data_round_trip() {
+        let data = CallDataBuilder::new(b"transfer")
+            .argument(&[0x0a, 0xff])
+            .argument(&[])
+            .argument_u64(7)
+            .build();
+
+        let call_data = parse_call_data(data.as_slice()).unwrap();
+        assert_eq!(call_data.function.as_slice(), b"transfer");
+        assert_eq!(call_data.arguments.len(), 3);
+        assert_eq!(call_data.arguments[0].as_slice(), &[0x0a, 0xff]);
+        assert!(call_data.arguments[1].is_empty());
+        assert_eq!(call_data.arguments[2].as_slice(), &[7]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_call_data_errors() {
+        assert_eq!(parse_call_data(b""), Err(CallDataError::EmptyFunction));
+        assert_eq!(parse_call_data(b"@0a"), Err(CallDataError::EmptyFunction));
+        assert_eq!(parse_call_data(b"transfer@0a0"), Err(CallDataError::OddArgumentLength));
+        assert_eq!(parse_call_data(b"transfer@0a@zz"), Err(CallDataError::InvalidHexDigit));
+    }
+}
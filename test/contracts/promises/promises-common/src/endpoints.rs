@@ -0,0 +1,117 @@
+use crate::section::{leb128_len, names_len, write_leb128, write_names};
+
+/// The name of the custom section listing the endpoints of a contract. It
+/// holds a vector of names, in the wasm encoding: the number of names, then
+/// each name prefixed by its length, all lengths as unsigned LEB128.
+pub const ENDPOINTS_SECTION_NAME: &str = "endpoints";
+
+/// The size of the endpoints section listing the given names.
+pub const fn endpoints_section_len(names: &[&str]) -> usize {
+    leb128_len(names.len()) + names_len(names)
+}
+
+/// Encodes the endpoints section listing the given names; `N` must be their
+/// `endpoints_section_len`.
+pub const fn endpoints_section<const N: usize>(names: &[&str]) -> [u8; N] {
+    let (section, offset) = write_leb128([0u8; N], 0, names.len());
+    let (section, offset) = write_names(section, offset, names);
+    if offset != N {
+        panic!("wrong endpoints section length");
+    }
+    section
+}
+
+const WRONG_NUMBER_OF_ARGUMENTS: &[u8] = b"wrong number of arguments";
+
+/// Fails the execution unless the endpoint received the given number of
+/// arguments, with the error of the elrond-wasm argument API, which it calls
+/// the host functions for directly so that it needs no allocator.
+#[doc(hidden)]
+#[inline(always)]
+pub fn check_num_arguments(expected: i32) {
+    unsafe {
+        if getNumArguments() != expected {
+            signalError(WRONG_NUMBER_OF_ARGUMENTS.as_ptr(), WRONG_NUMBER_OF_ARGUMENTS.len() as i32);
+        }
+    }
+}
+
+extern "C" {
+    fn getNumArguments() -> i32;
+    fn signalError(messageOffset: *const u8, messageLength: i32) -> !;
+}
+
+/// Exports the endpoints of a contract, which are implemented as functions
+/// without parameters in a module of the contract:
+///
+/// ```ignore
+/// endpoints! {
+///     module: implementation;
+///     answer,
+///     echo(1),
+///     callback(..),
+/// }
+/// ```
+///
+/// For each endpoint, the macro generates the `#[no_mangle] extern "C"` shim
+/// which checks the number of arguments and calls the implementation, marking
+/// the arguments and the body phases for the gas tracers of the VM. An
+/// endpoint takes no arguments unless their number is given after its name;
+/// `(..)` skips the check, for endpoints taking any number of arguments. The
+/// macro also writes the names of the endpoints to the endpoints section of
+/// the contract binary, which the VM checks against the exports at deployment,
+/// and their numbers of arguments to the argument schema section, so that the
+/// VM rejects the calls with wrong numbers of arguments without instantiating
+/// the contract.
+#[macro_export]
+macro_rules! endpoints {
+    (module: $module:ident; $( $name:ident $( ( $($num_args:tt)* ) )? ),* $(,)?) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $name() {
+                $crate::gas_phase($crate::GasPhase::Arguments);
+                $crate::endpoints!(@check_num_arguments $( $($num_args)* )?);
+                $crate::gas_phase($crate::GasPhase::Body);
+                $module::$name();
+            }
+        )*
+
+        const __ENDPOINTS_SECTION_LEN: usize =
+            $crate::endpoints_section_len(&[$( stringify!($name) ),*]);
+
+        #[used]
+        #[link_section = "endpoints"]
+        static __ENDPOINTS_SECTION: [u8; __ENDPOINTS_SECTION_LEN] =
+            $crate::endpoints_section::<__ENDPOINTS_SECTION_LEN>(&[$( stringify!($name) ),*]);
+
+        const __ARGUMENT_COUNTS: &[$crate::ArgumentCount] =
+            &[$( $crate::endpoints!(@argument_count $( $($num_args)* )?) ),*];
+        const __ARGUMENT_SCHEMA_SECTION_LEN: usize =
+            $crate::argument_schema_section_len(&[$( stringify!($name) ),*], __ARGUMENT_COUNTS);
+
+        #[used]
+        #[link_section = "argschema"]
+        static __ARGUMENT_SCHEMA_SECTION: [u8; __ARGUMENT_SCHEMA_SECTION_LEN] =
+            $crate::argument_schema_section::<__ARGUMENT_SCHEMA_SECTION_LEN>(
+                &[$( stringify!($name) ),*],
+                __ARGUMENT_COUNTS);
+    };
+
+    (@check_num_arguments ) => {
+        $crate::check_num_arguments(0)
+    };
+    (@check_num_arguments ..) => {};
+    (@check_num_arguments $num_args:literal) => {
+        $crate::check_num_arguments($num_args)
+    };
+
+    (@argument_count ) => {
+        $crate::ArgumentCount { min: 0, max: 0 }
+    };
+    (@argument_count ..) => {
+        $crate::ArgumentCount { min: 0, max: $crate::UNBOUNDED_ARGUMENTS }
+    };
+    (@argument_count $num_args:literal) => {
+        $crate::ArgumentCount { min: $num_args, max: $num_args }
+    };
+}
@@ -0,0 +1,39 @@
+/// The phases of an endpoint to which the gas tracers of the VM attribute the
+/// gas used, with the values of the `arwen.GasPhase` constants.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(i32)]
+pub enum GasPhase {
+    Arguments = 0,
+    Body = 1,
+    Storage = 2,
+    Finish = 3,
+    Async = 4,
+}
+
+#[cfg(feature = "gas-phases")]
+extern "C" {
+    fn gasPhase(phase: i32);
+}
+
+/// Marks the beginning of a phase, which lasts until the next marker or the
+/// end of the endpoint. The markers cost gas, so they are only written with
+/// the `gas-phases` feature; without it, the contract does not import the
+/// `gasPhase` host function at all.
+#[inline(always)]
+pub fn gas_phase(phase: GasPhase) {
+    #[cfg(feature = "gas-phases")]
+    unsafe {
+        gasPhase(phase as i32);
+    }
+    #[cfg(not(feature = "gas-phases"))]
+    let _ = phase;
+}
+
+/// Runs `f` in the given phase, then marks the body phase again.
+#[inline(always)]
+pub fn in_gas_phase<R, F: FnOnce() -> R>(phase: GasPhase, f: F) -> R {
+    gas_phase(phase);
+    let result = f();
+    gas_phase(GasPhase::Body);
+    result
+}
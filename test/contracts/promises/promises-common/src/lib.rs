@@ -1,7 +1,35 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
 pub use elrond_wasm::{Address, Vec};
 
+mod address;
+mod argument_schema;
+mod attributes;
+mod build_info;
+mod call_data;
+mod endpoints;
+mod gas_phase;
+mod payment_tokens;
+mod promise_handles;
+#[cfg(feature = "alloc")]
+mod queue_mapper;
+mod section;
+mod typed_error;
+
+pub use address::*;
+pub use argument_schema::*;
+pub use attributes::*;
+pub use build_info::*;
+pub use call_data::*;
+pub use endpoints::*;
+pub use gas_phase::*;
+pub use payment_tokens::*;
+pub use promise_handles::*;
+#[cfg(feature = "alloc")]
+pub use queue_mapper::*;
+pub use typed_error::*;
+
 pub const PARENT_ADDRESS: [u8; 32] = [
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x0F, b'p', b'a', b'r', b'e', b'n', b't',
     b'S', b'C', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.',
@@ -34,6 +62,7 @@ pub const GAS_100M: i64 = 100000000;
 pub const ZERO: [u8; 32] = [0u8; 32];
 pub const EMPTY_SLICE: &[u8] = &[];
 
+#[cfg(feature = "alloc")]
 pub fn construct_storage_key(key_parts: &[&[u8]]) -> Vec<u8> {
     let mut key = Vec::new();
 
@@ -44,42 +73,61 @@ pub fn construct_storage_key(key_parts: &[&[u8]]) -> Vec<u8> {
     key
 }
 
+/// Concatenates the parts of a storage key into the buffer, returning the key;
+/// it panics when the buffer is too small, so it suits the keys whose maximum
+/// length is known, without an allocator.
+pub fn write_storage_key<'a>(key_parts: &[&[u8]], buffer: &'a mut [u8]) -> &'a [u8] {
+    let mut length = 0;
+    for part in key_parts {
+        buffer[length..length + part.len()].copy_from_slice(part);
+        length += part.len();
+    }
+
+    &buffer[..length]
+}
+
+/// Registers an async call in the group with the given identifier, as the
+/// contracts built before the promise handles do.
 #[inline(always)]
 pub fn create_async_call(
     group_id: &[u8],
-    destination: &Address,
+    destination: &impl AddressHelpers,
     value: &[u8],
     data: &[u8],
     success_callback_name: &[u8],
     error_callback_name: &[u8],
-    gas: i64) 
-{
+    gas: i64,
+) {
+    let destination = destination.to_address_bytes();
     unsafe {
         createAsyncCall(
-            group_id.as_ref().as_ptr(),
+            group_id.as_ptr(),
             group_id.len() as i32,
-            destination.as_ref().as_ptr(),
-            value.as_ref().as_ptr(),
-            data.as_ref().as_ptr(),
+            destination.as_ptr(),
+            value.as_ptr(),
+            data.as_ptr(),
             data.len() as i32,
-            success_callback_name.as_ref().as_ptr(),
+            success_callback_name.as_ptr(),
             success_callback_name.len() as i32,
-            error_callback_name.as_ref().as_ptr(),
+            error_callback_name.as_ptr(),
             error_callback_name.len() as i32,
-            gas
+            gas,
         )
     }
 }
 
-extern {
+extern "C" {
     fn createAsyncCall(
-        groupIDOffset: *const u8, groupIDLength: i32,
+        groupIDOffset: *const u8,
+        groupIDLength: i32,
         destOffset: *const u8,
         valueOffset: *const u8,
         dataOffset: *const u8,
         dataLength: i32,
-        successCallbackNameOffset: *const u8, successCallbackNameLen: i32,
-        errorCallbackNameOffset: *const u8, errorCallbackNameLen: i32,
-        gas: i64
+        successCallbackNameOffset: *const u8,
+        successCallbackNameLen: i32,
+        errorCallbackNameOffset: *const u8,
+        errorCallbackNameLen: i32,
+        gas: i64,
     );
 }
@@ -0,0 +1,81 @@
+use crate::section::{leb128_len, names_len, write_leb128, write_names};
+
+/// The name of the custom section declaring the tokens accepted as payment by
+/// the endpoints of a contract, which the VM checks before instantiating the
+/// contract, failing the calls which transfer other tokens, so that the
+/// payment is returned to the sender. It holds a vector of entries, each made
+/// of the name of an endpoint and a vector of token identifiers, all lengths
+/// as unsigned LEB128.
+pub const PAYMENT_TOKENS_SECTION_NAME: &str = "paytokens";
+
+/// The token identifier which stands for the EGLD call value.
+pub const EGLD_TOKEN_IDENTIFIER: &str = "EGLD";
+
+/// The size of the payment tokens section of the given endpoints.
+pub const fn payment_tokens_section_len(names: &[&str], tokens: &[&[&str]]) -> usize {
+    let mut len = leb128_len(names.len()) + names_len(names);
+    let mut i = 0;
+    while i < tokens.len() {
+        len += leb128_len(tokens[i].len()) + names_len(tokens[i]);
+        i += 1;
+    }
+    len
+}
+
+/// Encodes the payment tokens section of the given endpoints; `N` must be
+/// their `payment_tokens_section_len`.
+pub const fn payment_tokens_section<const N: usize>(names: &[&str], tokens: &[&[&str]]) -> [u8; N] {
+    if names.len() != tokens.len() {
+        panic!("wrong number of token whitelists");
+    }
+
+    let (mut section, mut offset) = write_leb128([0u8; N], 0, names.len());
+    let mut i = 0;
+    while i < names.len() {
+        let (written, next) = write_names(section, offset, &[names[i]]);
+        let (written, next) = write_leb128(written, next, tokens[i].len());
+        let (written, next) = write_names(written, next, tokens[i]);
+        section = written;
+        offset = next;
+        i += 1;
+    }
+    if offset != N {
+        panic!("wrong payment tokens section length");
+    }
+    section
+}
+
+/// Declares the tokens accepted as payment by endpoints of a contract:
+///
+/// ```ignore
+/// payment_tokens! {
+///     deposit: [EGLD, "TKN-000001"],
+///     view: [],
+/// }
+/// ```
+///
+/// `EGLD` stands for the EGLD call value, and an empty list makes an endpoint
+/// reject any payment. The endpoints which are not listed accept any payment,
+/// and check it themselves.
+#[macro_export]
+macro_rules! payment_tokens {
+    ($( $name:ident : [ $( $token:tt ),* $(,)? ] ),* $(,)?) => {
+        const __PAYMENT_TOKENS: &[&[&str]] = &[$( &[$( $crate::payment_tokens!(@token $token) ),*] ),*];
+        const __PAYMENT_TOKENS_SECTION_LEN: usize =
+            $crate::payment_tokens_section_len(&[$( stringify!($name) ),*], __PAYMENT_TOKENS);
+
+        #[used]
+        #[link_section = "paytokens"]
+        static __PAYMENT_TOKENS_SECTION: [u8; __PAYMENT_TOKENS_SECTION_LEN] =
+            $crate::payment_tokens_section::<__PAYMENT_TOKENS_SECTION_LEN>(
+                &[$( stringify!($name) ),*],
+                __PAYMENT_TOKENS);
+    };
+
+    (@token EGLD) => {
+        $crate::EGLD_TOKEN_IDENTIFIER
+    };
+    (@token $token:literal) => {
+        $token
+    };
+}
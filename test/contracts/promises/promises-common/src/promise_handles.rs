@@ -0,0 +1,80 @@
+use crate::AddressHelpers;
+
+/// The handle returned by `createPromiseGroup` and the id returned by
+/// `createPromise` when the VM rejects the call without failing the execution.
+const INVALID_PROMISE_HANDLE: i32 = -1;
+
+/// The handle of a promise group, created by `create_promise_group` and valid
+/// until the end of the current execution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PromiseGroupId(i32);
+
+/// The identity of a promise registered by `create_promise` in the current
+/// execution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PromiseId(i32);
+
+/// Creates a new promise group. The VM rejects identifiers which are empty,
+/// longer than 32 bytes, made of anything else than ASCII letters, digits,
+/// `_` and `-`, or already used by another group of the same execution, so
+/// unrelated modules of a contract cannot share a group by accident; a
+/// rejected identifier gives `None`.
+#[inline(always)]
+pub fn create_promise_group(group_id: &[u8]) -> Option<PromiseGroupId> {
+    let handle = unsafe { createPromiseGroup(group_id.as_ptr(), group_id.len() as i32) };
+    if handle == INVALID_PROMISE_HANDLE {
+        return None;
+    }
+    Some(PromiseGroupId(handle))
+}
+
+/// Registers a promise in the given group, giving `None` when the VM rejects
+/// it.
+#[inline(always)]
+pub fn create_promise(
+    group: PromiseGroupId,
+    destination: &impl AddressHelpers,
+    value: &[u8],
+    data: &[u8],
+    success_callback_name: &[u8],
+    error_callback_name: &[u8],
+    gas: i64,
+) -> Option<PromiseId> {
+    let destination = destination.to_address_bytes();
+    let id = unsafe {
+        createPromise(
+            group.0,
+            destination.as_ptr(),
+            value.as_ptr(),
+            data.as_ptr(),
+            data.len() as i32,
+            success_callback_name.as_ptr(),
+            success_callback_name.len() as i32,
+            error_callback_name.as_ptr(),
+            error_callback_name.len() as i32,
+            gas,
+            0,
+        )
+    };
+    if id == INVALID_PROMISE_HANDLE {
+        return None;
+    }
+    Some(PromiseId(id))
+}
+
+extern "C" {
+    fn createPromiseGroup(groupIDOffset: *const u8, groupIDLength: i32) -> i32;
+    fn createPromise(
+        groupHandle: i32,
+        destOffset: *const u8,
+        valueOffset: *const u8,
+        dataOffset: *const u8,
+        dataLength: i32,
+        successCallbackNameOffset: *const u8,
+        successCallbackNameLen: i32,
+        errorCallbackNameOffset: *const u8,
+        errorCallbackNameLen: i32,
+        gas: i64,
+        extraGasForCallback: i64,
+    ) -> i32;
+}
@@ -0,0 +1,270 @@
+use elrond_wasm::api::{StorageReadApi, StorageWriteApi};
+use elrond_wasm::Vec;
+
+use crate::{construct_storage_key, in_gas_phase, GasPhase};
+
+const HEAD_SUFFIX: &[u8] = b".head";
+const TAIL_SUFFIX: &[u8] = b".tail";
+const NEXT_SUFFIX: &[u8] = b".next";
+const VALUE_SUFFIX: &[u8] = b".value";
+
+/// The node ID stored when there is no node, since node IDs start at 1.
+const NULL_NODE_ID: u64 = 0;
+
+/// A FIFO queue kept in the storage of the contract as a linked list. Under
+/// its prefix, the queue stores the IDs of its first and last nodes, and for
+/// each node its value and the ID of the next node:
+///
+/// `prefix.head`, `prefix.tail`, `prefix.value<ID>`, `prefix.next<ID>`
+///
+/// with the IDs as 8 big endian bytes. Pushing and popping write only the
+/// keys of the node and of its neighbour, no matter how many values the queue
+/// holds, unlike a series of keys indexed by a counter which must be shifted
+/// on every pop.
+///
+/// Pushing and popping are marked as the storage phase for the gas tracers of
+/// the VM.
+///
+/// The node IDs keep growing: the tail ID is kept when the queue empties, so
+/// that the keys of a popped node are never reused.
+pub struct QueueMapper<'a, A>
+where
+    A: StorageReadApi + StorageWriteApi,
+{
+    api: &'a A,
+    prefix: &'a [u8],
+}
+
+impl<'a, A> QueueMapper<'a, A>
+where
+    A: StorageReadApi + StorageWriteApi,
+{
+    pub fn new(api: &'a A, prefix: &'a [u8]) -> Self {
+        QueueMapper { api, prefix }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head() == NULL_NODE_ID
+    }
+
+    /// The value at the front of the queue, without popping it.
+    pub fn front(&self) -> Option<Vec<u8>> {
+        let head = self.head();
+        if head == NULL_NODE_ID {
+            return None;
+        }
+        Some(self.api.storage_load_vec_u8(&self.node_key(VALUE_SUFFIX, head)))
+    }
+
+    pub fn push_back(&self, value: &[u8]) {
+        in_gas_phase(GasPhase::Storage, || self.push_back_node(value))
+    }
+
+    /// Removes the value at the front of the queue, clearing the keys of its node.
+    pub fn pop_front(&self) -> Option<Vec<u8>> {
+        in_gas_phase(GasPhase::Storage, || self.pop_front_node())
+    }
+
+    fn push_back_node(&self, value: &[u8]) {
+        let tail = self.api.storage_load_u64(&self.key(TAIL_SUFFIX));
+        let node_id = tail + 1;
+        self.api.storage_store_slice_u8(&self.node_key(VALUE_SUFFIX, node_id), value);
+
+        if self.is_empty() {
+            self.api.storage_store_u64(&self.key(HEAD_SUFFIX), node_id);
+        } else {
+            self.api.storage_store_u64(&self.node_key(NEXT_SUFFIX, tail), node_id);
+        }
+        self.api.storage_store_u64(&self.key(TAIL_SUFFIX), node_id);
+    }
+
+    fn pop_front_node(&self) -> Option<Vec<u8>> {
+        let head = self.head();
+        if head == NULL_NODE_ID {
+            return None;
+        }
+
+        let value_key = self.node_key(VALUE_SUFFIX, head);
+        let next_key = self.node_key(NEXT_SUFFIX, head);
+        let value = self.api.storage_load_vec_u8(&value_key);
+        let next = self.api.storage_load_u64(&next_key);
+
+        self.api.storage_store_slice_u8(&value_key, &[]);
+        self.api.storage_store_slice_u8(&next_key, &[]);
+        self.api.storage_store_u64(&self.key(HEAD_SUFFIX), next);
+        Some(value)
+    }
+
+    /// Iterates the values from the front of the queue, reading one node at a time.
+    pub fn iter(&self) -> QueueMapperIter<'_, 'a, A> {
+        QueueMapperIter {
+            queue: self,
+            node_id: self.head(),
+        }
+    }
+
+    fn head(&self) -> u64 {
+        self.api.storage_load_u64(&self.key(HEAD_SUFFIX))
+    }
+
+    fn key(&self, suffix: &[u8]) -> Vec<u8> {
+        construct_storage_key(&[self.prefix, suffix])
+    }
+
+    fn node_key(&self, suffix: &[u8], node_id: u64) -> Vec<u8> {
+        construct_storage_key(&[self.prefix, suffix, &node_id.to_be_bytes()])
+    }
+}
+
+pub struct QueueMapperIter<'q, 'a, A>
+where
+    A: StorageReadApi + StorageWriteApi,
+{
+    queue: &'q QueueMapper<'a, A>,
+    node_id: u64,
+}
+
+impl<'q, 'a, A> Iterator for QueueMapperIter<'q, 'a, A>
+where
+    A: StorageReadApi + StorageWriteApi,
+{
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        if self.node_id == NULL_NODE_ID {
+            return None;
+        }
+
+        let api = self.queue.api;
+        let value = api.storage_load_vec_u8(&self.queue.node_key(VALUE_SUFFIX, self.node_id));
+        self.node_id = api.storage_load_u64(&self.queue.node_key(NEXT_SUFFIX, self.node_id));
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    /// Keeps the storage in memory, with the numbers encoded as big endian
+    /// bytes without leading zeros, as the VM does.
+    #[derive(Default)]
+    struct StorageMock {
+        storage: RefCell<BTreeMap<Vec<u8>, Vec<u8>>>,
+    }
+
+    impl StorageMock {
+        fn num_keys(&self) -> usize {
+            self.storage.borrow().values().filter(|value| !value.is_empty()).count()
+        }
+    }
+
+    impl StorageReadApi for StorageMock {
+        fn storage_load_len(&self, key: &[u8]) -> usize {
+            self.storage_load_vec_u8(key).len()
+        }
+
+        fn storage_load_vec_u8(&self, key: &[u8]) -> Vec<u8> {
+            self.storage.borrow().get(key).cloned().unwrap_or_default()
+        }
+
+        fn storage_load_bytes32(&self, key: &[u8]) -> [u8; 32] {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&self.storage_load_vec_u8(key));
+            bytes
+        }
+
+        fn storage_load_u64(&self, key: &[u8]) -> u64 {
+            self.storage_load_vec_u8(key).iter().fold(0, |value, byte| (value << 8) | *byte as u64)
+        }
+
+        fn storage_load_i64(&self, key: &[u8]) -> i64 {
+            self.storage_load_u64(key) as i64
+        }
+    }
+
+    impl StorageWriteApi for StorageMock {
+        fn storage_store_slice_u8(&self, key: &[u8], value: &[u8]) {
+            self.storage.borrow_mut().insert(key.to_vec(), value.to_vec());
+        }
+
+        fn storage_store_bytes32(&self, key: &[u8], value: &[u8; 32]) {
+            self.storage_store_slice_u8(key, value);
+        }
+
+        fn storage_store_u64(&self, key: &[u8], value: u64) {
+            let bytes = value.to_be_bytes();
+            let leading_zeros = (value.leading_zeros() / 8) as usize;
+            self.storage_store_slice_u8(key, &bytes[leading_zeros..]);
+        }
+
+        fn storage_store_i64(&self, key: &[u8], value: i64) {
+            self.storage_store_u64(key, value as u64);
+        }
+    }
+
+    fn len<A: StorageReadApi + StorageWriteApi>(queue: &QueueMapper<A>) -> usize {
+        queue.iter().count()
+    }
+
+    #[test]
+    fn test_queue_mapper_push_pop_front() {
+        let api = StorageMock::default();
+        let queue = QueueMapper::new(&api, b"queue");
+        assert!(queue.is_empty());
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.pop_front(), None);
+
+        queue.push_back(b"first");
+        queue.push_back(b"second");
+        queue.push_back(b"third");
+        assert!(!queue.is_empty());
+        assert_eq!(len(&queue), 3);
+        assert_eq!(queue.front().as_deref(), Some(&b"first"[..]));
+
+        assert_eq!(queue.pop_front().as_deref(), Some(&b"first"[..]));
+        assert_eq!(queue.front().as_deref(), Some(&b"second"[..]));
+        assert_eq!(len(&queue), 2);
+
+        queue.push_back(b"fourth");
+        let values: Vec<Vec<u8>> = queue.iter().collect();
+        assert_eq!(values, [b"second".to_vec(), b"third".to_vec(), b"fourth".to_vec()]);
+    }
+
+    #[test]
+    fn test_queue_mapper_empty_after_drain() {
+        let api = StorageMock::default();
+        let queue = QueueMapper::new(&api, b"queue");
+        queue.push_back(b"first");
+        queue.push_back(b"second");
+
+        assert_eq!(queue.pop_front().as_deref(), Some(&b"first"[..]));
+        assert_eq!(queue.pop_front().as_deref(), Some(&b"second"[..]));
+        assert!(queue.is_empty());
+        assert_eq!(len(&queue), 0);
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.pop_front(), None);
+
+        // only the tail ID is left, so that the IDs of the popped nodes are not reused
+        assert_eq!(api.num_keys(), 1);
+        queue.push_back(b"third");
+        assert_eq!(queue.front().as_deref(), Some(&b"third"[..]));
+        assert_eq!(api.storage_load_u64(b"queue.head"), 3);
+    }
+
+    #[test]
+    fn test_queue_mapper_prefixes() {
+        let api = StorageMock::default();
+        let first = QueueMapper::new(&api, b"first");
+        let second = QueueMapper::new(&api, b"second");
+        first.push_back(b"value");
+
+        assert_eq!(len(&first), 1);
+        assert!(second.is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+//! The encoding of the custom sections written to the contract binaries: the
+//! vectors and names of the wasm binary format, with all lengths as unsigned
+//! LEB128, built in const fns so that the sections are `static` arrays.
+
+pub(crate) const fn leb128_len(mut value: usize) -> usize {
+    let mut len = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+    len
+}
+
+pub(crate) const fn write_leb128<const N: usize>(mut section: [u8; N], mut offset: usize, mut value: usize) -> ([u8; N], usize) {
+    while value >= 0x80 {
+        section[offset] = (value as u8 & 0x7F) | 0x80;
+        value >>= 7;
+        offset += 1;
+    }
+    section[offset] = value as u8;
+    (section, offset + 1)
+}
+
+/// The size of the given names, each prefixed by its length.
+pub(crate) const fn names_len(names: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < names.len() {
+        len += leb128_len(names[i].len()) + names[i].len();
+        i += 1;
+    }
+    len
+}
+
+pub(crate) const fn write_names<const N: usize>(mut section: [u8; N], mut offset: usize, names: &[&str]) -> ([u8; N], usize) {
+    let mut i = 0;
+    while i < names.len() {
+        let name = names[i].as_bytes();
+        let (written, name_offset) = write_leb128(section, offset, name.len());
+        section = written;
+        offset = name_offset;
+
+        let mut j = 0;
+        while j < name.len() {
+            section[offset] = name[j];
+            offset += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    (section, offset)
+}
@@ -0,0 +1,123 @@
+use crate::AttributesReader;
+
+/// Writes the payload of a typed error in the nested encoding read by
+/// `AttributesReader`, for the contracts built without an allocator; it
+/// panics when the buffer is too small.
+pub struct TypedErrorPayloadWriter<'a> {
+    buffer: &'a mut [u8],
+    length: usize,
+}
+
+impl<'a> TypedErrorPayloadWriter<'a> {
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        TypedErrorPayloadWriter { buffer, length: 0 }
+    }
+
+    pub fn write_u8(self, value: u8) -> Self {
+        self.write_slice(&[value])
+    }
+
+    pub fn write_bool(self, value: bool) -> Self {
+        self.write_u8(value as u8)
+    }
+
+    pub fn write_u16(self, value: u16) -> Self {
+        self.write_slice(&value.to_be_bytes())
+    }
+
+    pub fn write_u32(self, value: u32) -> Self {
+        self.write_slice(&value.to_be_bytes())
+    }
+
+    pub fn write_u64(self, value: u64) -> Self {
+        self.write_slice(&value.to_be_bytes())
+    }
+
+    pub fn write_bytes(self, value: &[u8]) -> Self {
+        self.write_u32(value.len() as u32).write_slice(value)
+    }
+
+    pub fn build(self) -> &'a [u8] {
+        &self.buffer[..self.length]
+    }
+
+    fn write_slice(mut self, slice: &[u8]) -> Self {
+        self.buffer[self.length..self.length + slice.len()].copy_from_slice(slice);
+        self.length += slice.len();
+        self
+    }
+}
+
+/// Fails the execution with a typed error. The message becomes the return
+/// message, as with `signalError`, and the VM hands the code and the payload
+/// to the error callback of the caller, after the return code and the
+/// message, in the same shard and across shards alike. The VM rejects a zero
+/// code.
+#[inline(always)]
+pub fn signal_typed_error(code: u32, message: &[u8], payload: &[u8]) -> ! {
+    unsafe {
+        signalTypedError(
+            code as i64,
+            message.as_ptr(),
+            message.len() as i32,
+            payload.as_ptr(),
+            payload.len() as i32,
+        )
+    }
+}
+
+/// A typed error received by an error callback.
+#[derive(Debug, PartialEq)]
+pub struct TypedError<'a> {
+    pub message: &'a [u8],
+    pub code: u32,
+    pub payload: &'a [u8],
+}
+
+impl<'a> TypedError<'a> {
+    /// Decodes the arguments of an error callback: the return code, the
+    /// message, then the code and the payload of the typed error; it returns
+    /// None for the callbacks of the calls which failed without one.
+    pub fn from_callback_arguments(arguments: &[&'a [u8]]) -> Option<Self> {
+        if arguments.len() != 4 {
+            return None;
+        }
+
+        let code = decode_code(arguments[2])?;
+        Some(TypedError {
+            message: arguments[1],
+            code,
+            payload: arguments[3],
+        })
+    }
+
+    pub fn payload_reader(&self) -> AttributesReader<'a> {
+        AttributesReader::new(self.payload)
+    }
+}
+
+/// The code arrives as big endian bytes without leading zeros.
+fn decode_code(bytes: &[u8]) -> Option<u32> {
+    if bytes.is_empty() || bytes.len() > 4 {
+        return None;
+    }
+
+    let mut code = 0u32;
+    for byte in bytes {
+        code = code << 8 | *byte as u32;
+    }
+    if code == 0 {
+        return None;
+    }
+    Some(code)
+}
+
+extern "C" {
+    fn signalTypedError(
+        code: i64,
+        messageOffset: *const u8,
+        messageLength: i32,
+        payloadOffset: *const u8,
+        payloadLength: i32,
+    ) -> !;
+}
@@ -1,6 +1,6 @@
 #![no_std]
 
-use elrond_wasm::api::{ContractHookApi, EndpointArgumentApi, EndpointFinishApi, StorageReadApi, StorageWriteApi};
+use elrond_wasm::api::{ContractHookApi, EndpointArgumentApi, EndpointFinishApi, ErrorApi, StorageReadApi, StorageWriteApi};
 use elrond_wasm_node::ArwenApiImpl;
 
 use promises_common::*;
@@ -51,6 +51,8 @@ pub extern "C" fn call_first_and_second_contract() {
 
     let call_data_for_first_contract = EEI.get_argument_vec_u8(0);
     let call_data_for_second_contract = EEI.get_argument_vec_u8(1);
+    check_call_data(&call_data_for_first_contract);
+    check_call_data(&call_data_for_second_contract);
 
     create_async_call(COMMON_GROUP_ID,
         &Address::from(FIRST_CONTRACT_ADDRESS),
@@ -69,6 +71,12 @@ pub extern "C" fn call_first_and_second_contract() {
         GAS_100K);
 }
 
+fn check_call_data(call_data: &[u8]) {
+    if let Err(err) = parse_call_data(call_data) {
+        EEI.signal_error(err.message());
+    }
+}
+
 // callbacks
 
 // first argument is "0" for success, followed by data passed by finish() in callee contract
@@ -0,0 +1,28 @@
+(module
+  (type (;0;) (func (param i32) (result i64)))
+  (type (;1;) (func (param i64)))
+  (type (;2;) (func (param i64) (result i64)))
+  (type (;3;) (func))
+  (import "env" "int64getArgument" (func (;0;) (type 0)))
+  (import "env" "int64finish" (func (;1;) (type 1)))
+  (func (;2;) (type 2) (param i64) (result i64)
+    local.get 0
+    i64.eqz
+    if (result i64)
+      i64.const 0
+    else
+      local.get 0
+      i64.const 1
+      i64.sub
+      call 2
+      i64.const 1
+      i64.add
+    end)
+  (func (;3;) (type 3)
+    i32.const 0
+    call 0
+    call 2
+    call 1)
+  (memory (;0;) 2)
+  (export "memory" (memory 0))
+  (export "recurse" (func 3)))
@@ -0,0 +1,29 @@
+(module
+  (type (;0;) (func (param i32) (result i64)))
+  (type (;1;) (func (param i32 i32 i32 i32) (result i32)))
+  (type (;2;) (func))
+  (import "env" "int64getArgument" (func (;0;) (type 0)))
+  (import "env" "storageStore" (func (;1;) (type 1)))
+  (func (;2;) (type 2)
+    i32.const 0
+    i32.const 4
+    i32.const 1024
+    i32.const 0
+    call 0
+    i32.wrap_i64
+    call 1
+    drop)
+  (func (;3;) (type 2)
+    i32.const 1024
+    i32.const 0
+    call 0
+    i32.wrap_i64
+    i32.const 0
+    i32.const 4
+    call 1
+    drop)
+  (memory (;0;) 2)
+  (export "memory" (memory 0))
+  (export "storeValue" (func 2))
+  (export "storeKey" (func 3))
+  (data (;0;) (i32.const 0) "bomb"))
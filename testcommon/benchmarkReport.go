@@ -0,0 +1,70 @@
+package testcommon
+
+import (
+	"encoding/json"
+	"io/ioutil"
+	"path/filepath"
+	"testing"
+)
+
+// BenchmarkReportEnvVar names the environment variable holding the path of
+// the JSON file in which the benchmark results are exported
+const BenchmarkReportEnvVar = "ARWEN_BENCHMARK_REPORT"
+
+// NamedBenchmark is a benchmark function which can be run on its own or
+// exported in a benchmark report
+type NamedBenchmark struct {
+	Name      string
+	Benchmark func(b *testing.B)
+}
+
+// BenchmarkResult holds the measurements of a NamedBenchmark, in a format
+// suited for tracking regressions across revisions
+type BenchmarkResult struct {
+	Name             string  `json:"name"`
+	Iterations       int     `json:"iterations"`
+	NanosecondsPerOp int64   `json:"nsPerOp"`
+	AllocsPerOp      int64   `json:"allocsPerOp"`
+	BytesPerOp       int64   `json:"bytesPerOp"`
+	OpsPerSecond     float64 `json:"opsPerSecond"`
+}
+
+// RunBenchmarks runs the given benchmarks one after the other, with memory
+// allocation reporting enabled, and returns their results in the same order
+func RunBenchmarks(benchmarks []NamedBenchmark) []*BenchmarkResult {
+	results := make([]*BenchmarkResult, 0, len(benchmarks))
+	for _, namedBenchmark := range benchmarks {
+		benchmark := namedBenchmark.Benchmark
+		result := testing.Benchmark(func(b *testing.B) {
+			b.ReportAllocs()
+			benchmark(b)
+		})
+		results = append(results, newBenchmarkResult(namedBenchmark.Name, result))
+	}
+	return results
+}
+
+func newBenchmarkResult(name string, result testing.BenchmarkResult) *BenchmarkResult {
+	opsPerSecond := float64(0)
+	if result.T > 0 {
+		opsPerSecond = float64(result.N) / result.T.Seconds()
+	}
+
+	return &BenchmarkResult{
+		Name:             name,
+		Iterations:       result.N,
+		NanosecondsPerOp: result.NsPerOp(),
+		AllocsPerOp:      result.AllocsPerOp(),
+		BytesPerOp:       result.AllocedBytesPerOp(),
+		OpsPerSecond:     opsPerSecond,
+	}
+}
+
+// WriteBenchmarkReport exports the benchmark results as indented JSON
+func WriteBenchmarkReport(path string, results []*BenchmarkResult) error {
+	serialized, err := json.MarshalIndent(results, "", "  ")
+	if err != nil {
+		return err
+	}
+	return ioutil.WriteFile(filepath.Clean(path), serialized, 0644)
+}
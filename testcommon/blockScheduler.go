@@ -0,0 +1,167 @@
+package testcommon
+
+import (
+	"encoding/binary"
+	"errors"
+	"sort"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+)
+
+// DefaultRoundsPerEpoch is the number of rounds of an epoch in a BlockScheduler
+const DefaultRoundsPerEpoch = 100
+
+// DefaultTimePerRound is the timestamp increase of each block in a BlockScheduler
+const DefaultTimePerRound = 6
+
+// ErrCallScheduledInThePast signals that a call was scheduled for a block which was already executed
+var ErrCallScheduledInThePast = errors.New("call scheduled for a block which was already executed")
+
+// ScheduledCall is a contract call to be executed in a future block
+type ScheduledCall struct {
+	BlockNonce uint64
+	Input      *vmcommon.ContractCallInput
+}
+
+// ScheduledCallResult holds the outcome of a ScheduledCall
+type ScheduledCallResult struct {
+	Call     *ScheduledCall
+	VMOutput *vmcommon.VMOutput
+	Err      error
+}
+
+// BlockListener is notified by the BlockScheduler of every new block, after
+// the block info of the world was updated and before the scheduled calls run
+type BlockListener func(blockNonce uint64)
+
+// BlockScheduler advances the blocks of a MockWorld and executes the calls
+// scheduled for each block, in the order in which they were scheduled; the
+// output of successful calls is applied to the world before the next call
+type BlockScheduler struct {
+	host           arwen.VMHost
+	world          *worldmock.MockWorld
+	scheduledCalls []*ScheduledCall
+	listeners      []BlockListener
+	RoundsPerEpoch uint64
+	TimePerRound   uint64
+}
+
+// NewBlockScheduler creates a BlockScheduler starting from the current block of the world
+func NewBlockScheduler(host arwen.VMHost, world *worldmock.MockWorld) *BlockScheduler {
+	return &BlockScheduler{
+		host:           host,
+		world:          world,
+		scheduledCalls: make([]*ScheduledCall, 0),
+		listeners:      make([]BlockListener, 0),
+		RoundsPerEpoch: DefaultRoundsPerEpoch,
+		TimePerRound:   DefaultTimePerRound,
+	}
+}
+
+// ScheduleCall registers a call to be executed in the block with the given nonce
+func (scheduler *BlockScheduler) ScheduleCall(blockNonce uint64, input *vmcommon.ContractCallInput) error {
+	if blockNonce <= scheduler.world.CurrentNonce() {
+		return ErrCallScheduledInThePast
+	}
+
+	scheduler.scheduledCalls = append(scheduler.scheduledCalls, &ScheduledCall{
+		BlockNonce: blockNonce,
+		Input:      input,
+	})
+	sort.SliceStable(scheduler.scheduledCalls, func(i, j int) bool {
+		return scheduler.scheduledCalls[i].BlockNonce < scheduler.scheduledCalls[j].BlockNonce
+	})
+	return nil
+}
+
+// ScheduleEndpoint registers a call of an endpoint of a contract to be
+// executed in the block with the given nonce
+func (scheduler *BlockScheduler) ScheduleEndpoint(
+	blockNonce uint64,
+	caller []byte,
+	contract []byte,
+	function string,
+	gasProvided uint64,
+	arguments ...[]byte,
+) error {
+	input := CreateTestContractCallInputBuilder().
+		WithCallerAddr(caller).
+		WithRecipientAddr(contract).
+		WithFunction(function).
+		WithGasProvided(gasProvided).
+		WithArguments(arguments...).
+		Build()
+	return scheduler.ScheduleCall(blockNonce, input)
+}
+
+// AddBlockListener registers a function to be called on every new block
+func (scheduler *BlockScheduler) AddBlockListener(listener BlockListener) {
+	scheduler.listeners = append(scheduler.listeners, listener)
+}
+
+// NumScheduledCalls returns the number of calls not executed yet
+func (scheduler *BlockScheduler) NumScheduledCalls() int {
+	return len(scheduler.scheduledCalls)
+}
+
+// AdvanceBlocks moves the world forward by the given number of blocks and
+// returns the results of the calls executed in these blocks
+func (scheduler *BlockScheduler) AdvanceBlocks(numBlocks uint64) []*ScheduledCallResult {
+	return scheduler.AdvanceToBlock(scheduler.world.CurrentNonce() + numBlocks)
+}
+
+// AdvanceToBlock moves the world forward until the block with the given
+// nonce and returns the results of the calls executed on the way
+func (scheduler *BlockScheduler) AdvanceToBlock(blockNonce uint64) []*ScheduledCallResult {
+	results := make([]*ScheduledCallResult, 0)
+	for scheduler.world.CurrentNonce() < blockNonce {
+		scheduler.startNextBlock()
+		results = append(results, scheduler.executeCallsOfCurrentBlock()...)
+	}
+	return results
+}
+
+func (scheduler *BlockScheduler) startNextBlock() {
+	nonce := scheduler.world.CurrentNonce() + 1
+	round := scheduler.world.CurrentRound() + 1
+	epoch := scheduler.world.CurrentEpoch()
+	if scheduler.RoundsPerEpoch > 0 {
+		epoch = uint32(round / scheduler.RoundsPerEpoch)
+	}
+	timeStamp := scheduler.world.CurrentTimeStamp() + scheduler.TimePerRound
+
+	randomSeed := make([]byte, 8)
+	binary.BigEndian.PutUint64(randomSeed, nonce)
+
+	scheduler.world.SetCurrentBlockInfo(nonce, round, epoch, timeStamp, randomSeed)
+	for _, listener := range scheduler.listeners {
+		listener(nonce)
+	}
+}
+
+func (scheduler *BlockScheduler) executeCallsOfCurrentBlock() []*ScheduledCallResult {
+	currentNonce := scheduler.world.CurrentNonce()
+	results := make([]*ScheduledCallResult, 0)
+	for len(scheduler.scheduledCalls) > 0 && scheduler.scheduledCalls[0].BlockNonce <= currentNonce {
+		call := scheduler.scheduledCalls[0]
+		scheduler.scheduledCalls = scheduler.scheduledCalls[1:]
+		results = append(results, scheduler.executeCall(call))
+	}
+	return results
+}
+
+func (scheduler *BlockScheduler) executeCall(call *ScheduledCall) *ScheduledCallResult {
+	result := &ScheduledCallResult{
+		Call: call,
+	}
+
+	result.VMOutput, result.Err = scheduler.host.RunSmartContractCall(call.Input)
+	if result.Err != nil || result.VMOutput.ReturnCode != vmcommon.Ok {
+		return result
+	}
+
+	result.Err = scheduler.world.UpdateAccounts(result.VMOutput.OutputAccounts, result.VMOutput.DeletedAccounts)
+	return result
+}
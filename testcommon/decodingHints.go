@@ -0,0 +1,124 @@
+package testcommon
+
+import (
+	"encoding/hex"
+	"fmt"
+	"math/big"
+	"strconv"
+	"strings"
+	"sync"
+
+	"github.com/ElrondNetwork/elrond-vm-common/parsers"
+)
+
+// DecodingHint is the type of a storage value or of an endpoint argument,
+// used to show it decoded in traces and in failure messages instead of hex
+type DecodingHint uint8
+
+const (
+	// HintBytes shows the value in hex
+	HintBytes DecodingHint = iota
+
+	// HintString shows the value as quoted text
+	HintString
+
+	// HintUnsigned shows the value as a big endian unsigned integer
+	HintUnsigned
+
+	// HintSigned shows the value as a big endian two's complement integer
+	HintSigned
+
+	// HintBool shows the value as true or false
+	HintBool
+
+	// HintAddress shows the value as an address, readable if it is a test address
+	HintAddress
+)
+
+// Decode returns the value decoded according to the hint
+func (hint DecodingHint) Decode(value []byte) string {
+	switch hint {
+	case HintString:
+		return strconv.Quote(string(value))
+	case HintUnsigned:
+		return big.NewInt(0).SetBytes(value).String()
+	case HintSigned:
+		return twosComplementToBigInt(value).String()
+	case HintBool:
+		return strconv.FormatBool(big.NewInt(0).SetBytes(value).Sign() != 0)
+	case HintAddress:
+		return describeAddress(value)
+	}
+	return "0x" + hex.EncodeToString(value)
+}
+
+func twosComplementToBigInt(value []byte) *big.Int {
+	result := big.NewInt(0).SetBytes(value)
+	if len(value) > 0 && value[0]&0x80 != 0 {
+		result.Sub(result, big.NewInt(0).Lsh(big.NewInt(1), uint(len(value))*8))
+	}
+	return result
+}
+
+var hintsMutex sync.RWMutex
+var storageKeyHints = make(map[string]DecodingHint)
+var argumentHints = make(map[string][]DecodingHint)
+
+// RegisterStorageKeyHint sets the type of the values stored under the given
+// key, for all the tests of the package
+func RegisterStorageKeyHint(key []byte, hint DecodingHint) {
+	hintsMutex.Lock()
+	defer hintsMutex.Unlock()
+	storageKeyHints[string(key)] = hint
+}
+
+// RegisterArgumentHints sets the types of the arguments of the given
+// endpoint, in order, for all the tests of the package
+func RegisterArgumentHints(function string, hints ...DecodingHint) {
+	hintsMutex.Lock()
+	defer hintsMutex.Unlock()
+	argumentHints[function] = hints
+}
+
+// describeStorageValue shows the value stored under the key as
+// "key = value", decoded according to the hint of the key, if any
+func describeStorageValue(key []byte, value []byte) string {
+	hintsMutex.RLock()
+	hint, ok := storageKeyHints[string(key)]
+	hintsMutex.RUnlock()
+
+	if !ok {
+		return fmt.Sprintf("%s = %s", decodeKey(key), describeBytes(value))
+	}
+	return fmt.Sprintf("%s = %s", decodeKey(key), hint.Decode(value))
+}
+
+func decodeKey(key []byte) string {
+	if isPrintable(key) {
+		return string(key)
+	}
+	return "0x" + hex.EncodeToString(key)
+}
+
+// describeCallData shows the data of a transfer as a call of the endpoint,
+// with the arguments decoded according to the hints of the endpoint
+func describeCallData(data []byte) string {
+	function, args, err := parsers.NewCallArgsParser().ParseData(string(data))
+	if err != nil {
+		return describeBytes(data)
+	}
+
+	hintsMutex.RLock()
+	hints := argumentHints[function]
+	hintsMutex.RUnlock()
+
+	descriptions := make([]string, len(args))
+	for i, arg := range args {
+		if i < len(hints) {
+			descriptions[i] = hints[i].Decode(arg)
+		} else {
+			descriptions[i] = describeBytes(arg)
+		}
+	}
+	return fmt.Sprintf("%s(%s)", function, strings.Join(descriptions, ", "))
+}
@@ -0,0 +1,45 @@
+package testcommon
+
+import (
+	"encoding/hex"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDecodingHint_Decode(t *testing.T) {
+	require.Equal(t, "0x00ff", HintBytes.Decode([]byte{0x00, 0xff}))
+	require.Equal(t, `"abc"`, HintString.Decode([]byte("abc")))
+	require.Equal(t, "65535", HintUnsigned.Decode([]byte{0xff, 0xff}))
+	require.Equal(t, "0", HintUnsigned.Decode(nil))
+	require.Equal(t, "-1", HintSigned.Decode([]byte{0xff, 0xff}))
+	require.Equal(t, "127", HintSigned.Decode([]byte{0x7f}))
+	require.Equal(t, "-256", HintSigned.Decode([]byte{0xff, 0x00}))
+	require.Equal(t, "true", HintBool.Decode([]byte{0x01}))
+	require.Equal(t, "false", HintBool.Decode([]byte{0x00}))
+	require.Equal(t, "false", HintBool.Decode(nil))
+	require.Equal(t, `"userAccount....................."`, HintAddress.Decode(UserAddress))
+	require.Equal(t, "00ff", HintAddress.Decode([]byte{0x00, 0xff}))
+	require.Equal(t, "0x01", DecodingHint(100).Decode([]byte{0x01}))
+}
+
+func TestDescribeStorageValue(t *testing.T) {
+	RegisterStorageKeyHint([]byte("hintedBalance"), HintUnsigned)
+	RegisterStorageKeyHint([]byte("hintedName"), HintString)
+
+	require.Equal(t, "hintedBalance = 256", describeStorageValue([]byte("hintedBalance"), []byte{0x01, 0x00}))
+	require.Equal(t, `hintedName = "abc"`, describeStorageValue([]byte("hintedName"), []byte("abc")))
+
+	require.Equal(t, "unknownKey = 0x0100 (256)", describeStorageValue([]byte("unknownKey"), []byte{0x01, 0x00}))
+	require.Equal(t, `unknownKey = 0x616263 ("abc")`, describeStorageValue([]byte("unknownKey"), []byte("abc")))
+	require.Equal(t, "unknownKey = <empty>", describeStorageValue([]byte("unknownKey"), nil))
+	require.Equal(t, "0x00ff = 0x01 (1)", describeStorageValue([]byte{0x00, 0xff}, []byte{0x01}))
+}
+
+func TestDescribeCallData(t *testing.T) {
+	RegisterArgumentHints("hintedTransfer", HintAddress, HintUnsigned)
+
+	require.Equal(t, `hintedTransfer("userAccount.....................", 10, 0x01 (1))`,
+		describeCallData([]byte("hintedTransfer@"+hex.EncodeToString(UserAddress)+"@0a@01")))
+	require.Equal(t, "unknownFunction(0x0a (10))", describeCallData([]byte("unknownFunction@0a")))
+}
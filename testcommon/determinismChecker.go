@@ -0,0 +1,254 @@
+package testcommon
+
+import (
+	"bytes"
+	"fmt"
+	"strings"
+	"sync"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/ElrondNetwork/wasm-vm/tracing"
+	"github.com/stretchr/testify/require"
+)
+
+// DeterminismCheckConfig configures the executions compared by a
+// DeterminismChecker
+type DeterminismCheckConfig struct {
+	// Repetitions is the number of times each host executes the transaction
+	Repetitions int
+	// NumHosts is the number of hosts executing the repetitions concurrently,
+	// each on its own world; 0 means a single host
+	NumHosts int
+	// WarmInstances keeps the warm instances and the compiled code of the
+	// contracts between the repetitions of a host; otherwise, every
+	// repetition instantiates the contracts from their bytecode
+	WarmInstances bool
+}
+
+// ExecutionRecord is what a DeterminismChecker recorded for one execution:
+// its VMOutput, described line by line in canonical order, and its traces
+type ExecutionRecord struct {
+	Host          int
+	Repetition    int
+	Error         string
+	VMOutput      []string
+	CallGraph     string
+	GasFlamegraph string
+}
+
+// Divergence is a difference between an execution and the first one
+type Divergence struct {
+	Host       int
+	Repetition int
+	Field      string
+	Expected   string
+	Actual     string
+}
+
+// String describes the divergence
+func (divergence *Divergence) String() string {
+	return fmt.Sprintf("host %d, repetition %d: %s differs\nexpected: %s\nactual:   %s",
+		divergence.Host, divergence.Repetition, divergence.Field, divergence.Expected, divergence.Actual)
+}
+
+// DeterminismChecker executes the same transaction many times, on one or
+// more hosts, with or without the warm instances, and compares the VMOutputs
+// and the traces of the executions; any difference is a divergence, which
+// the caching and pooling features of the VM must never introduce
+type DeterminismChecker struct {
+	tb          testing.TB
+	config      DeterminismCheckConfig
+	newWorld    func() *worldmock.MockWorld
+	customizeFn func(builder *TestHostBuilder) *TestHostBuilder
+}
+
+// NewDeterminismChecker creates a DeterminismChecker; newWorld must create
+// the same initial world each time it is called, one for every host
+func NewDeterminismChecker(tb testing.TB, config DeterminismCheckConfig, newWorld func() *worldmock.MockWorld) *DeterminismChecker {
+	return &DeterminismChecker{
+		tb:       tb,
+		config:   config,
+		newWorld: newWorld,
+	}
+}
+
+// WithHostBuilder lets the test configure the hosts further, after their
+// blockchain hook, built-in functions and tracer are set
+func (checker *DeterminismChecker) WithHostBuilder(customizeFn func(builder *TestHostBuilder) *TestHostBuilder) *DeterminismChecker {
+	checker.customizeFn = customizeFn
+	return checker
+}
+
+type determinismHost struct {
+	index  int
+	world  *worldmock.MockWorld
+	host   arwen.VMHost
+	tracer *tracing.Tracer
+}
+
+// Run executes the transaction as configured and returns the records of all
+// the executions, host by host and repetition by repetition
+func (checker *DeterminismChecker) Run(input *vmcommon.ContractCallInput) []*ExecutionRecord {
+	hosts := checker.buildHosts()
+	defer func() {
+		for _, h := range hosts {
+			h.host.Reset()
+		}
+	}()
+
+	records := make([][]*ExecutionRecord, len(hosts))
+	var wg sync.WaitGroup
+	for i, h := range hosts {
+		wg.Add(1)
+		go func(i int, h *determinismHost) {
+			defer wg.Done()
+			records[i] = checker.runRepetitions(h, input)
+		}(i, h)
+	}
+	wg.Wait()
+
+	allRecords := make([]*ExecutionRecord, 0)
+	for _, hostRecords := range records {
+		allRecords = append(allRecords, hostRecords...)
+	}
+	return allRecords
+}
+
+// Check executes the transaction as configured and returns the differences
+// of every execution from the first one
+func (checker *DeterminismChecker) Check(input *vmcommon.ContractCallInput) []*Divergence {
+	return FindDivergences(checker.Run(input))
+}
+
+// RequireDeterministic fails the test if any execution differs from the
+// first one, reporting all the divergences
+func (checker *DeterminismChecker) RequireDeterministic(input *vmcommon.ContractCallInput) {
+	divergences := checker.Check(input)
+	descriptions := make([]string, len(divergences))
+	for i, divergence := range divergences {
+		descriptions[i] = divergence.String()
+	}
+	require.Empty(checker.tb, divergences, "nondeterministic execution:\n%s", strings.Join(descriptions, "\n"))
+}
+
+// the hosts are built on the goroutine of the test, which may fail it
+func (checker *DeterminismChecker) buildHosts() []*determinismHost {
+	numHosts := checker.config.NumHosts
+	if numHosts <= 0 {
+		numHosts = 1
+	}
+
+	hosts := make([]*determinismHost, numHosts)
+	for i := range hosts {
+		world := checker.newWorld()
+		tracer := tracing.NewTracer(tracing.Config{
+			CallGraph:     true,
+			GasFlamegraph: true,
+		})
+		builder := NewTestHostBuilder(checker.tb).
+			WithBlockchainHook(world).
+			WithBuiltinFunctions().
+			WithExecutionTracer(tracer)
+		if checker.customizeFn != nil {
+			builder = checker.customizeFn(builder)
+		}
+
+		hosts[i] = &determinismHost{
+			index:  i,
+			world:  world,
+			host:   builder.Build(),
+			tracer: tracer,
+		}
+	}
+	return hosts
+}
+
+func (checker *DeterminismChecker) runRepetitions(h *determinismHost, input *vmcommon.ContractCallInput) []*ExecutionRecord {
+	records := make([]*ExecutionRecord, checker.config.Repetitions)
+	for repetition := range records {
+		if !checker.config.WarmInstances {
+			h.host.Runtime().ClearWarmInstanceCache()
+			h.world.ClearCompiledCodes()
+		}
+		h.tracer.Reset()
+
+		vmOutput, err := h.host.RunSmartContractCall(copyContractCallInput(input))
+		records[repetition] = newExecutionRecord(h, repetition, vmOutput, err)
+	}
+	return records
+}
+
+func newExecutionRecord(h *determinismHost, repetition int, vmOutput *vmcommon.VMOutput, err error) *ExecutionRecord {
+	record := &ExecutionRecord{
+		Host:       h.index,
+		Repetition: repetition,
+		VMOutput:   DescribeVMOutput(vmOutput),
+	}
+	if err != nil {
+		record.Error = err.Error()
+	}
+
+	var callGraph bytes.Buffer
+	_ = h.tracer.CallGraph().WriteJSON(&callGraph)
+	record.CallGraph = callGraph.String()
+
+	var gasFlamegraph bytes.Buffer
+	_ = h.tracer.GasFlamegraph().WriteFolded(&gasFlamegraph)
+	record.GasFlamegraph = gasFlamegraph.String()
+
+	return record
+}
+
+// the host may change the input while executing it
+func copyContractCallInput(input *vmcommon.ContractCallInput) *vmcommon.ContractCallInput {
+	inputCopy := *input
+	inputCopy.Arguments = make([][]byte, len(input.Arguments))
+	copy(inputCopy.Arguments, input.Arguments)
+	return &inputCopy
+}
+
+// FindDivergences compares every record to the first one; of the VMOutput
+// and the traces, only the first differing line is reported
+func FindDivergences(records []*ExecutionRecord) []*Divergence {
+	divergences := make([]*Divergence, 0)
+	if len(records) == 0 {
+		return divergences
+	}
+
+	expected := records[0]
+	for _, actual := range records[1:] {
+		fields := []struct {
+			name     string
+			expected []string
+			actual   []string
+		}{
+			{"error", []string{expected.Error}, []string{actual.Error}},
+			{"VMOutput", expected.VMOutput, actual.VMOutput},
+			{"call graph", strings.Split(expected.CallGraph, "\n"), strings.Split(actual.CallGraph, "\n")},
+			{"gas flamegraph", strings.Split(expected.GasFlamegraph, "\n"), strings.Split(actual.GasFlamegraph, "\n")},
+		}
+		for _, field := range fields {
+			expectedLine, actualLine, differs := arwen.FirstDifferentLine(field.expected, field.actual)
+			if !differs {
+				continue
+			}
+			divergences = append(divergences, &Divergence{
+				Host:       actual.Host,
+				Repetition: actual.Repetition,
+				Field:      field.name,
+				Expected:   expectedLine,
+				Actual:     actualLine,
+			})
+		}
+	}
+	return divergences
+}
+
+// DescribeVMOutput renders the VMOutput one fact per line, in canonical
+// order, as arwen.DescribeVMOutput does
+func DescribeVMOutput(vmOutput *vmcommon.VMOutput) []string {
+	return arwen.DescribeVMOutput(vmOutput)
+}
@@ -6,6 +6,7 @@ import (
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	"github.com/ElrondNetwork/wasm-vm/config"
+	"github.com/ElrondNetwork/wasm-vm/executor"
 	contextmock "github.com/ElrondNetwork/wasm-vm/mock/context"
 )
 
@@ -47,6 +48,12 @@ type InstancesTestTemplate struct {
 	testTemplateConfig
 	contracts          []*InstanceTestSmartContract
 	gasSchedule        config.GasScheduleMap
+	enableEpochsHandler arwen.EnableEpochsHandler
+	storageLimits      arwen.StorageLimits
+	executorFactory    executor.ExecutorAbstractFactory
+	shadowFactory      executor.ExecutorAbstractFactory
+	shadowHandler      arwen.ShadowDivergenceHandler
+	executionTracer    arwen.ExecutionTracer
 	setup              func(arwen.VMHost, *contextmock.BlockchainHookStub)
 	assertResults      func(arwen.VMHost, *contextmock.BlockchainHookStub, *VMOutputVerifier)
 	host               arwen.VMHost
@@ -89,6 +96,37 @@ func (callerTest *InstancesTestTemplate) WithGasSchedule(gasSchedule config.GasS
 	return callerTest
 }
 
+// WithEnableEpochsHandler provides the flags and the activation epochs of the VM features for the test
+func (callerTest *InstancesTestTemplate) WithEnableEpochsHandler(enableEpochsHandler arwen.EnableEpochsHandler) *InstancesTestTemplate {
+	callerTest.enableEpochsHandler = enableEpochsHandler
+	return callerTest
+}
+
+// WithStorageLimits provides the bounds of the keys and the values which the contracts write to storage
+func (callerTest *InstancesTestTemplate) WithStorageLimits(storageLimits arwen.StorageLimits) *InstancesTestTemplate {
+	callerTest.storageLimits = storageLimits
+	return callerTest
+}
+
+// WithExecutorFactory provides the executor factory to be used by the contract call test
+func (callerTest *InstancesTestTemplate) WithExecutorFactory(executorFactory executor.ExecutorAbstractFactory) *InstancesTestTemplate {
+	callerTest.executorFactory = executorFactory
+	return callerTest
+}
+
+// WithShadowExecutor provides the executor factory on which the contract call test is executed again
+func (callerTest *InstancesTestTemplate) WithShadowExecutor(executorFactory executor.ExecutorAbstractFactory, handler arwen.ShadowDivergenceHandler) *InstancesTestTemplate {
+	callerTest.shadowFactory = executorFactory
+	callerTest.shadowHandler = handler
+	return callerTest
+}
+
+// WithExecutionTracer provides the execution tracer to be used by the contract call test
+func (callerTest *InstancesTestTemplate) WithExecutionTracer(executionTracer arwen.ExecutionTracer) *InstancesTestTemplate {
+	callerTest.executionTracer = executionTracer
+	return callerTest
+}
+
 // WithWasmerSIGSEGVPassthrough sets the wasmerSIGSEGVPassthrough flag
 func (callerTest *InstancesTestTemplate) WithWasmerSIGSEGVPassthrough(wasmerSIGSEGVPassthrough bool) *InstancesTestTemplate {
 	callerTest.wasmerSIGSEGVPassthrough = wasmerSIGSEGVPassthrough
@@ -113,6 +151,11 @@ func runTestWithInstances(callerTest *InstancesTestTemplate, reset bool) {
 		callerTest.host = NewTestHostBuilder(callerTest.tb).
 			WithBlockchainHook(callerTest.blockchainHookStub).
 			WithGasSchedule(callerTest.gasSchedule).
+			WithEnableEpochsHandler(callerTest.enableEpochsHandler).
+			WithStorageLimits(callerTest.storageLimits).
+			WithExecutorFactory(callerTest.executorFactory).
+			WithShadowExecutor(callerTest.shadowFactory, callerTest.shadowHandler).
+			WithExecutionTracer(callerTest.executionTracer).
 			WithWasmerSIGSEGVPassthrough(callerTest.wasmerSIGSEGVPassthrough).
 			Build()
 		callerTest.setup(callerTest.host, callerTest.blockchainHookStub)
@@ -20,6 +20,10 @@ type TestCreateTemplateConfig struct {
 	host                     arwen.VMHost
 	gasSchedule              config.GasScheduleMap
 	wasmerSIGSEGVPassthrough bool
+	strictEndpointValidation bool
+	contractLimits           arwen.ContractLimits
+	executionPolicy          arwen.ExecutionPolicy
+	enableEpochsHandler      arwen.EnableEpochsHandler
 	overrideExecutorFactory  executor.ExecutorAbstractFactory
 	stubAccountInitialNonce  uint64
 	blockchainHookStub       *contextmock.BlockchainHookStub
@@ -43,6 +47,30 @@ func (callerTest *TestCreateTemplateConfig) WithExecutor(executorFactory executo
 	return callerTest
 }
 
+// WithStrictEndpointValidation makes the VM check the exports of the deployed contract
+func (callerTest *TestCreateTemplateConfig) WithStrictEndpointValidation() *TestCreateTemplateConfig {
+	callerTest.strictEndpointValidation = true
+	return callerTest
+}
+
+// WithContractLimits sets the limits checked on the code of the deployed contract
+func (callerTest *TestCreateTemplateConfig) WithContractLimits(contractLimits arwen.ContractLimits) *TestCreateTemplateConfig {
+	callerTest.contractLimits = contractLimits
+	return callerTest
+}
+
+// WithExecutionPolicy sets the execution policy consulted before deploying the contract
+func (callerTest *TestCreateTemplateConfig) WithExecutionPolicy(executionPolicy arwen.ExecutionPolicy) *TestCreateTemplateConfig {
+	callerTest.executionPolicy = executionPolicy
+	return callerTest
+}
+
+// WithEnableEpochsHandler provides the flags and the activation epochs of the VM features for the test
+func (callerTest *TestCreateTemplateConfig) WithEnableEpochsHandler(enableEpochsHandler arwen.EnableEpochsHandler) *TestCreateTemplateConfig {
+	callerTest.enableEpochsHandler = enableEpochsHandler
+	return callerTest
+}
+
 // WithInput provides the ContractCreateInput for a TestCreateTemplateConfig
 func (callerTest *TestCreateTemplateConfig) WithInput(input *vmcommon.ContractCreateInput) *TestCreateTemplateConfig {
 	callerTest.input = input
@@ -112,5 +140,9 @@ func (callerTest *TestCreateTemplateConfig) createTestArwenVM() arwen.VMHost {
 		WithBlockchainHook(callerTest.blockchainHookStub).
 		WithGasSchedule(callerTest.gasSchedule).
 		WithWasmerSIGSEGVPassthrough(callerTest.wasmerSIGSEGVPassthrough).
+		WithStrictEndpointValidation(callerTest.strictEndpointValidation).
+		WithContractLimits(callerTest.contractLimits).
+		WithExecutionPolicy(callerTest.executionPolicy).
+		WithEnableEpochsHandler(callerTest.enableEpochsHandler).
 		Build()
 }
@@ -24,9 +24,15 @@ type testTemplateConfig struct {
 // MockInstancesTestTemplate holds the data to build a mock contract call test
 type MockInstancesTestTemplate struct {
 	testTemplateConfig
-	contracts     *[]MockTestSmartContract
-	setup         SetupFunction
-	assertResults func(*TestCallNode, *worldmock.MockWorld, *VMOutputVerifier, []string)
+	contracts            *[]MockTestSmartContract
+	setup                SetupFunction
+	vmVersionSchedule    arwen.VMVersionSchedule
+	executionTracer      arwen.ExecutionTracer
+	debugMode            bool
+	asyncLimits          arwen.AsyncLimits
+	restrictLibraryCalls bool
+	enableEpochsHandler  arwen.EnableEpochsHandler
+	assertResults        func(*TestCallNode, *worldmock.MockWorld, *VMOutputVerifier, []string)
 }
 
 // BuildMockInstanceCallTest starts the building process for a mock contract call test
@@ -65,6 +71,42 @@ func (callerTest *MockInstancesTestTemplate) WithWasmerSIGSEGVPassthrough(wasmer
 	return callerTest
 }
 
+// WithVMVersionSchedule provides the VM versions to be used by the mock contract call test
+func (callerTest *MockInstancesTestTemplate) WithVMVersionSchedule(schedule arwen.VMVersionSchedule) *MockInstancesTestTemplate {
+	callerTest.vmVersionSchedule = schedule
+	return callerTest
+}
+
+// WithExecutionTracer provides the execution tracer to be used by the mock contract call test
+func (callerTest *MockInstancesTestTemplate) WithExecutionTracer(executionTracer arwen.ExecutionTracer) *MockInstancesTestTemplate {
+	callerTest.executionTracer = executionTracer
+	return callerTest
+}
+
+// WithDebugMode runs the contracts of the mock contract call test in debug mode
+func (callerTest *MockInstancesTestTemplate) WithDebugMode(debugMode bool) *MockInstancesTestTemplate {
+	callerTest.debugMode = debugMode
+	return callerTest
+}
+
+// WithAsyncLimits provides the async limits to be enforced by the mock contract call test
+func (callerTest *MockInstancesTestTemplate) WithAsyncLimits(asyncLimits arwen.AsyncLimits) *MockInstancesTestTemplate {
+	callerTest.asyncLimits = asyncLimits
+	return callerTest
+}
+
+// WithRestrictLibraryCalls lets only the readable contracts run on the context of their callers in the mock contract call test
+func (callerTest *MockInstancesTestTemplate) WithRestrictLibraryCalls(restrictLibraryCalls bool) *MockInstancesTestTemplate {
+	callerTest.restrictLibraryCalls = restrictLibraryCalls
+	return callerTest
+}
+
+// WithEnableEpochsHandler provides the flags and the activation epochs of the VM features for the test
+func (callerTest *MockInstancesTestTemplate) WithEnableEpochsHandler(enableEpochsHandler arwen.EnableEpochsHandler) *MockInstancesTestTemplate {
+	callerTest.enableEpochsHandler = enableEpochsHandler
+	return callerTest
+}
+
 type AssertResultsFunc func(world *worldmock.MockWorld, verify *VMOutputVerifier)
 
 // AndAssertResults provides the function that will aserts the results
@@ -98,6 +140,12 @@ func (callerTest *MockInstancesTestTemplate) runTest(startNode *TestCallNode, wo
 	host := NewTestHostBuilder(callerTest.tb).
 		WithExecutorFactory(executorFactory).
 		WithBlockchainHook(world).
+		WithVMVersionSchedule(callerTest.vmVersionSchedule).
+		WithExecutionTracer(callerTest.executionTracer).
+		WithDebugMode(callerTest.debugMode).
+		WithAsyncLimits(callerTest.asyncLimits).
+		WithRestrictLibraryCalls(callerTest.restrictLibraryCalls).
+		WithEnableEpochsHandler(callerTest.enableEpochsHandler).
 		Build()
 
 	defer func() {
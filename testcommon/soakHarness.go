@@ -0,0 +1,303 @@
+package testcommon
+
+import (
+	"bytes"
+	"encoding/binary"
+	"fmt"
+	"math/big"
+	"math/rand"
+	"strings"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/block"
+	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
+	"github.com/ElrondNetwork/wasm-vm/fees"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+const soakTokenGas = uint64(5_000_000)
+const soakPromiseGas = uint64(50_000_000)
+
+var soakTokenSupply = big.NewInt(1_000_000)
+
+var soakPromiseEndpoints = []string{
+	"no_async",
+	"one_async_call_no_cb_with_call_value",
+	"one_async_call_no_cb_fail",
+	"one_async_call_success_cb",
+	"one_async_call_fail_cb",
+	"two_async_same_cb_success_both",
+	"two_async_same_cb_success_first_fail_second",
+	"two_async_same_cb_fail_both",
+}
+
+var soakChildEndpoints = []string{"answer", "echo", "fail"}
+
+// SoakConfig configures the blocks produced by a SoakHarness
+type SoakConfig struct {
+	Seed               int64
+	NumBlocks          uint64
+	MaxTxsPerBlock     int
+	NumUsers           int
+	NumTokens          int
+	CheckpointInterval uint64
+	// RestartHostAtCheckpoints replaces the VM host with a new one at every
+	// checkpoint, dropping its warm instances and the compiled code of the
+	// contracts; the produced checkpoints must not change
+	RestartHostAtCheckpoints bool
+}
+
+// SoakCheckpoint is the state of a SoakHarness after a block: the hash of
+// the world state and a hash chained over the receipts of all the blocks
+type SoakCheckpoint struct {
+	BlockNonce   uint64
+	StateHash    []byte
+	ReceiptsHash []byte
+}
+
+// Equal returns true if both checkpoints were taken after the same block
+// and found the same state and the same receipts
+func (checkpoint *SoakCheckpoint) Equal(other *SoakCheckpoint) bool {
+	return checkpoint.BlockNonce == other.BlockNonce &&
+		bytes.Equal(checkpoint.StateHash, other.StateHash) &&
+		bytes.Equal(checkpoint.ReceiptsHash, other.ReceiptsHash)
+}
+
+// SoakHarness produces pseudo-random blocks of transactions against the
+// erc20 and the promises test contracts, all derived from the seed of its
+// configuration. After every block it verifies that no instance was left
+// running and that the tokens were conserved; every CheckpointInterval
+// blocks it records a SoakCheckpoint, so that runs of the same seed on
+// differently configured hosts can be compared.
+type SoakHarness struct {
+	tb           testing.TB
+	config       SoakConfig
+	random       *rand.Rand
+	world        *worldmock.MockWorld
+	host         arwen.VMHost
+	executor     *block.BlockExecutor
+	feeComputer  *fees.FeeComputer
+	users        [][]byte
+	tokens       [][]byte
+	numTxs       uint64
+	receiptsHash []byte
+}
+
+// NewSoakHarness creates a SoakHarness with the users and the contracts
+// already in the world; the tokens are deployed by the first user
+func NewSoakHarness(tb testing.TB, config SoakConfig) *SoakHarness {
+	feeComputer, err := fees.NewFeeComputer(fees.FeeConfig{
+		GasPriceModifier:    fees.GasPriceModifierDenominator,
+		DeveloperPercentage: fees.DefaultDeveloperPercentage,
+	})
+	require.Nil(tb, err)
+
+	harness := &SoakHarness{
+		tb:          tb,
+		config:      config,
+		random:      rand.New(rand.NewSource(config.Seed)),
+		world:       worldmock.NewMockWorld(),
+		feeComputer: feeComputer,
+	}
+	harness.startHost()
+	harness.createAccounts()
+	return harness
+}
+
+// Run produces all the blocks of the configuration and returns the checkpoints
+func (harness *SoakHarness) Run() []*SoakCheckpoint {
+	defer func() {
+		harness.host.Reset()
+	}()
+
+	checkpoints := make([]*SoakCheckpoint, 0)
+	for nonce := uint64(1); nonce <= harness.config.NumBlocks; nonce++ {
+		harness.executeBlock(nonce)
+
+		if nonce%harness.config.CheckpointInterval != 0 && nonce != harness.config.NumBlocks {
+			continue
+		}
+		checkpoints = append(checkpoints, &SoakCheckpoint{
+			BlockNonce:   nonce,
+			StateHash:    harness.world.ComputeStateHash(),
+			ReceiptsHash: harness.receiptsHash,
+		})
+		if harness.config.RestartHostAtCheckpoints {
+			harness.host.Reset()
+			harness.world.ClearCompiledCodes()
+			harness.startHost()
+		}
+	}
+	return checkpoints
+}
+
+func (harness *SoakHarness) startHost() {
+	harness.host = NewTestHostBuilder(harness.tb).
+		WithBlockchainHook(harness.world).
+		WithBuiltinFunctions().
+		Build()
+
+	executor, err := block.NewBlockExecutor(block.ArgsNewBlockExecutor{
+		Host:        harness.host,
+		State:       harness.world,
+		BlockInfo:   harness.world,
+		FeeComputer: harness.feeComputer,
+	})
+	require.Nil(harness.tb, err)
+	harness.executor = executor
+}
+
+func (harness *SoakHarness) createAccounts() {
+	for i := 0; i < harness.config.NumUsers; i++ {
+		address := []byte(fmt.Sprintf("soak_user_%02d", i))
+		address = append(address, strings.Repeat("_", 32-len(address))...)
+		account := harness.world.AcctMap.CreateAccount(address, harness.world)
+		account.Nonce = 1
+		account.Balance = big.NewInt(1_000_000_000)
+		harness.users = append(harness.users, address)
+	}
+	owner := harness.users[0]
+
+	parentCode := GetTestSCCodeModule("promises/parent-simple", "parent-simple", "../../")
+	parent := harness.world.AcctMap.CreateSmartContractAccount(owner, ParentAddress, parentCode, harness.world)
+	parent.Balance = big.NewInt(1_000_000_000)
+	childCode := GetTestSCCodeModule("promises/child-simple", "child-simple", "../../")
+	harness.world.AcctMap.CreateSmartContractAccount(owner, ChildAddress, childCode, harness.world)
+
+	tokenCode := GetTestSCCode("erc20", "../../")
+	for i := 0; i < harness.config.NumTokens; i++ {
+		harness.tokens = append(harness.tokens, harness.deployToken(owner, tokenCode, i))
+	}
+	harness.world.AddInvariant("token supply", harness.checkTokenSupply)
+}
+
+func (harness *SoakHarness) deployToken(owner []byte, code []byte, index int) []byte {
+	address := MakeTestSCAddress(fmt.Sprintf("soakToken%02d", index))
+	ownerAccount := harness.world.AcctMap.GetAccount(owner)
+	harness.world.NewAddressMocks = append(harness.world.NewAddressMocks, &worldmock.NewAddressMock{
+		CreatorAddress: owner,
+		CreatorNonce:   ownerAccount.Nonce,
+		NewAddress:     address,
+	})
+	ownerAccount.Nonce++
+
+	input := CreateTestContractCreateInputBuilder().
+		WithCallerAddr(owner).
+		WithContractCode(code).
+		WithArguments(soakTokenSupply.Bytes()).
+		WithGasProvided(soakTokenGas).
+		Build()
+	vmOutput, err := harness.host.RunSmartContractCreate(input)
+	require.Nil(harness.tb, err)
+	require.Equal(harness.tb, vmcommon.Ok, vmOutput.ReturnCode, vmOutput.ReturnMessage)
+	_, err = harness.world.ApplyVMOutput(vmOutput)
+	require.Nil(harness.tb, err)
+	return address
+}
+
+func (harness *SoakHarness) executeBlock(nonce uint64) {
+	numTxs := harness.random.Intn(harness.config.MaxTxsPerBlock + 1)
+	txs := make([]*block.Transaction, numTxs)
+	for i := range txs {
+		txs[i] = harness.randomTransaction()
+	}
+
+	randomSeed := make([]byte, 8)
+	binary.BigEndian.PutUint64(randomSeed, harness.random.Uint64())
+	result, err := harness.executor.ExecuteBlock(txs, &block.BlockContext{
+		Nonce:      nonce,
+		Round:      nonce,
+		Epoch:      uint32(nonce / DefaultRoundsPerEpoch),
+		TimeStamp:  nonce * DefaultTimePerRound,
+		RandomSeed: randomSeed,
+	})
+	require.Nil(harness.tb, err, "block %d, seed %d", nonce, harness.config.Seed)
+
+	hash, err := hashing.NewHasher().Sha256(append(harness.receiptsHash, result.ReceiptsRootHash...))
+	require.Nil(harness.tb, err)
+	harness.receiptsHash = hash
+
+	_, numColdInstances := harness.host.Runtime().NumRunningInstances()
+	require.Zero(harness.tb, numColdInstances, "instances left running after block %d, seed %d", nonce, harness.config.Seed)
+	require.Nil(harness.tb, harness.world.CheckInvariants(), "block %d, seed %d", nonce, harness.config.Seed)
+}
+
+func (harness *SoakHarness) randomTransaction() *block.Transaction {
+	harness.numTxs++
+	txHash := make([]byte, 32)
+	binary.BigEndian.PutUint64(txHash[24:], harness.numTxs)
+
+	builder := CreateTestContractCallInputBuilder().
+		WithCallerAddr(harness.randomUser()).
+		WithCurrentTxHash(txHash).
+		WithGasProvided(soakTokenGas)
+
+	token := harness.tokens[harness.random.Intn(len(harness.tokens))]
+	switch harness.random.Intn(10) {
+	case 0, 1, 2, 3:
+		builder.WithRecipientAddr(token).
+			WithFunction("transferToken").
+			WithArguments(harness.randomUser(), harness.randomAmount())
+	case 4:
+		builder.WithRecipientAddr(token).
+			WithFunction("approve").
+			WithArguments(harness.randomUser(), harness.randomAmount())
+	case 5, 6:
+		builder.WithRecipientAddr(token).
+			WithFunction("transferFrom").
+			WithArguments(harness.randomUser(), harness.randomUser(), harness.randomAmount())
+	case 7, 8:
+		builder.WithRecipientAddr(ParentAddress).
+			WithFunction(soakPromiseEndpoints[harness.random.Intn(len(soakPromiseEndpoints))]).
+			WithGasProvided(soakPromiseGas)
+	default:
+		function := soakChildEndpoints[harness.random.Intn(len(soakChildEndpoints))]
+		builder.WithRecipientAddr(ChildAddress).
+			WithFunction(function)
+		if function == "echo" {
+			builder.WithArguments(big.NewInt(harness.random.Int63n(1000)).Bytes())
+		}
+	}
+
+	return &block.Transaction{
+		Hash: txHash,
+		Call: builder.Build(),
+	}
+}
+
+func (harness *SoakHarness) randomUser() []byte {
+	return harness.users[harness.random.Intn(len(harness.users))]
+}
+
+func (harness *SoakHarness) randomAmount() []byte {
+	maxAmount := soakTokenSupply.Int64() / int64(len(harness.users))
+	return big.NewInt(harness.random.Int63n(maxAmount)).Bytes()
+}
+
+// checkTokenSupply verifies that the balances held in each token add up to
+// its total supply; the erc20 contract keeps the total supply under a key of
+// zeros and the balances under keys starting with 1
+func (harness *SoakHarness) checkTokenSupply(world *worldmock.MockWorld) error {
+	totalSupplyKey := string(make([]byte, 32))
+	for _, token := range harness.tokens {
+		account := world.AcctMap.GetAccount(token)
+		if account == nil {
+			return fmt.Errorf("token %s not found", token)
+		}
+
+		totalBalance := big.NewInt(0)
+		for key, value := range account.Storage {
+			if len(key) == 32 && key[0] == 1 {
+				totalBalance.Add(totalBalance, big.NewInt(0).SetBytes(value))
+			}
+		}
+		totalSupply := big.NewInt(0).SetBytes(account.Storage[totalSupplyKey])
+		if totalBalance.Cmp(totalSupply) != 0 {
+			return fmt.Errorf("token %s holds %s of its supply of %s", token, totalBalance, totalSupply)
+		}
+	}
+	return nil
+}
@@ -0,0 +1,155 @@
+package testcommon
+
+import (
+	"sort"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/elrond-vm-common/parsers"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/arwen/contexts"
+	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+)
+
+// CreateCallbacksForSystemSCAsyncCalls executes all the async calls found in
+// the VMOutput which target a mocked system smart contract of the world,
+// applies their outputs to the world and returns the inputs of the
+// cross-shard callbacks to be sent back to the callers, as the metachain would
+// do. The output accounts are visited in the order of their addresses.
+func CreateCallbacksForSystemSCAsyncCalls(
+	world *worldmock.MockWorld,
+	vmOutput *vmcommon.VMOutput,
+) ([]*vmcommon.ContractCallInput, error) {
+	argParser := parsers.NewCallArgsParser()
+	callbackInputs := make([]*vmcommon.ContractCallInput, 0)
+
+	addresses := make([]string, 0, len(vmOutput.OutputAccounts))
+	for address := range vmOutput.OutputAccounts {
+		addresses = append(addresses, address)
+	}
+	sort.Strings(addresses)
+
+	for _, address := range addresses {
+		outputAccount := vmOutput.OutputAccounts[address]
+		if !world.IsSystemSmartContract(outputAccount.Address) {
+			continue
+		}
+
+		for _, outputTransfer := range outputAccount.OutputTransfers {
+			if outputTransfer.CallType != vm.AsynchronousCall {
+				continue
+			}
+
+			function, arguments, err := argParser.ParseData(string(outputTransfer.Data))
+			if err != nil {
+				return nil, err
+			}
+
+			// async data starts with a @, so the first parsed argument is always empty
+			asyncData, err := argParser.ParseArguments(string(outputTransfer.AsyncData))
+			if err != nil {
+				return nil, err
+			}
+			if len(asyncData) < 3 {
+				return nil, vmcommon.ErrAsyncParams
+			}
+			callID := asyncData[1]
+			callerCallID := asyncData[2]
+
+			systemSCOutput, err := world.ProcessSystemSCCall(&vmcommon.ContractCallInput{
+				VMInput: vmcommon.VMInput{
+					CallerAddr:  outputTransfer.SenderAddress,
+					Arguments:   arguments,
+					CallValue:   outputTransfer.Value,
+					CallType:    vm.AsynchronousCall,
+					GasProvided: outputTransfer.GasLimit,
+				},
+				RecipientAddr: outputAccount.Address,
+				Function:      function,
+			})
+			if err != nil {
+				return nil, err
+			}
+			if systemSCOutput.ReturnCode == vmcommon.Ok {
+				err = world.UpdateAccounts(systemSCOutput.OutputAccounts, systemSCOutput.DeletedAccounts)
+				if err != nil {
+					return nil, err
+				}
+			}
+
+			callbackInputs = append(callbackInputs, createSystemSCCallbackInput(
+				outputAccount.Address,
+				outputTransfer,
+				callID,
+				callerCallID,
+				systemSCOutput,
+			))
+		}
+	}
+
+	return callbackInputs, nil
+}
+
+func createSystemSCCallbackInput(
+	systemSCAddress []byte,
+	outputTransfer vmcommon.OutputTransfer,
+	callID []byte,
+	callerCallID []byte,
+	systemSCOutput *vmcommon.VMOutput,
+) *vmcommon.ContractCallInput {
+	arguments := [][]byte{contexts.ReturnCodeToBytes(systemSCOutput.ReturnCode)}
+	if systemSCOutput.ReturnCode == vmcommon.Ok {
+		arguments = append(arguments, systemSCOutput.ReturnData...)
+	} else {
+		arguments = append(arguments, []byte(systemSCOutput.ReturnMessage))
+	}
+
+	return CreateTestContractCallInputBuilder().
+		WithCallerAddr(systemSCAddress).
+		WithRecipientAddr(outputTransfer.SenderAddress).
+		WithFunction(arwen.CallbackFunctionName).
+		WithGasProvided(systemSCOutput.GasRemaining).
+		WithGasLocked(outputTransfer.GasLocked).
+		WithCallValue(0).
+		WithCallType(vm.AsynchronousCallBack).
+		WithArguments(arguments...).
+		WithAsyncArguments(&vmcommon.AsyncArguments{
+			CallID:                       contexts.GenerateNewCallID(hashing.NewHasher(), callID, []byte{0}),
+			CallerCallID:                 callID,
+			CallbackAsyncInitiatorCallID: callerCallID,
+			GasAccumulated:               0,
+		}).
+		Build()
+}
+
+// RunSystemSCAsyncCalls executes the async calls found in the VMOutput which
+// target the mocked system smart contracts, then runs the resulting callbacks
+// on the host and persists their output into the world.
+func RunSystemSCAsyncCalls(
+	host arwen.VMHost,
+	world *worldmock.MockWorld,
+	vmOutput *vmcommon.VMOutput,
+) ([]*vmcommon.VMOutput, error) {
+	callbackInputs, err := CreateCallbacksForSystemSCAsyncCalls(world, vmOutput)
+	if err != nil {
+		return nil, err
+	}
+
+	callbackOutputs := make([]*vmcommon.VMOutput, 0, len(callbackInputs))
+	for _, callbackInput := range callbackInputs {
+		world.SelfShardID = world.GetShardOfAddress(callbackInput.RecipientAddr)
+		callbackOutput, err := host.RunSmartContractCall(callbackInput)
+		if err != nil {
+			return nil, err
+		}
+
+		err = world.UpdateAccounts(callbackOutput.OutputAccounts, callbackOutput.DeletedAccounts)
+		if err != nil {
+			return nil, err
+		}
+		callbackOutputs = append(callbackOutputs, callbackOutput)
+	}
+
+	return callbackOutputs, nil
+}
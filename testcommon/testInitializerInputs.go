@@ -12,6 +12,7 @@ import (
 	"strings"
 	"testing"
 
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
 	"github.com/ElrondNetwork/elrond-go-core/data/vm"
 	logger "github.com/ElrondNetwork/elrond-go-logger"
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
@@ -20,6 +21,7 @@ import (
 	"github.com/ElrondNetwork/wasm-vm/arwen"
 	arwenHost "github.com/ElrondNetwork/wasm-vm/arwen/host"
 	"github.com/ElrondNetwork/wasm-vm/arwen/mock"
+	"github.com/ElrondNetwork/wasm-vm/build"
 	"github.com/ElrondNetwork/wasm-vm/config"
 	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
 	"github.com/ElrondNetwork/wasm-vm/executor"
@@ -57,6 +59,8 @@ var NephewAddress = MakeTestSCAddress("NephewAddress")
 
 var customGasSchedule = config.GasScheduleMap(nil)
 
+var _ arwen.EnableEpochsHandler = (*worldmock.EnableEpochsHandlerStub)(nil)
+
 // ESDTTransferGasCost is an exposed value to use in tests
 var ESDTTransferGasCost = uint64(1)
 
@@ -77,6 +81,7 @@ func MakeTestSCAddress(identifier string) []byte {
 
 // GetSCCode retrieves the bytecode of a WASM module from a file
 func GetSCCode(fileName string) []byte {
+	rebuildSCIfRequested(fileName)
 	code, err := ioutil.ReadFile(filepath.Clean(fileName))
 	if err != nil {
 		panic(fmt.Sprintf("GetSCCode(): %s", fileName))
@@ -91,6 +96,7 @@ func GetTestSCCode(scName string, prefixToTestSCs ...string) []byte {
 	for _, prefixToTestSC := range prefixToTestSCs {
 		pathToSC := prefixToTestSC + "test/contracts/" + scName + "/output/" + scName + ".wasm"
 		searchedPaths = append(searchedPaths, pathToSC)
+		rebuildSCIfRequested(pathToSC)
 		code, err := ioutil.ReadFile(filepath.Clean(pathToSC))
 		if err == nil {
 			return code
@@ -99,6 +105,15 @@ func GetTestSCCode(scName string, prefixToTestSCs ...string) []byte {
 	panic(fmt.Sprintf("GetSCCode(): %s", searchedPaths))
 }
 
+// rebuildSCIfRequested rebuilds the contract crate whose code is in the file,
+// when the tests are run with build.RebuildContractsVariable set
+func rebuildSCIfRequested(fileName string) {
+	err := build.EnsureBuilt(fileName)
+	if err != nil {
+		panic(fmt.Sprintf("rebuild contract %s: %v", fileName, err))
+	}
+}
+
 // GetTestSCCodeModule retrieves the bytecode of a WASM testing contract, given
 // a specific name of the WASM module
 func GetTestSCCodeModule(scName string, moduleName string, prefixToTestSCs string) []byte {
@@ -160,6 +175,19 @@ func (thb *TestHostBuilder) initializeBuiltInFuncContainer() {
 
 }
 
+// Makes the flags of a stub enable epochs handler follow the epoch of the blockchain hook; the
+// stub is copied, so that tests can reuse it for several hosts.
+func (thb *TestHostBuilder) initializeEnableEpochsHandler() {
+	stub, ok := thb.vmHostParameters.EnableEpochsHandler.(*worldmock.EnableEpochsHandlerStub)
+	if !ok || stub.CurrentEpochCalled != nil || check.IfNil(thb.blockchainHook) {
+		return
+	}
+
+	stubWithEpoch := *stub
+	stubWithEpoch.CurrentEpochCalled = thb.blockchainHook.CurrentEpoch
+	thb.vmHostParameters.EnableEpochsHandler = &stubWithEpoch
+}
+
 // WithBlockchainHook sets a pre-built blockchain hook for the VM to work with.
 func (thb *TestHostBuilder) WithBlockchainHook(blockchainHook vmcommon.BlockchainHook) *TestHostBuilder {
 	thb.blockchainHook = blockchainHook
@@ -184,6 +212,13 @@ func (thb *TestHostBuilder) WithExecutorFactory(executorFactory executor.Executo
 	return thb
 }
 
+// WithShadowExecutor allows tests to execute every transaction again on a second executor.
+func (thb *TestHostBuilder) WithShadowExecutor(executorFactory executor.ExecutorAbstractFactory, handler arwen.ShadowDivergenceHandler) *TestHostBuilder {
+	thb.vmHostParameters.ShadowVMExecutor = executorFactory
+	thb.vmHostParameters.ShadowDivergenceHandler = handler
+	return thb
+}
+
 // WithWasmerSIGSEGVPassthrough allows tests to configure the WasmerSIGSEGVPassthrough flag.
 func (thb *TestHostBuilder) WithWasmerSIGSEGVPassthrough(wasmerSIGSEGVPassthrough bool) *TestHostBuilder {
 	thb.vmHostParameters.WasmerSIGSEGVPassthrough = wasmerSIGSEGVPassthrough
@@ -196,6 +231,99 @@ func (thb *TestHostBuilder) WithGasSchedule(gasSchedule config.GasScheduleMap) *
 	return thb
 }
 
+// WithEnableEpochsHandler allows tests to configure the flags and the activation epochs of the VM features.
+// A nil handler keeps the default one, which enables everything.
+func (thb *TestHostBuilder) WithEnableEpochsHandler(enableEpochsHandler arwen.EnableEpochsHandler) *TestHostBuilder {
+	if enableEpochsHandler != nil {
+		thb.vmHostParameters.EnableEpochsHandler = enableEpochsHandler
+	}
+	return thb
+}
+
+// WithVMVersionSchedule allows tests to replay blocks with the execution semantics of past epochs.
+func (thb *TestHostBuilder) WithVMVersionSchedule(schedule arwen.VMVersionSchedule) *TestHostBuilder {
+	thb.vmHostParameters.VMVersionSchedule = schedule
+	return thb
+}
+
+// WithMetricsSink allows tests to collect the metrics of the VM host.
+func (thb *TestHostBuilder) WithMetricsSink(metricsSink arwen.MetricsSink) *TestHostBuilder {
+	thb.vmHostParameters.MetricsSink = metricsSink
+	return thb
+}
+
+// WithExecutionTracer allows tests to record the calls executed by the VM host.
+func (thb *TestHostBuilder) WithExecutionTracer(executionTracer arwen.ExecutionTracer) *TestHostBuilder {
+	thb.vmHostParameters.ExecutionTracer = executionTracer
+	return thb
+}
+
+// WithExecutionPolicy allows tests to deny the execution of contracts.
+func (thb *TestHostBuilder) WithExecutionPolicy(executionPolicy arwen.ExecutionPolicy) *TestHostBuilder {
+	thb.vmHostParameters.ExecutionPolicy = executionPolicy
+	return thb
+}
+
+// WithGuardedAccounts allows tests to make the VM host require the co-signature of the guardians.
+func (thb *TestHostBuilder) WithGuardedAccounts(guardedAccounts arwen.GuardedAccountsHandler) *TestHostBuilder {
+	thb.vmHostParameters.GuardedAccounts = guardedAccounts
+	return thb
+}
+
+// WithExecutionPaused allows tests to start the VM host with the execution of the contracts paused.
+func (thb *TestHostBuilder) WithExecutionPaused(executionPaused bool) *TestHostBuilder {
+	thb.vmHostParameters.ExecutionPaused = executionPaused
+	return thb
+}
+
+// WithNonceHandling allows tests to make the VM host handle the nonces of the senders.
+func (thb *TestHostBuilder) WithNonceHandling(nonceHandling arwen.NonceHandling) *TestHostBuilder {
+	thb.vmHostParameters.NonceHandling = nonceHandling
+	return thb
+}
+
+// WithDebugMode allows tests to run the contracts in debug mode.
+func (thb *TestHostBuilder) WithDebugMode(debugMode bool) *TestHostBuilder {
+	thb.vmHostParameters.DebugMode = debugMode
+	return thb
+}
+
+// WithStrictEndpointValidation allows tests to make the VM host check the exports of the deployed contracts.
+func (thb *TestHostBuilder) WithStrictEndpointValidation(strictEndpointValidation bool) *TestHostBuilder {
+	thb.vmHostParameters.StrictEndpointValidation = strictEndpointValidation
+	return thb
+}
+
+// WithContractLimits allows tests to configure the limits checked on the code of the deployed contracts.
+func (thb *TestHostBuilder) WithContractLimits(contractLimits arwen.ContractLimits) *TestHostBuilder {
+	thb.vmHostParameters.ContractLimits = contractLimits
+	return thb
+}
+
+// WithAsyncLimits allows tests to bound the async calls and the output transfers of the contracts.
+func (thb *TestHostBuilder) WithAsyncLimits(asyncLimits arwen.AsyncLimits) *TestHostBuilder {
+	thb.vmHostParameters.AsyncLimits = asyncLimits
+	return thb
+}
+
+// WithStorageLimits allows tests to bound the keys and the values which the contracts write to storage.
+func (thb *TestHostBuilder) WithStorageLimits(storageLimits arwen.StorageLimits) *TestHostBuilder {
+	thb.vmHostParameters.StorageLimits = storageLimits
+	return thb
+}
+
+// WithMeteringExemptContracts allows tests to run contracts without the metering of their opcodes.
+func (thb *TestHostBuilder) WithMeteringExemptContracts(addresses [][]byte) *TestHostBuilder {
+	thb.vmHostParameters.MeteringExemptContracts = addresses
+	return thb
+}
+
+// WithRestrictLibraryCalls allows tests to only let the readable contracts run on the context of their callers.
+func (thb *TestHostBuilder) WithRestrictLibraryCalls(restrictLibraryCalls bool) *TestHostBuilder {
+	thb.vmHostParameters.RestrictLibraryCalls = restrictLibraryCalls
+	return thb
+}
+
 // Build initializes the VM host with all configured options.
 func (thb *TestHostBuilder) Build() arwen.VMHost {
 	thb.initializeHost()
@@ -211,6 +339,7 @@ func (thb *TestHostBuilder) initializeHost() {
 
 func (thb *TestHostBuilder) newHost() arwen.VMHost {
 	thb.initializeBuiltInFuncContainer()
+	thb.initializeEnableEpochsHandler()
 	host, err := arwenHost.NewArwenVM(
 		thb.blockchainHook,
 		thb.vmHostParameters,
@@ -0,0 +1,252 @@
+package testcommon
+
+import (
+	"bytes"
+	"encoding/hex"
+	"fmt"
+	"math/big"
+	"strconv"
+	"strings"
+	"testing"
+	"unicode"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+// ExpectedDiff holds the changes expected from an execution: the balance
+// deltas of some accounts, the values of some storage keys and the emitted
+// logs. Accounts and keys which are not mentioned are not checked.
+type ExpectedDiff struct {
+	balanceDeltas []expectedBalanceDelta
+	storage       []expectedStorageValue
+	logs          []vmcommon.LogEntry
+}
+
+type expectedBalanceDelta struct {
+	address []byte
+	delta   *big.Int
+}
+
+type expectedStorageValue struct {
+	address []byte
+	key     []byte
+	value   []byte
+}
+
+// CreateExpectedDiff creates an empty diff, to be filled with the expected changes
+func CreateExpectedDiff() *ExpectedDiff {
+	return &ExpectedDiff{}
+}
+
+// WithBalanceDelta expects the balance of the account to change by the given delta
+func (diff *ExpectedDiff) WithBalanceDelta(address []byte, delta int64) *ExpectedDiff {
+	diff.balanceDeltas = append(diff.balanceDeltas, expectedBalanceDelta{
+		address: address,
+		delta:   big.NewInt(delta),
+	})
+	return diff
+}
+
+// WithStorage expects the key of the account to hold the given value; an
+// empty value expects the key to be deleted
+func (diff *ExpectedDiff) WithStorage(address []byte, key []byte, value []byte) *ExpectedDiff {
+	diff.storage = append(diff.storage, expectedStorageValue{
+		address: address,
+		key:     key,
+		value:   value,
+	})
+	return diff
+}
+
+// WithLog expects a log with the given address, identifier and topics to be
+// emitted; the data of the log is not checked
+func (diff *ExpectedDiff) WithLog(address []byte, identifier []byte, topics ...[]byte) *ExpectedDiff {
+	diff.logs = append(diff.logs, vmcommon.LogEntry{
+		Address:    address,
+		Identifier: identifier,
+		Topics:     topics,
+	})
+	return diff
+}
+
+// Diff verifies that the VMOutput contains the expected changes, reporting
+// all the mismatches at once
+func (v *VMOutputVerifier) Diff(expected *ExpectedDiff) *VMOutputVerifier {
+	mismatches := make([]string, 0)
+
+	for _, balanceDelta := range expected.balanceDeltas {
+		actual := big.NewInt(0)
+		account := v.VmOutput.OutputAccounts[string(balanceDelta.address)]
+		if account != nil && account.BalanceDelta != nil {
+			actual = account.BalanceDelta
+		}
+		if actual.Cmp(balanceDelta.delta) != 0 {
+			mismatches = append(mismatches, fmt.Sprintf("balance delta of %s: expected %s, got %s",
+				describeAddress(balanceDelta.address), balanceDelta.delta, actual))
+		}
+	}
+
+	for _, storageValue := range expected.storage {
+		var actual []byte
+		written := false
+		account := v.VmOutput.OutputAccounts[string(storageValue.address)]
+		if account != nil {
+			storageUpdate := account.StorageUpdates[string(storageValue.key)]
+			if storageUpdate != nil && storageUpdate.Written {
+				actual = storageUpdate.Data
+				written = true
+			}
+		}
+		if !written {
+			mismatches = append(mismatches, fmt.Sprintf("storage of %s: expected %s, but it was not written",
+				describeAddress(storageValue.address), describeStorageValue(storageValue.key, storageValue.value)))
+			continue
+		}
+		mismatches = appendStorageMismatch(mismatches, storageValue, actual)
+	}
+
+	for _, log := range expected.logs {
+		if !isLogEmitted(log, v.VmOutput.Logs) {
+			mismatches = append(mismatches, fmt.Sprintf("log not emitted: %s; emitted logs: %s",
+				describeLog(log), describeLogs(v.VmOutput.Logs)))
+		}
+	}
+
+	requireNoMismatches(v.T, "VMOutput", mismatches)
+	return v
+}
+
+// RequireWorldDiff verifies that the after snapshot of the accounts of a mock
+// world differs from the before snapshot by the expected changes; logs are not
+// kept by the world, so expecting logs is reported as a mismatch
+func RequireWorldDiff(t testing.TB, before worldmock.AccountMap, after worldmock.AccountMap, expected *ExpectedDiff) {
+	mismatches := make([]string, 0)
+
+	for _, balanceDelta := range expected.balanceDeltas {
+		actual := big.NewInt(0).Sub(balanceOf(after, balanceDelta.address), balanceOf(before, balanceDelta.address))
+		if actual.Cmp(balanceDelta.delta) != 0 {
+			mismatches = append(mismatches, fmt.Sprintf("balance delta of %s: expected %s, got %s",
+				describeAddress(balanceDelta.address), balanceDelta.delta, actual))
+		}
+	}
+
+	for _, storageValue := range expected.storage {
+		var actual []byte
+		account := after.GetAccount(storageValue.address)
+		if account != nil {
+			actual = account.StorageValue(string(storageValue.key))
+		}
+		mismatches = appendStorageMismatch(mismatches, storageValue, actual)
+	}
+
+	if len(expected.logs) > 0 {
+		mismatches = append(mismatches, "logs cannot be verified on world snapshots, verify them on the VMOutput")
+	}
+
+	requireNoMismatches(t, "world", mismatches)
+}
+
+func appendStorageMismatch(mismatches []string, storageValue expectedStorageValue, actual []byte) []string {
+	if bytes.Equal(storageValue.value, actual) {
+		return mismatches
+	}
+	return append(mismatches, fmt.Sprintf("storage of %s: expected %s, got %s",
+		describeAddress(storageValue.address),
+		describeStorageValue(storageValue.key, storageValue.value),
+		describeStorageValue(storageValue.key, actual)))
+}
+
+func requireNoMismatches(t testing.TB, subject string, mismatches []string) {
+	if len(mismatches) == 0 {
+		return
+	}
+	require.FailNow(t, fmt.Sprintf("unexpected %s diff:\n\t%s", subject, strings.Join(mismatches, "\n\t")))
+}
+
+func balanceOf(accounts worldmock.AccountMap, address []byte) *big.Int {
+	account := accounts.GetAccount(address)
+	if account == nil || account.Balance == nil {
+		return big.NewInt(0)
+	}
+	return account.Balance
+}
+
+func isLogEmitted(expected vmcommon.LogEntry, logs []*vmcommon.LogEntry) bool {
+	for _, log := range logs {
+		if !bytes.Equal(expected.Address, log.Address) || !bytes.Equal(expected.Identifier, log.Identifier) {
+			continue
+		}
+		if len(expected.Topics) != len(log.Topics) {
+			continue
+		}
+		topicsEqual := true
+		for i := range expected.Topics {
+			topicsEqual = topicsEqual && bytes.Equal(expected.Topics[i], log.Topics[i])
+		}
+		if topicsEqual {
+			return true
+		}
+	}
+	return false
+}
+
+func describeLogs(logs []*vmcommon.LogEntry) string {
+	if len(logs) == 0 {
+		return "none"
+	}
+	descriptions := make([]string, len(logs))
+	for i, log := range logs {
+		descriptions[i] = describeLog(*log)
+	}
+	return strings.Join(descriptions, ", ")
+}
+
+func describeLog(log vmcommon.LogEntry) string {
+	topics := make([]string, len(log.Topics))
+	for i, topic := range log.Topics {
+		topics[i] = describeBytes(topic)
+	}
+	return fmt.Sprintf("%s from %s [%s]", strconv.Quote(string(log.Identifier)), describeAddress(log.Address), strings.Join(topics, " "))
+}
+
+// describeAddress shows test addresses, which are usually readable strings,
+// as quoted text and any other address in hex
+func describeAddress(address []byte) string {
+	if isPrintable(address) {
+		return strconv.Quote(string(address))
+	}
+	return hex.EncodeToString(address)
+}
+
+// describeBytes shows a value in hex, followed by its best-effort decoding as
+// text or as an unsigned integer
+func describeBytes(value []byte) string {
+	if len(value) == 0 {
+		return "<empty>"
+	}
+	return fmt.Sprintf("0x%s (%s)", hex.EncodeToString(value), decodeBytes(value))
+}
+
+func decodeBytes(value []byte) string {
+	if isPrintable(value) {
+		return strconv.Quote(string(value))
+	}
+	if len(value) <= 32 {
+		return big.NewInt(0).SetBytes(value).String()
+	}
+	return fmt.Sprintf("%d bytes", len(value))
+}
+
+func isPrintable(value []byte) bool {
+	if len(value) == 0 {
+		return false
+	}
+	for _, c := range value {
+		if c >= unicode.MaxASCII || !unicode.IsPrint(rune(c)) {
+			return false
+		}
+	}
+	return true
+}
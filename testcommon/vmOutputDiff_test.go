@@ -0,0 +1,137 @@
+package testcommon
+
+import (
+	"fmt"
+	"math/big"
+	"strings"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+var diffKey = []byte("counter")
+
+// recordingTB records the failures reported through it instead of failing the test
+type recordingTB struct {
+	testing.TB
+	failures []string
+}
+
+func (tb *recordingTB) Helper() {}
+
+func (tb *recordingTB) Name() string {
+	return "recordingTB"
+}
+
+func (tb *recordingTB) Errorf(format string, args ...interface{}) {
+	tb.failures = append(tb.failures, fmt.Sprintf(format, args...))
+}
+
+func (tb *recordingTB) FailNow() {}
+
+func (tb *recordingTB) requireFailure(t *testing.T, substrings ...string) {
+	require.Len(t, tb.failures, 1)
+	for _, substring := range substrings {
+		require.True(t, strings.Contains(tb.failures[0], substring), "%q not found in %q", substring, tb.failures[0])
+	}
+}
+
+func diffTestVMOutput() *vmcommon.VMOutput {
+	return &vmcommon.VMOutput{
+		ReturnCode: vmcommon.Ok,
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			string(ParentAddress): {
+				Address:      ParentAddress,
+				BalanceDelta: big.NewInt(-10),
+				StorageUpdates: map[string]*vmcommon.StorageUpdate{
+					string(diffKey): {Offset: diffKey, Data: []byte{5}, Written: true},
+				},
+				OutputTransfers: []vmcommon.OutputTransfer{
+					{Value: big.NewInt(10), SenderAddress: ParentAddress},
+				},
+			},
+			string(ChildAddress): {
+				Address:      ChildAddress,
+				BalanceDelta: big.NewInt(10),
+			},
+		},
+		Logs: []*vmcommon.LogEntry{
+			{Address: ParentAddress, Identifier: []byte("transfer"), Topics: [][]byte{ChildAddress}},
+		},
+	}
+}
+
+func diffTestAccount(address []byte, balance int64) *worldmock.Account {
+	return &worldmock.Account{
+		Address:         address,
+		Balance:         big.NewInt(balance),
+		BalanceDelta:    big.NewInt(0),
+		DeveloperReward: big.NewInt(0),
+		Storage:         make(map[string][]byte),
+	}
+}
+
+func TestVMOutputDiff_Equal(t *testing.T) {
+	tb := &recordingTB{}
+	verify := &VMOutputVerifier{VmOutput: diffTestVMOutput(), T: tb}
+	verify.Diff(CreateExpectedDiff().
+		WithBalanceDelta(ParentAddress, -10).
+		WithBalanceDelta(ChildAddress, 10).
+		WithBalanceDelta(UserAddress, 0).
+		WithStorage(ParentAddress, diffKey, []byte{5}).
+		WithLog(ParentAddress, []byte("transfer"), ChildAddress))
+	require.Empty(t, tb.failures)
+}
+
+func TestVMOutputDiff_DifferentStorage(t *testing.T) {
+	tb := &recordingTB{}
+	verify := &VMOutputVerifier{VmOutput: diffTestVMOutput(), T: tb}
+	verify.Diff(CreateExpectedDiff().
+		WithStorage(ParentAddress, diffKey, []byte{6}).
+		WithStorage(ChildAddress, diffKey, []byte{5}))
+	tb.requireFailure(t,
+		"unexpected VMOutput diff",
+		"counter = 0x06 (6), got counter = 0x05 (5)",
+		"storage of "+describeAddress(ChildAddress)+": expected counter = 0x05 (5), but it was not written")
+}
+
+func TestVMOutputDiff_DifferentTransfers(t *testing.T) {
+	tb := &recordingTB{}
+	verify := &VMOutputVerifier{VmOutput: diffTestVMOutput(), T: tb}
+	verify.Diff(CreateExpectedDiff().
+		WithBalanceDelta(ParentAddress, -20).
+		WithBalanceDelta(ChildAddress, 20).
+		WithLog(ParentAddress, []byte("transfer"), UserAddress))
+	tb.requireFailure(t,
+		"balance delta of "+describeAddress(ParentAddress)+": expected -20, got -10",
+		"balance delta of "+describeAddress(ChildAddress)+": expected 20, got 10",
+		"log not emitted")
+}
+
+func TestRequireWorldDiff(t *testing.T) {
+	before := worldmock.NewAccountMap()
+	before.PutAccount(diffTestAccount(ParentAddress, 100))
+	after := before.Clone()
+	after.GetAccount(ParentAddress).Balance = big.NewInt(90)
+	after.GetAccount(ParentAddress).Storage[string(diffKey)] = []byte{5}
+	after.PutAccount(diffTestAccount(ChildAddress, 10))
+
+	tb := &recordingTB{}
+	RequireWorldDiff(tb, before, after, CreateExpectedDiff().
+		WithBalanceDelta(ParentAddress, -10).
+		WithBalanceDelta(ChildAddress, 10).
+		WithStorage(ParentAddress, diffKey, []byte{5}))
+	require.Empty(t, tb.failures)
+
+	RequireWorldDiff(tb, before, after, CreateExpectedDiff().
+		WithBalanceDelta(ChildAddress, 20).
+		WithStorage(ParentAddress, diffKey, nil).
+		WithLog(ParentAddress, []byte("transfer")))
+	tb.requireFailure(t,
+		"unexpected world diff",
+		"expected 20, got 10",
+		"expected counter = <empty>, got counter = 0x05 (5)",
+		"logs cannot be verified on world snapshots")
+}
@@ -297,7 +297,11 @@ func (v *VMOutputVerifier) Storage(expectedEntries ...StoreEntry) *VMOutputVerif
 		require.Equal(v.T, len(accountStorageMap), len(outputAccount.StorageUpdates), "Storage")
 		for key, value := range accountStorageMap {
 			if ignore := ignoredKeys[key]; !ignore {
-				require.Equal(v.T, value, *outputAccount.StorageUpdates[key], "Storage")
+				actualUpdate := outputAccount.StorageUpdates[key]
+				require.NotNil(v.T, actualUpdate, "Storage "+describeStorageValue(value.Offset, value.Data))
+				errMsg := fmt.Sprintf("Storage expected %s, got %s",
+					describeStorageValue(value.Offset, value.Data), describeStorageValue(actualUpdate.Offset, actualUpdate.Data))
+				require.Equal(v.T, value, *actualUpdate, errMsg)
 			}
 		}
 		delete(storage, string(outputAccount.Address))
@@ -470,11 +474,11 @@ func (v *VMOutputVerifier) Print() *VMOutputVerifier {
 			log.Trace("VMOutput", "| OutputTransfers["+fmt.Sprint(i)+"].GasLimit", transfer.GasLimit)
 			log.Trace("VMOutput", "| OutputTransfers["+fmt.Sprint(i)+"].GasLocked", transfer.GasLocked)
 			log.Trace("VMOutput", "| OutputTransfers["+fmt.Sprint(i)+"].Value", transfer.Value)
-			log.Trace("VMOutput", "└ OutputTransfers["+fmt.Sprint(i)+"].Data", transfer.Data)
+			log.Trace("VMOutput", "└ OutputTransfers["+fmt.Sprint(i)+"].Data", transfer.Data, "decoded", describeCallData(transfer.Data))
 		}
 		for i, storage := range account.StorageUpdates {
 			log.Trace("VMOutput", "| StorageUpdate["+fmt.Sprintf(i)+"].Offset", string(storage.Offset), "len", len(storage.Offset))
-			log.Trace("VMOutput", "| StorageUpdate["+fmt.Sprintf(i)+"].Data", storage.Data, "len", len(storage.Data))
+			log.Trace("VMOutput", "| StorageUpdate["+fmt.Sprintf(i)+"].Data", storage.Data, "len", len(storage.Data), "decoded", describeStorageValue(storage.Offset, storage.Data))
 			log.Trace("VMOutput", "└ StorageUpdate["+fmt.Sprintf(i)+"].Written", storage.Written)
 		}
 	}
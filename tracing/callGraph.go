@@ -0,0 +1,300 @@
+package tracing
+
+import (
+	"bytes"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"strings"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ arwen.ExecutionTracer = (*CallGraphRecorder)(nil)
+var _ arwen.MeteringModeTracer = (*CallGraphRecorder)(nil)
+
+// CallNode is a traced contract call, with the nested calls it made; an
+// unmetered call ran on an instance of a contract exempted from metering
+type CallNode struct {
+	Caller      []byte
+	Contract    []byte
+	Function    string
+	CallType    vm.CallType
+	GasProvided uint64
+	GasUsed     uint64
+	ReturnCode  vmcommon.ReturnCode
+	Unmetered   bool
+	Children    []*CallNode
+}
+
+// CallGraphRecorder is an ExecutionTracer which records the tree of the
+// calls of every execution, and exports it as a call graph.
+type CallGraphRecorder struct {
+	calls []*CallNode
+	stack []*CallNode
+}
+
+// NewCallGraphRecorder creates a new CallGraphRecorder
+func NewCallGraphRecorder() *CallGraphRecorder {
+	return &CallGraphRecorder{
+		calls: make([]*CallNode, 0),
+		stack: make([]*CallNode, 0),
+	}
+}
+
+// BeginCall starts a new node, nested in the call currently being executed, if any
+func (recorder *CallGraphRecorder) BeginCall(input *vmcommon.ContractCallInput) {
+	node := &CallNode{
+		Caller:      input.CallerAddr,
+		Contract:    input.RecipientAddr,
+		Function:    input.Function,
+		CallType:    input.CallType,
+		GasProvided: input.GasProvided,
+		ReturnCode:  vmcommon.ExecutionFailed,
+		Children:    make([]*CallNode, 0),
+	}
+
+	if len(recorder.stack) == 0 {
+		recorder.calls = append(recorder.calls, node)
+	} else {
+		parent := recorder.stack[len(recorder.stack)-1]
+		parent.Children = append(parent.Children, node)
+	}
+	recorder.stack = append(recorder.stack, node)
+}
+
+// EndCall completes the node of the call currently being executed with its
+// outcome; a missing VMOutput leaves the call failed
+func (recorder *CallGraphRecorder) EndCall(vmOutput *vmcommon.VMOutput) {
+	if len(recorder.stack) == 0 {
+		return
+	}
+
+	node := recorder.stack[len(recorder.stack)-1]
+	recorder.stack = recorder.stack[:len(recorder.stack)-1]
+	if vmOutput == nil {
+		return
+	}
+
+	node.ReturnCode = vmOutput.ReturnCode
+	if node.GasProvided > vmOutput.GasRemaining {
+		node.GasUsed = node.GasProvided - vmOutput.GasRemaining
+	}
+}
+
+// TraceMeteringMode marks the call currently being executed as unmetered, if
+// the instance was started for its contract without metering
+func (recorder *CallGraphRecorder) TraceMeteringMode(address []byte, mode executor.MeteringMode) {
+	if len(recorder.stack) == 0 || mode == executor.MeteringEnabled {
+		return
+	}
+
+	node := recorder.stack[len(recorder.stack)-1]
+	if bytes.Equal(node.Contract, address) {
+		node.Unmetered = true
+	}
+}
+
+// Calls returns the top-level calls recorded so far
+func (recorder *CallGraphRecorder) Calls() []*CallNode {
+	return recorder.calls
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (recorder *CallGraphRecorder) IsInterfaceNil() bool {
+	return recorder == nil
+}
+
+// Reset discards the recorded calls
+func (recorder *CallGraphRecorder) Reset() {
+	recorder.calls = make([]*CallNode, 0)
+	recorder.stack = make([]*CallNode, 0)
+}
+
+// CallGraphNode is an endpoint of a contract in a call graph; the caller of a
+// top-level call appears as a node without function
+type CallGraphNode struct {
+	ID        string `json:"id"`
+	Contract  string `json:"contract"`
+	Function  string `json:"function,omitempty"`
+	Unmetered bool   `json:"unmetered,omitempty"`
+}
+
+// CallGraphEdge aggregates the calls of the same kind from one endpoint to
+// another; the gas used includes the gas of the calls nested in them
+type CallGraphEdge struct {
+	From     string `json:"from"`
+	To       string `json:"to"`
+	CallType string `json:"callType"`
+	Calls    uint64 `json:"calls"`
+	GasUsed  uint64 `json:"gasUsed"`
+}
+
+// CallGraph is the call graph of the recorded executions, with the nodes and
+// the edges in the order in which they were first called
+type CallGraph struct {
+	Nodes []*CallGraphNode `json:"nodes"`
+	Edges []*CallGraphEdge `json:"edges"`
+}
+
+// Graph builds the call graph of the recorded executions. A nested call is
+// attributed to the call which was executing when it began, so the callbacks
+// of the asynchronous calls start from the endpoint which made the call.
+func (recorder *CallGraphRecorder) Graph() *CallGraph {
+	builder := &callGraphBuilder{
+		graph: &CallGraph{
+			Nodes: make([]*CallGraphNode, 0),
+			Edges: make([]*CallGraphEdge, 0),
+		},
+		nodes: make(map[string]*CallGraphNode),
+		edges: make(map[string]*CallGraphEdge),
+	}
+
+	for _, call := range recorder.calls {
+		caller := builder.node(call.Caller, "")
+		builder.addCall(caller, call)
+	}
+	return builder.graph
+}
+
+// WriteJSON writes the call graph of the recorded executions as JSON
+func (recorder *CallGraphRecorder) WriteJSON(writer io.Writer) error {
+	serialized, err := json.MarshalIndent(recorder.Graph(), "", "  ")
+	if err != nil {
+		return err
+	}
+
+	_, err = writer.Write(append(serialized, '\n'))
+	return err
+}
+
+// WriteDOT writes the call graph of the recorded executions in the DOT
+// language of Graphviz, with the endpoints of each contract grouped in a
+// cluster and the call type, number of calls and gas used on each edge
+func (recorder *CallGraphRecorder) WriteDOT(writer io.Writer) error {
+	graph := recorder.Graph()
+
+	var sb strings.Builder
+	sb.WriteString("digraph calls {\n")
+	sb.WriteString("\tnode [shape=box];\n")
+
+	contracts := make([]string, 0)
+	nodesOfContract := make(map[string][]*CallGraphNode)
+	for _, node := range graph.Nodes {
+		if node.Function == "" {
+			sb.WriteString(fmt.Sprintf("\t%s [label=%s, shape=ellipse];\n", node.ID, quoteDOT(node.Contract)))
+			continue
+		}
+		if _, ok := nodesOfContract[node.Contract]; !ok {
+			contracts = append(contracts, node.Contract)
+		}
+		nodesOfContract[node.Contract] = append(nodesOfContract[node.Contract], node)
+	}
+
+	for i, contract := range contracts {
+		sb.WriteString(fmt.Sprintf("\tsubgraph cluster_%d {\n", i))
+		sb.WriteString(fmt.Sprintf("\t\tlabel=%s;\n", quoteDOT(contract)))
+		for _, node := range nodesOfContract[contract] {
+			if node.Unmetered {
+				sb.WriteString(fmt.Sprintf("\t\t%s [label=%s, style=dashed];\n", node.ID, quoteDOT(node.Function+" (unmetered)")))
+				continue
+			}
+			sb.WriteString(fmt.Sprintf("\t\t%s [label=%s];\n", node.ID, quoteDOT(node.Function)))
+		}
+		sb.WriteString("\t}\n")
+	}
+
+	for _, edge := range graph.Edges {
+		label := fmt.Sprintf("%s x%d\\n%d gas", edge.CallType, edge.Calls, edge.GasUsed)
+		sb.WriteString(fmt.Sprintf("\t%s -> %s [label=\"%s\"];\n", edge.From, edge.To, label))
+	}
+	sb.WriteString("}\n")
+
+	_, err := io.WriteString(writer, sb.String())
+	return err
+}
+
+type callGraphBuilder struct {
+	graph *CallGraph
+	nodes map[string]*CallGraphNode
+	edges map[string]*CallGraphEdge
+}
+
+func (builder *callGraphBuilder) addCall(from *CallGraphNode, call *CallNode) {
+	to := builder.node(call.Contract, call.Function)
+	to.Unmetered = to.Unmetered || call.Unmetered
+	callType := CallTypeName(call.CallType)
+
+	key := from.ID + "->" + to.ID + ":" + callType
+	edge, ok := builder.edges[key]
+	if !ok {
+		edge = &CallGraphEdge{
+			From:     from.ID,
+			To:       to.ID,
+			CallType: callType,
+		}
+		builder.edges[key] = edge
+		builder.graph.Edges = append(builder.graph.Edges, edge)
+	}
+	edge.Calls++
+	edge.GasUsed += call.GasUsed
+
+	for _, child := range call.Children {
+		builder.addCall(to, child)
+	}
+}
+
+func (builder *callGraphBuilder) node(address []byte, function string) *CallGraphNode {
+	key := string(address) + "\x00" + function
+	node, ok := builder.nodes[key]
+	if ok {
+		return node
+	}
+
+	node = &CallGraphNode{
+		ID:       fmt.Sprintf("n%d", len(builder.graph.Nodes)),
+		Contract: FormatAddress(address),
+		Function: function,
+	}
+	builder.nodes[key] = node
+	builder.graph.Nodes = append(builder.graph.Nodes, node)
+	return node
+}
+
+// CallTypeName returns the name of the call type shown in the call graphs
+func CallTypeName(callType vm.CallType) string {
+	switch callType {
+	case vm.DirectCall:
+		return "sync"
+	case vm.AsynchronousCall:
+		return "async"
+	case vm.AsynchronousCallBack:
+		return "callback"
+	case vm.ESDTTransferAndExecute:
+		return "esdtTransferAndExecute"
+	}
+	return fmt.Sprintf("callType%d", callType)
+}
+
+// FormatAddress shows an address as text if it is readable once the leading
+// zero bytes are removed, as are the addresses of tests, and in hex otherwise
+func FormatAddress(address []byte) string {
+	trimmed := bytes.TrimLeft(address, "\x00")
+	if len(trimmed) == 0 {
+		return hex.EncodeToString(address)
+	}
+	for _, b := range trimmed {
+		if b < 0x20 || b > 0x7e {
+			return hex.EncodeToString(address)
+		}
+	}
+	return string(trimmed)
+}
+
+func quoteDOT(text string) string {
+	return "\"" + strings.ReplaceAll(strings.ReplaceAll(text, "\\", "\\\\"), "\"", "\\\"") + "\""
+}
@@ -0,0 +1,128 @@
+package tracing
+
+import (
+	"bytes"
+	"encoding/json"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/stretchr/testify/require"
+)
+
+func callInput(caller string, contract string, function string, callType vm.CallType, gasProvided uint64) *vmcommon.ContractCallInput {
+	return &vmcommon.ContractCallInput{
+		VMInput: vmcommon.VMInput{
+			CallerAddr:  []byte(caller),
+			CallType:    callType,
+			GasProvided: gasProvided,
+		},
+		RecipientAddr: []byte(contract),
+		Function:      function,
+	}
+}
+
+func recordParentCallingChildTwice(recorder *CallGraphRecorder) {
+	recorder.BeginCall(callInput("user", "parent", "run", vm.DirectCall, 1000))
+	recorder.BeginCall(callInput("parent", "child", "work", vm.DirectCall, 300))
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 200})
+	recorder.BeginCall(callInput("parent", "child", "work", vm.AsynchronousCall, 300))
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 250})
+	recorder.BeginCall(callInput("child", "parent", "callBack", vm.AsynchronousCallBack, 100))
+	recorder.EndCall(nil)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 400})
+}
+
+func TestCallGraphRecorder_Calls(t *testing.T) {
+	recorder := NewCallGraphRecorder()
+	recordParentCallingChildTwice(recorder)
+
+	calls := recorder.Calls()
+	require.Len(t, calls, 1)
+	require.Equal(t, "run", calls[0].Function)
+	require.Equal(t, uint64(600), calls[0].GasUsed)
+	require.Equal(t, vmcommon.Ok, calls[0].ReturnCode)
+
+	children := calls[0].Children
+	require.Len(t, children, 3)
+	require.Equal(t, uint64(100), children[0].GasUsed)
+	require.Equal(t, vm.AsynchronousCall, children[1].CallType)
+	require.Equal(t, uint64(50), children[1].GasUsed)
+	require.Equal(t, vmcommon.ExecutionFailed, children[2].ReturnCode)
+	require.Equal(t, uint64(0), children[2].GasUsed)
+
+	recorder.Reset()
+	require.Len(t, recorder.Calls(), 0)
+}
+
+func TestCallGraphRecorder_Graph(t *testing.T) {
+	recorder := NewCallGraphRecorder()
+	recordParentCallingChildTwice(recorder)
+	recordParentCallingChildTwice(recorder)
+
+	graph := recorder.Graph()
+	require.Equal(t, []*CallGraphNode{
+		{ID: "n0", Contract: "user"},
+		{ID: "n1", Contract: "parent", Function: "run"},
+		{ID: "n2", Contract: "child", Function: "work"},
+		{ID: "n3", Contract: "parent", Function: "callBack"},
+	}, graph.Nodes)
+	require.Equal(t, []*CallGraphEdge{
+		{From: "n0", To: "n1", CallType: "sync", Calls: 2, GasUsed: 1200},
+		{From: "n1", To: "n2", CallType: "sync", Calls: 2, GasUsed: 200},
+		{From: "n1", To: "n2", CallType: "async", Calls: 2, GasUsed: 100},
+		{From: "n1", To: "n3", CallType: "callback", Calls: 2, GasUsed: 0},
+	}, graph.Edges)
+
+	var serialized bytes.Buffer
+	err := recorder.WriteJSON(&serialized)
+	require.Nil(t, err)
+	deserialized := &CallGraph{}
+	err = json.Unmarshal(serialized.Bytes(), deserialized)
+	require.Nil(t, err)
+	require.Equal(t, graph, deserialized)
+}
+
+func TestCallGraphRecorder_WriteDOT(t *testing.T) {
+	recorder := NewCallGraphRecorder()
+	recordParentCallingChildTwice(recorder)
+
+	var dot bytes.Buffer
+	err := recorder.WriteDOT(&dot)
+	require.Nil(t, err)
+	require.Contains(t, dot.String(), "digraph calls {\n")
+	require.Contains(t, dot.String(), "\tn0 [label=\"user\", shape=ellipse];\n")
+	require.Contains(t, dot.String(), "\tsubgraph cluster_0 {\n\t\tlabel=\"parent\";\n\t\tn1 [label=\"run\"];\n\t\tn3 [label=\"callBack\"];\n\t}\n")
+	require.Contains(t, dot.String(), "\tn1 -> n2 [label=\"async x1\\n50 gas\"];\n")
+}
+
+func TestFormatAddress(t *testing.T) {
+	require.Equal(t, "parentSC....", FormatAddress([]byte("\x00\x00parentSC....")))
+	require.Equal(t, "0001ff", FormatAddress([]byte{0, 1, 255}))
+	require.Equal(t, "0000", FormatAddress([]byte{0, 0}))
+}
+
+func TestCallGraphRecorder_MeteringMode(t *testing.T) {
+	recorder := NewCallGraphRecorder()
+	recorder.BeginCall(callInput("user", "parent", "run", vm.DirectCall, 1000))
+	recorder.TraceMeteringMode([]byte("parent"), executor.MeteringEnabled)
+	recorder.BeginCall(callInput("parent", "system", "work", vm.DirectCall, 300))
+	recorder.TraceMeteringMode([]byte("system"), executor.MeteringDisabled)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 200})
+	recorder.TraceMeteringMode([]byte("other"), executor.MeteringDisabled)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 400})
+
+	calls := recorder.Calls()
+	require.False(t, calls[0].Unmetered)
+	require.True(t, calls[0].Children[0].Unmetered)
+
+	graph := recorder.Graph()
+	require.False(t, graph.Nodes[1].Unmetered)
+	require.True(t, graph.Nodes[2].Unmetered)
+
+	var dot bytes.Buffer
+	err := recorder.WriteDOT(&dot)
+	require.Nil(t, err)
+	require.Contains(t, dot.String(), "\t\tn2 [label=\"work (unmetered)\", style=dashed];\n")
+}
@@ -0,0 +1,30 @@
+package tracing
+
+import (
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.ExecutionTracer = (*DisabledTracer)(nil)
+
+// DisabledTracer is the ExecutionTracer used when the executions are not traced; it discards every call
+type DisabledTracer struct {
+}
+
+// NewDisabledTracer creates a new DisabledTracer
+func NewDisabledTracer() *DisabledTracer {
+	return &DisabledTracer{}
+}
+
+// BeginCall does nothing
+func (tracer *DisabledTracer) BeginCall(_ *vmcommon.ContractCallInput) {
+}
+
+// EndCall does nothing
+func (tracer *DisabledTracer) EndCall(_ *vmcommon.VMOutput) {
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (tracer *DisabledTracer) IsInterfaceNil() bool {
+	return tracer == nil
+}
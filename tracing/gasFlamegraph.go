@@ -0,0 +1,176 @@
+package tracing
+
+import (
+	"fmt"
+	"io"
+	"sort"
+	"strings"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.ExecutionTracer = (*GasFlamegraphRecorder)(nil)
+var _ arwen.HostFunctionGasTracer = (*GasFlamegraphRecorder)(nil)
+var _ arwen.GasPhaseTracer = (*GasFlamegraphRecorder)(nil)
+
+// GasFlamegraphRecorder is an ExecutionTracer which aggregates the gas used
+// by the nested calls and by the host functions into folded stacks, the input
+// format of the flamegraph tools such as inferno and flamegraph.pl. Each
+// frame of a stack is a call, named "contract::function", suffixed by the
+// call type unless it is a synchronous call, and the leaves are the host
+// functions. The value of a stack is the gas used by its last frame alone.
+//
+// The phases marked by the contract with the gasPhase host function are
+// frames between the call and the host functions and nested calls made
+// during the phase, named "[phase]"; the gas used by a call before its first
+// marker stays on the frame of the call.
+type GasFlamegraphRecorder struct {
+	stack  []*flamegraphFrame
+	folded map[string]uint64
+}
+
+type flamegraphFrame struct {
+	stack       string
+	gasProvided uint64
+
+	// the phase being executed and the gas used by its own frame so far
+	phaseStack   string
+	phaseGasUsed uint64
+	gasOfCalls   uint64
+	gasOfHooks   uint64
+}
+
+// NewGasFlamegraphRecorder creates a new GasFlamegraphRecorder
+func NewGasFlamegraphRecorder() *GasFlamegraphRecorder {
+	return &GasFlamegraphRecorder{
+		stack:  make([]*flamegraphFrame, 0),
+		folded: make(map[string]uint64),
+	}
+}
+
+// BeginCall pushes the frame of the call on the stack
+func (recorder *GasFlamegraphRecorder) BeginCall(input *vmcommon.ContractCallInput) {
+	name := FormatAddress(input.RecipientAddr) + "::" + input.Function
+	if input.CallType != vm.DirectCall {
+		name += " [" + CallTypeName(input.CallType) + "]"
+	}
+
+	stack := foldedFrameName(name)
+	if len(recorder.stack) > 0 {
+		stack = recorder.currentFrame().phaseStack + ";" + stack
+	}
+	recorder.stack = append(recorder.stack, &flamegraphFrame{
+		stack:       stack,
+		gasProvided: input.GasProvided,
+		phaseStack:  stack,
+	})
+}
+
+// EndCall pops the frame of the call and adds the gas it used in its last
+// phase, apart from the gas used by its nested calls and host functions, to
+// the stack of the phase
+func (recorder *GasFlamegraphRecorder) EndCall(vmOutput *vmcommon.VMOutput) {
+	if len(recorder.stack) == 0 {
+		return
+	}
+
+	frame := recorder.currentFrame()
+	recorder.stack = recorder.stack[:len(recorder.stack)-1]
+
+	gasUsed := uint64(0)
+	if vmOutput != nil && frame.gasProvided > vmOutput.GasRemaining {
+		gasUsed = frame.gasProvided - vmOutput.GasRemaining
+	}
+	recorder.endPhase(frame, gasUsed)
+	if len(recorder.stack) > 0 {
+		recorder.currentFrame().gasOfCalls += gasUsed
+	}
+}
+
+// TraceGasPhase ends the phase of the call currently being executed, adding
+// the gas it used to the stack of the phase, and begins the given phase
+func (recorder *GasFlamegraphRecorder) TraceGasPhase(phase arwen.GasPhase, gasLeft uint64) {
+	if len(recorder.stack) == 0 {
+		return
+	}
+
+	frame := recorder.currentFrame()
+	gasUsed := uint64(0)
+	if frame.gasProvided > gasLeft {
+		gasUsed = frame.gasProvided - gasLeft
+	}
+	recorder.endPhase(frame, gasUsed)
+	frame.phaseStack = frame.stack + ";[" + phase.String() + "]"
+}
+
+// endPhase adds the gas used by the frame since the beginning of its phase,
+// apart from its nested calls and host functions, to the stack of the phase
+func (recorder *GasFlamegraphRecorder) endPhase(frame *flamegraphFrame, gasUsed uint64) {
+	if gasUsed > frame.phaseGasUsed+frame.gasOfCalls+frame.gasOfHooks {
+		recorder.folded[frame.phaseStack] += gasUsed - frame.phaseGasUsed - frame.gasOfCalls - frame.gasOfHooks
+	}
+	if gasUsed > frame.phaseGasUsed {
+		frame.phaseGasUsed = gasUsed
+	}
+	frame.gasOfCalls = 0
+	frame.gasOfHooks = 0
+}
+
+// TraceHostFunctionGas adds the gas used by a host function to the stack of
+// the call currently being executed
+func (recorder *GasFlamegraphRecorder) TraceHostFunctionGas(functionName string, usedGas uint64) {
+	if len(recorder.stack) == 0 {
+		return
+	}
+
+	frame := recorder.currentFrame()
+	frame.gasOfHooks += usedGas
+	recorder.folded[frame.phaseStack+";"+foldedFrameName(functionName)] += usedGas
+}
+
+// FoldedStacks returns the gas recorded for each stack
+func (recorder *GasFlamegraphRecorder) FoldedStacks() map[string]uint64 {
+	return recorder.folded
+}
+
+// Reset discards the recorded stacks
+func (recorder *GasFlamegraphRecorder) Reset() {
+	recorder.stack = make([]*flamegraphFrame, 0)
+	recorder.folded = make(map[string]uint64)
+}
+
+// WriteFolded writes the recorded stacks in the folded format, one
+// "frame;frame;frame gas" line per stack, sorted by stack
+func (recorder *GasFlamegraphRecorder) WriteFolded(writer io.Writer) error {
+	stacks := make([]string, 0, len(recorder.folded))
+	for stack, gas := range recorder.folded {
+		if gas > 0 {
+			stacks = append(stacks, stack)
+		}
+	}
+	sort.Strings(stacks)
+
+	var sb strings.Builder
+	for _, stack := range stacks {
+		sb.WriteString(fmt.Sprintf("%s %d\n", stack, recorder.folded[stack]))
+	}
+
+	_, err := io.WriteString(writer, sb.String())
+	return err
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (recorder *GasFlamegraphRecorder) IsInterfaceNil() bool {
+	return recorder == nil
+}
+
+func (recorder *GasFlamegraphRecorder) currentFrame() *flamegraphFrame {
+	return recorder.stack[len(recorder.stack)-1]
+}
+
+// foldedFrameName removes the separators of the folded format from a frame name
+func foldedFrameName(name string) string {
+	return strings.NewReplacer(";", "_", "\n", "_").Replace(name)
+}
@@ -0,0 +1,103 @@
+package tracing
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/stretchr/testify/require"
+)
+
+func TestGasFlamegraphRecorder_FoldedStacks(t *testing.T) {
+	recorder := NewGasFlamegraphRecorder()
+
+	recorder.BeginCall(callInput("user", "parent", "run", vm.DirectCall, 1000))
+	recorder.TraceHostFunctionGas("getArgument", 10)
+	recorder.BeginCall(callInput("parent", "child", "work", vm.DirectCall, 300))
+	recorder.TraceHostFunctionGas("storageStore", 40)
+	recorder.TraceHostFunctionGas("storageStore", 20)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 200})
+	recorder.BeginCall(callInput("child", "parent", "callBack", vm.AsynchronousCallBack, 100))
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 70})
+	recorder.TraceHostFunctionGas("finish", 5)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 500})
+
+	require.Equal(t, map[string]uint64{
+		"parent::run":                             355,
+		"parent::run;getArgument":                 10,
+		"parent::run;finish":                      5,
+		"parent::run;child::work":                 40,
+		"parent::run;child::work;storageStore":    60,
+		"parent::run;parent::callBack [callback]": 30,
+	}, recorder.FoldedStacks())
+
+	var folded bytes.Buffer
+	err := recorder.WriteFolded(&folded)
+	require.Nil(t, err)
+	require.Equal(t, "parent::run 355\n"+
+		"parent::run;child::work 40\n"+
+		"parent::run;child::work;storageStore 60\n"+
+		"parent::run;finish 5\n"+
+		"parent::run;getArgument 10\n"+
+		"parent::run;parent::callBack [callback] 30\n", folded.String())
+
+	recorder.Reset()
+	require.Len(t, recorder.FoldedStacks(), 0)
+}
+
+func TestGasFlamegraphRecorder_GasPhases(t *testing.T) {
+	recorder := NewGasFlamegraphRecorder()
+
+	recorder.BeginCall(callInput("user", "parent", "run", vm.DirectCall, 1000))
+	recorder.TraceGasPhase(arwen.GasPhaseArguments, 990)
+	recorder.TraceHostFunctionGas("getArgument", 10)
+	recorder.TraceGasPhase(arwen.GasPhaseBody, 970)
+	recorder.BeginCall(callInput("parent", "child", "work", vm.DirectCall, 300))
+	recorder.TraceHostFunctionGas("storageStore", 40)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 200})
+	recorder.TraceGasPhase(arwen.GasPhaseFinish, 850)
+	recorder.TraceHostFunctionGas("finish", 5)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 800})
+
+	require.Equal(t, map[string]uint64{
+		"parent::run":                                 10,
+		"parent::run;[arguments]":                     10,
+		"parent::run;[arguments];getArgument":         10,
+		"parent::run;[body]":                          20,
+		"parent::run;[body];child::work":              60,
+		"parent::run;[body];child::work;storageStore": 40,
+		"parent::run;[finish]":                        45,
+		"parent::run;[finish];finish":                 5,
+	}, recorder.FoldedStacks())
+}
+
+func TestGasFlamegraphRecorder_FailedCall(t *testing.T) {
+	recorder := NewGasFlamegraphRecorder()
+
+	recorder.BeginCall(callInput("user", "parent;x", "run", vm.DirectCall, 1000))
+	recorder.TraceHostFunctionGas("getArgument", 10)
+	recorder.EndCall(nil)
+
+	require.Equal(t, map[string]uint64{
+		"parent_x::run;getArgument": 10,
+	}, recorder.FoldedStacks())
+}
+
+func TestTracer_Config(t *testing.T) {
+	tracer := NewTracer(Config{GasFlamegraph: true})
+	require.Nil(t, tracer.CallGraph())
+	require.NotNil(t, tracer.GasFlamegraph())
+
+	tracer.BeginCall(callInput("user", "parent", "run", vm.DirectCall, 1000))
+	tracer.TraceHostFunctionGas("getArgument", 10)
+	tracer.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 900})
+	require.Equal(t, uint64(90), tracer.GasFlamegraph().FoldedStacks()["parent::run"])
+
+	tracer = NewTracer(Config{CallGraph: true, GasFlamegraph: true})
+	tracer.BeginCall(callInput("user", "parent", "run", vm.DirectCall, 1000))
+	tracer.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 900})
+	require.Len(t, tracer.CallGraph().Calls(), 1)
+	require.Equal(t, uint64(100), tracer.GasFlamegraph().FoldedStacks()["parent::run"])
+}
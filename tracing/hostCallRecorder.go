@@ -0,0 +1,214 @@
+package tracing
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"io"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var _ arwen.ExecutionTracer = (*HostCallRecorder)(nil)
+var _ arwen.HostFunctionGasTracer = (*HostCallRecorder)(nil)
+
+// Redaction hides the payloads of the recorded calls, so that the traces of
+// production systems can be shared in bug reports without their call data:
+// the arguments, the return data and the return messages longer than the
+// threshold are replaced by their length and their SHA-256 hash, taken after
+// the salt. Short payloads are kept, since they are mostly flags and small
+// numbers, and the hash of a short payload is easily reversed anyway.
+type Redaction struct {
+	Enabled   bool
+	Threshold int
+	Salt      []byte
+}
+
+// RecordedValue is a payload of a recorded call, hex encoded; a redacted
+// payload keeps only its length and its hash
+type RecordedValue struct {
+	Data     string `json:"data,omitempty"`
+	Length   int    `json:"length"`
+	Redacted bool   `json:"redacted,omitempty"`
+	Hash     string `json:"sha256,omitempty"`
+}
+
+// RecordedHostCall is a run of consecutive calls of the same host function
+// by a contract call, with the gas they used together
+type RecordedHostCall struct {
+	Name    string `json:"name"`
+	Calls   uint64 `json:"calls"`
+	GasUsed uint64 `json:"gasUsed"`
+}
+
+// RecordedCall is a recorded contract call: its input, its outcome, the host
+// functions it called in order and the calls nested in it
+type RecordedCall struct {
+	Caller        string              `json:"caller"`
+	Contract      string              `json:"contract"`
+	Function      string              `json:"function"`
+	CallType      string              `json:"callType"`
+	CallValue     string              `json:"callValue"`
+	Arguments     []*RecordedValue    `json:"arguments"`
+	GasProvided   uint64              `json:"gasProvided"`
+	GasUsed       uint64              `json:"gasUsed"`
+	ReturnCode    string              `json:"returnCode"`
+	ReturnMessage *RecordedValue      `json:"returnMessage,omitempty"`
+	ReturnData    []*RecordedValue    `json:"returnData"`
+	HostCalls     []*RecordedHostCall `json:"hostCalls"`
+	Calls         []*RecordedCall     `json:"calls"`
+}
+
+// HostCallRecorder is an ExecutionTracer which records every contract call
+// with its arguments, its outcome and the host functions it called, to be
+// shared as a reproduction trace; with the redaction enabled, the large
+// payloads are hashed while the structure of the calls and their gas are
+// kept as they are.
+type HostCallRecorder struct {
+	redaction Redaction
+	calls     []*RecordedCall
+	stack     []*RecordedCall
+}
+
+// NewHostCallRecorder creates a new HostCallRecorder
+func NewHostCallRecorder(redaction Redaction) *HostCallRecorder {
+	return &HostCallRecorder{
+		redaction: redaction,
+		calls:     make([]*RecordedCall, 0),
+		stack:     make([]*RecordedCall, 0),
+	}
+}
+
+// BeginCall starts the record of a call, nested in the call currently being
+// executed, if any
+func (recorder *HostCallRecorder) BeginCall(input *vmcommon.ContractCallInput) {
+	callValue := "0"
+	if input.CallValue != nil {
+		callValue = input.CallValue.String()
+	}
+	call := &RecordedCall{
+		Caller:      hex.EncodeToString(input.CallerAddr),
+		Contract:    hex.EncodeToString(input.RecipientAddr),
+		Function:    input.Function,
+		CallType:    CallTypeName(input.CallType),
+		CallValue:   callValue,
+		Arguments:   recorder.recordValues(input.Arguments),
+		GasProvided: input.GasProvided,
+		ReturnCode:  vmcommon.ExecutionFailed.String(),
+		ReturnData:  make([]*RecordedValue, 0),
+		HostCalls:   make([]*RecordedHostCall, 0),
+		Calls:       make([]*RecordedCall, 0),
+	}
+
+	if len(recorder.stack) == 0 {
+		recorder.calls = append(recorder.calls, call)
+	} else {
+		parent := recorder.currentCall()
+		parent.Calls = append(parent.Calls, call)
+	}
+	recorder.stack = append(recorder.stack, call)
+}
+
+// EndCall completes the record of the call currently being executed with
+// its outcome; a missing VMOutput leaves the call failed
+func (recorder *HostCallRecorder) EndCall(vmOutput *vmcommon.VMOutput) {
+	if len(recorder.stack) == 0 {
+		return
+	}
+
+	call := recorder.currentCall()
+	recorder.stack = recorder.stack[:len(recorder.stack)-1]
+	if vmOutput == nil {
+		return
+	}
+
+	call.ReturnCode = vmOutput.ReturnCode.String()
+	if len(vmOutput.ReturnMessage) > 0 {
+		call.ReturnMessage = recorder.recordValue([]byte(vmOutput.ReturnMessage))
+	}
+	call.ReturnData = recorder.recordValues(vmOutput.ReturnData)
+	if call.GasProvided > vmOutput.GasRemaining {
+		call.GasUsed = call.GasProvided - vmOutput.GasRemaining
+	}
+}
+
+// TraceHostFunctionGas records the call of a host function by the call
+// currently being executed, merging it with the previous call of the same
+// host function, if there was no other in between
+func (recorder *HostCallRecorder) TraceHostFunctionGas(functionName string, usedGas uint64) {
+	if len(recorder.stack) == 0 {
+		return
+	}
+
+	call := recorder.currentCall()
+	numHostCalls := len(call.HostCalls)
+	if numHostCalls > 0 && call.HostCalls[numHostCalls-1].Name == functionName {
+		hostCall := call.HostCalls[numHostCalls-1]
+		hostCall.Calls++
+		hostCall.GasUsed += usedGas
+		return
+	}
+	call.HostCalls = append(call.HostCalls, &RecordedHostCall{
+		Name:    functionName,
+		Calls:   1,
+		GasUsed: usedGas,
+	})
+}
+
+// Calls returns the top-level calls recorded so far
+func (recorder *HostCallRecorder) Calls() []*RecordedCall {
+	return recorder.calls
+}
+
+// WriteJSON writes the recorded calls as JSON
+func (recorder *HostCallRecorder) WriteJSON(writer io.Writer) error {
+	serialized, err := json.MarshalIndent(recorder.calls, "", "  ")
+	if err != nil {
+		return err
+	}
+
+	_, err = writer.Write(append(serialized, '\n'))
+	return err
+}
+
+// Reset discards the recorded calls
+func (recorder *HostCallRecorder) Reset() {
+	recorder.calls = make([]*RecordedCall, 0)
+	recorder.stack = make([]*RecordedCall, 0)
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (recorder *HostCallRecorder) IsInterfaceNil() bool {
+	return recorder == nil
+}
+
+func (recorder *HostCallRecorder) currentCall() *RecordedCall {
+	return recorder.stack[len(recorder.stack)-1]
+}
+
+func (recorder *HostCallRecorder) recordValues(values [][]byte) []*RecordedValue {
+	recorded := make([]*RecordedValue, len(values))
+	for i, value := range values {
+		recorded[i] = recorder.recordValue(value)
+	}
+	return recorded
+}
+
+func (recorder *HostCallRecorder) recordValue(value []byte) *RecordedValue {
+	if !recorder.redaction.Enabled || len(value) <= recorder.redaction.Threshold {
+		return &RecordedValue{
+			Data:   hex.EncodeToString(value),
+			Length: len(value),
+		}
+	}
+
+	hasher := sha256.New()
+	_, _ = hasher.Write(recorder.redaction.Salt)
+	_, _ = hasher.Write(value)
+	return &RecordedValue{
+		Length:   len(value),
+		Redacted: true,
+		Hash:     hex.EncodeToString(hasher.Sum(nil)),
+	}
+}
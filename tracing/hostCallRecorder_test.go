@@ -0,0 +1,117 @@
+package tracing
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"math/big"
+	"testing"
+
+	"github.com/ElrondNetwork/elrond-go-core/data/vm"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/stretchr/testify/require"
+)
+
+var secretArgument = []byte("a confidential payload of the caller")
+
+func recordCallWithPayloads(recorder *HostCallRecorder) {
+	input := callInput("user", "parent", "run", vm.DirectCall, 1000)
+	input.CallValue = big.NewInt(5)
+	input.Arguments = [][]byte{{0x01}, secretArgument}
+	recorder.BeginCall(input)
+	recorder.TraceHostFunctionGas("getArgument", 10)
+	recorder.TraceHostFunctionGas("getArgument", 10)
+	recorder.BeginCall(callInput("parent", "child", "work", vm.DirectCall, 300))
+	recorder.TraceHostFunctionGas("storageStore", 50)
+	recorder.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.UserError, ReturnMessage: "denied", GasRemaining: 200})
+	recorder.TraceHostFunctionGas("finish", 5)
+	recorder.TraceHostFunctionGas("getArgument", 10)
+	recorder.EndCall(&vmcommon.VMOutput{
+		ReturnCode:   vmcommon.Ok,
+		ReturnData:   [][]byte{secretArgument},
+		GasRemaining: 400,
+	})
+}
+
+func TestHostCallRecorder_Calls(t *testing.T) {
+	recorder := NewHostCallRecorder(Redaction{})
+	recordCallWithPayloads(recorder)
+
+	calls := recorder.Calls()
+	require.Len(t, calls, 1)
+	call := calls[0]
+	require.Equal(t, hex.EncodeToString([]byte("parent")), call.Contract)
+	require.Equal(t, "run", call.Function)
+	require.Equal(t, "sync", call.CallType)
+	require.Equal(t, "5", call.CallValue)
+	require.Equal(t, []*RecordedValue{
+		{Data: "01", Length: 1},
+		{Data: hex.EncodeToString(secretArgument), Length: len(secretArgument)},
+	}, call.Arguments)
+	require.Equal(t, uint64(600), call.GasUsed)
+	require.Equal(t, vmcommon.Ok.String(), call.ReturnCode)
+	require.Nil(t, call.ReturnMessage)
+	require.Equal(t, []*RecordedValue{{Data: hex.EncodeToString(secretArgument), Length: len(secretArgument)}}, call.ReturnData)
+	require.Equal(t, []*RecordedHostCall{
+		{Name: "getArgument", Calls: 2, GasUsed: 20},
+		{Name: "finish", Calls: 1, GasUsed: 5},
+		{Name: "getArgument", Calls: 1, GasUsed: 10},
+	}, call.HostCalls)
+
+	require.Len(t, call.Calls, 1)
+	child := call.Calls[0]
+	require.Equal(t, uint64(100), child.GasUsed)
+	require.Equal(t, vmcommon.UserError.String(), child.ReturnCode)
+	require.Equal(t, &RecordedValue{Data: hex.EncodeToString([]byte("denied")), Length: 6}, child.ReturnMessage)
+	require.Equal(t, []*RecordedHostCall{{Name: "storageStore", Calls: 1, GasUsed: 50}}, child.HostCalls)
+
+	recorder.Reset()
+	require.Len(t, recorder.Calls(), 0)
+}
+
+func TestHostCallRecorder_Redaction(t *testing.T) {
+	redaction := Redaction{Enabled: true, Threshold: 8, Salt: []byte("salt")}
+	recorder := NewHostCallRecorder(redaction)
+	recordCallWithPayloads(recorder)
+
+	saltedHash := sha256.Sum256(append([]byte("salt"), secretArgument...))
+	redacted := &RecordedValue{
+		Length:   len(secretArgument),
+		Redacted: true,
+		Hash:     hex.EncodeToString(saltedHash[:]),
+	}
+	call := recorder.Calls()[0]
+	require.Equal(t, []*RecordedValue{{Data: "01", Length: 1}, redacted}, call.Arguments)
+	require.Equal(t, []*RecordedValue{redacted}, call.ReturnData)
+	require.Equal(t, uint64(600), call.GasUsed)
+	require.Len(t, call.HostCalls, 3)
+	require.Equal(t, &RecordedValue{Data: hex.EncodeToString([]byte("denied")), Length: 6}, call.Calls[0].ReturnMessage)
+
+	var serialized bytes.Buffer
+	err := recorder.WriteJSON(&serialized)
+	require.Nil(t, err)
+	require.NotContains(t, serialized.String(), hex.EncodeToString(secretArgument))
+
+	var decoded []*RecordedCall
+	err = json.Unmarshal(serialized.Bytes(), &decoded)
+	require.Nil(t, err)
+	require.Equal(t, recorder.Calls(), decoded)
+}
+
+func TestTracer_HostCalls(t *testing.T) {
+	tracer := NewTracer(Config{})
+	require.Nil(t, tracer.HostCalls())
+
+	tracer = NewTracer(Config{HostCalls: true, Redaction: Redaction{Enabled: true}})
+	tracer.BeginCall(callInput("user", "parent", "run", vm.DirectCall, 1000))
+	tracer.TraceHostFunctionGas("finish", 5)
+	tracer.EndCall(&vmcommon.VMOutput{ReturnCode: vmcommon.Ok, GasRemaining: 900})
+
+	calls := tracer.HostCalls().Calls()
+	require.Len(t, calls, 1)
+	require.Equal(t, []*RecordedHostCall{{Name: "finish", Calls: 1, GasUsed: 5}}, calls[0].HostCalls)
+
+	tracer.Reset()
+	require.Len(t, tracer.HostCalls().Calls(), 0)
+}
@@ -0,0 +1,192 @@
+package tracing
+
+import (
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/executor"
+)
+
+var _ arwen.ExecutionTracer = (*Tracer)(nil)
+var _ arwen.HostFunctionGasTracer = (*Tracer)(nil)
+var _ arwen.DebugMessageTracer = (*Tracer)(nil)
+var _ arwen.GasPhaseTracer = (*Tracer)(nil)
+var _ arwen.MeteringModeTracer = (*Tracer)(nil)
+var _ arwen.ManagedHandleLeakTracer = (*Tracer)(nil)
+
+// Config selects the traces recorded by a Tracer; Redaction applies to the
+// recorded host calls
+type Config struct {
+	CallGraph     bool
+	GasFlamegraph bool
+	HostCalls     bool
+	Redaction     Redaction
+	DebugMessages bool
+	LeakedHandles bool
+}
+
+// DebugMessage is a message printed by a contract in debug mode
+type DebugMessage struct {
+	Address []byte
+	Message []byte
+}
+
+// LeakedHandles are the managed values left held by an endpoint when it
+// returned, in debug mode
+type LeakedHandles struct {
+	Address  []byte
+	Function string
+	arwen.ManagedHandleCounts
+}
+
+// Tracer is the ExecutionTracer which feeds the recorders enabled by its
+// Config. Being a HostFunctionGasTracer, it turns on the gas tracing of the
+// host even without GasFlamegraph; a bare CallGraphRecorder avoids that.
+type Tracer struct {
+	callGraph     *CallGraphRecorder
+	gasFlamegraph *GasFlamegraphRecorder
+	hostCalls     *HostCallRecorder
+	debugMessages []*DebugMessage
+	traceMessages bool
+	leakedHandles []*LeakedHandles
+	traceLeaks    bool
+}
+
+// NewTracer creates a new Tracer with the recorders enabled by the config
+func NewTracer(config Config) *Tracer {
+	tracer := &Tracer{
+		debugMessages: make([]*DebugMessage, 0),
+		traceMessages: config.DebugMessages,
+		leakedHandles: make([]*LeakedHandles, 0),
+		traceLeaks:    config.LeakedHandles,
+	}
+	if config.CallGraph {
+		tracer.callGraph = NewCallGraphRecorder()
+	}
+	if config.GasFlamegraph {
+		tracer.gasFlamegraph = NewGasFlamegraphRecorder()
+	}
+	if config.HostCalls {
+		tracer.hostCalls = NewHostCallRecorder(config.Redaction)
+	}
+	return tracer
+}
+
+// CallGraph returns the CallGraphRecorder, or nil if it is not enabled
+func (tracer *Tracer) CallGraph() *CallGraphRecorder {
+	return tracer.callGraph
+}
+
+// GasFlamegraph returns the GasFlamegraphRecorder, or nil if it is not enabled
+func (tracer *Tracer) GasFlamegraph() *GasFlamegraphRecorder {
+	return tracer.gasFlamegraph
+}
+
+// HostCalls returns the HostCallRecorder, or nil if it is not enabled
+func (tracer *Tracer) HostCalls() *HostCallRecorder {
+	return tracer.hostCalls
+}
+
+// DebugMessages returns the messages printed by the contracts in debug mode,
+// if they are enabled
+func (tracer *Tracer) DebugMessages() []*DebugMessage {
+	return tracer.debugMessages
+}
+
+// LeakedHandles returns the managed values left held by the endpoints in
+// debug mode, per endpoint call, if they are enabled
+func (tracer *Tracer) LeakedHandles() []*LeakedHandles {
+	return tracer.leakedHandles
+}
+
+// BeginCall forwards the call to the enabled recorders
+func (tracer *Tracer) BeginCall(input *vmcommon.ContractCallInput) {
+	if tracer.callGraph != nil {
+		tracer.callGraph.BeginCall(input)
+	}
+	if tracer.gasFlamegraph != nil {
+		tracer.gasFlamegraph.BeginCall(input)
+	}
+	if tracer.hostCalls != nil {
+		tracer.hostCalls.BeginCall(input)
+	}
+}
+
+// EndCall forwards the call to the enabled recorders
+func (tracer *Tracer) EndCall(vmOutput *vmcommon.VMOutput) {
+	if tracer.callGraph != nil {
+		tracer.callGraph.EndCall(vmOutput)
+	}
+	if tracer.gasFlamegraph != nil {
+		tracer.gasFlamegraph.EndCall(vmOutput)
+	}
+	if tracer.hostCalls != nil {
+		tracer.hostCalls.EndCall(vmOutput)
+	}
+}
+
+// TraceHostFunctionGas forwards the call to the enabled recorders
+func (tracer *Tracer) TraceHostFunctionGas(functionName string, usedGas uint64) {
+	if tracer.gasFlamegraph != nil {
+		tracer.gasFlamegraph.TraceHostFunctionGas(functionName, usedGas)
+	}
+	if tracer.hostCalls != nil {
+		tracer.hostCalls.TraceHostFunctionGas(functionName, usedGas)
+	}
+}
+
+// TraceGasPhase forwards the call to the enabled recorders
+func (tracer *Tracer) TraceGasPhase(phase arwen.GasPhase, gasLeft uint64) {
+	if tracer.gasFlamegraph != nil {
+		tracer.gasFlamegraph.TraceGasPhase(phase, gasLeft)
+	}
+}
+
+// TraceMeteringMode forwards the call to the enabled recorders
+func (tracer *Tracer) TraceMeteringMode(address []byte, mode executor.MeteringMode) {
+	if tracer.callGraph != nil {
+		tracer.callGraph.TraceMeteringMode(address, mode)
+	}
+}
+
+// TraceDebugMessage records the message, if the messages are enabled
+func (tracer *Tracer) TraceDebugMessage(address []byte, message []byte) {
+	if !tracer.traceMessages {
+		return
+	}
+	tracer.debugMessages = append(tracer.debugMessages, &DebugMessage{
+		Address: address,
+		Message: message,
+	})
+}
+
+// TraceLeakedHandles records the leaked handles, if they are enabled
+func (tracer *Tracer) TraceLeakedHandles(address []byte, function string, counts arwen.ManagedHandleCounts) {
+	if !tracer.traceLeaks {
+		return
+	}
+	tracer.leakedHandles = append(tracer.leakedHandles, &LeakedHandles{
+		Address:             address,
+		Function:            function,
+		ManagedHandleCounts: counts,
+	})
+}
+
+// Reset discards the traces of the enabled recorders
+func (tracer *Tracer) Reset() {
+	tracer.debugMessages = make([]*DebugMessage, 0)
+	tracer.leakedHandles = make([]*LeakedHandles, 0)
+	if tracer.callGraph != nil {
+		tracer.callGraph.Reset()
+	}
+	if tracer.gasFlamegraph != nil {
+		tracer.gasFlamegraph.Reset()
+	}
+	if tracer.hostCalls != nil {
+		tracer.hostCalls.Reset()
+	}
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (tracer *Tracer) IsInterfaceNil() bool {
+	return tracer == nil
+}
@@ -0,0 +1,47 @@
+package trie
+
+import (
+	"math/big"
+)
+
+// AccountState is the value saved for each address in the accounts trie
+type AccountState struct {
+	Nonce       uint64
+	Balance     *big.Int
+	CodeHash    []byte
+	StorageRoot []byte
+}
+
+// Encode serializes the account state deterministically
+func (state *AccountState) Encode() []byte {
+	balance := state.Balance
+	if balance == nil {
+		balance = big.NewInt(0)
+	}
+
+	encoded := make([]byte, 0)
+	encoded = appendLengthPrefixed(encoded, big.NewInt(0).SetUint64(state.Nonce).Bytes())
+	encoded = appendLengthPrefixed(encoded, balance.Bytes())
+	encoded = appendLengthPrefixed(encoded, state.CodeHash)
+	return appendLengthPrefixed(encoded, state.StorageRoot)
+}
+
+// DecodeAccountState rebuilds an account state produced by Encode()
+func DecodeAccountState(encoded []byte) (*AccountState, error) {
+	fields := make([][]byte, 4)
+	rest := encoded
+	for i := range fields {
+		var err error
+		fields[i], rest, err = readLengthPrefixed(rest)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return &AccountState{
+		Nonce:       big.NewInt(0).SetBytes(fields[0]).Uint64(),
+		Balance:     big.NewInt(0).SetBytes(fields[1]),
+		CodeHash:    fields[2],
+		StorageRoot: fields[3],
+	}, nil
+}
@@ -0,0 +1,77 @@
+package trie
+
+import (
+	"errors"
+	"sync"
+)
+
+// ErrKeyNotFound signals that the requested key is missing from the KVStore
+var ErrKeyNotFound = errors.New("key not found")
+
+// ErrNilKVStore signals that a nil KVStore was provided
+var ErrNilKVStore = errors.New("nil KVStore")
+
+// KVStore is the persistence layer of the tries; trie nodes are saved under
+// the hash of their encoding.
+type KVStore interface {
+	Get(key []byte) ([]byte, error)
+	Put(key []byte, value []byte) error
+	Remove(key []byte) error
+	IsInterfaceNil() bool
+}
+
+type memoryKVStore struct {
+	mutData sync.RWMutex
+	data    map[string][]byte
+}
+
+// NewMemoryKVStore creates a KVStore which keeps all its data in memory
+func NewMemoryKVStore() *memoryKVStore {
+	return &memoryKVStore{
+		data: make(map[string][]byte),
+	}
+}
+
+// Get returns the value saved under the given key, or ErrKeyNotFound
+func (store *memoryKVStore) Get(key []byte) ([]byte, error) {
+	store.mutData.RLock()
+	defer store.mutData.RUnlock()
+
+	value, found := store.data[string(key)]
+	if !found {
+		return nil, ErrKeyNotFound
+	}
+
+	return value, nil
+}
+
+// Put saves the value under the given key, replacing any previous value
+func (store *memoryKVStore) Put(key []byte, value []byte) error {
+	store.mutData.Lock()
+	defer store.mutData.Unlock()
+
+	store.data[string(key)] = value
+	return nil
+}
+
+// Remove deletes the given key; removing a missing key is not an error
+func (store *memoryKVStore) Remove(key []byte) error {
+	store.mutData.Lock()
+	defer store.mutData.Unlock()
+
+	delete(store.data, string(key))
+	return nil
+}
+
+// Len returns the number of entries in the store
+func (store *memoryKVStore) Len() int {
+	store.mutData.RLock()
+	defer store.mutData.RUnlock()
+
+	return len(store.data)
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (store *memoryKVStore) IsInterfaceNil() bool {
+	return store == nil
+}
@@ -0,0 +1,183 @@
+package trie
+
+import (
+	"encoding/binary"
+	"errors"
+)
+
+// ErrInvalidNodeEncoding signals that a trie node could not be decoded
+var ErrInvalidNodeEncoding = errors.New("invalid trie node encoding")
+
+const (
+	leafNodeType      = byte(0)
+	extensionNodeType = byte(1)
+	branchNodeType    = byte(2)
+	branchWidth       = 16
+)
+
+// node is one of *leafNode, *extensionNode, *branchNode or hashNode. Nodes are
+// never modified after creation, which makes it safe to cache their hashes and
+// to share them between the tries of different snapshots.
+type node interface{}
+
+// leafNode holds a value under the remaining nibbles of its key
+type leafNode struct {
+	key   []byte
+	value []byte
+	hash  []byte
+}
+
+// extensionNode shortcuts a run of nibbles shared by all keys below it
+type extensionNode struct {
+	key   []byte
+	child node
+	hash  []byte
+}
+
+// branchNode forks on the next nibble of the key and can also hold the value
+// of the key which ends exactly at it
+type branchNode struct {
+	children [branchWidth]node
+	value    []byte
+	hash     []byte
+}
+
+// hashNode is a reference to a node which has not been loaded from the KVStore yet
+type hashNode []byte
+
+func keyToNibbles(key []byte) []byte {
+	nibbles := make([]byte, len(key)*2)
+	for i, b := range key {
+		nibbles[2*i] = b >> 4
+		nibbles[2*i+1] = b & 0x0f
+	}
+	return nibbles
+}
+
+func nibblesToKey(nibbles []byte) []byte {
+	key := make([]byte, len(nibbles)/2)
+	for i := range key {
+		key[i] = nibbles[2*i]<<4 | nibbles[2*i+1]
+	}
+	return key
+}
+
+func commonPrefixLength(a []byte, b []byte) int {
+	i := 0
+	for i < len(a) && i < len(b) && a[i] == b[i] {
+		i++
+	}
+	return i
+}
+
+func concatNibbles(parts ...[]byte) []byte {
+	result := make([]byte, 0)
+	for _, part := range parts {
+		result = append(result, part...)
+	}
+	return result
+}
+
+func appendLengthPrefixed(buffer []byte, data []byte) []byte {
+	lengthBytes := make([]byte, binary.MaxVarintLen64)
+	n := binary.PutUvarint(lengthBytes, uint64(len(data)))
+	buffer = append(buffer, lengthBytes[:n]...)
+	return append(buffer, data...)
+}
+
+func readLengthPrefixed(buffer []byte) ([]byte, []byte, error) {
+	length, n := binary.Uvarint(buffer)
+	if n <= 0 || uint64(len(buffer)-n) < length {
+		return nil, nil, ErrInvalidNodeEncoding
+	}
+	end := n + int(length)
+	return buffer[n:end], buffer[end:], nil
+}
+
+// encodeNode serializes a node, referencing its children by their hashes
+func (t *PatriciaTrie) encodeNode(n node) ([]byte, error) {
+	switch n := n.(type) {
+	case *leafNode:
+		encoded := []byte{leafNodeType}
+		encoded = appendLengthPrefixed(encoded, n.key)
+		return appendLengthPrefixed(encoded, n.value), nil
+	case *extensionNode:
+		childHash, err := t.hashOf(n.child)
+		if err != nil {
+			return nil, err
+		}
+		encoded := []byte{extensionNodeType}
+		encoded = appendLengthPrefixed(encoded, n.key)
+		return appendLengthPrefixed(encoded, childHash), nil
+	case *branchNode:
+		encoded := []byte{branchNodeType}
+		for _, child := range n.children {
+			var childHash []byte
+			if child != nil {
+				var err error
+				childHash, err = t.hashOf(child)
+				if err != nil {
+					return nil, err
+				}
+			}
+			encoded = appendLengthPrefixed(encoded, childHash)
+		}
+		return appendLengthPrefixed(encoded, n.value), nil
+	}
+
+	return nil, ErrInvalidNodeEncoding
+}
+
+// decodeNode rebuilds a node from its encoding; children are left as hashNodes
+func decodeNode(encoded []byte) (node, error) {
+	if len(encoded) == 0 {
+		return nil, ErrInvalidNodeEncoding
+	}
+
+	nodeType, rest := encoded[0], encoded[1:]
+	switch nodeType {
+	case leafNodeType:
+		key, rest, err := readLengthPrefixed(rest)
+		if err != nil {
+			return nil, err
+		}
+		value, _, err := readLengthPrefixed(rest)
+		if err != nil {
+			return nil, err
+		}
+		return &leafNode{key: key, value: value}, nil
+	case extensionNodeType:
+		key, rest, err := readLengthPrefixed(rest)
+		if err != nil {
+			return nil, err
+		}
+		childHash, _, err := readLengthPrefixed(rest)
+		if err != nil {
+			return nil, err
+		}
+		return &extensionNode{key: key, child: hashNode(childHash)}, nil
+	case branchNodeType:
+		branch := &branchNode{}
+		for i := 0; i < branchWidth; i++ {
+			var childHash []byte
+			var err error
+			childHash, rest, err = readLengthPrefixed(rest)
+			if err != nil {
+				return nil, err
+			}
+			if len(childHash) > 0 {
+				branch.children[i] = hashNode(childHash)
+			}
+		}
+		value, _, err := readLengthPrefixed(rest)
+		if err != nil {
+			return nil, err
+		}
+		if len(value) > 0 {
+			branch.value = value
+		}
+		return branch, nil
+	}
+
+	return nil, ErrInvalidNodeEncoding
+}
@@ -0,0 +1,583 @@
+package trie
+
+import (
+	"bytes"
+	"errors"
+	"sync"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	"github.com/ElrondNetwork/wasm-vm/crypto"
+	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
+)
+
+// ErrNilHasher signals that a nil hasher was provided
+var ErrNilHasher = errors.New("nil hasher")
+
+// ErrMissingTrieNode signals that a node referenced by the trie is missing from the KVStore
+var ErrMissingTrieNode = errors.New("missing trie node")
+
+// PatriciaTrie is a Merkle-Patricia trie over a KVStore. Changes are kept in
+// memory until Commit() is called, but the root hash can be computed at any
+// time.
+type PatriciaTrie struct {
+	mutTrie  sync.RWMutex
+	root     node
+	store    KVStore
+	hasher   crypto.Hasher
+	recorder map[string][]byte
+}
+
+// NewPatriciaTrie creates a trie on top of the given KVStore. If rootHash is
+// not empty, the trie is opened at that root, which must have been committed
+// to the store previously.
+func NewPatriciaTrie(store KVStore, rootHash []byte) (*PatriciaTrie, error) {
+	return NewPatriciaTrieWithHasher(store, hashing.NewHasher(), rootHash)
+}
+
+// NewPatriciaTrieWithHasher creates a trie which hashes its nodes with the given hasher
+func NewPatriciaTrieWithHasher(store KVStore, hasher crypto.Hasher, rootHash []byte) (*PatriciaTrie, error) {
+	if check.IfNil(store) {
+		return nil, ErrNilKVStore
+	}
+	if hasher == nil {
+		return nil, ErrNilHasher
+	}
+
+	t := &PatriciaTrie{
+		store:  store,
+		hasher: hasher,
+	}
+	if len(rootHash) > 0 && !bytes.Equal(rootHash, t.EmptyRootHash()) {
+		t.root = hashNode(rootHash)
+	}
+
+	return t, nil
+}
+
+// EmptyRootHash returns the root hash of a trie without any entries
+func (t *PatriciaTrie) EmptyRootHash() []byte {
+	hash, _ := t.hasher.Sha256(nil)
+	return hash
+}
+
+// Get returns the value saved under the given key, or nil if the key is missing
+func (t *PatriciaTrie) Get(key []byte) ([]byte, error) {
+	t.mutTrie.Lock()
+	defer t.mutTrie.Unlock()
+
+	value, newRoot, err := t.get(t.root, keyToNibbles(key))
+	if err != nil {
+		return nil, err
+	}
+	t.root = newRoot
+
+	return value, nil
+}
+
+// Update saves the value under the given key; an empty value deletes the key
+func (t *PatriciaTrie) Update(key []byte, value []byte) error {
+	t.mutTrie.Lock()
+	defer t.mutTrie.Unlock()
+
+	var newRoot node
+	var err error
+	if len(value) == 0 {
+		newRoot, err = t.delete(t.root, keyToNibbles(key))
+	} else {
+		newRoot, err = t.insert(t.root, keyToNibbles(key), value)
+	}
+	if err != nil {
+		return err
+	}
+
+	t.root = newRoot
+	return nil
+}
+
+// Delete removes the given key from the trie
+func (t *PatriciaTrie) Delete(key []byte) error {
+	return t.Update(key, nil)
+}
+
+// RootHash computes the Merkle root of the trie, without persisting anything
+func (t *PatriciaTrie) RootHash() ([]byte, error) {
+	t.mutTrie.RLock()
+	defer t.mutTrie.RUnlock()
+
+	if t.root == nil {
+		return t.EmptyRootHash(), nil
+	}
+
+	return t.hashOf(t.root)
+}
+
+// Commit saves all the nodes which are not yet in the KVStore and returns the
+// root hash of the trie
+func (t *PatriciaTrie) Commit() ([]byte, error) {
+	t.mutTrie.Lock()
+	defer t.mutTrie.Unlock()
+
+	if t.root == nil {
+		return t.EmptyRootHash(), nil
+	}
+
+	rootHash, err := t.commit(t.root)
+	if err != nil {
+		return nil, err
+	}
+
+	t.root = hashNode(rootHash)
+	return rootHash, nil
+}
+
+// Iterate calls the handler for each key-value pair in the trie, in key order
+func (t *PatriciaTrie) Iterate(handler func(key []byte, value []byte) error) error {
+	t.mutTrie.Lock()
+	defer t.mutTrie.Unlock()
+
+	newRoot, err := t.iterate(t.root, nil, handler)
+	if err != nil {
+		return err
+	}
+
+	t.root = newRoot
+	return nil
+}
+
+// Clone returns a new trie sharing all nodes with this one; changes made on
+// any of them are not visible in the other
+func (t *PatriciaTrie) Clone() *PatriciaTrie {
+	t.mutTrie.RLock()
+	defer t.mutTrie.RUnlock()
+
+	return &PatriciaTrie{
+		root:   t.root,
+		store:  t.store,
+		hasher: t.hasher,
+	}
+}
+
+// snapshot returns the current root of the trie; as the nodes are never
+// changed in place, reverting to it later undoes all the changes in between
+func (t *PatriciaTrie) snapshot() node {
+	t.mutTrie.RLock()
+	defer t.mutTrie.RUnlock()
+
+	return t.root
+}
+
+// revert brings the trie back to a root returned by snapshot()
+func (t *PatriciaTrie) revert(root node) {
+	t.mutTrie.Lock()
+	defer t.mutTrie.Unlock()
+
+	t.root = root
+}
+
+// StartRecording makes the trie record the encoding of every node visited by
+// reads and writes, until StopRecording() is called
+func (t *PatriciaTrie) StartRecording() {
+	t.mutTrie.Lock()
+	defer t.mutTrie.Unlock()
+
+	t.recorder = make(map[string][]byte)
+}
+
+// StopRecording returns the nodes visited since StartRecording(), indexed by their hashes
+func (t *PatriciaTrie) StopRecording() map[string][]byte {
+	t.mutTrie.Lock()
+	defer t.mutTrie.Unlock()
+
+	recorded := t.recorder
+	t.recorder = nil
+	return recorded
+}
+
+// resolve loads the node if it is a hashNode and records it if needed; every
+// traversal of the trie goes through resolve() for each node it visits
+func (t *PatriciaTrie) resolve(n node) (node, error) {
+	resolved, err := t.load(n)
+	if err != nil {
+		return nil, err
+	}
+
+	err = t.record(resolved)
+	if err != nil {
+		return nil, err
+	}
+
+	return resolved, nil
+}
+
+func (t *PatriciaTrie) load(n node) (node, error) {
+	hash, isHash := n.(hashNode)
+	if !isHash {
+		return n, nil
+	}
+
+	encoded, err := t.store.Get(hash)
+	if err != nil {
+		return nil, ErrMissingTrieNode
+	}
+
+	resolved, err := decodeNode(encoded)
+	if err != nil {
+		return nil, err
+	}
+	setCachedHash(resolved, hash)
+
+	return resolved, nil
+}
+
+func (t *PatriciaTrie) record(n node) error {
+	if t.recorder == nil || n == nil {
+		return nil
+	}
+
+	hash, err := t.hashOf(n)
+	if err != nil {
+		return err
+	}
+	if _, recorded := t.recorder[string(hash)]; recorded {
+		return nil
+	}
+
+	encoded, err := t.encodeNode(n)
+	if err != nil {
+		return err
+	}
+
+	t.recorder[string(hash)] = encoded
+	return nil
+}
+
+func (t *PatriciaTrie) get(n node, key []byte) ([]byte, node, error) {
+	n, err := t.resolve(n)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	switch n := n.(type) {
+	case nil:
+		return nil, nil, nil
+	case *leafNode:
+		if bytes.Equal(n.key, key) {
+			return n.value, n, nil
+		}
+		return nil, n, nil
+	case *extensionNode:
+		if !bytes.HasPrefix(key, n.key) {
+			return nil, n, nil
+		}
+		value, child, err := t.get(n.child, key[len(n.key):])
+		if err != nil {
+			return nil, nil, err
+		}
+		// the resolved child is kept, the node itself is unchanged
+		n.child = child
+		return value, n, nil
+	case *branchNode:
+		if len(key) == 0 {
+			return n.value, n, nil
+		}
+		value, child, err := t.get(n.children[key[0]], key[1:])
+		if err != nil {
+			return nil, nil, err
+		}
+		n.children[key[0]] = child
+		return value, n, nil
+	}
+
+	return nil, nil, ErrInvalidNodeEncoding
+}
+
+func (t *PatriciaTrie) insert(n node, key []byte, value []byte) (node, error) {
+	n, err := t.resolve(n)
+	if err != nil {
+		return nil, err
+	}
+
+	switch n := n.(type) {
+	case nil:
+		return &leafNode{key: key, value: value}, nil
+	case *leafNode:
+		prefixLength := commonPrefixLength(n.key, key)
+		if prefixLength == len(n.key) && prefixLength == len(key) {
+			return &leafNode{key: key, value: value}, nil
+		}
+
+		branch := &branchNode{}
+		putIntoBranch(branch, n.key[prefixLength:], n.value)
+		putIntoBranch(branch, key[prefixLength:], value)
+		return wrapInExtension(key[:prefixLength], branch), nil
+	case *extensionNode:
+		prefixLength := commonPrefixLength(n.key, key)
+		if prefixLength == len(n.key) {
+			child, err := t.insert(n.child, key[prefixLength:], value)
+			if err != nil {
+				return nil, err
+			}
+			return &extensionNode{key: n.key, child: child}, nil
+		}
+
+		branch := &branchNode{}
+		remainingKey := n.key[prefixLength+1:]
+		if len(remainingKey) == 0 {
+			branch.children[n.key[prefixLength]] = n.child
+		} else {
+			branch.children[n.key[prefixLength]] = &extensionNode{key: remainingKey, child: n.child}
+		}
+		putIntoBranch(branch, key[prefixLength:], value)
+		return wrapInExtension(key[:prefixLength], branch), nil
+	case *branchNode:
+		newBranch := n.copy()
+		if len(key) == 0 {
+			newBranch.value = value
+			return newBranch, nil
+		}
+
+		child, err := t.insert(n.children[key[0]], key[1:], value)
+		if err != nil {
+			return nil, err
+		}
+		newBranch.children[key[0]] = child
+		return newBranch, nil
+	}
+
+	return nil, ErrInvalidNodeEncoding
+}
+
+func (t *PatriciaTrie) delete(n node, key []byte) (node, error) {
+	n, err := t.resolve(n)
+	if err != nil {
+		return nil, err
+	}
+
+	switch n := n.(type) {
+	case nil:
+		return nil, nil
+	case *leafNode:
+		if bytes.Equal(n.key, key) {
+			return nil, nil
+		}
+		return n, nil
+	case *extensionNode:
+		if !bytes.HasPrefix(key, n.key) {
+			return n, nil
+		}
+		child, err := t.delete(n.child, key[len(n.key):])
+		if err != nil {
+			return nil, err
+		}
+		if child == nil {
+			return nil, nil
+		}
+		return t.joinWithChild(n.key, child)
+	case *branchNode:
+		newBranch := n.copy()
+		if len(key) == 0 {
+			newBranch.value = nil
+		} else {
+			child, err := t.delete(n.children[key[0]], key[1:])
+			if err != nil {
+				return nil, err
+			}
+			newBranch.children[key[0]] = child
+		}
+		return t.collapseBranch(newBranch)
+	}
+
+	return nil, ErrInvalidNodeEncoding
+}
+
+// collapseBranch replaces a branch with a single remaining entry by a shorter node
+func (t *PatriciaTrie) collapseBranch(branch *branchNode) (node, error) {
+	lastChildIndex := -1
+	numChildren := 0
+	for i, child := range branch.children {
+		if child != nil {
+			numChildren++
+			lastChildIndex = i
+		}
+	}
+
+	if numChildren == 0 {
+		if len(branch.value) == 0 {
+			return nil, nil
+		}
+		return &leafNode{key: []byte{}, value: branch.value}, nil
+	}
+	if numChildren > 1 || len(branch.value) > 0 {
+		return branch, nil
+	}
+
+	return t.joinWithChild([]byte{byte(lastChildIndex)}, branch.children[lastChildIndex])
+}
+
+// joinWithChild prepends the given nibbles to the key of the child node,
+// keeping the trie in its canonical form
+func (t *PatriciaTrie) joinWithChild(prefix []byte, child node) (node, error) {
+	child, err := t.resolve(child)
+	if err != nil {
+		return nil, err
+	}
+
+	switch child := child.(type) {
+	case *leafNode:
+		return &leafNode{key: concatNibbles(prefix, child.key), value: child.value}, nil
+	case *extensionNode:
+		return &extensionNode{key: concatNibbles(prefix, child.key), child: child.child}, nil
+	}
+
+	return &extensionNode{key: prefix, child: child}, nil
+}
+
+func (t *PatriciaTrie) iterate(n node, prefix []byte, handler func(key []byte, value []byte) error) (node, error) {
+	n, err := t.resolve(n)
+	if err != nil {
+		return nil, err
+	}
+
+	switch n := n.(type) {
+	case nil:
+		return nil, nil
+	case *leafNode:
+		return n, handler(nibblesToKey(concatNibbles(prefix, n.key)), n.value)
+	case *extensionNode:
+		child, err := t.iterate(n.child, concatNibbles(prefix, n.key), handler)
+		if err != nil {
+			return nil, err
+		}
+		n.child = child
+		return n, nil
+	case *branchNode:
+		if len(n.value) > 0 {
+			err = handler(nibblesToKey(prefix), n.value)
+			if err != nil {
+				return nil, err
+			}
+		}
+		for i, child := range n.children {
+			if child == nil {
+				continue
+			}
+			resolvedChild, err := t.iterate(child, concatNibbles(prefix, []byte{byte(i)}), handler)
+			if err != nil {
+				return nil, err
+			}
+			n.children[i] = resolvedChild
+		}
+		return n, nil
+	}
+
+	return nil, ErrInvalidNodeEncoding
+}
+
+func (t *PatriciaTrie) hashOf(n node) ([]byte, error) {
+	if hash, isHash := n.(hashNode); isHash {
+		return hash, nil
+	}
+
+	cachedHash := getCachedHash(n)
+	if cachedHash != nil {
+		return cachedHash, nil
+	}
+
+	encoded, err := t.encodeNode(n)
+	if err != nil {
+		return nil, err
+	}
+
+	hash, err := t.hasher.Sha256(encoded)
+	if err != nil {
+		return nil, err
+	}
+	setCachedHash(n, hash)
+
+	return hash, nil
+}
+
+func (t *PatriciaTrie) commit(n node) ([]byte, error) {
+	if hash, isHash := n.(hashNode); isHash {
+		return hash, nil
+	}
+
+	switch n := n.(type) {
+	case *extensionNode:
+		_, err := t.commit(n.child)
+		if err != nil {
+			return nil, err
+		}
+	case *branchNode:
+		for _, child := range n.children {
+			if child == nil {
+				continue
+			}
+			_, err := t.commit(child)
+			if err != nil {
+				return nil, err
+			}
+		}
+	}
+
+	encoded, err := t.encodeNode(n)
+	if err != nil {
+		return nil, err
+	}
+	hash, err := t.hashOf(n)
+	if err != nil {
+		return nil, err
+	}
+
+	err = t.store.Put(hash, encoded)
+	if err != nil {
+		return nil, err
+	}
+
+	return hash, nil
+}
+
+func putIntoBranch(branch *branchNode, key []byte, value []byte) {
+	if len(key) == 0 {
+		branch.value = value
+		return
+	}
+	branch.children[key[0]] = &leafNode{key: key[1:], value: value}
+}
+
+func wrapInExtension(key []byte, child node) node {
+	if len(key) == 0 {
+		return child
+	}
+	return &extensionNode{key: key, child: child}
+}
+
+func (n *branchNode) copy() *branchNode {
+	return &branchNode{
+		children: n.children,
+		value:    n.value,
+	}
+}
+
+func getCachedHash(n node) []byte {
+	switch n := n.(type) {
+	case *leafNode:
+		return n.hash
+	case *extensionNode:
+		return n.hash
+	case *branchNode:
+		return n.hash
+	}
+	return nil
+}
+
+func setCachedHash(n node, hash []byte) {
+	switch n := n.(type) {
+	case *leafNode:
+		n.hash = hash
+	case *extensionNode:
+		n.hash = hash
+	case *branchNode:
+		n.hash = hash
+	}
+}
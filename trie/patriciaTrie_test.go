@@ -0,0 +1,119 @@
+package trie
+
+import (
+	"fmt"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestPatriciaTrie_NewWithNilStore(t *testing.T) {
+	trie, err := NewPatriciaTrie(nil, nil)
+	require.Nil(t, trie)
+	require.Equal(t, ErrNilKVStore, err)
+}
+
+func TestPatriciaTrie_GetUpdateDelete(t *testing.T) {
+	trie, err := NewPatriciaTrie(NewMemoryKVStore(), nil)
+	require.Nil(t, err)
+
+	emptyRoot, err := trie.RootHash()
+	require.Nil(t, err)
+	require.Equal(t, trie.EmptyRootHash(), emptyRoot)
+
+	require.Nil(t, trie.Update([]byte("dog"), []byte("puppy")))
+	require.Nil(t, trie.Update([]byte("do"), []byte("verb")))
+	require.Nil(t, trie.Update([]byte("doge"), []byte("coin")))
+	require.Nil(t, trie.Update([]byte("horse"), []byte("stallion")))
+
+	value, err := trie.Get([]byte("dog"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("puppy"), value)
+
+	value, err = trie.Get([]byte("do"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("verb"), value)
+
+	value, err = trie.Get([]byte("d"))
+	require.Nil(t, err)
+	require.Nil(t, value)
+
+	require.Nil(t, trie.Delete([]byte("dog")))
+	value, err = trie.Get([]byte("dog"))
+	require.Nil(t, err)
+	require.Nil(t, value)
+
+	value, err = trie.Get([]byte("doge"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("coin"), value)
+
+	require.Nil(t, trie.Delete([]byte("do")))
+	require.Nil(t, trie.Delete([]byte("doge")))
+	require.Nil(t, trie.Delete([]byte("horse")))
+
+	rootHash, err := trie.RootHash()
+	require.Nil(t, err)
+	require.Equal(t, emptyRoot, rootHash)
+}
+
+func TestPatriciaTrie_RootHashIndependentOfInsertionOrder(t *testing.T) {
+	keys := make([][]byte, 0)
+	for i := 0; i < 50; i++ {
+		keys = append(keys, []byte(fmt.Sprintf("key%d", i*7)))
+	}
+
+	first, _ := NewPatriciaTrie(NewMemoryKVStore(), nil)
+	for _, key := range keys {
+		require.Nil(t, first.Update(key, append([]byte("value_"), key...)))
+	}
+
+	second, _ := NewPatriciaTrie(NewMemoryKVStore(), nil)
+	for i := len(keys) - 1; i >= 0; i-- {
+		require.Nil(t, second.Update(keys[i], append([]byte("value_"), keys[i]...)))
+	}
+	// an insertion followed by a deletion must not leave traces
+	require.Nil(t, second.Update([]byte("key"), []byte("temporary")))
+	require.Nil(t, second.Delete([]byte("key")))
+
+	firstRoot, err := first.RootHash()
+	require.Nil(t, err)
+	secondRoot, err := second.RootHash()
+	require.Nil(t, err)
+	require.Equal(t, firstRoot, secondRoot)
+}
+
+func TestPatriciaTrie_CommitAndReopen(t *testing.T) {
+	store := NewMemoryKVStore()
+	trie, _ := NewPatriciaTrie(store, nil)
+
+	for i := 0; i < 20; i++ {
+		key := []byte(fmt.Sprintf("k%d", i))
+		require.Nil(t, trie.Update(key, []byte(fmt.Sprintf("v%d", i))))
+	}
+
+	rootHash, err := trie.RootHash()
+	require.Nil(t, err)
+	committedRoot, err := trie.Commit()
+	require.Nil(t, err)
+	require.Equal(t, rootHash, committedRoot)
+	require.True(t, store.Len() > 0)
+
+	reopened, err := NewPatriciaTrie(store, committedRoot)
+	require.Nil(t, err)
+
+	value, err := reopened.Get([]byte("k13"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("v13"), value)
+
+	numEntries := 0
+	err = reopened.Iterate(func(key []byte, value []byte) error {
+		numEntries++
+		return nil
+	})
+	require.Nil(t, err)
+	require.Equal(t, 20, numEntries)
+
+	missing, _ := NewPatriciaTrie(NewMemoryKVStore(), committedRoot)
+	_, err = missing.Get([]byte("k13"))
+	require.Equal(t, ErrMissingTrieNode, err)
+}
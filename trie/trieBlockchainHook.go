@@ -0,0 +1,334 @@
+package trie
+
+import (
+	"errors"
+	"math/big"
+	"sync"
+
+	"github.com/ElrondNetwork/elrond-go-core/core/check"
+	logger "github.com/ElrondNetwork/elrond-go-logger"
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+)
+
+var log = logger.GetOrCreate("arwen/trie")
+
+// ErrNilBlockchainHook signals that a nil blockchain hook was provided
+var ErrNilBlockchainHook = errors.New("nil blockchain hook")
+
+// ErrNegativeBalance signals that a VMOutput would leave an account with a negative balance
+var ErrNegativeBalance = errors.New("negative balance")
+
+var _ vmcommon.BlockchainHook = (*TrieBlockchainHook)(nil)
+
+// TrieBlockchainHook decorates a blockchain hook with persistent state: the
+// storage of each account is kept in its own PatriciaTrie and the accounts
+// themselves are kept in an accounts trie, whose root is the state root hash.
+// Storage is served exclusively from the tries; all other calls are delegated
+// to the inner blockchain hook.
+type TrieBlockchainHook struct {
+	vmcommon.BlockchainHook
+
+	mutState        sync.RWMutex
+	store           KVStore
+	accountsTrie    *PatriciaTrie
+	storageTries    map[string]*PatriciaTrie
+	witnessRootHash []byte
+	witnessNodes    map[string][]byte
+}
+
+// NewTrieBlockchainHook creates a TrieBlockchainHook over the given KVStore,
+// opening the state at rootHash (which can be empty for a new state)
+func NewTrieBlockchainHook(
+	inner vmcommon.BlockchainHook,
+	store KVStore,
+	rootHash []byte,
+) (*TrieBlockchainHook, error) {
+	if check.IfNil(inner) {
+		return nil, ErrNilBlockchainHook
+	}
+
+	accountsTrie, err := NewPatriciaTrie(store, rootHash)
+	if err != nil {
+		return nil, err
+	}
+
+	return &TrieBlockchainHook{
+		BlockchainHook: inner,
+		store:          store,
+		accountsTrie:   accountsTrie,
+		storageTries:   make(map[string]*PatriciaTrie),
+	}, nil
+}
+
+// GetStorageData returns the value saved under the key in the storage trie of the account
+func (hook *TrieBlockchainHook) GetStorageData(accountAddress []byte, key []byte) ([]byte, uint32, error) {
+	hook.mutState.Lock()
+	defer hook.mutState.Unlock()
+
+	storageTrie, err := hook.getStorageTrie(accountAddress)
+	if err != nil {
+		return nil, 0, err
+	}
+
+	value, err := storageTrie.Get(key)
+	if err != nil {
+		return nil, 0, err
+	}
+	if value == nil {
+		value = []byte{}
+	}
+
+	return value, 0, nil
+}
+
+// GetAllState returns all the storage entries of the account
+func (hook *TrieBlockchainHook) GetAllState(accountAddress []byte) (map[string][]byte, error) {
+	hook.mutState.Lock()
+	defer hook.mutState.Unlock()
+
+	storageTrie, err := hook.getStorageTrie(accountAddress)
+	if err != nil {
+		return nil, err
+	}
+
+	state := make(map[string][]byte)
+	err = storageTrie.Iterate(func(key []byte, value []byte) error {
+		state[string(key)] = value
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	return state, nil
+}
+
+// GetStateRootHash returns the root hash of the accounts trie, including the
+// changes not yet committed
+func (hook *TrieBlockchainHook) GetStateRootHash() []byte {
+	hook.mutState.RLock()
+	defer hook.mutState.RUnlock()
+
+	rootHash, err := hook.accountsTrie.RootHash()
+	if err != nil {
+		log.Error("TrieBlockchainHook.GetStateRootHash", "error", err)
+		return nil
+	}
+
+	return rootHash
+}
+
+// ApplyVMOutput writes the storage updates, balances and nonces of the output
+// accounts into the tries, removes the deleted accounts and returns the new
+// state root hash. The VMOutput is applied atomically: if any of its changes
+// fails, for example because a balance would become negative, none of them
+// remains in the tries.
+func (hook *TrieBlockchainHook) ApplyVMOutput(vmOutput *vmcommon.VMOutput) ([]byte, error) {
+	hook.mutState.Lock()
+	defer hook.mutState.Unlock()
+
+	snapshot := hook.snapshotState()
+	rootHash, err := hook.applyVMOutput(vmOutput)
+	if err != nil {
+		hook.revertState(snapshot)
+		return nil, err
+	}
+
+	return rootHash, nil
+}
+
+func (hook *TrieBlockchainHook) applyVMOutput(vmOutput *vmcommon.VMOutput) ([]byte, error) {
+	for _, outputAccount := range arwen.SortedOutputAccounts(vmOutput.OutputAccounts) {
+		err := hook.applyOutputAccount(outputAccount)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	for _, deletedAddress := range vmOutput.DeletedAccounts {
+		err := hook.accountsTrie.Delete(deletedAddress)
+		if err != nil {
+			return nil, err
+		}
+		storageTrie, found := hook.storageTries[string(deletedAddress)]
+		if found {
+			hook.collectWitnessNodes(storageTrie)
+			delete(hook.storageTries, string(deletedAddress))
+		}
+	}
+
+	return hook.accountsTrie.RootHash()
+}
+
+type stateSnapshot struct {
+	accountsRoot node
+	storageTries map[string]*PatriciaTrie
+	storageRoots map[string]node
+}
+
+func (hook *TrieBlockchainHook) snapshotState() *stateSnapshot {
+	snapshot := &stateSnapshot{
+		accountsRoot: hook.accountsTrie.snapshot(),
+		storageTries: make(map[string]*PatriciaTrie, len(hook.storageTries)),
+		storageRoots: make(map[string]node, len(hook.storageTries)),
+	}
+	for address, storageTrie := range hook.storageTries {
+		snapshot.storageTries[address] = storageTrie
+		snapshot.storageRoots[address] = storageTrie.snapshot()
+	}
+	return snapshot
+}
+
+func (hook *TrieBlockchainHook) revertState(snapshot *stateSnapshot) {
+	hook.accountsTrie.revert(snapshot.accountsRoot)
+	for address, storageTrie := range snapshot.storageTries {
+		storageTrie.revert(snapshot.storageRoots[address])
+	}
+	hook.storageTries = snapshot.storageTries
+}
+
+// Commit persists all the tries into the KVStore and returns the state root hash
+func (hook *TrieBlockchainHook) Commit() ([]byte, error) {
+	hook.mutState.Lock()
+	defer hook.mutState.Unlock()
+
+	// the storage roots were already written into the account states by ApplyVMOutput()
+	for _, storageTrie := range hook.storageTries {
+		_, err := storageTrie.Commit()
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return hook.accountsTrie.Commit()
+}
+
+// GetAccountState returns the state of the account as saved in the accounts
+// trie, or nil if the account was never written
+func (hook *TrieBlockchainHook) GetAccountState(address []byte) (*AccountState, error) {
+	hook.mutState.Lock()
+	defer hook.mutState.Unlock()
+
+	return hook.loadAccountState(address)
+}
+
+func (hook *TrieBlockchainHook) applyOutputAccount(outputAccount *vmcommon.OutputAccount) error {
+	storageTrie, err := hook.getStorageTrie(outputAccount.Address)
+	if err != nil {
+		return err
+	}
+
+	for _, storageUpdate := range arwen.SortedStorageUpdates(outputAccount.StorageUpdates) {
+		err = storageTrie.Update(storageUpdate.Offset, storageUpdate.Data)
+		if err != nil {
+			return err
+		}
+	}
+
+	storageRoot, err := storageTrie.RootHash()
+	if err != nil {
+		return err
+	}
+
+	return hook.updateAccountState(outputAccount.Address, func(state *AccountState) error {
+		if outputAccount.Nonce > state.Nonce {
+			state.Nonce = outputAccount.Nonce
+		}
+		if outputAccount.BalanceDelta != nil {
+			state.Balance = big.NewInt(0).Add(state.Balance, outputAccount.BalanceDelta)
+		} else if outputAccount.Balance != nil {
+			state.Balance = big.NewInt(0).Set(outputAccount.Balance)
+		}
+		if state.Balance.Sign() < 0 {
+			return ErrNegativeBalance
+		}
+		if len(outputAccount.Code) > 0 {
+			state.CodeHash, _ = hook.accountsTrie.hasher.Sha256(outputAccount.Code)
+		}
+		state.StorageRoot = storageRoot
+		return nil
+	})
+}
+
+func (hook *TrieBlockchainHook) updateAccountState(address []byte, update func(state *AccountState) error) error {
+	state, err := hook.loadAccountState(address)
+	if err != nil {
+		return err
+	}
+	if state == nil {
+		state = hook.newAccountState(address)
+	}
+
+	err = update(state)
+	if err != nil {
+		return err
+	}
+	return hook.accountsTrie.Update(address, state.Encode())
+}
+
+func (hook *TrieBlockchainHook) loadAccountState(address []byte) (*AccountState, error) {
+	encoded, err := hook.accountsTrie.Get(address)
+	if err != nil {
+		return nil, err
+	}
+	if encoded == nil {
+		return nil, nil
+	}
+
+	return DecodeAccountState(encoded)
+}
+
+// newAccountState initializes the state of an account not yet in the trie from
+// the inner blockchain hook, so that balance deltas apply on top of it
+func (hook *TrieBlockchainHook) newAccountState(address []byte) *AccountState {
+	state := &AccountState{
+		Balance: big.NewInt(0),
+	}
+
+	account, err := hook.BlockchainHook.GetUserAccount(address)
+	if err != nil || check.IfNil(account) {
+		return state
+	}
+
+	state.Nonce = account.GetNonce()
+	if account.GetBalance() != nil {
+		state.Balance.Set(account.GetBalance())
+	}
+	state.CodeHash = account.GetCodeHash()
+
+	return state
+}
+
+func (hook *TrieBlockchainHook) getStorageTrie(address []byte) (*PatriciaTrie, error) {
+	storageTrie, found := hook.storageTries[string(address)]
+	if found {
+		return storageTrie, nil
+	}
+
+	state, err := hook.loadAccountState(address)
+	if err != nil {
+		return nil, err
+	}
+
+	var storageRoot []byte
+	if state != nil {
+		storageRoot = state.StorageRoot
+	}
+
+	storageTrie, err = NewPatriciaTrie(hook.store, storageRoot)
+	if err != nil {
+		return nil, err
+	}
+	if hook.witnessRootHash != nil {
+		storageTrie.StartRecording()
+	}
+
+	hook.storageTries[string(address)] = storageTrie
+	return storageTrie, nil
+}
+
+// IsInterfaceNil returns true if there is no value under the interface
+func (hook *TrieBlockchainHook) IsInterfaceNil() bool {
+	return hook == nil
+}
@@ -0,0 +1,117 @@
+package trie
+
+import (
+	"math/big"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+var testAddress = []byte("contract________________________")
+
+func makeVMOutputWithStorage(key string, value string) *vmcommon.VMOutput {
+	return &vmcommon.VMOutput{
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			string(testAddress): {
+				Address:      testAddress,
+				BalanceDelta: big.NewInt(10),
+				StorageUpdates: map[string]*vmcommon.StorageUpdate{
+					key: {Offset: []byte(key), Data: []byte(value)},
+				},
+			},
+		},
+	}
+}
+
+func TestTrieBlockchainHook_NilInner(t *testing.T) {
+	hook, err := NewTrieBlockchainHook(nil, NewMemoryKVStore(), nil)
+	require.Nil(t, hook)
+	require.Equal(t, ErrNilBlockchainHook, err)
+}
+
+func TestTrieBlockchainHook_ApplyVMOutput(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	world.AcctMap.CreateAccount(testAddress, world).Balance = big.NewInt(100)
+
+	hook, err := NewTrieBlockchainHook(world, NewMemoryKVStore(), nil)
+	require.Nil(t, err)
+	emptyRoot := hook.GetStateRootHash()
+
+	rootHash, err := hook.ApplyVMOutput(makeVMOutputWithStorage("answer", "42"))
+	require.Nil(t, err)
+	require.NotEqual(t, emptyRoot, rootHash)
+	require.Equal(t, rootHash, hook.GetStateRootHash())
+
+	value, _, err := hook.GetStorageData(testAddress, []byte("answer"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("42"), value)
+
+	state, err := hook.GetAccountState(testAddress)
+	require.Nil(t, err)
+	require.Equal(t, big.NewInt(110), state.Balance)
+
+	// deleting the only storage entry changes the root again
+	secondRoot, err := hook.ApplyVMOutput(makeVMOutputWithStorage("answer", ""))
+	require.Nil(t, err)
+	require.NotEqual(t, rootHash, secondRoot)
+
+	value, _, err = hook.GetStorageData(testAddress, []byte("answer"))
+	require.Nil(t, err)
+	require.Equal(t, []byte{}, value)
+}
+
+func TestTrieBlockchainHook_ApplyVMOutput_Atomic(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	hook, _ := NewTrieBlockchainHook(world, NewMemoryKVStore(), nil)
+	rootHash, err := hook.ApplyVMOutput(makeVMOutputWithStorage("answer", "42"))
+	require.Nil(t, err)
+
+	// the contract is applied first, then the debtor fails the whole output
+	debtorAddress := []byte("debtor__________________________")
+	vmOutput := makeVMOutputWithStorage("answer", "43")
+	vmOutput.OutputAccounts[string(debtorAddress)] = &vmcommon.OutputAccount{
+		Address:      debtorAddress,
+		BalanceDelta: big.NewInt(-1),
+		StorageUpdates: map[string]*vmcommon.StorageUpdate{
+			"debt": {Offset: []byte("debt"), Data: []byte{1}},
+		},
+	}
+	failedRoot, err := hook.ApplyVMOutput(vmOutput)
+	require.Nil(t, failedRoot)
+	require.Equal(t, ErrNegativeBalance, err)
+	require.Equal(t, rootHash, hook.GetStateRootHash())
+
+	value, _, err := hook.GetStorageData(testAddress, []byte("answer"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("42"), value)
+
+	state, err := hook.GetAccountState(testAddress)
+	require.Nil(t, err)
+	require.Equal(t, big.NewInt(10), state.Balance)
+
+	state, err = hook.GetAccountState(debtorAddress)
+	require.Nil(t, err)
+	require.Nil(t, state)
+}
+
+func TestTrieBlockchainHook_CommitAndReopen(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	store := NewMemoryKVStore()
+
+	hook, _ := NewTrieBlockchainHook(world, store, nil)
+	_, err := hook.ApplyVMOutput(makeVMOutputWithStorage("answer", "42"))
+	require.Nil(t, err)
+
+	rootHash, err := hook.Commit()
+	require.Nil(t, err)
+
+	reopened, err := NewTrieBlockchainHook(world, store, rootHash)
+	require.Nil(t, err)
+	require.Equal(t, rootHash, reopened.GetStateRootHash())
+
+	state, err := reopened.GetAllState(testAddress)
+	require.Nil(t, err)
+	require.Equal(t, map[string][]byte{"answer": []byte("42")}, state)
+}
@@ -0,0 +1,238 @@
+package trie
+
+import (
+	"bytes"
+	"errors"
+	"sort"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	"github.com/ElrondNetwork/wasm-vm/arwen"
+	"github.com/ElrondNetwork/wasm-vm/crypto/hashing"
+)
+
+// ErrWitnessRootHashMismatch signals that replaying a transaction against its
+// witness did not produce the expected state root hash
+var ErrWitnessRootHashMismatch = errors.New("state root hash obtained from witness does not match")
+
+// ErrWitnessRecordingNotStarted signals that a witness was requested without having started the recording
+var ErrWitnessRecordingNotStarted = errors.New("witness recording not started")
+
+// Witness holds all the trie nodes visited while executing a transaction and
+// applying its output, which is enough to re-execute it without the full state
+type Witness struct {
+	RootHash []byte
+	Nodes    map[string][]byte
+}
+
+// NumNodes returns the number of trie nodes in the witness
+func (w *Witness) NumNodes() int {
+	return len(w.Nodes)
+}
+
+// Size returns the total size of the encoded nodes in the witness
+func (w *Witness) Size() int {
+	size := 0
+	for _, encoded := range w.Nodes {
+		size += len(encoded)
+	}
+	return size
+}
+
+// Encode serializes the witness in a compact form: the root hash followed by
+// the encoded nodes, sorted by their hashes. The hashes of the nodes are not
+// included, since they are recomputed when decoding.
+func (w *Witness) Encode() []byte {
+	hashes := make([]string, 0, len(w.Nodes))
+	for hash := range w.Nodes {
+		hashes = append(hashes, hash)
+	}
+	sort.Strings(hashes)
+
+	encoded := appendLengthPrefixed(make([]byte, 0), w.RootHash)
+	for _, hash := range hashes {
+		encoded = appendLengthPrefixed(encoded, w.Nodes[hash])
+	}
+	return encoded
+}
+
+// DecodeWitness rebuilds a witness produced by Encode()
+func DecodeWitness(encoded []byte) (*Witness, error) {
+	rootHash, rest, err := readLengthPrefixed(encoded)
+	if err != nil {
+		return nil, err
+	}
+
+	hasher := hashing.NewHasher()
+	witness := &Witness{
+		RootHash: rootHash,
+		Nodes:    make(map[string][]byte),
+	}
+	for len(rest) > 0 {
+		var encodedNode []byte
+		encodedNode, rest, err = readLengthPrefixed(rest)
+		if err != nil {
+			return nil, err
+		}
+
+		hash, err := hasher.Sha256(encodedNode)
+		if err != nil {
+			return nil, err
+		}
+		witness.Nodes[string(hash)] = encodedNode
+	}
+
+	return witness, nil
+}
+
+// ToKVStore creates a KVStore containing only the nodes of the witness
+func (w *Witness) ToKVStore() KVStore {
+	store := NewMemoryKVStore()
+	for hash, encoded := range w.Nodes {
+		_ = store.Put([]byte(hash), encoded)
+	}
+	return store
+}
+
+// StartWitnessRecording makes the hook record all the trie nodes visited from
+// now on, either by reads during execution or by ApplyVMOutput()
+func (hook *TrieBlockchainHook) StartWitnessRecording() {
+	hook.mutState.Lock()
+	defer hook.mutState.Unlock()
+
+	preRootHash, err := hook.accountsTrie.RootHash()
+	if err != nil {
+		log.Error("TrieBlockchainHook.StartWitnessRecording", "error", err)
+	}
+
+	hook.witnessRootHash = preRootHash
+	hook.witnessNodes = make(map[string][]byte)
+	hook.accountsTrie.StartRecording()
+	for _, storageTrie := range hook.storageTries {
+		storageTrie.StartRecording()
+	}
+}
+
+// StopWitnessRecording returns the witness of everything visited since StartWitnessRecording()
+func (hook *TrieBlockchainHook) StopWitnessRecording() (*Witness, error) {
+	hook.mutState.Lock()
+	defer hook.mutState.Unlock()
+
+	if hook.witnessRootHash == nil {
+		return nil, ErrWitnessRecordingNotStarted
+	}
+
+	hook.collectWitnessNodes(hook.accountsTrie)
+	for _, storageTrie := range hook.storageTries {
+		hook.collectWitnessNodes(storageTrie)
+	}
+
+	witness := &Witness{
+		RootHash: hook.witnessRootHash,
+		Nodes:    hook.witnessNodes,
+	}
+
+	hook.witnessRootHash = nil
+	hook.witnessNodes = nil
+	return witness, nil
+}
+
+func (hook *TrieBlockchainHook) collectWitnessNodes(t *PatriciaTrie) {
+	if hook.witnessNodes == nil {
+		return
+	}
+
+	for hash, encoded := range t.StopRecording() {
+		hook.witnessNodes[hash] = encoded
+	}
+}
+
+// IsRecordingWitness returns true between StartWitnessRecording() and StopWitnessRecording()
+func (hook *TrieBlockchainHook) IsRecordingWitness() bool {
+	hook.mutState.RLock()
+	defer hook.mutState.RUnlock()
+
+	return hook.witnessRootHash != nil
+}
+
+// WitnessedVMOutput is the output of an execution together with its witness
+// and the state root hash obtained after applying it
+type WitnessedVMOutput struct {
+	*vmcommon.VMOutput
+	Witness      *Witness
+	PostRootHash []byte
+}
+
+// RunSmartContractCallWithWitness executes the call on the host, applies the
+// output on the hook and returns it along with the witness of the execution.
+// The host must have been created with the given hook as its blockchain hook.
+func RunSmartContractCallWithWitness(
+	host arwen.VMHost,
+	hook *TrieBlockchainHook,
+	input *vmcommon.ContractCallInput,
+) (*WitnessedVMOutput, error) {
+	hook.StartWitnessRecording()
+
+	vmOutput, err := host.RunSmartContractCall(input)
+	if err != nil {
+		_, _ = hook.StopWitnessRecording()
+		return nil, err
+	}
+
+	postRootHash, err := hook.ApplyVMOutput(vmOutput)
+	if err != nil {
+		_, _ = hook.StopWitnessRecording()
+		return nil, err
+	}
+
+	witness, err := hook.StopWitnessRecording()
+	if err != nil {
+		return nil, err
+	}
+
+	return &WitnessedVMOutput{
+		VMOutput:     vmOutput,
+		Witness:      witness,
+		PostRootHash: postRootHash,
+	}, nil
+}
+
+// HostFactory creates a VM host working on the given blockchain hook
+type HostFactory func(blockchainHook vmcommon.BlockchainHook) (arwen.VMHost, error)
+
+// VerifyWithWitness re-executes the call against the witness only and checks
+// that the same post-state root hash is obtained. Balances, code and all
+// non-storage data are still provided by the inner blockchain hook.
+func VerifyWithWitness(
+	hostFactory HostFactory,
+	inner vmcommon.BlockchainHook,
+	witness *Witness,
+	input *vmcommon.ContractCallInput,
+	expectedPostRootHash []byte,
+) (*vmcommon.VMOutput, error) {
+	hook, err := NewTrieBlockchainHook(inner, witness.ToKVStore(), witness.RootHash)
+	if err != nil {
+		return nil, err
+	}
+
+	host, err := hostFactory(hook)
+	if err != nil {
+		return nil, err
+	}
+	defer host.Reset()
+
+	vmOutput, err := host.RunSmartContractCall(input)
+	if err != nil {
+		return nil, err
+	}
+
+	postRootHash, err := hook.ApplyVMOutput(vmOutput)
+	if err != nil {
+		return nil, err
+	}
+
+	if !bytes.Equal(postRootHash, expectedPostRootHash) {
+		return vmOutput, ErrWitnessRootHashMismatch
+	}
+
+	return vmOutput, nil
+}
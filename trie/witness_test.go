@@ -0,0 +1,74 @@
+package trie
+
+import (
+	"fmt"
+	"testing"
+
+	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
+	worldmock "github.com/ElrondNetwork/wasm-vm/mock/world"
+	"github.com/stretchr/testify/require"
+)
+
+func TestWitness_StopWithoutStart(t *testing.T) {
+	hook, _ := NewTrieBlockchainHook(worldmock.NewMockWorld(), NewMemoryKVStore(), nil)
+
+	witness, err := hook.StopWitnessRecording()
+	require.Nil(t, witness)
+	require.Equal(t, ErrWitnessRecordingNotStarted, err)
+}
+
+func TestWitness_ReplayAgainstWitnessOnly(t *testing.T) {
+	world := worldmock.NewMockWorld()
+	hook, _ := NewTrieBlockchainHook(world, NewMemoryKVStore(), nil)
+
+	initialState := &vmcommon.VMOutput{
+		OutputAccounts: map[string]*vmcommon.OutputAccount{
+			string(testAddress): {
+				Address:        testAddress,
+				StorageUpdates: make(map[string]*vmcommon.StorageUpdate),
+			},
+		},
+	}
+	for i := 0; i < 100; i++ {
+		key := fmt.Sprintf("key%d", i)
+		initialState.OutputAccounts[string(testAddress)].StorageUpdates[key] = &vmcommon.StorageUpdate{
+			Offset: []byte(key),
+			Data:   []byte(fmt.Sprintf("value%d", i)),
+		}
+	}
+	_, err := hook.ApplyVMOutput(initialState)
+	require.Nil(t, err)
+	_, err = hook.Commit()
+	require.Nil(t, err)
+
+	hook.StartWitnessRecording()
+	require.True(t, hook.IsRecordingWitness())
+
+	value, _, err := hook.GetStorageData(testAddress, []byte("key42"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("value42"), value)
+
+	txOutput := makeVMOutputWithStorage("key7", "")
+	postRootHash, err := hook.ApplyVMOutput(txOutput)
+	require.Nil(t, err)
+
+	witness, err := hook.StopWitnessRecording()
+	require.Nil(t, err)
+	require.False(t, hook.IsRecordingWitness())
+	require.True(t, witness.NumNodes() > 0)
+
+	decodedWitness, err := DecodeWitness(witness.Encode())
+	require.Nil(t, err)
+	require.Equal(t, witness, decodedWitness)
+
+	replayHook, err := NewTrieBlockchainHook(world, decodedWitness.ToKVStore(), decodedWitness.RootHash)
+	require.Nil(t, err)
+
+	value, _, err = replayHook.GetStorageData(testAddress, []byte("key42"))
+	require.Nil(t, err)
+	require.Equal(t, []byte("value42"), value)
+
+	replayRootHash, err := replayHook.ApplyVMOutput(makeVMOutputWithStorage("key7", ""))
+	require.Nil(t, err)
+	require.Equal(t, postRootHash, replayRootHash)
+}
@@ -0,0 +1,12 @@
+//go:build !wasishim
+// +build !wasishim
+
+package wasmer
+
+// WASIShimEnabled is set by building with the wasishim tag; it adds a minimal
+// WASI preview1 surface to the imports, for running contract code off-chain
+const WASIShimEnabled = false
+
+func populateWASIImports(_ *wasmerImports) error {
+	return nil
+}
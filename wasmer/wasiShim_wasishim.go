@@ -0,0 +1,169 @@
+//go:build wasishim
+// +build wasishim
+
+package wasmer
+
+// // Declare the function signatures (see [cgo](https://golang.org/cmd/cgo/)).
+//
+// #include <stdlib.h>
+// typedef int int32_t;
+//
+// extern int32_t wasi_clock_time_get(void* context, int32_t clockID, long long precision, int32_t timeOffset);
+// extern int32_t wasi_random_get(void* context, int32_t bufferOffset, int32_t bufferLength);
+// extern int32_t wasi_fd_write(void* context, int32_t fd, int32_t iovsOffset, int32_t iovsLength, int32_t writtenOffset);
+import "C"
+
+import (
+	"crypto/rand"
+	"encoding/binary"
+	"time"
+	"unsafe"
+
+	"github.com/ElrondNetwork/wasm-vm/logging"
+)
+
+// WASIShimEnabled is set by building with the wasishim tag; it adds a minimal
+// WASI preview1 surface to the imports, for running contract code off-chain
+const WASIShimEnabled = true
+
+// WASIModule is the import module of the WASI preview1 functions
+const WASIModule = "wasi_snapshot_preview1"
+
+const (
+	wasiErrnoSuccess = 0
+	wasiErrnoBadf    = 8
+	wasiErrnoFault   = 21
+	wasiErrnoInval   = 28
+)
+
+const (
+	wasiClockRealtime  = 0
+	wasiClockMonotonic = 1
+)
+
+const (
+	wasiStdout = 1
+	wasiStderr = 2
+)
+
+var logWASI = logging.GetOrCreate(logging.SubsystemExecutor, "arwen/wasi")
+
+var wasiClockStart = time.Now()
+
+// populateWASIImports registers the clock, the random source and the writes
+// to stdout and stderr, which are mapped to trace logs; the calls are not
+// metered, because the shim never runs in consensus
+func populateWASIImports(imports *wasmerImports) error {
+	imports.currentNamespace = WASIModule
+	defer func() {
+		imports.currentNamespace = "env"
+	}()
+
+	err := imports.append("clock_time_get", wasi_clock_time_get, C.wasi_clock_time_get)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("random_get", wasi_random_get, C.wasi_random_get)
+	if err != nil {
+		return err
+	}
+
+	return imports.append("fd_write", wasi_fd_write, C.wasi_fd_write)
+}
+
+//export wasi_clock_time_get
+func wasi_clock_time_get(context unsafe.Pointer, clockID int32, _ int64, timeOffset int32) int32 {
+	var timestamp uint64
+	switch clockID {
+	case wasiClockRealtime:
+		timestamp = uint64(time.Now().UnixNano())
+	case wasiClockMonotonic:
+		timestamp = uint64(time.Since(wasiClockStart).Nanoseconds())
+	default:
+		return wasiErrnoInval
+	}
+
+	result, ok := wasiSlice(wasiMemory(context), timeOffset, 8)
+	if !ok {
+		return wasiErrnoFault
+	}
+	binary.LittleEndian.PutUint64(result, timestamp)
+	return wasiErrnoSuccess
+}
+
+//export wasi_random_get
+func wasi_random_get(context unsafe.Pointer, bufferOffset int32, bufferLength int32) int32 {
+	buffer, ok := wasiSlice(wasiMemory(context), bufferOffset, bufferLength)
+	if !ok {
+		return wasiErrnoFault
+	}
+	_, err := rand.Read(buffer)
+	if err != nil {
+		return wasiErrnoInval
+	}
+	return wasiErrnoSuccess
+}
+
+//export wasi_fd_write
+func wasi_fd_write(context unsafe.Pointer, fd int32, iovsOffset int32, iovsLength int32, writtenOffset int32) int32 {
+	if fd != wasiStdout && fd != wasiStderr {
+		return wasiErrnoBadf
+	}
+
+	memory := wasiMemory(context)
+	data, ok := wasiGatherIOVecs(memory, iovsOffset, iovsLength)
+	if !ok {
+		return wasiErrnoFault
+	}
+	written, ok := wasiSlice(memory, writtenOffset, 4)
+	if !ok {
+		return wasiErrnoFault
+	}
+
+	stream := "stdout"
+	if fd == wasiStderr {
+		stream = "stderr"
+	}
+	logWASI.Trace(stream, "text", string(data))
+
+	binary.LittleEndian.PutUint32(written, uint32(len(data)))
+	return wasiErrnoSuccess
+}
+
+func wasiMemory(context unsafe.Pointer) []byte {
+	instanceContext := IntoInstanceContext(context)
+	return instanceContext.Memory().Data()
+}
+
+// wasiSlice returns the given range of the memory, if it is within bounds
+func wasiSlice(memory []byte, offset int32, length int32) ([]byte, bool) {
+	if offset < 0 || length < 0 || int64(offset)+int64(length) > int64(len(memory)) {
+		return nil, false
+	}
+	return memory[offset : offset+length], true
+}
+
+// wasiGatherIOVecs concatenates the buffers described by an array of iovec,
+// each made of a little-endian pointer and length
+func wasiGatherIOVecs(memory []byte, iovsOffset int32, iovsLength int32) ([]byte, bool) {
+	if iovsLength < 0 {
+		return nil, false
+	}
+
+	data := make([]byte, 0)
+	for i := int32(0); i < iovsLength; i++ {
+		iov, ok := wasiSlice(memory, iovsOffset+8*i, 8)
+		if !ok {
+			return nil, false
+		}
+		bufferOffset := binary.LittleEndian.Uint32(iov[0:4])
+		bufferLength := binary.LittleEndian.Uint32(iov[4:8])
+		buffer, ok := wasiSlice(memory, int32(bufferOffset), int32(bufferLength))
+		if !ok {
+			return nil, false
+		}
+		data = append(data, buffer...)
+	}
+	return data, true
+}
@@ -0,0 +1,33 @@
+//go:build wasishim
+// +build wasishim
+
+package wasmer
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestWASIShim_GatherIOVecs(t *testing.T) {
+	memory := make([]byte, 32)
+	copy(memory[16:], "hello, world")
+	// iovec 0: "hello", iovec 1: ", world"
+	copy(memory[0:], []byte{16, 0, 0, 0, 5, 0, 0, 0, 21, 0, 0, 0, 7, 0, 0, 0})
+
+	data, ok := wasiGatherIOVecs(memory, 0, 2)
+	require.True(t, ok)
+	require.Equal(t, "hello, world", string(data))
+
+	data, ok = wasiGatherIOVecs(memory, 0, 0)
+	require.True(t, ok)
+	require.Len(t, data, 0)
+
+	_, ok = wasiGatherIOVecs(memory, 28, 1)
+	require.False(t, ok)
+
+	// iovec pointing past the end of the memory
+	copy(memory[0:], []byte{30, 0, 0, 0, 5, 0, 0, 0})
+	_, ok = wasiGatherIOVecs(memory, 0, 1)
+	require.False(t, ok)
+}
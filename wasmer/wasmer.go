@@ -1,6 +1,6 @@
 // Package wasmer is a Go library to run WebAssembly binaries.
 package wasmer
 
-import logger "github.com/ElrondNetwork/elrond-go-logger"
+import "github.com/ElrondNetwork/wasm-vm/logging"
 
-var logWasmer = logger.GetOrCreate("arwen/wasmer")
+var logWasmer = logging.GetOrCreate(logging.SubsystemExecutor, "arwen/wasmer")
@@ -147,6 +147,27 @@ func (imports *wasmerImports) append(importName string, implementation interface
 	return nil
 }
 
+// appendAliases registers the implementations of the target functions of the
+// current namespace under the aliases as well; the targets must have been
+// appended already, and an alias cannot replace an imported function
+func (imports *wasmerImports) appendAliases(aliases []executor.HostFunctionAlias) error {
+	namespacedImports := imports.imports[imports.currentNamespace]
+	for _, alias := range aliases {
+		_, exists := namespacedImports[alias.Alias]
+		if exists {
+			return NewImportedFunctionError(alias.Alias, "The alias `%s` is already an imported function.")
+		}
+		target, ok := namespacedImports[alias.Target]
+		if !ok {
+			return NewImportedFunctionError(alias.Alias, fmt.Sprintf("The alias `%%s` refers to `%s`, which is not an imported function.", alias.Target))
+		}
+
+		target.importedFunctionPointer = nil
+		namespacedImports[alias.Alias] = target
+	}
+	return nil
+}
+
 // Close closes/frees all imported functions that have been registered by Wasmer.
 func (imports *wasmerImports) Close() {
 	for _, namespacedImports := range imports.imports {
@@ -0,0 +1,49 @@
+package wasmer
+
+import (
+	"testing"
+
+	"github.com/ElrondNetwork/wasm-vm/executor"
+	"github.com/stretchr/testify/require"
+)
+
+func TestWasmerImports_AppendAliases(t *testing.T) {
+	imports := newWasmerImports()
+	imports.imports["env"] = map[string]wasmerImport{
+		"getGasLeft": {
+			wasmOutputs: []cWasmerValueTag{cWasmI64},
+			namespace:   "env",
+		},
+		"getSCAddress": {
+			wasmInputs: []cWasmerValueTag{cWasmI32},
+			namespace:  "env",
+		},
+	}
+
+	err := imports.appendAliases([]executor.HostFunctionAlias{
+		{Alias: "oldGetGasLeft", Target: "getGasLeft"},
+	})
+	require.Nil(t, err)
+	require.Equal(t, 3, imports.Count())
+	require.Equal(t, imports.imports["env"]["getGasLeft"], imports.imports["env"]["oldGetGasLeft"])
+
+	err = imports.appendAliases([]executor.HostFunctionAlias{
+		{Alias: "getSCAddress", Target: "getGasLeft"},
+	})
+	require.NotNil(t, err)
+
+	err = imports.appendAliases([]executor.HostFunctionAlias{
+		{Alias: "oldMissing", Target: "missing"},
+	})
+	require.NotNil(t, err)
+	require.Equal(t, 3, imports.Count())
+}
+
+func TestWasmerImports_HostFunctionAliasesResolve(t *testing.T) {
+	names, err := injectCgoFunctionPointers()
+	require.Nil(t, err)
+	for _, alias := range executor.HostFunctionAliases {
+		require.Contains(t, names, alias.Alias)
+		require.Contains(t, names, alias.Target)
+	}
+}
@@ -12,11 +12,15 @@ package wasmer
 // typedef int int32_t;
 //
 // extern long long v1_5_getGasLeft(void* context);
+// extern void      v1_5_stackHeightExceeded(void* context);
 // extern void      v1_5_getSCAddress(void* context, int32_t resultOffset);
 // extern void      v1_5_getOwnerAddress(void* context, int32_t resultOffset);
 // extern int32_t   v1_5_getShardOfAddress(void* context, int32_t addressOffset);
 // extern int32_t   v1_5_isSmartContract(void* context, int32_t addressOffset);
 // extern void      v1_5_signalError(void* context, int32_t messageOffset, int32_t messageLength);
+// extern void      v1_5_signalTypedError(void* context, long long code, int32_t messageOffset, int32_t messageLength, int32_t payloadOffset, int32_t payloadLength);
+// extern void      v1_5_debugPrint(void* context, int32_t messageOffset, int32_t messageLength);
+// extern void      v1_5_gasPhase(void* context, int32_t phase);
 // extern void      v1_5_getExternalBalance(void* context, int32_t addressOffset, int32_t resultOffset);
 // extern int32_t   v1_5_getBlockHash(void* context, long long nonce, int32_t resultOffset);
 // extern int32_t   v1_5_getESDTBalance(void* context, int32_t addressOffset, int32_t tokenIDOffset, int32_t tokenIDLen, long long nonce, int32_t resultOffset);
@@ -27,21 +31,26 @@ package wasmer
 // extern long long v1_5_getESDTLocalRoles(void* context, int32_t tokenIdHandle);
 // extern int32_t   v1_5_validateTokenIdentifier(void* context, int32_t tokenIdHandle);
 // extern int32_t   v1_5_transferValue(void* context, int32_t destOffset, int32_t valueOffset, int32_t dataOffset, int32_t length);
+// extern int32_t   v1_5_refundGasToCaller(void* context, long long gas);
 // extern int32_t   v1_5_transferValueExecute(void* context, int32_t destOffset, int32_t valueOffset, long long gasLimit, int32_t functionOffset, int32_t functionLength, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern int32_t   v1_5_transferESDTExecute(void* context, int32_t destOffset, int32_t tokenIDOffset, int32_t tokenIDLen, int32_t valueOffset, long long gasLimit, int32_t functionOffset, int32_t functionLength, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern int32_t   v1_5_transferESDTNFTExecute(void* context, int32_t destOffset, int32_t tokenIDOffset, int32_t tokenIDLen, int32_t valueOffset, long long nonce, long long gasLimit, int32_t functionOffset, int32_t functionLength, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern int32_t   v1_5_multiTransferESDTNFTExecute(void* context, int32_t destOffset, int32_t numTokenTransfers, int32_t tokenTransfersArgsLengthOffset, int32_t tokenTransferDataOffset, long long gasLimit, int32_t functionOffset, int32_t functionLength, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern int32_t   v1_5_createAsyncCall(void* context, int32_t destOffset, int32_t valueOffset, int32_t dataOffset, int32_t dataLength, int32_t successOffset, int32_t successLength, int32_t errorOffset, int32_t errorLength, long long gas, long long extraGasForCallback);
 // extern int32_t   v1_5_setAsyncContextCallback(void* context, int32_t callback, int32_t callbackLength, int32_t data, int32_t dataLength, long long gas);
+// extern int32_t   v1_5_createPromiseGroup(void* context, int32_t groupIDOffset, int32_t groupIDLength);
+// extern int32_t   v1_5_createPromise(void* context, int32_t groupHandle, int32_t destOffset, int32_t valueOffset, int32_t dataOffset, int32_t dataLength, int32_t successOffset, int32_t successLength, int32_t errorOffset, int32_t errorLength, long long gas, long long extraGasForCallback);
 // extern void      v1_5_upgradeContract(void* context, int32_t destOffset, long long gasLimit, int32_t valueOffset, int32_t codeOffset, int32_t codeMetadataOffset, int32_t length, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern void      v1_5_upgradeFromSourceContract(void* context, int32_t destOffset, long long gasLimit, int32_t valueOffset, int32_t sourceContractAddressOffset, int32_t codeMetadataOffset, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern void      v1_5_deleteContract(void* context, int32_t destOffset, long long gasLimit, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern void      v1_5_asyncCall(void* context, int32_t destOffset, int32_t valueOffset, int32_t dataOffset, int32_t length);
 // extern int32_t   v1_5_getArgumentLength(void* context, int32_t id);
 // extern int32_t   v1_5_getArgument(void* context, int32_t id, int32_t argOffset);
+// extern int32_t   v1_5_copyArgumentTo(void* context, int32_t id, int32_t offset, int32_t length, int32_t destOffset);
 // extern int32_t   v1_5_getFunction(void* context, int32_t functionOffset);
 // extern int32_t   v1_5_getNumArguments(void* context);
 // extern int32_t   v1_5_storageStore(void* context, int32_t keyOffset, int32_t keyLength, int32_t dataOffset, int32_t dataLength);
+// extern int32_t   v1_5_storageDelete(void* context, int32_t keyOffset, int32_t keyLength);
 // extern int32_t   v1_5_storageLoadLength(void* context, int32_t keyOffset, int32_t keyLength);
 // extern int32_t   v1_5_storageLoadFromAddress(void* context, int32_t addressOffset, int32_t keyOffset, int32_t keyLength, int32_t dataOffset);
 // extern int32_t   v1_5_storageLoad(void* context, int32_t keyOffset, int32_t keyLength, int32_t dataOffset);
@@ -78,6 +87,8 @@ package wasmer
 // extern long long v1_5_getPrevBlockEpoch(void* context);
 // extern void      v1_5_getPrevBlockRandomSeed(void* context, int32_t pointer);
 // extern void      v1_5_finish(void* context, int32_t pointer, int32_t length);
+// extern int32_t   v1_5_finishReserve(void* context, int32_t length);
+// extern int32_t   v1_5_finishAppend(void* context, int32_t dataOffset, int32_t length);
 // extern int32_t   v1_5_executeOnSameContext(void* context, long long gasLimit, int32_t addressOffset, int32_t valueOffset, int32_t functionOffset, int32_t functionLength, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern int32_t   v1_5_executeOnDestContext(void* context, long long gasLimit, int32_t addressOffset, int32_t valueOffset, int32_t functionOffset, int32_t functionLength, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
 // extern int32_t   v1_5_executeReadOnly(void* context, long long gasLimit, int32_t addressOffset, int32_t functionOffset, int32_t functionLength, int32_t numArguments, int32_t argumentsLengthOffset, int32_t dataOffset);
@@ -104,14 +115,34 @@ package wasmer
 // extern void      v1_5_managedGetMultiESDTCallValue(void* context, int32_t multiCallValueHandle);
 // extern void      v1_5_managedGetESDTBalance(void* context, int32_t addressHandle, int32_t tokenIDHandle, long long nonce, int32_t valueHandle);
 // extern void      v1_5_managedGetESDTTokenData(void* context, int32_t addressHandle, int32_t tokenIDHandle, long long nonce, int32_t valueHandle, int32_t propertiesHandle, int32_t hashHandle, int32_t nameHandle, int32_t attributesHandle, int32_t creatorHandle, int32_t royaltiesHandle, int32_t urisHandle);
+// extern int32_t   v1_5_managedGetESDTNFTAttributes(void* context, int32_t tokenIDHandle, long long nonce, int32_t attributesHandle);
+// extern int32_t   v1_5_managedGetESDTNFTAttributeValue(void* context, int32_t tokenIDHandle, long long nonce, int32_t keyHandle, int32_t valueHandle);
+// extern int32_t   v1_5_managedGetESDTNFTURIs(void* context, int32_t tokenIDHandle, long long nonce, int32_t urisHandle);
+// extern int32_t   v1_5_managedGetESDTNFTCreator(void* context, int32_t tokenIDHandle, long long nonce, int32_t creatorHandle);
+// extern long long v1_5_managedGetESDTNFTRoyalties(void* context, int32_t tokenIDHandle, long long nonce);
+// extern int32_t   v1_5_managedGetESDTTokenTypeOf(void* context, int32_t tokenIDHandle, long long nonce);
+// extern int32_t   v1_5_managedTransferNFTWithRoyalties(void* context, int32_t buyerHandle, int32_t tokenIDHandle, long long nonce, int32_t amountHandle, int32_t sellerHandle, int32_t paymentTokenHandle, long long paymentNonce, int32_t priceHandle);
+// extern int32_t   v1_5_managedEscrowLock(void* context, int32_t beneficiaryHandle, int32_t valueHandle, int32_t escrowIDHandle);
+// extern int32_t   v1_5_managedEscrowClaim(void* context, int32_t lockerHandle, int32_t escrowIDHandle);
+// extern int32_t   v1_5_managedEscrowRelease(void* context, int32_t lockerHandle, int32_t escrowIDHandle);
+// extern int32_t   v1_5_managedRegisterStorageMigration(void* context, int32_t sourcePrefixHandle, int32_t targetPrefixHandle, int32_t reencodeFunctionHandle, long long firstIndex, long long lastIndex);
+// extern long long v1_5_managedContinueStorageMigration(void* context, long long maxKeys);
 // extern void      v1_5_managedAsyncCall(void* context, int32_t destHandle, int32_t valueHandle, int32_t functionHandle, int32_t argumentsHandle);
 // extern int32_t   v1_5_managedCreateAsyncCall(void* context, int32_t destHandle, int32_t valueHandle, int32_t functionHandle, int32_t argumentsHandle, int32_t successOffset, int32_t successLength, int32_t errorOffset, int32_t errorLength, long long gas, long long extraGasForCallback, int32_t callbackClosureHandle);
+// extern int32_t   v1_5_managedCreateAsyncCallWithCallbackArgTypes(void* context, int32_t destHandle, int32_t valueHandle, int32_t functionHandle, int32_t argumentsHandle, int32_t successOffset, int32_t successLength, int32_t errorOffset, int32_t errorLength, long long gas, long long extraGasForCallback, int32_t callbackClosureHandle, int32_t callbackArgTypesHandle);
+// extern int32_t   v1_5_managedCreateAsyncCallWithExpiry(void* context, int32_t destHandle, int32_t valueHandle, int32_t functionHandle, int32_t argumentsHandle, int32_t successOffset, int32_t successLength, int32_t errorOffset, int32_t errorLength, long long gas, long long extraGasForCallback, int32_t callbackClosureHandle, int32_t callbackArgTypesHandle, long long expiryRounds);
 // extern void      v1_5_managedGetCallbackClosure(void* context, int32_t callbackClosureHandle);
+// extern void      v1_5_managedGetLockedValue(void* context, int32_t tokenIDHandle, long long nonce, int32_t resultHandle);
+// extern int32_t   v1_5_managedSetAsyncGroupCallback(void* context, int32_t groupIDHandle, int32_t successCallbackHandle, int32_t errorCallbackHandle, int32_t callbackDataHandle, long long gas);
+// extern int32_t   v1_5_managedAddAsyncSubGroup(void* context, int32_t parentGroupIDHandle, int32_t groupIDHandle);
 // extern void      v1_5_managedUpgradeFromSourceContract(void* context, int32_t destHandle, long long gas, int32_t valueHandle, int32_t addressHandle, int32_t codeMetadataHandle, int32_t argumentsHandle, int32_t resultHandle);
 // extern void      v1_5_managedUpgradeContract(void* context, int32_t destHandle, long long gas, int32_t valueHandle, int32_t codeHandle, int32_t codeMetadataHandle, int32_t argumentsHandle, int32_t resultHandle);
 // extern void      v1_5_managedDeleteContract(void* context, int32_t destHandle, long long gasLimit, int32_t argumentsHandle);
 // extern int32_t   v1_5_managedDeployFromSourceContract(void* context, long long gas, int32_t valueHandle, int32_t addressHandle, int32_t codeMetadataHandle, int32_t argumentsHandle, int32_t resultAddressHandle, int32_t resultHandle);
 // extern int32_t   v1_5_managedCreateContract(void* context, long long gas, int32_t valueHandle, int32_t codeHandle, int32_t codeMetadataHandle, int32_t argumentsHandle, int32_t resultAddressHandle, int32_t resultHandle);
+// extern int32_t   v1_5_managedCreateDeterministicContract(void* context, long long gas, int32_t valueHandle, int32_t codeHandle, int32_t codeMetadataHandle, int32_t argumentsHandle, int32_t saltHandle, int32_t resultAddressHandle, int32_t resultHandle);
+// extern int32_t   v1_5_managedComputeContractAddress(void* context, int32_t deployerHandle, long long nonce, int32_t resultHandle);
+// extern int32_t   v1_5_managedComputeDeterministicAddress(void* context, int32_t saltHandle, int32_t codeHashHandle, int32_t resultHandle);
 // extern int32_t   v1_5_managedExecuteReadOnly(void* context, long long gas, int32_t addressHandle, int32_t functionHandle, int32_t argumentsHandle, int32_t resultHandle);
 // extern int32_t   v1_5_managedExecuteOnSameContext(void* context, long long gas, int32_t addressHandle, int32_t valueHandle, int32_t functionHandle, int32_t argumentsHandle, int32_t resultHandle);
 // extern int32_t   v1_5_managedExecuteOnDestContext(void* context, long long gas, int32_t addressHandle, int32_t valueHandle, int32_t functionHandle, int32_t argumentsHandle, int32_t resultHandle);
@@ -153,6 +184,7 @@ package wasmer
 // extern void      v1_5_bigIntGetExternalBalance(void* context, int32_t addressOffset, int32_t result);
 // extern void      v1_5_bigIntGetESDTExternalBalance(void* context, int32_t addressOffset, int32_t tokenIDOffset, int32_t tokenIDLen, long long nonce, int32_t resultHandle);
 // extern int32_t   v1_5_bigIntNew(void* context, long long smallValue);
+// extern void      v1_5_bigIntDrop(void* context, int32_t referenceHandle);
 // extern int32_t   v1_5_bigIntUnsignedByteLength(void* context, int32_t referenceHandle);
 // extern int32_t   v1_5_bigIntSignedByteLength(void* context, int32_t referenceHandle);
 // extern int32_t   v1_5_bigIntGetUnsignedBytes(void* context, int32_t referenceHandle, int32_t byteOffset);
@@ -187,6 +219,7 @@ package wasmer
 // extern void      v1_5_bigIntToString(void* context, int32_t bigIntHandle, int32_t destinationHandle);
 // extern int32_t   v1_5_mBufferNew(void* context);
 // extern int32_t   v1_5_mBufferNewFromBytes(void* context, int32_t dataOffset, int32_t dataLength);
+// extern int32_t   v1_5_mBufferDrop(void* context, int32_t mBufferHandle);
 // extern int32_t   v1_5_mBufferGetLength(void* context, int32_t mBufferHandle);
 // extern int32_t   v1_5_mBufferGetBytes(void* context, int32_t mBufferHandle, int32_t resultOffset);
 // extern int32_t   v1_5_mBufferGetByteSlice(void* context, int32_t sourceHandle, int32_t startingPosition, int32_t sliceLength, int32_t resultOffset);
@@ -203,6 +236,7 @@ package wasmer
 // extern int32_t   v1_5_mBufferToBigFloat(void* context, int32_t mBufferHandle, int32_t bigFloatHandle);
 // extern int32_t   v1_5_mBufferFromBigFloat(void* context, int32_t mBufferHandle, int32_t bigFloatHandle);
 // extern int32_t   v1_5_mBufferStorageStore(void* context, int32_t keyHandle, int32_t sourceHandle);
+// extern int32_t   v1_5_mBufferStorageDelete(void* context, int32_t keyHandle);
 // extern int32_t   v1_5_mBufferStorageLoad(void* context, int32_t keyHandle, int32_t destinationHandle);
 // extern void      v1_5_mBufferStorageLoadFromAddress(void* context, int32_t addressHandle, int32_t keyHandle, int32_t destinationHandle);
 // extern int32_t   v1_5_mBufferGetArgument(void* context, int32_t id, int32_t destinationHandle);
@@ -272,6 +306,11 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("stackHeightExceeded", v1_5_stackHeightExceeded, C.v1_5_stackHeightExceeded)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("getSCAddress", v1_5_getSCAddress, C.v1_5_getSCAddress)
 	if err != nil {
 		return err
@@ -297,6 +336,21 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("signalTypedError", v1_5_signalTypedError, C.v1_5_signalTypedError)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("debugPrint", v1_5_debugPrint, C.v1_5_debugPrint)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("gasPhase", v1_5_gasPhase, C.v1_5_gasPhase)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("getExternalBalance", v1_5_getExternalBalance, C.v1_5_getExternalBalance)
 	if err != nil {
 		return err
@@ -347,6 +401,11 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("refundGasToCaller", v1_5_refundGasToCaller, C.v1_5_refundGasToCaller)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("transferValueExecute", v1_5_transferValueExecute, C.v1_5_transferValueExecute)
 	if err != nil {
 		return err
@@ -377,6 +436,16 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("createPromiseGroup", v1_5_createPromiseGroup, C.v1_5_createPromiseGroup)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("createPromise", v1_5_createPromise, C.v1_5_createPromise)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("upgradeContract", v1_5_upgradeContract, C.v1_5_upgradeContract)
 	if err != nil {
 		return err
@@ -407,6 +476,11 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("copyArgumentTo", v1_5_copyArgumentTo, C.v1_5_copyArgumentTo)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("getFunction", v1_5_getFunction, C.v1_5_getFunction)
 	if err != nil {
 		return err
@@ -422,6 +496,11 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("storageDelete", v1_5_storageDelete, C.v1_5_storageDelete)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("storageLoadLength", v1_5_storageLoadLength, C.v1_5_storageLoadLength)
 	if err != nil {
 		return err
@@ -602,6 +681,16 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("finishReserve", v1_5_finishReserve, C.v1_5_finishReserve)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("finishAppend", v1_5_finishAppend, C.v1_5_finishAppend)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("executeOnSameContext", v1_5_executeOnSameContext, C.v1_5_executeOnSameContext)
 	if err != nil {
 		return err
@@ -732,6 +821,66 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("managedGetESDTNFTAttributes", v1_5_managedGetESDTNFTAttributes, C.v1_5_managedGetESDTNFTAttributes)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedGetESDTNFTAttributeValue", v1_5_managedGetESDTNFTAttributeValue, C.v1_5_managedGetESDTNFTAttributeValue)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedGetESDTNFTURIs", v1_5_managedGetESDTNFTURIs, C.v1_5_managedGetESDTNFTURIs)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedGetESDTNFTCreator", v1_5_managedGetESDTNFTCreator, C.v1_5_managedGetESDTNFTCreator)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedGetESDTNFTRoyalties", v1_5_managedGetESDTNFTRoyalties, C.v1_5_managedGetESDTNFTRoyalties)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedGetESDTTokenTypeOf", v1_5_managedGetESDTTokenTypeOf, C.v1_5_managedGetESDTTokenTypeOf)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedTransferNFTWithRoyalties", v1_5_managedTransferNFTWithRoyalties, C.v1_5_managedTransferNFTWithRoyalties)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedEscrowLock", v1_5_managedEscrowLock, C.v1_5_managedEscrowLock)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedEscrowClaim", v1_5_managedEscrowClaim, C.v1_5_managedEscrowClaim)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedEscrowRelease", v1_5_managedEscrowRelease, C.v1_5_managedEscrowRelease)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedRegisterStorageMigration", v1_5_managedRegisterStorageMigration, C.v1_5_managedRegisterStorageMigration)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedContinueStorageMigration", v1_5_managedContinueStorageMigration, C.v1_5_managedContinueStorageMigration)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("managedAsyncCall", v1_5_managedAsyncCall, C.v1_5_managedAsyncCall)
 	if err != nil {
 		return err
@@ -742,11 +891,36 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("managedCreateAsyncCallWithCallbackArgTypes", v1_5_managedCreateAsyncCallWithCallbackArgTypes, C.v1_5_managedCreateAsyncCallWithCallbackArgTypes)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedCreateAsyncCallWithExpiry", v1_5_managedCreateAsyncCallWithExpiry, C.v1_5_managedCreateAsyncCallWithExpiry)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("managedGetCallbackClosure", v1_5_managedGetCallbackClosure, C.v1_5_managedGetCallbackClosure)
 	if err != nil {
 		return err
 	}
 
+	err = imports.append("managedGetLockedValue", v1_5_managedGetLockedValue, C.v1_5_managedGetLockedValue)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedSetAsyncGroupCallback", v1_5_managedSetAsyncGroupCallback, C.v1_5_managedSetAsyncGroupCallback)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedAddAsyncSubGroup", v1_5_managedAddAsyncSubGroup, C.v1_5_managedAddAsyncSubGroup)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("managedUpgradeFromSourceContract", v1_5_managedUpgradeFromSourceContract, C.v1_5_managedUpgradeFromSourceContract)
 	if err != nil {
 		return err
@@ -772,6 +946,21 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("managedCreateDeterministicContract", v1_5_managedCreateDeterministicContract, C.v1_5_managedCreateDeterministicContract)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedComputeContractAddress", v1_5_managedComputeContractAddress, C.v1_5_managedComputeContractAddress)
+	if err != nil {
+		return err
+	}
+
+	err = imports.append("managedComputeDeterministicAddress", v1_5_managedComputeDeterministicAddress, C.v1_5_managedComputeDeterministicAddress)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("managedExecuteReadOnly", v1_5_managedExecuteReadOnly, C.v1_5_managedExecuteReadOnly)
 	if err != nil {
 		return err
@@ -977,6 +1166,11 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("bigIntDrop", v1_5_bigIntDrop, C.v1_5_bigIntDrop)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("bigIntUnsignedByteLength", v1_5_bigIntUnsignedByteLength, C.v1_5_bigIntUnsignedByteLength)
 	if err != nil {
 		return err
@@ -1147,6 +1341,11 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("mBufferDrop", v1_5_mBufferDrop, C.v1_5_mBufferDrop)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("mBufferGetLength", v1_5_mBufferGetLength, C.v1_5_mBufferGetLength)
 	if err != nil {
 		return err
@@ -1227,6 +1426,11 @@ func populateWasmerImports(imports *wasmerImports) error {
 		return err
 	}
 
+	err = imports.append("mBufferStorageDelete", v1_5_mBufferStorageDelete, C.v1_5_mBufferStorageDelete)
+	if err != nil {
+		return err
+	}
+
 	err = imports.append("mBufferStorageLoad", v1_5_mBufferStorageLoad, C.v1_5_mBufferStorageLoad)
 	if err != nil {
 		return err
@@ -1508,1481 +1712,1966 @@ func populateWasmerImports(imports *wasmerImports) error {
 //export v1_5_getGasLeft
 func v1_5_getGasLeft(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getGasLeft", startVMHookCall(vmHooks))
 	return vmHooks.GetGasLeft()
 }
 
+//export v1_5_stackHeightExceeded
+func v1_5_stackHeightExceeded(context unsafe.Pointer) {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "stackHeightExceeded", startVMHookCall(vmHooks))
+	vmHooks.StackHeightExceeded()
+}
+
 //export v1_5_getSCAddress
 func v1_5_getSCAddress(context unsafe.Pointer, resultOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getSCAddress", startVMHookCall(vmHooks))
 	vmHooks.GetSCAddress(resultOffset)
 }
 
 //export v1_5_getOwnerAddress
 func v1_5_getOwnerAddress(context unsafe.Pointer, resultOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getOwnerAddress", startVMHookCall(vmHooks))
 	vmHooks.GetOwnerAddress(resultOffset)
 }
 
 //export v1_5_getShardOfAddress
 func v1_5_getShardOfAddress(context unsafe.Pointer, addressOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getShardOfAddress", startVMHookCall(vmHooks))
 	return vmHooks.GetShardOfAddress(addressOffset)
 }
 
 //export v1_5_isSmartContract
 func v1_5_isSmartContract(context unsafe.Pointer, addressOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "isSmartContract", startVMHookCall(vmHooks))
 	return vmHooks.IsSmartContract(addressOffset)
 }
 
 //export v1_5_signalError
 func v1_5_signalError(context unsafe.Pointer, messageOffset int32, messageLength int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "signalError", startVMHookCall(vmHooks))
 	vmHooks.SignalError(messageOffset, messageLength)
 }
 
+//export v1_5_signalTypedError
+func v1_5_signalTypedError(context unsafe.Pointer, code int64, messageOffset int32, messageLength int32, payloadOffset int32, payloadLength int32) {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "signalTypedError", startVMHookCall(vmHooks))
+	vmHooks.SignalTypedError(code, messageOffset, messageLength, payloadOffset, payloadLength)
+}
+
+//export v1_5_debugPrint
+func v1_5_debugPrint(context unsafe.Pointer, messageOffset int32, messageLength int32) {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "debugPrint", startVMHookCall(vmHooks))
+	vmHooks.DebugPrint(messageOffset, messageLength)
+}
+
+//export v1_5_gasPhase
+func v1_5_gasPhase(context unsafe.Pointer, phase int32) {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "gasPhase", startVMHookCall(vmHooks))
+	vmHooks.GasPhase(phase)
+}
+
 //export v1_5_getExternalBalance
 func v1_5_getExternalBalance(context unsafe.Pointer, addressOffset int32, resultOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getExternalBalance", startVMHookCall(vmHooks))
 	vmHooks.GetExternalBalance(addressOffset, resultOffset)
 }
 
 //export v1_5_getBlockHash
 func v1_5_getBlockHash(context unsafe.Pointer, nonce int64, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getBlockHash", startVMHookCall(vmHooks))
 	return vmHooks.GetBlockHash(nonce, resultOffset)
 }
 
 //export v1_5_getESDTBalance
 func v1_5_getESDTBalance(context unsafe.Pointer, addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTBalance", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTBalance(addressOffset, tokenIDOffset, tokenIDLen, nonce, resultOffset)
 }
 
 //export v1_5_getESDTNFTNameLength
 func v1_5_getESDTNFTNameLength(context unsafe.Pointer, addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTNFTNameLength", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTNFTNameLength(addressOffset, tokenIDOffset, tokenIDLen, nonce)
 }
 
 //export v1_5_getESDTNFTAttributeLength
 func v1_5_getESDTNFTAttributeLength(context unsafe.Pointer, addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTNFTAttributeLength", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTNFTAttributeLength(addressOffset, tokenIDOffset, tokenIDLen, nonce)
 }
 
 //export v1_5_getESDTNFTURILength
 func v1_5_getESDTNFTURILength(context unsafe.Pointer, addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTNFTURILength", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTNFTURILength(addressOffset, tokenIDOffset, tokenIDLen, nonce)
 }
 
 //export v1_5_getESDTTokenData
 func v1_5_getESDTTokenData(context unsafe.Pointer, addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64, valueHandle int32, propertiesOffset int32, hashOffset int32, nameOffset int32, attributesOffset int32, creatorOffset int32, royaltiesHandle int32, urisOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTTokenData", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTTokenData(addressOffset, tokenIDOffset, tokenIDLen, nonce, valueHandle, propertiesOffset, hashOffset, nameOffset, attributesOffset, creatorOffset, royaltiesHandle, urisOffset)
 }
 
 //export v1_5_getESDTLocalRoles
 func v1_5_getESDTLocalRoles(context unsafe.Pointer, tokenIdHandle int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTLocalRoles", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTLocalRoles(tokenIdHandle)
 }
 
 //export v1_5_validateTokenIdentifier
 func v1_5_validateTokenIdentifier(context unsafe.Pointer, tokenIdHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "validateTokenIdentifier", startVMHookCall(vmHooks))
 	return vmHooks.ValidateTokenIdentifier(tokenIdHandle)
 }
 
 //export v1_5_transferValue
 func v1_5_transferValue(context unsafe.Pointer, destOffset int32, valueOffset int32, dataOffset int32, length int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "transferValue", startVMHookCall(vmHooks))
 	return vmHooks.TransferValue(destOffset, valueOffset, dataOffset, length)
 }
 
+//export v1_5_refundGasToCaller
+func v1_5_refundGasToCaller(context unsafe.Pointer, gas int64) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "refundGasToCaller", startVMHookCall(vmHooks))
+	return vmHooks.RefundGasToCaller(gas)
+}
+
 //export v1_5_transferValueExecute
 func v1_5_transferValueExecute(context unsafe.Pointer, destOffset int32, valueOffset int32, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "transferValueExecute", startVMHookCall(vmHooks))
 	return vmHooks.TransferValueExecute(destOffset, valueOffset, gasLimit, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_transferESDTExecute
 func v1_5_transferESDTExecute(context unsafe.Pointer, destOffset int32, tokenIDOffset int32, tokenIDLen int32, valueOffset int32, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "transferESDTExecute", startVMHookCall(vmHooks))
 	return vmHooks.TransferESDTExecute(destOffset, tokenIDOffset, tokenIDLen, valueOffset, gasLimit, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_transferESDTNFTExecute
 func v1_5_transferESDTNFTExecute(context unsafe.Pointer, destOffset int32, tokenIDOffset int32, tokenIDLen int32, valueOffset int32, nonce int64, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "transferESDTNFTExecute", startVMHookCall(vmHooks))
 	return vmHooks.TransferESDTNFTExecute(destOffset, tokenIDOffset, tokenIDLen, valueOffset, nonce, gasLimit, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_multiTransferESDTNFTExecute
 func v1_5_multiTransferESDTNFTExecute(context unsafe.Pointer, destOffset int32, numTokenTransfers int32, tokenTransfersArgsLengthOffset int32, tokenTransferDataOffset int32, gasLimit int64, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "multiTransferESDTNFTExecute", startVMHookCall(vmHooks))
 	return vmHooks.MultiTransferESDTNFTExecute(destOffset, numTokenTransfers, tokenTransfersArgsLengthOffset, tokenTransferDataOffset, gasLimit, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_createAsyncCall
 func v1_5_createAsyncCall(context unsafe.Pointer, destOffset int32, valueOffset int32, dataOffset int32, dataLength int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "createAsyncCall", startVMHookCall(vmHooks))
 	return vmHooks.CreateAsyncCall(destOffset, valueOffset, dataOffset, dataLength, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback)
 }
 
 //export v1_5_setAsyncContextCallback
 func v1_5_setAsyncContextCallback(context unsafe.Pointer, callback int32, callbackLength int32, data int32, dataLength int32, gas int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "setAsyncContextCallback", startVMHookCall(vmHooks))
 	return vmHooks.SetAsyncContextCallback(callback, callbackLength, data, dataLength, gas)
 }
 
+//export v1_5_createPromiseGroup
+func v1_5_createPromiseGroup(context unsafe.Pointer, groupIDOffset int32, groupIDLength int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "createPromiseGroup", startVMHookCall(vmHooks))
+	return vmHooks.CreatePromiseGroup(groupIDOffset, groupIDLength)
+}
+
+//export v1_5_createPromise
+func v1_5_createPromise(context unsafe.Pointer, groupHandle int32, destOffset int32, valueOffset int32, dataOffset int32, dataLength int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "createPromise", startVMHookCall(vmHooks))
+	return vmHooks.CreatePromise(groupHandle, destOffset, valueOffset, dataOffset, dataLength, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback)
+}
+
 //export v1_5_upgradeContract
 func v1_5_upgradeContract(context unsafe.Pointer, destOffset int32, gasLimit int64, valueOffset int32, codeOffset int32, codeMetadataOffset int32, length int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "upgradeContract", startVMHookCall(vmHooks))
 	vmHooks.UpgradeContract(destOffset, gasLimit, valueOffset, codeOffset, codeMetadataOffset, length, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_upgradeFromSourceContract
 func v1_5_upgradeFromSourceContract(context unsafe.Pointer, destOffset int32, gasLimit int64, valueOffset int32, sourceContractAddressOffset int32, codeMetadataOffset int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "upgradeFromSourceContract", startVMHookCall(vmHooks))
 	vmHooks.UpgradeFromSourceContract(destOffset, gasLimit, valueOffset, sourceContractAddressOffset, codeMetadataOffset, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_deleteContract
 func v1_5_deleteContract(context unsafe.Pointer, destOffset int32, gasLimit int64, numArguments int32, argumentsLengthOffset int32, dataOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "deleteContract", startVMHookCall(vmHooks))
 	vmHooks.DeleteContract(destOffset, gasLimit, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_asyncCall
 func v1_5_asyncCall(context unsafe.Pointer, destOffset int32, valueOffset int32, dataOffset int32, length int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "asyncCall", startVMHookCall(vmHooks))
 	vmHooks.AsyncCall(destOffset, valueOffset, dataOffset, length)
 }
 
 //export v1_5_getArgumentLength
 func v1_5_getArgumentLength(context unsafe.Pointer, id int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getArgumentLength", startVMHookCall(vmHooks))
 	return vmHooks.GetArgumentLength(id)
 }
 
 //export v1_5_getArgument
 func v1_5_getArgument(context unsafe.Pointer, id int32, argOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getArgument", startVMHookCall(vmHooks))
 	return vmHooks.GetArgument(id, argOffset)
 }
 
+//export v1_5_copyArgumentTo
+func v1_5_copyArgumentTo(context unsafe.Pointer, id int32, offset int32, length int32, destOffset int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "copyArgumentTo", startVMHookCall(vmHooks))
+	return vmHooks.CopyArgumentTo(id, offset, length, destOffset)
+}
+
 //export v1_5_getFunction
 func v1_5_getFunction(context unsafe.Pointer, functionOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getFunction", startVMHookCall(vmHooks))
 	return vmHooks.GetFunction(functionOffset)
 }
 
 //export v1_5_getNumArguments
 func v1_5_getNumArguments(context unsafe.Pointer) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getNumArguments", startVMHookCall(vmHooks))
 	return vmHooks.GetNumArguments()
 }
 
 //export v1_5_storageStore
 func v1_5_storageStore(context unsafe.Pointer, keyOffset int32, keyLength int32, dataOffset int32, dataLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "storageStore", startVMHookCall(vmHooks))
 	return vmHooks.StorageStore(keyOffset, keyLength, dataOffset, dataLength)
 }
 
+//export v1_5_storageDelete
+func v1_5_storageDelete(context unsafe.Pointer, keyOffset int32, keyLength int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "storageDelete", startVMHookCall(vmHooks))
+	return vmHooks.StorageDelete(keyOffset, keyLength)
+}
+
 //export v1_5_storageLoadLength
 func v1_5_storageLoadLength(context unsafe.Pointer, keyOffset int32, keyLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "storageLoadLength", startVMHookCall(vmHooks))
 	return vmHooks.StorageLoadLength(keyOffset, keyLength)
 }
 
 //export v1_5_storageLoadFromAddress
 func v1_5_storageLoadFromAddress(context unsafe.Pointer, addressOffset int32, keyOffset int32, keyLength int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "storageLoadFromAddress", startVMHookCall(vmHooks))
 	return vmHooks.StorageLoadFromAddress(addressOffset, keyOffset, keyLength, dataOffset)
 }
 
 //export v1_5_storageLoad
 func v1_5_storageLoad(context unsafe.Pointer, keyOffset int32, keyLength int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "storageLoad", startVMHookCall(vmHooks))
 	return vmHooks.StorageLoad(keyOffset, keyLength, dataOffset)
 }
 
 //export v1_5_setStorageLock
 func v1_5_setStorageLock(context unsafe.Pointer, keyOffset int32, keyLength int32, lockTimestamp int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "setStorageLock", startVMHookCall(vmHooks))
 	return vmHooks.SetStorageLock(keyOffset, keyLength, lockTimestamp)
 }
 
 //export v1_5_getStorageLock
 func v1_5_getStorageLock(context unsafe.Pointer, keyOffset int32, keyLength int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getStorageLock", startVMHookCall(vmHooks))
 	return vmHooks.GetStorageLock(keyOffset, keyLength)
 }
 
 //export v1_5_isStorageLocked
 func v1_5_isStorageLocked(context unsafe.Pointer, keyOffset int32, keyLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "isStorageLocked", startVMHookCall(vmHooks))
 	return vmHooks.IsStorageLocked(keyOffset, keyLength)
 }
 
 //export v1_5_clearStorageLock
 func v1_5_clearStorageLock(context unsafe.Pointer, keyOffset int32, keyLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "clearStorageLock", startVMHookCall(vmHooks))
 	return vmHooks.ClearStorageLock(keyOffset, keyLength)
 }
 
 //export v1_5_getCaller
 func v1_5_getCaller(context unsafe.Pointer, resultOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getCaller", startVMHookCall(vmHooks))
 	vmHooks.GetCaller(resultOffset)
 }
 
 //export v1_5_checkNoPayment
 func v1_5_checkNoPayment(context unsafe.Pointer) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "checkNoPayment", startVMHookCall(vmHooks))
 	vmHooks.CheckNoPayment()
 }
 
 //export v1_5_getCallValue
 func v1_5_getCallValue(context unsafe.Pointer, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getCallValue", startVMHookCall(vmHooks))
 	return vmHooks.GetCallValue(resultOffset)
 }
 
 //export v1_5_getESDTValue
 func v1_5_getESDTValue(context unsafe.Pointer, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTValue", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTValue(resultOffset)
 }
 
 //export v1_5_getESDTValueByIndex
 func v1_5_getESDTValueByIndex(context unsafe.Pointer, resultOffset int32, index int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTValueByIndex", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTValueByIndex(resultOffset, index)
 }
 
 //export v1_5_getESDTTokenName
 func v1_5_getESDTTokenName(context unsafe.Pointer, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTTokenName", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTTokenName(resultOffset)
 }
 
 //export v1_5_getESDTTokenNameByIndex
 func v1_5_getESDTTokenNameByIndex(context unsafe.Pointer, resultOffset int32, index int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTTokenNameByIndex", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTTokenNameByIndex(resultOffset, index)
 }
 
 //export v1_5_getESDTTokenNonce
 func v1_5_getESDTTokenNonce(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTTokenNonce", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTTokenNonce()
 }
 
 //export v1_5_getESDTTokenNonceByIndex
 func v1_5_getESDTTokenNonceByIndex(context unsafe.Pointer, index int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTTokenNonceByIndex", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTTokenNonceByIndex(index)
 }
 
 //export v1_5_getCurrentESDTNFTNonce
 func v1_5_getCurrentESDTNFTNonce(context unsafe.Pointer, addressOffset int32, tokenIDOffset int32, tokenIDLen int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getCurrentESDTNFTNonce", startVMHookCall(vmHooks))
 	return vmHooks.GetCurrentESDTNFTNonce(addressOffset, tokenIDOffset, tokenIDLen)
 }
 
 //export v1_5_getESDTTokenType
 func v1_5_getESDTTokenType(context unsafe.Pointer) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTTokenType", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTTokenType()
 }
 
 //export v1_5_getESDTTokenTypeByIndex
 func v1_5_getESDTTokenTypeByIndex(context unsafe.Pointer, index int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getESDTTokenTypeByIndex", startVMHookCall(vmHooks))
 	return vmHooks.GetESDTTokenTypeByIndex(index)
 }
 
 //export v1_5_getNumESDTTransfers
 func v1_5_getNumESDTTransfers(context unsafe.Pointer) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getNumESDTTransfers", startVMHookCall(vmHooks))
 	return vmHooks.GetNumESDTTransfers()
 }
 
 //export v1_5_getCallValueTokenName
 func v1_5_getCallValueTokenName(context unsafe.Pointer, callValueOffset int32, tokenNameOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getCallValueTokenName", startVMHookCall(vmHooks))
 	return vmHooks.GetCallValueTokenName(callValueOffset, tokenNameOffset)
 }
 
 //export v1_5_getCallValueTokenNameByIndex
 func v1_5_getCallValueTokenNameByIndex(context unsafe.Pointer, callValueOffset int32, tokenNameOffset int32, index int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getCallValueTokenNameByIndex", startVMHookCall(vmHooks))
 	return vmHooks.GetCallValueTokenNameByIndex(callValueOffset, tokenNameOffset, index)
 }
 
 //export v1_5_writeLog
 func v1_5_writeLog(context unsafe.Pointer, dataPointer int32, dataLength int32, topicPtr int32, numTopics int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "writeLog", startVMHookCall(vmHooks))
 	vmHooks.WriteLog(dataPointer, dataLength, topicPtr, numTopics)
 }
 
 //export v1_5_writeEventLog
 func v1_5_writeEventLog(context unsafe.Pointer, numTopics int32, topicLengthsOffset int32, topicOffset int32, dataOffset int32, dataLength int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "writeEventLog", startVMHookCall(vmHooks))
 	vmHooks.WriteEventLog(numTopics, topicLengthsOffset, topicOffset, dataOffset, dataLength)
 }
 
 //export v1_5_getBlockTimestamp
 func v1_5_getBlockTimestamp(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getBlockTimestamp", startVMHookCall(vmHooks))
 	return vmHooks.GetBlockTimestamp()
 }
 
 //export v1_5_getBlockNonce
 func v1_5_getBlockNonce(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getBlockNonce", startVMHookCall(vmHooks))
 	return vmHooks.GetBlockNonce()
 }
 
 //export v1_5_getBlockRound
 func v1_5_getBlockRound(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getBlockRound", startVMHookCall(vmHooks))
 	return vmHooks.GetBlockRound()
 }
 
 //export v1_5_getBlockEpoch
 func v1_5_getBlockEpoch(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getBlockEpoch", startVMHookCall(vmHooks))
 	return vmHooks.GetBlockEpoch()
 }
 
 //export v1_5_getBlockRandomSeed
 func v1_5_getBlockRandomSeed(context unsafe.Pointer, pointer int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getBlockRandomSeed", startVMHookCall(vmHooks))
 	vmHooks.GetBlockRandomSeed(pointer)
 }
 
 //export v1_5_getStateRootHash
 func v1_5_getStateRootHash(context unsafe.Pointer, pointer int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getStateRootHash", startVMHookCall(vmHooks))
 	vmHooks.GetStateRootHash(pointer)
 }
 
 //export v1_5_getPrevBlockTimestamp
 func v1_5_getPrevBlockTimestamp(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getPrevBlockTimestamp", startVMHookCall(vmHooks))
 	return vmHooks.GetPrevBlockTimestamp()
 }
 
 //export v1_5_getPrevBlockNonce
 func v1_5_getPrevBlockNonce(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getPrevBlockNonce", startVMHookCall(vmHooks))
 	return vmHooks.GetPrevBlockNonce()
 }
 
 //export v1_5_getPrevBlockRound
 func v1_5_getPrevBlockRound(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getPrevBlockRound", startVMHookCall(vmHooks))
 	return vmHooks.GetPrevBlockRound()
 }
 
 //export v1_5_getPrevBlockEpoch
 func v1_5_getPrevBlockEpoch(context unsafe.Pointer) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getPrevBlockEpoch", startVMHookCall(vmHooks))
 	return vmHooks.GetPrevBlockEpoch()
 }
 
 //export v1_5_getPrevBlockRandomSeed
 func v1_5_getPrevBlockRandomSeed(context unsafe.Pointer, pointer int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getPrevBlockRandomSeed", startVMHookCall(vmHooks))
 	vmHooks.GetPrevBlockRandomSeed(pointer)
 }
 
 //export v1_5_finish
 func v1_5_finish(context unsafe.Pointer, pointer int32, length int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "finish", startVMHookCall(vmHooks))
 	vmHooks.Finish(pointer, length)
 }
 
+//export v1_5_finishReserve
+func v1_5_finishReserve(context unsafe.Pointer, length int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "finishReserve", startVMHookCall(vmHooks))
+	return vmHooks.FinishReserve(length)
+}
+
+//export v1_5_finishAppend
+func v1_5_finishAppend(context unsafe.Pointer, dataOffset int32, length int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "finishAppend", startVMHookCall(vmHooks))
+	return vmHooks.FinishAppend(dataOffset, length)
+}
+
 //export v1_5_executeOnSameContext
 func v1_5_executeOnSameContext(context unsafe.Pointer, gasLimit int64, addressOffset int32, valueOffset int32, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "executeOnSameContext", startVMHookCall(vmHooks))
 	return vmHooks.ExecuteOnSameContext(gasLimit, addressOffset, valueOffset, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_executeOnDestContext
 func v1_5_executeOnDestContext(context unsafe.Pointer, gasLimit int64, addressOffset int32, valueOffset int32, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "executeOnDestContext", startVMHookCall(vmHooks))
 	return vmHooks.ExecuteOnDestContext(gasLimit, addressOffset, valueOffset, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_executeReadOnly
 func v1_5_executeReadOnly(context unsafe.Pointer, gasLimit int64, addressOffset int32, functionOffset int32, functionLength int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "executeReadOnly", startVMHookCall(vmHooks))
 	return vmHooks.ExecuteReadOnly(gasLimit, addressOffset, functionOffset, functionLength, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_createContract
 func v1_5_createContract(context unsafe.Pointer, gasLimit int64, valueOffset int32, codeOffset int32, codeMetadataOffset int32, length int32, resultOffset int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "createContract", startVMHookCall(vmHooks))
 	return vmHooks.CreateContract(gasLimit, valueOffset, codeOffset, codeMetadataOffset, length, resultOffset, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_deployFromSourceContract
 func v1_5_deployFromSourceContract(context unsafe.Pointer, gasLimit int64, valueOffset int32, sourceContractAddressOffset int32, codeMetadataOffset int32, resultAddressOffset int32, numArguments int32, argumentsLengthOffset int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "deployFromSourceContract", startVMHookCall(vmHooks))
 	return vmHooks.DeployFromSourceContract(gasLimit, valueOffset, sourceContractAddressOffset, codeMetadataOffset, resultAddressOffset, numArguments, argumentsLengthOffset, dataOffset)
 }
 
 //export v1_5_getNumReturnData
 func v1_5_getNumReturnData(context unsafe.Pointer) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getNumReturnData", startVMHookCall(vmHooks))
 	return vmHooks.GetNumReturnData()
 }
 
 //export v1_5_getReturnDataSize
 func v1_5_getReturnDataSize(context unsafe.Pointer, resultID int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getReturnDataSize", startVMHookCall(vmHooks))
 	return vmHooks.GetReturnDataSize(resultID)
 }
 
 //export v1_5_getReturnData
 func v1_5_getReturnData(context unsafe.Pointer, resultID int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getReturnData", startVMHookCall(vmHooks))
 	return vmHooks.GetReturnData(resultID, dataOffset)
 }
 
 //export v1_5_cleanReturnData
 func v1_5_cleanReturnData(context unsafe.Pointer) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "cleanReturnData", startVMHookCall(vmHooks))
 	vmHooks.CleanReturnData()
 }
 
 //export v1_5_deleteFromReturnData
 func v1_5_deleteFromReturnData(context unsafe.Pointer, resultID int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "deleteFromReturnData", startVMHookCall(vmHooks))
 	vmHooks.DeleteFromReturnData(resultID)
 }
 
 //export v1_5_getOriginalTxHash
 func v1_5_getOriginalTxHash(context unsafe.Pointer, dataOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getOriginalTxHash", startVMHookCall(vmHooks))
 	vmHooks.GetOriginalTxHash(dataOffset)
 }
 
 //export v1_5_getCurrentTxHash
 func v1_5_getCurrentTxHash(context unsafe.Pointer, dataOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getCurrentTxHash", startVMHookCall(vmHooks))
 	vmHooks.GetCurrentTxHash(dataOffset)
 }
 
 //export v1_5_getPrevTxHash
 func v1_5_getPrevTxHash(context unsafe.Pointer, dataOffset int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getPrevTxHash", startVMHookCall(vmHooks))
 	vmHooks.GetPrevTxHash(dataOffset)
 }
 
 //export v1_5_managedSCAddress
 func v1_5_managedSCAddress(context unsafe.Pointer, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedSCAddress", startVMHookCall(vmHooks))
 	vmHooks.ManagedSCAddress(destinationHandle)
 }
 
 //export v1_5_managedOwnerAddress
 func v1_5_managedOwnerAddress(context unsafe.Pointer, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedOwnerAddress", startVMHookCall(vmHooks))
 	vmHooks.ManagedOwnerAddress(destinationHandle)
 }
 
 //export v1_5_managedCaller
 func v1_5_managedCaller(context unsafe.Pointer, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedCaller", startVMHookCall(vmHooks))
 	vmHooks.ManagedCaller(destinationHandle)
 }
 
 //export v1_5_managedSignalError
 func v1_5_managedSignalError(context unsafe.Pointer, errHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedSignalError", startVMHookCall(vmHooks))
 	vmHooks.ManagedSignalError(errHandle)
 }
 
 //export v1_5_managedWriteLog
 func v1_5_managedWriteLog(context unsafe.Pointer, topicsHandle int32, dataHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedWriteLog", startVMHookCall(vmHooks))
 	vmHooks.ManagedWriteLog(topicsHandle, dataHandle)
 }
 
 //export v1_5_managedGetOriginalTxHash
 func v1_5_managedGetOriginalTxHash(context unsafe.Pointer, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetOriginalTxHash", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetOriginalTxHash(resultHandle)
 }
 
 //export v1_5_managedGetStateRootHash
 func v1_5_managedGetStateRootHash(context unsafe.Pointer, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetStateRootHash", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetStateRootHash(resultHandle)
 }
 
 //export v1_5_managedGetBlockRandomSeed
 func v1_5_managedGetBlockRandomSeed(context unsafe.Pointer, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetBlockRandomSeed", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetBlockRandomSeed(resultHandle)
 }
 
 //export v1_5_managedGetPrevBlockRandomSeed
 func v1_5_managedGetPrevBlockRandomSeed(context unsafe.Pointer, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetPrevBlockRandomSeed", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetPrevBlockRandomSeed(resultHandle)
 }
 
 //export v1_5_managedGetReturnData
 func v1_5_managedGetReturnData(context unsafe.Pointer, resultID int32, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetReturnData", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetReturnData(resultID, resultHandle)
 }
 
 //export v1_5_managedGetMultiESDTCallValue
 func v1_5_managedGetMultiESDTCallValue(context unsafe.Pointer, multiCallValueHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetMultiESDTCallValue", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetMultiESDTCallValue(multiCallValueHandle)
 }
 
 //export v1_5_managedGetESDTBalance
 func v1_5_managedGetESDTBalance(context unsafe.Pointer, addressHandle int32, tokenIDHandle int32, nonce int64, valueHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTBalance", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetESDTBalance(addressHandle, tokenIDHandle, nonce, valueHandle)
 }
 
 //export v1_5_managedGetESDTTokenData
 func v1_5_managedGetESDTTokenData(context unsafe.Pointer, addressHandle int32, tokenIDHandle int32, nonce int64, valueHandle int32, propertiesHandle int32, hashHandle int32, nameHandle int32, attributesHandle int32, creatorHandle int32, royaltiesHandle int32, urisHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTTokenData", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetESDTTokenData(addressHandle, tokenIDHandle, nonce, valueHandle, propertiesHandle, hashHandle, nameHandle, attributesHandle, creatorHandle, royaltiesHandle, urisHandle)
 }
 
+//export v1_5_managedGetESDTNFTAttributes
+func v1_5_managedGetESDTNFTAttributes(context unsafe.Pointer, tokenIDHandle int32, nonce int64, attributesHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTNFTAttributes", startVMHookCall(vmHooks))
+	return vmHooks.ManagedGetESDTNFTAttributes(tokenIDHandle, nonce, attributesHandle)
+}
+
+//export v1_5_managedGetESDTNFTAttributeValue
+func v1_5_managedGetESDTNFTAttributeValue(context unsafe.Pointer, tokenIDHandle int32, nonce int64, keyHandle int32, valueHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTNFTAttributeValue", startVMHookCall(vmHooks))
+	return vmHooks.ManagedGetESDTNFTAttributeValue(tokenIDHandle, nonce, keyHandle, valueHandle)
+}
+
+//export v1_5_managedGetESDTNFTURIs
+func v1_5_managedGetESDTNFTURIs(context unsafe.Pointer, tokenIDHandle int32, nonce int64, urisHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTNFTURIs", startVMHookCall(vmHooks))
+	return vmHooks.ManagedGetESDTNFTURIs(tokenIDHandle, nonce, urisHandle)
+}
+
+//export v1_5_managedGetESDTNFTCreator
+func v1_5_managedGetESDTNFTCreator(context unsafe.Pointer, tokenIDHandle int32, nonce int64, creatorHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTNFTCreator", startVMHookCall(vmHooks))
+	return vmHooks.ManagedGetESDTNFTCreator(tokenIDHandle, nonce, creatorHandle)
+}
+
+//export v1_5_managedGetESDTNFTRoyalties
+func v1_5_managedGetESDTNFTRoyalties(context unsafe.Pointer, tokenIDHandle int32, nonce int64) int64 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTNFTRoyalties", startVMHookCall(vmHooks))
+	return vmHooks.ManagedGetESDTNFTRoyalties(tokenIDHandle, nonce)
+}
+
+//export v1_5_managedGetESDTTokenTypeOf
+func v1_5_managedGetESDTTokenTypeOf(context unsafe.Pointer, tokenIDHandle int32, nonce int64) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetESDTTokenTypeOf", startVMHookCall(vmHooks))
+	return vmHooks.ManagedGetESDTTokenTypeOf(tokenIDHandle, nonce)
+}
+
+//export v1_5_managedTransferNFTWithRoyalties
+func v1_5_managedTransferNFTWithRoyalties(context unsafe.Pointer, buyerHandle int32, tokenIDHandle int32, nonce int64, amountHandle int32, sellerHandle int32, paymentTokenHandle int32, paymentNonce int64, priceHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedTransferNFTWithRoyalties", startVMHookCall(vmHooks))
+	return vmHooks.ManagedTransferNFTWithRoyalties(buyerHandle, tokenIDHandle, nonce, amountHandle, sellerHandle, paymentTokenHandle, paymentNonce, priceHandle)
+}
+
+//export v1_5_managedEscrowLock
+func v1_5_managedEscrowLock(context unsafe.Pointer, beneficiaryHandle int32, valueHandle int32, escrowIDHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedEscrowLock", startVMHookCall(vmHooks))
+	return vmHooks.ManagedEscrowLock(beneficiaryHandle, valueHandle, escrowIDHandle)
+}
+
+//export v1_5_managedEscrowClaim
+func v1_5_managedEscrowClaim(context unsafe.Pointer, lockerHandle int32, escrowIDHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedEscrowClaim", startVMHookCall(vmHooks))
+	return vmHooks.ManagedEscrowClaim(lockerHandle, escrowIDHandle)
+}
+
+//export v1_5_managedEscrowRelease
+func v1_5_managedEscrowRelease(context unsafe.Pointer, lockerHandle int32, escrowIDHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedEscrowRelease", startVMHookCall(vmHooks))
+	return vmHooks.ManagedEscrowRelease(lockerHandle, escrowIDHandle)
+}
+
+//export v1_5_managedRegisterStorageMigration
+func v1_5_managedRegisterStorageMigration(context unsafe.Pointer, sourcePrefixHandle int32, targetPrefixHandle int32, reencodeFunctionHandle int32, firstIndex int64, lastIndex int64) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedRegisterStorageMigration", startVMHookCall(vmHooks))
+	return vmHooks.ManagedRegisterStorageMigration(sourcePrefixHandle, targetPrefixHandle, reencodeFunctionHandle, firstIndex, lastIndex)
+}
+
+//export v1_5_managedContinueStorageMigration
+func v1_5_managedContinueStorageMigration(context unsafe.Pointer, maxKeys int64) int64 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedContinueStorageMigration", startVMHookCall(vmHooks))
+	return vmHooks.ManagedContinueStorageMigration(maxKeys)
+}
+
 //export v1_5_managedAsyncCall
 func v1_5_managedAsyncCall(context unsafe.Pointer, destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedAsyncCall", startVMHookCall(vmHooks))
 	vmHooks.ManagedAsyncCall(destHandle, valueHandle, functionHandle, argumentsHandle)
 }
 
 //export v1_5_managedCreateAsyncCall
 func v1_5_managedCreateAsyncCall(context unsafe.Pointer, destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedCreateAsyncCall", startVMHookCall(vmHooks))
 	return vmHooks.ManagedCreateAsyncCall(destHandle, valueHandle, functionHandle, argumentsHandle, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback, callbackClosureHandle)
 }
 
+//export v1_5_managedCreateAsyncCallWithCallbackArgTypes
+func v1_5_managedCreateAsyncCallWithCallbackArgTypes(context unsafe.Pointer, destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32, callbackArgTypesHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedCreateAsyncCallWithCallbackArgTypes", startVMHookCall(vmHooks))
+	return vmHooks.ManagedCreateAsyncCallWithCallbackArgTypes(destHandle, valueHandle, functionHandle, argumentsHandle, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback, callbackClosureHandle, callbackArgTypesHandle)
+}
+
+//export v1_5_managedCreateAsyncCallWithExpiry
+func v1_5_managedCreateAsyncCallWithExpiry(context unsafe.Pointer, destHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, successOffset int32, successLength int32, errorOffset int32, errorLength int32, gas int64, extraGasForCallback int64, callbackClosureHandle int32, callbackArgTypesHandle int32, expiryRounds int64) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedCreateAsyncCallWithExpiry", startVMHookCall(vmHooks))
+	return vmHooks.ManagedCreateAsyncCallWithExpiry(destHandle, valueHandle, functionHandle, argumentsHandle, successOffset, successLength, errorOffset, errorLength, gas, extraGasForCallback, callbackClosureHandle, callbackArgTypesHandle, expiryRounds)
+}
+
 //export v1_5_managedGetCallbackClosure
 func v1_5_managedGetCallbackClosure(context unsafe.Pointer, callbackClosureHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetCallbackClosure", startVMHookCall(vmHooks))
 	vmHooks.ManagedGetCallbackClosure(callbackClosureHandle)
 }
 
+//export v1_5_managedGetLockedValue
+func v1_5_managedGetLockedValue(context unsafe.Pointer, tokenIDHandle int32, nonce int64, resultHandle int32) {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGetLockedValue", startVMHookCall(vmHooks))
+	vmHooks.ManagedGetLockedValue(tokenIDHandle, nonce, resultHandle)
+}
+
+//export v1_5_managedSetAsyncGroupCallback
+func v1_5_managedSetAsyncGroupCallback(context unsafe.Pointer, groupIDHandle int32, successCallbackHandle int32, errorCallbackHandle int32, callbackDataHandle int32, gas int64) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedSetAsyncGroupCallback", startVMHookCall(vmHooks))
+	return vmHooks.ManagedSetAsyncGroupCallback(groupIDHandle, successCallbackHandle, errorCallbackHandle, callbackDataHandle, gas)
+}
+
+//export v1_5_managedAddAsyncSubGroup
+func v1_5_managedAddAsyncSubGroup(context unsafe.Pointer, parentGroupIDHandle int32, groupIDHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedAddAsyncSubGroup", startVMHookCall(vmHooks))
+	return vmHooks.ManagedAddAsyncSubGroup(parentGroupIDHandle, groupIDHandle)
+}
+
 //export v1_5_managedUpgradeFromSourceContract
 func v1_5_managedUpgradeFromSourceContract(context unsafe.Pointer, destHandle int32, gas int64, valueHandle int32, addressHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedUpgradeFromSourceContract", startVMHookCall(vmHooks))
 	vmHooks.ManagedUpgradeFromSourceContract(destHandle, gas, valueHandle, addressHandle, codeMetadataHandle, argumentsHandle, resultHandle)
 }
 
 //export v1_5_managedUpgradeContract
 func v1_5_managedUpgradeContract(context unsafe.Pointer, destHandle int32, gas int64, valueHandle int32, codeHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedUpgradeContract", startVMHookCall(vmHooks))
 	vmHooks.ManagedUpgradeContract(destHandle, gas, valueHandle, codeHandle, codeMetadataHandle, argumentsHandle, resultHandle)
 }
 
 //export v1_5_managedDeleteContract
 func v1_5_managedDeleteContract(context unsafe.Pointer, destHandle int32, gasLimit int64, argumentsHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedDeleteContract", startVMHookCall(vmHooks))
 	vmHooks.ManagedDeleteContract(destHandle, gasLimit, argumentsHandle)
 }
 
 //export v1_5_managedDeployFromSourceContract
 func v1_5_managedDeployFromSourceContract(context unsafe.Pointer, gas int64, valueHandle int32, addressHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultAddressHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedDeployFromSourceContract", startVMHookCall(vmHooks))
 	return vmHooks.ManagedDeployFromSourceContract(gas, valueHandle, addressHandle, codeMetadataHandle, argumentsHandle, resultAddressHandle, resultHandle)
 }
 
 //export v1_5_managedCreateContract
 func v1_5_managedCreateContract(context unsafe.Pointer, gas int64, valueHandle int32, codeHandle int32, codeMetadataHandle int32, argumentsHandle int32, resultAddressHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedCreateContract", startVMHookCall(vmHooks))
 	return vmHooks.ManagedCreateContract(gas, valueHandle, codeHandle, codeMetadataHandle, argumentsHandle, resultAddressHandle, resultHandle)
 }
 
+//export v1_5_managedCreateDeterministicContract
+func v1_5_managedCreateDeterministicContract(context unsafe.Pointer, gas int64, valueHandle int32, codeHandle int32, codeMetadataHandle int32, argumentsHandle int32, saltHandle int32, resultAddressHandle int32, resultHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedCreateDeterministicContract", startVMHookCall(vmHooks))
+	return vmHooks.ManagedCreateDeterministicContract(gas, valueHandle, codeHandle, codeMetadataHandle, argumentsHandle, saltHandle, resultAddressHandle, resultHandle)
+}
+
+//export v1_5_managedComputeContractAddress
+func v1_5_managedComputeContractAddress(context unsafe.Pointer, deployerHandle int32, nonce int64, resultHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedComputeContractAddress", startVMHookCall(vmHooks))
+	return vmHooks.ManagedComputeContractAddress(deployerHandle, nonce, resultHandle)
+}
+
+//export v1_5_managedComputeDeterministicAddress
+func v1_5_managedComputeDeterministicAddress(context unsafe.Pointer, saltHandle int32, codeHashHandle int32, resultHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedComputeDeterministicAddress", startVMHookCall(vmHooks))
+	return vmHooks.ManagedComputeDeterministicAddress(saltHandle, codeHashHandle, resultHandle)
+}
+
 //export v1_5_managedExecuteReadOnly
 func v1_5_managedExecuteReadOnly(context unsafe.Pointer, gas int64, addressHandle int32, functionHandle int32, argumentsHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedExecuteReadOnly", startVMHookCall(vmHooks))
 	return vmHooks.ManagedExecuteReadOnly(gas, addressHandle, functionHandle, argumentsHandle, resultHandle)
 }
 
 //export v1_5_managedExecuteOnSameContext
 func v1_5_managedExecuteOnSameContext(context unsafe.Pointer, gas int64, addressHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedExecuteOnSameContext", startVMHookCall(vmHooks))
 	return vmHooks.ManagedExecuteOnSameContext(gas, addressHandle, valueHandle, functionHandle, argumentsHandle, resultHandle)
 }
 
 //export v1_5_managedExecuteOnDestContext
 func v1_5_managedExecuteOnDestContext(context unsafe.Pointer, gas int64, addressHandle int32, valueHandle int32, functionHandle int32, argumentsHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedExecuteOnDestContext", startVMHookCall(vmHooks))
 	return vmHooks.ManagedExecuteOnDestContext(gas, addressHandle, valueHandle, functionHandle, argumentsHandle, resultHandle)
 }
 
 //export v1_5_managedMultiTransferESDTNFTExecute
 func v1_5_managedMultiTransferESDTNFTExecute(context unsafe.Pointer, dstHandle int32, tokenTransfersHandle int32, gasLimit int64, functionHandle int32, argumentsHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedMultiTransferESDTNFTExecute", startVMHookCall(vmHooks))
 	return vmHooks.ManagedMultiTransferESDTNFTExecute(dstHandle, tokenTransfersHandle, gasLimit, functionHandle, argumentsHandle)
 }
 
 //export v1_5_managedTransferValueExecute
 func v1_5_managedTransferValueExecute(context unsafe.Pointer, dstHandle int32, valueHandle int32, gasLimit int64, functionHandle int32, argumentsHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedTransferValueExecute", startVMHookCall(vmHooks))
 	return vmHooks.ManagedTransferValueExecute(dstHandle, valueHandle, gasLimit, functionHandle, argumentsHandle)
 }
 
 //export v1_5_managedIsESDTFrozen
 func v1_5_managedIsESDTFrozen(context unsafe.Pointer, addressHandle int32, tokenIDHandle int32, nonce int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedIsESDTFrozen", startVMHookCall(vmHooks))
 	return vmHooks.ManagedIsESDTFrozen(addressHandle, tokenIDHandle, nonce)
 }
 
 //export v1_5_managedIsESDTLimitedTransfer
 func v1_5_managedIsESDTLimitedTransfer(context unsafe.Pointer, tokenIDHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedIsESDTLimitedTransfer", startVMHookCall(vmHooks))
 	return vmHooks.ManagedIsESDTLimitedTransfer(tokenIDHandle)
 }
 
 //export v1_5_managedIsESDTPaused
 func v1_5_managedIsESDTPaused(context unsafe.Pointer, tokenIDHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedIsESDTPaused", startVMHookCall(vmHooks))
 	return vmHooks.ManagedIsESDTPaused(tokenIDHandle)
 }
 
 //export v1_5_managedBufferToHex
 func v1_5_managedBufferToHex(context unsafe.Pointer, sourceHandle int32, destHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedBufferToHex", startVMHookCall(vmHooks))
 	vmHooks.ManagedBufferToHex(sourceHandle, destHandle)
 }
 
 //export v1_5_bigFloatNewFromParts
 func v1_5_bigFloatNewFromParts(context unsafe.Pointer, integralPart int32, fractionalPart int32, exponent int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatNewFromParts", startVMHookCall(vmHooks))
 	return vmHooks.BigFloatNewFromParts(integralPart, fractionalPart, exponent)
 }
 
 //export v1_5_bigFloatNewFromFrac
 func v1_5_bigFloatNewFromFrac(context unsafe.Pointer, numerator int64, denominator int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatNewFromFrac", startVMHookCall(vmHooks))
 	return vmHooks.BigFloatNewFromFrac(numerator, denominator)
 }
 
 //export v1_5_bigFloatNewFromSci
 func v1_5_bigFloatNewFromSci(context unsafe.Pointer, significand int64, exponent int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatNewFromSci", startVMHookCall(vmHooks))
 	return vmHooks.BigFloatNewFromSci(significand, exponent)
 }
 
 //export v1_5_bigFloatAdd
 func v1_5_bigFloatAdd(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatAdd", startVMHookCall(vmHooks))
 	vmHooks.BigFloatAdd(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigFloatSub
 func v1_5_bigFloatSub(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatSub", startVMHookCall(vmHooks))
 	vmHooks.BigFloatSub(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigFloatMul
 func v1_5_bigFloatMul(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatMul", startVMHookCall(vmHooks))
 	vmHooks.BigFloatMul(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigFloatDiv
 func v1_5_bigFloatDiv(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatDiv", startVMHookCall(vmHooks))
 	vmHooks.BigFloatDiv(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigFloatNeg
 func v1_5_bigFloatNeg(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatNeg", startVMHookCall(vmHooks))
 	vmHooks.BigFloatNeg(destinationHandle, opHandle)
 }
 
 //export v1_5_bigFloatClone
 func v1_5_bigFloatClone(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatClone", startVMHookCall(vmHooks))
 	vmHooks.BigFloatClone(destinationHandle, opHandle)
 }
 
 //export v1_5_bigFloatCmp
 func v1_5_bigFloatCmp(context unsafe.Pointer, op1Handle int32, op2Handle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatCmp", startVMHookCall(vmHooks))
 	return vmHooks.BigFloatCmp(op1Handle, op2Handle)
 }
 
 //export v1_5_bigFloatAbs
 func v1_5_bigFloatAbs(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatAbs", startVMHookCall(vmHooks))
 	vmHooks.BigFloatAbs(destinationHandle, opHandle)
 }
 
 //export v1_5_bigFloatSign
 func v1_5_bigFloatSign(context unsafe.Pointer, opHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatSign", startVMHookCall(vmHooks))
 	return vmHooks.BigFloatSign(opHandle)
 }
 
 //export v1_5_bigFloatSqrt
 func v1_5_bigFloatSqrt(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatSqrt", startVMHookCall(vmHooks))
 	vmHooks.BigFloatSqrt(destinationHandle, opHandle)
 }
 
 //export v1_5_bigFloatPow
 func v1_5_bigFloatPow(context unsafe.Pointer, destinationHandle int32, opHandle int32, exponent int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatPow", startVMHookCall(vmHooks))
 	vmHooks.BigFloatPow(destinationHandle, opHandle, exponent)
 }
 
 //export v1_5_bigFloatFloor
 func v1_5_bigFloatFloor(context unsafe.Pointer, destBigIntHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatFloor", startVMHookCall(vmHooks))
 	vmHooks.BigFloatFloor(destBigIntHandle, opHandle)
 }
 
 //export v1_5_bigFloatCeil
 func v1_5_bigFloatCeil(context unsafe.Pointer, destBigIntHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatCeil", startVMHookCall(vmHooks))
 	vmHooks.BigFloatCeil(destBigIntHandle, opHandle)
 }
 
 //export v1_5_bigFloatTruncate
 func v1_5_bigFloatTruncate(context unsafe.Pointer, destBigIntHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatTruncate", startVMHookCall(vmHooks))
 	vmHooks.BigFloatTruncate(destBigIntHandle, opHandle)
 }
 
 //export v1_5_bigFloatSetInt64
 func v1_5_bigFloatSetInt64(context unsafe.Pointer, destinationHandle int32, value int64) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatSetInt64", startVMHookCall(vmHooks))
 	vmHooks.BigFloatSetInt64(destinationHandle, value)
 }
 
 //export v1_5_bigFloatIsInt
 func v1_5_bigFloatIsInt(context unsafe.Pointer, opHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatIsInt", startVMHookCall(vmHooks))
 	return vmHooks.BigFloatIsInt(opHandle)
 }
 
 //export v1_5_bigFloatSetBigInt
 func v1_5_bigFloatSetBigInt(context unsafe.Pointer, destinationHandle int32, bigIntHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatSetBigInt", startVMHookCall(vmHooks))
 	vmHooks.BigFloatSetBigInt(destinationHandle, bigIntHandle)
 }
 
 //export v1_5_bigFloatGetConstPi
 func v1_5_bigFloatGetConstPi(context unsafe.Pointer, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatGetConstPi", startVMHookCall(vmHooks))
 	vmHooks.BigFloatGetConstPi(destinationHandle)
 }
 
 //export v1_5_bigFloatGetConstE
 func v1_5_bigFloatGetConstE(context unsafe.Pointer, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigFloatGetConstE", startVMHookCall(vmHooks))
 	vmHooks.BigFloatGetConstE(destinationHandle)
 }
 
 //export v1_5_bigIntGetUnsignedArgument
 func v1_5_bigIntGetUnsignedArgument(context unsafe.Pointer, id int32, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetUnsignedArgument", startVMHookCall(vmHooks))
 	vmHooks.BigIntGetUnsignedArgument(id, destinationHandle)
 }
 
 //export v1_5_bigIntGetSignedArgument
 func v1_5_bigIntGetSignedArgument(context unsafe.Pointer, id int32, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetSignedArgument", startVMHookCall(vmHooks))
 	vmHooks.BigIntGetSignedArgument(id, destinationHandle)
 }
 
 //export v1_5_bigIntStorageStoreUnsigned
 func v1_5_bigIntStorageStoreUnsigned(context unsafe.Pointer, keyOffset int32, keyLength int32, sourceHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntStorageStoreUnsigned", startVMHookCall(vmHooks))
 	return vmHooks.BigIntStorageStoreUnsigned(keyOffset, keyLength, sourceHandle)
 }
 
 //export v1_5_bigIntStorageLoadUnsigned
 func v1_5_bigIntStorageLoadUnsigned(context unsafe.Pointer, keyOffset int32, keyLength int32, destinationHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntStorageLoadUnsigned", startVMHookCall(vmHooks))
 	return vmHooks.BigIntStorageLoadUnsigned(keyOffset, keyLength, destinationHandle)
 }
 
 //export v1_5_bigIntGetCallValue
 func v1_5_bigIntGetCallValue(context unsafe.Pointer, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetCallValue", startVMHookCall(vmHooks))
 	vmHooks.BigIntGetCallValue(destinationHandle)
 }
 
 //export v1_5_bigIntGetESDTCallValue
 func v1_5_bigIntGetESDTCallValue(context unsafe.Pointer, destination int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetESDTCallValue", startVMHookCall(vmHooks))
 	vmHooks.BigIntGetESDTCallValue(destination)
 }
 
 //export v1_5_bigIntGetESDTCallValueByIndex
 func v1_5_bigIntGetESDTCallValueByIndex(context unsafe.Pointer, destinationHandle int32, index int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetESDTCallValueByIndex", startVMHookCall(vmHooks))
 	vmHooks.BigIntGetESDTCallValueByIndex(destinationHandle, index)
 }
 
 //export v1_5_bigIntGetExternalBalance
 func v1_5_bigIntGetExternalBalance(context unsafe.Pointer, addressOffset int32, result int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetExternalBalance", startVMHookCall(vmHooks))
 	vmHooks.BigIntGetExternalBalance(addressOffset, result)
 }
 
 //export v1_5_bigIntGetESDTExternalBalance
 func v1_5_bigIntGetESDTExternalBalance(context unsafe.Pointer, addressOffset int32, tokenIDOffset int32, tokenIDLen int32, nonce int64, resultHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetESDTExternalBalance", startVMHookCall(vmHooks))
 	vmHooks.BigIntGetESDTExternalBalance(addressOffset, tokenIDOffset, tokenIDLen, nonce, resultHandle)
 }
 
 //export v1_5_bigIntNew
 func v1_5_bigIntNew(context unsafe.Pointer, smallValue int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntNew", startVMHookCall(vmHooks))
 	return vmHooks.BigIntNew(smallValue)
 }
 
+//export v1_5_bigIntDrop
+func v1_5_bigIntDrop(context unsafe.Pointer, referenceHandle int32) {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntDrop", startVMHookCall(vmHooks))
+	vmHooks.BigIntDrop(referenceHandle)
+}
+
 //export v1_5_bigIntUnsignedByteLength
 func v1_5_bigIntUnsignedByteLength(context unsafe.Pointer, referenceHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntUnsignedByteLength", startVMHookCall(vmHooks))
 	return vmHooks.BigIntUnsignedByteLength(referenceHandle)
 }
 
 //export v1_5_bigIntSignedByteLength
 func v1_5_bigIntSignedByteLength(context unsafe.Pointer, referenceHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntSignedByteLength", startVMHookCall(vmHooks))
 	return vmHooks.BigIntSignedByteLength(referenceHandle)
 }
 
 //export v1_5_bigIntGetUnsignedBytes
 func v1_5_bigIntGetUnsignedBytes(context unsafe.Pointer, referenceHandle int32, byteOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetUnsignedBytes", startVMHookCall(vmHooks))
 	return vmHooks.BigIntGetUnsignedBytes(referenceHandle, byteOffset)
 }
 
 //export v1_5_bigIntGetSignedBytes
 func v1_5_bigIntGetSignedBytes(context unsafe.Pointer, referenceHandle int32, byteOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetSignedBytes", startVMHookCall(vmHooks))
 	return vmHooks.BigIntGetSignedBytes(referenceHandle, byteOffset)
 }
 
 //export v1_5_bigIntSetUnsignedBytes
 func v1_5_bigIntSetUnsignedBytes(context unsafe.Pointer, destinationHandle int32, byteOffset int32, byteLength int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntSetUnsignedBytes", startVMHookCall(vmHooks))
 	vmHooks.BigIntSetUnsignedBytes(destinationHandle, byteOffset, byteLength)
 }
 
 //export v1_5_bigIntSetSignedBytes
 func v1_5_bigIntSetSignedBytes(context unsafe.Pointer, destinationHandle int32, byteOffset int32, byteLength int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntSetSignedBytes", startVMHookCall(vmHooks))
 	vmHooks.BigIntSetSignedBytes(destinationHandle, byteOffset, byteLength)
 }
 
 //export v1_5_bigIntIsInt64
 func v1_5_bigIntIsInt64(context unsafe.Pointer, destinationHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntIsInt64", startVMHookCall(vmHooks))
 	return vmHooks.BigIntIsInt64(destinationHandle)
 }
 
 //export v1_5_bigIntGetInt64
 func v1_5_bigIntGetInt64(context unsafe.Pointer, destinationHandle int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntGetInt64", startVMHookCall(vmHooks))
 	return vmHooks.BigIntGetInt64(destinationHandle)
 }
 
 //export v1_5_bigIntSetInt64
 func v1_5_bigIntSetInt64(context unsafe.Pointer, destinationHandle int32, value int64) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntSetInt64", startVMHookCall(vmHooks))
 	vmHooks.BigIntSetInt64(destinationHandle, value)
 }
 
 //export v1_5_bigIntAdd
 func v1_5_bigIntAdd(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntAdd", startVMHookCall(vmHooks))
 	vmHooks.BigIntAdd(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntSub
 func v1_5_bigIntSub(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntSub", startVMHookCall(vmHooks))
 	vmHooks.BigIntSub(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntMul
 func v1_5_bigIntMul(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntMul", startVMHookCall(vmHooks))
 	vmHooks.BigIntMul(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntTDiv
 func v1_5_bigIntTDiv(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntTDiv", startVMHookCall(vmHooks))
 	vmHooks.BigIntTDiv(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntTMod
 func v1_5_bigIntTMod(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntTMod", startVMHookCall(vmHooks))
 	vmHooks.BigIntTMod(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntEDiv
 func v1_5_bigIntEDiv(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntEDiv", startVMHookCall(vmHooks))
 	vmHooks.BigIntEDiv(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntEMod
 func v1_5_bigIntEMod(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntEMod", startVMHookCall(vmHooks))
 	vmHooks.BigIntEMod(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntSqrt
 func v1_5_bigIntSqrt(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntSqrt", startVMHookCall(vmHooks))
 	vmHooks.BigIntSqrt(destinationHandle, opHandle)
 }
 
 //export v1_5_bigIntPow
 func v1_5_bigIntPow(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntPow", startVMHookCall(vmHooks))
 	vmHooks.BigIntPow(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntLog2
 func v1_5_bigIntLog2(context unsafe.Pointer, op1Handle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntLog2", startVMHookCall(vmHooks))
 	return vmHooks.BigIntLog2(op1Handle)
 }
 
 //export v1_5_bigIntAbs
 func v1_5_bigIntAbs(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntAbs", startVMHookCall(vmHooks))
 	vmHooks.BigIntAbs(destinationHandle, opHandle)
 }
 
 //export v1_5_bigIntNeg
 func v1_5_bigIntNeg(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntNeg", startVMHookCall(vmHooks))
 	vmHooks.BigIntNeg(destinationHandle, opHandle)
 }
 
 //export v1_5_bigIntSign
 func v1_5_bigIntSign(context unsafe.Pointer, opHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntSign", startVMHookCall(vmHooks))
 	return vmHooks.BigIntSign(opHandle)
 }
 
 //export v1_5_bigIntCmp
 func v1_5_bigIntCmp(context unsafe.Pointer, op1Handle int32, op2Handle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntCmp", startVMHookCall(vmHooks))
 	return vmHooks.BigIntCmp(op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntNot
 func v1_5_bigIntNot(context unsafe.Pointer, destinationHandle int32, opHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntNot", startVMHookCall(vmHooks))
 	vmHooks.BigIntNot(destinationHandle, opHandle)
 }
 
 //export v1_5_bigIntAnd
 func v1_5_bigIntAnd(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntAnd", startVMHookCall(vmHooks))
 	vmHooks.BigIntAnd(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntOr
 func v1_5_bigIntOr(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntOr", startVMHookCall(vmHooks))
 	vmHooks.BigIntOr(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntXor
 func v1_5_bigIntXor(context unsafe.Pointer, destinationHandle int32, op1Handle int32, op2Handle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntXor", startVMHookCall(vmHooks))
 	vmHooks.BigIntXor(destinationHandle, op1Handle, op2Handle)
 }
 
 //export v1_5_bigIntShr
 func v1_5_bigIntShr(context unsafe.Pointer, destinationHandle int32, opHandle int32, bits int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntShr", startVMHookCall(vmHooks))
 	vmHooks.BigIntShr(destinationHandle, opHandle, bits)
 }
 
 //export v1_5_bigIntShl
 func v1_5_bigIntShl(context unsafe.Pointer, destinationHandle int32, opHandle int32, bits int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntShl", startVMHookCall(vmHooks))
 	vmHooks.BigIntShl(destinationHandle, opHandle, bits)
 }
 
 //export v1_5_bigIntFinishUnsigned
 func v1_5_bigIntFinishUnsigned(context unsafe.Pointer, referenceHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntFinishUnsigned", startVMHookCall(vmHooks))
 	vmHooks.BigIntFinishUnsigned(referenceHandle)
 }
 
 //export v1_5_bigIntFinishSigned
 func v1_5_bigIntFinishSigned(context unsafe.Pointer, referenceHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntFinishSigned", startVMHookCall(vmHooks))
 	vmHooks.BigIntFinishSigned(referenceHandle)
 }
 
 //export v1_5_bigIntToString
 func v1_5_bigIntToString(context unsafe.Pointer, bigIntHandle int32, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "bigIntToString", startVMHookCall(vmHooks))
 	vmHooks.BigIntToString(bigIntHandle, destinationHandle)
 }
 
 //export v1_5_mBufferNew
 func v1_5_mBufferNew(context unsafe.Pointer) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferNew", startVMHookCall(vmHooks))
 	return vmHooks.MBufferNew()
 }
 
 //export v1_5_mBufferNewFromBytes
 func v1_5_mBufferNewFromBytes(context unsafe.Pointer, dataOffset int32, dataLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferNewFromBytes", startVMHookCall(vmHooks))
 	return vmHooks.MBufferNewFromBytes(dataOffset, dataLength)
 }
 
+//export v1_5_mBufferDrop
+func v1_5_mBufferDrop(context unsafe.Pointer, mBufferHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferDrop", startVMHookCall(vmHooks))
+	return vmHooks.MBufferDrop(mBufferHandle)
+}
+
 //export v1_5_mBufferGetLength
 func v1_5_mBufferGetLength(context unsafe.Pointer, mBufferHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferGetLength", startVMHookCall(vmHooks))
 	return vmHooks.MBufferGetLength(mBufferHandle)
 }
 
 //export v1_5_mBufferGetBytes
 func v1_5_mBufferGetBytes(context unsafe.Pointer, mBufferHandle int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferGetBytes", startVMHookCall(vmHooks))
 	return vmHooks.MBufferGetBytes(mBufferHandle, resultOffset)
 }
 
 //export v1_5_mBufferGetByteSlice
 func v1_5_mBufferGetByteSlice(context unsafe.Pointer, sourceHandle int32, startingPosition int32, sliceLength int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferGetByteSlice", startVMHookCall(vmHooks))
 	return vmHooks.MBufferGetByteSlice(sourceHandle, startingPosition, sliceLength, resultOffset)
 }
 
 //export v1_5_mBufferCopyByteSlice
 func v1_5_mBufferCopyByteSlice(context unsafe.Pointer, sourceHandle int32, startingPosition int32, sliceLength int32, destinationHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferCopyByteSlice", startVMHookCall(vmHooks))
 	return vmHooks.MBufferCopyByteSlice(sourceHandle, startingPosition, sliceLength, destinationHandle)
 }
 
 //export v1_5_mBufferEq
 func v1_5_mBufferEq(context unsafe.Pointer, mBufferHandle1 int32, mBufferHandle2 int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferEq", startVMHookCall(vmHooks))
 	return vmHooks.MBufferEq(mBufferHandle1, mBufferHandle2)
 }
 
 //export v1_5_mBufferSetBytes
 func v1_5_mBufferSetBytes(context unsafe.Pointer, mBufferHandle int32, dataOffset int32, dataLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferSetBytes", startVMHookCall(vmHooks))
 	return vmHooks.MBufferSetBytes(mBufferHandle, dataOffset, dataLength)
 }
 
 //export v1_5_mBufferSetByteSlice
 func v1_5_mBufferSetByteSlice(context unsafe.Pointer, mBufferHandle int32, startingPosition int32, dataLength int32, dataOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferSetByteSlice", startVMHookCall(vmHooks))
 	return vmHooks.MBufferSetByteSlice(mBufferHandle, startingPosition, dataLength, dataOffset)
 }
 
 //export v1_5_mBufferAppend
 func v1_5_mBufferAppend(context unsafe.Pointer, accumulatorHandle int32, dataHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferAppend", startVMHookCall(vmHooks))
 	return vmHooks.MBufferAppend(accumulatorHandle, dataHandle)
 }
 
 //export v1_5_mBufferAppendBytes
 func v1_5_mBufferAppendBytes(context unsafe.Pointer, accumulatorHandle int32, dataOffset int32, dataLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferAppendBytes", startVMHookCall(vmHooks))
 	return vmHooks.MBufferAppendBytes(accumulatorHandle, dataOffset, dataLength)
 }
 
 //export v1_5_mBufferToBigIntUnsigned
 func v1_5_mBufferToBigIntUnsigned(context unsafe.Pointer, mBufferHandle int32, bigIntHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferToBigIntUnsigned", startVMHookCall(vmHooks))
 	return vmHooks.MBufferToBigIntUnsigned(mBufferHandle, bigIntHandle)
 }
 
 //export v1_5_mBufferToBigIntSigned
 func v1_5_mBufferToBigIntSigned(context unsafe.Pointer, mBufferHandle int32, bigIntHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferToBigIntSigned", startVMHookCall(vmHooks))
 	return vmHooks.MBufferToBigIntSigned(mBufferHandle, bigIntHandle)
 }
 
 //export v1_5_mBufferFromBigIntUnsigned
 func v1_5_mBufferFromBigIntUnsigned(context unsafe.Pointer, mBufferHandle int32, bigIntHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferFromBigIntUnsigned", startVMHookCall(vmHooks))
 	return vmHooks.MBufferFromBigIntUnsigned(mBufferHandle, bigIntHandle)
 }
 
 //export v1_5_mBufferFromBigIntSigned
 func v1_5_mBufferFromBigIntSigned(context unsafe.Pointer, mBufferHandle int32, bigIntHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferFromBigIntSigned", startVMHookCall(vmHooks))
 	return vmHooks.MBufferFromBigIntSigned(mBufferHandle, bigIntHandle)
 }
 
 //export v1_5_mBufferToBigFloat
 func v1_5_mBufferToBigFloat(context unsafe.Pointer, mBufferHandle int32, bigFloatHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferToBigFloat", startVMHookCall(vmHooks))
 	return vmHooks.MBufferToBigFloat(mBufferHandle, bigFloatHandle)
 }
 
 //export v1_5_mBufferFromBigFloat
 func v1_5_mBufferFromBigFloat(context unsafe.Pointer, mBufferHandle int32, bigFloatHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferFromBigFloat", startVMHookCall(vmHooks))
 	return vmHooks.MBufferFromBigFloat(mBufferHandle, bigFloatHandle)
 }
 
 //export v1_5_mBufferStorageStore
 func v1_5_mBufferStorageStore(context unsafe.Pointer, keyHandle int32, sourceHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferStorageStore", startVMHookCall(vmHooks))
 	return vmHooks.MBufferStorageStore(keyHandle, sourceHandle)
 }
 
+//export v1_5_mBufferStorageDelete
+func v1_5_mBufferStorageDelete(context unsafe.Pointer, keyHandle int32) int32 {
+	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferStorageDelete", startVMHookCall(vmHooks))
+	return vmHooks.MBufferStorageDelete(keyHandle)
+}
+
 //export v1_5_mBufferStorageLoad
 func v1_5_mBufferStorageLoad(context unsafe.Pointer, keyHandle int32, destinationHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferStorageLoad", startVMHookCall(vmHooks))
 	return vmHooks.MBufferStorageLoad(keyHandle, destinationHandle)
 }
 
 //export v1_5_mBufferStorageLoadFromAddress
 func v1_5_mBufferStorageLoadFromAddress(context unsafe.Pointer, addressHandle int32, keyHandle int32, destinationHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferStorageLoadFromAddress", startVMHookCall(vmHooks))
 	vmHooks.MBufferStorageLoadFromAddress(addressHandle, keyHandle, destinationHandle)
 }
 
 //export v1_5_mBufferGetArgument
 func v1_5_mBufferGetArgument(context unsafe.Pointer, id int32, destinationHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferGetArgument", startVMHookCall(vmHooks))
 	return vmHooks.MBufferGetArgument(id, destinationHandle)
 }
 
 //export v1_5_mBufferFinish
 func v1_5_mBufferFinish(context unsafe.Pointer, sourceHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferFinish", startVMHookCall(vmHooks))
 	return vmHooks.MBufferFinish(sourceHandle)
 }
 
 //export v1_5_mBufferSetRandom
 func v1_5_mBufferSetRandom(context unsafe.Pointer, destinationHandle int32, length int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "mBufferSetRandom", startVMHookCall(vmHooks))
 	return vmHooks.MBufferSetRandom(destinationHandle, length)
 }
 
 //export v1_5_smallIntGetUnsignedArgument
 func v1_5_smallIntGetUnsignedArgument(context unsafe.Pointer, id int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntGetUnsignedArgument", startVMHookCall(vmHooks))
 	return vmHooks.SmallIntGetUnsignedArgument(id)
 }
 
 //export v1_5_smallIntGetSignedArgument
 func v1_5_smallIntGetSignedArgument(context unsafe.Pointer, id int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntGetSignedArgument", startVMHookCall(vmHooks))
 	return vmHooks.SmallIntGetSignedArgument(id)
 }
 
 //export v1_5_smallIntFinishUnsigned
 func v1_5_smallIntFinishUnsigned(context unsafe.Pointer, value int64) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntFinishUnsigned", startVMHookCall(vmHooks))
 	vmHooks.SmallIntFinishUnsigned(value)
 }
 
 //export v1_5_smallIntFinishSigned
 func v1_5_smallIntFinishSigned(context unsafe.Pointer, value int64) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntFinishSigned", startVMHookCall(vmHooks))
 	vmHooks.SmallIntFinishSigned(value)
 }
 
 //export v1_5_smallIntStorageStoreUnsigned
 func v1_5_smallIntStorageStoreUnsigned(context unsafe.Pointer, keyOffset int32, keyLength int32, value int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntStorageStoreUnsigned", startVMHookCall(vmHooks))
 	return vmHooks.SmallIntStorageStoreUnsigned(keyOffset, keyLength, value)
 }
 
 //export v1_5_smallIntStorageStoreSigned
 func v1_5_smallIntStorageStoreSigned(context unsafe.Pointer, keyOffset int32, keyLength int32, value int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntStorageStoreSigned", startVMHookCall(vmHooks))
 	return vmHooks.SmallIntStorageStoreSigned(keyOffset, keyLength, value)
 }
 
 //export v1_5_smallIntStorageLoadUnsigned
 func v1_5_smallIntStorageLoadUnsigned(context unsafe.Pointer, keyOffset int32, keyLength int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntStorageLoadUnsigned", startVMHookCall(vmHooks))
 	return vmHooks.SmallIntStorageLoadUnsigned(keyOffset, keyLength)
 }
 
 //export v1_5_smallIntStorageLoadSigned
 func v1_5_smallIntStorageLoadSigned(context unsafe.Pointer, keyOffset int32, keyLength int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "smallIntStorageLoadSigned", startVMHookCall(vmHooks))
 	return vmHooks.SmallIntStorageLoadSigned(keyOffset, keyLength)
 }
 
 //export v1_5_int64getArgument
 func v1_5_int64getArgument(context unsafe.Pointer, id int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "int64getArgument", startVMHookCall(vmHooks))
 	return vmHooks.Int64getArgument(id)
 }
 
 //export v1_5_int64finish
 func v1_5_int64finish(context unsafe.Pointer, value int64) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "int64finish", startVMHookCall(vmHooks))
 	vmHooks.Int64finish(value)
 }
 
 //export v1_5_int64storageStore
 func v1_5_int64storageStore(context unsafe.Pointer, keyOffset int32, keyLength int32, value int64) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "int64storageStore", startVMHookCall(vmHooks))
 	return vmHooks.Int64storageStore(keyOffset, keyLength, value)
 }
 
 //export v1_5_int64storageLoad
 func v1_5_int64storageLoad(context unsafe.Pointer, keyOffset int32, keyLength int32) int64 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "int64storageLoad", startVMHookCall(vmHooks))
 	return vmHooks.Int64storageLoad(keyOffset, keyLength)
 }
 
 //export v1_5_sha256
 func v1_5_sha256(context unsafe.Pointer, dataOffset int32, length int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "sha256", startVMHookCall(vmHooks))
 	return vmHooks.Sha256(dataOffset, length, resultOffset)
 }
 
 //export v1_5_managedSha256
 func v1_5_managedSha256(context unsafe.Pointer, inputHandle int32, outputHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedSha256", startVMHookCall(vmHooks))
 	return vmHooks.ManagedSha256(inputHandle, outputHandle)
 }
 
 //export v1_5_keccak256
 func v1_5_keccak256(context unsafe.Pointer, dataOffset int32, length int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "keccak256", startVMHookCall(vmHooks))
 	return vmHooks.Keccak256(dataOffset, length, resultOffset)
 }
 
 //export v1_5_managedKeccak256
 func v1_5_managedKeccak256(context unsafe.Pointer, inputHandle int32, outputHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedKeccak256", startVMHookCall(vmHooks))
 	return vmHooks.ManagedKeccak256(inputHandle, outputHandle)
 }
 
 //export v1_5_ripemd160
 func v1_5_ripemd160(context unsafe.Pointer, dataOffset int32, length int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "ripemd160", startVMHookCall(vmHooks))
 	return vmHooks.Ripemd160(dataOffset, length, resultOffset)
 }
 
 //export v1_5_managedRipemd160
 func v1_5_managedRipemd160(context unsafe.Pointer, inputHandle int32, outputHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedRipemd160", startVMHookCall(vmHooks))
 	return vmHooks.ManagedRipemd160(inputHandle, outputHandle)
 }
 
 //export v1_5_verifyBLS
 func v1_5_verifyBLS(context unsafe.Pointer, keyOffset int32, messageOffset int32, messageLength int32, sigOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "verifyBLS", startVMHookCall(vmHooks))
 	return vmHooks.VerifyBLS(keyOffset, messageOffset, messageLength, sigOffset)
 }
 
 //export v1_5_managedVerifyBLS
 func v1_5_managedVerifyBLS(context unsafe.Pointer, keyHandle int32, messageHandle int32, sigHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedVerifyBLS", startVMHookCall(vmHooks))
 	return vmHooks.ManagedVerifyBLS(keyHandle, messageHandle, sigHandle)
 }
 
 //export v1_5_verifyEd25519
 func v1_5_verifyEd25519(context unsafe.Pointer, keyOffset int32, messageOffset int32, messageLength int32, sigOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "verifyEd25519", startVMHookCall(vmHooks))
 	return vmHooks.VerifyEd25519(keyOffset, messageOffset, messageLength, sigOffset)
 }
 
 //export v1_5_managedVerifyEd25519
 func v1_5_managedVerifyEd25519(context unsafe.Pointer, keyHandle int32, messageHandle int32, sigHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedVerifyEd25519", startVMHookCall(vmHooks))
 	return vmHooks.ManagedVerifyEd25519(keyHandle, messageHandle, sigHandle)
 }
 
 //export v1_5_verifyCustomSecp256k1
 func v1_5_verifyCustomSecp256k1(context unsafe.Pointer, keyOffset int32, keyLength int32, messageOffset int32, messageLength int32, sigOffset int32, hashType int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "verifyCustomSecp256k1", startVMHookCall(vmHooks))
 	return vmHooks.VerifyCustomSecp256k1(keyOffset, keyLength, messageOffset, messageLength, sigOffset, hashType)
 }
 
 //export v1_5_managedVerifyCustomSecp256k1
 func v1_5_managedVerifyCustomSecp256k1(context unsafe.Pointer, keyHandle int32, messageHandle int32, sigHandle int32, hashType int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedVerifyCustomSecp256k1", startVMHookCall(vmHooks))
 	return vmHooks.ManagedVerifyCustomSecp256k1(keyHandle, messageHandle, sigHandle, hashType)
 }
 
 //export v1_5_verifySecp256k1
 func v1_5_verifySecp256k1(context unsafe.Pointer, keyOffset int32, keyLength int32, messageOffset int32, messageLength int32, sigOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "verifySecp256k1", startVMHookCall(vmHooks))
 	return vmHooks.VerifySecp256k1(keyOffset, keyLength, messageOffset, messageLength, sigOffset)
 }
 
 //export v1_5_managedVerifySecp256k1
 func v1_5_managedVerifySecp256k1(context unsafe.Pointer, keyHandle int32, messageHandle int32, sigHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedVerifySecp256k1", startVMHookCall(vmHooks))
 	return vmHooks.ManagedVerifySecp256k1(keyHandle, messageHandle, sigHandle)
 }
 
 //export v1_5_encodeSecp256k1DerSignature
 func v1_5_encodeSecp256k1DerSignature(context unsafe.Pointer, rOffset int32, rLength int32, sOffset int32, sLength int32, sigOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "encodeSecp256k1DerSignature", startVMHookCall(vmHooks))
 	return vmHooks.EncodeSecp256k1DerSignature(rOffset, rLength, sOffset, sLength, sigOffset)
 }
 
 //export v1_5_managedEncodeSecp256k1DerSignature
 func v1_5_managedEncodeSecp256k1DerSignature(context unsafe.Pointer, rHandle int32, sHandle int32, sigHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedEncodeSecp256k1DerSignature", startVMHookCall(vmHooks))
 	return vmHooks.ManagedEncodeSecp256k1DerSignature(rHandle, sHandle, sigHandle)
 }
 
 //export v1_5_addEC
 func v1_5_addEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, fstPointXHandle int32, fstPointYHandle int32, sndPointXHandle int32, sndPointYHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "addEC", startVMHookCall(vmHooks))
 	vmHooks.AddEC(xResultHandle, yResultHandle, ecHandle, fstPointXHandle, fstPointYHandle, sndPointXHandle, sndPointYHandle)
 }
 
 //export v1_5_doubleEC
 func v1_5_doubleEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, pointXHandle int32, pointYHandle int32) {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "doubleEC", startVMHookCall(vmHooks))
 	vmHooks.DoubleEC(xResultHandle, yResultHandle, ecHandle, pointXHandle, pointYHandle)
 }
 
 //export v1_5_isOnCurveEC
 func v1_5_isOnCurveEC(context unsafe.Pointer, ecHandle int32, pointXHandle int32, pointYHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "isOnCurveEC", startVMHookCall(vmHooks))
 	return vmHooks.IsOnCurveEC(ecHandle, pointXHandle, pointYHandle)
 }
 
 //export v1_5_scalarBaseMultEC
 func v1_5_scalarBaseMultEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, dataOffset int32, length int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "scalarBaseMultEC", startVMHookCall(vmHooks))
 	return vmHooks.ScalarBaseMultEC(xResultHandle, yResultHandle, ecHandle, dataOffset, length)
 }
 
 //export v1_5_managedScalarBaseMultEC
 func v1_5_managedScalarBaseMultEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, dataHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedScalarBaseMultEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedScalarBaseMultEC(xResultHandle, yResultHandle, ecHandle, dataHandle)
 }
 
 //export v1_5_scalarMultEC
 func v1_5_scalarMultEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, pointXHandle int32, pointYHandle int32, dataOffset int32, length int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "scalarMultEC", startVMHookCall(vmHooks))
 	return vmHooks.ScalarMultEC(xResultHandle, yResultHandle, ecHandle, pointXHandle, pointYHandle, dataOffset, length)
 }
 
 //export v1_5_managedScalarMultEC
 func v1_5_managedScalarMultEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, pointXHandle int32, pointYHandle int32, dataHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedScalarMultEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedScalarMultEC(xResultHandle, yResultHandle, ecHandle, pointXHandle, pointYHandle, dataHandle)
 }
 
 //export v1_5_marshalEC
 func v1_5_marshalEC(context unsafe.Pointer, xPairHandle int32, yPairHandle int32, ecHandle int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "marshalEC", startVMHookCall(vmHooks))
 	return vmHooks.MarshalEC(xPairHandle, yPairHandle, ecHandle, resultOffset)
 }
 
 //export v1_5_managedMarshalEC
 func v1_5_managedMarshalEC(context unsafe.Pointer, xPairHandle int32, yPairHandle int32, ecHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedMarshalEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedMarshalEC(xPairHandle, yPairHandle, ecHandle, resultHandle)
 }
 
 //export v1_5_marshalCompressedEC
 func v1_5_marshalCompressedEC(context unsafe.Pointer, xPairHandle int32, yPairHandle int32, ecHandle int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "marshalCompressedEC", startVMHookCall(vmHooks))
 	return vmHooks.MarshalCompressedEC(xPairHandle, yPairHandle, ecHandle, resultOffset)
 }
 
 //export v1_5_managedMarshalCompressedEC
 func v1_5_managedMarshalCompressedEC(context unsafe.Pointer, xPairHandle int32, yPairHandle int32, ecHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedMarshalCompressedEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedMarshalCompressedEC(xPairHandle, yPairHandle, ecHandle, resultHandle)
 }
 
 //export v1_5_unmarshalEC
 func v1_5_unmarshalEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, dataOffset int32, length int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "unmarshalEC", startVMHookCall(vmHooks))
 	return vmHooks.UnmarshalEC(xResultHandle, yResultHandle, ecHandle, dataOffset, length)
 }
 
 //export v1_5_managedUnmarshalEC
 func v1_5_managedUnmarshalEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, dataHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedUnmarshalEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedUnmarshalEC(xResultHandle, yResultHandle, ecHandle, dataHandle)
 }
 
 //export v1_5_unmarshalCompressedEC
 func v1_5_unmarshalCompressedEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, dataOffset int32, length int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "unmarshalCompressedEC", startVMHookCall(vmHooks))
 	return vmHooks.UnmarshalCompressedEC(xResultHandle, yResultHandle, ecHandle, dataOffset, length)
 }
 
 //export v1_5_managedUnmarshalCompressedEC
 func v1_5_managedUnmarshalCompressedEC(context unsafe.Pointer, xResultHandle int32, yResultHandle int32, ecHandle int32, dataHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedUnmarshalCompressedEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedUnmarshalCompressedEC(xResultHandle, yResultHandle, ecHandle, dataHandle)
 }
 
 //export v1_5_generateKeyEC
 func v1_5_generateKeyEC(context unsafe.Pointer, xPubKeyHandle int32, yPubKeyHandle int32, ecHandle int32, resultOffset int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "generateKeyEC", startVMHookCall(vmHooks))
 	return vmHooks.GenerateKeyEC(xPubKeyHandle, yPubKeyHandle, ecHandle, resultOffset)
 }
 
 //export v1_5_managedGenerateKeyEC
 func v1_5_managedGenerateKeyEC(context unsafe.Pointer, xPubKeyHandle int32, yPubKeyHandle int32, ecHandle int32, resultHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedGenerateKeyEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedGenerateKeyEC(xPubKeyHandle, yPubKeyHandle, ecHandle, resultHandle)
 }
 
 //export v1_5_createEC
 func v1_5_createEC(context unsafe.Pointer, dataOffset int32, dataLength int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "createEC", startVMHookCall(vmHooks))
 	return vmHooks.CreateEC(dataOffset, dataLength)
 }
 
 //export v1_5_managedCreateEC
 func v1_5_managedCreateEC(context unsafe.Pointer, dataHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "managedCreateEC", startVMHookCall(vmHooks))
 	return vmHooks.ManagedCreateEC(dataHandle)
 }
 
 //export v1_5_getCurveLengthEC
 func v1_5_getCurveLengthEC(context unsafe.Pointer, ecHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getCurveLengthEC", startVMHookCall(vmHooks))
 	return vmHooks.GetCurveLengthEC(ecHandle)
 }
 
 //export v1_5_getPrivKeyByteLengthEC
 func v1_5_getPrivKeyByteLengthEC(context unsafe.Pointer, ecHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "getPrivKeyByteLengthEC", startVMHookCall(vmHooks))
 	return vmHooks.GetPrivKeyByteLengthEC(ecHandle)
 }
 
 //export v1_5_ellipticCurveGetValues
 func v1_5_ellipticCurveGetValues(context unsafe.Pointer, ecHandle int32, fieldOrderHandle int32, basePointOrderHandle int32, eqConstantHandle int32, xBasePointHandle int32, yBasePointHandle int32) int32 {
 	vmHooks := getVMHooksFromContextRawPtr(context)
+	defer finishVMHookCall(vmHooks, "ellipticCurveGetValues", startVMHookCall(vmHooks))
 	return vmHooks.EllipticCurveGetValues(ecHandle, fieldOrderHandle, basePointOrderHandle, eqConstantHandle, xBasePointHandle, yBasePointHandle)
 }
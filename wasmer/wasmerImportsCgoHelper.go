@@ -1,6 +1,8 @@
 package wasmer
 
 import (
+	"sync"
+	"time"
 	"unsafe"
 
 	vmcommon "github.com/ElrondNetwork/elrond-vm-common"
@@ -13,11 +15,69 @@ func getVMHooksFromContextRawPtr(contextPtr unsafe.Pointer) executor.VMHooks {
 	return *(*executor.VMHooks)(unsafe.Pointer(vmHooksPtr))
 }
 
+// startVMHookCall returns the moment when a VM hook call starts, or the zero
+// time if the VMHooks do not observe the duration of their calls
+func startVMHookCall(vmHooks executor.VMHooks) time.Time {
+	callObserver, ok := vmHooks.(executor.VMHookCallObserver)
+	if !ok || !callObserver.VMHookCallsObserved() {
+		return time.Time{}
+	}
+	return time.Now()
+}
+
+// finishVMHookCall is deferred by every exported VM hook; it reports the
+// duration of the call to the VMHooks observing them, and recovers from
+// panics, so that a panic in the host code never unwinds through the Wasmer
+// frames and takes down the process
+func finishVMHookCall(vmHooks executor.VMHooks, hookName string, startTime time.Time) {
+	if !startTime.IsZero() {
+		callObserver, ok := vmHooks.(executor.VMHookCallObserver)
+		if ok {
+			callObserver.ObserveVMHookCall(hookName, time.Since(startTime))
+		}
+	}
+
+	panicValue := recover()
+	if panicValue == nil {
+		return
+	}
+
+	panicHandler, ok := vmHooks.(executor.VMHookPanicHandler)
+	if !ok {
+		panic(panicValue)
+	}
+	panicHandler.HandleVMHookPanic(hookName, panicValue)
+}
+
+var importsOnce sync.Once
+var importNames vmcommon.FunctionNames
+var importsErr error
+
+// injectCgoFunctionPointers builds the import object of the host functions and
+// caches it in Wasmer, which links it to every new instance; the import object
+// does not depend on the executor, so it is built only once per process,
+// however many executors are created
 func injectCgoFunctionPointers() (vmcommon.FunctionNames, error) {
+	importsOnce.Do(func() {
+		importNames, importsErr = buildCachedImportObject()
+	})
+	return importNames, importsErr
+}
+
+func buildCachedImportObject() (vmcommon.FunctionNames, error) {
 	importsInfo := newWasmerImports()
 	defer importsInfo.Close()
 
 	populateWasmerImports(importsInfo)
+	err := importsInfo.appendAliases(executor.HostFunctionAliases)
+	if err != nil {
+		return nil, err
+	}
+	err = populateWASIImports(importsInfo)
+	if err != nil {
+		return nil, err
+	}
+
 	wasmImportsCPointer, numberOfImports := generateWasmerImports(importsInfo)
 
 	var result = cWasmerCacheImportObjectFromImports(
@@ -911,7 +911,10 @@ func toOpcodeCostsArray(opcode_costs_struct *executor.WASMOpcodeCost) [opcodeCou
 	opcode_costs[OpcodeLocalAllocate] = opcode_costs_struct.LocalAllocate
 	// LocalsUnmetered, MaxMemoryGrow and MaxMemoryGrowDelta are not added to the
 	// opcode_costs array; the values will be sent to Wasmer as compilation
-	// options instead
+	// options instead; MemoryGrowPerPage and MaxMemoryPages are not read by the
+	// metering middleware of Wasmer, so the runtime context applies them after
+	// each call of the instance; MaxStackHeight is
+	// applied by instrumenting the contract code before it is compiled
 
 	return opcode_costs
 }
@@ -0,0 +1,9 @@
+package wasmreader
+
+import "errors"
+
+// ErrUnexpectedEnd signals that the contract binary ended in the middle of a construct
+var ErrUnexpectedEnd = errors.New("unexpected end of wasm binary")
+
+// ErrInvalidLEB128 signals that a variable-length integer is longer than its type allows
+var ErrInvalidLEB128 = errors.New("invalid LEB128 integer")
@@ -0,0 +1,163 @@
+package wasmreader
+
+import (
+	"encoding/binary"
+)
+
+// Reader decodes the primitive values of the wasm binary format, for the
+// interpreter, the inspector and the post-processors of the contract builds
+type Reader struct {
+	data   []byte
+	offset int
+}
+
+// NewReader creates a Reader positioned at the start of the data
+func NewReader(data []byte) *Reader {
+	return &Reader{
+		data: data,
+	}
+}
+
+// HasMore returns true if some bytes are left to read
+func (reader *Reader) HasMore() bool {
+	return reader.offset < len(reader.data)
+}
+
+// Offset returns the number of bytes read so far
+func (reader *Reader) Offset() int {
+	return reader.offset
+}
+
+// Remaining returns the number of bytes left to read
+func (reader *Reader) Remaining() int {
+	return len(reader.data) - reader.offset
+}
+
+// Rest returns the bytes left to read, without consuming them
+func (reader *Reader) Rest() []byte {
+	return reader.data[reader.offset:]
+}
+
+// Since returns the bytes read from the given offset up to the current one
+func (reader *Reader) Since(start int) []byte {
+	return reader.data[start:reader.offset]
+}
+
+// ReadByte reads a single byte
+func (reader *Reader) ReadByte() (byte, error) {
+	if !reader.HasMore() {
+		return 0, ErrUnexpectedEnd
+	}
+	value := reader.data[reader.offset]
+	reader.offset++
+	return value, nil
+}
+
+// PeekByte returns the next byte without consuming it
+func (reader *Reader) PeekByte() (byte, error) {
+	if !reader.HasMore() {
+		return 0, ErrUnexpectedEnd
+	}
+	return reader.data[reader.offset], nil
+}
+
+// ReadBytes reads the given number of bytes, sharing them with the data
+func (reader *Reader) ReadBytes(length uint32) ([]byte, error) {
+	if uint64(reader.Remaining()) < uint64(length) {
+		return nil, ErrUnexpectedEnd
+	}
+	value := reader.data[reader.offset : reader.offset+int(length)]
+	reader.offset += int(length)
+	return value, nil
+}
+
+// Skip consumes the given number of bytes
+func (reader *Reader) Skip(length uint32) error {
+	_, err := reader.ReadBytes(length)
+	return err
+}
+
+// ReadU32 decodes an unsigned LEB128 integer of at most 32 bits
+func (reader *Reader) ReadU32() (uint32, error) {
+	value, err := reader.ReadUnsigned(32)
+	return uint32(value), err
+}
+
+// ReadFixedU32 decodes a little-endian integer of 4 bytes
+func (reader *Reader) ReadFixedU32() (uint32, error) {
+	value, err := reader.ReadBytes(4)
+	if err != nil {
+		return 0, err
+	}
+	return binary.LittleEndian.Uint32(value), nil
+}
+
+// ReadFixedU64 decodes a little-endian integer of 8 bytes
+func (reader *Reader) ReadFixedU64() (uint64, error) {
+	value, err := reader.ReadBytes(8)
+	if err != nil {
+		return 0, err
+	}
+	return binary.LittleEndian.Uint64(value), nil
+}
+
+// ReadUnsigned decodes an unsigned LEB128 integer of at most the given number of bits
+func (reader *Reader) ReadUnsigned(bits uint) (uint64, error) {
+	result := uint64(0)
+	for shift := uint(0); ; shift += 7 {
+		if shift >= bits {
+			return 0, ErrInvalidLEB128
+		}
+		b, err := reader.ReadByte()
+		if err != nil {
+			return 0, err
+		}
+		result |= uint64(b&0x7f) << shift
+		if b&0x80 == 0 {
+			return result, nil
+		}
+	}
+}
+
+// ReadSigned decodes a signed LEB128 integer of at most the given number of
+// bits, sign-extended to 64 bits
+func (reader *Reader) ReadSigned(bits uint) (int64, error) {
+	result := int64(0)
+	for shift := uint(0); ; shift += 7 {
+		if shift >= bits {
+			return 0, ErrInvalidLEB128
+		}
+		b, err := reader.ReadByte()
+		if err != nil {
+			return 0, err
+		}
+		result |= int64(b&0x7f) << shift
+		if b&0x80 != 0 {
+			continue
+		}
+		shift += 7
+		if shift < 64 && b&0x40 != 0 {
+			result |= -1 << shift
+		}
+		return result, nil
+	}
+}
+
+// SkipSigned consumes a signed LEB128 integer of at most the given number of bits
+func (reader *Reader) SkipSigned(bits uint) error {
+	_, err := reader.ReadSigned(bits)
+	return err
+}
+
+// ReadName decodes a name, as a length followed by its bytes
+func (reader *Reader) ReadName() (string, error) {
+	length, err := reader.ReadU32()
+	if err != nil {
+		return "", err
+	}
+	name, err := reader.ReadBytes(length)
+	if err != nil {
+		return "", err
+	}
+	return string(name), nil
+}
@@ -0,0 +1,61 @@
+package wasmreader
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestReader_Unsigned(t *testing.T) {
+	reader := NewReader([]byte{0xe5, 0x8e, 0x26, 0x07})
+	value, err := reader.ReadU32()
+	require.Nil(t, err)
+	require.Equal(t, uint32(624485), value)
+	require.Equal(t, 3, reader.Offset())
+	require.Equal(t, 1, reader.Remaining())
+
+	value, err = reader.ReadU32()
+	require.Nil(t, err)
+	require.Equal(t, uint32(7), value)
+	require.False(t, reader.HasMore())
+
+	_, err = reader.ReadU32()
+	require.Equal(t, ErrUnexpectedEnd, err)
+
+	_, err = NewReader([]byte{0x80, 0x80, 0x80, 0x80, 0x80, 0x01}).ReadU32()
+	require.Equal(t, ErrInvalidLEB128, err)
+}
+
+func TestReader_Signed(t *testing.T) {
+	value, err := NewReader([]byte{0x7f}).ReadSigned(32)
+	require.Nil(t, err)
+	require.Equal(t, int64(-1), value)
+
+	value, err = NewReader([]byte{0xc0, 0xbb, 0x78}).ReadSigned(64)
+	require.Nil(t, err)
+	require.Equal(t, int64(-123456), value)
+
+	reader := NewReader([]byte{0x80, 0x7f, 0x2a})
+	require.Nil(t, reader.SkipSigned(32))
+	require.Equal(t, []byte{0x80, 0x7f}, reader.Since(0))
+	require.Equal(t, []byte{0x2a}, reader.Rest())
+}
+
+func TestReader_BytesAndNames(t *testing.T) {
+	reader := NewReader([]byte{0x03, 'a', 'b', 'c', 0x01, 0x02, 0x03, 0x04, 0xff})
+	name, err := reader.ReadName()
+	require.Nil(t, err)
+	require.Equal(t, "abc", name)
+
+	fixed, err := reader.ReadFixedU32()
+	require.Nil(t, err)
+	require.Equal(t, uint32(0x04030201), fixed)
+
+	next, err := reader.PeekByte()
+	require.Nil(t, err)
+	require.Equal(t, byte(0xff), next)
+	require.Equal(t, ErrUnexpectedEnd, reader.Skip(2))
+
+	_, err = NewReader([]byte{0x05, 'a'}).ReadName()
+	require.Equal(t, ErrUnexpectedEnd, err)
+}